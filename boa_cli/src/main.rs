@@ -59,11 +59,13 @@
     rustdoc::missing_doc_code_examples
 )]
 
-use boa_engine::{syntax::ast::node::StatementList, Context};
+use boa_engine::{
+    builtins::temporal::ZoneInfoTimeZoneProvider, syntax::ast::node::StatementList, Context,
+};
 use clap::{ArgEnum, Parser};
 use colored::{Color, Colorize};
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
-use std::{fs::read, fs::OpenOptions, io, path::PathBuf};
+use std::{fs::read, fs::OpenOptions, io, path::PathBuf, rc::Rc};
 mod helper;
 
 #[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
@@ -183,7 +185,14 @@ where
 pub fn main() -> Result<(), io::Error> {
     let args = Opt::parse();
 
-    let mut context = Context::default();
+    // The CLI owns its thread, so `Atomics.wait` can suspend it, like in a worker.
+    let mut builder = Context::builder().can_block(true);
+
+    // Named time zones are resolved with the time zone database of the system, if it has one.
+    if let Ok(provider) = ZoneInfoTimeZoneProvider::from_system() {
+        builder = builder.time_zone_provider(Rc::new(provider));
+    }
+    let mut context = builder.build();
 
     // Trace Output
     context.set_trace(args.trace);
@@ -196,9 +205,15 @@ pub fn main() -> Result<(), io::Error> {
                 eprintln!("{e}");
             }
         } else {
-            match context.eval(&buffer) {
+            match context.eval_with_name(&buffer, file.display().to_string()) {
                 Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {}", v.display()),
+                Err(v) => {
+                    let v = v.to_opaque(&mut context);
+                    eprintln!("Uncaught {}", v.display());
+                    for frame in v.stack_trace().unwrap_or_default() {
+                        eprintln!("    at {frame}");
+                    }
+                }
             }
         }
     }
@@ -248,7 +263,7 @@ pub fn main() -> Result<(), io::Error> {
                                 eprintln!(
                                     "{}: {}",
                                     "Uncaught".red(),
-                                    v.display().to_string().red()
+                                    v.to_opaque(&mut context).display().to_string().red()
                                 );
                             }
                         }
@@ -1,6 +1,6 @@
 //! Benchmarks of the whole execution engine in Boa.
 
-use boa_engine::{realm::Realm, Context};
+use boa_engine::Context;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 #[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
@@ -11,7 +11,8 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
 
 fn create_realm(c: &mut Criterion) {
-    c.bench_function("Create Realm", move |b| b.iter(Realm::create));
+    let mut context = Context::default();
+    c.bench_function("Create Realm", move |b| b.iter(|| context.create_realm()));
 }
 
 macro_rules! full_benchmarks {
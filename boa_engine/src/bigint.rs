@@ -1,12 +1,16 @@
 //! This module implements the JavaScript bigint primitive rust type.
 
-use crate::{builtins::Number, Context, JsValue};
+use crate::{
+    builtins::Number,
+    error::JsNativeError,
+    value::{TaggedRc, BIGINT_TAG},
+    JsResult,
+};
 use num_integer::Integer;
 use num_traits::{pow::Pow, FromPrimitive, One, ToPrimitive, Zero};
 use std::{
     fmt::{self, Display},
     ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Shl, Shr, Sub},
-    rc::Rc,
 };
 
 /// The raw bigint type.
@@ -18,11 +22,18 @@ use serde::{Deserialize, Serialize};
 /// JavaScript bigint primitive rust type.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct JsBigInt {
-    inner: Rc<RawBigInt>,
+    inner: TaggedRc<RawBigInt, BIGINT_TAG>,
 }
 
 impl JsBigInt {
+    /// Consumes the bigint, returning its tagged address.
+    #[inline]
+    pub(crate) fn into_raw(self) -> usize {
+        self.inner.into_raw()
+    }
+
     /// Create a new [`JsBigInt`].
     #[inline]
     pub fn new<T: Into<Self>>(value: T) -> Self {
@@ -33,7 +44,7 @@ impl JsBigInt {
     #[inline]
     pub fn zero() -> Self {
         Self {
-            inner: Rc::new(RawBigInt::zero()),
+            inner: TaggedRc::new(RawBigInt::zero()),
         }
     }
 
@@ -47,7 +58,7 @@ impl JsBigInt {
     #[inline]
     pub fn one() -> Self {
         Self {
-            inner: Rc::new(RawBigInt::one()),
+            inner: TaggedRc::new(RawBigInt::one()),
         }
     }
 
@@ -75,7 +86,7 @@ impl JsBigInt {
     #[inline]
     pub fn from_string_radix(buf: &str, radix: u32) -> Option<Self> {
         Some(Self {
-            inner: Rc::new(RawBigInt::parse_bytes(buf.as_bytes(), radix)?),
+            inner: TaggedRc::new(RawBigInt::parse_bytes(buf.as_bytes(), radix)?),
         })
     }
 
@@ -148,11 +159,13 @@ impl JsBigInt {
     }
 
     #[inline]
-    pub fn pow(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn pow(x: &Self, y: &Self) -> JsResult<Self> {
         let y = if let Some(y) = y.inner.to_biguint() {
             y
         } else {
-            return context.throw_range_error("BigInt negative exponent");
+            return Err(JsNativeError::range()
+                .with_message("BigInt negative exponent")
+                .into());
         };
 
         let num_bits = (x.inner.bits() as f64
@@ -161,14 +174,16 @@ impl JsBigInt {
             + 1f64;
 
         if num_bits > 1_000_000_000f64 {
-            return context.throw_range_error("Maximum BigInt size exceeded");
+            return Err(JsNativeError::range()
+                .with_message("Maximum BigInt size exceeded")
+                .into());
         }
 
         Ok(Self::new(x.inner.as_ref().clone().pow(y)))
     }
 
     #[inline]
-    pub fn shift_right(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn shift_right(x: &Self, y: &Self) -> JsResult<Self> {
         if let Some(n) = y.inner.to_i32() {
             let inner = if n > 0 {
                 x.inner.as_ref().clone().shr(n as usize)
@@ -178,12 +193,14 @@ impl JsBigInt {
 
             Ok(Self::new(inner))
         } else {
-            context.throw_range_error("Maximum BigInt size exceeded")
+            Err(JsNativeError::range()
+                .with_message("Maximum BigInt size exceeded")
+                .into())
         }
     }
 
     #[inline]
-    pub fn shift_left(x: &Self, y: &Self, context: &mut Context) -> Result<Self, JsValue> {
+    pub fn shift_left(x: &Self, y: &Self) -> JsResult<Self> {
         if let Some(n) = y.inner.to_i32() {
             let inner = if n > 0 {
                 x.inner.as_ref().clone().shl(n as usize)
@@ -193,7 +210,9 @@ impl JsBigInt {
 
             Ok(Self::new(inner))
         } else {
-            context.throw_range_error("Maximum BigInt size exceeded")
+            Err(JsNativeError::range()
+                .with_message("Maximum BigInt size exceeded")
+                .into())
         }
     }
 
@@ -269,7 +288,7 @@ impl JsBigInt {
 impl Display for JsBigInt {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Display::fmt(&self.inner, f)
+        Display::fmt(self.as_inner(), f)
     }
 }
 
@@ -277,7 +296,7 @@ impl From<RawBigInt> for JsBigInt {
     #[inline]
     fn from(value: RawBigInt) -> Self {
         Self {
-            inner: Rc::new(value),
+            inner: TaggedRc::new(value),
         }
     }
 }
@@ -286,7 +305,7 @@ impl From<Box<RawBigInt>> for JsBigInt {
     #[inline]
     fn from(value: Box<RawBigInt>) -> Self {
         Self {
-            inner: value.into(),
+            inner: TaggedRc::new(*value),
         }
     }
 }
@@ -295,7 +314,7 @@ impl From<i8> for JsBigInt {
     #[inline]
     fn from(value: i8) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -304,7 +323,7 @@ impl From<u8> for JsBigInt {
     #[inline]
     fn from(value: u8) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -313,7 +332,7 @@ impl From<i16> for JsBigInt {
     #[inline]
     fn from(value: i16) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -322,7 +341,7 @@ impl From<u16> for JsBigInt {
     #[inline]
     fn from(value: u16) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -331,7 +350,7 @@ impl From<i32> for JsBigInt {
     #[inline]
     fn from(value: i32) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -340,7 +359,7 @@ impl From<u32> for JsBigInt {
     #[inline]
     fn from(value: u32) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -349,7 +368,7 @@ impl From<i64> for JsBigInt {
     #[inline]
     fn from(value: i64) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -358,7 +377,7 @@ impl From<u64> for JsBigInt {
     #[inline]
     fn from(value: u64) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -367,7 +386,7 @@ impl From<isize> for JsBigInt {
     #[inline]
     fn from(value: isize) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -376,7 +395,7 @@ impl From<usize> for JsBigInt {
     #[inline]
     fn from(value: usize) -> Self {
         Self {
-            inner: Rc::new(RawBigInt::from(value)),
+            inner: TaggedRc::new(RawBigInt::from(value)),
         }
     }
 }
@@ -23,13 +23,14 @@ use crate::{
     builtins::BuiltIn,
     builtins::Number,
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsFunction, JsObject, ObjectData,
     },
     property::{Attribute, PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
-    value::{IntegerOrInfinity, JsValue},
+    value::{IntegerOrInfinity, JsValue, JsVariant},
     Context, JsResult, JsString,
 };
 use std::cmp::{max, min, Ordering};
@@ -176,7 +177,9 @@ impl Array {
                     .expect("this ToUint32 call must not fail");
                 // ii. If SameValueZero(intLen, len) is false, throw a RangeError exception.
                 if !JsValue::same_value_zero(&int_len.into(), len) {
-                    return context.throw_range_error("invalid array length");
+                    return Err(JsNativeError::range()
+                        .with_message("invalid array length")
+                        .into());
                 }
                 int_len
             };
@@ -223,7 +226,9 @@ impl Array {
     ) -> JsResult<JsObject> {
         // 1. If length > 2^32 - 1, throw a RangeError exception.
         if length > 2u64.pow(32) - 1 {
-            return context.throw_range_error("array exceeded max size");
+            return Err(JsNativeError::range()
+                .with_message("array exceeded max size")
+                .into());
         }
         // 7. Return A.
         // 2. If proto is not present, set proto to %Array.prototype%.
@@ -376,7 +381,9 @@ impl Array {
             // 8. Return ? Construct(C, « 𝔽(length) »).
             c.construct(&[JsValue::new(length)], Some(c), context)
         } else {
-            context.throw_type_error("Symbol.species must be a constructor")
+            Err(JsNativeError::typ()
+                .with_message("Symbol.species must be a constructor")
+                .into())
         }
     }
 
@@ -404,10 +411,14 @@ impl Array {
         // 3. Else,
         //     a. If IsCallable(mapfn) is false, throw a TypeError exception.
         //     b. Let mapping be true.
-        let mapping = match mapfn {
-            JsValue::Undefined => None,
-            JsValue::Object(o) if o.is_callable() => Some(o),
-            _ => return context.throw_type_error(format!("{} is not a function", mapfn.type_of())),
+        let mapping = match mapfn.variant() {
+            JsVariant::Undefined => None,
+            JsVariant::Object(o) if o.is_callable() => Some(o),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("{} is not a function", mapfn.type_of()))
+                    .into())
+            }
         };
 
         // 4. Let usingIterator be ? GetMethod(items, @@iterator).
@@ -477,7 +488,9 @@ impl Array {
             // which is why it's safe to have this as the fallback return
             //
             // 1. Let error be ThrowCompletion(a newly created TypeError object).
-            let error = context.throw_type_error("Invalid array length");
+            let error = Err(JsNativeError::typ()
+                .with_message("Invalid array length")
+                .into());
 
             // 2. Return ? IteratorClose(iteratorRecord, error).
             iterator_record.close(error, context)
@@ -668,9 +681,11 @@ impl Array {
                 let len = item.length_of_array_like(context)?;
                 // iii. If n + len > 2^53 - 1, throw a TypeError exception.
                 if n + len > Number::MAX_SAFE_INTEGER as u64 {
-                    return context.throw_type_error(
-                        "length + number of arguments exceeds the max safe integer limit",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message(
+                            "length + number of arguments exceeds the max safe integer limit",
+                        )
+                        .into());
                 }
                 // iv. Repeat, while k < len,
                 for k in 0..len {
@@ -694,7 +709,9 @@ impl Array {
                 // i. NOTE: E is added as a single item rather than spread.
                 // ii. If n ≥ 2^53 - 1, throw a TypeError exception.
                 if n >= Number::MAX_SAFE_INTEGER as u64 {
-                    return context.throw_type_error("length exceeds the max safe integer limit");
+                    return Err(JsNativeError::typ()
+                        .with_message("length exceeds the max safe integer limit")
+                        .into());
                 }
                 // iii. Perform ? CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), E).
                 arr.create_data_property_or_throw(n, item, context)?;
@@ -734,9 +751,11 @@ impl Array {
         let arg_count = args.len() as u64;
         // 4. If len + argCount > 2^53 - 1, throw a TypeError exception.
         if len + arg_count > 2u64.pow(53) - 1 {
-            return context.throw_type_error(
-                "the length + the number of arguments exceed the maximum safe integer limit",
-            );
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "the length + the number of arguments exceed the maximum safe integer limit",
+                )
+                .into());
         }
         // 5. For each element E of items, do
         for element in args.iter().cloned() {
@@ -811,7 +830,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.forEach: invalid callback function")
+            JsNativeError::typ().with_message("Array.prototype.forEach: invalid callback function")
         })?;
         // 4. Let k be 0.
         // 5. Repeat, while k < len,
@@ -865,13 +884,13 @@ impl Array {
         };
 
         // 5. Let R be the empty String.
-        let mut r = String::new();
+        let mut r = Vec::new();
         // 6. Let k be 0.
         // 7. Repeat, while k < len,
         for k in 0..len {
             // a. If k > 0, set R to the string-concatenation of R and sep.
             if k > 0 {
-                r.push_str(&separator);
+                r.extend_from_slice(&separator);
             }
             // b. Let element be ? Get(O, ! ToString(𝔽(k))).
             let element = o.get(k, context)?;
@@ -882,11 +901,11 @@ impl Array {
                 element.to_string(context)?
             };
             // d. Set R to the string-concatenation of R and next.
-            r.push_str(&next);
+            r.extend_from_slice(&next);
             // e. Set k to k + 1.
         }
         // 8. Return R.
-        Ok(r.into())
+        Ok(JsString::from(r).into())
     }
 
     /// `Array.prototype.toString( separator )`
@@ -1085,9 +1104,9 @@ impl Array {
         if arg_count > 0 {
             // a. If len + argCount > 2^53 - 1, throw a TypeError exception.
             if len + arg_count > 2u64.pow(53) - 1 {
-                return context.throw_type_error(
-                    "length + number of arguments exceeds the max safe integer limit",
-                );
+                return Err(JsNativeError::typ()
+                    .with_message("length + number of arguments exceeds the max safe integer limit")
+                    .into());
             }
             // b. Let k be len.
             let mut k = len;
@@ -1152,7 +1171,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.every: callback is not callable")
+            JsNativeError::typ().with_message("Array.prototype.every: callback is not callable")
         })?;
 
         let this_arg = args.get_or_undefined(1);
@@ -1204,7 +1223,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.map: Callbackfn is not callable")
+            JsNativeError::typ().with_message("Array.prototype.map: Callbackfn is not callable")
         })?;
 
         // 4. Let A be ? ArraySpeciesCreate(O, len).
@@ -1412,7 +1431,7 @@ impl Array {
 
         // 3. If IsCallable(predicate) is false, throw a TypeError exception.
         let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.find: predicate is not callable")
+            JsNativeError::typ().with_message("Array.prototype.find: predicate is not callable")
         })?;
 
         let this_arg = args.get_or_undefined(1);
@@ -1469,7 +1488,8 @@ impl Array {
 
         // 3. If IsCallable(predicate) is false, throw a TypeError exception.
         let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.findIndex: predicate is not callable")
+            JsNativeError::typ()
+                .with_message("Array.prototype.findIndex: predicate is not callable")
         })?;
 
         let this_arg = args.get_or_undefined(1);
@@ -1520,7 +1540,7 @@ impl Array {
 
         // 3. If IsCallable(predicate) is false, throw a TypeError exception.
         let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.findLast: predicate is not callable")
+            JsNativeError::typ().with_message("Array.prototype.findLast: predicate is not callable")
         })?;
 
         let this_arg = args.get_or_undefined(1);
@@ -1572,7 +1592,8 @@ impl Array {
 
         // 3. If IsCallable(predicate) is false, throw a TypeError exception.
         let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.findLastIndex: predicate is not callable")
+            JsNativeError::typ()
+                .with_message("Array.prototype.findLastIndex: predicate is not callable")
         })?;
 
         let this_arg = args.get_or_undefined(1);
@@ -1677,7 +1698,7 @@ impl Array {
 
         // 3. If ! IsCallable(mapperFunction) is false, throw a TypeError exception.
         let mapper_function = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("flatMap mapper function is not callable")
+            JsNativeError::typ().with_message("flatMap mapper function is not callable")
         })?;
 
         // 4. Let A be ? ArraySpeciesCreate(O, 0).
@@ -1793,8 +1814,9 @@ impl Array {
                 } else {
                     // 1. If targetIndex >= 2^53 - 1, throw a TypeError exception
                     if target_index >= Number::MAX_SAFE_INTEGER as u64 {
-                        return context
-                            .throw_type_error("Target index exceeded max safe integer value");
+                        return Err(JsNativeError::typ()
+                            .with_message("Target index exceeded max safe integer value")
+                            .into());
                     }
 
                     // 2. Perform ? CreateDataPropertyOrThrow(target, targetIndex, element)
@@ -2064,7 +2086,9 @@ impl Array {
 
         // 10. If len + insertCount - actualDeleteCount > 2^53 - 1, throw a TypeError exception.
         if len + insert_count - actual_delete_count > Number::MAX_SAFE_INTEGER as u64 {
-            return context.throw_type_error("Target splice exceeded max safe integer value");
+            return Err(JsNativeError::typ()
+                .with_message("Target splice exceeded max safe integer value")
+                .into());
         }
 
         // 11. Let A be ? ArraySpeciesCreate(O, actualDeleteCount).
@@ -2206,7 +2230,7 @@ impl Array {
 
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.filter: `callback` must be callable")
+            JsNativeError::typ().with_message("Array.prototype.filter: `callback` must be callable")
         })?;
         let this_arg = args.get_or_undefined(1);
 
@@ -2270,7 +2294,7 @@ impl Array {
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error("Array.prototype.some: callback is not callable")
+            JsNativeError::typ().with_message("Array.prototype.some: callback is not callable")
         })?;
 
         // 4. Let k be 0.
@@ -2317,13 +2341,13 @@ impl Array {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
-        let comparefn = match args.get_or_undefined(0) {
-            JsValue::Object(ref obj) if obj.is_callable() => Some(obj),
-            JsValue::Undefined => None,
+        let comparefn = match args.get_or_undefined(0).variant() {
+            JsVariant::Object(obj) if obj.is_callable() => Some(obj),
+            JsVariant::Undefined => None,
             _ => {
-                return context.throw_type_error(
-                    "The comparison function must be either a function or undefined",
-                )
+                return Err(JsNativeError::typ()
+                    .with_message("The comparison function must be either a function or undefined")
+                    .into())
             }
         };
 
@@ -2350,7 +2374,7 @@ impl Array {
                     let args = [x.clone(), y.clone()];
                     // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
                     let v = cmp
-                        .call(&JsValue::Undefined, &args, context)?
+                        .call(&JsValue::undefined(), &args, context)?
                         .to_number(context)?;
                     // b. If v is NaN, return +0𝔽.
                     // c. Return v.
@@ -2455,15 +2479,17 @@ impl Array {
 
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context
-                .construct_type_error("Array.prototype.reduce: callback function is not callable")
+            JsNativeError::typ()
+                .with_message("Array.prototype.reduce: callback function is not callable")
         })?;
 
         // 4. If len = 0 and initialValue is not present, throw a TypeError exception.
         if len == 0 && args.get(1).is_none() {
-            return context.throw_type_error(
-                "Array.prototype.reduce: called on an empty array and with no initial value",
-            );
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "Array.prototype.reduce: called on an empty array and with no initial value",
+                )
+                .into());
         }
 
         // 5. Let k be 0.
@@ -2495,9 +2521,7 @@ impl Array {
             }
             // c. If kPresent is false, throw a TypeError exception.
             if !k_present {
-                return context.throw_type_error(
-                    "Array.prototype.reduce: called on an empty array and with no initial value",
-                );
+                return Err(JsNativeError::typ().with_message("Array.prototype.reduce: called on an empty array and with no initial value").into());
             }
         }
 
@@ -2550,16 +2574,13 @@ impl Array {
 
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
-            context.construct_type_error(
-                "Array.prototype.reduceRight: callback function is not callable",
-            )
+            JsNativeError::typ()
+                .with_message("Array.prototype.reduceRight: callback function is not callable")
         })?;
 
         // 4. If len is 0 and initialValue is not present, throw a TypeError exception.
         if len == 0 && args.get(1).is_none() {
-            return context.throw_type_error(
-                "Array.prototype.reduceRight: called on an empty array and with no initial value",
-            );
+            return Err(JsNativeError::typ().with_message("Array.prototype.reduceRight: called on an empty array and with no initial value").into());
         }
 
         // 5. Let k be len - 1.
@@ -2590,9 +2611,7 @@ impl Array {
             }
             // c. If kPresent is false, throw a TypeError exception.
             if !k_present {
-                return context.throw_type_error(
-                    "Array.prototype.reduceRight: called on an empty array and with no initial value",
-                );
+                return Err(JsNativeError::typ().with_message("Array.prototype.reduceRight: called on an empty array and with no initial value").into());
             }
         }
 
@@ -2,8 +2,9 @@
 mod tests;
 
 use crate::{
-    builtins::{typed_array::TypedArrayKind, BuiltIn, JsArgs},
+    builtins::{shared_array_buffer::SharedMemory, typed_array::TypedArrayKind, BuiltIn, JsArgs},
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsObject, ObjectData,
@@ -11,6 +12,7 @@ use crate::{
     property::Attribute,
     symbol::WellKnownSymbols,
     value::{IntegerOrInfinity, Numeric},
+    vm::HeapCharge,
     Context, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
@@ -20,15 +22,132 @@ use tap::{Conv, Pipe};
 
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct ArrayBuffer {
-    pub array_buffer_data: Option<Vec<u8>>,
+    #[unsafe_ignore_trace]
+    pub array_buffer_data: Option<DataBlock>,
     pub array_buffer_byte_length: u64,
+    /// The `[[ArrayBufferMaxByteLength]]` of the buffer, which is `None` for fixed length buffers.
+    pub array_buffer_max_byte_length: Option<u64>,
     pub array_buffer_detach_key: JsValue,
+    /// The memory of the Data Block, charged to the context that created it.
+    ///
+    /// Shared Data Blocks can be viewed by the agents of other threads, so they aren't charged.
+    #[unsafe_ignore_trace]
+    pub(crate) data_charge: HeapCharge,
 }
 
 impl ArrayBuffer {
     pub(crate) fn array_buffer_byte_length(&self) -> u64 {
         self.array_buffer_byte_length
     }
+
+    /// `25.1.3.8 IsFixedLengthArrayBuffer ( arrayBuffer )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isfixedlengtharraybuffer
+    pub fn is_fixed_length_array_buffer(&self) -> bool {
+        // 1. If arrayBuffer has an [[ArrayBufferMaxByteLength]] internal slot, return false.
+        // 2. Return true.
+        self.array_buffer_max_byte_length.is_none()
+    }
+
+    /// `25.1.3.4 DetachArrayBuffer ( arrayBuffer [ , key ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-detacharraybuffer
+    pub(crate) fn detach(&mut self, key: &JsValue) -> JsResult<()> {
+        // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false.
+        debug_assert!(!self.is_shared_array_buffer());
+
+        // 2. If key is not present, set key to undefined.
+        // 3. If SameValue(arrayBuffer.[[ArrayBufferDetachKey]], key) is false, throw a TypeError exception.
+        if !JsValue::same_value(&self.array_buffer_detach_key, key) {
+            return Err(JsNativeError::typ()
+                .with_message("Cannot detach array buffer with different key")
+                .into());
+        }
+
+        // 4. Set arrayBuffer.[[ArrayBufferData]] to null.
+        self.array_buffer_data = None;
+        self.data_charge = HeapCharge::default();
+
+        // 5. Set arrayBuffer.[[ArrayBufferByteLength]] to 0.
+        self.array_buffer_byte_length = 0;
+
+        // 6. Return unused.
+        Ok(())
+    }
+
+    /// `25.2.1.2 IsSharedArrayBuffer ( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-issharedarraybuffer
+    pub fn is_shared_array_buffer(&self) -> bool {
+        // 1. Let bufferData be obj.[[ArrayBufferData]].
+        // 2. If bufferData is null, return false.
+        // 3. If bufferData is a Data Block, return false.
+        // 4. Assert: bufferData is a Shared Data Block.
+        // 5. Return true.
+        matches!(self.array_buffer_data, Some(DataBlock::Shared(_)))
+    }
+}
+
+/// The `[[ArrayBufferData]]` of an `ArrayBuffer` or a `SharedArrayBuffer`.
+#[derive(Debug, Clone)]
+pub enum DataBlock {
+    /// A Data Block, which belongs to a single `ArrayBuffer`.
+    Owned(Vec<u8>),
+    /// A Shared Data Block, which can be viewed by the `SharedArrayBuffer`s of several agents.
+    Shared(SharedMemory),
+}
+
+impl DataBlock {
+    /// Returns the number of bytes of the block.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Owned(block) => block.len(),
+            Self::Shared(block) => block.len(),
+        }
+    }
+
+    /// Returns `true` if the block has a length of zero bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Copies the bytes of the block starting at `index` into `buf`.
+    ///
+    /// The bytes of a Shared Data Block are read as unordered events.
+    pub(crate) fn read(&self, index: usize, buf: &mut [u8]) {
+        match self {
+            Self::Owned(block) => buf.copy_from_slice(&block[index..index + buf.len()]),
+            Self::Shared(block) => block.read(index, buf),
+        }
+    }
+
+    /// Stores `bytes` into the block, starting at `index`.
+    ///
+    /// The bytes of a Shared Data Block are written as unordered events.
+    pub(crate) fn write(&mut self, index: usize, bytes: &[u8]) {
+        match self {
+            Self::Owned(block) => block[index..index + bytes.len()].copy_from_slice(bytes),
+            Self::Shared(block) => block.write(index, bytes),
+        }
+    }
+}
+
+impl From<Vec<u8>> for DataBlock {
+    #[inline]
+    fn from(block: Vec<u8>) -> Self {
+        Self::Owned(block)
+    }
 }
 
 impl BuiltIn for ArrayBuffer {
@@ -48,6 +167,18 @@ impl BuiltIn for ArrayBuffer {
             .name("get byteLength")
             .build();
 
+        let get_max_byte_length = FunctionBuilder::native(context, Self::get_max_byte_length)
+            .name("get maxByteLength")
+            .build();
+
+        let get_resizable = FunctionBuilder::native(context, Self::get_resizable)
+            .name("get resizable")
+            .build();
+
+        let get_detached = FunctionBuilder::native(context, Self::get_detached)
+            .name("get detached")
+            .build();
+
         ConstructorBuilder::with_standard_constructor(
             context,
             Self::constructor,
@@ -56,6 +187,14 @@ impl BuiltIn for ArrayBuffer {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .accessor("byteLength", Some(get_byte_length), None, flag_attributes)
+        .accessor(
+            "maxByteLength",
+            Some(get_max_byte_length),
+            None,
+            flag_attributes,
+        )
+        .accessor("resizable", Some(get_resizable), None, flag_attributes)
+        .accessor("detached", Some(get_detached), None, flag_attributes)
         .static_accessor(
             WellKnownSymbols::species(),
             Some(get_species),
@@ -63,7 +202,10 @@ impl BuiltIn for ArrayBuffer {
             Attribute::CONFIGURABLE,
         )
         .static_method(Self::is_view, "isView", 1)
+        .method(Self::resize, "resize", 1)
         .method(Self::slice, "slice", 2)
+        .method(Self::transfer, "transfer", 0)
+        .method(Self::transfer_to_fixed_length, "transferToFixedLength", 0)
         .property(
             WellKnownSymbols::to_string_tag(),
             Self::NAME,
@@ -78,7 +220,7 @@ impl BuiltIn for ArrayBuffer {
 impl ArrayBuffer {
     const LENGTH: usize = 1;
 
-    /// `25.1.3.1 ArrayBuffer ( length )`
+    /// `25.1.4.1 ArrayBuffer ( length [ , options ] )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -91,15 +233,49 @@ impl ArrayBuffer {
     ) -> JsResult<JsValue> {
         // 1. If NewTarget is undefined, throw a TypeError exception.
         if new_target.is_undefined() {
-            return context
-                .throw_type_error("ArrayBuffer.constructor called with undefined new target");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.constructor called with undefined new target")
+                .into());
         }
 
         // 2. Let byteLength be ? ToIndex(length).
         let byte_length = args.get_or_undefined(0).to_index(context)?;
 
-        // 3. Return ? AllocateArrayBuffer(NewTarget, byteLength).
-        Ok(Self::allocate(new_target, byte_length, context)?.into())
+        // 3. Let requestedMaxByteLength be ? GetArrayBufferMaxByteLengthOption(options).
+        let requested_max_byte_length =
+            Self::get_max_byte_length_option(args.get_or_undefined(1), context)?;
+
+        // 4. Return ? AllocateArrayBuffer(NewTarget, byteLength, requestedMaxByteLength).
+        Ok(Self::allocate(new_target, byte_length, requested_max_byte_length, context)?.into())
+    }
+
+    /// `25.1.3.6 GetArrayBufferMaxByteLengthOption ( options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getarraybuffermaxbytelengthoption
+    fn get_max_byte_length_option(
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Option<u64>> {
+        // 1. If Type(options) is not Object, return empty.
+        let options = if let Some(options) = options.as_object() {
+            options
+        } else {
+            return Ok(None);
+        };
+
+        // 2. Let maxByteLength be ? Get(options, "maxByteLength").
+        let max_byte_length = options.get("maxByteLength", context)?;
+
+        // 3. If maxByteLength is undefined, return empty.
+        if max_byte_length.is_undefined() {
+            return Ok(None);
+        }
+
+        // 4. Return ? ToIndex(maxByteLength).
+        max_byte_length.to_index(context).map(Some)
     }
 
     /// `25.1.4.3 get ArrayBuffer [ @@species ]`
@@ -142,24 +318,32 @@ impl ArrayBuffer {
     pub(crate) fn get_byte_length(
         this: &JsValue,
         _args: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
         let obj = if let Some(obj) = this.as_object() {
             obj
         } else {
-            return context.throw_type_error("ArrayBuffer.byteLength called with non-object value");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.byteLength called with non-object value")
+                .into());
         };
         let obj = obj.borrow();
         let o = if let Some(o) = obj.as_array_buffer() {
             o
         } else {
-            return context.throw_type_error("ArrayBuffer.byteLength called with invalid object");
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.byteLength called with invalid object")
+                .into());
         };
 
-        // TODO: Shared Array Buffer
         // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        if o.is_shared_array_buffer() {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.byteLength called with a SharedArrayBuffer")
+                .into());
+        }
 
         // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
         if Self::is_detached_buffer(o) {
@@ -171,37 +355,359 @@ impl ArrayBuffer {
         Ok(o.array_buffer_byte_length.into())
     }
 
-    /// `25.1.5.3 ArrayBuffer.prototype.slice ( start, end )`
+    /// `25.1.6.4 get ArrayBuffer.prototype.maxByteLength`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
-    fn slice(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.maxbytelength
+    pub(crate) fn get_max_byte_length(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
-        let obj = if let Some(obj) = this.as_object() {
-            obj
-        } else {
-            return context.throw_type_error("ArrayBuffer.slice called with non-object value");
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.maxByteLength")?;
+        let o = obj.borrow();
+        let o = o.as_array_buffer().expect("checked to be an ArrayBuffer");
+
+        // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
+        if o.is_detached_buffer() {
+            return Ok(0.into());
+        }
+
+        // 5. If IsFixedLengthArrayBuffer(O) is true, then
+        //     a. Let length be O.[[ArrayBufferByteLength]].
+        // 6. Else,
+        //     a. Let length be O.[[ArrayBufferMaxByteLength]].
+        let length = o
+            .array_buffer_max_byte_length
+            .unwrap_or(o.array_buffer_byte_length);
+
+        // 7. Return 𝔽(length).
+        Ok(length.into())
+    }
+
+    /// `25.1.6.5 get ArrayBuffer.prototype.resizable`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.resizable
+    fn get_resizable(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.resizable")?;
+        let o = obj.borrow();
+        let o = o.as_array_buffer().expect("checked to be an ArrayBuffer");
+
+        // 4. If IsFixedLengthArrayBuffer(O) is false, return true; otherwise return false.
+        Ok((!o.is_fixed_length_array_buffer()).into())
+    }
+
+    /// `25.1.6.3 get ArrayBuffer.prototype.detached`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.detached
+    fn get_detached(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.detached")?;
+        let o = obj.borrow();
+        let o = o.as_array_buffer().expect("checked to be an ArrayBuffer");
+
+        // 4. Return IsDetachedBuffer(O).
+        Ok(o.is_detached_buffer().into())
+    }
+
+    /// `25.1.6.6 ArrayBuffer.prototype.resize ( newLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.resize
+    pub(crate) fn resize(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferMaxByteLength]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.resize")?;
+        if obj
+            .borrow()
+            .as_array_buffer()
+            .expect("checked to be an ArrayBuffer")
+            .is_fixed_length_array_buffer()
+        {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.resize called with a fixed length ArrayBuffer")
+                .into());
+        }
+
+        // 4. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        // 5-14.
+        obj.borrow_mut()
+            .as_array_buffer_mut()
+            .expect("checked to be an ArrayBuffer")
+            .resize_buffer(new_byte_length, context)?;
+
+        // 15. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Resizes a resizable `ArrayBuffer` to `new_byte_length` bytes, which is done by steps 5 to
+    /// 14 of `ArrayBuffer.prototype.resize`.
+    ///
+    /// The bytes added at the end of the buffer are set to zero, and the change of its size is
+    /// charged to the heap of the context.
+    pub(crate) fn resize_buffer(
+        &mut self,
+        new_byte_length: u64,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 5. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+        let block = match self.array_buffer_data {
+            Some(DataBlock::Owned(ref mut block)) => block,
+            Some(DataBlock::Shared(_)) => {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot resize a SharedArrayBuffer")
+                    .into())
+            }
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot resize a detached ArrayBuffer")
+                    .into())
+            }
         };
-        let obj_borrow = obj.borrow();
-        let o = if let Some(o) = obj_borrow.as_array_buffer() {
-            o
+
+        // 6. If newByteLength > O.[[ArrayBufferMaxByteLength]], throw a RangeError exception.
+        let max_byte_length = self.array_buffer_max_byte_length.ok_or_else(|| {
+            JsNativeError::typ().with_message("cannot resize a fixed length ArrayBuffer")
+        })?;
+        if new_byte_length > max_byte_length {
+            return Err(JsNativeError::range()
+                .with_message("new length is greater than the maxByteLength of the ArrayBuffer")
+                .into());
+        }
+
+        // 7. Let hostHandled be ? HostResizeArrayBuffer(O, newByteLength).
+        // 8. If hostHandled is handled, return undefined.
+        // 9. Let oldBlock be O.[[ArrayBufferData]].
+        // 10. Let newBlock be ? CreateByteDataBlock(newByteLength).
+        // 11. Let copyLength be min(newByteLength, O.[[ArrayBufferByteLength]]).
+        // 12. Perform CopyDataBlockBytes(newBlock, 0, oldBlock, 0, copyLength).
+        // 13. NOTE: Neither creation of the new Data Block nor copying from the old Data Block are observable. Implementations may implement this method as in-place growth or shrinkage.
+        // 14. Set O.[[ArrayBufferData]] to newBlock and O.[[ArrayBufferByteLength]] to newByteLength.
+        //
+        // The block is resized in place. Its capacity isn't reserved up front, so the
+        // maxByteLength is only an upper bound, and growing can fail like a new allocation.
+        let new_byte_length_usize = new_byte_length as usize;
+        if let Some(additional) = new_byte_length_usize.checked_sub(block.len()) {
+            context.reserve_heap(additional)?;
+            block.try_reserve_exact(additional).map_err(|e| {
+                JsNativeError::range().with_message(format!("couldn't grow the ArrayBuffer: {e}"))
+            })?;
+            block.resize(new_byte_length_usize, 0);
+        } else {
+            block.truncate(new_byte_length_usize);
+            block.shrink_to_fit();
+        }
+        self.data_charge.resize(block.capacity());
+        self.array_buffer_byte_length = new_byte_length;
+
+        Ok(())
+    }
+
+    /// `25.1.6.8 ArrayBuffer.prototype.transfer ( [ newLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfer
+    fn transfer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, preserve-resizability).
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.transfer")?;
+        Self::copy_and_detach(&obj, args.get_or_undefined(0), true, context).map(Into::into)
+    }
+
+    /// `25.1.6.9 ArrayBuffer.prototype.transferToFixedLength ( [ newLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfertofixedlength
+    fn transfer_to_fixed_length(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, fixed-length).
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.transferToFixedLength")?;
+        Self::copy_and_detach(&obj, args.get_or_undefined(0), false, context).map(Into::into)
+    }
+
+    /// `25.1.3.2 ArrayBufferCopyAndDetach ( arrayBuffer, newLength, preserveResizability )`
+    ///
+    /// `array_buffer` must be an `ArrayBuffer` that is not a `SharedArrayBuffer`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffercopyanddetach
+    pub(crate) fn copy_and_detach(
+        array_buffer: &JsObject,
+        new_length: &JsValue,
+        preserve_resizability: bool,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Perform ? RequireInternalSlot(arrayBuffer, [[ArrayBufferData]]).
+        // 2. If IsSharedArrayBuffer(arrayBuffer) is true, throw a TypeError exception.
+        // 3. If newLength is undefined, then
+        //     a. Let newByteLength be arrayBuffer.[[ArrayBufferByteLength]].
+        // 4. Else,
+        //     a. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = if new_length.is_undefined() {
+            array_buffer
+                .borrow()
+                .as_array_buffer()
+                .expect("must be an ArrayBuffer")
+                .array_buffer_byte_length
         } else {
-            return context.throw_type_error("ArrayBuffer.slice called with invalid object");
+            new_length.to_index(context)?
         };
 
-        // TODO: Shared Array Buffer
-        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let new_max_byte_length = {
+            let buffer = array_buffer.borrow();
+            let buffer = buffer.as_array_buffer().expect("must be an ArrayBuffer");
 
-        // 4. If IsDetachedBuffer(O) is true, throw a TypeError exception.
-        if Self::is_detached_buffer(o) {
-            return context.throw_type_error("ArrayBuffer.slice called with detached buffer");
+            // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError exception.
+            if buffer.is_detached_buffer() {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot transfer a detached ArrayBuffer")
+                    .into());
+            }
+
+            // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a TypeError exception.
+            if !buffer.array_buffer_detach_key.is_undefined() {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot transfer an ArrayBuffer with a detach key")
+                    .into());
+            }
+
+            // 6. If preserveResizability is preserve-resizability and IsFixedLengthArrayBuffer(arrayBuffer) is false, then
+            //     a. Let newMaxByteLength be arrayBuffer.[[ArrayBufferMaxByteLength]].
+            // 7. Else,
+            //     a. Let newMaxByteLength be empty.
+            if preserve_resizability {
+                buffer.array_buffer_max_byte_length
+            } else {
+                None
+            }
+        };
+
+        // 9. Let newBuffer be ? AllocateArrayBuffer(%ArrayBuffer%, newByteLength, newMaxByteLength).
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .array_buffer()
+            .constructor()
+            .into();
+        let new_buffer =
+            Self::allocate(&constructor, new_byte_length, new_max_byte_length, context)?;
+
+        {
+            let mut buffer = array_buffer.borrow_mut();
+            let buffer = buffer
+                .as_array_buffer_mut()
+                .expect("must be an ArrayBuffer");
+            let mut new = new_buffer.borrow_mut();
+            let new = new.as_array_buffer_mut().expect("must be an ArrayBuffer");
+
+            // 10. Let copyLength be min(newByteLength, arrayBuffer.[[ArrayBufferByteLength]]).
+            let copy_length = std::cmp::min(new_byte_length, buffer.array_buffer_byte_length);
+
+            // 11. Let fromBlock be arrayBuffer.[[ArrayBufferData]].
+            // 12. Let toBlock be newBuffer.[[ArrayBufferData]].
+            // 13. Perform CopyDataBlockBytes(toBlock, 0, fromBlock, 0, copyLength).
+            // 14. NOTE: Neither creation of the new Data Block nor copying from the old Data Block are observable. Implementations may implement this method as a zero-copy move or a realloc.
+            copy_data_block_bytes(
+                new.array_buffer_data
+                    .as_mut()
+                    .expect("new buffer cannot be detached"),
+                0,
+                buffer
+                    .array_buffer_data
+                    .as_ref()
+                    .expect("checked to not be detached"),
+                0,
+                copy_length as usize,
+            );
+
+            // 15. Perform ! DetachArrayBuffer(arrayBuffer).
+            buffer
+                .detach(&JsValue::undefined())
+                .expect("the detach key was checked to be undefined");
         }
 
-        // 5. Let len be O.[[ArrayBufferByteLength]].
-        let len = o.array_buffer_byte_length as i64;
+        // 16. Return newBuffer.
+        Ok(new_buffer)
+    }
+
+    /// Returns `this` if it is an `ArrayBuffer` that is not a `SharedArrayBuffer`, or throws a
+    /// `TypeError` naming `method` otherwise.
+    fn this_array_buffer(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| {
+                obj.borrow()
+                    .as_array_buffer()
+                    .map_or(false, |buffer| !buffer.is_shared_array_buffer())
+            })
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!("{method} called with invalid object"))
+                    .into()
+            })
+    }
+
+    /// `25.1.5.3 ArrayBuffer.prototype.slice ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+    fn slice(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.slice")?;
+
+        let len = {
+            let o = obj.borrow();
+            let o = o.as_array_buffer().expect("checked to be an ArrayBuffer");
+
+            // 4. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+            if o.is_detached_buffer() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer.slice called with detached buffer")
+                    .into());
+            }
+
+            // 5. Let len be O.[[ArrayBufferByteLength]].
+            o.array_buffer_byte_length as i64
+        };
 
         // 6. Let relativeStart be ? ToIntegerOrInfinity(start).
         let relative_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
@@ -247,25 +753,28 @@ impl ArrayBuffer {
             let new_obj = new.borrow();
             // 17. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
             let new_array_buffer = new_obj.as_array_buffer().ok_or_else(|| {
-                context.construct_type_error("ArrayBuffer constructor returned invalid object")
+                JsNativeError::typ().with_message("ArrayBuffer constructor returned invalid object")
             })?;
 
-            // TODO: Shared Array Buffer
             // 18. If IsSharedArrayBuffer(new) is true, throw a TypeError exception.
+            if new_array_buffer.is_shared_array_buffer() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer constructor returned a SharedArrayBuffer")
+                    .into());
+            }
 
             // 19. If IsDetachedBuffer(new) is true, throw a TypeError exception.
             if new_array_buffer.is_detached_buffer() {
-                return context
-                    .throw_type_error("ArrayBuffer constructor returned detached ArrayBuffer");
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer constructor returned detached ArrayBuffer")
+                    .into());
             }
         }
         // 20. If SameValue(new, O) is true, throw a TypeError exception.
-        if this
-            .as_object()
-            .map(|obj| JsObject::equals(obj, &new))
-            .unwrap_or_default()
-        {
-            return context.throw_type_error("New ArrayBuffer is the same as this ArrayBuffer");
+        if JsObject::equals(&obj, &new) {
+            return Err(JsNativeError::typ()
+                .with_message("New ArrayBuffer is the same as this ArrayBuffer")
+                .into());
         }
 
         {
@@ -276,14 +785,21 @@ impl ArrayBuffer {
 
             // 21. If new.[[ArrayBufferByteLength]] < newLen, throw a TypeError exception.
             if new_array_buffer.array_buffer_byte_length < new_len {
-                return context.throw_type_error("New ArrayBuffer length too small");
+                return Err(JsNativeError::typ()
+                    .with_message("New ArrayBuffer length too small")
+                    .into());
             }
 
-            // 22. NOTE: Side-effects of the above steps may have detached O.
+            // 22. NOTE: Side-effects of the above steps may have detached or resized O.
             // 23. If IsDetachedBuffer(O) is true, throw a TypeError exception.
-            if Self::is_detached_buffer(o) {
-                return context
-                    .throw_type_error("ArrayBuffer detached while ArrayBuffer.slice was running");
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow
+                .as_array_buffer()
+                .expect("checked to be an ArrayBuffer");
+            if o.is_detached_buffer() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer detached while ArrayBuffer.slice was running")
+                    .into());
             }
 
             // 24. Let fromBuf be O.[[ArrayBufferData]].
@@ -298,15 +814,27 @@ impl ArrayBuffer {
                 .as_mut()
                 .expect("ArrayBuffer cannot be detached here");
 
-            // 26. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
-            copy_data_block_bytes(to_buf, 0, from_buf, first as usize, new_len as usize);
+            // 26. Let currentLen be O.[[ArrayBufferByteLength]].
+            let current_len = o.array_buffer_byte_length;
+
+            // 27. If first < currentLen, then
+            let first = first as u64;
+            if first < current_len {
+                // a. Let count be min(newLen, currentLen - first).
+                let count = std::cmp::min(new_len, current_len - first);
+
+                // b. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, count).
+                copy_data_block_bytes(to_buf, 0, from_buf, first as usize, count as usize);
+            }
         }
 
-        // 27. Return new.
+        // 28. Return new.
         Ok(new.into())
     }
 
-    /// `25.1.2.1 AllocateArrayBuffer ( constructor, byteLength )`
+    /// `25.1.3.1 AllocateArrayBuffer ( constructor, byteLength [ , maxByteLength ] )`
+    ///
+    /// The buffer is resizable if `max_byte_length` is not `None`.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -315,9 +843,23 @@ impl ArrayBuffer {
     pub(crate) fn allocate(
         constructor: &JsValue,
         byte_length: u64,
+        max_byte_length: Option<u64>,
         context: &mut Context,
     ) -> JsResult<JsObject> {
-        // 1. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] »).
+        // 1. Let slots be « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] ».
+        // 2. If maxByteLength is present and maxByteLength is not empty, let allocatingResizableBuffer be true; otherwise let allocatingResizableBuffer be false.
+        // 3. If allocatingResizableBuffer is true, then
+        //     a. If byteLength > maxByteLength, throw a RangeError exception.
+        //     b. Append [[ArrayBufferMaxByteLength]] to slots.
+        if let Some(max_byte_length) = max_byte_length {
+            if byte_length > max_byte_length {
+                return Err(JsNativeError::range()
+                    .with_message("ArrayBuffer byte length is greater than its maxByteLength")
+                    .into());
+            }
+        }
+
+        // 4. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", slots).
         let prototype = get_prototype_from_constructor(
             constructor,
             StandardConstructors::array_buffer,
@@ -326,18 +868,31 @@ impl ArrayBuffer {
         let obj = context.construct_object();
         obj.set_prototype(prototype.into());
 
-        // 2. Let block be ? CreateByteDataBlock(byteLength).
-        let block = create_byte_data_block(byte_length, context)?;
+        // The memory is reserved before it is allocated, so a huge buffer can't exceed the heap
+        // limit of the context. Resizable buffers reserve the rest when they grow.
+        context.reserve_heap(usize::try_from(byte_length).unwrap_or(usize::MAX))?;
 
-        // 3. Set obj.[[ArrayBufferData]] to block.
-        // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 5. Let block be ? CreateByteDataBlock(byteLength).
+        let block = create_byte_data_block(byte_length)?;
+
+        // 8. If allocatingResizableBuffer is true, then
+        //     a. If it is not possible to create a Data Block block consisting of maxByteLength bytes, throw a RangeError exception.
+        //     b. NOTE: Resizable ArrayBuffers are designed to be implementable with in-place growth. Implementations may throw if, for example, virtual memory cannot be reserved up front.
+        //     c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
+        //
+        // The memory for maxByteLength is only allocated when the buffer grows, in `resize_buffer`.
+
+        // 6. Set obj.[[ArrayBufferData]] to block.
+        // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
         obj.borrow_mut().data = ObjectData::array_buffer(Self {
-            array_buffer_data: Some(block),
+            data_charge: HeapCharge::new(block.capacity()),
+            array_buffer_data: Some(block.into()),
             array_buffer_byte_length: byte_length,
+            array_buffer_max_byte_length: max_byte_length,
             array_buffer_detach_key: JsValue::Undefined,
         });
 
-        // 5. Return obj.
+        // 9. Return obj.
         Ok(obj)
     }
 
@@ -367,14 +922,16 @@ impl ArrayBuffer {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         // 1. Let targetBuffer be ? AllocateArrayBuffer(cloneConstructor, srcLength).
-        let target_buffer = Self::allocate(clone_constructor, src_length, context)?;
+        let target_buffer = Self::allocate(clone_constructor, src_length, None, context)?;
 
         // 2. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
         // 3. Let srcBlock be srcBuffer.[[ArrayBufferData]].
         let src_block = if let Some(b) = &self.array_buffer_data {
             b
         } else {
-            return context.throw_syntax_error("Cannot clone detached array buffer");
+            return Err(JsNativeError::syntax()
+                .with_message("Cannot clone detached array buffer")
+                .into());
         };
 
         {
@@ -407,7 +964,7 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isunclampedintegerelementtype
-    fn is_unclamped_integer_element_type(t: TypedArrayKind) -> bool {
+    pub(crate) fn is_unclamped_integer_element_type(t: TypedArrayKind) -> bool {
         // 1. If type is Int8, Uint8, Int16, Uint16, Int32, or Uint32, return true.
         // 2. Return false.
         matches!(
@@ -427,7 +984,7 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isbigintelementtype
-    fn is_big_int_element_type(t: TypedArrayKind) -> bool {
+    pub(crate) fn is_big_int_element_type(t: TypedArrayKind) -> bool {
         // 1. If type is BigUint64 or BigInt64, return true.
         // 2. Return false.
         matches!(t, TypedArrayKind::BigUint64 | TypedArrayKind::BigInt64)
@@ -439,8 +996,6 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isnotearconfiguration
-    // TODO: Allow unused function until shared array buffers are implemented.
-    #[allow(dead_code)]
     fn is_no_tear_configuration(t: TypedArrayKind, order: SharedMemoryOrder) -> bool {
         // 1. If ! IsUnclampedIntegerElementType(type) is true, return true.
         if Self::is_unclamped_integer_element_type(t) {
@@ -467,7 +1022,11 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
-    fn raw_bytes_to_numeric(t: TypedArrayKind, bytes: &[u8], is_little_endian: bool) -> JsValue {
+    pub(crate) fn raw_bytes_to_numeric(
+        t: TypedArrayKind,
+        bytes: &[u8],
+        is_little_endian: bool,
+    ) -> JsValue {
         let n: Numeric = match t {
             TypedArrayKind::Int8 => {
                 if is_little_endian {
@@ -570,8 +1129,8 @@ impl ArrayBuffer {
         &self,
         byte_index: u64,
         t: TypedArrayKind,
-        _is_typed_array: bool,
-        _order: SharedMemoryOrder,
+        is_typed_array: bool,
+        order: SharedMemoryOrder,
         is_little_endian: Option<bool>,
     ) -> JsValue {
         // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
@@ -585,13 +1144,33 @@ impl ArrayBuffer {
         // 4. Let elementSize be the Element Size value specified in Table 73 for Element Type type.
         let element_size = t.element_size() as usize;
 
-        // TODO: Shared Array Buffer
-        // 5. If IsSharedArrayBuffer(arrayBuffer) is true, then
-
-        // 6. Else, let rawValue be a List whose elements are bytes from block at indices byteIndex (inclusive) through byteIndex + elementSize (exclusive).
-        // 7. Assert: The number of elements in rawValue is elementSize.
         let byte_index = byte_index as usize;
-        let raw_value = &block[byte_index..byte_index + element_size];
+        let mut raw_value = [0; 8];
+        let raw_value = &mut raw_value[..element_size];
+
+        match block {
+            // 5. If IsSharedArrayBuffer(arrayBuffer) is true, then
+            DataBlock::Shared(block) => {
+                // c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+                // d. Let rawValue be a List of length elementSize whose elements are nondeterministically chosen byte values.
+                // f. Let readEvent be ReadSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize }.
+                //
+                // Reads that must not tear, and sequentially consistent reads, happen in the
+                // critical section of the block, where they cannot interleave with atomic writes.
+                if order == SharedMemoryOrder::SeqCst
+                    || (is_typed_array && Self::is_no_tear_configuration(t, order))
+                {
+                    block.atomically(|| block.read(byte_index, raw_value));
+                } else {
+                    block.read(byte_index, raw_value);
+                }
+            }
+            // 6. Else, let rawValue be a List whose elements are bytes from block at indices byteIndex (inclusive) through byteIndex + elementSize (exclusive).
+            DataBlock::Owned(block) => {
+                raw_value.copy_from_slice(&block[byte_index..byte_index + element_size]);
+            }
+        }
+        // 7. Assert: The number of elements in rawValue is elementSize.
 
         // TODO: Agent Record [[LittleEndian]] filed
         // 8. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
@@ -607,7 +1186,7 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
-    fn numeric_to_raw_bytes(
+    pub(crate) fn numeric_to_raw_bytes(
         t: TypedArrayKind,
         value: &JsValue,
         is_little_endian: bool,
@@ -704,7 +1283,7 @@ impl ArrayBuffer {
         byte_index: u64,
         t: TypedArrayKind,
         value: &JsValue,
-        _order: SharedMemoryOrder,
+        order: SharedMemoryOrder,
         is_little_endian: Option<bool>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
@@ -726,17 +1305,97 @@ impl ArrayBuffer {
         // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
         let raw_bytes = Self::numeric_to_raw_bytes(t, value, is_little_endian, context)?;
 
-        // TODO: Shared Array Buffer
-        // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
-
-        // 9. Else, store the individual bytes of rawBytes into block, starting at block[byteIndex].
-        for (i, raw_byte) in raw_bytes.iter().enumerate() {
-            block[byte_index as usize + i] = *raw_byte;
+        let byte_index = byte_index as usize;
+        match block {
+            // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
+            DataBlock::Shared(block) => {
+                // b. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+                // c. Append WriteSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes } to eventsRecord.[[EventList]].
+                //
+                // Writes that must not tear, and sequentially consistent writes, happen in the
+                // critical section of the block, where they cannot interleave with atomic reads.
+                if order == SharedMemoryOrder::SeqCst || Self::is_no_tear_configuration(t, order) {
+                    block.atomically(|| block.write(byte_index, &raw_bytes));
+                } else {
+                    block.write(byte_index, &raw_bytes);
+                }
+            }
+            // 9. Else, store the individual bytes of rawBytes into block, starting at block[byteIndex].
+            DataBlock::Owned(block) => {
+                block[byte_index..byte_index + raw_bytes.len()].copy_from_slice(&raw_bytes);
+            }
         }
 
         // 10. Return NormalCompletion(undefined).
         Ok(JsValue::undefined())
     }
+
+    /// `25.1.2.13 GetModifySetValueInBuffer ( arrayBuffer, byteIndex, type, value, op [ , isLittleEndian ] )`
+    ///
+    /// The raw bytes are passed to `op` as little endian integers, and the bytes of its result
+    /// that do not fit in an element of type `t` are discarded.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodifysetvalueinbuffer
+    pub(crate) fn get_modify_set_value_in_buffer(
+        &mut self,
+        byte_index: u64,
+        t: TypedArrayKind,
+        value: &JsValue,
+        op: fn(u64, u64) -> u64,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
+        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
+        // 3. Assert: Type(value) is BigInt if ! IsBigIntElementType(type) is true; otherwise, Type(value) is Number.
+        // 4. Let block be arrayBuffer.[[ArrayBufferData]].
+        let block = self
+            .array_buffer_data
+            .as_mut()
+            .expect("ArrayBuffer cannot be detached here");
+
+        // 5. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
+        let element_size = t.element_size() as usize;
+
+        // 6. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
+        let raw_bytes = Self::numeric_to_raw_bytes(t, value, true, context)?;
+
+        let byte_index = byte_index as usize;
+        let mut raw_bytes_read = [0; 8];
+        let modify = |block: &mut DataBlock, raw_bytes_read: &mut [u8; 8]| {
+            block.read(byte_index, &mut raw_bytes_read[..element_size]);
+            let mut operand = [0; 8];
+            operand[..element_size].copy_from_slice(&raw_bytes);
+            let modified = op(
+                u64::from_le_bytes(*raw_bytes_read),
+                u64::from_le_bytes(operand),
+            );
+            block.write(byte_index, &modified.to_le_bytes()[..element_size]);
+        };
+
+        // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
+        //     a-e. Append ReadModifyWriteSharedMemory { [[Order]]: SeqCst, [[NoTear]]: true, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes, [[ModifyOp]]: op } to eventsRecord.[[EventList]].
+        // 9. Else,
+        //     a. Let rawBytesRead be a List of length elementSize whose elements are the sequence of elementSize bytes starting with block[byteIndex].
+        //     b. Let rawBytesModified be op(rawBytesRead, rawBytes).
+        //     c. Store the individual bytes of rawBytesModified into block, starting at block[byteIndex].
+        if let DataBlock::Shared(shared) = block {
+            let shared = shared.clone();
+            shared.atomically(|| modify(block, &mut raw_bytes_read));
+        } else {
+            modify(block, &mut raw_bytes_read);
+        }
+
+        // 10. Return RawBytesToNumeric(type, rawBytesRead, isLittleEndian).
+        Ok(Self::raw_bytes_to_numeric(
+            t,
+            &raw_bytes_read[..element_size],
+            true,
+        ))
+    }
 }
 
 /// `CreateByteDataBlock ( size )` abstract operation.
@@ -745,12 +1404,12 @@ impl ArrayBuffer {
 /// integer). For more information, check the [spec][spec].
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-createbytedatablock
-pub fn create_byte_data_block(size: u64, context: &mut Context) -> JsResult<Vec<u8>> {
+pub fn create_byte_data_block(size: u64) -> JsResult<Vec<u8>> {
     // 1. Let db be a new Data Block value consisting of size bytes. If it is impossible to
     //    create such a Data Block, throw a RangeError exception.
     let mut data_block = Vec::new();
     data_block.try_reserve(size as usize).map_err(|e| {
-        context.construct_range_error(format!("couldn't allocate the data block: {e}"))
+        JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
     })?;
 
     // 2. Set all of the bytes of db to 0.
@@ -766,12 +1425,12 @@ pub fn create_byte_data_block(size: u64, context: &mut Context) -> JsResult<Vec<
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-copydatablockbytes
-fn copy_data_block_bytes(
-    to_block: &mut [u8],
-    mut to_index: usize,
-    from_block: &[u8],
-    mut from_index: usize,
-    mut count: usize,
+pub(crate) fn copy_data_block_bytes(
+    to_block: &mut DataBlock,
+    to_index: usize,
+    from_block: &DataBlock,
+    from_index: usize,
+    count: usize,
 ) {
     // 1. Assert: fromBlock and toBlock are distinct values.
     // 2. Let fromSize be the number of bytes in fromBlock.
@@ -787,32 +1446,34 @@ fn copy_data_block_bytes(
     assert!(to_index + count <= to_size);
 
     // 6. Repeat, while count > 0,
-    while count > 0 {
-        // a. If fromBlock is a Shared Data Block, then
-        // TODO: Shared Data Block
-
-        // b. Else,
-        // i. Assert: toBlock is not a Shared Data Block.
-        // ii. Set toBlock[toIndex] to fromBlock[fromIndex].
-        to_block[to_index] = from_block[from_index];
-
-        // c. Set toIndex to toIndex + 1.
-        to_index += 1;
-
-        // d. Set fromIndex to fromIndex + 1.
-        from_index += 1;
-
-        // e. Set count to count - 1.
-        count -= 1;
+    //     a. If fromBlock is a Shared Data Block, then
+    //         i-vi. Read the byte as an Unordered event, and write it to toBlock as an Unordered
+    //               event if toBlock is a Shared Data Block.
+    //     b. Else,
+    //         i. Assert: toBlock is not a Shared Data Block.
+    //         ii. Set toBlock[toIndex] to fromBlock[fromIndex].
+    //     c. Set toIndex to toIndex + 1.
+    //     d. Set fromIndex to fromIndex + 1.
+    //     e. Set count to count - 1.
+    match (to_block, from_block) {
+        (DataBlock::Owned(to_block), DataBlock::Owned(from_block)) => {
+            to_block[to_index..to_index + count]
+                .copy_from_slice(&from_block[from_index..from_index + count]);
+        }
+        (to_block, from_block) => {
+            let mut bytes = vec![0; count];
+            from_block.read(from_index, &mut bytes);
+            to_block.write(to_index, &bytes);
+        }
     }
 
     // 7. Return NormalCompletion(empty).
 }
 
-// TODO: Allow unused variants until shared array buffers are implemented.
-#[allow(dead_code)]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub(crate) enum SharedMemoryOrder {
+    // The memory model is not implemented, so nothing initializes a block with `Init` events.
+    #[allow(dead_code)]
     Init,
     SeqCst,
     Unordered,
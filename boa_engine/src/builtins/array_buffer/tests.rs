@@ -1,15 +1,218 @@
 use super::*;
+use crate::{forward, object::JsArrayBuffer};
 
 #[test]
 fn ut_sunny_day_create_byte_data_block() {
+    assert!(create_byte_data_block(100).is_ok());
+}
+
+#[test]
+fn ut_rainy_day_create_byte_data_block() {
+    assert!(create_byte_data_block(u64::MAX).is_err());
+}
+
+#[test]
+fn resizable() {
     let mut context = Context::default();
+    let init = r#"
+        const fixed = new ArrayBuffer(4);
+        const buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        new Uint8Array(buffer).set([1, 2, 3, 4]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "fixed.resizable"), "false");
+    assert_eq!(forward(&mut context, "fixed.maxByteLength"), "4");
+    assert_eq!(forward(&mut context, "buffer.resizable"), "true");
+    assert_eq!(forward(&mut context, "buffer.maxByteLength"), "8");
+    assert_eq!(
+        forward(&mut context, "buffer.resize(6); buffer.byteLength"),
+        "6"
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(buffer).join()"),
+        "\"1,2,3,4,0,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "buffer.resize(2); new Uint8Array(buffer).join()"
+        ),
+        "\"1,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { buffer.resize(9) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { fixed.resize(2) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(4, { maxByteLength: 2 }) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
 
-    assert!(create_byte_data_block(100, &mut context).is_ok());
+#[test]
+fn resizable_max_byte_length_is_not_allocated() {
+    let mut context = Context::default();
+    // A terabyte can't be allocated, but the maxByteLength is only an upper bound.
+    forward(
+        &mut context,
+        "const buffer = new ArrayBuffer(0, { maxByteLength: 2 ** 40 });",
+    );
+    assert_eq!(
+        forward(&mut context, "buffer.maxByteLength"),
+        "1099511627776"
+    );
+    assert_eq!(
+        forward(&mut context, "buffer.resize(16); buffer.byteLength"),
+        "16"
+    );
 }
 
 #[test]
-fn ut_rainy_day_create_byte_data_block() {
+fn transfer() {
     let mut context = Context::default();
+    let init = r#"
+        const buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        new Uint8Array(buffer).set([1, 2, 3, 4]);
+        const moved = buffer.transfer(6);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "buffer.detached"), "true");
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "0");
+    assert_eq!(forward(&mut context, "moved.detached"), "false");
+    assert_eq!(forward(&mut context, "moved.resizable"), "true");
+    assert_eq!(forward(&mut context, "moved.maxByteLength"), "8");
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(moved).join()"),
+        "\"1,2,3,4,0,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { buffer.transfer() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+
+    forward(
+        &mut context,
+        "const fixed = moved.transferToFixedLength(2);",
+    );
+    assert_eq!(forward(&mut context, "moved.detached"), "true");
+    assert_eq!(forward(&mut context, "fixed.resizable"), "false");
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(fixed).join()"),
+        "\"1,2\""
+    );
+}
+
+#[test]
+fn length_tracking_views() {
+    let mut context = Context::default();
+    let init = r#"
+        const buffer = new ArrayBuffer(8, { maxByteLength: 16 });
+        const tracking = new Uint16Array(buffer, 2);
+        const fixed = new Uint16Array(buffer, 2, 2);
+        const view = new DataView(buffer, 2);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "tracking.length"), "3");
+    assert_eq!(forward(&mut context, "view.byteLength"), "6");
+
+    forward(&mut context, "buffer.resize(12);");
+    assert_eq!(forward(&mut context, "tracking.length"), "5");
+    assert_eq!(forward(&mut context, "tracking.byteLength"), "10");
+    assert_eq!(forward(&mut context, "fixed.length"), "2");
+    assert_eq!(forward(&mut context, "view.byteLength"), "10");
+    assert_eq!(
+        forward(&mut context, "tracking[4] = 7; view.getUint16(8, true)"),
+        "7"
+    );
+    assert_eq!(forward(&mut context, "tracking.subarray(1).length"), "4");
+
+    forward(&mut context, "buffer.resize(4);");
+    assert_eq!(forward(&mut context, "tracking.length"), "1");
+    assert_eq!(forward(&mut context, "fixed.length"), "0");
+    assert_eq!(forward(&mut context, "fixed.byteOffset"), "0");
+    assert_eq!(forward(&mut context, "fixed[0]"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { fixed.fill(0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+
+    forward(&mut context, "buffer.resize(1);");
+    assert_eq!(forward(&mut context, "tracking.length"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.byteLength } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.getUint8(0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn shrink_during_conversion() {
+    let mut context = Context::default();
+    let init = r#"
+        const buffer = new ArrayBuffer(8, { maxByteLength: 8 });
+        const array = new Uint8Array(buffer);
+        const shrink = { valueOf() { buffer.resize(2); return 0; } };
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "array.set([1, 2, shrink, 4]); array.join()"),
+        "\"1,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "buffer.resize(8); array.copyWithin(0, shrink).length"
+        ),
+        "2"
+    );
+}
+
+#[test]
+fn js_array_buffer_resize_and_transfer() {
+    let mut context = Context::default();
+    let buffer = JsArrayBuffer::new_resizable(4, 8, &mut context).unwrap();
+    assert!(buffer.is_resizable());
+    assert_eq!(buffer.max_byte_length(&mut context), 8);
+
+    buffer.resize(6, &mut context).unwrap();
+    assert_eq!(buffer.byte_length(&mut context), 6);
+    assert!(buffer.resize(9, &mut context).is_err());
+
+    let moved = buffer.transfer(None, &mut context).unwrap();
+    assert_eq!(buffer.byte_length(&mut context), 0);
+    assert_eq!(moved.byte_length(&mut context), 6);
+    assert_eq!(moved.max_byte_length(&mut context), 8);
+    assert!(buffer.transfer(None, &mut context).is_err());
 
-    assert!(create_byte_data_block(u64::MAX, &mut context).is_err());
+    let fixed = JsArrayBuffer::new(4, &mut context).unwrap();
+    assert!(!fixed.is_resizable());
+    assert!(fixed.resize(2, &mut context).is_err());
 }
@@ -10,6 +10,7 @@ use crate::{
         generator::GeneratorContext, iterable::create_iter_result_object,
         promise::if_abrupt_reject_promise, promise::PromiseCapability, BuiltIn, JsArgs, Promise,
     },
+    error::{JsError, JsNativeError},
     object::{ConstructorBuilder, FunctionBuilder, JsObject, ObjectData},
     property::{Attribute, PropertyDescriptor},
     symbol::WellKnownSymbols,
@@ -173,12 +174,16 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ()
+                .with_message("generator resumed on non generator object")
+                .into()
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ()
+                .with_message("generator resumed on non generator object")
+                .into()
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -262,12 +267,16 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ()
+                .with_message("generator resumed on non generator object")
+                .into()
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ()
+                .with_message("generator resumed on non generator object")
+                .into()
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -346,12 +355,16 @@ impl AsyncGenerator {
         // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
         // 4. IfAbruptRejectPromise(result, promiseCapability).
         let generator_object = generator.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ()
+                .with_message("generator resumed on non generator object")
+                .into()
         });
         if_abrupt_reject_promise!(generator_object, promise_capability, context);
         let mut generator_obj_mut = generator_object.borrow_mut();
         let generator = generator_obj_mut.as_async_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ()
+                .with_message("generator resumed on non generator object")
+                .into()
         });
         if_abrupt_reject_promise!(generator, promise_capability, context);
 
@@ -386,7 +399,10 @@ impl AsyncGenerator {
         }
 
         // 8. Let completion be ThrowCompletion(exception).
-        let completion = (Err(args.get_or_undefined(0).clone()), false);
+        let completion = (
+            Err(JsError::from_opaque(args.get_or_undefined(0).clone())),
+            false,
+        );
 
         // 9. Perform AsyncGeneratorEnqueue(generator, completion, promiseCapability).
         generator.enqueue(completion.clone(), promise_capability.clone());
@@ -457,6 +473,7 @@ impl AsyncGenerator {
         match completion {
             // 7. If completion.[[Type]] is throw, then
             Err(value) => {
+                let value = value.to_opaque(context);
                 // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « value »).
                 promise_capability
                     .reject()
@@ -520,9 +537,10 @@ impl AsyncGenerator {
 
         // 6. Push genContext onto the execution context stack; genContext is now the running execution context.
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context_mut.environments,
         );
+        std::mem::swap(&mut context.realm, &mut generator_context_mut.realm);
         std::mem::swap(&mut context.vm.stack, &mut generator_context_mut.stack);
         context
             .vm
@@ -539,6 +557,7 @@ impl AsyncGenerator {
                 }
             }
             (Err(value), _) => {
+                let value = value.to_opaque(context);
                 context.vm.push(value);
                 context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Throw;
             }
@@ -548,9 +567,10 @@ impl AsyncGenerator {
 
         let mut generator_context_mut = generator_context.borrow_mut();
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context_mut.environments,
         );
+        std::mem::swap(&mut context.realm, &mut generator_context_mut.realm);
         std::mem::swap(&mut context.vm.stack, &mut generator_context_mut.stack);
         generator_context_mut.call_frame =
             context.vm.pop_frame().expect("generator frame must exist");
@@ -655,7 +675,7 @@ impl AsyncGenerator {
                 gen.state = AsyncGeneratorState::Completed;
 
                 // b. Let result be ThrowCompletion(reason).
-                let result = Err(args.get_or_undefined(0).clone());
+                let result = Err(JsError::from_opaque(args.get_or_undefined(0).clone()));
 
                 // c. Perform AsyncGeneratorCompleteStep(generator, result, true).
                 let next = gen.queue.pop_front().expect("must have one entry");
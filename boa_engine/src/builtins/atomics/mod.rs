@@ -0,0 +1,655 @@
+//! This module implements the global `Atomics` object.
+//!
+//! The `Atomics` object provides atomic operations on the integer typed arrays that view a
+//! `SharedArrayBuffer`, and the `wait` and `notify` operations used to synchronize agents.
+//! The operations also work on typed arrays viewing an `ArrayBuffer`, except for `wait`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-atomics-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array_buffer::{ArrayBuffer, DataBlock, SharedMemoryOrder},
+        shared_array_buffer::WaitResult,
+        typed_array::{
+            integer_indexed_object::{ContentType, IntegerIndexed},
+            TypedArrayKind,
+        },
+        BuiltIn, JsArgs,
+    },
+    error::JsNativeError,
+    object::{JsObject, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    vm::INTERRUPTED_MESSAGE,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use std::time::Duration;
+use tap::{Conv, Pipe};
+
+/// Javascript `Atomics` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Atomics;
+
+impl BuiltIn for Atomics {
+    const NAME: &'static str = "Atomics";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ObjectInitializer::new(context)
+            .function(Self::add, "add", 3)
+            .function(Self::and, "and", 3)
+            .function(Self::compare_exchange, "compareExchange", 4)
+            .function(Self::exchange, "exchange", 3)
+            .function(Self::is_lock_free, "isLockFree", 1)
+            .function(Self::load, "load", 2)
+            .function(Self::or, "or", 3)
+            .function(Self::store, "store", 3)
+            .function(Self::sub, "sub", 3)
+            .function(Self::wait, "wait", 4)
+            .function(Self::notify, "notify", 3)
+            .function(Self::xor, "xor", 3)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl Atomics {
+    /// `25.4.3 Atomics.add ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.add
+    fn add(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let type be TypedArrayElementType(typedArray).
+        // 2. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 3. Let add be a new read-modify-write modification function with parameters (xBytes, yBytes) ...
+        // 4. Return ? AtomicReadModifyWrite(typedArray, index, value, add).
+        Self::atomic_read_modify_write(args, u64::wrapping_add, context)
+    }
+
+    /// `25.4.4 Atomics.and ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.and
+    fn and(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let and be a new read-modify-write modification function with parameters (xBytes, yBytes) ...
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, and).
+        Self::atomic_read_modify_write(args, |x, y| x & y, context)
+    }
+
+    /// `25.4.5 Atomics.compareExchange ( typedArray, index, expectedValue, replacementValue )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.compareexchange
+    fn compare_exchange(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let array = Self::validate_integer_typed_array(args.get_or_undefined(0), false)?;
+
+        // 2. Let block be buffer.[[ArrayBufferData]].
+        // 3. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let i = Self::validate_atomic_access(&array, args.get_or_undefined(1), context)?;
+
+        // 4. If typedArray.[[ContentType]] is BigInt, then
+        //     a. Let expected be ? ToBigInt(expectedValue).
+        //     b. Let replacement be ? ToBigInt(replacementValue).
+        // 5. Else,
+        //     a. Let expected be 𝔽(? ToIntegerOrInfinity(expectedValue)).
+        //     b. Let replacement be 𝔽(? ToIntegerOrInfinity(replacementValue)).
+        let kind = array.typed_array_name();
+        let expected = Self::to_integer_value(kind, args.get_or_undefined(2), context)?;
+        let replacement = Self::to_integer_value(kind, args.get_or_undefined(3), context)?;
+
+        // 6. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        // 7. NOTE: The above check is not redundant with the check in ValidateIntegerTypedArray because the call to ToBigInt or ToIntegerOrInfinity on the preceding lines can have arbitrary side effects, which could cause the buffer to become detached or shrunk.
+        let buffer = Self::revalidate_atomic_access(&array, i)?;
+        let mut buffer = buffer.borrow_mut();
+        let block = buffer
+            .as_array_buffer_mut()
+            .and_then(|buffer| buffer.array_buffer_data.as_mut())
+            .expect("the buffer was checked to be attached");
+
+        // 8. Let elementType be TypedArrayElementType(typedArray).
+        // 9. Let elementSize be TypedArrayElementSize(typedArray).
+        let element_size = kind.element_size() as usize;
+
+        // 10. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 11. Let expectedBytes be NumericToRawBytes(elementType, expected, isLittleEndian).
+        let expected = ArrayBuffer::numeric_to_raw_bytes(kind, &expected, true, context)?;
+
+        // 12. Let replacementBytes be NumericToRawBytes(elementType, replacement, isLittleEndian).
+        let replacement = ArrayBuffer::numeric_to_raw_bytes(kind, &replacement, true, context)?;
+
+        let i = i as usize;
+        let mut raw_bytes_read = [0; 8];
+        let raw_bytes_read = &mut raw_bytes_read[..element_size];
+        let compare_exchange = |block: &mut DataBlock, raw_bytes_read: &mut [u8]| {
+            block.read(i, raw_bytes_read);
+            if *raw_bytes_read == *expected {
+                block.write(i, &replacement);
+            }
+        };
+
+        // 13. If IsSharedArrayBuffer(buffer) is true, then
+        //     a-e. Append ReadModifyWriteSharedMemory { [[Order]]: SeqCst, [[NoTear]]: true, [[Block]]: block, [[ByteIndex]]: i, [[ElementSize]]: elementSize, [[Payload]]: replacementBytes, [[ModifyOp]]: second } to eventsRecord.[[EventList]], where second only writes replacementBytes if rawBytesRead equals expectedBytes.
+        // 14. Else,
+        //     a. Let rawBytesRead be a List of length elementSize whose elements are the sequence of elementSize bytes starting with block[i].
+        //     b. If ByteListEqual(rawBytesRead, expectedBytes) is true, then
+        //         i. Store the individual bytes of replacementBytes into block, starting at block[i].
+        if let DataBlock::Shared(shared) = block {
+            let shared = shared.clone();
+            shared.atomically(|| compare_exchange(block, raw_bytes_read));
+        } else {
+            compare_exchange(block, raw_bytes_read);
+        }
+
+        // 15. Return RawBytesToNumeric(elementType, rawBytesRead, isLittleEndian).
+        Ok(ArrayBuffer::raw_bytes_to_numeric(
+            kind,
+            raw_bytes_read,
+            true,
+        ))
+    }
+
+    /// `25.4.6 Atomics.exchange ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.exchange
+    fn exchange(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let second be a new read-modify-write modification function with parameters (oldBytes, newBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return newBytes.
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, second).
+        Self::atomic_read_modify_write(args, |_, y| y, context)
+    }
+
+    /// `25.4.7 Atomics.isLockFree ( size )`
+    ///
+    /// The atomic operations of this implementation are done in a critical section, so only the
+    /// size that the specification requires to be lock free is reported as such.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.islockfree
+    fn is_lock_free(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let n be ? ToIntegerOrInfinity(size).
+        let n = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        // 2. Let AR be the Agent Record of the surrounding agent.
+        // 3. If n = 1, return AR.[[IsLockFree1]].
+        // 4. If n = 2, return AR.[[IsLockFree2]].
+        // 5. If n = 4, return true.
+        // 6. If n = 8, return AR.[[IsLockFree8]].
+        // 7. Return false.
+        Ok((n == 4).into())
+    }
+
+    /// `25.4.8 Atomics.load ( typedArray, index )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.load
+    fn load(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let array = Self::validate_integer_typed_array(args.get_or_undefined(0), false)?;
+
+        // 2. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let i = Self::validate_atomic_access(&array, args.get_or_undefined(1), context)?;
+
+        // 3. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        // 4. NOTE: The above check is not redundant with the check in ValidateIntegerTypedArray because the call to ValidateAtomicAccess on the preceding line can have arbitrary side effects, which could cause the buffer to become detached or shrunk.
+        let buffer = Self::revalidate_atomic_access(&array, i)?;
+        let buffer = buffer.borrow();
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("the viewed buffer of a typed array must be an array buffer");
+
+        // 5. Let elementType be TypedArrayElementType(typedArray).
+        // 6. Return GetValueFromBuffer(buffer, i, elementType, true, SeqCst).
+        Ok(buffer.get_value_from_buffer(
+            i,
+            array.typed_array_name(),
+            true,
+            SharedMemoryOrder::SeqCst,
+            None,
+        ))
+    }
+
+    /// `25.4.9 Atomics.or ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.or
+    fn or(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let or be a new read-modify-write modification function with parameters (xBytes, yBytes) ...
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, or).
+        Self::atomic_read_modify_write(args, |x, y| x | y, context)
+    }
+
+    /// `25.4.10 Atomics.store ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.store
+    fn store(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let array = Self::validate_integer_typed_array(args.get_or_undefined(0), false)?;
+
+        // 2. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let i = Self::validate_atomic_access(&array, args.get_or_undefined(1), context)?;
+
+        // 3. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        // 4. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let kind = array.typed_array_name();
+        let v = Self::to_integer_value(kind, args.get_or_undefined(2), context)?;
+
+        // 5. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        // 6. NOTE: The above check is not redundant with the check in ValidateIntegerTypedArray because the call to ToBigInt or ToIntegerOrInfinity on the preceding lines can have arbitrary side effects, which could cause the buffer to become detached or shrunk.
+        let buffer = Self::revalidate_atomic_access(&array, i)?;
+        let mut buffer = buffer.borrow_mut();
+        let buffer = buffer
+            .as_array_buffer_mut()
+            .expect("the viewed buffer of a typed array must be an array buffer");
+
+        // 7. Let elementType be TypedArrayElementType(typedArray).
+        // 8. Perform SetValueInBuffer(buffer, i, elementType, v, true, SeqCst).
+        buffer.set_value_in_buffer(i, kind, &v, SharedMemoryOrder::SeqCst, None, context)?;
+
+        // 9. Return v.
+        Ok(v)
+    }
+
+    /// `25.4.11 Atomics.sub ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.sub
+    fn sub(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let type be TypedArrayElementType(typedArray).
+        // 2. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 3. Let subtract be a new read-modify-write modification function with parameters (xBytes, yBytes) ...
+        // 4. Return ? AtomicReadModifyWrite(typedArray, index, value, subtract).
+        Self::atomic_read_modify_write(args, u64::wrapping_sub, context)
+    }
+
+    /// `25.4.12 Atomics.wait ( typedArray, index, value, timeout )`
+    ///
+    /// Suspends the current agent until another agent notifies it, or until `timeout`
+    /// milliseconds elapse. Throws a `TypeError` unless the context was built to block, with
+    /// [`ContextBuilder::can_block`][crate::context::ContextBuilder::can_block]. A suspended agent
+    /// can still be terminated with its [`InterruptHandle`][crate::vm::InterruptHandle].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.wait
+    fn wait(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray, true).
+        let array = Self::validate_integer_typed_array(args.get_or_undefined(0), true)?;
+
+        // 2. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+        let block = Self::shared_block(&array).ok_or_else(|| {
+            JsNativeError::typ().with_message("Atomics.wait can only wait on a SharedArrayBuffer")
+        })?;
+
+        // 3. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let i = Self::validate_atomic_access(&array, args.get_or_undefined(1), context)?;
+
+        // 4. Let arrayTypeName be typedArray.[[TypedArrayName]].
+        // 5. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
+        // 6. Otherwise, let v be ? ToInt32(value).
+        //
+        // The raw bytes of v are compared to the raw bytes of the element instead of comparing
+        // the two numeric values.
+        let kind = array.typed_array_name();
+        let v = ArrayBuffer::numeric_to_raw_bytes(kind, args.get_or_undefined(2), true, context)?;
+
+        // 7. Let q be ? ToNumber(timeout).
+        let q = args.get_or_undefined(3).to_number(context)?;
+
+        // 8. If q is NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
+        let t = if q.is_nan() || q == f64::INFINITY {
+            None
+        } else {
+            // The saturating conversion turns timeouts too long to be represented into the
+            // longest representable timeout.
+            Some(Duration::from_micros((q.max(0.0) * 1000.0) as u64))
+        };
+
+        // 9. Let B be AgentCanSuspend().
+        // 10. If B is false, throw a TypeError exception.
+        if !context.can_block() {
+            return Err(JsNativeError::typ()
+                .with_message("Atomics.wait cannot suspend this agent")
+                .into());
+        }
+
+        // 11. Let block be buffer.[[ArrayBufferData]].
+        // 12. Let offset be typedArray.[[ByteOffset]].
+        // 13. Let byteIndexInBuffer be (i × 4) + offset.
+        // 14. Let WL be GetWaiterList(block, byteIndexInBuffer).
+        // 15. Perform EnterCriticalSection(WL).
+        // 16. Let elementType be TypedArrayElementType(typedArray).
+        // 17. Let w be GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, SeqCst).
+        // 18. If v ≠ w, then
+        //     a. Perform LeaveCriticalSection(WL).
+        //     b. Return "not-equal".
+        // 19. Let W be AgentSignifier().
+        // 20. Perform AddWaiter(WL, W).
+        // 21. Let notified be SuspendAgent(WL, W, t).
+        // 22. If notified is true, then
+        //     a. Assert: W is not on the list of waiters in WL.
+        // 23. Else,
+        //     a. Perform RemoveWaiter(WL, W).
+        // 24. Perform LeaveCriticalSection(WL).
+        // 25. If notified is true, return "ok".
+        // 26. Return "timed-out".
+        //
+        // The host can still interrupt the agent while it is suspended, which terminates the
+        // execution instead of returning.
+        let i = i as usize;
+        let interrupt_handle = context.interrupt_handle();
+        let result = block.wait(
+            i,
+            || {
+                let mut w_bytes = [0; 8];
+                let w_bytes = &mut w_bytes[..v.len()];
+                block.read(i, w_bytes);
+                *w_bytes == *v
+            },
+            t,
+            || interrupt_handle.take(),
+        );
+
+        Ok(match result {
+            WaitResult::NotEqual => "not-equal",
+            WaitResult::Ok => "ok",
+            WaitResult::TimedOut => "timed-out",
+            WaitResult::Interrupted => return Err(context.terminate(INTERRUPTED_MESSAGE)),
+        }
+        .into())
+    }
+
+    /// `25.4.13 Atomics.notify ( typedArray, index, count )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.notify
+    fn notify(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray, true).
+        let array = Self::validate_integer_typed_array(args.get_or_undefined(0), true)?;
+
+        // 2. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let i = Self::validate_atomic_access(&array, args.get_or_undefined(1), context)?;
+
+        // 3. If count is undefined, let c be +∞.
+        // 4. Else,
+        //     a. Let intCount be ? ToIntegerOrInfinity(count).
+        //     b. Let c be max(intCount, 0).
+        let count = args.get_or_undefined(2);
+        let c = if count.is_undefined() {
+            u64::MAX
+        } else {
+            match count.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::PositiveInfinity => u64::MAX,
+                IntegerOrInfinity::NegativeInfinity => 0,
+                IntegerOrInfinity::Integer(c) => c.max(0) as u64,
+            }
+        };
+
+        // 5. Let block be buffer.[[ArrayBufferData]].
+        // 6. Let offset be typedArray.[[ByteOffset]].
+        // 7. Let byteIndexInBuffer be (i × 4) + offset.
+        // 8. If IsSharedArrayBuffer(buffer) is false, return +0𝔽.
+        let block = if let Some(block) = Self::shared_block(&array) {
+            block
+        } else {
+            return Ok(0.into());
+        };
+
+        // 9. Let WL be GetWaiterList(block, byteIndexInBuffer).
+        // 10. Let n be 0.
+        // 11. Perform EnterCriticalSection(WL).
+        // 12. Let S be RemoveWaiters(WL, c).
+        // 13. For each element W of S, do
+        //     a. Perform NotifyWaiter(WL, W).
+        //     b. Set n to n + 1.
+        // 14. Perform LeaveCriticalSection(WL).
+        let n = block.notify(i as usize, c);
+
+        // 15. Return 𝔽(n).
+        Ok(n.into())
+    }
+
+    /// `25.4.14 Atomics.xor ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.xor
+    fn xor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let xor be a new read-modify-write modification function with parameters (xBytes, yBytes) ...
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, xor).
+        Self::atomic_read_modify_write(args, |x, y| x ^ y, context)
+    }
+
+    /// `25.4.2.1 ValidateIntegerTypedArray ( typedArray [ , waitable ] )`
+    ///
+    /// Returns the data of the typed array, which holds its buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateintegertypedarray
+    fn validate_integer_typed_array(array: &JsValue, waitable: bool) -> JsResult<IntegerIndexed> {
+        // 1. If waitable is not present, set waitable to false.
+        // 2. Perform ? ValidateTypedArray(typedArray).
+        let array = array
+            .as_object()
+            .and_then(|array| array.borrow().as_typed_array().cloned())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("Atomics: the value is not a typed array")
+            })?;
+        if array.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Atomics: the buffer of the typed array is detached or out of bounds")
+                .into());
+        }
+
+        // 3. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        let kind = array.typed_array_name();
+
+        // 4. If waitable is true, then
+        if waitable {
+            // a. If typedArray.[[TypedArrayName]] is not "Int32Array" or "BigInt64Array", throw a TypeError exception.
+            if !matches!(kind, TypedArrayKind::Int32 | TypedArrayKind::BigInt64) {
+                return Err(JsNativeError::typ()
+                    .with_message("Atomics: can only wait on an Int32Array or a BigInt64Array")
+                    .into());
+            }
+        }
+        // 5. Else,
+        //     a. Let type be TypedArrayElementType(typedArray).
+        //     b. If IsUnclampedIntegerElementType(type) is false and IsBigIntElementType(type) is false, throw a TypeError exception.
+        else if !ArrayBuffer::is_unclamped_integer_element_type(kind)
+            && !ArrayBuffer::is_big_int_element_type(kind)
+        {
+            return Err(JsNativeError::typ()
+                .with_message("Atomics: the typed array is not an integer typed array")
+                .into());
+        }
+
+        // 6. Return buffer.
+        Ok(array)
+    }
+
+    /// `25.4.2.2 ValidateAtomicAccess ( typedArray, requestIndex )`
+    ///
+    /// Returns the byte index of the element in the buffer of the typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateatomicaccess
+    fn validate_atomic_access(
+        array: &IntegerIndexed,
+        request_index: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<u64> {
+        // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(typedArray, unordered).
+        // 2. NOTE: Bounds checking is not a synchronizing operation when typedArray's backing buffer is a growable SharedArrayBuffer.
+        // 3. Let length be TypedArrayLength(taRecord).
+        let length = array.array_length();
+
+        // 2. Let accessIndex be ? ToIndex(requestIndex).
+        let access_index = request_index.to_index(context)?;
+
+        // 3. Assert: accessIndex ≥ 0.
+        // 4. If accessIndex ≥ length, throw a RangeError exception.
+        if access_index >= length {
+            return Err(JsNativeError::range()
+                .with_message("Atomics: the index is out of the bounds of the typed array")
+                .into());
+        }
+
+        // 5. Let elementSize be TypedArrayElementSize(typedArray).
+        // 6. Let offset be typedArray.[[ByteOffset]].
+        // 7. Return (accessIndex × elementSize) + offset.
+        Ok(access_index * array.typed_array_name().element_size() + array.byte_offset())
+    }
+
+    /// `25.4.2.11 AtomicReadModifyWrite ( typedArray, index, value, op )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomicreadmodifywrite
+    fn atomic_read_modify_write(
+        args: &[JsValue],
+        op: fn(u64, u64) -> u64,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let array = Self::validate_integer_typed_array(args.get_or_undefined(0), false)?;
+
+        // 2. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let i = Self::validate_atomic_access(&array, args.get_or_undefined(1), context)?;
+
+        // 3. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        // 4. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let kind = array.typed_array_name();
+        let v = Self::to_integer_value(kind, args.get_or_undefined(2), context)?;
+
+        // 5. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        // 6. NOTE: The above check is not redundant with the check in ValidateIntegerTypedArray because the call to ToBigInt or ToIntegerOrInfinity on the preceding lines can have arbitrary side effects, which could cause the buffer to become detached or shrunk.
+        let buffer = Self::revalidate_atomic_access(&array, i)?;
+        let mut buffer = buffer.borrow_mut();
+        let buffer = buffer
+            .as_array_buffer_mut()
+            .expect("the viewed buffer of a typed array must be an array buffer");
+
+        // 7. Let elementType be TypedArrayElementType(typedArray).
+        // 8. Return GetModifySetValueInBuffer(buffer, i, elementType, v, op).
+        buffer.get_modify_set_value_in_buffer(i, kind, &v, op, context)
+    }
+
+    /// Converts `value` to the kind of integer stored by the elements of type `kind`, which is
+    /// a `BigInt` for `BigInt` typed arrays and an integral `Number` otherwise.
+    fn to_integer_value(
+        kind: TypedArrayKind,
+        value: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if kind.content_type() == ContentType::BigInt {
+            return Ok(value.to_bigint(context)?.into());
+        }
+
+        Ok(match value.to_integer_or_infinity(context)? {
+            IntegerOrInfinity::PositiveInfinity => f64::INFINITY.into(),
+            IntegerOrInfinity::NegativeInfinity => f64::NEG_INFINITY.into(),
+            IntegerOrInfinity::Integer(i) => i.into(),
+        })
+    }
+
+    /// Abstract operation `RevalidateAtomicAccess ( typedArray, byteIndexInBuffer )`.
+    ///
+    /// Returns the buffer of the typed array, after checking that the byte index is still
+    /// inside of it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-revalidateatomicaccess
+    fn revalidate_atomic_access(
+        array: &IntegerIndexed,
+        byte_index_in_buffer: u64,
+    ) -> JsResult<JsObject> {
+        // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(typedArray, unordered).
+        // 2. NOTE: Bounds checking is not a synchronizing operation when typedArray's backing buffer is a growable SharedArrayBuffer.
+        // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        if array.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Atomics: the buffer of the typed array is detached or out of bounds")
+                .into());
+        }
+        let buffer = array
+            .viewed_array_buffer()
+            .expect("a typed array must have a viewed array buffer");
+
+        // 4. Assert: byteIndexInBuffer ≥ typedArray.[[ByteOffset]].
+        // 5. If byteIndexInBuffer ≥ taRecord.[[CachedBufferByteLength]], throw a RangeError exception.
+        let buffer_byte_length = buffer
+            .borrow()
+            .as_array_buffer()
+            .expect("the viewed buffer of a typed array must be an array buffer")
+            .array_buffer_byte_length();
+        if byte_index_in_buffer >= buffer_byte_length {
+            return Err(JsNativeError::range()
+                .with_message("Atomics: the index is out of the bounds of the typed array")
+                .into());
+        }
+
+        // 6. Return unused.
+        Ok(buffer.clone())
+    }
+
+    /// Returns the Shared Data Block viewed by the typed array, if its buffer is a
+    /// `SharedArrayBuffer`.
+    fn shared_block(
+        array: &IntegerIndexed,
+    ) -> Option<crate::builtins::shared_array_buffer::SharedMemory> {
+        let buffer = array.viewed_array_buffer()?.borrow();
+        match buffer.as_array_buffer()?.array_buffer_data {
+            Some(DataBlock::Shared(ref block)) => Some(block.clone()),
+            _ => None,
+        }
+    }
+}
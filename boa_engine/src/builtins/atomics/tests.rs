@@ -0,0 +1,216 @@
+use crate::{
+    builtins::shared_array_buffer::SharedMemory, forward, object::JsSharedArrayBuffer,
+    property::Attribute, Context,
+};
+use std::thread;
+
+#[test]
+fn read_modify_write() {
+    let mut context = Context::default();
+    let init = r#"
+        const ta = new Int32Array(new SharedArrayBuffer(16));
+        ta[0] = 5;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "Atomics.add(ta, 0, 3)"), "5");
+    assert_eq!(forward(&mut context, "Atomics.sub(ta, 0, 1)"), "8");
+    assert_eq!(forward(&mut context, "Atomics.and(ta, 0, 6)"), "7");
+    assert_eq!(forward(&mut context, "Atomics.or(ta, 0, 1)"), "6");
+    assert_eq!(forward(&mut context, "Atomics.xor(ta, 0, 3)"), "7");
+    assert_eq!(forward(&mut context, "Atomics.exchange(ta, 0, -1)"), "4");
+    assert_eq!(forward(&mut context, "Atomics.load(ta, 0)"), "-1");
+    assert_eq!(
+        forward(&mut context, "Atomics.add(ta, 0, 1); ta[0]"),
+        "0",
+        "the operations must wrap around"
+    );
+    assert_eq!(forward(&mut context, "Atomics.store(ta, 1, 3.7)"), "3");
+    assert_eq!(forward(&mut context, "ta[1]"), "3");
+}
+
+#[test]
+fn compare_exchange() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "const ta = new Uint8Array(new ArrayBuffer(4));",
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.compareExchange(ta, 0, 1, 2)"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "ta[0]"), "0");
+    assert_eq!(
+        forward(&mut context, "Atomics.compareExchange(ta, 0, 256, 2)"),
+        "0",
+        "the expected value must be converted to the element type"
+    );
+    assert_eq!(forward(&mut context, "ta[0]"), "2");
+}
+
+#[test]
+fn big_int_arrays() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "const ta = new BigInt64Array(new SharedArrayBuffer(16));",
+    );
+    assert_eq!(forward(&mut context, "Atomics.store(ta, 1, 5n)"), "5n");
+    assert_eq!(forward(&mut context, "Atomics.sub(ta, 1, 7n)"), "5n");
+    assert_eq!(forward(&mut context, "Atomics.load(ta, 1)"), "-2n");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.add(ta, 0, 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn validation() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "const ta = new Int32Array(new SharedArrayBuffer(8));",
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.load(new Float64Array(4), 0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.load(new Uint8ClampedArray(4), 0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.load(ta, 2) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.wait(new Int32Array(4), 0, 0, 0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.wait(new Uint32Array(new SharedArrayBuffer(8)), 0, 0, 0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Atomics.isLockFree(4)"), "true");
+    assert_eq!(forward(&mut context, "Atomics.isLockFree(3)"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(Atomics)"),
+        "\"[object Atomics]\""
+    );
+}
+
+#[test]
+fn wait_without_notify() {
+    let mut context = Context::builder().can_block(true).build();
+    forward(
+        &mut context,
+        "const ta = new Int32Array(new SharedArrayBuffer(8));",
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.wait(ta, 0, 1)"),
+        "\"not-equal\""
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.wait(ta, 0, 0, 10)"),
+        "\"timed-out\""
+    );
+    assert_eq!(forward(&mut context, "Atomics.notify(ta, 0)"), "0");
+    assert_eq!(
+        forward(&mut context, "Atomics.notify(new Int32Array(4), 0)"),
+        "0"
+    );
+}
+
+#[test]
+fn cannot_block() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "const ta = new Int32Array(new SharedArrayBuffer(8));",
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.wait(ta, 0, 0, 0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn wait_and_notify_across_threads() {
+    let memory = SharedMemory::new(8).unwrap();
+
+    let waiter = {
+        let memory = memory.clone();
+        thread::spawn(move || {
+            let mut context = Context::builder().can_block(true).build();
+            let sab = JsSharedArrayBuffer::from_memory(memory, &mut context).unwrap();
+            context.register_global_property("sab", sab, Attribute::all());
+            forward(
+                &mut context,
+                "const ta = new Int32Array(sab); Atomics.wait(ta, 1, 0)",
+            )
+        })
+    };
+
+    let mut context = Context::default();
+    let sab = JsSharedArrayBuffer::from_memory(memory, &mut context).unwrap();
+    context.register_global_property("sab", sab, Attribute::all());
+    // The element is only changed after the waiter is woken up, otherwise a waiter that is slow
+    // to start would return "not-equal" and never be notified.
+    let notify = r#"
+        const ta = new Int32Array(sab);
+        while (Atomics.notify(ta, 1, 1) === 0) {}
+        Atomics.store(ta, 1, 7);
+        "#;
+    forward(&mut context, notify);
+
+    assert_eq!(waiter.join().unwrap(), "\"ok\"");
+    assert_eq!(forward(&mut context, "Atomics.load(ta, 1)"), "7");
+}
+
+#[test]
+fn interrupt_waiting_agent() {
+    let mut context = Context::builder().can_block(true).build();
+    let handle = context.interrupt_handle();
+
+    let thread = thread::spawn(move || {
+        thread::sleep(std::time::Duration::from_millis(50));
+        handle.interrupt();
+    });
+    let error = context
+        .eval(
+            r#"
+            try {
+                Atomics.wait(new Int32Array(new SharedArrayBuffer(4)), 0, 0);
+            } catch (e) {}
+            "#,
+        )
+        .unwrap_err();
+    thread.join().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "RangeError: Script execution was interrupted"
+    );
+
+    assert_eq!(forward(&mut context, "1 + 1"), "2");
+}
@@ -0,0 +1,164 @@
+//! The loggers used by the `console` object to print its messages.
+
+#![allow(clippy::print_stdout, clippy::print_stderr)]
+
+use crate::vm::StackFrame;
+use std::{cell::RefCell, fmt::Debug};
+
+/// The log level of a message printed by the `console` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogLevel {
+    /// Messages printed by `console.trace()`.
+    Trace,
+    /// Messages printed by `console.debug()`.
+    Debug,
+    /// Messages printed by `console.log()`, `console.timeLog()`.
+    Log,
+    /// Messages printed by `console.info()`, `console.count()`, `console.group()`...
+    Info,
+    /// Messages printed by `console.warn()` and the warnings of the other methods.
+    Warn,
+    /// Messages printed by `console.error()` and `console.assert()`.
+    Error,
+}
+
+/// A host hook that receives the messages printed by the `console` object.
+///
+/// This is the [Printer][spec] operation of the console specification. The logger of a
+/// [`Context`](crate::Context) can be set with
+/// [`ContextBuilder::logger`](crate::context::ContextBuilder::logger), and defaults to a
+/// [`StdoutLogger`].
+///
+/// [spec]: https://console.spec.whatwg.org/#printer
+pub trait Logger: Debug {
+    /// Prints a message.
+    ///
+    /// `group_depth` is the number of groups created by `console.group()` that contain the
+    /// message, and `stack_trace` is the stack trace of the code that called the `console`
+    /// method, starting with the innermost frame. The stack trace is empty for the levels for
+    /// which [`Logger::wants_stack_trace`] returns `false`.
+    fn log(&self, level: LogLevel, message: &str, group_depth: usize, stack_trace: &[StackFrame]);
+
+    /// Returns `true` if the messages of `level` should receive the stack trace of the code that
+    /// printed them.
+    ///
+    /// Capturing a stack trace walks all the active call frames, so by default it is only done
+    /// for the messages of the [`LogLevel::Trace`] and [`LogLevel::Error`] levels.
+    fn wants_stack_trace(&self, level: LogLevel) -> bool {
+        matches!(level, LogLevel::Trace | LogLevel::Error)
+    }
+}
+
+/// A logger that prints errors to the standard error, and all other messages to the standard
+/// output.
+///
+/// Messages are indented by two spaces for every group that contains them, and messages printed
+/// by `console.trace()` are followed by their stack trace.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdoutLogger;
+
+impl Logger for StdoutLogger {
+    fn log(&self, level: LogLevel, message: &str, group_depth: usize, stack_trace: &[StackFrame]) {
+        let indent = 2 * group_depth;
+        let mut output = String::new();
+        // `console.trace()` can be called without a label, in which case only the stack trace
+        // is printed.
+        if level != LogLevel::Trace || !message.is_empty() {
+            for line in message.split('\n') {
+                output.push_str(&format!("{:indent$}{line}\n", ""));
+            }
+        }
+        if level == LogLevel::Trace {
+            for frame in stack_trace {
+                output.push_str(&format!("{:indent$}    at {frame}\n", ""));
+            }
+        }
+
+        if level == LogLevel::Error {
+            eprint!("{output}");
+        } else {
+            print!("{output}");
+        }
+    }
+}
+
+/// A message stored by a [`CapturingLogger`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    level: LogLevel,
+    message: String,
+    group_depth: usize,
+    stack_trace: Vec<StackFrame>,
+}
+
+impl LogRecord {
+    /// Gets the log level of the message.
+    #[inline]
+    pub fn level(&self) -> LogLevel {
+        self.level
+    }
+
+    /// Gets the formatted message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the number of groups that contained the message.
+    #[inline]
+    pub fn group_depth(&self) -> usize {
+        self.group_depth
+    }
+
+    /// Gets the stack trace of the code that printed the message, which is empty unless the
+    /// message is a trace or an error, like for the other loggers that do not override
+    /// [`Logger::wants_stack_trace`].
+    #[inline]
+    pub fn stack_trace(&self) -> &[StackFrame] {
+        &self.stack_trace
+    }
+}
+
+/// A logger that stores the printed messages instead of printing them, which is useful to test
+/// the output of scripts.
+///
+/// # Examples
+/// ```
+///# use boa_engine::{builtins::console::{CapturingLogger, LogLevel}, Context};
+///# use std::rc::Rc;
+/// let logger = Rc::new(CapturingLogger::new());
+/// let mut context = Context::builder().logger(logger.clone()).build();
+///
+/// context.eval("console.warn('careful')").unwrap();
+///
+/// let records = logger.take();
+/// assert_eq!(records[0].level(), LogLevel::Warn);
+/// assert_eq!(records[0].message(), "careful");
+/// ```
+#[derive(Debug, Default)]
+pub struct CapturingLogger {
+    records: RefCell<Vec<LogRecord>>,
+}
+
+impl CapturingLogger {
+    /// Creates a new `CapturingLogger` without any messages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the messages printed since the last call to [`CapturingLogger::take`].
+    pub fn take(&self) -> Vec<LogRecord> {
+        std::mem::take(&mut self.records.borrow_mut())
+    }
+}
+
+impl Logger for CapturingLogger {
+    fn log(&self, level: LogLevel, message: &str, group_depth: usize, stack_trace: &[StackFrame]) {
+        self.records.borrow_mut().push(LogRecord {
+            level,
+            message: message.to_owned(),
+            group_depth,
+            stack_trace: stack_trace.to_vec(),
+        });
+    }
+}
@@ -11,11 +11,13 @@
 //! [spec]: https://console.spec.whatwg.org/
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Console
 
-#![allow(clippy::print_stdout)]
+mod logger;
 
 #[cfg(test)]
 mod tests;
 
+pub use logger::{CapturingLogger, LogLevel, LogRecord, Logger, StdoutLogger};
+
 use crate::{
     builtins::{BuiltIn, JsArgs},
     object::ObjectInitializer,
@@ -24,10 +26,11 @@ use crate::{
 };
 use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
-use std::time::SystemTime;
+use std::{rc::Rc, time::SystemTime};
 use tap::{Conv, Pipe};
 
 /// This represents the different types of log messages.
+#[deprecated(note = "the `console` object now passes a `LogLevel` to the `Logger` of the context")]
 #[derive(Debug)]
 pub enum LogMessage {
     Log(String),
@@ -36,18 +39,17 @@ pub enum LogMessage {
     Error(String),
 }
 
-/// Helper function for logging messages.
-pub(crate) fn logger(msg: LogMessage, console_state: &Console) {
-    let indent = 2 * console_state.groups.len();
-
-    match msg {
-        LogMessage::Error(msg) => {
-            eprintln!("{msg:>indent$}");
-        }
-        LogMessage::Log(msg) | LogMessage::Info(msg) | LogMessage::Warn(msg) => {
-            println!("{msg:>indent$}");
-        }
-    }
+/// Helper function for printing messages with the logger of the context.
+pub(crate) fn logger(level: LogLevel, message: &str, context: &Context) {
+    let console = context.console();
+    let stack_trace = if console.logger.wants_stack_trace(level) {
+        context.stack_trace()
+    } else {
+        Vec::new()
+    };
+    console
+        .logger
+        .log(level, message, console.groups.len(), &stack_trace);
 }
 
 /// This represents the `console` formatter.
@@ -56,11 +58,12 @@ pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
         .get(0)
         .cloned()
         .unwrap_or_default()
-        .to_string(context)?;
+        .to_string(context)?
+        .to_std_string_escaped();
 
     match data.len() {
         0 => Ok(String::new()),
-        1 => Ok(target.to_string()),
+        1 => Ok(target),
         _ => {
             let mut formatted = String::new();
             let mut arg_index = 1;
@@ -96,7 +99,8 @@ pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
                                 .get(arg_index)
                                 .cloned()
                                 .unwrap_or_default()
-                                .to_string(context)?;
+                                .to_string(context)?
+                                .to_std_string_escaped();
                             formatted.push_str(&arg);
                             arg_index += 1;
                         }
@@ -123,11 +127,12 @@ pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
 }
 
 /// This is the internal console object state.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub(crate) struct Console {
     count_map: FxHashMap<JsString, u32>,
     timer_map: FxHashMap<JsString, u128>,
     groups: Vec<String>,
+    logger: Rc<dyn Logger>,
 }
 
 impl BuiltIn for Console {
@@ -165,6 +170,16 @@ impl Console {
     /// The name of the object.
     pub(crate) const NAME: &'static str = "console";
 
+    /// Creates the console state of a context, which prints its messages with `logger`.
+    pub(crate) fn new(logger: Rc<dyn Logger>) -> Self {
+        Self {
+            count_map: FxHashMap::default(),
+            timer_map: FxHashMap::default(),
+            groups: Vec::new(),
+            logger,
+        }
+    }
+
     /// `console.assert(condition, ...data)`
     ///
     /// Prints a JavaScript value to the standard error if first argument evaluates to `false` or there
@@ -195,10 +210,7 @@ impl Console {
                 args[0] = JsValue::new(concat);
             }
 
-            logger(
-                LogMessage::Error(formatter(&args, context)?),
-                context.console(),
-            );
+            logger(LogLevel::Error, &formatter(&args, context)?, context);
         }
 
         Ok(JsValue::undefined())
@@ -231,10 +243,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#debug
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/debug
     pub(crate) fn debug(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Log(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogLevel::Debug, &formatter(args, context)?, context);
         Ok(JsValue::undefined())
     }
 
@@ -249,10 +258,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#error
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/error
     pub(crate) fn error(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Error(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogLevel::Error, &formatter(args, context)?, context);
         Ok(JsValue::undefined())
     }
 
@@ -267,10 +273,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#info
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/info
     pub(crate) fn info(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Info(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogLevel::Info, &formatter(args, context)?, context);
         Ok(JsValue::undefined())
     }
 
@@ -285,28 +288,10 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#log
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/log
     pub(crate) fn log(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Log(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogLevel::Log, &formatter(args, context)?, context);
         Ok(JsValue::undefined())
     }
 
-    fn get_stack_trace(context: &mut Context) -> Vec<String> {
-        let mut stack_trace: Vec<String> = vec![];
-
-        for frame in context.vm.frames.iter().rev() {
-            stack_trace.push(
-                context
-                    .interner()
-                    .resolve_expect(frame.code.name)
-                    .to_owned(),
-            );
-        }
-
-        stack_trace
-    }
-
     /// `console.trace(...data)`
     ///
     /// Prints a stack trace with "trace" logLevel, optionally labelled by data.
@@ -318,16 +303,8 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#trace
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/trace
     pub(crate) fn trace(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        if !args.is_empty() {
-            logger(
-                LogMessage::Log(formatter(args, context)?),
-                context.console(),
-            );
-
-            let stack_trace_dump = Self::get_stack_trace(context).join("\n");
-            logger(LogMessage::Log(stack_trace_dump), context.console());
-        }
-
+        // The stack trace itself is passed to the logger with every message.
+        logger(LogLevel::Trace, &formatter(args, context)?, context);
         Ok(JsValue::undefined())
     }
 
@@ -342,10 +319,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#warn
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/warn
     pub(crate) fn warn(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Warn(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogLevel::Warn, &formatter(args, context)?, context);
         Ok(JsValue::undefined())
     }
 
@@ -369,7 +343,7 @@ impl Console {
         let c = context.console_mut().count_map.entry(label).or_insert(0);
         *c += 1;
 
-        logger(LogMessage::Info(format!("{msg} {c}")), context.console());
+        logger(LogLevel::Info, &format!("{msg} {c}"), context);
         Ok(JsValue::undefined())
     }
 
@@ -395,10 +369,7 @@ impl Console {
 
         context.console_mut().count_map.remove(&label);
 
-        logger(
-            LogMessage::Warn(format!("countReset {label}")),
-            context.console(),
-        );
+        logger(LogLevel::Warn, &format!("countReset {label}"), context);
 
         Ok(JsValue::undefined())
     }
@@ -429,8 +400,9 @@ impl Console {
 
         if context.console().timer_map.get(&label).is_some() {
             logger(
-                LogMessage::Warn(format!("Timer '{label}' already exist")),
-                context.console(),
+                LogLevel::Warn,
+                &format!("Timer '{label}' already exist"),
+                context,
             );
         } else {
             let time = Self::system_time_in_ms();
@@ -466,11 +438,12 @@ impl Console {
             for msg in args.iter().skip(1) {
                 concat = concat + " " + &msg.display().to_string();
             }
-            logger(LogMessage::Log(concat), context.console());
+            logger(LogLevel::Log, &concat, context);
         } else {
             logger(
-                LogMessage::Warn(format!("Timer '{label}' doesn't exist")),
-                context.console(),
+                LogLevel::Warn,
+                &format!("Timer '{label}' doesn't exist"),
+                context,
             );
         }
 
@@ -497,16 +470,18 @@ impl Console {
             None => "default".into(),
         };
 
-        if let Some(t) = context.console_mut().timer_map.remove(label.as_str()) {
+        if let Some(t) = context.console_mut().timer_map.remove(&label) {
             let time = Self::system_time_in_ms();
             logger(
-                LogMessage::Info(format!("{label}: {} ms - timer removed", time - t)),
-                context.console(),
+                LogLevel::Info,
+                &format!("{label}: {} ms - timer removed", time - t),
+                context,
             );
         } else {
             logger(
-                LogMessage::Warn(format!("Timer '{label}' doesn't exist")),
-                context.console(),
+                LogLevel::Warn,
+                &format!("Timer '{label}' doesn't exist"),
+                context,
             );
         }
 
@@ -526,10 +501,7 @@ impl Console {
     pub(crate) fn group(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let group_label = formatter(args, context)?;
 
-        logger(
-            LogMessage::Info(format!("group: {group_label}")),
-            context.console(),
-        );
+        logger(LogLevel::Info, &format!("group: {group_label}"), context);
         context.console_mut().groups.push(group_label);
 
         Ok(JsValue::undefined())
@@ -569,8 +541,9 @@ impl Console {
     #[allow(clippy::unnecessary_wraps)]
     pub(crate) fn dir(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         logger(
-            LogMessage::Info(display_obj(args.get_or_undefined(0), true)),
-            context.console(),
+            LogLevel::Info,
+            &display_obj(args.get_or_undefined(0), true),
+            context,
         );
         Ok(JsValue::undefined())
     }
@@ -1,4 +1,9 @@
-use crate::{builtins::console::formatter, Context, JsValue};
+use crate::{
+    builtins::console::{formatter, CapturingLogger, LogLevel, Logger},
+    vm::StackFrame,
+    Context, JsString, JsValue,
+};
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn formatter_no_args_is_empty_string() {
@@ -66,3 +71,110 @@ fn formatter_float_format_works() {
     let res = formatter(&val, &mut context).unwrap();
     assert_eq!(res, "3.141500");
 }
+
+#[test]
+fn logger_receives_levels_and_group_depth() {
+    let logger = Rc::new(CapturingLogger::new());
+    let mut context = Context::builder().logger(logger.clone()).build();
+
+    context
+        .eval(
+            r#"
+            console.log("log");
+            console.debug("debug %d", 4.5);
+            console.group("outer");
+            console.warn("warn");
+            console.groupEnd();
+            console.error("error");
+        "#,
+        )
+        .unwrap();
+
+    let records = logger
+        .take()
+        .into_iter()
+        .map(|record| {
+            (
+                record.level(),
+                record.message().to_owned(),
+                record.group_depth(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        records,
+        [
+            (LogLevel::Log, "log".to_owned(), 0),
+            (LogLevel::Debug, "debug 4".to_owned(), 0),
+            (LogLevel::Info, "group: outer".to_owned(), 0),
+            (LogLevel::Warn, "warn".to_owned(), 1),
+            (LogLevel::Error, "error".to_owned(), 0),
+        ]
+    );
+}
+
+#[test]
+fn logger_receives_stack_trace() {
+    let logger = Rc::new(CapturingLogger::new());
+    let mut context = Context::builder().logger(logger.clone()).build();
+
+    context
+        .eval_with_name(
+            "function inner() {\n  console.trace('here');\n}\ninner();",
+            "trace.js",
+        )
+        .unwrap();
+
+    let records = logger.take();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].level(), LogLevel::Trace);
+    assert_eq!(records[0].message(), "here");
+    let names = records[0]
+        .stack_trace()
+        .iter()
+        .map(|frame| frame.function_name().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(names, [JsString::from("inner"), JsString::from("<main>")]);
+}
+
+#[test]
+fn stack_trace_is_only_captured_for_traces_and_errors() {
+    let logger = Rc::new(CapturingLogger::new());
+    let mut context = Context::builder().logger(logger.clone()).build();
+
+    context
+        .eval("console.log('log'); console.warn('warn'); console.error('error');")
+        .unwrap();
+
+    let records = logger.take();
+    assert!(records[0].stack_trace().is_empty());
+    assert!(records[1].stack_trace().is_empty());
+    assert_eq!(records[2].stack_trace().len(), 1);
+}
+
+#[test]
+fn logger_can_request_stack_traces_for_all_levels() {
+    #[derive(Debug, Default)]
+    struct TracingLogger {
+        frame_counts: RefCell<Vec<usize>>,
+    }
+
+    impl Logger for TracingLogger {
+        fn log(&self, _: LogLevel, _: &str, _: usize, stack_trace: &[StackFrame]) {
+            self.frame_counts.borrow_mut().push(stack_trace.len());
+        }
+
+        fn wants_stack_trace(&self, _: LogLevel) -> bool {
+            true
+        }
+    }
+
+    let logger = Rc::new(TracingLogger::default());
+    let mut context = Context::builder().logger(logger.clone()).build();
+
+    context
+        .eval("function f() { console.log('log'); console.info('info'); } f();")
+        .unwrap();
+
+    assert_eq!(*logger.frame_counts.borrow(), [2, 2]);
+}
@@ -3,8 +3,9 @@ mod tests;
 
 use super::JsArgs;
 use crate::{
-    builtins::BuiltIn,
+    builtins::{temporal::Instant, BuiltIn},
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
     },
@@ -47,8 +48,8 @@ fn ignore_ambiguity<T>(result: LocalResult<T>) -> Option<T> {
 
 macro_rules! getter_method {
     ($name:ident) => {{
-        fn get_value(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-            Ok(JsValue::new(this_time_value(this, context)?.$name()))
+        fn get_value(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+            Ok(JsValue::new(this_time_value(this)?.$name()))
         }
         get_value
     }};
@@ -134,6 +135,7 @@ impl BuiltIn for Date {
         .method(getter_method!(to_gmt_string), "toGMTString", 0)
         .method(Self::to_iso_string, "toISOString", 0)
         .method(Self::to_json, "toJSON", 1)
+        .method(Self::to_temporal_instant, "toTemporalInstant", 0)
         // Locale strings
         .method(Self::to_string, "toString", 0)
         .method(Self::to_time_string, "toTimeString", 0)
@@ -173,6 +175,12 @@ impl Date {
         }
     }
 
+    /// Creates a new `Date` from a UTC date and time, or an invalid `Date` if `datetime` is `None`.
+    #[inline]
+    pub(crate) fn new(datetime: Option<NaiveDateTime>) -> Self {
+        Self(datetime)
+    }
+
     /// Converts the `Date` to a local `DateTime`.
     ///
     /// If the `Date` is invalid (i.e. NAN), this function will return `None`.
@@ -390,14 +398,16 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         let value = &args[0];
-        let tv = match this_time_value(value, context) {
+        let tv = match this_time_value(value) {
             Ok(dt) => dt.0,
-            _ => match value.to_primitive(context, PreferredType::Default)? {
-                JsValue::String(ref str) => match chrono::DateTime::parse_from_rfc3339(str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
-                },
-                tv => {
+            _ => {
+                let tv = value.to_primitive(context, PreferredType::Default)?;
+                if let Some(str) = tv.as_string() {
+                    match chrono::DateTime::parse_from_rfc3339(&str.to_std_string_escaped()) {
+                        Ok(dt) => Some(dt.naive_utc()),
+                        _ => None,
+                    }
+                } else {
                     let tv = tv.to_number(context)?;
                     if tv.is_nan() {
                         None
@@ -407,7 +417,7 @@ impl Date {
                         NaiveDateTime::from_timestamp_opt(secs, nano_secs)
                     }
                 }
-            },
+            }
         };
 
         let tv = tv.filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
@@ -507,12 +517,18 @@ impl Date {
         let o = if let Some(o) = this.as_object() {
             o
         } else {
-            return context.throw_type_error("Date.prototype[@@toPrimitive] called on non object");
+            return Err(JsNativeError::typ()
+                .with_message("Date.prototype[@@toPrimitive] called on non object")
+                .into());
         };
 
         let hint = args.get_or_undefined(0);
 
-        let try_first = match hint.as_string().map(JsString::as_str) {
+        let try_first = match hint
+            .as_string()
+            .and_then(|hint| hint.to_std_string().ok())
+            .as_deref()
+        {
             // 3. If hint is "string" or "default", then
             // a. Let tryFirst be string.
             Some("string" | "default") => PreferredType::String,
@@ -521,8 +537,9 @@ impl Date {
             Some("number") => PreferredType::Number,
             // 5. Else, throw a TypeError exception.
             _ => {
-                return context
-                    .throw_type_error("Date.prototype[@@toPrimitive] called with invalid hint")
+                return Err(JsNativeError::typ()
+                    .with_message("Date.prototype[@@toPrimitive] called with invalid hint")
+                    .into())
             }
         };
 
@@ -700,10 +717,10 @@ impl Date {
     pub fn get_timezone_offset(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let t = this_time_value(this, context)?;
+        let t = this_time_value(this)?;
 
         // 2. If t is NaN, return NaN.
         if t.0.is_none() {
@@ -849,7 +866,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setDate
     pub fn set_date(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let dt be ? ToNumber(date).
         let dt = args
@@ -888,7 +905,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         if t.0.is_none() {
@@ -946,7 +963,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setHours
     pub fn set_hours(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let h be ? ToNumber(hour).
         let h = args
@@ -1005,7 +1022,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Set ms to ? ToNumber(ms).
         let ms = args
@@ -1043,7 +1060,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(min).
         let m = args
@@ -1091,7 +1108,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMonth
     pub fn set_month(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(month).
         let m = args
@@ -1136,7 +1153,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be LocalTime(? thisTimeValue(this value)).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let s be ? ToNumber(sec).
         let s = args
@@ -1177,7 +1194,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setYear
     pub fn set_year(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         if t.0.is_none() {
@@ -1234,7 +1251,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setTime
     pub fn set_time(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Perform ? thisTimeValue(this value).
-        this_time_value(this, context)?;
+        this_time_value(this)?;
 
         // 2. Let t be ? ToNumber(time).
         let t = if let Some(t) = args.get(0) {
@@ -1275,7 +1292,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let dt be ? ToNumber(date).
         let dt = args
@@ -1314,7 +1331,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. If t is NaN, set t to +0𝔽.
         if t.0.is_none() {
@@ -1376,7 +1393,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let h be ? ToNumber(hour).
         let h = args
@@ -1435,7 +1452,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let milli be ? ToNumber(ms).
         let ms = args
@@ -1473,7 +1490,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(min).
         let m = args
@@ -1529,7 +1546,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let m be ? ToNumber(month).
         let m = args
@@ -1576,7 +1593,7 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let t be ? thisTimeValue(this value).
-        let mut t = this_time_value(this, context)?;
+        let mut t = this_time_value(this)?;
 
         // 2. Let s be ? ToNumber(sec).
         let s = args
@@ -1618,14 +1635,10 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.todatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toDateString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_date_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub fn to_date_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be this Date object.
         // 2. Let tv be ? thisTimeValue(O).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 3. If tv is NaN, return "Invalid Date".
         // 4. Let t be LocalTime(tv).
@@ -1669,12 +1682,8 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toisostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toISOString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_iso_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        if let Some(t) = this_time_value(this, context)?.0 {
+    pub fn to_iso_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        if let Some(t) = this_time_value(this)?.0 {
             Ok(Utc::now()
                 .timezone()
                 .from_utc_datetime(&t)
@@ -1682,7 +1691,9 @@ impl Date {
                 .to_string()
                 .into())
         } else {
-            context.throw_range_error("Invalid time value")
+            Err(JsNativeError::range()
+                .with_message("Invalid time value")
+                .into())
         }
     }
 
@@ -1716,6 +1727,37 @@ impl Date {
         context.call(&func, &o.into(), &[])
     }
 
+    /// `Date.prototype.toTemporalInstant()`
+    ///
+    /// The `toTemporalInstant()` method returns a `Temporal.Instant` with the same exact time as
+    /// the specified Date object.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-date.prototype.totemporalinstant
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTemporalInstant
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_temporal_instant(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let t be dateObject.[[DateValue]].
+        // 4. If t is NaN, throw a RangeError exception.
+        let t = this_time_value(this)?
+            .to_utc()
+            .ok_or_else(|| JsNativeError::range().with_message("Invalid time value"))?;
+
+        // 5. Let ns be ? NumberToBigInt(t) × ℤ(10^6).
+        // 6. Return ! CreateTemporalInstant(ns).
+        let ns = i128::from(t.timestamp_millis()) * 1_000_000;
+        Instant::create(ns, None, context).map(Into::into)
+    }
+
     /// `Date.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Date object.
@@ -1727,9 +1769,9 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    pub fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let tv be ? thisTimeValue(this value).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 2. Return ToDateString(tv).
         if let Some(t) = tv.0 {
@@ -1756,14 +1798,10 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.totimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTimeString
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_time_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub fn to_time_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be this Date object.
         // 2. Let tv be ? thisTimeValue(O).
-        let tv = this_time_value(this, context)?;
+        let tv = this_time_value(this)?;
 
         // 3. If tv is NaN, return "Invalid Date".
         // 4. Let t be LocalTime(tv).
@@ -1846,7 +1884,7 @@ impl Date {
             return Ok(JsValue::nan());
         }
 
-        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
+        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?.to_std_string_escaped()) {
             Ok(v) => Ok(JsValue::new(v.naive_utc().timestamp_millis() as f64)),
             _ => Ok(JsValue::new(f64::NAN)),
         }
@@ -1923,9 +1961,13 @@ impl Date {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-thistimevalue
 #[inline]
-pub fn this_time_value(value: &JsValue, context: &mut Context) -> JsResult<Date> {
+pub fn this_time_value(value: &JsValue) -> JsResult<Date> {
     value
         .as_object()
         .and_then(|obj| obj.borrow().as_date().copied())
-        .ok_or_else(|| context.construct_type_error("'this' is not a Date"))
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Date")
+                .into()
+        })
 }
@@ -1,6 +1,6 @@
 #![allow(clippy::zero_prefixed_literal)]
 
-use crate::{forward, forward_val, Context, JsValue};
+use crate::{error::JsNativeErrorKind, forward, forward_val, value::JsVariant, Context, JsValue};
 use chrono::prelude::*;
 
 // NOTE: Javascript Uses 0-based months, where chrono uses 1-based months. Many of the assertions look wrong because of
@@ -13,7 +13,7 @@ fn forward_dt_utc(context: &mut Context, src: &str) -> Option<NaiveDateTime> {
         panic!("expected success")
     };
 
-    if let JsValue::Object(ref date_time) = date_time {
+    if let JsVariant::Object(date_time) = date_time.variant() {
         if let Some(date_time) = date_time.borrow().as_date() {
             date_time.0
         } else {
@@ -60,13 +60,10 @@ fn date_this_time_value() {
         "({toString: Date.prototype.toString}).toString()",
     )
     .expect_err("Expected error");
-    let message_property = &error
-        .get_property("message")
-        .expect("Expected 'message' property")
-        .expect_value()
-        .clone();
+    let error = error.as_native().expect("Expected a native error");
 
-    assert_eq!(JsValue::new("\'this\' is not a Date"), *message_property);
+    assert_eq!(error.kind(), &JsNativeErrorKind::Type);
+    assert_eq!(error.message(), "'this' is not a Date");
 }
 
 #[test]
@@ -1287,3 +1284,30 @@ fn date_json() {
         actual
     );
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn js_date_chrono_roundtrip() {
+    use crate::object::JsDate;
+
+    let mut context = Context::default();
+
+    let datetime = Utc.ymd(2020, 2, 29).and_hms_milli(23, 59, 59, 999);
+    let date = JsDate::from_datetime(&datetime, &mut context);
+    assert_eq!(date.to_datetime(), Some(datetime));
+    assert_eq!(
+        date.to_iso_string(&mut context).unwrap(),
+        JsValue::new("2020-02-29T23:59:59.999Z")
+    );
+
+    let date = JsDate::from_object(date.into()).unwrap();
+    assert_eq!(date.get_time(), 1_583_020_799_999.0);
+
+    let date = forward_val(&mut context, "new Date(NaN)").unwrap();
+    let date = JsDate::from_object(date.as_object().unwrap().clone()).unwrap();
+    assert!(date.get_time().is_nan());
+    assert_eq!(date.to_datetime(), None);
+    assert!(date.to_iso_string(&mut context).is_err());
+
+    assert!(JsDate::from_object(context.construct_object()).is_err());
+}
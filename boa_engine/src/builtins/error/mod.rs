@@ -13,8 +13,10 @@
 use crate::{
     builtins::BuiltIn,
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
     object::{
-        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
     },
     property::Attribute,
     Context, JsResult, JsString, JsValue,
@@ -53,6 +55,16 @@ impl BuiltIn for Error {
     fn init(context: &mut Context) -> Option<JsValue> {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
+        let get_stack = FunctionBuilder::native(context, Self::get_stack)
+            .name("get stack")
+            .constructor(false)
+            .build();
+        let set_stack = FunctionBuilder::native(context, Self::set_stack)
+            .name("set stack")
+            .length(1)
+            .constructor(false)
+            .build();
+
         let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
         ConstructorBuilder::with_standard_constructor(
             context,
@@ -64,6 +76,12 @@ impl BuiltIn for Error {
         .property("name", Self::NAME, attribute)
         .property("message", "", attribute)
         .method(Self::to_string, "toString", 0)
+        .accessor(
+            "stack",
+            Some(get_stack),
+            Some(set_stack),
+            Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .build()
         .conv::<JsValue>()
         .pipe(Some)
@@ -107,7 +125,7 @@ impl Error {
         // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%Error.prototype%", « [[ErrorData]] »).
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::error, context)?;
-        let o = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let o = JsObject::from_proto_and_data(prototype, ObjectData::error(context.stack_trace()));
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -147,7 +165,9 @@ impl Error {
             o
         // 2. If Type(O) is not Object, throw a TypeError exception.
         } else {
-            return context.throw_type_error("'this' is not an Object");
+            return Err(JsNativeError::typ()
+                .with_message("'this' is not an Object")
+                .into());
         };
 
         // 3. Let name be ? Get(O, "name").
@@ -184,4 +204,78 @@ impl Error {
         // the code unit 0x0020 (SPACE), and msg.
         Ok(format!("{name}: {msg}").into())
     }
+
+    /// `get Error.prototype.stack`
+    ///
+    /// Returns the stack trace that was captured when the error object was created, as a string
+    /// that starts with the string representation of the error, followed by a line for each
+    /// frame of the stack.
+    ///
+    /// This is not part of ECMAScript yet, but it follows the [Error Stacks proposal][proposal],
+    /// and the format of the string is compatible with other engines.
+    ///
+    /// [proposal]: https://github.com/tc39/proposal-error-stacks
+    pub(crate) fn get_stack(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let o = if let Some(o) = this.as_object() {
+            o
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("'this' is not an Object")
+                .into());
+        };
+
+        let stack = if let Some(stack) = o.borrow().error_stack() {
+            stack.to_vec()
+        } else {
+            return Ok(JsValue::undefined());
+        };
+
+        let mut result = Self::to_string(this, &[], context)?
+            .to_string(context)?
+            .to_string();
+        for frame in &stack {
+            result.push_str("\n    at ");
+            result.push_str(&frame.to_string());
+        }
+
+        Ok(result.into())
+    }
+
+    /// `set Error.prototype.stack`
+    ///
+    /// Replaces the stack of an object with an own `stack` data property, without modifying
+    /// `Error.prototype`.
+    pub(crate) fn set_stack(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let o = if let Some(o) = this.as_object() {
+            o
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("'this' is not an Object")
+                .into());
+        };
+
+        let prototype = context.intrinsics().constructors().error().prototype();
+        if JsObject::equals(o, &prototype) {
+            return Err(JsNativeError::typ()
+                .with_message("cannot set the stack of Error.prototype")
+                .into());
+        }
+
+        let value = args.get_or_undefined(0).clone();
+        if o.has_own_property("stack", context)? {
+            o.set("stack", value, true, context)?;
+        } else {
+            o.create_data_property_or_throw("stack", value, context)?;
+        }
+
+        Ok(JsValue::undefined())
+    }
 }
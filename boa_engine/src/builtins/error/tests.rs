@@ -1,4 +1,7 @@
-use crate::{forward, Context};
+use crate::{
+    error::{JsError, JsNativeError, JsNativeErrorKind},
+    forward, Context, JsValue,
+};
 
 #[test]
 fn error_to_string() {
@@ -84,3 +87,130 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn error_stack() {
+    let mut context = Context::default();
+    let init = r#"
+function outer() {
+    return inner();
+}
+function inner() {
+    return new TypeError("boom");
+}
+var error = outer();
+"#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "error.stack"),
+        "\"TypeError: boom\n    at inner (<anonymous>:6:12)\n    at outer (<anonymous>:3:17)\n    at <main> (<anonymous>:8:18)\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyNames(error).includes('stack')"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn error_stack_of_thrown_errors() {
+    let mut context = Context::default();
+    let init = r#"
+var error;
+try {
+    null.x;
+} catch (e) {
+    error = e;
+}
+"#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "error.stack"),
+        "\"TypeError: cannot convert 'null' or 'undefined' to object\n    at <main> (<anonymous>:4:9)\""
+    );
+}
+
+#[test]
+fn error_stack_accessor() {
+    let mut context = Context::default();
+    assert_eq!(forward(&mut context, "Error.prototype.stack"), "undefined");
+    assert_eq!(
+        forward(&mut context, "({ __proto__: Error.prototype }).stack"),
+        "undefined"
+    );
+
+    let init = r#"
+var error = new Error();
+error.stack = "replaced";
+"#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error.stack"), "\"replaced\"");
+    assert_eq!(
+        forward(&mut context, "error.hasOwnProperty('stack')"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Error.prototype.stack = 1 } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn native_error_to_opaque() {
+    let mut context = Context::default();
+
+    let error = JsNativeError::range()
+        .with_message("out of range")
+        .with_cause(JsError::from_opaque(JsValue::new(5)))
+        .to_opaque(&mut context);
+
+    assert!(error.is_error());
+    assert_eq!(
+        error.get("name", &mut context).unwrap(),
+        JsValue::new("RangeError")
+    );
+    assert_eq!(
+        error.get("message", &mut context).unwrap(),
+        JsValue::new("out of range")
+    );
+    assert_eq!(error.get("cause", &mut context).unwrap(), JsValue::new(5));
+}
+
+#[test]
+fn thrown_error_to_native() {
+    let mut context = Context::default();
+
+    let error = context
+        .eval("throw new TypeError('bad value', { cause: 1 })")
+        .unwrap_err();
+    assert!(error.as_native().is_none());
+
+    let native = error.try_native(&mut context).unwrap();
+    assert_eq!(native.kind(), &JsNativeErrorKind::Type);
+    assert_eq!(native.message(), "bad value");
+    assert_eq!(
+        native.cause().and_then(JsError::as_opaque),
+        Some(&JsValue::new(1))
+    );
+
+    let error = context.eval("throw 1").unwrap_err();
+    assert!(error.try_native(&mut context).is_err());
+}
+
+#[test]
+fn native_error_is_catchable() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { null.x } catch (e) { `${e instanceof TypeError} ${e.message}` }"
+        ),
+        "\"true cannot convert 'null' or 'undefined' to object\""
+    );
+}
@@ -11,9 +11,11 @@
 
 use crate::{
     builtins::{BuiltIn, JsArgs},
+    error::JsNativeError,
     object::FunctionBuilder,
     property::Attribute,
-    Context, JsValue,
+    syntax::ast::node::StatementList,
+    Context, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
 use rustc_hash::FxHashSet;
@@ -48,7 +50,7 @@ impl Eval {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-eval-x
-    fn eval(_: &JsValue, args: &[JsValue], context: &mut Context) -> Result<JsValue, JsValue> {
+    fn eval(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Return ? PerformEval(x, false, false).
         Self::perform_eval(args.get_or_undefined(0), false, false, context)
     }
@@ -64,7 +66,7 @@ impl Eval {
         direct: bool,
         strict: bool,
         context: &mut Context,
-    ) -> Result<JsValue, JsValue> {
+    ) -> JsResult<JsValue> {
         // 1. Assert: If direct is false, then strictCaller is also false.
         if !direct {
             debug_assert!(!strict);
@@ -72,7 +74,7 @@ impl Eval {
 
         // 2. If Type(x) is not String, return x.
         let x = if let Some(x) = x.as_string() {
-            x.clone()
+            x.to_std_string_escaped()
         } else {
             return Ok(x.clone());
         };
@@ -82,7 +84,7 @@ impl Eval {
         // Parse the script body and handle early errors (6 - 11)
         let body = match context.parse_eval(x.as_bytes(), direct, strict) {
             Ok(body) => body,
-            Err(e) => return context.throw_syntax_error(e.to_string()),
+            Err(e) => return Err(JsNativeError::syntax().with_message(e.to_string()).into()),
         };
 
         // 12 - 13 are implicit in the call of `Context::compile_with_new_declarative`.
@@ -93,57 +95,70 @@ impl Eval {
             // If the call to eval is direct, the code is executed in the current environment.
 
             // Poison the current environment, because it may contain new declarations after/during eval.
-            context.realm.environments.poison_current();
+            context.vm.environments.poison_current();
 
             // Set the compile time environment to the current running environment and save the number of current environments.
-            context.realm.compile_env = context.realm.environments.current_compile_environment();
-            let environments_len = context.realm.environments.len();
+            context
+                .realm
+                .set_compile_env(context.vm.environments.current_compile_environment());
+            let environments_len = context.vm.environments.len();
 
             // Error if any var declaration in the eval code already exists as a let/const declaration in the current running environment.
             let mut vars = FxHashSet::default();
             body.var_declared_names_new(&mut vars);
             if let Some(name) = context
-                .realm
+                .vm
                 .environments
                 .has_lex_binding_until_function_environment(&vars)
             {
                 let name = context.interner().resolve_expect(name);
                 let msg = format!("variable declaration {name} in eval function already exists as lexically declaration");
-                return context.throw_syntax_error(msg);
+                return Err(JsNativeError::syntax().with_message(msg).into());
             }
 
             // Compile and execute the eval statement list.
             let code_block = context.compile_with_new_declarative(&body, strict)?;
-            context
-                .realm
-                .environments
-                .extend_outer_function_environment();
+            context.vm.environments.extend_outer_function_environment();
             let result = context.execute(code_block);
 
             // Pop any added runtime environments that where not removed during the eval execution.
-            context.realm.environments.truncate(environments_len);
+            context.vm.environments.truncate(environments_len);
 
             result
         } else {
             // If the call to eval is indirect, the code is executed in the global environment.
-
-            // Poison all environments, because the global environment may contain new declarations after/during eval.
-            context.realm.environments.poison_all();
-
-            // Pop all environments before the eval execution.
-            let environments = context.realm.environments.pop_to_global();
-            let environments_len = context.realm.environments.len();
-            context.realm.compile_env = context.realm.environments.current_compile_environment();
-
-            // Compile and execute the eval statement list.
-            let code_block = context.compile_with_new_declarative(&body, false)?;
-            let result = context.execute(code_block);
-
-            // Restore all environments to the state from before the eval execution.
-            context.realm.environments.truncate(environments_len);
-            context.realm.environments.extend(environments);
-
-            result
+            Self::execute_indirect(&body, context)
         }
     }
+
+    /// Executes the already parsed `body` of an indirect eval in the global environment of the
+    /// current realm.
+    ///
+    /// This is also used by `ShadowRealm.prototype.evaluate`, which has the same semantics as an
+    /// indirect eval, but has to report parse errors differently.
+    pub(crate) fn execute_indirect(
+        body: &StatementList,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // Poison all environments, because the global environment may contain new declarations after/during eval.
+        context.vm.environments.poison_all();
+
+        // Pop all environments before the eval execution.
+        let environments = context.vm.environments.pop_to_global();
+        let environments_len = context.vm.environments.len();
+        context
+            .realm
+            .set_compile_env(context.vm.environments.current_compile_environment());
+
+        // Compile and execute the eval statement list.
+        let result = context
+            .compile_with_new_declarative(body, false)
+            .and_then(|code_block| context.execute(code_block));
+
+        // Restore all environments to the state from before the eval execution.
+        context.vm.environments.truncate(environments_len);
+        context.vm.environments.extend(environments);
+
+        result
+    }
 }
@@ -0,0 +1,384 @@
+//! This module implements the global `FinalizationRegistry` object.
+//!
+//! A `FinalizationRegistry` lets a program request a cleanup callback to be called after an
+//! object registered within it has been garbage collected.
+//!
+//! Cleanup callbacks never run in the middle of a collection. Instead, registries with collected
+//! targets are found before the queued jobs of the [`Context`] are run, and a cleanup job is
+//! enqueued for each one of them with [`Context::enqueue_job`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+use super::JsArgs;
+use crate::{
+    builtins::BuiltIn,
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    job::JobCallback,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, Object, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Cell as GcCell, Finalize, Trace, WeakGc};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+#[cfg(test)]
+mod tests;
+
+/// A record of the `[[Cells]]` list of a `FinalizationRegistry`.
+#[derive(Debug, Trace, Finalize)]
+struct RegistryCell {
+    target: WeakGc<GcCell<Object>>,
+    held_value: JsValue,
+    unregister_token: Option<WeakGc<GcCell<Object>>>,
+}
+
+/// The internal representation of a `FinalizationRegistry` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    cleanup_callback: JobCallback,
+    cells: Vec<RegistryCell>,
+    cleanup_pending: bool,
+}
+
+impl BuiltIn for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .finalization_registry()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::register, "register", 2)
+        .method(Self::unregister, "unregister", 1)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl FinalizationRegistry {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `FinalizationRegistry ( cleanupCallback )`
+    ///
+    /// Constructor for `FinalizationRegistry` objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/FinalizationRegistry
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "calling a builtin FinalizationRegistry constructor without new is forbidden",
+                )
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = if let Some(callback) = args.get_or_undefined(0).as_callable() {
+            callback.clone()
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry: cleanup callback is not callable")
+                .into());
+        };
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let registry = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::finalization_registry(Self {
+                cleanup_callback: JobCallback::make_job_callback(cleanup_callback),
+                cells: Vec::new(),
+                cleanup_pending: false,
+            }),
+        );
+        context.register_finalization_registry(&registry);
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+
+    /// `FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// Registers an object with the registry, so that the cleanup callback is called with
+    /// `heldValue` once the object is collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .filter(|obj| obj.borrow().is_finalization_registry());
+        let registry = if let Some(registry) = registry {
+            registry
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.register: called with non-FinalizationRegistry value",
+                )
+                .into());
+        };
+
+        // 3. If target is not an Object, throw a TypeError exception.
+        let target = args.get_or_undefined(0);
+        let target = if let Some(target) = target.as_object() {
+            target
+        } else {
+            return Err(JsNativeError::typ().with_message(format!(
+                "FinalizationRegistry.register: expected target argument of type `object`, got target of type `{}`",
+                target.type_of()
+            )).into());
+        };
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        let held_value = args.get_or_undefined(1);
+        if held_value
+            .as_object()
+            .map_or(false, |held_value| JsObject::equals(target, held_value))
+        {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.register: target and held value must not be the same",
+                )
+                .into());
+        }
+
+        // 5. If unregisterToken is not an Object, then
+        let unregister_token = args.get_or_undefined(2);
+        let unregister_token = if let Some(token) = unregister_token.as_object() {
+            Some(WeakGc::new(token.inner()))
+        } else {
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            if !unregister_token.is_undefined() {
+                return Err(JsNativeError::typ().with_message(format!(
+                    "FinalizationRegistry.register: expected unregister token of type `object` or `undefined`, got token of type `{}`",
+                    unregister_token.type_of()
+                )).into());
+            }
+
+            // b. Set unregisterToken to empty.
+            None
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry
+            .borrow_mut()
+            .as_finalization_registry_mut()
+            .expect("checked that `this` was a FinalizationRegistry")
+            .cells
+            .push(RegistryCell {
+                target: WeakGc::new(target.inner()),
+                held_value: held_value.clone(),
+                unregister_token,
+            });
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `FinalizationRegistry.prototype.unregister ( unregisterToken )`
+    ///
+    /// Removes all the cells of the registry that were registered with `unregisterToken`.
+    /// Returns true if any cell was removed, and false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .filter(|obj| obj.borrow().is_finalization_registry());
+        let registry = if let Some(registry) = registry {
+            registry
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.unregister: called with non-FinalizationRegistry value",
+                )
+                .into());
+        };
+
+        // 3. If unregisterToken is not an Object, throw a TypeError exception.
+        let token = args.get_or_undefined(0);
+        let token = if let Some(token) = token.as_object() {
+            token
+        } else {
+            return Err(JsNativeError::typ().with_message(format!(
+                "FinalizationRegistry.unregister: expected unregister token of type `object`, got token of type `{}`",
+                token.type_of()
+            )).into());
+        };
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        let mut registry = registry.borrow_mut();
+        let cells = &mut registry
+            .as_finalization_registry_mut()
+            .expect("checked that `this` was a FinalizationRegistry")
+            .cells;
+        let len = cells.len();
+        cells.retain(|cell| {
+            !cell
+                .unregister_token
+                .as_ref()
+                .map_or(false, |cell_token| cell_token.ptr_eq(token.inner()))
+        });
+
+        // 6. Return removed.
+        Ok((cells.len() != len).into())
+    }
+
+    /// Returns `true` if the registry has a cell whose target has been collected, and no
+    /// cleanup job is pending for it yet. The registry is then marked as having a pending
+    /// cleanup job.
+    pub(crate) fn needs_cleanup(&mut self) -> bool {
+        if self.cleanup_pending || self.cells.iter().all(|cell| cell.target.is_alive()) {
+            return false;
+        }
+        self.cleanup_pending = true;
+        true
+    }
+
+    /// `HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )`
+    ///
+    /// Creates the job that calls the cleanup callback of `registry` for each one of its
+    /// collected targets.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub(crate) fn cleanup_job(registry: JsObject, context: &mut Context) -> JobCallback {
+        // 1. Let cleanupJob be a new Job Abstract Closure with no parameters that captures finalizationRegistry and performs the following steps when called:
+        //     a. Let cleanupResult be Completion(CleanupFinalizationRegistry(finalizationRegistry)).
+        //     b. If cleanupResult is an abrupt completion, perform any host-defined steps for reporting the error.
+        //     c. Return unused.
+        let job = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, _args, registry, context| {
+                Self::cleanup(registry, context)?;
+                Ok(JsValue::undefined())
+            },
+            registry,
+        )
+        .build();
+
+        // 2. Let realm be ? GetFunctionRealm(finalizationRegistry.[[CleanupCallback]].[[Callback]]).
+        // 3. Perform HostEnqueuePromiseJob(cleanupJob, realm).
+        JobCallback::make_job_callback(job.into())
+    }
+
+    /// `CleanupFinalizationRegistry ( finalizationRegistry )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    fn cleanup(registry: &JsObject, context: &mut Context) -> JsResult<()> {
+        // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+        // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+        let callback = {
+            let mut registry = registry.borrow_mut();
+            let registry = registry
+                .as_finalization_registry_mut()
+                .expect("the cleanup job must be created for a FinalizationRegistry");
+            registry.cleanup_pending = false;
+            registry.cleanup_callback.clone()
+        };
+
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty, an implementation may perform the following steps:
+        loop {
+            // a. Choose any such cell.
+            // b. Remove cell from finalizationRegistry.[[Cells]].
+            let cell = {
+                let mut registry = registry.borrow_mut();
+                let cells = &mut registry
+                    .as_finalization_registry_mut()
+                    .expect("the cleanup job must be created for a FinalizationRegistry")
+                    .cells;
+                cells
+                    .iter()
+                    .position(|cell| !cell.target.is_alive())
+                    .map(|index| cells.remove(index))
+            };
+            let cell = if let Some(cell) = cell {
+                cell
+            } else {
+                break;
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            callback.call_job_callback(
+                &JsValue::undefined(),
+                std::slice::from_ref(&cell.held_value),
+                context,
+            )?;
+        }
+
+        // 4. Return unused.
+        Ok(())
+    }
+}
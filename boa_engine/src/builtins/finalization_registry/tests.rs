@@ -0,0 +1,117 @@
+use crate::{forward, Context};
+use boa_gc::force_collect;
+
+#[test]
+fn construct() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "const registry = new FinalizationRegistry(() => {});",
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(registry)"),
+        "\"[object FinalizationRegistry]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new FinalizationRegistry(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { FinalizationRegistry(() => {}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn register_and_unregister() {
+    let mut context = Context::default();
+    let init = r#"
+        const registry = new FinalizationRegistry(() => {});
+        const target = {};
+        const token = {};
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "registry.register(target, 'held', token)"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "true");
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(1, 'held') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, target) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, 'held', 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.unregister(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn cleanup_callback_runs_as_job() {
+    let mut context = Context::default();
+    let init = r#"
+        var held = [];
+        var registry = new FinalizationRegistry(value => { held.push(value); });
+        registry.register({}, 'first');
+        var target = {};
+        registry.register(target, 'second');
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "held.length"), "0");
+
+    force_collect();
+
+    // The cleanup callback doesn't run during the collection, but as a job after the next
+    // execution.
+    assert_eq!(forward(&mut context, "held.length"), "0");
+    assert_eq!(forward(&mut context, "held.join()"), "\"first\"");
+
+    forward(&mut context, "target = undefined;");
+    force_collect();
+    forward(&mut context, "");
+    assert_eq!(forward(&mut context, "held.join()"), "\"first,second\"");
+}
+
+#[test]
+fn unregistered_cells_are_not_cleaned_up() {
+    let mut context = Context::default();
+    let init = r#"
+        var held = [];
+        var registry = new FinalizationRegistry(value => { held.push(value); });
+        var token = {};
+        registry.register({}, 'held', token);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "true");
+
+    force_collect();
+    forward(&mut context, "");
+    assert_eq!(forward(&mut context, "held.length"), "0");
+}
@@ -16,18 +16,22 @@ use crate::{
     bytecompiler::{FunctionCompiler, FunctionKind},
     context::intrinsics::StandardConstructors,
     environments::DeclarativeEnvironmentStack,
+    error::JsNativeError,
+    js_string,
     object::{
         internal_methods::get_prototype_from_constructor, JsObject, NativeObject, Object,
         ObjectData,
     },
     object::{ConstructorBuilder, FunctionBuilder, JsFunction, PrivateElement, Ref, RefMut},
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    string::utf16,
     symbol::WellKnownSymbols,
     syntax::{
         ast::node::{FormalParameterList, StatementList},
         Parser,
     },
-    value::IntegerOrInfinity,
+    value::{IntegerOrInfinity, IntoJs, TryFromJs},
     Context, JsResult, JsString, JsValue,
 };
 use boa_gc::{self, custom_trace, Finalize, Gc, Trace};
@@ -90,6 +94,55 @@ impl<T> ClosureFunctionSignature for T where
 // Allows cloning Box<dyn ClosureFunctionSignature>
 dyn_clone::clone_trait_object!(ClosureFunctionSignature);
 
+/// Trait representing a native function with typed parameters.
+///
+/// It is implemented for functions and `Copy` closures that take up to six parameters
+/// implementing [`TryFromJs`] followed by the [`Context`], and return a [`JsResult`] of a type
+/// implementing [`IntoJs`]. Each argument is converted before calling the function, throwing a
+/// `TypeError` if the conversion fails. Missing arguments are converted from `undefined`, so
+/// optional parameters can be declared as an [`Option`].
+///
+/// See [`FunctionBuilder::typed`] to create a function object from it.
+pub trait TypedFunctionSignature<Args>: Copy + 'static {
+    /// The number of parameters of the function, used as its `length` property.
+    const LENGTH: usize;
+
+    /// Converts the arguments, calls the function and converts its result back to a
+    /// [`JsValue`].
+    fn call_typed(self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue>;
+}
+
+macro_rules! impl_typed_function_signature {
+    ($length:literal $(, $name:ident: $index:literal)*) => {
+        impl<Func, Ret $(, $name)*> TypedFunctionSignature<($($name,)*)> for Func
+        where
+            Func: Fn($($name,)* &mut Context) -> JsResult<Ret> + Copy + 'static,
+            Ret: IntoJs,
+            $($name: TryFromJs,)*
+        {
+            const LENGTH: usize = $length;
+
+            #[inline]
+            #[allow(unused_variables)]
+            fn call_typed(self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+                let result = self(
+                    $($name::try_from_js(args.get_or_undefined($index), context)?,)*
+                    context,
+                )?;
+                Ok(result.into_js(context))
+            }
+        }
+    };
+}
+
+impl_typed_function_signature!(0);
+impl_typed_function_signature!(1, A: 0);
+impl_typed_function_signature!(2, A: 0, B: 1);
+impl_typed_function_signature!(3, A: 0, B: 1, C: 2);
+impl_typed_function_signature!(4, A: 0, B: 1, C: 2, D: 3);
+impl_typed_function_signature!(5, A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_typed_function_signature!(6, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
 #[derive(Debug, Trace, Finalize, PartialEq, Eq, Clone)]
 pub enum ThisMode {
     Lexical,
@@ -215,16 +268,21 @@ pub enum Function {
     Native {
         function: NativeFunctionSignature,
         constructor: Option<ConstructorKind>,
+        realm: Realm,
     },
     Closure {
         function: Box<dyn ClosureFunctionSignature>,
         constructor: Option<ConstructorKind>,
         captures: Captures,
+        realm: Realm,
     },
     Ordinary {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
 
+        /// The `[[Realm]]` internal slot.
+        realm: Realm,
+
         /// The `[[ConstructorKind]]` internal slot.
         constructor_kind: ConstructorKind,
 
@@ -240,41 +298,50 @@ pub enum Function {
     Async {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
+        realm: Realm,
         promise_capability: PromiseCapability,
     },
     Generator {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
+        realm: Realm,
     },
     AsyncGenerator {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
+        realm: Realm,
     },
 }
 
 unsafe impl Trace for Function {
     custom_trace! {this, {
         match this {
-            Self::Native { .. } => {}
-            Self::Closure { captures, .. } => mark(captures),
-            Self::Ordinary { code, environments, home_object, fields, private_methods, .. } => {
+            Self::Native { realm, .. } => mark(realm),
+            Self::Closure { captures, realm, .. } => {
+                mark(captures);
+                mark(realm);
+            }
+            Self::Ordinary { code, environments, realm, home_object, fields, private_methods, .. } => {
                 mark(code);
                 mark(environments);
+                mark(realm);
                 mark(home_object);
                 mark(fields);
                 for (_, elem) in private_methods {
                     mark(elem);
                 }
             }
-            Self::Async { code, environments, promise_capability } => {
+            Self::Async { code, environments, realm, promise_capability } => {
                 mark(code);
                 mark(environments);
+                mark(realm);
                 mark(promise_capability);
             }
-            Self::Generator { code, environments }
-            | Self::AsyncGenerator { code, environments } => {
+            Self::Generator { code, environments, realm }
+            | Self::AsyncGenerator { code, environments, realm } => {
                 mark(code);
                 mark(environments);
+                mark(realm);
             }
         }
     }}
@@ -287,6 +354,18 @@ impl fmt::Debug for Function {
 }
 
 impl Function {
+    /// Returns the `[[Realm]]` of the function, the realm it was created in.
+    pub(crate) fn realm(&self) -> &Realm {
+        match self {
+            Self::Native { realm, .. }
+            | Self::Closure { realm, .. }
+            | Self::Ordinary { realm, .. }
+            | Self::Async { realm, .. }
+            | Self::Generator { realm, .. }
+            | Self::AsyncGenerator { realm, .. } => realm,
+        }
+    }
+
     /// Returns true if the function object is a constructor.
     pub fn is_constructor(&self) -> bool {
         match self {
@@ -424,6 +503,7 @@ pub(crate) fn make_builtin_fn<N>(
         ObjectData::function(Function::Native {
             function,
             constructor: None,
+            realm: interpreter.realm().clone(),
         }),
     );
     let attribute = PropertyDescriptor::builder()
@@ -498,7 +578,7 @@ impl BuiltInFunctionObject {
             } else {
                 let mut parameters = Vec::with_capacity(args.len());
                 for arg in args {
-                    parameters.push(arg.to_string(context)?);
+                    parameters.push(arg.to_string(context)?.to_std_string_escaped());
                 }
                 let mut parameters = parameters.join(",");
                 parameters.push(')');
@@ -510,16 +590,14 @@ impl BuiltInFunctionObject {
                 ) {
                     Ok(parameters) => parameters,
                     Err(e) => {
-                        return context.throw_syntax_error(format!(
-                            "failed to parse function parameters: {e}"
-                        ))
+                        return Err(JsNativeError::syntax()
+                            .with_message(format!("failed to parse function parameters: {e}"))
+                            .into())
                     }
                 };
 
                 if generator && parameters.contains_yield_expression() {
-                    return context.throw_syntax_error(
-                            "yield expression is not allowed in formal parameter list of generator function",
-                        );
+                    return Err(JsNativeError::syntax().with_message("yield expression is not allowed in formal parameter list of generator function").into());
                 }
 
                 parameters
@@ -527,19 +605,19 @@ impl BuiltInFunctionObject {
 
             // It is a Syntax Error if FormalParameters Contains YieldExpression is true.
             if generator && r#async && parameters.contains_yield_expression() {
-                return context.throw_syntax_error(
-                    "yield expression not allowed in async generator parameters",
-                );
+                return Err(JsNativeError::syntax()
+                    .with_message("yield expression not allowed in async generator parameters")
+                    .into());
             }
 
             // It is a Syntax Error if FormalParameters Contains AwaitExpression is true.
             if generator && r#async && parameters.contains_await_expression() {
-                return context.throw_syntax_error(
-                    "await expression not allowed in async generator parameters",
-                );
+                return Err(JsNativeError::syntax()
+                    .with_message("await expression not allowed in async generator parameters")
+                    .into());
             }
 
-            let body_arg = body_arg.to_string(context)?;
+            let body_arg = body_arg.to_string(context)?.to_std_string_escaped();
 
             let body = match Parser::new(body_arg.as_bytes()).parse_function_body(
                 context.interner_mut(),
@@ -548,8 +626,9 @@ impl BuiltInFunctionObject {
             ) {
                 Ok(statement_list) => statement_list,
                 Err(e) => {
-                    return context
-                        .throw_syntax_error(format!("failed to parse function body: {e}"))
+                    return Err(JsNativeError::syntax()
+                        .with_message(format!("failed to parse function body: {e}"))
+                        .into())
                 }
             };
 
@@ -559,9 +638,9 @@ impl BuiltInFunctionObject {
                 for parameter in parameters.parameters.iter() {
                     for name in parameter.names() {
                         if name == Sym::ARGUMENTS || name == Sym::EVAL {
-                            return context.throw_syntax_error(
-                                " Unexpected 'eval' or 'arguments' in strict mode",
-                            );
+                            return Err(JsNativeError::syntax()
+                                .with_message(" Unexpected 'eval' or 'arguments' in strict mode")
+                                .into());
                         }
                     }
                 }
@@ -570,16 +649,19 @@ impl BuiltInFunctionObject {
             // Early Error: If the source code matching FormalParameters is strict mode code,
             // the Early Error rules for UniqueFormalParameters : FormalParameters are applied.
             if (body.strict()) && parameters.has_duplicates() {
-                return context
-                    .throw_syntax_error("Duplicate parameter name not allowed in this context");
+                return Err(JsNativeError::syntax()
+                    .with_message("Duplicate parameter name not allowed in this context")
+                    .into());
             }
 
             // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of GeneratorBody is true
             // and IsSimpleParameterList of FormalParameters is false.
             if body.strict() && !parameters.is_simple() {
-                return context.throw_syntax_error(
-                    "Illegal 'use strict' directive in function with non-simple parameter list",
-                );
+                return Err(JsNativeError::syntax()
+                    .with_message(
+                        "Illegal 'use strict' directive in function with non-simple parameter list",
+                    )
+                    .into());
             }
 
             // It is a Syntax Error if any element of the BoundNames of FormalParameters
@@ -593,10 +675,12 @@ impl BuiltInFunctionObject {
                             .iter()
                             .any(|(name, _)| *name == param_name)
                         {
-                            return context.throw_syntax_error(format!(
-                                "Redeclaration of formal parameter `{}`",
-                                context.interner().resolve_expect(param_name)
-                            ));
+                            return Err(JsNativeError::syntax()
+                                .with_message(format!(
+                                    "Redeclaration of formal parameter `{}`",
+                                    context.interner().resolve_expect(param_name)
+                                ))
+                                .into());
                         }
                     }
                 }
@@ -609,7 +693,7 @@ impl BuiltInFunctionObject {
                 .kind(FunctionKind::Expression)
                 .compile(&parameters, &body, context)?;
 
-            let environments = context.realm.environments.pop_to_global();
+            let environments = context.vm.environments.pop_to_global();
 
             let function_object = if generator {
                 crate::vm::create_generator_function_object(code, r#async, context)
@@ -617,7 +701,7 @@ impl BuiltInFunctionObject {
                 crate::vm::create_function_object(code, r#async, Some(prototype), context)
             };
 
-            context.realm.environments.extend(environments);
+            context.vm.environments.extend(environments);
 
             Ok(function_object)
         } else if generator {
@@ -631,10 +715,10 @@ impl BuiltInFunctionObject {
                     context,
                 )?;
 
-            let environments = context.realm.environments.pop_to_global();
+            let environments = context.vm.environments.pop_to_global();
             let function_object =
                 crate::vm::create_generator_function_object(code, r#async, context);
-            context.realm.environments.extend(environments);
+            context.vm.environments.extend(environments);
 
             Ok(function_object)
         } else {
@@ -647,10 +731,10 @@ impl BuiltInFunctionObject {
                     context,
                 )?;
 
-            let environments = context.realm.environments.pop_to_global();
+            let environments = context.vm.environments.pop_to_global();
             let function_object =
                 crate::vm::create_function_object(code, r#async, Some(prototype), context);
-            context.realm.environments.extend(environments);
+            context.vm.environments.extend(environments);
 
             Ok(function_object)
         }
@@ -671,7 +755,7 @@ impl BuiltInFunctionObject {
         // 1. Let func be the this value.
         // 2. If IsCallable(func) is false, throw a TypeError exception.
         let func = this.as_callable().ok_or_else(|| {
-            context.construct_type_error(format!("{} is not a function", this.display()))
+            JsNativeError::typ().with_message(format!("{} is not a function", this.display()))
         })?;
 
         let this_arg = args.get_or_undefined(0);
@@ -711,7 +795,8 @@ impl BuiltInFunctionObject {
         // 1. Let Target be the this value.
         // 2. If IsCallable(Target) is false, throw a TypeError exception.
         let target = this.as_callable().ok_or_else(|| {
-            context.construct_type_error("cannot bind `this` without a `[[Call]]` internal method")
+            JsNativeError::typ()
+                .with_message("cannot bind `this` without a `[[Call]]` internal method")
         })?;
 
         let this_arg = args.get_or_undefined(0).clone();
@@ -772,7 +857,7 @@ impl BuiltInFunctionObject {
             .map_or(JsString::new(""), Clone::clone);
 
         // 10. Perform SetFunctionName(F, targetName, "bound").
-        set_function_name(&f, &target_name.into(), Some("bound"), context);
+        set_function_name(&f, &target_name.into(), Some(utf16!("bound")), context);
 
         // 11. Return F.
         Ok(f.into())
@@ -792,7 +877,7 @@ impl BuiltInFunctionObject {
         // 1. Let func be the this value.
         // 2. If IsCallable(func) is false, throw a TypeError exception.
         let func = this.as_callable().ok_or_else(|| {
-            context.construct_type_error(format!("{} is not a function", this.display()))
+            JsNativeError::typ().with_message(format!("{} is not a function", this.display()))
         })?;
         let this_arg = args.get_or_undefined(0);
 
@@ -809,7 +894,7 @@ impl BuiltInFunctionObject {
         let function = object
             .as_deref()
             .and_then(Object::as_function)
-            .ok_or_else(|| context.construct_type_error("Not a function"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("Not a function"))?;
 
         let name = {
             // Is there a case here where if there is no name field on a value
@@ -826,13 +911,9 @@ impl BuiltInFunctionObject {
         };
 
         match (function, name) {
-            (
-                Function::Native {
-                    function: _,
-                    constructor: _,
-                },
-                Some(name),
-            ) => Ok(format!("function {name}() {{\n  [native Code]\n}}").into()),
+            (Function::Native { .. }, Some(name)) => {
+                Ok(format!("function {name}() {{\n  [native Code]\n}}").into())
+            }
             (Function::Ordinary { .. }, Some(name)) if name.is_empty() => {
                 Ok("[Function (anonymous)]".into())
             }
@@ -930,7 +1011,7 @@ impl BuiltIn for BuiltInFunctionObject {
 fn set_function_name(
     function: &JsObject,
     name: &PropertyKey,
-    prefix: Option<&str>,
+    prefix: Option<&[u16]>,
     context: &mut Context,
 ) {
     // 1. Assert: F is an extensible object that does not have a "name" own property.
@@ -940,7 +1021,7 @@ fn set_function_name(
             // a. Let description be name's [[Description]] value.
             if let Some(desc) = sym.description() {
                 // c. Else, set name to the string-concatenation of "[", description, and "]".
-                Cow::Owned(JsString::concat_array(&["[", &desc, "]"]))
+                Cow::Owned(js_string!(utf16!("["), &desc, utf16!("]")))
             } else {
                 // b. If description is undefined, set name to the empty String.
                 Cow::Owned(JsString::new(""))
@@ -960,7 +1041,7 @@ fn set_function_name(
 
     // 5. If prefix is present, then
     if let Some(prefix) = prefix {
-        name = Cow::Owned(JsString::concat_array(&[prefix, " ", &name]));
+        name = Cow::Owned(js_string!(prefix, utf16!(" "), &name));
         // b. If F has an [[InitialName]] internal slot, then
         // i. Optionally, set F.[[InitialName]] to name.
         // todo: implement [[InitialName]] for builtins
@@ -1,8 +1,10 @@
 use crate::{
+    error::JsNativeError,
     forward, forward_val,
     object::FunctionBuilder,
     property::{Attribute, PropertyDescriptor},
-    Context, JsString,
+    string::utf16,
+    Context, JsResult, JsString, JsValue,
 };
 
 #[allow(clippy::float_cmp)]
@@ -139,10 +141,12 @@ fn function_prototype_call_throw() {
         let call = Function.prototype.call;
         call(call)
         "#;
-    let value = forward_val(&mut context, throw).unwrap_err();
+    let value = forward_val(&mut context, throw)
+        .unwrap_err()
+        .to_opaque(&mut context);
     assert!(value.is_object());
     let string = value.to_string(&mut context).unwrap();
-    assert!(string.starts_with("TypeError"));
+    assert!(string.starts_with(utf16!("TypeError")));
 }
 
 #[test]
@@ -247,7 +251,8 @@ fn closure_capture_clone() {
                     .__get_own_property__(&"key".into(), context)?
                     .and_then(|prop| prop.value().cloned())
                     .and_then(|val| val.as_string().cloned())
-                    .ok_or_else(|| context.construct_type_error("invalid `key` property"))?,
+                    .ok_or_else(|| JsNativeError::typ().with_message("invalid `key` property"))?
+                    .as_slice(),
             );
             Ok(hw.into())
         },
@@ -260,3 +265,77 @@ fn closure_capture_clone() {
 
     assert_eq!(forward(&mut context, "closure()"), "\"Hello world!\"");
 }
+
+#[test]
+fn typed_function() {
+    let mut context = Context::default();
+
+    context.register_global_typed_function(
+        "scale",
+        |values: Vec<f64>, factor: Option<f64>, _: &mut Context| -> JsResult<Vec<f64>> {
+            let factor = factor.unwrap_or(2.0);
+            Ok(values.into_iter().map(|v| v * factor).collect())
+        },
+    );
+
+    assert_eq!(forward(&mut context, "scale.length"), "2");
+    assert_eq!(forward(&mut context, "scale([1, 2]).join()"), "\"2,4\"");
+    assert_eq!(
+        forward(&mut context, "scale([1, 2], 10).join()"),
+        "\"10,20\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { scale(1) } catch (e) { e.message }"),
+        "\"cannot convert value of type `number` to an array\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { scale([1], 'x') } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { scale({ length: 2 ** 53 - 1 }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { new scale([]) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn js_function_call_and_construct() {
+    use crate::object::JsFunction;
+
+    let mut context = Context::default();
+
+    let point = forward_val(
+        &mut context,
+        "(class Point { constructor(x, y) { this.x = x; this.y = y; } sum() { return this.x + this.y; } })",
+    )
+    .unwrap();
+    let point = JsFunction::from_object(point.as_object().unwrap().clone()).unwrap();
+    assert!(point.is_constructor());
+
+    let instance = point
+        .construct(&[1.into(), 2.into()], None, &mut context)
+        .unwrap();
+    let sum = instance.get("sum", &mut context).unwrap();
+    let sum = JsFunction::from_object(sum.as_object().unwrap().clone()).unwrap();
+
+    let result: i32 = sum
+        .call_with(&instance.clone().into(), (), &mut context)
+        .unwrap();
+    assert_eq!(result, 3);
+
+    let arrow = forward_val(&mut context, "() => {}").unwrap();
+    let arrow = JsFunction::from_object(arrow.as_object().unwrap().clone()).unwrap();
+    assert!(!arrow.is_constructor());
+    assert!(arrow.construct(&[], None, &mut context).is_err());
+
+    assert!(point
+        .call(&JsValue::undefined(), &[], &mut context)
+        .is_err());
+}
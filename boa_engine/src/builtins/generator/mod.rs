@@ -12,8 +12,10 @@
 use crate::{
     builtins::{iterable::create_iter_result_object, BuiltIn, JsArgs},
     environments::DeclarativeEnvironmentStack,
+    error::{JsError, JsNativeError},
     object::{ConstructorBuilder, JsObject, ObjectData},
     property::{Attribute, PropertyDescriptor},
+    realm::Realm,
     symbol::WellKnownSymbols,
     value::JsValue,
     vm::{CallFrame, GeneratorResumeKind, ReturnType},
@@ -39,6 +41,7 @@ pub(crate) enum GeneratorState {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub(crate) struct GeneratorContext {
     pub(crate) environments: DeclarativeEnvironmentStack,
+    pub(crate) realm: Realm,
     pub(crate) call_frame: CallFrame,
     pub(crate) stack: Vec<JsValue>,
 }
@@ -151,7 +154,9 @@ impl Generator {
             Some(obj) if obj.is_generator() => {
                 Self::generator_resume(obj, args.get_or_undefined(0), context)
             }
-            _ => context.throw_type_error("Generator.prototype.next called on non generator"),
+            _ => Err(JsNativeError::typ()
+                .with_message("Generator.prototype.next called on non generator")
+                .into()),
         }
     }
 
@@ -195,7 +200,11 @@ impl Generator {
         // 1. Let g be the this value.
         // 2. Let C be ThrowCompletion(exception).
         // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
-        Self::generator_resume_abrupt(this, Err(args.get_or_undefined(0).clone()), context)
+        Self::generator_resume_abrupt(
+            this,
+            Err(JsError::from_opaque(args.get_or_undefined(0).clone())),
+            context,
+        )
     }
 
     /// `27.5.3.3 GeneratorResume ( generator, value, generatorBrand )`
@@ -212,12 +221,14 @@ impl Generator {
         // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
         let mut generator_obj_mut = generator_obj.borrow_mut();
         let generator = generator_obj_mut.as_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let state = generator.state;
 
         if state == GeneratorState::Executing {
-            return Err(context.construct_type_error("Generator should not be executing"));
+            return Err(JsNativeError::typ()
+                .with_message("Generator should not be executing")
+                .into());
         }
 
         // 2. If state is completed, return CreateIterResultObject(undefined, true).
@@ -250,9 +261,10 @@ impl Generator {
         drop(generator_obj_mut);
 
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context.environments,
         );
+        std::mem::swap(&mut context.realm, &mut generator_context.realm);
         std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
         context.vm.push_frame(generator_context.call_frame.clone());
         if !first_execution {
@@ -268,9 +280,10 @@ impl Generator {
             .pop_frame()
             .expect("generator call frame must exist");
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context.environments,
         );
+        std::mem::swap(&mut context.realm, &mut generator_context.realm);
         std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
 
         let mut generator_obj_mut = generator_obj.borrow_mut();
@@ -314,16 +327,18 @@ impl Generator {
     ) -> JsResult<JsValue> {
         // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
         let generator_obj = this.as_object().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let mut generator_obj_mut = generator_obj.borrow_mut();
         let generator = generator_obj_mut.as_generator_mut().ok_or_else(|| {
-            context.construct_type_error("generator resumed on non generator object")
+            JsNativeError::typ().with_message("generator resumed on non generator object")
         })?;
         let mut state = generator.state;
 
         if state == GeneratorState::Executing {
-            return Err(context.construct_type_error("Generator should not be executing"));
+            return Err(JsNativeError::typ()
+                .with_message("Generator should not be executing")
+                .into());
         }
 
         // 2. If state is suspendedStart, then
@@ -366,9 +381,10 @@ impl Generator {
         drop(generator_obj_mut);
 
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context.environments,
         );
+        std::mem::swap(&mut context.realm, &mut generator_context.realm);
         std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
         context.vm.push_frame(generator_context.call_frame.clone());
 
@@ -379,6 +395,7 @@ impl Generator {
                 context.run()
             }
             Err(value) => {
+                let value = value.to_opaque(context);
                 context.vm.push(value);
                 context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Throw;
                 context.run()
@@ -389,9 +406,10 @@ impl Generator {
             .pop_frame()
             .expect("generator call frame must exist");
         std::mem::swap(
-            &mut context.realm.environments,
+            &mut context.vm.environments,
             &mut generator_context.environments,
         );
+        std::mem::swap(&mut context.realm, &mut generator_context.realm);
         std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
 
         let mut generator_obj_mut = generator_obj.borrow_mut();
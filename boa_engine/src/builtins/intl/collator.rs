@@ -0,0 +1,733 @@
+//! This module implements the global `Intl.Collator` object.
+//!
+//! `Intl.Collator` is a built-in object that enables language-sensitive string comparison.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#collator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator
+
+use super::{
+    canonicalize_locale_list, coerce_options_to_object, get_option, get_string_option,
+    resolve_locale, supported_locales, DateTimeFormatRecord, GetOptionType, LocaleDataRecord,
+    StringOption,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    js_string,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use rustc_hash::FxHashMap;
+use std::cmp::Ordering;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// The languages that have collation data.
+///
+/// Boa doesn't ship the ICU collation data, so strings are compared with a hand-written
+/// approximation of collation (see [`Collator::compare_strings`]), which is not the root order of
+/// the Unicode Collation Algorithm. It only orders the unaccented and accented Latin letters like
+/// the real collation of languages that don't tailor them, so languages like Spanish (`ñ`),
+/// Swedish (`å`, `ä`, `ö`) or the ones written in other scripts are not available.
+const AVAILABLE_LANGUAGES: [&str; 6] = ["de", "en", "fr", "it", "nl", "pt"];
+
+/// The `usage` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Usage {
+    Sort,
+    Search,
+}
+
+impl StringOption for Usage {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "sort" => Self::Sort,
+            "search" => Self::Search,
+            _ => return None,
+        })
+    }
+}
+
+impl Usage {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Sort => "sort",
+            Self::Search => "search",
+        }
+    }
+}
+
+/// The `sensitivity` option, which selects the differences between strings that compare as
+/// unequal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sensitivity {
+    /// Only strings that differ in base letters compare as unequal.
+    Base,
+    /// Strings that differ in base letters or accents compare as unequal.
+    Accent,
+    /// Strings that differ in base letters or case compare as unequal.
+    Case,
+    /// Strings that differ in base letters, accents or case compare as unequal.
+    Variant,
+}
+
+impl StringOption for Sensitivity {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "base" => Self::Base,
+            "accent" => Self::Accent,
+            "case" => Self::Case,
+            "variant" => Self::Variant,
+            _ => return None,
+        })
+    }
+}
+
+impl Sensitivity {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Base => "base",
+            Self::Accent => "accent",
+            Self::Case => "case",
+            Self::Variant => "variant",
+        }
+    }
+
+    fn compares_accents(self) -> bool {
+        matches!(self, Self::Accent | Self::Variant)
+    }
+
+    fn compares_case(self) -> bool {
+        matches!(self, Self::Case | Self::Variant)
+    }
+}
+
+/// The `caseFirst` option, which selects whether upper case or lower case sorts first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaseFirst {
+    Upper,
+    Lower,
+    False,
+}
+
+impl CaseFirst {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "upper" => Self::Upper,
+            "lower" => Self::Lower,
+            "false" => Self::False,
+            _ => return None,
+        })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Upper => "upper",
+            Self::Lower => "lower",
+            Self::False => "false",
+        }
+    }
+}
+
+/// The primary weight of a collation element.
+///
+/// Symbols sort before digits, and digits sort before letters.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Primary {
+    Symbol(char),
+    /// A run of digits, ordered by its length first so numeric collation compares the values.
+    Digits(usize, String),
+    Letter(char),
+}
+
+/// A collation element, with the weights of its three levels.
+#[derive(Debug)]
+struct CollationElement {
+    primary: Primary,
+    /// The accents of the element.
+    secondary: Vec<char>,
+    /// Whether the element is upper case.
+    tertiary: bool,
+}
+
+/// JavaScript `Intl.Collator` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Collator {
+    locale: JsString,
+    #[unsafe_ignore_trace]
+    usage: Usage,
+    #[unsafe_ignore_trace]
+    sensitivity: Sensitivity,
+    ignore_punctuation: bool,
+    collation: JsString,
+    numeric: bool,
+    #[unsafe_ignore_trace]
+    case_first: CaseFirst,
+    bound_compare: Option<JsObject>,
+}
+
+impl Collator {
+    const NAME: &'static str = "Collator";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_compare = FunctionBuilder::native(context, Self::get_compare)
+            .name("get compare")
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().collator().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.Collator",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "compare",
+            Some(get_compare),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .build()
+    }
+
+    /// Gets the available locales of `Intl.Collator`.
+    fn available_locales() -> Vec<JsString> {
+        AVAILABLE_LANGUAGES
+            .iter()
+            .map(|&language| JsString::from(language))
+            .collect()
+    }
+
+    /// The `Intl.Collator` constructor is the `%Collator%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.collator
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/Collator
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = if new_target.is_undefined() {
+            context
+                .intrinsics()
+                .constructors()
+                .collator()
+                .constructor()
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let internalSlotsList be « [[InitializedCollator]], [[Locale]], ... ».
+        // 3. Let collator be ? OrdinaryCreateFromConstructor(newTarget, "%Collator.prototype%", internalSlotsList).
+        let prototype =
+            get_prototype_from_constructor(&new_target, StandardConstructors::collator, context)?;
+
+        // 4. Return ? InitializeCollator(collator, locales, options).
+        let collator =
+            Self::initialize(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::collator(collator)).into())
+    }
+
+    /// Abstract operation `InitializeCollator ( collator, locales, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializecollator
+    pub(crate) fn initialize(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(std::slice::from_ref(locales), context)?
+            .into_iter()
+            .map(|locale| JsString::from(locale.to_string()))
+            .collect::<Vec<_>>();
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let usage be ? GetOption(options, "usage", string, « "sort", "search" », "sort").
+        // 4. Set collator.[[Usage]] to usage.
+        let usage = get_string_option(&options, "usage", context)?.unwrap_or(Usage::Sort);
+
+        // 5. If usage is "sort", then
+        //     a. Let localeData be %Collator%.[[SortLocaleData]].
+        // 6. Else,
+        //     a. Let localeData be %Collator%.[[SearchLocaleData]].
+        // Both usages share the same locale data.
+        let available_locales = Self::available_locales();
+        let locale_data: LocaleDataRecord = available_locales
+            .iter()
+            .map(|locale| {
+                let mut data = FxHashMap::default();
+                data.insert(js_string!("co"), Vec::new());
+                data.insert(
+                    js_string!("kf"),
+                    vec![
+                        js_string!("false"),
+                        js_string!("lower"),
+                        js_string!("upper"),
+                    ],
+                );
+                data.insert(
+                    js_string!("kn"),
+                    vec![js_string!("false"), js_string!("true")],
+                );
+                (locale.clone(), data)
+            })
+            .collect();
+
+        // 7. Let opt be a new Record.
+        // 8. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 9. Set opt.[[localeMatcher]] to matcher.
+        let matcher = get_option(
+            &options,
+            "localeMatcher",
+            &GetOptionType::String,
+            &[js_string!("lookup"), js_string!("best fit")],
+            &js_string!("best fit").into(),
+            context,
+        )?;
+
+        // 10. Let collation be ? GetOption(options, "collation", string, empty, undefined).
+        let collation = get_option(
+            &options,
+            "collation",
+            &GetOptionType::String,
+            &[],
+            &JsValue::undefined(),
+            context,
+        )?;
+
+        // 11. If collation is not undefined, then
+        //     a. If collation does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        if let Some(collation) = collation.as_string() {
+            let collation = collation.to_std_string_escaped();
+            let is_type = collation.split('-').all(|subtag| {
+                (3..=8).contains(&subtag.len())
+                    && subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+            });
+            if !is_type {
+                return Err(JsNativeError::range()
+                    .with_message(format!("'{collation}' is not a valid collation"))
+                    .into());
+            }
+        }
+
+        // 12. Set opt.[[co]] to collation.
+        let mut properties = FxHashMap::default();
+        properties.insert(js_string!("co"), collation);
+
+        // 13. Let numeric be ? GetOption(options, "numeric", boolean, empty, undefined).
+        let numeric = get_option(
+            &options,
+            "numeric",
+            &GetOptionType::Boolean,
+            &[],
+            &JsValue::undefined(),
+            context,
+        )?;
+
+        // 14. If numeric is not undefined, then
+        //     a. Let numeric be ! ToString(numeric).
+        let numeric = if numeric.is_undefined() {
+            numeric
+        } else {
+            numeric
+                .to_string(context)
+                .expect("converting a boolean to a string cannot fail")
+                .into()
+        };
+
+        // 15. Set opt.[[kn]] to numeric.
+        properties.insert(js_string!("kn"), numeric);
+
+        // 16. Let caseFirst be ? GetOption(options, "caseFirst", string, « "upper", "lower", "false" », undefined).
+        let case_first = get_option(
+            &options,
+            "caseFirst",
+            &GetOptionType::String,
+            &[
+                js_string!("upper"),
+                js_string!("lower"),
+                js_string!("false"),
+            ],
+            &JsValue::undefined(),
+            context,
+        )?;
+
+        // 17. Set opt.[[kf]] to caseFirst.
+        properties.insert(js_string!("kf"), case_first);
+
+        let opt = DateTimeFormatRecord {
+            locale_matcher: matcher
+                .as_string()
+                .cloned()
+                .unwrap_or_else(|| js_string!("best fit")),
+            properties,
+        };
+
+        // 18. Let relevantExtensionKeys be %Collator%.[[RelevantExtensionKeys]].
+        let relevant_extension_keys = [js_string!("co"), js_string!("kf"), js_string!("kn")];
+
+        // 19. Let r be ResolveLocale(%Collator%.[[AvailableLocales]], requestedLocales, opt, relevantExtensionKeys, localeData).
+        let r = resolve_locale(
+            &available_locales,
+            &requested_locales,
+            &opt,
+            &relevant_extension_keys,
+            &locale_data,
+            context,
+        );
+
+        // 20. Set collator.[[Locale]] to r.[[locale]].
+        let locale = r.locale;
+
+        // 21. Let collation be r.[[co]].
+        // 22. If collation is null, let collation be "default".
+        // 23. Set collator.[[Collation]] to collation.
+        let collation = r
+            .properties
+            .get(&js_string!("co"))
+            .and_then(JsValue::as_string)
+            .cloned()
+            .unwrap_or_else(|| js_string!("default"));
+
+        // 24. If relevantExtensionKeys contains "kn", then
+        //     a. Set collator.[[Numeric]] to SameValue(r.[[kn]], "true").
+        let numeric = r
+            .properties
+            .get(&js_string!("kn"))
+            .and_then(JsValue::as_string)
+            .map_or(false, |kn| kn == &js_string!("true"));
+
+        // 25. If relevantExtensionKeys contains "kf", then
+        //     a. Set collator.[[CaseFirst]] to r.[[kf]].
+        let case_first = r
+            .properties
+            .get(&js_string!("kf"))
+            .and_then(JsValue::as_string)
+            .and_then(|kf| CaseFirst::from_name(&kf.to_std_string_escaped()))
+            .unwrap_or(CaseFirst::False);
+
+        // 26. Let sensitivity be ? GetOption(options, "sensitivity", string, « "base", "accent", "case", "variant" », undefined).
+        // 27. If sensitivity is undefined, then
+        //     a. If usage is "sort", then
+        //         i. Let sensitivity be "variant".
+        //     b. Else,
+        //         i. Let dataLocale be r.[[dataLocale]].
+        //         ii. Let dataLocaleData be localeData.[[<dataLocale>]].
+        //         iii. Let sensitivity be dataLocaleData.[[sensitivity]].
+        // 28. Set collator.[[Sensitivity]] to sensitivity.
+        // Every locale uses the "variant" sensitivity for searches.
+        let sensitivity =
+            get_string_option(&options, "sensitivity", context)?.unwrap_or(Sensitivity::Variant);
+
+        // 29. Let ignorePunctuation be ? GetOption(options, "ignorePunctuation", boolean, empty, false).
+        // 30. Set collator.[[IgnorePunctuation]] to ignorePunctuation.
+        let ignore_punctuation = get_option(
+            &options,
+            "ignorePunctuation",
+            &GetOptionType::Boolean,
+            &[],
+            &false.into(),
+            context,
+        )?
+        .to_boolean();
+
+        // 31. Return collator.
+        Ok(Self {
+            locale,
+            usage,
+            sensitivity,
+            ignore_punctuation,
+            collation,
+            numeric,
+            case_first,
+            bound_compare: None,
+        })
+    }
+
+    /// Splits a string into its collation elements.
+    ///
+    /// The string is decomposed to the NFD form, so the accents of a letter become combining
+    /// marks that are collected in the secondary weight of the letter.
+    fn collation_elements(&self, string: &JsString) -> Vec<CollationElement> {
+        let string = string.to_std_string_escaped();
+        let mut chars = string.nfd().peekable();
+        let mut elements: Vec<CollationElement> = Vec::new();
+
+        while let Some(c) = chars.next() {
+            if is_combining_mark(c) {
+                if let Some(last) = elements.last_mut() {
+                    last.secondary.push(c);
+                }
+                continue;
+            }
+
+            if c.is_ascii_digit() {
+                let mut digits = String::from(c);
+                if self.numeric {
+                    while let Some(&next) = chars.peek() {
+                        if !next.is_ascii_digit() {
+                            break;
+                        }
+                        digits.push(next);
+                        chars.next();
+                    }
+                    let trimmed = digits.trim_start_matches('0');
+                    digits = if trimmed.is_empty() { "0" } else { trimmed }.to_owned();
+                }
+                elements.push(CollationElement {
+                    primary: Primary::Digits(digits.len(), digits),
+                    secondary: Vec::new(),
+                    tertiary: false,
+                });
+            } else if c.is_alphanumeric() {
+                for (i, lower) in c.to_lowercase().enumerate() {
+                    elements.push(CollationElement {
+                        primary: Primary::Letter(lower),
+                        secondary: Vec::new(),
+                        tertiary: i == 0 && c.is_uppercase(),
+                    });
+                }
+            } else if !(self.ignore_punctuation
+                && (c.is_whitespace() || c.is_ascii_punctuation() || is_general_punctuation(c)))
+            {
+                elements.push(CollationElement {
+                    primary: Primary::Symbol(c),
+                    secondary: Vec::new(),
+                    tertiary: false,
+                });
+            }
+        }
+
+        elements
+    }
+
+    /// Compares two strings with the options of the collator.
+    ///
+    /// The strings are compared by their base letters first, then by their accents and then by
+    /// their case, skipping the levels that the sensitivity of the collator ignores.
+    ///
+    /// This is an approximation, not the Unicode Collation Algorithm: symbols sort before digits
+    /// and digits before letters, but each group is ordered by code point, and there are no
+    /// contractions, expansions or variable weighting. Only the relative order of the Latin
+    /// letters and their accents matches the collation of the available languages.
+    pub(crate) fn compare_strings(&self, x: &JsString, y: &JsString) -> Ordering {
+        let x = self.collation_elements(x);
+        let y = self.collation_elements(y);
+
+        let primary = x
+            .iter()
+            .map(|element| &element.primary)
+            .cmp(y.iter().map(|element| &element.primary));
+        if primary != Ordering::Equal {
+            return primary;
+        }
+
+        if self.sensitivity.compares_accents() {
+            let secondary = x
+                .iter()
+                .map(|element| &element.secondary)
+                .cmp(y.iter().map(|element| &element.secondary));
+            if secondary != Ordering::Equal {
+                return secondary;
+            }
+        }
+
+        if self.sensitivity.compares_case() {
+            let upper_first = self.case_first == CaseFirst::Upper;
+            return x
+                .iter()
+                .map(|element| element.tertiary != upper_first)
+                .cmp(y.iter().map(|element| element.tertiary != upper_first));
+        }
+
+        Ordering::Equal
+    }
+
+    /// Gets the `Intl.Collator` object of the `this` value.
+    fn this_collator(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.borrow().as_collator().is_some())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("this value must be an Intl.Collator object")
+                    .into()
+            })
+    }
+
+    /// `Intl.Collator.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// The supported locales are the few languages written in the Latin alphabet whose ordering
+    /// of letters is matched by the approximate collation of Boa; strings that mix in symbols,
+    /// digits or other scripts may not be ordered as with the real collation of the locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.collator.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %Collator%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            &Self::available_locales(),
+            requested_locales,
+            args.get_or_undefined(1),
+            context,
+        )
+    }
+
+    /// `get Intl.Collator.prototype.compare`
+    ///
+    /// Returns a function bound to the collator, that compares two strings.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.collator.prototype.compare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/compare
+    fn get_compare(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let collator be the this value.
+        // 2. Perform ? RequireInternalSlot(collator, [[InitializedCollator]]).
+        let collator = Self::this_collator(this)?;
+
+        // 3. If collator.[[BoundCompare]] is undefined, then
+        let bound_compare = collator
+            .borrow()
+            .as_collator()
+            .and_then(|collator| collator.bound_compare.clone());
+        if let Some(bound_compare) = bound_compare {
+            // 4. Return collator.[[BoundCompare]].
+            return Ok(bound_compare.into());
+        }
+
+        // a. Let F be a new built-in function object as defined in 10.3.3.1.
+        // b. Set F.[[Collator]] to collator.
+        let bound_compare = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, collator, context| {
+                // 1. Let collator be F.[[Collator]].
+                // 2. Assert: Type(collator) is Object and collator has an [[InitializedCollator]] internal slot.
+                // 3. If x is not provided, let x be undefined.
+                // 4. If y is not provided, let y be undefined.
+                // 5. Let X be ? ToString(x).
+                let x = args.get_or_undefined(0).to_string(context)?;
+                // 6. Let Y be ? ToString(y).
+                let y = args.get_or_undefined(1).to_string(context)?;
+
+                // 7. Return CompareStrings(collator, X, Y).
+                let collator = collator.borrow();
+                let collator = collator
+                    .as_collator()
+                    .expect("the captured object must be an Intl.Collator object");
+                Ok((collator.compare_strings(&x, &y) as i32).into())
+            },
+            collator.clone(),
+        )
+        .length(2)
+        .build();
+
+        // c. Set collator.[[BoundCompare]] to F.
+        if let Some(collator) = collator.borrow_mut().as_collator_mut() {
+            collator.bound_compare = Some(bound_compare.clone().into());
+        }
+
+        // 4. Return collator.[[BoundCompare]].
+        Ok(bound_compare.into())
+    }
+
+    /// `Intl.Collator.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.collator.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let collator be the this value.
+        // 2. Perform ? RequireInternalSlot(collator, [[InitializedCollator]]).
+        let collator = Self::this_collator(this)?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 4, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of collator's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If the current row has an Extension Key value, then
+        //         i. Let extensionKey be the Extension Key value of the current row.
+        //         ii. If %Collator%.[[RelevantExtensionKeys]] does not contain extensionKey, then
+        //             1. Let v be undefined.
+        //     d. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let properties: [(&str, JsValue); 7] = {
+            let collator = collator.borrow();
+            let collator = collator.as_collator().expect("checked above");
+            [
+                ("locale", collator.locale.clone().into()),
+                ("usage", collator.usage.as_str().into()),
+                ("sensitivity", collator.sensitivity.as_str().into()),
+                ("ignorePunctuation", collator.ignore_punctuation.into()),
+                ("collation", collator.collation.clone().into()),
+                ("numeric", collator.numeric.into()),
+                ("caseFirst", collator.case_first.as_str().into()),
+            ]
+        };
+
+        let options = context.construct_object();
+        for (key, value) in properties {
+            options
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining a property of a new object cannot fail");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Checks if `c` is in the General Punctuation block, which holds the dashes, quotation marks
+/// and other punctuation that is ignored with the `ignorePunctuation` option.
+fn is_general_punctuation(c: char) -> bool {
+    ('\u{2010}'..='\u{205E}').contains(&c)
+}
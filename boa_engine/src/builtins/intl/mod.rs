@@ -8,15 +8,24 @@
 //! [spec]: https://tc39.es/ecma402/#intl-object
 
 use crate::{
-    builtins::intl::date_time_format::DateTimeFormat,
+    builtins::intl::{
+        collator::Collator, date_time_format::DateTimeFormat, number_format::NumberFormat,
+        plural_rules::PluralRules,
+    },
     builtins::{Array, BuiltIn, JsArgs},
-    object::{JsObject, ObjectInitializer},
+    error::JsNativeError,
+    js_string,
+    object::{JsObject, ObjectData, ObjectInitializer},
     property::Attribute,
+    string::utf16,
     symbol::WellKnownSymbols,
     Context, JsResult, JsString, JsValue,
 };
 
+pub mod collator;
 pub mod date_time_format;
+pub mod number_format;
+pub mod plural_rules;
 #[cfg(test)]
 mod tests;
 
@@ -38,7 +47,10 @@ impl BuiltIn for Intl {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
         let string_tag = WellKnownSymbols::to_string_tag();
+        let collator = Collator::init(context);
         let date_time_format = DateTimeFormat::init(context);
+        let number_format = NumberFormat::init(context);
+        let plural_rules = PluralRules::init(context);
         ObjectInitializer::new(context)
             .function(Self::get_canonical_locales, "getCanonicalLocales", 1)
             .property(
@@ -46,11 +58,26 @@ impl BuiltIn for Intl {
                 Self::NAME,
                 Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
             )
+            .property(
+                "Collator",
+                collator,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .property(
                 "DateTimeFormat",
                 date_time_format,
                 Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
             )
+            .property(
+                "NumberFormat",
+                number_format,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                "PluralRules",
+                plural_rules,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build()
             .conv::<JsValue>()
             .pipe(Some)
@@ -132,19 +159,21 @@ fn best_available_locale(available_locales: &[JsString], locale: &JsString) -> O
         }
 
         // b. Let pos be the character index of the last occurrence of "-" (U+002D) within candidate. If that character does not occur, return undefined.
-        let pos = candidate.rfind('-');
+        let pos = candidate.iter().rposition(|&c| c == u16::from(b'-'));
         match pos {
             Some(ind) => {
                 // c. If pos ≥ 2 and the character "-" occurs at index pos-2 of candidate, decrease pos by 2.
-                let tmp_candidate = candidate[..ind].to_string();
-                let prev_dash = tmp_candidate.rfind('-').unwrap_or(ind);
+                let prev_dash = candidate[..ind]
+                    .iter()
+                    .rposition(|&c| c == u16::from(b'-'))
+                    .unwrap_or(ind);
                 let trim_ind = if ind >= 2 && prev_dash == ind - 2 {
                     ind - 2
                 } else {
                     ind
                 };
                 // d. Let candidate be the substring of candidate from position 0, inclusive, to position pos, exclusive.
-                candidate = JsString::new(&candidate[..trim_ind]);
+                candidate = JsString::from(&candidate[..trim_ind]);
             }
             None => return None,
         }
@@ -171,8 +200,8 @@ fn lookup_matcher(
     for locale_str in requested_locales {
         // a. Let noExtensionsLocale be the String value that is locale with any Unicode locale
         //    extension sequences removed.
-        let parsed_locale =
-            Locale::from_bytes(locale_str.as_bytes()).expect("Locale parsing failed");
+        let parsed_locale = Locale::from_bytes(locale_str.to_std_string_escaped().as_bytes())
+            .expect("Locale parsing failed");
         let no_extensions_locale = JsString::new(parsed_locale.id.to_string());
 
         // b. Let availableLocale be ! BestAvailableLocale(availableLocales, noExtensionsLocale).
@@ -189,7 +218,7 @@ fn lookup_matcher(
                 // 1. Let extension be the String value consisting of the substring of the Unicode
                 //    locale extension sequence within locale.
                 // 2. Set result.[[extension]] to extension.
-                JsString::new(parsed_locale.extensions.to_string())
+                JsString::new(format!("-{}", parsed_locale.extensions))
             };
 
             // iii. Return result.
@@ -288,7 +317,7 @@ fn unicode_extension_components(extension: &JsString) -> UniExtRecord {
 
         // c. Let subtag be the String value equal to the substring of extension consisting of the
         // code units at indices k (inclusive) through k + len (exclusive).
-        let subtag = JsString::new(&extension[k..k + len]);
+        let subtag = JsString::from(&extension[k..k + len]);
 
         // d. If keyword is undefined and len ≠ 2, then
         if keyword.is_none() && len != 2 {
@@ -459,7 +488,9 @@ fn canonicalize_locale_list(args: &[JsValue], context: &mut Context) -> JsResult
             let k_value = o.get(k, context)?;
             // ii. If Type(kValue) is not String or Object, throw a TypeError exception.
             if !(k_value.is_object() || k_value.is_string()) {
-                return context.throw_type_error("locale should be a String or Object");
+                return Err(JsNativeError::typ()
+                    .with_message("locale should be a String or Object")
+                    .into());
             }
             // iii. If Type(kValue) is Object and kValue has an [[InitializedLocale]] internal slot, then
             // TODO: handle checks for InitializedLocale internal slot (there should be an if statement here)
@@ -468,8 +499,9 @@ fn canonicalize_locale_list(args: &[JsValue], context: &mut Context) -> JsResult
             // 1. Let tag be ? ToString(kValue).
             let tag = k_value.to_string(context)?;
             // v. If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
-            let mut tag = tag.parse().map_err(|_| {
-                context.construct_range_error("locale is not a structurally valid language tag")
+            let mut tag = tag.to_std_string_escaped().parse().map_err(|_| {
+                JsNativeError::range()
+                    .with_message("locale is not a structurally valid language tag")
             })?;
 
             // vi. Let canonicalizedTag be CanonicalizeUnicodeLocaleId(tag).
@@ -521,7 +553,6 @@ struct ResolveLocaleRecord {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-resolvelocale
-#[allow(dead_code)]
 fn resolve_locale(
     available_locales: &[JsString],
     requested_locales: &[JsString],
@@ -616,18 +647,18 @@ fn resolve_locale(
                     // a. If keyLocaleData contains requestedValue, then
                     if key_locale_data.contains(requested_value) {
                         // i. Let value be requestedValue.
-                        value = JsValue::String(JsString::new(requested_value));
+                        value = JsValue::String(requested_value.clone());
                         // ii. Let supportedExtensionAddition be the string-concatenation
                         // of "-", key, "-", and value.
                         supported_extension_addition =
-                            JsString::concat_array(&["-", key, "-", requested_value]);
+                            js_string!(utf16!("-"), key, utf16!("-"), requested_value);
                     }
                 // 4. Else if keyLocaleData contains "true", then
                 } else if key_locale_data.contains(&JsString::new("true")) {
                     // a. Let value be "true".
                     value = JsValue::String(JsString::new("true"));
                     // b. Let supportedExtensionAddition be the string-concatenation of "-" and key.
-                    supported_extension_addition = JsString::concat_array(&["-", key]);
+                    supported_extension_addition = js_string!(utf16!("-"), key);
                 }
             }
         }
@@ -684,15 +715,15 @@ fn resolve_locale(
         result.properties.insert(key.clone(), value);
 
         // k. Append supportedExtensionAddition to supportedExtension.
-        supported_extension = JsString::concat(supported_extension, &supported_extension_addition);
+        supported_extension = js_string!(&supported_extension, &supported_extension_addition);
     }
 
     // 10. If the number of elements in supportedExtension is greater than 2, then
     if supported_extension.len() > 2 {
         // a. Let foundLocale be InsertUnicodeExtensionAndCanonicalize(foundLocale, supportedExtension).
         found_locale = insert_unicode_extension_and_canonicalize(
-            &found_locale,
-            &supported_extension,
+            &found_locale.to_std_string_escaped(),
+            &supported_extension.to_std_string_escaped(),
             context.icu().locale_canonicalizer(),
         );
     }
@@ -704,7 +735,6 @@ fn resolve_locale(
     result
 }
 
-#[allow(unused)]
 pub(crate) enum GetOptionType {
     String,
     Boolean,
@@ -721,7 +751,6 @@ pub(crate) enum GetOptionType {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-getoption
-#[allow(unused)]
 pub(crate) fn get_option(
     options: &JsObject,
     property: &str,
@@ -751,7 +780,9 @@ pub(crate) fn get_option(
         GetOptionType::String => {
             let string_value = value.to_string(context)?;
             if !values.is_empty() && !values.contains(&string_value) {
-                return context.throw_range_error("GetOption: values array does not contain value");
+                return Err(JsNativeError::range()
+                    .with_message("GetOption: values array does not contain value")
+                    .into());
             }
             JsValue::String(string_value)
         }
@@ -761,6 +792,119 @@ pub(crate) fn get_option(
     Ok(value)
 }
 
+/// A string option whose allowed values are the variants of an enumeration.
+pub(crate) trait StringOption: Sized {
+    /// Parses the value of the option.
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+/// Abstract operation `GetOption ( options, property, "string", values, fallback )` for the
+/// options whose `values` are the variants of `T`.
+///
+/// Returns `None` if the option is undefined, so the caller can pick the `fallback`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_string_option<T: StringOption>(
+    options: &JsObject,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<T>> {
+    // 1. Let value be ? Get(options, property).
+    let value = options.get(property, context)?;
+
+    // 2. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    // 3. Set value to ? ToString(value).
+    // 4. If values is not undefined and values does not contain an element equal to value,
+    // throw a RangeError exception.
+    let value = value.to_string(context)?.to_std_string_escaped();
+    T::from_name(&value).map(Some).ok_or_else(|| {
+        JsNativeError::range()
+            .with_message(format!(
+                "'{value}' is not a valid value for option {property}"
+            ))
+            .into()
+    })
+}
+
+/// Abstract operation `CoerceOptionsToObject ( options )`
+///
+/// Converts `options` to an object, or creates an empty object with a `null` prototype if it
+/// is undefined.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-coerceoptionstoobject
+pub(crate) fn coerce_options_to_object(
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(JsObject::from_proto_and_data(None, ObjectData::ordinary()));
+    }
+
+    // 2. Return ? ToObject(options).
+    options.to_object(context)
+}
+
+/// Abstract operation `SupportedLocales ( availableLocales, requestedLocales, options )`
+///
+/// Returns the subset of `requested_locales` for which `available_locales` has a match, as an
+/// array.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-supportedlocales
+fn supported_locales(
+    available_locales: &[JsString],
+    requested_locales: Vec<Locale>,
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(options, context)?;
+
+    // 2. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+    get_option(
+        &options,
+        "localeMatcher",
+        &GetOptionType::String,
+        &[js_string!("lookup"), js_string!("best fit")],
+        &js_string!("best fit").into(),
+        context,
+    )?;
+
+    // 3. If matcher is "best fit", then
+    //     a. Let supportedLocales be BestFitSupportedLocales(availableLocales, requestedLocales).
+    // 4. Else,
+    //     a. Let supportedLocales be LookupSupportedLocales(availableLocales, requestedLocales).
+    // Both matchers are the lookup matcher in this implementation.
+    let supported_locales = requested_locales
+        .into_iter()
+        .filter(|locale| {
+            // Let noExtensionsLocale be the String value that is locale with any Unicode locale
+            // extension sequences removed.
+            let no_extensions_locale = JsString::from(locale.id.to_string());
+
+            // Let availableLocale be ! BestAvailableLocale(availableLocales, noExtensionsLocale).
+            best_available_locale(available_locales, &no_extensions_locale).is_some()
+        })
+        .map(|locale| JsValue::from(locale.to_string()));
+
+    // 5. Return CreateArrayFromList(supportedLocales).
+    Ok(Array::create_array_from_list(supported_locales, context).into())
+}
+
 /// Abstract operation `GetNumberOption ( options, property, minimum, maximum, fallback )`
 ///
 /// Extracts the value of the property named `property` from the provided `options`
@@ -771,7 +915,6 @@ pub(crate) fn get_option(
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-getnumberoption
-#[allow(unused)]
 pub(crate) fn get_number_option(
     options: &JsObject,
     property: &str,
@@ -797,7 +940,6 @@ pub(crate) fn get_number_option(
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-defaultnumberoption
-#[allow(unused)]
 pub(crate) fn default_number_option(
     value: &JsValue,
     minimum: f64,
@@ -815,7 +957,9 @@ pub(crate) fn default_number_option(
 
     // 3. If value is NaN or less than minimum or greater than maximum, throw a RangeError exception.
     if value.is_nan() || value < minimum || value > maximum {
-        return context.throw_range_error("DefaultNumberOption: value is out of range.");
+        return Err(JsNativeError::range()
+            .with_message("DefaultNumberOption: value is out of range.")
+            .into());
     }
 
     // 4. Return floor(value).
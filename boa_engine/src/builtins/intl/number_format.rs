@@ -0,0 +1,1978 @@
+//! This module implements the global `Intl.NumberFormat` object.
+//!
+//! `Intl.NumberFormat` is a built-in object that enables language-sensitive number formatting.
+//!
+//! The digits and the rounding of the numbers are computed by the engine. The icu data
+//! provider of Boa doesn't have the number formatting data of CLDR yet, so the symbols and
+//! patterns are taken from a small table that only covers a few languages, which are the
+//! available locales of `Intl.NumberFormat`. The symbols and names of the currencies and the long
+//! names of the units are only known in English, so the other locales fall back to the ISO codes
+//! of the currencies and to the short abbreviations of the units, instead of mixing languages.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#numberformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+
+use super::{
+    canonicalize_locale_list, coerce_options_to_object, default_number_option, get_number_option,
+    get_option, get_string_option, resolve_locale, supported_locales, DateTimeFormatRecord,
+    GetOptionType, LocaleDataRecord, StringOption,
+};
+use crate::{
+    builtins::{Array, JsArgs},
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    js_string,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::Numeric,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use rustc_hash::FxHashMap;
+use tap::{Conv, Pipe};
+
+/// The symbols and patterns used to format numbers in a language.
+#[derive(Debug, Clone, Copy)]
+struct LocaleSymbols {
+    language: &'static str,
+    decimal: &'static str,
+    group: &'static str,
+    /// The minimum number of integer digits of a number to separate its groups of digits.
+    minimum_grouping_digits: usize,
+    /// The separator between a number and the percent sign.
+    percent_separator: &'static str,
+    /// Whether the currency goes after the number instead of before it.
+    currency_after: bool,
+    /// The short suffixes of the thousands, millions, billions and trillions in compact
+    /// notation, or an empty string if the magnitude is not compacted.
+    compact_short: [&'static str; 4],
+    /// The long suffixes of the thousands, millions, billions and trillions in compact notation.
+    compact_long: [&'static str; 4],
+    /// The separator between a number and its short compact suffix.
+    compact_short_separator: &'static str,
+}
+
+/// The symbols of the languages supported by `Intl.NumberFormat`, which are its available
+/// locales.
+///
+/// The values are taken from the CLDR data of each language.
+const LOCALE_SYMBOLS: [LocaleSymbols; 5] = [
+    LocaleSymbols {
+        language: "de",
+        decimal: ",",
+        group: ".",
+        minimum_grouping_digits: 4,
+        percent_separator: "\u{a0}",
+        currency_after: true,
+        compact_short: ["", "Mio.", "Mrd.", "Bio."],
+        compact_long: ["Tausend", "Millionen", "Milliarden", "Billionen"],
+        compact_short_separator: "\u{a0}",
+    },
+    LocaleSymbols {
+        language: "en",
+        decimal: ".",
+        group: ",",
+        minimum_grouping_digits: 4,
+        percent_separator: "",
+        currency_after: false,
+        compact_short: ["K", "M", "B", "T"],
+        compact_long: ["thousand", "million", "billion", "trillion"],
+        compact_short_separator: "",
+    },
+    LocaleSymbols {
+        language: "es",
+        decimal: ",",
+        group: ".",
+        minimum_grouping_digits: 5,
+        percent_separator: "\u{a0}",
+        currency_after: true,
+        compact_short: ["mil", "M", "mil\u{a0}M", "B"],
+        compact_long: ["mil", "millones", "mil millones", "billones"],
+        compact_short_separator: "\u{a0}",
+    },
+    LocaleSymbols {
+        language: "fr",
+        decimal: ",",
+        group: "\u{202f}",
+        minimum_grouping_digits: 4,
+        percent_separator: "\u{202f}",
+        currency_after: true,
+        compact_short: ["k", "M", "Md", "Bn"],
+        compact_long: ["mille", "millions", "milliards", "billions"],
+        compact_short_separator: "\u{a0}",
+    },
+    LocaleSymbols {
+        language: "it",
+        decimal: ",",
+        group: ".",
+        minimum_grouping_digits: 4,
+        percent_separator: "",
+        currency_after: true,
+        compact_short: ["", "Mln", "Mrd", "Bln"],
+        compact_long: ["mila", "milioni", "miliardi", "mila miliardi"],
+        compact_short_separator: "\u{a0}",
+    },
+];
+
+impl LocaleSymbols {
+    /// Gets the symbols of the language of `locale`, or the English symbols if the language is
+    /// not supported.
+    fn of(locale: &JsString) -> &'static Self {
+        let locale = locale.to_std_string_escaped();
+        let language = locale.split('-').next().unwrap_or_default();
+        LOCALE_SYMBOLS
+            .iter()
+            .find(|symbols| symbols.language == language)
+            .unwrap_or(&LOCALE_SYMBOLS[1])
+    }
+}
+
+/// The symbols and English names of the currencies known by `Intl.NumberFormat`, as
+/// `(code, symbol, narrow symbol, singular name, plural name)`.
+///
+/// The narrow symbols are the same in every language, but the symbols and the names are only
+/// valid in English, so the other languages display the code instead, as the root locale of CLDR
+/// does. The other currencies are always displayed with their code.
+const CURRENCIES: [(&str, &str, &str, &str, &str); 13] = [
+    ("AUD", "A$", "$", "Australian dollar", "Australian dollars"),
+    ("BRL", "R$", "R$", "Brazilian real", "Brazilian reals"),
+    ("CAD", "CA$", "$", "Canadian dollar", "Canadian dollars"),
+    ("CHF", "CHF", "CHF", "Swiss franc", "Swiss francs"),
+    ("CNY", "CN¥", "¥", "Chinese yuan", "Chinese yuan"),
+    ("EUR", "€", "€", "euro", "euros"),
+    ("GBP", "£", "£", "British pound", "British pounds"),
+    ("INR", "₹", "₹", "Indian rupee", "Indian rupees"),
+    ("JPY", "¥", "¥", "Japanese yen", "Japanese yen"),
+    ("KRW", "₩", "₩", "South Korean won", "South Korean won"),
+    ("MXN", "MX$", "$", "Mexican peso", "Mexican pesos"),
+    ("RUB", "RUB", "₽", "Russian ruble", "Russian rubles"),
+    ("USD", "$", "$", "US dollar", "US dollars"),
+];
+
+/// The sanctioned single unit identifiers, with the English patterns of their short (singular
+/// and plural), narrow and long (singular and plural) displays.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#table-sanctioned-single-unit-identifiers
+const UNITS: [(&str, [&str; 5]); 45] = [
+    (
+        "acre",
+        ["{0} ac", "{0} ac", "{0}ac", "{0} acre", "{0} acres"],
+    ),
+    (
+        "bit",
+        ["{0} bit", "{0} bit", "{0}bit", "{0} bit", "{0} bits"],
+    ),
+    (
+        "byte",
+        ["{0} byte", "{0} byte", "{0}B", "{0} byte", "{0} bytes"],
+    ),
+    (
+        "celsius",
+        [
+            "{0}°C",
+            "{0}°C",
+            "{0}°C",
+            "{0} degree Celsius",
+            "{0} degrees Celsius",
+        ],
+    ),
+    (
+        "centimeter",
+        [
+            "{0} cm",
+            "{0} cm",
+            "{0}cm",
+            "{0} centimeter",
+            "{0} centimeters",
+        ],
+    ),
+    (
+        "day",
+        ["{0} day", "{0} days", "{0}d", "{0} day", "{0} days"],
+    ),
+    (
+        "degree",
+        ["{0} deg", "{0} deg", "{0}°", "{0} degree", "{0} degrees"],
+    ),
+    (
+        "fahrenheit",
+        [
+            "{0}°F",
+            "{0}°F",
+            "{0}°",
+            "{0} degree Fahrenheit",
+            "{0} degrees Fahrenheit",
+        ],
+    ),
+    (
+        "fluid-ounce",
+        [
+            "{0} fl oz",
+            "{0} fl oz",
+            "{0}fl oz",
+            "{0} fluid ounce",
+            "{0} fluid ounces",
+        ],
+    ),
+    ("foot", ["{0} ft", "{0} ft", "{0}′", "{0} foot", "{0} feet"]),
+    (
+        "gallon",
+        ["{0} gal", "{0} gal", "{0}gal", "{0} gallon", "{0} gallons"],
+    ),
+    (
+        "gigabit",
+        ["{0} Gb", "{0} Gb", "{0}Gb", "{0} gigabit", "{0} gigabits"],
+    ),
+    (
+        "gigabyte",
+        ["{0} GB", "{0} GB", "{0}GB", "{0} gigabyte", "{0} gigabytes"],
+    ),
+    ("gram", ["{0} g", "{0} g", "{0}g", "{0} gram", "{0} grams"]),
+    (
+        "hectare",
+        ["{0} ha", "{0} ha", "{0}ha", "{0} hectare", "{0} hectares"],
+    ),
+    (
+        "hour",
+        ["{0} hr", "{0} hr", "{0}h", "{0} hour", "{0} hours"],
+    ),
+    (
+        "inch",
+        ["{0} in", "{0} in", "{0}″", "{0} inch", "{0} inches"],
+    ),
+    (
+        "kilobit",
+        ["{0} kb", "{0} kb", "{0}kb", "{0} kilobit", "{0} kilobits"],
+    ),
+    (
+        "kilobyte",
+        ["{0} kB", "{0} kB", "{0}kB", "{0} kilobyte", "{0} kilobytes"],
+    ),
+    (
+        "kilogram",
+        ["{0} kg", "{0} kg", "{0}kg", "{0} kilogram", "{0} kilograms"],
+    ),
+    (
+        "kilometer",
+        [
+            "{0} km",
+            "{0} km",
+            "{0}km",
+            "{0} kilometer",
+            "{0} kilometers",
+        ],
+    ),
+    (
+        "liter",
+        ["{0} L", "{0} L", "{0}L", "{0} liter", "{0} liters"],
+    ),
+    (
+        "megabit",
+        ["{0} Mb", "{0} Mb", "{0}Mb", "{0} megabit", "{0} megabits"],
+    ),
+    (
+        "megabyte",
+        ["{0} MB", "{0} MB", "{0}MB", "{0} megabyte", "{0} megabytes"],
+    ),
+    (
+        "meter",
+        ["{0} m", "{0} m", "{0}m", "{0} meter", "{0} meters"],
+    ),
+    (
+        "microsecond",
+        [
+            "{0} μs",
+            "{0} μs",
+            "{0}μs",
+            "{0} microsecond",
+            "{0} microseconds",
+        ],
+    ),
+    (
+        "mile",
+        ["{0} mi", "{0} mi", "{0}mi", "{0} mile", "{0} miles"],
+    ),
+    (
+        "mile-scandinavian",
+        [
+            "{0} smi",
+            "{0} smi",
+            "{0}smi",
+            "{0} mile-scandinavian",
+            "{0} miles-scandinavian",
+        ],
+    ),
+    (
+        "milliliter",
+        [
+            "{0} mL",
+            "{0} mL",
+            "{0}mL",
+            "{0} milliliter",
+            "{0} milliliters",
+        ],
+    ),
+    (
+        "millimeter",
+        [
+            "{0} mm",
+            "{0} mm",
+            "{0}mm",
+            "{0} millimeter",
+            "{0} millimeters",
+        ],
+    ),
+    (
+        "millisecond",
+        [
+            "{0} ms",
+            "{0} ms",
+            "{0}ms",
+            "{0} millisecond",
+            "{0} milliseconds",
+        ],
+    ),
+    (
+        "minute",
+        ["{0} min", "{0} min", "{0}m", "{0} minute", "{0} minutes"],
+    ),
+    (
+        "month",
+        ["{0} mth", "{0} mths", "{0}m", "{0} month", "{0} months"],
+    ),
+    (
+        "nanosecond",
+        [
+            "{0} ns",
+            "{0} ns",
+            "{0}ns",
+            "{0} nanosecond",
+            "{0} nanoseconds",
+        ],
+    ),
+    (
+        "ounce",
+        ["{0} oz", "{0} oz", "{0}oz", "{0} ounce", "{0} ounces"],
+    ),
+    (
+        "percent",
+        ["{0}%", "{0}%", "{0}%", "{0} percent", "{0} percent"],
+    ),
+    (
+        "petabyte",
+        ["{0} PB", "{0} PB", "{0}PB", "{0} petabyte", "{0} petabytes"],
+    ),
+    (
+        "pound",
+        ["{0} lb", "{0} lb", "{0}lb", "{0} pound", "{0} pounds"],
+    ),
+    (
+        "second",
+        ["{0} sec", "{0} sec", "{0}s", "{0} second", "{0} seconds"],
+    ),
+    (
+        "stone",
+        ["{0} st", "{0} st", "{0}st", "{0} stone", "{0} stones"],
+    ),
+    (
+        "terabit",
+        ["{0} Tb", "{0} Tb", "{0}Tb", "{0} terabit", "{0} terabits"],
+    ),
+    (
+        "terabyte",
+        ["{0} TB", "{0} TB", "{0}TB", "{0} terabyte", "{0} terabytes"],
+    ),
+    (
+        "week",
+        ["{0} wk", "{0} wks", "{0}w", "{0} week", "{0} weeks"],
+    ),
+    (
+        "yard",
+        ["{0} yd", "{0} yd", "{0}yd", "{0} yard", "{0} yards"],
+    ),
+    (
+        "year",
+        ["{0} yr", "{0} yrs", "{0}y", "{0} year", "{0} years"],
+    ),
+];
+
+/// The compound units whose patterns are not built from the patterns of their numerator and
+/// denominator.
+const COMPOUND_UNITS: [(&str, [&str; 5]); 3] = [
+    (
+        "kilometer-per-hour",
+        [
+            "{0} km/h",
+            "{0} km/h",
+            "{0}km/h",
+            "{0} kilometer per hour",
+            "{0} kilometers per hour",
+        ],
+    ),
+    (
+        "liter-per-kilometer",
+        [
+            "{0} L/km",
+            "{0} L/km",
+            "{0}L/km",
+            "{0} liter per kilometer",
+            "{0} liters per kilometer",
+        ],
+    ),
+    (
+        "mile-per-hour",
+        [
+            "{0} mph",
+            "{0} mph",
+            "{0}mph",
+            "{0} mile per hour",
+            "{0} miles per hour",
+        ],
+    ),
+];
+
+/// Gets the display patterns of a sanctioned single unit identifier.
+fn unit_patterns(unit: &str) -> Option<&'static [&'static str; 5]> {
+    UNITS
+        .iter()
+        .find(|(id, _)| *id == unit)
+        .map(|(_, patterns)| patterns)
+}
+
+/// Abstract operation `IsWellFormedUnitIdentifier ( unitIdentifier )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-iswellformedunitidentifier
+fn is_well_formed_unit_identifier(unit: &str) -> bool {
+    // 1. If ! IsSanctionedSingleUnitIdentifier(unitIdentifier) is true, then
+    if unit_patterns(unit).is_some() {
+        // a. Return true.
+        return true;
+    }
+
+    // 2. Let i be StringIndexOf(unitIdentifier, "-per-", 0).
+    // 3. If i is -1 or StringIndexOf(unitIdentifier, "-per-", i + 1) is not -1, then
+    //     a. Return false.
+    // 4-7. Return true if the numerator and the denominator are sanctioned single units.
+    match unit.split_once("-per-") {
+        Some((numerator, denominator)) => {
+            !denominator.contains("-per-")
+                && unit_patterns(numerator).is_some()
+                && unit_patterns(denominator).is_some()
+        }
+        None => false,
+    }
+}
+
+/// Abstract operation `IsWellFormedCurrencyCode ( currency )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-iswellformedcurrencycode
+fn is_well_formed_currency_code(currency: &str) -> bool {
+    // 1. If the length of currency is not 3, return false.
+    // 2. Let normalized be the ASCII-uppercase of currency.
+    // 3. If normalized contains any code unit outside of 0x0041 through 0x005A, return false.
+    // 4. Return true.
+    currency.len() == 3 && currency.bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+/// Abstract operation `CurrencyDigits ( currency )`
+///
+/// Gets the number of minor unit digits of a currency in ISO 4217, which is 2 for the
+/// currencies that are not listed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-currencydigits
+fn currency_digits(currency: &str) -> u8 {
+    match currency {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+/// A non-negative decimal number, stored as its significant digits and the power of ten of
+/// the first digit.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct Decimal {
+    /// The significant digits, without leading or trailing zeros. Empty for zero.
+    digits: Vec<u8>,
+    /// The power of ten of the first digit.
+    exponent: i32,
+}
+
+impl Decimal {
+    /// Creates a decimal from its digits, removing the leading and trailing zeros.
+    fn from_digits(mut digits: Vec<u8>, mut exponent: i32) -> Self {
+        let leading_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        if leading_zeros == digits.len() {
+            return Self::default();
+        }
+        digits.drain(..leading_zeros);
+        exponent -= leading_zeros as i32;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self { digits, exponent }
+    }
+
+    /// Gets the absolute value of a finite number, with the shortest digits that round-trip
+    /// to the same number.
+    pub(super) fn from_f64(x: f64) -> Self {
+        debug_assert!(x.is_finite());
+        let repr = format!("{:e}", x.abs());
+        let (mantissa, exponent) = repr
+            .split_once('e')
+            .expect("the exponential representation of a number must have an exponent");
+        let exponent = exponent
+            .parse()
+            .expect("the exponent of a number must be an integer");
+        let digits = mantissa
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|byte| byte - b'0')
+            .collect();
+        Self::from_digits(digits, exponent)
+    }
+
+    /// Gets the absolute value of a `BigInt`, from its decimal representation.
+    fn from_integer_string(integer: &str) -> Self {
+        let digits: Vec<u8> = integer
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|byte| byte - b'0')
+            .collect();
+        let exponent = digits.len() as i32 - 1;
+        Self::from_digits(digits, exponent)
+    }
+
+    /// Returns `true` if the number is zero.
+    fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Gets the power of ten of the first digit, which is 0 for zero.
+    fn magnitude(&self) -> i32 {
+        if self.is_zero() {
+            0
+        } else {
+            self.exponent
+        }
+    }
+
+    /// Gets the digit of the number at the given power of ten.
+    fn digit(&self, power: i32) -> u8 {
+        usize::try_from(self.exponent - power)
+            .ok()
+            .and_then(|index| self.digits.get(index))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Multiplies the number by `10^n`.
+    fn scale(&self, n: i32) -> Self {
+        let mut result = self.clone();
+        if !result.is_zero() {
+            result.exponent += n;
+        }
+        result
+    }
+
+    /// Rounds the number to a multiple of `10^power`, with ties away from zero.
+    fn round(&self, power: i32) -> Self {
+        let keep = self.exponent - power + 1;
+        if self.is_zero() || keep >= self.digits.len() as i32 {
+            return self.clone();
+        }
+        if keep < 0 {
+            return Self::default();
+        }
+
+        let keep = keep as usize;
+        let mut digits = self.digits[..keep].to_vec();
+        let mut exponent = self.exponent;
+        if self.digits[keep] >= 5 {
+            let mut index = keep;
+            loop {
+                if index == 0 {
+                    digits.insert(0, 1);
+                    exponent += 1;
+                    break;
+                }
+                index -= 1;
+                if digits[index] == 9 {
+                    digits[index] = 0;
+                } else {
+                    digits[index] += 1;
+                    break;
+                }
+            }
+        }
+        Self::from_digits(digits, exponent)
+    }
+}
+
+/// The `[[RoundingType]]` and the digit options of the `Intl.NumberFormat` and
+/// `Intl.PluralRules` objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RoundingType {
+    FractionDigits {
+        minimum: u8,
+        maximum: u8,
+    },
+    SignificantDigits {
+        minimum: u8,
+        maximum: u8,
+    },
+    /// The rounding of the compact notation, which drops the fraction digits of the numbers
+    /// with two or more integer digits, and keeps two significant digits of the other numbers.
+    CompactRounding,
+}
+
+/// The digit options of the `Intl.NumberFormat` and `Intl.PluralRules` objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct DigitOptions {
+    pub(super) minimum_integer_digits: u8,
+    pub(super) rounding_type: RoundingType,
+}
+
+impl DigitOptions {
+    /// Abstract operation `SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-setnfdigitoptions
+    #[allow(clippy::similar_names)]
+    pub(super) fn from_options(
+        options: &JsObject,
+        minimum_fraction_digits_default: u8,
+        maximum_fraction_digits_default: u8,
+        compact: bool,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits,", 1, 21, 1).
+        let minimum_integer_digits = get_number_option(
+            options,
+            "minimumIntegerDigits",
+            1.0,
+            21.0,
+            Some(1.0),
+            context,
+        )?
+        .unwrap_or(1.0) as u8;
+
+        // 2. Let mnfd be ? Get(options, "minimumFractionDigits").
+        let mnfd = options.get("minimumFractionDigits", context)?;
+        // 3. Let mxfd be ? Get(options, "maximumFractionDigits").
+        let mxfd = options.get("maximumFractionDigits", context)?;
+        // 4. Let mnsd be ? Get(options, "minimumSignificantDigits").
+        let mnsd = options.get("minimumSignificantDigits", context)?;
+        // 5. Let mxsd be ? Get(options, "maximumSignificantDigits").
+        let mxsd = options.get("maximumSignificantDigits", context)?;
+
+        // 6. Set intlObj.[[MinimumIntegerDigits]] to mnid.
+        let rounding_type = if !mnsd.is_undefined() || !mxsd.is_undefined() {
+            // 7. If mnsd is not undefined or mxsd is not undefined, then
+            //     a. Set intlObj.[[RoundingType]] to significantDigits.
+            //     b. Let mnsd be ? DefaultNumberOption(mnsd, 1, 21, 1).
+            //     c. Let mxsd be ? DefaultNumberOption(mxsd, mnsd, 21, 21).
+            let minimum =
+                default_number_option(&mnsd, 1.0, 21.0, Some(1.0), context)?.unwrap_or(1.0);
+            let maximum =
+                default_number_option(&mxsd, minimum, 21.0, Some(21.0), context)?.unwrap_or(21.0);
+            RoundingType::SignificantDigits {
+                minimum: minimum as u8,
+                maximum: maximum as u8,
+            }
+        } else if !mnfd.is_undefined() || !mxfd.is_undefined() {
+            // 8. Else if mnfd is not undefined or mxfd is not undefined, then
+            //     a. Set intlObj.[[RoundingType]] to fractionDigits.
+            //     b. Let mnfd be ? DefaultNumberOption(mnfd, 0, 20, undefined).
+            //     c. Let mxfd be ? DefaultNumberOption(mxfd, 0, 20, undefined).
+            let minimum = default_number_option(&mnfd, 0.0, 20.0, None, context)?;
+            let maximum = default_number_option(&mxfd, 0.0, 20.0, None, context)?;
+            let (minimum, maximum) = match (minimum, maximum) {
+                // d. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+                (None, Some(maximum)) => (
+                    f64::from(minimum_fraction_digits_default).min(maximum),
+                    maximum,
+                ),
+                // e. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+                (Some(minimum), None) => (
+                    minimum,
+                    f64::from(maximum_fraction_digits_default).max(minimum),
+                ),
+                // f. Else if mnfd is greater than mxfd, throw a RangeError exception.
+                (Some(minimum), Some(maximum)) if minimum > maximum => {
+                    return Err(JsNativeError::range()
+                        .with_message("minimumFractionDigits is greater than maximumFractionDigits")
+                        .into());
+                }
+                (Some(minimum), Some(maximum)) => (minimum, maximum),
+                (None, None) => unreachable!("one of the fraction digit options is defined"),
+            };
+            RoundingType::FractionDigits {
+                minimum: minimum as u8,
+                maximum: maximum as u8,
+            }
+        } else if compact {
+            // 9. Else if notation is "compact", then
+            //     a. Set intlObj.[[RoundingType]] to compactRounding.
+            RoundingType::CompactRounding
+        } else {
+            // 10. Else,
+            //     a. Set intlObj.[[RoundingType]] to fractionDigits.
+            //     b. Set intlObj.[[MinimumFractionDigits]] to mnfdDefault.
+            //     c. Set intlObj.[[MaximumFractionDigits]] to mxfdDefault.
+            RoundingType::FractionDigits {
+                minimum: minimum_fraction_digits_default,
+                maximum: maximum_fraction_digits_default,
+            }
+        };
+
+        Ok(Self {
+            minimum_integer_digits,
+            rounding_type,
+        })
+    }
+
+    /// Abstract operation `FormatNumericToString ( intlObject, x )`
+    ///
+    /// Rounds `x` with the digit options, and returns the rounded number with the digits of its
+    /// integer and fraction parts.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumberstring
+    pub(super) fn format(self, x: &Decimal) -> (Decimal, String, String) {
+        let (rounded, minimum_fraction_digits) = match self.rounding_type {
+            // 1. If intlObject.[[RoundingType]] is significantDigits, then
+            //     a. Let result be ToRawPrecision(x, intlObject.[[MinimumSignificantDigits]],
+            //        intlObject.[[MaximumSignificantDigits]]).
+            RoundingType::SignificantDigits { minimum, maximum } => {
+                let rounded = x.round(x.magnitude() - i32::from(maximum) + 1);
+                let lowest_power = rounded.magnitude() - i32::from(minimum) + 1;
+                (rounded, (-lowest_power).max(0))
+            }
+            // 2. Else if intlObject.[[RoundingType]] is fractionDigits, then
+            //     a. Let result be ToRawFixed(x, intlObject.[[MinimumFractionDigits]],
+            //        intlObject.[[MaximumFractionDigits]]).
+            RoundingType::FractionDigits { minimum, maximum } => {
+                (x.round(-i32::from(maximum)), i32::from(minimum))
+            }
+            // 3. Else,
+            //     a. Assert: intlObject.[[RoundingType]] is compactRounding.
+            //     b. Let result be ToRawPrecision(x, 1, 2).
+            //     c. If result.[[IntegerDigitsCount]] > 1, then
+            //         i. Let result be ToRawFixed(x, 0, 0).
+            RoundingType::CompactRounding => {
+                if x.magnitude() >= 1 {
+                    (x.round(0), 0)
+                } else {
+                    (x.round(x.magnitude() - 1), 0)
+                }
+            }
+        };
+
+        let lowest_power = if rounded.is_zero() {
+            0
+        } else {
+            rounded.exponent - rounded.digits.len() as i32 + 1
+        }
+        .min(-minimum_fraction_digits);
+
+        // 4. Let x be result.[[RoundedNumber]].
+        // 5. Let string be result.[[FormattedString]].
+        let integer: String = (0..=rounded.magnitude().max(0))
+            .rev()
+            .map(|power| char::from(b'0' + rounded.digit(power)))
+            .collect();
+        let fraction: String = (lowest_power..0)
+            .rev()
+            .map(|power| char::from(b'0' + rounded.digit(power)))
+            .collect();
+
+        // 6. Let int be result.[[IntegerDigitsCount]].
+        // 7. Let minInteger be intlObject.[[MinimumIntegerDigits]].
+        // 8. If int < minInteger, then
+        //     a. Let forwardZeros be the String consisting of minInteger–int occurrences of the
+        //        character "0".
+        //     b. Set string to the string-concatenation of forwardZeros and string.
+        let minimum_integer_digits = usize::from(self.minimum_integer_digits);
+        let integer = if integer.len() < minimum_integer_digits {
+            "0".repeat(minimum_integer_digits - integer.len()) + &integer
+        } else {
+            integer
+        };
+
+        // 9. If isNegative is true, then ...
+        // The sign is added by the callers.
+
+        // 10. Return the Record { [[RoundedNumber]]: x, [[FormattedString]]: string }.
+        (rounded, integer, fraction)
+    }
+
+    /// Adds the digit options to the object returned by `resolvedOptions`.
+    pub(super) fn resolved_options(self, options: &mut Vec<(&'static str, JsValue)>) {
+        options.push(("minimumIntegerDigits", self.minimum_integer_digits.into()));
+        match self.rounding_type {
+            RoundingType::FractionDigits { minimum, maximum } => {
+                options.push(("minimumFractionDigits", minimum.into()));
+                options.push(("maximumFractionDigits", maximum.into()));
+            }
+            RoundingType::SignificantDigits { minimum, maximum } => {
+                options.push(("minimumSignificantDigits", minimum.into()));
+                options.push(("maximumSignificantDigits", maximum.into()));
+            }
+            RoundingType::CompactRounding => {}
+        }
+    }
+}
+
+/// The `style` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Decimal,
+    Percent,
+    Currency,
+    Unit,
+}
+
+/// The `currencyDisplay` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CurrencyDisplay {
+    Code,
+    Symbol,
+    NarrowSymbol,
+    Name,
+}
+
+/// The `currencySign` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CurrencySign {
+    Standard,
+    Accounting,
+}
+
+/// The `unitDisplay` and `compactDisplay` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Display {
+    Short,
+    Narrow,
+    Long,
+}
+
+/// The `notation` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Notation {
+    Standard,
+    Scientific,
+    Engineering,
+    Compact,
+}
+
+/// The `signDisplay` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignDisplay {
+    Auto,
+    Never,
+    Always,
+    ExceptZero,
+    Negative,
+}
+
+impl StringOption for Style {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "decimal" => Self::Decimal,
+            "percent" => Self::Percent,
+            "currency" => Self::Currency,
+            "unit" => Self::Unit,
+            _ => return None,
+        })
+    }
+}
+
+impl StringOption for CurrencyDisplay {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "code" => Self::Code,
+            "symbol" => Self::Symbol,
+            "narrowSymbol" => Self::NarrowSymbol,
+            "name" => Self::Name,
+            _ => return None,
+        })
+    }
+}
+
+impl StringOption for CurrencySign {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "standard" => Self::Standard,
+            "accounting" => Self::Accounting,
+            _ => return None,
+        })
+    }
+}
+
+impl StringOption for Display {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "short" => Self::Short,
+            "narrow" => Self::Narrow,
+            "long" => Self::Long,
+            _ => return None,
+        })
+    }
+}
+
+impl StringOption for Notation {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "standard" => Self::Standard,
+            "scientific" => Self::Scientific,
+            "engineering" => Self::Engineering,
+            "compact" => Self::Compact,
+            _ => return None,
+        })
+    }
+}
+
+impl StringOption for SignDisplay {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "auto" => Self::Auto,
+            "never" => Self::Never,
+            "always" => Self::Always,
+            "exceptZero" => Self::ExceptZero,
+            "negative" => Self::Negative,
+            _ => return None,
+        })
+    }
+}
+
+impl Style {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Decimal => "decimal",
+            Self::Percent => "percent",
+            Self::Currency => "currency",
+            Self::Unit => "unit",
+        }
+    }
+}
+
+impl CurrencyDisplay {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Code => "code",
+            Self::Symbol => "symbol",
+            Self::NarrowSymbol => "narrowSymbol",
+            Self::Name => "name",
+        }
+    }
+}
+
+impl CurrencySign {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Accounting => "accounting",
+        }
+    }
+}
+
+impl Display {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+            Self::Long => "long",
+        }
+    }
+}
+
+impl Notation {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Scientific => "scientific",
+            Self::Engineering => "engineering",
+            Self::Compact => "compact",
+        }
+    }
+}
+
+impl SignDisplay {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Never => "never",
+            Self::Always => "always",
+            Self::ExceptZero => "exceptZero",
+            Self::Negative => "negative",
+        }
+    }
+}
+
+/// A part of a formatted number, as returned by `formatToParts`.
+type Part = (&'static str, String);
+
+/// JavaScript `Intl.NumberFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct NumberFormat {
+    locale: JsString,
+    numbering_system: JsString,
+    #[unsafe_ignore_trace]
+    style: Style,
+    currency: Option<JsString>,
+    #[unsafe_ignore_trace]
+    currency_display: CurrencyDisplay,
+    #[unsafe_ignore_trace]
+    currency_sign: CurrencySign,
+    unit: Option<JsString>,
+    #[unsafe_ignore_trace]
+    unit_display: Display,
+    #[unsafe_ignore_trace]
+    digit_options: DigitOptions,
+    #[unsafe_ignore_trace]
+    notation: Notation,
+    #[unsafe_ignore_trace]
+    compact_display: Display,
+    use_grouping: bool,
+    #[unsafe_ignore_trace]
+    sign_display: SignDisplay,
+    bound_format: Option<JsObject>,
+}
+
+impl NumberFormat {
+    const NAME: &'static str = "NumberFormat";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_format = FunctionBuilder::native(context, Self::get_format)
+            .name("get format")
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().number_format().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.NumberFormat",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "format",
+            Some(get_format),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .method(Self::format_to_parts, "formatToParts", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .build()
+    }
+
+    /// Gets the available locales of `Intl.NumberFormat`.
+    fn available_locales() -> Vec<JsString> {
+        LOCALE_SYMBOLS
+            .iter()
+            .map(|symbols| JsString::from(symbols.language))
+            .collect()
+    }
+
+    /// The `Intl.NumberFormat` constructor is the `%NumberFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = if new_target.is_undefined() {
+            context
+                .intrinsics()
+                .constructors()
+                .number_format()
+                .constructor()
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let numberFormat be ? OrdinaryCreateFromConstructor(newTarget, "%NumberFormat.prototype%", « ... »).
+        let prototype = get_prototype_from_constructor(
+            &new_target,
+            StandardConstructors::number_format,
+            context,
+        )?;
+
+        // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
+        let number_format =
+            Self::initialize(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        // 4. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then ...
+        // 5. Return numberFormat.
+        Ok(
+            JsObject::from_proto_and_data(prototype, ObjectData::number_format(number_format))
+                .into(),
+        )
+    }
+
+    /// Abstract operation `InitializeNumberFormat ( numberFormat, locales, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    pub(crate) fn initialize(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(std::slice::from_ref(locales), context)?
+            .into_iter()
+            .map(|locale| JsString::from(locale.to_string()))
+            .collect::<Vec<_>>();
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[localeMatcher]] to matcher.
+        let matcher = get_option(
+            &options,
+            "localeMatcher",
+            &GetOptionType::String,
+            &[js_string!("lookup"), js_string!("best fit")],
+            &js_string!("best fit").into(),
+            context,
+        )?;
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        let numbering_system = get_option(
+            &options,
+            "numberingSystem",
+            &GetOptionType::String,
+            &[],
+            &JsValue::undefined(),
+            context,
+        )?;
+
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        if let Some(numbering_system) = numbering_system.as_string() {
+            let numbering_system = numbering_system.to_std_string_escaped();
+            let is_type = numbering_system.split('-').all(|subtag| {
+                (3..=8).contains(&subtag.len())
+                    && subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+            });
+            if !is_type {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "'{numbering_system}' is not a valid numbering system"
+                    ))
+                    .into());
+            }
+        }
+
+        // 8. Set opt.[[nu]] to numberingSystem.
+        let mut properties = FxHashMap::default();
+        properties.insert(js_string!("nu"), numbering_system);
+        let opt = DateTimeFormatRecord {
+            locale_matcher: matcher
+                .as_string()
+                .cloned()
+                .unwrap_or_else(|| js_string!("best fit")),
+            properties,
+        };
+
+        // 9. Let localeData be %NumberFormat%.[[LocaleData]].
+        // Only the Latin digits are supported.
+        let available_locales = Self::available_locales();
+        let locale_data: LocaleDataRecord = available_locales
+            .iter()
+            .map(|locale| {
+                let mut data = FxHashMap::default();
+                data.insert(js_string!("nu"), vec![js_string!("latn")]);
+                (locale.clone(), data)
+            })
+            .collect();
+
+        // 10. Let r be ResolveLocale(%NumberFormat%.[[AvailableLocales]], requestedLocales, opt, %NumberFormat%.[[RelevantExtensionKeys]], localeData).
+        let r = resolve_locale(
+            &available_locales,
+            &requested_locales,
+            &opt,
+            &[js_string!("nu")],
+            &locale_data,
+            context,
+        );
+
+        // 11. Set numberFormat.[[Locale]] to r.[[locale]].
+        // 12. Set numberFormat.[[DataLocale]] to r.[[dataLocale]].
+        // 13. Set numberFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = r.locale;
+        let numbering_system = js_string!("latn");
+
+        // 14. Perform ? SetNumberFormatUnitOptions(numberFormat, options).
+        // SetNumberFormatUnitOptions ( intlObj, options )
+        // 1. Let style be ? GetOption(options, "style", string, « "decimal", "percent", "currency", "unit" », "decimal").
+        let style = get_string_option(&options, "style", context)?.unwrap_or(Style::Decimal);
+
+        // 3. Let currency be ? GetOption(options, "currency", string, empty, undefined).
+        let currency = options.get("currency", context)?;
+        let currency = if currency.is_undefined() {
+            // 4. If currency is undefined, then
+            //     a. If style is "currency", throw a TypeError exception.
+            if style == Style::Currency {
+                return Err(JsNativeError::typ()
+                    .with_message("the currency option is required with the currency style")
+                    .into());
+            }
+            None
+        } else {
+            // 5. Else,
+            //     a. If IsWellFormedCurrencyCode(currency) is false, throw a RangeError exception.
+            let currency = currency.to_string(context)?.to_std_string_escaped();
+            if !is_well_formed_currency_code(&currency) {
+                return Err(JsNativeError::range()
+                    .with_message(format!("'{currency}' is not a well-formed currency code"))
+                    .into());
+            }
+            Some(currency.to_ascii_uppercase())
+        };
+
+        // 6. Let currencyDisplay be ? GetOption(options, "currencyDisplay", string, « "code", "symbol", "narrowSymbol", "name" », "symbol").
+        let currency_display = get_string_option(&options, "currencyDisplay", context)?
+            .unwrap_or(CurrencyDisplay::Symbol);
+
+        // 7. Let currencySign be ? GetOption(options, "currencySign", string, « "standard", "accounting" », "standard").
+        let currency_sign =
+            get_string_option(&options, "currencySign", context)?.unwrap_or(CurrencySign::Standard);
+
+        // 8. Let unit be ? GetOption(options, "unit", string, empty, undefined).
+        let unit = options.get("unit", context)?;
+        let unit = if unit.is_undefined() {
+            // 9. If unit is undefined, then
+            //     a. If style is "unit", throw a TypeError exception.
+            if style == Style::Unit {
+                return Err(JsNativeError::typ()
+                    .with_message("the unit option is required with the unit style")
+                    .into());
+            }
+            None
+        } else {
+            // 10. Else,
+            //     a. If IsWellFormedUnitIdentifier(unit) is false, throw a RangeError exception.
+            let unit = unit.to_string(context)?.to_std_string_escaped();
+            if !is_well_formed_unit_identifier(&unit) {
+                return Err(JsNativeError::range()
+                    .with_message(format!("'{unit}' is not a well-formed unit identifier"))
+                    .into());
+            }
+            Some(unit)
+        };
+
+        // 11. Let unitDisplay be ? GetOption(options, "unitDisplay", string, « "short", "narrow", "long" », "short").
+        let unit_display =
+            get_string_option(&options, "unitDisplay", context)?.unwrap_or(Display::Short);
+
+        // 12. If style is "currency", then
+        //     a. Set intlObj.[[Currency]] to the ASCII-uppercase of currency.
+        //     b. Set intlObj.[[CurrencyDisplay]] to currencyDisplay.
+        //     c. Set intlObj.[[CurrencySign]] to currencySign.
+        // 13. If style is "unit", then
+        //     a. Set intlObj.[[Unit]] to unit.
+        //     b. Set intlObj.[[UnitDisplay]] to unitDisplay.
+        let currency = currency.filter(|_| style == Style::Currency);
+        let unit = unit.filter(|_| style == Style::Unit);
+
+        // 15. Let style be numberFormat.[[Style]].
+        // 16. If style is "currency", then
+        //     a. Let currency be numberFormat.[[Currency]].
+        //     b. Let cDigits be CurrencyDigits(currency).
+        //     c. Let mnfdDefault be cDigits.
+        //     d. Let mxfdDefault be cDigits.
+        // 17. Else,
+        //     a. Let mnfdDefault be 0.
+        //     b. If style is "percent", then
+        //         i. Let mxfdDefault be 0.
+        //     c. Else,
+        //         i. Let mxfdDefault be 3.
+        let (minimum_fraction_digits_default, maximum_fraction_digits_default) = match &currency {
+            Some(currency) => {
+                let digits = currency_digits(currency);
+                (digits, digits)
+            }
+            None if style == Style::Percent => (0, 0),
+            None => (0, 3),
+        };
+
+        // 18. Let notation be ? GetOption(options, "notation", string, « "standard", "scientific", "engineering", "compact" », "standard").
+        // 19. Set numberFormat.[[Notation]] to notation.
+        let notation =
+            get_string_option(&options, "notation", context)?.unwrap_or(Notation::Standard);
+
+        // 20. Perform ? SetNumberFormatDigitOptions(numberFormat, options, mnfdDefault, mxfdDefault, notation).
+        let digit_options = DigitOptions::from_options(
+            &options,
+            minimum_fraction_digits_default,
+            maximum_fraction_digits_default,
+            notation == Notation::Compact,
+            context,
+        )?;
+
+        // 21. Let compactDisplay be ? GetOption(options, "compactDisplay", string, « "short", "long" », "short").
+        // 22. If notation is "compact", then
+        //     a. Set numberFormat.[[CompactDisplay]] to compactDisplay.
+        let compact_display = match get_string_option(&options, "compactDisplay", context)?
+            .unwrap_or(Display::Short)
+        {
+            Display::Narrow => {
+                return Err(JsNativeError::range()
+                    .with_message("'narrow' is not a valid value for option compactDisplay")
+                    .into())
+            }
+            display => display,
+        };
+
+        // 23. Let useGrouping be ? GetOption(options, "useGrouping", boolean, empty, true).
+        // 24. Set numberFormat.[[UseGrouping]] to useGrouping.
+        let use_grouping = get_option(
+            &options,
+            "useGrouping",
+            &GetOptionType::Boolean,
+            &[],
+            &true.into(),
+            context,
+        )?
+        .to_boolean();
+
+        // 25. Let signDisplay be ? GetOption(options, "signDisplay", string, « "auto", "never", "always", "exceptZero", "negative" », "auto").
+        // 26. Set numberFormat.[[SignDisplay]] to signDisplay.
+        let sign_display =
+            get_string_option(&options, "signDisplay", context)?.unwrap_or(SignDisplay::Auto);
+
+        Ok(Self {
+            locale,
+            numbering_system,
+            style,
+            currency: currency.map(JsString::from),
+            currency_display,
+            currency_sign,
+            unit: unit.map(JsString::from),
+            unit_display,
+            digit_options,
+            notation,
+            compact_display,
+            use_grouping,
+            sign_display,
+            bound_format: None,
+        })
+    }
+
+    /// Abstract operation `PartitionNumberPattern ( numberFormat, x )`
+    ///
+    /// Formats the number `x` into a list of parts.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberpattern
+    fn partition_number_pattern(&self, x: &Numeric) -> Vec<Part> {
+        let symbols = LocaleSymbols::of(&self.locale);
+
+        let (negative, x) = match x {
+            Numeric::Number(x) if x.is_nan() => {
+                // 1. If x is NaN, then
+                //     a. Let n be an implementation- and locale-dependent (ILD) String value indicating the NaN value.
+                return self.apply_style(symbols, vec![("nan", "NaN".to_owned())], "other");
+            }
+            Numeric::Number(x) => (x.is_sign_negative(), *x),
+            Numeric::BigInt(x) => {
+                let string = x.to_string_radix(10);
+                let decimal = Decimal::from_integer_string(&string);
+                return self.partition_decimal(symbols, string.starts_with('-'), &decimal);
+            }
+        };
+
+        if x.is_infinite() {
+            // 2. Else if x is a non-finite Number, then
+            //     a. Let n be an ILD String value indicating infinity.
+            let sign = self.sign(negative, false);
+            let mut parts = sign.into_iter().collect::<Vec<_>>();
+            parts.push(("infinity", "∞".to_owned()));
+            return self.apply_style(symbols, parts, "other");
+        }
+
+        self.partition_decimal(symbols, negative, &Decimal::from_f64(x))
+    }
+
+    /// Formats the finite number with absolute value `x` into a list of parts.
+    fn partition_decimal(&self, symbols: &LocaleSymbols, negative: bool, x: &Decimal) -> Vec<Part> {
+        // 3. Else,
+        //     a. If numberFormat.[[Style]] is "percent", let x be 100 × x.
+        let x = if self.style == Style::Percent {
+            x.scale(2)
+        } else {
+            x.clone()
+        };
+
+        //     b. Let exponent be ComputeExponent(numberFormat, x).
+        //     c. Let x be x × 10^-exponent.
+        //     d. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+        let mut exponent = self.compute_exponent(symbols, x.magnitude());
+        let (mut rounded, mut integer, mut fraction) =
+            self.digit_options.format(&x.scale(-exponent));
+
+        // ComputeExponent ( numberFormat, x )
+        // 4. Let x be x × 10^-exponent.
+        // 5. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+        // 6. If formatNumberResult.[[RoundedNumber]] = 0, then
+        //     a. Return exponent.
+        // 7. Let newMagnitude be the base 10 logarithm of formatNumberResult.[[RoundedNumber]] rounded down to the nearest integer.
+        // 8. If newMagnitude is magnitude - exponent, then
+        //     a. Return exponent.
+        // 9. Return ComputeExponentForMagnitude(numberFormat, magnitude + 1).
+        if !rounded.is_zero() && rounded.magnitude() + exponent != x.magnitude() {
+            let new_exponent = self.compute_exponent(symbols, rounded.magnitude() + exponent);
+            if new_exponent != exponent {
+                exponent = new_exponent;
+                (rounded, integer, fraction) = self.digit_options.format(&x.scale(-exponent));
+            }
+        }
+
+        //     e. Let n be formatNumberResult.[[FormattedString]].
+        // 4. Let pattern be GetNumberFormatPattern(numberFormat, x).
+        // 5-6. For each part of the pattern, insert the parts of the number.
+        let mut number = Vec::new();
+        number.extend(self.sign(negative, rounded.is_zero()));
+
+        // Partition the integer digits into groups of three, if the locale uses grouping.
+        if self.use_grouping && integer.len() >= symbols.minimum_grouping_digits {
+            let first = integer.len() % 3;
+            if first > 0 {
+                number.push(("integer", integer[..first].to_owned()));
+            }
+            for (index, start) in (first..integer.len()).step_by(3).enumerate() {
+                if index > 0 || first > 0 {
+                    number.push(("group", symbols.group.to_owned()));
+                }
+                number.push(("integer", integer[start..start + 3].to_owned()));
+            }
+        } else {
+            number.push(("integer", integer.clone()));
+        }
+
+        if !fraction.is_empty() {
+            number.push(("decimal", symbols.decimal.to_owned()));
+            number.push(("fraction", fraction.clone()));
+        }
+
+        match self.notation {
+            Notation::Standard => {}
+            Notation::Scientific | Notation::Engineering => {
+                number.push(("exponentSeparator", "E".to_owned()));
+                if exponent < 0 {
+                    number.push(("exponentMinusSign", "-".to_owned()));
+                }
+                number.push(("exponentInteger", exponent.unsigned_abs().to_string()));
+            }
+            Notation::Compact if exponent == 0 => {}
+            Notation::Compact => {
+                let index = (exponent / 3 - 1) as usize;
+                if self.compact_display == Display::Long {
+                    number.push(("literal", " ".to_owned()));
+                    number.push(("compact", symbols.compact_long[index].to_owned()));
+                } else {
+                    if !symbols.compact_short_separator.is_empty() {
+                        number.push(("literal", symbols.compact_short_separator.to_owned()));
+                    }
+                    number.push(("compact", symbols.compact_short[index].to_owned()));
+                }
+            }
+        }
+
+        // The plural category of the number in English, which selects the names of the
+        // currencies and units.
+        let plural = if integer == "1" && fraction.is_empty() && exponent == 0 {
+            "one"
+        } else {
+            "other"
+        };
+
+        self.apply_style(symbols, number, plural)
+    }
+
+    /// Abstract operation `ComputeExponentForMagnitude ( numberFormat, magnitude )`
+    ///
+    /// Gets the exponent of the power of ten used to scale a number of the given magnitude in the
+    /// notation of the number format.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-computeexponentformagnitude
+    fn compute_exponent(&self, symbols: &LocaleSymbols, magnitude: i32) -> i32 {
+        match self.notation {
+            // 2. If notation is "scientific", then
+            //     a. Return magnitude.
+            Notation::Scientific => magnitude,
+            // 3. Else if notation is "engineering", then
+            //     a. Let thousands be the greatest integer that is not greater than magnitude / 3.
+            //     b. Return thousands × 3.
+            Notation::Engineering => magnitude.div_euclid(3) * 3,
+            // 4. Else if notation is "compact", then
+            //     a. Let exponent be an ILD integer by which to scale a number of the given magnitude in compact notation for the current locale.
+            //     b. Return exponent.
+            Notation::Compact if magnitude >= 3 => {
+                let exponent = magnitude.min(14) / 3 * 3;
+                let index = (exponent / 3 - 1) as usize;
+                if symbols.compact_short[index].is_empty() {
+                    0
+                } else {
+                    exponent
+                }
+            }
+            // 5. Else,
+            //     a. Assert: notation is "standard".
+            //     b. Return 0.
+            Notation::Compact | Notation::Standard => 0,
+        }
+    }
+
+    /// Gets the sign of a number as displayed with the `signDisplay` option, or `None` if the
+    /// number is displayed without a sign.
+    fn sign(&self, negative: bool, zero: bool) -> Option<Part> {
+        let minus = match self.sign_display {
+            SignDisplay::Auto | SignDisplay::Always => negative,
+            SignDisplay::ExceptZero | SignDisplay::Negative => negative && !zero,
+            SignDisplay::Never => false,
+        };
+        let plus = !negative
+            && match self.sign_display {
+                SignDisplay::Always => true,
+                SignDisplay::ExceptZero => !zero,
+                SignDisplay::Auto | SignDisplay::Never | SignDisplay::Negative => false,
+            };
+
+        if minus {
+            Some(("minusSign", "-".to_owned()))
+        } else if plus {
+            Some(("plusSign", "+".to_owned()))
+        } else {
+            None
+        }
+    }
+
+    /// Adds the percent sign, the currency or the unit of the style of the number format to the
+    /// parts of the formatted number.
+    fn apply_style(&self, symbols: &LocaleSymbols, number: Vec<Part>, plural: &str) -> Vec<Part> {
+        let literal = |text: &str| ("literal", text.to_owned());
+        match self.style {
+            Style::Decimal => number,
+            Style::Percent => {
+                let mut parts = number;
+                if !symbols.percent_separator.is_empty() {
+                    parts.push(literal(symbols.percent_separator));
+                }
+                parts.push(("percentSign", "%".to_owned()));
+                parts
+            }
+            Style::Currency => {
+                let code = self
+                    .currency
+                    .as_ref()
+                    .map(JsString::to_std_string_escaped)
+                    .unwrap_or_default();
+                let known = CURRENCIES.iter().find(|currency| currency.0 == code);
+                let english = symbols.language == "en";
+                let name = self.currency_display == CurrencyDisplay::Name && english;
+                let display = match (self.currency_display, known) {
+                    (CurrencyDisplay::NarrowSymbol, Some(currency)) => currency.2.to_owned(),
+                    (CurrencyDisplay::Symbol, Some(currency)) if english => currency.1.to_owned(),
+                    (CurrencyDisplay::Name, Some(currency)) if name && plural == "one" => {
+                        currency.3.to_owned()
+                    }
+                    (CurrencyDisplay::Name, Some(currency)) if name => currency.4.to_owned(),
+                    _ => code,
+                };
+
+                // The sign goes before the currency symbol, and the accounting format replaces
+                // the minus sign with parentheses around the whole number.
+                let (mut sign, number): (Vec<_>, Vec<_>) = number
+                    .into_iter()
+                    .partition(|(kind, _)| *kind == "minusSign" || *kind == "plusSign");
+                let accounting = self.currency_sign == CurrencySign::Accounting
+                    && sign.iter().any(|(kind, _)| *kind == "minusSign");
+                if accounting {
+                    sign.clear();
+                }
+
+                let mut parts = Vec::new();
+                if accounting {
+                    parts.push(literal("("));
+                }
+                parts.extend(sign);
+                if symbols.currency_after || name {
+                    parts.extend(number);
+                    parts.push(literal(if name { " " } else { "\u{a0}" }));
+                    parts.push(("currency", display));
+                } else {
+                    let separator = display.chars().last().map_or(false, char::is_alphabetic);
+                    parts.push(("currency", display));
+                    if separator {
+                        parts.push(literal("\u{a0}"));
+                    }
+                    parts.extend(number);
+                }
+                if accounting {
+                    parts.push(literal(")"));
+                }
+                parts
+            }
+            Style::Unit => {
+                let unit = self
+                    .unit
+                    .as_ref()
+                    .map(JsString::to_std_string_escaped)
+                    .unwrap_or_default();
+                // The long names of the units are only known in English, so the other languages
+                // display their short abbreviations.
+                let display = match self.unit_display {
+                    Display::Long if symbols.language != "en" => Display::Short,
+                    display => display,
+                };
+                let pattern = Self::unit_pattern(&unit, display, plural);
+                let (before, after) = pattern
+                    .split_once("{0}")
+                    .expect("unit patterns must have a placeholder");
+                let mut parts = Vec::new();
+                Self::push_unit_text(&mut parts, before);
+                parts.extend(number);
+                Self::push_unit_text(&mut parts, after);
+                parts
+            }
+        }
+    }
+
+    /// Gets the display pattern of a unit, where `{0}` is the placeholder of the number.
+    fn unit_pattern(unit: &str, display: Display, plural: &str) -> String {
+        let index = match (display, plural) {
+            (Display::Short, "one") => 0,
+            (Display::Short, _) => 1,
+            (Display::Narrow, _) => 2,
+            (Display::Long, "one") => 3,
+            (Display::Long, _) => 4,
+        };
+        if let Some((_, patterns)) = UNITS
+            .iter()
+            .chain(&COMPOUND_UNITS)
+            .find(|(id, _)| *id == unit)
+        {
+            return patterns[index].to_owned();
+        }
+
+        // A compound unit is displayed with the numerator and the singular denominator.
+        let (numerator, denominator) = unit
+            .split_once("-per-")
+            .expect("a unit must be a single or a compound unit");
+        let numerator = unit_patterns(numerator).expect("the numerator must be sanctioned");
+        let denominator = unit_patterns(denominator).expect("the denominator must be sanctioned");
+        let denominator_name = |pattern: &str| pattern.replace("{0}", "").trim().to_owned();
+        match display {
+            Display::Long => format!(
+                "{} per {}",
+                numerator[index],
+                denominator_name(denominator[3])
+            ),
+            Display::Short | Display::Narrow => {
+                format!("{}/{}", numerator[index], denominator_name(denominator[0]))
+            }
+        }
+    }
+
+    /// Adds the text of a unit pattern around the number, as a literal for the spaces and as a
+    /// unit for the rest.
+    fn push_unit_text(parts: &mut Vec<Part>, text: &str) {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            if !text.is_empty() {
+                parts.push(("literal", text.to_owned()));
+            }
+            return;
+        }
+        let start = text.len() - text.trim_start().len();
+        let end = text.trim_end().len();
+        if start > 0 {
+            parts.push(("literal", text[..start].to_owned()));
+        }
+        parts.push(("unit", trimmed.to_owned()));
+        if end < text.len() {
+            parts.push(("literal", text[end..].to_owned()));
+        }
+    }
+
+    /// Abstract operation `FormatNumeric ( numberFormat, x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    pub(crate) fn format(&self, x: &Numeric) -> JsString {
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        self.partition_number_pattern(x)
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<String>()
+            .into()
+    }
+
+    /// Gets the `Intl.NumberFormat` object of the `this` value.
+    fn this_number_format(this: &JsValue) -> JsResult<JsObject> {
+        // 1. Let nf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set nf to ? UnwrapNumberFormat(nf).
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        this.as_object()
+            .filter(|object| object.borrow().as_number_format().is_some())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("this value must be an Intl.NumberFormat object")
+                    .into()
+            })
+    }
+
+    /// `Intl.NumberFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %NumberFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            &Self::available_locales(),
+            requested_locales,
+            args.get_or_undefined(1),
+            context,
+        )
+    }
+
+    /// `get Intl.NumberFormat.prototype.format`
+    ///
+    /// Returns a function bound to the number format, that formats a number.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3. Let nf be the this value, and perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let number_format = Self::this_number_format(this)?;
+
+        // 4. If nf.[[BoundFormat]] is undefined, then
+        let bound_format = number_format
+            .borrow()
+            .as_number_format()
+            .and_then(|nf| nf.bound_format.clone());
+        if let Some(bound_format) = bound_format {
+            // 5. Return nf.[[BoundFormat]].
+            return Ok(bound_format.into());
+        }
+
+        // a. Let F be a new built-in function object as defined in Number Format Functions (15.5.2).
+        // b. Set F.[[NumberFormat]] to nf.
+        let bound_format = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, number_format, context| {
+                // 1. Let nf be F.[[NumberFormat]].
+                // 2. Assert: Type(nf) is Object and nf has an [[InitializedNumberFormat]] internal slot.
+                // 3. If value is not provided, let value be undefined.
+                // 4. Let x be ? ToIntlMathematicalValue(value).
+                let x = args.get_or_undefined(0).to_numeric(context)?;
+
+                // 5. Return ? FormatNumeric(nf, x).
+                let number_format = number_format.borrow();
+                let number_format = number_format
+                    .as_number_format()
+                    .expect("the captured object must be an Intl.NumberFormat object");
+                Ok(number_format.format(&x).into())
+            },
+            number_format.clone(),
+        )
+        .length(1)
+        .build();
+
+        // c. Set nf.[[BoundFormat]] to F.
+        if let Some(nf) = number_format.borrow_mut().as_number_format_mut() {
+            nf.bound_format = Some(bound_format.clone().into());
+        }
+
+        // 5. Return nf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// `Intl.NumberFormat.prototype.formatToParts ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formattoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let number_format = Self::this_number_format(this)?;
+
+        // 3. Let x be ? ToIntlMathematicalValue(value).
+        let x = args.get_or_undefined(0).to_numeric(context)?;
+
+        // 4. Return ? FormatNumericToParts(nf, x).
+        let parts = number_format
+            .borrow()
+            .as_number_format()
+            .expect("checked above")
+            .partition_number_pattern(&x);
+
+        // FormatNumericToParts ( numberFormat, x )
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+        //     e. Increment n by 1.
+        let parts = parts
+            .into_iter()
+            .map(|(kind, value)| {
+                let part = context.construct_object();
+                part.create_data_property_or_throw("type", kind, context)
+                    .expect("defining a property of a new object cannot fail");
+                part.create_data_property_or_throw("value", value, context)
+                    .expect("defining a property of a new object cannot fail");
+                part.into()
+            })
+            .collect::<Vec<JsValue>>();
+
+        // 5. Return result.
+        Ok(Array::create_array_from_list(parts, context).into())
+    }
+
+    /// `Intl.NumberFormat.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2-3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let number_format = Self::this_number_format(this)?;
+        let nf = number_format
+            .borrow()
+            .as_number_format()
+            .expect("checked above")
+            .clone();
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 11, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of nf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut properties = vec![
+            ("locale", nf.locale.clone().into()),
+            ("numberingSystem", nf.numbering_system.clone().into()),
+            ("style", nf.style.as_str().into()),
+        ];
+        if let Some(currency) = nf.currency.clone() {
+            properties.push(("currency", currency.into()));
+            properties.push(("currencyDisplay", nf.currency_display.as_str().into()));
+            properties.push(("currencySign", nf.currency_sign.as_str().into()));
+        }
+        if let Some(unit) = nf.unit.clone() {
+            properties.push(("unit", unit.into()));
+            properties.push(("unitDisplay", nf.unit_display.as_str().into()));
+        }
+        nf.digit_options.resolved_options(&mut properties);
+        properties.push(("useGrouping", nf.use_grouping.into()));
+        properties.push(("notation", nf.notation.as_str().into()));
+        if nf.notation == Notation::Compact {
+            properties.push(("compactDisplay", nf.compact_display.as_str().into()));
+        }
+        properties.push(("signDisplay", nf.sign_display.as_str().into()));
+
+        let options = context.construct_object();
+        for (key, value) in properties {
+            options
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining a property of a new object cannot fail");
+        }
+
+        // 6. Return options.
+        options.conv::<JsValue>().pipe(Ok)
+    }
+}
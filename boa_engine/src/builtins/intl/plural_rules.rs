@@ -0,0 +1,446 @@
+//! This module implements the global `Intl.PluralRules` object.
+//!
+//! `Intl.PluralRules` is a built-in object that enables plural-sensitive formatting and
+//! plural-related language rules.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#pluralrules-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules
+
+use super::{
+    canonicalize_locale_list, coerce_options_to_object, get_option, get_string_option,
+    number_format::{Decimal, DigitOptions},
+    resolve_locale, supported_locales, DateTimeFormatRecord, GetOptionType, LocaleDataRecord,
+    StringOption,
+};
+use crate::{
+    builtins::{Array, JsArgs},
+    context::{intrinsics::StandardConstructors, BoaProvider},
+    error::JsNativeError,
+    js_string,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction, JsObject,
+        ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_locid::Locale;
+use icu_plurals::{PluralCategory, PluralOperands, PluralRuleType};
+use rustc_hash::FxHashMap;
+use std::fmt;
+
+/// The `type` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PluralType {
+    Cardinal,
+    Ordinal,
+}
+
+impl StringOption for PluralType {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "cardinal" => Self::Cardinal,
+            "ordinal" => Self::Ordinal,
+            _ => return None,
+        })
+    }
+}
+
+impl PluralType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Cardinal => "cardinal",
+            Self::Ordinal => "ordinal",
+        }
+    }
+
+    fn rule_type(self) -> PluralRuleType {
+        match self {
+            Self::Cardinal => PluralRuleType::Cardinal,
+            Self::Ordinal => PluralRuleType::Ordinal,
+        }
+    }
+}
+
+/// Gets the name of a plural category.
+fn category_name(category: PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
+
+/// Gets the locale and each of its prefixes, from the longest to the shortest, without their
+/// extensions.
+fn locale_fallbacks(locale: &Locale) -> impl Iterator<Item = String> {
+    let locale = locale.id.to_string();
+    let mut candidate = Some(locale);
+    std::iter::from_fn(move || {
+        let current = candidate.take()?;
+        candidate = current.rfind('-').map(|index| current[..index].to_owned());
+        Some(current)
+    })
+}
+
+/// JavaScript `Intl.PluralRules` object.
+#[derive(Trace, Finalize)]
+pub struct PluralRules {
+    locale: JsString,
+    #[unsafe_ignore_trace]
+    plural_type: PluralType,
+    #[unsafe_ignore_trace]
+    digit_options: DigitOptions,
+    #[unsafe_ignore_trace]
+    rules: icu_plurals::PluralRules,
+}
+
+impl fmt::Debug for PluralRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluralRules")
+            .field("locale", &self.locale)
+            .field("plural_type", &self.plural_type)
+            .field("digit_options", &self.digit_options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PluralRules {
+    const NAME: &'static str = "PluralRules";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plural_rules().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.PluralRules",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::select, "select", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .build()
+    }
+
+    /// Gets the locales of `requested_locales`, or their prefixes, that have plural rules in
+    /// the icu data provider.
+    ///
+    /// The plural rules come from the provider of the context, so the available locales of
+    /// `Intl.PluralRules` are the locales that the provider has data for.
+    fn available_locales(
+        requested_locales: &[Locale],
+        provider: &dyn BoaProvider,
+    ) -> Vec<JsString> {
+        requested_locales
+            .iter()
+            .flat_map(locale_fallbacks)
+            .filter(|candidate| {
+                candidate.parse::<Locale>().map_or(false, |locale| {
+                    icu_plurals::PluralRules::try_new_cardinal(locale, provider).is_ok()
+                })
+            })
+            .map(JsString::from)
+            .collect()
+    }
+
+    /// Loads the plural rules of the longest prefix of `locale` that has data in the icu data
+    /// provider, or of the root locale.
+    fn load_rules(
+        locale: &Locale,
+        plural_type: PluralType,
+        provider: &dyn BoaProvider,
+    ) -> JsResult<icu_plurals::PluralRules> {
+        locale_fallbacks(locale)
+            .filter_map(|candidate| candidate.parse::<Locale>().ok())
+            .chain(std::iter::once(Locale::UND))
+            .find_map(|candidate| {
+                icu_plurals::PluralRules::try_new(candidate, provider, plural_type.rule_type()).ok()
+            })
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message(format!("there are no plural rules for the locale {locale}"))
+                    .into()
+            })
+    }
+
+    /// The `Intl.PluralRules` constructor is the `%PluralRules%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/PluralRules
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Intl.PluralRules constructor cannot be invoked without 'new'")
+                .into());
+        }
+
+        // 2. Let pluralRules be ? OrdinaryCreateFromConstructor(NewTarget, "%PluralRules.prototype%", « ... »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::plural_rules,
+            context,
+        )?;
+
+        // 3. Return ? InitializePluralRules(pluralRules, locales, options).
+        let plural_rules =
+            Self::initialize(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::plural_rules(plural_rules)).into())
+    }
+
+    /// Abstract operation `InitializePluralRules ( pluralRules, locales, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializepluralrules
+    fn initialize(locales: &JsValue, options: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(std::slice::from_ref(locales), context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[localeMatcher]] to matcher.
+        let matcher = get_option(
+            &options,
+            "localeMatcher",
+            &GetOptionType::String,
+            &[js_string!("lookup"), js_string!("best fit")],
+            &js_string!("best fit").into(),
+            context,
+        )?;
+        let opt = DateTimeFormatRecord {
+            locale_matcher: matcher
+                .as_string()
+                .cloned()
+                .unwrap_or_else(|| js_string!("best fit")),
+            properties: FxHashMap::default(),
+        };
+
+        // 6. Let t be ? GetOption(options, "type", string, « "cardinal", "ordinal" », "cardinal").
+        // 7. Set pluralRules.[[Type]] to t.
+        let plural_type =
+            get_string_option(&options, "type", context)?.unwrap_or(PluralType::Cardinal);
+
+        // 8. Perform ? SetNumberFormatDigitOptions(pluralRules, options, 0, 3, "standard").
+        let digit_options = DigitOptions::from_options(&options, 0, 3, false, context)?;
+
+        // 9. Let localeData be %PluralRules%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%PluralRules%.[[AvailableLocales]], requestedLocales, opt, %PluralRules%.[[RelevantExtensionKeys]], localeData).
+        let available_locales =
+            Self::available_locales(&requested_locales, context.icu().provider());
+        let requested_locales = requested_locales
+            .iter()
+            .map(|locale| JsString::from(locale.to_string()))
+            .collect::<Vec<_>>();
+        let r = resolve_locale(
+            &available_locales,
+            &requested_locales,
+            &opt,
+            &[],
+            &LocaleDataRecord::default(),
+            context,
+        );
+
+        // 11. Set pluralRules.[[Locale]] to r.[[locale]].
+        let data_locale = r
+            .data_locale
+            .to_std_string_escaped()
+            .parse::<Locale>()
+            .unwrap_or(Locale::UND);
+        let rules = Self::load_rules(&data_locale, plural_type, context.icu().provider())?;
+
+        // 12. Return pluralRules.
+        Ok(Self {
+            locale: r.locale,
+            plural_type,
+            digit_options,
+            rules,
+        })
+    }
+
+    /// Abstract operation `ResolvePlural ( pluralRules, n )`
+    ///
+    /// Gets the plural category of the number `n`, after rounding it with the digit options.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-resolveplural
+    fn resolve_plural(&self, n: f64) -> &'static str {
+        // 1. Assert: Type(pluralRules) is Object.
+        // 2. Assert: pluralRules has an [[InitializedPluralRules]] internal slot.
+        // 3. Assert: Type(n) is Number.
+        // 4. If n is not a finite Number, then
+        if !n.is_finite() {
+            // a. Return "other".
+            return "other";
+        }
+
+        // 5. Let locale be pluralRules.[[Locale]].
+        // 6. Let type be pluralRules.[[Type]].
+        // 7. Let res be ! FormatNumericToString(pluralRules, n).
+        // 8. Let s be res.[[FormattedString]].
+        let (_, integer, fraction) = self.digit_options.format(&Decimal::from_f64(n));
+        let s = if fraction.is_empty() {
+            integer
+        } else {
+            format!("{integer}.{fraction}")
+        };
+
+        // 9. Let operands be ! GetOperands(s).
+        // 10. Let p be ! PluralRuleSelect(locale, type, n, operands).
+        // 11. Return p.
+        s.parse::<PluralOperands>().map_or("other", |operands| {
+            category_name(self.rules.select(operands))
+        })
+    }
+
+    /// Gets the `Intl.PluralRules` object of the `this` value.
+    fn this_plural_rules(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.borrow().as_plural_rules().is_some())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("this value must be an Intl.PluralRules object")
+                    .into()
+            })
+    }
+
+    /// `Intl.PluralRules.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %PluralRules%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args, context)?;
+        let available_locales =
+            Self::available_locales(&requested_locales, context.icu().provider());
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            &available_locales,
+            requested_locales,
+            args.get_or_undefined(1),
+            context,
+        )
+    }
+
+    /// `Intl.PluralRules.prototype.select ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.select
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/select
+    fn select(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let plural_rules = Self::this_plural_rules(this)?;
+
+        // 3. Let n be ? ToNumber(value).
+        let n = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Return ! ResolvePlural(pr, n).
+        let category = plural_rules
+            .borrow()
+            .as_plural_rules()
+            .expect("checked above")
+            .resolve_plural(n);
+        Ok(category.into())
+    }
+
+    /// `Intl.PluralRules.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let plural_rules = Self::this_plural_rules(this)?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 13, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of pr's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let (mut properties, categories) = {
+            let plural_rules = plural_rules.borrow();
+            let pr = plural_rules.as_plural_rules().expect("checked above");
+            let mut properties = vec![
+                ("locale", pr.locale.clone().into()),
+                ("type", pr.plural_type.as_str().into()),
+            ];
+            pr.digit_options.resolved_options(&mut properties);
+
+            // 5. Let pluralCategories be a List of Strings containing all possible results of
+            //    PluralRuleSelect for the selected locale pr.[[Locale]].
+            let categories = pr
+                .rules
+                .categories()
+                .map(|category| category_name(category).into())
+                .collect::<Vec<JsValue>>();
+            (properties, categories)
+        };
+
+        // 6. Perform ! CreateDataProperty(options, "pluralCategories", CreateArrayFromList(pluralCategories)).
+        properties.push((
+            "pluralCategories",
+            Array::create_array_from_list(categories, context).into(),
+        ));
+
+        let options = context.construct_object();
+        for (key, value) in properties {
+            options
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining a property of a new object cannot fail");
+        }
+
+        // 7. Return options.
+        Ok(options.into())
+    }
+}
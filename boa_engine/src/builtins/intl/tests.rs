@@ -5,6 +5,7 @@ use crate::{
         get_number_option, get_option, insert_unicode_extension_and_canonicalize, lookup_matcher,
         resolve_locale, unicode_extension_components, DateTimeFormatRecord, GetOptionType,
     },
+    forward,
     object::JsObject,
     Context, JsString, JsValue,
 };
@@ -79,7 +80,7 @@ fn lookup_match() {
 
     let matcher = lookup_matcher(&available_locales, &requested_locales, &canonicalizer);
     assert_eq!(matcher.locale, "fr-FR");
-    assert_eq!(matcher.extension, "u-hc-h12");
+    assert_eq!(matcher.extension, "-u-hc-h12");
 
     // available: [es-ES], requested: [es-ES]
     let available_locales = vec![JsString::new("es-ES")];
@@ -95,24 +96,24 @@ fn insert_unicode_ext() {
     let provider = icu_testdata::get_provider();
     let canonicalizer =
         LocaleCanonicalizer::new(&provider).expect("Could not create canonicalizer");
-    let locale = JsString::new("hu-HU");
-    let ext = JsString::empty();
+    let locale = "hu-HU";
+    let ext = "";
     assert_eq!(
-        insert_unicode_extension_and_canonicalize(&locale, &ext, &canonicalizer),
-        locale
+        insert_unicode_extension_and_canonicalize(locale, ext, &canonicalizer),
+        JsString::new(locale)
     );
 
-    let locale = JsString::new("hu-HU");
-    let ext = JsString::new("-u-hc-h12");
+    let locale = "hu-HU";
+    let ext = "-u-hc-h12";
     assert_eq!(
-        insert_unicode_extension_and_canonicalize(&locale, &ext, &canonicalizer),
+        insert_unicode_extension_and_canonicalize(locale, ext, &canonicalizer),
         JsString::new("hu-HU-u-hc-h12")
     );
 
-    let locale = JsString::new("hu-HU-x-PRIVATE");
-    let ext = JsString::new("-u-hc-h12");
+    let locale = "hu-HU-x-PRIVATE";
+    let ext = "-u-hc-h12";
     assert_eq!(
-        insert_unicode_extension_and_canonicalize(&locale, &ext, &canonicalizer),
+        insert_unicode_extension_and_canonicalize(locale, ext, &canonicalizer),
         JsString::new("hu-HU-u-hc-h12-x-private")
     );
 }
@@ -543,3 +544,176 @@ fn to_date_time_opts() {
         Ok(numeric_jsstring)
     );
 }
+
+#[test]
+fn number_format() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en').format(1234567.891)"
+        ),
+        "\"1,234,567.891\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('de').format(1234567.891)"
+        ),
+        "\"1.234.567,891\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { style: 'percent' }).format(0.256)"
+        ),
+        "\"26%\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'USD' }).format(-12.5)"
+        ),
+        "\"-$12.50\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'JPY' }).format(1234.5)"
+        ),
+        "\"¥1,235\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { style: 'unit', unit: 'kilometer-per-hour' }).format(50)"
+        ),
+        "\"50 km/h\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en', { notation: 'compact' }).format(1234567)"
+        ),
+        "\"1.2M\""
+    );
+    assert_eq!(
+        forward(&mut context, "(1234.5).toLocaleString('en')"),
+        "\"1,234.5\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('en', { style: 'currency' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('de', { style: 'currency', currency: 'EUR', currencyDisplay: 'narrowSymbol' })
+                .format(1234.5) === '1.234,50\\u00a0€'"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('de', { style: 'currency', currency: 'EUR' })
+                .format(1234.5) === '1.234,50\\u00a0EUR'"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('de', { style: 'currency', currency: 'EUR', currencyDisplay: 'name' })
+                .format(1) === '1,00\\u00a0EUR'"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(1).toLocaleString('fr', { style: 'unit', unit: 'meter', unitDisplay: 'long' })"
+        ),
+        "\"1 m\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.NumberFormat.supportedLocalesOf(['ja', 'zh', 'ru', 'fr']).join()"
+        ),
+        "\"fr\""
+    );
+}
+
+#[test]
+fn plural_rules() {
+    let mut context = Context::default();
+
+    forward(
+        &mut context,
+        "var cardinal = new Intl.PluralRules('en');
+         var ordinal = new Intl.PluralRules('en', { type: 'ordinal' });",
+    );
+    assert_eq!(forward(&mut context, "cardinal.select(1)"), "\"one\"");
+    assert_eq!(forward(&mut context, "cardinal.select(2)"), "\"other\"");
+    assert_eq!(forward(&mut context, "ordinal.select(2)"), "\"two\"");
+    assert_eq!(forward(&mut context, "ordinal.select(23)"), "\"few\"");
+    assert_eq!(forward(&mut context, "ordinal.select(11)"), "\"other\"");
+    assert_eq!(
+        forward(&mut context, "ordinal.resolvedOptions().type"),
+        "\"ordinal\""
+    );
+}
+
+#[test]
+fn collator() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "['b', 'a', 'C', 'á'].sort(new Intl.Collator('en').compare).join()"
+        ),
+        "\"a,á,b,C\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('en', { sensitivity: 'base' }).compare('a', 'Á')"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('en', { sensitivity: 'accent' }).compare('a', 'á')"
+        ),
+        "-1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('en', { numeric: true }).compare('item 10', 'item 9')"
+        ),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('en-u-kn').resolvedOptions().numeric"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "'a'.localeCompare('B', 'en')"), "-1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.Collator.supportedLocalesOf(['ja', 'zh', 'ru', 'es', 'de']).join()"
+        ),
+        "\"de\""
+    );
+}
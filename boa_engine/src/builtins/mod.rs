@@ -5,12 +5,14 @@ pub mod array_buffer;
 pub mod async_function;
 pub mod async_generator;
 pub mod async_generator_function;
+pub mod atomics;
 pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
 pub mod error;
 pub mod eval;
+pub mod finalization_registry;
 pub mod function;
 pub mod generator;
 pub mod generator_function;
@@ -28,10 +30,16 @@ pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
+pub mod shadow_realm;
+pub mod shared_array_buffer;
 pub mod string;
 pub mod symbol;
+pub mod temporal;
 pub mod typed_array;
 pub mod undefined;
+pub mod weak_map;
+pub mod weak_ref;
+pub mod weak_set;
 
 #[cfg(feature = "console")]
 pub mod console;
@@ -42,6 +50,7 @@ pub mod intl;
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
     async_function::AsyncFunction,
+    atomics::Atomics,
     bigint::BigInt,
     boolean::Boolean,
     dataview::DataView,
@@ -51,6 +60,7 @@ pub(crate) use self::{
         UriError,
     },
     eval::Eval,
+    finalization_registry::FinalizationRegistry,
     function::BuiltInFunctionObject,
     global_this::GlobalThis,
     infinity::Infinity,
@@ -68,13 +78,19 @@ pub(crate) use self::{
     regexp::RegExp,
     set::set_iterator::SetIterator,
     set::Set,
+    shadow_realm::ShadowRealm,
+    shared_array_buffer::SharedArrayBuffer,
     string::String,
     symbol::Symbol,
+    temporal::Temporal,
     typed_array::{
         BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
         Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
     },
     undefined::Undefined,
+    weak_map::WeakMap,
+    weak_ref::WeakRef,
+    weak_set::WeakSet,
 };
 
 use crate::{
@@ -156,6 +172,8 @@ pub fn init(context: &mut Context) {
         Array,
         Proxy,
         ArrayBuffer,
+        SharedArrayBuffer,
+        Atomics,
         BigInt,
         Boolean,
         Date,
@@ -164,6 +182,12 @@ pub fn init(context: &mut Context) {
         Number,
         Eval,
         Set,
+        WeakMap,
+        WeakSet,
+        WeakRef,
+        FinalizationRegistry,
+        ShadowRealm,
+        Temporal,
         String,
         RegExp,
         TypedArray,
@@ -220,7 +244,6 @@ pub trait JsArgs {
 
 impl JsArgs for [JsValue] {
     fn get_or_undefined(&self, index: usize) -> &JsValue {
-        const UNDEFINED: &JsValue = &JsValue::Undefined;
-        self.get(index).unwrap_or(UNDEFINED)
+        self.get(index).unwrap_or_else(|| JsValue::undefined_ref())
     }
 }
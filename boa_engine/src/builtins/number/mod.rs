@@ -16,17 +16,21 @@
 use crate::{
     builtins::{string::is_trimmable_whitespace, BuiltIn, JsArgs},
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsObject, ObjectData,
     },
     property::Attribute,
-    value::{AbstractRelation, IntegerOrInfinity, JsValue},
+    value::{AbstractRelation, IntegerOrInfinity, JsValue, JsVariant},
     Context, JsResult,
 };
 use boa_profiler::Profiler;
 use num_traits::{float::FloatCore, Num};
 
+#[cfg(feature = "intl")]
+use crate::{builtins::intl::number_format::NumberFormat, value::Numeric};
+
 mod conversions;
 
 pub(crate) use conversions::{f64_to_int32, f64_to_uint32};
@@ -194,11 +198,15 @@ impl Number {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-thisnumbervalue
-    fn this_number_value(value: &JsValue, context: &mut Context) -> JsResult<f64> {
+    fn this_number_value(value: &JsValue) -> JsResult<f64> {
         value
             .as_number()
             .or_else(|| value.as_object().and_then(|obj| obj.borrow().as_number()))
-            .ok_or_else(|| context.construct_type_error("'this' is not a number"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a number")
+                    .into()
+            })
     }
 
     /// `Number.prototype.toExponential( [fractionDigits] )`
@@ -218,11 +226,11 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let x be ? thisNumberValue(this value).
-        let this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this)?;
         let precision = match args.get(0) {
-            None | Some(JsValue::Undefined) => None,
             // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
-            Some(n) => Some(n.to_integer_or_infinity(context)?),
+            Some(n) if !n.is_undefined() => Some(n.to_integer_or_infinity(context)?),
+            _ => None,
         };
         // 4. If x is not finite, return ! Number::toString(x).
         if !this_num.is_finite() {
@@ -238,8 +246,9 @@ impl Number {
                 f64_to_exponential_with_precision(this_num, precision as usize)
             }
             _ => {
-                return context
-                    .throw_range_error("toExponential() argument must be between 0 and 100")
+                return Err(JsNativeError::range()
+                    .with_message("toExponential() argument must be between 0 and 100")
+                    .into())
             }
         };
         Ok(JsValue::new(this_str_num))
@@ -262,7 +271,7 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let this_num be ? thisNumberValue(this value).
-        let this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this)?;
 
         // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
         // 3. Assert: If fractionDigits is undefined, then f is 0.
@@ -273,7 +282,8 @@ impl Number {
             .as_integer()
             .filter(|i| (0..=100).contains(i))
             .ok_or_else(|| {
-                context.construct_range_error("toFixed() digits argument must be between 0 and 100")
+                JsNativeError::range()
+                    .with_message("toFixed() digits argument must be between 0 and 100")
             })? as usize;
 
         // 6. If x is not finite, return ! Number::toString(x).
@@ -294,26 +304,58 @@ impl Number {
     ///
     /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
     ///
-    /// Note that while this technically conforms to the Ecma standard, it does no actual
+    /// With the `intl` feature, the number is formatted by an `Intl.NumberFormat`. Otherwise,
+    /// this technically conforms to the Ecma standard, but it does no actual
     /// internationalization logic.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [ECMAScript Internationalization reference][spec-402]
     ///  - [MDN documentation][mdn]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
+    /// [spec-402]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
     #[allow(clippy::wrong_self_convention)]
+    #[cfg(not(feature = "intl"))]
     pub(crate) fn to_locale_string(
         this: &JsValue,
         _: &[JsValue],
-        context: &mut Context,
+        _: &mut Context,
     ) -> JsResult<JsValue> {
-        let this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this)?;
         let this_str_num = this_num.to_string();
         Ok(JsValue::new(this_str_num))
     }
 
+    /// `Number.prototype.toLocaleString( [locales [, options]] )`
+    ///
+    /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
+    ///
+    /// More information:
+    ///  - [ECMAScript Internationalization reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(feature = "intl")]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisNumberValue(this value).
+        let x = Self::this_number_value(this)?;
+
+        // 2. Let numberFormat be ? Construct(%NumberFormat%, « locales, options »).
+        let number_format =
+            NumberFormat::initialize(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        // 3. Return ? FormatNumeric(numberFormat, x).
+        Ok(number_format.format(&Numeric::Number(x)).into())
+    }
+
     /// `flt_str_to_exp` - used in `to_precision`
     ///
     /// This function traverses a string representing a number,
@@ -422,7 +464,7 @@ impl Number {
         let precision = args.get_or_undefined(0);
 
         // 1 & 6
-        let mut this_num = Self::this_number_value(this, context)?;
+        let mut this_num = Self::this_number_value(this)?;
         // 2
         if precision.is_undefined() {
             return Self::to_string(this, &[], context);
@@ -440,9 +482,9 @@ impl Number {
             IntegerOrInfinity::Integer(x) if (1..=100).contains(&x) => x as usize,
             _ => {
                 // 5
-                return context.throw_range_error(
-                    "precision must be an integer at least 1 and no greater than 100",
-                );
+                return Err(JsNativeError::range()
+                    .with_message("precision must be an integer at least 1 and no greater than 100")
+                    .into());
             }
         };
         let precision_i32 = precision as i32;
@@ -672,7 +714,7 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let x be ? thisNumberValue(this value).
-        let x = Self::this_number_value(this, context)?;
+        let x = Self::this_number_value(this)?;
 
         let radix = args.get_or_undefined(0);
         let radix_number = if radix.is_undefined() {
@@ -686,9 +728,8 @@ impl Number {
                 // 4. If radixNumber < 2 or radixNumber > 36, throw a RangeError exception.
                 .filter(|i| (2..=36).contains(i))
                 .ok_or_else(|| {
-                    context.construct_range_error(
-                        "radix must be an integer at least 2 and no greater than 36",
-                    )
+                    JsNativeError::range()
+                        .with_message("radix must be an integer at least 2 and no greater than 36")
                 })?
         } as u8;
 
@@ -729,12 +770,8 @@ impl Number {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.valueof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/valueOf
-    pub(crate) fn value_of(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        Ok(JsValue::new(Self::this_number_value(this, context)?))
+    pub(crate) fn value_of(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(JsValue::new(Self::this_number_value(this)?))
     }
 
     /// Builtin javascript 'parseInt(str, radix)' function.
@@ -758,7 +795,7 @@ impl Number {
     ) -> JsResult<JsValue> {
         if let (Some(val), radix) = (args.get(0), args.get_or_undefined(1)) {
             // 1. Let inputString be ? ToString(string).
-            let input_string = val.to_string(context)?;
+            let input_string = val.to_string(context)?.to_std_string_escaped();
 
             // 2. Let S be ! TrimString(inputString, start).
             let mut var_s = input_string.trim_start_matches(is_trimmable_whitespace);
@@ -884,7 +921,7 @@ impl Number {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         if let Some(val) = args.get(0) {
-            let input_string = val.to_string(context)?;
+            let input_string = val.to_string(context)?.to_std_string_escaped();
             let s = input_string.trim_start_matches(is_trimmable_whitespace);
             let s_prefix_lower = s.chars().take(4).collect::<String>().to_ascii_lowercase();
 
@@ -992,9 +1029,9 @@ impl Number {
         _ctx: &mut Context,
     ) -> JsResult<JsValue> {
         Ok(JsValue::new(if let Some(val) = args.get(0) {
-            match val {
-                JsValue::Integer(_) => true,
-                JsValue::Rational(number) => number.is_finite(),
+            match val.variant() {
+                JsVariant::Integer(_) => true,
+                JsVariant::Rational(number) => number.is_finite(),
                 _ => false,
             }
         } else {
@@ -1042,7 +1079,7 @@ impl Number {
         _ctx: &mut Context,
     ) -> JsResult<JsValue> {
         Ok(JsValue::new(
-            if let Some(&JsValue::Rational(number)) = args.get(0) {
+            if let Some(JsVariant::Rational(number)) = args.first().map(JsValue::variant) {
                 number.is_nan()
             } else {
                 false
@@ -1070,9 +1107,9 @@ impl Number {
         args: &[JsValue],
         _ctx: &mut Context,
     ) -> JsResult<JsValue> {
-        Ok(JsValue::new(match args.get(0) {
-            Some(JsValue::Integer(_)) => true,
-            Some(JsValue::Rational(number)) if Self::is_float_integer(*number) => {
+        Ok(JsValue::new(match args.first().map(JsValue::variant) {
+            Some(JsVariant::Integer(_)) => true,
+            Some(JsVariant::Rational(number)) if Self::is_float_integer(number) => {
                 number.abs() <= Self::MAX_SAFE_INTEGER
             }
             _ => false,
@@ -1087,9 +1124,9 @@ impl Number {
     /// [spec]: https://tc39.es/ecma262/#sec-isinteger
     #[inline]
     pub(crate) fn is_integer(val: &JsValue) -> bool {
-        match val {
-            JsValue::Integer(_) => true,
-            JsValue::Rational(number) => Self::is_float_integer(*number),
+        match val.variant() {
+            JsVariant::Integer(_) => true,
+            JsVariant::Rational(number) => Self::is_float_integer(number),
             _ => false,
         }
     }
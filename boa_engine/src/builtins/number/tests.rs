@@ -121,8 +121,25 @@ fn to_locale_string() {
 
     assert_eq!(default_locale, "\"0\"");
     assert_eq!(small_locale, "\"5\"");
+    #[cfg(not(feature = "intl"))]
     assert_eq!(big_locale, "\"345600\"");
     assert_eq!(neg_locale, "\"-25\"");
+
+    // With the `intl` feature, the numbers are formatted with the grouping of the default locale.
+    #[cfg(feature = "intl")]
+    {
+        assert_eq!(
+            forward(
+                &mut context,
+                "big_locale === new Intl.NumberFormat().format(345600)"
+            ),
+            "true"
+        );
+        assert_eq!(
+            forward(&mut context, "Number(345600).toLocaleString('en')"),
+            "\"345,600\""
+        );
+    }
 }
 
 #[test]
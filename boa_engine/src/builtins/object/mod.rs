@@ -17,13 +17,16 @@ use super::Array;
 use crate::{
     builtins::{map, BuiltIn, JsArgs},
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    js_string,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         IntegrityLevel, JsObject, ObjectData, ObjectKind,
     },
     property::{Attribute, PropertyDescriptor, PropertyKey, PropertyNameKind},
+    string::utf16,
     symbol::WellKnownSymbols,
-    value::JsValue,
+    value::{JsValue, JsVariant},
     Context, JsResult, JsString,
 };
 use boa_profiler::Profiler;
@@ -175,18 +178,18 @@ impl Object {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. If Type(proto) is neither Object nor Null, return undefined.
-        let proto = match args.get_or_undefined(0) {
-            JsValue::Object(proto) => Some(proto.clone()),
-            JsValue::Null => None,
+        let proto = match args.get_or_undefined(0).variant() {
+            JsVariant::Object(proto) => Some(proto.clone()),
+            JsVariant::Null => None,
             _ => return Ok(JsValue::undefined()),
         };
 
         // 3. If Type(O) is not Object, return undefined.
-        let object = match this {
-            JsValue::Object(object) => object,
+        let object = match this.variant() {
+            JsVariant::Object(object) => object,
             _ => return Ok(JsValue::undefined()),
         };
 
@@ -195,7 +198,9 @@ impl Object {
 
         // 5. If status is false, throw a TypeError exception.
         if !status {
-            return context.throw_type_error("__proto__ called on null or undefined");
+            return Err(JsNativeError::typ()
+                .with_message("__proto__ called on null or undefined")
+                .into());
         }
 
         // 6. Return undefined.
@@ -224,8 +229,9 @@ impl Object {
 
         // 2. If IsCallable(getter) is false, throw a TypeError exception.
         if !getter.is_callable() {
-            return context
-                .throw_type_error("Object.prototype.__defineGetter__: Expecting function");
+            return Err(JsNativeError::typ()
+                .with_message("Object.prototype.__defineGetter__: Expecting function")
+                .into());
         }
 
         // 3. Let desc be PropertyDescriptor { [[Get]]: getter, [[Enumerable]]: true, [[Configurable]]: true }.
@@ -266,8 +272,9 @@ impl Object {
 
         // 2. If IsCallable(setter) is false, throw a TypeError exception.
         if !setter.is_callable() {
-            return context
-                .throw_type_error("Object.prototype.__defineSetter__: Expecting function");
+            return Err(JsNativeError::typ()
+                .with_message("Object.prototype.__defineSetter__: Expecting function")
+                .into());
         }
 
         // 3. Let desc be PropertyDescriptor { [[Set]]: setter, [[Enumerable]]: true, [[Configurable]]: true }.
@@ -389,16 +396,18 @@ impl Object {
         let prototype = args.get_or_undefined(0);
         let properties = args.get_or_undefined(1);
 
-        let obj = match prototype {
-            JsValue::Object(_) | JsValue::Null => JsObject::from_proto_and_data(
+        let obj = match prototype.variant() {
+            JsVariant::Object(_) | JsVariant::Null => JsObject::from_proto_and_data(
                 prototype.as_object().cloned(),
                 ObjectData::ordinary(),
             ),
             _ => {
-                return context.throw_type_error(format!(
-                    "Object prototype may only be an Object or null: {}",
-                    prototype.display()
-                ))
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "Object prototype may only be an Object or null: {}",
+                        prototype.display()
+                    ))
+                    .into())
             }
         };
 
@@ -563,9 +572,11 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.setprototypeof
     pub fn get_prototype_of(_: &JsValue, args: &[JsValue], ctx: &mut Context) -> JsResult<JsValue> {
         if args.is_empty() {
-            return ctx.throw_type_error(
-                "Object.getPrototypeOf: At least 1 argument required, but only 0 passed",
-            );
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "Object.getPrototypeOf: At least 1 argument required, but only 0 passed",
+                )
+                .into());
         }
 
         // 1. Let obj be ? ToObject(O).
@@ -584,10 +595,12 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.setprototypeof
     pub fn set_prototype_of(_: &JsValue, args: &[JsValue], ctx: &mut Context) -> JsResult<JsValue> {
         if args.len() < 2 {
-            return ctx.throw_type_error(format!(
-                "Object.setPrototypeOf: At least 2 arguments required, but only {} passed",
-                args.len()
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "Object.setPrototypeOf: At least 2 arguments required, but only {} passed",
+                    args.len()
+                ))
+                .into());
         }
 
         // 1. Set O to ? RequireObjectCoercible(O).
@@ -595,16 +608,21 @@ impl Object {
             .get(0)
             .cloned()
             .unwrap_or_default()
-            .require_object_coercible(ctx)?
+            .require_object_coercible()?
             .clone();
 
-        let proto = match args.get_or_undefined(1) {
-            JsValue::Object(obj) => Some(obj.clone()),
-            JsValue::Null => None,
+        let proto = args.get_or_undefined(1);
+        let proto = match proto.variant() {
+            JsVariant::Object(obj) => Some(obj.clone()),
+            JsVariant::Null => None,
             // 2. If Type(proto) is neither Object nor Null, throw a TypeError exception.
-            val => {
-                return ctx
-                    .throw_type_error(format!("expected an object or null, got {}", val.type_of()))
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "expected an object or null, got {}",
+                        proto.type_of()
+                    ))
+                    .into())
             }
         };
 
@@ -620,7 +638,9 @@ impl Object {
 
         // 5. If status is false, throw a TypeError exception.
         if !status {
-            return ctx.throw_type_error("can't set prototype of this object");
+            return Err(JsNativeError::typ()
+                .with_message("can't set prototype of this object")
+                .into());
         }
 
         // 6. Return O.
@@ -666,21 +686,17 @@ impl Object {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let object = args.get_or_undefined(0);
-        if let JsValue::Object(object) = object {
-            let key = args
-                .get(1)
-                .unwrap_or(&JsValue::Undefined)
-                .to_property_key(context)?;
-            let desc = args
-                .get(2)
-                .unwrap_or(&JsValue::Undefined)
-                .to_property_descriptor(context)?;
+        if let JsVariant::Object(object) = object.variant() {
+            let key = args.get_or_undefined(1).to_property_key(context)?;
+            let desc = args.get_or_undefined(2).to_property_descriptor(context)?;
 
             object.define_property_or_throw(key, desc, context)?;
 
             Ok(object.clone().into())
         } else {
-            context.throw_type_error("Object.defineProperty called on non-object")
+            Err(JsNativeError::typ()
+                .with_message("Object.defineProperty called on non-object")
+                .into())
         }
     }
 
@@ -700,12 +716,14 @@ impl Object {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let arg = args.get_or_undefined(0);
-        if let JsValue::Object(obj) = arg {
+        if let JsVariant::Object(obj) = arg.variant() {
             let props = args.get_or_undefined(1);
             object_define_properties(obj, props, context)?;
             Ok(arg.clone())
         } else {
-            context.throw_type_error("Expected an object")
+            Err(JsNativeError::typ()
+                .with_message("Expected an object")
+                .into())
         }
     }
 
@@ -748,7 +766,7 @@ impl Object {
         //  4. Let isArray be ? IsArray(O).
         //  5. If isArray is true, let builtinTag be "Array".
         let builtin_tag = if JsValue::from(o.clone()).is_array(context)? {
-            "Array"
+            utf16!("Array")
         } else {
             // 6. Else if O has a [[ParameterMap]] internal slot, let builtinTag be "Arguments".
             // 7. Else if O has a [[Call]] internal method, let builtinTag be "Function".
@@ -761,16 +779,16 @@ impl Object {
             // 14. Else, let builtinTag be "Object".
             let o = o.borrow();
             match o.kind() {
-                ObjectKind::Array => "Array",
-                ObjectKind::Arguments(_) => "Arguments",
-                ObjectKind::Function(_) => "Function",
-                ObjectKind::Error => "Error",
-                ObjectKind::Boolean(_) => "Boolean",
-                ObjectKind::Number(_) => "Number",
-                ObjectKind::String(_) => "String",
-                ObjectKind::Date(_) => "Date",
-                ObjectKind::RegExp(_) => "RegExp",
-                _ => "Object",
+                ObjectKind::Array => utf16!("Array"),
+                ObjectKind::Arguments(_) => utf16!("Arguments"),
+                ObjectKind::Function(_) => utf16!("Function"),
+                ObjectKind::Error(_) => utf16!("Error"),
+                ObjectKind::Boolean(_) => utf16!("Boolean"),
+                ObjectKind::Number(_) => utf16!("Number"),
+                ObjectKind::String(_) => utf16!("String"),
+                ObjectKind::Date(_) => utf16!("Date"),
+                ObjectKind::RegExp(_) => utf16!("RegExp"),
+                _ => utf16!("Object"),
             }
         };
 
@@ -778,10 +796,10 @@ impl Object {
         let tag = o.get(WellKnownSymbols::to_string_tag(), context)?;
 
         // 16. If Type(tag) is not String, set tag to builtinTag.
-        let tag_str = tag.as_string().map_or(builtin_tag, JsString::as_str);
+        let tag_str = tag.as_string().map_or(builtin_tag, JsString::as_slice);
 
         // 17. Return the string-concatenation of "[object ", tag, and "]".
-        Ok(format!("[object {tag_str}]").into())
+        Ok(js_string!(utf16!("[object "), tag_str, utf16!("]")).into())
     }
 
     /// `Object.prototype.toLocaleString( [ reserved1 [ , reserved2 ] ] )`
@@ -1012,7 +1030,9 @@ impl Object {
             let status = o.set_integrity_level(IntegrityLevel::Sealed, context)?;
             // 3. If status is false, throw a TypeError exception.
             if !status {
-                return context.throw_type_error("cannot seal object");
+                return Err(JsNativeError::typ()
+                    .with_message("cannot seal object")
+                    .into());
             }
         }
         // 1. If Type(O) is not Object, return O.
@@ -1057,7 +1077,9 @@ impl Object {
             let status = o.set_integrity_level(IntegrityLevel::Frozen, context)?;
             // 3. If status is false, throw a TypeError exception.
             if !status {
-                return context.throw_type_error("cannot freeze object");
+                return Err(JsNativeError::typ()
+                    .with_message("cannot freeze object")
+                    .into());
             }
         }
         // 1. If Type(O) is not Object, return O.
@@ -1106,7 +1128,9 @@ impl Object {
             let status = o.__prevent_extensions__(context)?;
             // 3. If status is false, throw a TypeError exception.
             if !status {
-                return context.throw_type_error("cannot prevent extensions");
+                return Err(JsNativeError::typ()
+                    .with_message("cannot prevent extensions")
+                    .into());
             }
         }
         // 1. If Type(O) is not Object, return O.
@@ -1202,7 +1226,7 @@ impl Object {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries
     pub fn from_entries(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Perform ? RequireObjectCoercible(iterable).
-        let iterable = args.get_or_undefined(0).require_object_coercible(context)?;
+        let iterable = args.get_or_undefined(0).require_object_coercible()?;
 
         // 2. Let obj be ! OrdinaryObjectCreate(%Object.prototype%).
         // 3. Assert: obj is an extensible ordinary object with no own properties.
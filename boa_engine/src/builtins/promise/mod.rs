@@ -10,6 +10,7 @@ use super::{iterable::IteratorRecord, JsArgs};
 use crate::{
     builtins::{Array, BuiltIn},
     context::intrinsics::StandardConstructors,
+    error::{JsError, JsNativeError},
     job::JobCallback,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -20,7 +21,7 @@ use crate::{
     value::JsValue,
     Context, JsResult,
 };
-use boa_gc::{Cell as GcCell, Finalize, Gc, Trace};
+use boa_gc::{custom_trace, Cell as GcCell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
 use std::{cell::Cell, rc::Rc};
 use tap::{Conv, Pipe};
@@ -37,8 +38,9 @@ macro_rules! if_abrupt_reject_promise {
     ($value:ident, $capability:expr, $context: expr) => {
         let $value = match $value {
             // 1. If value is an abrupt completion, then
-            Err(value) => {
+            Err(err) => {
                 // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+                let value = $crate::JsError::to_opaque(&err, $context);
                 $context.call(
                     &$capability.reject().clone().into(),
                     &JsValue::undefined(),
@@ -56,17 +58,32 @@ macro_rules! if_abrupt_reject_promise {
 
 pub(crate) use if_abrupt_reject_promise;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum PromiseState {
+/// The current state of a [`Promise`], with the value it was settled with, if any.
+///
+/// This combines the `[[PromiseState]]` and `[[PromiseResult]]` internal slots of the promise.
+#[derive(Debug, Clone, PartialEq, Finalize)]
+pub enum PromiseState {
+    /// The promise has not been settled yet.
     Pending,
-    Fulfilled,
-    Rejected,
+    /// The promise was fulfilled with the contained value.
+    Fulfilled(JsValue),
+    /// The promise was rejected with the contained reason.
+    Rejected(JsValue),
+}
+
+// Implemented manually instead of derived, since the derive also implements `Drop`, which would
+// prevent moving the value out of the state.
+unsafe impl Trace for PromiseState {
+    custom_trace!(this, {
+        match this {
+            Self::Fulfilled(value) | Self::Rejected(value) => mark(value),
+            Self::Pending => {}
+        }
+    });
 }
 
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct Promise {
-    promise_result: Option<JsValue>,
-    #[unsafe_ignore_trace]
     promise_state: PromiseState,
     promise_fulfill_reactions: Vec<ReactionRecord>,
     promise_reject_reactions: Vec<ReactionRecord>,
@@ -110,7 +127,9 @@ impl PromiseCapability {
 
         match c.as_constructor() {
             // 1. If IsConstructor(C) is false, throw a TypeError exception.
-            None => context.throw_type_error("PromiseCapability: expected constructor"),
+            None => Err(JsNativeError::typ()
+                .with_message("PromiseCapability: expected constructor")
+                .into()),
             Some(c) => {
                 let c = c.clone();
 
@@ -125,19 +144,20 @@ impl PromiseCapability {
                 // 5. Let executor be CreateBuiltinFunction(executorClosure, 2, "", « »).
                 let executor = FunctionBuilder::closure_with_captures(
                     context,
-                    |_this, args: &[JsValue], captures, context| {
+                    |_this, args: &[JsValue], captures, _context| {
                         let mut promise_capability = captures.borrow_mut();
                         // a. If promiseCapability.[[Resolve]] is not undefined, throw a TypeError exception.
                         if !promise_capability.resolve.is_undefined() {
-                            return context.throw_type_error(
-                                "promiseCapability.[[Resolve]] is not undefined",
-                            );
+                            return Err(JsNativeError::typ()
+                                .with_message("promiseCapability.[[Resolve]] is not undefined")
+                                .into());
                         }
 
                         // b. If promiseCapability.[[Reject]] is not undefined, throw a TypeError exception.
                         if !promise_capability.reject.is_undefined() {
-                            return context
-                                .throw_type_error("promiseCapability.[[Reject]] is not undefined");
+                            return Err(JsNativeError::typ()
+                                .with_message("promiseCapability.[[Reject]] is not undefined")
+                                .into());
                         }
 
                         let resolve = args.get_or_undefined(0);
@@ -173,8 +193,8 @@ impl PromiseCapability {
                     .cloned()
                     .and_then(JsFunction::from_object)
                     .ok_or_else(|| {
-                        context
-                            .construct_type_error("promiseCapability.[[Resolve]] is not callable")
+                        JsNativeError::typ()
+                            .with_message("promiseCapability.[[Resolve]] is not callable")
                     })?;
 
                 // 8. If IsCallable(promiseCapability.[[Reject]]) is false, throw a TypeError exception.
@@ -183,7 +203,8 @@ impl PromiseCapability {
                     .cloned()
                     .and_then(JsFunction::from_object)
                     .ok_or_else(|| {
-                        context.construct_type_error("promiseCapability.[[Reject]] is not callable")
+                        JsNativeError::typ()
+                            .with_message("promiseCapability.[[Reject]] is not callable")
                     })?;
 
                 // 9. Set promiseCapability.[[Promise]] to promise.
@@ -271,6 +292,12 @@ struct ResolvingFunctionsRecord {
 impl Promise {
     const LENGTH: usize = 1;
 
+    /// Gets the current state of the promise.
+    #[inline]
+    pub(crate) fn state(&self) -> &PromiseState {
+        &self.promise_state
+    }
+
     /// `Promise ( executor )`
     ///
     /// More information:
@@ -284,14 +311,18 @@ impl Promise {
     ) -> JsResult<JsValue> {
         // 1. If NewTarget is undefined, throw a TypeError exception.
         if new_target.is_undefined() {
-            return context.throw_type_error("Promise NewTarget cannot be undefined");
+            return Err(JsNativeError::typ()
+                .with_message("Promise NewTarget cannot be undefined")
+                .into());
         }
 
         let executor = args.get_or_undefined(0);
 
         // 2. If IsCallable(executor) is false, throw a TypeError exception.
         if !executor.is_callable() {
-            return context.throw_type_error("Promise executor is not callable");
+            return Err(JsNativeError::typ()
+                .with_message("Promise executor is not callable")
+                .into());
         }
 
         // 3. Let promise be ? OrdinaryCreateFromConstructor(NewTarget, "%Promise.prototype%", « [[PromiseState]], [[PromiseResult]], [[PromiseFulfillReactions]], [[PromiseRejectReactions]], [[PromiseIsHandled]] »).
@@ -301,7 +332,6 @@ impl Promise {
         let promise = JsObject::from_proto_and_data(
             promise,
             ObjectData::promise(Self {
-                promise_result: None,
                 // 4. Set promise.[[PromiseState]] to pending.
                 promise_state: PromiseState::Pending,
                 // 5. Set promise.[[PromiseFulfillReactions]] to a new empty List.
@@ -319,7 +349,7 @@ impl Promise {
         // 9. Let completion Completion(Call(executor, undefined, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »)be ).
         let completion = context.call(
             executor,
-            &JsValue::Undefined,
+            &JsValue::undefined(),
             &[
                 resolving_functions.resolve,
                 resolving_functions.reject.clone(),
@@ -329,7 +359,8 @@ impl Promise {
         // 10. If completion is an abrupt completion, then
         if let Err(value) = completion {
             // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
-            context.call(&resolving_functions.reject, &JsValue::Undefined, &[value])?;
+            let value = value.to_opaque(context);
+            context.call(&resolving_functions.reject, &JsValue::undefined(), &[value])?;
         }
 
         // 11. Return promise.
@@ -1033,7 +1064,7 @@ impl Promise {
                                 .constructors()
                                 .aggregate_error()
                                 .prototype(),
-                            ObjectData::error(),
+                            ObjectData::error(context.stack_trace()),
                         );
 
                         // 2. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
@@ -1053,7 +1084,7 @@ impl Promise {
                             .expect("cannot fail per spec");
 
                         // 3. Return ThrowCompletion(error).
-                        return Err(error.into());
+                        return Err(JsError::from_opaque(error.into()));
                     }
 
                     // iv. Return resultCapability.[[Promise]].
@@ -1128,7 +1159,7 @@ impl Promise {
                                 .constructors()
                                 .aggregate_error()
                                 .prototype(),
-                            ObjectData::error(),
+                            ObjectData::error(context.stack_trace()),
                         );
 
                         // b. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
@@ -1244,14 +1275,14 @@ impl Promise {
                 if JsValue::same_value(resolution, &promise.clone().into()) {
                     //   a. Let selfResolutionError be a newly created TypeError object.
                     let self_resolution_error =
-                        context.construct_type_error("SameValue(resolution, promise) is true");
+                        JsNativeError::typ().with_message("SameValue(resolution, promise) is true");
 
                     //   b. Perform RejectPromise(promise, selfResolutionError).
                     promise
                         .borrow_mut()
                         .as_promise_mut()
                         .expect("Expected promise to be a Promise")
-                        .reject_promise(&self_resolution_error, context);
+                        .reject_promise(&self_resolution_error.to_opaque(context).into(), context);
 
                     //   c. Return undefined.
                     return Ok(JsValue::Undefined);
@@ -1281,7 +1312,7 @@ impl Promise {
                             .borrow_mut()
                             .as_promise_mut()
                             .expect("Expected promise to be a Promise")
-                            .reject_promise(&value, context);
+                            .reject_promise(&value.to_opaque(context), context);
 
                         //   b. Return undefined.
                         return Ok(JsValue::Undefined);
@@ -1318,7 +1349,7 @@ impl Promise {
                 );
 
                 // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
-                context.host_enqueue_promise_job(job);
+                context.enqueue_job(job);
 
                 // 16. Return undefined.
                 Ok(JsValue::Undefined)
@@ -1397,9 +1428,8 @@ impl Promise {
     /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
     pub fn fulfill_promise(&mut self, value: &JsValue, context: &mut Context) -> JsResult<()> {
         // 1. Assert: The value of promise.[[PromiseState]] is pending.
-        assert_eq!(
-            self.promise_state,
-            PromiseState::Pending,
+        assert!(
+            matches!(self.promise_state, PromiseState::Pending),
             "promise was not pending"
         );
 
@@ -1411,7 +1441,7 @@ impl Promise {
         // reordering this statement does not affect the semantics
 
         // 3. Set promise.[[PromiseResult]] to value.
-        self.promise_result = Some(value.clone());
+        // Note: the result is stored with the state in step 6.
 
         // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
         self.promise_fulfill_reactions = Vec::new();
@@ -1420,7 +1450,7 @@ impl Promise {
         self.promise_reject_reactions = Vec::new();
 
         // 6. Set promise.[[PromiseState]] to fulfilled.
-        self.promise_state = PromiseState::Fulfilled;
+        self.promise_state = PromiseState::Fulfilled(value.clone());
 
         // 8. Return unused.
         Ok(())
@@ -1437,9 +1467,8 @@ impl Promise {
     /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
     pub fn reject_promise(&mut self, reason: &JsValue, context: &mut Context) {
         // 1. Assert: The value of promise.[[PromiseState]] is pending.
-        assert_eq!(
-            self.promise_state,
-            PromiseState::Pending,
+        assert!(
+            matches!(self.promise_state, PromiseState::Pending),
             "Expected promise.[[PromiseState]] to be pending"
         );
 
@@ -1451,7 +1480,7 @@ impl Promise {
         // reordering this statement does not affect the semantics
 
         // 3. Set promise.[[PromiseResult]] to reason.
-        self.promise_result = Some(reason.clone());
+        // Note: the result is stored with the state in step 6.
 
         // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
         self.promise_fulfill_reactions = Vec::new();
@@ -1460,7 +1489,7 @@ impl Promise {
         self.promise_reject_reactions = Vec::new();
 
         // 6. Set promise.[[PromiseState]] to rejected.
-        self.promise_state = PromiseState::Rejected;
+        self.promise_state = PromiseState::Rejected(reason.clone());
 
         // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
         if !self.promise_is_handled {
@@ -1495,7 +1524,7 @@ impl Promise {
                 PromiseJob::new_promise_reaction_job(reaction.clone(), argument.clone(), context);
 
             // b. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
-            context.host_enqueue_promise_job(job);
+            context.enqueue_job(job);
         }
 
         // 2. Return unused.
@@ -1674,7 +1703,9 @@ impl Promise {
             Self::promise_resolve(c.clone(), x.clone(), context)
         } else {
             // 2. If Type(C) is not Object, throw a TypeError exception.
-            context.throw_type_error("Promise.resolve() called on a non-object")
+            Err(JsNativeError::typ()
+                .with_message("Promise.resolve() called on a non-object")
+                .into())
         }
     }
 
@@ -1731,7 +1762,9 @@ impl Promise {
         let promise_obj = if let Some(p) = promise.as_object() {
             p
         } else {
-            return context.throw_type_error("finally called with a non-object promise");
+            return Err(JsNativeError::typ()
+                .with_message("finally called with a non-object promise")
+                .into());
         };
 
         // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
@@ -1819,7 +1852,7 @@ impl Promise {
                         context,
                         |_this, _args, captures, _context| {
                             // 1. Return ThrowCompletion(reason).
-                            Err(captures.reason.clone())
+                            Err(JsError::from_opaque(captures.reason.clone()))
                         },
                         ThrowReasonCaptures {
                             reason: reason.clone(),
@@ -1868,7 +1901,11 @@ impl Promise {
         // 2. If IsPromise(promise) is false, throw a TypeError exception.
         let promise_obj = match promise.as_promise() {
             Some(obj) => obj,
-            None => return context.throw_type_error("IsPromise(promise) is false"),
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message("IsPromise(promise) is false")
+                    .into())
+            }
         };
 
         // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
@@ -1943,7 +1980,7 @@ impl Promise {
             handler: on_rejected_job_callback,
         };
 
-        match self.promise_state {
+        match &self.promise_state {
             // 9. If promise.[[PromiseState]] is pending, then
             PromiseState::Pending => {
                 //   a. Append fulfillReaction as the last element of the List that is promise.[[PromiseFulfillReactions]].
@@ -1954,29 +1991,23 @@ impl Promise {
             }
 
             // 10. Else if promise.[[PromiseState]] is fulfilled, then
-            PromiseState::Fulfilled => {
+            PromiseState::Fulfilled(value) => {
                 //   a. Let value be promise.[[PromiseResult]].
-                let value = self
-                    .promise_result
-                    .clone()
-                    .expect("promise.[[PromiseResult]] cannot be empty");
+                let value = value.clone();
 
                 //   b. Let fulfillJob be NewPromiseReactionJob(fulfillReaction, value).
                 let fulfill_job =
                     PromiseJob::new_promise_reaction_job(fulfill_reaction, value, context);
 
                 //   c. Perform HostEnqueuePromiseJob(fulfillJob.[[Job]], fulfillJob.[[Realm]]).
-                context.host_enqueue_promise_job(fulfill_job);
+                context.enqueue_job(fulfill_job);
             }
 
             // 11. Else,
             //   a. Assert: The value of promise.[[PromiseState]] is rejected.
-            PromiseState::Rejected => {
+            PromiseState::Rejected(reason) => {
                 //   b. Let reason be promise.[[PromiseResult]].
-                let reason = self
-                    .promise_result
-                    .clone()
-                    .expect("promise.[[PromiseResult]] cannot be empty");
+                let reason = reason.clone();
 
                 //   c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
                 if !self.promise_is_handled {
@@ -1989,7 +2020,7 @@ impl Promise {
                     PromiseJob::new_promise_reaction_job(reject_reaction, reason, context);
 
                 //   e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
-                context.host_enqueue_promise_job(reject_job);
+                context.enqueue_job(reject_job);
 
                 // 12. Set promise.[[PromiseIsHandled]] to true.
                 self.promise_is_handled = true;
@@ -2068,7 +2099,9 @@ impl Promise {
             // 3. Return promiseResolve.
             Ok(promise_resolve.clone())
         } else {
-            context.throw_type_error("retrieving a non-callable promise resolver")
+            Err(JsNativeError::typ()
+                .with_message("retrieving a non-callable promise resolver")
+                .into())
         }
     }
 }
@@ -1,6 +1,7 @@
 use super::{Promise, PromiseCapability};
 use crate::{
     builtins::promise::{ReactionRecord, ReactionType},
+    error::JsError,
     job::JobCallback,
     object::{FunctionBuilder, JsObject},
     Context, JsValue,
@@ -50,13 +51,15 @@ impl PromiseJob {
                         //   1. Assert: type is Reject.
                         ReactionType::Reject => {
                             // 2. Let handlerResult be ThrowCompletion(argument).
-                            Err(argument.clone())
+                            Err(JsError::from_opaque(argument.clone()))
                         }
                     },
                     //   e. Else, let handlerResult be Completion(HostCallJobCallback(handler, undefined, « argument »)).
-                    Some(handler) => {
-                        handler.call_job_callback(&JsValue::Undefined, &[argument.clone()], context)
-                    }
+                    Some(handler) => handler.call_job_callback(
+                        &JsValue::undefined(),
+                        &[argument.clone()],
+                        context,
+                    ),
                 };
 
                 match promise_capability {
@@ -83,13 +86,22 @@ impl PromiseJob {
                             // h. If handlerResult is an abrupt completion, then
                             Err(value) => {
                                 // i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
-                                context.call(&reject.clone().into(), &JsValue::Undefined, &[value])
+                                let value = value.to_opaque(context);
+                                context.call(
+                                    &reject.clone().into(),
+                                    &JsValue::undefined(),
+                                    &[value],
+                                )
                             }
 
                             // i. Else,
                             Ok(value) => {
                                 // i. Return ? Call(promiseCapability.[[Resolve]], undefined, « handlerResult.[[Value]] »).
-                                context.call(&resolve.clone().into(), &JsValue::Undefined, &[value])
+                                context.call(
+                                    &resolve.clone().into(),
+                                    &JsValue::undefined(),
+                                    &[value],
+                                )
                             }
                         }
                     }
@@ -147,9 +159,10 @@ impl PromiseJob {
                 //    c. If thenCallResult is an abrupt completion, then
                 if let Err(value) = then_call_result {
                     //    i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
+                    let value = value.to_opaque(context);
                     return context.call(
                         &resolving_functions.reject,
-                        &JsValue::Undefined,
+                        &JsValue::undefined(),
                         &[value],
                     );
                 }
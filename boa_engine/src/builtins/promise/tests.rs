@@ -1,4 +1,10 @@
-use crate::{forward, Context};
+use super::PromiseState;
+use crate::{
+    error::JsNativeError,
+    forward,
+    object::{FunctionBuilder, JsPromise},
+    Context, JsValue,
+};
 
 #[test]
 fn promise() {
@@ -17,3 +23,63 @@ fn promise() {
     let after_completion = forward(&mut context, "count");
     assert_eq!(after_completion, String::from("3"));
 }
+
+#[test]
+fn js_promise_settles() {
+    let mut context = Context::default();
+
+    let (promise, resolvers) = JsPromise::new_pending(&mut context);
+    assert_eq!(promise.state(), PromiseState::Pending);
+    resolvers
+        .resolve
+        .call(&JsValue::undefined(), &[JsValue::new(1)], &mut context)
+        .unwrap();
+    assert_eq!(promise.state(), PromiseState::Fulfilled(JsValue::new(1)));
+
+    // Settling a promise a second time has no effect.
+    resolvers
+        .reject
+        .call(&JsValue::undefined(), &[JsValue::new(2)], &mut context)
+        .unwrap();
+    assert_eq!(promise.state(), PromiseState::Fulfilled(JsValue::new(1)));
+
+    let rejected =
+        JsPromise::reject(JsNativeError::typ().with_message("failed"), &mut context).unwrap();
+    match rejected.state() {
+        PromiseState::Rejected(reason) => assert_eq!(
+            reason
+                .to_string(&mut context)
+                .unwrap()
+                .to_std_string_escaped(),
+            "TypeError: failed"
+        ),
+        state => panic!("expected a rejected promise, got {state:?}"),
+    }
+
+    let resolved = JsPromise::resolve("value", &mut context).unwrap();
+    assert_eq!(
+        resolved.state(),
+        PromiseState::Fulfilled(JsValue::new("value"))
+    );
+}
+
+#[test]
+fn js_promise_then() {
+    let mut context = Context::default();
+    let (promise, resolvers) = JsPromise::new_pending(&mut context);
+
+    let double = FunctionBuilder::closure(&mut context, |_, args, context| {
+        Ok(JsValue::new(args[0].to_number(context)? * 2.0))
+    })
+    .build();
+    let doubled = promise.then(Some(double), None, &mut context).unwrap();
+
+    resolvers
+        .resolve
+        .call(&JsValue::undefined(), &[JsValue::new(21)], &mut context)
+        .unwrap();
+    assert_eq!(doubled.state(), PromiseState::Pending);
+
+    context.run_jobs().unwrap();
+    assert_eq!(doubled.state(), PromiseState::Fulfilled(JsValue::new(42)));
+}
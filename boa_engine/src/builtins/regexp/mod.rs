@@ -16,11 +16,14 @@ use super::JsArgs;
 use crate::{
     builtins::{array::Array, string, BuiltIn},
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    js_string,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsObject, ObjectData,
     },
     property::{Attribute, PropertyDescriptorBuilder},
+    string::utf16,
     symbol::WellKnownSymbols,
     syntax::lexer::regex::RegExpFlags,
     value::{IntegerOrInfinity, JsValue},
@@ -281,8 +284,9 @@ impl RegExp {
 
         // 5. If F contains any code unit other than "g", "i", "m", "s", "u", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
-        let flags = match RegExpFlags::from_str(&f) {
-            Err(msg) => return context.throw_syntax_error(msg),
+        let f_str = f.to_std_string_escaped();
+        let flags = match RegExpFlags::from_str(&f_str) {
+            Err(msg) => return Err(JsNativeError::syntax().with_message(msg).into()),
             Ok(result) => result,
         };
 
@@ -295,10 +299,11 @@ impl RegExp {
         // 13. Set obj.[[OriginalFlags]] to F.
         // 14. NOTE: The definitions of DotAll, IgnoreCase, Multiline, and Unicode in 22.2.2.1 refer to this value of obj.[[OriginalFlags]].
         // 15. Set obj.[[RegExpMatcher]] to CompilePattern of parseResult.
-        let matcher = match Regex::with_flags(&p, f.as_ref()) {
+        let matcher = match Regex::with_flags(&p.to_std_string_escaped(), f_str.as_str()) {
             Err(error) => {
-                return context
-                    .throw_syntax_error(format!("failed to create matcher: {}", error.text));
+                return Err(JsNativeError::syntax()
+                    .with_message(format!("failed to create matcher: {}", error.text))
+                    .into());
             }
             Ok(val) => val,
         };
@@ -385,9 +390,11 @@ impl RegExp {
             _ => unreachable!(),
         };
 
-        context.throw_type_error(format!(
-            "RegExp.prototype.{name} getter called on non-RegExp object",
-        ))
+        Err(JsNativeError::typ()
+            .with_message(format!(
+                "RegExp.prototype.{name} getter called on non-RegExp object",
+            ))
+            .into())
     }
 
     /// `get RegExp.prototype.hasIndices`
@@ -582,7 +589,9 @@ impl RegExp {
             return Ok(result.into());
         }
 
-        context.throw_type_error("RegExp.prototype.flags getter called on non-object")
+        Err(JsNativeError::typ()
+            .with_message("RegExp.prototype.flags getter called on non-object")
+            .into())
     }
 
     /// `get RegExp.prototype.source`
@@ -617,9 +626,11 @@ impl RegExp {
                     ) {
                         Ok(JsValue::new("(?:)"))
                     } else {
-                        context.throw_type_error(
-                            "RegExp.prototype.source method called on incompatible value",
-                        )
+                        Err(JsNativeError::typ()
+                            .with_message(
+                                "RegExp.prototype.source method called on incompatible value",
+                            )
+                            .into())
                     }
                 }
                 // 4. Assert: R has an [[OriginalFlags]] internal slot.
@@ -634,7 +645,9 @@ impl RegExp {
                 }
             }
         } else {
-            context.throw_type_error("RegExp.prototype.source method called on incompatible value")
+            Err(JsNativeError::typ()
+                .with_message("RegExp.prototype.source method called on incompatible value")
+                .into())
         }
     }
 
@@ -644,22 +657,22 @@ impl RegExp {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-escaperegexppattern
-    fn escape_pattern(src: &str, _flags: &str) -> JsValue {
+    fn escape_pattern(src: &[u16], _flags: &[u16]) -> JsValue {
         if src.is_empty() {
             JsValue::new("(?:)")
         } else {
-            let mut s = String::from("");
+            let mut s = Vec::with_capacity(src.len());
 
-            for c in src.chars() {
-                match c {
-                    '/' => s.push_str("\\/"),
-                    '\n' => s.push_str("\\\\n"),
-                    '\r' => s.push_str("\\\\r"),
+            for &c in src {
+                match u8::try_from(c) {
+                    Ok(b'/') => s.extend_from_slice(utf16!("\\/")),
+                    Ok(b'\n') => s.extend_from_slice(utf16!("\\\\n")),
+                    Ok(b'\r') => s.extend_from_slice(utf16!("\\\\r")),
                     _ => s.push(c),
                 }
             }
 
-            JsValue::new(s)
+            JsString::from(s).into()
         }
     }
 
@@ -683,8 +696,8 @@ impl RegExp {
         // 1. Let R be the this value.
         // 2. If Type(R) is not Object, throw a TypeError exception.
         let this = this.as_object().ok_or_else(|| {
-            context
-                .construct_type_error("RegExp.prototype.test method called on incompatible value")
+            JsNativeError::typ()
+                .with_message("RegExp.prototype.test method called on incompatible value")
         })?;
 
         // 3. Let string be ? ToString(S).
@@ -728,7 +741,7 @@ impl RegExp {
             .as_object()
             .filter(|obj| obj.is_regexp())
             .ok_or_else(|| {
-                context.construct_type_error("RegExp.prototype.exec called with invalid value")
+                JsNativeError::typ().with_message("RegExp.prototype.exec called with invalid value")
             })?;
 
         // 3. Let S be ? ToString(string).
@@ -766,7 +779,9 @@ impl RegExp {
 
             // b. If Type(result) is neither Object nor Null, throw a TypeError exception.
             if !result.is_object() && !result.is_null() {
-                return context.throw_type_error("regexp exec returned neither object nor null");
+                return Err(JsNativeError::typ()
+                    .with_message("regexp exec returned neither object nor null")
+                    .into());
             }
 
             // c. Return result.
@@ -775,7 +790,9 @@ impl RegExp {
 
         // 5. Perform ? RequireInternalSlot(R, [[RegExpMatcher]]).
         if !this.is_regexp() {
-            return context.throw_type_error("RegExpExec called with invalid value");
+            return Err(JsNativeError::typ()
+                .with_message("RegExpExec called with invalid value")
+                .into());
         }
 
         // 6. Return ? RegExpBuiltinExec(R, S).
@@ -799,14 +816,16 @@ impl RegExp {
             if let Some(rx) = obj.as_regexp() {
                 rx.clone()
             } else {
-                return context.throw_type_error("RegExpBuiltinExec called with invalid value");
+                return Err(JsNativeError::typ()
+                    .with_message("RegExpBuiltinExec called with invalid value")
+                    .into());
             }
         };
 
         // 2. Assert: Type(S) is String.
 
         // 3. Let length be the number of code units in S.
-        let length = input.encode_utf16().count() as u64;
+        let length = input.len() as u64;
 
         // 4. Let lastIndex be ℝ(? ToLength(? Get(R, "lastIndex"))).
         let mut last_index = this.get("lastIndex", context)?.to_length(context)?;
@@ -815,10 +834,10 @@ impl RegExp {
         let flags = &rx.original_flags;
 
         // 6. If flags contains "g", let global be true; else let global be false.
-        let global = flags.contains('g');
+        let global = flags.contains(&u16::from(b'g'));
 
         // 7. If flags contains "y", let sticky be true; else let sticky be false.
-        let sticky = flags.contains('y');
+        let sticky = flags.contains(&u16::from(b'y'));
 
         // 8. If global is false and sticky is false, set lastIndex to 0.
         if !global && !sticky {
@@ -829,7 +848,11 @@ impl RegExp {
         let matcher = &rx.matcher;
 
         // 10. If flags contains "u", let fullUnicode be true; else let fullUnicode be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains(&u16::from(b'u'));
+
+        // The matcher only works on UTF-8 text. The lossy conversion keeps the number of code
+        // units of every code point, so the indices of a match can be mapped back to `input`.
+        let lossy_input = input.to_std_string_lossy();
 
         // 11. Let matchSucceeded be false.
         // 12. Repeat, while matchSucceeded is false,
@@ -847,20 +870,9 @@ impl RegExp {
             }
 
             // b. Let r be matcher(S, lastIndex).
-            // Check if last_index is a valid utf8 index into input.
-            let last_byte_index = match String::from_utf16(
-                &input
-                    .encode_utf16()
-                    .take(last_index as usize)
-                    .collect::<Vec<u16>>(),
-            ) {
-                Ok(s) => s.len(),
-                Err(_) => {
-                    return context
-                        .throw_type_error("Failed to get byte index from utf16 encoded string")
-                }
-            };
-            let r = matcher.find_from(input, last_byte_index).next();
+            // An index pointing inside of a surrogate pair can never start a match.
+            let r = utf16_to_byte_index(&lossy_input, last_index as usize)
+                .and_then(|index| matcher.find_from(&lossy_input, index).next());
 
             match r {
                 // c. If r is failure, then
@@ -879,9 +891,11 @@ impl RegExp {
                 }
 
                 Some(m) => {
+                    let start = byte_to_utf16_index(&lossy_input, m.start()) as u64;
+
                     // c. If r is failure, then
                     #[allow(clippy::if_not_else)]
-                    if m.start() as u64 != last_index {
+                    if start != last_index {
                         // i. If sticky is true, then
                         if sticky {
                             // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
@@ -892,7 +906,10 @@ impl RegExp {
                         }
 
                         // ii. Set lastIndex to AdvanceStringIndex(S, lastIndex, fullUnicode).
-                        last_index = advance_string_index(input, last_index, unicode);
+                        //
+                        // The matcher already found the first match after lastIndex, so every
+                        // index before it would fail to match.
+                        last_index = start;
                     // d. Else,
                     } else {
                         //i. Assert: r is a State.
@@ -904,16 +921,12 @@ impl RegExp {
         };
 
         // 13. Let e be r's endIndex value.
-        let mut e = match_value.end();
-
         // 14. If fullUnicode is true, then
-        if unicode {
-            // e is an index into the Input character list, derived from S, matched by matcher.
-            // Let eUTF be the smallest index into S that corresponds to the character at element e of Input.
-            // If e is greater than or equal to the number of elements in Input, then eUTF is the number of code units in S.
-            // b. Set e to eUTF.
-            e = input.split_at(e).0.encode_utf16().count();
-        }
+        //     e is an index into the Input character list, derived from S, matched by matcher.
+        //     Let eUTF be the smallest index into S that corresponds to the character at element e of Input.
+        //     If e is greater than or equal to the number of elements in Input, then eUTF is the number of code units in S.
+        //     b. Set e to eUTF.
+        let e = byte_to_utf16_index(&lossy_input, match_value.end());
 
         // 15. If global is true or sticky is true, then
         if global || sticky {
@@ -931,20 +944,23 @@ impl RegExp {
         let a = Array::array_create(n + 1, None, context)?;
 
         // 20. Perform ! CreateDataPropertyOrThrow(A, "index", 𝔽(lastIndex)).
-        a.create_data_property_or_throw("index", match_value.start(), context)
+        a.create_data_property_or_throw("index", last_index, context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
         // 21. Perform ! CreateDataPropertyOrThrow(A, "input", S).
         a.create_data_property_or_throw("input", input.clone(), context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
-        // 22. Let matchedSubstr be the substring of S from lastIndex to e.
-        let matched_substr = if let Some(s) = input.get(match_value.range()) {
-            s
-        } else {
-            ""
+        // Returns the substring of S that corresponds to a range of the lossy input.
+        let substring = |range: std::ops::Range<usize>| {
+            let start = byte_to_utf16_index(&lossy_input, range.start);
+            let end = byte_to_utf16_index(&lossy_input, range.end);
+            JsString::from(&input[start..end])
         };
 
+        // 22. Let matchedSubstr be the substring of S from lastIndex to e.
+        let matched_substr = substring(match_value.range());
+
         // 23. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
         a.create_data_property_or_throw(0, matched_substr, context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
@@ -962,15 +978,9 @@ impl RegExp {
             // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
             for (name, range) in named_groups {
                 if let Some(range) = range {
-                    let value = if let Some(s) = input.get(range.clone()) {
-                        s
-                    } else {
-                        ""
-                    };
-
                     groups
                         .to_object(context)?
-                        .create_data_property_or_throw(name, value, context)
+                        .create_data_property_or_throw(name, substring(range), context)
                         .expect("this CreateDataPropertyOrThrow call must not fail");
                 }
             }
@@ -994,13 +1004,7 @@ impl RegExp {
                 None => JsValue::undefined(),
                 // c. Else if fullUnicode is true, then
                 // d. Else,
-                Some(range) => {
-                    if let Some(s) = input.get(range) {
-                        s.into()
-                    } else {
-                        "".into()
-                    }
-                }
+                Some(range) => substring(range).into(),
             };
 
             // e. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
@@ -1032,8 +1036,9 @@ impl RegExp {
         let rx = if let Some(rx) = this.as_object() {
             rx
         } else {
-            return context
-                .throw_type_error("RegExp.prototype.match method called on incompatible value");
+            return Err(JsNativeError::typ()
+                .with_message("RegExp.prototype.match method called on incompatible value")
+                .into());
         };
 
         // 3. Let S be ? ToString(string).
@@ -1124,25 +1129,23 @@ impl RegExp {
     /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype.tostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/toString
     #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         let (body, flags) = if let Some(object) = this.as_object() {
             let object = object.borrow();
             let regex = object.as_regexp().ok_or_else(|| {
-                context.construct_type_error(format!(
+                JsNativeError::typ().with_message(format!(
                     "Method RegExp.prototype.toString called on incompatible receiver {}",
                     this.display()
                 ))
             })?;
             (regex.original_source.clone(), regex.original_flags.clone())
         } else {
-            return context.throw_type_error(format!(
-                "Method RegExp.prototype.toString called on incompatible receiver {}",
-                this.display()
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "Method RegExp.prototype.toString called on incompatible receiver {}",
+                    this.display()
+                ))
+                .into());
         };
         Ok(format!("/{body}/{flags}").into())
     }
@@ -1165,9 +1168,8 @@ impl RegExp {
         // 1. Let R be the this value.
         // 2. If Type(R) is not Object, throw a TypeError exception.
         let regexp = this.as_object().ok_or_else(|| {
-            context.construct_type_error(
-                "RegExp.prototype.match_all method called on incompatible value",
-            )
+            JsNativeError::typ()
+                .with_message("RegExp.prototype.match_all method called on incompatible value")
         })?;
 
         // 3. Let S be ? ToString(string).
@@ -1190,11 +1192,11 @@ impl RegExp {
 
         // 9. If flags contains "g", let global be true.
         // 10. Else, let global be false.
-        let global = flags.contains('g');
+        let global = flags.contains(&u16::from(b'g'));
 
         // 11. If flags contains "u", let fullUnicode be true.
         // 12. Else, let fullUnicode be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains(&u16::from(b'u'));
 
         // 13. Return ! CreateRegExpStringIterator(matcher, S, global, fullUnicode).
         Ok(RegExpStringIterator::create_regexp_string_iterator(
@@ -1228,9 +1230,11 @@ impl RegExp {
         let rx = if let Some(rx) = this.as_object() {
             rx
         } else {
-            return context.throw_type_error(
-                "RegExp.prototype[Symbol.replace] method called on incompatible value",
-            );
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "RegExp.prototype[Symbol.replace] method called on incompatible value",
+                )
+                .into());
         };
 
         // 3. Let S be ? ToString(string).
@@ -1241,7 +1245,7 @@ impl RegExp {
             .to_string(context)?;
 
         // 4. Let lengthS be the number of code unit elements in S.
-        let length_arg_str = arg_str.encode_utf16().count();
+        let length_arg_str = arg_str.len();
 
         // 5. Let functionalReplace be IsCallable(replaceValue).
         let mut replace_value = args.get_or_undefined(1).clone();
@@ -1310,7 +1314,7 @@ impl RegExp {
         }
 
         // 12. Let accumulatedResult be the empty String.
-        let mut accumulated_result = JsString::new("");
+        let mut accumulated_result = Vec::new();
 
         // 13. Let nextSourcePosition be 0.
         let mut next_source_position = 0;
@@ -1327,7 +1331,7 @@ impl RegExp {
             let matched = result.get("0", context)?.to_string(context)?;
 
             // d. Let matchLength be the number of code units in matched.
-            let match_length = matched.encode_utf16().count();
+            let match_length = matched.len();
 
             // e. Let position be ? ToIntegerOrInfinity(? Get(result, "index")).
             let position = result
@@ -1409,8 +1413,8 @@ impl RegExp {
 
                 // ii. Let replacement be ? GetSubstitution(matched, S, position, captures, namedCaptures, replaceValue).
                 replacement = string::get_substitution(
-                    matched.as_str(),
-                    arg_str.as_str(),
+                    &matched,
+                    &arg_str,
                     position,
                     &captures,
                     &named_captures,
@@ -1427,13 +1431,8 @@ impl RegExp {
                 //    In such cases, the corresponding substitution is ignored.
                 // ii. Set accumulatedResult to the string-concatenation of accumulatedResult,
                 //     the substring of S from nextSourcePosition to position, and replacement.
-                accumulated_result = format!(
-                    "{accumulated_result}{}{replacement}",
-                    arg_str
-                        .get(next_source_position..position)
-                        .expect("index of a regexp match cannot be greater than the input string"),
-                )
-                .into();
+                accumulated_result.extend_from_slice(&arg_str[next_source_position..position]);
+                accumulated_result.extend_from_slice(&replacement);
 
                 // iii. Set nextSourcePosition to position + matchLength.
                 next_source_position = position + match_length;
@@ -1442,18 +1441,11 @@ impl RegExp {
 
         // 15. If nextSourcePosition ≥ lengthS, return accumulatedResult.
         if next_source_position >= length_arg_str {
-            return Ok(accumulated_result.into());
+            return Ok(JsString::from(accumulated_result).into());
         }
 
         // 16. Return the string-concatenation of accumulatedResult and the substring of S from nextSourcePosition.
-        Ok(format!(
-            "{}{}",
-            accumulated_result,
-            arg_str
-                .get(next_source_position..)
-                .expect("next_source_position cannot be greater than the input string")
-        )
-        .into())
+        Ok(js_string!(&accumulated_result, &arg_str[next_source_position..]).into())
     }
 
     /// `RegExp.prototype[ @@search ]( string )`
@@ -1476,9 +1468,9 @@ impl RegExp {
         let rx = if let Some(rx) = this.as_object() {
             rx
         } else {
-            return context.throw_type_error(
-                "RegExp.prototype[Symbol.search] method called on incompatible value",
-            );
+            return Err(JsNativeError::typ()
+                .with_message("RegExp.prototype[Symbol.search] method called on incompatible value")
+                .into());
         };
 
         // 3. Let S be ? ToString(string).
@@ -1538,8 +1530,9 @@ impl RegExp {
         let rx = if let Some(rx) = this.as_object() {
             rx
         } else {
-            return context
-                .throw_type_error("RegExp.prototype.split method called on incompatible value");
+            return Err(JsNativeError::typ()
+                .with_message("RegExp.prototype.split method called on incompatible value")
+                .into());
         };
 
         // 3. Let S be ? ToString(string).
@@ -1557,14 +1550,14 @@ impl RegExp {
 
         // 6. If flags contains "u", let unicodeMatching be true.
         // 7. Else, let unicodeMatching be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains(&u16::from(b'u'));
 
         // 8. If flags contains "y", let newFlags be flags.
         // 9. Else, let newFlags be the string-concatenation of flags and "y".
-        let new_flags = if flags.contains('y') {
-            flags.to_string()
+        let new_flags = if flags.contains(&u16::from(b'y')) {
+            flags
         } else {
-            format!("{flags}y")
+            js_string!(&flags, utf16!("y"))
         };
 
         // 10. Let splitter be ? Construct(C, « rx, newFlags »).
@@ -1594,7 +1587,7 @@ impl RegExp {
         }
 
         // 15. Let size be the length of S.
-        let size = arg_str.encode_utf16().count() as u64;
+        let size = arg_str.len() as u64;
 
         // 16. If size is 0, then
         if size == 0 {
@@ -1642,13 +1635,7 @@ impl RegExp {
                     q = advance_string_index(&arg_str, q, unicode);
                 } else {
                     // 1. Let T be the substring of S from p to q.
-                    let arg_str_substring = String::from_utf16_lossy(
-                        &arg_str
-                            .encode_utf16()
-                            .skip(p as usize)
-                            .take((q - p) as usize)
-                            .collect::<Vec<u16>>(),
-                    );
+                    let arg_str_substring = JsString::from(&arg_str[p as usize..q as usize]);
 
                     // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
                     a.create_data_property_or_throw(length_a, arg_str_substring, context)
@@ -1703,13 +1690,7 @@ impl RegExp {
         }
 
         // 20. Let T be the substring of S from p to size.
-        let arg_str_substring = String::from_utf16_lossy(
-            &arg_str
-                .encode_utf16()
-                .skip(p as usize)
-                .take((size - p) as usize)
-                .collect::<Vec<u16>>(),
-        );
+        let arg_str_substring = JsString::from(&arg_str[p as usize..size as usize]);
 
         // 21. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
         a.create_data_property_or_throw(length_a, arg_str_substring, context)
@@ -1727,8 +1708,6 @@ impl RegExp {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-advancestringindex
 fn advance_string_index(s: &JsString, index: u64, unicode: bool) -> u64 {
-    // Regress only works with utf8, so this function differs from the spec.
-
     // 1. Assert: index ≤ 2^53 - 1.
 
     // 2. If unicode is false, return index + 1.
@@ -1737,15 +1716,37 @@ fn advance_string_index(s: &JsString, index: u64, unicode: bool) -> u64 {
     }
 
     // 3. Let length be the number of code units in S.
-    let length = s.encode_utf16().count() as u64;
+    let length = s.len() as u64;
 
     // 4. If index + 1 ≥ length, return index + 1.
-    if index + 1 > length {
+    if index + 1 >= length {
         return index + 1;
     }
 
     // 5. Let cp be ! CodePointAt(S, index).
-    let (_, offset, _) = crate::builtins::string::code_point_at(s, index);
+    let cp = s.code_point_at(index as usize);
+
+    // 6. Return index + cp.[[CodeUnitCount]].
+    index + cp.code_unit_count() as u64
+}
+
+/// Converts an index into the code units of a string to a byte index into its UTF-8 encoded
+/// representation `s`.
+///
+/// Returns `None` if the index points inside of a surrogate pair.
+fn utf16_to_byte_index(s: &str, index: usize) -> Option<usize> {
+    let mut code_units = 0;
+    for (byte_index, c) in s.char_indices() {
+        if code_units >= index {
+            return (code_units == index).then(|| byte_index);
+        }
+        code_units += c.len_utf16();
+    }
+    (code_units == index).then(|| s.len())
+}
 
-    index + u64::from(offset)
+/// Converts a byte index into the UTF-8 encoded representation `s` of a string to an index into
+/// its code units.
+fn byte_to_utf16_index(s: &str, index: usize) -> usize {
+    s[..index].chars().map(char::len_utf16).sum()
 }
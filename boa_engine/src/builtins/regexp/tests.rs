@@ -237,3 +237,29 @@ fn search() {
     assert_eq!(forward(&mut context, "/u/[Symbol.search](null)"), "1");
     assert_eq!(forward(&mut context, "/d/[Symbol.search](undefined)"), "2");
 }
+
+#[test]
+fn js_regexp() {
+    use crate::{object::JsRegExp, JsValue};
+
+    let mut context = Context::default();
+
+    let regexp = JsRegExp::new("(\\w+)@(\\w+)", "gy", &mut context).unwrap();
+    assert!(regexp.global(&mut context).unwrap());
+    assert!(regexp.sticky(&mut context).unwrap());
+    assert!(!regexp.ignore_case(&mut context).unwrap());
+    assert_eq!(regexp.flags(&mut context).unwrap(), "gy");
+    assert_eq!(regexp.source(&mut context).unwrap(), "(\\w+)@(\\w+)");
+    assert_eq!(regexp.to_string(&mut context).unwrap(), "/(\\w+)@(\\w+)/gy");
+
+    let result = regexp.exec("user@host", &mut context).unwrap().unwrap();
+    assert_eq!(result.at(1, &mut context).unwrap(), JsValue::new("user"));
+    assert_eq!(result.at(2, &mut context).unwrap(), JsValue::new("host"));
+
+    // The global flag makes the regexp continue from the end of the last match.
+    assert!(!regexp.test("user@host", &mut context).unwrap());
+    assert!(regexp.exec("user@host", &mut context).unwrap().is_some());
+
+    assert!(JsRegExp::new("(", "", &mut context).is_err());
+    assert!(JsRegExp::from_object(context.construct_object()).is_err());
+}
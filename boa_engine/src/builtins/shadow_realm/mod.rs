@@ -0,0 +1,490 @@
+//! This module implements the global `ShadowRealm` object.
+//!
+//! A `ShadowRealm` is a distinct global environment, with its own global object and its own set
+//! of builtins, in which code can be evaluated without affecting the realm that created it.
+//! Only primitives and callables can cross the boundary between the two realms: callables are
+//! wrapped in functions that wrap their arguments and results in the same way.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+use super::JsArgs;
+use crate::{
+    builtins::{eval::Eval, BuiltIn},
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    module::Module,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, JsPromise, ObjectData,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ShadowRealm;
+
+impl BuiltIn for ShadowRealm {
+    const NAME: &'static str = "ShadowRealm";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().shadow_realm().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::evaluate, "evaluate", 1)
+        .method(Self::import_value, "importValue", 2)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl ShadowRealm {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `ShadowRealm ( )`
+    ///
+    /// Constructor for `ShadowRealm` objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("calling a builtin ShadowRealm constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]], [[ExecutionContext]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let realmRec be CreateRealm().
+        // 4-12. Set up the execution context, the global object and the default global bindings.
+        let realm = context.create_realm();
+
+        // 5. Set O.[[ShadowRealm]] to realmRec.
+        // 13. Return O.
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::shadow_realm(realm)).into())
+    }
+
+    /// `ShadowRealm.prototype.evaluate ( sourceText )`
+    ///
+    /// Evaluates `sourceText` as a script in the global environment of the shadow realm, and
+    /// returns its completion value wrapped for the calling realm.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    pub(crate) fn evaluate(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "evaluate")?;
+
+        // 3. If Type(sourceText) is not String, throw a TypeError exception.
+        let source_text = args.get_or_undefined(0);
+        let source_text = if let Some(source_text) = source_text.as_string() {
+            source_text
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "ShadowRealm.prototype.evaluate: expected source text of type `string`, got `{}`",
+                    source_text.type_of()
+                ))
+                .into());
+        };
+
+        // 4. Let callerRealm be the current Realm Record.
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        Self::perform_shadow_realm_eval(source_text, eval_realm, context)
+    }
+
+    /// `ShadowRealm.prototype.importValue ( specifier, exportName )`
+    ///
+    /// Imports the module `specifier` in the shadow realm, and returns a promise for its export
+    /// `exportName` wrapped for the calling realm.
+    ///
+    /// The module is loaded with the [`ModuleLoader`][crate::module::ModuleLoader] of the context,
+    /// as if it was imported by a module at the root of the loader created in the shadow realm.
+    /// Every shadow realm gets its own instance of the module, evaluated with its own builtins.
+    ///
+    /// The import is done synchronously, so the returned promise is always settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    pub(crate) fn import_value(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If Type(exportName) is not String, throw a TypeError exception.
+        let export_name = args.get_or_undefined(1);
+        let export_name = if let Some(export_name) = export_name.as_string() {
+            export_name
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "ShadowRealm.prototype.importValue: expected export name of type `string`, got `{}`",
+                    export_name.type_of()
+                ))
+                .into());
+        };
+
+        // 5. Let callerRealm be the current Realm Record.
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Let evalContext be O.[[ExecutionContext]].
+        // 8. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm, evalContext).
+        let caller_realm = context.enter_realm(eval_realm);
+        let result = Self::import_export(&specifier, export_name, context);
+        context.enter_realm(caller_realm.clone());
+
+        let promise = match result
+            .and_then(|value| Self::get_wrapped_value(&caller_realm, &value, context))
+        {
+            Ok(value) => JsPromise::resolve(value, context)?,
+            Err(err) => JsPromise::reject(
+                JsNativeError::typ().with_message(format!(
+                    "ShadowRealm.prototype.importValue: cannot import `{}` from `{}`: {err}",
+                    export_name.to_std_string_escaped(),
+                    specifier.to_std_string_escaped()
+                )),
+                context,
+            )?,
+        };
+
+        Ok(promise.into())
+    }
+
+    /// Abstract operation `ValidateShadowRealmObject ( O )`.
+    ///
+    /// Returns the realm of the `ShadowRealm` object `this`.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn validate(this: &JsValue, method: &str) -> JsResult<Realm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        // 2. Perform ? RequireInternalSlot(O, [[ExecutionContext]]).
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_shadow_realm().cloned())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.{method}: called with non-ShadowRealm value"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Abstract operation `PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`.
+    ///
+    /// The source text is evaluated like the code of an indirect `eval` in `eval_realm`. Abrupt
+    /// completions never leak an object of the shadow realm: they are reported to the calling
+    /// realm as a `TypeError`.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+    fn perform_shadow_realm_eval(
+        source_text: &JsString,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let source_text = source_text.to_std_string_escaped();
+
+        // 1-11. Parse the script and check the early errors in the context of evalRealm.
+        // 12-24. Evaluate the script in a new execution context of evalRealm.
+        let caller_realm = context.enter_realm(eval_realm);
+        let result = context
+            .parse_eval(source_text.as_bytes(), false, false)
+            .map(|body| Eval::execute_indirect(&body, context));
+        context.enter_realm(caller_realm.clone());
+
+        match result {
+            // 7. If script is a List of errors, throw a SyntaxError exception.
+            Err(err) => Err(JsNativeError::syntax().with_message(err.to_string()).into()),
+            // 25. If result.[[Type]] is not normal, throw a TypeError exception.
+            Ok(Err(err)) => Err(JsNativeError::typ()
+                .with_message(format!(
+                    "ShadowRealm.prototype.evaluate: uncaught exception in the shadow realm: {err}"
+                ))
+                .into()),
+            // 26. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+            Ok(Ok(value)) => Self::get_wrapped_value(&caller_realm, &value, context),
+        }
+    }
+
+    /// Loads, links and evaluates the module `specifier` in the current realm, and returns the
+    /// value of its export `export_name`.
+    ///
+    /// This is the part of `ShadowRealmImportValue` that runs in the shadow realm.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+    fn import_export(
+        specifier: &JsString,
+        export_name: &JsString,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let specifier = specifier.to_std_string_escaped();
+
+        // 9. Perform HostLoadImportedModule(evalRealm, specifierString, ...).
+        //
+        // The referrer is created in the current realm, which is evalRealm.
+        let referrer = Module::parse("", None, context)?;
+        let module = context
+            .module_loader()
+            .load_imported_module(&referrer, &specifier, context)?;
+        module.load(context)?;
+        module.link(context)?;
+        module.evaluate(context)?;
+
+        // ExportGetter steps:
+        // 1. Let exports be ? GetModuleNamespace(m).
+        let exports = module.namespace(context)?;
+
+        // 4. If ! HasOwnProperty(exports, string) is false, throw a TypeError exception.
+        if !exports.has_own_property(export_name.clone(), context)? {
+            return Err(JsNativeError::typ()
+                .with_message("the module does not have such an export")
+                .into());
+        }
+
+        // 5. Let value be ? Get(exports, string).
+        exports.get(export_name.clone(), context)
+    }
+
+    /// Abstract operation `GetWrappedValue ( callerRealm, value )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+    pub(crate) fn get_wrapped_value(
+        caller_realm: &Realm,
+        value: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If Type(value) is Object, then
+        if let Some(object) = value.as_object() {
+            // a. If IsCallable(value) is false, throw a TypeError exception.
+            if !object.is_callable() {
+                return Err(JsNativeError::typ()
+                    .with_message("only primitives and callables can cross a ShadowRealm boundary")
+                    .into());
+            }
+
+            // b. Return ? WrappedFunctionCreate(callerRealm, value).
+            return Self::wrapped_function_create(caller_realm, object, context).map(Into::into);
+        }
+
+        // 2. Return value.
+        Ok(value.clone())
+    }
+
+    /// Abstract operation `WrappedFunctionCreate ( callerRealm, Target )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+    fn wrapped_function_create(
+        caller_realm: &Realm,
+        target: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsFunction> {
+        let previous = context.enter_realm(caller_realm.clone());
+
+        // 1-6. Create a function of callerRealm, whose [[Call]] is the one of wrapped function
+        //      exotic objects, with target as its [[WrappedTargetFunction]].
+        let wrapped =
+            FunctionBuilder::closure_with_captures(context, Self::wrapped_call, target.clone())
+                .build();
+
+        // 7. Let result be Completion(CopyNameAndLength(wrapped, Target)).
+        let result = Self::copy_name_and_length(&wrapped, target, context);
+
+        context.enter_realm(previous);
+
+        // 8. If result is an abrupt completion, throw a TypeError exception.
+        if result.is_err() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot copy the name and length of a wrapped function")
+                .into());
+        }
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    }
+
+    /// `[[Call]] ( thisArgument, argumentsList )` of wrapped function exotic objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+    fn wrapped_call(
+        this: &JsValue,
+        args: &[JsValue],
+        target: &mut JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let target be F.[[WrappedTargetFunction]].
+        // 3. Let callerRealm be F.[[Realm]].
+        //    The function is a closure of callerRealm, so callerRealm is the current realm.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let targetRealm be ? GetFunctionRealm(target).
+        let target_realm = target.get_function_realm(context)?;
+
+        // 6. Let wrappedArgs be a new empty List.
+        // 7. For each element arg of argumentsList, do
+        //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+        //     b. Append wrappedValue to wrappedArgs.
+        let wrapped_args = args
+            .iter()
+            .map(|arg| Self::get_wrapped_value(&target_realm, arg, context))
+            .collect::<JsResult<Vec<_>>>()?;
+
+        // 8. Let wrappedThisArgument to ? GetWrappedValue(targetRealm, thisArgument).
+        let wrapped_this = Self::get_wrapped_value(&target_realm, this, context)?;
+
+        // 9. Let result be the Completion Record of Call(target, wrappedThisArgument, wrappedArgs).
+        // 10. If result.[[Type]] is normal or result.[[Type]] is return, then
+        //     a. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        // 11. Else,
+        //     a. Throw a newly created TypeError object associated with callerRealm.
+        match target.call(&wrapped_this, &wrapped_args, context) {
+            Ok(result) => Self::get_wrapped_value(&caller_realm, &result, context),
+            Err(err) => Err(JsNativeError::typ()
+                .with_message(format!(
+                    "uncaught exception in a function of another realm: {err}"
+                ))
+                .into()),
+        }
+    }
+
+    /// Abstract operation `CopyNameAndLength ( F, Target )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+    fn copy_name_and_length(
+        function: &JsObject,
+        target: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. If argCount is undefined, then set argCount to 0.
+        // 2. Let L be 0.
+        let mut length = JsValue::new(0);
+
+        // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+        // 4. If targetHasLength is true, then
+        if target.has_own_property("length", context)? {
+            // a. Let targetLen be ? Get(Target, "length").
+            let target_len = target.get("length", context)?;
+
+            // b. If Type(targetLen) is Number, then
+            if target_len.is_number() {
+                match target_len
+                    .to_integer_or_infinity(context)
+                    .expect("to_integer_or_infinity cannot fail for a number")
+                {
+                    // i. If targetLen is +∞𝔽, then set L to +∞.
+                    IntegerOrInfinity::PositiveInfinity => length = f64::INFINITY.into(),
+                    // ii. Else if targetLen is -∞𝔽, then set L to 0.
+                    IntegerOrInfinity::NegativeInfinity => {}
+                    // iii. Else,
+                    //     1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                    //     2. Assert: targetLenAsInt is finite.
+                    //     3. Set L to max(targetLenAsInt - argCount, 0).
+                    IntegerOrInfinity::Integer(target_len) => length = target_len.max(0).into(),
+                }
+            }
+        }
+
+        // 5. Perform SetFunctionLength(F, L).
+        function.define_property_or_throw(
+            "length",
+            PropertyDescriptor::builder()
+                .value(length)
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        // 6. Let targetName be ? Get(Target, "name").
+        let target_name = target.get("name", context)?;
+
+        // 7. If Type(targetName) is not String, set targetName to the empty String.
+        let target_name = target_name
+            .as_string()
+            .map_or(JsString::new(""), Clone::clone);
+
+        // 8. Perform SetFunctionName(F, targetName, prefix).
+        function.define_property_or_throw(
+            "name",
+            PropertyDescriptor::builder()
+                .value(target_name)
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        Ok(())
+    }
+}
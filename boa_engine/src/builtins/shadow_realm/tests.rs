@@ -0,0 +1,194 @@
+use crate::{forward, module::MapModuleLoader, Context};
+use std::rc::Rc;
+
+#[test]
+fn construct() {
+    let mut context = Context::default();
+    forward(&mut context, "const realm = new ShadowRealm();");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(realm)"),
+        "\"[object ShadowRealm]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { ShadowRealm() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { ShadowRealm.prototype.evaluate.call({}, '1') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn evaluate() {
+    let mut context = Context::default();
+    let init = r#"
+        const realm = new ShadowRealm();
+        var outer = 1;
+        realm.evaluate("var outer = 2; let inner = 3;");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "realm.evaluate('1 + 2')"), "3");
+    assert_eq!(forward(&mut context, "outer"), "1");
+    assert_eq!(forward(&mut context, "realm.evaluate('outer')"), "2");
+    assert_eq!(
+        forward(&mut context, "realm.evaluate('typeof inner')"),
+        "\"undefined\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "realm.evaluate('globalThis.Array') === undefined"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('({})') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('throw new RangeError()') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('let') } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn separate_intrinsics() {
+    let mut context = Context::default();
+    let init = r#"
+        const realm = new ShadowRealm();
+        realm.evaluate("Array.prototype.first = function () { return this[0]; }");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "realm.evaluate('[1, 2].first()')"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Array.prototype.first"),
+        "\"undefined\""
+    );
+}
+
+#[test]
+fn wrapped_functions() {
+    let mut context = Context::default();
+    let init = r#"
+        const realm = new ShadowRealm();
+        const add = realm.evaluate("(function add(a, b) { return a + b; })");
+        const apply = realm.evaluate("(f, x) => f(x)");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "add(1, 2)"), "3");
+    assert_eq!(forward(&mut context, "add.name"), "\"add\"");
+    assert_eq!(forward(&mut context, "add.length"), "2");
+    assert_eq!(forward(&mut context, "add instanceof Function"), "true");
+    assert_eq!(forward(&mut context, "'prototype' in add"), "false");
+    assert_eq!(forward(&mut context, "apply(x => x * 2, 21)"), "42");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { apply(() => ({}), 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { add({}, 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new add(1, 2) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn import_value() {
+    let loader = MapModuleLoader::new().with_module(
+        "math",
+        "export const answer = 42; export function double(x) { return x * 2; }",
+    );
+    let mut context = Context::builder().module_loader(Rc::new(loader)).build();
+    let init = r#"
+        const realm = new ShadowRealm();
+        var results = [];
+        realm.importValue("math", "answer").then(v => results.push(v));
+        realm.importValue("math", "double").then(f => results.push(f(4)));
+        realm.importValue("math", "missing").catch(e => results.push(e instanceof TypeError));
+        realm.importValue("unknown", "answer").catch(e => results.push(e instanceof TypeError));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "results.join()"),
+        "\"42,8,true,true\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.importValue('math', 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn import_value_isolates_realms() {
+    let loader = MapModuleLoader::new().with_module(
+        "counter",
+        r#"
+        let count = 0;
+        export function increment() { return ++count; }
+        export function tenant() { return globalThis.tenant; }
+        "#,
+    );
+    let mut context = Context::builder().module_loader(Rc::new(loader)).build();
+    let init = r#"
+        const first = new ShadowRealm();
+        const second = new ShadowRealm();
+        first.evaluate("globalThis.tenant = 'first'");
+        second.evaluate("globalThis.tenant = 'second'");
+        var results = [];
+        first.importValue("counter", "increment").then(f => results.push(f(), f()));
+        second.importValue("counter", "increment").then(f => results.push(f()));
+        first.importValue("counter", "increment").then(f => results.push(f()));
+        first.importValue("counter", "tenant").then(f => results.push(f()));
+        second.importValue("counter", "tenant").then(f => results.push(f()));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "results.join()"),
+        "\"1,2,1,3,first,second\""
+    );
+}
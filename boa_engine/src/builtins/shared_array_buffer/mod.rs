@@ -0,0 +1,569 @@
+//! This module implements the global `SharedArrayBuffer` object.
+//!
+//! A `SharedArrayBuffer` is a fixed length buffer of raw binary data, like an `ArrayBuffer`,
+//! whose memory can be viewed at the same time by several agents, each of them running on its own
+//! thread. The memory is represented by [`SharedMemory`], which can be sent to other threads.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array_buffer::{copy_data_block_bytes, ArrayBuffer, DataBlock},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    vm::HeapCharge,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+use tap::{Conv, Pipe};
+
+/// A Shared Data Block: a block of memory that can be viewed by the `SharedArrayBuffer`s of
+/// several agents.
+///
+/// This is a cheap handle to the memory: clones of it refer to the same memory, and two handles
+/// compare equal if they refer to the same memory. Unlike the values of a [`Context`], it can be
+/// sent to other threads, where
+/// [`JsSharedArrayBuffer::from_memory`][crate::object::JsSharedArrayBuffer::from_memory] turns it
+/// into a `SharedArrayBuffer` of another context.
+#[derive(Clone)]
+pub struct SharedMemory {
+    inner: Arc<SharedBlock>,
+}
+
+struct SharedBlock {
+    bytes: Box<[AtomicU8]>,
+
+    /// The critical section of the block, which holds the agents waiting on the block.
+    ///
+    /// The atomic operations on the block are done while holding the lock, which makes them
+    /// indivisible and sequentially consistent with each other.
+    critical_section: Mutex<WaiterList>,
+
+    /// Wakes up the agents waiting on the block.
+    waiters_condvar: Condvar,
+}
+
+/// The agents waiting on a block, in the order they started to wait.
+#[derive(Default)]
+struct WaiterList {
+    waiters: Vec<Waiter>,
+    next_id: u64,
+}
+
+struct Waiter {
+    id: u64,
+    byte_index: usize,
+}
+
+/// The longest time that [`SharedMemory::wait`] suspends the thread before checking if the
+/// execution was interrupted.
+const WAIT_SLICE: Duration = Duration::from_millis(10);
+
+/// The result of [`SharedMemory::wait`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WaitResult {
+    /// The value was not the expected one, so the agent did not wait.
+    NotEqual,
+    /// The agent was woken up by a call to [`SharedMemory::notify`].
+    Ok,
+    /// The agent was not woken up before the timeout.
+    TimedOut,
+    /// The agent stopped waiting because its execution was interrupted.
+    Interrupted,
+}
+
+impl fmt::Debug for SharedMemory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedMemory")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for SharedMemory {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for SharedMemory {}
+
+impl SharedMemory {
+    /// `6.2.8.2 CreateSharedByteDataBlock ( size )`
+    ///
+    /// Creates a new block of `size` bytes, all set to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RangeError` if the block cannot be allocated.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createsharedbytedatablock
+    pub fn new(size: u64) -> JsResult<Self> {
+        // 1. Let db be a new Shared Data Block value consisting of size bytes. If it is impossible to
+        //    create such a Shared Data Block, throw a RangeError exception.
+        let mut bytes = Vec::new();
+        bytes.try_reserve(size as usize).map_err(|e| {
+            JsNativeError::range()
+                .with_message(format!("couldn't allocate the shared data block: {e}"))
+        })?;
+
+        // 2-5. Set all of the bytes of db to 0.
+        bytes.resize_with(size as usize, || AtomicU8::new(0));
+
+        // 6. Return db.
+        Ok(Self {
+            inner: Arc::new(SharedBlock {
+                bytes: bytes.into_boxed_slice(),
+                critical_section: Mutex::default(),
+                waiters_condvar: Condvar::new(),
+            }),
+        })
+    }
+
+    /// Returns the number of bytes of the memory.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.bytes.len()
+    }
+
+    /// Returns `true` if the memory has a length of zero bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.bytes.is_empty()
+    }
+
+    /// Copies the bytes of the memory starting at `index` into `buf`.
+    ///
+    /// Every byte is read atomically, but the writes of other agents can be observed in the
+    /// middle of the copy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index + buf.len()` is greater than the length of the memory.
+    pub fn read(&self, index: usize, buf: &mut [u8]) {
+        let bytes = &self.inner.bytes[index..index + buf.len()];
+        for (byte, shared) in buf.iter_mut().zip(bytes) {
+            *byte = shared.load(Ordering::Relaxed);
+        }
+    }
+
+    /// Stores `bytes` into the memory, starting at `index`.
+    ///
+    /// Every byte is written atomically, but the reads of other agents can observe the memory in
+    /// the middle of the copy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index + bytes.len()` is greater than the length of the memory.
+    pub fn write(&self, index: usize, bytes: &[u8]) {
+        let shared = &self.inner.bytes[index..index + bytes.len()];
+        for (shared, byte) in shared.iter().zip(bytes) {
+            shared.store(*byte, Ordering::Relaxed);
+        }
+    }
+
+    /// Runs `f` in the critical section of the block.
+    pub(crate) fn atomically<R>(&self, f: impl FnOnce() -> R) -> R {
+        let _guard = self.lock();
+        f()
+    }
+
+    /// Suspends the current thread until another agent wakes it up with [`SharedMemory::notify`],
+    /// or until `timeout` elapses.
+    ///
+    /// `is_expected` is called in the critical section before suspending the thread, and the
+    /// thread is not suspended if it returns `false`. A `timeout` of `None` waits forever.
+    ///
+    /// The thread wakes up every [`WAIT_SLICE`] to call `is_interrupted`, and stops waiting if it
+    /// returns `true`, so the host can still interrupt an agent that is never notified.
+    pub(crate) fn wait(
+        &self,
+        byte_index: usize,
+        is_expected: impl FnOnce() -> bool,
+        timeout: Option<Duration>,
+        mut is_interrupted: impl FnMut() -> bool,
+    ) -> WaitResult {
+        let mut list = self.lock();
+        if !is_expected() {
+            return WaitResult::NotEqual;
+        }
+
+        let id = list.next_id;
+        list.next_id += 1;
+        list.waiters.push(Waiter { id, byte_index });
+
+        // A timeout too long to be represented is the same as no timeout.
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+
+        // The waiter is removed from the list by the agent that notifies it, so the thread keeps
+        // waiting after spurious wake ups until it is not in the list anymore.
+        while list.waiters.iter().any(|waiter| waiter.id == id) {
+            if is_interrupted() {
+                list.waiters.retain(|waiter| waiter.id != id);
+                return WaitResult::Interrupted;
+            }
+
+            let slice = match deadline {
+                None => WAIT_SLICE,
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        list.waiters.retain(|waiter| waiter.id != id);
+                        return WaitResult::TimedOut;
+                    }
+                    WAIT_SLICE.min(deadline - now)
+                }
+            };
+            list = self
+                .inner
+                .waiters_condvar
+                .wait_timeout(list, slice)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+
+        WaitResult::Ok
+    }
+
+    /// Wakes up at most `count` of the agents waiting on `byte_index`, in the order they
+    /// started to wait, and returns the number of agents woken up.
+    pub(crate) fn notify(&self, byte_index: usize, count: u64) -> u64 {
+        let mut list = self.lock();
+        let mut notified = 0;
+        list.waiters.retain(|waiter| {
+            if notified < count && waiter.byte_index == byte_index {
+                notified += 1;
+                false
+            } else {
+                true
+            }
+        });
+
+        if notified > 0 {
+            self.inner.waiters_condvar.notify_all();
+        }
+
+        notified
+    }
+
+    fn lock(&self) -> MutexGuard<'_, WaiterList> {
+        // The waiter list is never left in an inconsistent state, so a panic of another thread
+        // while holding the lock can be ignored.
+        self.inner
+            .critical_section
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The global `SharedArrayBuffer` object.
+///
+/// `SharedArrayBuffer` objects are represented by the same [`ArrayBuffer`] data as
+/// `ArrayBuffer` objects, with a [`DataBlock::Shared`] as their data.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SharedArrayBuffer;
+
+impl BuiltIn for SharedArrayBuffer {
+    const NAME: &'static str = "SharedArrayBuffer";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let flag_attributes = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructor(false)
+            .build();
+
+        let get_byte_length = FunctionBuilder::native(context, Self::get_byte_length)
+            .name("get byteLength")
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .shared_array_buffer()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor("byteLength", Some(get_byte_length), None, flag_attributes)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::slice, "slice", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl SharedArrayBuffer {
+    const LENGTH: usize = 1;
+
+    /// `25.2.2.1 SharedArrayBuffer ( length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-length
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("SharedArrayBuffer.constructor called with undefined new target")
+                .into());
+        }
+
+        // 2. Let byteLength be ? ToIndex(length).
+        let byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        // 3. Return ? AllocateSharedArrayBuffer(NewTarget, byteLength).
+        Ok(Self::allocate(new_target, byte_length, context)?.into())
+    }
+
+    /// `25.2.3.2 get SharedArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-@@species
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `25.2.4.1 get SharedArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.bytelength
+    pub(crate) fn get_byte_length(
+        this: &JsValue,
+        _args: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let length = this
+            .as_object()
+            .and_then(|obj| {
+                obj.borrow()
+                    .as_array_buffer()
+                    .filter(|buffer| buffer.is_shared_array_buffer())
+                    .map(ArrayBuffer::array_buffer_byte_length)
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("SharedArrayBuffer.byteLength called with invalid object")
+            })?;
+
+        // 4. Let length be O.[[ArrayBufferByteLength]].
+        // 5. Return 𝔽(length).
+        Ok(length.into())
+    }
+
+    /// `25.2.4.3 SharedArrayBuffer.prototype.slice ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.slice
+    fn slice(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let (obj, block) = this
+            .as_object()
+            .and_then(|obj| {
+                let block = match obj.borrow().as_array_buffer()?.array_buffer_data {
+                    Some(DataBlock::Shared(ref block)) => block.clone(),
+                    _ => return None,
+                };
+                Some((obj, block))
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("SharedArrayBuffer.slice called with invalid object")
+            })?;
+
+        // 4. Let len be O.[[ArrayBufferByteLength]].
+        let len = block.len() as i64;
+
+        // 5. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        let first = match relative_start {
+            // 6. If relativeStart is -∞, let first be 0.
+            IntegerOrInfinity::NegativeInfinity => 0,
+            // 7. Else if relativeStart < 0, let first be max(len + relativeStart, 0).
+            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(len + i, 0),
+            // 8. Else, let first be min(relativeStart, len).
+            IntegerOrInfinity::Integer(i) => std::cmp::min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        };
+
+        // 9. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end = args.get_or_undefined(1);
+        let relative_end = if end.is_undefined() {
+            IntegerOrInfinity::Integer(len)
+        } else {
+            end.to_integer_or_infinity(context)?
+        };
+
+        let r#final = match relative_end {
+            // 10. If relativeEnd is -∞, let final be 0.
+            IntegerOrInfinity::NegativeInfinity => 0,
+            // 11. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(len + i, 0),
+            // 12. Else, let final be min(relativeEnd, len).
+            IntegerOrInfinity::Integer(i) => std::cmp::min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        };
+
+        // 13. Let newLen be max(final - first, 0).
+        let new_len = std::cmp::max(r#final - first, 0) as u64;
+
+        // 14. Let ctor be ? SpeciesConstructor(O, %SharedArrayBuffer%).
+        let ctor = obj.species_constructor(StandardConstructors::shared_array_buffer, context)?;
+
+        // 15. Let new be ? Construct(ctor, « 𝔽(newLen) »).
+        let new = ctor.construct(&[new_len.into()], Some(&ctor), context)?;
+
+        {
+            let mut new_obj = new.borrow_mut();
+
+            // 16. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
+            // 17. If IsSharedArrayBuffer(new) is false, throw a TypeError exception.
+            let new_array_buffer = new_obj
+                .as_array_buffer_mut()
+                .filter(|buffer| buffer.is_shared_array_buffer())
+                .ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("SharedArrayBuffer constructor returned invalid object")
+                })?;
+
+            // 18. If new.[[ArrayBufferData]] and O.[[ArrayBufferData]] are the same Shared Data Block values, throw a TypeError exception.
+            let to_block = new_array_buffer
+                .array_buffer_data
+                .as_mut()
+                .expect("SharedArrayBuffers cannot be detached");
+            if matches!(to_block, DataBlock::Shared(ref to_block) if *to_block == block) {
+                return Err(JsNativeError::typ()
+                    .with_message("New SharedArrayBuffer is the same as this SharedArrayBuffer")
+                    .into());
+            }
+
+            // 19. If new.[[ArrayBufferByteLength]] < newLen, throw a TypeError exception.
+            if new_array_buffer.array_buffer_byte_length < new_len {
+                return Err(JsNativeError::typ()
+                    .with_message("New SharedArrayBuffer length too small")
+                    .into());
+            }
+
+            // 20. Let fromBuf be O.[[ArrayBufferData]].
+            // 21. Let toBuf be new.[[ArrayBufferData]].
+            // 22. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
+            copy_data_block_bytes(
+                to_block,
+                0,
+                &DataBlock::Shared(block),
+                first as usize,
+                new_len as usize,
+            );
+        }
+
+        // 23. Return new.
+        Ok(new.into())
+    }
+
+    /// `25.2.1.1 AllocateSharedArrayBuffer ( constructor, byteLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatesharedarraybuffer
+    pub(crate) fn allocate(
+        constructor: &JsValue,
+        byte_length: u64,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%SharedArrayBuffer.prototype%", « [[ArrayBufferData]], [[ArrayBufferByteLength]] »).
+        let prototype = get_prototype_from_constructor(
+            constructor,
+            StandardConstructors::shared_array_buffer,
+            context,
+        )?;
+
+        // 2. Let block be ? CreateSharedByteDataBlock(byteLength).
+        let block = SharedMemory::new(byte_length)?;
+
+        // 3. Set obj.[[ArrayBufferData]] to block.
+        // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 5. Return obj.
+        Ok(Self::create(prototype, block))
+    }
+
+    /// Creates a new `SharedArrayBuffer` object with prototype `prototype`, that views `block`.
+    pub(crate) fn create(prototype: JsObject, block: SharedMemory) -> JsObject {
+        let byte_length = block.len() as u64;
+        JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::array_buffer(ArrayBuffer {
+                array_buffer_data: Some(DataBlock::Shared(block)),
+                array_buffer_byte_length: byte_length,
+                array_buffer_max_byte_length: None,
+                array_buffer_detach_key: JsValue::Undefined,
+                data_charge: HeapCharge::default(),
+            }),
+        )
+    }
+}
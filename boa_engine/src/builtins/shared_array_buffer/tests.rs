@@ -0,0 +1,102 @@
+use super::SharedMemory;
+use crate::{forward, forward_val, object::JsSharedArrayBuffer, property::Attribute, Context};
+use std::thread;
+
+#[test]
+fn construct() {
+    let mut context = Context::default();
+    forward(&mut context, "const sab = new SharedArrayBuffer(8);");
+    assert_eq!(forward(&mut context, "sab.byteLength"), "8");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(sab)"),
+        "\"[object SharedArrayBuffer]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { SharedArrayBuffer(8) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.getOwnPropertyDescriptor(ArrayBuffer.prototype, 'byteLength').get.call(sab) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.getOwnPropertyDescriptor(SharedArrayBuffer.prototype, 'byteLength').get.call(new ArrayBuffer(8)) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn slice() {
+    let mut context = Context::default();
+    let init = r#"
+        const sab = new SharedArrayBuffer(8);
+        const view = new Uint8Array(sab);
+        view.set([1, 2, 3, 4, 5, 6, 7, 8]);
+        const sliced = sab.slice(2, -2);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sliced.byteLength"), "4");
+    assert_eq!(
+        forward(&mut context, "sliced instanceof SharedArrayBuffer"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(sliced).join()"),
+        "\"3,4,5,6\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(sliced)[0] = 0; view[2]"),
+        "3",
+        "the slice must be a copy"
+    );
+}
+
+#[test]
+fn views_share_memory() {
+    let mut context = Context::default();
+    let init = r#"
+        const sab = new SharedArrayBuffer(8);
+        const bytes = new Uint8Array(sab);
+        const words = new Int32Array(sab);
+        const view = new DataView(sab);
+        words[1] = -2;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "bytes[4]"), "254");
+    assert_eq!(forward(&mut context, "view.getInt32(4, true)"), "-2");
+}
+
+#[test]
+fn memory_is_shared_between_threads() {
+    let memory = SharedMemory::new(8).unwrap();
+
+    let writer = {
+        let memory = memory.clone();
+        thread::spawn(move || {
+            let mut context = Context::default();
+            let sab = JsSharedArrayBuffer::from_memory(memory, &mut context).unwrap();
+            context.register_global_property("sab", sab, Attribute::all());
+            forward_val(&mut context, "Atomics.store(new Int32Array(sab), 1, 42)").unwrap();
+        })
+    };
+    writer.join().unwrap();
+
+    let mut context = Context::default();
+    let sab = JsSharedArrayBuffer::from_memory(memory.clone(), &mut context).unwrap();
+    assert_eq!(sab.byte_length(), 8);
+    assert_eq!(sab.memory(), memory);
+    context.register_global_property("sab", sab, Attribute::all());
+    assert_eq!(
+        forward(&mut context, "Atomics.load(new Int32Array(sab), 1)"),
+        "42"
+    );
+}
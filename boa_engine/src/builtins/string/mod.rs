@@ -17,54 +17,34 @@ use super::JsArgs;
 use crate::{
     builtins::{string::string_iterator::StringIterator, Array, BuiltIn, Number, RegExp},
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    js_string,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
     },
     property::{Attribute, PropertyDescriptor},
+    string::{utf16, CodePoint},
     symbol::WellKnownSymbols,
-    value::IntegerOrInfinity,
+    value::{IntegerOrInfinity, JsVariant},
     Context, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
 use std::{
-    char::from_u32,
     cmp::{max, min},
     string::String as StdString,
 };
 use tap::{Conv, Pipe};
 use unicode_normalization::UnicodeNormalization;
 
+#[cfg(feature = "intl")]
+use crate::builtins::intl::collator::Collator;
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub(crate) enum Placement {
     Start,
     End,
 }
 
-pub(crate) fn code_point_at(string: &JsString, position: u64) -> (u32, u8, bool) {
-    let mut encoded = string.encode_utf16();
-    let size = encoded.clone().count() as u64;
-
-    let first = encoded
-        .nth(position as usize)
-        .expect("The callers of this function must've already checked bounds.");
-    if !is_leading_surrogate(first) && !is_trailing_surrogate(first) {
-        return (u32::from(first), 1, false);
-    }
-
-    if is_trailing_surrogate(first) || position + 1 == size {
-        return (u32::from(first), 1, true);
-    }
-
-    let second = encoded
-        .next()
-        .expect("The callers of this function must've already checked bounds.");
-    if !is_trailing_surrogate(second) {
-        return (u32::from(first), 1, true);
-    }
-    let cp = (u32::from(first) - 0xD800) * 0x400 + (u32::from(second) - 0xDC00) + 0x10000;
-    (cp, 2, false)
-}
-
 /// Helper function to check if a `char` is trimmable.
 #[inline]
 pub(crate) fn is_trimmable_whitespace(c: char) -> bool {
@@ -86,14 +66,6 @@ pub(crate) fn is_trimmable_whitespace(c: char) -> bool {
     )
 }
 
-pub(crate) fn is_leading_surrogate(value: u16) -> bool {
-    (0xD800..=0xDBFF).contains(&value)
-}
-
-pub(crate) fn is_trailing_surrogate(value: u16) -> bool {
-    (0xDC00..=0xDFFF).contains(&value)
-}
-
 /// JavaScript `String` implementation.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct String;
@@ -131,6 +103,7 @@ impl BuiltIn for String {
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
         .method(Self::r#match, "match", 1)
+        .method(Self::locale_compare, "localeCompare", 1)
         .method(Self::normalize, "normalize", 1)
         .method(Self::pad_end, "padEnd", 1)
         .method(Self::pad_start, "padStart", 1)
@@ -179,8 +152,9 @@ impl String {
         let string = match args.get(0) {
             // 2. Else,
             // a. If NewTarget is undefined and Type(value) is Symbol, return SymbolDescriptiveString(value).
-            Some(JsValue::Symbol(ref sym)) if new_target.is_undefined() => {
-                return Ok(sym.descriptive_string().into())
+            Some(value) if value.is_symbol() && new_target.is_undefined() => {
+                let sym = value.as_symbol().expect("value must be a symbol");
+                return Ok(sym.descriptive_string().into());
             }
             // b. Let s be ? ToString(value).
             Some(value) => value.to_string(context)?,
@@ -208,7 +182,7 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-stringcreate
     fn string_create(value: JsString, prototype: JsObject, context: &mut Context) -> JsObject {
         // 7. Let length be the number of code unit elements in value.
-        let len = value.encode_utf16().count();
+        let len = value.len();
 
         // 1. Let S be ! MakeBasicObject(« [[Prototype]], [[Extensible]], [[StringData]] »).
         // 2. Set S.[[Prototype]] to prototype.
@@ -241,7 +215,7 @@ impl String {
     /// - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#thisstringvalue
-    fn this_string_value(this: &JsValue, context: &mut Context) -> JsResult<JsString> {
+    fn this_string_value(this: &JsValue) -> JsResult<JsString> {
         // 1. If Type(value) is String, return value.
         this.as_string()
             .cloned()
@@ -251,7 +225,11 @@ impl String {
             //     c. Return s.
             .or_else(|| this.as_object().and_then(|obj| obj.borrow().as_string()))
             // 3. Throw a TypeError exception.
-            .ok_or_else(|| context.construct_type_error("'this' is not a string"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a string")
+                    .into()
+            })
     }
 
     /// `String.fromCodePoint(num1[, ...[, numN]])`
@@ -270,7 +248,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let result be the empty String.
-        let mut result = StdString::new();
+        let mut result = Vec::new();
 
         // 2. For each element next of codePoints, do
         for arg in args.iter() {
@@ -279,22 +257,30 @@ impl String {
 
             // b. If ! IsIntegralNumber(nextCP) is false, throw a RangeError exception.
             if !Number::is_float_integer(nextcp) {
-                return Err(context.construct_range_error(format!("invalid code point: {nextcp}")));
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid code point: {nextcp}"))
+                    .into());
             }
 
             // c. If ℝ(nextCP) < 0 or ℝ(nextCP) > 0x10FFFF, throw a RangeError exception.
             if nextcp < 0.0 || nextcp > f64::from(0x10FFFF) {
-                return Err(context.construct_range_error(format!("invalid code point: {nextcp}")));
+                return Err(JsNativeError::range()
+                    .with_message(format!("invalid code point: {nextcp}"))
+                    .into());
             }
 
-            // TODO: Full UTF-16 support
             // d. Set result to the string-concatenation of result and ! UTF16EncodeCodePoint(ℝ(nextCP)).
-            result.push(char::try_from(nextcp as u32).unwrap_or('\u{FFFD}' /* replacement char */));
+            let nextcp = nextcp as u32;
+            let code_point = char::from_u32(nextcp).map_or(
+                CodePoint::UnpairedSurrogate(nextcp as u16),
+                CodePoint::Unicode,
+            );
+            result.extend_from_slice(code_point.encode_utf16(&mut [0; 2]));
         }
 
         // 3. Assert: If codePoints is empty, then result is the empty String.
         // 4. Return result.
-        Ok(result.into())
+        Ok(JsString::from(result).into())
     }
 
     /// `String.prototype.raw( template, ...substitutions )`
@@ -340,13 +326,13 @@ impl String {
             let next_seg = raw.get(next_key, context)?.to_string(context)?;
 
             // c. Append the code unit elements of nextSeg to the end of stringElements.
-            string_elements.extend(next_seg.encode_utf16());
+            string_elements.extend_from_slice(&next_seg);
 
             // d. If nextIndex + 1 = literalSegments, then
             if next_index + 1 == literal_segments {
                 // i. Return the String value whose code units are the elements in the List stringElements.
                 //    If stringElements has no elements, the empty String is returned.
-                return Ok(StdString::from_utf16_lossy(&string_elements).into());
+                return Ok(JsString::from(string_elements).into());
             }
 
             // e. If nextIndex < numberOfSubstitutions, let next be substitutions[nextIndex].
@@ -362,7 +348,7 @@ impl String {
             let next_sub = next.to_string(context)?;
 
             // h. Append the code unit elements of nextSub to the end of stringElements.
-            string_elements.extend(next_sub.encode_utf16());
+            string_elements.extend_from_slice(&next_sub);
 
             // i. Set nextIndex to nextIndex + 1.
             next_index += 1;
@@ -393,9 +379,7 @@ impl String {
 
         // 4. Return the String value whose code units are the elements in the List elements.
         //    If codeUnits is empty, the empty String is returned.
-
-        let s = std::string::String::from_utf16_lossy(elements.as_slice());
-        Ok(JsValue::String(JsString::new(s)))
+        Ok(JsValue::String(JsString::from(elements)))
     }
 
     /// `String.prototype.toString ( )`
@@ -406,13 +390,9 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.tostring
     #[allow(clippy::wrong_self_convention)]
     #[inline]
-    pub(crate) fn to_string(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Return ? thisStringValue(this value).
-        Ok(Self::this_string_value(this, context)?.into())
+        Ok(Self::this_string_value(this)?.into())
     }
 
     /// `String.prototype.charAt( index )`
@@ -437,26 +417,20 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
 
         // 4. Let size be the length of S.
-        let size = string.encode_utf16().count() as i64;
+        let size = string.len() as i64;
 
         // 3. Let position be ? ToIntegerOrInfinity(pos).
         match args.get_or_undefined(0).to_integer_or_infinity(context)? {
             IntegerOrInfinity::Integer(position) if (0..size).contains(&position) => {
                 // 6. Return the substring of S from position to position + 1.
-                let char = string
-                    .encode_utf16()
-                    .nth(position as usize)
-                    .expect("Already checked bounds above");
-
-                Ok(char::try_from(u32::from(char))
-                    .unwrap_or('\u{FFFD}' /* replacement char */)
-                    .into())
+                let position = position as usize;
+                Ok(JsString::from(&string[position..=position]).into())
             }
             _ => {
                 // 5. If position < 0 or position ≥ size, return the empty String.
@@ -478,13 +452,13 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/at
     pub(crate) fn at(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let s = this.to_string(context)?;
 
         // 3. Let len be the length of S.
-        let len = s.encode_utf16().count() as i64;
+        let len = s.len() as i64;
 
         // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
         let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
@@ -500,13 +474,7 @@ impl String {
         };
 
         // 8. Return the substring of S from k to k + 1.
-        if let Some(utf16_val) = s.encode_utf16().nth(k) {
-            Ok(JsValue::new(
-                from_u32(u32::from(utf16_val)).expect("invalid utf-16 character"),
-            ))
-        } else {
-            Ok(JsValue::undefined())
-        }
+        Ok(JsString::from(&s[k..=k]).into())
     }
 
     /// `String.prototype.codePointAt( index )`
@@ -529,7 +497,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -538,13 +506,13 @@ impl String {
         let position = args.get_or_undefined(0).to_integer_or_infinity(context)?;
 
         // 4. Let size be the length of S.
-        let size = string.encode_utf16().count() as i64;
+        let size = string.len() as i64;
 
         match position {
             IntegerOrInfinity::Integer(position) if (0..size).contains(&position) => {
                 // 6. Let cp be ! CodePointAt(S, position).
                 // 7. Return 𝔽(cp.[[CodePoint]]).
-                Ok(code_point_at(&string, position as u64).0.into())
+                Ok(string.code_point_at(position as usize).as_u32().into())
             }
             // 5. If position < 0 or position ≥ size, return undefined.
             _ => Ok(JsValue::undefined()),
@@ -571,7 +539,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -580,18 +548,12 @@ impl String {
         let position = args.get_or_undefined(0).to_integer_or_infinity(context)?;
 
         // 4. Let size be the length of S.
-        let size = string.encode_utf16().count() as i64;
+        let size = string.len() as i64;
 
         match position {
             IntegerOrInfinity::Integer(position) if (0..size).contains(&position) => {
                 // 6. Return the Number value for the numeric value of the code unit at index position within the String S.
-                let char_code = u32::from(
-                    string
-                        .encode_utf16()
-                        .nth(position as usize)
-                        .expect("Already checked bounds above."),
-                );
-                Ok(char_code.into())
+                Ok(u32::from(string[position as usize]).into())
             }
             // 5. If position < 0 or position ≥ size, return NaN.
             _ => Ok(JsValue::nan()),
@@ -618,21 +580,21 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
-        let mut string = this.to_string(context)?.to_string();
+        let mut string = this.to_string(context)?.to_vec();
 
         // 3. Let R be S.
         // 4. For each element next of args, do
         for arg in args {
             // a. Let nextString be ? ToString(next).
             // b. Set R to the string-concatenation of R and nextString.
-            string.push_str(&arg.to_string(context)?);
+            string.extend_from_slice(&arg.to_string(context)?);
         }
 
         // 5. Return R.
-        Ok(JsValue::new(string))
+        Ok(JsString::from(string).into())
     }
 
     /// `String.prototype.repeat( count )`
@@ -652,12 +614,12 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
 
-        let len = string.encode_utf16().count();
+        let len = string.len();
 
         // 3. Let n be ? ToIntegerOrInfinity(count).
         match args.get_or_undefined(0).to_integer_or_infinity(context)? {
@@ -667,23 +629,19 @@ impl String {
                 if string.is_empty() {
                     return Ok("".into());
                 }
-                let n = n as usize;
-                let mut result = std::string::String::with_capacity(n * len);
-
-                std::iter::repeat(&string[..])
-                    .take(n)
-                    .for_each(|s| result.push_str(s));
-
+                context.reserve_heap((n as usize) * len * std::mem::size_of::<u16>())?;
                 // 6. Return the String value that is made from n copies of S appended together.
-                Ok(result.into())
+                Ok(JsString::from(string.repeat(n as usize)).into())
             }
             // 5. If n is 0, return the empty String.
             IntegerOrInfinity::Integer(n) if n == 0 => Ok("".into()),
             // 4. If n < 0 or n is +∞, throw a RangeError exception.
-            _ => context.throw_range_error(
-                "repeat count must be a positive finite number \
+            _ => Err(JsNativeError::range()
+                .with_message(
+                    "repeat count must be a positive finite number \
                         that doesn't overflow the maximum string length (2^32 - 1)",
-            ),
+                )
+                .into()),
         }
     }
 
@@ -703,13 +661,13 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
 
         // 3. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 4. Let intStart be ? ToIntegerOrInfinity(start).
         let from = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
@@ -748,10 +706,7 @@ impl String {
             Ok("".into())
         } else {
             // 13. Return the substring of S from from to to.
-            let span = to - from;
-            let substring_utf16: Vec<u16> = string.encode_utf16().skip(from).take(span).collect();
-            let substring_lossy = StdString::from_utf16_lossy(&substring_utf16);
-            Ok(substring_lossy.into())
+            Ok(JsString::from(&string[from..to]).into())
         }
     }
 
@@ -771,7 +726,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -781,28 +736,32 @@ impl String {
         // 3. Let isRegExp be ? IsRegExp(searchString).
         // 4. If isRegExp is true, throw a TypeError exception.
         if is_reg_exp(search_string, context)? {
-            context.throw_type_error(
-                "First argument to String.prototype.startsWith must not be a regular expression",
-            )?;
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "First argument to String.prototype.startsWith must not be a regular expression",
+                )
+                .into());
         }
 
         // 5. Let searchStr be ? ToString(searchString).
         let search_string = search_string.to_string(context)?;
 
         // 6. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 7. If position is undefined, let pos be 0; else let pos be ? ToIntegerOrInfinity(position).
-        let pos = match args.get_or_undefined(1) {
-            &JsValue::Undefined => IntegerOrInfinity::Integer(0),
-            position => position.to_integer_or_infinity(context)?,
+        let position = args.get_or_undefined(1);
+        let pos = if position.is_undefined() {
+            IntegerOrInfinity::Integer(0)
+        } else {
+            position.to_integer_or_infinity(context)?
         };
 
         // 8. Let start be the result of clamping pos between 0 and len.
         let start = pos.clamp_finite(0, len) as usize;
 
         // 9. Let searchLength be the length of searchStr.
-        let search_length = search_string.encode_utf16().count();
+        let search_length = search_string.len();
 
         // 10. If searchLength = 0, return true.
         if search_length == 0 {
@@ -820,9 +779,7 @@ impl String {
             // 14. Return ! SameValueNonNumeric(substring, searchStr).
             // `SameValueNonNumeric` forwards to `==`, so directly check
             // equality to avoid converting to `JsValue`
-            let substring_utf16 = string.encode_utf16().skip(start).take(search_length);
-            let search_str_utf16 = search_string.encode_utf16();
-            Ok(JsValue::new(substring_utf16.eq(search_str_utf16)))
+            Ok(JsValue::new(string[start..end] == search_string[..]))
         }
     }
 
@@ -842,7 +799,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -851,16 +808,14 @@ impl String {
             // 3. Let isRegExp be ? IsRegExp(searchString).
             // 4. If isRegExp is true, throw a TypeError exception.
             search_string if is_reg_exp(search_string, context)? => {
-                return context.throw_type_error(
-                    "First argument to String.prototype.endsWith must not be a regular expression",
-                );
+                return Err(JsNativeError::typ().with_message("First argument to String.prototype.endsWith must not be a regular expression").into());
             }
             // 5. Let searchStr be ? ToString(searchString).
             search_string => search_string.to_string(context)?,
         };
 
         // 6. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 7. If endPosition is undefined, let pos be len; else let pos be ? ToIntegerOrInfinity(endPosition).
         let end = match args.get_or_undefined(1) {
@@ -872,7 +827,7 @@ impl String {
         let end = end.clamp_finite(0, len) as usize;
 
         // 9. Let searchLength be the length of searchStr.
-        let search_length = search_str.encode_utf16().count();
+        let search_length = search_str.len();
 
         // 10. If searchLength = 0, return true.
         if search_length == 0 {
@@ -885,11 +840,7 @@ impl String {
             // 14. Return ! SameValueNonNumeric(substring, searchStr).
             // `SameValueNonNumeric` forwards to `==`, so directly check
             // equality to avoid converting to `JsValue`
-
-            let substring_utf16 = string.encode_utf16().skip(start).take(search_length);
-            let search_str_utf16 = search_str.encode_utf16();
-
-            Ok(JsValue::new(substring_utf16.eq(search_str_utf16)))
+            Ok(JsValue::new(string[start..end] == search_str[..]))
         } else {
             // 12. If start < 0, return false.
             Ok(false.into())
@@ -912,7 +863,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -920,10 +871,8 @@ impl String {
         let search_str = match args.get_or_undefined(0) {
             // 3. Let isRegExp be ? IsRegExp(searchString).
             search_string if is_reg_exp(search_string, context)? => {
-                return context.throw_type_error(
-                    // 4. If isRegExp is true, throw a TypeError exception.
-                    "First argument to String.prototype.includes must not be a regular expression",
-                );
+                return Err(JsNativeError::typ().with_message(// 4. If isRegExp is true, throw a TypeError exception.
+                    "First argument to String.prototype.includes must not be a regular expression").into());
             }
             // 5. Let searchStr be ? ToString(searchString).
             search_string => search_string.to_string(context)?,
@@ -935,7 +884,7 @@ impl String {
 
         // 8. Let len be the length of S.
         // 9. Let start be the result of clamping pos between 0 and len.
-        let start = pos.clamp_finite(0, string.encode_utf16().count() as i64) as usize;
+        let start = pos.clamp_finite(0, string.len() as i64) as usize;
 
         // 10. Let index be ! StringIndexOf(S, searchStr, start).
         // 11. If index is not -1, return true.
@@ -964,7 +913,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        this.require_object_coercible(context)?;
+        this.require_object_coercible()?;
 
         let search_value = args.get_or_undefined(0);
 
@@ -1013,9 +962,7 @@ impl String {
         };
 
         // 10. Let preserved be the substring of string from 0 to position.
-        let preserved = StdString::from_utf16_lossy(
-            &this_str.encode_utf16().take(position).collect::<Vec<u16>>(),
-        );
+        let preserved = &this_str[..position];
 
         // 11. If functionalReplace is true, then
         // 12. Else,
@@ -1035,8 +982,8 @@ impl String {
 
             // c. Let replacement be ! GetSubstitution(searchString, string, position, captures, undefined, replaceValue).
             get_substitution(
-                search_str.as_str(),
-                this_str.as_str(),
+                &search_str,
+                &this_str,
                 position,
                 &captures,
                 &JsValue::undefined(),
@@ -1046,14 +993,10 @@ impl String {
         };
 
         // 13. Return the string-concatenation of preserved, replacement, and the substring of string from position + searchLength.
-        Ok(format!(
-            "{preserved}{replacement}{}",
-            StdString::from_utf16_lossy(
-                &this_str
-                    .encode_utf16()
-                    .skip(position + search_length)
-                    .collect::<Vec<u16>>()
-            )
+        Ok(js_string!(
+            preserved,
+            &replacement,
+            &this_str[position + search_length..]
         )
         .into())
     }
@@ -1080,7 +1023,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let o = this.require_object_coercible(context)?;
+        let o = this.require_object_coercible()?;
 
         let search_value = args.get_or_undefined(0);
         let replace_value = args.get_or_undefined(1);
@@ -1095,13 +1038,11 @@ impl String {
                     let flags = obj.get("flags", context)?;
 
                     // ii. Perform ? RequireObjectCoercible(flags).
-                    flags.require_object_coercible(context)?;
+                    flags.require_object_coercible()?;
 
                     // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
-                    if !flags.to_string(context)?.contains('g') {
-                        return context.throw_type_error(
-                            "String.prototype.replaceAll called with a non-global RegExp argument",
-                        );
+                    if !flags.to_string(context)?.contains(&u16::from(b'g')) {
+                        return Err(JsNativeError::typ().with_message("String.prototype.replaceAll called with a non-global RegExp argument").into());
                     }
                 }
             }
@@ -1137,7 +1078,7 @@ impl String {
         };
 
         // 7. Let searchLength be the length of searchString.
-        let search_length = search_string.encode_utf16().count();
+        let search_length = search_string.len();
 
         // 8. Let advanceBy be max(1, searchLength).
         let advance_by = max(1, search_length);
@@ -1161,19 +1102,12 @@ impl String {
         let mut end_of_last_match = 0;
 
         // 13. Let result be the empty String.
-        let mut result = JsString::new("");
+        let mut result = Vec::with_capacity(string.len());
 
         // 14. For each element p of matchPositions, do
         for p in match_positions {
             // a. Let preserved be the substring of string from endOfLastMatch to p.
-            let preserved = StdString::from_utf16_lossy(
-                &string
-                    .clone()
-                    .encode_utf16()
-                    .skip(end_of_last_match)
-                    .take(p - end_of_last_match)
-                    .collect::<Vec<u16>>(),
-            );
+            let preserved = &string[end_of_last_match..p];
 
             // c. Else,
             let replacement = if let Some(ref replace_value) = replace_value_string {
@@ -1208,29 +1142,21 @@ impl String {
             };
 
             // d. Set result to the string-concatenation of result, preserved, and replacement.
-            result = JsString::new(format!("{}{preserved}{replacement}", result.as_str()));
+            result.extend_from_slice(preserved);
+            result.extend_from_slice(&replacement);
 
             // e. Set endOfLastMatch to p + searchLength.
             end_of_last_match = p + search_length;
         }
 
         // 15. If endOfLastMatch < the length of string, then
-        if end_of_last_match < string.encode_utf16().count() {
+        if end_of_last_match < string.len() {
             // a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
-            result = JsString::new(format!(
-                "{}{}",
-                result.as_str(),
-                &StdString::from_utf16_lossy(
-                    &string
-                        .encode_utf16()
-                        .skip(end_of_last_match)
-                        .collect::<Vec<u16>>()
-                )
-            ));
+            result.extend_from_slice(&string[end_of_last_match..]);
         }
 
         // 16. Return result.
-        Ok(result.into())
+        Ok(JsString::from(result).into())
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -1252,7 +1178,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -1265,7 +1191,7 @@ impl String {
         let pos = args.get_or_undefined(1).to_integer_or_infinity(context)?;
 
         // 6. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 7. Let start be the result of clamping pos between 0 and len.
         let start = pos.clamp_finite(0, len) as usize;
@@ -1296,7 +1222,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -1318,7 +1244,7 @@ impl String {
         };
 
         // 7. Let len be the length of S.
-        let len = string.encode_utf16().count();
+        let len = string.len();
         // 8. Let start be the result of clamping pos between 0 and len.
         let start = pos.clamp_finite(0, len as i64) as usize;
 
@@ -1328,17 +1254,17 @@ impl String {
         }
 
         // 10. Let searchLen be the length of searchStr.
-        let search_len = search_str.encode_utf16().count();
+        let search_len = search_str.len();
 
         // 11. For each non-negative integer i starting with start such that i ≤ len - searchLen, in descending order, do
         // a. Let candidate be the substring of S from i to i + searchLen.
-        let substring_utf16: Vec<u16> = string.encode_utf16().take(start + search_len).collect();
-        let substring_lossy = StdString::from_utf16_lossy(&substring_utf16);
-        if let Some(position) = substring_lossy.rfind(search_str.as_str()) {
-            // b. If candidate is the same sequence of code units as searchStr, return 𝔽(i).
-            return Ok(JsValue::new(
-                substring_lossy[..position].encode_utf16().count(),
-            ));
+        if let Some(end) = len.checked_sub(search_len) {
+            for i in (0..=min(start, end)).rev() {
+                // b. If candidate is the same sequence of code units as searchStr, return 𝔽(i).
+                if string[i..i + search_len] == search_str[..] {
+                    return Ok(JsValue::new(i));
+                }
+            }
         }
 
         // 12. Return -1𝔽.
@@ -1362,7 +1288,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let o = this.require_object_coercible(context)?;
+        let o = this.require_object_coercible()?;
 
         // 2. If regexp is neither undefined nor null, then
         let regexp = args.get_or_undefined(0);
@@ -1380,7 +1306,7 @@ impl String {
         let s = o.to_string(context)?;
 
         // 4. Let rx be ? RegExpCreate(regexp, undefined).
-        let rx = RegExp::create(regexp, &JsValue::Undefined, context)?;
+        let rx = RegExp::create(regexp, &JsValue::undefined(), context)?;
 
         // 5. Return ? Invoke(rx, @@match, « S »).
         rx.invoke(WellKnownSymbols::r#match(), &[JsValue::new(s)], context)
@@ -1408,7 +1334,7 @@ impl String {
         let int_max_length = max_length.to_length(context)?;
 
         // 3. Let stringLength be the length of S.
-        let string_length = string.encode_utf16().count() as u64;
+        let string_length = string.len() as u64;
 
         // 4. If intMaxLength ≤ stringLength, return S.
         if int_max_length <= string_length {
@@ -1417,7 +1343,7 @@ impl String {
 
         // 5. If fillString is undefined, let filler be the String value consisting solely of the code unit 0x0020 (SPACE).
         let filler = if fill_string.is_undefined() {
-            "\u{0020}".into()
+            js_string!(" ")
         } else {
             // 6. Else, let filler be ? ToString(fillString).
             fill_string.to_string(context)?
@@ -1430,7 +1356,7 @@ impl String {
 
         // 8. Let fillLen be intMaxLength - stringLength.
         let fill_len = int_max_length - string_length;
-        let filler_len = filler.encode_utf16().count() as u64;
+        let filler_len = filler.len() as u64;
 
         // 9. Let truncatedStringFiller be the String value consisting of repeated
         // concatenations of filler truncated to length fillLen.
@@ -1444,20 +1370,15 @@ impl String {
             }
         };
 
-        let truncated_string_filler = filler
-            .repeat(repetitions as usize)
-            .encode_utf16()
-            .take(fill_len as usize)
-            .collect::<Vec<_>>();
-        let truncated_string_filler =
-            std::string::String::from_utf16_lossy(truncated_string_filler.as_slice());
+        let mut truncated_string_filler = filler.repeat(repetitions as usize);
+        truncated_string_filler.truncate(fill_len as usize);
 
         // 10. If placement is start, return the string-concatenation of truncatedStringFiller and S.
         if placement == Placement::Start {
-            Ok(format!("{truncated_string_filler}{string}").into())
+            Ok(js_string!(&truncated_string_filler, &string).into())
         } else {
             // 11. Else, return the string-concatenation of S and truncatedStringFiller.
-            Ok(format!("{string}{truncated_string_filler}").into())
+            Ok(js_string!(&string, &truncated_string_filler).into())
         }
     }
 
@@ -1479,7 +1400,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         let max_length = args.get_or_undefined(0);
         let fill_string = args.get_or_undefined(1);
@@ -1506,7 +1427,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         let max_length = args.get_or_undefined(0);
         let fill_string = args.get_or_undefined(1);
@@ -1528,9 +1449,9 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.trim
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trim
     pub(crate) fn trim(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let object = this.require_object_coercible(context)?;
+        let object = this.require_object_coercible()?;
         let string = object.to_string(context)?;
-        Ok(JsValue::new(string.trim_matches(is_trimmable_whitespace)))
+        Ok(JsString::from(string.trim()).into())
     }
 
     /// `String.prototype.trimStart()`
@@ -1550,11 +1471,9 @@ impl String {
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
         let string = this.to_string(context)?;
-        Ok(JsValue::new(
-            string.trim_start_matches(is_trimmable_whitespace),
-        ))
+        Ok(JsString::from(string.trim_start()).into())
     }
 
     /// String.prototype.trimEnd()
@@ -1574,11 +1493,9 @@ impl String {
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
         let string = this.to_string(context)?;
-        Ok(JsValue::new(
-            string.trim_end_matches(is_trimmable_whitespace),
-        ))
+        Ok(JsString::from(string.trim_end()).into())
     }
 
     /// `String.prototype.toLowerCase()`
@@ -1598,7 +1515,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -1608,7 +1525,7 @@ impl String {
         // the Unicode Default Case Conversion algorithm.
         // 5. Let L be ! CodePointsToString(lowerText).
         // 6. Return L.
-        Ok(JsValue::new(string.to_lowercase()))
+        Ok(JsString::from(map_valid_segments(&string, str::to_lowercase)).into())
     }
 
     /// `String.prototype.toUpperCase()`
@@ -1635,7 +1552,7 @@ impl String {
         // Comments below are an adaptation of the `String.prototype.toLowerCase` documentation.
 
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
@@ -1645,7 +1562,7 @@ impl String {
         // the Unicode Default Case Conversion algorithm.
         // 5. Let L be ! CodePointsToString(upperText).
         // 6. Return L.
-        Ok(JsValue::new(string.to_uppercase()))
+        Ok(JsString::from(map_valid_segments(&string, str::to_uppercase)).into())
     }
 
     /// `String.prototype.substring( indexStart[, indexEnd] )`
@@ -1664,21 +1581,23 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
 
         // 3. Let len be the length of S.
-        let len = string.encode_utf16().count() as i64;
+        let len = string.len() as i64;
 
         // 4. Let intStart be ? ToIntegerOrInfinity(start).
         let int_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
 
         // 5. If end is undefined, let intEnd be len; else let intEnd be ? ToIntegerOrInfinity(end).
-        let int_end = match args.get_or_undefined(1) {
-            &JsValue::Undefined => IntegerOrInfinity::Integer(len),
-            end => end.to_integer_or_infinity(context)?,
+        let end = args.get_or_undefined(1);
+        let int_end = if end.is_undefined() {
+            IntegerOrInfinity::Integer(len)
+        } else {
+            end.to_integer_or_infinity(context)?
         };
 
         // 6. Let finalStart be the result of clamping intStart between 0 and len.
@@ -1696,11 +1615,7 @@ impl String {
         // 10. Return the substring of S from from to to.
         // Extract the part of the string contained between the from index and the to index
         // where from is guaranteed to be smaller or equal to to
-        // TODO: Full UTF-16 support
-        let substring_utf16: Vec<u16> = string.encode_utf16().skip(from).take(to - from).collect();
-        let substring = StdString::from_utf16_lossy(&substring_utf16);
-
-        Ok(substring.into())
+        Ok(JsString::from(&string[from..to]).into())
     }
 
     /// `String.prototype.substr( start[, length] )`
@@ -1720,22 +1635,24 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let string = this.to_string(context)?;
 
         // 3. Let size be the length of S.
-        let size = string.encode_utf16().count() as i64;
+        let size = string.len() as i64;
 
         // 4. Let intStart be ? ToIntegerOrInfinity(start).
         let int_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
 
         // 7. If length is undefined, let intLength be size; otherwise let intLength be ? ToIntegerOrInfinity(length).
         // Moved it before to ensure an error throws before returning the empty string on `match int_start`
-        let int_length = match args.get_or_undefined(1) {
-            &JsValue::Undefined => IntegerOrInfinity::Integer(size),
-            val => val.to_integer_or_infinity(context)?,
+        let length = args.get_or_undefined(1);
+        let int_length = if length.is_undefined() {
+            IntegerOrInfinity::Integer(size)
+        } else {
+            length.to_integer_or_infinity(context)?
         };
 
         let int_start = match int_start {
@@ -1759,14 +1676,11 @@ impl String {
 
         // 11. Return the substring of S from intStart to intEnd.
         // 10. If intStart ≥ intEnd, return the empty String.
-        let substring_utf16: Vec<u16> = string
-            .encode_utf16()
-            .skip(int_start)
-            .take(int_end - int_start)
-            .collect();
-        let substring = StdString::from_utf16_lossy(&substring_utf16);
-
-        Ok(substring.into())
+        if int_start >= int_end {
+            return Ok("".into());
+        }
+
+        Ok(JsString::from(&string[int_start..int_end]).into())
     }
 
     /// `String.prototype.split ( separator, limit )`
@@ -1786,7 +1700,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         let separator = args.get_or_undefined(0);
         let limit = args.get_or_undefined(1);
@@ -1837,7 +1751,7 @@ impl String {
         }
 
         // 10. Let s be the length of S.
-        let this_str_length = this_str.encode_utf16().count();
+        let this_str_length = this_str.len();
 
         // 11. If s = 0, then
         if this_str_length == 0 {
@@ -1874,13 +1788,7 @@ impl String {
                         q += 1;
                     } else {
                         // 1. Let T be the substring of S from p to q.
-                        let this_str_substring = StdString::from_utf16_lossy(
-                            &this_str
-                                .encode_utf16()
-                                .skip(p)
-                                .take(q - p)
-                                .collect::<Vec<u16>>(),
-                        );
+                        let this_str_substring = JsString::from(&this_str[p..q]);
 
                         // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
                         a.create_data_property_or_throw(length_a, this_str_substring, context)
@@ -1905,13 +1813,7 @@ impl String {
         }
 
         // 15. Let T be the substring of S from p to s.
-        let this_str_substring = StdString::from_utf16_lossy(
-            &this_str
-                .encode_utf16()
-                .skip(p)
-                .take(this_str_length - p)
-                .collect::<Vec<u16>>(),
-        );
+        let this_str_substring = JsString::from(&this_str[p..this_str_length]);
 
         // 16. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
         a.create_data_property_or_throw(length_a, this_str_substring, context)
@@ -1931,13 +1833,9 @@ impl String {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.value_of
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/valueOf
-    pub(crate) fn value_of(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn value_of(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Return ? thisStringValue(this value).
-        Self::this_string_value(this, context).map(JsValue::from)
+        Self::this_string_value(this).map(JsValue::from)
     }
 
     /// `String.prototype.matchAll( regexp )`
@@ -1958,7 +1856,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let o = this.require_object_coercible(context)?;
+        let o = this.require_object_coercible()?;
 
         // 2. If regexp is neither undefined nor null, then
         let regexp = args.get_or_undefined(0);
@@ -1970,13 +1868,15 @@ impl String {
                 let flags = regexp_obj.get("flags", context)?;
 
                 // ii. Perform ? RequireObjectCoercible(flags).
-                flags.require_object_coercible(context)?;
+                flags.require_object_coercible()?;
 
                 // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
-                if !flags.to_string(context)?.contains('g') {
-                    return context.throw_type_error(
-                        "String.prototype.matchAll called with a non-global RegExp argument",
-                    );
+                if !flags.to_string(context)?.contains(&u16::from(b'g')) {
+                    return Err(JsNativeError::typ()
+                        .with_message(
+                            "String.prototype.matchAll called with a non-global RegExp argument",
+                        )
+                        .into());
                 }
             }
             // c. Let matcher be ? GetMethod(regexp, @@matchAll).
@@ -1997,6 +1897,74 @@ impl String {
         rx.invoke(WellKnownSymbols::match_all(), &[JsValue::new(s)], context)
     }
 
+    /// `String.prototype.localeCompare( that [ , locales [ , options ] ] )`
+    ///
+    /// The `localeCompare()` method returns a number indicating whether a reference string comes
+    /// before, or after, or is the same as the given string in sort order.
+    ///
+    /// Without the `intl` feature, the strings are compared by their code units, after they are
+    /// normalized to the NFC form so that canonically equivalent strings compare as equal.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.localecompare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+    #[cfg(not(feature = "intl"))]
+    pub(crate) fn locale_compare(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible()?;
+
+        // 2. Let S be ? ToString(O).
+        let s = this.to_string(context)?;
+
+        // 3. Let thatValue be ? ToString(that).
+        let that_value = args.get_or_undefined(0).to_string(context)?;
+
+        let normalize = |s: &JsString| map_valid_segments(s, |s| s.nfc().collect());
+        let ordering = normalize(&s).cmp(&normalize(&that_value));
+        Ok((ordering as i32).into())
+    }
+
+    /// `String.prototype.localeCompare( that [ , locales [ , options ] ] )`
+    ///
+    /// The `localeCompare()` method returns a number indicating whether a reference string comes
+    /// before, or after, or is the same as the given string in sort order.
+    ///
+    /// More information:
+    ///  - [ECMAScript Internationalization reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-String.prototype.localeCompare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+    #[cfg(feature = "intl")]
+    pub(crate) fn locale_compare(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible()?;
+
+        // 2. Let S be ? ToString(O).
+        let s = this.to_string(context)?;
+
+        // 3. Let thatValue be ? ToString(that).
+        let that_value = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. Let collator be ? Construct(%Collator%, « locales, options »).
+        let collator =
+            Collator::initialize(args.get_or_undefined(1), args.get_or_undefined(2), context)?;
+
+        // 5. Return CompareStrings(collator, S, thatValue).
+        Ok((collator.compare_strings(&s, &that_value) as i32).into())
+    }
+
     /// `String.prototype.normalize( [ form ] )`
     ///
     /// The normalize() method normalizes a string into a form specified in the Unicode® Standard Annex #15
@@ -2013,37 +1981,41 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible(context)?;
+        let this = this.require_object_coercible()?;
 
         // 2. Let S be ? ToString(O).
         let s = this.to_string(context)?;
 
         let form = args.get_or_undefined(0);
 
-        let f_str;
-
         let f = if form.is_undefined() {
             // 3. If form is undefined, let f be "NFC".
-            "NFC"
+            js_string!("NFC")
         } else {
             // 4. Else, let f be ? ToString(form).
-            f_str = form.to_string(context)?;
-            f_str.as_str()
+            form.to_string(context)?
+        };
+
+        let normalize: fn(&str) -> StdString = if f.as_slice() == utf16!("NFC") {
+            |s| s.nfc().collect()
+        } else if f.as_slice() == utf16!("NFD") {
+            |s| s.nfd().collect()
+        } else if f.as_slice() == utf16!("NFKC") {
+            |s| s.nfkc().collect()
+        } else if f.as_slice() == utf16!("NFKD") {
+            |s| s.nfkd().collect()
+        } else {
+            // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message("The normalization form should be one of NFC, NFD, NFKC, NFKD.")
+                .into());
         };
 
         // 6. Let ns be the String value that is the result of normalizing S
         // into the normalization form named by f as specified in
         // https://unicode.org/reports/tr15/.
         // 7. Return ns.
-        match f {
-            "NFC" => Ok(JsValue::new(s.nfc().collect::<StdString>())),
-            "NFD" => Ok(JsValue::new(s.nfd().collect::<StdString>())),
-            "NFKC" => Ok(JsValue::new(s.nfkc().collect::<StdString>())),
-            "NFKD" => Ok(JsValue::new(s.nfkd().collect::<StdString>())),
-            // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
-            _ => context
-                .throw_range_error("The normalization form should be one of NFC, NFD, NFKC, NFKD."),
-        }
+        Ok(JsString::from(map_valid_segments(&s, normalize)).into())
     }
 
     /// `String.prototype.search( regexp )`
@@ -2062,7 +2034,7 @@ impl String {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let O be ? RequireObjectCoercible(this value).
-        let o = this.require_object_coercible(context)?;
+        let o = this.require_object_coercible()?;
 
         // 2. If regexp is neither undefined nor null, then
         let regexp = args.get_or_undefined(0);
@@ -2080,7 +2052,7 @@ impl String {
         let string = o.to_string(context)?;
 
         // 4. Let rx be ? RegExpCreate(regexp, undefined).
-        let rx = RegExp::create(regexp, &JsValue::Undefined, context)?;
+        let rx = RegExp::create(regexp, &JsValue::undefined(), context)?;
 
         // 5. Return ? Invoke(rx, @@search, « string »).
         rx.invoke(WellKnownSymbols::search(), &[JsValue::new(string)], context)
@@ -2095,6 +2067,30 @@ impl String {
     }
 }
 
+/// Applies `f` to every run of valid code points of `string`.
+///
+/// Unpaired surrogates are not touched by case conversion or normalization, so they are copied
+/// to the result as they are.
+fn map_valid_segments<F>(string: &JsString, mut f: F) -> Vec<u16>
+where
+    F: FnMut(&str) -> StdString,
+{
+    let mut result = Vec::with_capacity(string.len());
+    let mut segment = StdString::new();
+    for code_point in string.code_points() {
+        match code_point {
+            CodePoint::Unicode(c) => segment.push(c),
+            CodePoint::UnpairedSurrogate(surrogate) => {
+                result.extend(f(&segment).encode_utf16());
+                segment.clear();
+                result.push(surrogate);
+            }
+        }
+    }
+    result.extend(f(&segment).encode_utf16());
+    result
+}
+
 /// Abstract operation `GetSubstitution ( matched, str, position, captures, namedCaptures, replacement )`
 ///
 /// More information:
@@ -2102,23 +2098,31 @@ impl String {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
 pub(crate) fn get_substitution(
-    matched: &str,
-    str: &str,
+    matched: &[u16],
+    str: &[u16],
     position: usize,
     captures: &[JsValue],
     named_captures: &JsValue,
-    replacement: &JsString,
+    replacement: &[u16],
     context: &mut Context,
 ) -> JsResult<JsString> {
+    /// Returns the ASCII character of a code unit, if it is one.
+    fn to_ascii(code_unit: u16) -> Option<char> {
+        u8::try_from(code_unit)
+            .ok()
+            .filter(u8::is_ascii)
+            .map(char::from)
+    }
+
     // 1. Assert: Type(matched) is String.
 
     // 2. Let matchLength be the number of code units in matched.
-    let match_length = matched.encode_utf16().count();
+    let match_length = matched.len();
 
     // 3. Assert: Type(str) is String.
 
     // 4. Let stringLength be the number of code units in str.
-    let str_length = str.encode_utf16().count();
+    let str_length = str.len();
 
     // 5. Assert: position ≤ stringLength.
     // 6. Assert: captures is a possibly empty List of Strings.
@@ -2134,43 +2138,44 @@ pub(crate) fn get_substitution(
     //     from replacement to result while performing replacements as specified in Table 58.
     //     These $ replacements are done left-to-right, and, once such a replacement is performed,
     //     the new replacement text is not subject to further replacements.
-    let mut result = StdString::new();
-    let mut chars = replacement.chars().peekable();
+    let mut result = Vec::with_capacity(replacement.len());
+    let mut chars = replacement.iter().copied().peekable();
 
     while let Some(first) = chars.next() {
-        if first == '$' {
+        if first == u16::from(b'$') {
             let second = chars.next();
-            let second_is_digit = second.as_ref().map_or(false, char::is_ascii_digit);
+            let second_char = second.and_then(to_ascii);
+            let second_is_digit = second_char.map_or(false, |c| c.is_ascii_digit());
             // we use peek so that it is still in the iterator if not used
-            let third = if second_is_digit { chars.peek() } else { None };
-            let third_is_digit = third.map_or(false, char::is_ascii_digit);
+            let third = if second_is_digit {
+                chars.peek().copied().and_then(to_ascii)
+            } else {
+                None
+            };
+            let third_is_digit = third.map_or(false, |c| c.is_ascii_digit());
 
-            match (second, third) {
+            match (second_char, third) {
                 // $$
                 (Some('$'), _) => {
                     // $
-                    result.push('$');
+                    result.push(u16::from(b'$'));
                 }
                 // $&
                 (Some('&'), _) => {
                     // matched
-                    result.push_str(matched);
+                    result.extend_from_slice(matched);
                 }
                 // $`
                 (Some('`'), _) => {
                     // The replacement is the substring of str from 0 to position.
-                    result.push_str(&StdString::from_utf16_lossy(
-                        &str.encode_utf16().take(position).collect::<Vec<u16>>(),
-                    ));
+                    result.extend_from_slice(&str[..position]);
                 }
                 // $'
                 (Some('\''), _) => {
                     // If tailPos ≥ stringLength, the replacement is the empty String.
                     // Otherwise the replacement is the substring of str from tailPos.
                     if tail_pos < str_length {
-                        result.push_str(&StdString::from_utf16_lossy(
-                            &str.encode_utf16().skip(tail_pos).collect::<Vec<u16>>(),
-                        ));
+                        result.extend_from_slice(&str[tail_pos..]);
                     }
                 }
                 // $nn
@@ -2189,12 +2194,10 @@ pub(crate) fn get_substitution(
                     // If nn ≤ m and the nnth element of captures is undefined, use the empty String instead.
                     // If nn is 00 or nn > m, no replacement is done.
                     if nn == 0 || nn > m {
-                        result.push('$');
-                        result.push(second);
-                        result.push(*third);
+                        result.extend_from_slice(&[u16::from(b'$'), second as u16, third as u16]);
                     } else if let Some(capture) = captures.get(nn - 1) {
                         if let Some(s) = capture.as_string() {
-                            result.push_str(s);
+                            result.extend_from_slice(s);
                         }
                     }
 
@@ -2211,11 +2214,10 @@ pub(crate) fn get_substitution(
                     // If n ≤ m and the nth element of captures is undefined, use the empty String instead.
                     // If n > m, no replacement is done.
                     if n == 0 || n > m {
-                        result.push('$');
-                        result.push(second);
+                        result.extend_from_slice(&[u16::from(b'$'), second as u16]);
                     } else if let Some(capture) = captures.get(n - 1) {
                         if let Some(s) = capture.as_string() {
-                            result.push_str(s);
+                            result.extend_from_slice(s);
                         }
                     }
                 }
@@ -2224,7 +2226,7 @@ pub(crate) fn get_substitution(
                     // 1. If namedCaptures is undefined, the replacement text is the String "$<".
                     // 2. Else,
                     if named_captures.is_undefined() {
-                        result.push_str("$<");
+                        result.extend_from_slice(utf16!("$<"));
                     } else {
                         // a. Assert: Type(namedCaptures) is Object.
                         let named_captures = named_captures
@@ -2232,41 +2234,39 @@ pub(crate) fn get_substitution(
                             .expect("should be an object according to spec");
 
                         // b. Scan until the next > U+003E (GREATER-THAN SIGN).
-                        let mut group_name = StdString::new();
+                        let mut group_name = Vec::new();
                         let mut found = false;
-                        loop {
-                            match chars.next() {
-                                Some('>') => {
-                                    found = true;
-                                    break;
-                                }
-                                Some(c) => group_name.push(c),
-                                None => break,
+                        for c in chars.by_ref() {
+                            if c == u16::from(b'>') {
+                                found = true;
+                                break;
                             }
+                            group_name.push(c);
                         }
 
                         // c. If none is found, the replacement text is the String "$<".
                         #[allow(clippy::if_not_else)]
                         if !found {
-                            result.push_str("$<");
-                            result.push_str(&group_name);
+                            result.extend_from_slice(utf16!("$<"));
+                            result.extend_from_slice(&group_name);
                         // d. Else,
                         } else {
                             // i. Let groupName be the enclosed substring.
                             // ii. Let capture be ? Get(namedCaptures, groupName).
-                            let capture = named_captures.get(group_name, context)?;
+                            let capture =
+                                named_captures.get(JsString::from(group_name), context)?;
 
                             // iii. If capture is undefined, replace the text through > with the empty String.
                             // iv. Otherwise, replace the text through > with ? ToString(capture).
                             if !capture.is_undefined() {
-                                result.push_str(capture.to_string(context)?.as_str());
+                                result.extend_from_slice(&capture.to_string(context)?);
                             }
                         }
                     }
                 }
                 // $?, ? is none of the above
                 _ => {
-                    result.push('$');
+                    result.push(u16::from(b'$'));
                     if let Some(second) = second {
                         result.push(second);
                     }
@@ -2278,7 +2278,7 @@ pub(crate) fn get_substitution(
     }
 
     // 11. Return result.
-    Ok(result.into())
+    Ok(JsString::from(result))
 }
 
 /// `22.1.3.21.1 SplitMatch ( S, q, R )`
@@ -2287,12 +2287,12 @@ pub(crate) fn get_substitution(
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-splitmatch
-fn split_match(s_str: &str, q: usize, r_str: &str) -> Option<usize> {
+fn split_match(s_str: &[u16], q: usize, r_str: &[u16]) -> Option<usize> {
     // 1. Let r be the number of code units in R.
-    let r = r_str.encode_utf16().count();
+    let r = r_str.len();
 
     // 2. Let s be the number of code units in S.
-    let s = s_str.encode_utf16().count();
+    let s = s_str.len();
 
     // 3. If q + r > s, return not-matched.
     if q + r > s {
@@ -2302,14 +2302,8 @@ fn split_match(s_str: &str, q: usize, r_str: &str) -> Option<usize> {
     // 4. If there exists an integer i between 0 (inclusive) and r (exclusive)
     //    such that the code unit at index q + i within S is different from the code unit at index i within R,
     //    return not-matched.
-    for i in 0..r {
-        if let Some(s_char) = s_str.encode_utf16().nth(q + i) {
-            if let Some(r_char) = r_str.encode_utf16().nth(i) {
-                if s_char != r_char {
-                    return None;
-                }
-            }
-        }
+    if s_str[q..q + r] != *r_str {
+        return None;
     }
 
     // 5. Return q + r.
@@ -2324,8 +2318,8 @@ fn split_match(s_str: &str, q: usize, r_str: &str) -> Option<usize> {
 /// [spec]: https://tc39.es/ecma262/#sec-isregexp
 fn is_reg_exp(argument: &JsValue, context: &mut Context) -> JsResult<bool> {
     // 1. If Type(argument) is not Object, return false.
-    let argument = match argument {
-        JsValue::Object(o) => o,
+    let argument = match argument.variant() {
+        JsVariant::Object(o) => o,
         _ => return Ok(false),
     };
 
@@ -1,7 +1,6 @@
 use crate::{
-    builtins::{
-        function::make_builtin_fn, iterable::create_iter_result_object, string::code_point_at,
-    },
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    error::JsNativeError,
     object::{JsObject, ObjectData},
     property::PropertyDescriptor,
     symbol::WellKnownSymbols,
@@ -41,7 +40,7 @@ impl StringIterator {
         let string_iterator = string_iterator
             .as_mut()
             .and_then(|obj| obj.as_string_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not an ArrayIterator"))?;
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not an ArrayIterator"))?;
 
         if string_iterator.string.is_undefined() {
             return Ok(create_iter_result_object(
@@ -51,7 +50,7 @@ impl StringIterator {
             ));
         }
         let native_string = string_iterator.string.to_string(context)?;
-        let len = native_string.encode_utf16().count() as i32;
+        let len = native_string.len() as i32;
         let position = string_iterator.next_index;
         if position >= len {
             string_iterator.string = JsValue::undefined();
@@ -61,8 +60,8 @@ impl StringIterator {
                 context,
             ));
         }
-        let (_, code_unit_count, _) = code_point_at(&native_string, position as u64);
-        string_iterator.next_index += i32::from(code_unit_count);
+        let code_point = native_string.code_point_at(position as usize);
+        string_iterator.next_index += code_point.code_unit_count() as i32;
         let result_string = crate::builtins::string::String::substring(
             &string_iterator.string,
             &[position.into(), string_iterator.next_index.into()],
@@ -681,13 +681,15 @@ fn split() {
         forward(&mut context, "['']")
     );
 
-    // TODO: Support keeping invalid code point in string
     assert_eq!(
         forward(
             &mut context,
             "\'\u{1d7d8}\u{1d7d9}\u{1d7da}\u{1d7db}\'.split(\'\')"
         ),
-        forward(&mut context, "['�','�','�','�','�','�','�','�']")
+        forward(
+            &mut context,
+            "['\\uD835','\\uDFD8','\\uD835','\\uDFD9','\\uD835','\\uDFDA','\\uD835','\\uDFDB']"
+        )
     );
 }
 
@@ -965,7 +967,7 @@ fn char_at() {
     assert_eq!(forward(&mut context, "'abc'.charAt(9)"), "\"\"");
     assert_eq!(forward(&mut context, "'abc'.charAt()"), "\"a\"");
     assert_eq!(forward(&mut context, "'abc'.charAt(null)"), "\"a\"");
-    assert_eq!(forward(&mut context, "'\\uDBFF'.charAt(0)"), "\"\u{FFFD}\"");
+    assert_eq!(forward(&mut context, "'\\uDBFF'.charAt(0)"), "\"\\uDBFF\"");
 }
 
 #[test]
@@ -1138,7 +1140,7 @@ fn string_get_property() {
     assert_eq!(forward(&mut context, "'abc'[2]"), "\"c\"");
     assert_eq!(forward(&mut context, "'abc'[3]"), "undefined");
     assert_eq!(forward(&mut context, "'abc'['foo']"), "undefined");
-    assert_eq!(forward(&mut context, "'😀'[0]"), "\"�\"");
+    assert_eq!(forward(&mut context, "'😀'[0] === '\\uD83D'"), "true");
 }
 
 #[test]
@@ -1150,3 +1152,40 @@ fn search() {
     assert_eq!(forward(&mut context, "'aa'.search(/a/g)"), "0");
     assert_eq!(forward(&mut context, "'ba'.search(/a/)"), "1");
 }
+
+#[test]
+fn lone_surrogates() {
+    let mut context = Context::default();
+    forward(&mut context, "var lone = '\\uD83D';");
+
+    assert_eq!(forward(&mut context, "lone.length"), "1");
+    assert_eq!(forward(&mut context, "lone.charCodeAt(0)"), "55357");
+    assert_eq!(forward(&mut context, "(lone + '\\uDE00') === '😀'"), "true");
+    assert_eq!(
+        forward(&mut context, "'😀'.slice(1).charCodeAt(0)"),
+        "56832"
+    );
+    assert_eq!(
+        forward(&mut context, "'a\\uD800b'.split('\\uD800').length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\uDC00\\uD800' < '\\uD800'"),
+        "false"
+    );
+}
+
+#[cfg(not(feature = "intl"))]
+#[test]
+fn locale_compare() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "'a'.localeCompare('b')"), "-1");
+    assert_eq!(forward(&mut context, "'b'.localeCompare('a')"), "1");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('a')"), "0");
+    assert_eq!(
+        forward(&mut context, "'\\u00E9'.localeCompare('e\\u0301')"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "'abc'.localeCompare()"), "-1");
+}
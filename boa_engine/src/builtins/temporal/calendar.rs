@@ -0,0 +1,630 @@
+//! This module implements the `Temporal.Calendar` object.
+//!
+//! A `Temporal.Calendar` is the calendar system of the dates of the other `Temporal` objects.
+//! Only the ISO 8601 calendar, whose identifier is `iso8601`, is supported, so all the calendar
+//! objects behave in the same way. Custom calendar objects are not supported either.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendar-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Calendar
+
+use super::{
+    duration::{Duration, DurationRecord},
+    fields::{Field, TemporalFields},
+    iso::{IsoDateTime, IsoTime},
+    options::{
+        get_option, get_options_object, get_temporal_unit, to_temporal_overflow, ShowCalendar,
+        Unit, UnitGroup, UnitOption,
+    },
+    parser::parse_calendar,
+    plain_date::PlainDate,
+    Component,
+};
+use crate::{
+    builtins::{Array, JsArgs},
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::{Attribute, PropertyNameKind},
+    symbol::WellKnownSymbols,
+    value::JsVariant,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The identifier of the ISO 8601 calendar, which is the only supported calendar.
+pub(crate) const ISO8601: &str = "iso8601";
+
+/// The internal representation of a `Temporal.Calendar` object.
+#[derive(Debug, Clone, Copy, Finalize)]
+pub struct Calendar;
+
+// Safety: `Calendar` does not contain any objects which need to be traced.
+unsafe impl Trace for Calendar {
+    unsafe_empty_trace!();
+}
+
+impl Calendar {
+    const NAME: &'static str = "Calendar";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_id = FunctionBuilder::native(context, Self::get_id)
+            .name("get id")
+            .build();
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().calendar().clone(),
+        );
+        builder
+            .name(Self::NAME)
+            .length(1)
+            .static_method(Self::from, "from", 1)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.Calendar",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                "id",
+                Some(get_id),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::date_from_fields, "dateFromFields", 1)
+            .method(Self::date_add, "dateAdd", 2)
+            .method(Self::date_until, "dateUntil", 2)
+            .method(Self::fields, "fields", 1)
+            .method(Self::merge_fields, "mergeFields", 2)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_string, "toJSON", 0);
+
+        for component in Component::DATE {
+            let method = FunctionBuilder::closure_with_captures(
+                builder.context(),
+                |this, args, component: &mut Component, context| {
+                    this_calendar(this)?;
+                    let date =
+                        PlainDate::to_temporal_date(args.get_or_undefined(0), None, context)?;
+                    Ok(component.get(IsoDateTime::new(date.iso, IsoTime::default())))
+                },
+                component,
+            )
+            .name(component.name())
+            .length(1)
+            .build();
+            builder.property(
+                component.name(),
+                method,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+        }
+
+        builder.build()
+    }
+
+    /// `Temporal.Calendar ( id )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        //     a. Throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.Calendar must be called with new")
+                .into());
+        }
+
+        // 2. Set id to ? ToString(id).
+        let id = args.get_or_undefined(0).to_string(context)?;
+
+        // 3. If IsBuiltinCalendar(id) is false, then
+        //     a. Throw a RangeError exception.
+        if !id.to_std_string_escaped().eq_ignore_ascii_case(ISO8601) {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "'{}' is not a supported calendar",
+                    id.to_std_string_escaped()
+                ))
+                .into());
+        }
+
+        // 4. Return ? CreateTemporalCalendar(id, NewTarget).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::calendar, context)?;
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::calendar(Self)).into())
+    }
+
+    /// Abstract operation `CreateTemporalCalendar ( identifier )`
+    ///
+    /// Creates a new `Temporal.Calendar` object for the ISO 8601 calendar.
+    pub(crate) fn create(context: &mut Context) -> JsObject {
+        JsObject::from_proto_and_data(
+            context.intrinsics().constructors().calendar().prototype(),
+            ObjectData::calendar(Self),
+        )
+    }
+
+    /// Abstract operation `ToTemporalCalendar ( temporalCalendarLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalcalendar
+    pub(crate) fn to_temporal_calendar(
+        calendar_like: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let mut calendar_like = calendar_like.clone();
+
+        // 1. If Type(temporalCalendarLike) is Object, then
+        if let Some(object) = calendar_like.as_object().cloned() {
+            // a. If temporalCalendarLike has an [[InitializedTemporalCalendar]] internal slot, then
+            //     i. Return temporalCalendarLike.
+            // b. If temporalCalendarLike has a [[Calendar]] internal slot, then
+            //     i. Return temporalCalendarLike.[[Calendar]].
+            if let Some(calendar) = calendar_slot(&object) {
+                return Ok(calendar);
+            }
+
+            // c. If ? HasProperty(temporalCalendarLike, "calendar") is false, return temporalCalendarLike.
+            // d. Set temporalCalendarLike to ? Get(temporalCalendarLike, "calendar").
+            // e. If Type(temporalCalendarLike) is Object and ? HasProperty(temporalCalendarLike, "calendar") is false, return temporalCalendarLike.
+            if object.has_property("calendar", context)? {
+                calendar_like = object.get("calendar", context)?;
+                if let Some(object) = calendar_like.as_object() {
+                    if object.borrow().as_calendar().is_some() {
+                        return Ok(object.clone());
+                    }
+                    if !object.has_property("calendar", context)? {
+                        return Err(custom_calendar_error());
+                    }
+                }
+            } else {
+                return Err(custom_calendar_error());
+            }
+        }
+
+        // 2. Let identifier be ? ToString(temporalCalendarLike).
+        let identifier = calendar_like.to_string(context)?.to_std_string_escaped();
+
+        // 3. Set identifier to ? ParseTemporalCalendarString(identifier).
+        let identifier = parse_calendar(&identifier)?;
+
+        // 4. If IsBuiltinCalendar(identifier) is false, throw a RangeError exception.
+        Self::check_annotation(Some(&identifier))?;
+
+        // 5. Return ! CreateTemporalCalendar(identifier).
+        Ok(Self::create(context))
+    }
+
+    /// Throws a `RangeError` if the calendar annotation of a parsed ISO 8601 string is not the
+    /// ISO 8601 calendar.
+    pub(crate) fn check_annotation(calendar: Option<&str>) -> JsResult<()> {
+        match calendar {
+            Some(identifier) if !identifier.eq_ignore_ascii_case(ISO8601) => {
+                Err(JsNativeError::range()
+                    .with_message(format!("'{identifier}' is not a supported calendar"))
+                    .into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Abstract operation `ToTemporalCalendarWithISODefault ( temporalCalendarLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalcalendarwithisodefault
+    pub(crate) fn to_temporal_calendar_with_iso_default(
+        calendar_like: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If temporalCalendarLike is undefined, then
+        //     a. Return ! GetISO8601Calendar().
+        if calendar_like.is_undefined() {
+            return Ok(Self::create(context));
+        }
+
+        // 2. Return ? ToTemporalCalendar(temporalCalendarLike).
+        Self::to_temporal_calendar(calendar_like, context)
+    }
+
+    /// Abstract operation `GetTemporalCalendarWithISODefault ( item )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalcalendarwithisodefault
+    pub(crate) fn get_temporal_calendar_with_iso_default(
+        item: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If item has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]], [[InitializedTemporalTime]], [[InitializedTemporalYearMonth]], or [[InitializedTemporalZonedDateTime]] internal slot, then
+        //     a. Return item.[[Calendar]].
+        if let Some(calendar) =
+            calendar_slot(item).filter(|_| item.borrow().as_calendar().is_none())
+        {
+            return Ok(calendar);
+        }
+
+        // 2. Let calendarLike be ? Get(item, "calendar").
+        let calendar_like = item.get("calendar", context)?;
+
+        // 3. Return ? ToTemporalCalendarWithISODefault(calendarLike).
+        Self::to_temporal_calendar_with_iso_default(&calendar_like, context)
+    }
+
+    /// Abstract operation `ToCalendarNameOption ( normalizedOptions )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tocalendarnameoption
+    pub(crate) fn to_calendar_name_option(
+        options: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<ShowCalendar> {
+        Ok(get_option(options, "calendarName", context)?.unwrap_or(ShowCalendar::Auto))
+    }
+
+    /// Abstract operation `MaybeFormatCalendarAnnotation ( calendarObject, showCalendar )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-maybeformatcalendarannotation
+    pub(crate) const fn format_annotation(show: ShowCalendar) -> &'static str {
+        match show {
+            // The calendar is always iso8601, which is only printed if it is requested.
+            ShowCalendar::Auto | ShowCalendar::Never => "",
+            ShowCalendar::Always => "[u-ca=iso8601]",
+            ShowCalendar::Critical => "[!u-ca=iso8601]",
+        }
+    }
+
+    /// `Temporal.Calendar.from ( calendarLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalCalendar(calendarLike).
+        Self::to_temporal_calendar(args.get_or_undefined(0), context).map(Into::into)
+    }
+
+    /// `get Temporal.Calendar.prototype.id`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.calendar.prototype.id
+    fn get_id(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        this_calendar(this)?;
+        Ok(ISO8601.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dateFromFields ( fields [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar.prototype.datefromfields
+    fn date_from_fields(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let calendar be the this value.
+        // 2. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        let calendar = this_calendar(this)?;
+
+        // 3. Assert: calendar.[[Identifier]] is "iso8601".
+        // 4. If Type(fields) is not Object, throw a TypeError exception.
+        let fields = args
+            .get_or_undefined(0)
+            .as_object()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("dateFromFields requires a fields object")
+            })?;
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 6. Let result be ? ISODateFromFields(fields, options).
+        let fields = TemporalFields::prepare(
+            &fields,
+            Field::DATE,
+            Some(&[Field::Day, Field::Year]),
+            context,
+        )?;
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+        let date = fields.date(overflow)?;
+
+        // 7. Return ? CreateTemporalDate(result.[[Year]], result.[[Month]], result.[[Day]], calendar).
+        PlainDate::new(date, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Calendar.prototype.dateAdd ( date, duration [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar.prototype.dateadd
+    fn date_add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let calendar be the this value.
+        // 2. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        let calendar = this_calendar(this)?;
+
+        // 3. Assert: calendar.[[Identifier]] is "iso8601".
+        // 4. Set date to ? ToTemporalDate(date).
+        let date = PlainDate::to_temporal_date(args.get_or_undefined(0), None, context)?;
+
+        // 5. Set duration to ? ToTemporalDuration(duration).
+        let duration = Duration::to_temporal_duration(args.get_or_undefined(1), context)?;
+
+        // 6. Set options to ? GetOptionsObject(options).
+        // 7. Let overflow be ? ToTemporalOverflow(options).
+        let options = get_options_object(args.get_or_undefined(2))?;
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+
+        // 8. Let balanceResult be ? BalanceTimeDuration(duration.[[Days]], duration.[[Hours]], duration.[[Minutes]], duration.[[Seconds]], duration.[[Milliseconds]], duration.[[Microseconds]], duration.[[Nanoseconds]], "day").
+        // 9. Let result be ? AddISODate(date.[[ISOYear]], date.[[ISOMonth]], date.[[ISODay]], duration.[[Years]], duration.[[Months]], duration.[[Weeks]], balanceResult.[[Days]], overflow).
+        let date = date
+            .iso
+            .add_duration(duration.date_duration_without_time(), overflow)?;
+
+        // 10. Return ? CreateTemporalDate(result.[[Year]], result.[[Month]], result.[[Day]], calendar).
+        PlainDate::new(date, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Calendar.prototype.dateUntil ( one, two [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar.prototype.dateuntil
+    fn date_until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let calendar be the this value.
+        // 2. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        this_calendar(this)?;
+
+        // 3. Assert: calendar.[[Identifier]] is "iso8601".
+        // 4. Set one to ? ToTemporalDate(one).
+        // 5. Set two to ? ToTemporalDate(two).
+        let one = PlainDate::to_temporal_date(args.get_or_undefined(0), None, context)?;
+        let two = PlainDate::to_temporal_date(args.get_or_undefined(1), None, context)?;
+
+        // 6. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(2))?;
+
+        // 7. Let largestUnit be ? GetTemporalUnit(options, "largestUnit", date, "auto").
+        // 8. If largestUnit is "auto", set largestUnit to "day".
+        let largest_unit = get_temporal_unit(
+            options.as_ref(),
+            "largestUnit",
+            UnitGroup::Date,
+            true,
+            context,
+        )?
+        .and_then(UnitOption::unit)
+        .unwrap_or(Unit::Day);
+
+        // 9. Let result be DifferenceISODate(one.[[ISOYear]], one.[[ISOMonth]], one.[[ISODay]], two.[[ISOYear]], two.[[ISOMonth]], two.[[ISODay]], largestUnit).
+        let result = one.iso.difference(two.iso, largest_unit);
+
+        // 10. Return ! CreateTemporalDuration(result.[[Years]], result.[[Months]], result.[[Weeks]], result.[[Days]], 0, 0, 0, 0, 0, 0).
+        DurationRecord::from_date_duration(result)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Calendar.prototype.fields ( fields )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar.prototype.fields
+    fn fields(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        const VALID_FIELDS: [&str; 10] = [
+            "year",
+            "month",
+            "monthCode",
+            "day",
+            "hour",
+            "minute",
+            "second",
+            "millisecond",
+            "microsecond",
+            "nanosecond",
+        ];
+
+        // 1. Let calendar be the this value.
+        // 2. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        this_calendar(this)?;
+
+        // 3. Let iteratorRecord be ? GetIterator(fields, sync).
+        let iterator = args.get_or_undefined(0).get_iterator(context, None, None)?;
+
+        // 4. Let fieldNames be a new empty List.
+        let mut field_names = Vec::new();
+
+        // 5. Let next be true.
+        // 6. Repeat, while next is not false,
+        //     a. Set next to ? IteratorStep(iteratorRecord).
+        while let Some(next) = iterator.step(context)? {
+            // b. If next is not false, then
+            //     i. Let nextValue be ? IteratorValue(next).
+            let next_value = next.value(context)?;
+
+            // ii. If Type(nextValue) is not String, then
+            //     1. Let completion be ThrowCompletion(a newly created TypeError object).
+            //     2. Return ? IteratorClose(iteratorRecord, completion).
+            // iii. If fieldNames contains nextValue, then
+            //     1. Let completion be ThrowCompletion(a newly created RangeError object).
+            //     2. Return ? IteratorClose(iteratorRecord, completion).
+            // iv. If nextValue is not one of "year", "month", "monthCode", "day", "hour", "minute", "second", "millisecond", "microsecond", "nanosecond", then
+            //     1. Let completion be ThrowCompletion(a newly created RangeError object).
+            //     2. Return ? IteratorClose(iteratorRecord, completion).
+            let error = match next_value.variant() {
+                JsVariant::String(name) => {
+                    let name = name.to_std_string_escaped();
+                    if !VALID_FIELDS.contains(&name.as_str()) {
+                        Some(
+                            JsNativeError::range().with_message(format!("'{name}' is not a field")),
+                        )
+                    } else if field_names.contains(&next_value) {
+                        Some(
+                            JsNativeError::range()
+                                .with_message(format!("duplicate field '{name}'")),
+                        )
+                    } else {
+                        None
+                    }
+                }
+                _ => Some(JsNativeError::typ().with_message("the field names must be strings")),
+            };
+            if let Some(error) = error {
+                return iterator.close(Err(error.into()), context);
+            }
+
+            // v. Append nextValue to the end of the List fieldNames.
+            field_names.push(next_value);
+        }
+
+        // 7. Return CreateArrayFromList(fieldNames).
+        Ok(Array::create_array_from_list(field_names, context).into())
+    }
+
+    /// `Temporal.Calendar.prototype.mergeFields ( fields, additionalFields )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar.prototype.mergefields
+    fn merge_fields(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let calendar be the this value.
+        // 2. Perform ? RequireInternalSlot(calendar, [[InitializedTemporalCalendar]]).
+        this_calendar(this)?;
+
+        // 3. Set fields to ? ToObject(fields).
+        // 4. Set additionalFields to ? ToObject(additionalFields).
+        let fields = args.get_or_undefined(0).to_object(context)?;
+        let additional_fields = args.get_or_undefined(1).to_object(context)?;
+
+        // 5. Assert: calendar.[[Identifier]] is "iso8601".
+        // 6. Return ? DefaultMergeCalendarFields(fields, additionalFields).
+        let merged = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::ordinary(),
+        );
+
+        let is_month = |key: &JsValue| match key.variant() {
+            JsVariant::String(key) => key == "month" || key == "monthCode",
+            _ => false,
+        };
+
+        let fields_keys = fields.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+        for key in fields_keys.iter().filter(|key| !is_month(key)) {
+            let key = key.to_property_key(context)?;
+            let value = fields.get(key.clone(), context)?;
+            if !value.is_undefined() {
+                merged.create_data_property_or_throw(key, value, context)?;
+            }
+        }
+
+        let new_keys =
+            additional_fields.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+        for key in &new_keys {
+            let key = key.to_property_key(context)?;
+            let value = additional_fields.get(key.clone(), context)?;
+            if !value.is_undefined() {
+                merged.create_data_property_or_throw(key, value, context)?;
+            }
+        }
+
+        if !new_keys.iter().any(is_month) {
+            for key in ["month", "monthCode"] {
+                let value = fields.get(key, context)?;
+                if !value.is_undefined() {
+                    merged.create_data_property_or_throw(key, value, context)?;
+                }
+            }
+        }
+
+        Ok(merged.into())
+    }
+
+    /// `Temporal.Calendar.prototype.toString ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar.prototype.tostring
+    fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        this_calendar(this)?;
+        Ok(ISO8601.into())
+    }
+}
+
+/// Gets the calendar object of a `Temporal.Calendar`, or the `[[Calendar]]` internal slot of
+/// another `Temporal` object.
+fn calendar_slot(object: &JsObject) -> Option<JsObject> {
+    let borrowed = object.borrow();
+    if borrowed.as_calendar().is_some() {
+        Some(object.clone())
+    } else if let Some(date) = borrowed.as_plain_date() {
+        Some(date.calendar.clone())
+    } else if let Some(time) = borrowed.as_plain_time() {
+        Some(time.calendar.clone())
+    } else if let Some(date_time) = borrowed.as_plain_date_time() {
+        Some(date_time.calendar.clone())
+    } else {
+        borrowed
+            .as_zoned_date_time()
+            .map(|zoned| zoned.calendar.clone())
+    }
+}
+
+/// The error thrown for calendar objects that are not `Temporal.Calendar` objects.
+fn custom_calendar_error() -> crate::JsError {
+    JsNativeError::typ()
+        .with_message("custom calendars are not supported")
+        .into()
+}
+
+/// Gets the `this` value of a `Temporal.Calendar` method, which must be a calendar.
+fn this_calendar(this: &JsValue) -> JsResult<JsObject> {
+    this.as_object()
+        .filter(|object| object.borrow().as_calendar().is_some())
+        .cloned()
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Temporal.Calendar")
+                .into()
+        })
+}
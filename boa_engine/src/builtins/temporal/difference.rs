@@ -0,0 +1,1034 @@
+//! The abstract operations that add durations to exact times, and that compute and round the
+//! differences between the `Temporal` objects.
+//!
+//! The durations of this module are internal duration records, with the years, months, weeks
+//! and days as integers, and the rest of the duration as an exact number of nanoseconds.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-duration-abstract-ops
+
+use super::{
+    calendar::Calendar,
+    fields::{Field, TemporalFields},
+    iso::{check_epoch_nanoseconds, DateDuration, IsoDate, IsoDateTime},
+    options::{
+        DifferenceSettings, Disambiguation, OffsetOption, Overflow, RoundingMode, Unit, NS_PER_DAY,
+        NS_PER_SECOND,
+    },
+    parser::{parse_date_time, parse_offset, parse_zoned_date_time},
+    time_zone::TimeZone,
+    zoned_date_time::{interpret_iso_date_time_offset, OffsetBehaviour},
+};
+use crate::{error::JsNativeError, object::JsObject, value::JsVariant, Context, JsResult};
+
+/// The largest absolute value of the time part of a duration, in nanoseconds, which is just
+/// below 2^53 seconds.
+pub(crate) const MAX_TIME_DURATION: i128 = (1 << 53) * NS_PER_SECOND - 1;
+
+/// An internal duration record, which is a date duration and an exact number of nanoseconds.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-internal-duration-records
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct InternalDuration {
+    pub(crate) date: DateDuration,
+    pub(crate) time: i128,
+}
+
+impl InternalDuration {
+    /// Abstract operation `CombineDateAndTimeDuration ( dateDuration, timeDuration )`
+    pub(crate) const fn new(date: DateDuration, time: i128) -> Self {
+        Self { date, time }
+    }
+
+    /// Abstract operation `InternalDurationSign ( internalDuration )`
+    pub(crate) fn sign(self) -> i64 {
+        match self.date.sign() {
+            0 => self.time.signum() as i64,
+            sign => sign,
+        }
+    }
+}
+
+/// The starting point of a duration that is rounded or totalled relative to a date, and the
+/// time zone in which the dates are interpreted, if any.
+struct Origin<'a> {
+    date_time: IsoDateTime,
+    time_zone: Option<&'a TimeZone>,
+}
+
+impl Origin<'_> {
+    /// Adds a date duration to the date of the origin.
+    fn add(&self, duration: DateDuration) -> JsResult<IsoDate> {
+        calendar_date_add(self.date_time.date, duration, Overflow::Constrain)
+    }
+
+    /// Gets the exact time of `date` at the wall-clock time of the origin.
+    fn epoch_nanoseconds_of(&self, date: IsoDate, context: &Context) -> JsResult<i128> {
+        let date_time = IsoDateTime::new(date, self.date_time.time);
+        match self.time_zone {
+            None => Ok(date_time.to_epoch_nanoseconds()),
+            Some(time_zone) => {
+                time_zone.epoch_nanoseconds_for(date_time, Disambiguation::Compatible, context)
+            }
+        }
+    }
+}
+
+/// The result of nudging a duration to a rounding increment.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-duration-nudge-result-records
+struct Nudge {
+    duration: InternalDuration,
+    epoch_nanoseconds: i128,
+    expanded: bool,
+}
+
+/// The `relativeTo` option of the methods of `Temporal.Duration`.
+#[derive(Debug, Clone)]
+pub(crate) enum RelativeTo {
+    None,
+    Plain(IsoDate),
+    Zoned {
+        epoch_nanoseconds: i128,
+        time_zone: TimeZone,
+    },
+}
+
+impl RelativeTo {
+    /// Abstract operation `GetTemporalRelativeToOption ( options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalrelativetooption
+    pub(crate) fn from_options(
+        options: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let value be ? Get(options, "relativeTo").
+        // 2. If value is undefined, return the Record { [[PlainRelativeTo]]: undefined, [[ZonedRelativeTo]]: undefined }.
+        let value = match options {
+            Some(options) => options.get("relativeTo", context)?,
+            None => return Ok(Self::None),
+        };
+
+        match value.variant() {
+            JsVariant::Undefined => Ok(Self::None),
+            // 5. If value is an Object, then
+            JsVariant::Object(object) => {
+                // a. If value has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i. Return the Record { [[PlainRelativeTo]]: undefined, [[ZonedRelativeTo]]: value }.
+                // b. If value has an [[InitializedTemporalDate]] internal slot, then
+                //     i. Return the Record { [[PlainRelativeTo]]: value, [[ZonedRelativeTo]]: undefined }.
+                // c. If value has an [[InitializedTemporalDateTime]] internal slot, then
+                //     i. Let plainDate be ! CreateTemporalDate(value.[[ISODateTime]].[[ISODate]], value.[[Calendar]]).
+                //     ii. Return the Record { [[PlainRelativeTo]]: plainDate, [[ZonedRelativeTo]]: undefined }.
+                {
+                    let borrowed = object.borrow();
+                    if let Some(zoned) = borrowed.as_zoned_date_time() {
+                        return Ok(Self::Zoned {
+                            epoch_nanoseconds: zoned.epoch_nanoseconds,
+                            time_zone: TimeZone::of(&zoned.time_zone),
+                        });
+                    }
+                    if let Some(date) = borrowed.as_plain_date() {
+                        return Ok(Self::Plain(date.iso));
+                    }
+                    if let Some(date_time) = borrowed.as_plain_date_time() {
+                        return Ok(Self::Plain(date_time.iso.date));
+                    }
+                }
+
+                // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(value).
+                Calendar::get_temporal_calendar_with_iso_default(object, context)?;
+
+                // e. Let fields be ? PrepareCalendarFields(calendar, value, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset, time-zone », «»).
+                let fields =
+                    TemporalFields::prepare(object, Field::ZONED_DATE_TIME, Some(&[]), context)?;
+
+                // f. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, constrain).
+                let date = fields.date(Overflow::Constrain)?;
+                let time = fields.time(Overflow::Constrain)?;
+
+                // g. Let timeZone be fields.[[TimeZone]].
+                // h. Let offsetString be fields.[[OffsetString]].
+                // i. If offsetString is unset, then
+                //     i. Set offsetBehaviour to wall.
+                let time_zone = match &fields.time_zone {
+                    None => return Ok(Self::Plain(date.check_limits()?)),
+                    Some(time_zone) => {
+                        TimeZone::of(&TimeZone::to_temporal_time_zone(time_zone, context)?)
+                    }
+                };
+                let behaviour = match &fields.offset {
+                    Some(offset) => OffsetBehaviour::Option(parse_offset(offset)?),
+                    None => OffsetBehaviour::Wall,
+                };
+
+                // 8-12. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time, offsetBehaviour, offsetNs, timeZone, compatible, reject, matchBehaviour).
+                let epoch_nanoseconds = interpret_iso_date_time_offset(
+                    IsoDateTime::new(date, time),
+                    behaviour,
+                    &time_zone,
+                    Disambiguation::Compatible,
+                    OffsetOption::Reject,
+                    false,
+                    context,
+                )?;
+                Ok(Self::Zoned {
+                    epoch_nanoseconds,
+                    time_zone,
+                })
+            }
+            // 6. Else,
+            JsVariant::String(string) => {
+                // b. Let result be ? ParseISODateTime(value, « TemporalDateTimeString[+Zoned], TemporalDateTimeString[~Zoned] »).
+                let string = string.to_std_string_escaped();
+                let parsed =
+                    parse_zoned_date_time(&string).or_else(|_| parse_date_time(&string))?;
+
+                // i-k. Let calendar be result.[[Calendar]].
+                Calendar::check_annotation(parsed.calendar.as_deref())?;
+
+                let date = parsed.date.expect("a date-time string must have a date");
+
+                // g. If annotation is empty, then
+                //     i. Let timeZone be unset.
+                // 7. If timeZone is unset, then
+                //     a. Let plainDate be ? CreateTemporalDate(isoDate, calendar).
+                //     b. Return the Record { [[PlainRelativeTo]]: plainDate, [[ZonedRelativeTo]]: undefined }.
+                let time_zone = match parsed.time_zone {
+                    None => return Ok(Self::Plain(date.check_limits()?)),
+                    Some(time_zone) => TimeZone::from_parsed(time_zone, context)?,
+                };
+
+                let epoch_nanoseconds = match parsed.time {
+                    None => time_zone.start_of_day(date, context)?,
+                    Some(time) => {
+                        let (behaviour, match_minutes) =
+                            OffsetBehaviour::from_parsed(parsed.offset);
+                        interpret_iso_date_time_offset(
+                            IsoDateTime::new(date, time),
+                            behaviour,
+                            &time_zone,
+                            Disambiguation::Compatible,
+                            OffsetOption::Reject,
+                            match_minutes,
+                            context,
+                        )?
+                    }
+                };
+                Ok(Self::Zoned {
+                    epoch_nanoseconds,
+                    time_zone,
+                })
+            }
+            // a. If value is not a String, throw a TypeError exception.
+            _ => Err(JsNativeError::typ()
+                .with_message("relativeTo must be a Temporal object, a property bag or a string")
+                .into()),
+        }
+    }
+}
+
+/// Abstract operation `RoundTimeDurationToIncrement ( d, increment, roundingMode )`
+///
+/// Rounds a number of nanoseconds to `increment`, throwing a `RangeError` if the result is not
+/// a valid time duration.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundtimedurationtoincrement
+fn round_time_duration_to_increment(
+    time: i128,
+    increment: i128,
+    mode: RoundingMode,
+) -> JsResult<i128> {
+    check_time_duration(mode.round(time, increment))
+}
+
+/// Abstract operation `RoundTimeDuration ( timeDuration, increment, unit, roundingMode )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundtimeduration
+pub(crate) fn round_time_duration(
+    time: i128,
+    increment: u64,
+    unit: Unit,
+    mode: RoundingMode,
+) -> JsResult<i128> {
+    round_time_duration_to_increment(time, unit.nanoseconds() * i128::from(increment), mode)
+}
+
+/// Throws a `RangeError` if `time` is longer than the maximum time duration.
+pub(crate) fn check_time_duration(time: i128) -> JsResult<i128> {
+    if time.abs() > MAX_TIME_DURATION {
+        return Err(JsNativeError::range()
+            .with_message("the duration is outside of the supported range")
+            .into());
+    }
+    Ok(time)
+}
+
+/// Divides two integers, with the precision of a floating point number.
+pub(crate) fn divide(numerator: i128, denominator: i128) -> f64 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    quotient as f64 + remainder as f64 / denominator as f64
+}
+
+/// Abstract operation `TotalTimeDuration ( timeDuration, unit )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totaltimeduration
+pub(crate) fn total_time_duration(time: i128, unit: Unit) -> f64 {
+    divide(time, unit.nanoseconds())
+}
+
+/// Abstract operation `CalendarDateAdd ( calendar, isoDate, duration, overflow )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendardateadd
+pub(crate) fn calendar_date_add(
+    date: IsoDate,
+    duration: DateDuration,
+    overflow: Overflow,
+) -> JsResult<IsoDate> {
+    date.add_duration(duration, overflow)?.check_limits()
+}
+
+/// Abstract operation `AddInstant ( epochNanoseconds, timeDuration )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-addinstant
+pub(crate) fn add_instant(epoch_nanoseconds: i128, time: i128) -> JsResult<i128> {
+    // 1. Let result be AddTimeDurationToEpochNanoseconds(timeDuration, epochNanoseconds).
+    // 2. If IsValidEpochNanoseconds(result) is false, throw a RangeError exception.
+    // 3. Return result.
+    check_epoch_nanoseconds(epoch_nanoseconds + time)
+}
+
+/// Abstract operation `AddZonedDateTime ( epochNanoseconds, timeZone, calendar, duration, overflow )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-addzoneddatetime
+pub(crate) fn add_zoned_date_time(
+    epoch_nanoseconds: i128,
+    time_zone: &TimeZone,
+    duration: InternalDuration,
+    overflow: Overflow,
+    context: &Context,
+) -> JsResult<i128> {
+    // 1. If DateDurationSign(duration.[[Date]]) = 0, then
+    //     a. Return ? AddInstant(epochNanoseconds, duration.[[Time]]).
+    if duration.date.sign() == 0 {
+        return add_instant(epoch_nanoseconds, duration.time);
+    }
+
+    // 2. Let isoDateTime be GetISODateTimeFor(timeZone, epochNanoseconds).
+    let date_time = time_zone.date_time_for(epoch_nanoseconds, context);
+
+    // 3. Let addedDate be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], duration.[[Date]], overflow).
+    let added = calendar_date_add(date_time.date, duration.date, overflow)?;
+
+    // 4. Let intermediateDateTime be CombineISODateAndTimeRecord(addedDate, isoDateTime.[[Time]]).
+    // 5. If ISODateTimeWithinLimits(intermediateDateTime) is false, throw a RangeError exception.
+    let intermediate = IsoDateTime::new(added, date_time.time).check_limits()?;
+
+    // 6. Let intermediateNs be ! GetEpochNanosecondsFor(timeZone, intermediateDateTime, compatible).
+    let intermediate =
+        time_zone.epoch_nanoseconds_for(intermediate, Disambiguation::Compatible, context)?;
+
+    // 7. Return ? AddInstant(intermediateNs, duration.[[Time]]).
+    add_instant(intermediate, duration.time)
+}
+
+/// Abstract operation `DifferenceInstant ( ns1, ns2, roundingIncrement, smallestUnit, roundingMode )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceinstant
+pub(crate) fn difference_instant(
+    one: i128,
+    two: i128,
+    settings: DifferenceSettings,
+) -> JsResult<InternalDuration> {
+    // 1. Let timeDuration be TimeDurationFromEpochNanosecondsDifference(ns2, ns1).
+    // 2. Set timeDuration to ! RoundTimeDuration(timeDuration, roundingIncrement, smallestUnit, roundingMode).
+    let time = round_time_duration(
+        two - one,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    )?;
+
+    // 3. Return CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+    Ok(InternalDuration::new(DateDuration::default(), time))
+}
+
+/// Abstract operation `DifferencePlainDateTimeWithRounding ( isoDateTime1, isoDateTime2, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithrounding
+pub(crate) fn difference_plain_date_time_with_rounding(
+    one: IsoDateTime,
+    two: IsoDateTime,
+    settings: DifferenceSettings,
+    context: &Context,
+) -> JsResult<InternalDuration> {
+    // 1. If CompareISODateTime(isoDateTime1, isoDateTime2) = 0, then
+    //     a. Return CombineDateAndTimeDuration(ZeroDateDuration(), 0).
+    if one == two {
+        return Ok(InternalDuration::default());
+    }
+
+    // 2. If ISODateTimeWithinLimits(isoDateTime1) is false or ISODateTimeWithinLimits(isoDateTime2) is false, throw a RangeError exception.
+    one.check_limits()?;
+    two.check_limits()?;
+
+    // 3. Let diff be DifferenceISODateTime(isoDateTime1, isoDateTime2, calendar, largestUnit).
+    let (date, time) = one.difference(two, settings.largest_unit);
+    let difference = InternalDuration::new(date, time);
+
+    // 4. If smallestUnit is nanosecond and roundingIncrement = 1, return diff.
+    if settings.smallest_unit == Unit::Nanosecond && settings.rounding_increment == 1 {
+        return Ok(difference);
+    }
+
+    // 5. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTime2).
+    // 6. Return ? RoundRelativeDuration(diff, destEpochNs, isoDateTime1, unset, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+    let origin = Origin {
+        date_time: one,
+        time_zone: None,
+    };
+    round_relative_duration(
+        difference,
+        two.to_epoch_nanoseconds(),
+        &origin,
+        settings,
+        context,
+    )
+}
+
+/// Abstract operation `DifferencePlainDateTimeWithTotal ( isoDateTime1, isoDateTime2, calendar, unit )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithtotal
+pub(crate) fn difference_plain_date_time_with_total(
+    one: IsoDateTime,
+    two: IsoDateTime,
+    unit: Unit,
+    context: &Context,
+) -> JsResult<f64> {
+    // 1. If CompareISODateTime(isoDateTime1, isoDateTime2) = 0, then
+    //     a. Return 0.
+    if one == two {
+        return Ok(0.0);
+    }
+
+    // 2. If ISODateTimeWithinLimits(isoDateTime1) is false or ISODateTimeWithinLimits(isoDateTime2) is false, throw a RangeError exception.
+    one.check_limits()?;
+    two.check_limits()?;
+
+    // 3. Let diff be DifferenceISODateTime(isoDateTime1, isoDateTime2, calendar, unit).
+    let (date, time) = one.difference(two, unit);
+
+    // 4. If unit is nanosecond, return diff.[[Time]].
+    if unit == Unit::Nanosecond {
+        return Ok(time as f64);
+    }
+
+    // 5. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTime2).
+    // 6. Return ? TotalRelativeDuration(diff, destEpochNs, isoDateTime1, unset, calendar, unit).
+    let origin = Origin {
+        date_time: one,
+        time_zone: None,
+    };
+    total_relative_duration(
+        InternalDuration::new(date, time),
+        two.to_epoch_nanoseconds(),
+        &origin,
+        unit,
+        context,
+    )
+}
+
+/// Abstract operation `DifferenceZonedDateTime ( ns1, ns2, timeZone, calendar, largestUnit )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetime
+fn difference_zoned_date_time(
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    largest_unit: Unit,
+    context: &Context,
+) -> JsResult<InternalDuration> {
+    // 1. If ns1 = ns2, return CombineDateAndTimeDuration(ZeroDateDuration(), 0).
+    if one == two {
+        return Ok(InternalDuration::default());
+    }
+
+    // 2. Let startDateTime be GetISODateTimeFor(timeZone, ns1).
+    // 3. Let endDateTime be GetISODateTimeFor(timeZone, ns2).
+    let start = time_zone.date_time_for(one, context);
+    let end = time_zone.date_time_for(two, context);
+
+    // 4. If CompareISODate(startDateTime.[[ISODate]], endDateTime.[[ISODate]]) = 0, then
+    //     a. Let timeDuration be TimeDurationFromEpochNanosecondsDifference(ns2, ns1).
+    //     b. Return CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+    if start.date == end.date {
+        return Ok(InternalDuration::new(DateDuration::default(), two - one));
+    }
+
+    // 5. If ns2 - ns1 < 0, let sign be -1; else let sign be 1.
+    let sign: i64 = if two < one { -1 } else { 1 };
+
+    // 6. If sign = 1, let maxDayCorrection be 2; else let maxDayCorrection be 1.
+    let max_day_correction = if sign == 1 { 2 } else { 1 };
+
+    // 7. Let dayCorrection be 0.
+    // 8. Let timeDuration be DifferenceTime(startDateTime.[[Time]], endDateTime.[[Time]]).
+    // 9. If TimeDurationSign(timeDuration) = -sign, set dayCorrection to dayCorrection + 1.
+    let time = end.time.to_nanoseconds() - start.time.to_nanoseconds();
+    let mut day_correction = i64::from(time.signum() as i64 == -sign);
+
+    // 10. Let success be false.
+    // 11. Repeat, while dayCorrection ≤ maxDayCorrection and success is false,
+    while day_correction <= max_day_correction {
+        // a. Let intermediateDate be BalanceISODate(endDateTime.[[ISODate]].[[Year]], endDateTime.[[ISODate]].[[Month]], endDateTime.[[ISODate]].[[Day]] - dayCorrection × sign).
+        let intermediate_date =
+            IsoDate::from_epoch_days(end.date.epoch_days() - day_correction * sign);
+
+        // b. Let intermediateDateTime be CombineISODateAndTimeRecord(intermediateDate, startDateTime.[[Time]]).
+        // c. Let intermediateNs be ? GetEpochNanosecondsFor(timeZone, intermediateDateTime, compatible).
+        let intermediate = time_zone.epoch_nanoseconds_for(
+            IsoDateTime::new(intermediate_date, start.time),
+            Disambiguation::Compatible,
+            context,
+        )?;
+
+        // d. Set timeDuration to TimeDurationFromEpochNanosecondsDifference(ns2, intermediateNs).
+        // e. Let timeSign be TimeDurationSign(timeDuration).
+        // f. If sign ≠ -timeSign, then
+        //     i. Set success to true.
+        let time = two - intermediate;
+        if time.signum() as i64 != -sign {
+            // 13. Let dateLargestUnit be LargerOfTwoTemporalUnits(largestUnit, day).
+            // 14. Let dateDifference be CalendarDateUntil(calendar, startDateTime.[[ISODate]], intermediateDate, dateLargestUnit).
+            // 15. Return CombineDateAndTimeDuration(dateDifference, timeDuration).
+            let date = start
+                .date
+                .difference(intermediate_date, largest_unit.max(Unit::Day));
+            return Ok(InternalDuration::new(date, time));
+        }
+
+        // g. Set dayCorrection to dayCorrection + 1.
+        day_correction += 1;
+    }
+
+    // 12. Assert: success is true.
+    Err(JsNativeError::range()
+        .with_message("the time zone has transitions that are too close to each other")
+        .into())
+}
+
+/// Abstract operation `DifferenceZonedDateTimeWithRounding ( ns1, ns2, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithrounding
+pub(crate) fn difference_zoned_date_time_with_rounding(
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    settings: DifferenceSettings,
+    context: &Context,
+) -> JsResult<InternalDuration> {
+    // 1. If TemporalUnitCategory(largestUnit) is time, then
+    //     a. Return DifferenceInstant(ns1, ns2, roundingIncrement, smallestUnit, roundingMode).
+    if !settings.largest_unit.is_date_unit() {
+        return difference_instant(one, two, settings);
+    }
+
+    // 2. Let difference be ? DifferenceZonedDateTime(ns1, ns2, timeZone, calendar, largestUnit).
+    let difference =
+        difference_zoned_date_time(one, two, time_zone, settings.largest_unit, context)?;
+
+    // 3. If smallestUnit is nanosecond and roundingIncrement = 1, return difference.
+    if settings.smallest_unit == Unit::Nanosecond && settings.rounding_increment == 1 {
+        return Ok(difference);
+    }
+
+    // 4. Let dateTime be GetISODateTimeFor(timeZone, ns1).
+    // 5. Return ? RoundRelativeDuration(difference, ns2, dateTime, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+    let origin = Origin {
+        date_time: time_zone.date_time_for(one, context),
+        time_zone: Some(time_zone),
+    };
+    round_relative_duration(difference, two, &origin, settings, context)
+}
+
+/// Abstract operation `DifferenceZonedDateTimeWithTotal ( ns1, ns2, timeZone, calendar, unit )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithtotal
+pub(crate) fn difference_zoned_date_time_with_total(
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    unit: Unit,
+    context: &Context,
+) -> JsResult<f64> {
+    // 1. If TemporalUnitCategory(unit) is time, then
+    //     a. Let difference be TimeDurationFromEpochNanosecondsDifference(ns2, ns1).
+    //     b. Return TotalTimeDuration(difference, unit).
+    if !unit.is_date_unit() {
+        return Ok(total_time_duration(two - one, unit));
+    }
+
+    // 2. Let difference be ? DifferenceZonedDateTime(ns1, ns2, timeZone, calendar, unit).
+    let difference = difference_zoned_date_time(one, two, time_zone, unit, context)?;
+
+    // 3. Let dateTime be GetISODateTimeFor(timeZone, ns1).
+    // 4. Return ? TotalRelativeDuration(difference, ns2, dateTime, timeZone, calendar, unit).
+    let origin = Origin {
+        date_time: time_zone.date_time_for(one, context),
+        time_zone: Some(time_zone),
+    };
+    total_relative_duration(difference, two, &origin, unit, context)
+}
+
+/// Abstract operation `NudgeToCalendarUnit ( sign, duration, destEpochNs, isoDateTime, timeZone, calendar, increment, unit, roundingMode )`
+///
+/// Rounds a duration to an increment of a calendar unit, or of days in a time zone. Returns the
+/// nudged duration and the total of the duration in the unit.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetocalendarunit
+fn nudge_to_calendar_unit(
+    sign: i64,
+    duration: InternalDuration,
+    destination: i128,
+    origin: &Origin<'_>,
+    settings: DifferenceSettings,
+    context: &Context,
+) -> JsResult<(Nudge, f64)> {
+    let increment = settings.rounding_increment as i64;
+    let date = duration.date;
+    let truncate = |value: i64| value - value % increment;
+
+    // 1-5. Compute r1, r2, startDuration and endDuration.
+    let (r1, start, end) = match settings.smallest_unit {
+        Unit::Year => {
+            let years = truncate(date.years);
+            (
+                years,
+                DateDuration::new(years, 0, 0, 0),
+                DateDuration::new(years + increment * sign, 0, 0, 0),
+            )
+        }
+        Unit::Month => {
+            let months = truncate(date.months);
+            (
+                months,
+                DateDuration::new(date.years, months, 0, 0),
+                DateDuration::new(date.years, months + increment * sign, 0, 0),
+            )
+        }
+        Unit::Week => {
+            let weeks_start = origin.add(DateDuration::new(date.years, date.months, 0, 0))?;
+            let weeks_end = IsoDate::from_epoch_days(weeks_start.epoch_days() + date.days);
+            let until = weeks_start.difference(weeks_end, Unit::Week);
+            let weeks = truncate(date.weeks + until.weeks);
+            (
+                weeks,
+                DateDuration::new(date.years, date.months, weeks, 0),
+                DateDuration::new(date.years, date.months, weeks + increment * sign, 0),
+            )
+        }
+        _ => {
+            let days = truncate(date.days);
+            (
+                days,
+                DateDuration::new(date.years, date.months, date.weeks, days),
+                DateDuration::new(date.years, date.months, date.weeks, days + increment * sign),
+            )
+        }
+    };
+
+    // 7-12. Let startEpochNs and endEpochNs be the exact times of the start and the end.
+    let start_epoch_nanoseconds = origin.epoch_nanoseconds_of(origin.add(start)?, context)?;
+    let end_epoch_nanoseconds = origin.epoch_nanoseconds_of(origin.add(end)?, context)?;
+
+    // 14. Assert: startEpochNs ≠ endEpochNs.
+    // 15. Let progress be (destEpochNs - startEpochNs) / (endEpochNs - startEpochNs).
+    // 16. Let total be r1 + progress × increment × sign.
+    let span = (end_epoch_nanoseconds - start_epoch_nanoseconds).abs();
+    let progress = (destination - start_epoch_nanoseconds).abs();
+    if span == 0 || progress > span {
+        return Err(JsNativeError::range()
+            .with_message("the duration cannot be rounded relative to this date")
+            .into());
+    }
+    let increment = i128::from(increment);
+    let r1 = i128::from(r1.abs());
+    let r2 = r1 + increment;
+    let numerator = r1 * span + increment * progress;
+    let total = divide(i128::from(sign) * numerator, span);
+
+    // 17-20. Let roundedUnit be ApplyUnsignedRoundingMode(abs(total), abs(r1), abs(r2), unsignedRoundingMode).
+    let rounded_unit = if progress == span {
+        r2
+    } else {
+        settings
+            .rounding_mode
+            .round(i128::from(sign) * numerator, increment * span)
+            .abs()
+            / span
+    };
+
+    // 21. If roundedUnit - abs(r2) = 0, then
+    //     a. Let didExpandCalendarUnit be true.
+    //     b. Let resultDuration be endDuration.
+    //     c. Let nudgedEpochNs be endEpochNs.
+    // 22. Else,
+    //     a. Let didExpandCalendarUnit be false.
+    //     b. Let resultDuration be startDuration.
+    //     c. Let nudgedEpochNs be startEpochNs.
+    let nudge = if rounded_unit == r2 {
+        Nudge {
+            duration: InternalDuration::new(end, 0),
+            epoch_nanoseconds: end_epoch_nanoseconds,
+            expanded: true,
+        }
+    } else {
+        Nudge {
+            duration: InternalDuration::new(start, 0),
+            epoch_nanoseconds: start_epoch_nanoseconds,
+            expanded: false,
+        }
+    };
+
+    // 23-25. Return the Record { [[NudgeResult]]: nudgeResult, [[Total]]: total }.
+    Ok((nudge, total))
+}
+
+/// Abstract operation `NudgeToZonedTime ( sign, duration, isoDateTime, timeZone, calendar, increment, unit, roundingMode )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetozonedtime
+fn nudge_to_zoned_time(
+    sign: i64,
+    duration: InternalDuration,
+    origin: &Origin<'_>,
+    settings: DifferenceSettings,
+    context: &Context,
+) -> JsResult<Nudge> {
+    // 1. Let start be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], duration.[[Date]], constrain).
+    // 2. Let startDateTime be CombineISODateAndTimeRecord(start, isoDateTime.[[Time]]).
+    // 3. Let endDate be BalanceISODate(start.[[Year]], start.[[Month]], start.[[Day]] + sign).
+    // 4. Let endDateTime be CombineISODateAndTimeRecord(endDate, isoDateTime.[[Time]]).
+    let start = origin.add(duration.date)?;
+    let end = IsoDate::from_epoch_days(start.epoch_days() + sign);
+
+    // 5. Let startEpochNs be ? GetEpochNanosecondsFor(timeZone, startDateTime, compatible).
+    // 6. Let endEpochNs be ? GetEpochNanosecondsFor(timeZone, endDateTime, compatible).
+    let start_epoch_nanoseconds = origin.epoch_nanoseconds_of(start, context)?;
+    let end_epoch_nanoseconds = origin.epoch_nanoseconds_of(end, context)?;
+
+    // 7. Let daySpan be TimeDurationFromEpochNanosecondsDifference(endEpochNs, startEpochNs).
+    let day_span = end_epoch_nanoseconds - start_epoch_nanoseconds;
+
+    // 9. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 21 whose "Value" column contains unit.
+    // 10. Let roundedTimeDuration be ? RoundTimeDurationToIncrement(duration.[[Time]], increment × unitLength, roundingMode).
+    let increment = settings.smallest_unit.nanoseconds() * i128::from(settings.rounding_increment);
+    let mut rounded =
+        round_time_duration_to_increment(duration.time, increment, settings.rounding_mode)?;
+
+    // 11. Let beyondDaySpan be ! AddTimeDuration(roundedTimeDuration, -daySpan).
+    let beyond_day_span = rounded - day_span;
+
+    // 12. If TimeDurationSign(beyondDaySpan) ≠ -sign, then
+    //     a. Let didRoundBeyondDay be true.
+    //     b. Let dayDelta be sign.
+    //     c. Set roundedTimeDuration to ? RoundTimeDurationToIncrement(beyondDaySpan, increment × unitLength, roundingMode).
+    //     d. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(roundedTimeDuration, endEpochNs).
+    // 13. Else,
+    //     a. Let didRoundBeyondDay be false.
+    //     b. Let dayDelta be 0.
+    //     c. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(roundedTimeDuration, startEpochNs).
+    let (day_delta, epoch_nanoseconds) = if beyond_day_span.signum() as i64 == -sign {
+        (0, start_epoch_nanoseconds + rounded)
+    } else {
+        rounded =
+            round_time_duration_to_increment(beyond_day_span, increment, settings.rounding_mode)?;
+        (sign, end_epoch_nanoseconds + rounded)
+    };
+
+    // 14. Let dateDuration be ! AdjustDateDurationRecord(duration.[[Date]], duration.[[Date]].[[Days]] + dayDelta).
+    // 15. Let resultDuration be CombineDateAndTimeDuration(dateDuration, roundedTimeDuration).
+    // 16. Return Duration Nudge Result Record { [[Duration]]: resultDuration, [[NudgedEpochNs]]: nudgedEpochNs, [[DidExpandCalendarUnit]]: didRoundBeyondDay }.
+    Ok(Nudge {
+        duration: InternalDuration::new(
+            DateDuration {
+                days: duration.date.days + day_delta,
+                ..duration.date
+            },
+            rounded,
+        ),
+        epoch_nanoseconds,
+        expanded: day_delta != 0,
+    })
+}
+
+/// Abstract operation `NudgeToDayOrTime ( duration, destEpochNs, largestUnit, increment, smallestUnit, roundingMode )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetodayortime
+fn nudge_to_day_or_time(
+    duration: InternalDuration,
+    destination: i128,
+    settings: DifferenceSettings,
+) -> JsResult<Nudge> {
+    // 1. Let timeDuration be ! Add24HourDaysToTimeDuration(duration.[[Time]], duration.[[Date]].[[Days]]).
+    let time = check_time_duration(duration.time + i128::from(duration.date.days) * NS_PER_DAY)?;
+
+    // 2-3. Let roundedTime be ? RoundTimeDurationToIncrement(timeDuration, unitLength × increment, roundingMode).
+    let rounded = round_time_duration(
+        time,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    )?;
+
+    // 4. Let diffTime be ! AddTimeDuration(roundedTime, -timeDuration).
+    // 5. Let wholeDays be truncate(TotalTimeDuration(timeDuration, day)).
+    // 6. Let roundedWholeDays be truncate(TotalTimeDuration(roundedTime, day)).
+    // 7. Let dayDelta be roundedWholeDays - wholeDays.
+    // 8. If dayDelta < 0, let dayDeltaSign be -1; else if dayDelta > 0, let dayDeltaSign be 1; else let dayDeltaSign be 0.
+    // 9. If dayDeltaSign = TimeDurationSign(timeDuration), let didExpandDays be true; else let didExpandDays be false.
+    let difference = rounded - time;
+    let whole_days = time / NS_PER_DAY;
+    let rounded_whole_days = rounded / NS_PER_DAY;
+    let expanded = (rounded_whole_days - whole_days).signum() == time.signum();
+
+    // 10. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(diffTime, destEpochNs).
+    let epoch_nanoseconds = destination + difference;
+
+    // 11. Let days be 0.
+    // 12. Let remainder be roundedTime.
+    // 13. If TemporalUnitCategory(largestUnit) is date, then
+    //     a. Set days to roundedWholeDays.
+    //     b. Set remainder to ! AddTimeDuration(roundedTime, TimeDurationFromComponents(-roundedWholeDays × HoursPerDay, 0, 0, 0, 0, 0)).
+    let (days, remainder) = if settings.largest_unit.is_date_unit() {
+        (
+            rounded_whole_days,
+            rounded - rounded_whole_days * NS_PER_DAY,
+        )
+    } else {
+        (0, rounded)
+    };
+
+    // 14. Let dateDuration be ! AdjustDateDurationRecord(duration.[[Date]], days).
+    // 15. Let resultDuration be CombineDateAndTimeDuration(dateDuration, remainder).
+    // 16. Return Duration Nudge Result Record { [[Duration]]: resultDuration, [[NudgedEpochNs]]: nudgedEpochNs, [[DidExpandCalendarUnit]]: didExpandDays }.
+    Ok(Nudge {
+        duration: InternalDuration::new(
+            DateDuration {
+                days: days as i64,
+                ..duration.date
+            },
+            remainder,
+        ),
+        epoch_nanoseconds,
+        expanded,
+    })
+}
+
+/// Abstract operation `BubbleRelativeDuration ( sign, duration, nudgedEpochNs, isoDateTime, timeZone, calendar, largestUnit, smallestUnit )`
+///
+/// Carries the units of a rounded duration that reached the length of the next larger unit.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-bubblerelativeduration
+fn bubble_relative_duration(
+    sign: i64,
+    mut duration: InternalDuration,
+    nudged_epoch_nanoseconds: i128,
+    origin: &Origin<'_>,
+    largest_unit: Unit,
+    smallest_unit: Unit,
+    context: &Context,
+) -> JsResult<InternalDuration> {
+    // 1. If smallestUnit is largestUnit, return duration.
+    // 2-6. Repeat, for each unit larger than smallestUnit, from the smallest to largestUnit,
+    for unit in [Unit::Week, Unit::Month, Unit::Year] {
+        if unit <= smallest_unit || unit > largest_unit {
+            continue;
+        }
+
+        // a. Let unit be the value in the "Value" column of Table 21 in the row whose ordinal index is unitIndex.
+        // b. If unit is not week, or largestUnit is week, then
+        if unit == Unit::Week && largest_unit != Unit::Week {
+            continue;
+        }
+
+        // i-iii. Let endDuration be the duration with one more unit.
+        let date = duration.date;
+        let end = match unit {
+            Unit::Year => DateDuration::new(date.years + sign, 0, 0, 0),
+            Unit::Month => DateDuration::new(date.years, date.months + sign, 0, 0),
+            _ => DateDuration::new(date.years, date.months, date.weeks + sign, 0),
+        };
+
+        // iv-vi. Let endEpochNs be the exact time of the end of the duration.
+        let end_epoch_nanoseconds = origin.epoch_nanoseconds_of(origin.add(end)?, context)?;
+
+        // vii. Let beyondEnd be nudgedEpochNs - endEpochNs.
+        // viii. If beyondEnd < 0, let beyondEndSign be -1; else if beyondEnd > 0, let beyondEndSign be 1; else let beyondEndSign be 0.
+        // ix. If beyondEndSign ≠ -sign, then
+        //     1. Set duration to CombineDateAndTimeDuration(endDuration, 0).
+        // x. Else,
+        //     1. Set done to true.
+        let beyond_end = nudged_epoch_nanoseconds - end_epoch_nanoseconds;
+        if beyond_end.signum() as i64 == -sign {
+            break;
+        }
+        duration = InternalDuration::new(end, 0);
+    }
+
+    // 7. Return duration.
+    Ok(duration)
+}
+
+/// Abstract operation `RoundRelativeDuration ( duration, destEpochNs, isoDateTime, timeZone, calendar, largestUnit, increment, smallestUnit, roundingMode )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundrelativeduration
+fn round_relative_duration(
+    duration: InternalDuration,
+    destination: i128,
+    origin: &Origin<'_>,
+    settings: DifferenceSettings,
+    context: &Context,
+) -> JsResult<InternalDuration> {
+    // 1. Let irregularLengthUnit be false.
+    // 2. If IsCalendarUnit(smallestUnit) is true, set irregularLengthUnit to true.
+    // 3. If timeZone is not unset and smallestUnit is day, set irregularLengthUnit to true.
+    let unit = settings.smallest_unit;
+    let irregular_length_unit =
+        unit.is_calendar_unit() || (origin.time_zone.is_some() && unit == Unit::Day);
+
+    // 4. If InternalDurationSign(duration) < 0, let sign be -1; else let sign be 1.
+    let sign = if duration.sign() < 0 { -1 } else { 1 };
+
+    // 5. If irregularLengthUnit is true, then
+    //     a. Let record be ? NudgeToCalendarUnit(sign, duration, destEpochNs, isoDateTime, timeZone, calendar, increment, smallestUnit, roundingMode).
+    //     b. Let nudgeResult be record.[[NudgeResult]].
+    // 6. Else if timeZone is not unset, then
+    //     a. Let nudgeResult be ? NudgeToZonedTime(sign, duration, isoDateTime, timeZone, calendar, increment, smallestUnit, roundingMode).
+    // 7. Else,
+    //     a. Let nudgeResult be ? NudgeToDayOrTime(duration, destEpochNs, largestUnit, increment, smallestUnit, roundingMode).
+    let nudge = if irregular_length_unit {
+        nudge_to_calendar_unit(sign, duration, destination, origin, settings, context)?.0
+    } else if origin.time_zone.is_some() {
+        nudge_to_zoned_time(sign, duration, origin, settings, context)?
+    } else {
+        nudge_to_day_or_time(duration, destination, settings)?
+    };
+
+    // 8. Set duration to nudgeResult.[[Duration]].
+    // 9. If nudgeResult.[[DidExpandCalendarUnit]] is true and smallestUnit is not week, then
+    //     a. Let startUnit be LargerOfTwoTemporalUnits(smallestUnit, day).
+    //     b. Set duration to ? BubbleRelativeDuration(sign, duration, nudgeResult.[[NudgedEpochNs]], isoDateTime, timeZone, calendar, largestUnit, startUnit).
+    if nudge.expanded && unit != Unit::Week {
+        return bubble_relative_duration(
+            sign,
+            nudge.duration,
+            nudge.epoch_nanoseconds,
+            origin,
+            settings.largest_unit,
+            unit.max(Unit::Day),
+            context,
+        );
+    }
+
+    // 10. Return duration.
+    Ok(nudge.duration)
+}
+
+/// Abstract operation `TotalRelativeDuration ( duration, destEpochNs, isoDateTime, timeZone, calendar, unit )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totalrelativeduration
+fn total_relative_duration(
+    duration: InternalDuration,
+    destination: i128,
+    origin: &Origin<'_>,
+    unit: Unit,
+    context: &Context,
+) -> JsResult<f64> {
+    // 1. If IsCalendarUnit(unit) is true, or timeZone is not unset and unit is day, then
+    if unit.is_calendar_unit() || (origin.time_zone.is_some() && unit == Unit::Day) {
+        // a. Let sign be InternalDurationSign(duration).
+        // b. Let record be ? NudgeToCalendarUnit(sign, duration, destEpochNs, isoDateTime, timeZone, calendar, 1, unit, trunc).
+        // c. Return record.[[Total]].
+        let sign = if duration.sign() < 0 { -1 } else { 1 };
+        let settings = DifferenceSettings {
+            largest_unit: unit,
+            smallest_unit: unit,
+            rounding_increment: 1,
+            rounding_mode: RoundingMode::Trunc,
+        };
+        return Ok(
+            nudge_to_calendar_unit(sign, duration, destination, origin, settings, context)?.1,
+        );
+    }
+
+    // 2. Let timeDuration be ! Add24HourDaysToTimeDuration(duration.[[Time]], duration.[[Date]].[[Days]]).
+    // 3. Return TotalTimeDuration(timeDuration, unit).
+    let time = check_time_duration(duration.time + i128::from(duration.date.days) * NS_PER_DAY)?;
+    Ok(total_time_duration(time, unit))
+}
@@ -0,0 +1,1311 @@
+//! This module implements the `Temporal.Duration` object.
+//!
+//! A `Temporal.Duration` is a length of time, with separate fields for the years, months, weeks,
+//! days, hours, minutes, seconds and fractions of a second. Durations with years, months or
+//! weeks can only be rounded or compared relative to a date.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-duration-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration
+
+use super::{
+    difference::{
+        add_zoned_date_time, check_time_duration, difference_plain_date_time_with_rounding,
+        difference_plain_date_time_with_total, difference_zoned_date_time_with_rounding,
+        difference_zoned_date_time_with_total, round_time_duration, total_time_duration,
+        InternalDuration, RelativeTo,
+    },
+    iso::{format_fraction, DateDuration, IsoDateTime, IsoTime},
+    options::{
+        get_options_object, get_round_to_options, get_temporal_unit, to_seconds_string_precision,
+        to_temporal_rounding_increment, to_temporal_rounding_mode,
+        validate_temporal_rounding_increment, DifferenceSettings, Overflow, Precision,
+        RoundingMode, Unit, UnitGroup, UnitOption, NS_PER_DAY, NS_PER_HOUR, NS_PER_MICROSECOND,
+        NS_PER_MILLISECOND, NS_PER_MINUTE, NS_PER_SECOND,
+    },
+    parser::parse_duration,
+    to_integer_if_integral, value_of,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::JsVariant,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use std::fmt::Write;
+
+/// The names of the fields of a duration, from the largest to the smallest unit.
+const FIELDS: [&str; 10] = [
+    "years",
+    "months",
+    "weeks",
+    "days",
+    "hours",
+    "minutes",
+    "seconds",
+    "milliseconds",
+    "microseconds",
+    "nanoseconds",
+];
+
+/// The length in nanoseconds of the units of the fields of a duration, from days to
+/// nanoseconds.
+const TIME_UNITS: [i128; 7] = [
+    NS_PER_DAY,
+    NS_PER_HOUR,
+    NS_PER_MINUTE,
+    NS_PER_SECOND,
+    NS_PER_MILLISECOND,
+    NS_PER_MICROSECOND,
+    1,
+];
+
+/// A duration record, with the fields of a `Temporal.Duration`.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-duration-records
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct DurationRecord {
+    pub(crate) years: f64,
+    pub(crate) months: f64,
+    pub(crate) weeks: f64,
+    pub(crate) days: f64,
+    pub(crate) hours: f64,
+    pub(crate) minutes: f64,
+    pub(crate) seconds: f64,
+    pub(crate) milliseconds: f64,
+    pub(crate) microseconds: f64,
+    pub(crate) nanoseconds: f64,
+}
+
+impl DurationRecord {
+    /// Gets the fields of the duration, from the largest to the smallest unit.
+    const fn to_array(self) -> [f64; 10] {
+        [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.milliseconds,
+            self.microseconds,
+            self.nanoseconds,
+        ]
+    }
+
+    /// Creates a duration from its fields, from the largest to the smallest unit.
+    const fn from_array(fields: [f64; 10]) -> Self {
+        let [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds] =
+            fields;
+        Self {
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        }
+    }
+
+    /// Creates a duration with the years, months, weeks and days of a date duration.
+    pub(crate) fn from_date_duration(duration: DateDuration) -> Self {
+        Self {
+            years: duration.years as f64,
+            months: duration.months as f64,
+            weeks: duration.weeks as f64,
+            days: duration.days as f64,
+            ..Self::default()
+        }
+    }
+
+    /// Abstract operation `DurationSign ( duration )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-durationsign
+    pub(crate) fn sign(self) -> i32 {
+        self.to_array()
+            .into_iter()
+            .find(|&value| value != 0.0)
+            .map_or(0, |value| if value < 0.0 { -1 } else { 1 })
+    }
+
+    /// Abstract operation `IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isvalidduration
+    pub(crate) fn is_valid(self) -> bool {
+        // 1. Let sign be DurationSign(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        let sign = self.sign();
+
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        //     a. If 𝔽(v) is not finite, return false.
+        //     b. If v < 0 and sign > 0, return false.
+        //     c. If v > 0 and sign < 0, return false.
+        let fields = self.to_array();
+        if fields
+            .iter()
+            .any(|&v| !v.is_finite() || (v < 0.0 && sign > 0) || (v > 0.0 && sign < 0))
+        {
+            return false;
+        }
+
+        // 3. If abs(years) ≥ 2^32, return false.
+        // 4. If abs(months) ≥ 2^32, return false.
+        // 5. If abs(weeks) ≥ 2^32, return false.
+        if fields[..3].iter().any(|v| v.abs() >= 4_294_967_296.0) {
+            return false;
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds + ℝ(𝔽(milliseconds)) × 10^-3 + ℝ(𝔽(microseconds)) × 10^-6 + ℝ(𝔽(nanoseconds)) × 10^-9.
+        // 7. If abs(normalizedSeconds) ≥ 2^53, return false.
+        // Each term is bounded first, so that the exact sum cannot overflow.
+        let mut total = 0i128;
+        for (&value, unit) in fields[3..].iter().zip(TIME_UNITS) {
+            if value.abs() * unit as f64 > 1e25 {
+                return false;
+            }
+            total += value as i128 * unit;
+        }
+
+        // 8. Return true.
+        total.abs() <= super::difference::MAX_TIME_DURATION
+    }
+
+    /// Abstract operation `CreateTemporalDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporalduration
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If IsValidDuration(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds) is false, throw a RangeError exception.
+        let record = self.check_valid()?;
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.Duration%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.Duration.prototype%", « [[InitializedTemporalDuration]], [[Years]], [[Months]], [[Weeks]], [[Days]], [[Hours]], [[Minutes]], [[Seconds]], [[Milliseconds]], [[Microseconds]], [[Nanoseconds]] »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::duration, context)?
+            }
+            None => context.intrinsics().constructors().duration().prototype(),
+        };
+
+        // 4-14. Set the fields of object.
+        // 15. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::duration(Duration { record }),
+        ))
+    }
+
+    /// Throws a `RangeError` if the duration is not valid.
+    fn check_valid(self) -> JsResult<Self> {
+        if self.is_valid() {
+            Ok(self)
+        } else {
+            Err(JsNativeError::range()
+                .with_message("the duration is outside of the supported range")
+                .into())
+        }
+    }
+
+    /// Abstract operation `CreateNegatedTemporalDuration ( duration )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createnegatedtemporalduration
+    pub(crate) fn negated(self) -> Self {
+        // Zeroes are kept positive.
+        Self::from_array(self.to_array().map(|value| 0.0 - value))
+    }
+
+    /// Gets the duration with the absolute values of the fields.
+    fn abs(self) -> Self {
+        Self::from_array(self.to_array().map(f64::abs))
+    }
+
+    /// Gets the years, months, weeks and days of the duration.
+    pub(crate) fn date_duration(self) -> DateDuration {
+        DateDuration::new(
+            self.years as i64,
+            self.months as i64,
+            self.weeks as i64,
+            self.days as i64,
+        )
+    }
+
+    /// Abstract operation `TimeDurationFromComponents ( hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`
+    ///
+    /// Gets the hours and the smaller units of the duration as a number of nanoseconds.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-timedurationfromcomponents
+    pub(crate) fn time_duration(self) -> i128 {
+        self.to_array()[4..]
+            .iter()
+            .zip(&TIME_UNITS[1..])
+            .map(|(&value, &unit)| value as i128 * unit)
+            .sum()
+    }
+
+    /// Abstract operation `ToInternalDurationRecord ( duration )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tointernaldurationrecord
+    pub(crate) fn to_internal(self) -> InternalDuration {
+        InternalDuration::new(self.date_duration(), self.time_duration())
+    }
+
+    /// Abstract operation `ToInternalDurationRecordWith24HourDays ( duration )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tointernaldurationrecordwith24hourdays
+    pub(crate) fn to_internal_with_24_hour_days(self) -> InternalDuration {
+        InternalDuration::new(
+            DateDuration::new(self.years as i64, self.months as i64, self.weeks as i64, 0),
+            self.time_duration() + self.days as i128 * NS_PER_DAY,
+        )
+    }
+
+    /// Abstract operation `ToDateDurationRecordWithoutTime ( duration )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-todatedurationrecordwithouttime
+    pub(crate) fn date_duration_without_time(self) -> DateDuration {
+        // 1. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+        // 2. Let days be truncate(internalDuration.[[Time]] / nsPerDay).
+        // 3. Return ! CreateDateDurationRecord(internalDuration.[[Date]].[[Years]], internalDuration.[[Date]].[[Months]], internalDuration.[[Date]].[[Weeks]], days).
+        let internal = self.to_internal_with_24_hour_days();
+        DateDuration {
+            days: (internal.time / NS_PER_DAY) as i64,
+            ..internal.date
+        }
+    }
+
+    /// Abstract operation `DefaultTemporalLargestUnit ( duration )`
+    ///
+    /// Gets the largest unit of the non-zero fields of the duration.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-defaulttemporallargestunit
+    pub(crate) fn default_largest_unit(self) -> Unit {
+        const UNITS: [Unit; 10] = [
+            Unit::Year,
+            Unit::Month,
+            Unit::Week,
+            Unit::Day,
+            Unit::Hour,
+            Unit::Minute,
+            Unit::Second,
+            Unit::Millisecond,
+            Unit::Microsecond,
+            Unit::Nanosecond,
+        ];
+        self.to_array()
+            .into_iter()
+            .zip(UNITS)
+            .find(|&(value, _)| value != 0.0)
+            .map_or(Unit::Nanosecond, |(_, unit)| unit)
+    }
+
+    /// Abstract operation `TemporalDurationFromInternal ( internalDuration, largestUnit )`
+    ///
+    /// Balances the time of an internal duration up to `largest_unit`, throwing a `RangeError`
+    /// if the result is not a valid duration.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationfrominternal
+    pub(crate) fn from_internal(internal: InternalDuration, largest_unit: Unit) -> JsResult<Self> {
+        // 1. Let days, hours, minutes, seconds, milliseconds, and microseconds be 0.
+        // 2. Let sign be TimeDurationSign(internalDuration.[[Time]]).
+        // 3. Let nanoseconds be abs(internalDuration.[[Time]]).
+        let sign = internal.time.signum();
+        let mut remainder = internal.time.abs();
+
+        // 4-10. Balance the nanoseconds up to the largest unit.
+        let mut fields = [0i128; 7];
+        let first = match largest_unit {
+            Unit::Year | Unit::Month | Unit::Week | Unit::Day => 0,
+            Unit::Hour => 1,
+            Unit::Minute => 2,
+            Unit::Second => 3,
+            Unit::Millisecond => 4,
+            Unit::Microsecond => 5,
+            Unit::Nanosecond => 6,
+        };
+        for (field, unit) in fields.iter_mut().zip(TIME_UNITS).skip(first) {
+            *field = remainder / unit * sign;
+            remainder %= unit;
+        }
+
+        // 11. Return ? CreateTemporalDuration(internalDuration.[[Date]].[[Years]], internalDuration.[[Date]].[[Months]], internalDuration.[[Date]].[[Weeks]], internalDuration.[[Date]].[[Days]] + days × sign, hours × sign, minutes × sign, seconds × sign, milliseconds × sign, microseconds × sign, nanoseconds × sign).
+        let date = internal.date;
+        let [days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds] =
+            fields.map(|value| value as f64 + 0.0);
+        Self {
+            years: date.years as f64,
+            months: date.months as f64,
+            weeks: date.weeks as f64,
+            days: date.days as f64 + days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        }
+        .check_valid()
+    }
+
+    /// Abstract operation `TemporalDurationToString ( duration, precision )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationtostring
+    fn to_string_with_precision(self, precision: Precision) -> String {
+        // 1. Let sign be DurationSign(duration).
+        let sign = self.sign();
+        let duration = self.abs();
+
+        // 2-6. Let datePart be the years, months, weeks and days that are not zero.
+        let mut date_part = String::new();
+        for (value, designator) in [
+            (duration.years, 'Y'),
+            (duration.months, 'M'),
+            (duration.weeks, 'W'),
+            (duration.days, 'D'),
+        ] {
+            if value != 0.0 {
+                let _ = write!(date_part, "{value}{designator}");
+            }
+        }
+
+        // 7-9. Let timePart be the hours and minutes that are not zero.
+        let mut time_part = String::new();
+        for (value, designator) in [(duration.hours, 'H'), (duration.minutes, 'M')] {
+            if value != 0.0 {
+                let _ = write!(time_part, "{value}{designator}");
+            }
+        }
+
+        // 10. Let zeroMinutesAndHigherUnits be false.
+        // 11. If years = 0, months = 0, weeks = 0, days = 0, hours = 0, and minutes = 0, set zeroMinutesAndHigherUnits to true.
+        let zero_minutes_and_higher_units = date_part.is_empty() && time_part.is_empty();
+
+        // 12. Let secondsDuration be TimeDurationFromComponents(0, 0, duration.[[Seconds]], duration.[[Milliseconds]], duration.[[Microseconds]], duration.[[Nanoseconds]]).
+        let seconds_duration = duration.seconds as i128 * NS_PER_SECOND
+            + duration.milliseconds as i128 * NS_PER_MILLISECOND
+            + duration.microseconds as i128 * NS_PER_MICROSECOND
+            + duration.nanoseconds as i128;
+
+        // 13. If TimeDurationSign(secondsDuration) ≠ 0, or zeroMinutesAndHigherUnits is true, or precision is not auto, then
+        if seconds_duration != 0 || zero_minutes_and_higher_units || precision != Precision::Auto {
+            // a. Let secondsPart be abs(truncate(secondsDuration / 10^9)) formatted as a decimal number.
+            // b. Let subSecondsPart be FormatFractionalSeconds(abs(remainder(secondsDuration, 10^9)), precision).
+            // c. Set timePart to the string concatenation of timePart, secondsPart, subSecondsPart, and the code unit 0x0053 (LATIN CAPITAL LETTER S).
+            let _ = write!(
+                time_part,
+                "{}{}S",
+                seconds_duration / NS_PER_SECOND,
+                format_fraction((seconds_duration % NS_PER_SECOND) as u32, precision)
+            );
+        }
+
+        // 14. Let signPart be the code unit 0x002D (HYPHEN-MINUS) if sign < 0, and otherwise the empty String.
+        // 15. Let result be the string concatenation of signPart, the code unit 0x0050 (LATIN CAPITAL LETTER P) and datePart.
+        // 16. If timePart is not the empty String, then
+        //     a. Set result to the string concatenation of result, the code unit 0x0054 (LATIN CAPITAL LETTER T), and timePart.
+        let mut result = String::from(if sign < 0 { "-P" } else { "P" });
+        result.push_str(&date_part);
+        if !time_part.is_empty() {
+            result.push('T');
+            result.push_str(&time_part);
+        }
+
+        // 17. Return result.
+        result
+    }
+}
+
+/// The internal representation of a `Temporal.Duration` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Duration {
+    pub(crate) record: DurationRecord,
+}
+
+impl Duration {
+    const NAME: &'static str = "Duration";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let attribute = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+        let get_sign = FunctionBuilder::native(context, Self::get_sign)
+            .name("get sign")
+            .build();
+        let get_blank = FunctionBuilder::native(context, Self::get_blank)
+            .name("get blank")
+            .build();
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().duration().clone(),
+        );
+        builder
+            .name(Self::NAME)
+            .length(0)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.Duration",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor("sign", Some(get_sign), None, attribute)
+            .accessor("blank", Some(get_blank), None, attribute)
+            .method(Self::with, "with", 1)
+            .method(Self::negated, "negated", 0)
+            .method(Self::abs, "abs", 0)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::total, "total", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0);
+
+        for (index, name) in FIELDS.into_iter().enumerate() {
+            let getter = FunctionBuilder::closure_with_captures(
+                builder.context(),
+                |this, _, index: &mut usize, _| Ok(this_duration(this)?.to_array()[*index].into()),
+                index,
+            )
+            .name(format!("get {name}"))
+            .build();
+            builder.accessor(name, Some(getter), None, attribute);
+        }
+
+        builder.build()
+    }
+
+    /// `Temporal.Duration ( [ years [ , months [ , weeks [ , days [ , hours [ , minutes [ , seconds [ , milliseconds [ , microseconds [ , nanoseconds ] ] ] ] ] ] ] ] ] ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        //     a. Throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.Duration must be called with new")
+                .into());
+        }
+
+        // 2-11. If the field is undefined, let it be 0, otherwise let it be ? ToIntegerIfIntegral(field).
+        let mut fields = [0.0; 10];
+        for (index, field) in fields.iter_mut().enumerate() {
+            let value = args.get_or_undefined(index);
+            if !value.is_undefined() {
+                *field = to_integer_if_integral(value, context)?;
+            }
+        }
+
+        // 12. Return ? CreateTemporalDuration(y, mo, w, d, h, m, s, ms, mis, ns, NewTarget).
+        DurationRecord::from_array(fields)
+            .create(Some(new_target), context)
+            .map(Into::into)
+    }
+
+    /// Abstract operation `ToTemporalDuration ( item )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalduration
+    pub(crate) fn to_temporal_duration(
+        item: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<DurationRecord> {
+        let object = match item.variant() {
+            // 1. If item is an Object and item has an [[InitializedTemporalDuration]] internal slot, then
+            //     a. Return ! CreateTemporalDuration(item.[[Years]], ...).
+            JsVariant::Object(object) => {
+                if let Some(duration) = object.borrow().as_duration() {
+                    return Ok(duration.record);
+                }
+                object
+            }
+            // 2. If item is not an Object, then
+            //     a. If item is not a String, throw a TypeError exception.
+            //     b. Return ? ParseTemporalDurationString(item).
+            JsVariant::String(string) => {
+                let parsed = parse_duration(&string.to_std_string_escaped())?;
+                return DurationRecord {
+                    years: parsed.years,
+                    months: parsed.months,
+                    weeks: parsed.weeks,
+                    days: parsed.days,
+                    hours: parsed.hours,
+                    minutes: parsed.minutes,
+                    seconds: parsed.seconds,
+                    milliseconds: parsed.milliseconds,
+                    microseconds: parsed.microseconds,
+                    nanoseconds: parsed.nanoseconds,
+                }
+                .check_valid();
+            }
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("a duration must be a Temporal.Duration, an object or a string")
+                    .into())
+            }
+        };
+
+        // 3. Let result be a new Duration Record with each field set to 0.
+        // 4. Let partial be ? ToTemporalPartialDurationRecord(item).
+        // 5. For each field of partial that is not undefined, set the field of result.
+        let partial = to_temporal_partial_duration_record(object, context)?;
+        let mut fields = [0.0; 10];
+        for (field, value) in fields.iter_mut().zip(partial) {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+
+        // 6. Return ? CreateDurationRecord(result.[[Years]], ...).
+        DurationRecord::from_array(fields).check_valid()
+    }
+
+    /// `Temporal.Duration.from ( item )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalDuration(item).
+        Self::to_temporal_duration(args.get_or_undefined(0), context)?
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Duration.compare ( one, two [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDuration(one).
+        // 2. Set two to ? ToTemporalDuration(two).
+        let one = Self::to_temporal_duration(args.get_or_undefined(0), context)?;
+        let two = Self::to_temporal_duration(args.get_or_undefined(1), context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let relativeToRecord be ? GetTemporalRelativeToOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(2))?;
+        let relative_to = RelativeTo::from_options(options.as_ref(), context)?;
+
+        // 5. If one.[[Years]] = two.[[Years]], and ..., and one.[[Nanoseconds]] = two.[[Nanoseconds]], then
+        //     a. Return +0𝔽.
+        if one == two {
+            return Ok(0.into());
+        }
+
+        // 8. Let largestUnit1 be DefaultTemporalLargestUnit(one).
+        // 9. Let largestUnit2 be DefaultTemporalLargestUnit(two).
+        // 10. Let duration1 be ToInternalDurationRecord(one).
+        // 11. Let duration2 be ToInternalDurationRecord(two).
+        let largest_unit_one = one.default_largest_unit();
+        let largest_unit_two = two.default_largest_unit();
+        let duration_one = one.to_internal();
+        let duration_two = two.to_internal();
+
+        // 12. If zonedRelativeTo is not undefined, and either TemporalUnitCategory(largestUnit1) or TemporalUnitCategory(largestUnit2) is date, then
+        if let RelativeTo::Zoned {
+            epoch_nanoseconds,
+            time_zone,
+        } = &relative_to
+        {
+            if largest_unit_one.is_date_unit() || largest_unit_two.is_date_unit() {
+                // a-c. Let after1 be ? AddZonedDateTime(zonedRelativeTo.[[EpochNanoseconds]], timeZone, calendar, duration1, constrain).
+                // d. Let after2 be ? AddZonedDateTime(zonedRelativeTo.[[EpochNanoseconds]], timeZone, calendar, duration2, constrain).
+                let after_one = add_zoned_date_time(
+                    *epoch_nanoseconds,
+                    time_zone,
+                    duration_one,
+                    Overflow::Constrain,
+                    context,
+                )?;
+                let after_two = add_zoned_date_time(
+                    *epoch_nanoseconds,
+                    time_zone,
+                    duration_two,
+                    Overflow::Constrain,
+                    context,
+                )?;
+
+                // e. If after1 > after2, return 1𝔽.
+                // f. If after1 < after2, return -1𝔽.
+                // g. Return +0𝔽.
+                return Ok((after_one.cmp(&after_two) as i32).into());
+            }
+        }
+
+        // 13. If IsCalendarUnit(largestUnit1) is true or IsCalendarUnit(largestUnit2) is true, then
+        let (days_one, days_two) =
+            if largest_unit_one.is_calendar_unit() || largest_unit_two.is_calendar_unit() {
+                // a. If plainRelativeTo is undefined, throw a RangeError exception.
+                // b. Let days1 be ? DateDurationDays(duration1.[[Date]], plainRelativeTo).
+                // c. Let days2 be ? DateDurationDays(duration2.[[Date]], plainRelativeTo).
+                let date =
+                    match relative_to {
+                        RelativeTo::Plain(date) => date,
+                        _ => return Err(JsNativeError::range()
+                            .with_message(
+                                "durations with years, months or weeks require a relativeTo date",
+                            )
+                            .into()),
+                    };
+                let days = |duration: DateDuration| -> JsResult<i64> {
+                    // DateDurationDays ( dateDuration, plainRelativeTo )
+                    let later = super::difference::calendar_date_add(
+                        date,
+                        DateDuration {
+                            days: 0,
+                            ..duration
+                        },
+                        Overflow::Constrain,
+                    )?;
+                    Ok(duration.days + later.epoch_days() - date.epoch_days())
+                };
+                (days(duration_one.date)?, days(duration_two.date)?)
+            } else {
+                // 14. Else,
+                //     a. Let days1 be one.[[Days]].
+                //     b. Let days2 be two.[[Days]].
+                (duration_one.date.days, duration_two.date.days)
+            };
+
+        // 15. Let timeDuration1 be ? Add24HourDaysToTimeDuration(duration1.[[Time]], days1).
+        // 16. Let timeDuration2 be ? Add24HourDaysToTimeDuration(duration2.[[Time]], days2).
+        let time_one = check_time_duration(duration_one.time + i128::from(days_one) * NS_PER_DAY)?;
+        let time_two = check_time_duration(duration_two.time + i128::from(days_two) * NS_PER_DAY)?;
+
+        // 17. Return 𝔽(CompareTimeDuration(timeDuration1, timeDuration2)).
+        Ok((time_one.cmp(&time_two) as i32).into())
+    }
+
+    /// `get Temporal.Duration.prototype.sign`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.sign
+    fn get_sign(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(this_duration(this)?.sign().into())
+    }
+
+    /// `get Temporal.Duration.prototype.blank`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.blank
+    fn get_blank(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok((this_duration(this)?.sign() == 0).into())
+    }
+
+    /// `Temporal.Duration.prototype.with ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = this_duration(this)?;
+
+        // 3. Let temporalDurationLike be ? ToTemporalPartialDurationRecord(temporalDurationLike).
+        let like = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("the argument of with must be an object")
+        })?;
+        let partial = to_temporal_partial_duration_record(like, context)?;
+
+        // 4-23. If a field of temporalDurationLike is not undefined, use it, otherwise use the field of duration.
+        let mut fields = duration.to_array();
+        for (field, value) in fields.iter_mut().zip(partial) {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+
+        // 24. Return ? CreateTemporalDuration(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        DurationRecord::from_array(fields)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Duration.prototype.negated ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.negated
+    fn negated(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return ! CreateNegatedTemporalDuration(duration).
+        this_duration(this)?
+            .negated()
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Duration.prototype.abs ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.abs
+    fn abs(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return ! CreateTemporalDuration(abs(duration.[[Years]]), ..., abs(duration.[[Nanoseconds]])).
+        this_duration(this)?
+            .abs()
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Duration.prototype.add ( other )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return ? AddDurations(add, duration, other).
+        Self::add_durations(false, this, args, context)
+    }
+
+    /// `Temporal.Duration.prototype.subtract ( other )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return ? AddDurations(subtract, duration, other).
+        Self::add_durations(true, this, args, context)
+    }
+
+    /// Abstract operation `AddDurations ( operation, duration, other )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurations
+    fn add_durations(
+        subtract: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let duration = this_duration(this)?;
+
+        // 1. Set other to ? ToTemporalDuration(other).
+        // 2. If operation is subtract, set other to CreateNegatedTemporalDuration(other).
+        let mut other = Self::to_temporal_duration(args.get_or_undefined(0), context)?;
+        if subtract {
+            other = other.negated();
+        }
+
+        // 3. Let largestUnit1 be DefaultTemporalLargestUnit(duration).
+        // 4. Let largestUnit2 be DefaultTemporalLargestUnit(other).
+        // 5. Let largestUnit be LargerOfTwoTemporalUnits(largestUnit1, largestUnit2).
+        let largest_unit = duration
+            .default_largest_unit()
+            .max(other.default_largest_unit());
+
+        // 6. If IsCalendarUnit(largestUnit) is true, throw a RangeError exception.
+        if largest_unit.is_calendar_unit() {
+            return Err(JsNativeError::range()
+                .with_message("durations with years, months or weeks cannot be added")
+                .into());
+        }
+
+        // 7. Let d1 be ToInternalDurationRecordWith24HourDays(duration).
+        // 8. Let d2 be ToInternalDurationRecordWith24HourDays(other).
+        // 9. Let timeResult be ? AddTimeDuration(d1.[[Time]], d2.[[Time]]).
+        let time = check_time_duration(
+            duration.to_internal_with_24_hour_days().time
+                + other.to_internal_with_24_hour_days().time,
+        )?;
+
+        // 10. Let result be CombineDateAndTimeDuration(ZeroDateDuration(), timeResult).
+        // 11. Return ? TemporalDurationFromInternal(result, largestUnit).
+        DurationRecord::from_internal(
+            InternalDuration::new(DateDuration::default(), time),
+            largest_unit,
+        )?
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.Duration.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = this_duration(this)?;
+
+        // 3-5. Let roundTo be the options object of roundTo.
+        let round_to = get_round_to_options(args.get_or_undefined(0), context)?;
+        let options = Some(&round_to);
+
+        // 6. Let smallestUnitPresent be true.
+        // 7. Let largestUnitPresent be true.
+        // 8. Let largestUnit be ? GetTemporalUnitValuedOption(roundTo, "largestUnit", datetime, unset, « auto »).
+        let largest_unit =
+            get_temporal_unit(options, "largestUnit", UnitGroup::DateTime, true, context)?;
+
+        // 9. Let relativeToRecord be ? GetTemporalRelativeToOption(roundTo).
+        let relative_to = RelativeTo::from_options(options, context)?;
+
+        // 12. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let rounding_increment = to_temporal_rounding_increment(options, context)?;
+
+        // 13. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode = to_temporal_rounding_mode(options, RoundingMode::HalfExpand, context)?;
+
+        // 14. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", datetime, unset).
+        let smallest_unit =
+            get_temporal_unit(options, "smallestUnit", UnitGroup::DateTime, false, context)?
+                .and_then(UnitOption::unit);
+
+        // 15. If smallestUnit is unset, then
+        //     a. Set smallestUnitPresent to false.
+        //     b. Set smallestUnit to nanosecond.
+        let smallest_unit_present = smallest_unit.is_some();
+        let smallest_unit = smallest_unit.unwrap_or(Unit::Nanosecond);
+
+        // 16. Let existingLargestUnit be DefaultTemporalLargestUnit(duration).
+        // 17. Let defaultLargestUnit be LargerOfTwoTemporalUnits(existingLargestUnit, smallestUnit).
+        let existing_largest_unit = duration.default_largest_unit();
+        let default_largest_unit = existing_largest_unit.max(smallest_unit);
+
+        // 18. If largestUnit is unset, then
+        //     a. Set largestUnitPresent to false.
+        //     b. Set largestUnit to defaultLargestUnit.
+        // 19. Else if largestUnit is auto, then
+        //     a. Set largestUnit to defaultLargestUnit.
+        let largest_unit_present = largest_unit.is_some();
+        let largest_unit = largest_unit
+            .and_then(UnitOption::unit)
+            .unwrap_or(default_largest_unit);
+
+        // 20. If smallestUnitPresent is false and largestUnitPresent is false, then
+        //     a. Throw a RangeError exception.
+        if !smallest_unit_present && !largest_unit_present {
+            return Err(JsNativeError::range()
+                .with_message("either smallestUnit or largestUnit is required")
+                .into());
+        }
+
+        // 21. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+        if largest_unit < smallest_unit {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "largestUnit '{largest_unit}' is smaller than smallestUnit '{smallest_unit}'"
+                ))
+                .into());
+        }
+
+        // 22. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        // 23. If maximum is not unset, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+        if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+            validate_temporal_rounding_increment(rounding_increment, maximum, false)?;
+        }
+
+        // 24. If roundingIncrement > 1, and largestUnit is not smallestUnit, and TemporalUnitCategory(smallestUnit) is date, throw a RangeError exception.
+        if rounding_increment > 1 && largest_unit != smallest_unit && smallest_unit.is_date_unit() {
+            return Err(JsNativeError::range()
+                .with_message("roundingIncrement must be 1 when rounding to several date units")
+                .into());
+        }
+
+        let settings = DifferenceSettings {
+            largest_unit,
+            smallest_unit,
+            rounding_increment,
+            rounding_mode,
+        };
+
+        let result = match relative_to {
+            // 25. If zonedRelativeTo is not undefined, then
+            RelativeTo::Zoned {
+                epoch_nanoseconds,
+                time_zone,
+            } => {
+                // a. Let internalDuration be ToInternalDurationRecord(duration).
+                // b-e. Let targetEpochNs be ? AddZonedDateTime(relativeEpochNs, timeZone, calendar, internalDuration, constrain).
+                let target = add_zoned_date_time(
+                    epoch_nanoseconds,
+                    &time_zone,
+                    duration.to_internal(),
+                    Overflow::Constrain,
+                    context,
+                )?;
+
+                // f. Set internalDuration to ? DifferenceZonedDateTimeWithRounding(relativeEpochNs, targetEpochNs, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+                let internal = difference_zoned_date_time_with_rounding(
+                    epoch_nanoseconds,
+                    target,
+                    &time_zone,
+                    settings,
+                    context,
+                )?;
+
+                // g. If TemporalUnitCategory(largestUnit) is date, set largestUnit to hour.
+                // h. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                DurationRecord::from_internal(internal, largest_unit.min(Unit::Hour))?
+            }
+            // 26. If plainRelativeTo is not undefined, then
+            RelativeTo::Plain(date) => {
+                // a. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+                // b. Let targetTime be AddTime(MidnightTimeRecord(), internalDuration.[[Time]]).
+                // c-e. Let targetDate be ? CalendarDateAdd(calendar, plainRelativeTo.[[ISODate]], dateDuration, constrain).
+                // f. Let isoDateTime be CombineISODateAndTimeRecord(plainRelativeTo.[[ISODate]], MidnightTimeRecord()).
+                // g. Let targetDateTime be CombineISODateAndTimeRecord(targetDate, targetTime).
+                let internal = duration.to_internal_with_24_hour_days();
+                let origin = IsoDateTime::new(date, IsoTime::default());
+                let target = origin.add(internal.date, internal.time, Overflow::Constrain)?;
+
+                // h. Set internalDuration to ? DifferencePlainDateTimeWithRounding(isoDateTime, targetDateTime, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+                // i. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                let internal =
+                    difference_plain_date_time_with_rounding(origin, target, settings, context)?;
+                DurationRecord::from_internal(internal, largest_unit)?
+            }
+            RelativeTo::None => {
+                // 27. If IsCalendarUnit(existingLargestUnit) is true, or IsCalendarUnit(largestUnit) is true, throw a RangeError exception.
+                if existing_largest_unit.is_calendar_unit() || largest_unit.is_calendar_unit() {
+                    return Err(JsNativeError::range()
+                        .with_message(
+                            "durations with years, months or weeks require a relativeTo date",
+                        )
+                        .into());
+                }
+
+                // 28. Assert: IsCalendarUnit(smallestUnit) is false.
+                // 29. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+                let internal = duration.to_internal_with_24_hour_days();
+
+                // 30. If smallestUnit is day, then
+                //     a. Let fractionalDays be TotalTimeDuration(internalDuration.[[Time]], day).
+                //     b. Let days be RoundNumberToIncrement(fractionalDays, roundingIncrement, roundingMode).
+                //     c. Let dateDuration be ? CreateDateDurationRecord(0, 0, 0, days).
+                //     d. Set internalDuration to CombineDateAndTimeDuration(dateDuration, 0).
+                // 31. Else,
+                //     a. Let timeDuration be ? RoundTimeDuration(internalDuration.[[Time]], roundingIncrement, smallestUnit, roundingMode).
+                //     b. Set internalDuration to CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+                let time = round_time_duration(
+                    internal.time,
+                    rounding_increment,
+                    smallest_unit,
+                    rounding_mode,
+                )?;
+                let internal = if smallest_unit == Unit::Day {
+                    InternalDuration::new(DateDuration::new(0, 0, 0, (time / NS_PER_DAY) as i64), 0)
+                } else {
+                    InternalDuration::new(DateDuration::default(), time)
+                };
+
+                // 32. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                DurationRecord::from_internal(internal, largest_unit)?
+            }
+        };
+
+        result.create(None, context).map(Into::into)
+    }
+
+    /// `Temporal.Duration.prototype.total ( totalOf )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.total
+    fn total(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = this_duration(this)?;
+
+        // 3. If totalOf is undefined, throw a TypeError exception.
+        // 4. If totalOf is a String, then
+        //     a. Let paramString be totalOf.
+        //     b. Set totalOf to OrdinaryObjectCreate(null).
+        //     c. Perform ! CreateDataPropertyOrThrow(totalOf, "unit", paramString).
+        // 5. Else,
+        //     a. Set totalOf to ? GetOptionsObject(totalOf).
+        let total_of = args.get_or_undefined(0);
+        let total_of = match total_of.variant() {
+            JsVariant::Undefined => {
+                return Err(JsNativeError::typ()
+                    .with_message("total requires a unit or an options object")
+                    .into())
+            }
+            JsVariant::String(unit) => {
+                let total_of = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+                total_of
+                    .create_data_property_or_throw("unit", unit.clone(), context)
+                    .expect("defining a property of a new object cannot fail");
+                total_of
+            }
+            _ => get_options_object(total_of)?.expect("totalOf is not undefined"),
+        };
+        let options = Some(&total_of);
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order (GetTemporalRelativeToOption reads "relativeTo").
+        // 7. Let relativeToRecord be ? GetTemporalRelativeToOption(totalOf).
+        let relative_to = RelativeTo::from_options(options, context)?;
+
+        // 10. Let unit be ? GetTemporalUnitValuedOption(totalOf, "unit", datetime, required).
+        let unit = get_temporal_unit(options, "unit", UnitGroup::DateTime, false, context)?
+            .and_then(UnitOption::unit)
+            .ok_or_else(|| JsNativeError::range().with_message("unit is required"))?;
+
+        let total = match relative_to {
+            // 11. If zonedRelativeTo is not undefined, then
+            RelativeTo::Zoned {
+                epoch_nanoseconds,
+                time_zone,
+            } => {
+                // a. Let internalDuration be ToInternalDurationRecord(duration).
+                // b-d. Let targetEpochNs be ? AddZonedDateTime(relativeEpochNs, timeZone, calendar, internalDuration, constrain).
+                let target = add_zoned_date_time(
+                    epoch_nanoseconds,
+                    &time_zone,
+                    duration.to_internal(),
+                    Overflow::Constrain,
+                    context,
+                )?;
+
+                // e. Let total be ? DifferenceZonedDateTimeWithTotal(relativeEpochNs, targetEpochNs, timeZone, calendar, unit).
+                difference_zoned_date_time_with_total(
+                    epoch_nanoseconds,
+                    target,
+                    &time_zone,
+                    unit,
+                    context,
+                )?
+            }
+            // 12. Else if plainRelativeTo is not undefined, then
+            RelativeTo::Plain(date) => {
+                // a. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+                // b-g. Let targetDateTime be the date and time of the end of the duration.
+                let internal = duration.to_internal_with_24_hour_days();
+                let origin = IsoDateTime::new(date, IsoTime::default());
+                let target = origin.add(internal.date, internal.time, Overflow::Constrain)?;
+
+                // h. Let total be ? DifferencePlainDateTimeWithTotal(isoDateTime, targetDateTime, calendar, unit).
+                difference_plain_date_time_with_total(origin, target, unit, context)?
+            }
+            // 13. Else,
+            RelativeTo::None => {
+                // a. Let largestUnit be DefaultTemporalLargestUnit(duration).
+                // b. If IsCalendarUnit(largestUnit) is true, or IsCalendarUnit(unit) is true, throw a RangeError exception.
+                if duration.default_largest_unit().is_calendar_unit() || unit.is_calendar_unit() {
+                    return Err(JsNativeError::range()
+                        .with_message(
+                            "durations with years, months or weeks require a relativeTo date",
+                        )
+                        .into());
+                }
+
+                // c. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+                // d. Let total be TotalTimeDuration(internalDuration.[[Time]], unit).
+                total_time_duration(duration.to_internal_with_24_hour_days().time, unit)
+            }
+        };
+
+        // 14. Return 𝔽(total).
+        Ok(total.into())
+    }
+
+    /// `Temporal.Duration.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tostring
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = this_duration(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4-8. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        let options = get_options_object(args.get_or_undefined(0))?;
+        let (rounding, rounding_mode) = to_seconds_string_precision(options.as_ref(), context)?;
+
+        // 7. If smallestUnit is hour or minute, throw a RangeError exception.
+        if rounding.precision == Precision::Minute {
+            return Err(JsNativeError::range()
+                .with_message("smallestUnit must not be 'minute'")
+                .into());
+        }
+
+        // 9. If precision.[[Unit]] is nanosecond and precision.[[Increment]] = 1, then
+        //     a. Return TemporalDurationToString(duration, precision.[[Precision]]).
+        if rounding.unit == Unit::Nanosecond && rounding.increment == 1 {
+            return Ok(duration.to_string_with_precision(rounding.precision).into());
+        }
+
+        // 10. Let largestUnit be DefaultTemporalLargestUnit(duration).
+        // 11. Let internalDuration be ToInternalDurationRecord(duration).
+        // 12. Let timeDuration be ? RoundTimeDuration(internalDuration.[[Time]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        // 13. Set internalDuration to CombineDateAndTimeDuration(internalDuration.[[Date]], timeDuration).
+        let largest_unit = duration.default_largest_unit();
+        let internal = duration.to_internal();
+        let time = round_time_duration(
+            internal.time,
+            rounding.increment,
+            rounding.unit,
+            rounding_mode,
+        )?;
+
+        // 14. Let roundedLargestUnit be LargerOfTwoTemporalUnits(largestUnit, second).
+        // 15. Let roundedDuration be ? TemporalDurationFromInternal(internalDuration, roundedLargestUnit).
+        let rounded = DurationRecord::from_internal(
+            InternalDuration::new(internal.date, time),
+            largest_unit.max(Unit::Second),
+        )?;
+
+        // 16. Return TemporalDurationToString(roundedDuration, precision.[[Precision]]).
+        Ok(rounded.to_string_with_precision(rounding.precision).into())
+    }
+
+    /// `Temporal.Duration.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tojson
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return TemporalDurationToString(duration, auto).
+        Ok(this_duration(this)?
+            .to_string_with_precision(Precision::Auto)
+            .into())
+    }
+}
+
+/// Abstract operation `ToTemporalPartialDurationRecord ( temporalDurationLike )`
+///
+/// Reads the fields of a duration-like object in alphabetical order, and returns them from the
+/// largest to the smallest unit. At least one of the fields must be present.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalpartialdurationrecord
+fn to_temporal_partial_duration_record(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<[Option<f64>; 10]> {
+    /// The indices of the fields of a duration in alphabetical order.
+    const ALPHABETICAL: [usize; 10] = [3, 4, 8, 7, 5, 1, 9, 6, 2, 0];
+
+    let mut result = [None; 10];
+    for index in ALPHABETICAL {
+        let value = object.get(FIELDS[index], context)?;
+        if !value.is_undefined() {
+            result[index] = Some(to_integer_if_integral(&value, context)?);
+        }
+    }
+
+    // If years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, and nanoseconds are all undefined, throw a TypeError exception.
+    if result.iter().all(Option::is_none) {
+        return Err(JsNativeError::typ()
+            .with_message("the object must have at least one of the duration properties")
+            .into());
+    }
+
+    Ok(result)
+}
+
+/// Gets the `this` value of a `Temporal.Duration` method, which must be a duration.
+fn this_duration(this: &JsValue) -> JsResult<DurationRecord> {
+    this.as_object()
+        .and_then(|object| {
+            object
+                .borrow()
+                .as_duration()
+                .map(|duration| duration.record)
+        })
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Temporal.Duration")
+                .into()
+        })
+}
@@ -0,0 +1,411 @@
+//! The property bags that are passed to the `from` and `with` methods of the `Temporal` objects,
+//! like `{ year: 2020, month: 2, day: 29 }`.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-preparetemporalfields
+
+use super::{
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    options::Overflow,
+    to_integer_with_truncation, to_positive_integer_with_truncation,
+};
+use crate::{error::JsNativeError, object::JsObject, Context, JsResult, JsValue};
+
+/// The name of a property of a property bag.
+///
+/// The variants are sorted alphabetically by name, which is the order in which the properties
+/// are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Field {
+    Day,
+    Hour,
+    Microsecond,
+    Millisecond,
+    Minute,
+    Month,
+    MonthCode,
+    Nanosecond,
+    Offset,
+    Second,
+    TimeZone,
+    Year,
+}
+
+impl Field {
+    /// The fields of a date of the ISO 8601 calendar.
+    pub(crate) const DATE: &'static [Self] = &[Self::Day, Self::Month, Self::MonthCode, Self::Year];
+
+    /// The fields of a wall-clock time.
+    pub(crate) const TIME: &'static [Self] = &[
+        Self::Hour,
+        Self::Microsecond,
+        Self::Millisecond,
+        Self::Minute,
+        Self::Nanosecond,
+        Self::Second,
+    ];
+
+    /// The fields of a date and a wall-clock time.
+    pub(crate) const DATE_TIME: &'static [Self] = &[
+        Self::Day,
+        Self::Hour,
+        Self::Microsecond,
+        Self::Millisecond,
+        Self::Minute,
+        Self::Month,
+        Self::MonthCode,
+        Self::Nanosecond,
+        Self::Second,
+        Self::Year,
+    ];
+
+    /// The fields of a date and a wall-clock time in a time zone.
+    pub(crate) const ZONED_DATE_TIME: &'static [Self] = &[
+        Self::Day,
+        Self::Hour,
+        Self::Microsecond,
+        Self::Millisecond,
+        Self::Minute,
+        Self::Month,
+        Self::MonthCode,
+        Self::Nanosecond,
+        Self::Offset,
+        Self::Second,
+        Self::TimeZone,
+        Self::Year,
+    ];
+
+    /// Gets the name of the property.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Microsecond => "microsecond",
+            Self::Millisecond => "millisecond",
+            Self::Minute => "minute",
+            Self::Month => "month",
+            Self::MonthCode => "monthCode",
+            Self::Nanosecond => "nanosecond",
+            Self::Offset => "offset",
+            Self::Second => "second",
+            Self::TimeZone => "timeZone",
+            Self::Year => "year",
+        }
+    }
+}
+
+/// The values of a property bag, converted to their types.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TemporalFields {
+    pub(crate) year: Option<f64>,
+    pub(crate) month: Option<f64>,
+    pub(crate) month_code: Option<String>,
+    pub(crate) day: Option<f64>,
+    pub(crate) hour: Option<f64>,
+    pub(crate) minute: Option<f64>,
+    pub(crate) second: Option<f64>,
+    pub(crate) millisecond: Option<f64>,
+    pub(crate) microsecond: Option<f64>,
+    pub(crate) nanosecond: Option<f64>,
+    pub(crate) offset: Option<String>,
+    pub(crate) time_zone: Option<JsValue>,
+}
+
+impl TemporalFields {
+    /// Abstract operation `PrepareTemporalFields ( fields, fieldNames, requiredFields )`
+    ///
+    /// Reads the properties `names` of `object`, which must be sorted. If `required` is `None`,
+    /// the fields are partial, and at least one of them must be present. Otherwise, the fields
+    /// of `required` must be present.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-preparetemporalfields
+    pub(crate) fn prepare(
+        object: &JsObject,
+        names: &[Field],
+        required: Option<&[Field]>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let result be OrdinaryObjectCreate(null).
+        let mut result = Self::default();
+
+        // 2. Let any be false.
+        let mut any = false;
+
+        // 3. For each value property of fieldNames, do
+        for &field in names {
+            // a. Let value be ? Get(fields, property).
+            let value = object.get(field.name(), context)?;
+
+            // b. If value is undefined, then
+            if value.is_undefined() {
+                // i. If requiredFields is partial, continue.
+                // ii. If requiredFields contains property, throw a TypeError exception.
+                if required.map_or(false, |required| required.contains(&field)) {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!("required property {} is missing", field.name()))
+                        .into());
+                }
+                continue;
+            }
+
+            // c. Else,
+            //     i. Set any to true.
+            //     ii. If property is in the Property column of Table 15 and there is a Conversion value in the same row, then
+            //         1. Let Conversion be the Conversion value of the same row.
+            //         2. If Conversion is ToIntegerWithTruncation, set value to ? ToIntegerWithTruncation(value).
+            //         3. Else if Conversion is ToPositiveIntegerWithTruncation, set value to ? ToPositiveIntegerWithTruncation(value).
+            //         4. Else, set value to ? ToString(value).
+            any = true;
+            match field {
+                Field::Day => {
+                    result.day = Some(to_positive_integer_with_truncation(&value, context)?);
+                }
+                Field::Month => {
+                    result.month = Some(to_positive_integer_with_truncation(&value, context)?);
+                }
+                Field::MonthCode => {
+                    result.month_code = Some(value.to_string(context)?.to_std_string_escaped());
+                }
+                Field::Offset => {
+                    result.offset = Some(value.to_string(context)?.to_std_string_escaped());
+                }
+                Field::TimeZone => result.time_zone = Some(value),
+                Field::Year => result.year = Some(to_integer_with_truncation(&value, context)?),
+                Field::Hour => result.hour = Some(to_integer_with_truncation(&value, context)?),
+                Field::Minute => result.minute = Some(to_integer_with_truncation(&value, context)?),
+                Field::Second => result.second = Some(to_integer_with_truncation(&value, context)?),
+                Field::Millisecond => {
+                    result.millisecond = Some(to_integer_with_truncation(&value, context)?);
+                }
+                Field::Microsecond => {
+                    result.microsecond = Some(to_integer_with_truncation(&value, context)?);
+                }
+                Field::Nanosecond => {
+                    result.nanosecond = Some(to_integer_with_truncation(&value, context)?);
+                }
+            }
+        }
+
+        // 4. If requiredFields is partial and any is false, then
+        //     a. Throw a TypeError exception.
+        if required.is_none() && !any {
+            return Err(JsNativeError::typ()
+                .with_message("the object must have at least one of the Temporal properties")
+                .into());
+        }
+
+        // 5. Return result.
+        Ok(result)
+    }
+
+    /// Creates the fields of a date and a wall-clock time, which are the fields of an existing
+    /// `Temporal` object that the `with` methods merge with a property bag.
+    pub(crate) fn from_date_time(date_time: IsoDateTime) -> Self {
+        let IsoDateTime { date, time } = date_time;
+        Self {
+            year: Some(f64::from(date.year)),
+            month: Some(f64::from(date.month)),
+            month_code: Some(format!("M{:02}", date.month)),
+            day: Some(f64::from(date.day)),
+            hour: Some(f64::from(time.hour)),
+            minute: Some(f64::from(time.minute)),
+            second: Some(f64::from(time.second)),
+            millisecond: Some(f64::from(time.millisecond)),
+            microsecond: Some(f64::from(time.microsecond)),
+            nanosecond: Some(f64::from(time.nanosecond)),
+            offset: None,
+            time_zone: None,
+        }
+    }
+
+    /// Abstract operation `DefaultMergeCalendarFields ( fields, additionalFields )`
+    ///
+    /// Merges the fields of a property bag into these fields. If the property bag has a month
+    /// or a month code, both the month and the month code of these fields are replaced.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-defaultmergecalendarfields
+    pub(crate) fn merge(mut self, additional: Self) -> Self {
+        if additional.month.is_some() || additional.month_code.is_some() {
+            self.month = additional.month;
+            self.month_code = additional.month_code;
+        }
+
+        macro_rules! merge {
+            ($($field:ident),*) => {
+                $(if additional.$field.is_some() {
+                    self.$field = additional.$field;
+                })*
+            };
+        }
+        merge!(
+            year,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            offset,
+            time_zone
+        );
+
+        self
+    }
+
+    /// Abstract operation `ResolveISOMonth ( fields )`
+    ///
+    /// Gets the month of the fields, which must be consistent with the month code if both are
+    /// present.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-resolveisomonth
+    #[allow(clippy::float_cmp)]
+    pub(crate) fn resolve_month(&self) -> JsResult<f64> {
+        // 1. Let month be ! Get(fields, "month").
+        // 2. Let monthCode be ! Get(fields, "monthCode").
+        // 3. If monthCode is undefined, then
+        let month_code = match &self.month_code {
+            None => {
+                // a. If month is undefined, throw a TypeError exception.
+                // b. Return ℝ(month).
+                return self.month.ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("either month or monthCode is required")
+                        .into()
+                });
+            }
+            Some(month_code) => month_code,
+        };
+
+        // 4. Assert: Type(monthCode) is String.
+        // 5. If the length of monthCode is not 3, throw a RangeError exception.
+        // 6. If the first code unit of monthCode is not 0x004D (LATIN CAPITAL LETTER M), throw a RangeError exception.
+        // 7. Let monthCodeDigits be the substring of monthCode from 1.
+        // 8. If ParseText(StringToCodePoints(monthCodeDigits), DateMonth) is a List of errors, throw a RangeError exception.
+        // 9. Let monthCodeInteger be ℝ(StringToNumber(monthCodeDigits)).
+        let month_code_integer = month_code
+            .strip_prefix('M')
+            .filter(|digits| digits.len() == 2 && digits.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|digits| digits.parse::<u8>().ok())
+            .filter(|month| (1..=12).contains(month))
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message(format!("'{month_code}' is not a valid month code"))
+            })?;
+
+        // 10. If month is not undefined and SameValue(month, monthCodeInteger) is false, throw a RangeError exception.
+        if let Some(month) = self.month {
+            if month != f64::from(month_code_integer) {
+                return Err(JsNativeError::range()
+                    .with_message("the month and the month code do not match")
+                    .into());
+            }
+        }
+
+        // 11. Return monthCodeInteger.
+        Ok(f64::from(month_code_integer))
+    }
+
+    /// Abstract operation `ISODateFromFields ( fields, options )`
+    ///
+    /// Gets the date of the fields, which must have a year, a day, and a month or a month code.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isodatefromfields
+    pub(crate) fn date(&self, overflow: Overflow) -> JsResult<IsoDate> {
+        let missing = |name| {
+            JsNativeError::typ().with_message(format!("required property {name} is missing"))
+        };
+
+        // 1. Set fields to ? PrepareTemporalFields(fields, « "day", "month", "monthCode", "year" », « "year", "day" »).
+        let year = self.year.ok_or_else(|| missing("year"))?;
+        let day = self.day.ok_or_else(|| missing("day"))?;
+
+        // 2. Let overflow be ? ToTemporalOverflow(options).
+        // 3. Let month be ? ResolveISOMonth(fields).
+        let month = self.resolve_month()?;
+
+        // 4. Return ? RegulateISODate(year, month, day, overflow).
+        IsoDate::regulate(year, month, day, overflow)
+    }
+
+    /// Abstract operation `ToTemporalTimeRecord ( temporalTimeLike )` followed by
+    /// `RegulateTime`
+    ///
+    /// Gets the wall-clock time of the fields, where missing fields are zero.
+    pub(crate) fn time(&self, overflow: Overflow) -> JsResult<IsoTime> {
+        IsoTime::regulate(
+            [
+                self.hour.unwrap_or_default(),
+                self.minute.unwrap_or_default(),
+                self.second.unwrap_or_default(),
+                self.millisecond.unwrap_or_default(),
+                self.microsecond.unwrap_or_default(),
+                self.nanosecond.unwrap_or_default(),
+            ],
+            overflow,
+        )
+    }
+}
+
+/// Abstract operation `RejectObjectWithCalendarOrTimeZone ( object )`
+///
+/// Throws a `TypeError` if the argument of a `with` method is a `Temporal` object, or has a
+/// `calendar` or a `timeZone` property.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-rejectobjectwithcalendarortimezone
+pub(crate) fn reject_object_with_calendar_or_time_zone(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. Assert: Type(object) is Object.
+    // 2. If object has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]], [[InitializedTemporalTime]], [[InitializedTemporalYearMonth]], or [[InitializedTemporalZonedDateTime]] internal slot, then
+    //     a. Throw a TypeError exception.
+    {
+        let object = object.borrow();
+        if object.as_plain_date().is_some()
+            || object.as_plain_date_time().is_some()
+            || object.as_plain_time().is_some()
+            || object.as_zoned_date_time().is_some()
+        {
+            return Err(JsNativeError::typ()
+                .with_message("the argument of with cannot be a Temporal object")
+                .into());
+        }
+    }
+
+    // 3. Let calendarProperty be ? Get(object, "calendar").
+    // 4. If calendarProperty is not undefined, then
+    //     a. Throw a TypeError exception.
+    // 5. Let timeZoneProperty be ? Get(object, "timeZone").
+    // 6. If timeZoneProperty is not undefined, then
+    //     a. Throw a TypeError exception.
+    for property in ["calendar", "timeZone"] {
+        if !object.get(property, context)?.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "the argument of with cannot have a {property} property"
+                ))
+                .into());
+        }
+    }
+
+    // 7. Return unused.
+    Ok(())
+}
@@ -0,0 +1,817 @@
+//! This module implements the `Temporal.Instant` object.
+//!
+//! A `Temporal.Instant` is an exact point in time, with nanosecond precision, which is stored as
+//! the number of nanoseconds since the epoch. It has no time zone nor calendar.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-instant-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant
+
+use super::{
+    bigint_to_i128,
+    calendar::Calendar,
+    difference::{add_instant, difference_instant},
+    duration::{Duration, DurationRecord},
+    i128_to_bigint,
+    iso::{check_epoch_nanoseconds, IsoDateTime},
+    options::{
+        get_difference_settings, get_options_object, get_round_to_options, get_temporal_unit,
+        to_seconds_string_precision, to_temporal_rounding_increment, to_temporal_rounding_mode,
+        validate_temporal_rounding_increment, Precision, RoundingMode, Unit, UnitGroup, UnitOption,
+        NS_PER_DAY, NS_PER_MICROSECOND, NS_PER_MILLISECOND, NS_PER_SECOND,
+    },
+    parser::{parse_instant, ParsedOffset},
+    time_zone::{format_offset_rounded, TimeZone},
+    value_of,
+    zoned_date_time::ZonedDateTime,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::PreferredType,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+
+/// The internal representation of a `Temporal.Instant` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Instant {
+    pub(crate) epoch_nanoseconds: i128,
+}
+
+impl Instant {
+    const NAME: &'static str = "Instant";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let attribute = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+        let get_epoch_seconds = FunctionBuilder::native(context, Self::get_epoch_seconds)
+            .name("get epochSeconds")
+            .build();
+        let get_epoch_milliseconds = FunctionBuilder::native(context, Self::get_epoch_milliseconds)
+            .name("get epochMilliseconds")
+            .build();
+        let get_epoch_microseconds = FunctionBuilder::native(context, Self::get_epoch_microseconds)
+            .name("get epochMicroseconds")
+            .build();
+        let get_epoch_nanoseconds = FunctionBuilder::native(context, Self::get_epoch_nanoseconds)
+            .name("get epochNanoseconds")
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().instant().clone(),
+        )
+        .name(Self::NAME)
+        .length(1)
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::from_epoch_seconds, "fromEpochSeconds", 1)
+        .static_method(Self::from_epoch_milliseconds, "fromEpochMilliseconds", 1)
+        .static_method(Self::from_epoch_microseconds, "fromEpochMicroseconds", 1)
+        .static_method(Self::from_epoch_nanoseconds, "fromEpochNanoseconds", 1)
+        .static_method(Self::compare, "compare", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Temporal.Instant",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .accessor("epochSeconds", Some(get_epoch_seconds), None, attribute)
+        .accessor(
+            "epochMilliseconds",
+            Some(get_epoch_milliseconds),
+            None,
+            attribute,
+        )
+        .accessor(
+            "epochMicroseconds",
+            Some(get_epoch_microseconds),
+            None,
+            attribute,
+        )
+        .accessor(
+            "epochNanoseconds",
+            Some(get_epoch_nanoseconds),
+            None,
+            attribute,
+        )
+        .method(Self::add, "add", 1)
+        .method(Self::subtract, "subtract", 1)
+        .method(Self::until, "until", 1)
+        .method(Self::since, "since", 1)
+        .method(Self::round, "round", 1)
+        .method(Self::equals, "equals", 1)
+        .method(Self::to_string, "toString", 0)
+        .method(Self::to_json, "toJSON", 0)
+        .method(Self::to_json, "toLocaleString", 0)
+        .method(value_of, "valueOf", 0)
+        .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+        .method(Self::to_zoned_date_time_iso, "toZonedDateTimeISO", 1)
+        .build()
+    }
+
+    /// `Temporal.Instant ( epochNanoseconds )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        //     a. Throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.Instant must be called with new")
+                .into());
+        }
+
+        // 2. Let epochNanoseconds be ? ToBigInt(epochNanoseconds).
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch_nanoseconds = args.get_or_undefined(0).to_bigint(context)?;
+        let epoch_nanoseconds = bigint_to_i128(&epoch_nanoseconds).map_or_else(
+            || check_epoch_nanoseconds(i128::MAX),
+            check_epoch_nanoseconds,
+        )?;
+
+        // 4. Return ? CreateTemporalInstant(epochNanoseconds, NewTarget).
+        Self::create(epoch_nanoseconds, Some(new_target), context).map(Into::into)
+    }
+
+    /// Abstract operation `CreateTemporalInstant ( epochNanoseconds [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporalinstant
+    pub(crate) fn create(
+        epoch_nanoseconds: i128,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: ! IsValidEpochNanoseconds(epochNanoseconds) is true.
+        debug_assert!(check_epoch_nanoseconds(epoch_nanoseconds).is_ok());
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.Instant%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.Instant.prototype%", « [[InitializedTemporalInstant]], [[Nanoseconds]] »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::instant, context)?
+            }
+            None => context.intrinsics().constructors().instant().prototype(),
+        };
+
+        // 4. Set object.[[Nanoseconds]] to epochNanoseconds.
+        // 5. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::instant(Self { epoch_nanoseconds }),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalInstant ( item )`
+    ///
+    /// Returns the epoch nanoseconds of the instant.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalinstant
+    pub(crate) fn to_temporal_instant(item: &JsValue, context: &mut Context) -> JsResult<i128> {
+        // 1. If item is an Object, then
+        let item = if let Some(object) = item.as_object() {
+            // a. If item has an [[InitializedTemporalInstant]] or [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Return ! CreateTemporalInstant(item.[[EpochNanoseconds]]).
+            {
+                let borrowed = object.borrow();
+                if let Some(instant) = borrowed.as_instant() {
+                    return Ok(instant.epoch_nanoseconds);
+                }
+                if let Some(zoned) = borrowed.as_zoned_date_time() {
+                    return Ok(zoned.epoch_nanoseconds);
+                }
+            }
+
+            // b. NOTE: This use of ToPrimitive allows Instant-like objects to be converted.
+            // c. Set item to ? ToPrimitive(item, string).
+            item.to_primitive(context, PreferredType::String)?
+        } else {
+            item.clone()
+        };
+
+        // 2. If item is not a String, throw a TypeError exception.
+        let string = item.as_string().ok_or_else(|| {
+            JsNativeError::typ().with_message("an instant must be a Temporal.Instant or a string")
+        })?;
+
+        // 3. Let parsed be ? ParseTemporalInstantString(item).
+        let parsed = parse_instant(&string.to_std_string_escaped())?;
+        Calendar::check_annotation(parsed.calendar.as_deref())?;
+
+        // 4. If parsed.[[TimeZone]].[[Z]] is true, let offsetNanoseconds be 0; otherwise, let offsetNanoseconds be ! ParseDateTimeUTCOffset(parsed.[[TimeZone]].[[OffsetString]]).
+        let offset = match parsed.offset {
+            Some(ParsedOffset::Offset { nanoseconds, .. }) => i128::from(nanoseconds),
+            _ => 0,
+        };
+
+        // 5. If parsed.[[Time]] is start-of-day, let time be MidnightTimeRecord(); else let time be parsed.[[Time]].
+        // 6. Let balanced be BalanceISODateTime(parsed.[[Year]], ..., time.[[Nanosecond]] - offsetNanoseconds).
+        // 7. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
+        // 8. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+        // 9. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let date_time = IsoDateTime::new(
+            parsed.date.expect("an instant string must have a date"),
+            parsed.time.expect("an instant string must have a time"),
+        );
+        check_epoch_nanoseconds(date_time.to_epoch_nanoseconds() - offset)
+    }
+
+    /// `Temporal.Instant.from ( item )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalInstant(item).
+        let epoch_nanoseconds = Self::to_temporal_instant(args.get_or_undefined(0), context)?;
+        Self::create(epoch_nanoseconds, None, context).map(Into::into)
+    }
+
+    /// Creates an instant from a number of `unit`s since the epoch, which is the argument of
+    /// the `fromEpoch*` functions.
+    #[allow(clippy::float_cmp)]
+    fn from_epoch_number(epoch: &JsValue, unit: i128, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set epochMilliseconds to ? ToNumber(epochMilliseconds).
+        // 2. Set epochMilliseconds to ? NumberToBigInt(epochMilliseconds).
+        let epoch = epoch.to_number(context)?;
+        if !epoch.is_finite() || epoch.trunc() != epoch {
+            return Err(JsNativeError::range()
+                .with_message(format!("{epoch} is not an integral number"))
+                .into());
+        }
+
+        // 3. Let epochNanoseconds be epochMilliseconds × ℤ(10^6).
+        // 4. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        // The limits of an instant are within ±10^17 seconds, so larger numbers are out of range.
+        let epoch_nanoseconds = if epoch.abs() < 1e22 {
+            epoch as i128 * unit
+        } else {
+            i128::MAX
+        };
+        let epoch_nanoseconds = check_epoch_nanoseconds(epoch_nanoseconds)?;
+
+        // 5. Return ! CreateTemporalInstant(epochNanoseconds).
+        Self::create(epoch_nanoseconds, None, context).map(Into::into)
+    }
+
+    /// Creates an instant from a `BigInt` number of `unit`s since the epoch, which is the
+    /// argument of the `fromEpochMicroseconds` and `fromEpochNanoseconds` functions.
+    fn from_epoch_bigint(epoch: &JsValue, unit: i128, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set epochMicroseconds to ? ToBigInt(epochMicroseconds).
+        // 2. Let epochNanoseconds be epochMicroseconds × 1000ℤ.
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch = epoch.to_bigint(context)?;
+        let epoch_nanoseconds = bigint_to_i128(&epoch)
+            .and_then(|epoch| epoch.checked_mul(unit))
+            .unwrap_or(i128::MAX);
+        let epoch_nanoseconds = check_epoch_nanoseconds(epoch_nanoseconds)?;
+
+        // 4. Return ! CreateTemporalInstant(epochNanoseconds).
+        Self::create(epoch_nanoseconds, None, context).map(Into::into)
+    }
+
+    /// `Temporal.Instant.fromEpochSeconds ( epochSeconds )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochseconds
+    fn from_epoch_seconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::from_epoch_number(args.get_or_undefined(0), NS_PER_SECOND, context)
+    }
+
+    /// `Temporal.Instant.fromEpochMilliseconds ( epochMilliseconds )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochmilliseconds
+    fn from_epoch_milliseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::from_epoch_number(args.get_or_undefined(0), NS_PER_MILLISECOND, context)
+    }
+
+    /// `Temporal.Instant.fromEpochMicroseconds ( epochMicroseconds )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochmicroseconds
+    fn from_epoch_microseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::from_epoch_bigint(args.get_or_undefined(0), NS_PER_MICROSECOND, context)
+    }
+
+    /// `Temporal.Instant.fromEpochNanoseconds ( epochNanoseconds )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochnanoseconds
+    fn from_epoch_nanoseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::from_epoch_bigint(args.get_or_undefined(0), 1, context)
+    }
+
+    /// `Temporal.Instant.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalInstant(one).
+        // 2. Set two to ? ToTemporalInstant(two).
+        let one = Self::to_temporal_instant(args.get_or_undefined(0), context)?;
+        let two = Self::to_temporal_instant(args.get_or_undefined(1), context)?;
+
+        // 3. Return 𝔽(CompareEpochNanoseconds(one.[[Nanoseconds]], two.[[Nanoseconds]])).
+        Ok((one.cmp(&two) as i32).into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochSeconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochseconds
+    fn get_epoch_seconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Let s be floor(ℝ(ns) / 10^9).
+        // 5. Return 𝔽(s).
+        let epoch_nanoseconds = this_instant(this)?;
+        Ok((epoch_nanoseconds.div_euclid(NS_PER_SECOND) as f64).into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochMilliseconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochmilliseconds
+    fn get_epoch_milliseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Let ms be floor(ℝ(ns) / 10^6).
+        // 5. Return 𝔽(ms).
+        let epoch_nanoseconds = this_instant(this)?;
+        Ok((epoch_nanoseconds.div_euclid(NS_PER_MILLISECOND) as f64).into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochMicroseconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochmicroseconds
+    fn get_epoch_microseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Let µs be floor(ℝ(ns) / 10^3).
+        // 5. Return ℤ(µs).
+        let epoch_nanoseconds = this_instant(this)?;
+        Ok(i128_to_bigint(epoch_nanoseconds.div_euclid(NS_PER_MICROSECOND)).into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochNanoseconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochnanoseconds
+    fn get_epoch_nanoseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Return ns.
+        Ok(i128_to_bigint(this_instant(this)?).into())
+    }
+
+    /// `Temporal.Instant.prototype.add ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        // 3. Return ? AddDurationToInstant(add, instant, temporalDurationLike).
+        Self::add_duration(false, this, args, context)
+    }
+
+    /// `Temporal.Instant.prototype.subtract ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        // 3. Return ? AddDurationToInstant(subtract, instant, temporalDurationLike).
+        Self::add_duration(true, this, args, context)
+    }
+
+    /// Abstract operation `AddDurationToInstant ( operation, instant, temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtoinstant
+    fn add_duration(
+        subtract: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let epoch_nanoseconds = this_instant(this)?;
+
+        // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::to_temporal_duration(args.get_or_undefined(0), context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 3. Let largestUnit be DefaultTemporalLargestUnit(duration).
+        // 4. If TemporalUnitCategory(largestUnit) is date, throw a RangeError exception.
+        if duration.default_largest_unit().is_date_unit() {
+            return Err(JsNativeError::range()
+                .with_message("durations with days or larger units cannot be added to an instant")
+                .into());
+        }
+
+        // 5. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+        // 6. Let ns be ? AddInstant(instant.[[EpochNanoseconds]], internalDuration.[[Time]]).
+        // 7. Return ! CreateTemporalInstant(ns).
+        let epoch_nanoseconds = add_instant(epoch_nanoseconds, duration.time_duration())?;
+        Self::create(epoch_nanoseconds, None, context).map(Into::into)
+    }
+
+    /// `Temporal.Instant.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalInstant(until, instant, other, options).
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.Instant.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalInstant(since, instant, other, options).
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation `DifferenceTemporalInstant ( operation, instant, other, options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalinstant
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let epoch_nanoseconds = this_instant(this)?;
+
+        // 1. Set other to ? ToTemporalInstant(other).
+        let other = Self::to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 2. Let resolvedOptions be ? GetOptionsObject(options).
+        // 3. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, time, « », nanosecond, second).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::Time,
+            Unit::Nanosecond,
+            Unit::Second,
+            context,
+        )?;
+
+        // 4. Let internalDuration be DifferenceInstant(instant.[[EpochNanoseconds]], other.[[EpochNanoseconds]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        // 5. Let result be ! TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+        // 6. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 7. Return result.
+        let internal = difference_instant(epoch_nanoseconds, other, settings)?;
+        let mut result = DurationRecord::from_internal(internal, settings.largest_unit)?;
+        if since {
+            result = result.negated();
+        }
+        result.create(None, context).map(Into::into)
+    }
+
+    /// `Temporal.Instant.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_nanoseconds = this_instant(this)?;
+
+        // 3-5. Let roundTo be the options object of roundTo.
+        let round_to = get_round_to_options(args.get_or_undefined(0), context)?;
+        let options = Some(&round_to);
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order (GetRoundingIncrementOption reads "roundingIncrement" and GetRoundingModeOption reads "roundingMode").
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let increment = to_temporal_rounding_increment(options, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let mode = to_temporal_rounding_mode(options, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required).
+        let unit = get_temporal_unit(options, "smallestUnit", UnitGroup::Time, false, context)?
+            .and_then(UnitOption::unit)
+            .ok_or_else(|| JsNativeError::range().with_message("smallestUnit is required"))?;
+
+        // 10-16. Let maximum be the number of smallestUnits in a day.
+        // 17. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, true).
+        let maximum = (NS_PER_DAY / unit.nanoseconds()) as u64;
+        validate_temporal_rounding_increment(increment, maximum, true)?;
+
+        // 18. Let roundedNs be RoundTemporalInstant(instant.[[EpochNanoseconds]], roundingIncrement, smallestUnit, roundingMode).
+        // 19. Return ! CreateTemporalInstant(roundedNs).
+        let rounded = round_epoch_nanoseconds(epoch_nanoseconds, increment, unit, mode);
+        Self::create(rounded, None, context).map(Into::into)
+    }
+
+    /// `Temporal.Instant.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_nanoseconds = this_instant(this)?;
+
+        // 3. Set other to ? ToTemporalInstant(other).
+        let other = Self::to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4. If instant.[[Nanoseconds]] ≠ other.[[Nanoseconds]], return false.
+        // 5. Return true.
+        Ok((epoch_nanoseconds == other).into())
+    }
+
+    /// `Temporal.Instant.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tostring
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_nanoseconds = this_instant(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. NOTE: The following steps read options and perform independent validation in alphabetical order (GetTemporalFractionalSecondDigitsOption reads "fractionalSecondDigits" and GetRoundingModeOption reads "roundingMode").
+        // 5-7. Let digits, roundingMode and smallestUnit be the options.
+        let options = get_options_object(args.get_or_undefined(0))?;
+        let (rounding, mode) = to_seconds_string_precision(options.as_ref(), context)?;
+
+        // 8. Let timeZone be ? Get(resolvedOptions, "timeZone").
+        // 11. If timeZone is not undefined, then
+        //     a. Set timeZone to ? ToTemporalTimeZoneIdentifier(timeZone).
+        let time_zone = match &options {
+            Some(options) => options.get("timeZone", context)?,
+            None => JsValue::undefined(),
+        };
+        let time_zone = if time_zone.is_undefined() {
+            None
+        } else {
+            Some(TimeZone::of(&TimeZone::to_temporal_time_zone(
+                &time_zone, context,
+            )?))
+        };
+
+        // 12. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        // 13. Let roundedNs be RoundTemporalInstant(instant.[[EpochNanoseconds]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        // 14. Return TemporalInstantToString(roundedNs, timeZone, precision.[[Precision]]).
+        let rounded =
+            round_epoch_nanoseconds(epoch_nanoseconds, rounding.increment, rounding.unit, mode);
+        Ok(instant_to_string(rounded, time_zone.as_ref(), rounding.precision, context).into())
+    }
+
+    /// `Temporal.Instant.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tojson
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        // 3. Return TemporalInstantToString(instant, undefined, auto).
+        let epoch_nanoseconds = this_instant(this)?;
+        Ok(instant_to_string(epoch_nanoseconds, None, Precision::Auto, context).into())
+    }
+
+    /// `Temporal.Instant.prototype.toZonedDateTime ( item )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tozoneddatetime
+    fn to_zoned_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_nanoseconds = this_instant(this)?;
+
+        // 3. If Type(item) is not Object, then
+        //     a. Throw a TypeError exception.
+        let item = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("toZonedDateTime requires an options object")
+        })?;
+
+        // 4. Let calendarLike be ? Get(item, "calendar").
+        // 5. If calendarLike is undefined, then
+        //     a. Throw a TypeError exception.
+        // 6. Let calendar be ? ToTemporalCalendar(calendarLike).
+        let calendar_like = item.get("calendar", context)?;
+        if calendar_like.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("toZonedDateTime requires a calendar")
+                .into());
+        }
+        let calendar = Calendar::to_temporal_calendar(&calendar_like, context)?;
+
+        // 7. Let temporalTimeZoneLike be ? Get(item, "timeZone").
+        // 8. If temporalTimeZoneLike is undefined, then
+        //     a. Throw a TypeError exception.
+        // 9. Let timeZone be ? ToTemporalTimeZone(temporalTimeZoneLike).
+        let time_zone_like = item.get("timeZone", context)?;
+        if time_zone_like.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("toZonedDateTime requires a time zone")
+                .into());
+        }
+        let time_zone = TimeZone::to_temporal_time_zone(&time_zone_like, context)?;
+
+        // 10. Return ? CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, calendar).
+        ZonedDateTime::new(epoch_nanoseconds, time_zone, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Instant.prototype.toZonedDateTimeISO ( timeZone )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tozoneddatetimeiso
+    fn to_zoned_date_time_iso(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_nanoseconds = this_instant(this)?;
+
+        // 3. Set timeZone to ? ToTemporalTimeZone(timeZone).
+        let time_zone = TimeZone::to_temporal_time_zone(args.get_or_undefined(0), context)?;
+
+        // 4. Let calendar be ! GetISO8601Calendar().
+        // 5. Return ? CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, calendar).
+        let calendar = Calendar::create(context);
+        ZonedDateTime::new(epoch_nanoseconds, time_zone, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+}
+
+/// Abstract operation `RoundTemporalInstant ( ns, increment, unit, roundingMode )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundtemporalinstant
+pub(crate) fn round_epoch_nanoseconds(
+    epoch_nanoseconds: i128,
+    increment: u64,
+    unit: Unit,
+    mode: RoundingMode,
+) -> i128 {
+    // 1. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 21 whose "Value" column contains unit.
+    // 2. Let incrementNs be increment × unitLength.
+    // 3. Return ℤ(RoundNumberToIncrementAsIfPositive(ℝ(ns), incrementNs, roundingMode)).
+    mode.as_if_positive().round(
+        epoch_nanoseconds,
+        unit.nanoseconds() * i128::from(increment),
+    )
+}
+
+/// Abstract operation `TemporalInstantToString ( instant, timeZone, precision )`
+///
+/// Formats an exact time in a time zone, or in UTC with the `Z` designator if the time zone is
+/// `None`.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporalinstanttostring
+fn instant_to_string(
+    epoch_nanoseconds: i128,
+    time_zone: Option<&TimeZone>,
+    precision: Precision,
+    context: &Context,
+) -> String {
+    // 1. Let outputTimeZone be timeZone.
+    // 2. If outputTimeZone is undefined, set outputTimeZone to "UTC".
+    // 3. Let epochNs be instant.[[EpochNanoseconds]].
+    // 4. Let isoDateTime be GetISODateTimeFor(outputTimeZone, epochNs).
+    // 5. Let dateTimeString be ISODateTimeToString(isoDateTime, "iso8601", precision, never).
+    let (date_time, time_zone_string) = match time_zone {
+        // 6. If timeZone is undefined, then
+        //     a. Let timeZoneString be "Z".
+        None => (
+            IsoDateTime::from_epoch_nanoseconds(epoch_nanoseconds),
+            "Z".to_owned(),
+        ),
+        // 7. Else,
+        //     a. Let offsetNanoseconds be GetOffsetNanosecondsFor(outputTimeZone, epochNs).
+        //     b. Let timeZoneString be FormatDateTimeUTCOffsetRounded(offsetNanoseconds).
+        Some(time_zone) => (
+            time_zone.date_time_for(epoch_nanoseconds, context),
+            format_offset_rounded(time_zone.offset_nanoseconds_for(epoch_nanoseconds, context)),
+        ),
+    };
+
+    // 8. Return the string-concatenation of dateTimeString and timeZoneString.
+    format!(
+        "{}T{}{time_zone_string}",
+        date_time.date,
+        date_time.time.to_string_with_precision(precision)
+    )
+}
+
+/// Gets the epoch nanoseconds of the `this` value of a `Temporal.Instant` method, which must be
+/// an instant.
+fn this_instant(this: &JsValue) -> JsResult<i128> {
+    this.as_object()
+        .and_then(|object| {
+            object
+                .borrow()
+                .as_instant()
+                .map(|instant| instant.epoch_nanoseconds)
+        })
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Temporal.Instant")
+                .into()
+        })
+}
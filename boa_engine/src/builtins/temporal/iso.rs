@@ -0,0 +1,845 @@
+//! The ISO 8601 date and time records used by the `Temporal` builtins, and the abstract
+//! operations that do arithmetic on them.
+//!
+//! All the records of this module are proleptic Gregorian dates and wall-clock times, which are
+//! not tied to any time zone.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindate-abstract-ops
+
+use super::options::{
+    Overflow, Precision, RoundingMode, Unit, NS_PER_DAY, NS_PER_HOUR, NS_PER_MICROSECOND,
+    NS_PER_MILLISECOND, NS_PER_MINUTE, NS_PER_SECOND,
+};
+use crate::{error::JsNativeError, JsResult};
+use std::fmt::{self, Write};
+
+/// The largest number of nanoseconds from the epoch that an `Instant` can represent, which is
+/// 10^8 days.
+pub(crate) const NS_MAX_INSTANT: i128 = 100_000_000 * NS_PER_DAY;
+/// The smallest number of nanoseconds from the epoch that an `Instant` can represent.
+pub(crate) const NS_MIN_INSTANT: i128 = -NS_MAX_INSTANT;
+
+/// The largest absolute year that the arithmetic of this module accepts, which is far outside
+/// of the range of the `Temporal` objects but guards against overflows.
+const MAX_ABSOLUTE_YEAR: i64 = 1_000_000;
+
+/// Abstract operation `IsValidEpochNanoseconds ( epochNanoseconds )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isvalidepochnanoseconds
+pub(crate) fn is_valid_epoch_nanoseconds(epoch_nanoseconds: i128) -> bool {
+    (NS_MIN_INSTANT..=NS_MAX_INSTANT).contains(&epoch_nanoseconds)
+}
+
+/// Throws a `RangeError` if `epoch_nanoseconds` is outside of the range of an `Instant`.
+pub(crate) fn check_epoch_nanoseconds(epoch_nanoseconds: i128) -> JsResult<i128> {
+    if is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+        Ok(epoch_nanoseconds)
+    } else {
+        Err(JsNativeError::range()
+            .with_message("the exact time is outside of the supported range")
+            .into())
+    }
+}
+
+/// Abstract operation `MathematicalInLeapYear ( t )`
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Abstract operation `ISODaysInMonth ( year, month )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isodaysinmonth
+pub(crate) const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+    }
+}
+
+/// Gets the number of days of the ISO year `year`.
+pub(crate) const fn days_in_year(year: i32) -> u16 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// The years, months, weeks and days of a duration, as computed by `DifferenceISODate`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct DateDuration {
+    pub(crate) years: i64,
+    pub(crate) months: i64,
+    pub(crate) weeks: i64,
+    pub(crate) days: i64,
+}
+
+impl DateDuration {
+    /// Creates a new `DateDuration`.
+    pub(crate) const fn new(years: i64, months: i64, weeks: i64, days: i64) -> Self {
+        Self {
+            years,
+            months,
+            weeks,
+            days,
+        }
+    }
+
+    /// Abstract operation `DateDurationSign ( dateDuration )`
+    ///
+    /// Gets the sign of the first non-zero field of the duration, or zero if all of them are
+    /// zero.
+    pub(crate) fn sign(self) -> i64 {
+        [self.years, self.months, self.weeks, self.days]
+            .into_iter()
+            .find(|&value| value != 0)
+            .map_or(0, i64::signum)
+    }
+}
+
+/// A date of the ISO 8601 calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoDate {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+}
+
+impl IsoDate {
+    /// Abstract operation `IsValidISODate ( year, month, day )`
+    ///
+    /// Creates a new date, or returns `None` if the month or the day is out of range.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isvalidisodate
+    pub(crate) fn new(year: i64, month: i64, day: i64) -> Option<Self> {
+        if year.abs() > MAX_ABSOLUTE_YEAR || !(1..=12).contains(&month) {
+            return None;
+        }
+        let year = year as i32;
+        let month = month as u8;
+        if !(1..=i64::from(days_in_month(year, month))).contains(&day) {
+            return None;
+        }
+        Some(Self {
+            year,
+            month,
+            day: day as u8,
+        })
+    }
+
+    /// Abstract operation `RegulateISODate ( year, month, day, overflow )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-regulateisodate
+    pub(crate) fn regulate(year: f64, month: f64, day: f64, overflow: Overflow) -> JsResult<Self> {
+        let out_of_range = || {
+            JsNativeError::range()
+                .with_message("the date is outside of the supported range")
+                .into()
+        };
+        if year.abs() > MAX_ABSOLUTE_YEAR as f64 {
+            return Err(out_of_range());
+        }
+        let year = year as i64;
+
+        match overflow {
+            // 1. If overflow is "constrain", then
+            Overflow::Constrain => {
+                // a. Set month to the result of clamping month between 1 and 12.
+                let month = month.clamp(1.0, 12.0) as u8;
+                // b. Let daysInMonth be ISODaysInMonth(year, month).
+                let days_in_month = days_in_month(year as i32, month);
+                // c. Set day to the result of clamping day between 1 and daysInMonth.
+                let day = day.clamp(1.0, f64::from(days_in_month)) as u8;
+                // d. Return CreateISODateRecord(year, month, day).
+                Ok(Self {
+                    year: year as i32,
+                    month,
+                    day,
+                })
+            }
+            // 2. Else,
+            //     a. Assert: overflow is "reject".
+            //     b. If IsValidISODate(year, month, day) is false, throw a RangeError exception.
+            //     c. Return CreateISODateRecord(year, month, day).
+            Overflow::Reject => {
+                if !(1.0..=12.0).contains(&month) || !(1.0..=31.0).contains(&day) {
+                    return Err(JsNativeError::range()
+                        .with_message("the month or the day is out of range")
+                        .into());
+                }
+                Self::new(year, month as i64, day as i64).ok_or_else(|| {
+                    JsNativeError::range()
+                        .with_message("the day is out of range for the month")
+                        .into()
+                })
+            }
+        }
+    }
+
+    /// Gets the number of days from the epoch (1970-01-01) to the date.
+    pub(crate) fn epoch_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Creates a date from the number of days from the epoch (1970-01-01).
+    pub(crate) fn from_epoch_days(epoch_days: i64) -> Self {
+        let days = epoch_days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    /// Abstract operation `ISODateWithinLimits ( year, month, day )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isodatewithinlimits
+    pub(crate) fn within_limits(self) -> bool {
+        IsoDateTime::new(
+            self,
+            IsoTime {
+                hour: 12,
+                ..IsoTime::default()
+            },
+        )
+        .within_limits()
+    }
+
+    /// Throws a `RangeError` if the date is outside of the range of a `PlainDate`.
+    pub(crate) fn check_limits(self) -> JsResult<Self> {
+        if self.within_limits() {
+            Ok(self)
+        } else {
+            Err(JsNativeError::range()
+                .with_message("the date is outside of the supported range")
+                .into())
+        }
+    }
+
+    /// Abstract operation `ToISODayOfWeek ( year, month, day )`
+    ///
+    /// Gets the day of the week of the date, from 1 for Monday to 7 for Sunday.
+    pub(crate) fn day_of_week(self) -> u8 {
+        // 1970-01-01 was a Thursday.
+        ((self.epoch_days() + 3).rem_euclid(7) + 1) as u8
+    }
+
+    /// Abstract operation `ToISODayOfYear ( year, month, day )`
+    pub(crate) fn day_of_year(self) -> u16 {
+        (self.epoch_days()
+            - Self {
+                year: self.year,
+                month: 1,
+                day: 1,
+            }
+            .epoch_days()
+            + 1) as u16
+    }
+
+    /// Abstract operation `ToISOWeekOfYear ( year, month, day )`
+    ///
+    /// Gets the ISO week number of the date, and the year that the week belongs to.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-toisoweekofyear
+    pub(crate) fn week_of_year(self) -> (u8, i32) {
+        /// Gets the number of ISO weeks of a year, which is 53 if the year starts on a Thursday,
+        /// or on a Wednesday in leap years.
+        fn weeks_in_year(year: i32) -> i64 {
+            let first_day = IsoDate {
+                year,
+                month: 1,
+                day: 1,
+            }
+            .day_of_week();
+            if first_day == 4 || (first_day == 3 && is_leap_year(year)) {
+                53
+            } else {
+                52
+            }
+        }
+
+        let week = (i64::from(self.day_of_year()) - i64::from(self.day_of_week()) + 10) / 7;
+        if week < 1 {
+            (weeks_in_year(self.year - 1) as u8, self.year - 1)
+        } else if week > weeks_in_year(self.year) {
+            (1, self.year + 1)
+        } else {
+            (week as u8, self.year)
+        }
+    }
+
+    /// Abstract operation `AddISODate ( year, month, day, years, months, weeks, days, overflow )`
+    ///
+    /// Adds `years`, `months` and `days` to the date. Weeks must already be converted to days.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-addisodate
+    pub(crate) fn add(
+        self,
+        years: i64,
+        months: i64,
+        days: i64,
+        overflow: Overflow,
+    ) -> JsResult<Self> {
+        // 1. Let intermediate be ! BalanceISOYearMonth(year + years, month + months).
+        let months = i64::from(self.month) - 1 + months;
+        let year = i64::from(self.year) + years + months.div_euclid(12);
+        let month = months.rem_euclid(12) + 1;
+        if year.abs() > MAX_ABSOLUTE_YEAR {
+            return Err(JsNativeError::range()
+                .with_message("the date is outside of the supported range")
+                .into());
+        }
+
+        // 2. Let intermediate be ? RegulateISODate(intermediate.[[Year]], intermediate.[[Month]], day, overflow).
+        let intermediate =
+            Self::regulate(year as f64, month as f64, f64::from(self.day), overflow)?;
+
+        // 3. Set days to days + 7 × weeks.
+        // 4. Let d be intermediate.[[Day]] + days.
+        // 5. Return BalanceISODate(intermediate.[[Year]], intermediate.[[Month]], d).
+        let epoch_days = intermediate.epoch_days() + days;
+        if epoch_days.abs() > MAX_ABSOLUTE_YEAR * 366 {
+            return Err(JsNativeError::range()
+                .with_message("the date is outside of the supported range")
+                .into());
+        }
+        Ok(Self::from_epoch_days(epoch_days))
+    }
+
+    /// Adds the years, months, weeks and days of a date duration to the date.
+    pub(crate) fn add_duration(self, duration: DateDuration, overflow: Overflow) -> JsResult<Self> {
+        self.add(
+            duration.years,
+            duration.months,
+            duration.weeks * 7 + duration.days,
+            overflow,
+        )
+    }
+
+    /// Adds `years`, `months` and `days` to a date that is within the limits of a `PlainDate`,
+    /// which cannot overflow if the result is between two such dates.
+    fn add_constrained(self, years: i64, months: i64) -> Self {
+        self.add(years, months, 0, Overflow::Constrain)
+            .expect("adding to a date between two valid dates cannot fail")
+    }
+
+    /// Abstract operation `DifferenceISODate ( y1, m1, d1, y2, m2, d2, largestUnit )`
+    ///
+    /// Gets the duration from this date to `other`, with `largest_unit` as the largest unit,
+    /// which must be a date unit.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceisodate
+    pub(crate) fn difference(self, other: Self, largest_unit: Unit) -> DateDuration {
+        match largest_unit {
+            // 1. If largestUnit is "year" or "month", then
+            Unit::Year | Unit::Month => {
+                // a. Let sign be -(! CompareISODate(y1, m1, d1, y2, m2, d2)).
+                // b. If sign is 0, return ! CreateDateDurationRecord(0, 0, 0, 0).
+                let sign: i64 = match other.cmp(&self) {
+                    std::cmp::Ordering::Less => -1,
+                    std::cmp::Ordering::Equal => return DateDuration::default(),
+                    std::cmp::Ordering::Greater => 1,
+                };
+                let sign_of = |mid: Self| match other.cmp(&mid) {
+                    std::cmp::Ordering::Less => -1,
+                    std::cmp::Ordering::Equal => 0,
+                    std::cmp::Ordering::Greater => 1,
+                };
+
+                // c-e. Let years be end.[[Year]] - start.[[Year]].
+                let mut years = i64::from(other.year) - i64::from(self.year);
+
+                // f. Let mid be ! AddISODate(y1, m1, d1, years, 0, 0, 0, "constrain").
+                let mut mid = self.add_constrained(years, 0);
+
+                // g. Let midSign be -(! CompareISODate(mid.[[Year]], mid.[[Month]], mid.[[Day]], y2, m2, d2)).
+                let mut mid_sign = sign_of(mid);
+
+                // h. If midSign is 0, then
+                if mid_sign == 0 {
+                    // i. If largestUnit is "year", return ! CreateDateDurationRecord(years, 0, 0, 0).
+                    // ii. Return ! CreateDateDurationRecord(0, years × 12, 0, 0).
+                    return if largest_unit == Unit::Year {
+                        DateDuration::new(years, 0, 0, 0)
+                    } else {
+                        DateDuration::new(0, years * 12, 0, 0)
+                    };
+                }
+
+                // i. Let months be end.[[Month]] - start.[[Month]].
+                let mut months = i64::from(other.month) - i64::from(self.month);
+
+                // j. If midSign is not equal to sign, then
+                if mid_sign != sign {
+                    // i. Set years to years - sign.
+                    years -= sign;
+                    // ii. Set months to months + sign × 12.
+                    months += sign * 12;
+                }
+
+                // k. Set mid to ! AddISODate(y1, m1, d1, years, months, 0, 0, "constrain").
+                mid = self.add_constrained(years, months);
+
+                // l. Set midSign to -(! CompareISODate(mid.[[Year]], mid.[[Month]], mid.[[Day]], y2, m2, d2)).
+                mid_sign = sign_of(mid);
+
+                // m. If midSign is 0, then
+                if mid_sign == 0 {
+                    // i. If largestUnit is "year", return ! CreateDateDurationRecord(years, months, 0, 0).
+                    // ii. Return ! CreateDateDurationRecord(0, months + years × 12, 0, 0).
+                    return if largest_unit == Unit::Year {
+                        DateDuration::new(years, months, 0, 0)
+                    } else {
+                        DateDuration::new(0, months + years * 12, 0, 0)
+                    };
+                }
+
+                // n. If midSign is not equal to sign, then
+                if mid_sign != sign {
+                    // i. Set months to months - sign.
+                    months -= sign;
+                    // ii. If months is equal to -sign, then
+                    if months == -sign {
+                        // 1. Set years to years - sign.
+                        years -= sign;
+                        // 2. Set months to 11 × sign.
+                        months = 11 * sign;
+                    }
+                    // iii. Set mid to ! AddISODate(y1, m1, d1, years, months, 0, 0, "constrain").
+                    mid = self.add_constrained(years, months);
+                }
+
+                // o. If mid.[[Month]] = end.[[Month]], then
+                let days = if mid.month == other.month {
+                    // i. Assert: mid.[[Year]] = end.[[Year]].
+                    // ii. Let days be end.[[Day]] - mid.[[Day]].
+                    i64::from(other.day) - i64::from(mid.day)
+                }
+                // p. Else if sign < 0, let days be -mid.[[Day]] - (! ISODaysInMonth(end.[[Year]], end.[[Month]]) - end.[[Day]]).
+                else if sign < 0 {
+                    -i64::from(mid.day)
+                        - (i64::from(days_in_month(other.year, other.month)) - i64::from(other.day))
+                }
+                // q. Else, let days be end.[[Day]] + (! ISODaysInMonth(mid.[[Year]], mid.[[Month]]) - mid.[[Day]]).
+                else {
+                    i64::from(other.day)
+                        + (i64::from(days_in_month(mid.year, mid.month)) - i64::from(mid.day))
+                };
+
+                // r. If largestUnit is "month", then
+                if largest_unit == Unit::Month {
+                    // i. Set months to months + years × 12.
+                    // ii. Set years to 0.
+                    months += years * 12;
+                    years = 0;
+                }
+
+                // s. Return ! CreateDateDurationRecord(years, months, 0, days).
+                DateDuration::new(years, months, 0, days)
+            }
+            // 2. Else,
+            //     a. Assert: largestUnit is "day" or "week".
+            _ => {
+                // b-e. Let days be the number of days between the two dates.
+                let mut days = other.epoch_days() - self.epoch_days();
+
+                // f. Let weeks be 0.
+                // g. If largestUnit is "week", then
+                //     i. Set weeks to truncate(days / 7).
+                //     ii. Set days to remainder(days, 7).
+                let mut weeks = 0;
+                if largest_unit == Unit::Week {
+                    weeks = days / 7;
+                    days %= 7;
+                }
+
+                // h. Return ! CreateDateDurationRecord(0, 0, weeks, days).
+                DateDuration::new(0, 0, weeks, days)
+            }
+        }
+    }
+}
+
+impl fmt::Display for IsoDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // PadISOYear ( y )
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}", self.year)?;
+        } else {
+            let sign = if self.year < 0 { '-' } else { '+' };
+            write!(f, "{sign}{:06}", self.year.unsigned_abs())?;
+        }
+        write!(f, "-{:02}-{:02}", self.month, self.day)
+    }
+}
+
+/// A wall-clock time, with nanosecond precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoTime {
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) millisecond: u16,
+    pub(crate) microsecond: u16,
+    pub(crate) nanosecond: u16,
+}
+
+impl IsoTime {
+    /// Abstract operation `RegulateTime ( hour, minute, second, millisecond, microsecond, nanosecond, overflow )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-regulatetime
+    pub(crate) fn regulate(fields: [f64; 6], overflow: Overflow) -> JsResult<Self> {
+        const MAXIMUMS: [f64; 6] = [23.0, 59.0, 59.0, 999.0, 999.0, 999.0];
+
+        let mut values = [0.0; 6];
+        for ((value, field), maximum) in values.iter_mut().zip(fields).zip(MAXIMUMS) {
+            *value = match overflow {
+                // 1. If overflow is "constrain", then
+                //     a. Return ! ConstrainTime(hour, minute, second, millisecond, microsecond, nanosecond).
+                Overflow::Constrain => field.clamp(0.0, maximum),
+                // 2. Else,
+                //     a. Assert: overflow is "reject".
+                //     b. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+                Overflow::Reject => {
+                    if !(0.0..=maximum).contains(&field) {
+                        return Err(JsNativeError::range()
+                            .with_message("the time is out of range")
+                            .into());
+                    }
+                    field
+                }
+            };
+        }
+
+        // 3. Return CreateTimeRecord(hour, minute, second, millisecond, microsecond, nanosecond).
+        Ok(Self {
+            hour: values[0] as u8,
+            minute: values[1] as u8,
+            second: values[2] as u8,
+            millisecond: values[3] as u16,
+            microsecond: values[4] as u16,
+            nanosecond: values[5] as u16,
+        })
+    }
+
+    /// Gets the number of nanoseconds since midnight.
+    pub(crate) fn to_nanoseconds(self) -> i128 {
+        i128::from(self.hour) * NS_PER_HOUR
+            + i128::from(self.minute) * NS_PER_MINUTE
+            + i128::from(self.second) * NS_PER_SECOND
+            + i128::from(self.millisecond) * NS_PER_MILLISECOND
+            + i128::from(self.microsecond) * NS_PER_MICROSECOND
+            + i128::from(self.nanosecond)
+    }
+
+    /// Abstract operation `BalanceTime ( hour, minute, second, millisecond, microsecond, nanosecond )`
+    ///
+    /// Creates a time from a number of nanoseconds since midnight, which may be negative or
+    /// longer than a day. Returns the number of days that overflowed, and the time.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-balancetime
+    pub(crate) fn from_nanoseconds(nanoseconds: i128) -> (i64, Self) {
+        let days = nanoseconds.div_euclid(NS_PER_DAY);
+        let nanoseconds = nanoseconds.rem_euclid(NS_PER_DAY);
+        (
+            days as i64,
+            Self {
+                hour: (nanoseconds / NS_PER_HOUR) as u8,
+                minute: (nanoseconds % NS_PER_HOUR / NS_PER_MINUTE) as u8,
+                second: (nanoseconds % NS_PER_MINUTE / NS_PER_SECOND) as u8,
+                millisecond: (nanoseconds % NS_PER_SECOND / NS_PER_MILLISECOND) as u16,
+                microsecond: (nanoseconds % NS_PER_MILLISECOND / NS_PER_MICROSECOND) as u16,
+                nanosecond: (nanoseconds % NS_PER_MICROSECOND) as u16,
+            },
+        )
+    }
+
+    /// Abstract operation `RoundTime ( hour, minute, second, millisecond, microsecond, nanosecond, increment, unit, roundingMode )`
+    ///
+    /// Rounds the time to `increment` times `unit`, which must be a time unit or `day`. Returns
+    /// the number of days that overflowed, and the rounded time.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundtime
+    pub(crate) fn round(self, increment: u64, unit: Unit, mode: RoundingMode) -> (i64, Self) {
+        let rounded = mode.round(
+            self.to_nanoseconds(),
+            unit.nanoseconds() * i128::from(increment),
+        );
+        Self::from_nanoseconds(rounded)
+    }
+
+    /// Formats the time as `HH:MM:SS.fffffffff`, with the given precision for the seconds.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-formattimestring
+    pub(crate) fn to_string_with_precision(self, precision: Precision) -> String {
+        let mut result = format!("{:02}:{:02}", self.hour, self.minute);
+        if precision != Precision::Minute {
+            let _ = write!(result, ":{:02}", self.second);
+            let fraction = u32::from(self.millisecond) * 1_000_000
+                + u32::from(self.microsecond) * 1_000
+                + u32::from(self.nanosecond);
+            result.push_str(&format_fraction(fraction, precision));
+        }
+        result
+    }
+}
+
+/// Formats the nanoseconds `fraction` of a second as `.fffffffff`, with the given precision.
+///
+/// An empty string is returned if the precision is automatic and the fraction is zero, or if
+/// the precision is zero digits.
+pub(crate) fn format_fraction(fraction: u32, precision: Precision) -> String {
+    let digits = format!("{fraction:09}");
+    match precision {
+        Precision::Minute | Precision::Digits(0) => String::new(),
+        Precision::Auto => {
+            let digits = digits.trim_end_matches('0');
+            if digits.is_empty() {
+                String::new()
+            } else {
+                format!(".{digits}")
+            }
+        }
+        Precision::Digits(count) => format!(".{}", &digits[..usize::from(count)]),
+    }
+}
+
+impl fmt::Display for IsoTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_with_precision(Precision::Auto))
+    }
+}
+
+/// A date and a wall-clock time of the ISO 8601 calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoDateTime {
+    pub(crate) date: IsoDate,
+    pub(crate) time: IsoTime,
+}
+
+impl IsoDateTime {
+    /// Creates a new `IsoDateTime`.
+    pub(crate) const fn new(date: IsoDate, time: IsoTime) -> Self {
+        Self { date, time }
+    }
+
+    /// Abstract operation `GetUTCEpochNanoseconds ( year, month, day, hour, minute, second, millisecond, microsecond, nanosecond )`
+    ///
+    /// Gets the number of nanoseconds from the epoch to the date and time, interpreted as UTC.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getutcepochnanoseconds
+    pub(crate) fn to_epoch_nanoseconds(self) -> i128 {
+        i128::from(self.date.epoch_days()) * NS_PER_DAY + self.time.to_nanoseconds()
+    }
+
+    /// Abstract operation `GetISOPartsFromEpoch ( epochNanoseconds )`
+    ///
+    /// Gets the UTC date and time of the number of nanoseconds `epoch_nanoseconds` from the
+    /// epoch.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getisopartsfromepoch
+    pub(crate) fn from_epoch_nanoseconds(epoch_nanoseconds: i128) -> Self {
+        let (days, time) = IsoTime::from_nanoseconds(epoch_nanoseconds);
+        Self {
+            date: IsoDate::from_epoch_days(days),
+            time,
+        }
+    }
+
+    /// Abstract operation `ISODateTimeWithinLimits ( year, month, day, hour, minute, second, millisecond, microsecond, nanosecond )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isodatetimewithinlimits
+    pub(crate) fn within_limits(self) -> bool {
+        let epoch_nanoseconds = self.to_epoch_nanoseconds();
+        epoch_nanoseconds > NS_MIN_INSTANT - NS_PER_DAY
+            && epoch_nanoseconds < NS_MAX_INSTANT + NS_PER_DAY
+    }
+
+    /// Throws a `RangeError` if the date and time are outside of the range of a
+    /// `PlainDateTime`.
+    pub(crate) fn check_limits(self) -> JsResult<Self> {
+        if self.within_limits() {
+            Ok(self)
+        } else {
+            Err(JsNativeError::range()
+                .with_message("the date and time are outside of the supported range")
+                .into())
+        }
+    }
+
+    /// Abstract operation `AddDateTime ( year, month, day, hour, minute, second, millisecond, microsecond, nanosecond, calendar, years, months, weeks, days, norm, options )`
+    ///
+    /// Adds a date duration and `nanoseconds` to the date and time.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddatetime
+    pub(crate) fn add(
+        self,
+        duration: DateDuration,
+        nanoseconds: i128,
+        overflow: Overflow,
+    ) -> JsResult<Self> {
+        // 1. Let timeResult be AddTime(hour, minute, second, millisecond, microsecond, nanosecond, norm).
+        let (days, time) = IsoTime::from_nanoseconds(self.time.to_nanoseconds() + nanoseconds);
+
+        // 2. Let datePart be ! CreateTemporalDate(year, month, day, calendar).
+        // 3. Let dateDuration be ? CreateTemporalDuration(years, months, weeks, days + timeResult.[[Days]], 0, 0, 0, 0, 0, 0).
+        // 4. Let addedDate be ? AddDate(calendarRec, datePart, dateDuration, options).
+        let date = self.date.add(
+            duration.years,
+            duration.months,
+            duration.weeks * 7 + duration.days + days,
+            overflow,
+        )?;
+
+        // 5. Return ISO Date-Time Record { [[Year]]: addedDate.[[ISOYear]], [[Month]]: addedDate.[[ISOMonth]], [[Day]]: addedDate.[[ISODay]], [[Hour]]: timeResult.[[Hour]], ... }.
+        Ok(Self { date, time })
+    }
+
+    /// Abstract operation `RoundISODateTime ( year, month, day, hour, minute, second, millisecond, microsecond, nanosecond, increment, unit, roundingMode )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundisodatetime
+    pub(crate) fn round(self, increment: u64, unit: Unit, mode: RoundingMode) -> Self {
+        let (days, time) = self.time.round(increment, unit, mode);
+        Self {
+            date: IsoDate::from_epoch_days(self.date.epoch_days() + days),
+            time,
+        }
+    }
+
+    /// Abstract operation `DifferenceISODateTime ( y1, mon1, d1, h1, min1, s1, ms1, mus1, ns1, y2, mon2, d2, h2, min2, s2, ms2, mus2, ns2, calendarRec, largestUnit, options )`
+    ///
+    /// Gets the duration from this date and time to `other`, as a date duration and a number of
+    /// nanoseconds smaller than a day, which both have the same sign.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceisodatetime
+    pub(crate) fn difference(self, other: Self, largest_unit: Unit) -> (DateDuration, i128) {
+        // 1. Let timeDuration be DifferenceTime(h1, min1, s1, ms1, mus1, ns1, h2, min2, s2, ms2, mus2, ns2).
+        let mut time = other.time.to_nanoseconds() - self.time.to_nanoseconds();
+
+        // 2. Let timeSign be NormalizedTimeDurationSign(timeDuration).
+        // 3. Let dateSign be CompareISODate(y2, mon2, d2, y1, mon1, d1).
+        let time_sign = time.signum();
+        let date_sign = match other.date.cmp(&self.date) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        };
+
+        // 4. Let adjustedDate be CreateISODateRecord(y2, mon2, d2).
+        let mut adjusted_date = other.date;
+
+        // 5. If timeSign = -dateSign, then
+        if time_sign == -date_sign {
+            // a. Set adjustedDate to BalanceISODate(adjustedDate.[[Year]], adjustedDate.[[Month]], adjustedDate.[[Day]] + timeSign).
+            adjusted_date = IsoDate::from_epoch_days(adjusted_date.epoch_days() + time_sign as i64);
+            // b. Set timeDuration to ? Add24HourDaysToNormalizedTimeDuration(timeDuration, -timeSign).
+            time -= time_sign * NS_PER_DAY;
+        }
+
+        // 6-10. Let dateDifference be ? DifferenceDate(calendarRec, date1, date2, untilOptions).
+        let date_largest_unit = largest_unit.max(Unit::Day);
+        let mut date = self.date.difference(adjusted_date, date_largest_unit);
+
+        // 11. If largestUnit is not dateLargestUnit, then
+        if largest_unit < Unit::Day {
+            // a. Set timeDuration to ? Add24HourDaysToNormalizedTimeDuration(timeDuration, days).
+            // b. Set days to 0.
+            time += i128::from(date.days) * NS_PER_DAY;
+            date.days = 0;
+        }
+
+        // 12. Return ? CreateNormalizedDurationRecord(dateDifference.[[Years]], dateDifference.[[Months]], dateDifference.[[Weeks]], days, timeDuration).
+        (date, time)
+    }
+}
+
+impl fmt::Display for IsoDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
@@ -0,0 +1,338 @@
+//! This module implements the global `Temporal` object.
+//!
+//! `Temporal` is a namespace object with a modern date and time API: exact times (`Instant`),
+//! wall-clock dates and times (`PlainDate`, `PlainTime` and `PlainDateTime`), exact times in a
+//! time zone (`ZonedDateTime`), durations, time zones and calendars.
+//!
+//! Only the ISO 8601 calendar is supported, and `PlainYearMonth` and `PlainMonthDay` are not
+//! implemented yet. Named time zones are resolved by the [`TimeZoneProvider`] of the context.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal
+
+pub mod calendar;
+pub mod duration;
+pub mod instant;
+pub mod now;
+pub mod plain_date;
+pub mod plain_date_time;
+pub mod plain_time;
+pub mod time_zone;
+pub mod zoned_date_time;
+
+mod difference;
+mod fields;
+mod iso;
+mod options;
+mod parser;
+mod provider;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::{
+    calendar::Calendar,
+    duration::Duration,
+    instant::Instant,
+    plain_date::PlainDate,
+    plain_date_time::PlainDateTime,
+    plain_time::PlainTime,
+    provider::{TimeZoneProvider, UtcTimeZoneProvider, ZoneInfoTimeZoneProvider},
+    time_zone::TimeZone,
+    zoned_date_time::ZonedDateTime,
+};
+
+use self::{iso::IsoDateTime, now::Now};
+use crate::{
+    bigint::RawBigInt,
+    builtins::BuiltIn,
+    error::JsNativeError,
+    object::{ConstructorBuilder, FunctionBuilder, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsBigInt, JsResult, JsValue,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use num_traits::ToPrimitive;
+use tap::{Conv, Pipe};
+
+/// JavaScript `Temporal` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Temporal;
+
+impl BuiltIn for Temporal {
+    const NAME: &'static str = "Temporal";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let instant = Instant::init(context);
+        let plain_date = PlainDate::init(context);
+        let plain_time = PlainTime::init(context);
+        let plain_date_time = PlainDateTime::init(context);
+        let zoned_date_time = ZonedDateTime::init(context);
+        let duration = Duration::init(context);
+        let time_zone = TimeZone::init(context);
+        let calendar = Calendar::init(context);
+        let now = Now::init(context);
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        ObjectInitializer::new(context)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property("Instant", instant, attribute)
+            .property("PlainDate", plain_date, attribute)
+            .property("PlainTime", plain_time, attribute)
+            .property("PlainDateTime", plain_date_time, attribute)
+            .property("ZonedDateTime", zoned_date_time, attribute)
+            .property("Duration", duration, attribute)
+            .property("TimeZone", time_zone, attribute)
+            .property("Calendar", calendar, attribute)
+            .property("Now", now, attribute)
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+/// Abstract operation `ToIntegerWithTruncation ( argument )`
+///
+/// Converts `value` to an integral number, throwing a `RangeError` if it is not finite.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-tointegerwithtruncation
+pub(crate) fn to_integer_with_truncation(value: &JsValue, context: &mut Context) -> JsResult<f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = value.to_number(context)?;
+
+    // 2. If number is NaN, +∞𝔽 or -∞𝔽, throw a RangeError exception.
+    if !number.is_finite() {
+        return Err(JsNativeError::range()
+            .with_message("expected a finite number")
+            .into());
+    }
+
+    // 3. Return truncate(ℝ(number)).
+    Ok(number.trunc() + 0.0)
+}
+
+/// Abstract operation `ToPositiveIntegerWithTruncation ( argument )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-topositiveintegerwithtruncation
+pub(crate) fn to_positive_integer_with_truncation(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<f64> {
+    // 1. Let integer be ? ToIntegerWithTruncation(argument).
+    let integer = to_integer_with_truncation(value, context)?;
+
+    // 2. If integer ≤ 0, throw a RangeError exception.
+    if integer <= 0.0 {
+        return Err(JsNativeError::range()
+            .with_message("expected a positive integer")
+            .into());
+    }
+
+    // 3. Return integer.
+    Ok(integer)
+}
+
+/// Abstract operation `ToIntegerIfIntegral ( argument )`
+///
+/// Converts `value` to a number, throwing a `RangeError` if it is not an integral number.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-tointegerifintegral
+#[allow(clippy::float_cmp)]
+pub(crate) fn to_integer_if_integral(value: &JsValue, context: &mut Context) -> JsResult<f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = value.to_number(context)?;
+
+    // 2. If IsIntegralNumber(number) is false, throw a RangeError exception.
+    if !number.is_finite() || number.trunc() != number {
+        return Err(JsNativeError::range()
+            .with_message(format!("{number} is not an integral number"))
+            .into());
+    }
+
+    // 3. Return ℝ(number).
+    Ok(number + 0.0)
+}
+
+/// Converts a `BigInt` to a number of nanoseconds, or returns `None` if it doesn't fit.
+pub(crate) fn bigint_to_i128(bigint: &JsBigInt) -> Option<i128> {
+    bigint.as_inner().to_i128()
+}
+
+/// Converts a number of nanoseconds to a `BigInt`.
+pub(crate) fn i128_to_bigint(value: i128) -> JsBigInt {
+    JsBigInt::new(RawBigInt::from(value))
+}
+
+/// Abstract operation `SystemUTCEpochNanoseconds ( )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-systemutcepochnanoseconds
+pub(crate) fn system_epoch_nanoseconds() -> i128 {
+    let now = chrono::Utc::now();
+    i128::from(now.timestamp()) * 1_000_000_000 + i128::from(now.timestamp_subsec_nanos())
+}
+
+/// A component of a date or of a wall-clock time, which the `Temporal` objects expose as
+/// getters, and `Temporal.Calendar` as methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub(crate) enum Component {
+    Year,
+    Month,
+    MonthCode,
+    Day,
+    DayOfWeek,
+    DayOfYear,
+    WeekOfYear,
+    DaysInWeek,
+    DaysInMonth,
+    DaysInYear,
+    MonthsInYear,
+    InLeapYear,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+// Safety: `Component` does not contain any objects which need to be traced.
+unsafe impl Trace for Component {
+    unsafe_empty_trace!();
+}
+
+impl Component {
+    /// The components of a date.
+    pub(crate) const DATE: [Self; 12] = [
+        Self::Year,
+        Self::Month,
+        Self::MonthCode,
+        Self::Day,
+        Self::DayOfWeek,
+        Self::DayOfYear,
+        Self::WeekOfYear,
+        Self::DaysInWeek,
+        Self::DaysInMonth,
+        Self::DaysInYear,
+        Self::MonthsInYear,
+        Self::InLeapYear,
+    ];
+
+    /// The components of a wall-clock time.
+    pub(crate) const TIME: [Self; 6] = [
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+        Self::Millisecond,
+        Self::Microsecond,
+        Self::Nanosecond,
+    ];
+
+    /// Gets the name of the getter of the component.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::MonthCode => "monthCode",
+            Self::Day => "day",
+            Self::DayOfWeek => "dayOfWeek",
+            Self::DayOfYear => "dayOfYear",
+            Self::WeekOfYear => "weekOfYear",
+            Self::DaysInWeek => "daysInWeek",
+            Self::DaysInMonth => "daysInMonth",
+            Self::DaysInYear => "daysInYear",
+            Self::MonthsInYear => "monthsInYear",
+            Self::InLeapYear => "inLeapYear",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+            Self::Millisecond => "millisecond",
+            Self::Microsecond => "microsecond",
+            Self::Nanosecond => "nanosecond",
+        }
+    }
+
+    /// Gets the value of the component in the ISO 8601 calendar.
+    pub(crate) fn get(self, date_time: IsoDateTime) -> JsValue {
+        let IsoDateTime { date, time } = date_time;
+        match self {
+            Self::Year => date.year.into(),
+            Self::Month => date.month.into(),
+            Self::MonthCode => format!("M{:02}", date.month).into(),
+            Self::Day => date.day.into(),
+            Self::DayOfWeek => date.day_of_week().into(),
+            Self::DayOfYear => date.day_of_year().into(),
+            Self::WeekOfYear => date.week_of_year().0.into(),
+            Self::DaysInWeek => 7.into(),
+            Self::DaysInMonth => iso::days_in_month(date.year, date.month).into(),
+            Self::DaysInYear => iso::days_in_year(date.year).into(),
+            Self::MonthsInYear => 12.into(),
+            Self::InLeapYear => iso::is_leap_year(date.year).into(),
+            Self::Hour => time.hour.into(),
+            Self::Minute => time.minute.into(),
+            Self::Second => time.second.into(),
+            Self::Millisecond => time.millisecond.into(),
+            Self::Microsecond => time.microsecond.into(),
+            Self::Nanosecond => time.nanosecond.into(),
+        }
+    }
+
+    /// Adds a getter for each of `components` to the prototype of a `Temporal` constructor,
+    /// which reads the component from the date and time that `get` returns for the `this`
+    /// value.
+    pub(crate) fn add_getters(
+        builder: &mut ConstructorBuilder<'_>,
+        components: &[Self],
+        get: fn(&JsValue, &mut Context) -> JsResult<IsoDateTime>,
+    ) {
+        for &component in components {
+            let getter =
+                FunctionBuilder::closure_with_captures(
+                    builder.context(),
+                    move |this, _, component: &mut Self, context| {
+                        Ok(component.get(get(this, context)?))
+                    },
+                    component,
+                )
+                .name(format!("get {}", component.name()))
+                .build();
+            builder.accessor(
+                component.name(),
+                Some(getter),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            );
+        }
+    }
+}
+
+/// Throws the `TypeError` of the `valueOf` methods of the `Temporal` objects, which cannot be
+/// compared with relational operators.
+pub(crate) fn value_of(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Err(JsNativeError::typ()
+        .with_message("Temporal objects cannot be converted to primitives, use compare or equals")
+        .into())
+}
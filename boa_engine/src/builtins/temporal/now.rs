@@ -0,0 +1,259 @@
+//! This module implements the `Temporal.Now` object.
+//!
+//! `Temporal.Now` is a namespace object with functions that get the current exact time, and the
+//! current wall-clock date and time in the time zone of the host or in a given time zone.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-now-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now
+
+use super::{
+    calendar::Calendar, instant::Instant, iso::IsoDateTime, plain_date::PlainDate,
+    plain_date_time::PlainDateTime, plain_time::PlainTime, system_epoch_nanoseconds,
+    time_zone::TimeZone, zoned_date_time::ZonedDateTime,
+};
+use crate::{
+    builtins::JsArgs,
+    object::{JsObject, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::Conv;
+
+/// JavaScript `Temporal.Now` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Now;
+
+impl Now {
+    const NAME: &'static str = "Temporal.Now";
+
+    pub(super) fn init(context: &mut Context) -> JsValue {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ObjectInitializer::new(context)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .function(Self::instant, "instant", 0)
+            .function(Self::time_zone_id, "timeZoneId", 0)
+            .function(Self::time_zone, "timeZone", 0)
+            .function(Self::zoned_date_time, "zonedDateTime", 1)
+            .function(Self::zoned_date_time_iso, "zonedDateTimeISO", 0)
+            .function(Self::plain_date_time, "plainDateTime", 1)
+            .function(Self::plain_date_time_iso, "plainDateTimeISO", 0)
+            .function(Self::plain_date, "plainDate", 1)
+            .function(Self::plain_date_iso, "plainDateISO", 0)
+            .function(Self::plain_time_iso, "plainTimeISO", 0)
+            .build()
+            .conv::<JsValue>()
+    }
+
+    /// Abstract operation `SystemTimeZoneIdentifier ( )`
+    ///
+    /// Gets the time zone of the host from the time zone provider of the context, or `UTC` if
+    /// the provider doesn't know it.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-systemtimezoneidentifier
+    fn system_time_zone(context: &mut Context) -> TimeZone {
+        context
+            .time_zone_provider()
+            .system_time_zone_identifier()
+            .and_then(|identifier| TimeZone::from_name(&identifier, context).ok())
+            .unwrap_or_else(TimeZone::utc)
+    }
+
+    /// Abstract operation `SystemDateTime ( temporalTimeZoneLike )`
+    ///
+    /// Gets the current wall-clock date and time in `time_zone_like`, or in the time zone of the
+    /// host if it is `undefined`.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-systemdatetime
+    fn system_date_time(time_zone_like: &JsValue, context: &mut Context) -> JsResult<IsoDateTime> {
+        // 1. If temporalTimeZoneLike is undefined, then
+        //     a. Let timeZone be SystemTimeZoneIdentifier().
+        // 2. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        let time_zone = if time_zone_like.is_undefined() {
+            Self::system_time_zone(context)
+        } else {
+            TimeZone::of(&TimeZone::to_temporal_time_zone(time_zone_like, context)?)
+        };
+
+        // 3. Let epochNs be SystemUTCEpochNanoseconds().
+        // 4. Return GetISODateTimeFor(timeZone, epochNs).
+        Ok(time_zone.date_time_for(system_epoch_nanoseconds(), context))
+    }
+
+    /// Abstract operation `SystemZonedDateTime ( temporalTimeZoneLike, calendar )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-systemzoneddatetime
+    fn system_zoned_date_time(
+        time_zone_like: &JsValue,
+        calendar: JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If temporalTimeZoneLike is undefined, then
+        //     a. Let timeZone be SystemTimeZoneIdentifier().
+        // 2. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        let time_zone = if time_zone_like.is_undefined() {
+            Self::system_time_zone(context).create(None, context)?
+        } else {
+            TimeZone::to_temporal_time_zone(time_zone_like, context)?
+        };
+
+        // 3. Let ns be SystemUTCEpochNanoseconds().
+        // 4. Return ! CreateTemporalZonedDateTime(ns, timeZone, calendar).
+        ZonedDateTime::new(system_epoch_nanoseconds(), time_zone, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Now.instant ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.instant
+    fn instant(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let ns be SystemUTCEpochNanoseconds().
+        // 2. Return ! CreateTemporalInstant(ns).
+        Instant::create(system_epoch_nanoseconds(), None, context).map(Into::into)
+    }
+
+    /// `Temporal.Now.timeZoneId ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.timezoneid
+    #[allow(clippy::unnecessary_wraps)]
+    fn time_zone_id(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return SystemTimeZoneIdentifier().
+        Ok(Self::system_time_zone(context).identifier().into())
+    }
+
+    /// `Temporal.Now.timeZone ( )`
+    ///
+    /// Returns the time zone of the host as a `Temporal.TimeZone` object.
+    fn time_zone(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::system_time_zone(context)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Now.zonedDateTime ( calendarLike [ , temporalTimeZoneLike ] )`
+    ///
+    /// Returns the current exact time in a time zone, with the calendar `calendarLike`.
+    fn zoned_date_time(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let calendar = Calendar::to_temporal_calendar(args.get_or_undefined(0), context)?;
+        Self::system_zoned_date_time(args.get_or_undefined(1), calendar, context)
+    }
+
+    /// `Temporal.Now.zonedDateTimeISO ( [ temporalTimeZoneLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.zoneddatetimeiso
+    fn zoned_date_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? SystemZonedDateTime(temporalTimeZoneLike, "iso8601").
+        let calendar = Calendar::create(context);
+        Self::system_zoned_date_time(args.get_or_undefined(0), calendar, context)
+    }
+
+    /// `Temporal.Now.plainDateTime ( calendarLike [ , temporalTimeZoneLike ] )`
+    ///
+    /// Returns the current wall-clock date and time in a time zone, with the calendar
+    /// `calendarLike`.
+    fn plain_date_time(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let calendar = Calendar::to_temporal_calendar(args.get_or_undefined(0), context)?;
+        let date_time = Self::system_date_time(args.get_or_undefined(1), context)?;
+        PlainDateTime::new(date_time, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Now.plainDateTimeISO ( [ temporalTimeZoneLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.plaindatetimeiso
+    fn plain_date_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        // 2. Return ! CreateTemporalDateTime(isoDateTime, "iso8601").
+        let date_time = Self::system_date_time(args.get_or_undefined(0), context)?;
+        let calendar = Calendar::create(context);
+        PlainDateTime::new(date_time, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Now.plainDate ( calendarLike [ , temporalTimeZoneLike ] )`
+    ///
+    /// Returns the current wall-clock date in a time zone, with the calendar `calendarLike`.
+    fn plain_date(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let calendar = Calendar::to_temporal_calendar(args.get_or_undefined(0), context)?;
+        let date_time = Self::system_date_time(args.get_or_undefined(1), context)?;
+        PlainDate::new(date_time.date, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Now.plainDateISO ( [ temporalTimeZoneLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.plaindateiso
+    fn plain_date_iso(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        // 2. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], "iso8601").
+        let date_time = Self::system_date_time(args.get_or_undefined(0), context)?;
+        let calendar = Calendar::create(context);
+        PlainDate::new(date_time.date, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.Now.plainTimeISO ( [ temporalTimeZoneLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.plaintimeiso
+    fn plain_time_iso(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        // 2. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+        let date_time = Self::system_date_time(args.get_or_undefined(0), context)?;
+        let calendar = Calendar::create(context);
+        PlainTime::new(date_time.time, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+}
@@ -0,0 +1,904 @@
+//! The options records of the `Temporal` builtins, and the abstract operations that read them
+//! from the options objects passed by the user.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-abstract-ops
+
+use crate::{
+    error::JsNativeError,
+    object::{JsObject, ObjectData},
+    value::JsVariant,
+    Context, JsResult, JsValue,
+};
+use std::fmt;
+
+/// The number of nanoseconds in a microsecond.
+pub(crate) const NS_PER_MICROSECOND: i128 = 1_000;
+/// The number of nanoseconds in a millisecond.
+pub(crate) const NS_PER_MILLISECOND: i128 = 1_000_000;
+/// The number of nanoseconds in a second.
+pub(crate) const NS_PER_SECOND: i128 = 1_000_000_000;
+/// The number of nanoseconds in a minute.
+pub(crate) const NS_PER_MINUTE: i128 = 60 * NS_PER_SECOND;
+/// The number of nanoseconds in an hour.
+pub(crate) const NS_PER_HOUR: i128 = 60 * NS_PER_MINUTE;
+/// The number of nanoseconds in a 24-hour day.
+pub(crate) const NS_PER_DAY: i128 = 24 * NS_PER_HOUR;
+
+/// A unit of time, from the smallest to the largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Unit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Unit {
+    /// Parses the singular or plural name of a unit.
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "year" | "years" => Self::Year,
+            "month" | "months" => Self::Month,
+            "week" | "weeks" => Self::Week,
+            "day" | "days" => Self::Day,
+            "hour" | "hours" => Self::Hour,
+            "minute" | "minutes" => Self::Minute,
+            "second" | "seconds" => Self::Second,
+            "millisecond" | "milliseconds" => Self::Millisecond,
+            "microsecond" | "microseconds" => Self::Microsecond,
+            "nanosecond" | "nanoseconds" => Self::Nanosecond,
+            _ => return None,
+        })
+    }
+
+    /// Gets the singular name of the unit.
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Week => "week",
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+            Self::Millisecond => "millisecond",
+            Self::Microsecond => "microsecond",
+            Self::Nanosecond => "nanosecond",
+        }
+    }
+
+    /// Returns `true` if the unit is `year`, `month`, `week` or `day`.
+    pub(crate) fn is_date_unit(self) -> bool {
+        self >= Self::Day
+    }
+
+    /// Returns `true` if the unit is `year`, `month` or `week`, whose length depends on the
+    /// calendar.
+    pub(crate) fn is_calendar_unit(self) -> bool {
+        self >= Self::Week
+    }
+
+    /// Gets the length of the unit in nanoseconds, counting days as 24 hours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the unit is a calendar unit, which have no fixed length.
+    pub(crate) fn nanoseconds(self) -> i128 {
+        match self {
+            Self::Nanosecond => 1,
+            Self::Microsecond => NS_PER_MICROSECOND,
+            Self::Millisecond => NS_PER_MILLISECOND,
+            Self::Second => NS_PER_SECOND,
+            Self::Minute => NS_PER_MINUTE,
+            Self::Hour => NS_PER_HOUR,
+            Self::Day => NS_PER_DAY,
+            Self::Week | Self::Month | Self::Year => {
+                unreachable!("calendar units have no fixed length")
+            }
+        }
+    }
+
+    /// Gets the maximum rounding increment of the unit, which is the number of units in the next
+    /// larger unit, or `None` if the increment is unbounded.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-maximumtemporaldurationroundingincrement
+    pub(crate) const fn maximum_rounding_increment(self) -> Option<u64> {
+        match self {
+            Self::Year | Self::Month | Self::Week | Self::Day => None,
+            Self::Hour => Some(24),
+            Self::Minute | Self::Second => Some(60),
+            Self::Millisecond | Self::Microsecond | Self::Nanosecond => Some(1000),
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The group of units accepted by an option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitGroup {
+    Date,
+    Time,
+    DateTime,
+}
+
+/// The value of a unit option, which can also be `"auto"` if the option allows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitOption {
+    Auto,
+    Unit(Unit),
+}
+
+impl UnitOption {
+    /// Gets the unit of the option, or `None` if it is `"auto"`.
+    pub(crate) const fn unit(self) -> Option<Unit> {
+        match self {
+            Self::Auto => None,
+            Self::Unit(unit) => Some(unit),
+        }
+    }
+}
+
+/// A string option whose values are parsed from a fixed list of names.
+pub(crate) trait TemporalOption: Sized {
+    /// Parses the value of the option.
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+/// The `roundingMode` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoundingMode {
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    HalfExpand,
+    HalfTrunc,
+    HalfEven,
+}
+
+impl TemporalOption for RoundingMode {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "ceil" => Self::Ceil,
+            "floor" => Self::Floor,
+            "expand" => Self::Expand,
+            "trunc" => Self::Trunc,
+            "halfCeil" => Self::HalfCeil,
+            "halfFloor" => Self::HalfFloor,
+            "halfExpand" => Self::HalfExpand,
+            "halfTrunc" => Self::HalfTrunc,
+            "halfEven" => Self::HalfEven,
+            _ => return None,
+        })
+    }
+}
+
+impl RoundingMode {
+    /// Abstract operation `NegateTemporalRoundingMode ( roundingMode )`
+    ///
+    /// Gets the rounding mode that rounds a negated value in the same direction, which is used
+    /// by the `since` methods.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-negatetemporalroundingmode
+    pub(crate) const fn negate(self) -> Self {
+        match self {
+            Self::Ceil => Self::Floor,
+            Self::Floor => Self::Ceil,
+            Self::HalfCeil => Self::HalfFloor,
+            Self::HalfFloor => Self::HalfCeil,
+            mode => mode,
+        }
+    }
+
+    /// Gets the rounding mode that rounds negative values like positive ones, which is used to
+    /// round exact times in `RoundNumberToIncrementAsIfPositive`.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrementasifpositive
+    pub(crate) const fn as_if_positive(self) -> Self {
+        match self {
+            Self::Trunc => Self::Floor,
+            Self::Expand => Self::Ceil,
+            Self::HalfTrunc => Self::HalfFloor,
+            Self::HalfExpand => Self::HalfCeil,
+            mode => mode,
+        }
+    }
+
+    /// Abstract operation `RoundNumberToIncrement ( x, increment, roundingMode )`
+    ///
+    /// Rounds the integer `x` to a multiple of `increment`, without any loss of precision.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrement
+    pub(crate) fn round(self, x: i128, increment: i128) -> i128 {
+        debug_assert!(increment > 0);
+
+        let quotient = x.div_euclid(increment);
+        let remainder = x.rem_euclid(increment);
+        if remainder == 0 {
+            return x;
+        }
+
+        let floor = quotient * increment;
+        let ceil = floor + increment;
+        let negative = x < 0;
+        let (toward_zero, away_from_zero) = if negative {
+            (ceil, floor)
+        } else {
+            (floor, ceil)
+        };
+
+        match self {
+            Self::Ceil => ceil,
+            Self::Floor => floor,
+            Self::Trunc => toward_zero,
+            Self::Expand => away_from_zero,
+            _ => match (remainder * 2).cmp(&increment) {
+                std::cmp::Ordering::Less => floor,
+                std::cmp::Ordering::Greater => ceil,
+                std::cmp::Ordering::Equal => match self {
+                    Self::HalfCeil => ceil,
+                    Self::HalfFloor => floor,
+                    Self::HalfExpand => away_from_zero,
+                    Self::HalfTrunc => toward_zero,
+                    _ => {
+                        if quotient % 2 == 0 {
+                            floor
+                        } else {
+                            ceil
+                        }
+                    }
+                },
+            },
+        }
+    }
+}
+
+/// The `overflow` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Overflow {
+    Constrain,
+    Reject,
+}
+
+impl TemporalOption for Overflow {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "constrain" => Some(Self::Constrain),
+            "reject" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// The `disambiguation` option, which chooses an exact time for a wall-clock time that is
+/// ambiguous or skipped in a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Disambiguation {
+    Compatible,
+    Earlier,
+    Later,
+    Reject,
+}
+
+impl TemporalOption for Disambiguation {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "compatible" => Self::Compatible,
+            "earlier" => Self::Earlier,
+            "later" => Self::Later,
+            "reject" => Self::Reject,
+            _ => return None,
+        })
+    }
+}
+
+/// The `offset` option of `ZonedDateTime`, which tells how to resolve conflicts between an
+/// offset and a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetOption {
+    Prefer,
+    Use,
+    Ignore,
+    Reject,
+}
+
+impl TemporalOption for OffsetOption {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "prefer" => Self::Prefer,
+            "use" => Self::Use,
+            "ignore" => Self::Ignore,
+            "reject" => Self::Reject,
+            _ => return None,
+        })
+    }
+}
+
+/// The `calendarName` option of the `toString` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShowCalendar {
+    Auto,
+    Always,
+    Never,
+    Critical,
+}
+
+impl TemporalOption for ShowCalendar {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "auto" => Self::Auto,
+            "always" => Self::Always,
+            "never" => Self::Never,
+            "critical" => Self::Critical,
+            _ => return None,
+        })
+    }
+}
+
+/// The `timeZoneName` option of `ZonedDateTime.prototype.toString`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShowTimeZone {
+    Auto,
+    Never,
+    Critical,
+}
+
+impl TemporalOption for ShowTimeZone {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "auto" => Self::Auto,
+            "never" => Self::Never,
+            "critical" => Self::Critical,
+            _ => return None,
+        })
+    }
+}
+
+/// The `offset` option of `ZonedDateTime.prototype.toString`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShowOffset {
+    Auto,
+    Never,
+}
+
+impl TemporalOption for ShowOffset {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "auto" => Some(Self::Auto),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+
+/// The number of digits of the fractional part of the seconds printed by `toString`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Precision {
+    /// Print as many digits as needed, without trailing zeroes.
+    Auto,
+    /// Do not print the seconds.
+    Minute,
+    /// Print exactly this number of digits.
+    Digits(u8),
+}
+
+/// The precision of the seconds of a `toString` method, and the rounding that must be applied
+/// to the value before printing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ToStringRounding {
+    pub(crate) precision: Precision,
+    pub(crate) unit: Unit,
+    pub(crate) increment: u64,
+}
+
+/// Abstract operation `GetOptionsObject ( options )`
+///
+/// Returns `None` if `options` is undefined, and throws a `TypeError` if it is not an object.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-getoptionsobject
+pub(crate) fn get_options_object(options: &JsValue) -> JsResult<Option<JsObject>> {
+    match options.variant() {
+        JsVariant::Undefined => Ok(None),
+        JsVariant::Object(object) => Ok(Some(object.clone())),
+        _ => Err(JsNativeError::typ()
+            .with_message("options must be an object or undefined")
+            .into()),
+    }
+}
+
+/// Abstract operation `GetOption ( options, property, "string", values, default )`
+///
+/// Reads the string option `property` of `options`, returning `None` if it is undefined and
+/// throwing a `RangeError` if it is not one of the values of `T`.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-getoption
+pub(crate) fn get_option<T: TemporalOption>(
+    options: Option<&JsObject>,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<T>> {
+    let options = if let Some(options) = options {
+        options
+    } else {
+        return Ok(None);
+    };
+
+    let value = options.get(property, context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    let value = value.to_string(context)?.to_std_string_escaped();
+    T::from_name(&value).map(Some).ok_or_else(|| {
+        JsNativeError::range()
+            .with_message(format!(
+                "'{value}' is not a valid value for option {property}"
+            ))
+            .into()
+    })
+}
+
+/// Abstract operation `GetTemporalUnit ( normalizedOptions, key, unitGroup, default [ , extraValues ] )`
+///
+/// Reads the unit option `key` of `options`, which can be singular or plural, and must be part
+/// of `group`. `"auto"` is only accepted if `allow_auto` is `true`.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalunit
+pub(crate) fn get_temporal_unit(
+    options: Option<&JsObject>,
+    key: &str,
+    group: UnitGroup,
+    allow_auto: bool,
+    context: &mut Context,
+) -> JsResult<Option<UnitOption>> {
+    let options = if let Some(options) = options {
+        options
+    } else {
+        return Ok(None);
+    };
+
+    let value = options.get(key, context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    let value = value.to_string(context)?.to_std_string_escaped();
+    if allow_auto && value == "auto" {
+        return Ok(Some(UnitOption::Auto));
+    }
+
+    match Unit::from_name(&value) {
+        Some(unit)
+            if match group {
+                UnitGroup::Date => unit.is_date_unit(),
+                UnitGroup::Time => !unit.is_date_unit(),
+                UnitGroup::DateTime => true,
+            } =>
+        {
+            Ok(Some(UnitOption::Unit(unit)))
+        }
+        _ => Err(JsNativeError::range()
+            .with_message(format!("'{value}' is not a valid value for option {key}"))
+            .into()),
+    }
+}
+
+/// Abstract operation `ToTemporalOverflow ( options )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaloverflow
+pub(crate) fn to_temporal_overflow(
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<Overflow> {
+    Ok(get_option(options, "overflow", context)?.unwrap_or(Overflow::Constrain))
+}
+
+/// Abstract operation `ToTemporalDisambiguation ( options )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldisambiguation
+pub(crate) fn to_temporal_disambiguation(
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<Disambiguation> {
+    Ok(get_option(options, "disambiguation", context)?.unwrap_or(Disambiguation::Compatible))
+}
+
+/// Abstract operation `ToTemporalRoundingMode ( normalizedOptions, fallback )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalroundingmode
+pub(crate) fn to_temporal_rounding_mode(
+    options: Option<&JsObject>,
+    fallback: RoundingMode,
+    context: &mut Context,
+) -> JsResult<RoundingMode> {
+    Ok(get_option(options, "roundingMode", context)?.unwrap_or(fallback))
+}
+
+/// Abstract operation `ToTemporalRoundingIncrement ( normalizedOptions )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalroundingincrement
+pub(crate) fn to_temporal_rounding_increment(
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<u64> {
+    // 1. Let increment be ? GetOption(normalizedOptions, "roundingIncrement", "number", undefined, 1𝔽).
+    let increment = match options {
+        Some(options) => options.get("roundingIncrement", context)?,
+        None => JsValue::undefined(),
+    };
+    if increment.is_undefined() {
+        return Ok(1);
+    }
+    let increment = increment.to_number(context)?;
+
+    // 2. If increment is not finite, throw a RangeError exception.
+    // 3. Let integerIncrement be truncate(ℝ(increment)).
+    // 4. If integerIncrement < 1 or integerIncrement > 10^9, throw a RangeError exception.
+    let integer_increment = increment.trunc();
+    if !increment.is_finite() || !(1.0..=1e9).contains(&integer_increment) {
+        return Err(JsNativeError::range()
+            .with_message("roundingIncrement must be an integer between 1 and 1e9")
+            .into());
+    }
+
+    // 5. Return integerIncrement.
+    Ok(integer_increment as u64)
+}
+
+/// Abstract operation `ValidateTemporalRoundingIncrement ( increment, dividend, inclusive )`
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-validatetemporalroundingincrement
+pub(crate) fn validate_temporal_rounding_increment(
+    increment: u64,
+    dividend: u64,
+    inclusive: bool,
+) -> JsResult<()> {
+    // 1. If inclusive is true, then
+    //     a. Let maximum be dividend.
+    // 2. Else,
+    //     a. Assert: dividend > 1.
+    //     b. Let maximum be dividend - 1.
+    let maximum = if inclusive { dividend } else { dividend - 1 };
+
+    // 3. If increment > maximum, throw a RangeError exception.
+    // 4. If dividend modulo increment ≠ 0, then
+    //     a. Throw a RangeError exception.
+    if increment > maximum || dividend % increment != 0 {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "roundingIncrement {increment} does not divide evenly into {dividend}"
+            ))
+            .into());
+    }
+
+    // 5. Return unused.
+    Ok(())
+}
+
+/// Abstract operations `GetTemporalFractionalSecondDigitsOption ( normalizedOptions )` and
+/// `ToSecondsStringPrecisionRecord ( smallestUnit, fractionalDigitCount )`
+///
+/// Reads the `fractionalSecondDigits`, `roundingMode` and `smallestUnit` options of a
+/// `toString` method.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tosecondsstringprecisionrecord
+pub(crate) fn to_seconds_string_precision(
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<(ToStringRounding, RoundingMode)> {
+    let digits = match options {
+        Some(options) => options.get("fractionalSecondDigits", context)?,
+        None => JsValue::undefined(),
+    };
+    let digits = match digits.variant() {
+        JsVariant::Undefined => None,
+        JsVariant::Integer(_) | JsVariant::Rational(_) => {
+            let digits = digits.to_number(context)?;
+            if !digits.is_finite() || !(0.0..10.0).contains(&digits.floor()) {
+                return Err(JsNativeError::range()
+                    .with_message("fractionalSecondDigits must be an integer between 0 and 9")
+                    .into());
+            }
+            Some(digits.floor() as u8)
+        }
+        _ => {
+            if digits.to_string(context)? != "auto" {
+                return Err(JsNativeError::range()
+                    .with_message("fractionalSecondDigits must be 'auto' or a number")
+                    .into());
+            }
+            None
+        }
+    };
+
+    let rounding_mode = to_temporal_rounding_mode(options, RoundingMode::Trunc, context)?;
+
+    let smallest_unit =
+        get_temporal_unit(options, "smallestUnit", UnitGroup::Time, false, context)?
+            .and_then(UnitOption::unit);
+
+    let rounding = match smallest_unit {
+        Some(Unit::Hour) => {
+            return Err(JsNativeError::range()
+                .with_message("smallestUnit must not be 'hour'")
+                .into())
+        }
+        Some(Unit::Minute) => ToStringRounding {
+            precision: Precision::Minute,
+            unit: Unit::Minute,
+            increment: 1,
+        },
+        Some(Unit::Second) => ToStringRounding {
+            precision: Precision::Digits(0),
+            unit: Unit::Second,
+            increment: 1,
+        },
+        Some(Unit::Millisecond) => ToStringRounding {
+            precision: Precision::Digits(3),
+            unit: Unit::Millisecond,
+            increment: 1,
+        },
+        Some(Unit::Microsecond) => ToStringRounding {
+            precision: Precision::Digits(6),
+            unit: Unit::Microsecond,
+            increment: 1,
+        },
+        Some(_) => ToStringRounding {
+            precision: Precision::Digits(9),
+            unit: Unit::Nanosecond,
+            increment: 1,
+        },
+        None => match digits {
+            None => ToStringRounding {
+                precision: Precision::Auto,
+                unit: Unit::Nanosecond,
+                increment: 1,
+            },
+            Some(digits) => {
+                let unit = match digits {
+                    0 => Unit::Second,
+                    1..=3 => Unit::Millisecond,
+                    4..=6 => Unit::Microsecond,
+                    _ => Unit::Nanosecond,
+                };
+                ToStringRounding {
+                    precision: Precision::Digits(digits),
+                    unit,
+                    increment: 10u64.pow(u32::from((3 - digits % 3) % 3)),
+                }
+            }
+        },
+    };
+
+    Ok((rounding, rounding_mode))
+}
+
+/// The `largestUnit`, `smallestUnit`, `roundingIncrement` and `roundingMode` options of the
+/// `until` and `since` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DifferenceSettings {
+    pub(crate) largest_unit: Unit,
+    pub(crate) smallest_unit: Unit,
+    pub(crate) rounding_increment: u64,
+    pub(crate) rounding_mode: RoundingMode,
+}
+
+/// Abstract operation `GetDifferenceSettings ( operation, options, unitGroup, disallowedUnits, fallbackSmallestUnit, smallestLargestDefaultUnit )`
+///
+/// Reads the options of an `until` or `since` method. The rounding mode is negated for `since`,
+/// which computes the difference from the receiver to the argument and negates the result.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getdifferencesettings
+pub(crate) fn get_difference_settings(
+    since: bool,
+    options: &JsValue,
+    group: UnitGroup,
+    fallback_smallest_unit: Unit,
+    smallest_largest_default_unit: Unit,
+    context: &mut Context,
+) -> JsResult<DifferenceSettings> {
+    let options = get_options_object(options)?;
+    let options = options.as_ref();
+
+    // 1. Let largestUnit be ? GetTemporalUnit(options, "largestUnit", unitGroup, "auto").
+    // 2. If disallowedUnits contains largestUnit, throw a RangeError exception.
+    let largest_unit = get_temporal_unit(options, "largestUnit", group, true, context)?;
+
+    // 3. Let roundingIncrement be ? ToTemporalRoundingIncrement(options).
+    let rounding_increment = to_temporal_rounding_increment(options, context)?;
+
+    // 4. Let roundingMode be ? ToTemporalRoundingMode(options, "trunc").
+    // 5. If operation is since, then
+    //     a. Set roundingMode to ! NegateTemporalRoundingMode(roundingMode).
+    let mut rounding_mode = to_temporal_rounding_mode(options, RoundingMode::Trunc, context)?;
+    if since {
+        rounding_mode = rounding_mode.negate();
+    }
+
+    // 6. Let smallestUnit be ? GetTemporalUnit(options, "smallestUnit", unitGroup, fallbackSmallestUnit).
+    // 7. If disallowedUnits contains smallestUnit, throw a RangeError exception.
+    let smallest_unit = get_temporal_unit(options, "smallestUnit", group, false, context)?
+        .and_then(UnitOption::unit)
+        .unwrap_or(fallback_smallest_unit);
+
+    // 8. Let defaultLargestUnit be ! LargerOfTwoTemporalUnits(smallestLargestDefaultUnit, smallestUnit).
+    // 9. If largestUnit is "auto", set largestUnit to defaultLargestUnit.
+    let largest_unit = largest_unit
+        .and_then(UnitOption::unit)
+        .unwrap_or_else(|| smallest_largest_default_unit.max(smallest_unit));
+
+    // 10. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+    if largest_unit < smallest_unit {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "largestUnit '{largest_unit}' is smaller than smallestUnit '{smallest_unit}'"
+            ))
+            .into());
+    }
+
+    // 11. Let maximum be ! MaximumTemporalDurationRoundingIncrement(smallestUnit).
+    // 12. If maximum is not undefined, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+    if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+        validate_temporal_rounding_increment(rounding_increment, maximum, false)?;
+    }
+
+    // 13. Return the Record { [[SmallestUnit]]: smallestUnit, [[LargestUnit]]: largestUnit, [[RoundingMode]]: roundingMode, [[RoundingIncrement]]: roundingIncrement, }.
+    Ok(DifferenceSettings {
+        largest_unit,
+        smallest_unit,
+        rounding_increment,
+        rounding_mode,
+    })
+}
+
+/// Reads the `roundTo` argument of a `round` method, which is either the `smallestUnit` option
+/// or an options object, and returns the options object.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.round
+pub(crate) fn get_round_to_options(
+    round_to: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    match round_to.variant() {
+        // 3. If roundTo is undefined, then
+        //     a. Throw a TypeError exception.
+        JsVariant::Undefined => Err(JsNativeError::typ()
+            .with_message("round requires a smallestUnit or an options object")
+            .into()),
+        // 4. If Type(roundTo) is String, then
+        //     a. Let paramString be roundTo.
+        //     b. Set roundTo to OrdinaryObjectCreate(null).
+        //     c. Perform ! CreateDataPropertyOrThrow(roundTo, "smallestUnit", paramString).
+        JsVariant::String(unit) => {
+            let options = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+            options
+                .create_data_property_or_throw("smallestUnit", unit.clone(), context)
+                .expect("defining a property of a new object cannot fail");
+            Ok(options)
+        }
+        // 5. Else,
+        //     a. Set roundTo to ? GetOptionsObject(roundTo).
+        _ => Ok(get_options_object(round_to)?.expect("roundTo is not undefined")),
+    }
+}
+
+/// Reads the `roundingIncrement`, `roundingMode` and `smallestUnit` options of the `round`
+/// method of a `PlainTime`, a `PlainDateTime` or a `ZonedDateTime`, whose smallest unit is a
+/// time unit or `day`.
+///
+/// Returns the increment, the smallest unit and the rounding mode.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.round
+pub(crate) fn get_time_rounding_options(
+    round_to: &JsValue,
+    allow_day: bool,
+    context: &mut Context,
+) -> JsResult<(u64, Unit, RoundingMode)> {
+    let options = get_round_to_options(round_to, context)?;
+    let options = Some(&options);
+
+    // 6. Let roundingIncrement be ? ToTemporalRoundingIncrement(roundTo).
+    let increment = to_temporal_rounding_increment(options, context)?;
+
+    // 7. Let roundingMode be ? ToTemporalRoundingMode(roundTo, "halfExpand").
+    let mode = to_temporal_rounding_mode(options, RoundingMode::HalfExpand, context)?;
+
+    // 8. Let smallestUnit be ? GetTemporalUnit(roundTo, "smallestUnit", time, required, « day »).
+    let unit =
+        match get_temporal_unit(options, "smallestUnit", UnitGroup::DateTime, false, context)?
+            .and_then(UnitOption::unit)
+        {
+            Some(unit) if !unit.is_date_unit() || (allow_day && unit == Unit::Day) => unit,
+            Some(unit) => {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "'{unit}' is not a valid value for option smallestUnit"
+                    ))
+                    .into())
+            }
+            None => {
+                return Err(JsNativeError::range()
+                    .with_message("smallestUnit is required")
+                    .into())
+            }
+        };
+
+    // 9. If smallestUnit is "day", then
+    //     a. Let maximum be 1.
+    //     b. Let inclusive be true.
+    // 10. Else,
+    //     a. Let maximum be ! MaximumTemporalDurationRoundingIncrement(smallestUnit).
+    //     b. Assert: maximum is not undefined.
+    //     c. Let inclusive be false.
+    // 11. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+    match unit.maximum_rounding_increment() {
+        Some(maximum) => validate_temporal_rounding_increment(increment, maximum, false)?,
+        None => validate_temporal_rounding_increment(increment, 1, true)?,
+    }
+
+    Ok((increment, unit, mode))
+}
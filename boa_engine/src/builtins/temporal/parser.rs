@@ -0,0 +1,720 @@
+//! The parser of the ISO 8601 strings accepted by the `Temporal` builtins, which is the
+//! extended format of [RFC 9557][rfc] with bracketed time zone and calendar annotations.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-iso8601grammar
+//! [rfc]: https://www.rfc-editor.org/rfc/rfc9557
+
+use super::{
+    iso::{IsoDate, IsoTime},
+    options::{NS_PER_HOUR, NS_PER_MINUTE, NS_PER_SECOND},
+};
+use crate::{error::JsNativeError, JsResult};
+
+/// The UTC offset of a parsed string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParsedOffset {
+    /// The `Z` designator, which means that the string is an exact time without a known local
+    /// offset.
+    Utc,
+    /// A numeric UTC offset.
+    Offset {
+        nanoseconds: i64,
+        /// Whether the offset was written with seconds or fractional seconds.
+        sub_minute: bool,
+    },
+}
+
+/// A time zone identifier, which is either the name of a time zone of the time zone database
+/// or a fixed UTC offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParsedTimeZone {
+    Named(String),
+    Offset(i64),
+}
+
+/// The components of a parsed ISO 8601 date-time string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedDateTime {
+    /// The date, which is `None` for time-only strings.
+    pub(crate) date: Option<IsoDate>,
+    /// The time, which is `None` for date-only strings.
+    pub(crate) time: Option<IsoTime>,
+    pub(crate) offset: Option<ParsedOffset>,
+    /// The bracketed time zone annotation.
+    pub(crate) time_zone: Option<ParsedTimeZone>,
+    /// The identifier of the `u-ca` calendar annotation.
+    pub(crate) calendar: Option<String>,
+}
+
+/// The fields of a parsed ISO 8601 duration string.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct ParsedDuration {
+    pub(crate) years: f64,
+    pub(crate) months: f64,
+    pub(crate) weeks: f64,
+    pub(crate) days: f64,
+    pub(crate) hours: f64,
+    pub(crate) minutes: f64,
+    pub(crate) seconds: f64,
+    pub(crate) milliseconds: f64,
+    pub(crate) microseconds: f64,
+    pub(crate) nanoseconds: f64,
+}
+
+/// A cursor over the bytes of the parsed string.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            bytes: source.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.pos + offset).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_ignore_case(&mut self, byte: u8) -> bool {
+        if self.peek().map(|b| b.to_ascii_uppercase()) == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_sign(&mut self) -> Option<i64> {
+        if self.eat(b'+') {
+            Some(1)
+        } else if self.eat(b'-') {
+            Some(-1)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the next `count` bytes are ASCII digits.
+    fn has_digits(&self, count: usize) -> bool {
+        (0..count).all(|i| self.peek_at(i).map_or(false, |b| b.is_ascii_digit()))
+    }
+
+    /// Parses exactly `count` ASCII digits.
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        if !self.has_digits(count) {
+            return None;
+        }
+        let mut value = 0;
+        for _ in 0..count {
+            value = value * 10 + u32::from(self.bytes[self.pos] - b'0');
+            self.pos += 1;
+        }
+        Some(value)
+    }
+
+    /// Parses a fraction of one to nine digits after a `.` or `,` separator, as a number of
+    /// nanoseconds.
+    #[allow(clippy::option_option)]
+    fn fraction(&mut self) -> Option<Option<u32>> {
+        if !matches!(self.peek(), Some(b'.' | b',')) {
+            return Some(None);
+        }
+        self.pos += 1;
+        let start = self.pos;
+        while self.peek().map_or(false, |b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits = &self.bytes[start..self.pos];
+        if digits.is_empty() || digits.len() > 9 {
+            return None;
+        }
+        let mut value = 0;
+        for i in 0..9 {
+            value = value * 10 + digits.get(i).map_or(0, |b| u32::from(b - b'0'));
+        }
+        Some(Some(value))
+    }
+}
+
+/// Throws the `RangeError` of an invalid string.
+fn invalid(source: &str) -> JsNativeError {
+    JsNativeError::range().with_message(format!("'{source}' is not a valid ISO 8601 string"))
+}
+
+/// Parses a `DateYear`, `DateMonth` and `DateDay`, in the extended or in the basic format.
+fn parse_date(cursor: &mut Cursor<'_>) -> Option<IsoDate> {
+    let year = if let Some(sign) = cursor.eat_sign() {
+        let year = i64::from(cursor.digits(6)?);
+        // -000000 is not a valid year.
+        if sign < 0 && year == 0 {
+            return None;
+        }
+        sign * year
+    } else {
+        i64::from(cursor.digits(4)?)
+    };
+    let extended = cursor.eat(b'-');
+    let month = cursor.digits(2)?;
+    if extended && !cursor.eat(b'-') {
+        return None;
+    }
+    let day = cursor.digits(2)?;
+    IsoDate::new(year, i64::from(month), i64::from(day))
+}
+
+/// Parses a `TimeSpec`, in the extended or in the basic format.
+fn parse_time(cursor: &mut Cursor<'_>) -> Option<IsoTime> {
+    let hour = cursor.digits(2)?;
+    let mut minute = 0;
+    let mut second = 0;
+    let mut fraction = 0;
+    if cursor.eat(b':') {
+        minute = cursor.digits(2)?;
+        if cursor.eat(b':') {
+            second = cursor.digits(2)?;
+            fraction = cursor.fraction()?.unwrap_or(0);
+        }
+    } else if cursor.has_digits(2) {
+        minute = cursor.digits(2)?;
+        if cursor.has_digits(2) {
+            second = cursor.digits(2)?;
+            fraction = cursor.fraction()?.unwrap_or(0);
+        }
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    Some(IsoTime {
+        hour: hour as u8,
+        minute: minute as u8,
+        // A leap second is treated as the last second of the minute.
+        second: second.min(59) as u8,
+        millisecond: (fraction / 1_000_000) as u16,
+        microsecond: (fraction / 1_000 % 1_000) as u16,
+        nanosecond: (fraction % 1_000) as u16,
+    })
+}
+
+/// Parses a numeric UTC offset, as `±HH`, `±HH:MM`, `±HH:MM:SS` or `±HH:MM:SS.fffffffff`, or the
+/// same in the basic format.
+fn parse_numeric_offset(cursor: &mut Cursor<'_>) -> Option<ParsedOffset> {
+    let sign = cursor.eat_sign()?;
+    let hours = cursor.digits(2)?;
+    let mut minutes = 0;
+    let mut seconds = 0;
+    let mut fraction = 0;
+    let mut sub_minute = false;
+    let extended = cursor.eat(b':');
+    if extended || cursor.has_digits(2) {
+        minutes = cursor.digits(2)?;
+        let has_seconds = if extended {
+            cursor.eat(b':')
+        } else {
+            cursor.has_digits(2)
+        };
+        if has_seconds {
+            seconds = cursor.digits(2)?;
+            fraction = cursor.fraction()?.unwrap_or(0);
+            sub_minute = true;
+        }
+    }
+    if hours > 23 || minutes > 59 || seconds > 59 {
+        return None;
+    }
+    let nanoseconds = i128::from(hours) * NS_PER_HOUR
+        + i128::from(minutes) * NS_PER_MINUTE
+        + i128::from(seconds) * NS_PER_SECOND
+        + i128::from(fraction);
+    Some(ParsedOffset::Offset {
+        nanoseconds: sign * nanoseconds as i64,
+        sub_minute,
+    })
+}
+
+/// Checks if `name` is a syntactically valid IANA time zone name.
+fn is_time_zone_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('/').all(|part| {
+            !part.is_empty()
+                && part != "."
+                && part != ".."
+                && !part.starts_with('-')
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-' | b'+'))
+        })
+}
+
+/// Parses the contents of a time zone annotation, or a time zone identifier.
+fn parse_time_zone_identifier_exact(source: &str) -> Option<ParsedTimeZone> {
+    if matches!(source.as_bytes().first(), Some(b'+' | b'-')) {
+        let mut cursor = Cursor::new(source);
+        // Offset time zones cannot have sub-minute precision.
+        match parse_numeric_offset(&mut cursor)? {
+            ParsedOffset::Offset {
+                nanoseconds,
+                sub_minute: false,
+            } if cursor.is_done() => Some(ParsedTimeZone::Offset(nanoseconds)),
+            _ => None,
+        }
+    } else if is_time_zone_name(source) {
+        Some(ParsedTimeZone::Named(source.to_owned()))
+    } else {
+        None
+    }
+}
+
+/// Parses the bracketed annotations at the end of a string.
+fn parse_annotations(
+    cursor: &mut Cursor<'_>,
+    source: &str,
+) -> JsResult<(Option<ParsedTimeZone>, Option<String>)> {
+    let mut time_zone = None;
+    let mut calendar: Option<String> = None;
+    let mut calendar_critical = false;
+    let mut first = true;
+
+    while cursor.eat(b'[') {
+        let critical = cursor.eat(b'!');
+        let start = cursor.pos;
+        while cursor.peek().map_or(false, |b| b != b']') {
+            cursor.pos += 1;
+        }
+        if !cursor.eat(b']') {
+            return Err(invalid(source).into());
+        }
+        let content = &source[start..cursor.pos - 1];
+
+        if let Some((key, value)) = content.split_once('=') {
+            let valid_key = key
+                .bytes()
+                .next()
+                .map_or(false, |b| b.is_ascii_lowercase() || b == b'_')
+                && key
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b"_-".contains(&b));
+            let valid_value = !value.is_empty()
+                && value.split('-').all(|part| {
+                    !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric())
+                });
+            if !valid_key || !valid_value {
+                return Err(invalid(source).into());
+            }
+
+            if key == "u-ca" {
+                // If there is more than one calendar annotation, the first one is used unless
+                // one of them is critical.
+                if calendar.is_some() {
+                    if critical || calendar_critical {
+                        return Err(JsNativeError::range()
+                            .with_message(format!(
+                                "'{source}' has more than one critical calendar annotation"
+                            ))
+                            .into());
+                    }
+                } else {
+                    calendar = Some(value.to_owned());
+                    calendar_critical = critical;
+                }
+            } else if critical {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "'{source}' has an unknown critical annotation '{key}'"
+                    ))
+                    .into());
+            }
+        } else {
+            // The time zone annotation must be the first one.
+            if !first {
+                return Err(invalid(source).into());
+            }
+            time_zone =
+                Some(parse_time_zone_identifier_exact(content).ok_or_else(|| invalid(source))?);
+        }
+        first = false;
+    }
+
+    Ok((time_zone, calendar))
+}
+
+/// Parses a date-time string, with an optional time, UTC offset and annotations.
+///
+/// If `time_only` is `true`, the date can also be omitted.
+fn parse_date_time_string(source: &str, time_only: bool) -> JsResult<ParsedDateTime> {
+    let mut cursor = Cursor::new(source);
+
+    let (date, time) = if time_only && cursor.eat_ignore_case(b'T') {
+        (
+            None,
+            Some(parse_time(&mut cursor).ok_or_else(|| invalid(source))?),
+        )
+    } else if let Some(date) = parse_date(&mut cursor) {
+        if cursor.eat_ignore_case(b'T') || cursor.eat(b' ') {
+            (
+                Some(date),
+                Some(parse_time(&mut cursor).ok_or_else(|| invalid(source))?),
+            )
+        } else if time_only {
+            // A date without a time is not a valid time string.
+            return Err(invalid(source).into());
+        } else {
+            (Some(date), None)
+        }
+    } else if time_only {
+        cursor.pos = 0;
+        (
+            None,
+            Some(parse_time(&mut cursor).ok_or_else(|| invalid(source))?),
+        )
+    } else {
+        return Err(invalid(source).into());
+    };
+    let has_time_designator = date.is_some() || source.starts_with(['T', 't']);
+
+    let offset = if time.is_some() && cursor.eat_ignore_case(b'Z') {
+        Some(ParsedOffset::Utc)
+    } else if time.is_some() && matches!(cursor.peek(), Some(b'+' | b'-')) {
+        Some(parse_numeric_offset(&mut cursor).ok_or_else(|| invalid(source))?)
+    } else {
+        None
+    };
+
+    let annotations_start = cursor.pos;
+    let (time_zone, calendar) = parse_annotations(&mut cursor, source)?;
+    if !cursor.is_done() {
+        return Err(invalid(source).into());
+    }
+
+    // A time without a designator must not be ambiguous with a year-month or a month-day.
+    if date.is_none() && !has_time_designator {
+        let unannotated = &source[..annotations_start];
+        if is_year_month(unannotated) || is_month_day(unannotated) {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "'{source}' is ambiguous, use a 'T' prefix for a time"
+                ))
+                .into());
+        }
+    }
+
+    Ok(ParsedDateTime {
+        date,
+        time,
+        offset,
+        time_zone,
+        calendar,
+    })
+}
+
+/// Checks if `source` is a valid `DateSpecYearMonth`.
+fn is_year_month(source: &str) -> bool {
+    let mut cursor = Cursor::new(source);
+    let year = if cursor.eat_sign().is_some() {
+        cursor.digits(6)
+    } else {
+        cursor.digits(4)
+    };
+    cursor.eat(b'-');
+    year.is_some() && cursor.digits(2).map_or(false, |m| (1..=12).contains(&m)) && cursor.is_done()
+}
+
+/// Checks if `source` is a valid `DateSpecMonthDay`.
+fn is_month_day(source: &str) -> bool {
+    let mut cursor = Cursor::new(source);
+    if cursor.eat(b'-') && !cursor.eat(b'-') {
+        return false;
+    }
+    let month = cursor.digits(2);
+    cursor.eat(b'-');
+    let day = cursor.digits(2);
+    match (month, day) {
+        (Some(month), Some(day)) => {
+            cursor.is_done() && IsoDate::new(1972, i64::from(month), i64::from(day)).is_some()
+        }
+        _ => false,
+    }
+}
+
+/// Parses a `TemporalDateTimeString`, which is used by `PlainDate` and `PlainDateTime`.
+///
+/// The UTC designator `Z` is not allowed, since the string would represent an exact time.
+pub(crate) fn parse_date_time(source: &str) -> JsResult<ParsedDateTime> {
+    let parsed = parse_date_time_string(source, false)?;
+    if parsed.offset == Some(ParsedOffset::Utc) {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "'{source}' is an exact time, the 'Z' designator is not allowed"
+            ))
+            .into());
+    }
+    Ok(parsed)
+}
+
+/// Parses a `TemporalTimeString`, which is used by `PlainTime`.
+pub(crate) fn parse_time_string(source: &str) -> JsResult<ParsedDateTime> {
+    let parsed = parse_date_time_string(source, true)?;
+    if parsed.offset == Some(ParsedOffset::Utc) {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "'{source}' is an exact time, the 'Z' designator is not allowed"
+            ))
+            .into());
+    }
+    Ok(parsed)
+}
+
+/// Parses a `TemporalInstantString`, which must have a time and a UTC offset.
+pub(crate) fn parse_instant(source: &str) -> JsResult<ParsedDateTime> {
+    let parsed = parse_date_time_string(source, false)?;
+    if parsed.time.is_none() || parsed.offset.is_none() {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "'{source}' is not an exact time, it must have a time and a UTC offset"
+            ))
+            .into());
+    }
+    Ok(parsed)
+}
+
+/// Parses a `TemporalZonedDateTimeString`, which must have a time zone annotation.
+pub(crate) fn parse_zoned_date_time(source: &str) -> JsResult<ParsedDateTime> {
+    let parsed = parse_date_time_string(source, false)?;
+    if parsed.time_zone.is_none() {
+        return Err(JsNativeError::range()
+            .with_message(format!("'{source}' does not have a time zone annotation"))
+            .into());
+    }
+    Ok(parsed)
+}
+
+/// Parses a `TimeZoneIdentifier`, or the time zone of a date-time string, which is the
+/// time zone annotation, or `UTC` for the UTC designator, or the numeric UTC offset.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaltimezonestring
+pub(crate) fn parse_time_zone(source: &str) -> JsResult<ParsedTimeZone> {
+    if let Some(time_zone) = parse_time_zone_identifier_exact(source) {
+        return Ok(time_zone);
+    }
+
+    let parsed = parse_date_time_string(source, false)?;
+    match (parsed.time_zone, parsed.offset) {
+        (Some(time_zone), _) => Ok(time_zone),
+        (None, Some(ParsedOffset::Utc)) => Ok(ParsedTimeZone::Named("UTC".to_owned())),
+        (
+            None,
+            Some(ParsedOffset::Offset {
+                nanoseconds,
+                sub_minute: false,
+            }),
+        ) => Ok(ParsedTimeZone::Offset(nanoseconds)),
+        _ => Err(JsNativeError::range()
+            .with_message(format!("'{source}' does not have a time zone"))
+            .into()),
+    }
+}
+
+/// Parses the calendar identifier of a calendar string, which is either the identifier itself
+/// or a date-time string with an optional calendar annotation, which defaults to `iso8601`.
+pub(crate) fn parse_calendar(source: &str) -> JsResult<String> {
+    if source
+        .bytes()
+        .next()
+        .map_or(false, |b| b.is_ascii_alphabetic())
+        && source.split('-').all(|part| {
+            (3..=8).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_alphanumeric())
+        })
+    {
+        return Ok(source.to_owned());
+    }
+
+    let parsed = parse_date_time_string(source, true)?;
+    Ok(parsed.calendar.unwrap_or_else(|| "iso8601".to_owned()))
+}
+
+/// Parses a UTC offset string of the form `±HH:MM[:SS[.fffffffff]]`, returning the offset in
+/// nanoseconds.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-parsedatetimeutcoffset
+pub(crate) fn parse_offset(source: &str) -> JsResult<i64> {
+    let mut cursor = Cursor::new(source);
+    match parse_numeric_offset(&mut cursor) {
+        Some(ParsedOffset::Offset { nanoseconds, .. }) if cursor.is_done() => Ok(nanoseconds),
+        _ => Err(JsNativeError::range()
+            .with_message(format!("'{source}' is not a valid UTC offset"))
+            .into()),
+    }
+}
+
+/// Parses an ISO 8601 duration string, like `P1Y2M3W4DT5H6M7.008009010S`.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaldurationstring
+pub(crate) fn parse_duration(source: &str) -> JsResult<ParsedDuration> {
+    /// Parses a run of digits as a number.
+    fn number(cursor: &mut Cursor<'_>) -> Option<f64> {
+        let start = cursor.pos;
+        while cursor.peek().map_or(false, |b| b.is_ascii_digit()) {
+            cursor.pos += 1;
+        }
+        if start == cursor.pos {
+            return None;
+        }
+        std::str::from_utf8(&cursor.bytes[start..cursor.pos])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    let mut cursor = Cursor::new(source);
+    let sign = cursor.eat_sign().unwrap_or(1) as f64;
+    if !cursor.eat_ignore_case(b'P') {
+        return Err(invalid(source).into());
+    }
+
+    let mut duration = ParsedDuration::default();
+    let mut any = false;
+
+    // The date designators must appear in order, each at most once.
+    let mut date_designators = b"YMWD".iter();
+    while !cursor.is_done() && !matches!(cursor.peek(), Some(b'T' | b't')) {
+        let value = number(&mut cursor).ok_or_else(|| invalid(source))?;
+        let designator = cursor
+            .peek()
+            .map(|b| b.to_ascii_uppercase())
+            .ok_or_else(|| invalid(source))?;
+        cursor.pos += 1;
+        loop {
+            match date_designators.next() {
+                Some(&d) if d == designator => break,
+                Some(_) => {}
+                None => return Err(invalid(source).into()),
+            }
+        }
+        match designator {
+            b'Y' => duration.years = value,
+            b'M' => duration.months = value,
+            b'W' => duration.weeks = value,
+            _ => duration.days = value,
+        }
+        any = true;
+    }
+
+    if cursor.eat_ignore_case(b'T') {
+        let mut time_designators = b"HMS".iter();
+        let mut any_time = false;
+        let mut fraction_seen = false;
+        while !cursor.is_done() {
+            // Only the smallest time unit can have a fraction.
+            if fraction_seen {
+                return Err(invalid(source).into());
+            }
+            let value = number(&mut cursor).ok_or_else(|| invalid(source))?;
+            let fraction = cursor.fraction().ok_or_else(|| invalid(source))?;
+            let designator = cursor
+                .peek()
+                .map(|b| b.to_ascii_uppercase())
+                .ok_or_else(|| invalid(source))?;
+            cursor.pos += 1;
+            loop {
+                match time_designators.next() {
+                    Some(&d) if d == designator => break,
+                    Some(_) => {}
+                    None => return Err(invalid(source).into()),
+                }
+            }
+
+            // The fraction is distributed to the smaller units, without loss of precision.
+            let unit_nanoseconds = match designator {
+                b'H' => {
+                    duration.hours = value;
+                    NS_PER_HOUR
+                }
+                b'M' => {
+                    duration.minutes = value;
+                    NS_PER_MINUTE
+                }
+                _ => {
+                    duration.seconds = value;
+                    NS_PER_SECOND
+                }
+            };
+            if let Some(fraction) = fraction {
+                fraction_seen = true;
+                let nanoseconds = i128::from(fraction) * unit_nanoseconds / NS_PER_SECOND;
+                if designator == b'H' {
+                    duration.minutes = (nanoseconds / NS_PER_MINUTE) as f64;
+                    duration.seconds = (nanoseconds % NS_PER_MINUTE / NS_PER_SECOND) as f64;
+                } else if designator == b'M' {
+                    duration.seconds = (nanoseconds / NS_PER_SECOND) as f64;
+                }
+                let sub_second = nanoseconds % NS_PER_SECOND;
+                duration.milliseconds = (sub_second / 1_000_000) as f64;
+                duration.microseconds = (sub_second / 1_000 % 1_000) as f64;
+                duration.nanoseconds = (sub_second % 1_000) as f64;
+            }
+            any_time = true;
+        }
+        if !any_time {
+            return Err(invalid(source).into());
+        }
+        any = true;
+    }
+
+    if !any || !cursor.is_done() {
+        return Err(invalid(source).into());
+    }
+
+    if sign < 0.0 {
+        for field in [
+            &mut duration.years,
+            &mut duration.months,
+            &mut duration.weeks,
+            &mut duration.days,
+            &mut duration.hours,
+            &mut duration.minutes,
+            &mut duration.seconds,
+            &mut duration.milliseconds,
+            &mut duration.microseconds,
+            &mut duration.nanoseconds,
+        ] {
+            // Avoid negative zeroes.
+            if *field != 0.0 {
+                *field = -*field;
+            }
+        }
+    }
+
+    Ok(duration)
+}
@@ -0,0 +1,684 @@
+//! This module implements the `Temporal.PlainDate` object.
+//!
+//! A `Temporal.PlainDate` is a calendar date, which is not associated with a wall-clock time
+//! nor with a time zone.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindate-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate
+
+use super::{
+    calendar::Calendar,
+    difference::{calendar_date_add, difference_plain_date_time_with_rounding},
+    duration::{Duration, DurationRecord},
+    fields::{reject_object_with_calendar_or_time_zone, Field, TemporalFields},
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_difference_settings, get_options_object, to_temporal_overflow, Disambiguation, Unit,
+        UnitGroup,
+    },
+    parser::parse_date_time,
+    plain_date_time::PlainDateTime,
+    plain_time::PlainTime,
+    time_zone::TimeZone,
+    to_integer_with_truncation, value_of,
+    zoned_date_time::ZonedDateTime,
+    Component,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::JsVariant,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The internal representation of a `Temporal.PlainDate` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PlainDate {
+    #[unsafe_ignore_trace]
+    pub(crate) iso: IsoDate,
+    pub(crate) calendar: JsObject,
+}
+
+impl PlainDate {
+    const NAME: &'static str = "PlainDate";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_calendar = FunctionBuilder::native(context, Self::get_calendar)
+            .name("get calendar")
+            .build();
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plain_date().clone(),
+        );
+        builder
+            .name(Self::NAME)
+            .length(3)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainDate",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                "calendar",
+                Some(get_calendar),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::get_iso_fields, "getISOFields", 0)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0);
+
+        Component::add_getters(&mut builder, &Component::DATE, |this, _| {
+            Ok(IsoDateTime::new(
+                this_plain_date(this)?.iso,
+                IsoTime::default(),
+            ))
+        });
+
+        builder.build()
+    }
+
+    /// Creates a new `PlainDate` with the given calendar.
+    pub(crate) const fn new(iso: IsoDate, calendar: JsObject) -> Self {
+        Self { iso, calendar }
+    }
+
+    /// `Temporal.PlainDate ( isoYear, isoMonth, isoDay [ , calendarLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        //     a. Throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.PlainDate must be called with new")
+                .into());
+        }
+
+        // 2. Let y be ? ToIntegerWithTruncation(isoYear).
+        // 3. Let m be ? ToIntegerWithTruncation(isoMonth).
+        // 4. Let d be ? ToIntegerWithTruncation(isoDay).
+        let year = to_integer_with_truncation(args.get_or_undefined(0), context)?;
+        let month = to_integer_with_truncation(args.get_or_undefined(1), context)?;
+        let day = to_integer_with_truncation(args.get_or_undefined(2), context)?;
+
+        // 5. Let calendar be ? ToTemporalCalendarWithISODefault(calendarLike).
+        let calendar =
+            Calendar::to_temporal_calendar_with_iso_default(args.get_or_undefined(3), context)?;
+
+        // 6. If IsValidISODate(y, m, d) is false, throw a RangeError exception.
+        // 7. Return ? CreateTemporalDate(y, m, d, calendar, NewTarget).
+        let date = IsoDate::new(year as i64, month as i64, day as i64)
+            .ok_or_else(|| JsNativeError::range().with_message("the date is not valid"))?;
+        Self::new(date, calendar)
+            .create(Some(new_target), context)
+            .map(Into::into)
+    }
+
+    /// Abstract operation `CreateTemporalDate ( isoDate, calendar [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldate
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If ISODateWithinLimits(isoDate) is false, throw a RangeError exception.
+        self.iso.check_limits()?;
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.PlainDate%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainDate.prototype%", « [[InitializedTemporalDate]], [[ISODate]], [[Calendar]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::plain_date,
+                context,
+            )?,
+            None => context.intrinsics().constructors().plain_date().prototype(),
+        };
+
+        // 4. Set object.[[ISODate]] to isoDate.
+        // 5. Set object.[[Calendar]] to calendar.
+        // 6. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_date(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalDate ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldate
+    pub(crate) fn to_temporal_date(
+        item: &JsValue,
+        options: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        match item.variant() {
+            // 2. If item is an Object, then
+            JsVariant::Object(object) => {
+                // a. If item has an [[InitializedTemporalDate]] internal slot, then
+                //     i-ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalDate(item.[[ISODate]], item.[[Calendar]]).
+                // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+                //     ii-iii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iv. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], item.[[Calendar]]).
+                // c. If item has an [[InitializedTemporalDateTime]] internal slot, then
+                //     i-ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalDate(item.[[ISODateTime]].[[ISODate]], item.[[Calendar]]).
+                let date = {
+                    let borrowed = object.borrow();
+                    if let Some(date) = borrowed.as_plain_date() {
+                        Some(date.clone())
+                    } else if let Some(zoned) = borrowed.as_zoned_date_time() {
+                        Some(Self::new(
+                            zoned.date_time(context).date,
+                            zoned.calendar.clone(),
+                        ))
+                    } else {
+                        borrowed.as_plain_date_time().map(|date_time| {
+                            Self::new(date_time.iso.date, date_time.calendar.clone())
+                        })
+                    }
+                };
+                if let Some(date) = date {
+                    to_temporal_overflow(options, context)?;
+                    return Ok(date);
+                }
+
+                // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+                let calendar = Calendar::get_temporal_calendar_with_iso_default(object, context)?;
+
+                // e. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », «», «»).
+                let fields = TemporalFields::prepare(object, Field::DATE, Some(&[]), context)?;
+
+                // f-g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+                // h. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+                // i. Return ! CreateTemporalDate(isoDate, calendar).
+                let overflow = to_temporal_overflow(options, context)?;
+                Ok(Self::new(fields.date(overflow)?, calendar))
+            }
+            // 3. If item is not a String, throw a TypeError exception.
+            JsVariant::String(string) => {
+                // 4. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[~Zoned] »).
+                let parsed = parse_date_time(&string.to_std_string_escaped())?;
+
+                // 5-8. Let calendar be result.[[Calendar]], which must be a supported calendar.
+                Calendar::check_annotation(parsed.calendar.as_deref())?;
+
+                // 9-10. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                to_temporal_overflow(options, context)?;
+
+                // 11. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+                // 12. Return ? CreateTemporalDate(isoDate, calendar).
+                let date = parsed.date.expect("a date-time string must have a date");
+                Ok(Self::new(date.check_limits()?, Calendar::create(context)))
+            }
+            _ => Err(JsNativeError::typ()
+                .with_message("a date must be a Temporal object, a property bag or a string")
+                .into()),
+        }
+    }
+
+    /// `Temporal.PlainDate.from ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalDate(item, options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        Self::to_temporal_date(args.get_or_undefined(0), options.as_ref(), context)?
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDate.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDate(one).
+        // 2. Set two to ? ToTemporalDate(two).
+        let one = Self::to_temporal_date(args.get_or_undefined(0), None, context)?;
+        let two = Self::to_temporal_date(args.get_or_undefined(1), None, context)?;
+
+        // 3. Return 𝔽(CompareISODate(one.[[ISODate]], two.[[ISODate]])).
+        Ok((one.iso.cmp(&two.iso) as i32).into())
+    }
+
+    /// `get Temporal.PlainDate.prototype.calendar`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.calendar
+    fn get_calendar(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(this_plain_date(this)?.calendar.clone().into())
+    }
+
+    /// `Temporal.PlainDate.prototype.add ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? AddDurationToDate(add, temporalDate, temporalDurationLike, options).
+        Self::add_duration(false, this, args, context)
+    }
+
+    /// `Temporal.PlainDate.prototype.subtract ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? AddDurationToDate(subtract, temporalDate, temporalDurationLike, options).
+        Self::add_duration(true, this, args, context)
+    }
+
+    /// Abstract operation `AddDurationToDate ( operation, temporalDate, temporalDurationLike, options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodate
+    fn add_duration(
+        subtract: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let calendar be temporalDate.[[Calendar]].
+        let date = this_plain_date(this)?;
+
+        // 2. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 3. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::to_temporal_duration(args.get_or_undefined(0), context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 4. Let dateDuration be ToDateDurationRecordWithoutTime(duration).
+        let date_duration = duration.date_duration_without_time();
+
+        // 5. Let resolvedOptions be ? GetOptionsObject(options).
+        // 6. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+
+        // 7. Let result be ? CalendarDateAdd(calendar, temporalDate.[[ISODate]], dateDuration, overflow).
+        // 8. Return ! CreateTemporalDate(result, calendar).
+        let result = calendar_date_add(date.iso, date_duration, overflow)?;
+        Self::new(result, date.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDate.prototype.with ( temporalDateLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = this_plain_date(this)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalDateLike) is false, throw a TypeError exception.
+        let like = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("the argument of with must be an object")
+        })?;
+        reject_object_with_calendar_or_time_zone(like, context)?;
+
+        // 4. Let calendar be temporalDate.[[Calendar]].
+        // 5. Let fields be ISODateToFields(calendar, temporalDate.[[ISODate]], date).
+        // 6. Let partialDate be ? PrepareCalendarFields(calendar, temporalDateLike, « year, month, month-code, day », « », partial).
+        // 7. Set fields to CalendarMergeFields(calendar, fields, partialDate).
+        let fields = TemporalFields::from_date_time(IsoDateTime::new(date.iso, IsoTime::default()));
+        let partial = TemporalFields::prepare(like, Field::DATE, None, context)?;
+        let fields = fields.merge(partial);
+
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+
+        // 10. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+        // 11. Return ! CreateTemporalDate(isoDate, calendar).
+        Self::new(fields.date(overflow)?, date.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDate.prototype.withCalendar ( calendarLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.withcalendar
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = this_plain_date(this)?;
+
+        // 3. Let calendar be ? ToTemporalCalendar(calendarLike).
+        let calendar = Calendar::to_temporal_calendar(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalDate(temporalDate.[[ISODate]], calendar).
+        Self::new(date.iso, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDate.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalPlainDate(until, temporalDate, other, options).
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.PlainDate.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalPlainDate(since, temporalDate, other, options).
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainDate ( operation, temporalDate, other, options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindate
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date = this_plain_date(this)?;
+
+        // 1. Set other to ? ToTemporalDate(other).
+        // 2. If CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]) is false, throw a RangeError exception.
+        let other = Self::to_temporal_date(args.get_or_undefined(0), None, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, date, « », day, day).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::Date,
+            Unit::Day,
+            Unit::Day,
+            context,
+        )?;
+
+        // 5. If CompareISODate(temporalDate.[[ISODate]], other.[[ISODate]]) = 0, then
+        //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        // 6. Let dateDifference be CalendarDateUntil(calendar, temporalDate.[[ISODate]], other.[[ISODate]], settings.[[LargestUnit]]).
+        // 7. Let duration be CombineDateAndTimeDuration(dateDifference, 0).
+        // 8. If settings.[[SmallestUnit]] is not day or settings.[[RoundingIncrement]] ≠ 1, then
+        //     a-c. Set duration to ? RoundRelativeDuration(duration, destEpochNs, isoDateTime, unset, calendar, settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let internal = difference_plain_date_time_with_rounding(
+            IsoDateTime::new(date.iso, IsoTime::default()),
+            IsoDateTime::new(other.iso, IsoTime::default()),
+            settings,
+            context,
+        )?;
+
+        // 9. Let result be ! TemporalDurationFromInternal(duration, day).
+        // 10. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 11. Return result.
+        let mut result = DurationRecord::from_internal(internal, Unit::Day)?;
+        if since {
+            result = result.negated();
+        }
+        result.create(None, context).map(Into::into)
+    }
+
+    /// `Temporal.PlainDate.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = this_plain_date(this)?;
+
+        // 3. Set other to ? ToTemporalDate(other).
+        let other = Self::to_temporal_date(args.get_or_undefined(0), None, context)?;
+
+        // 4. If CompareISODate(temporalDate.[[ISODate]], other.[[ISODate]]) ≠ 0, return false.
+        // 5. Return CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]).
+        Ok((date.iso == other.iso).into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toPlainDateTime ( [ temporalTime ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplaindatetime
+    fn to_plain_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = this_plain_date(this)?;
+
+        // 3. Let time be ? ToTimeRecordOrMidnight(temporalTime).
+        let time = args.get_or_undefined(0);
+        let time = if time.is_undefined() {
+            IsoTime::default()
+        } else {
+            PlainTime::to_temporal_time(time, None, context)?
+        };
+
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], time).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, temporalDate.[[Calendar]]).
+        PlainDateTime::new(IsoDateTime::new(date.iso, time), date.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDate.prototype.toZonedDateTime ( item )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tozoneddatetime
+    fn to_zoned_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = this_plain_date(this)?;
+        let item = args.get_or_undefined(0);
+
+        // 3. If item is an Object, then
+        //     a. Let timeZoneLike be ? Get(item, "timeZone").
+        //     b. If timeZoneLike is undefined, then
+        //         i. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+        //         ii. Let temporalTime be undefined.
+        //     c. Else,
+        //         i. Let timeZone be ? ToTemporalTimeZoneIdentifier(timeZoneLike).
+        //         ii. Let temporalTime be ? Get(item, "plainTime").
+        // 4. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+        //     b. Let temporalTime be undefined.
+        let (time_zone, time) = match item.as_object() {
+            Some(object) if object.borrow().as_time_zone().is_none() => {
+                let time_zone_like = object.get("timeZone", context)?;
+                if time_zone_like.is_undefined() {
+                    (TimeZone::to_temporal_time_zone(item, context)?, None)
+                } else {
+                    let time_zone = TimeZone::to_temporal_time_zone(&time_zone_like, context)?;
+                    (time_zone, Some(object.get("plainTime", context)?))
+                }
+            }
+            _ => (TimeZone::to_temporal_time_zone(item, context)?, None),
+        };
+
+        let epoch_nanoseconds = match time.filter(|time| !time.is_undefined()) {
+            // 5. If temporalTime is undefined, then
+            //     a. Let epochNs be ? GetStartOfDay(timeZone, temporalDate.[[ISODate]]).
+            None => TimeZone::of(&time_zone).start_of_day(date.iso, context)?,
+            // 6. Else,
+            //     a. Set temporalTime to ? ToTemporalTime(temporalTime).
+            //     b. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], temporalTime.[[Time]]).
+            //     c. If ISODateTimeWithinLimits(isoDateTime) is false, throw a RangeError exception.
+            //     d. Let epochNs be ? GetEpochNanosecondsFor(timeZone, isoDateTime, compatible).
+            Some(time) => {
+                let time = PlainTime::to_temporal_time(&time, None, context)?;
+                let date_time = IsoDateTime::new(date.iso, time).check_limits()?;
+                TimeZone::of(&time_zone).epoch_nanoseconds_for(
+                    date_time,
+                    Disambiguation::Compatible,
+                    context,
+                )?
+            }
+        };
+
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, temporalDate.[[Calendar]]).
+        ZonedDateTime::new(epoch_nanoseconds, time_zone, date.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDate.prototype.getISOFields ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.getisofields
+    fn get_iso_fields(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = this_plain_date(this)?;
+
+        // 3. Let fields be OrdinaryObjectCreate(%Object.prototype%).
+        // 4-7. Perform ! CreateDataPropertyOrThrow(fields, "calendar", temporalDate.[[Calendar]]), and the same for the ISO fields.
+        // 8. Return fields.
+        let fields = context.construct_object();
+        for (key, value) in [
+            ("calendar", JsValue::from(date.calendar.clone())),
+            ("isoDay", date.iso.day.into()),
+            ("isoMonth", date.iso.month.into()),
+            ("isoYear", date.iso.year.into()),
+        ] {
+            fields
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining a property of a new object cannot fail");
+        }
+        Ok(fields.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tostring
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = this_plain_date(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(0))?;
+        let show_calendar = Calendar::to_calendar_name_option(options.as_ref(), context)?;
+
+        // 5. Return TemporalDateToString(temporalDate, showCalendar).
+        Ok(format!("{}{}", date.iso, Calendar::format_annotation(show_calendar)).into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tojson
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        // 3. Return TemporalDateToString(temporalDate, auto).
+        Ok(this_plain_date(this)?.iso.to_string().into())
+    }
+}
+
+/// Gets the `this` value of a `Temporal.PlainDate` method, which must be a plain date.
+fn this_plain_date(this: &JsValue) -> JsResult<PlainDate> {
+    this.as_object()
+        .and_then(|object| object.borrow().as_plain_date().cloned())
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Temporal.PlainDate")
+                .into()
+        })
+}
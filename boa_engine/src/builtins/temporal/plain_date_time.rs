@@ -0,0 +1,804 @@
+//! This module implements the `Temporal.PlainDateTime` object.
+//!
+//! A `Temporal.PlainDateTime` is a calendar date and a wall-clock time, which are not
+//! associated with a time zone.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime
+
+use super::{
+    calendar::Calendar,
+    difference::difference_plain_date_time_with_rounding,
+    duration::{Duration, DurationRecord},
+    fields::{reject_object_with_calendar_or_time_zone, Field, TemporalFields},
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_difference_settings, get_options_object, get_time_rounding_options,
+        to_seconds_string_precision, to_temporal_disambiguation, to_temporal_overflow, Overflow,
+        Precision, Unit, UnitGroup,
+    },
+    parser::parse_date_time,
+    plain_date::PlainDate,
+    plain_time::PlainTime,
+    time_zone::TimeZone,
+    to_integer_with_truncation, value_of,
+    zoned_date_time::ZonedDateTime,
+    Component,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::JsVariant,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The internal representation of a `Temporal.PlainDateTime` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PlainDateTime {
+    #[unsafe_ignore_trace]
+    pub(crate) iso: IsoDateTime,
+    pub(crate) calendar: JsObject,
+}
+
+impl PlainDateTime {
+    const NAME: &'static str = "PlainDateTime";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_calendar = FunctionBuilder::native(context, Self::get_calendar)
+            .name("get calendar")
+            .build();
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .plain_date_time()
+                .clone(),
+        );
+        builder
+            .name(Self::NAME)
+            .length(3)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainDateTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                "calendar",
+                Some(get_calendar),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::with, "with", 1)
+            .method(Self::with_plain_time, "withPlainTime", 0)
+            .method(Self::with_plain_date, "withPlainDate", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::to_plain_date, "toPlainDate", 0)
+            .method(Self::to_plain_time, "toPlainTime", 0)
+            .method(Self::get_iso_fields, "getISOFields", 0);
+
+        let components = [&Component::DATE[..], &Component::TIME[..]].concat();
+        Component::add_getters(&mut builder, &components, |this, _| {
+            Ok(this_plain_date_time(this)?.iso)
+        });
+
+        builder.build()
+    }
+
+    /// Creates a new `PlainDateTime` with the given calendar.
+    pub(crate) const fn new(iso: IsoDateTime, calendar: JsObject) -> Self {
+        Self { iso, calendar }
+    }
+
+    /// `Temporal.PlainDateTime ( isoYear, isoMonth, isoDay [ , hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond [ , calendarLike ] ] ] ] ] ] ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        //     a. Throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.PlainDateTime must be called with new")
+                .into());
+        }
+
+        // 2. Set isoYear to ? ToIntegerWithTruncation(isoYear).
+        // 3. Set isoMonth to ? ToIntegerWithTruncation(isoMonth).
+        // 4. Set isoDay to ? ToIntegerWithTruncation(isoDay).
+        let year = to_integer_with_truncation(args.get_or_undefined(0), context)?;
+        let month = to_integer_with_truncation(args.get_or_undefined(1), context)?;
+        let day = to_integer_with_truncation(args.get_or_undefined(2), context)?;
+
+        // 5-10. If the time argument is undefined, let the field be 0, otherwise ? ToIntegerWithTruncation(argument).
+        let mut fields = [0.0; 6];
+        for (index, field) in fields.iter_mut().enumerate() {
+            let value = args.get_or_undefined(index + 3);
+            if !value.is_undefined() {
+                *field = to_integer_with_truncation(value, context)?;
+            }
+        }
+
+        // 11. Let calendar be ? ToTemporalCalendarWithISODefault(calendarLike).
+        let calendar =
+            Calendar::to_temporal_calendar_with_iso_default(args.get_or_undefined(9), context)?;
+
+        // 12. If IsValidISODate(isoYear, isoMonth, isoDay) is false, throw a RangeError exception.
+        // 13. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+        let date = IsoDate::new(year as i64, month as i64, day as i64)
+            .ok_or_else(|| JsNativeError::range().with_message("the date is not valid"))?;
+        let time = IsoTime::regulate(fields, Overflow::Reject)?;
+
+        // 14. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+        // 15. Return ? CreateTemporalDateTime(isoDateTime, calendar, NewTarget).
+        Self::new(IsoDateTime::new(date, time), calendar)
+            .create(Some(new_target), context)
+            .map(Into::into)
+    }
+
+    /// Abstract operation `CreateTemporalDateTime ( isoDateTime, calendar [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldatetime
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If ISODateTimeWithinLimits(isoDateTime) is false, then
+        //     a. Throw a RangeError exception.
+        self.iso.check_limits()?;
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.PlainDateTime%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainDateTime.prototype%", « [[InitializedTemporalDateTime]], [[ISODateTime]], [[Calendar]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::plain_date_time,
+                context,
+            )?,
+            None => context
+                .intrinsics()
+                .constructors()
+                .plain_date_time()
+                .prototype(),
+        };
+
+        // 4. Set object.[[ISODateTime]] to isoDateTime.
+        // 5. Set object.[[Calendar]] to calendar.
+        // 6. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_date_time(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalDateTime ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldatetime
+    pub(crate) fn to_temporal_date_time(
+        item: &JsValue,
+        options: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        match item.variant() {
+            // 2. If item is an Object, then
+            JsVariant::Object(object) => {
+                // a. If item has an [[InitializedTemporalDateTime]] internal slot, then
+                //     i-ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalDateTime(item.[[ISODateTime]], item.[[Calendar]]).
+                // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+                //     ii-iii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iv. Return ! CreateTemporalDateTime(isoDateTime, item.[[Calendar]]).
+                // c. If item has an [[InitializedTemporalDate]] internal slot, then
+                //     i-ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Let isoDateTime be CombineISODateAndTimeRecord(item.[[ISODate]], MidnightTimeRecord()).
+                //     iv. Return ? CreateTemporalDateTime(isoDateTime, item.[[Calendar]]).
+                let date_time = {
+                    let borrowed = object.borrow();
+                    if let Some(date_time) = borrowed.as_plain_date_time() {
+                        Some(date_time.clone())
+                    } else if let Some(zoned) = borrowed.as_zoned_date_time() {
+                        Some(Self::new(zoned.date_time(context), zoned.calendar.clone()))
+                    } else {
+                        borrowed.as_plain_date().map(|date| {
+                            Self::new(
+                                IsoDateTime::new(date.iso, IsoTime::default()),
+                                date.calendar.clone(),
+                            )
+                        })
+                    }
+                };
+                if let Some(date_time) = date_time {
+                    to_temporal_overflow(options, context)?;
+                    return Ok(date_time);
+                }
+
+                // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+                let calendar = Calendar::get_temporal_calendar_with_iso_default(object, context)?;
+
+                // e. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond », «»).
+                let fields = TemporalFields::prepare(object, Field::DATE_TIME, Some(&[]), context)?;
+
+                // f-g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+                // h. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+                let overflow = to_temporal_overflow(options, context)?;
+                let date = fields.date(overflow)?;
+                let time = fields.time(overflow)?;
+
+                // i. Return ? CreateTemporalDateTime(result, calendar).
+                Ok(Self::new(IsoDateTime::new(date, time), calendar))
+            }
+            // 3. If item is not a String, throw a TypeError exception.
+            JsVariant::String(string) => {
+                // 4. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[~Zoned] »).
+                let parsed = parse_date_time(&string.to_std_string_escaped())?;
+
+                // 5. If result.[[Time]] is start-of-day, let time be MidnightTimeRecord(); else let time be result.[[Time]].
+                // 6-9. Let calendar be result.[[Calendar]], which must be a supported calendar.
+                Calendar::check_annotation(parsed.calendar.as_deref())?;
+
+                // 10-11. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                to_temporal_overflow(options, context)?;
+
+                // 12. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+                // 13. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+                // 14. Return ? CreateTemporalDateTime(isoDateTime, calendar).
+                let date = parsed.date.expect("a date-time string must have a date");
+                let date_time = IsoDateTime::new(date, parsed.time.unwrap_or_default());
+                Ok(Self::new(
+                    date_time.check_limits()?,
+                    Calendar::create(context),
+                ))
+            }
+            _ => Err(JsNativeError::typ()
+                .with_message("a date-time must be a Temporal object, a property bag or a string")
+                .into()),
+        }
+    }
+
+    /// `Temporal.PlainDateTime.from ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalDateTime(item, options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        Self::to_temporal_date_time(args.get_or_undefined(0), options.as_ref(), context)?
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDateTime(one).
+        // 2. Set two to ? ToTemporalDateTime(two).
+        let one = Self::to_temporal_date_time(args.get_or_undefined(0), None, context)?;
+        let two = Self::to_temporal_date_time(args.get_or_undefined(1), None, context)?;
+
+        // 3. Return 𝔽(CompareISODateTime(one.[[ISODateTime]], two.[[ISODateTime]])).
+        Ok((one.iso.cmp(&two.iso) as i32).into())
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.calendar`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.calendar
+    fn get_calendar(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(this_plain_date_time(this)?.calendar.clone().into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.with ( temporalDateTimeLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalDateTimeLike) is false, throw a TypeError exception.
+        let like = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("the argument of with must be an object")
+        })?;
+        reject_object_with_calendar_or_time_zone(like, context)?;
+
+        // 4. Let calendar be dateTime.[[Calendar]].
+        // 5. Let fields be ISODateToFields(calendar, dateTime.[[ISODateTime]].[[ISODate]], date).
+        // 6-11. Set fields.[[Hour]], ..., fields.[[Nanosecond]] to the time of dateTime.
+        // 12. Let partialDateTime be ? PrepareCalendarFields(calendar, temporalDateTimeLike, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond », partial).
+        // 13. Set fields to CalendarMergeFields(calendar, fields, partialDateTime).
+        let partial = TemporalFields::prepare(like, Field::DATE_TIME, None, context)?;
+        let fields = TemporalFields::from_date_time(date_time.iso).merge(partial);
+
+        // 14. Let resolvedOptions be ? GetOptionsObject(options).
+        // 15. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+
+        // 16. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        // 17. Return ? CreateTemporalDateTime(result, calendar).
+        let date = fields.date(overflow)?;
+        let time = fields.time(overflow)?;
+        Self::new(IsoDateTime::new(date, time), date_time.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withPlainTime ( [ plainTimeLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withplaintime
+    fn with_plain_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3. Let time be ? ToTimeRecordOrMidnight(plainTimeLike).
+        let time = args.get_or_undefined(0);
+        let time = if time.is_undefined() {
+            IsoTime::default()
+        } else {
+            PlainTime::to_temporal_time(time, None, context)?
+        };
+
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(dateTime.[[ISODateTime]].[[ISODate]], time).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, dateTime.[[Calendar]]).
+        Self::new(
+            IsoDateTime::new(date_time.iso.date, time),
+            date_time.calendar.clone(),
+        )
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withPlainDate ( plainDateLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withplaindate
+    fn with_plain_date(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3. Let plainDate be ? ToTemporalDate(plainDateLike).
+        // 4. Let calendar be ? ConsolidateCalendars(dateTime.[[Calendar]], plainDate.[[Calendar]]).
+        let date = PlainDate::to_temporal_date(args.get_or_undefined(0), None, context)?;
+
+        // 5. Let isoDateTime be CombineISODateAndTimeRecord(plainDate.[[ISODate]], dateTime.[[ISODateTime]].[[Time]]).
+        // 6. Return ? CreateTemporalDateTime(isoDateTime, calendar).
+        Self::new(
+            IsoDateTime::new(date.iso, date_time.iso.time),
+            date.calendar.clone(),
+        )
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withCalendar ( calendarLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withcalendar
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3. Let calendar be ? ToTemporalCalendar(calendarLike).
+        let calendar = Calendar::to_temporal_calendar(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalDateTime(dateTime.[[ISODateTime]], calendar).
+        Self::new(date_time.iso, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.add ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? AddDurationToDateTime(add, dateTime, temporalDurationLike, options).
+        Self::add_duration(false, this, args, context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.subtract ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? AddDurationToDateTime(subtract, dateTime, temporalDurationLike, options).
+        Self::add_duration(true, this, args, context)
+    }
+
+    /// Abstract operation `AddDurationToDateTime ( operation, dateTime, temporalDurationLike, options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodatetime
+    fn add_duration(
+        subtract: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date_time = this_plain_date_time(this)?;
+
+        // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::to_temporal_duration(args.get_or_undefined(0), context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+
+        // 5. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+        // 6. Let timeResult be AddTime(dateTime.[[ISODateTime]].[[Time]], internalDuration.[[Time]]).
+        // 7. Let dateDuration be ? AdjustDateDurationRecord(internalDuration.[[Date]], timeResult.[[Days]]).
+        // 8. Let addedDate be ? CalendarDateAdd(calendar, dateTime.[[ISODateTime]].[[ISODate]], dateDuration, overflow).
+        // 9. Let result be CombineISODateAndTimeRecord(addedDate, timeResult).
+        let internal = duration.to_internal_with_24_hour_days();
+        let result = date_time.iso.add(internal.date, internal.time, overflow)?;
+
+        // 10. Return ? CreateTemporalDateTime(result, calendar).
+        Self::new(result, date_time.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalPlainDateTime(until, dateTime, other, options).
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalPlainDateTime(since, dateTime, other, options).
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainDateTime ( operation, dateTime, other, options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindatetime
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date_time = this_plain_date_time(this)?;
+
+        // 1. Set other to ? ToTemporalDateTime(other).
+        // 2. If CalendarEquals(dateTime.[[Calendar]], other.[[Calendar]]) is false, throw a RangeError exception.
+        let other = Self::to_temporal_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, datetime, « », nanosecond, day).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::DateTime,
+            Unit::Nanosecond,
+            Unit::Day,
+            context,
+        )?;
+
+        // 5. If CompareISODateTime(dateTime.[[ISODateTime]], other.[[ISODateTime]]) = 0, then
+        //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        // 6. Let internalDuration be ? DifferencePlainDateTimeWithRounding(dateTime.[[ISODateTime]], other.[[ISODateTime]], dateTime.[[Calendar]], settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let internal =
+            difference_plain_date_time_with_rounding(date_time.iso, other.iso, settings, context)?;
+
+        // 7. Let result be ? TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+        // 8. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 9. Return result.
+        let mut result = DurationRecord::from_internal(internal, settings.largest_unit)?;
+        if since {
+            result = result.negated();
+        }
+        result.create(None, context).map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3-13. Let roundingIncrement, smallestUnit and roundingMode be the options of roundTo.
+        let (increment, unit, mode) =
+            get_time_rounding_options(args.get_or_undefined(0), true, context)?;
+
+        // 14. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        //     a. Return ! CreateTemporalDateTime(dateTime.[[ISODateTime]], dateTime.[[Calendar]]).
+        // 15. Let result be RoundISODateTime(dateTime.[[ISODateTime]], roundingIncrement, smallestUnit, roundingMode).
+        // 16. Return ? CreateTemporalDateTime(result, dateTime.[[Calendar]]).
+        let result = date_time.iso.round(increment, unit, mode);
+        Self::new(result, date_time.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3. Set other to ? ToTemporalDateTime(other).
+        let other = Self::to_temporal_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 4. If CompareISODateTime(dateTime.[[ISODateTime]], other.[[ISODateTime]]) ≠ 0, return false.
+        // 5. Return CalendarEquals(dateTime.[[Calendar]], other.[[Calendar]]).
+        Ok((date_time.iso == other.iso).into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tostring
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        // 5-9. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        let options = get_options_object(args.get_or_undefined(0))?;
+        let show_calendar = Calendar::to_calendar_name_option(options.as_ref(), context)?;
+        let (rounding, mode) = to_seconds_string_precision(options.as_ref(), context)?;
+
+        // 10. Let result be RoundISODateTime(dateTime.[[ISODateTime]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        // 11. If ISODateTimeWithinLimits(result) is false, throw a RangeError exception.
+        let result = date_time
+            .iso
+            .round(rounding.increment, rounding.unit, mode)
+            .check_limits()?;
+
+        // 12. Return ISODateTimeToString(result, dateTime.[[Calendar]], precision.[[Precision]], showCalendar).
+        Ok(format!(
+            "{}T{}{}",
+            result.date,
+            result.time.to_string_with_precision(rounding.precision),
+            Calendar::format_annotation(show_calendar)
+        )
+        .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tojson
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ISODateTimeToString(dateTime.[[ISODateTime]], dateTime.[[Calendar]], auto, auto).
+        let iso = this_plain_date_time(this)?.iso;
+        Ok(format!(
+            "{}T{}",
+            iso.date,
+            iso.time.to_string_with_precision(Precision::Auto)
+        )
+        .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toZonedDateTime ( temporalTimeZoneLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tozoneddatetime
+    fn to_zoned_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        let time_zone = TimeZone::to_temporal_time_zone(args.get_or_undefined(0), context)?;
+
+        // 4. Let resolvedOptions be ? GetOptionsObject(options).
+        // 5. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let disambiguation = to_temporal_disambiguation(options.as_ref(), context)?;
+
+        // 6. Let epochNs be ? GetEpochNanosecondsFor(timeZone, dateTime.[[ISODateTime]], disambiguation).
+        let epoch_nanoseconds = TimeZone::of(&time_zone).epoch_nanoseconds_for(
+            date_time.iso,
+            disambiguation,
+            context,
+        )?;
+
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, dateTime.[[Calendar]]).
+        ZonedDateTime::new(epoch_nanoseconds, time_zone, date_time.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toPlainDate ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaindate
+    fn to_plain_date(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ! CreateTemporalDate(dateTime.[[ISODateTime]].[[ISODate]], dateTime.[[Calendar]]).
+        let date_time = this_plain_date_time(this)?;
+        PlainDate::new(date_time.iso.date, date_time.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toPlainTime ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaintime
+    fn to_plain_time(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ! CreateTemporalTime(dateTime.[[ISODateTime]].[[Time]]).
+        let date_time = this_plain_date_time(this)?;
+        let calendar = Calendar::create(context);
+        PlainTime::new(date_time.iso.time, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.getISOFields ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.getisofields
+    fn get_iso_fields(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this_plain_date_time(this)?;
+
+        // 3. Let fields be OrdinaryObjectCreate(%Object.prototype%).
+        // 4-13. Perform ! CreateDataPropertyOrThrow(fields, "calendar", dateTime.[[Calendar]]), and the same for the ISO fields.
+        // 14. Return fields.
+        let fields = context.construct_object();
+        let IsoDateTime { date, time } = date_time.iso;
+        for (key, value) in [
+            ("calendar", JsValue::from(date_time.calendar.clone())),
+            ("isoDay", date.day.into()),
+            ("isoHour", time.hour.into()),
+            ("isoMicrosecond", time.microsecond.into()),
+            ("isoMillisecond", time.millisecond.into()),
+            ("isoMinute", time.minute.into()),
+            ("isoMonth", date.month.into()),
+            ("isoNanosecond", time.nanosecond.into()),
+            ("isoSecond", time.second.into()),
+            ("isoYear", date.year.into()),
+        ] {
+            fields
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining a property of a new object cannot fail");
+        }
+        Ok(fields.into())
+    }
+}
+
+/// Gets the `this` value of a `Temporal.PlainDateTime` method, which must be a plain date-time.
+fn this_plain_date_time(this: &JsValue) -> JsResult<PlainDateTime> {
+    this.as_object()
+        .and_then(|object| object.borrow().as_plain_date_time().cloned())
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Temporal.PlainDateTime")
+                .into()
+        })
+}
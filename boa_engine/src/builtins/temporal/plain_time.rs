@@ -0,0 +1,677 @@
+//! This module implements the `Temporal.PlainTime` object.
+//!
+//! A `Temporal.PlainTime` is a wall-clock time, with nanosecond precision, which is not
+//! associated with a date nor with a time zone.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaintime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime
+
+use super::{
+    calendar::Calendar,
+    difference::{round_time_duration, InternalDuration},
+    duration::{Duration, DurationRecord},
+    fields::{reject_object_with_calendar_or_time_zone, Field, TemporalFields},
+    iso::{DateDuration, IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_difference_settings, get_options_object, get_time_rounding_options,
+        to_seconds_string_precision, to_temporal_overflow, Disambiguation, Overflow, Precision,
+        Unit, UnitGroup,
+    },
+    parser::parse_time_string,
+    plain_date::PlainDate,
+    plain_date_time::PlainDateTime,
+    time_zone::TimeZone,
+    to_integer_with_truncation, value_of,
+    zoned_date_time::ZonedDateTime,
+    Component,
+};
+use crate::{
+    builtins::JsArgs,
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::JsVariant,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The internal representation of a `Temporal.PlainTime` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PlainTime {
+    #[unsafe_ignore_trace]
+    pub(crate) iso: IsoTime,
+    pub(crate) calendar: JsObject,
+}
+
+impl PlainTime {
+    const NAME: &'static str = "PlainTime";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_calendar = FunctionBuilder::native(context, Self::get_calendar)
+            .name("get calendar")
+            .build();
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plain_time().clone(),
+        );
+        builder
+            .name(Self::NAME)
+            .length(0)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                "calendar",
+                Some(get_calendar),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 1)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::get_iso_fields, "getISOFields", 0)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0);
+
+        Component::add_getters(&mut builder, &Component::TIME, |this, _| {
+            // The date of the epoch is a placeholder, which time components don't read.
+            Ok(IsoDateTime::new(
+                IsoDate::from_epoch_days(0),
+                this_plain_time(this)?.iso,
+            ))
+        });
+
+        builder.build()
+    }
+
+    /// Creates a new `PlainTime` with the given calendar.
+    pub(crate) const fn new(iso: IsoTime, calendar: JsObject) -> Self {
+        Self { iso, calendar }
+    }
+
+    /// `Temporal.PlainTime ( [ hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond ] ] ] ] ] ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        //     a. Throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.PlainTime must be called with new")
+                .into());
+        }
+
+        // 2-7. If the argument is undefined, let the field be 0, otherwise ? ToIntegerWithTruncation(argument).
+        let mut fields = [0.0; 6];
+        for (index, field) in fields.iter_mut().enumerate() {
+            let value = args.get_or_undefined(index);
+            if !value.is_undefined() {
+                *field = to_integer_with_truncation(value, context)?;
+            }
+        }
+
+        // 8. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+        let time = IsoTime::regulate(fields, Overflow::Reject)?;
+
+        // 9. Return ? CreateTemporalTime(time, NewTarget).
+        let calendar = Calendar::create(context);
+        Self::new(time, calendar)
+            .create(Some(new_target), context)
+            .map(Into::into)
+    }
+
+    /// Abstract operation `CreateTemporalTime ( time [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporaltime
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If newTarget is not present, set newTarget to %Temporal.PlainTime%.
+        // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainTime.prototype%", « [[InitializedTemporalTime]], [[Time]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::plain_time,
+                context,
+            )?,
+            None => context.intrinsics().constructors().plain_time().prototype(),
+        };
+
+        // 3. Set object.[[Time]] to time.
+        // 4. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_time(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalTime ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaltime
+    pub(crate) fn to_temporal_time(
+        item: &JsValue,
+        options: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<IsoTime> {
+        match item.variant() {
+            // 2. If item is an Object, then
+            JsVariant::Object(object) => {
+                // a. If item has an [[InitializedTemporalTime]] internal slot, then
+                //     i. Let resolvedOptions be ? GetOptionsObject(options).
+                //     ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalTime(item.[[Time]]).
+                // b. If item has an [[InitializedTemporalDateTime]] internal slot, then
+                //     i-iii. Return ! CreateTemporalTime(item.[[ISODateTime]].[[Time]]).
+                // c. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+                //     ii-iv. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+                let time = {
+                    let borrowed = object.borrow();
+                    if let Some(time) = borrowed.as_plain_time() {
+                        Some(time.iso)
+                    } else if let Some(date_time) = borrowed.as_plain_date_time() {
+                        Some(date_time.iso.time)
+                    } else {
+                        borrowed.as_zoned_date_time().map(|zoned| {
+                            TimeZone::of(&zoned.time_zone)
+                                .date_time_for(zoned.epoch_nanoseconds, context)
+                                .time
+                        })
+                    }
+                };
+                if let Some(time) = time {
+                    to_temporal_overflow(options, context)?;
+                    return Ok(time);
+                }
+
+                // d. Let result be ? ToTemporalTimeRecord(item).
+                let fields = TemporalFields::prepare(object, Field::TIME, None, context)?;
+
+                // e. Let resolvedOptions be ? GetOptionsObject(options).
+                // f. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+                // g. Set result to ? RegulateTime(result.[[Hour]], ..., result.[[Nanosecond]], overflow).
+                let overflow = to_temporal_overflow(options, context)?;
+                fields.time(overflow)
+            }
+            // 3. Else,
+            //     a. If item is not a String, throw a TypeError exception.
+            //     b. Let parseResult be ? ParseTemporalTimeString(item).
+            //     c. Assert: IsValidTime(...) is true.
+            //     d. Set result to parseResult.[[Time]].
+            //     e-f. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            JsVariant::String(string) => {
+                let parsed = parse_time_string(&string.to_std_string_escaped())?;
+                Calendar::check_annotation(parsed.calendar.as_deref())?;
+                to_temporal_overflow(options, context)?;
+                Ok(parsed.time.unwrap_or_default())
+            }
+            _ => Err(JsNativeError::typ()
+                .with_message("a time must be a Temporal object, a property bag or a string")
+                .into()),
+        }
+    }
+
+    /// `Temporal.PlainTime.from ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalTime(item, options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let time = Self::to_temporal_time(args.get_or_undefined(0), options.as_ref(), context)?;
+        let calendar = Calendar::create(context);
+        Self::new(time, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainTime.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalTime(one).
+        // 2. Set two to ? ToTemporalTime(two).
+        let one = Self::to_temporal_time(args.get_or_undefined(0), None, context)?;
+        let two = Self::to_temporal_time(args.get_or_undefined(1), None, context)?;
+
+        // 3. Return 𝔽(CompareTimeRecord(one.[[Time]], two.[[Time]])).
+        Ok((one.cmp(&two) as i32).into())
+    }
+
+    /// `get Temporal.PlainTime.prototype.calendar`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.plaintime.prototype.calendar
+    fn get_calendar(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(this_plain_time(this)?.calendar.clone().into())
+    }
+
+    /// `Temporal.PlainTime.prototype.add ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? AddDurationToTime(add, temporalTime, temporalDurationLike).
+        Self::add_duration(false, this, args, context)
+    }
+
+    /// `Temporal.PlainTime.prototype.subtract ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? AddDurationToTime(subtract, temporalTime, temporalDurationLike).
+        Self::add_duration(true, this, args, context)
+    }
+
+    /// Abstract operation `AddDurationToTime ( operation, temporalTime, temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtotime
+    fn add_duration(
+        subtract: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let time = this_plain_time(this)?;
+
+        // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::to_temporal_duration(args.get_or_undefined(0), context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 3. Let internalDuration be ToInternalDurationRecord(duration).
+        // 4. Let result be AddTime(temporalTime.[[Time]], internalDuration.[[Time]]).
+        // 5. Return ! CreateTemporalTime(result).
+        let (_, result) =
+            IsoTime::from_nanoseconds(time.iso.to_nanoseconds() + duration.time_duration());
+        Self::new(result, time.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainTime.prototype.with ( temporalTimeLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = this_plain_time(this)?;
+
+        // 3. If temporalTimeLike is not an Object, then
+        //     a. Throw a TypeError exception.
+        let like = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("the argument of with must be an object")
+        })?;
+
+        // 4. Perform ? RejectTemporalLikeObject(temporalTimeLike).
+        reject_object_with_calendar_or_time_zone(like, context)?;
+
+        // 5. Let partialTime be ? ToTemporalTimeRecord(temporalTimeLike, partial).
+        let partial = TemporalFields::prepare(like, Field::TIME, None, context)?;
+
+        // 6-17. If a field of partialTime is not undefined, use it, otherwise use the field of temporalTime.
+        let iso = time.iso;
+        let fields = TemporalFields {
+            hour: Some(f64::from(iso.hour)),
+            minute: Some(f64::from(iso.minute)),
+            second: Some(f64::from(iso.second)),
+            millisecond: Some(f64::from(iso.millisecond)),
+            microsecond: Some(f64::from(iso.microsecond)),
+            nanosecond: Some(f64::from(iso.nanosecond)),
+            ..TemporalFields::default()
+        }
+        .merge(partial);
+
+        // 18. Let resolvedOptions be ? GetOptionsObject(options).
+        // 19. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+
+        // 20. Let result be ? RegulateTime(hour, minute, second, millisecond, microsecond, nanosecond, overflow).
+        // 21. Return ! CreateTemporalTime(result).
+        Self::new(fields.time(overflow)?, time.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainTime.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalPlainTime(until, temporalTime, other, options).
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.PlainTime.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalPlainTime(since, temporalTime, other, options).
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainTime ( operation, temporalTime, other, options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaintime
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let time = this_plain_time(this)?;
+
+        // 1. Set other to ? ToTemporalTime(other).
+        let other = Self::to_temporal_time(args.get_or_undefined(0), None, context)?;
+
+        // 2. Let resolvedOptions be ? GetOptionsObject(options).
+        // 3. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, time, « », nanosecond, hour).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::Time,
+            Unit::Nanosecond,
+            Unit::Hour,
+            context,
+        )?;
+
+        // 4. Let timeDuration be DifferenceTime(temporalTime.[[Time]], other.[[Time]]).
+        // 5. Set timeDuration to ! RoundTimeDuration(timeDuration, settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let time_duration = round_time_duration(
+            other.to_nanoseconds() - time.iso.to_nanoseconds(),
+            settings.rounding_increment,
+            settings.smallest_unit,
+            settings.rounding_mode,
+        )?;
+
+        // 6. Let duration be CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+        // 7. Let result be ! TemporalDurationFromInternal(duration, settings.[[LargestUnit]]).
+        // 8. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 9. Return result.
+        let mut result = DurationRecord::from_internal(
+            InternalDuration::new(DateDuration::default(), time_duration),
+            settings.largest_unit,
+        )?;
+        if since {
+            result = result.negated();
+        }
+        result.create(None, context).map(Into::into)
+    }
+
+    /// `Temporal.PlainTime.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = this_plain_time(this)?;
+
+        // 3-12. Let roundingIncrement, smallestUnit and roundingMode be the options of roundTo.
+        let (increment, unit, mode) =
+            get_time_rounding_options(args.get_or_undefined(0), false, context)?;
+
+        // 13. Let result be RoundTime(temporalTime.[[Time]], roundingIncrement, smallestUnit, roundingMode).
+        // 14. Return ! CreateTemporalTime(result).
+        let (_, result) = time.iso.round(increment, unit, mode);
+        Self::new(result, time.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainTime.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = this_plain_time(this)?;
+
+        // 3. Set other to ? ToTemporalTime(other).
+        let other = Self::to_temporal_time(args.get_or_undefined(0), None, context)?;
+
+        // 4. If CompareTimeRecord(temporalTime.[[Time]], other.[[Time]]) = 0, return true.
+        // 5. Return false.
+        Ok((time.iso == other).into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toPlainDateTime ( temporalDate )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.toplaindatetime
+    fn to_plain_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = this_plain_time(this)?;
+
+        // 3. Set temporalDate to ? ToTemporalDate(temporalDate).
+        let date = PlainDate::to_temporal_date(args.get_or_undefined(0), None, context)?;
+
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], temporalTime.[[Time]]).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, temporalDate.[[Calendar]]).
+        PlainDateTime::new(IsoDateTime::new(date.iso, time.iso), date.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainTime.prototype.toZonedDateTime ( item )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tozoneddatetime
+    fn to_zoned_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = this_plain_time(this)?;
+
+        // 3. If Type(item) is not Object, then
+        //     a. Throw a TypeError exception.
+        let item = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("toZonedDateTime requires an options object")
+        })?;
+
+        // 4. Let temporalDateLike be ? Get(item, "plainDate").
+        // 5. If temporalDateLike is undefined, then
+        //     a. Throw a TypeError exception.
+        // 6. Let temporalDate be ? ToTemporalDate(temporalDateLike).
+        let date_like = item.get("plainDate", context)?;
+        if date_like.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("toZonedDateTime requires a plainDate")
+                .into());
+        }
+        let date = PlainDate::to_temporal_date(&date_like, None, context)?;
+
+        // 7. Let temporalTimeZoneLike be ? Get(item, "timeZone").
+        // 8. If temporalTimeZoneLike is undefined, then
+        //     a. Throw a TypeError exception.
+        // 9. Let timeZone be ? ToTemporalTimeZone(temporalTimeZoneLike).
+        let time_zone_like = item.get("timeZone", context)?;
+        if time_zone_like.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("toZonedDateTime requires a time zone")
+                .into());
+        }
+        let time_zone = TimeZone::to_temporal_time_zone(&time_zone_like, context)?;
+
+        // 10. Let temporalDateTime be ? CreateTemporalDateTime(temporalDate.[[ISOYear]], ..., temporalTime.[[ISONanosecond]], temporalDate.[[Calendar]]).
+        // 11. Let instant be ? BuiltinTimeZoneGetInstantFor(timeZone, temporalDateTime, "compatible").
+        // 12. Return ! CreateTemporalZonedDateTime(instant.[[Nanoseconds]], timeZone, temporalDate.[[Calendar]]).
+        let date_time = IsoDateTime::new(date.iso, time.iso).check_limits()?;
+        let epoch_nanoseconds = TimeZone::of(&time_zone).epoch_nanoseconds_for(
+            date_time,
+            Disambiguation::Compatible,
+            context,
+        )?;
+        ZonedDateTime::new(epoch_nanoseconds, time_zone, date.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.PlainTime.prototype.getISOFields ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.getisofields
+    fn get_iso_fields(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = this_plain_time(this)?;
+
+        // 3. Let fields be OrdinaryObjectCreate(%Object.prototype%).
+        // 4-10. Perform ! CreateDataPropertyOrThrow(fields, "calendar", temporalTime.[[Calendar]]), and the same for the ISO fields.
+        // 11. Return fields.
+        let fields = context.construct_object();
+        let iso = time.iso;
+        for (key, value) in [
+            ("calendar", JsValue::from(time.calendar.clone())),
+            ("isoHour", iso.hour.into()),
+            ("isoMicrosecond", iso.microsecond.into()),
+            ("isoMillisecond", iso.millisecond.into()),
+            ("isoMinute", iso.minute.into()),
+            ("isoNanosecond", iso.nanosecond.into()),
+            ("isoSecond", iso.second.into()),
+        ] {
+            fields
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining a property of a new object cannot fail");
+        }
+        Ok(fields.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tostring
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = this_plain_time(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4-8. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        let options = get_options_object(args.get_or_undefined(0))?;
+        let (rounding, mode) = to_seconds_string_precision(options.as_ref(), context)?;
+
+        // 9. Let roundResult be RoundTime(temporalTime.[[Time]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        // 10. Return TimeRecordToString(roundResult, precision.[[Precision]]).
+        let (_, rounded) = time.iso.round(rounding.increment, rounding.unit, mode);
+        Ok(rounded.to_string_with_precision(rounding.precision).into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tojson
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        // 3. Return TimeRecordToString(temporalTime.[[Time]], auto).
+        Ok(this_plain_time(this)?
+            .iso
+            .to_string_with_precision(Precision::Auto)
+            .into())
+    }
+}
+
+/// Gets the `this` value of a `Temporal.PlainTime` method, which must be a plain time.
+fn this_plain_time(this: &JsValue) -> JsResult<PlainTime> {
+    this.as_object()
+        .and_then(|object| object.borrow().as_plain_time().cloned())
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Temporal.PlainTime")
+                .into()
+        })
+}
@@ -0,0 +1,793 @@
+//! Time zone providers, which the host uses to give the `Temporal` builtins access to a time
+//! zone database.
+//!
+//! The engine always supports the `UTC` time zone and fixed offset time zones like `+05:30`.
+//! All the other named time zones, like `Europe/Madrid`, are resolved by the
+//! [`TimeZoneProvider`] of the [`Context`][crate::Context].
+
+use super::options::{NS_PER_DAY, NS_PER_SECOND};
+use crate::builtins::temporal::iso::IsoDate;
+use once_cell::unsync::OnceCell;
+use rustc_hash::FxHashMap;
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    fs, io,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+/// A host hook that gives the `Temporal` builtins access to the rules of the named time zones.
+///
+/// This is Boa's version of the time zone host operations of the [`Temporal` proposal][spec].
+/// All the instants are numbers of nanoseconds since the epoch, and all the offsets are numbers
+/// of nanoseconds that must be added to UTC to get the local time.
+///
+/// The `UTC` time zone is always handled by the engine, so providers don't need to support it.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-time-zone-operations
+pub trait TimeZoneProvider: Debug {
+    /// Gets the identifier of the available named time zone `identifier`, compared
+    /// case-insensitively, with the case used by the time zone database.
+    ///
+    /// Returns `None` if the time zone is not available.
+    fn get_available_identifier(&self, identifier: &str) -> Option<String>;
+
+    /// Gets the UTC offset of the time zone `identifier` at the exact time
+    /// `epoch_nanoseconds`.
+    fn get_offset_nanoseconds_for(&self, identifier: &str, epoch_nanoseconds: i128) -> i64;
+
+    /// Gets the exact times at which the wall-clock time of the time zone `identifier` is the
+    /// time that `local_nanoseconds` represents in UTC, sorted from the earliest.
+    ///
+    /// This is empty if the wall-clock time is skipped by a transition, and has more than one
+    /// element if it is repeated by a transition. The default implementation assumes that there
+    /// is at most one transition in the two days around the wall-clock time.
+    fn get_possible_epoch_nanoseconds(
+        &self,
+        identifier: &str,
+        local_nanoseconds: i128,
+    ) -> Vec<i128> {
+        let before = self.get_offset_nanoseconds_for(identifier, local_nanoseconds - NS_PER_DAY);
+        let after = self.get_offset_nanoseconds_for(identifier, local_nanoseconds + NS_PER_DAY);
+
+        let mut possible = Vec::with_capacity(2);
+        for offset in [before, after] {
+            let candidate = local_nanoseconds - i128::from(offset);
+            if self.get_offset_nanoseconds_for(identifier, candidate) == offset
+                && !possible.contains(&candidate)
+            {
+                possible.push(candidate);
+            }
+        }
+        possible.sort_unstable();
+        possible
+    }
+
+    /// Gets the first exact time after `epoch_nanoseconds` at which the UTC offset of the time
+    /// zone `identifier` changes, or `None` if there is no such transition.
+    fn get_next_transition(&self, _identifier: &str, _epoch_nanoseconds: i128) -> Option<i128> {
+        None
+    }
+
+    /// Gets the last exact time before `epoch_nanoseconds` at which the UTC offset of the time
+    /// zone `identifier` changed, or `None` if there is no such transition.
+    fn get_previous_transition(&self, _identifier: &str, _epoch_nanoseconds: i128) -> Option<i128> {
+        None
+    }
+
+    /// Gets the identifier of the time zone of the host, which is used by `Temporal.Now`.
+    ///
+    /// If this returns `None`, or a time zone that is not available, the engine uses `UTC`.
+    fn system_time_zone_identifier(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A time zone provider without a time zone database, which only supports the `UTC` time zone
+/// and fixed offset time zones.
+///
+/// This is the default time zone provider of a [`Context`][crate::Context].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UtcTimeZoneProvider;
+
+impl TimeZoneProvider for UtcTimeZoneProvider {
+    fn get_available_identifier(&self, _identifier: &str) -> Option<String> {
+        None
+    }
+
+    fn get_offset_nanoseconds_for(&self, _identifier: &str, _epoch_nanoseconds: i128) -> i64 {
+        0
+    }
+}
+
+/// A time zone provider that reads the time zone database from a directory of [TZif][rfc]
+/// files, like the `/usr/share/zoneinfo` directory of most Unix systems.
+///
+/// The files are read lazily, the first time that a time zone is used, and are cached for the
+/// lifetime of the provider. This doesn't need a network connection.
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc8536
+#[derive(Debug)]
+pub struct ZoneInfoTimeZoneProvider {
+    root: PathBuf,
+    /// The identifiers of the time zones of the directory, by their lowercase form.
+    identifiers: OnceCell<FxHashMap<String, String>>,
+    zones: RefCell<FxHashMap<String, Option<Rc<Tzif>>>>,
+}
+
+impl ZoneInfoTimeZoneProvider {
+    /// The default location of the time zone database on Unix systems.
+    const SYSTEM_DIRECTORY: &'static str = "/usr/share/zoneinfo";
+
+    /// Creates a new `ZoneInfoTimeZoneProvider` that reads the time zone database from the
+    /// directory `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `root` cannot be canonicalized or is not a directory.
+    pub fn new<P: AsRef<Path>>(root: P) -> io::Result<Self> {
+        let root = root.as_ref().canonicalize()?;
+        if !root.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not a directory", root.display()),
+            ));
+        }
+        Ok(Self {
+            root,
+            identifiers: OnceCell::new(),
+            zones: RefCell::default(),
+        })
+    }
+
+    /// Creates a new `ZoneInfoTimeZoneProvider` that reads the time zone database of the
+    /// system, from the directory in the `TZDIR` environment variable or from
+    /// `/usr/share/zoneinfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory does not exist.
+    pub fn from_system() -> io::Result<Self> {
+        let root = std::env::var_os("TZDIR")
+            .map_or_else(|| PathBuf::from(Self::SYSTEM_DIRECTORY), PathBuf::from);
+        Self::new(root)
+    }
+
+    /// Lists the identifiers of all the time zones of the directory.
+    fn identifiers(&self) -> &FxHashMap<String, String> {
+        fn walk(root: &Path, dir: &Path, identifiers: &mut FxHashMap<String, String>) {
+            let entries = if let Ok(entries) = fs::read_dir(dir) {
+                entries
+            } else {
+                return;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                // Skip the duplicated databases with leap seconds and the metadata files.
+                if name.starts_with('.')
+                    || name.contains('.')
+                    || name == "posix"
+                    || name == "right"
+                    || name == "posixrules"
+                    || name == "localtime"
+                {
+                    continue;
+                }
+                if path.is_dir() {
+                    walk(root, &path, identifiers);
+                } else if is_tzif_file(&path) {
+                    if let Ok(relative) = path.strip_prefix(root) {
+                        let identifier = relative
+                            .components()
+                            .map(|c| c.as_os_str().to_string_lossy())
+                            .collect::<Vec<_>>()
+                            .join("/");
+                        identifiers.insert(identifier.to_ascii_lowercase(), identifier);
+                    }
+                }
+            }
+        }
+
+        self.identifiers.get_or_init(|| {
+            let mut identifiers = FxHashMap::default();
+            walk(&self.root, &self.root, &mut identifiers);
+            identifiers
+        })
+    }
+
+    /// Gets the parsed `TZif` file of the time zone `identifier`.
+    fn zone(&self, identifier: &str) -> Option<Rc<Tzif>> {
+        if let Some(zone) = self.zones.borrow().get(identifier) {
+            return zone.clone();
+        }
+
+        let zone = fs::read(self.root.join(identifier))
+            .ok()
+            .and_then(|bytes| Tzif::parse(&bytes))
+            .map(Rc::new);
+        self.zones
+            .borrow_mut()
+            .insert(identifier.to_owned(), zone.clone());
+        zone
+    }
+}
+
+impl TimeZoneProvider for ZoneInfoTimeZoneProvider {
+    fn get_available_identifier(&self, identifier: &str) -> Option<String> {
+        self.identifiers()
+            .get(&identifier.to_ascii_lowercase())
+            .cloned()
+    }
+
+    fn get_offset_nanoseconds_for(&self, identifier: &str, epoch_nanoseconds: i128) -> i64 {
+        self.zone(identifier).map_or(0, |zone| {
+            i64::from(zone.offset_at(epoch_nanoseconds.div_euclid(NS_PER_SECOND) as i64))
+                * NS_PER_SECOND as i64
+        })
+    }
+
+    fn get_next_transition(&self, identifier: &str, epoch_nanoseconds: i128) -> Option<i128> {
+        self.zone(identifier)?.next_transition(epoch_nanoseconds)
+    }
+
+    fn get_previous_transition(&self, identifier: &str, epoch_nanoseconds: i128) -> Option<i128> {
+        self.zone(identifier)?
+            .previous_transition(epoch_nanoseconds)
+    }
+
+    fn system_time_zone_identifier(&self) -> Option<String> {
+        // The `TZ` environment variable can name a time zone, optionally prefixed by `:`.
+        if let Ok(tz) = std::env::var("TZ") {
+            let tz = tz.trim_start_matches(':');
+            let tz = Path::new(tz)
+                .strip_prefix(&self.root)
+                .ok()
+                .and_then(Path::to_str)
+                .unwrap_or(tz);
+            if let Some(identifier) = self.get_available_identifier(tz) {
+                return Some(identifier);
+            }
+        }
+
+        // Otherwise, `/etc/localtime` is usually a link to the file of the time zone.
+        let target = fs::read_link("/etc/localtime").ok()?;
+        let target = target.to_string_lossy();
+        let (_, identifier) = target.rsplit_once("zoneinfo/")?;
+        self.get_available_identifier(identifier)
+    }
+}
+
+/// Checks if the file at `path` starts with the magic bytes of a `TZif` file.
+fn is_tzif_file(path: &Path) -> bool {
+    use std::io::Read;
+
+    let mut magic = [0; 4];
+    fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .map_or(false, |()| &magic == b"TZif")
+}
+
+/// A local time type of a `TZif` file.
+#[derive(Debug, Clone, Copy)]
+struct LocalTimeType {
+    /// The UTC offset, in seconds.
+    offset: i32,
+}
+
+/// The parsed contents of a `TZif` file.
+///
+/// More information:
+///  - [RFC 8536][rfc]
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc8536
+#[derive(Debug)]
+struct Tzif {
+    /// The transition times, in seconds since the epoch.
+    transitions: Vec<i64>,
+    /// The index of the local time type of each transition.
+    transition_types: Vec<u8>,
+    types: Vec<LocalTimeType>,
+    /// The rule of the times after the last transition.
+    footer: Option<PosixTimeZone>,
+}
+
+impl Tzif {
+    /// Parses a `TZif` file of any version.
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        /// The counts of the header of a `TZif` data block.
+        struct Header {
+            isutcnt: usize,
+            isstdcnt: usize,
+            leapcnt: usize,
+            timecnt: usize,
+            typecnt: usize,
+            charcnt: usize,
+        }
+
+        fn header(bytes: &[u8]) -> Option<(u8, Header)> {
+            if bytes.len() < 44 || &bytes[..4] != b"TZif" {
+                return None;
+            }
+            let count = |i: usize| {
+                u32::from_be_bytes([
+                    bytes[20 + i * 4],
+                    bytes[21 + i * 4],
+                    bytes[22 + i * 4],
+                    bytes[23 + i * 4],
+                ]) as usize
+            };
+            Some((
+                bytes[4],
+                Header {
+                    isutcnt: count(0),
+                    isstdcnt: count(1),
+                    leapcnt: count(2),
+                    timecnt: count(3),
+                    typecnt: count(4),
+                    charcnt: count(5),
+                },
+            ))
+        }
+
+        fn block_len(header: &Header, time_size: usize) -> usize {
+            header.timecnt * time_size
+                + header.timecnt
+                + header.typecnt * 6
+                + header.charcnt
+                + header.leapcnt * (time_size + 4)
+                + header.isstdcnt
+                + header.isutcnt
+        }
+
+        let (version, v1_header) = header(bytes)?;
+        let (data, header, time_size) = if version == 0 {
+            (bytes.get(44..)?, v1_header, 4)
+        } else {
+            // Skip the 32-bit data block of version 1, and use the 64-bit one.
+            let v2 = bytes.get(44 + block_len(&v1_header, 4)..)?;
+            let (_, v2_header) = header(v2)?;
+            (v2.get(44..)?, v2_header, 8)
+        };
+        if data.len() < block_len(&header, time_size) {
+            return None;
+        }
+
+        let mut pos = 0;
+        let mut transitions = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            let time = if time_size == 4 {
+                i64::from(i32::from_be_bytes(data[pos..pos + 4].try_into().ok()?))
+            } else {
+                i64::from_be_bytes(data[pos..pos + 8].try_into().ok()?)
+            };
+            transitions.push(time);
+            pos += time_size;
+        }
+
+        let transition_types = data[pos..pos + header.timecnt].to_vec();
+        pos += header.timecnt;
+
+        let mut types = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            types.push(LocalTimeType {
+                offset: i32::from_be_bytes(data[pos..pos + 4].try_into().ok()?),
+            });
+            pos += 6;
+        }
+        if types.is_empty()
+            || transition_types
+                .iter()
+                .any(|&index| usize::from(index) >= types.len())
+        {
+            return None;
+        }
+        pos += header.charcnt + header.leapcnt * (time_size + 4) + header.isstdcnt + header.isutcnt;
+
+        // The footer of version 2 and later files is a POSIX TZ string between newlines.
+        let footer = if version == 0 {
+            None
+        } else {
+            data.get(pos..)
+                .and_then(|footer| std::str::from_utf8(footer).ok())
+                .and_then(|footer| footer.strip_prefix('\n'))
+                .and_then(|footer| footer.split('\n').next())
+                .filter(|footer| !footer.is_empty())
+                .and_then(PosixTimeZone::parse)
+        };
+
+        Some(Self {
+            transitions,
+            transition_types,
+            types,
+            footer,
+        })
+    }
+
+    /// Gets the UTC offset in seconds at `seconds` since the epoch.
+    fn offset_at(&self, seconds: i64) -> i32 {
+        // Local time for timestamps before the first transition is specified by the first time
+        // type, and the footer specifies the local time after the last transition.
+        let index = self.transitions.partition_point(|&t| t <= seconds);
+        if index == 0 {
+            if self.transitions.is_empty() {
+                if let Some(footer) = &self.footer {
+                    return footer.offset_at(seconds);
+                }
+            }
+            return self.types[0].offset;
+        }
+        if index == self.transitions.len() {
+            if let Some(footer) = &self.footer {
+                return footer.offset_at(seconds);
+            }
+        }
+        self.types[usize::from(self.transition_types[index - 1])].offset
+    }
+
+    /// Gets the UTC offset in seconds just before the transition `index`.
+    fn offset_before_transition(&self, index: usize) -> i32 {
+        if index == 0 {
+            self.types[0].offset
+        } else {
+            self.types[usize::from(self.transition_types[index - 1])].offset
+        }
+    }
+
+    /// Gets the first transition that changes the UTC offset after `epoch_nanoseconds`.
+    fn next_transition(&self, epoch_nanoseconds: i128) -> Option<i128> {
+        let start = self
+            .transitions
+            .partition_point(|&t| i128::from(t) * NS_PER_SECOND <= epoch_nanoseconds);
+        for index in start..self.transitions.len() {
+            let offset = self.types[usize::from(self.transition_types[index])].offset;
+            if offset != self.offset_before_transition(index) {
+                return Some(i128::from(self.transitions[index]) * NS_PER_SECOND);
+            }
+        }
+
+        let footer = self.footer.as_ref()?;
+        let mut seconds = epoch_nanoseconds.div_euclid(NS_PER_SECOND) as i64;
+        if let Some(&last) = self.transitions.last() {
+            seconds = seconds.max(last);
+        }
+        // The transitions of the footer alternate between different offsets, but the first
+        // one may not change the offset of the last explicit transition.
+        for _ in 0..2 {
+            let next = footer.next_transition(seconds)?;
+            if self.offset_at(next - 1) != self.offset_at(next) {
+                return Some(i128::from(next) * NS_PER_SECOND);
+            }
+            seconds = next;
+        }
+        None
+    }
+
+    /// Gets the last transition that changed the UTC offset before `epoch_nanoseconds`.
+    fn previous_transition(&self, epoch_nanoseconds: i128) -> Option<i128> {
+        // Get the last second strictly before the instant.
+        let seconds = (epoch_nanoseconds - 1).div_euclid(NS_PER_SECOND) as i64;
+
+        if let Some(footer) = &self.footer {
+            let last = self.transitions.last().copied();
+            if last.map_or(true, |last| seconds > last) {
+                let mut current = seconds + 1;
+                for _ in 0..2 {
+                    match footer.previous_transition(current) {
+                        Some(previous) if last.map_or(true, |last| previous > last) => {
+                            if self.offset_at(previous - 1) != self.offset_at(previous) {
+                                return Some(i128::from(previous) * NS_PER_SECOND);
+                            }
+                            current = previous;
+                        }
+                        _ => break,
+                    }
+                }
+            }
+        }
+
+        let end = self.transitions.partition_point(|&t| t <= seconds);
+        for index in (0..end).rev() {
+            let offset = self.types[usize::from(self.transition_types[index])].offset;
+            if offset != self.offset_before_transition(index) {
+                return Some(i128::from(self.transitions[index]) * NS_PER_SECOND);
+            }
+        }
+        None
+    }
+}
+
+/// A date rule of a POSIX TZ string.
+#[derive(Debug, Clone, Copy)]
+enum PosixDate {
+    /// `Jn`: the day `n` of the year, from 1 to 365, without counting February 29.
+    JulianWithoutLeap(u16),
+    /// `n`: the day `n` of the year, from 0 to 365, counting February 29.
+    Julian(u16),
+    /// `Mm.w.d`: the day `d` of the week (0 is Sunday) of the week `w` of the month `m`, where
+    /// week 5 is the last one.
+    MonthWeekDay { month: u8, week: u8, day: u8 },
+}
+
+impl PosixDate {
+    /// Gets the date of the rule in the year `year`.
+    fn date_in(self, year: i32) -> IsoDate {
+        let january_first = IsoDate {
+            year,
+            month: 1,
+            day: 1,
+        }
+        .epoch_days();
+        match self {
+            Self::JulianWithoutLeap(day) => {
+                let leap_day = i64::from(super::iso::is_leap_year(year) && day >= 60);
+                IsoDate::from_epoch_days(january_first + i64::from(day) - 1 + leap_day)
+            }
+            Self::Julian(day) => IsoDate::from_epoch_days(january_first + i64::from(day)),
+            Self::MonthWeekDay { month, week, day } => {
+                let first = IsoDate {
+                    year,
+                    month,
+                    day: 1,
+                };
+                // Convert the POSIX day of the week, where 0 is Sunday, to the ISO one.
+                let target = if day == 0 { 7 } else { day };
+                let first_match = 1 + (target + 7 - first.day_of_week()) % 7;
+                let mut day = first_match + (week - 1) * 7;
+                if day > super::iso::days_in_month(year, month) {
+                    day -= 7;
+                }
+                IsoDate { year, month, day }
+            }
+        }
+    }
+}
+
+/// The daylight saving time rule of a POSIX TZ string.
+#[derive(Debug, Clone, Copy)]
+struct PosixDst {
+    /// The UTC offset of daylight saving time, in seconds.
+    offset: i32,
+    start: PosixDate,
+    /// The local time of the start, in seconds after midnight of standard time.
+    start_time: i32,
+    end: PosixDate,
+    /// The local time of the end, in seconds after midnight of daylight saving time.
+    end_time: i32,
+}
+
+/// A POSIX TZ string, like `EST5EDT,M3.2.0,M11.1.0`, which describes the rule of a time zone.
+///
+/// More information:
+///  - [POSIX specification][spec]
+///
+/// [spec]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html
+#[derive(Debug, Clone, Copy)]
+struct PosixTimeZone {
+    /// The UTC offset of standard time, in seconds.
+    std_offset: i32,
+    dst: Option<PosixDst>,
+}
+
+impl PosixTimeZone {
+    /// Parses a POSIX TZ string.
+    fn parse(source: &str) -> Option<Self> {
+        struct Parser<'a> {
+            bytes: &'a [u8],
+            pos: usize,
+        }
+
+        impl Parser<'_> {
+            fn peek(&self) -> Option<u8> {
+                self.bytes.get(self.pos).copied()
+            }
+
+            fn eat(&mut self, byte: u8) -> bool {
+                if self.peek() == Some(byte) {
+                    self.pos += 1;
+                    true
+                } else {
+                    false
+                }
+            }
+
+            fn number(&mut self) -> Option<i32> {
+                let start = self.pos;
+                while self.peek().map_or(false, |b| b.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                std::str::from_utf8(&self.bytes[start..self.pos])
+                    .ok()?
+                    .parse()
+                    .ok()
+            }
+
+            fn name(&mut self) -> Option<()> {
+                if self.eat(b'<') {
+                    while self.peek()? != b'>' {
+                        self.pos += 1;
+                    }
+                    self.pos += 1;
+                } else {
+                    let start = self.pos;
+                    while self.peek().map_or(false, |b| b.is_ascii_alphabetic()) {
+                        self.pos += 1;
+                    }
+                    if self.pos - start < 3 {
+                        return None;
+                    }
+                }
+                Some(())
+            }
+
+            /// Parses a `[+|-]hh[:mm[:ss]]` time, in seconds.
+            fn time(&mut self) -> Option<i32> {
+                let sign = if self.eat(b'-') {
+                    -1
+                } else {
+                    self.eat(b'+');
+                    1
+                };
+                let mut seconds = self.number()? * 3600;
+                if self.eat(b':') {
+                    seconds += self.number()? * 60;
+                    if self.eat(b':') {
+                        seconds += self.number()?;
+                    }
+                }
+                Some(sign * seconds)
+            }
+
+            fn date(&mut self) -> Option<(PosixDate, i32)> {
+                let date = if self.eat(b'J') {
+                    PosixDate::JulianWithoutLeap(u16::try_from(self.number()?).ok()?)
+                } else if self.eat(b'M') {
+                    let month = u8::try_from(self.number()?).ok()?;
+                    if !self.eat(b'.') {
+                        return None;
+                    }
+                    let week = u8::try_from(self.number()?).ok()?;
+                    if !self.eat(b'.') {
+                        return None;
+                    }
+                    let day = u8::try_from(self.number()?).ok()?;
+                    if !(1..=12).contains(&month) || !(1..=5).contains(&week) || day > 6 {
+                        return None;
+                    }
+                    PosixDate::MonthWeekDay { month, week, day }
+                } else {
+                    PosixDate::Julian(u16::try_from(self.number()?).ok()?)
+                };
+                let time = if self.eat(b'/') { self.time()? } else { 7200 };
+                Some((date, time))
+            }
+        }
+
+        let mut parser = Parser {
+            bytes: source.as_bytes(),
+            pos: 0,
+        };
+
+        parser.name()?;
+        // POSIX offsets are positive to the west of Greenwich.
+        let std_offset = -parser.time()?;
+        if parser.peek().is_none() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+
+        parser.name()?;
+        let dst_offset = match parser.peek() {
+            Some(b',') | None => std_offset + 3600,
+            _ => -parser.time()?,
+        };
+        let ((start, start_time), (end, end_time)) = if parser.eat(b',') {
+            let start = parser.date()?;
+            if !parser.eat(b',') {
+                return None;
+            }
+            (start, parser.date()?)
+        } else {
+            // The default rule of POSIX is the rule of the United States.
+            (
+                (
+                    PosixDate::MonthWeekDay {
+                        month: 3,
+                        week: 2,
+                        day: 0,
+                    },
+                    7200,
+                ),
+                (
+                    PosixDate::MonthWeekDay {
+                        month: 11,
+                        week: 1,
+                        day: 0,
+                    },
+                    7200,
+                ),
+            )
+        };
+        if parser.peek().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            std_offset,
+            dst: Some(PosixDst {
+                offset: dst_offset,
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+        })
+    }
+
+    /// Gets the exact times, in seconds since the epoch, at which daylight saving time starts
+    /// and ends in the year `year`.
+    fn transitions_in(&self, dst: &PosixDst, year: i32) -> (i64, i64) {
+        let start = dst.start.date_in(year).epoch_days() * 86400 + i64::from(dst.start_time)
+            - i64::from(self.std_offset);
+        let end = dst.end.date_in(year).epoch_days() * 86400 + i64::from(dst.end_time)
+            - i64::from(dst.offset);
+        (start, end)
+    }
+
+    /// Gets the year of the UTC date of `seconds` since the epoch.
+    fn year_of(seconds: i64) -> i32 {
+        IsoDate::from_epoch_days(seconds.div_euclid(86400)).year
+    }
+
+    /// Gets the UTC offset in seconds at `seconds` since the epoch.
+    fn offset_at(&self, seconds: i64) -> i32 {
+        let dst = if let Some(dst) = &self.dst {
+            dst
+        } else {
+            return self.std_offset;
+        };
+
+        let (start, end) = self.transitions_in(dst, Self::year_of(seconds));
+        let in_dst = if start < end {
+            start <= seconds && seconds < end
+        } else {
+            // Daylight saving time spans the end of the year in the southern hemisphere.
+            !(end <= seconds && seconds < start)
+        };
+        if in_dst {
+            dst.offset
+        } else {
+            self.std_offset
+        }
+    }
+
+    /// Gets the first transition of the rule after `seconds` since the epoch.
+    fn next_transition(&self, seconds: i64) -> Option<i64> {
+        let dst = self.dst.as_ref()?;
+        let year = Self::year_of(seconds);
+        (year - 1..=year + 1)
+            .flat_map(|year| {
+                let (start, end) = self.transitions_in(dst, year);
+                [start, end]
+            })
+            .filter(|&t| t > seconds)
+            .min()
+    }
+
+    /// Gets the last transition of the rule before `seconds` since the epoch.
+    fn previous_transition(&self, seconds: i64) -> Option<i64> {
+        let dst = self.dst.as_ref()?;
+        let year = Self::year_of(seconds);
+        (year - 1..=year + 1)
+            .flat_map(|year| {
+                let (start, end) = self.transitions_in(dst, year);
+                [start, end]
+            })
+            .filter(|&t| t < seconds)
+            .max()
+    }
+}
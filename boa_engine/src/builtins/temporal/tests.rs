@@ -0,0 +1,401 @@
+use super::ZoneInfoTimeZoneProvider;
+use crate::{forward, Context};
+use std::{fs, path::PathBuf, rc::Rc};
+
+/// Writes a version 1 TZif file for a time zone that is at `+01:00`, changes to `+02:00` at
+/// 2020-03-29T01:00Z and changes back to `+01:00` at 2020-10-25T01:00Z, and returns the
+/// directory of the time zone database.
+fn write_test_zone(name: &str) -> PathBuf {
+    const TRANSITIONS: [i32; 2] = [1_585_443_600, 1_603_587_600];
+    const OFFSETS: [i32; 2] = [3600, 7200];
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"TZif");
+    bytes.push(0);
+    bytes.extend_from_slice(&[0; 15]);
+    // isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt
+    for count in [0_u32, 0, 0, 2, 2, 4] {
+        bytes.extend_from_slice(&count.to_be_bytes());
+    }
+    for transition in TRANSITIONS {
+        bytes.extend_from_slice(&transition.to_be_bytes());
+    }
+    bytes.extend_from_slice(&[1, 0]);
+    for (index, offset) in OFFSETS.into_iter().enumerate() {
+        bytes.extend_from_slice(&offset.to_be_bytes());
+        bytes.push(index as u8);
+        bytes.push(0);
+    }
+    bytes.extend_from_slice(b"TST\0");
+
+    let root = std::env::temp_dir().join(format!("boa-tzif-{name}-{}", std::process::id()));
+    fs::create_dir_all(root.join("Test")).expect("could not create the time zone directory");
+    fs::write(root.join("Test").join("Zone"), bytes).expect("could not write the TZif file");
+    root
+}
+
+#[test]
+fn namespace() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(Temporal)"),
+        "\"[object Temporal]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(Temporal.Now)"),
+        "\"[object Temporal.Now]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Temporal.PlainDate(2020, 1, 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Temporal.Instant.fromEpochSeconds(0) < 1 } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn instant() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Instant.from('2020-01-01T12:30:00.5+01:00').toString()"
+        ),
+        "\"2020-01-01T11:30:00.5Z\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Temporal.Instant(1_000_000_000n).epochMilliseconds"
+        ),
+        "1000"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Instant.fromEpochMilliseconds(0).add({ hours: 25 }).toString()"
+        ),
+        "\"1970-01-02T01:00:00Z\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Temporal.Instant(10n ** 30n) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(1_234_567).toTemporalInstant().epochNanoseconds"
+        ),
+        "1234567000000n"
+    );
+}
+
+#[test]
+fn plain_date() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "const date = Temporal.PlainDate.from('2020-01-31');",
+    );
+    assert_eq!(
+        forward(&mut context, "date.add({ months: 1 }).toString()"),
+        "\"2020-02-29\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { date.add({ months: 1 }, { overflow: 'reject' }) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "date.with({ day: 1 }).dayOfWeek"),
+        "3"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.until('2021-03-01', { largestUnit: 'years' }).toString()"
+        ),
+        "\"P1Y1M1D\""
+    );
+    assert_eq!(forward(&mut context, "date.daysInYear"), "366");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainDate.compare('2020-01-01', { year: 2019, month: 12, day: 31 })"
+        ),
+        "1"
+    );
+}
+
+#[test]
+fn plain_time_and_date_time() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Temporal.PlainTime(23, 59, 30).add({ seconds: 45 }).toString()"
+        ),
+        "\"00:00:15\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainTime.from('12:34:56.789').round({ smallestUnit: 'second' }).toString()"
+        ),
+        "\"12:34:57\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainDateTime.from('2020-12-31T23:30').round({ smallestUnit: 'hour' }).toString()"
+        ),
+        "\"2021-01-01T00:00:00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainDateTime.from('2020-01-01T00:00').until('2020-01-02T12:00').toString()"
+        ),
+        "\"P1DT12H\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.PlainDateTime.from('2020-01-01T12:00').toString({ fractionalSecondDigits: 3 })"
+        ),
+        "\"2020-01-01T12:00:00.000\""
+    );
+}
+
+#[test]
+fn duration() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Duration.from('P1DT12H').total({ unit: 'hours' })"
+        ),
+        "36"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Duration.from({ minutes: 90 }).round({ largestUnit: 'hours' }).toString()"
+        ),
+        "\"PT1H30M\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Duration.from('P1M').total({ unit: 'days', relativeTo: '2020-02-01' })"
+        ),
+        "29"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Temporal.Duration(1, -1) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Duration.from('-PT1.5S').negated().toString()"
+        ),
+        "\"PT1.5S\""
+    );
+}
+
+#[test]
+fn zoned_date_time_fixed_offsets() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "const zoned = Temporal.ZonedDateTime.from('2020-01-01T12:00+05:30[+05:30]');",
+    );
+    assert_eq!(forward(&mut context, "zoned.offset"), "\"+05:30\"");
+    assert_eq!(forward(&mut context, "zoned.hoursInDay"), "24");
+    assert_eq!(
+        forward(&mut context, "zoned.toInstant().toString()"),
+        "\"2020-01-01T06:30:00Z\""
+    );
+    assert_eq!(
+        forward(&mut context, "zoned.withTimeZone('UTC').toString()"),
+        "\"2020-01-01T06:30:00+00:00[UTC]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Temporal.ZonedDateTime.from('2020-01-01T12:00+01:00[+05:30]') } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.ZonedDateTime.from('2020-01-01T12:00+01:00[+05:30]', { offset: 'use' }).hour"
+        ),
+        "16"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Temporal.ZonedDateTime.from('2020-01-01T12:00[Europe/Madrid]') } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn zoned_date_time_provider() {
+    let root = write_test_zone("zoned");
+    let provider = ZoneInfoTimeZoneProvider::new(&root).expect("the directory must exist");
+    let mut context = Context::builder()
+        .time_zone_provider(Rc::new(provider))
+        .build();
+
+    // The time zone identifiers are case-insensitive.
+    assert_eq!(
+        forward(&mut context, "new Temporal.TimeZone('test/zone').id"),
+        "\"Test/Zone\""
+    );
+
+    // 02:30 is skipped on 2020-03-29.
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.ZonedDateTime.from('2020-03-29T02:30[Test/Zone]').toString()"
+        ),
+        "\"2020-03-29T03:30:00+02:00[Test/Zone]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.ZonedDateTime.from('2020-03-29T02:30[Test/Zone]', { disambiguation: 'earlier' }).toString()"
+        ),
+        "\"2020-03-29T01:30:00+01:00[Test/Zone]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Temporal.ZonedDateTime.from('2020-03-29T02:30[Test/Zone]', { disambiguation: 'reject' }) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+
+    // 02:30 is repeated on 2020-10-25, and the offset chooses between the two exact times.
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.ZonedDateTime.from('2020-10-25T02:30[Test/Zone]').offset"
+        ),
+        "\"+02:00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.ZonedDateTime.from('2020-10-25T02:30+01:00[Test/Zone]').offset"
+        ),
+        "\"+01:00\""
+    );
+
+    forward(
+        &mut context,
+        "const zone = Temporal.TimeZone.from('Test/Zone');",
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.ZonedDateTime.from('2020-03-29T12:00[Test/Zone]').hoursInDay"
+        ),
+        "23"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.ZonedDateTime.from('2020-10-25T12:00[Test/Zone]').hoursInDay"
+        ),
+        "25"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "zone.getNextTransition('2020-01-01T00:00Z').toString()"
+        ),
+        "\"2020-03-29T01:00:00Z\""
+    );
+
+    // Adding a day keeps the wall-clock time across the transition, adding 24 hours doesn't.
+    forward(
+        &mut context,
+        "const before = Temporal.ZonedDateTime.from('2020-03-28T12:00[Test/Zone]');",
+    );
+    assert_eq!(
+        forward(&mut context, "before.add({ days: 1 }).toString()"),
+        "\"2020-03-29T12:00:00+02:00[Test/Zone]\""
+    );
+    assert_eq!(
+        forward(&mut context, "before.add({ hours: 24 }).toString()"),
+        "\"2020-03-29T13:00:00+02:00[Test/Zone]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "before.until(before.add({ days: 1 }), { largestUnit: 'days' }).toString()"
+        ),
+        "\"P1D\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "before.until(before.add({ days: 1 })).toString()"
+        ),
+        "\"PT23H\""
+    );
+
+    fs::remove_dir_all(root).expect("could not remove the time zone directory");
+}
+
+#[test]
+fn now() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "Temporal.Now.timeZoneId()"),
+        "\"UTC\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Now.instant() instanceof Temporal.Instant"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Now.zonedDateTimeISO('+01:00').offset"
+        ),
+        "\"+01:00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Now.plainDateISO().equals(Temporal.Now.zonedDateTimeISO().toPlainDate())"
+        ),
+        "true"
+    );
+}
@@ -0,0 +1,820 @@
+//! This module implements the `Temporal.TimeZone` object.
+//!
+//! A `Temporal.TimeZone` is either the `UTC` time zone, a time zone with a fixed UTC offset like
+//! `+05:30`, or a named time zone like `Europe/Madrid`. The rules of the named time zones come
+//! from the [`TimeZoneProvider`][super::TimeZoneProvider] of the context.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-timezone-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/TimeZone
+
+use super::{
+    calendar::Calendar,
+    instant::Instant,
+    iso::{check_epoch_nanoseconds, DateDuration, IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_options_object, to_temporal_disambiguation, Disambiguation, Overflow, RoundingMode,
+        NS_PER_DAY, NS_PER_HOUR, NS_PER_MINUTE, NS_PER_SECOND,
+    },
+    parser::{parse_offset, parse_time_zone, ParsedTimeZone},
+    plain_date_time::PlainDateTime,
+};
+use crate::{
+    builtins::{Array, JsArgs},
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+
+/// The kind of a time zone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Kind {
+    /// A named time zone, which is either `UTC` or a time zone of the time zone provider.
+    Named(String),
+    /// A time zone with a fixed UTC offset, in nanoseconds.
+    Offset(i64),
+}
+
+/// The internal representation of a `Temporal.TimeZone` object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZone {
+    kind: Kind,
+}
+
+impl TimeZone {
+    const NAME: &'static str = "TimeZone";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_id = FunctionBuilder::native(context, Self::get_id)
+            .name("get id")
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().time_zone().clone(),
+        )
+        .name(Self::NAME)
+        .length(1)
+        .static_method(Self::from, "from", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Temporal.TimeZone",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "id",
+            Some(get_id),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .method(
+            Self::get_offset_nanoseconds_for,
+            "getOffsetNanosecondsFor",
+            1,
+        )
+        .method(Self::get_offset_string_for, "getOffsetStringFor", 1)
+        .method(Self::get_plain_date_time_for, "getPlainDateTimeFor", 1)
+        .method(Self::get_instant_for, "getInstantFor", 1)
+        .method(Self::get_possible_instants_for, "getPossibleInstantsFor", 1)
+        .method(Self::get_next_transition, "getNextTransition", 1)
+        .method(Self::get_previous_transition, "getPreviousTransition", 1)
+        .method(Self::to_string, "toString", 0)
+        .method(Self::to_string, "toJSON", 0)
+        .build()
+    }
+
+    /// `Temporal.TimeZone ( identifier )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        //     a. Throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.TimeZone must be called with new")
+                .into());
+        }
+
+        // 2. Set identifier to ? ToString(identifier).
+        let identifier = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 3. If IsTimeZoneOffsetString(identifier) is false, then
+        //     a. If IsAvailableTimeZoneName(identifier) is false, then
+        //         i. Throw a RangeError exception.
+        //     b. Set identifier to ! CanonicalizeTimeZoneName(identifier).
+        let time_zone = if identifier.starts_with(['+', '-', '\u{2212}']) {
+            Self {
+                kind: Kind::Offset(parse_offset(&identifier)?),
+            }
+        } else {
+            Self::from_name(&identifier, context)?
+        };
+
+        // 4. Return ? CreateTemporalTimeZone(identifier, NewTarget).
+        time_zone.create(Some(new_target), context).map(Into::into)
+    }
+
+    /// Creates the `UTC` time zone.
+    pub(crate) fn utc() -> Self {
+        Self {
+            kind: Kind::Named("UTC".to_owned()),
+        }
+    }
+
+    /// Creates a named time zone, or throws a `RangeError` if the time zone is not available.
+    ///
+    /// The identifier is canonicalized to the case used by the time zone database.
+    pub(crate) fn from_name(name: &str, context: &mut Context) -> JsResult<Self> {
+        if name.eq_ignore_ascii_case("UTC") {
+            return Ok(Self::utc());
+        }
+
+        context
+            .time_zone_provider()
+            .get_available_identifier(name)
+            .map(|identifier| Self {
+                kind: Kind::Named(identifier),
+            })
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message(format!("'{name}' is not an available time zone"))
+                    .into()
+            })
+    }
+
+    /// Creates the time zone of a parsed time zone string.
+    pub(crate) fn from_parsed(parsed: ParsedTimeZone, context: &mut Context) -> JsResult<Self> {
+        match parsed {
+            ParsedTimeZone::Named(name) => Self::from_name(&name, context),
+            ParsedTimeZone::Offset(offset) => Ok(Self {
+                kind: Kind::Offset(offset),
+            }),
+        }
+    }
+
+    /// Abstract operation `CreateTemporalTimeZone ( identifier [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporaltimezone
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If newTarget is not present, set newTarget to %Temporal.TimeZone%.
+        // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.TimeZone.prototype%", « [[InitializedTemporalTimeZone]], [[Identifier]], [[OffsetNanoseconds]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::time_zone,
+                context,
+            )?,
+            None => context.intrinsics().constructors().time_zone().prototype(),
+        };
+
+        // 3-5. Set object.[[Identifier]] and object.[[OffsetNanoseconds]].
+        // 6. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::time_zone(self),
+        ))
+    }
+
+    /// Gets the time zone of a `Temporal.TimeZone` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is not a `Temporal.TimeZone`, which is checked when the time zone
+    /// objects are stored in the other `Temporal` objects.
+    pub(crate) fn of(object: &JsObject) -> Self {
+        object
+            .borrow()
+            .as_time_zone()
+            .cloned()
+            .expect("the object must be a Temporal.TimeZone")
+    }
+
+    /// Abstract operation `ToTemporalTimeZone ( temporalTimeZoneLike )`
+    ///
+    /// Custom time zone objects are not supported, so the result is always a
+    /// `Temporal.TimeZone`.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaltimezone
+    pub(crate) fn to_temporal_time_zone(
+        time_zone_like: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let custom_time_zone_error = || {
+            JsNativeError::typ()
+                .with_message("custom time zones are not supported")
+                .into()
+        };
+
+        let mut time_zone_like = time_zone_like.clone();
+
+        // 1. If Type(temporalTimeZoneLike) is Object, then
+        if let Some(object) = time_zone_like.as_object().cloned() {
+            // a. If temporalTimeZoneLike has an [[InitializedTemporalTimeZone]] internal slot, then
+            //     i. Return temporalTimeZoneLike.
+            // b. If temporalTimeZoneLike has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Return temporalTimeZoneLike.[[TimeZone]].
+            {
+                let borrowed = object.borrow();
+                if borrowed.as_time_zone().is_some() {
+                    return Ok(object.clone());
+                }
+                if let Some(zoned) = borrowed.as_zoned_date_time() {
+                    return Ok(zoned.time_zone.clone());
+                }
+            }
+
+            // c. If ? HasProperty(temporalTimeZoneLike, "timeZone") is false, return temporalTimeZoneLike.
+            if !object.has_property("timeZone", context)? {
+                return Err(custom_time_zone_error());
+            }
+
+            // d. Set temporalTimeZoneLike to ? Get(temporalTimeZoneLike, "timeZone").
+            time_zone_like = object.get("timeZone", context)?;
+
+            // e. If Type(temporalTimeZoneLike) is Object and ? HasProperty(temporalTimeZoneLike, "timeZone") is false, return temporalTimeZoneLike.
+            if let Some(object) = time_zone_like.as_object() {
+                if object.borrow().as_time_zone().is_some() {
+                    return Ok(object.clone());
+                }
+                if !object.has_property("timeZone", context)? {
+                    return Err(custom_time_zone_error());
+                }
+            }
+        }
+
+        // 2. Let identifier be ? ToString(temporalTimeZoneLike).
+        let identifier = time_zone_like.to_string(context)?.to_std_string_escaped();
+
+        // 3. Let parseResult be ? ParseTemporalTimeZoneString(identifier).
+        // 4-6. Return ! CreateTemporalTimeZone(...).
+        let parsed = parse_time_zone(&identifier)?;
+        Self::from_parsed(parsed, context)?.create(None, context)
+    }
+
+    /// Gets the identifier of the time zone.
+    pub(crate) fn identifier(&self) -> String {
+        match &self.kind {
+            Kind::Named(name) => name.clone(),
+            Kind::Offset(offset) => format_offset(*offset),
+        }
+    }
+
+    /// Abstract operation `GetOffsetNanosecondsFor ( timeZone, instant )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getoffsetnanosecondsfor
+    pub(crate) fn offset_nanoseconds_for(&self, epoch_nanoseconds: i128, context: &Context) -> i64 {
+        match &self.kind {
+            Kind::Offset(offset) => *offset,
+            Kind::Named(name) if name == "UTC" => 0,
+            Kind::Named(name) => context
+                .time_zone_provider()
+                .get_offset_nanoseconds_for(name, epoch_nanoseconds),
+        }
+    }
+
+    /// Abstract operation `GetPlainDateTimeFor ( timeZone, instant, calendar )`
+    ///
+    /// Gets the wall-clock date and time of the time zone at an exact time.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getplaindatetimefor
+    pub(crate) fn date_time_for(&self, epoch_nanoseconds: i128, context: &Context) -> IsoDateTime {
+        // 1. Let offsetNanoseconds be ? GetOffsetNanosecondsFor(timeZone, instant).
+        let offset = self.offset_nanoseconds_for(epoch_nanoseconds, context);
+
+        // 2. Let result be ! GetISOPartsFromEpoch(ℝ(instant.[[Nanoseconds]])).
+        // 3. Set result to BalanceISODateTime(result.[[Year]], ..., result.[[Nanosecond]] + offsetNanoseconds).
+        // 4. Return ? CreateTemporalDateTime(result.[[Year]], ..., calendar).
+        IsoDateTime::from_epoch_nanoseconds(epoch_nanoseconds + i128::from(offset))
+    }
+
+    /// Abstract operation `GetPossibleEpochNanoseconds ( timeZone, isoDateTime )`
+    ///
+    /// Gets the exact times at which the wall-clock time of the time zone is `date_time`.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getpossibleepochnanoseconds
+    pub(crate) fn possible_epoch_nanoseconds_for(
+        &self,
+        date_time: IsoDateTime,
+        context: &Context,
+    ) -> JsResult<Vec<i128>> {
+        let local = date_time.to_epoch_nanoseconds();
+        let possible = match &self.kind {
+            Kind::Offset(offset) => vec![local - i128::from(*offset)],
+            Kind::Named(name) if name == "UTC" => vec![local],
+            Kind::Named(name) => context
+                .time_zone_provider()
+                .get_possible_epoch_nanoseconds(name, local),
+        };
+
+        // For each value epochNanoseconds in possibleEpochNanoseconds, do
+        //     a. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        for &epoch_nanoseconds in &possible {
+            check_epoch_nanoseconds(epoch_nanoseconds)?;
+        }
+        Ok(possible)
+    }
+
+    /// Abstract operations `GetInstantFor ( timeZone, dateTime, disambiguation )` and
+    /// `DisambiguatePossibleInstants ( possibleInstants, timeZone, dateTime, disambiguation )`
+    ///
+    /// Gets the exact time at which the wall-clock time of the time zone is `date_time`, using
+    /// `disambiguation` to choose one if it is repeated or skipped by a transition.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-disambiguatepossibleinstants
+    pub(crate) fn epoch_nanoseconds_for(
+        &self,
+        date_time: IsoDateTime,
+        disambiguation: Disambiguation,
+        context: &Context,
+    ) -> JsResult<i128> {
+        let possible = self.possible_epoch_nanoseconds_for(date_time, context)?;
+
+        // 2. Let n be possibleInstants's length.
+        match (possible.as_slice(), disambiguation) {
+            // 3. If n = 1, then
+            //     a. Return possibleInstants[0].
+            ([epoch_nanoseconds], _) => return Ok(*epoch_nanoseconds),
+            // 4. If n ≠ 0, then
+            //     a. If disambiguation is "earlier" or "compatible", then
+            //         i. Return possibleInstants[0].
+            //     b. If disambiguation is "later", then
+            //         i. Return possibleInstants[n - 1].
+            //     c. Assert: disambiguation is "reject".
+            //     d. Throw a RangeError exception.
+            ([first, ..], Disambiguation::Earlier | Disambiguation::Compatible) => {
+                return Ok(*first)
+            }
+            ([.., last], Disambiguation::Later) => return Ok(*last),
+            ([_, ..], Disambiguation::Reject) => {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "{date_time} is ambiguous in the time zone {}",
+                        self.identifier()
+                    ))
+                    .into())
+            }
+            // 5. Assert: n = 0.
+            // 6. If disambiguation is "reject", then
+            //     a. Throw a RangeError exception.
+            ([], Disambiguation::Reject) => {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "{date_time} does not exist in the time zone {}",
+                        self.identifier()
+                    ))
+                    .into())
+            }
+            ([], _) => {}
+        }
+
+        // 7. Let epochNanoseconds be GetUTCEpochNanoseconds(dateTime.[[ISOYear]], ..., dateTime.[[ISONanosecond]]).
+        let epoch_nanoseconds = date_time.to_epoch_nanoseconds();
+
+        // 8. Let dayBeforeNs be epochNanoseconds - ℤ(nsPerDay).
+        // 9. If IsValidEpochNanoseconds(dayBeforeNs) is false, throw a RangeError exception.
+        // 10. Let dayBefore be ! CreateTemporalInstant(dayBeforeNs).
+        let day_before = check_epoch_nanoseconds(epoch_nanoseconds - NS_PER_DAY)?;
+
+        // 11. Let dayAfterNs be epochNanoseconds + ℤ(nsPerDay).
+        // 12. If IsValidEpochNanoseconds(dayAfterNs) is false, throw a RangeError exception.
+        // 13. Let dayAfter be ! CreateTemporalInstant(dayAfterNs).
+        let day_after = check_epoch_nanoseconds(epoch_nanoseconds + NS_PER_DAY)?;
+
+        // 14. Let offsetBefore be ? GetOffsetNanosecondsFor(timeZone, dayBefore).
+        // 15. Let offsetAfter be ? GetOffsetNanosecondsFor(timeZone, dayAfter).
+        // 16. Let nanoseconds be offsetAfter - offsetBefore.
+        let nanoseconds = i128::from(self.offset_nanoseconds_for(day_after, context))
+            - i128::from(self.offset_nanoseconds_for(day_before, context));
+
+        let shifted = |nanoseconds| {
+            date_time
+                .add(DateDuration::default(), nanoseconds, Overflow::Constrain)
+                .and_then(IsoDateTime::check_limits)
+        };
+        let missing = || {
+            JsNativeError::range()
+                .with_message(format!(
+                    "cannot disambiguate {date_time} in the time zone {}",
+                    self.identifier()
+                ))
+                .into()
+        };
+
+        // 17. If disambiguation is "earlier", then
+        if disambiguation == Disambiguation::Earlier {
+            // a. Let earlier be ? AddDateTime(dateTime.[[ISOYear]], ..., 0, 0, 0, 0, 0, 0, 0, 0, 0, -nanoseconds, undefined).
+            // b. Let earlierDateTime be ! CreateTemporalDateTime(earlier.[[Year]], ..., dateTime.[[Calendar]]).
+            // c. Set possibleInstants to ? GetPossibleInstantsFor(timeZone, earlierDateTime).
+            // d. If possibleInstants is empty, throw a RangeError exception.
+            // e. Return possibleInstants[0].
+            let earlier = shifted(-nanoseconds)?;
+            return self
+                .possible_epoch_nanoseconds_for(earlier, context)?
+                .first()
+                .copied()
+                .ok_or_else(missing);
+        }
+
+        // 18. Assert: disambiguation is "compatible" or "later".
+        // 19. Let later be ? AddDateTime(dateTime.[[ISOYear]], ..., 0, 0, 0, 0, 0, 0, 0, 0, 0, nanoseconds, undefined).
+        // 20. Let laterDateTime be ! CreateTemporalDateTime(later.[[Year]], ..., dateTime.[[Calendar]]).
+        // 21. Set possibleInstants to ? GetPossibleInstantsFor(timeZone, laterDateTime).
+        // 22. Set n to possibleInstants's length.
+        // 23. If n = 0, throw a RangeError exception.
+        // 24. Return possibleInstants[n - 1].
+        let later = shifted(nanoseconds)?;
+        self.possible_epoch_nanoseconds_for(later, context)?
+            .last()
+            .copied()
+            .ok_or_else(missing)
+    }
+
+    /// Abstract operation `GetStartOfDay ( timeZone, isoDate )`
+    ///
+    /// Gets the first exact time of `date` in the time zone, which is not midnight if midnight
+    /// is skipped by a transition.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getstartofday
+    pub(crate) fn start_of_day(&self, date: IsoDate, context: &Context) -> JsResult<i128> {
+        // 1. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, MidnightTimeRecord()).
+        let date_time = IsoDateTime::new(date, IsoTime::default()).check_limits()?;
+
+        // 2. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone, isoDateTime).
+        // 3. If possibleEpochNs is not empty, return possibleEpochNs[0].
+        let possible = self.possible_epoch_nanoseconds_for(date_time, context)?;
+        if let Some(&first) = possible.first() {
+            return Ok(first);
+        }
+
+        // 4. Assert: IsOffsetTimeZoneIdentifier(timeZone) is false.
+        // 5. Let possibleEpochNsAfter be GetNamedTimeZoneEpochNanoseconds(timeZone, isoDateTimeAfter), where isoDateTimeAfter is the ISO Date-Time Record for which DifferenceISODateTime(isoDateTimeAfter, isoDateTime, "iso8601", hour).[[Time]] is 24 hours.
+        // 6. Assert: possibleEpochNsAfter's length is 1.
+        // 7. Return possibleEpochNsAfter[0].
+        self.next_transition(date_time.to_epoch_nanoseconds() - NS_PER_DAY, context)
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message(format!(
+                        "{date} does not start in the time zone {}",
+                        self.identifier()
+                    ))
+                    .into()
+            })
+    }
+
+    /// Gets the first exact time after `epoch_nanoseconds` at which the UTC offset of the time
+    /// zone changes.
+    fn next_transition(&self, epoch_nanoseconds: i128, context: &Context) -> Option<i128> {
+        match &self.kind {
+            Kind::Named(name) if name != "UTC" => context
+                .time_zone_provider()
+                .get_next_transition(name, epoch_nanoseconds)
+                .filter(|transition| check_epoch_nanoseconds(*transition).is_ok()),
+            _ => None,
+        }
+    }
+
+    /// Gets the last exact time before `epoch_nanoseconds` at which the UTC offset of the time
+    /// zone changed.
+    fn previous_transition(&self, epoch_nanoseconds: i128, context: &Context) -> Option<i128> {
+        match &self.kind {
+            Kind::Named(name) if name != "UTC" => context
+                .time_zone_provider()
+                .get_previous_transition(name, epoch_nanoseconds)
+                .filter(|transition| check_epoch_nanoseconds(*transition).is_ok()),
+            _ => None,
+        }
+    }
+
+    /// `Temporal.TimeZone.from ( item )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalTimeZone(item).
+        Self::to_temporal_time_zone(args.get_or_undefined(0), context).map(Into::into)
+    }
+
+    /// `get Temporal.TimeZone.prototype.id`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.timezone.prototype.id
+    fn get_id(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(this_time_zone(this)?.identifier().into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getOffsetNanosecondsFor ( instant )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.prototype.getoffsetnanosecondsfor
+    fn get_offset_nanoseconds_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let time_zone = this_time_zone(this)?;
+
+        // 3. Set instant to ? ToTemporalInstant(instant).
+        let instant = Instant::to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4-5. Return 𝔽(GetNamedTimeZoneOffsetNanoseconds(timeZone.[[Identifier]], instant.[[Nanoseconds]])).
+        Ok(time_zone.offset_nanoseconds_for(instant, context).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getOffsetStringFor ( instant )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.prototype.getoffsetstringfor
+    fn get_offset_string_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let time_zone = this_time_zone(this)?;
+
+        // 3. Set instant to ? ToTemporalInstant(instant).
+        let instant = Instant::to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4. Return ? BuiltinTimeZoneGetOffsetStringFor(timeZone, instant).
+        Ok(format_offset(time_zone.offset_nanoseconds_for(instant, context)).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getPlainDateTimeFor ( instant [ , calendarLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.prototype.getplaindatetimefor
+    fn get_plain_date_time_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        let time_zone = this_time_zone(this)?;
+
+        // 2. Set instant to ? ToTemporalInstant(instant).
+        let instant = Instant::to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 3. Let calendar be ? ToTemporalCalendarWithISODefault(calendarLike).
+        let calendar =
+            Calendar::to_temporal_calendar_with_iso_default(args.get_or_undefined(1), context)?;
+
+        // 4. Return ? BuiltinTimeZoneGetPlainDateTimeFor(timeZone, instant, calendar).
+        let date_time = time_zone.date_time_for(instant, context);
+        PlainDateTime::new(date_time, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.TimeZone.prototype.getInstantFor ( dateTime [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.prototype.getinstantfor
+    fn get_instant_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let time_zone = this_time_zone(this)?;
+
+        // 3. Set dateTime to ? ToTemporalDateTime(dateTime).
+        let date_time =
+            PlainDateTime::to_temporal_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 4. Set options to ? GetOptionsObject(options).
+        // 5. Let disambiguation be ? ToTemporalDisambiguation(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let disambiguation = to_temporal_disambiguation(options.as_ref(), context)?;
+
+        // 6. Return ? BuiltinTimeZoneGetInstantFor(timeZone, dateTime, disambiguation).
+        let epoch_nanoseconds =
+            time_zone.epoch_nanoseconds_for(date_time.iso, disambiguation, context)?;
+        Instant::create(epoch_nanoseconds, None, context).map(Into::into)
+    }
+
+    /// `Temporal.TimeZone.prototype.getPossibleInstantsFor ( dateTime )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.prototype.getpossibleinstantsfor
+    fn get_possible_instants_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let time_zone = this_time_zone(this)?;
+
+        // 3. Set dateTime to ? ToTemporalDateTime(dateTime).
+        let date_time =
+            PlainDateTime::to_temporal_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 4-6. Let possibleEpochNanoseconds be ? GetPossibleEpochNanoseconds(...).
+        let possible = time_zone.possible_epoch_nanoseconds_for(date_time.iso, context)?;
+
+        // 7. Let possibleInstants be a new empty List.
+        // 8. For each value epochNanoseconds in possibleEpochNanoseconds, do
+        //     a. Let instant be ! CreateTemporalInstant(epochNanoseconds).
+        //     b. Append instant to possibleInstants.
+        let mut instants = Vec::with_capacity(possible.len());
+        for epoch_nanoseconds in possible {
+            instants.push(Instant::create(epoch_nanoseconds, None, context)?.into());
+        }
+
+        // 9. Return CreateArrayFromList(possibleInstants).
+        Ok(Array::create_array_from_list(instants, context).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getNextTransition ( startingPoint )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.prototype.getnexttransition
+    fn get_next_transition(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let time_zone = this_time_zone(this)?;
+
+        // 3. Set startingPoint to ? ToTemporalInstant(startingPoint).
+        let starting_point = Instant::to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4. If timeZone.[[OffsetNanoseconds]] is not undefined, return null.
+        // 5. Let transition be GetNamedTimeZoneNextTransition(timeZone.[[Identifier]], startingPoint.[[Nanoseconds]]).
+        // 6. If transition is null, return null.
+        // 7. Return ! CreateTemporalInstant(transition).
+        match time_zone.next_transition(starting_point, context) {
+            Some(transition) => Instant::create(transition, None, context).map(Into::into),
+            None => Ok(JsValue::null()),
+        }
+    }
+
+    /// `Temporal.TimeZone.prototype.getPreviousTransition ( startingPoint )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.prototype.getprevioustransition
+    fn get_previous_transition(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        let time_zone = this_time_zone(this)?;
+
+        // 3. Set startingPoint to ? ToTemporalInstant(startingPoint).
+        let starting_point = Instant::to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 4. If timeZone.[[OffsetNanoseconds]] is not undefined, return null.
+        // 5. Let transition be GetNamedTimeZonePreviousTransition(timeZone.[[Identifier]], startingPoint.[[Nanoseconds]]).
+        // 6. If transition is null, return null.
+        // 7. Return ! CreateTemporalInstant(transition).
+        match time_zone.previous_transition(starting_point, context) {
+            Some(transition) => Instant::create(transition, None, context).map(Into::into),
+            None => Ok(JsValue::null()),
+        }
+    }
+
+    /// `Temporal.TimeZone.prototype.toString ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone.prototype.tostring
+    fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let timeZone be the this value.
+        // 2. Perform ? RequireInternalSlot(timeZone, [[InitializedTemporalTimeZone]]).
+        // 3. Return timeZone.[[Identifier]].
+        Ok(this_time_zone(this)?.identifier().into())
+    }
+}
+
+/// Gets the `this` value of a `Temporal.TimeZone` method, which must be a time zone.
+fn this_time_zone(this: &JsValue) -> JsResult<TimeZone> {
+    this.as_object()
+        .and_then(|object| object.borrow().as_time_zone().cloned())
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Temporal.TimeZone")
+                .into()
+        })
+}
+
+/// Abstract operation `FormatTimeZoneOffsetString ( offsetNanoseconds )`
+///
+/// Formats a UTC offset as `±HH:MM`, followed by the seconds and the fraction of a second if
+/// they are not zero.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-formattimezoneoffsetstring
+pub(crate) fn format_offset(offset_nanoseconds: i64) -> String {
+    let sign = if offset_nanoseconds < 0 { '-' } else { '+' };
+    let nanoseconds = i128::from(offset_nanoseconds).abs();
+    let hours = nanoseconds / NS_PER_HOUR;
+    let minutes = nanoseconds % NS_PER_HOUR / NS_PER_MINUTE;
+    let seconds = nanoseconds % NS_PER_MINUTE / NS_PER_SECOND;
+    let fraction = nanoseconds % NS_PER_SECOND;
+
+    if fraction != 0 {
+        let fraction = format!("{fraction:09}");
+        format!(
+            "{sign}{hours:02}:{minutes:02}:{seconds:02}.{}",
+            fraction.trim_end_matches('0')
+        )
+    } else if seconds != 0 {
+        format!("{sign}{hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{sign}{hours:02}:{minutes:02}")
+    }
+}
+
+/// Abstract operation `FormatISOTimeZoneOffsetString ( offsetNanoseconds )`
+///
+/// Formats a UTC offset rounded to the minute, as `±HH:MM`.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-formatisotimezoneoffsetstring
+pub(crate) fn format_offset_rounded(offset_nanoseconds: i64) -> String {
+    // 1. Assert: offsetNanoseconds is an integer.
+    // 2. Set offsetNanoseconds to RoundNumberToIncrement(offsetNanoseconds, 60 × 10^9, "halfExpand").
+    let offset = RoundingMode::HalfExpand.round(i128::from(offset_nanoseconds), NS_PER_MINUTE);
+
+    // 3-9. Return the string-concatenation of sign, h, the code unit 0x003A (COLON), and m.
+    format_offset(offset as i64)
+}
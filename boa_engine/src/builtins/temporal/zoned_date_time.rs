@@ -0,0 +1,1423 @@
+//! This module implements the `Temporal.ZonedDateTime` object.
+//!
+//! A `Temporal.ZonedDateTime` is an exact time with a time zone and a calendar, which
+//! represents a wall-clock time at a specific place.
+//!
+//! More information:
+//!  - [Temporal proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime
+
+use super::{
+    bigint_to_i128,
+    calendar::Calendar,
+    difference::{
+        add_zoned_date_time, difference_zoned_date_time_with_rounding, total_time_duration,
+    },
+    duration::{Duration, DurationRecord},
+    fields::{reject_object_with_calendar_or_time_zone, Field, TemporalFields},
+    i128_to_bigint,
+    instant::{round_epoch_nanoseconds, Instant},
+    iso::{check_epoch_nanoseconds, IsoDate, IsoDateTime},
+    options::{
+        get_difference_settings, get_option, get_options_object, get_time_rounding_options,
+        to_seconds_string_precision, to_temporal_disambiguation, to_temporal_overflow,
+        Disambiguation, OffsetOption, Precision, RoundingMode, ShowOffset, ShowTimeZone, Unit,
+        UnitGroup, NS_PER_MICROSECOND, NS_PER_MILLISECOND, NS_PER_MINUTE, NS_PER_SECOND,
+    },
+    parser::{parse_offset, parse_zoned_date_time, ParsedOffset},
+    plain_date::PlainDate,
+    plain_date_time::PlainDateTime,
+    plain_time::PlainTime,
+    time_zone::{format_offset, format_offset_rounded, TimeZone},
+    value_of, Component,
+};
+use crate::{
+    builtins::{function::NativeFunctionSignature, JsArgs},
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsFunction, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::JsVariant,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The fields of the argument of `Temporal.ZonedDateTime.prototype.with`.
+const WITH_FIELDS: &[Field] = &[
+    Field::Day,
+    Field::Hour,
+    Field::Microsecond,
+    Field::Millisecond,
+    Field::Minute,
+    Field::Month,
+    Field::MonthCode,
+    Field::Nanosecond,
+    Field::Offset,
+    Field::Second,
+    Field::Year,
+];
+
+/// The internal representation of a `Temporal.ZonedDateTime` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ZonedDateTime {
+    #[unsafe_ignore_trace]
+    pub(crate) epoch_nanoseconds: i128,
+    pub(crate) time_zone: JsObject,
+    pub(crate) calendar: JsObject,
+}
+
+/// How the offset of a date-time string or property bag is used to interpret its wall-clock
+/// time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetBehaviour {
+    /// A numeric offset, which must be checked against the time zone, in nanoseconds.
+    Option(i64),
+    /// An exact time, given with the `Z` designator, whose offset is always used.
+    Exact(i64),
+    /// No offset, so the wall-clock time is interpreted in the time zone.
+    Wall,
+}
+
+impl OffsetBehaviour {
+    /// Gets the offset behaviour of the offset of a parsed string, and whether the offset can
+    /// match an offset of the time zone rounded to the minute, which is the case if the string
+    /// doesn't have sub-minute precision.
+    pub(crate) fn from_parsed(offset: Option<ParsedOffset>) -> (Self, bool) {
+        match offset {
+            None => (Self::Wall, false),
+            Some(ParsedOffset::Utc) => (Self::Exact(0), false),
+            Some(ParsedOffset::Offset {
+                nanoseconds,
+                sub_minute,
+            }) => (Self::Option(nanoseconds), !sub_minute),
+        }
+    }
+}
+
+/// Abstract operation `InterpretISODateTimeOffset ( isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour )`
+///
+/// Gets the exact time of a wall-clock time in a time zone, which may have an offset that must
+/// be reconciled with the offsets of the time zone.
+///
+/// More information:
+///  - [Temporal proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-interpretisodatetimeoffset
+pub(crate) fn interpret_iso_date_time_offset(
+    date_time: IsoDateTime,
+    behaviour: OffsetBehaviour,
+    time_zone: &TimeZone,
+    disambiguation: Disambiguation,
+    offset_option: OffsetOption,
+    match_minutes: bool,
+    context: &Context,
+) -> JsResult<i128> {
+    let offset = match (behaviour, offset_option) {
+        // 2. If offsetBehaviour is wall, or offsetBehaviour is option and offsetOption is ignore, then
+        //     a. Return ? GetEpochNanosecondsFor(timeZone, isoDateTime, disambiguation).
+        (OffsetBehaviour::Wall, _) | (OffsetBehaviour::Option(_), OffsetOption::Ignore) => {
+            return time_zone.epoch_nanoseconds_for(date_time, disambiguation, context);
+        }
+        // 3. If offsetBehaviour is exact, or offsetBehaviour is option and offsetOption is use, then
+        //     a. Let balanced be BalanceISODateTime(isoDate.[[Year]], isoDate.[[Month]], isoDate.[[Day]], time.[[Hour]], time.[[Minute]], time.[[Second]], time.[[Millisecond]], time.[[Microsecond]], time.[[Nanosecond]] - offsetNanoseconds).
+        //     b. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
+        //     c. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+        //     d. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        //     e. Return epochNanoseconds.
+        (OffsetBehaviour::Exact(offset), _)
+        | (OffsetBehaviour::Option(offset), OffsetOption::Use) => {
+            return check_epoch_nanoseconds(date_time.to_epoch_nanoseconds() - i128::from(offset));
+        }
+        (OffsetBehaviour::Option(offset), _) => offset,
+    };
+
+    // 4. Assert: offsetBehaviour is option.
+    // 5. Assert: offsetOption is prefer or reject.
+    // 6. Perform ? CheckISODaysRange(isoDate).
+    let date_time = date_time.check_limits()?;
+
+    // 7. Let utcEpochNanoseconds be GetUTCEpochNanoseconds(isoDateTime).
+    // 8. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone, isoDateTime).
+    let utc_epoch_nanoseconds = date_time.to_epoch_nanoseconds();
+    let possible = time_zone.possible_epoch_nanoseconds_for(date_time, context)?;
+
+    // 9. For each element candidate of possibleEpochNs, do
+    for candidate in possible {
+        // a. Let candidateOffset be utcEpochNanoseconds - candidate.
+        let candidate_offset = utc_epoch_nanoseconds - candidate;
+
+        // b. If candidateOffset = offsetNanoseconds, then
+        //     i. Return candidate.
+        if candidate_offset == i128::from(offset) {
+            return Ok(candidate);
+        }
+
+        // c. If matchBehaviour is match-minutes, then
+        //     i. Let roundedCandidateNanoseconds be RoundNumberToIncrement(candidateOffset, 60 × 10^9, half-expand).
+        //     ii. If roundedCandidateNanoseconds = offsetNanoseconds, then
+        //         1. Return candidate.
+        if match_minutes
+            && RoundingMode::HalfExpand.round(candidate_offset, NS_PER_MINUTE) == i128::from(offset)
+        {
+            return Ok(candidate);
+        }
+    }
+
+    // 10. If offsetOption is reject, throw a RangeError exception.
+    if offset_option == OffsetOption::Reject {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "the offset {} is not valid for {date_time} in the time zone {}",
+                format_offset(offset),
+                time_zone.identifier()
+            ))
+            .into());
+    }
+
+    // 11. Return ? DisambiguatePossibleEpochNanoseconds(possibleEpochNs, timeZone, isoDateTime, disambiguation).
+    time_zone.epoch_nanoseconds_for(date_time, disambiguation, context)
+}
+
+impl ZonedDateTime {
+    const NAME: &'static str = "ZonedDateTime";
+
+    pub(super) fn init(context: &mut Context) -> JsFunction {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let attribute = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+
+        let getters: [(&str, NativeFunctionSignature); 10] = [
+            ("calendar", Self::get_calendar),
+            ("timeZone", Self::get_time_zone),
+            ("epochSeconds", Self::get_epoch_seconds),
+            ("epochMilliseconds", Self::get_epoch_milliseconds),
+            ("epochMicroseconds", Self::get_epoch_microseconds),
+            ("epochNanoseconds", Self::get_epoch_nanoseconds),
+            ("hoursInDay", Self::get_hours_in_day),
+            ("offsetNanoseconds", Self::get_offset_nanoseconds),
+            ("offset", Self::get_offset),
+            ("timeZoneId", Self::get_time_zone_id),
+        ];
+        let getters = getters.map(|(name, getter)| {
+            let function = FunctionBuilder::native(context, getter)
+                .name(format!("get {name}"))
+                .build();
+            (name, function)
+        });
+
+        let mut builder = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .zoned_date_time()
+                .clone(),
+        );
+        builder
+            .name(Self::NAME)
+            .length(2)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.ZonedDateTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::with, "with", 1)
+            .method(Self::with_plain_time, "withPlainTime", 0)
+            .method(Self::with_plain_date, "withPlainDate", 1)
+            .method(Self::with_time_zone, "withTimeZone", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0)
+            .method(Self::start_of_day, "startOfDay", 0)
+            .method(Self::to_instant, "toInstant", 0)
+            .method(Self::to_plain_date, "toPlainDate", 0)
+            .method(Self::to_plain_time, "toPlainTime", 0)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 0)
+            .method(Self::get_iso_fields, "getISOFields", 0);
+
+        for (name, getter) in getters {
+            builder.accessor(name, Some(getter), None, attribute);
+        }
+
+        let components = [&Component::DATE[..], &Component::TIME[..]].concat();
+        Component::add_getters(&mut builder, &components, |this, context| {
+            Ok(this_zoned_date_time(this)?.date_time(context))
+        });
+
+        builder.build()
+    }
+
+    /// Creates a new `ZonedDateTime` with the given time zone and calendar.
+    pub(crate) const fn new(
+        epoch_nanoseconds: i128,
+        time_zone: JsObject,
+        calendar: JsObject,
+    ) -> Self {
+        Self {
+            epoch_nanoseconds,
+            time_zone,
+            calendar,
+        }
+    }
+
+    /// Gets the wall-clock date and time of the exact time in its time zone.
+    pub(crate) fn date_time(&self, context: &Context) -> IsoDateTime {
+        TimeZone::of(&self.time_zone).date_time_for(self.epoch_nanoseconds, context)
+    }
+
+    /// `Temporal.ZonedDateTime ( epochNanoseconds, timeZoneLike [ , calendarLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        //     a. Throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("Temporal.ZonedDateTime must be called with new")
+                .into());
+        }
+
+        // 2. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch_nanoseconds = args.get_or_undefined(0).to_bigint(context)?;
+        let epoch_nanoseconds = bigint_to_i128(&epoch_nanoseconds).map_or_else(
+            || check_epoch_nanoseconds(i128::MAX),
+            check_epoch_nanoseconds,
+        )?;
+
+        // 4. Let timeZone be ? ToTemporalTimeZone(timeZoneLike).
+        let time_zone = TimeZone::to_temporal_time_zone(args.get_or_undefined(1), context)?;
+
+        // 5. Let calendar be ? ToTemporalCalendarWithISODefault(calendarLike).
+        let calendar =
+            Calendar::to_temporal_calendar_with_iso_default(args.get_or_undefined(2), context)?;
+
+        // 6. Return ? CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar, NewTarget).
+        Self::new(epoch_nanoseconds, time_zone, calendar)
+            .create(Some(new_target), context)
+            .map(Into::into)
+    }
+
+    /// Abstract operation `CreateTemporalZonedDateTime ( epochNanoseconds, timeZone, calendar [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporalzoneddatetime
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: IsValidEpochNanoseconds(epochNanoseconds) is true.
+        debug_assert!(check_epoch_nanoseconds(self.epoch_nanoseconds).is_ok());
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.ZonedDateTime%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.ZonedDateTime.prototype%", « [[InitializedTemporalZonedDateTime]], [[EpochNanoseconds]], [[TimeZone]], [[Calendar]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::zoned_date_time,
+                context,
+            )?,
+            None => context
+                .intrinsics()
+                .constructors()
+                .zoned_date_time()
+                .prototype(),
+        };
+
+        // 4. Set object.[[EpochNanoseconds]] to epochNanoseconds.
+        // 5. Set object.[[TimeZone]] to timeZone.
+        // 6. Set object.[[Calendar]] to calendar.
+        // 7. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::zoned_date_time(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalZonedDateTime ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalzoneddatetime
+    pub(crate) fn to_temporal_zoned_date_time(
+        item: &JsValue,
+        options: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let (date, time, behaviour, match_minutes, time_zone, calendar) = match item.variant() {
+            // 2. If item is an Object, then
+            JsVariant::Object(object) => {
+                // a. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i-v. Perform ? GetTemporalDisambiguationOption(resolvedOptions), ? GetTemporalOffsetOption(resolvedOptions, reject) and ? GetTemporalOverflowOption(resolvedOptions).
+                //     vi. Return ! CreateTemporalZonedDateTime(item.[[EpochNanoseconds]], item.[[TimeZone]], item.[[Calendar]]).
+                let zoned = object.borrow().as_zoned_date_time().cloned();
+                if let Some(zoned) = zoned {
+                    to_temporal_disambiguation(options, context)?;
+                    get_option::<OffsetOption>(options, "offset", context)?;
+                    to_temporal_overflow(options, context)?;
+                    return Ok(zoned);
+                }
+
+                // b. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+                let calendar = Calendar::get_temporal_calendar_with_iso_default(object, context)?;
+
+                // c. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset, time-zone », « time-zone »).
+                let fields = TemporalFields::prepare(
+                    object,
+                    Field::ZONED_DATE_TIME,
+                    Some(&[Field::TimeZone]),
+                    context,
+                )?;
+
+                // d. Let timeZone be fields.[[TimeZone]].
+                let time_zone = TimeZone::to_temporal_time_zone(
+                    fields
+                        .time_zone
+                        .as_ref()
+                        .expect("the time zone is a required field"),
+                    context,
+                )?;
+
+                // e. Let offsetString be fields.[[OffsetString]].
+                // f. If offsetString is unset, then
+                //     i. Set offsetBehaviour to wall.
+                let behaviour = match &fields.offset {
+                    Some(offset) => OffsetBehaviour::Option(parse_offset(offset)?),
+                    None => OffsetBehaviour::Wall,
+                };
+
+                // g-h. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+                // i. Let offsetOption be ? GetTemporalOffsetOption(resolvedOptions, reject).
+                // j. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+                // k. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+                let disambiguation = to_temporal_disambiguation(options, context)?;
+                let offset_option =
+                    get_option(options, "offset", context)?.unwrap_or(OffsetOption::Reject);
+                let overflow = to_temporal_overflow(options, context)?;
+                let date = fields.date(overflow)?;
+                let time = fields.time(overflow)?;
+
+                // 5-7. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, match-exactly).
+                let epoch_nanoseconds = interpret_iso_date_time_offset(
+                    IsoDateTime::new(date, time),
+                    behaviour,
+                    &TimeZone::of(&time_zone),
+                    disambiguation,
+                    offset_option,
+                    false,
+                    context,
+                )?;
+                return Ok(Self::new(epoch_nanoseconds, time_zone, calendar));
+            }
+            // 3. Else,
+            //     a. If item is not a String, throw a TypeError exception.
+            JsVariant::String(string) => {
+                // b. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[+Zoned] »).
+                let parsed = parse_zoned_date_time(&string.to_std_string_escaped())?;
+
+                // c. Let annotation be result.[[TimeZone]].[[TimeZoneAnnotation]].
+                // d. Assert: annotation is not empty.
+                // e. Let timeZone be ? ToTemporalTimeZoneIdentifier(annotation).
+                let time_zone = TimeZone::from_parsed(
+                    parsed
+                        .time_zone
+                        .expect("a zoned date-time string must have a time zone"),
+                    context,
+                )?
+                .create(None, context)?;
+
+                // f-h. Let offsetString be result.[[TimeZone]].[[OffsetString]], and set the offset behaviour.
+                // i-l. Let calendar be result.[[Calendar]], which must be a supported calendar.
+                // m. Set matchBehaviour to match-minutes, unless the offset has sub-minute precision.
+                let (behaviour, match_minutes) = OffsetBehaviour::from_parsed(parsed.offset);
+                Calendar::check_annotation(parsed.calendar.as_deref())?;
+                let calendar = Calendar::create(context);
+
+                (
+                    parsed.date.expect("a date-time string must have a date"),
+                    parsed.time,
+                    behaviour,
+                    match_minutes,
+                    time_zone,
+                    calendar,
+                )
+            }
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "a zoned date-time must be a Temporal object, a property bag or a string",
+                    )
+                    .into())
+            }
+        };
+
+        // 3.n-s. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        //     Let offsetOption be ? GetTemporalOffsetOption(resolvedOptions, reject).
+        //     Perform ? GetTemporalOverflowOption(resolvedOptions).
+        let disambiguation = to_temporal_disambiguation(options, context)?;
+        let offset_option = get_option(options, "offset", context)?.unwrap_or(OffsetOption::Reject);
+        to_temporal_overflow(options, context)?;
+
+        // 4. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+        // 5-7. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour).
+        let zone = TimeZone::of(&time_zone);
+        let epoch_nanoseconds = match time {
+            None => zone.start_of_day(date, context)?,
+            Some(time) => interpret_iso_date_time_offset(
+                IsoDateTime::new(date, time),
+                behaviour,
+                &zone,
+                disambiguation,
+                offset_option,
+                match_minutes,
+                context,
+            )?,
+        };
+
+        // 8. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::new(epoch_nanoseconds, time_zone, calendar))
+    }
+
+    /// `Temporal.ZonedDateTime.from ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalZonedDateTime(item, options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        Self::to_temporal_zoned_date_time(args.get_or_undefined(0), options.as_ref(), context)?
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalZonedDateTime(one).
+        // 2. Set two to ? ToTemporalZonedDateTime(two).
+        let one = Self::to_temporal_zoned_date_time(args.get_or_undefined(0), None, context)?;
+        let two = Self::to_temporal_zoned_date_time(args.get_or_undefined(1), None, context)?;
+
+        // 3. Return 𝔽(CompareEpochNanoseconds(one.[[EpochNanoseconds]], two.[[EpochNanoseconds]])).
+        Ok((one.epoch_nanoseconds.cmp(&two.epoch_nanoseconds) as i32).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.calendar`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.calendar
+    fn get_calendar(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(this_zoned_date_time(this)?.calendar.clone().into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.timeZone`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.timezone
+    fn get_time_zone(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(this_zoned_date_time(this)?.time_zone.clone().into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.timeZoneId`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.timezoneid
+    fn get_time_zone_id(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let zoned = this_zoned_date_time(this)?;
+        Ok(TimeZone::of(&zoned.time_zone).identifier().into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochSeconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochseconds
+    fn get_epoch_seconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be zonedDateTime.[[EpochNanoseconds]].
+        // 4. Let s be floor(ℝ(ns) / 10^9).
+        // 5. Return 𝔽(s).
+        let epoch_nanoseconds = this_zoned_date_time(this)?.epoch_nanoseconds;
+        Ok((epoch_nanoseconds.div_euclid(NS_PER_SECOND) as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochMilliseconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochmilliseconds
+    fn get_epoch_milliseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be zonedDateTime.[[EpochNanoseconds]].
+        // 4. Let ms be floor(ℝ(ns) / 10^6).
+        // 5. Return 𝔽(ms).
+        let epoch_nanoseconds = this_zoned_date_time(this)?.epoch_nanoseconds;
+        Ok((epoch_nanoseconds.div_euclid(NS_PER_MILLISECOND) as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochMicroseconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochmicroseconds
+    fn get_epoch_microseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Let ns be zonedDateTime.[[EpochNanoseconds]].
+        // 4. Let µs be floor(ℝ(ns) / 10^3).
+        // 5. Return ℤ(µs).
+        let epoch_nanoseconds = this_zoned_date_time(this)?.epoch_nanoseconds;
+        Ok(i128_to_bigint(epoch_nanoseconds.div_euclid(NS_PER_MICROSECOND)).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochNanoseconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochnanoseconds
+    fn get_epoch_nanoseconds(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 3. Return zonedDateTime.[[EpochNanoseconds]].
+        Ok(i128_to_bigint(this_zoned_date_time(this)?.epoch_nanoseconds).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.hoursInDay`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.hoursinday
+    fn get_hours_in_day(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        let zoned = this_zoned_date_time(this)?;
+        let time_zone = TimeZone::of(&zoned.time_zone);
+
+        // 4. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 5. Let today be isoDateTime.[[ISODate]].
+        // 6. Let tomorrow be BalanceISODate(today.[[Year]], today.[[Month]], today.[[Day]] + 1).
+        let today = zoned.date_time(context).date;
+        let tomorrow = IsoDate::from_epoch_days(today.epoch_days() + 1);
+
+        // 7. Let todayNs be ? GetStartOfDay(timeZone, today).
+        // 8. Let tomorrowNs be ? GetStartOfDay(timeZone, tomorrow).
+        let today = time_zone.start_of_day(today, context)?;
+        let tomorrow = time_zone.start_of_day(tomorrow, context)?;
+
+        // 9. Let diff be TimeDurationFromEpochNanosecondsDifference(tomorrowNs, todayNs).
+        // 10. Return 𝔽(TotalTimeDuration(diff, hour)).
+        Ok(total_time_duration(tomorrow - today, Unit::Hour).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.offsetNanoseconds`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offsetnanoseconds
+    fn get_offset_nanoseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 3. Return 𝔽(GetOffsetNanosecondsFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]])).
+        let zoned = this_zoned_date_time(this)?;
+        let offset =
+            TimeZone::of(&zoned.time_zone).offset_nanoseconds_for(zoned.epoch_nanoseconds, context);
+        Ok((offset as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.offset`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offset
+    fn get_offset(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Let offsetNanoseconds be GetOffsetNanosecondsFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return FormatUTCOffsetNanoseconds(offsetNanoseconds).
+        let zoned = this_zoned_date_time(this)?;
+        let offset =
+            TimeZone::of(&zoned.time_zone).offset_nanoseconds_for(zoned.epoch_nanoseconds, context);
+        Ok(format_offset(offset).into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.with ( temporalZonedDateTimeLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = this_zoned_date_time(this)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalZonedDateTimeLike) is false, throw a TypeError exception.
+        let like = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("the argument of with must be an object")
+        })?;
+        reject_object_with_calendar_or_time_zone(like, context)?;
+
+        // 4. Let epochNs be zonedDateTime.[[EpochNanoseconds]].
+        // 5. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 6. Let calendar be zonedDateTime.[[Calendar]].
+        // 7. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+        // 8. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+        // 9-15. Let fields be ISODateToFields(calendar, isoDateTime.[[ISODate]], date), with the time and the offset of zonedDateTime.
+        let time_zone = TimeZone::of(&zoned.time_zone);
+        let offset = time_zone.offset_nanoseconds_for(zoned.epoch_nanoseconds, context);
+        let mut fields = TemporalFields::from_date_time(zoned.date_time(context));
+        fields.offset = Some(format_offset(offset));
+
+        // 16. Let partialZonedDateTime be ? PrepareCalendarFields(calendar, temporalZonedDateTimeLike, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset », partial).
+        // 17. Set fields to CalendarMergeFields(calendar, fields, partialZonedDateTime).
+        let partial = TemporalFields::prepare(like, WITH_FIELDS, None, context)?;
+        let fields = fields.merge(partial);
+
+        // 18. Let resolvedOptions be ? GetOptionsObject(options).
+        // 19. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        // 20. Let offset be ? GetTemporalOffsetOption(resolvedOptions, prefer).
+        // 21. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let disambiguation = to_temporal_disambiguation(options.as_ref(), context)?;
+        let offset_option =
+            get_option(options.as_ref(), "offset", context)?.unwrap_or(OffsetOption::Prefer);
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+
+        // 22. Let dateTimeResult be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        let date = fields.date(overflow)?;
+        let time = fields.time(overflow)?;
+
+        // 23. Let newOffsetNanoseconds be ! ParseDateTimeUTCOffset(fields.[[OffsetString]]).
+        let offset = parse_offset(
+            fields
+                .offset
+                .as_deref()
+                .expect("the offset of zonedDateTime is always present"),
+        )?;
+
+        // 24. Let epochNanoseconds be ? InterpretISODateTimeOffset(dateTimeResult.[[ISODate]], dateTimeResult.[[Time]], option, newOffsetNanoseconds, timeZone, disambiguation, offset, match-exactly).
+        let epoch_nanoseconds = interpret_iso_date_time_offset(
+            IsoDateTime::new(date, time),
+            OffsetBehaviour::Option(offset),
+            &time_zone,
+            disambiguation,
+            offset_option,
+            false,
+            context,
+        )?;
+
+        // 25. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Self::new(
+            epoch_nanoseconds,
+            zoned.time_zone.clone(),
+            zoned.calendar.clone(),
+        )
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withPlainTime ( [ plainTimeLike ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withplaintime
+    fn with_plain_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let calendar be zonedDateTime.[[Calendar]].
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        let zoned = this_zoned_date_time(this)?;
+        let time_zone = TimeZone::of(&zoned.time_zone);
+        let date = zoned.date_time(context).date;
+
+        let plain_time_like = args.get_or_undefined(0);
+        let epoch_nanoseconds = if plain_time_like.is_undefined() {
+            // 6. If plainTimeLike is undefined, then
+            //     a. Let epochNs be ? GetStartOfDay(timeZone, isoDateTime.[[ISODate]]).
+            time_zone.start_of_day(date, context)?
+        } else {
+            // 7. Else,
+            //     a. Let plainTime be ? ToTemporalTime(plainTimeLike).
+            //     b. Let resultISODateTime be CombineISODateAndTimeRecord(isoDateTime.[[ISODate]], plainTime.[[Time]]).
+            //     c. Let epochNs be ? GetEpochNanosecondsFor(timeZone, resultISODateTime, compatible).
+            let time = PlainTime::to_temporal_time(plain_time_like, None, context)?;
+            time_zone.epoch_nanoseconds_for(
+                IsoDateTime::new(date, time),
+                Disambiguation::Compatible,
+                context,
+            )?
+        };
+
+        // 8. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, calendar).
+        Self::new(
+            epoch_nanoseconds,
+            zoned.time_zone.clone(),
+            zoned.calendar.clone(),
+        )
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withPlainDate ( plainDateLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withplaindate
+    fn with_plain_date(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = this_zoned_date_time(this)?;
+
+        // 3. Let plainDate be ? ToTemporalDate(plainDateLike).
+        // 4. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 6. Let calendar be ? ConsolidateCalendars(zonedDateTime.[[Calendar]], plainDate.[[Calendar]]).
+        let date = PlainDate::to_temporal_date(args.get_or_undefined(0), None, context)?;
+        let time = zoned.date_time(context).time;
+
+        // 7. Let resultISODateTime be CombineISODateAndTimeRecord(plainDate.[[ISODate]], isoDateTime.[[Time]]).
+        // 8. Let epochNs be ? GetEpochNanosecondsFor(timeZone, resultISODateTime, compatible).
+        let epoch_nanoseconds = TimeZone::of(&zoned.time_zone).epoch_nanoseconds_for(
+            IsoDateTime::new(date.iso, time).check_limits()?,
+            Disambiguation::Compatible,
+            context,
+        )?;
+
+        // 9. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, calendar).
+        Self::new(
+            epoch_nanoseconds,
+            zoned.time_zone.clone(),
+            date.calendar.clone(),
+        )
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withTimeZone ( timeZoneLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withtimezone
+    fn with_time_zone(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = this_zoned_date_time(this)?;
+
+        // 3. Let timeZone be ? ToTemporalTimeZone(timeZoneLike).
+        let time_zone = TimeZone::to_temporal_time_zone(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], timeZone, zonedDateTime.[[Calendar]]).
+        Self::new(zoned.epoch_nanoseconds, time_zone, zoned.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withCalendar ( calendarLike )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withcalendar
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = this_zoned_date_time(this)?;
+
+        // 3. Let calendar be ? ToTemporalCalendar(calendarLike).
+        let calendar = Calendar::to_temporal_calendar(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], calendar).
+        Self::new(zoned.epoch_nanoseconds, zoned.time_zone.clone(), calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.add ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? AddDurationToZonedDateTime(add, zonedDateTime, temporalDurationLike, options).
+        Self::add_duration(false, this, args, context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.subtract ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? AddDurationToZonedDateTime(subtract, zonedDateTime, temporalDurationLike, options).
+        Self::add_duration(true, this, args, context)
+    }
+
+    /// Abstract operation `AddDurationToZonedDateTime ( operation, zonedDateTime, temporalDurationLike, options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtozoneddatetime
+    fn add_duration(
+        subtract: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let zoned = this_zoned_date_time(this)?;
+
+        // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::to_temporal_duration(args.get_or_undefined(0), context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let overflow = to_temporal_overflow(options.as_ref(), context)?;
+
+        // 5-6. Let timeZone be zonedDateTime.[[TimeZone]], and calendar be zonedDateTime.[[Calendar]].
+        // 7. Let internalDuration be ToInternalDurationRecord(duration).
+        // 8. Let epochNanoseconds be ? AddZonedDateTime(zonedDateTime.[[EpochNanoseconds]], timeZone, calendar, internalDuration, overflow).
+        let epoch_nanoseconds = add_zoned_date_time(
+            zoned.epoch_nanoseconds,
+            &TimeZone::of(&zoned.time_zone),
+            duration.to_internal(),
+            overflow,
+            context,
+        )?;
+
+        // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Self::new(
+            epoch_nanoseconds,
+            zoned.time_zone.clone(),
+            zoned.calendar.clone(),
+        )
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalZonedDateTime(until, zonedDateTime, other, options).
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return ? DifferenceTemporalZonedDateTime(since, zonedDateTime, other, options).
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation `DifferenceTemporalZonedDateTime ( operation, zonedDateTime, other, options )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalzoneddatetime
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let zoned = this_zoned_date_time(this)?;
+
+        // 1. Set other to ? ToTemporalZonedDateTime(other).
+        // 2. If CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]) is false, then
+        //     a. Throw a RangeError exception.
+        let other = Self::to_temporal_zoned_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, datetime, « », nanosecond, hour).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::DateTime,
+            Unit::Nanosecond,
+            Unit::Hour,
+            context,
+        )?;
+
+        // 5. If TemporalUnitCategory(settings.[[LargestUnit]]) is time, then
+        //     a. Let internalDuration be DifferenceInstant(zonedDateTime.[[EpochNanoseconds]], other.[[EpochNanoseconds]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        //     b. Return ! TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+        // 6. NOTE: To calculate differences in two different time zones, settings.[[LargestUnit]] must be a time unit, because day lengths can vary between time zones due to DST and other UTC offset shifts.
+        // 7. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, then
+        //     a. Throw a RangeError exception.
+        let time_zone = TimeZone::of(&zoned.time_zone);
+        let largest_unit = if settings.largest_unit.is_date_unit() {
+            if time_zone.identifier() != TimeZone::of(&other.time_zone).identifier() {
+                return Err(JsNativeError::range()
+                    .with_message(
+                        "cannot compute a difference in days between different time zones",
+                    )
+                    .into());
+            }
+            Unit::Hour
+        } else {
+            settings.largest_unit
+        };
+
+        // 8. If zonedDateTime.[[EpochNanoseconds]] = other.[[EpochNanoseconds]], then
+        //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        // 9. Let internalDuration be ? DifferenceZonedDateTimeWithRounding(zonedDateTime.[[EpochNanoseconds]], other.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]], settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        // 10. Let result be ! TemporalDurationFromInternal(internalDuration, hour).
+        let internal = difference_zoned_date_time_with_rounding(
+            zoned.epoch_nanoseconds,
+            other.epoch_nanoseconds,
+            &time_zone,
+            settings,
+            context,
+        )?;
+        let result = DurationRecord::from_internal(internal, largest_unit)?;
+
+        // 11. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 12. Return result.
+        let result = if since { result.negated() } else { result };
+        result.create(None, context).map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = this_zoned_date_time(this)?;
+
+        // 3-12. Let roundingIncrement, smallestUnit and roundingMode be the options of roundTo.
+        let (increment, unit, mode) =
+            get_time_rounding_options(args.get_or_undefined(0), true, context)?;
+
+        // 13. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        //     a. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]]).
+        if unit == Unit::Nanosecond && increment == 1 {
+            return zoned.create(None, context).map(Into::into);
+        }
+
+        // 14. Let thisNs be zonedDateTime.[[EpochNanoseconds]].
+        // 15. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 16. Let calendar be zonedDateTime.[[Calendar]].
+        // 17. Let isoDateTime be GetISODateTimeFor(timeZone, thisNs).
+        let time_zone = TimeZone::of(&zoned.time_zone);
+        let date_time = zoned.date_time(context);
+
+        let epoch_nanoseconds = if unit == Unit::Day {
+            // 18. If smallestUnit is day, then
+            //     a. Let dateStart be isoDateTime.[[ISODate]].
+            //     b. Let dateEnd be BalanceISODate(dateStart.[[Year]], dateStart.[[Month]], dateStart.[[Day]] + 1).
+            //     c. Let startNs be ? GetStartOfDay(timeZone, dateStart).
+            //     d. Assert: thisNs ≥ startNs.
+            //     e. Let endNs be ? GetStartOfDay(timeZone, dateEnd).
+            //     f. Assert: thisNs < endNs.
+            //     g. Let dayLengthNs be ℝ(endNs - startNs).
+            //     h. Let dayProgressNs be TimeDurationFromEpochNanosecondsDifference(thisNs, startNs).
+            //     i. Let roundedDayNs be ! RoundTimeDurationToIncrement(dayProgressNs, dayLengthNs, roundingMode).
+            //     j. Let epochNanoseconds be AddTimeDurationToEpochNanoseconds(roundedDayNs, startNs).
+            let end = IsoDate::from_epoch_days(date_time.date.epoch_days() + 1);
+            let start = time_zone.start_of_day(date_time.date, context)?;
+            let end = time_zone.start_of_day(end, context)?;
+            let rounded = mode
+                .as_if_positive()
+                .round(zoned.epoch_nanoseconds - start, end - start);
+            check_epoch_nanoseconds(start + rounded)?
+        } else {
+            // 19. Else,
+            //     a. Let roundResult be RoundISODateTime(isoDateTime, roundingIncrement, smallestUnit, roundingMode).
+            //     b. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, thisNs).
+            //     c. Let epochNanoseconds be ? InterpretISODateTimeOffset(roundResult.[[ISODate]], roundResult.[[Time]], option, offsetNanoseconds, timeZone, compatible, prefer, match-exactly).
+            let rounded = date_time.round(increment, unit, mode);
+            let offset = time_zone.offset_nanoseconds_for(zoned.epoch_nanoseconds, context);
+            interpret_iso_date_time_offset(
+                rounded,
+                OffsetBehaviour::Option(offset),
+                &time_zone,
+                Disambiguation::Compatible,
+                OffsetOption::Prefer,
+                false,
+                context,
+            )?
+        };
+
+        // 20. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Self::new(
+            epoch_nanoseconds,
+            zoned.time_zone.clone(),
+            zoned.calendar.clone(),
+        )
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = this_zoned_date_time(this)?;
+
+        // 3. Set other to ? ToTemporalZonedDateTime(other).
+        let other = Self::to_temporal_zoned_date_time(args.get_or_undefined(0), None, context)?;
+
+        // 4. If zonedDateTime.[[EpochNanoseconds]] ≠ other.[[EpochNanoseconds]], return false.
+        // 5. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, return false.
+        // 6. Return CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]).
+        Ok((zoned.epoch_nanoseconds == other.epoch_nanoseconds
+            && TimeZone::of(&zoned.time_zone).identifier()
+                == TimeZone::of(&other.time_zone).identifier())
+        .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tostring
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = this_zoned_date_time(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        // 6-8. Let digits, showOffset, roundingMode and smallestUnit be the options.
+        // 9. Let showTimeZone be ? GetTemporalShowTimeZoneNameOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(0))?;
+        let show_calendar = Calendar::to_calendar_name_option(options.as_ref(), context)?;
+        let (rounding, mode) = to_seconds_string_precision(options.as_ref(), context)?;
+        let show_offset =
+            get_option(options.as_ref(), "offset", context)?.unwrap_or(ShowOffset::Auto);
+        let show_time_zone =
+            get_option(options.as_ref(), "timeZoneName", context)?.unwrap_or(ShowTimeZone::Auto);
+
+        // 10-12. Return TemporalZonedDateTimeToString(zonedDateTime, precision.[[Precision]], showCalendar, showTimeZone, showOffset, precision.[[Increment]], precision.[[Unit]], roundingMode).
+        Ok(zoned
+            .to_string_with_options(
+                rounding.precision,
+                show_offset,
+                show_time_zone,
+                (rounding.increment, rounding.unit, mode),
+                context,
+            )
+            .map(|string| format!("{string}{}", Calendar::format_annotation(show_calendar)))?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tojson
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return TemporalZonedDateTimeToString(zonedDateTime, auto, auto, auto, auto).
+        let zoned = this_zoned_date_time(this)?;
+        Ok(zoned
+            .to_string_with_options(
+                Precision::Auto,
+                ShowOffset::Auto,
+                ShowTimeZone::Auto,
+                (1, Unit::Nanosecond, RoundingMode::Trunc),
+                context,
+            )?
+            .into())
+    }
+
+    /// Abstract operation `TemporalZonedDateTimeToString ( zonedDateTime, precision, showCalendar, showTimeZone, showOffset [ , increment, unit, roundingMode ] )`
+    ///
+    /// Formats the zoned date-time without its calendar annotation, which is the same for all
+    /// the zoned date-times of the ISO 8601 calendar.
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporalzoneddatetimetostring
+    fn to_string_with_options(
+        &self,
+        precision: Precision,
+        show_offset: ShowOffset,
+        show_time_zone: ShowTimeZone,
+        (increment, unit, mode): (u64, Unit, RoundingMode),
+        context: &Context,
+    ) -> JsResult<String> {
+        // 1-3. If increment, unit and roundingMode are not present, set them to 1, nanosecond and trunc.
+        // 4. Let epochNs be zonedDateTime.[[EpochNanoseconds]].
+        // 5. Set epochNs to RoundTemporalInstant(epochNs, increment, unit, roundingMode).
+        let epoch_nanoseconds = check_epoch_nanoseconds(round_epoch_nanoseconds(
+            self.epoch_nanoseconds,
+            increment,
+            unit,
+            mode,
+        ))?;
+
+        // 6. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 7. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+        // 8. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+        // 9. Let dateTimeString be ISODateTimeToString(isoDateTime, "iso8601", precision, never).
+        let time_zone = TimeZone::of(&self.time_zone);
+        let offset = time_zone.offset_nanoseconds_for(epoch_nanoseconds, context);
+        let date_time = time_zone.date_time_for(epoch_nanoseconds, context);
+
+        // 10. If showOffset is never, then
+        //     a. Let offsetString be the empty String.
+        // 11. Else,
+        //     a. Let offsetString be FormatDateTimeUTCOffsetRounded(offsetNanoseconds).
+        let offset = match show_offset {
+            ShowOffset::Never => String::new(),
+            ShowOffset::Auto => format_offset_rounded(offset),
+        };
+
+        // 12. If showTimeZone is never, then
+        //     a. Let timeZoneString be the empty String.
+        // 13. Else,
+        //     a. If showTimeZone is critical, let flag be "!"; else let flag be the empty String.
+        //     b. Let timeZoneString be the string-concatenation of the code unit 0x005B (LEFT SQUARE BRACKET), flag, timeZone, and the code unit 0x005D (RIGHT SQUARE BRACKET).
+        let time_zone = match show_time_zone {
+            ShowTimeZone::Never => String::new(),
+            ShowTimeZone::Auto => format!("[{}]", time_zone.identifier()),
+            ShowTimeZone::Critical => format!("[!{}]", time_zone.identifier()),
+        };
+
+        // 14-15. Return the string-concatenation of dateTimeString, offsetString, timeZoneString, and calendarString.
+        Ok(format!(
+            "{}T{}{offset}{time_zone}",
+            date_time.date,
+            date_time.time.to_string_with_precision(precision)
+        ))
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.startOfDay ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.startofday
+    fn start_of_day(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3-4. Let timeZone be zonedDateTime.[[TimeZone]], and calendar be zonedDateTime.[[Calendar]].
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 6. Let epochNanoseconds be ? GetStartOfDay(timeZone, isoDateTime.[[ISODate]]).
+        // 7. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        let zoned = this_zoned_date_time(this)?;
+        let date = zoned.date_time(context).date;
+        let epoch_nanoseconds = TimeZone::of(&zoned.time_zone).start_of_day(date, context)?;
+        Self::new(
+            epoch_nanoseconds,
+            zoned.time_zone.clone(),
+            zoned.calendar.clone(),
+        )
+        .create(None, context)
+        .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toInstant ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toinstant
+    fn to_instant(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return ! CreateTemporalInstant(zonedDateTime.[[EpochNanoseconds]]).
+        let zoned = this_zoned_date_time(this)?;
+        Instant::create(zoned.epoch_nanoseconds, None, context).map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainDate ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindate
+    fn to_plain_date(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], zonedDateTime.[[Calendar]]).
+        let zoned = this_zoned_date_time(this)?;
+        let date = zoned.date_time(context).date;
+        PlainDate::new(date, zoned.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainTime ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaintime
+    fn to_plain_time(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+        let zoned = this_zoned_date_time(this)?;
+        let time = zoned.date_time(context).time;
+        let calendar = Calendar::create(context);
+        PlainTime::new(time, calendar)
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainDateTime ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindatetime
+    fn to_plain_date_time(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalDateTime(isoDateTime, zonedDateTime.[[Calendar]]).
+        let zoned = this_zoned_date_time(this)?;
+        let date_time = zoned.date_time(context);
+        PlainDateTime::new(date_time, zoned.calendar.clone())
+            .create(None, context)
+            .map(Into::into)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.getISOFields ( )`
+    ///
+    /// More information:
+    ///  - [Temporal proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.getisofields
+    fn get_iso_fields(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = this_zoned_date_time(this)?;
+
+        // 3. Let fields be OrdinaryObjectCreate(%Object.prototype%).
+        // 4-9. Let dateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]), and offset be FormatUTCOffsetNanoseconds(GetOffsetNanosecondsFor(timeZone, zonedDateTime.[[EpochNanoseconds]])).
+        // 10-21. Perform ! CreateDataPropertyOrThrow(fields, "calendar", calendar), and the same for the ISO fields, the offset and the time zone.
+        // 22. Return fields.
+        let fields = context.construct_object();
+        let IsoDateTime { date, time } = zoned.date_time(context);
+        let offset =
+            TimeZone::of(&zoned.time_zone).offset_nanoseconds_for(zoned.epoch_nanoseconds, context);
+        for (key, value) in [
+            ("calendar", JsValue::from(zoned.calendar.clone())),
+            ("isoDay", date.day.into()),
+            ("isoHour", time.hour.into()),
+            ("isoMicrosecond", time.microsecond.into()),
+            ("isoMillisecond", time.millisecond.into()),
+            ("isoMinute", time.minute.into()),
+            ("isoMonth", date.month.into()),
+            ("isoNanosecond", time.nanosecond.into()),
+            ("isoSecond", time.second.into()),
+            ("isoYear", date.year.into()),
+            ("offset", format_offset(offset).into()),
+            ("timeZone", zoned.time_zone.clone().into()),
+        ] {
+            fields
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining a property of a new object cannot fail");
+        }
+        Ok(fields.into())
+    }
+}
+
+/// Gets the `this` value of a `Temporal.ZonedDateTime` method, which must be a zoned date-time.
+fn this_zoned_date_time(this: &JsValue) -> JsResult<ZonedDateTime> {
+    this.as_object()
+        .and_then(|object| object.borrow().as_zoned_date_time().cloned())
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("'this' is not a Temporal.ZonedDateTime")
+                .into()
+        })
+}
@@ -9,7 +9,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects
 
 use crate::{
-    builtins::typed_array::TypedArrayKind,
+    builtins::{array_buffer::ArrayBuffer, typed_array::TypedArrayKind},
     object::{JsObject, ObjectData},
     Context,
 };
@@ -31,6 +31,7 @@ pub struct IntegerIndexed {
     byte_offset: u64,
     byte_length: u64,
     array_length: u64,
+    tracks_length: bool,
 }
 
 impl IntegerIndexed {
@@ -47,6 +48,7 @@ impl IntegerIndexed {
             byte_offset,
             byte_length,
             array_length,
+            tracks_length: false,
         }
     }
 
@@ -81,23 +83,64 @@ impl IntegerIndexed {
         a
     }
 
-    /// Abstract operation `IsDetachedBuffer ( arrayBuffer )`.
+    /// Abstract operation `IsTypedArrayOutOfBounds ( taRecord )`.
     ///
-    /// Check if `[[ArrayBufferData]]` is null.
+    /// Checks if the integer indexed object is viewing a detached buffer, or if any of its
+    /// elements lies outside of the bounds of its (possibly resized) buffer.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-isdetachedbuffer
-    pub(crate) fn is_detached(&self) -> bool {
-        if let Some(obj) = &self.viewed_array_buffer {
-            obj.borrow()
-                .as_array_buffer()
-                .expect("Typed array must have internal array buffer object")
-                .is_detached_buffer()
+    /// [spec]: https://tc39.es/ecma262/#sec-istypedarrayoutofbounds
+    pub(crate) fn is_out_of_bounds(&self) -> bool {
+        let buffer = if let Some(buffer) = &self.viewed_array_buffer {
+            buffer.borrow()
         } else {
-            false
+            return false;
+        };
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("Typed array must have internal array buffer object");
+
+        // 1. Let O be taRecord.[[Object]].
+        // 2. Let bufferByteLength be taRecord.[[CachedBufferByteLength]].
+        // 3. Assert: IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true if and only if bufferByteLength is detached.
+        // 4. If bufferByteLength is detached, return true.
+        if buffer.is_detached_buffer() {
+            return true;
         }
+        let buffer_byte_length = buffer.array_buffer_byte_length();
+
+        // 5. Let byteOffsetStart be O.[[ByteOffset]].
+        let byte_offset_start = self.byte_offset;
+
+        // 6. If O.[[ArrayLength]] is auto, then
+        let byte_offset_end = if self.tracks_length {
+            // a. Let byteOffsetEnd be bufferByteLength.
+            buffer_byte_length
+        }
+        // 7. Else,
+        else {
+            // a. Let elementSize be TypedArrayElementSize(O).
+            // b. Let byteOffsetEnd be byteOffsetStart + O.[[ArrayLength]] × elementSize.
+            byte_offset_start + self.array_length * self.typed_array_name.element_size()
+        };
+
+        // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        // 9. NOTE: 0-length TypedArrays are not considered out-of-bounds.
+        // 10. Return false.
+        byte_offset_start > buffer_byte_length || byte_offset_end > buffer_byte_length
+    }
+
+    /// Returns `true` if the integer indexed object's `[[ArrayLength]]` is `auto`, meaning
+    /// its length follows the length of its resizable buffer.
+    pub(crate) fn is_length_tracking(&self) -> bool {
+        self.tracks_length
+    }
+
+    /// Set whether the integer indexed object's length follows the length of its buffer.
+    pub(crate) fn set_length_tracking(&mut self, tracks_length: bool) {
+        self.tracks_length = tracks_length;
     }
 
     /// Get the integer indexed object's byte offset.
@@ -126,8 +169,22 @@ impl IntegerIndexed {
     }
 
     /// Get the integer indexed object's byte length.
+    ///
+    /// This follows the abstract operation `TypedArrayByteLength ( taRecord )`, returning `0`
+    /// if the integer indexed object is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraybytelength
     pub fn byte_length(&self) -> u64 {
-        self.byte_length
+        if !self.tracks_length {
+            if self.is_out_of_bounds() {
+                return 0;
+            }
+            return self.byte_length;
+        }
+        self.array_length() * self.typed_array_name.element_size()
     }
 
     /// Set the integer indexed object's byte length.
@@ -136,8 +193,44 @@ impl IntegerIndexed {
     }
 
     /// Get the integer indexed object's array length.
+    ///
+    /// This follows the abstract operation `TypedArrayLength ( taRecord )`, returning `0`
+    /// if the integer indexed object is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraylength
     pub fn array_length(&self) -> u64 {
-        self.array_length
+        // 1. Assert: IsTypedArrayOutOfBounds(taRecord) is false.
+        if self.is_out_of_bounds() {
+            return 0;
+        }
+
+        // 2. Let O be taRecord.[[Object]].
+        // 3. If O.[[ArrayLength]] is not auto, return O.[[ArrayLength]].
+        if !self.tracks_length {
+            return self.array_length;
+        }
+
+        // 4. Assert: IsFixedLengthArrayBuffer(O.[[ViewedArrayBuffer]]) is false.
+        // 5. Let byteOffset be O.[[ByteOffset]].
+        // 6. Let elementSize be TypedArrayElementSize(O).
+        // 7. Let byteLength be taRecord.[[CachedBufferByteLength]].
+        // 8. Assert: byteLength is not detached.
+        let byte_length = self
+            .viewed_array_buffer
+            .as_ref()
+            .and_then(|buffer| {
+                buffer
+                    .borrow()
+                    .as_array_buffer()
+                    .map(ArrayBuffer::array_buffer_byte_length)
+            })
+            .unwrap_or_default();
+
+        // 9. Return floor((byteLength - byteOffset) / elementSize).
+        (byte_length - self.byte_offset) / self.typed_array_name.element_size()
     }
 
     /// Set the integer indexed object's array length.
@@ -20,13 +20,14 @@ use crate::{
         Array, ArrayIterator, BuiltIn, JsArgs,
     },
     context::intrinsics::{StandardConstructor, StandardConstructors},
+    error::JsNativeError,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsObject, ObjectData,
     },
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
-    value::{IntegerOrInfinity, JsValue},
+    value::{IntegerOrInfinity, JsValue, JsVariant},
     Context, JsResult, JsString,
 };
 use boa_profiler::Profiler;
@@ -120,10 +121,12 @@ macro_rules! typed_array {
             ) -> JsResult<JsValue> {
                 // 1. If NewTarget is undefined, throw a TypeError exception.
                 if new_target.is_undefined() {
-                    return context.throw_type_error(concat!(
-                        "new target was undefined when constructing an ",
-                        $name
-                    ));
+                    return Err(JsNativeError::typ()
+                        .with_message(concat!(
+                            "new target was undefined when constructing an ",
+                            $name
+                        ))
+                        .into());
                 }
 
                 // 2. Let constructorName be the String value of the Constructor Name value specified in Table 72 for this TypedArray constructor.
@@ -379,13 +382,11 @@ impl TypedArray {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%
-    fn constructor(
-        _new_target: &JsValue,
-        _args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    fn constructor(_new_target: &JsValue, _args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Throw a TypeError exception.
-        context.throw_type_error("the TypedArray constructor should never be called directly")
+        Err(JsNativeError::typ()
+            .with_message("the TypedArray constructor should never be called directly")
+            .into())
     }
 
     /// `23.2.2.1 %TypedArray%.from ( source [ , mapfn [ , thisArg ] ] )`
@@ -400,22 +401,25 @@ impl TypedArray {
         let constructor = match this.as_object() {
             Some(obj) if obj.is_constructor() => obj,
             _ => {
-                return context
-                    .throw_type_error("TypedArray.from called on non-constructable value")
+                return Err(JsNativeError::typ()
+                    .with_message("TypedArray.from called on non-constructable value")
+                    .into())
             }
         };
 
         let mapping = match args.get(1) {
             // 3. If mapfn is undefined, let mapping be false.
-            None | Some(JsValue::Undefined) => None,
+            None => None,
+            Some(v) if v.is_undefined() => None,
             // 4. Else,
             Some(v) => match v.as_object() {
                 // b. Let mapping be true.
                 Some(obj) if obj.is_callable() => Some(obj),
                 // a. If IsCallable(mapfn) is false, throw a TypeError exception.
                 _ => {
-                    return context
-                        .throw_type_error("TypedArray.from called with non-callable mapfn")
+                    return Err(JsNativeError::typ()
+                        .with_message("TypedArray.from called with non-callable mapfn")
+                        .into())
                 }
             },
         };
@@ -510,7 +514,9 @@ impl TypedArray {
         let constructor = match this.as_object() {
             Some(obj) if obj.is_constructor() => obj,
             _ => {
-                return context.throw_type_error("TypedArray.of called on non-constructable value")
+                return Err(JsNativeError::typ()
+                    .with_message("TypedArray.of called on non-constructable value")
+                    .into())
             }
         };
 
@@ -551,15 +557,17 @@ impl TypedArray {
     pub(crate) fn at(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -596,17 +604,17 @@ impl TypedArray {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
-    fn buffer(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    fn buffer(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let typed_array = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let typed_array = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         // 5. Return buffer.
@@ -621,31 +629,22 @@ impl TypedArray {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.bytelength
-    pub(crate) fn byte_length(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn byte_length(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let typed_array = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let typed_array = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
-        // 6. Let size be O.[[ByteLength]].
-        // 7. Return 𝔽(size).
-        if typed_array.is_detached() {
-            Ok(0.into())
-        } else {
-            Ok(typed_array.byte_length().into())
-        }
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. Let size be TypedArrayByteLength(taRecord).
+        // 6. Return 𝔽(size).
+        Ok(typed_array.byte_length().into())
     }
 
     /// `23.2.3.4 get %TypedArray%.prototype.byteOffset`
@@ -654,27 +653,23 @@ impl TypedArray {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.byteoffset
-    pub(crate) fn byte_offset(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn byte_offset(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let typed_array = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let typed_array = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return +0𝔽.
         // 6. Let offset be O.[[ByteOffset]].
         // 7. Return 𝔽(offset).
-        if typed_array.is_detached() {
+        if typed_array.is_out_of_bounds() {
             Ok(0.into())
         } else {
             Ok(typed_array.byte_offset().into())
@@ -689,19 +684,21 @@ impl TypedArray {
     /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.copywithin
     fn copy_within(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         let len = {
             let obj_borrow = obj.borrow();
-            let o = obj_borrow
-                .as_typed_array()
-                .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+            let o = obj_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("Value is not a typed array object")
+            })?;
 
             // 2. Perform ? ValidateTypedArray(O).
-            if o.is_detached() {
-                return context.throw_type_error("Buffer of the typed array is detached");
+            if o.is_out_of_bounds() {
+                return Err(JsNativeError::typ()
+                    .with_message("Buffer of the typed array is detached or out of bounds")
+                    .into());
             }
 
             // 3. Let len be O.[[ArrayLength]].
@@ -756,38 +753,44 @@ impl TypedArray {
         let count = std::cmp::min(r#final - from, len - to);
 
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 17. If count > 0, then
         if count > 0 {
             // a. NOTE: The copying must be performed in a manner that preserves the bit-level encoding of the source data.
             // b. Let buffer be O.[[ViewedArrayBuffer]].
-            // c. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-            if o.is_detached() {
-                return context.throw_type_error("Buffer of the typed array is detached");
+            // c. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // d. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            if o.is_out_of_bounds() {
+                return Err(JsNativeError::typ()
+                    .with_message("Buffer of the typed array is detached or out of bounds")
+                    .into());
             }
 
-            // d. Let typedArrayName be the String value of O.[[TypedArrayName]].
-            let typed_array_name = o.typed_array_name();
+            // e. Set len to TypedArrayLength(taRecord).
+            let len = o.array_length() as i64;
 
-            // e. Let elementSize be the Element Size value specified in Table 73 for typedArrayName.
-            let element_size = typed_array_name.element_size() as i64;
+            // f. Let elementSize be TypedArrayElementSize(O).
+            let element_size = o.typed_array_name().element_size() as i64;
 
-            // f. Let byteOffset be O.[[ByteOffset]].
+            // g. Let byteOffset be O.[[ByteOffset]].
             let byte_offset = o.byte_offset() as i64;
 
-            // g. Let toByteIndex be to × elementSize + byteOffset.
+            // h. Let bufferByteLimit be len × elementSize + byteOffset.
+            let buffer_byte_limit = len * element_size + byte_offset;
+
+            // i. Let toByteIndex be to × elementSize + byteOffset.
             let mut to_byte_index = to * element_size + byte_offset;
 
-            // h. Let fromByteIndex be from × elementSize + byteOffset.
+            // j. Let fromByteIndex be from × elementSize + byteOffset.
             let mut from_byte_index = from * element_size + byte_offset;
 
-            // i. Let countBytes be count × elementSize.
+            // k. Let countBytes be count × elementSize.
             let mut count_bytes = count * element_size;
 
-            // j. If fromByteIndex < toByteIndex and toByteIndex < fromByteIndex + countBytes, then
+            // l. If fromByteIndex < toByteIndex and toByteIndex < fromByteIndex + countBytes, then
             let direction = if from_byte_index < to_byte_index
                 && to_byte_index < from_byte_index + count_bytes
             {
@@ -800,7 +803,7 @@ impl TypedArray {
                 // i. Let direction be -1.
                 -1
             }
-            // k. Else,
+            // m. Else,
             else {
                 // i. Let direction be 1.
                 1
@@ -814,9 +817,16 @@ impl TypedArray {
                 .as_array_buffer_mut()
                 .expect("Already checked for detached buffer");
 
-            // l. Repeat, while countBytes > 0,
+            // n. Repeat, while countBytes > 0,
             while count_bytes > 0 {
-                // i. Let value be GetValueFromBuffer(buffer, fromByteIndex, Uint8, true, Unordered).
+                // i. If fromByteIndex < bufferByteLimit and toByteIndex < bufferByteLimit, then
+                // ii. Else,
+                if from_byte_index >= buffer_byte_limit || to_byte_index >= buffer_byte_limit {
+                    // 1. Set countBytes to 0.
+                    break;
+                }
+
+                // 1. Let value be GetValueFromBuffer(buffer, fromByteIndex, Uint8, true, unordered).
                 let value = buffer.get_value_from_buffer(
                     from_byte_index as u64,
                     TypedArrayKind::Uint8,
@@ -825,7 +835,7 @@ impl TypedArray {
                     None,
                 );
 
-                // ii. Perform SetValueInBuffer(buffer, toByteIndex, Uint8, value, true, Unordered).
+                // 2. Perform SetValueInBuffer(buffer, toByteIndex, Uint8, value, true, unordered).
                 buffer.set_value_in_buffer(
                     to_byte_index as u64,
                     TypedArrayKind::Uint8,
@@ -835,13 +845,13 @@ impl TypedArray {
                     context,
                 )?;
 
-                // iii. Set fromByteIndex to fromByteIndex + direction.
+                // 3. Set fromByteIndex to fromByteIndex + direction.
                 from_byte_index += direction;
 
-                // iv. Set toByteIndex to toByteIndex + direction.
+                // 4. Set toByteIndex to toByteIndex + direction.
                 to_byte_index += direction;
 
-                // v. Set countBytes to countBytes - 1.
+                // 5. Set countBytes to countBytes - 1.
                 count_bytes -= 1;
             }
         }
@@ -859,15 +869,17 @@ impl TypedArray {
     fn entries(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let o = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.borrow()
             .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
-            .is_detached()
+            .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
+            .is_out_of_bounds()
         {
-            return context.throw_type_error("Buffer of the typed array is detached");
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Return CreateArrayIterator(O, key+value).
@@ -891,15 +903,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -909,9 +923,11 @@ impl TypedArray {
         let callback_fn = match args.get_or_undefined(0).as_object() {
             Some(obj) if obj.is_callable() => obj,
             _ => {
-                return context.throw_type_error(
-                    "TypedArray.prototype.every called with non-callable callback function",
-                )
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "TypedArray.prototype.every called with non-callable callback function",
+                    )
+                    .into())
             }
         };
 
@@ -954,15 +970,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1006,8 +1024,10 @@ impl TypedArray {
         };
 
         // 14. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, throw a TypeError exception.
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 15. Repeat, while k < final,
@@ -1038,29 +1058,32 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
         let len = o.array_length();
 
         // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback_fn = match args.get_or_undefined(0).as_object() {
-            Some(obj) if obj.is_callable() => obj,
-            _ => {
-                return context.throw_type_error(
-                    "TypedArray.prototype.filter called with non-callable callback function",
-                )
-            }
-        };
+        let callback_fn =
+            match args.get_or_undefined(0).as_object() {
+                Some(obj) if obj.is_callable() => obj,
+                _ => return Err(JsNativeError::typ()
+                    .with_message(
+                        "TypedArray.prototype.filter called with non-callable callback function",
+                    )
+                    .into()),
+            };
 
         // 5. Let kept be a new empty List.
         let mut kept = Vec::new();
@@ -1123,15 +1146,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1141,9 +1166,11 @@ impl TypedArray {
         let predicate = match args.get_or_undefined(0).as_object() {
             Some(obj) if obj.is_callable() => obj,
             _ => {
-                return context.throw_type_error(
-                    "TypedArray.prototype.find called with non-callable predicate function",
-                )
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "TypedArray.prototype.find called with non-callable predicate function",
+                    )
+                    .into())
             }
         };
 
@@ -1185,28 +1212,31 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
         let len = o.array_length();
 
         // 4. If IsCallable(predicate) is false, throw a TypeError exception.
-        let predicate =
-            match args.get_or_undefined(0).as_object() {
-                Some(obj) if obj.is_callable() => obj,
-                _ => return context.throw_type_error(
+        let predicate = match args.get_or_undefined(0).as_object() {
+            Some(obj) if obj.is_callable() => obj,
+            _ => return Err(JsNativeError::typ()
+                .with_message(
                     "TypedArray.prototype.findindex called with non-callable predicate function",
-                ),
-            };
+                )
+                .into()),
+        };
 
         // 5. Let k be 0.
         // 6. Repeat, while k < len,
@@ -1246,29 +1276,32 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
         let len = o.array_length();
 
         // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback_fn = match args.get_or_undefined(0).as_object() {
-            Some(obj) if obj.is_callable() => obj,
-            _ => {
-                return context.throw_type_error(
-                    "TypedArray.prototype.foreach called with non-callable callback function",
-                )
-            }
-        };
+        let callback_fn =
+            match args.get_or_undefined(0).as_object() {
+                Some(obj) if obj.is_callable() => obj,
+                _ => return Err(JsNativeError::typ()
+                    .with_message(
+                        "TypedArray.prototype.foreach called with non-callable callback function",
+                    )
+                    .into()),
+            };
 
         // 5. Let k be 0.
         // 6. Repeat, while k < len,
@@ -1302,15 +1335,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1379,15 +1414,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1465,15 +1502,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1496,7 +1535,7 @@ impl TypedArray {
         for k in 0..len {
             // a. If k > 0, set R to the string-concatenation of R and sep.
             if k > 0 {
-                r = JsString::concat(r, sep.clone());
+                r = JsString::concat(&r, &sep);
             }
 
             // b. Let element be ! Get(O, ! ToString(𝔽(k))).
@@ -1505,7 +1544,7 @@ impl TypedArray {
             // c. If element is undefined, let next be the empty String; otherwise, let next be ! ToString(element).
             // d. Set R to the string-concatenation of R and next.
             if !element.is_undefined() {
-                r = JsString::concat(r, element.to_string(context)?);
+                r = JsString::concat(&r, &element.to_string(context)?);
             }
         }
 
@@ -1522,15 +1561,17 @@ impl TypedArray {
     pub(crate) fn keys(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let o = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.borrow()
             .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
-            .is_detached()
+            .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
+            .is_out_of_bounds()
         {
-            return context.throw_type_error("Buffer of the typed array is detached");
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Return CreateArrayIterator(O, key).
@@ -1554,15 +1595,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1625,31 +1668,23 @@ impl TypedArray {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length
-    pub(crate) fn length(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub(crate) fn length(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has [[ViewedArrayBuffer]] and [[ArrayLength]] internal slots.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let typed_array = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let typed_array = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
-        // 6. Let length be O.[[ArrayLength]].
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return +0𝔽.
+        // 6. Let length be TypedArrayLength(taRecord).
         // 7. Return 𝔽(length).
-        if typed_array.is_detached() {
-            Ok(0.into())
-        } else {
-            Ok(typed_array.array_length().into())
-        }
+        Ok(typed_array.array_length().into())
     }
 
     /// `23.2.3.20 %TypedArray%.prototype.map ( callbackfn [ , thisArg ] )`
@@ -1665,15 +1700,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1683,9 +1720,11 @@ impl TypedArray {
         let callback_fn = match args.get_or_undefined(0).as_object() {
             Some(obj) if obj.is_callable() => obj,
             _ => {
-                return context.throw_type_error(
-                    "TypedArray.prototype.map called with non-callable callback function",
-                )
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "TypedArray.prototype.map called with non-callable callback function",
+                    )
+                    .into())
             }
         };
 
@@ -1727,34 +1766,38 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
         let len = o.array_length();
 
         // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback_fn = match args.get_or_undefined(0).as_object() {
-            Some(obj) if obj.is_callable() => obj,
-            _ => {
-                return context.throw_type_error(
-                    "TypedArray.prototype.reduce called with non-callable callback function",
-                )
-            }
-        };
+        let callback_fn =
+            match args.get_or_undefined(0).as_object() {
+                Some(obj) if obj.is_callable() => obj,
+                _ => return Err(JsNativeError::typ()
+                    .with_message(
+                        "TypedArray.prototype.reduce called with non-callable callback function",
+                    )
+                    .into()),
+            };
 
         // 5. If len = 0 and initialValue is not present, throw a TypeError exception.
         if len == 0 && args.get(1).is_none() {
-            return context
-                .throw_type_error("Typed array length is 0 and initial value is not present");
+            return Err(JsNativeError::typ()
+                .with_message("Typed array length is 0 and initial value is not present")
+                .into());
         }
 
         // 6. Let k be 0.
@@ -1808,33 +1851,37 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
         let len = o.array_length() as i64;
 
         // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback_fn =
-            match args.get_or_undefined(0).as_object() {
-                Some(obj) if obj.is_callable() => obj,
-                _ => return context.throw_type_error(
+        let callback_fn = match args.get_or_undefined(0).as_object() {
+            Some(obj) if obj.is_callable() => obj,
+            _ => return Err(JsNativeError::typ()
+                .with_message(
                     "TypedArray.prototype.reduceright called with non-callable callback function",
-                ),
-            };
+                )
+                .into()),
+        };
 
         // 5. If len = 0 and initialValue is not present, throw a TypeError exception.
         if len == 0 && args.get(1).is_none() {
-            return context
-                .throw_type_error("Typed array length is 0 and initial value is not present");
+            return Err(JsNativeError::typ()
+                .with_message("Typed array length is 0 and initial value is not present")
+                .into());
         }
 
         // 6. Let k be len - 1.
@@ -1892,15 +1939,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1953,10 +2002,12 @@ impl TypedArray {
         // 2. Perform ? RequireInternalSlot(target, [[TypedArrayName]]).
         // 3. Assert: target has a [[ViewedArrayBuffer]] internal slot.
         let target = this.as_object().ok_or_else(|| {
-            context.construct_type_error("TypedArray.set must be called on typed array object")
+            JsNativeError::typ().with_message("TypedArray.set must be called on typed array object")
         })?;
         if !target.is_typed_array() {
-            return context.throw_type_error("TypedArray.set must be called on typed array object");
+            return Err(JsNativeError::typ()
+                .with_message("TypedArray.set must be called on typed array object")
+                .into());
         }
 
         // 4. Let targetOffset be ? ToIntegerOrInfinity(offset).
@@ -1965,18 +2016,22 @@ impl TypedArray {
         // 5. If targetOffset < 0, throw a RangeError exception.
         match target_offset {
             IntegerOrInfinity::Integer(i) if i < 0 => {
-                return context.throw_range_error("TypedArray.set called with negative offset")
+                return Err(JsNativeError::range()
+                    .with_message("TypedArray.set called with negative offset")
+                    .into())
             }
             IntegerOrInfinity::NegativeInfinity => {
-                return context.throw_range_error("TypedArray.set called with negative offset")
+                return Err(JsNativeError::range()
+                    .with_message("TypedArray.set called with negative offset")
+                    .into())
             }
             _ => {}
         }
 
         let source = args.get_or_undefined(0);
-        match source {
+        match source.variant() {
             // 6. If source is an Object that has a [[TypedArrayName]] internal slot, then
-            JsValue::Object(source) if source.is_typed_array() => {
+            JsVariant::Object(source) if source.is_typed_array() => {
                 // a. Perform ? SetTypedArrayFromTypedArray(target, targetOffset, source).
                 Self::set_typed_array_from_typed_array(target, target_offset, source, context)?;
             }
@@ -2015,8 +2070,10 @@ impl TypedArray {
 
         // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
         // 2. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
-        if target_array.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if target_array.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
         let target_buffer_obj = target_array
             .viewed_array_buffer()
@@ -2027,8 +2084,10 @@ impl TypedArray {
 
         // 4. Let srcBuffer be source.[[ViewedArrayBuffer]].
         // 5. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
-        if source_array.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if source_array.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
         let mut src_buffer_obj = source_array
             .viewed_array_buffer()
@@ -2062,23 +2121,27 @@ impl TypedArray {
         let target_offset = match target_offset {
             IntegerOrInfinity::Integer(i) if i >= 0 => i as u64,
             IntegerOrInfinity::PositiveInfinity => {
-                return context.throw_range_error("Target offset cannot be Infinity");
+                return Err(JsNativeError::range()
+                    .with_message("Target offset cannot be Infinity")
+                    .into());
             }
             _ => unreachable!(),
         };
 
         // 16. If srcLength + targetOffset > targetLength, throw a RangeError exception.
         if src_length + target_offset > target_length {
-            return context.throw_range_error(
-                "Source typed array and target offset longer than target typed array",
-            );
+            return Err(JsNativeError::range()
+                .with_message("Source typed array and target offset longer than target typed array")
+                .into());
         }
 
         // 17. If target.[[ContentType]] ≠ source.[[ContentType]], throw a TypeError exception.
         if target_name.content_type() != src_name.content_type() {
-            return context.throw_type_error(
-                "Source typed array and target typed array have different content types",
-            );
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "Source typed array and target typed array have different content types",
+                )
+                .into());
         }
 
         // TODO: Shared Array Buffer
@@ -2225,8 +2288,10 @@ impl TypedArray {
 
         // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
         // 2. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
-        if target_array.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if target_array.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let targetLength be target.[[ArrayLength]].
@@ -2251,7 +2316,9 @@ impl TypedArray {
         let target_offset = match target_offset {
             // 10. If targetOffset is +∞, throw a RangeError exception.
             IntegerOrInfinity::PositiveInfinity => {
-                return context.throw_range_error("Target offset cannot be Infinity")
+                return Err(JsNativeError::range()
+                    .with_message("Target offset cannot be Infinity")
+                    .into())
             }
             IntegerOrInfinity::Integer(i) if i >= 0 => i as u64,
             _ => unreachable!(),
@@ -2259,9 +2326,9 @@ impl TypedArray {
 
         // 11. If srcLength + targetOffset > targetLength, throw a RangeError exception.
         if src_length + target_offset > target_length {
-            return context.throw_range_error(
-                "Source object and target offset longer than target typed array",
-            );
+            return Err(JsNativeError::range()
+                .with_message("Source object and target offset longer than target typed array")
+                .into());
         }
 
         // 12. Let targetByteIndex be targetOffset × targetElementSize + targetByteOffset.
@@ -2287,6 +2354,14 @@ impl TypedArray {
                 value.to_number(context)?.into()
             };
 
+            // e. If IsValidIntegerIndex(target, 𝔽(targetOffset + k)) is true, then
+            // NOTE: The target buffer could have been detached or resized by the conversions above.
+            if target_array.is_out_of_bounds() || target_offset + k >= target_array.array_length() {
+                k += 1;
+                target_byte_index += target_element_size;
+                continue;
+            }
+
             let target_buffer_obj = target_array
                 .viewed_array_buffer()
                 .expect("Already checked for detached buffer");
@@ -2295,11 +2370,6 @@ impl TypedArray {
                 .as_array_buffer_mut()
                 .expect("Already checked for detached buffer");
 
-            // e. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
-            if target_buffer.is_detached_buffer() {
-                return context.throw_type_error("Cannot set value on detached array buffer");
-            }
-
             // f. Perform SetValueInBuffer(targetBuffer, targetByteIndex, targetType, value, true, Unordered).
             target_buffer.set_value_in_buffer(
                 target_byte_index,
@@ -2333,15 +2403,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -2388,22 +2460,29 @@ impl TypedArray {
 
         // 14. If count > 0, then
         if count > 0 {
-            // a. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, throw a TypeError exception.
-            if o.is_detached() {
-                return context.throw_type_error("Buffer of the typed array is detached");
+            // a. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // b. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            if o.is_out_of_bounds() {
+                return Err(JsNativeError::typ()
+                    .with_message("Buffer of the typed array is detached or out of bounds")
+                    .into());
             }
 
-            // b. Let srcName be the String value of O.[[TypedArrayName]].
-            // c. Let srcType be the Element Type value in Table 73 for srcName.
-            // d. Let targetName be the String value of A.[[TypedArrayName]].
-            // e. Let targetType be the Element Type value in Table 73 for targetName.
-            // f. If srcType is different from targetType, then
+            // c. Let endIndex be min(final, TypedArrayLength(taRecord)).
+            let end_index = std::cmp::min(r#final, o.array_length() as i64);
+
+            // d. Set countBytes to max(endIndex - startIndex, 0).
+            let count = std::cmp::max(end_index - k, 0) as u64;
+
+            // e. Let srcType be TypedArrayElementType(O).
+            // f. Let targetType be TypedArrayElementType(A).
+            // g. If srcType is different from targetType, then
             #[allow(clippy::if_not_else)]
             if o.typed_array_name() != a_array.typed_array_name() {
                 // i. Let n be 0.
                 let mut n = 0;
-                // ii. Repeat, while k < final,
-                while k < r#final {
+                // ii. Repeat, while k < endIndex,
+                while k < end_index {
                     // 1. Let Pk be ! ToString(𝔽(k)).
                     // 2. Let kValue be ! Get(O, Pk).
                     let k_value = obj.get(k, context).expect("Get cannot fail here");
@@ -2418,7 +2497,7 @@ impl TypedArray {
                     // 5. Set n to n + 1.
                     n += 1;
                 }
-            // g. Else,
+            // h. Else,
             } else {
                 // i. Let srcBuffer be O.[[ViewedArrayBuffer]].
                 let src_buffer_obj = o.viewed_array_buffer().expect("Cannot be detached here");
@@ -2502,15 +2581,17 @@ impl TypedArray {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -2520,9 +2601,11 @@ impl TypedArray {
         let callback_fn = match args.get_or_undefined(0).as_object() {
             Some(obj) if obj.is_callable() => obj,
             _ => {
-                return context.throw_type_error(
-                    "TypedArray.prototype.some called with non-callable callback function",
-                )
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "TypedArray.prototype.some called with non-callable callback function",
+                    )
+                    .into())
             }
         };
 
@@ -2563,18 +2646,20 @@ impl TypedArray {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
-        let compare_fn = match args.get(0) {
-            None | Some(JsValue::Undefined) => None,
-            Some(JsValue::Object(obj)) if obj.is_callable() => Some(obj),
+        let compare_fn = match args.first().map(JsValue::variant) {
+            None | Some(JsVariant::Undefined) => None,
+            Some(JsVariant::Object(obj)) if obj.is_callable() => Some(obj),
             _ => {
-                return context
-                    .throw_type_error("TypedArray.sort called with non-callable comparefn")
+                return Err(JsNativeError::typ()
+                    .with_message("TypedArray.sort called with non-callable comparefn")
+                    .into())
             }
         };
 
         // 2. Let obj be the this value.
         let obj = this.as_object().ok_or_else(|| {
-            context.construct_type_error("TypedArray.sort must be called on typed array object")
+            JsNativeError::typ()
+                .with_message("TypedArray.sort must be called on typed array object")
         })?;
 
         // 4. Let buffer be obj.[[ViewedArrayBuffer]].
@@ -2583,12 +2668,15 @@ impl TypedArray {
             // 3. Perform ? ValidateTypedArray(obj).
             let obj_borrow = obj.borrow();
             let o = obj_borrow.as_typed_array().ok_or_else(|| {
-                context.construct_type_error("TypedArray.sort must be called on typed array object")
+                JsNativeError::typ()
+                    .with_message("TypedArray.sort must be called on typed array object")
             })?;
-            if o.is_detached() {
-                return context.throw_type_error(
-                    "TypedArray.sort called on typed array object with detached array buffer",
-                );
+            if o.is_out_of_bounds() {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "TypedArray.sort called on typed array object with detached or out of bounds array buffer",
+                    )
+                    .into());
             }
 
             (
@@ -2640,8 +2728,9 @@ impl TypedArray {
                     .expect("Must be array buffer")
                     .is_detached_buffer()
                 {
-                    return context
-                        .throw_type_error("Cannot sort typed array with detached buffer");
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot sort typed array with detached buffer")
+                        .into());
                 }
 
                 // c. If v is NaN, return +0𝔽.
@@ -2649,7 +2738,7 @@ impl TypedArray {
                 return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
             }
 
-            if let (JsValue::BigInt(x), JsValue::BigInt(y)) = (x, y) {
+            if let (JsVariant::BigInt(x), JsVariant::BigInt(y)) = (x.variant(), y.variant()) {
                 // 6. If x < y, return -1𝔽.
                 if x < y {
                     return Ok(Ordering::Less);
@@ -2777,20 +2866,24 @@ impl TypedArray {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        let obj = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         let obj_borrow = obj.borrow();
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
         // 4. Let buffer be O.[[ViewedArrayBuffer]].
         let buffer = o
             .viewed_array_buffer()
             .expect("Buffer cannot be detached here");
 
-        // 5. Let srcLength be O.[[ArrayLength]].
+        // 5. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 6. If IsTypedArrayOutOfBounds(srcRecord) is true, then
+        //     a. Let srcLength be 0.
+        // 7. Else,
+        //     a. Let srcLength be TypedArrayLength(srcRecord).
         let src_length = o.array_length() as i64;
 
         // 6. Let relativeBegin be ? ToIntegerOrInfinity(begin).
@@ -2835,19 +2928,23 @@ impl TypedArray {
         // 18. Let beginByteOffset be srcByteOffset + beginIndex × elementSize.
         let begin_byte_offset = src_byte_offset + begin_index as u64 * element_size;
 
-        // 19. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
-        // 20. Return ? TypedArraySpeciesCreate(O, argumentsList).
-        Ok(Self::species_create(
-            obj,
-            o.typed_array_name(),
-            &[
+        // 19. If O.[[ArrayLength]] is auto and end is undefined, then
+        let arguments_list = if o.is_length_tracking() && end.is_undefined() {
+            // a. Let argumentsList be « buffer, 𝔽(beginByteOffset) ».
+            vec![buffer.clone().into(), begin_byte_offset.into()]
+        }
+        // 20. Else,
+        else {
+            // a. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
+            vec![
                 buffer.clone().into(),
                 begin_byte_offset.into(),
                 new_length.into(),
-            ],
-            context,
-        )?
-        .into())
+            ]
+        };
+
+        // 21. Return ? TypedArraySpeciesCreate(O, argumentsList).
+        Ok(Self::species_create(obj, o.typed_array_name(), &arguments_list, context)?.into())
     }
 
     // TODO: 23.2.3.29 %TypedArray%.prototype.toLocaleString ( [ reserved1 [ , reserved2 ] ] )
@@ -2861,15 +2958,17 @@ impl TypedArray {
     fn values(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
-        let o = this
-            .as_object()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
         if o.borrow()
             .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
-            .is_detached()
+            .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
+            .is_out_of_bounds()
         {
-            return context.throw_type_error("Buffer of the typed array is detached");
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. Return CreateArrayIterator(O, value).
@@ -2947,8 +3046,9 @@ impl TypedArray {
             .content_type()
             != typed_array_name.content_type()
         {
-            return context
-                .throw_type_error("New typed array has different context type than exemplar");
+            return Err(JsNativeError::typ()
+                .with_message("New typed array has different context type than exemplar")
+                .into());
         }
 
         // 6. Return result.
@@ -2971,11 +3071,13 @@ impl TypedArray {
 
         let obj_borrow = new_typed_array.borrow();
         // 2. Perform ? ValidateTypedArray(newTypedArray).
-        let o = obj_borrow
-            .as_typed_array()
-            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Buffer of the typed array is detached or out of bounds")
+                .into());
         }
 
         // 3. If argumentList is a List of a single Number, then
@@ -2983,8 +3085,9 @@ impl TypedArray {
             if let Some(number) = args[0].as_number() {
                 // a. If newTypedArray.[[ArrayLength]] < ℝ(argumentList[0]), throw a TypeError exception.
                 if (o.array_length() as f64) < number {
-                    return context
-                        .throw_type_error("New typed array length is smaller than expected");
+                    return Err(JsNativeError::typ()
+                        .with_message("New typed array length is smaller than expected")
+                        .into());
                 }
             }
         }
@@ -3018,6 +3121,7 @@ impl TypedArray {
                 .constructor()
                 .into(),
             byte_length,
+            None,
             context,
         )?;
 
@@ -3130,9 +3234,12 @@ impl TypedArray {
             .expect("this must be a typed array");
 
         // 1. Let srcData be srcArray.[[ViewedArrayBuffer]].
-        // 2. If IsDetachedBuffer(srcData) is true, throw a TypeError exception.
-        if src_array.is_detached() {
-            return context.throw_type_error("Cannot initialize typed array from detached buffer");
+        // 2. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(srcArray, seq-cst).
+        // 3. If IsTypedArrayOutOfBounds(srcRecord) is true, throw a TypeError exception.
+        if src_array.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Cannot initialize typed array from detached or out of bounds buffer")
+                .into());
         }
         let src_data_obj = src_array
             .viewed_array_buffer()
@@ -3182,7 +3289,8 @@ impl TypedArray {
         // 15. Else,
         } else {
             // a. Let data be ? AllocateArrayBuffer(bufferConstructor, byteLength).
-            let data_obj = ArrayBuffer::allocate(&buffer_constructor.into(), byte_length, context)?;
+            let data_obj =
+                ArrayBuffer::allocate(&buffer_constructor.into(), byte_length, None, context)?;
             let mut data_obj_b = data_obj.borrow_mut();
             let data = data_obj_b
                 .as_array_buffer_mut()
@@ -3190,14 +3298,16 @@ impl TypedArray {
 
             // b. If IsDetachedBuffer(srcData) is true, throw a TypeError exception.
             if src_data.is_detached_buffer() {
-                return context
-                    .throw_type_error("Cannot initialize typed array from detached buffer");
+                return Err(JsNativeError::typ()
+                    .with_message("Cannot initialize typed array from detached buffer")
+                    .into());
             }
 
             // c. If srcArray.[[ContentType]] ≠ O.[[ContentType]], throw a TypeError exception.
             if src_name.content_type() != constructor_name.content_type() {
-                return context
-                    .throw_type_error("Cannot initialize typed array from different content type");
+                return Err(JsNativeError::typ()
+                    .with_message("Cannot initialize typed array from different content type")
+                    .into());
             }
 
             // d. Let srcByteIndex be srcByteOffset.
@@ -3282,70 +3392,106 @@ impl TypedArray {
 
         // 4. If offset modulo elementSize ≠ 0, throw a RangeError exception.
         if offset % constructor_name.element_size() != 0 {
-            return context.throw_range_error("Invalid length for typed array");
+            return Err(JsNativeError::range()
+                .with_message("Invalid length for typed array")
+                .into());
         }
 
+        // 5. Let bufferIsFixedLength be IsFixedLengthArrayBuffer(buffer).
+        let buffer_is_fixed_length = buffer
+            .borrow()
+            .as_array_buffer()
+            .expect("This must be an ArrayBuffer")
+            .is_fixed_length_array_buffer();
+
+        // 6. If length is not undefined, then
+        //     a. Let newLength be ? ToIndex(length).
+        let new_length = if length.is_undefined() {
+            None
+        } else {
+            Some(length.to_index(context)?)
+        };
+
         let buffer_byte_length = {
             let buffer_obj_b = buffer.borrow();
             let buffer_array = buffer_obj_b
                 .as_array_buffer()
                 .expect("This must be an ArrayBuffer");
 
-            // 6. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+            // 7. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
             if buffer_array.is_detached_buffer() {
-                return context
-                    .throw_type_error("Cannot construct typed array from detached buffer");
+                return Err(JsNativeError::typ()
+                    .with_message("Cannot construct typed array from detached buffer")
+                    .into());
             }
 
-            // 7. Let bufferByteLength be buffer.[[ArrayBufferByteLength]].
+            // 8. Let bufferByteLength be ArrayBufferByteLength(buffer, seq-cst).
             buffer_array.array_buffer_byte_length()
         };
 
-        // 8. If length is undefined, then
-        let new_byte_length = if length.is_undefined() {
-            // a. If bufferByteLength modulo elementSize ≠ 0, throw a RangeError exception.
-            if buffer_byte_length % constructor_name.element_size() != 0 {
-                return context.throw_range_error("Invalid length for typed array");
-            }
+        let mut o_obj_borrow = o.borrow_mut();
+        let o = o_obj_borrow
+            .as_typed_array_mut()
+            .expect("This must be an ArrayBuffer");
 
-            // b. Let newByteLength be bufferByteLength - offset.
-            let new_byte_length = buffer_byte_length as i64 - offset as i64;
+        match new_length {
+            // 9. If length is undefined and bufferIsFixedLength is false, then
+            None if !buffer_is_fixed_length => {
+                // a. If offset > bufferByteLength, throw a RangeError exception.
+                if offset > buffer_byte_length {
+                    return Err(JsNativeError::range()
+                        .with_message("Invalid length for typed array")
+                        .into());
+                }
 
-            // c. If newByteLength < 0, throw a RangeError exception.
-            if new_byte_length < 0 {
-                return context.throw_range_error("Invalid length for typed array");
+                // b. Set O.[[ByteLength]] to auto.
+                // c. Set O.[[ArrayLength]] to auto.
+                o.set_length_tracking(true);
             }
+            // 10. Else,
+            new_length => {
+                let new_byte_length = if let Some(new_length) = new_length {
+                    // b. Else,
+                    // i. Let newByteLength be newLength × elementSize.
+                    let new_byte_length = new_length * constructor_name.element_size();
+
+                    // ii. If offset + newByteLength > bufferByteLength, throw a RangeError exception.
+                    if offset + new_byte_length > buffer_byte_length {
+                        return Err(JsNativeError::range()
+                            .with_message("Invalid length for typed array")
+                            .into());
+                    }
 
-            new_byte_length as u64
-        // 9. Else,
-        } else {
-            // 5. If length is not undefined, then
-            // a. Let newLength be ? ToIndex(length).
-
-            // a. Let newByteLength be newLength × elementSize.
-            let new_byte_length = length.to_index(context)? * constructor_name.element_size();
+                    new_byte_length
+                } else {
+                    // a. If length is undefined, then
+                    // i. If bufferByteLength modulo elementSize ≠ 0, throw a RangeError exception.
+                    if buffer_byte_length % constructor_name.element_size() != 0 {
+                        return Err(JsNativeError::range()
+                            .with_message("Invalid length for typed array")
+                            .into());
+                    }
 
-            // b. If offset + newByteLength > bufferByteLength, throw a RangeError exception.
-            if offset + new_byte_length > buffer_byte_length {
-                return context.throw_range_error("Invalid length for typed array");
-            }
+                    // ii. Let newByteLength be bufferByteLength - offset.
+                    // iii. If newByteLength < 0, throw a RangeError exception.
+                    buffer_byte_length.checked_sub(offset).ok_or_else(|| {
+                        JsNativeError::range().with_message("Invalid length for typed array")
+                    })?
+                };
 
-            new_byte_length
-        };
+                // c. Set O.[[ByteLength]] to newByteLength.
+                o.set_byte_length(new_byte_length);
 
-        let mut o_obj_borrow = o.borrow_mut();
-        let o = o_obj_borrow
-            .as_typed_array_mut()
-            .expect("This must be an ArrayBuffer");
+                // d. Set O.[[ArrayLength]] to newByteLength / elementSize.
+                o.set_array_length(new_byte_length / constructor_name.element_size());
+            }
+        }
 
-        // 10. Set O.[[ViewedArrayBuffer]] to buffer.
+        // 11. Set O.[[ViewedArrayBuffer]] to buffer.
         o.set_viewed_array_buffer(Some(buffer));
-        // 11. Set O.[[ByteLength]] to newByteLength.
-        o.set_byte_length(new_byte_length);
+
         // 12. Set O.[[ByteOffset]] to offset.
         o.set_byte_offset(offset);
-        // 13. Set O.[[ArrayLength]] to newByteLength / elementSize.
-        o.set_array_length(new_byte_length / constructor_name.element_size());
 
         Ok(())
     }
@@ -0,0 +1,282 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! The JavaScript `WeakMap` class is a global object that is used in the construction of weak
+//! maps; which are collections of key-value pairs where the keys are objects that are held
+//! weakly, meaning that an entry is removed once its key is garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use super::JsArgs;
+use crate::{
+    builtins::{map::add_entries_from_iterable, BuiltIn},
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().weak_map().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakMap ( [ iterable ] )`
+    ///
+    /// Constructor for `WeakMap` objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("calling a builtin WeakMap constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Let map be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakMap.prototype%", « [[WeakMapData]] »).
+        // 3. Set map.[[WeakMapData]] to a new empty List.
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::weak_map, context)?;
+        let map =
+            JsObject::from_proto_and_data(prototype, ObjectData::weak_map(boa_gc::WeakMap::new()));
+
+        // 4. If iterable is either undefined or null, return map.
+        let iterable = args.get_or_undefined(0);
+        if iterable.is_null_or_undefined() {
+            return Ok(map.into());
+        }
+
+        // 5. Let adder be ? Get(map, "set").
+        let adder = map.get("set", context)?;
+
+        // 6. Return ? AddEntriesFromIterable(map, iterable, adder).
+        add_entries_from_iterable(&map, iterable, &adder, context)
+    }
+
+    /// `WeakMap.prototype.delete ( key )`
+    ///
+    /// Removes the element associated with the key, if it exists.
+    /// Returns true if there was an element, and false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let map = this.as_object().filter(|obj| obj.borrow().is_weak_map());
+        let map = if let Some(map) = map {
+            map
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakMap.delete: called with non-object value")
+                .into());
+        };
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If key does not have an Object value, return false.
+        let key = if let Some(key) = args.get_or_undefined(0).as_object() {
+            key
+        } else {
+            return Ok(false.into());
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Key]] to empty.
+        //         ii. Set p.[[Value]] to empty.
+        //         iii. Return true.
+        // 6. Return false.
+        let mut map = map.borrow_mut();
+        let map = map
+            .as_weak_map_mut()
+            .expect("checked that `this` was a WeakMap");
+        Ok(map.remove(key.inner()).into())
+    }
+
+    /// `WeakMap.prototype.get ( key )`
+    ///
+    /// Returns the value associated with the key, or undefined if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let map = this.as_object().filter(|obj| obj.borrow().is_weak_map());
+        let map = if let Some(map) = map {
+            map
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakMap.get: called with non-object value")
+                .into());
+        };
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If key does not have an Object value, return undefined.
+        let key = if let Some(key) = args.get_or_undefined(0).as_object() {
+            key
+        } else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 6. Return undefined.
+        let map = map.borrow();
+        let map = map
+            .as_weak_map_ref()
+            .expect("checked that `this` was a WeakMap");
+        Ok(map.get(key.inner()).unwrap_or_default())
+    }
+
+    /// `WeakMap.prototype.has ( key )`
+    ///
+    /// Returns a boolean indicating whether an element with the specified key exists or not.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let map = this.as_object().filter(|obj| obj.borrow().is_weak_map());
+        let map = if let Some(map) = map {
+            map
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakMap.has: called with non-object value")
+                .into());
+        };
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If key does not have an Object value, return false.
+        let key = if let Some(key) = args.get_or_undefined(0).as_object() {
+            key
+        } else {
+            return Ok(false.into());
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return true.
+        // 6. Return false.
+        let map = map.borrow();
+        let map = map
+            .as_weak_map_ref()
+            .expect("checked that `this` was a WeakMap");
+        Ok(map.contains_key(key.inner()).into())
+    }
+
+    /// `WeakMap.prototype.set ( key, value )`
+    ///
+    /// Sets the value for the key in the `WeakMap` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let map = this.as_object().filter(|obj| obj.borrow().is_weak_map());
+        let map = if let Some(map) = map {
+            map
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakMap.set: called with non-object value")
+                .into());
+        };
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If key does not have an Object value, throw a TypeError exception.
+        let key = args.get_or_undefined(0);
+        let key = if let Some(key) = key.as_object() {
+            key
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                "WeakMap.set: expected target argument of type `object`, got target of type `{}`",
+                key.type_of()
+            ))
+                .into());
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Value]] to value.
+        //         ii. Return M.
+        // 6. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 7. Append p as the last element of entries.
+        map.borrow_mut()
+            .as_weak_map_mut()
+            .expect("checked that `this` was a WeakMap")
+            .insert(key.inner(), args.get_or_undefined(1).clone());
+
+        // 8. Return M.
+        Ok(this.clone())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{forward, forward_val, Context};
+use boa_gc::{force_collect, WeakGc};
+
+#[test]
+fn construct() {
+    let mut context = Context::default();
+    let init = r#"
+        const a = {};
+        const b = {};
+        const map = new WeakMap([[a, 1], [b, 2]]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.get(a)"), "1");
+    assert_eq!(forward(&mut context, "map.get(b)"), "2");
+    assert_eq!(forward(&mut context, "map.get({})"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(map)"),
+        "\"[object WeakMap]\""
+    );
+}
+
+#[test]
+fn set_get_has_delete() {
+    let mut context = Context::default();
+    let init = r#"
+        const key = {};
+        const map = new WeakMap();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "map.set(key, 'value') === map"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "map.has(key)"), "true");
+    assert_eq!(forward(&mut context, "map.get(key)"), "\"value\"");
+    assert_eq!(
+        forward(&mut context, "map.set(key, 'other'); map.get(key)"),
+        "\"other\""
+    );
+    assert_eq!(forward(&mut context, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "false");
+    assert_eq!(forward(&mut context, "map.has(key)"), "false");
+}
+
+#[test]
+fn non_object_keys() {
+    let mut context = Context::default();
+    forward(&mut context, "const map = new WeakMap();");
+    assert_eq!(forward(&mut context, "map.has(1)"), "false");
+    assert_eq!(forward(&mut context, "map.get('key')"), "undefined");
+    assert_eq!(forward(&mut context, "map.delete(Symbol())"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { map.set(1, 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakMap.prototype.get.call(new Map(), {}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn values_are_collected_with_their_keys() {
+    let mut context = Context::default();
+    let init = r#"
+        var key = {};
+        var map = new WeakMap();
+        map.set(key, { data: 1 });
+        "#;
+    forward(&mut context, init);
+
+    let value = forward_val(&mut context, "map.get(key)").unwrap();
+    let weak = WeakGc::new(value.as_object().unwrap().inner());
+    drop(value);
+
+    force_collect();
+    assert!(weak.is_alive());
+
+    forward(&mut context, "key = undefined;");
+    force_collect();
+    assert!(!weak.is_alive());
+}
@@ -0,0 +1,95 @@
+use crate::{forward, Context, JsValue};
+use boa_gc::force_collect;
+
+#[test]
+fn construct() {
+    let mut context = Context::default();
+    let init = r#"
+        const target = {};
+        const ref = new WeakRef(target);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "ref.deref() === target"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(ref)"),
+        "\"[object WeakRef]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new WeakRef(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakRef({}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakRef.prototype.deref.call({}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn target_is_collected() {
+    let mut context = Context::default();
+    forward(&mut context, "var ref = new WeakRef({ data: 1 });");
+
+    force_collect();
+    assert_eq!(forward(&mut context, "ref.deref()"), "undefined");
+}
+
+#[test]
+fn target_is_kept_alive_during_execution() {
+    let mut context = Context::default();
+    let init = r#"
+        var ref = new WeakRef({ data: 1 });
+        var alive = ref.deref() !== undefined;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "alive"), "true");
+
+    // The target was only kept alive until the end of the script.
+    force_collect();
+    assert_eq!(forward(&mut context, "ref.deref()"), "undefined");
+}
+
+#[test]
+fn reachable_target_is_not_collected() {
+    let mut context = Context::default();
+    let init = r#"
+        var target = { data: 1 };
+        var ref = new WeakRef(target);
+        "#;
+    forward(&mut context, init);
+
+    force_collect();
+    assert_eq!(forward(&mut context, "ref.deref().data"), "1");
+}
+
+#[test]
+fn target_is_released_after_each_job() {
+    let mut context = Context::default();
+    context
+        .register_global_closure("gc", 0, |_, _, _| {
+            force_collect();
+            Ok(JsValue::undefined())
+        })
+        .unwrap();
+    let init = r#"
+        var ref = new WeakRef({ data: 1 });
+        var collected;
+        Promise.resolve()
+            .then(() => { ref.deref(); })
+            .then(() => { gc(); collected = ref.deref() === undefined; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "collected"), "true");
+}
@@ -0,0 +1,264 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! The JavaScript `WeakSet` class is a global object that is used in the construction of weak
+//! sets; which are collections of objects that are held weakly, meaning that an object is
+//! removed from the set once it is garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+use super::JsArgs;
+use crate::{
+    builtins::BuiltIn,
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().weak_set().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::add, "add", 1)
+        .method(Self::delete, "delete", 1)
+        .method(Self::has, "has", 1)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl WeakSet {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakSet ( [ iterable ] )`
+    ///
+    /// Constructor for `WeakSet` objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/WeakSet
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("calling a builtin WeakSet constructor without new is forbidden")
+                .into());
+        }
+
+        // 2. Let set be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakSet.prototype%", « [[WeakSetData]] »).
+        // 3. Set set.[[WeakSetData]] to a new empty List.
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::weak_set, context)?;
+        let set =
+            JsObject::from_proto_and_data(prototype, ObjectData::weak_set(boa_gc::WeakMap::new()));
+
+        // 4. If iterable is either undefined or null, return set.
+        let iterable = args.get_or_undefined(0);
+        if iterable.is_null_or_undefined() {
+            return Ok(set.into());
+        }
+
+        // 5. Let adder be ? Get(set, "add").
+        let adder = set.get("add", context)?;
+
+        // 6. If IsCallable(adder) is false, throw a TypeError exception.
+        let adder = adder
+            .as_callable()
+            .ok_or_else(|| JsNativeError::typ().with_message("WeakSet: 'add' is not a function"))?;
+
+        // 7. Let iteratorRecord be ? GetIterator(iterable).
+        let iterator_record = iterable.clone().get_iterator(context, None, None)?;
+
+        // 8. Repeat,
+        //     a. Let next be ? IteratorStep(iteratorRecord).
+        //     b. If next is false, return set.
+        //     c. Let nextValue be ? IteratorValue(next).
+        //     d. Let status be Completion(Call(adder, set, « nextValue »)).
+        //     e. IfAbruptCloseIterator(status, iteratorRecord).
+        while let Some(next) = iterator_record.step(context)? {
+            // c
+            let next_value = next.value(context)?;
+
+            // d, e
+            if let Err(status) = adder.call(&set.clone().into(), &[next_value], context) {
+                return iterator_record.close(Err(status), context);
+            }
+        }
+
+        // 8.b
+        Ok(set.into())
+    }
+
+    /// `WeakSet.prototype.add( value )`
+    ///
+    /// Appends a new object to the end of the `WeakSet` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let set = this.as_object().filter(|obj| obj.borrow().is_weak_set());
+        let set = if let Some(set) = set {
+            set
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakSet.add: called with non-object value")
+                .into());
+        };
+
+        // 3. If value is not an Object, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let value = if let Some(value) = value.as_object() {
+            value
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                "WeakSet.add: expected target argument of type `object`, got target of type `{}`",
+                value.type_of()
+            ))
+                .into());
+        };
+
+        // 4. Let entries be the List that is S.[[WeakSetData]].
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, then
+        //         i. Return S.
+        // 6. Append value as the last element of entries.
+        let mut set = set.borrow_mut();
+        let set = set
+            .as_weak_set_mut()
+            .expect("checked that `this` was a WeakSet");
+        if !set.contains_key(value.inner()) {
+            set.insert(value.inner(), ());
+        }
+
+        // 7. Return S.
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.delete( value )`
+    ///
+    /// Removes the specified element from the `WeakSet` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let set = this.as_object().filter(|obj| obj.borrow().is_weak_set());
+        let set = if let Some(set) = set {
+            set
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakSet.delete: called with non-object value")
+                .into());
+        };
+
+        // 3. If value is not an Object, return false.
+        let value = if let Some(value) = args.get_or_undefined(0).as_object() {
+            value
+        } else {
+            return Ok(false.into());
+        };
+
+        // 4. Let entries be the List that is S.[[WeakSetData]].
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, then
+        //         i. Replace the element of entries whose value is e with an element whose value is empty.
+        //         ii. Return true.
+        // 6. Return false.
+        let mut set = set.borrow_mut();
+        let set = set
+            .as_weak_set_mut()
+            .expect("checked that `this` was a WeakSet");
+        Ok(set.remove(value.inner()).into())
+    }
+
+    /// `WeakSet.prototype.has( value )`
+    ///
+    /// Returns a boolean indicating whether an object exists in a `WeakSet` or not.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let set = this.as_object().filter(|obj| obj.borrow().is_weak_set());
+        let set = if let Some(set) = set {
+            set
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakSet.has: called with non-object value")
+                .into());
+        };
+
+        // 3. Let entries be the List that is S.[[WeakSetData]].
+        // 4. If value is not an Object, return false.
+        let value = if let Some(value) = args.get_or_undefined(0).as_object() {
+            value
+        } else {
+            return Ok(false.into());
+        };
+
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, return true.
+        // 6. Return false.
+        let set = set.borrow();
+        let set = set
+            .as_weak_set_ref()
+            .expect("checked that `this` was a WeakSet");
+        Ok(set.contains_key(value.inner()).into())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{forward, forward_val, Context};
+use boa_gc::{force_collect, WeakGc};
+
+#[test]
+fn construct() {
+    let mut context = Context::default();
+    let init = r#"
+        const a = {};
+        const b = {};
+        const set = new WeakSet([a, b, a]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.has(a)"), "true");
+    assert_eq!(forward(&mut context, "set.has(b)"), "true");
+    assert_eq!(forward(&mut context, "set.has({})"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(set)"),
+        "\"[object WeakSet]\""
+    );
+}
+
+#[test]
+fn add_has_delete() {
+    let mut context = Context::default();
+    let init = r#"
+        const value = {};
+        const set = new WeakSet();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.add(value) === set"), "true");
+    assert_eq!(forward(&mut context, "set.has(value)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(value)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(value)"), "false");
+    assert_eq!(forward(&mut context, "set.has(value)"), "false");
+    assert_eq!(forward(&mut context, "set.has(1)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { set.add('value') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn values_are_held_weakly() {
+    let mut context = Context::default();
+    let init = r#"
+        var value = {};
+        var set = new WeakSet([value]);
+        "#;
+    forward(&mut context, init);
+
+    let value = forward_val(&mut context, "value").unwrap();
+    let weak = WeakGc::new(value.as_object().unwrap().inner());
+    drop(value);
+
+    force_collect();
+    assert!(weak.is_alive());
+
+    forward(&mut context, "value = undefined;");
+    force_collect();
+    assert!(!weak.is_alive());
+}
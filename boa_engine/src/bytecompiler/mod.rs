@@ -1,7 +1,9 @@
 mod function;
+mod module;
 
 use crate::{
     environments::{BindingLocator, CompileTimeEnvironment},
+    error::JsNativeError,
     syntax::ast::{
         node::{
             declaration::{
@@ -12,12 +14,12 @@ use crate::{
             object::{MethodDefinition, PropertyDefinition, PropertyName},
             operator::assign::AssignTarget,
             template::TemplateElement,
-            Class, Declaration, GetConstField, GetField, GetSuperField,
+            Class, Declaration, GetConstField, GetField, GetSuperField, StatementList,
         },
         op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
-        Const, Node,
+        Const, Node, Position, Span,
     },
-    vm::{BindingOpcode, CodeBlock, Opcode},
+    vm::{BindingOpcode, CodeBlock, Opcode, PropertyCache},
     Context, JsBigInt, JsResult, JsString, JsValue,
 };
 use boa_gc::Gc;
@@ -95,6 +97,12 @@ impl<'b> ByteCompiler<'b> {
         }
     }
 
+    /// Sets the name of the source text that is being compiled.
+    #[inline]
+    pub fn set_source_name(&mut self, source_name: Option<JsString>) {
+        self.code_block.source_name = source_name;
+    }
+
     #[inline]
     fn interner(&self) -> &Interner {
         self.context.interner()
@@ -111,6 +119,19 @@ impl<'b> ByteCompiler<'b> {
         index
     }
 
+    /// Create a mutable binding in the current compile time environment.
+    ///
+    /// Bindings that end up as properties of the global object are recorded in the `CodeBlock`,
+    /// so they can be recreated when the code is loaded from a bytecode cache.
+    #[inline]
+    fn create_mutable_binding(&mut self, name: Sym, function_scope: bool) {
+        if self.context.create_mutable_binding(name, function_scope)
+            && !self.code_block.global_var_names.contains(&name)
+        {
+            self.code_block.global_var_names.push(name);
+        }
+    }
+
     #[inline]
     fn get_or_insert_literal(&mut self, literal: Literal) -> u32 {
         if let Some(index) = self.literals_map.get(&literal) {
@@ -197,6 +218,45 @@ impl<'b> ByteCompiler<'b> {
         }
     }
 
+    /// Marks the code emitted from now on as starting at `position` in the source text.
+    #[inline]
+    fn emit_position(&mut self, position: Position) {
+        let pc = self.next_opcode_location();
+        match self.code_block.line_table.last_mut() {
+            Some((last_pc, last_position)) if *last_pc == pc => *last_position = position,
+            Some((_, last_position)) if *last_position == position => {}
+            _ => self.code_block.line_table.push((pc, position)),
+        }
+    }
+
+    /// Marks the code emitted by `f` as starting at `span` in the source text, if the span is
+    /// known, so errors thrown by that code report the location of the expression instead of the
+    /// location of the enclosing statement.
+    #[inline]
+    fn with_span<T, F>(&mut self, span: Option<Span>, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let span = match span {
+            Some(span) => span,
+            None => return f(self),
+        };
+
+        // The code emitted after `f` belongs to the enclosing expression again.
+        let enclosing_position = self
+            .code_block
+            .line_table
+            .last()
+            .map(|(_, position)| *position);
+
+        self.emit_position(span.start());
+        let result = f(self);
+        if let Some(position) = enclosing_position {
+            self.emit_position(position);
+        }
+        result
+    }
+
     #[inline]
     fn next_opcode_location(&mut self) -> u32 {
         assert!(self.code_block.code.len() < u32::MAX as usize);
@@ -211,6 +271,16 @@ impl<'b> ByteCompiler<'b> {
         }
     }
 
+    /// Emits a property access by name, which gets its own inline cache.
+    #[inline]
+    fn emit_property_access(&mut self, opcode: Opcode, name_index: u32) {
+        let cache_index = self.code_block.property_caches.len() as u32;
+        self.code_block
+            .property_caches
+            .push(PropertyCache::default());
+        self.emit(opcode, &[name_index, cache_index]);
+    }
+
     #[inline]
     fn emit_u64(&mut self, value: u64) {
         self.code_block.code.extend(&value.to_ne_bytes());
@@ -513,12 +583,16 @@ impl<'b> ByteCompiler<'b> {
             Access::ByName { node } => {
                 let index = self.get_or_insert_name(node.field());
                 self.compile_expr(node.obj(), true)?;
-                self.emit(Opcode::GetPropertyByName, &[index]);
+                self.with_span(node.span(), |compiler| {
+                    compiler.emit_property_access(Opcode::GetPropertyByName, index);
+                });
             }
             Access::ByValue { node } => {
                 self.compile_expr(node.field(), true)?;
                 self.compile_expr(node.obj(), true)?;
-                self.emit(Opcode::GetPropertyByValue, &[]);
+                self.with_span(node.span(), |compiler| {
+                    compiler.emit(Opcode::GetPropertyByValue, &[]);
+                });
             }
             Access::This => {
                 self.emit(Opcode::This, &[]);
@@ -555,12 +629,16 @@ impl<'b> ByteCompiler<'b> {
             Access::ByName { node } => {
                 self.compile_expr(node.obj(), true)?;
                 let index = self.get_or_insert_name(node.field());
-                self.emit(Opcode::SetPropertyByName, &[index]);
+                self.with_span(node.span(), |compiler| {
+                    compiler.emit_property_access(Opcode::SetPropertyByName, index);
+                });
             }
             Access::ByValue { node } => {
                 self.compile_expr(node.field(), true)?;
                 self.compile_expr(node.obj(), true)?;
-                self.emit(Opcode::SetPropertyByValue, &[]);
+                self.with_span(node.span(), |compiler| {
+                    compiler.emit(Opcode::SetPropertyByValue, &[]);
+                });
             }
             Access::This => todo!("access_set 'this'"),
         }
@@ -568,12 +646,24 @@ impl<'b> ByteCompiler<'b> {
     }
 
     #[inline]
-    pub fn compile_statement_list(&mut self, list: &[Node], use_expr: bool) -> JsResult<()> {
-        if let Some((last, items)) = list.split_last() {
-            for node in items {
-                self.compile_stmt(node, false)?;
-            }
-            self.compile_stmt(last, use_expr)?;
+    pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool) -> JsResult<()> {
+        // The code emitted after the list belongs to the enclosing statement again.
+        let enclosing_position = self
+            .code_block
+            .line_table
+            .last()
+            .map(|(_, position)| *position);
+
+        let items = list.items();
+        for (i, node) in items.iter().enumerate() {
+            if let Some(span) = list.spans().get(i) {
+                self.emit_position(span.start());
+            }
+            self.compile_stmt(node, use_expr && i + 1 == items.len())?;
+        }
+
+        if let Some(position) = enclosing_position {
+            self.emit_position(position);
         }
         Ok(())
     }
@@ -582,21 +672,15 @@ impl<'b> ByteCompiler<'b> {
     #[inline]
     pub(crate) fn compile_statement_list_with_new_declarative(
         &mut self,
-        list: &[Node],
+        list: &StatementList,
         use_expr: bool,
         strict: bool,
     ) -> JsResult<()> {
         self.context.push_compile_time_environment(strict);
         let push_env = self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
-        self.create_declarations(list)?;
-
-        if let Some((last, items)) = list.split_last() {
-            for node in items {
-                self.compile_stmt(node, false)?;
-            }
-            self.compile_stmt(last, use_expr)?;
-        }
+        self.create_declarations(list.items())?;
+        self.compile_statement_list(list, use_expr)?;
 
         let (num_bindings, compile_environment) = self.context.pop_compile_time_environment();
         let index_compile_environment = self.push_compile_environment(compile_environment);
@@ -613,7 +697,7 @@ impl<'b> ByteCompiler<'b> {
             Node::Const(c) => {
                 match c {
                     Const::String(v) => self.emit_push_literal(Literal::String(
-                        self.interner().resolve_expect(*v).into(),
+                        self.interner().resolve_expect_utf16(*v).as_ref().into(),
                     )),
                     Const::Int(v) => self.emit_push_integer(*v),
                     Const::Num(v) => self.emit_push_rational(*v),
@@ -635,8 +719,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit(Opcode::Inc, &[]);
 
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid increment operand")
+                            JsNativeError::syntax().with_message("Invalid increment operand")
                         })?;
                         self.access_set(access, None, true)?;
                         None
@@ -646,8 +729,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit(Opcode::Dec, &[]);
 
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid decrement operand")
+                            JsNativeError::syntax().with_message("Invalid decrement operand")
                         })?;
                         self.access_set(access, None, true)?;
                         None
@@ -657,8 +739,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit(Opcode::IncPost, &[]);
 
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid increment operand")
+                            JsNativeError::syntax().with_message("Invalid increment operand")
                         })?;
                         self.access_set(access, None, false)?;
 
@@ -669,8 +750,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit(Opcode::DecPost, &[]);
 
                         let access = Self::compile_access(unary.target()).ok_or_else(|| {
-                            self.context
-                                .construct_syntax_error("Invalid decrement operand")
+                            JsNativeError::syntax().with_message("Invalid decrement operand")
                         })?;
                         self.access_set(access, None, false)?;
 
@@ -821,9 +901,8 @@ impl<'b> ByteCompiler<'b> {
                                 self.compile_expr(binary.rhs(), true)?;
                                 let access =
                                     Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
+                                        JsNativeError::syntax()
+                                            .with_message("Invalid left-hand side in assignment")
                                     })?;
                                 self.access_set(access, None, use_expr)?;
                                 self.patch_jump(exit);
@@ -834,9 +913,8 @@ impl<'b> ByteCompiler<'b> {
                                 self.compile_expr(binary.rhs(), true)?;
                                 let access =
                                     Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
+                                        JsNativeError::syntax()
+                                            .with_message("Invalid left-hand side in assignment")
                                     })?;
                                 self.access_set(access, None, use_expr)?;
                                 self.patch_jump(exit);
@@ -847,9 +925,8 @@ impl<'b> ByteCompiler<'b> {
                                 self.compile_expr(binary.rhs(), true)?;
                                 let access =
                                     Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                        self.context.construct_syntax_error(
-                                            "Invalid left-hand side in assignment",
-                                        )
+                                        JsNativeError::syntax()
+                                            .with_message("Invalid left-hand side in assignment")
                                     })?;
                                 self.access_set(access, None, use_expr)?;
                                 self.patch_jump(exit);
@@ -861,8 +938,8 @@ impl<'b> ByteCompiler<'b> {
                             self.compile_expr(binary.rhs(), true)?;
                             self.emit(opcode, &[]);
                             let access = Self::compile_access(binary.lhs()).ok_or_else(|| {
-                                self.context
-                                    .construct_syntax_error("Invalid left-hand side in assignment")
+                                JsNativeError::syntax()
+                                    .with_message("Invalid left-hand side in assignment")
                             })?;
                             self.access_set(access, None, use_expr)?;
                         }
@@ -993,9 +1070,9 @@ impl<'b> ByteCompiler<'b> {
                             self.emit_opcode(Opcode::Pop);
                         }
                         PropertyDefinition::CoverInitializedName(_, _) => {
-                            return self.context.throw_syntax_error(
-                                "invalid assignment pattern in object literal",
-                            );
+                            return Err(JsNativeError::syntax()
+                                .with_message("invalid assignment pattern in object literal")
+                                .into());
                         }
                     }
                 }
@@ -1055,7 +1132,7 @@ impl<'b> ByteCompiler<'b> {
                 GetSuperField::Const(field) => {
                     let index = self.get_or_insert_name(*field);
                     self.emit_opcode(Opcode::Super);
-                    self.emit(Opcode::GetPropertyByName, &[index]);
+                    self.emit_property_access(Opcode::GetPropertyByName, index);
                     if !use_expr {
                         self.emit_opcode(Opcode::Pop);
                     }
@@ -1117,7 +1194,7 @@ impl<'b> ByteCompiler<'b> {
                 for element in template_literal.elements() {
                     match element {
                         TemplateElement::String(s) => self.emit_push_literal(Literal::String(
-                            self.interner().resolve_expect(*s).into(),
+                            self.interner().resolve_expect_utf16(*s).as_ref().into(),
                         )),
                         TemplateElement::Expr(expr) => {
                             self.compile_expr(expr, true)?;
@@ -1185,7 +1262,7 @@ impl<'b> ByteCompiler<'b> {
                         self.compile_expr(field.obj(), true)?;
                         self.emit(Opcode::Dup, &[]);
                         let index = self.get_or_insert_name(field.field());
-                        self.emit(Opcode::GetPropertyByName, &[index]);
+                        self.emit_property_access(Opcode::GetPropertyByName, index);
                     }
                     Node::GetField(field) => {
                         self.compile_expr(field.obj(), true)?;
@@ -1205,7 +1282,10 @@ impl<'b> ByteCompiler<'b> {
                 for cooked in template.cookeds() {
                     if let Some(cooked) = cooked {
                         self.emit_push_literal(Literal::String(
-                            self.interner().resolve_expect(*cooked).into(),
+                            self.interner()
+                                .resolve_expect_utf16(*cooked)
+                                .as_ref()
+                                .into(),
                         ));
                     } else {
                         self.emit_opcode(Opcode::PushUndefined);
@@ -1224,7 +1304,7 @@ impl<'b> ByteCompiler<'b> {
 
                 self.emit_opcode(Opcode::Swap);
                 let index = self.get_or_insert_name(Sym::RAW);
-                self.emit(Opcode::SetPropertyByName, &[index]);
+                self.emit_property_access(Opcode::SetPropertyByName, index);
 
                 for expr in template.exprs() {
                     self.compile_expr(expr, true)?;
@@ -1426,7 +1506,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
                     for name in init_bound_names {
-                        self.context.create_mutable_binding(name, false);
+                        self.create_mutable_binding(name, false);
                     }
                     self.compile_expr(for_in_loop.expr(), true)?;
 
@@ -1453,31 +1533,31 @@ impl<'b> ByteCompiler<'b> {
 
                 match for_in_loop.init() {
                     IterableLoopInitializer::Identifier(ref ident) => {
-                        self.context.create_mutable_binding(ident.sym(), true);
+                        self.create_mutable_binding(ident.sym(), true);
                         let binding = self.context.set_mutable_binding(ident.sym());
                         let index = self.get_or_insert_binding(binding);
                         self.emit(Opcode::DefInitVar, &[index]);
                     }
                     IterableLoopInitializer::Var(declaration) => match declaration {
                         Declaration::Identifier { ident, .. } => {
-                            self.context.create_mutable_binding(ident.sym(), true);
+                            self.create_mutable_binding(ident.sym(), true);
                             self.emit_binding(BindingOpcode::InitVar, ident.sym());
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
-                                self.context.create_mutable_binding(ident, true);
+                                self.create_mutable_binding(ident, true);
                             }
                             self.compile_declaration_pattern(pattern, BindingOpcode::InitVar)?;
                         }
                     },
                     IterableLoopInitializer::Let(declaration) => match declaration {
                         Declaration::Identifier { ident, .. } => {
-                            self.context.create_mutable_binding(ident.sym(), false);
+                            self.create_mutable_binding(ident.sym(), false);
                             self.emit_binding(BindingOpcode::InitLet, ident.sym());
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
-                                self.context.create_mutable_binding(ident, false);
+                                self.create_mutable_binding(ident, false);
                             }
                             self.compile_declaration_pattern(pattern, BindingOpcode::InitLet)?;
                         }
@@ -1496,7 +1576,7 @@ impl<'b> ByteCompiler<'b> {
                     },
                     IterableLoopInitializer::DeclarationPattern(pattern) => {
                         for ident in pattern.idents() {
-                            self.context.create_mutable_binding(ident, true);
+                            self.create_mutable_binding(ident, true);
                         }
                         self.compile_declaration_pattern(pattern, BindingOpcode::InitVar)?;
                     }
@@ -1530,7 +1610,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
                     for name in init_bound_names {
-                        self.context.create_mutable_binding(name, false);
+                        self.create_mutable_binding(name, false);
                     }
                     self.compile_expr(for_of_loop.iterable(), true)?;
 
@@ -1557,31 +1637,31 @@ impl<'b> ByteCompiler<'b> {
 
                 match for_of_loop.init() {
                     IterableLoopInitializer::Identifier(ref ident) => {
-                        self.context.create_mutable_binding(ident.sym(), true);
+                        self.create_mutable_binding(ident.sym(), true);
                         let binding = self.context.set_mutable_binding(ident.sym());
                         let index = self.get_or_insert_binding(binding);
                         self.emit(Opcode::DefInitVar, &[index]);
                     }
                     IterableLoopInitializer::Var(declaration) => match declaration {
                         Declaration::Identifier { ident, .. } => {
-                            self.context.create_mutable_binding(ident.sym(), true);
+                            self.create_mutable_binding(ident.sym(), true);
                             self.emit_binding(BindingOpcode::InitVar, ident.sym());
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
-                                self.context.create_mutable_binding(ident, true);
+                                self.create_mutable_binding(ident, true);
                             }
                             self.compile_declaration_pattern(pattern, BindingOpcode::InitVar)?;
                         }
                     },
                     IterableLoopInitializer::Let(declaration) => match declaration {
                         Declaration::Identifier { ident, .. } => {
-                            self.context.create_mutable_binding(ident.sym(), false);
+                            self.create_mutable_binding(ident.sym(), false);
                             self.emit_binding(BindingOpcode::InitLet, ident.sym());
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
-                                self.context.create_mutable_binding(ident, false);
+                                self.create_mutable_binding(ident, false);
                             }
                             self.compile_declaration_pattern(pattern, BindingOpcode::InitLet)?;
                         }
@@ -1600,7 +1680,7 @@ impl<'b> ByteCompiler<'b> {
                     },
                     IterableLoopInitializer::DeclarationPattern(pattern) => {
                         for ident in pattern.idents() {
-                            self.context.create_mutable_binding(ident, true);
+                            self.create_mutable_binding(ident, true);
                         }
                         self.compile_declaration_pattern(pattern, BindingOpcode::InitVar)?;
                     }
@@ -1711,7 +1791,7 @@ impl<'b> ByteCompiler<'b> {
                         }
                         let address = address_info
                             .ok_or_else(|| {
-                                self.context.construct_syntax_error(format!(
+                                JsNativeError::syntax().with_message(format!(
                                     "Cannot use the undeclared label '{}'",
                                     self.context.interner().resolve_expect(label_name)
                                 ))
@@ -1730,8 +1810,7 @@ impl<'b> ByteCompiler<'b> {
                         items
                             .next()
                             .ok_or_else(|| {
-                                self.context
-                                    .construct_syntax_error("continue must be inside loop")
+                                JsNativeError::syntax().with_message("continue must be inside loop")
                             })?
                             .start_address
                     };
@@ -1775,18 +1854,19 @@ impl<'b> ByteCompiler<'b> {
                         }
                     }
                     if !found {
-                        return self.context.throw_syntax_error(format!(
-                            "Cannot use the undeclared label '{}'",
-                            self.interner().resolve_expect(label_name)
-                        ));
+                        return Err(JsNativeError::syntax()
+                            .with_message(format!(
+                                "Cannot use the undeclared label '{}'",
+                                self.interner().resolve_expect(label_name)
+                            ))
+                            .into());
                     }
                 } else {
                     self.jump_info
                         .last_mut()
                         .ok_or_else(|| {
-                            self.context.construct_syntax_error(
-                                "unlabeled break must be inside loop or switch",
-                            )
+                            JsNativeError::syntax()
+                                .with_message("unlabeled break must be inside loop or switch")
                         })?
                         .breaks
                         .push(label);
@@ -1797,7 +1877,7 @@ impl<'b> ByteCompiler<'b> {
                 let push_env =
                     self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
                 self.create_declarations(block.items())?;
-                self.compile_statement_list(block.items(), use_expr)?;
+                self.compile_statement_list(block.statement_list(), use_expr)?;
                 let (num_bindings, compile_environment) =
                     self.context.pop_compile_time_environment();
                 let index_compile_environment = self.push_compile_environment(compile_environment);
@@ -1832,12 +1912,12 @@ impl<'b> ByteCompiler<'b> {
 
                 for (label, case) in labels.into_iter().zip(switch.cases()) {
                     self.patch_jump(label);
-                    self.compile_statement_list(case.body().items(), false)?;
+                    self.compile_statement_list(case.body(), false)?;
                 }
 
                 self.patch_jump(exit);
-                if let Some(body) = switch.default() {
-                    self.create_declarations(body)?;
+                if let Some(body) = switch.default_statement_list() {
+                    self.create_declarations(body.items())?;
                     self.compile_statement_list(body, false)?;
                 }
 
@@ -1870,7 +1950,7 @@ impl<'b> ByteCompiler<'b> {
                     self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
                 self.create_declarations(t.block().items())?;
-                self.compile_statement_list(t.block().items(), use_expr)?;
+                self.compile_statement_list(t.block().statement_list(), use_expr)?;
 
                 let (num_bindings, compile_environment) =
                     self.context.pop_compile_time_environment();
@@ -1896,12 +1976,12 @@ impl<'b> ByteCompiler<'b> {
                     if let Some(decl) = catch.parameter() {
                         match decl {
                             Declaration::Identifier { ident, .. } => {
-                                self.context.create_mutable_binding(ident.sym(), false);
+                                self.create_mutable_binding(ident.sym(), false);
                                 self.emit_binding(BindingOpcode::InitLet, ident.sym());
                             }
                             Declaration::Pattern(pattern) => {
                                 for ident in pattern.idents() {
-                                    self.context.create_mutable_binding(ident, false);
+                                    self.create_mutable_binding(ident, false);
                                 }
                                 self.compile_declaration_pattern(pattern, BindingOpcode::InitLet)?;
                             }
@@ -1911,7 +1991,7 @@ impl<'b> ByteCompiler<'b> {
                     }
 
                     self.create_declarations(catch.block().items())?;
-                    self.compile_statement_list(catch.block().items(), use_expr)?;
+                    self.compile_statement_list(catch.block().statement_list(), use_expr)?;
 
                     let (num_bindings, compile_environment) =
                         self.context.pop_compile_time_environment();
@@ -1948,7 +2028,7 @@ impl<'b> ByteCompiler<'b> {
                         self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
                     self.create_declarations(finally.items())?;
-                    self.compile_statement_list(finally.items(), false)?;
+                    self.compile_statement_list(finally.statement_list(), false)?;
 
                     let (num_bindings, compile_environment) =
                         self.context.pop_compile_time_environment();
@@ -2058,6 +2138,7 @@ impl<'b> ByteCompiler<'b> {
             .r#async(r#async)
             .strict(self.code_block.strict)
             .kind(kind)
+            .source_name(self.code_block.source_name.clone())
             .compile(parameters, body, self.context)?;
 
         let index = self.code_block.functions.len() as u32;
@@ -2114,7 +2195,9 @@ impl<'b> ByteCompiler<'b> {
                     self.emit(Opcode::Dup, &[]);
                 }
                 let index = self.get_or_insert_name(field.field());
-                self.emit(Opcode::GetPropertyByName, &[index]);
+                self.with_span(field.span(), |compiler| {
+                    compiler.emit_property_access(Opcode::GetPropertyByName, index);
+                });
             }
             Node::GetField(field) => {
                 self.compile_expr(field.obj(), true)?;
@@ -2123,7 +2206,9 @@ impl<'b> ByteCompiler<'b> {
                 }
                 self.compile_expr(field.field(), true)?;
                 self.emit(Opcode::Swap, &[]);
-                self.emit(Opcode::GetPropertyByValue, &[]);
+                self.with_span(field.span(), |compiler| {
+                    compiler.emit(Opcode::GetPropertyByValue, &[]);
+                });
             }
             Node::GetSuperField(get_super_field) => {
                 if kind == CallKind::Call {
@@ -2133,7 +2218,7 @@ impl<'b> ByteCompiler<'b> {
                 match get_super_field {
                     GetSuperField::Const(field) => {
                         let index = self.get_or_insert_name(*field);
-                        self.emit(Opcode::GetPropertyByName, &[index]);
+                        self.emit_property_access(Opcode::GetPropertyByName, index);
                     }
                     GetSuperField::Expr(expr) => {
                         self.compile_expr(expr, true)?;
@@ -2178,14 +2263,14 @@ impl<'b> ByteCompiler<'b> {
             }
         }
 
-        match kind {
-            CallKind::CallEval if contains_spread => self.emit_opcode(Opcode::CallEvalSpread),
-            CallKind::CallEval => self.emit(Opcode::CallEval, &[call.args().len() as u32]),
-            CallKind::Call if contains_spread => self.emit_opcode(Opcode::CallSpread),
-            CallKind::Call => self.emit(Opcode::Call, &[call.args().len() as u32]),
-            CallKind::New if contains_spread => self.emit_opcode(Opcode::NewSpread),
-            CallKind::New => self.emit(Opcode::New, &[call.args().len() as u32]),
-        }
+        self.with_span(call.span(), |compiler| match kind {
+            CallKind::CallEval if contains_spread => compiler.emit_opcode(Opcode::CallEvalSpread),
+            CallKind::CallEval => compiler.emit(Opcode::CallEval, &[call.args().len() as u32]),
+            CallKind::Call if contains_spread => compiler.emit_opcode(Opcode::CallSpread),
+            CallKind::Call => compiler.emit(Opcode::Call, &[call.args().len() as u32]),
+            CallKind::New if contains_spread => compiler.emit_opcode(Opcode::NewSpread),
+            CallKind::New => compiler.emit(Opcode::New, &[call.args().len() as u32]),
+        });
 
         if !use_expr {
             self.emit(Opcode::Pop, &[]);
@@ -2239,7 +2324,7 @@ impl<'b> ByteCompiler<'b> {
                             match property_name {
                                 PropertyName::Literal(name) => {
                                     let index = self.get_or_insert_name(*name);
-                                    self.emit(Opcode::GetPropertyByName, &[index]);
+                                    self.emit_property_access(Opcode::GetPropertyByName, index);
                                 }
                                 PropertyName::Computed(node) => {
                                     self.compile_expr(node, true)?;
@@ -2313,7 +2398,7 @@ impl<'b> ByteCompiler<'b> {
                             match property_name {
                                 PropertyName::Literal(name) => {
                                     let index = self.get_or_insert_name(*name);
-                                    self.emit(Opcode::GetPropertyByName, &[index]);
+                                    self.emit_property_access(Opcode::GetPropertyByName, index);
                                 }
                                 PropertyName::Computed(node) => {
                                     self.compile_expr(node, true)?;
@@ -2355,7 +2440,7 @@ impl<'b> ByteCompiler<'b> {
                             match property_name {
                                 PropertyName::Literal(name) => {
                                     let index = self.get_or_insert_name(*name);
-                                    self.emit(Opcode::GetPropertyByName, &[index]);
+                                    self.emit_property_access(Opcode::GetPropertyByName, index);
                                 }
                                 PropertyName::Computed(node) => {
                                     self.compile_expr(node, true)?;
@@ -2391,7 +2476,7 @@ impl<'b> ByteCompiler<'b> {
                             match ident {
                                 PropertyName::Literal(name) => {
                                     let index = self.get_or_insert_name(*name);
-                                    self.emit(Opcode::GetPropertyByName, &[index]);
+                                    self.emit_property_access(Opcode::GetPropertyByName, index);
                                 }
                                 PropertyName::Computed(node) => {
                                     self.compile_expr(node, true)?;
@@ -2526,14 +2611,14 @@ impl<'b> ByteCompiler<'b> {
                             if ident == Sym::ARGUMENTS {
                                 has_identifier_argument = true;
                             }
-                            self.context.create_mutable_binding(ident, true);
+                            self.create_mutable_binding(ident, true);
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
                                 if ident == Sym::ARGUMENTS {
                                     has_identifier_argument = true;
                                 }
-                                self.context.create_mutable_binding(ident, true);
+                                self.create_mutable_binding(ident, true);
                             }
                         }
                     }
@@ -2547,14 +2632,14 @@ impl<'b> ByteCompiler<'b> {
                             if ident == Sym::ARGUMENTS {
                                 has_identifier_argument = true;
                             }
-                            self.context.create_mutable_binding(ident, false);
+                            self.create_mutable_binding(ident, false);
                         }
                         Declaration::Pattern(pattern) => {
                             for ident in pattern.idents() {
                                 if ident == Sym::ARGUMENTS {
                                     has_identifier_argument = true;
                                 }
-                                self.context.create_mutable_binding(ident, false);
+                                self.create_mutable_binding(ident, false);
                             }
                         }
                     }
@@ -2582,35 +2667,35 @@ impl<'b> ByteCompiler<'b> {
                 }
             }
             Node::ClassDecl(decl) => {
-                self.context.create_mutable_binding(decl.name(), false);
+                self.create_mutable_binding(decl.name(), false);
             }
             Node::FunctionDecl(decl) => {
                 let ident = decl.name();
                 if ident == Sym::ARGUMENTS {
                     has_identifier_argument = true;
                 }
-                self.context.create_mutable_binding(ident, true);
+                self.create_mutable_binding(ident, true);
             }
             Node::GeneratorDecl(decl) => {
                 let ident = decl.name();
                 if ident == Sym::ARGUMENTS {
                     has_identifier_argument = true;
                 }
-                self.context.create_mutable_binding(ident, true);
+                self.create_mutable_binding(ident, true);
             }
             Node::AsyncFunctionDecl(decl) => {
                 let ident = decl.name();
                 if ident == Sym::ARGUMENTS {
                     has_identifier_argument = true;
                 }
-                self.context.create_mutable_binding(ident, true);
+                self.create_mutable_binding(ident, true);
             }
             Node::AsyncGeneratorDecl(decl) => {
                 let ident = decl.name();
                 if ident == Sym::ARGUMENTS {
                     has_identifier_argument = true;
                 }
-                self.context.create_mutable_binding(ident, true);
+                self.create_mutable_binding(ident, true);
             }
             Node::DoWhileLoop(do_while_loop) => {
                 if !matches!(do_while_loop.body(), Node::Block(_)) {
@@ -2638,7 +2723,8 @@ impl<'b> ByteCompiler<'b> {
     /// A class declaration binds the resulting class object to it's identifier.
     /// A class expression leaves the resulting class object on the stack for following operations.
     fn class(&mut self, class: &Class, expression: bool) -> JsResult<()> {
-        let code = CodeBlock::new(class.name(), 0, true);
+        let mut code = CodeBlock::new(class.name(), 0, true);
+        code.source_name.clone_from(&self.code_block.source_name);
         let mut compiler = ByteCompiler {
             code_block: code,
             literals_map: FxHashMap::default(),
@@ -2652,7 +2738,7 @@ impl<'b> ByteCompiler<'b> {
 
         if let Some(expr) = class.constructor() {
             compiler.code_block.length = expr.parameters().length();
-            compiler.code_block.params = expr.parameters().clone();
+            compiler.code_block.params = expr.parameters().into();
             compiler
                 .context
                 .create_mutable_binding(Sym::ARGUMENTS, false);
@@ -2698,7 +2784,7 @@ impl<'b> ByteCompiler<'b> {
                 None
             };
             compiler.create_declarations(expr.body().items())?;
-            compiler.compile_statement_list(expr.body().items(), false)?;
+            compiler.compile_statement_list(expr.body(), false)?;
             if let Some(env_label) = env_label {
                 let (num_bindings, compile_environment) =
                     compiler.context.pop_compile_time_environment();
@@ -2881,14 +2967,17 @@ impl<'b> ByteCompiler<'b> {
                     match name {
                         PropertyName::Literal(name) => {
                             self.emit_push_literal(Literal::String(
-                                self.interner().resolve_expect(*name).into(),
+                                self.interner().resolve_expect_utf16(*name).as_ref().into(),
                             ));
                         }
                         PropertyName::Computed(name) => {
                             self.compile_expr(name, true)?;
                         }
                     }
-                    let field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+                    let mut field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+                    field_code
+                        .source_name
+                        .clone_from(&self.code_block.source_name);
                     let mut field_compiler = ByteCompiler {
                         code_block: field_code,
                         literals_map: FxHashMap::default(),
@@ -2922,7 +3011,10 @@ impl<'b> ByteCompiler<'b> {
                 ClassElement::PrivateFieldDefinition(name, field) => {
                     self.emit_opcode(Opcode::Dup);
                     let name_index = self.get_or_insert_name(*name);
-                    let field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+                    let mut field_code = CodeBlock::new(Sym::EMPTY_STRING, 0, true);
+                    field_code
+                        .source_name
+                        .clone_from(&self.code_block.source_name);
                     let mut field_compiler = ByteCompiler {
                         code_block: field_code,
                         literals_map: FxHashMap::default(),
@@ -2990,10 +3082,12 @@ impl<'b> ByteCompiler<'b> {
                 }
                 ClassElement::StaticBlock(statement_list) => {
                     self.emit_opcode(Opcode::Dup);
+                    let source_name = self.code_block.source_name.clone();
                     let mut compiler = ByteCompiler::new(Sym::EMPTY_STRING, true, self.context);
+                    compiler.code_block.source_name = source_name;
                     compiler.context.push_compile_time_environment(true);
                     compiler.create_declarations(statement_list.items())?;
-                    compiler.compile_statement_list(statement_list.items(), false)?;
+                    compiler.compile_statement_list(statement_list, false)?;
                     let (num_bindings, compile_environment) =
                         compiler.context.pop_compile_time_environment();
                     compiler
@@ -0,0 +1,123 @@
+use crate::{
+    bytecompiler::ByteCompiler,
+    syntax::ast::node::{ExportDeclaration, ModuleItem, ModuleItemList, Node},
+    vm::BindingOpcode,
+    JsResult,
+};
+use boa_interner::Sym;
+use rustc_hash::FxHashSet;
+
+impl ByteCompiler<'_> {
+    /// Creates the bindings of a module environment.
+    ///
+    /// Import bindings are created as immutable bindings that are never initialized in the
+    /// module environment, since their values are resolved from the modules that export them.
+    pub(crate) fn create_module_declarations(&mut self, module: &ModuleItemList) -> JsResult<()> {
+        for item in module.items() {
+            match item {
+                ModuleItem::ImportDeclaration(import) => {
+                    for name in import.bound_names() {
+                        self.context.create_immutable_binding(name);
+                    }
+                }
+                ModuleItem::ExportDeclaration(export) => match export {
+                    ExportDeclaration::Declaration(node)
+                    | ExportDeclaration::DefaultHoistableDeclaration(node) => {
+                        self.create_decls_from_stmt(node)?;
+                    }
+                    ExportDeclaration::DefaultClassDeclaration(class) => {
+                        self.create_mutable_binding(class.name(), false);
+                    }
+                    ExportDeclaration::DefaultAssignmentExpression(_) => {
+                        self.create_mutable_binding(Sym::DEFAULT, false);
+                    }
+                    ExportDeclaration::ReExport { .. } | ExportDeclaration::List(_) => {}
+                },
+                ModuleItem::StatementListItem(node) => {
+                    self.create_decls_from_stmt(node)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Compiles the code that instantiates the hoisted declarations of a module.
+    ///
+    /// This initializes all `var` bindings to `undefined` and creates the function objects of all
+    /// function declarations, so that they are available to other modules before the module body
+    /// is evaluated.
+    pub(crate) fn compile_module_initialization(
+        &mut self,
+        module: &ModuleItemList,
+    ) -> JsResult<()> {
+        let mut var_names = FxHashSet::default();
+        module.var_declared_names(&mut var_names);
+        for name in var_names {
+            self.emit_binding(BindingOpcode::Var, name);
+        }
+
+        for item in module.items() {
+            let node = match item {
+                ModuleItem::ExportDeclaration(
+                    ExportDeclaration::Declaration(node)
+                    | ExportDeclaration::DefaultHoistableDeclaration(node),
+                )
+                | ModuleItem::StatementListItem(node) => node,
+                _ => continue,
+            };
+            if is_hoistable_declaration(node) {
+                self.compile_stmt(node, false)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the body of a module, skipping the hoisted declarations that are compiled by
+    /// [`Self::compile_module_initialization`].
+    pub(crate) fn compile_module_body(&mut self, module: &ModuleItemList) -> JsResult<()> {
+        for (i, item) in module.items().iter().enumerate() {
+            if let Some(span) = module.spans().get(i) {
+                self.emit_position(span.start());
+            }
+            match item {
+                ModuleItem::ImportDeclaration(_)
+                | ModuleItem::ExportDeclaration(
+                    ExportDeclaration::ReExport { .. }
+                    | ExportDeclaration::List(_)
+                    | ExportDeclaration::DefaultHoistableDeclaration(_),
+                ) => {}
+                ModuleItem::ExportDeclaration(ExportDeclaration::DefaultClassDeclaration(
+                    class,
+                )) => {
+                    self.class(class, false)?;
+                }
+                ModuleItem::ExportDeclaration(ExportDeclaration::DefaultAssignmentExpression(
+                    expr,
+                )) => {
+                    self.compile_expr(expr, true)?;
+                    self.emit_binding(BindingOpcode::InitLet, Sym::DEFAULT);
+                }
+                ModuleItem::ExportDeclaration(ExportDeclaration::Declaration(node))
+                | ModuleItem::StatementListItem(node) => {
+                    if !is_hoistable_declaration(node) {
+                        self.compile_stmt(node, false)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if the node is a declaration that is instantiated when the module environment
+/// is initialized.
+fn is_hoistable_declaration(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::FunctionDecl(_)
+            | Node::GeneratorDecl(_)
+            | Node::AsyncFunctionDecl(_)
+            | Node::AsyncGeneratorDecl(_)
+    )
+}
@@ -30,7 +30,7 @@
 //!         // This is equivalent to `String(arg)`.
 //!         let kind = args.get_or_undefined(0).to_string(context)?;
 //!
-//!         let animal = match kind.as_str() {
+//!         let animal = match kind.to_std_string_escaped().as_str() {
 //!             "cat" => Self::Cat,
 //!             "dog" => Self::Dog,
 //!             _ => Self::Other,
@@ -59,15 +59,75 @@
 //! }
 //! ```
 //!
+//! The [`Class`][class-trait] and [`ClassMethods`] implementations can also be generated with the
+//! [`JsClass`] derive and the [`js_class`] attribute. The arguments of the methods are converted
+//! with [`TryFromJs`][try-from-js], and their results with [`IntoJs`][into-js]:
+//! ```
+//!# use boa_engine::{
+//!#    class::{js_class, JsClass},
+//!#    Context, JsResult, JsValue,
+//!# };
+//!# use boa_gc::{Finalize, Trace};
+//! #[derive(Debug, Trace, Finalize, JsClass)]
+//! struct Counter {
+//!     count: u32,
+//! }
+//!
+//! #[js_class]
+//! impl Counter {
+//!     // Called by `new Counter(start)`.
+//!     #[boa(constructor)]
+//!     fn new(start: Option<u32>) -> Self {
+//!         Self {
+//!             count: start.unwrap_or(0),
+//!         }
+//!     }
+//!
+//!     // Added to the prototype as `counter.increment(step)`.
+//!     fn increment(&mut self, step: u32) -> u32 {
+//!         self.count += step;
+//!         self.count
+//!     }
+//!
+//!     // Added to the class object as `Counter.fromText(text)`.
+//!     fn from_text(text: String, context: &mut Context) -> JsResult<JsValue> {
+//!         let start = context.eval(text)?;
+//!         context.eval(format!("new Counter({})", start.display()))
+//!     }
+//! }
+//!
+//! let mut context = Context::default();
+//! context.register_global_class::<Counter>().unwrap();
+//!
+//! let result = context
+//!     .eval("const counter = new Counter(5); counter.increment(2); counter.increment(3)")
+//!     .unwrap();
+//! assert_eq!(result, JsValue::new(10));
+//!
+//! // Arguments that cannot be converted throw a `TypeError`, and so do methods called on
+//! // values that are not instances of the class.
+//! assert!(context.eval("counter.increment('one')").is_err());
+//! assert!(context.eval("Counter.prototype.increment.call({}, 1)").is_err());
+//!
+//! let result = context.eval("Counter.fromText('1 + 1').increment(1)").unwrap();
+//! assert_eq!(result, JsValue::new(3));
+//! ```
+//!
 //! [class-trait]: ./trait.Class.html
+//! [try-from-js]: crate::value::TryFromJs
+//! [into-js]: crate::value::IntoJs
 
 use crate::{
     builtins::function::NativeFunctionSignature,
+    error::JsNativeError,
     object::{ConstructorBuilder, JsFunction, JsObject, NativeObject, ObjectData, PROTOTYPE},
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    value::JsVariant,
     Context, JsResult, JsValue,
 };
 
+pub use boa_macros::{js_class, JsClass};
+
 /// Native class.
 pub trait Class: NativeObject + Sized {
     /// The binding name of the object.
@@ -84,6 +144,21 @@ pub trait Class: NativeObject + Sized {
     fn init(class: &mut ClassBuilder<'_>) -> JsResult<()>;
 }
 
+/// The constructor and the methods of a native class.
+///
+/// This is implemented by the [`js_class`] attribute, and used by the [`Class`] implementation
+/// generated by the [`JsClass`] derive.
+pub trait ClassMethods: Sized {
+    /// The amount of arguments the class `constructor` takes.
+    const LENGTH: usize;
+
+    /// The constructor of the class.
+    fn constructor(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self>;
+
+    /// Registers the methods of the class.
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()>;
+}
+
 /// This is a wrapper around `Class::constructor` that sets the internal data of a class.
 ///
 /// This is automatically implemented, when a type implements `Class`.
@@ -104,29 +179,35 @@ impl<T: Class> ClassConstructor for T {
         Self: Sized,
     {
         if this.is_undefined() {
-            return context.throw_type_error(format!(
-                "cannot call constructor of native class `{}` without new",
-                T::NAME
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "cannot call constructor of native class `{}` without new",
+                    T::NAME
+                ))
+                .into());
         }
 
         let class_constructor = context.global_object().clone().get(T::NAME, context)?;
-        let class_constructor = if let JsValue::Object(ref obj) = class_constructor {
+        let class_constructor = if let JsVariant::Object(obj) = class_constructor.variant() {
             obj
         } else {
-            return context.throw_type_error(format!(
-                "invalid constructor for native class `{}` ",
-                T::NAME
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "invalid constructor for native class `{}` ",
+                    T::NAME
+                ))
+                .into());
         };
         let class_prototype =
-            if let JsValue::Object(ref obj) = class_constructor.get(PROTOTYPE, context)? {
+            if let Some(obj) = class_constructor.get(PROTOTYPE, context)?.as_object() {
                 obj.clone()
             } else {
-                return context.throw_type_error(format!(
-                    "invalid default prototype for native class `{}`",
-                    T::NAME
-                ));
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "invalid default prototype for native class `{}`",
+                        T::NAME
+                    ))
+                    .into());
             };
 
         let prototype = this
@@ -6,20 +6,29 @@ use crate::{
     property::PropertyDescriptorBuilder,
     Context,
 };
+use boa_gc::{custom_trace, Cell, Finalize, Ref, Trace};
 
-#[derive(Debug, Default)]
+/// The intrinsics of a realm.
+#[derive(Debug, Default, Trace, Finalize)]
 pub struct Intrinsics {
     /// Cached standard constructors
     pub(super) constructors: StandardConstructors,
     /// Cached intrinsic objects
-    pub(super) objects: IntrinsicObjects,
+    ///
+    /// They are created after the standard constructors of the realm, since they depend on them.
+    objects: Cell<IntrinsicObjects>,
 }
 
 impl Intrinsics {
     /// Return the cached intrinsic objects.
     #[inline]
-    pub fn objects(&self) -> &IntrinsicObjects {
-        &self.objects
+    pub fn objects(&self) -> Ref<'_, IntrinsicObjects> {
+        self.objects.borrow()
+    }
+
+    /// Sets the intrinsic objects, once the standard constructors are initialized.
+    pub(crate) fn set_objects(&self, objects: IntrinsicObjects) {
+        *self.objects.borrow_mut() = objects;
     }
 
     /// Return the cached standard constructors.
@@ -30,12 +39,19 @@ impl Intrinsics {
 }
 
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Finalize)]
 pub struct StandardConstructor {
     pub(crate) constructor: JsObject,
     pub(crate) prototype: JsObject,
 }
 
+unsafe impl Trace for StandardConstructor {
+    custom_trace!(this, {
+        mark(&this.constructor);
+        mark(&this.prototype);
+    });
+}
+
 impl Default for StandardConstructor {
     fn default() -> Self {
         Self {
@@ -72,7 +88,7 @@ impl StandardConstructor {
 }
 
 /// Cached core standard constructors.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Trace, Finalize)]
 pub struct StandardConstructors {
     async_generator_function: StandardConstructor,
     async_generator: StandardConstructor,
@@ -100,6 +116,19 @@ pub struct StandardConstructors {
     aggregate_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
+    weak_map: StandardConstructor,
+    weak_set: StandardConstructor,
+    weak_ref: StandardConstructor,
+    finalization_registry: StandardConstructor,
+    shadow_realm: StandardConstructor,
+    instant: StandardConstructor,
+    plain_date: StandardConstructor,
+    plain_time: StandardConstructor,
+    plain_date_time: StandardConstructor,
+    zoned_date_time: StandardConstructor,
+    duration: StandardConstructor,
+    time_zone: StandardConstructor,
+    calendar: StandardConstructor,
     typed_array: StandardConstructor,
     typed_int8_array: StandardConstructor,
     typed_uint8_array: StandardConstructor,
@@ -113,8 +142,12 @@ pub struct StandardConstructors {
     typed_float32_array: StandardConstructor,
     typed_float64_array: StandardConstructor,
     array_buffer: StandardConstructor,
+    shared_array_buffer: StandardConstructor,
     data_view: StandardConstructor,
     date_time_format: StandardConstructor,
+    number_format: StandardConstructor,
+    plural_rules: StandardConstructor,
+    collator: StandardConstructor,
     promise: StandardConstructor,
 }
 
@@ -159,6 +192,19 @@ impl Default for StandardConstructors {
             aggregate_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            weak_set: StandardConstructor::default(),
+            weak_ref: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
+            shadow_realm: StandardConstructor::default(),
+            instant: StandardConstructor::default(),
+            plain_date: StandardConstructor::default(),
+            plain_time: StandardConstructor::default(),
+            plain_date_time: StandardConstructor::default(),
+            zoned_date_time: StandardConstructor::default(),
+            duration: StandardConstructor::default(),
+            time_zone: StandardConstructor::default(),
+            calendar: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
             typed_int8_array: StandardConstructor::default(),
             typed_uint8_array: StandardConstructor::default(),
@@ -172,8 +218,12 @@ impl Default for StandardConstructors {
             typed_float32_array: StandardConstructor::default(),
             typed_float64_array: StandardConstructor::default(),
             array_buffer: StandardConstructor::default(),
+            shared_array_buffer: StandardConstructor::default(),
             data_view: StandardConstructor::default(),
             date_time_format: StandardConstructor::default(),
+            number_format: StandardConstructor::default(),
+            plural_rules: StandardConstructor::default(),
+            collator: StandardConstructor::default(),
             promise: StandardConstructor::default(),
         };
 
@@ -323,6 +373,71 @@ impl StandardConstructors {
         &self.set
     }
 
+    #[inline]
+    pub fn weak_map(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn weak_set(&self) -> &StandardConstructor {
+        &self.weak_set
+    }
+
+    #[inline]
+    pub fn weak_ref(&self) -> &StandardConstructor {
+        &self.weak_ref
+    }
+
+    #[inline]
+    pub fn finalization_registry(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+
+    #[inline]
+    pub fn shadow_realm(&self) -> &StandardConstructor {
+        &self.shadow_realm
+    }
+
+    #[inline]
+    pub fn instant(&self) -> &StandardConstructor {
+        &self.instant
+    }
+
+    #[inline]
+    pub fn plain_date(&self) -> &StandardConstructor {
+        &self.plain_date
+    }
+
+    #[inline]
+    pub fn plain_time(&self) -> &StandardConstructor {
+        &self.plain_time
+    }
+
+    #[inline]
+    pub fn plain_date_time(&self) -> &StandardConstructor {
+        &self.plain_date_time
+    }
+
+    #[inline]
+    pub fn zoned_date_time(&self) -> &StandardConstructor {
+        &self.zoned_date_time
+    }
+
+    #[inline]
+    pub fn duration(&self) -> &StandardConstructor {
+        &self.duration
+    }
+
+    #[inline]
+    pub fn time_zone(&self) -> &StandardConstructor {
+        &self.time_zone
+    }
+
+    #[inline]
+    pub fn calendar(&self) -> &StandardConstructor {
+        &self.calendar
+    }
+
     #[inline]
     pub fn typed_array(&self) -> &StandardConstructor {
         &self.typed_array
@@ -388,6 +503,11 @@ impl StandardConstructors {
         &self.array_buffer
     }
 
+    #[inline]
+    pub fn shared_array_buffer(&self) -> &StandardConstructor {
+        &self.shared_array_buffer
+    }
+
     #[inline]
     pub fn data_view(&self) -> &StandardConstructor {
         &self.data_view
@@ -398,6 +518,21 @@ impl StandardConstructors {
         &self.date_time_format
     }
 
+    #[inline]
+    pub fn number_format(&self) -> &StandardConstructor {
+        &self.number_format
+    }
+
+    #[inline]
+    pub fn plural_rules(&self) -> &StandardConstructor {
+        &self.plural_rules
+    }
+
+    #[inline]
+    pub fn collator(&self) -> &StandardConstructor {
+        &self.collator
+    }
+
     #[inline]
     pub fn promise(&self) -> &StandardConstructor {
         &self.promise
@@ -405,7 +540,7 @@ impl StandardConstructors {
 }
 
 /// Cached intrinsic objects
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Trace, Finalize)]
 pub struct IntrinsicObjects {
     /// %ThrowTypeError% intrinsic object
     throw_type_error: JsObject,
@@ -5,26 +5,38 @@ pub mod intrinsics;
 #[cfg(feature = "intl")]
 mod icu;
 
-use std::collections::VecDeque;
+use std::rc::Rc;
 
 use intrinsics::{IntrinsicObjects, Intrinsics};
 
 #[cfg(feature = "console")]
-use crate::builtins::console::Console;
+use crate::builtins::console::{Console, Logger, StdoutLogger};
 use crate::{
-    builtins::{self, function::NativeFunctionSignature},
+    builtins::{
+        self,
+        function::{NativeFunctionSignature, TypedFunctionSignature},
+        temporal::{TimeZoneProvider, UtcTimeZoneProvider},
+        FinalizationRegistry,
+    },
     bytecompiler::ByteCompiler,
     class::{Class, ClassBuilder},
-    job::JobCallback,
-    object::{FunctionBuilder, GlobalPropertyMap, JsObject, ObjectData},
+    environments::DeclarativeEnvironmentStack,
+    error::JsNativeError,
+    heap_snapshot::HeapSnapshot,
+    job::{FutureJob, JobCallback, JobQueue, SimpleJobQueue},
+    module::{IdleModuleLoader, Module, ModuleLoader},
+    object::{FunctionBuilder, GlobalPropertyMap, JsObject, Object, ObjectData, RefMut},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     syntax::{ast::node::StatementList, parser::ParseError, Parser},
-    vm::{CallFrame, CodeBlock, FinallyReturn, GeneratorResumeKind, Vm},
-    JsResult, JsValue,
+    vm::{
+        CallFrame, CodeBlock, FinallyReturn, GeneratorResumeKind, HeapAccount, InterruptHandle,
+        RuntimeLimits, SourceLocation, StackFrame, Vm,
+    },
+    JsResult, JsString, JsValue,
 };
 
-use boa_gc::Gc;
+use boa_gc::{Cell as GcCell, Gc, GcConfig, GcStats, WeakGc};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 
@@ -32,7 +44,7 @@ use boa_profiler::Profiler;
 use icu_provider::DataError;
 
 #[doc(inline)]
-#[cfg(all(feature = "intl", doc))]
+#[cfg(feature = "intl")]
 pub use icu::BoaProvider;
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -82,7 +94,7 @@ pub use icu::BoaProvider;
 /// ```
 #[derive(Debug)]
 pub struct Context {
-    /// realm holds both the global object and the environment
+    /// The current realm, which holds the global object and the intrinsics.
     pub(crate) realm: Realm,
 
     /// String interner in the context.
@@ -92,16 +104,30 @@ pub struct Context {
     #[cfg(feature = "console")]
     console: Console,
 
-    /// Intrinsic objects
-    intrinsics: Intrinsics,
-
     /// ICU related utilities
     #[cfg(feature = "intl")]
     icu: icu::Icu,
 
     pub(crate) vm: Vm,
 
-    pub(crate) promise_job_queue: VecDeque<JobCallback>,
+    /// The host hook used to schedule promise jobs and the jobs of the host.
+    job_queue: Rc<dyn JobQueue>,
+
+    /// The `FinalizationRegistry` objects created in this context.
+    finalization_registries: Vec<WeakGc<GcCell<Object>>>,
+
+    /// The `[[KeptAlive]]` list of the agent, which keeps the targets of weak references alive
+    /// until the current synchronous execution finishes.
+    kept_alive: Vec<JsObject>,
+
+    /// The host hook used to load the modules imported by other modules.
+    module_loader: Rc<dyn ModuleLoader>,
+
+    /// The host hook used by the `Temporal` builtins to resolve named time zones.
+    time_zone_provider: Rc<dyn TimeZoneProvider>,
+
+    /// The `[[CanBlock]]` field of the agent, which tells if `Atomics.wait` can suspend it.
+    can_block: bool,
 }
 
 impl Default for Context {
@@ -210,7 +236,11 @@ impl Context {
         // 2. If IsCallable(F) is false, throw a TypeError exception.
         // 3. Return ? F.[[Call]](V, argumentsList).
         f.as_callable()
-            .ok_or_else(|| self.construct_type_error("Value is not callable"))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("Value is not callable")
+                    .into()
+            })
             .and_then(|f| f.call(v, arguments_list, self))
     }
 
@@ -222,202 +252,10 @@ impl Context {
 
     /// Return a mutable reference to the global object string bindings.
     #[inline]
-    pub(crate) fn global_bindings_mut(&mut self) -> &mut GlobalPropertyMap {
+    pub(crate) fn global_bindings_mut(&mut self) -> RefMut<'_, Object, GlobalPropertyMap> {
         self.realm.global_bindings_mut()
     }
 
-    /// Constructs a `Error` with the specified message.
-    #[inline]
-    pub fn construct_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::Error::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `Error` with the specified message.
-    #[inline]
-    pub fn throw_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_error(message))
-    }
-
-    /// Constructs a `RangeError` with the specified message.
-    #[inline]
-    pub fn construct_range_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::RangeError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .range_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `RangeError` with the specified message.
-    #[inline]
-    pub fn throw_range_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_range_error(message))
-    }
-
-    /// Constructs a `TypeError` with the specified message.
-    #[inline]
-    pub fn construct_type_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::TypeError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .type_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `TypeError` with the specified message.
-    #[inline]
-    pub fn throw_type_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_type_error(message))
-    }
-
-    /// Constructs a `ReferenceError` with the specified message.
-    #[inline]
-    pub fn construct_reference_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::ReferenceError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .reference_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `ReferenceError` with the specified message.
-    #[inline]
-    pub fn throw_reference_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_reference_error(message))
-    }
-
-    /// Constructs a `SyntaxError` with the specified message.
-    #[inline]
-    pub fn construct_syntax_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::SyntaxError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .syntax_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `SyntaxError` with the specified message.
-    #[inline]
-    pub fn throw_syntax_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_syntax_error(message))
-    }
-
-    /// Constructs a `EvalError` with the specified message.
-    pub fn construct_eval_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::EvalError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .eval_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Constructs a `URIError` with the specified message.
-    pub fn construct_uri_error<M>(&mut self, message: M) -> JsValue
-    where
-        M: Into<Box<str>>,
-    {
-        crate::builtins::error::UriError::constructor(
-            &self
-                .intrinsics()
-                .constructors()
-                .uri_error()
-                .constructor()
-                .into(),
-            &[message.into().into()],
-            self,
-        )
-        .expect("Into<String> used as message")
-    }
-
-    /// Throws a `EvalError` with the specified message.
-    pub fn throw_eval_error<M, R>(&mut self, message: M) -> JsResult<R>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_eval_error(message))
-    }
-
-    /// Throws a `URIError` with the specified message.
-    pub fn throw_uri_error<M>(&mut self, message: M) -> JsResult<JsValue>
-    where
-        M: Into<Box<str>>,
-    {
-        Err(self.construct_uri_error(message))
-    }
-
     /// Register a global native function.
     ///
     /// This is more efficient that creating a closure function, since this does not allocate,
@@ -542,6 +380,67 @@ impl Context {
         Ok(())
     }
 
+    /// Register a global native function with typed parameters.
+    ///
+    /// The arguments are converted to the parameter types of `body`, throwing a `TypeError` if
+    /// they do not match. See [`FunctionBuilder::typed`] for more information.
+    ///
+    /// The function will be bound to the global object with `writable`, `non-enumerable`
+    /// and `configurable` attributes. The same as when you create a function in JavaScript.
+    #[inline]
+    pub fn register_global_typed_function<F, Args>(&mut self, name: &str, body: F)
+    where
+        F: TypedFunctionSignature<Args>,
+    {
+        let function = FunctionBuilder::typed(self, body)
+            .name(name)
+            .constructor(false)
+            .build();
+
+        self.global_bindings_mut().insert(
+            name.into(),
+            PropertyDescriptor::builder()
+                .value(function)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true)
+                .build(),
+        );
+    }
+
+    /// Register a global async native function.
+    ///
+    /// Calling the function returns a promise that settles with the result of the [`Future`]
+    /// returned by `body`, once the [`JobQueue`] of the context polls it to completion. See
+    /// [`FunctionBuilder::async_native`] for more information.
+    ///
+    /// The function will be bound to the global object with `writable`, `non-enumerable`
+    /// and `configurable` attributes. The same as when you create a function in JavaScript.
+    ///
+    /// [`Future`]: std::future::Future
+    #[inline]
+    pub fn register_global_async_function<F, Fut>(&mut self, name: &str, length: usize, body: F)
+    where
+        F: Fn(&JsValue, &[JsValue], &mut Self) -> Fut + 'static,
+        Fut: std::future::Future<Output = JsResult<JsValue>> + 'static,
+    {
+        let function = FunctionBuilder::async_native(self, body)
+            .name(name)
+            .length(length)
+            .constructor(false)
+            .build();
+
+        self.global_bindings_mut().insert(
+            name.into(),
+            PropertyDescriptor::builder()
+                .value(function)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true)
+                .build(),
+        );
+    }
+
     /// <https://tc39.es/ecma262/#sec-hasproperty>
     #[inline]
     pub(crate) fn has_property(&mut self, obj: &JsValue, key: &PropertyKey) -> JsResult<bool> {
@@ -627,7 +526,7 @@ impl Context {
         K: Into<PropertyKey>,
         V: Into<JsValue>,
     {
-        self.realm.global_property_map.insert(
+        self.global_object().borrow_mut().properties_mut().insert(
             &key.into(),
             PropertyDescriptor::builder()
                 .value(value)
@@ -640,6 +539,11 @@ impl Context {
 
     /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
     ///
+    /// The pending jobs are run after the code, like in [`Context::execute`]. With the default
+    /// [`SimpleJobQueue`], this does not wait for the futures of native async functions: if some
+    /// of them are still pending, `eval` returns and their promises stay pending until a later
+    /// call to [`Context::run_jobs`] polls them to completion.
+    ///
     /// # Examples
     /// ```
     ///# use boa_engine::Context;
@@ -652,6 +556,35 @@ impl Context {
     /// ```
     #[allow(clippy::unit_arg, clippy::drop_copy)]
     pub fn eval<S>(&mut self, src: S) -> JsResult<JsValue>
+    where
+        S: AsRef<[u8]>,
+    {
+        self.eval_script(src, None)
+    }
+
+    /// Evaluates the given code like [`Context::eval`], using `source_name` as the name of the
+    /// source text in the locations reported by the engine, like the ones printed by
+    /// `console.trace()`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa_engine::Context;
+    /// let mut context = Context::default();
+    ///
+    /// let value = context.eval_with_name("1 + 3", "script.js").unwrap();
+    ///
+    /// assert_eq!(value.as_number(), Some(4.0));
+    /// ```
+    pub fn eval_with_name<S, N>(&mut self, src: S, source_name: N) -> JsResult<JsValue>
+    where
+        S: AsRef<[u8]>,
+        N: Into<JsString>,
+    {
+        self.eval_script(src, Some(source_name.into()))
+    }
+
+    /// Parses, compiles and executes a script.
+    fn eval_script<S>(&mut self, src: S, source_name: Option<JsString>) -> JsResult<JsValue>
     where
         S: AsRef<[u8]>,
     {
@@ -663,10 +596,10 @@ impl Context {
 
         let statement_list = match parsing_result {
             Ok(statement_list) => statement_list,
-            Err(e) => return self.throw_syntax_error(e),
+            Err(e) => return Err(JsNativeError::syntax().with_message(e).into()),
         };
 
-        let code_block = self.compile(&statement_list)?;
+        let code_block = self.compile_script(&statement_list, source_name)?;
         let result = self.execute(code_block);
 
         // The main_timer needs to be dropped before the Profiler is.
@@ -676,16 +609,135 @@ impl Context {
         result
     }
 
+    /// Parses, loads, links and evaluates the given source text as an ECMAScript module,
+    /// returning the evaluated module.
+    ///
+    /// The modules imported by the module are loaded with the [`ModuleLoader`] of the context.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa_engine::Context;
+    /// let mut context = Context::default();
+    ///
+    /// let module = context.eval_module("export const answer = 6 * 7;").unwrap();
+    /// let namespace = module.namespace(&mut context).unwrap();
+    ///
+    /// assert_eq!(namespace.get("answer", &mut context).unwrap().as_number(), Some(42.0));
+    /// ```
+    pub fn eval_module<S>(&mut self, src: S) -> JsResult<Module>
+    where
+        S: AsRef<[u8]>,
+    {
+        let main_timer = Profiler::global().start_event("Module evaluation", "Main");
+
+        let module = Module::parse(src, None, self)?;
+        module.load(self)?;
+        module.link(self)?;
+        let result = module.evaluate(self);
+
+        // The main_timer needs to be dropped before the Profiler is.
+        drop(main_timer);
+        Profiler::global().drop();
+
+        result.map(|_| module)
+    }
+
+    /// Gets the module loader of the context.
+    #[inline]
+    pub fn module_loader(&self) -> Rc<dyn ModuleLoader> {
+        self.module_loader.clone()
+    }
+
+    /// Gets the time zone provider of the context.
+    #[inline]
+    pub fn time_zone_provider(&self) -> Rc<dyn TimeZoneProvider> {
+        self.time_zone_provider.clone()
+    }
+
+    /// Returns `true` if the agent of this context can be suspended by `Atomics.wait`.
+    #[inline]
+    pub const fn can_block(&self) -> bool {
+        self.can_block
+    }
+
     /// Compile the AST into a `CodeBlock` ready to be executed by the VM.
     #[inline]
     pub fn compile(&mut self, statement_list: &StatementList) -> JsResult<Gc<CodeBlock>> {
+        self.compile_script(statement_list, None)
+    }
+
+    /// Compile the AST into a `CodeBlock`, using `source_name` as the name of the source text
+    /// that the AST was parsed from.
+    #[inline]
+    pub fn compile_with_name<N>(
+        &mut self,
+        statement_list: &StatementList,
+        source_name: N,
+    ) -> JsResult<Gc<CodeBlock>>
+    where
+        N: Into<JsString>,
+    {
+        self.compile_script(statement_list, Some(source_name.into()))
+    }
+
+    fn compile_script(
+        &mut self,
+        statement_list: &StatementList,
+        source_name: Option<JsString>,
+    ) -> JsResult<Gc<CodeBlock>> {
         let _timer = Profiler::global().start_event("Compilation", "Main");
         let mut compiler = ByteCompiler::new(Sym::MAIN, statement_list.strict(), self);
+        compiler.set_source_name(source_name);
         compiler.create_declarations(statement_list.items())?;
-        compiler.compile_statement_list(statement_list.items(), true)?;
+        compiler.compile_statement_list(statement_list, true)?;
         Ok(Gc::new(compiler.finish()))
     }
 
+    /// Serializes a `CodeBlock` compiled by this context into a bytecode cache.
+    ///
+    /// The cache contains the code block, its inner functions and the strings they reference, so
+    /// it can be loaded by [`Context::deserialize_code_block`] in another context, skipping
+    /// parsing and compilation.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa_engine::Context;
+    /// let mut context = Context::default();
+    /// let statement_list = context.parse("let x = 40; x + 2").unwrap();
+    /// let code_block = context.compile(&statement_list).unwrap();
+    /// let cache = context.serialize_code_block(&code_block);
+    ///
+    /// let mut context = Context::default();
+    /// let code_block = context.deserialize_code_block(&cache).unwrap();
+    /// let value = context.execute(code_block).unwrap();
+    ///
+    /// assert_eq!(value.as_number(), Some(42.0));
+    /// ```
+    #[inline]
+    pub fn serialize_code_block(&self, code_block: &CodeBlock) -> Vec<u8> {
+        crate::vm::cache::serialize(code_block, self)
+    }
+
+    /// Loads a bytecode cache created by [`Context::serialize_code_block`].
+    ///
+    /// The global `var`, `let`, `const` and function declarations of the code are declared in
+    /// this context, like compiling its source text would.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache is malformed or corrupted, if it was created by a different
+    /// version of the engine or if its global declarations conflict with the existing global
+    /// declarations of this context.
+    ///
+    /// The cache is checked against the checksum stored in it, and every instruction is checked to
+    /// be valid and to only reference the literals, names, bindings and functions of its code
+    /// block. This does not check that the bytecode keeps the stack and the environments
+    /// balanced, so caches should only be loaded if they were created by this engine.
+    pub fn deserialize_code_block(&mut self, bytes: &[u8]) -> JsResult<Gc<CodeBlock>> {
+        let _timer = Profiler::global().start_event("Bytecode cache loading", "Main");
+        crate::vm::cache::deserialize(bytes, self)
+    }
+
     /// Compile the AST into a `CodeBlock` with an additional declarative environment.
     #[inline]
     pub(crate) fn compile_with_new_declarative(
@@ -696,7 +748,7 @@ impl Context {
         let _timer = Profiler::global().start_event("Compilation", "Main");
         let mut compiler = ByteCompiler::new(Sym::MAIN, statement_list.strict(), self);
         compiler.compile_statement_list_with_new_declarative(
-            statement_list.items(),
+            statement_list,
             true,
             strict || statement_list.strict(),
         )?;
@@ -713,6 +765,14 @@ impl Context {
     pub fn execute(&mut self, code_block: Gc<CodeBlock>) -> JsResult<JsValue> {
         let _timer = Profiler::global().start_event("Execution", "Main");
 
+        // Only the outermost execution resets the state used by the runtime limits, since
+        // nested executions (e.g. `eval` calls) are part of it.
+        let top_level = self.vm.frames.is_empty();
+        if top_level {
+            self.vm.instruction_count = 0;
+            self.vm.termination = None;
+        }
+
         self.vm.push_frame(CallFrame {
             code: code_block,
             pc: 0,
@@ -732,26 +792,318 @@ impl Context {
             async_generator: None,
         });
 
-        self.realm.set_global_binding_number();
+        let environments_len = self.vm.environments.len();
+        let binding_number = self.realm.compile_env().borrow().num_bindings();
+        self.vm
+            .environments
+            .set_global_binding_number(binding_number);
         let result = self.run();
         self.vm.pop_frame();
-        self.run_queued_jobs()?;
+
+        // An uncaught error leaves the environments of the blocks it escaped from on the stack.
+        if result.is_err() {
+            self.vm.environments.truncate(environments_len);
+        }
+        let jobs_result = self.run_jobs();
+
+        // A termination could have been swallowed by the promise jobs, so it is reported
+        // here instead.
+        if top_level {
+            if let Some(error) = self.vm.termination.take() {
+                return Err(error);
+            }
+        }
+
+        jobs_result?;
         let (result, _) = result?;
         Ok(result)
     }
 
-    /// Runs all the jobs in the job queue.
-    fn run_queued_jobs(&mut self) -> JsResult<()> {
-        while let Some(job) = self.promise_job_queue.pop_front() {
-            job.call_job_callback(&JsValue::Undefined, &[], self)?;
+    /// Gets the location in the source text of the code that is currently executed, if any.
+    ///
+    /// When called from a native function, this is the location of the call to the native
+    /// function.
+    #[inline]
+    pub fn current_location(&self) -> Option<SourceLocation> {
+        self.vm.frames.last().and_then(CallFrame::location)
+    }
+
+    /// Captures the current stack trace of the executing code, starting with the innermost
+    /// frame.
+    ///
+    /// Native functions do not have frames of their own, so when called from a native function
+    /// the first frame is the frame of the code that called the native function.
+    pub fn stack_trace(&self) -> Vec<StackFrame> {
+        self.vm
+            .frames
+            .iter()
+            .rev()
+            .map(|frame| frame.stack_frame(&self.interner))
+            .collect()
+    }
+
+    /// Runs the pending jobs of the [`JobQueue`] of the context.
+    ///
+    /// This is called automatically at the end of [`Context::execute`], so hosts only need to
+    /// call it after enqueuing jobs outside of a script execution, e.g. from their own event
+    /// loop, or to poll the futures of native async functions that were still pending.
+    ///
+    /// After the queue runs its jobs, a cleanup job is enqueued for every
+    /// `FinalizationRegistry` that has targets collected by the garbage collector, and the
+    /// queue is run again until no more cleanup jobs are needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error thrown by the first job that fails, if any.
+    pub fn run_jobs(&mut self) -> JsResult<()> {
+        self.clear_kept_objects();
+        loop {
+            self.job_queue.clone().run_jobs(self)?;
+            self.clear_kept_objects();
+            if !self.enqueue_finalization_registry_cleanup_jobs() {
+                return Ok(());
+            }
         }
-        Ok(())
     }
 
-    /// Return the intrinsic constructors and objects.
+    /// Enqueues a job in the [`JobQueue`] of the context.
+    ///
+    /// This is the entry point of the [`HostEnqueuePromiseJob`][spec] host hook, used by the
+    /// engine to schedule promise reactions, but hosts can also use it to schedule their own
+    /// jobs, which will run along with the promise jobs.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    pub fn enqueue_job(&mut self, job: JobCallback) {
+        self.job_queue.clone().enqueue_promise_job(job, self);
+    }
+
+    /// Enqueues a future in the [`JobQueue`] of the context.
+    ///
+    /// The [`NativeJob`](crate::job::NativeJob) produced by the future is run once the queue
+    /// polls the future to completion.
+    pub fn enqueue_future_job(&mut self, future: FutureJob) {
+        self.job_queue.clone().enqueue_future_job(future, self);
+    }
+
+    /// Gets the job queue of the context.
+    #[inline]
+    pub fn job_queue(&self) -> Rc<dyn JobQueue> {
+        self.job_queue.clone()
+    }
+
+    /// Tracks a new `FinalizationRegistry`, to schedule its cleanup jobs once any of its targets
+    /// is collected.
+    pub(crate) fn register_finalization_registry(&mut self, registry: &JsObject) {
+        self.finalization_registries
+            .push(WeakGc::new(registry.inner()));
+    }
+
+    /// Enqueues a cleanup job for each `FinalizationRegistry` with collected targets.
+    ///
+    /// Returns `true` if any job was enqueued.
+    fn enqueue_finalization_registry_cleanup_jobs(&mut self) -> bool {
+        self.finalization_registries.retain(WeakGc::is_alive);
+
+        let registries: Vec<_> = self
+            .finalization_registries
+            .iter()
+            .filter_map(WeakGc::upgrade)
+            .map(JsObject::from_inner)
+            .filter(|registry| {
+                registry
+                    .borrow_mut()
+                    .as_finalization_registry_mut()
+                    .map_or(false, FinalizationRegistry::needs_cleanup)
+            })
+            .collect();
+
+        let enqueued = !registries.is_empty();
+        for registry in registries {
+            let job = FinalizationRegistry::cleanup_job(registry, self);
+            self.enqueue_job(job);
+        }
+        enqueued
+    }
+
+    /// `AddToKeptObjects ( object )`
+    ///
+    /// Keeps `object` alive until the end of the current synchronous execution.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-addtokeptobjects
+    pub(crate) fn add_to_kept_objects(&mut self, object: JsObject) {
+        // 1. Let agentRecord be the surrounding agent's Agent Record.
+        // 2. Append object to agentRecord.[[KeptAlive]].
+        self.kept_alive.push(object);
+    }
+
+    /// `ClearKeptObjects ( )`
+    ///
+    /// Releases the objects kept alive by `WeakRef` constructions and dereferences during the
+    /// current synchronous execution, so they can be collected by the next garbage collection.
+    ///
+    /// The context calls this after running a script and before and after running the jobs of
+    /// its [`JobQueue`], and the default [`SimpleJobQueue`] calls it after each job. Hosts may
+    /// need to call it themselves to observe collections in the middle of an execution, e.g.
+    /// before calling [`boa_gc::force_collect`] in tests, and custom job queues should call it
+    /// after each job they run.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-clear-kept-objects
+    pub fn clear_kept_objects(&mut self) {
+        // 1. Let agentRecord be the surrounding agent's Agent Record.
+        // 2. Set agentRecord.[[KeptAlive]] to a new empty List.
+        self.kept_alive.clear();
+    }
+
+    /// Return the intrinsic constructors and objects of the current realm.
     #[inline]
     pub fn intrinsics(&self) -> &Intrinsics {
-        &self.intrinsics
+        self.realm.intrinsics()
+    }
+
+    /// Returns the current realm.
+    #[inline]
+    pub fn realm(&self) -> &Realm {
+        &self.realm
+    }
+
+    /// Creates a new realm, with its own global object and its own set of builtins.
+    ///
+    /// This is much cheaper than creating a new `Context`, and the realms of a context can share
+    /// values. The current realm is not changed; use [`Context::enter_realm`] to run code in the
+    /// new realm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{Context, JsValue};
+    ///
+    /// let mut context = Context::default();
+    /// context.eval("var tenant = 'main'").unwrap();
+    ///
+    /// let realm = context.create_realm();
+    /// let main = context.enter_realm(realm);
+    /// assert_eq!(context.eval("typeof tenant").unwrap(), JsValue::new("undefined"));
+    /// let array = context.eval("[]").unwrap();
+    ///
+    /// context.enter_realm(main);
+    /// assert_eq!(context.eval("tenant").unwrap(), JsValue::new("main"));
+    /// assert_eq!(
+    ///     context.eval("Array.isArray").unwrap().as_callable().unwrap()
+    ///         .call(&JsValue::undefined(), &[array.clone()], &mut context)
+    ///         .unwrap(),
+    ///     JsValue::new(true)
+    /// );
+    /// ```
+    pub fn create_realm(&mut self) -> Realm {
+        let _timer = Profiler::global().start_event("Context::create_realm", "realm");
+        let realm = Realm::create();
+        let previous = self.enter_realm(realm.clone());
+        self.initialize_realm();
+        self.enter_realm(previous);
+        realm
+    }
+
+    /// Makes `realm` the current realm, and returns the previous one.
+    ///
+    /// Code evaluated after this call runs in the global environment of `realm` and uses its
+    /// builtins. Functions keep running in the realm they were created in, whatever the current
+    /// realm is when they are called.
+    #[inline]
+    pub fn enter_realm(&mut self, realm: Realm) -> Realm {
+        self.vm
+            .environments
+            .replace_global(realm.global_env().clone());
+        std::mem::replace(&mut self.realm, realm)
+    }
+
+    /// Creates the intrinsics and the global bindings of the current realm.
+    fn initialize_realm(&mut self) {
+        let objects = IntrinsicObjects::init(self);
+        self.realm.intrinsics().set_objects(objects);
+        self.create_intrinsics();
+    }
+
+    /// Gets the limits on the execution of the context.
+    #[inline]
+    pub fn runtime_limits(&self) -> RuntimeLimits {
+        self.vm.runtime_limits
+    }
+
+    /// Sets the limits on the execution of the context.
+    #[inline]
+    pub fn set_runtime_limits(&mut self, runtime_limits: RuntimeLimits) {
+        self.vm.runtime_limits = runtime_limits;
+    }
+
+    /// Gets the statistics of the garbage collector: the number of collections, the time the
+    /// program was paused by them and the size of the heap.
+    ///
+    /// The collector is per thread, so the statistics cover every context of the current thread.
+    #[inline]
+    pub fn gc_stats(&self) -> GcStats {
+        boa_gc::stats()
+    }
+
+    /// Gets the configuration of the garbage collector of the current thread.
+    #[inline]
+    pub fn gc_config(&self) -> GcConfig {
+        boa_gc::config()
+    }
+
+    /// Sets the configuration of the garbage collector.
+    ///
+    /// The collector is per thread, so the configuration applies to every context of the current
+    /// thread.
+    #[inline]
+    pub fn set_gc_config(&mut self, config: GcConfig) {
+        boa_gc::set_config(config);
+    }
+
+    /// Gets the number of bytes of memory charged to the context, which is limited by the heap
+    /// limit of its [`RuntimeLimits`].
+    ///
+    /// Objects, including the storage of their properties, strings and `ArrayBuffer` data are
+    /// charged to the context that was running code when they were allocated, or that was
+    /// created last if none has run code yet, until they are freed. Unreachable objects are
+    /// only freed by garbage collections, so this includes the garbage that hasn't been
+    /// collected yet.
+    #[inline]
+    pub fn heap_usage(&self) -> usize {
+        self.vm.heap.used()
+    }
+
+    /// Takes a snapshot of the objects that are reachable from the context, which can be
+    /// exported to the Chrome DevTools to find out what keeps them alive.
+    ///
+    /// The roots of the graph are the global object, the realm of the context, the values and
+    /// environments of the code that is running, and the objects kept alive by `WeakRef`s.
+    ///
+    /// If a garbage collection is in progress, it is finished first.
+    pub fn heap_snapshot(&self) -> HeapSnapshot {
+        HeapSnapshot::new(
+            self,
+            vec![
+                ("global", boa_gc::edges(self.global_object())),
+                ("realm", boa_gc::edges(&self.realm)),
+                ("stack", boa_gc::edges(&self.vm.stack)),
+                ("environments", boa_gc::edges(&self.vm.environments)),
+                ("frames", boa_gc::edges(&self.vm.frames)),
+                ("kept alive", boa_gc::edges(&self.kept_alive)),
+            ],
+        )
+    }
+
+    /// Gets a handle that can be sent to other threads to interrupt the execution of the
+    /// context.
+    #[inline]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.vm.interrupt_handle.clone()
     }
 
     /// Set the value of trace on the context
@@ -765,18 +1117,6 @@ impl Context {
     pub(crate) fn icu(&self) -> &icu::Icu {
         &self.icu
     }
-
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
-    pub fn host_enqueue_promise_job(&mut self, job: JobCallback /* , realm: Realm */) {
-        // If realm is not null ...
-        // TODO
-        // Let scriptOrModule be ...
-        // TODO
-        self.promise_job_queue.push_back(job);
-    }
 }
 /// Builder for the [`Context`] type.
 ///
@@ -795,6 +1135,14 @@ pub struct ContextBuilder {
     interner: Option<Interner>,
     #[cfg(feature = "intl")]
     icu: Option<icu::Icu>,
+    module_loader: Option<Rc<dyn ModuleLoader>>,
+    time_zone_provider: Option<Rc<dyn TimeZoneProvider>>,
+    job_queue: Option<Rc<dyn JobQueue>>,
+    can_block: Option<bool>,
+    #[cfg(feature = "console")]
+    logger: Option<Rc<dyn Logger>>,
+    runtime_limits: RuntimeLimits,
+    gc_config: Option<GcConfig>,
 }
 
 impl ContextBuilder {
@@ -817,6 +1165,81 @@ impl ContextBuilder {
         Ok(self)
     }
 
+    /// Initializes the [`ModuleLoader`] used to load the modules imported by other modules.
+    ///
+    /// If no module loader is provided, the context uses an [`IdleModuleLoader`], which
+    /// refuses to load any module.
+    #[must_use]
+    pub fn module_loader(mut self, module_loader: Rc<dyn ModuleLoader>) -> Self {
+        self.module_loader = Some(module_loader);
+        self
+    }
+
+    /// Initializes the [`TimeZoneProvider`] used by the `Temporal` builtins to resolve named time
+    /// zones, like `Europe/Madrid`.
+    ///
+    /// If no time zone provider is provided, the context uses an [`UtcTimeZoneProvider`], which
+    /// only supports the `UTC` time zone and fixed offset time zones.
+    #[must_use]
+    pub fn time_zone_provider(mut self, time_zone_provider: Rc<dyn TimeZoneProvider>) -> Self {
+        self.time_zone_provider = Some(time_zone_provider);
+        self
+    }
+
+    /// Initializes the [`JobQueue`] used to schedule promise jobs and the jobs of the host.
+    ///
+    /// If no job queue is provided, the context uses a [`SimpleJobQueue`], which runs all
+    /// pending jobs at the end of every script execution, without waiting for pending futures.
+    #[must_use]
+    pub fn job_queue(mut self, job_queue: Rc<dyn JobQueue>) -> Self {
+        self.job_queue = Some(job_queue);
+        self
+    }
+
+    /// Sets whether `Atomics.wait` can suspend the agent of the context.
+    ///
+    /// Agents cannot block by default, which makes `Atomics.wait` throw a `TypeError`, so that
+    /// untrusted code cannot suspend the thread of the host. Hosts that run agents on their own
+    /// threads can enable it, and terminate a suspended agent with its
+    /// [`InterruptHandle`][crate::vm::InterruptHandle].
+    #[must_use]
+    pub fn can_block(mut self, can_block: bool) -> Self {
+        self.can_block = Some(can_block);
+        self
+    }
+
+    /// Initializes the [`Logger`] that prints the messages of the `console` object.
+    ///
+    /// If no logger is provided, the context uses a [`StdoutLogger`], which prints the messages
+    /// to the standard output and the standard error.
+    ///
+    /// This function is only available if the `console` feature is enabled.
+    #[cfg(feature = "console")]
+    #[must_use]
+    pub fn logger(mut self, logger: Rc<dyn Logger>) -> Self {
+        self.logger = Some(logger);
+        self
+    }
+
+    /// Initializes the [`RuntimeLimits`] of the context, to protect the host from scripts that
+    /// run for too long or recurse too deeply.
+    #[must_use]
+    pub fn runtime_limits(mut self, runtime_limits: RuntimeLimits) -> Self {
+        self.runtime_limits = runtime_limits;
+        self
+    }
+
+    /// Initializes the [`GcConfig`] of the garbage collector, which decides how often the young
+    /// and the old generations are collected.
+    ///
+    /// The collector is per thread, so the configuration applies to every context of the current
+    /// thread. If no configuration is provided, the current one is kept.
+    #[must_use]
+    pub fn gc_config(mut self, gc_config: GcConfig) -> Self {
+        self.gc_config = Some(gc_config);
+        self
+    }
+
     /// Creates a new [`ContextBuilder`] with a default empty [`Interner`]
     /// and a default [`BoaProvider`] if the `intl` feature is enabled.
     pub fn new() -> Self {
@@ -826,17 +1249,28 @@ impl ContextBuilder {
     /// Builds a new [`Context`] with the provided parameters, and defaults
     /// all missing parameters to their default values.
     pub fn build(self) -> Context {
+        if let Some(gc_config) = self.gc_config {
+            boa_gc::set_config(gc_config);
+        }
+        // The intrinsics are charged to the new context.
+        let heap = Rc::new(HeapAccount::default());
+        HeapAccount::make_current(&heap);
+        let realm = Realm::create();
         let mut context = Context {
-            realm: Realm::create(),
+            realm: realm.clone(),
             interner: self.interner.unwrap_or_default(),
             #[cfg(feature = "console")]
-            console: Console::default(),
-            intrinsics: Intrinsics::default(),
+            console: Console::new(self.logger.unwrap_or_else(|| Rc::new(StdoutLogger))),
             vm: Vm {
                 frames: Vec::with_capacity(16),
                 stack: Vec::with_capacity(1024),
+                environments: DeclarativeEnvironmentStack::new(realm.global_env().clone()),
                 trace: false,
-                stack_size_limit: 1024,
+                runtime_limits: self.runtime_limits,
+                interrupt_handle: InterruptHandle::default(),
+                instruction_count: 0,
+                termination: None,
+                heap,
             },
             #[cfg(feature = "intl")]
             icu: self.icu.unwrap_or_else(|| {
@@ -844,14 +1278,24 @@ impl ContextBuilder {
                 icu::Icu::new(Box::new(icu_testdata::get_provider()))
                     .expect("Failed to initialize default icu data.")
             }),
-            promise_job_queue: VecDeque::new(),
+            job_queue: self
+                .job_queue
+                .unwrap_or_else(|| Rc::new(SimpleJobQueue::new())),
+            finalization_registries: Vec::new(),
+            kept_alive: Vec::new(),
+            module_loader: self
+                .module_loader
+                .unwrap_or_else(|| Rc::new(IdleModuleLoader)),
+            time_zone_provider: self
+                .time_zone_provider
+                .unwrap_or_else(|| Rc::new(UtcTimeZoneProvider)),
+            can_block: self.can_block.unwrap_or(false),
         };
 
         // Add new builtIns to Context Realm
         // At a later date this can be removed from here and called explicitly,
         // but for now we almost always want these default builtins
-        context.intrinsics.objects = IntrinsicObjects::init(&mut context);
-        context.create_intrinsics();
+        context.initialize_realm();
         context
     }
 }
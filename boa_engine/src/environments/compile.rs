@@ -8,11 +8,11 @@ use rustc_hash::FxHashMap;
 /// A compile time binding represents a binding at bytecode compile time in a [`CompileTimeEnvironment`].
 ///
 /// It contains the binding index and a flag to indicate if this is a mutable binding or not.
-#[derive(Debug)]
-struct CompileTimeBinding {
-    index: usize,
-    mutable: bool,
-    lex: bool,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CompileTimeBinding {
+    pub(crate) index: usize,
+    pub(crate) mutable: bool,
+    pub(crate) lex: bool,
 }
 
 /// A compile time environment maps bound identifiers to their binding positions.
@@ -39,6 +39,47 @@ impl CompileTimeEnvironment {
         }
     }
 
+    /// Crate a new compile time environment nested in `outer`.
+    #[inline]
+    pub(crate) fn new(outer: Gc<Cell<Self>>, function_scope: bool) -> Self {
+        let environment_index = outer.borrow().environment_index + 1;
+        Self {
+            outer: Some(outer),
+            environment_index,
+            bindings: FxHashMap::default(),
+            function_scope,
+        }
+    }
+
+    /// Returns the outer environment of this environment, or `None` if this is the global
+    /// environment.
+    #[inline]
+    pub(crate) fn outer(&self) -> Option<Gc<Cell<Self>>> {
+        self.outer.clone()
+    }
+
+    /// Returns an iterator over the bindings of this environment, in no particular order.
+    #[inline]
+    pub(crate) fn bindings(&self) -> impl Iterator<Item = (Sym, CompileTimeBinding)> + '_ {
+        self.bindings
+            .iter()
+            .map(|(name, binding)| (*name, *binding))
+    }
+
+    /// Get the binding with the given name, without looking at the outer environments.
+    #[inline]
+    pub(crate) fn get_compile_time_binding(&self, name: Sym) -> Option<CompileTimeBinding> {
+        self.bindings.get(&name).copied()
+    }
+
+    /// Insert a binding with a known binding index.
+    ///
+    /// This is used to recreate environments that were not created by the bytecode compiler.
+    #[inline]
+    pub(crate) fn insert_binding(&mut self, name: Sym, binding: CompileTimeBinding) {
+        self.bindings.insert(name, binding);
+    }
+
     /// Check if environment has a lexical binding with the given name.
     #[inline]
     pub(crate) fn has_lex_binding(&self, name: Sym) -> bool {
@@ -210,15 +251,12 @@ impl Context {
     /// Note: This function only works at bytecode compile time!
     #[inline]
     pub(crate) fn push_compile_time_environment(&mut self, function_scope: bool) {
-        let environment_index = self.realm.compile_env.borrow().environment_index + 1;
-        let outer = self.realm.compile_env.clone();
-
-        self.realm.compile_env = Gc::new(Cell::new(CompileTimeEnvironment {
-            outer: Some(outer),
-            environment_index,
-            bindings: FxHashMap::default(),
-            function_scope,
-        }));
+        let outer = self.realm.compile_env();
+        self.realm
+            .set_compile_env(Gc::new(Cell::new(CompileTimeEnvironment::new(
+                outer,
+                function_scope,
+            ))));
     }
 
     /// Pop the last compile time environment from the stack.
@@ -232,13 +270,13 @@ impl Context {
     pub(crate) fn pop_compile_time_environment(
         &mut self,
     ) -> (usize, Gc<Cell<CompileTimeEnvironment>>) {
-        let current_env_borrow = self.realm.compile_env.borrow();
+        let current = self.realm.compile_env();
+        let current_env_borrow = current.borrow();
         if let Some(outer) = &current_env_borrow.outer {
             let outer_clone = outer.clone();
             let num_bindings = current_env_borrow.num_bindings();
             drop(current_env_borrow);
-            let current = self.realm.compile_env.clone();
-            self.realm.compile_env = outer_clone;
+            self.realm.set_compile_env(outer_clone);
             (num_bindings, current)
         } else {
             panic!("cannot pop global environment")
@@ -254,7 +292,7 @@ impl Context {
     /// Panics if there are no environments on the compile time environment stack.
     #[inline]
     pub(crate) fn get_binding_number(&self) -> usize {
-        self.realm.compile_env.borrow().num_bindings()
+        self.realm.compile_env().borrow().num_bindings()
     }
 
     /// Get the binding locator of the binding at bytecode compile time.
@@ -262,7 +300,10 @@ impl Context {
     /// Note: This function only works at bytecode compile time!
     #[inline]
     pub(crate) fn get_binding_value(&self, name: Sym) -> BindingLocator {
-        self.realm.compile_env.borrow().get_binding_recursive(name)
+        self.realm
+            .compile_env()
+            .borrow()
+            .get_binding_recursive(name)
     }
 
     /// Return if a declarative binding exists at bytecode compile time.
@@ -271,31 +312,38 @@ impl Context {
     /// Note: This function only works at bytecode compile time!
     #[inline]
     pub(crate) fn has_binding(&self, name: Sym) -> bool {
-        self.realm.compile_env.borrow().has_binding_recursive(name)
+        self.realm
+            .compile_env()
+            .borrow()
+            .has_binding_recursive(name)
     }
 
     /// Create a mutable binding at bytecode compile time.
     /// This function returns a syntax error, if the binding is a redeclaration.
     ///
+    /// Returns `true` if the binding is a property of the global object.
+    ///
     /// Note: This function only works at bytecode compile time!
     ///
     /// # Panics
     ///
     /// Panics if the global environment is not function scoped.
     #[inline]
-    pub(crate) fn create_mutable_binding(&mut self, name: Sym, function_scope: bool) {
-        if !self
+    pub(crate) fn create_mutable_binding(&mut self, name: Sym, function_scope: bool) -> bool {
+        if self
             .realm
-            .compile_env
+            .compile_env()
             .borrow_mut()
             .create_mutable_binding(name, function_scope)
         {
+            false
+        } else {
             let name_str = JsString::from(self.interner().resolve_expect(name));
             let desc = self
-                .realm
-                .global_property_map
-                .string_property_map()
-                .get(&name_str);
+                .global_object()
+                .borrow()
+                .properties()
+                .get(&name_str.clone().into());
             if desc.is_none() {
                 self.global_bindings_mut().insert(
                     name_str,
@@ -307,6 +355,7 @@ impl Context {
                         .build(),
                 );
             }
+            true
         }
     }
 
@@ -320,7 +369,7 @@ impl Context {
         function_scope: bool,
     ) -> BindingLocator {
         self.realm
-            .compile_env
+            .compile_env()
             .borrow()
             .initialize_mutable_binding(name, function_scope)
     }
@@ -336,7 +385,7 @@ impl Context {
     #[inline]
     pub(crate) fn create_immutable_binding(&mut self, name: Sym) {
         self.realm
-            .compile_env
+            .compile_env()
             .borrow_mut()
             .create_immutable_binding(name);
     }
@@ -351,7 +400,7 @@ impl Context {
     #[inline]
     pub(crate) fn initialize_immutable_binding(&self, name: Sym) -> BindingLocator {
         self.realm
-            .compile_env
+            .compile_env()
             .borrow()
             .initialize_immutable_binding(name)
     }
@@ -362,7 +411,7 @@ impl Context {
     #[inline]
     pub(crate) fn set_mutable_binding(&self, name: Sym) -> BindingLocator {
         self.realm
-            .compile_env
+            .compile_env()
             .borrow()
             .set_mutable_binding_recursive(name)
     }
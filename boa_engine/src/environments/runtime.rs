@@ -1,4 +1,7 @@
-use crate::{environments::CompileTimeEnvironment, object::JsObject, Context, JsResult, JsValue};
+use crate::{
+    environments::CompileTimeEnvironment, error::JsNativeError, module::Module, object::JsObject,
+    Context, JsResult, JsValue,
+};
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_interner::Sym;
 use rustc_hash::FxHashSet;
@@ -11,6 +14,9 @@ use rustc_hash::FxHashSet;
 /// Optionally, an environment can hold a `this` value.
 /// The `this` value is present only if the environment is a function environment.
 ///
+/// Module environments additionally hold a reference to their module record, which is used
+/// to resolve the indirect bindings created by import declarations.
+///
 /// Code evaluation at runtime (e.g. the `eval` built-in function) can add
 /// bindings to existing, compiled function environments.
 /// This makes it impossible to determine the location of all bindings at compile time.
@@ -36,6 +42,7 @@ pub(crate) struct DeclarativeEnvironment {
 pub(crate) enum EnvironmentSlots {
     Function(Cell<FunctionSlots>),
     Global,
+    Module(Module),
 }
 
 impl EnvironmentSlots {
@@ -176,11 +183,54 @@ enum ThisBindingStatus {
 }
 
 impl DeclarativeEnvironment {
+    /// Creates the declarative part of a global environment.
+    pub(crate) fn new_global(compile: Gc<Cell<CompileTimeEnvironment>>) -> Gc<Self> {
+        Gc::new(Self {
+            bindings: Cell::new(Vec::new()),
+            compile,
+            poisoned: Cell::new(false),
+            slots: Some(EnvironmentSlots::Global),
+        })
+    }
+
     /// Returns the internal slot data of the current environment.
     pub(crate) fn slots(&self) -> Option<&EnvironmentSlots> {
         self.slots.as_ref()
     }
 
+    /// Returns the compile time environment of the current environment.
+    pub(crate) fn compile_env(&self) -> Gc<Cell<CompileTimeEnvironment>> {
+        self.compile.clone()
+    }
+
+    /// Returns the values of the bindings stored in the environment, by binding index.
+    pub(crate) fn bindings(&self) -> boa_gc::Ref<'_, Vec<Option<JsValue>>> {
+        self.bindings.borrow()
+    }
+
+    /// Get the binding value from the environment by it's index, or `None` if the binding is not
+    /// initialized.
+    ///
+    /// The import bindings of module environments are never initialized in the environment itself;
+    /// their values are resolved from the environment of the module that exports them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the binding value is out of range.
+    #[inline]
+    pub(crate) fn get_optional(&self, index: usize) -> Option<JsValue> {
+        let value = self
+            .bindings
+            .borrow()
+            .get(index)
+            .expect("binding index must be in range")
+            .clone();
+        match (value, &self.slots) {
+            (None, Some(EnvironmentSlots::Module(module))) => module.get_indirect_binding(index),
+            (value, _) => value,
+        }
+    }
+
     /// Get the binding value from the environment by it's index.
     ///
     /// # Panics
@@ -210,6 +260,20 @@ impl DeclarativeEnvironment {
         assert!(!binding.is_none(), "binding must be initialized");
         *binding = Some(value);
     }
+
+    /// Initialize the binding at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the binding value is out of range.
+    #[inline]
+    pub(crate) fn initialize(&self, index: usize, value: JsValue) {
+        *self
+            .bindings
+            .borrow_mut()
+            .get_mut(index)
+            .expect("binding index must be in range") = Some(value);
+    }
 }
 
 /// A declarative environment stack holds all declarative environments at runtime.
@@ -224,17 +288,21 @@ pub struct DeclarativeEnvironmentStack {
 impl DeclarativeEnvironmentStack {
     /// Create a new environment stack with the most outer declarative environment.
     #[inline]
-    pub(crate) fn new(global_compile_environment: Gc<Cell<CompileTimeEnvironment>>) -> Self {
+    pub(crate) fn new(global: Gc<DeclarativeEnvironment>) -> Self {
         Self {
-            stack: vec![Gc::new(DeclarativeEnvironment {
-                bindings: Cell::new(Vec::new()),
-                compile: global_compile_environment,
-                poisoned: Cell::new(false),
-                slots: Some(EnvironmentSlots::Global),
-            })],
+            stack: vec![global],
         }
     }
 
+    /// Replaces the most outer declarative environment of the stack.
+    ///
+    /// This is used when switching realms, to make the code run in the global environment of the
+    /// new realm.
+    #[inline]
+    pub(crate) fn replace_global(&mut self, global: Gc<DeclarativeEnvironment>) {
+        self.stack[0] = global;
+    }
+
     /// Extends the length of the next outer function environment to the number of compiled bindings.
     ///
     /// This is only useful when compiled bindings are added after the initial compilation (eval).
@@ -334,7 +402,7 @@ impl DeclarativeEnvironmentStack {
                             return slots;
                         }
                     }
-                    EnvironmentSlots::Global => return slots,
+                    EnvironmentSlots::Global | EnvironmentSlots::Module(_) => return slots,
                 }
             }
         }
@@ -444,6 +512,33 @@ impl DeclarativeEnvironmentStack {
         }));
     }
 
+    /// Push a module environment on the environments stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no environment exists on the stack.
+    pub(crate) fn push_module(
+        &mut self,
+        num_bindings: usize,
+        compile_environment: Gc<Cell<CompileTimeEnvironment>>,
+        module: Module,
+    ) {
+        let poisoned = self
+            .stack
+            .last()
+            .expect("global environment must always exist")
+            .poisoned
+            .borrow()
+            .to_owned();
+
+        self.stack.push(Gc::new(DeclarativeEnvironment {
+            bindings: Cell::new(vec![None; num_bindings]),
+            compile: compile_environment,
+            poisoned: Cell::new(poisoned),
+            slots: Some(EnvironmentSlots::Module(module)),
+        }));
+    }
+
     /// Pop environment from the environments stack.
     #[inline]
     pub(crate) fn pop(&mut self) -> Gc<DeclarativeEnvironment> {
@@ -542,11 +637,7 @@ impl DeclarativeEnvironmentStack {
         self.stack
             .get(environment_index)
             .expect("environment index must be in range")
-            .bindings
-            .borrow()
-            .get(binding_index)
-            .expect("binding index must be in range")
-            .clone()
+            .get_optional(binding_index)
     }
 
     /// Get the value of a binding by it's name.
@@ -728,11 +819,7 @@ pub(crate) struct BindingLocator {
 impl BindingLocator {
     /// Creates a new declarative binding locator that has knows indices.
     #[inline]
-    pub(in crate::environments) fn declarative(
-        name: Sym,
-        environment_index: usize,
-        binding_index: usize,
-    ) -> Self {
+    pub(crate) fn declarative(name: Sym, environment_index: usize, binding_index: usize) -> Self {
         Self {
             name,
             environment_index,
@@ -744,7 +831,7 @@ impl BindingLocator {
 
     /// Creates a binding locator that indicates that the binding is on the global object.
     #[inline]
-    pub(in crate::environments) fn global(name: Sym) -> Self {
+    pub(crate) fn global(name: Sym) -> Self {
         Self {
             name,
             environment_index: 0,
@@ -757,7 +844,7 @@ impl BindingLocator {
     /// Creates a binding locator that indicates that it was attempted to mutate an immutable binding.
     /// At runtime this should always produce a type error.
     #[inline]
-    pub(in crate::environments) fn mutate_immutable(name: Sym) -> Self {
+    pub(crate) fn mutate_immutable(name: Sym) -> Self {
         Self {
             name,
             environment_index: 0,
@@ -791,14 +878,22 @@ impl BindingLocator {
         self.binding_index
     }
 
+    /// Returns if the binding is an attempt to mutate an immutable binding.
+    #[inline]
+    pub(crate) fn is_mutate_immutable(&self) -> bool {
+        self.mutate_immutable
+    }
+
     /// Helper method to throws an error if the binding access is illegal.
     #[inline]
     pub(crate) fn throw_mutate_immutable(&self, context: &mut Context) -> JsResult<()> {
         if self.mutate_immutable {
-            context.throw_type_error(format!(
-                "cannot mutate an immutable binding '{}'",
-                context.interner().resolve_expect(self.name)
-            ))
+            Err(JsNativeError::typ()
+                .with_message(format!(
+                    "cannot mutate an immutable binding '{}'",
+                    context.interner().resolve_expect(self.name)
+                ))
+                .into())
         } else {
             Ok(())
         }
@@ -0,0 +1,432 @@
+//! Error-related types and conversions.
+//!
+//! Every fallible operation of the engine returns a [`JsResult`], whose error is a [`JsError`].
+//! A [`JsError`] is either a value thrown by JavaScript code (e.g. `throw 42`), or a
+//! [`JsNativeError`] thrown by the engine or the host. Native errors are only converted into
+//! JavaScript error objects when JavaScript code needs to observe them, which saves the cost of
+//! creating the objects for errors that are only inspected by Rust code.
+
+use crate::{
+    builtins::Array,
+    object::{JsObject, ObjectData},
+    vm::StackFrame,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::fmt;
+
+/// The error type returned by all operations related to the execution of JavaScript code.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{Context, JsNativeError, JsValue};
+/// let mut context = Context::default();
+///
+/// let error = context.eval("null.x").unwrap_err();
+/// assert_eq!(
+///     error.as_native().unwrap().message(),
+///     "cannot convert 'null' or 'undefined' to object"
+/// );
+///
+/// let error = context.eval("throw 42").unwrap_err();
+/// assert_eq!(error.as_opaque(), Some(&JsValue::new(42)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+pub struct JsError {
+    inner: Repr,
+}
+
+/// Internal representation of a [`JsError`].
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+enum Repr {
+    Native(JsNativeError),
+    Opaque(JsValue),
+}
+
+impl JsError {
+    /// Creates a new `JsError` from a native error.
+    #[inline]
+    pub fn from_native(error: JsNativeError) -> Self {
+        Self {
+            inner: Repr::Native(error),
+        }
+    }
+
+    /// Creates a new `JsError` from a value thrown by JavaScript code.
+    #[inline]
+    pub fn from_opaque(value: JsValue) -> Self {
+        Self {
+            inner: Repr::Opaque(value),
+        }
+    }
+
+    /// Converts the error into the value observed by JavaScript code when catching it.
+    ///
+    /// Native errors are converted into new error objects, so calling this twice on a native
+    /// error returns two different objects.
+    pub fn to_opaque(&self, context: &mut Context) -> JsValue {
+        match &self.inner {
+            Repr::Native(error) => error.to_opaque(context).into(),
+            Repr::Opaque(value) => value.clone(),
+        }
+    }
+
+    /// Converts the error into a native error.
+    ///
+    /// Thrown error objects are converted into the native error of the same kind, with their
+    /// `message` and `cause` properties.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the thrown value is not an error object, and any error thrown
+    /// while reading the properties of the error object.
+    pub fn try_native(&self, context: &mut Context) -> JsResult<JsNativeError> {
+        let value = match &self.inner {
+            Repr::Native(error) => return Ok(error.clone()),
+            Repr::Opaque(value) => value,
+        };
+
+        let object = match value.as_object() {
+            Some(object) if object.is_error() => object,
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("the thrown value is not an error object")
+                    .into())
+            }
+        };
+
+        let kind = JsNativeErrorKind::from_prototype(object, context);
+        let message = object
+            .get("message", context)?
+            .to_string(context)?
+            .to_std_string_escaped();
+        let cause = if object.has_own_property("cause", context)? {
+            Some(Box::new(Self::from_opaque(object.get("cause", context)?)))
+        } else {
+            None
+        };
+
+        Ok(JsNativeError {
+            kind,
+            message: message.into(),
+            cause,
+            stack: None,
+        })
+    }
+
+    /// Gets the native error, if this is a native error.
+    #[inline]
+    pub fn as_native(&self) -> Option<&JsNativeError> {
+        match &self.inner {
+            Repr::Native(error) => Some(error),
+            Repr::Opaque(_) => None,
+        }
+    }
+
+    /// Gets the thrown value, if this is a value thrown by JavaScript code.
+    #[inline]
+    pub fn as_opaque(&self) -> Option<&JsValue> {
+        match &self.inner {
+            Repr::Native(_) => None,
+            Repr::Opaque(value) => Some(value),
+        }
+    }
+
+    /// Records the stack trace of a native error, if it doesn't have one yet.
+    ///
+    /// The stack trace must be recorded before the frames that threw the error are left, since
+    /// native errors are converted into error objects later.
+    pub(crate) fn record_stack_trace(&mut self, context: &Context) {
+        if let Repr::Native(error) = &mut self.inner {
+            if error.stack.is_none() {
+                error.stack = Some(context.stack_trace().into_boxed_slice());
+            }
+        }
+    }
+}
+
+impl From<JsNativeError> for JsError {
+    #[inline]
+    fn from(error: JsNativeError) -> Self {
+        Self::from_native(error)
+    }
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            Repr::Native(error) => error.fmt(f),
+            Repr::Opaque(value) => value.display().fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for JsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.as_native()
+            .and_then(|error| error.cause())
+            .map(|cause| cause as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// An error created by the engine or the host, which is converted into a JavaScript error
+/// object only if JavaScript code catches it.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{JsError, JsNativeError, JsNativeErrorKind};
+/// let error = JsNativeError::typ().with_message("not a function");
+/// assert_eq!(error.kind(), &JsNativeErrorKind::Type);
+/// assert_eq!(error.to_string(), "TypeError: not a function");
+///
+/// let error: JsError = error.into();
+/// assert!(error.as_native().is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+pub struct JsNativeError {
+    kind: JsNativeErrorKind,
+    #[unsafe_ignore_trace]
+    message: Box<str>,
+    cause: Option<Box<JsError>>,
+    #[unsafe_ignore_trace]
+    stack: Option<Box<[StackFrame]>>,
+}
+
+impl JsNativeError {
+    fn new(kind: JsNativeErrorKind) -> Self {
+        Self {
+            kind,
+            message: "".into(),
+            cause: None,
+            stack: None,
+        }
+    }
+
+    /// Creates a new `AggregateError` with the given inner errors.
+    #[inline]
+    pub fn aggregate(errors: Vec<JsError>) -> Self {
+        Self::new(JsNativeErrorKind::Aggregate(errors))
+    }
+
+    /// Creates a new `Error`.
+    #[inline]
+    pub fn error() -> Self {
+        Self::new(JsNativeErrorKind::Error)
+    }
+
+    /// Creates a new `EvalError`.
+    #[inline]
+    pub fn eval() -> Self {
+        Self::new(JsNativeErrorKind::Eval)
+    }
+
+    /// Creates a new `RangeError`.
+    #[inline]
+    pub fn range() -> Self {
+        Self::new(JsNativeErrorKind::Range)
+    }
+
+    /// Creates a new `ReferenceError`.
+    #[inline]
+    pub fn reference() -> Self {
+        Self::new(JsNativeErrorKind::Reference)
+    }
+
+    /// Creates a new `SyntaxError`.
+    #[inline]
+    pub fn syntax() -> Self {
+        Self::new(JsNativeErrorKind::Syntax)
+    }
+
+    /// Creates a new `TypeError`.
+    ///
+    /// The name is shortened since `type` is a keyword.
+    #[inline]
+    pub fn typ() -> Self {
+        Self::new(JsNativeErrorKind::Type)
+    }
+
+    /// Creates a new `URIError`.
+    #[inline]
+    pub fn uri() -> Self {
+        Self::new(JsNativeErrorKind::Uri)
+    }
+
+    /// Sets the message of the error.
+    #[must_use]
+    #[inline]
+    pub fn with_message<M>(mut self, message: M) -> Self
+    where
+        M: Into<Box<str>>,
+    {
+        self.message = message.into();
+        self
+    }
+
+    /// Sets the cause of the error.
+    #[must_use]
+    #[inline]
+    pub fn with_cause<C>(mut self, cause: C) -> Self
+    where
+        C: Into<JsError>,
+    {
+        self.cause = Some(Box::new(cause.into()));
+        self
+    }
+
+    /// Gets the kind of the error.
+    #[inline]
+    pub fn kind(&self) -> &JsNativeErrorKind {
+        &self.kind
+    }
+
+    /// Gets the message of the error.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the cause of the error, if any.
+    #[inline]
+    pub fn cause(&self) -> Option<&JsError> {
+        self.cause.as_deref()
+    }
+
+    /// Converts the error into a new JavaScript error object.
+    pub fn to_opaque(&self, context: &mut Context) -> JsObject {
+        let constructors = context.intrinsics().constructors();
+        let prototype = match self.kind {
+            JsNativeErrorKind::Aggregate(_) => constructors.aggregate_error().prototype(),
+            JsNativeErrorKind::Error => constructors.error().prototype(),
+            JsNativeErrorKind::Eval => constructors.eval_error().prototype(),
+            JsNativeErrorKind::Range => constructors.range_error().prototype(),
+            JsNativeErrorKind::Reference => constructors.reference_error().prototype(),
+            JsNativeErrorKind::Syntax => constructors.syntax_error().prototype(),
+            JsNativeErrorKind::Type => constructors.type_error().prototype(),
+            JsNativeErrorKind::Uri => constructors.uri_error().prototype(),
+        };
+
+        let stack = self
+            .stack
+            .as_ref()
+            .map_or_else(|| context.stack_trace(), |stack| stack.to_vec());
+        let object = JsObject::from_proto_and_data(prototype, ObjectData::error(stack));
+
+        if !self.message.is_empty() {
+            object.create_non_enumerable_data_property_or_throw("message", &*self.message, context);
+        }
+
+        if let Some(cause) = &self.cause {
+            let cause = cause.to_opaque(context);
+            object.create_non_enumerable_data_property_or_throw("cause", cause, context);
+        }
+
+        if let JsNativeErrorKind::Aggregate(errors) = &self.kind {
+            let errors = errors
+                .iter()
+                .map(|error| error.to_opaque(context))
+                .collect::<Vec<_>>();
+            let errors = Array::create_array_from_list(errors, context);
+            object.create_non_enumerable_data_property_or_throw("errors", errors, context);
+        }
+
+        object
+    }
+}
+
+impl fmt::Display for JsNativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for JsNativeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.cause()
+            .map(|cause| cause as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// The kind of a [`JsNativeError`], which determines the constructor of the error objects it
+/// is converted to.
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+#[non_exhaustive]
+pub enum JsNativeErrorKind {
+    /// An `AggregateError`, which wraps the errors of multiple operations.
+    Aggregate(Vec<JsError>),
+    /// A generic `Error`.
+    Error,
+    /// An `EvalError`, related to the global `eval` function.
+    Eval,
+    /// A `RangeError`, for values outside of their valid range.
+    Range,
+    /// A `ReferenceError`, for references to undeclared variables.
+    Reference,
+    /// A `SyntaxError`, for invalid source text.
+    Syntax,
+    /// A `TypeError`, for values of an unexpected type.
+    Type,
+    /// A `URIError`, related to the global URI handling functions.
+    Uri,
+}
+
+impl JsNativeErrorKind {
+    /// Finds the kind of the native error matching the prototype of an error object, falling
+    /// back to a generic `Error`.
+    fn from_prototype(object: &JsObject, context: &mut Context) -> Self {
+        let prototype = match object.prototype().clone() {
+            Some(prototype) => prototype,
+            None => return Self::Error,
+        };
+        let constructors = context.intrinsics().constructors();
+        if prototype == constructors.aggregate_error().prototype() {
+            let errors = object
+                .get("errors", context)
+                .ok()
+                .and_then(|errors| errors.as_object().cloned())
+                .and_then(|errors| {
+                    let length = errors.length_of_array_like(context).ok()?;
+                    (0..length)
+                        .map(|i| errors.get(i, context).map(JsError::from_opaque).ok())
+                        .collect()
+                })
+                .unwrap_or_default();
+            Self::Aggregate(errors)
+        } else if prototype == constructors.eval_error().prototype() {
+            Self::Eval
+        } else if prototype == constructors.range_error().prototype() {
+            Self::Range
+        } else if prototype == constructors.reference_error().prototype() {
+            Self::Reference
+        } else if prototype == constructors.syntax_error().prototype() {
+            Self::Syntax
+        } else if prototype == constructors.type_error().prototype() {
+            Self::Type
+        } else if prototype == constructors.uri_error().prototype() {
+            Self::Uri
+        } else {
+            Self::Error
+        }
+    }
+}
+
+impl fmt::Display for JsNativeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Aggregate(_) => "AggregateError",
+            Self::Error => "Error",
+            Self::Eval => "EvalError",
+            Self::Range => "RangeError",
+            Self::Reference => "ReferenceError",
+            Self::Syntax => "SyntaxError",
+            Self::Type => "TypeError",
+            Self::Uri => "URIError",
+        })
+    }
+}
@@ -0,0 +1,122 @@
+//! Serialization of heap snapshots in the `.heapsnapshot` format of the Chrome DevTools.
+//!
+//! The format stores the nodes and the edges of the graph as flat arrays of numbers, with a
+//! fixed number of fields per node and per edge, described by the `meta` object of the
+//! snapshot. Strings are stored once in the `strings` array and referenced by index. The edges
+//! of each node follow the edges of the previous node, in the order of the nodes.
+
+use super::{HeapEdgeKind, HeapNodeKind, HeapSnapshot};
+use indexmap::IndexSet;
+use serde_json::{json, Value};
+
+/// The types of nodes, in the order of their indices in the `type` field of the nodes.
+const NODE_TYPES: [&str; 14] = [
+    "hidden",
+    "array",
+    "string",
+    "object",
+    "code",
+    "closure",
+    "regexp",
+    "number",
+    "native",
+    "synthetic",
+    "concatenated string",
+    "sliced string",
+    "symbol",
+    "bigint",
+];
+
+/// The types of edges, in the order of their indices in the `type` field of the edges.
+const EDGE_TYPES: [&str; 7] = [
+    "context", "element", "property", "internal", "hidden", "shortcut", "weak",
+];
+
+/// The number of fields of each node.
+const NODE_FIELD_COUNT: usize = 6;
+
+/// Converts `snapshot` to the JSON value of a `.heapsnapshot` file.
+pub(super) fn to_json(snapshot: &HeapSnapshot) -> Value {
+    let mut strings = IndexSet::new();
+    let mut string = |s: &str| {
+        if let Some(index) = strings.get_index_of(s) {
+            index
+        } else {
+            strings.insert_full(s.to_owned()).0
+        }
+    };
+
+    let mut nodes = Vec::with_capacity(snapshot.nodes().len() * NODE_FIELD_COUNT);
+    let mut edges = Vec::new();
+    for (index, node) in snapshot.nodes().iter().enumerate() {
+        let node_type = match node.kind() {
+            HeapNodeKind::Root => "synthetic",
+            HeapNodeKind::Object(kind) => match kind.as_str() {
+                "Array" => "array",
+                "RegExp" => "regexp",
+                "Function" | "BoundFunction" | "GeneratorFunction" | "AsyncGeneratorFunction" => {
+                    "closure"
+                }
+                _ => "object",
+            },
+            HeapNodeKind::Environment => "object",
+            HeapNodeKind::Internal => "hidden",
+        };
+        nodes.extend([
+            type_index(&NODE_TYPES, node_type),
+            string(node.name()),
+            // Node ids must be unique, and odd ids are used for JavaScript objects by V8.
+            index * 2 + 1,
+            node.size(),
+            node.edges().len(),
+            0,
+        ]);
+
+        for edge in node.edges() {
+            let (edge_type, name_or_index) = match edge.kind() {
+                HeapEdgeKind::Property(name) => ("property", string(name)),
+                HeapEdgeKind::Element(index) => ("element", *index as usize),
+                HeapEdgeKind::Binding(name) => ("context", string(name)),
+                HeapEdgeKind::Internal(name) => ("internal", string(name)),
+            };
+            edges.extend([
+                type_index(&EDGE_TYPES, edge_type),
+                name_or_index,
+                edge.to() * NODE_FIELD_COUNT,
+            ]);
+        }
+    }
+
+    json!({
+        "snapshot": {
+            "meta": {
+                "node_fields": ["type", "name", "id", "self_size", "edge_count", "trace_node_id"],
+                "node_types": [NODE_TYPES, "string", "number", "number", "number", "number"],
+                "edge_fields": ["type", "name_or_index", "to_node"],
+                "edge_types": [EDGE_TYPES, "string_or_number", "node"],
+                "trace_function_info_fields": [],
+                "trace_node_fields": [],
+                "sample_fields": [],
+                "location_fields": [],
+            },
+            "node_count": snapshot.nodes().len(),
+            "edge_count": edges.len() / 3,
+            "trace_function_count": 0,
+        },
+        "nodes": nodes,
+        "edges": edges,
+        "trace_function_infos": [],
+        "trace_tree": [],
+        "samples": [],
+        "locations": [],
+        "strings": strings.into_iter().collect::<Vec<_>>(),
+    })
+}
+
+/// Gets the index of `name` in `types`.
+fn type_index(types: &[&str], name: &str) -> usize {
+    types
+        .iter()
+        .position(|t| *t == name)
+        .expect("the type must be listed")
+}
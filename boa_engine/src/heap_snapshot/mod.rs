@@ -0,0 +1,444 @@
+//! Snapshots of the objects that are reachable from a [`Context`].
+//!
+//! A [`HeapSnapshot`] is the graph of the allocations of the garbage collector that are
+//! reachable from the roots of a context: its realm, the values and environments of the running
+//! code and the objects kept alive by `WeakRef`s. Each [`HeapNode`] is an object, the
+//! environment of a closure or an internal allocation of the engine, and each [`HeapEdge`] is a
+//! reference from one node to another, like a property or a captured binding.
+//!
+//! Snapshots can be exported to the `.heapsnapshot` format of the Chrome DevTools with
+//! [`HeapSnapshot::to_devtools_json`], to be inspected with their memory panel.
+//!
+//! Primitive values, like strings, aren't allocated by the garbage collector, so they aren't
+//! part of the graph; their memory is included in the size of the nodes that own them when it
+//! is known.
+
+mod devtools;
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    environments::DeclarativeEnvironment,
+    object::{JsObject, Object, ObjectKind, PrivateElement},
+    property::PropertyKey,
+    Context, JsValue,
+};
+use boa_gc::{Gc, GcAllocation};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{collections::VecDeque, mem};
+
+/// The graph of the allocations that are reachable from a [`Context`].
+///
+/// The first node is a synthetic root, which points to the roots of the context.
+///
+/// A snapshot is created with [`Context::heap_snapshot`].
+#[derive(Debug, Clone)]
+pub struct HeapSnapshot {
+    nodes: Vec<HeapNode>,
+}
+
+impl HeapSnapshot {
+    /// Walks the graph of the allocations that are reachable from `roots`, each of them
+    /// referenced from the synthetic root with an edge of the given name.
+    pub(crate) fn new(context: &Context, roots: Vec<(&str, Vec<GcAllocation>)>) -> Self {
+        let mut walker = Walker {
+            context,
+            nodes: vec![HeapNode {
+                kind: HeapNodeKind::Root,
+                name: String::from("(GC roots)"),
+                size: 0,
+                edges: Vec::new(),
+            }],
+            indices: FxHashMap::default(),
+            queue: VecDeque::new(),
+        };
+
+        let mut edges = Edges::default();
+        for (name, allocations) in roots {
+            for allocation in allocations {
+                edges.add(
+                    &mut walker,
+                    HeapEdgeKind::Internal(String::from(name)),
+                    allocation,
+                );
+            }
+        }
+        walker.nodes[0].edges = edges.edges;
+
+        while let Some((index, allocation)) = walker.queue.pop_front() {
+            walker.describe(index, &allocation);
+        }
+
+        Self {
+            nodes: walker.nodes,
+        }
+    }
+
+    /// Gets the nodes of the graph.
+    ///
+    /// The first node is the synthetic root, and the other nodes are in breadth-first order
+    /// from it. The targets of the edges are indices into this slice.
+    #[inline]
+    pub fn nodes(&self) -> &[HeapNode] {
+        &self.nodes
+    }
+
+    /// Gets the number of bytes of all the nodes of the graph.
+    pub fn total_size(&self) -> usize {
+        self.nodes.iter().map(HeapNode::size).sum()
+    }
+
+    /// Serializes the snapshot in the `.heapsnapshot` format of the Chrome DevTools.
+    ///
+    /// The result can be saved to a file with the `.heapsnapshot` extension and loaded in the
+    /// memory panel of the DevTools.
+    pub fn to_devtools_json(&self) -> String {
+        devtools::to_json(self).to_string()
+    }
+
+    /// Writes the snapshot to `writer` in the `.heapsnapshot` format of the Chrome DevTools.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `writer`.
+    pub fn write_devtools_json<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        serde_json::to_writer(writer, &devtools::to_json(self)).map_err(Into::into)
+    }
+}
+
+/// An allocation of a [`HeapSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeapNode {
+    kind: HeapNodeKind,
+    name: String,
+    size: usize,
+    edges: Vec<HeapEdge>,
+}
+
+impl HeapNode {
+    /// Gets the kind of the node.
+    #[inline]
+    pub fn kind(&self) -> &HeapNodeKind {
+        &self.kind
+    }
+
+    /// Gets the name of the node.
+    ///
+    /// Functions are named by their `name` property, ordinary objects by the name of their
+    /// constructor and the other objects by their kind. The other allocations are named by
+    /// their Rust type.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the approximate number of bytes owned by the node.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Gets the references from the node to other nodes.
+    #[inline]
+    pub fn edges(&self) -> &[HeapEdge] {
+        &self.edges
+    }
+}
+
+/// The kind of a [`HeapNode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeapNodeKind {
+    /// The synthetic root of the graph, which points to the roots of the context.
+    Root,
+    /// A JavaScript object, with the kind of its internal data, e.g. `Array` or `Function`.
+    Object(String),
+    /// A declarative environment, holding the bindings of a scope that closures capture.
+    Environment,
+    /// Another allocation of the engine, like compiled code or a property shape.
+    Internal,
+}
+
+/// A reference from a [`HeapNode`] to another node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeapEdge {
+    kind: HeapEdgeKind,
+    to: usize,
+}
+
+impl HeapEdge {
+    /// Gets the kind of the reference.
+    #[inline]
+    pub fn kind(&self) -> &HeapEdgeKind {
+        &self.kind
+    }
+
+    /// Gets the index of the referenced node in [`HeapSnapshot::nodes`].
+    #[inline]
+    pub fn to(&self) -> usize {
+        self.to
+    }
+}
+
+/// The kind of a [`HeapEdge`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeapEdgeKind {
+    /// A named property of an object, or its prototype as `__proto__`.
+    Property(String),
+    /// An indexed property of an object.
+    Element(u32),
+    /// A binding of an environment.
+    Binding(String),
+    /// Any other reference, with a description like `get x` for accessors, `#x` for private
+    /// elements or `context` for the environment of a closure.
+    Internal(String),
+}
+
+/// The state of the walk of the graph.
+struct Walker<'a> {
+    context: &'a Context,
+    nodes: Vec<HeapNode>,
+    /// The indices of the nodes of the allocations that were found, by address.
+    indices: FxHashMap<usize, usize>,
+    /// The nodes that were found but not described yet.
+    queue: VecDeque<(usize, GcAllocation)>,
+}
+
+impl Walker<'_> {
+    /// Gets the index of the node of `allocation`, adding it to the graph if it is new.
+    fn node(&mut self, allocation: GcAllocation) -> usize {
+        if let Some(&index) = self.indices.get(&allocation.address()) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(HeapNode {
+            kind: HeapNodeKind::Internal,
+            name: String::new(),
+            size: 0,
+            edges: Vec::new(),
+        });
+        self.indices.insert(allocation.address(), index);
+        self.queue.push_back((index, allocation));
+        index
+    }
+
+    /// Fills the node at `index` with the description of `allocation`.
+    fn describe(&mut self, index: usize, allocation: &GcAllocation) {
+        let mut edges = Edges::default();
+        let (kind, name, size) = if let Some(object) = allocation
+            .downcast::<boa_gc::Cell<Object>>()
+            .map(JsObject::from_inner)
+        {
+            self.describe_object(&object, &mut edges)
+        } else if let Some(environment) = allocation.downcast::<DeclarativeEnvironment>() {
+            self.describe_environment(&environment, allocation.size(), &mut edges)
+        } else {
+            (
+                HeapNodeKind::Internal,
+                short_type_name(allocation.type_name()),
+                allocation.size(),
+            )
+        };
+
+        // Everything else the allocation points to, like the code of a function or the
+        // entries of a `Map`, is an internal reference.
+        for target in allocation.edges() {
+            let name = if target.downcast::<DeclarativeEnvironment>().is_some() {
+                String::from("context")
+            } else {
+                short_type_name(target.type_name())
+            };
+            edges.add_new(self, HeapEdgeKind::Internal(name), target);
+        }
+
+        let node = &mut self.nodes[index];
+        node.kind = kind;
+        node.name = name;
+        node.size = size;
+        node.edges = edges.edges;
+    }
+
+    fn describe_object(
+        &mut self,
+        object: &JsObject,
+        edges: &mut Edges,
+    ) -> (HeapNodeKind, String, usize) {
+        let object = object.borrow();
+
+        for (key, property) in object.properties().iter() {
+            if let Some(value) = property.value() {
+                let kind = match &key {
+                    PropertyKey::Index(index) => HeapEdgeKind::Element(*index),
+                    key => HeapEdgeKind::Property(key.to_string()),
+                };
+                edges.add_value(self, kind, value);
+            }
+            if let Some(get) = property.get() {
+                edges.add_value(self, HeapEdgeKind::Internal(format!("get {key}")), get);
+            }
+            if let Some(set) = property.set() {
+                edges.add_value(self, HeapEdgeKind::Internal(format!("set {key}")), set);
+            }
+        }
+
+        if let Some(prototype) = object.prototype() {
+            edges.add_object(
+                self,
+                HeapEdgeKind::Property(String::from("__proto__")),
+                prototype,
+            );
+        }
+
+        let interner = self.context.interner();
+        for (name, element) in object.private_elements() {
+            let name = format!("#{}", interner.resolve_expect(name));
+            match element {
+                PrivateElement::Field(value) => {
+                    edges.add_value(self, HeapEdgeKind::Internal(name), value);
+                }
+                PrivateElement::Method(method) => {
+                    edges.add_object(self, HeapEdgeKind::Internal(name), method);
+                }
+                PrivateElement::Accessor { getter, setter } => {
+                    if let Some(getter) = getter {
+                        edges.add_object(
+                            self,
+                            HeapEdgeKind::Internal(format!("get {name}")),
+                            getter,
+                        );
+                    }
+                    if let Some(setter) = setter {
+                        edges.add_object(
+                            self,
+                            HeapEdgeKind::Internal(format!("set {name}")),
+                            setter,
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut size = object.properties().charged_bytes();
+        if let Some(buffer) = object.as_array_buffer() {
+            size += buffer.data_charge.bytes();
+        }
+
+        (
+            HeapNodeKind::Object(object.kind().to_string()),
+            object_name(&object),
+            size,
+        )
+    }
+
+    fn describe_environment(
+        &mut self,
+        environment: &Gc<DeclarativeEnvironment>,
+        allocation_size: usize,
+        edges: &mut Edges,
+    ) -> (HeapNodeKind, String, usize) {
+        let bindings = environment.bindings();
+        let compile = environment.compile_env();
+        let compile = compile.borrow();
+
+        let mut names = compile.bindings().collect::<Vec<_>>();
+        names.sort_by_key(|(_, binding)| binding.index);
+        let interner = self.context.interner();
+        for (name, binding) in names {
+            if let Some(Some(value)) = bindings.get(binding.index) {
+                let name = interner.resolve_expect(name).to_owned();
+                edges.add_value(self, HeapEdgeKind::Binding(name), value);
+            }
+        }
+
+        let size = allocation_size + bindings.capacity() * mem::size_of::<Option<JsValue>>();
+        (HeapNodeKind::Environment, String::from("Environment"), size)
+    }
+}
+
+/// The edges of a node.
+#[derive(Default)]
+struct Edges {
+    edges: Vec<HeapEdge>,
+    /// The addresses of the allocations that the node already has an edge to.
+    targets: FxHashSet<usize>,
+}
+
+impl Edges {
+    /// Adds an edge to `allocation`.
+    fn add(&mut self, walker: &mut Walker<'_>, kind: HeapEdgeKind, allocation: GcAllocation) {
+        self.targets.insert(allocation.address());
+        let to = walker.node(allocation);
+        self.edges.push(HeapEdge { kind, to });
+    }
+
+    /// Adds an edge to `allocation`, unless the node already has an edge to it.
+    fn add_new(&mut self, walker: &mut Walker<'_>, kind: HeapEdgeKind, allocation: GcAllocation) {
+        if !self.targets.contains(&allocation.address()) {
+            self.add(walker, kind, allocation);
+        }
+    }
+
+    /// Adds an edge to `object`.
+    fn add_object(&mut self, walker: &mut Walker<'_>, kind: HeapEdgeKind, object: &JsObject) {
+        self.add(walker, kind, GcAllocation::new(object.inner()));
+    }
+
+    /// Adds an edge to `value` if it is an object; other values aren't nodes of the graph.
+    fn add_value(&mut self, walker: &mut Walker<'_>, kind: HeapEdgeKind, value: &JsValue) {
+        if let Some(object) = value.as_object() {
+            self.add_object(walker, kind, object);
+        }
+    }
+}
+
+/// Gets the name of the node of `object`.
+fn object_name(object: &Object) -> String {
+    match object.kind() {
+        ObjectKind::Function(_)
+        | ObjectKind::BoundFunction(_)
+        | ObjectKind::GeneratorFunction(_)
+        | ObjectKind::AsyncGeneratorFunction(_) => own_string(object, "name")
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| String::from("(anonymous)")),
+        ObjectKind::Ordinary => object
+            .prototype()
+            .as_ref()
+            .and_then(|prototype| {
+                let constructor = prototype
+                    .try_borrow()
+                    .ok()?
+                    .properties()
+                    .get(&"constructor".into())?;
+                let constructor = constructor.value()?.as_object()?.try_borrow().ok()?;
+                own_string(&constructor, "name")
+            })
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| String::from("Object")),
+        kind => kind.to_string(),
+    }
+}
+
+/// Gets the own data property `key` of `object`, if it is a string.
+fn own_string(object: &Object, key: &str) -> Option<String> {
+    let property = object.properties().get(&key.into())?;
+    let string = property.value()?.as_string()?.to_std_string_escaped();
+    Some(string)
+}
+
+/// Removes the paths from the names of the types in `type_name`, e.g.
+/// `boa_gc::Gc<boa_engine::vm::CodeBlock>` becomes `Gc<CodeBlock>`.
+fn short_type_name(type_name: &str) -> String {
+    let mut short = String::with_capacity(type_name.len());
+    let mut segment = String::new();
+    for c in type_name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            short.push_str(segment.rsplit("::").next().unwrap_or_default());
+            segment.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(segment.rsplit("::").next().unwrap_or_default());
+    short
+}
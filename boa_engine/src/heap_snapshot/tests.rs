@@ -0,0 +1,132 @@
+use super::{HeapEdgeKind, HeapNode, HeapNodeKind, HeapSnapshot};
+use crate::Context;
+
+/// Finds the target of the edge of `node` of the given kind.
+fn target<'a>(
+    snapshot: &'a HeapSnapshot,
+    node: &HeapNode,
+    kind: &HeapEdgeKind,
+) -> Option<&'a HeapNode> {
+    node.edges()
+        .iter()
+        .find(|edge| edge.kind() == kind)
+        .map(|edge| &snapshot.nodes()[edge.to()])
+}
+
+fn property(name: &str) -> HeapEdgeKind {
+    HeapEdgeKind::Property(String::from(name))
+}
+
+#[test]
+fn heap_snapshot_edges() {
+    let mut context = Context::default();
+    context
+        .eval(
+            r"
+            class Point {
+                #tag = [1];
+                constructor(x) { this.x = x; }
+            }
+            var point = new Point({});
+            var counter = (() => {
+                let count = { value: 0 };
+                return function increment() { return count.value++; };
+            })();
+            var list = [point];
+        ",
+        )
+        .unwrap();
+
+    let snapshot = context.heap_snapshot();
+    let nodes = snapshot.nodes();
+    assert_eq!(nodes[0].kind(), &HeapNodeKind::Root);
+    assert!(nodes[0].edges().iter().all(|edge| edge.to() < nodes.len()));
+
+    let global = target(
+        &snapshot,
+        &nodes[0],
+        &HeapEdgeKind::Internal(String::from("global")),
+    )
+    .unwrap();
+    assert_eq!(global.kind(), &HeapNodeKind::Object(String::from("Global")));
+
+    // Class instances are named by their constructor, and point to their prototype.
+    let point = target(&snapshot, global, &property("point")).unwrap();
+    assert_eq!(point.name(), "Point");
+    assert_eq!(
+        point.kind(),
+        &HeapNodeKind::Object(String::from("Ordinary"))
+    );
+    assert!(point.size() > 0);
+    let prototype = target(&snapshot, point, &property("__proto__")).unwrap();
+    assert!(target(&snapshot, prototype, &property("constructor")).is_some());
+    let x = target(&snapshot, point, &property("x")).unwrap();
+    assert_eq!(x.name(), "Object");
+    let tag = target(
+        &snapshot,
+        point,
+        &HeapEdgeKind::Internal(String::from("#tag")),
+    )
+    .unwrap();
+    assert_eq!(tag.kind(), &HeapNodeKind::Object(String::from("Array")));
+
+    // Elements are indexed edges.
+    let list = target(&snapshot, global, &property("list")).unwrap();
+    assert_eq!(
+        target(&snapshot, list, &HeapEdgeKind::Element(0)).map(HeapNode::name),
+        Some("Point")
+    );
+
+    // Closures point to the environments of the bindings they capture.
+    let counter = target(&snapshot, global, &property("counter")).unwrap();
+    assert_eq!(counter.name(), "increment");
+    assert!(counter.edges().iter().any(|edge| {
+        let environment = &nodes[edge.to()];
+        environment.kind() == &HeapNodeKind::Environment
+            && target(
+                &snapshot,
+                environment,
+                &HeapEdgeKind::Binding(String::from("count")),
+            )
+            .is_some()
+    }));
+
+    // Every reachable object is a single node.
+    let points = nodes.iter().filter(|node| node.name() == "Point").count();
+    assert_eq!(points, 2, "the instance and the class");
+}
+
+#[test]
+fn heap_snapshot_devtools_json() {
+    let mut context = Context::default();
+    context.eval("var o = { a: [1, 2], f() {} };").unwrap();
+
+    let snapshot = context.heap_snapshot();
+    let json: serde_json::Value = serde_json::from_str(&snapshot.to_devtools_json()).unwrap();
+
+    let meta = &json["snapshot"]["meta"];
+    let node_fields = meta["node_fields"].as_array().unwrap().len();
+    let edge_fields = meta["edge_fields"].as_array().unwrap().len();
+    let nodes = json["nodes"].as_array().unwrap();
+    let edges = json["edges"].as_array().unwrap();
+    let strings = json["strings"].as_array().unwrap();
+
+    assert_eq!(nodes.len(), snapshot.nodes().len() * node_fields);
+    assert_eq!(json["snapshot"]["node_count"], snapshot.nodes().len());
+    assert_eq!(edges.len() / edge_fields, json["snapshot"]["edge_count"]);
+
+    // The edge counts of the nodes add up to the edges, which point to the start of a node.
+    let edge_count: u64 = nodes
+        .chunks(node_fields)
+        .map(|node| node[4].as_u64().unwrap())
+        .sum();
+    assert_eq!(edge_count as usize * edge_fields, edges.len());
+    for edge in edges.chunks(edge_fields) {
+        let to_node = edge[2].as_u64().unwrap() as usize;
+        assert_eq!(to_node % node_fields, 0);
+        assert!(to_node < nodes.len());
+    }
+
+    assert_eq!(strings[nodes[1].as_u64().unwrap() as usize], "(GC roots)");
+    assert!(strings.contains(&serde_json::Value::from("a")));
+}
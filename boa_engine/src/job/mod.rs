@@ -0,0 +1,254 @@
+//! Boa's implementation of ECMAScript jobs and the job queue of the host.
+//!
+//! Jobs are scheduled with [`Context::enqueue_job`], which hands them to the [`JobQueue`] of the
+//! context. The default queue, [`SimpleJobQueue`], runs all pending jobs in FIFO order every time
+//! [`Context::run_jobs`] is called, which the context also does at the end of every script
+//! execution. Hosts with their own event loop can provide a different queue through
+//! [`ContextBuilder::job_queue`][crate::context::ContextBuilder::job_queue] to decide when
+//! jobs run.
+//!
+//! Native functions can also hand a Rust [`Future`] to the queue with
+//! [`Context::enqueue_future_job`]. The queue polls it until it completes and then runs the
+//! [`NativeJob`] it produces, which is how native async functions settle their promises.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{prelude::JsObject, Context, JsResult, JsValue};
+use boa_gc::{Finalize, Trace};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{self, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+/// `JobCallback` records
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-jobcallback-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JobCallback {
+    callback: JsObject,
+}
+
+impl JobCallback {
+    /// `HostMakeJobCallback ( callback )`
+    ///
+    /// The host-defined abstract operation `HostMakeJobCallback` takes argument `callback` (a
+    /// function object) and returns a `JobCallback` Record.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostmakejobcallback
+    pub fn make_job_callback(callback: JsObject) -> Self {
+        // 1. Return the JobCallback Record { [[Callback]]: callback, [[HostDefined]]: empty }.
+        Self { callback }
+    }
+
+    /// `HostCallJobCallback ( jobCallback, V, argumentsList )`
+    ///
+    /// The host-defined abstract operation `HostCallJobCallback` takes arguments `jobCallback` (a
+    /// `JobCallback` Record), `V` (an ECMAScript language value), and `argumentsList` (a `List` of
+    /// ECMAScript language values) and returns either a normal completion containing an ECMAScript
+    /// language value or a throw completion.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostcalljobcallback
+    pub fn call_job_callback(
+        &self,
+        v: &JsValue,
+        arguments_list: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // It must perform and return the result of Call(jobCallback.[[Callback]], V, argumentsList).
+
+        // 1. Assert: IsCallable(jobCallback.[[Callback]]) is true.
+        assert!(
+            self.callback.is_callable(),
+            "the callback of the job callback was not callable"
+        );
+
+        // 2. Return ? Call(jobCallback.[[Callback]], V, argumentsList).
+        self.callback.__call__(v, arguments_list, context)
+    }
+}
+
+/// A job implemented in Rust, that has access to the [`Context`] when it runs.
+pub struct NativeJob {
+    #[allow(clippy::type_complexity)]
+    function: Box<dyn FnOnce(&mut Context) -> JsResult<JsValue>>,
+}
+
+impl NativeJob {
+    /// Creates a new `NativeJob` from a closure.
+    pub fn new<F>(function: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    {
+        Self {
+            function: Box::new(function),
+        }
+    }
+
+    /// Runs the job.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the closure of the job, if any.
+    pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
+        (self.function)(context)
+    }
+}
+
+impl Debug for NativeJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NativeJob")
+    }
+}
+
+/// A Rust [`Future`] that produces a [`NativeJob`] once it completes.
+///
+/// Futures can't borrow the [`Context`] while they are pending, so everything that needs the
+/// context must be done by the returned job.
+pub type FutureJob = Pin<Box<dyn Future<Output = NativeJob> + 'static>>;
+
+/// A host hook that schedules the jobs of a [`Context`].
+///
+/// This is Boa's version of the [`HostEnqueuePromiseJob`][spec] host hook, extended to also
+/// drive the execution of the enqueued jobs. Every job enqueued with [`Context::enqueue_job`]
+/// is passed to [`JobQueue::enqueue_promise_job`], and [`Context::run_jobs`] calls
+/// [`JobQueue::run_jobs`].
+///
+/// Jobs are run by calling [`JobCallback::call_job_callback`] with `undefined` as `this` and
+/// no arguments. Implementors must run the jobs in the order they were enqueued, as required by
+/// the specification.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+pub trait JobQueue: Debug {
+    /// Enqueues a new job to be run later.
+    fn enqueue_promise_job(&self, job: JobCallback, context: &mut Context);
+
+    /// Enqueues a future, whose [`NativeJob`] must be run once the future completes.
+    ///
+    /// Queues are free to decide how and when the future is polled, e.g. by spawning it on the
+    /// executor of the host.
+    fn enqueue_future_job(&self, future: FutureJob, context: &mut Context);
+
+    /// Runs the enqueued jobs, including the jobs enqueued while running them.
+    ///
+    /// Queues that let the host decide when each job runs may choose to do nothing here.
+    ///
+    /// Every job is a separate synchronous execution, so queues should call
+    /// [`Context::clear_kept_objects`] after each job they run.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first job that throws, if any.
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()>;
+}
+
+/// A job queue that runs all pending jobs in FIFO order when asked to run them.
+///
+/// Futures are polled on the current thread by [`JobQueue::run_jobs`], which never blocks:
+/// futures that are still pending once no other job can run are left in the queue, and polled
+/// again by the next call. Pending futures unpark the thread that last polled them when they
+/// can make progress, so a host can call [`std::thread::park`] between calls to
+/// [`Context::run_jobs`] while it waits for them.
+///
+/// This is the default job queue of a [`Context`].
+#[derive(Default)]
+pub struct SimpleJobQueue {
+    jobs: RefCell<VecDeque<JobCallback>>,
+    futures: RefCell<VecDeque<FutureJob>>,
+}
+
+impl Debug for SimpleJobQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimpleJobQueue")
+            .field("jobs", &self.jobs)
+            .field("futures", &self.futures.borrow().len())
+            .finish()
+    }
+}
+
+impl SimpleJobQueue {
+    /// Creates a new, empty `SimpleJobQueue`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl JobQueue for SimpleJobQueue {
+    fn enqueue_promise_job(&self, job: JobCallback, _context: &mut Context) {
+        self.jobs.borrow_mut().push_back(job);
+    }
+
+    fn enqueue_future_job(&self, future: FutureJob, _context: &mut Context) {
+        self.futures.borrow_mut().push_back(future);
+    }
+
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()> {
+        let waker = Waker::from(Arc::new(ThreadWaker {
+            thread: thread::current(),
+        }));
+        let mut task_context = task::Context::from_waker(&waker);
+
+        loop {
+            // The borrow must end before running the job, since the job can enqueue new jobs.
+            let next_job = || self.jobs.borrow_mut().pop_front();
+            while let Some(job) = next_job() {
+                let result = job.call_job_callback(&JsValue::undefined(), &[], context);
+                context.clear_kept_objects();
+                result?;
+            }
+
+            // The jobs of the completed futures can enqueue more jobs, so the futures are polled
+            // again until none of them completes.
+            let mut futures = std::mem::take(&mut *self.futures.borrow_mut());
+            let mut completed = false;
+            while let Some(mut future) = futures.pop_front() {
+                match future.as_mut().poll(&mut task_context) {
+                    Poll::Ready(job) => {
+                        completed = true;
+                        let result = job.call(context);
+                        context.clear_kept_objects();
+                        if let Err(err) = result {
+                            self.futures.borrow_mut().extend(futures);
+                            return Err(err);
+                        }
+                    }
+                    Poll::Pending => self.futures.borrow_mut().push_back(future),
+                }
+            }
+
+            if !completed {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// A waker that unparks the thread running the jobs of a [`SimpleJobQueue`].
+struct ThreadWaker {
+    thread: Thread,
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.thread.unpark();
+    }
+}
@@ -0,0 +1,228 @@
+use super::{FutureJob, JobCallback, JobQueue};
+use crate::{error::JsNativeError, object::FunctionBuilder, Context, JsResult, JsValue};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A job queue that only runs its jobs when the host asks for them one at a time.
+#[derive(Debug, Default)]
+struct ManualJobQueue(RefCell<VecDeque<JobCallback>>);
+
+impl ManualJobQueue {
+    fn run_next(&self, context: &mut Context) -> Option<JsResult<JsValue>> {
+        let job = self.0.borrow_mut().pop_front()?;
+        Some(job.call_job_callback(&JsValue::undefined(), &[], context))
+    }
+}
+
+impl JobQueue for ManualJobQueue {
+    fn enqueue_promise_job(&self, job: JobCallback, _context: &mut Context) {
+        self.0.borrow_mut().push_back(job);
+    }
+
+    fn enqueue_future_job(&self, _future: FutureJob, _context: &mut Context) {
+        unimplemented!("the manual job queue does not support futures")
+    }
+
+    fn run_jobs(&self, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
+}
+
+/// A future that completes with `value` after another thread wakes it up.
+struct Delay {
+    value: f64,
+    state: Arc<Mutex<(bool, Option<Waker>)>>,
+}
+
+impl Delay {
+    fn new(value: f64) -> Self {
+        let state = Arc::new(Mutex::new((false, None::<Waker>)));
+        let thread_state = state.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            let mut state = thread_state.lock().unwrap();
+            state.0 = true;
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        });
+        Self { value, state }
+    }
+}
+
+impl Future for Delay {
+    type Output = f64;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<f64> {
+        let mut state = self.state.lock().unwrap();
+        if state.0 {
+            Poll::Ready(self.value)
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn promise_jobs_run_after_execution() {
+    let mut context = Context::default();
+    context
+        .eval("var result = 0; Promise.resolve(1).then(v => { result = v; });")
+        .unwrap();
+    assert_eq!(context.eval("result").unwrap(), JsValue::new(1));
+}
+
+#[test]
+fn custom_job_queue() {
+    let queue = Rc::new(ManualJobQueue::default());
+    let mut context = Context::builder().job_queue(queue.clone()).build();
+
+    context
+        .eval("var result = []; Promise.resolve().then(() => result.push(1)).then(() => result.push(2));")
+        .unwrap();
+    assert_eq!(context.eval("result.length").unwrap(), JsValue::new(0));
+
+    assert!(queue.run_next(&mut context).unwrap().is_ok());
+    assert_eq!(context.eval("result.length").unwrap(), JsValue::new(1));
+
+    assert!(queue.run_next(&mut context).unwrap().is_ok());
+    assert_eq!(context.eval("result.join()").unwrap(), JsValue::new("1,2"));
+    assert!(queue.run_next(&mut context).is_none());
+}
+
+#[test]
+fn enqueue_host_job() {
+    let mut context = Context::default();
+    context
+        .eval("var log = []; Promise.resolve().then(() => log.push('promise'));")
+        .unwrap();
+
+    let job = FunctionBuilder::closure(&mut context, |_, _, context| {
+        context.eval("log.push('host')")
+    })
+    .build();
+    context.enqueue_job(JobCallback::make_job_callback(job.into()));
+    context
+        .eval("Promise.resolve().then(() => log.push('after'));")
+        .unwrap();
+
+    assert_eq!(
+        context.eval("log.join()").unwrap(),
+        JsValue::new("promise,host,after")
+    );
+}
+
+#[test]
+fn run_jobs_reports_errors() {
+    let mut context = Context::default();
+    let job = FunctionBuilder::closure(&mut context, |_, _, _| {
+        Err(JsNativeError::typ().with_message("job failed").into())
+    })
+    .build();
+    context.enqueue_job(JobCallback::make_job_callback(job.into()));
+
+    assert!(context.run_jobs().is_err());
+    assert!(context.run_jobs().is_ok());
+}
+
+#[test]
+fn async_native_function() {
+    let mut context = Context::default();
+    context.register_global_async_function("delayed", 1, |_, args, context| {
+        let value = args[0].to_number(context);
+        async move {
+            let value = value?;
+            if value < 0.0 {
+                return Err(JsNativeError::range().with_message("negative delay").into());
+            }
+            Ok(JsValue::new(Delay::new(value).await + 1.0))
+        }
+    });
+
+    context
+        .eval(
+            r#"
+            var log = [];
+            var promise = delayed(41);
+            log.push(promise instanceof Promise);
+            promise.then(value => log.push(value));
+            delayed(-1).catch(error => log.push(error.message));
+            log.push("sync");
+        "#,
+        )
+        .unwrap();
+    assert_eq!(
+        context.eval("log.join()").unwrap(),
+        JsValue::new("true,sync,negative delay")
+    );
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while context.eval("log.length").unwrap() != JsValue::new(4) {
+        assert!(Instant::now() < deadline, "the future never completed");
+        thread::park_timeout(Duration::from_millis(100));
+        context.run_jobs().unwrap();
+    }
+    assert_eq!(
+        context.eval("log.join()").unwrap(),
+        JsValue::new("true,sync,negative delay,42")
+    );
+}
+
+#[test]
+fn pending_futures_do_not_block_execution() {
+    let mut context = Context::default();
+    context.register_global_async_function("never", 0, |_, _, _| async {
+        std::future::pending::<()>().await;
+        Ok(JsValue::undefined())
+    });
+
+    context
+        .eval("var settled = false; never().then(() => { settled = true; });")
+        .unwrap();
+    context.run_jobs().unwrap();
+    assert_eq!(context.eval("settled").unwrap(), JsValue::new(false));
+}
+
+#[test]
+fn async_native_function_with_state() {
+    #[derive(Debug, Default)]
+    struct Client {
+        requests: RefCell<Vec<String>>,
+    }
+
+    let client = Rc::new(Client::default());
+    let mut context = Context::default();
+    let captured = client.clone();
+    context.register_global_async_function("fetch", 1, move |_, args, context| {
+        let url = args[0].to_string(context);
+        let client = captured.clone();
+        async move {
+            let url = url?.to_std_string_escaped();
+            client.requests.borrow_mut().push(url.clone());
+            Ok(JsValue::new(url.len()))
+        }
+    });
+
+    context
+        .eval(
+            r#"
+            var lengths = [];
+            fetch("a").then((length) => lengths.push(length));
+            fetch("bcd").then((length) => lengths.push(length));
+            "#,
+        )
+        .unwrap();
+
+    assert_eq!(*client.requests.borrow(), ["a", "bcd"]);
+    assert_eq!(context.eval("lengths.join()").unwrap(), JsValue::new("1,3"));
+}
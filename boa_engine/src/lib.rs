@@ -81,7 +81,10 @@ pub mod bytecompiler;
 pub mod class;
 pub mod context;
 pub mod environments;
+pub mod error;
+pub mod heap_snapshot;
 pub mod job;
+pub mod module;
 pub mod object;
 pub mod property;
 pub mod realm;
@@ -96,7 +99,11 @@ mod tests;
 
 /// A convenience module that re-exports the most commonly-used Boa APIs
 pub mod prelude {
-    pub use crate::{object::JsObject, Context, JsBigInt, JsResult, JsString, JsValue};
+    pub use crate::{
+        error::{JsError, JsNativeError, JsNativeErrorKind},
+        object::JsObject,
+        Context, JsBigInt, JsResult, JsString, JsValue,
+    };
 }
 
 use std::result::Result as StdResult;
@@ -104,11 +111,16 @@ use std::result::Result as StdResult;
 // Export things to root level
 #[doc(inline)]
 pub use crate::{
-    bigint::JsBigInt, context::Context, string::JsString, symbol::JsSymbol, value::JsValue,
+    bigint::JsBigInt,
+    context::Context,
+    error::{JsError, JsNativeError, JsNativeErrorKind},
+    string::JsString,
+    symbol::JsSymbol,
+    value::JsValue,
 };
 
 /// The result of a Javascript expression is represented like this so it can succeed (`Ok`) or fail (`Err`)
-pub type JsResult<T> = StdResult<T, JsValue>;
+pub type JsResult<T> = StdResult<T, JsError>;
 
 /// Execute the code using an existing `Context`.
 ///
@@ -118,10 +130,10 @@ pub(crate) fn forward<S>(context: &mut Context, src: S) -> String
 where
     S: AsRef<[u8]>,
 {
-    context.eval(src.as_ref()).map_or_else(
-        |e| format!("Uncaught {}", e.display()),
-        |v| v.display().to_string(),
-    )
+    match context.eval(src.as_ref()) {
+        Ok(v) => v.display().to_string(),
+        Err(e) => format!("Uncaught {}", e.to_opaque(context).display()),
+    }
 }
 
 /// Execute the code using an existing Context.
@@ -150,9 +162,10 @@ pub(crate) fn forward_val<T: AsRef<[u8]>>(context: &mut Context, src: T) -> JsRe
 pub(crate) fn exec<T: AsRef<[u8]>>(src: T) -> String {
     let src_bytes: &[u8] = src.as_ref();
 
-    match Context::default().eval(src_bytes) {
+    let mut context = Context::default();
+    match context.eval(src_bytes) {
         Ok(value) => value.display().to_string(),
-        Err(error) => error.display().to_string(),
+        Err(error) => error.to_opaque(&mut context).display().to_string(),
     }
 }
 
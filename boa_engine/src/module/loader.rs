@@ -0,0 +1,280 @@
+//! Module loaders, which the host uses to resolve and load the modules imported by other modules.
+
+use super::Module;
+use crate::{error::JsNativeError, realm::Realm, Context, JsResult};
+use rustc_hash::FxHashMap;
+use std::{
+    fmt::Debug,
+    path::{Component, Path, PathBuf},
+};
+
+/// A host hook that loads the modules imported by other modules.
+///
+/// This is Boa's version of the [`HostLoadImportedModule`][spec] host hook. Implementors must
+/// return the same module record every time they are asked to load the same module from the same
+/// realm, otherwise the module would be evaluated more than once.
+///
+/// The loader is called with the realm of `referrer` as the current realm, so a module parsed by
+/// [`Module::parse`] is created in that realm. Modules must not be shared between realms, since a
+/// module is evaluated in its own realm and holds on to its builtins: loaders that cache modules
+/// should cache them per realm, for example with [`Realm::loaded_module`].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+pub trait ModuleLoader: Debug {
+    /// Loads the module requested by `referrer` using the module specifier `specifier`.
+    ///
+    /// The returned module only needs to be parsed, since the engine takes care of loading its
+    /// own dependencies, linking it and evaluating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the module cannot be found or if its source text is not valid.
+    fn load_imported_module(
+        &self,
+        referrer: &Module,
+        specifier: &str,
+        context: &mut Context,
+    ) -> JsResult<Module>;
+}
+
+/// A module loader that refuses to load any module.
+///
+/// This is the default module loader of a [`Context`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdleModuleLoader;
+
+impl ModuleLoader for IdleModuleLoader {
+    fn load_imported_module(
+        &self,
+        _referrer: &Module,
+        specifier: &str,
+        _context: &mut Context,
+    ) -> JsResult<Module> {
+        Err(JsNativeError::typ()
+            .with_message(format!(
+                "cannot load module '{specifier}': module loading is disabled"
+            ))
+            .into())
+    }
+}
+
+/// A module loader that loads modules from the file system.
+///
+/// Relative specifiers (starting with `./` or `../`) are resolved against the directory of the
+/// importing module, while all other specifiers are resolved against the root directory of the
+/// loader. Modules cannot be loaded from outside of the root directory, even through symbolic
+/// links.
+///
+/// Every realm gets its own copy of a module, which is cached in the realm by its canonical
+/// path, so modules whose path is not valid UTF-8 cannot be loaded. The entry module of a module graph should be parsed with
+/// [`SimpleModuleLoader::parse_entry_module`], so that it is cached too and the modules that
+/// import it back get the same module.
+///
+/// # Examples
+/// ```no_run
+///# use boa_engine::{module::SimpleModuleLoader, Context};
+///# use std::{path::Path, rc::Rc};
+/// let loader = Rc::new(SimpleModuleLoader::new("scripts").unwrap());
+/// let mut context = Context::builder().module_loader(loader.clone()).build();
+///
+/// let path = Path::new("scripts/main.js");
+/// let source = std::fs::read(path).unwrap();
+/// let module = loader.parse_entry_module(source, path, &mut context).unwrap();
+/// module.load(&mut context).unwrap();
+/// module.link(&mut context).unwrap();
+/// module.evaluate(&mut context).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct SimpleModuleLoader {
+    root: PathBuf,
+}
+
+impl SimpleModuleLoader {
+    /// Creates a new `SimpleModuleLoader` that loads modules from the directory `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `root` cannot be canonicalized.
+    pub fn new<P: AsRef<Path>>(root: P) -> std::io::Result<Self> {
+        Ok(Self {
+            root: root.as_ref().canonicalize()?,
+        })
+    }
+
+    /// Parses the source text of the module at `path` as the entry module of a module graph,
+    /// and inserts it into the module map of the current realm, so that it is not loaded again
+    /// if it is imported by the modules of the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` cannot be canonicalized or is not valid UTF-8, or if the source
+    /// text is not a valid module.
+    pub fn parse_entry_module<S>(&self, src: S, path: &Path, context: &mut Context) -> JsResult<Module>
+    where
+        S: AsRef<[u8]>,
+    {
+        let path = match path.canonicalize() {
+            Ok(path) => path,
+            Err(err) => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("cannot load module '{}': {err}", path.display()))
+                    .into())
+            }
+        };
+
+        let module = Module::parse(src, Some(&path), context)?;
+        Self::insert(&path, module.clone())?;
+        Ok(module)
+    }
+
+    /// Inserts an already parsed module into the module map of its realm, so that it is not
+    /// loaded again if it is imported by another module of the realm.
+    ///
+    /// The `path` must be canonical, like the paths of the modules loaded by the loader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` is not valid UTF-8, since the modules are cached by their path.
+    pub fn insert(path: &Path, module: Module) -> JsResult<()> {
+        let key = path.to_str().ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "cannot load module '{}': the path is not valid UTF-8",
+                path.display()
+            ))
+        })?;
+        module.realm().clone().insert_loaded_module(key.to_owned(), module);
+        Ok(())
+    }
+
+    /// Gets the module that was previously loaded from `path` in `realm`, if any.
+    pub fn get(realm: &Realm, path: &Path) -> Option<Module> {
+        realm.loaded_module(path.to_str()?)
+    }
+
+    /// Resolves `specifier` to an absolute path, which is not canonicalized yet.
+    fn resolve(&self, referrer: &Module, specifier: &str) -> PathBuf {
+        let base = if specifier.starts_with("./") || specifier.starts_with("../") {
+            referrer
+                .path()
+                .and_then(Path::parent)
+                .map_or_else(|| self.root.clone(), Path::to_path_buf)
+        } else {
+            self.root.clone()
+        };
+
+        let mut path = base;
+        for component in Path::new(specifier).components() {
+            match component {
+                Component::ParentDir => {
+                    path.pop();
+                }
+                Component::Normal(name) => path.push(name),
+                Component::CurDir | Component::RootDir | Component::Prefix(_) => {}
+            }
+        }
+
+        path
+    }
+}
+
+impl ModuleLoader for SimpleModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: &Module,
+        specifier: &str,
+        context: &mut Context,
+    ) -> JsResult<Module> {
+        // The path is canonicalized before checking that it is inside of the root directory,
+        // since a symbolic link inside of the root could point outside of it.
+        let path = match self.resolve(referrer, specifier).canonicalize() {
+            Ok(path) if path.starts_with(&self.root) => path,
+            Ok(_) => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "cannot load module '{specifier}': path is outside of the module root"
+                    ))
+                    .into())
+            }
+            Err(err) => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("cannot load module '{specifier}': {err}"))
+                    .into())
+            }
+        };
+
+        if let Some(module) = Self::get(referrer.realm(), &path) {
+            return Ok(module);
+        }
+
+        let source = match std::fs::read(&path) {
+            Ok(source) => source,
+            Err(err) => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("cannot load module '{specifier}': {err}"))
+                    .into())
+            }
+        };
+
+        let module = Module::parse(source, Some(&path), context)?;
+        Self::insert(&path, module.clone())?;
+
+        Ok(module)
+    }
+}
+
+/// A module loader that loads modules from source texts stored in memory.
+///
+/// Specifiers are used as-is to look up the source texts, without any resolution. Every realm
+/// gets its own copy of a module, which is cached in the realm by its specifier.
+#[derive(Debug, Default)]
+pub struct MapModuleLoader {
+    sources: FxHashMap<String, String>,
+}
+
+impl MapModuleLoader {
+    /// Creates a new, empty `MapModuleLoader`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the source text of the module with specifier `specifier`.
+    #[must_use]
+    pub fn with_module<K, S>(mut self, specifier: K, source: S) -> Self
+    where
+        K: Into<String>,
+        S: Into<String>,
+    {
+        self.sources.insert(specifier.into(), source.into());
+        self
+    }
+}
+
+impl ModuleLoader for MapModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: &Module,
+        specifier: &str,
+        context: &mut Context,
+    ) -> JsResult<Module> {
+        if let Some(module) = referrer.realm().loaded_module(specifier) {
+            return Ok(module);
+        }
+
+        let source = match self.sources.get(specifier) {
+            Some(source) => source,
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("cannot load module '{specifier}': not found"))
+                    .into())
+            }
+        };
+
+        let module = Module::parse(source, None, context)?;
+        module
+            .realm()
+            .insert_loaded_module(specifier.to_owned(), module.clone());
+
+        Ok(module)
+    }
+}
@@ -0,0 +1,1152 @@
+//! Boa's implementation of ECMAScript modules.
+//!
+//! A [`Module`] is a [Source Text Module Record][spec]. Its life cycle mirrors the one described
+//! by the specification:
+//!
+//! 1. [`Module::parse`] parses the source text of the module using the `Module` goal symbol.
+//! 2. [`Module::load`] recursively loads all modules requested by `import` and `export ... from`
+//!    declarations, using the [`ModuleLoader`] of the [`Context`].
+//! 3. [`Module::link`] resolves all imports and creates the module environments.
+//! 4. [`Module::evaluate`] executes the module body and the bodies of all its dependencies.
+//!
+//! [`Context::eval_module`] performs all of the above in a single call.
+//!
+//! Top-level `await` is not supported yet, so module evaluation is always synchronous.
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+
+mod loader;
+
+#[cfg(test)]
+mod tests;
+
+pub use loader::{IdleModuleLoader, MapModuleLoader, ModuleLoader, SimpleModuleLoader};
+
+use crate::{
+    bytecompiler::ByteCompiler,
+    environments::DeclarativeEnvironment,
+    error::{JsError, JsNativeError},
+    object::{JsObject, ObjectData},
+    property::{PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    syntax::{
+        ast::node::{
+            declaration::{
+                export_decl::{
+                    ExportEntry, IndirectExportEntry, LocalExportEntry, ReExportImportName,
+                },
+                import_decl::{ImportEntry, ImportName},
+            },
+            ModuleItemList,
+        },
+        Parser,
+    },
+    realm::Realm,
+    vm::CodeBlock,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_gc::{Cell, Finalize, Gc, Trace};
+use boa_interner::Sym;
+use boa_profiler::Profiler;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// The status of a module record.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-cyclic-module-fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleStatus {
+    /// The module has not been linked yet.
+    Unlinked,
+    /// The module is being linked.
+    Linking,
+    /// The module and all its dependencies have been linked.
+    Linked,
+    /// The module is being evaluated.
+    Evaluating,
+    /// The module has been evaluated, either successfully or with an error.
+    Evaluated,
+}
+
+/// An ECMAScript module.
+///
+/// This is a cheaply cloneable, garbage collected handle to a module record.
+/// Two handles compare equal if they point to the same module record.
+#[derive(Clone, Trace, Finalize)]
+pub struct Module {
+    inner: Gc<Inner>,
+}
+
+#[derive(Trace, Finalize)]
+struct Inner {
+    /// The `[[Realm]]` the module was created in, where it is linked and evaluated.
+    realm: Realm,
+
+    /// The path of the source file of the module, if any.
+    #[unsafe_ignore_trace]
+    path: Option<PathBuf>,
+
+    /// The `[[ECMAScriptCode]]` of the module.
+    #[unsafe_ignore_trace]
+    body: ModuleItemList,
+
+    /// The `[[RequestedModules]]` of the module.
+    #[unsafe_ignore_trace]
+    requested_modules: Vec<Sym>,
+
+    /// The `[[ImportEntries]]` of the module.
+    #[unsafe_ignore_trace]
+    import_entries: Vec<ImportEntry>,
+
+    /// The `[[LocalExportEntries]]` of the module.
+    #[unsafe_ignore_trace]
+    local_export_entries: Vec<LocalExportEntry>,
+
+    /// The `[[IndirectExportEntries]]` of the module.
+    #[unsafe_ignore_trace]
+    indirect_export_entries: Vec<IndirectExportEntry>,
+
+    /// The `[[StarExportEntries]]` of the module, as the list of their module requests.
+    #[unsafe_ignore_trace]
+    star_export_entries: Vec<Sym>,
+
+    /// The `[[LoadedModules]]` of the module, in the same order as `requested_modules`.
+    loaded_modules: Cell<Vec<Module>>,
+
+    #[unsafe_ignore_trace]
+    status: std::cell::Cell<ModuleStatus>,
+
+    #[unsafe_ignore_trace]
+    dfs_index: std::cell::Cell<usize>,
+
+    #[unsafe_ignore_trace]
+    dfs_ancestor_index: std::cell::Cell<usize>,
+
+    /// The `[[EvaluationError]]` of the module.
+    evaluation_error: Cell<Option<JsError>>,
+
+    /// The `[[Environment]]` of the module.
+    environment: Cell<Option<Gc<DeclarativeEnvironment>>>,
+
+    /// The `[[Namespace]]` of the module.
+    namespace: Cell<Option<JsObject>>,
+
+    /// The code that evaluates the module body.
+    code: Cell<Option<Gc<CodeBlock>>>,
+
+    /// The import bindings of the module environment that are resolved to a binding of another module.
+    import_bindings: Cell<Vec<ImportBinding>>,
+}
+
+/// An import binding of a module environment, which is an indirection to the binding
+/// `name` in the environment of `module`.
+#[derive(Trace, Finalize)]
+struct ImportBinding {
+    #[unsafe_ignore_trace]
+    index: usize,
+    module: Module,
+    #[unsafe_ignore_trace]
+    name: Sym,
+}
+
+/// The binding name of a resolved export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BindingName {
+    Name(Sym),
+    Namespace,
+}
+
+/// The result of the `ResolveExport` abstract operation.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#resolvedbinding-record
+#[derive(Debug, Clone)]
+enum ResolvedExport {
+    Resolved {
+        module: Module,
+        binding: BindingName,
+    },
+    NotFound,
+    Ambiguous,
+}
+
+impl Module {
+    /// Parses the source text of an ECMAScript module.
+    ///
+    /// The `path` is only used by module loaders to resolve relative specifiers of the modules
+    /// imported by this module. The module is created in the current realm of the context.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if the source text is not a valid module.
+    pub fn parse<S>(src: S, path: Option<&Path>, context: &mut Context) -> JsResult<Self>
+    where
+        S: AsRef<[u8]>,
+    {
+        let _timer = Profiler::global().start_event("Module parsing", "Main");
+
+        let body = match Parser::new(src.as_ref()).parse_module(context) {
+            Ok(body) => body,
+            Err(e) => return Err(JsNativeError::syntax().with_message(e.to_string()).into()),
+        };
+
+        Ok(Self::from_ast(
+            body,
+            context.realm().clone(),
+            path.map(Path::to_path_buf),
+        ))
+    }
+
+    /// Creates a new module record from the AST of a module.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-parsemodule
+    fn from_ast(body: ModuleItemList, realm: Realm, path: Option<PathBuf>) -> Self {
+        // 3. Let requestedModules be the ModuleRequests of body.
+        let requested_modules = body.requests();
+
+        // 4. Let importEntries be ImportEntries of body.
+        let import_entries = body.import_entries();
+
+        // 6. Let indirectExportEntries be a new empty List.
+        // 7. Let localExportEntries be a new empty List.
+        // 8. Let starExportEntries be a new empty List.
+        let mut local_export_entries = Vec::new();
+        let mut indirect_export_entries = Vec::new();
+        let mut star_export_entries = Vec::new();
+
+        // 9. Let exportEntries be ExportEntries of body.
+        // 10. For each ExportEntry Record ee of exportEntries, do
+        for entry in body.export_entries() {
+            match entry {
+                // a. If ee.[[ModuleRequest]] is null, then
+                ExportEntry::Ordinary(entry) => {
+                    // i. If importedBoundNames does not contain ee.[[LocalName]], then
+                    //     1. Append ee to localExportEntries.
+                    // ii. Else,
+                    //     1. Let ie be the element of importEntries whose [[LocalName]] is the same as ee.[[LocalName]].
+                    match import_entries
+                        .iter()
+                        .find(|ie| ie.local_name() == entry.local_name())
+                        .map(|ie| (ie.module_request(), ie.import_name()))
+                    {
+                        // 3. Else,
+                        //     a. NOTE: This is a re-export of a single name.
+                        //     b. Append the ExportEntry Record { [[ModuleRequest]]: ie.[[ModuleRequest]],
+                        //        [[ImportName]]: ie.[[ImportName]], [[LocalName]]: null,
+                        //        [[ExportName]]: ee.[[ExportName]] } to indirectExportEntries.
+                        Some((request, ImportName::Name(name))) => {
+                            indirect_export_entries.push(IndirectExportEntry::new(
+                                request,
+                                ReExportImportName::Name(name),
+                                entry.export_name(),
+                            ));
+                        }
+                        // 2. If ie.[[ImportName]] is namespace-object, then
+                        //     a. NOTE: This is a re-export of an imported module namespace object.
+                        //     b. Append ee to localExportEntries.
+                        Some((_, ImportName::Namespace)) | None => {
+                            local_export_entries.push(entry);
+                        }
+                    }
+                }
+                // b. Else if ee.[[ImportName]] is all-but-default, then
+                //     ii. Append ee to starExportEntries.
+                ExportEntry::StarReExport { module_request } => {
+                    star_export_entries.push(module_request);
+                }
+                // c. Else,
+                //     i. Append ee to indirectExportEntries.
+                ExportEntry::ReExport(entry) => indirect_export_entries.push(entry),
+            }
+        }
+
+        Self {
+            inner: Gc::new(Inner {
+                realm,
+                path,
+                body,
+                requested_modules,
+                import_entries,
+                local_export_entries,
+                indirect_export_entries,
+                star_export_entries,
+                loaded_modules: Cell::new(Vec::new()),
+                status: std::cell::Cell::new(ModuleStatus::Unlinked),
+                dfs_index: std::cell::Cell::new(0),
+                dfs_ancestor_index: std::cell::Cell::new(0),
+                evaluation_error: Cell::new(None),
+                environment: Cell::new(None),
+                namespace: Cell::new(None),
+                code: Cell::new(None),
+                import_bindings: Cell::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Gets the realm the module was created in.
+    ///
+    /// This is the current realm of the context when the module was parsed. The module is linked
+    /// and evaluated in this realm, whatever the current realm is at that time.
+    #[inline]
+    pub fn realm(&self) -> &Realm {
+        &self.inner.realm
+    }
+
+    /// Gets the path of the source file of the module, if any.
+    #[inline]
+    pub fn path(&self) -> Option<&Path> {
+        self.inner.path.as_deref()
+    }
+
+    /// Gets the current status of the module.
+    #[inline]
+    pub fn status(&self) -> ModuleStatus {
+        self.inner.status.get()
+    }
+
+    /// Gets the module specifiers requested by this module, in source text order.
+    pub fn requested_modules(&self, context: &Context) -> Vec<String> {
+        self.inner
+            .requested_modules
+            .iter()
+            .map(|request| context.interner().resolve_expect(*request).to_owned())
+            .collect()
+    }
+
+    /// Loads all the modules requested by this module and, recursively, by its dependencies.
+    ///
+    /// Modules are loaded using the [`ModuleLoader`] of the context.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-LoadRequestedModules
+    ///
+    /// # Errors
+    ///
+    /// Returns any error thrown by the module loader.
+    pub fn load(&self, context: &mut Context) -> JsResult<()> {
+        let _timer = Profiler::global().start_event("Module loading", "Main");
+
+        if self.inner.requested_modules.is_empty() || !self.inner.loaded_modules.borrow().is_empty()
+        {
+            return Ok(());
+        }
+
+        // The requested modules are loaded in the realm of the module, so that the loader creates
+        // them in that realm.
+        let loader = context.module_loader();
+        let previous_realm = context.enter_realm(self.realm().clone());
+        let modules = self
+            .inner
+            .requested_modules
+            .iter()
+            .map(|request| {
+                let specifier = context.interner().resolve_expect(*request).to_owned();
+                loader.load_imported_module(self, &specifier, context)
+            })
+            .collect::<JsResult<Vec<_>>>();
+        context.enter_realm(previous_realm);
+        let modules = modules?;
+
+        // The list of loaded modules must be set before loading the dependencies,
+        // otherwise cyclic imports would be loaded forever.
+        *self.inner.loaded_modules.borrow_mut() = modules.clone();
+
+        for module in modules {
+            module.load(context)?;
+        }
+
+        Ok(())
+    }
+
+    /// Links the module and all its dependencies.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduledeclarationlinking
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if an import cannot be resolved.
+    pub fn link(&self, context: &mut Context) -> JsResult<()> {
+        let _timer = Profiler::global().start_event("Module linking", "Main");
+
+        // 1. Assert: module.[[Status]] is not linking or evaluating.
+        debug_assert!(!matches!(
+            self.status(),
+            ModuleStatus::Linking | ModuleStatus::Evaluating
+        ));
+
+        // 2. Let stack be a new empty List.
+        let mut stack = Vec::new();
+
+        // 3. Let result be Completion(InnerModuleLinking(module, stack, 0)).
+        // 4. If result is an abrupt completion, then
+        if let Err(err) = self.inner_link(&mut stack, 0, context) {
+            // a. For each Cyclic Module Record m of stack, do
+            for module in stack {
+                // i. Assert: m.[[Status]] is linking.
+                // ii. Set m.[[Status]] to unlinked.
+                module.inner.status.set(ModuleStatus::Unlinked);
+            }
+
+            // b. Assert: module.[[Status]] is unlinked.
+            // c. Return ? result.
+            return Err(err);
+        }
+
+        // 5. Assert: module.[[Status]] is one of linked, evaluating-async, or evaluated.
+        // 6. Assert: stack is empty.
+        debug_assert!(stack.is_empty());
+
+        // 7. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation `InnerModuleLinking ( module, stack, index )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-InnerModuleLinking
+    fn inner_link(
+        &self,
+        stack: &mut Vec<Self>,
+        mut index: usize,
+        context: &mut Context,
+    ) -> JsResult<usize> {
+        // 2. If module.[[Status]] is linking, linked, evaluating-async, or evaluated, then
+        if self.status() != ModuleStatus::Unlinked {
+            // a. Return index.
+            return Ok(index);
+        }
+
+        // 4. Set module.[[Status]] to linking.
+        // 5. Set module.[[DFSIndex]] to index.
+        // 6. Set module.[[DFSAncestorIndex]] to index.
+        // 7. Set index to index + 1.
+        // 8. Append module to stack.
+        self.inner.status.set(ModuleStatus::Linking);
+        self.inner.dfs_index.set(index);
+        self.inner.dfs_ancestor_index.set(index);
+        index += 1;
+        stack.push(self.clone());
+
+        // 9. For each String required of module.[[RequestedModules]], do
+        let loaded = self.inner.loaded_modules.borrow().clone();
+        for required in loaded {
+            // a. Let requiredModule be GetImportedModule(module, required).
+            // b. Set index to ? InnerModuleLinking(requiredModule, stack, index).
+            index = required.inner_link(stack, index, context)?;
+
+            // c. If requiredModule is a Cyclic Module Record, then
+            //     ii. If requiredModule.[[Status]] is linking, then
+            if required.status() == ModuleStatus::Linking {
+                // 1. Set module.[[DFSAncestorIndex]] to min(module.[[DFSAncestorIndex]], requiredModule.[[DFSAncestorIndex]]).
+                self.inner.dfs_ancestor_index.set(
+                    self.inner
+                        .dfs_ancestor_index
+                        .get()
+                        .min(required.inner.dfs_ancestor_index.get()),
+                );
+            }
+        }
+
+        // 10. Perform ? module.InitializeEnvironment().
+        //
+        // The environment is created in the global environment of module.[[Realm]].
+        let previous_realm = context.enter_realm(self.realm().clone());
+        let result = self.initialize_environment(context);
+        context.enter_realm(previous_realm);
+        result?;
+
+        // 13. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
+        if self.inner.dfs_ancestor_index.get() == self.inner.dfs_index.get() {
+            // a. Let done be false.
+            // b. Repeat, while done is false,
+            while let Some(required) = stack.pop() {
+                // i. Let requiredModule be the last element of stack.
+                // ii. Remove the last element of stack.
+                // iv. Set requiredModule.[[Status]] to linked.
+                required.inner.status.set(ModuleStatus::Linked);
+
+                // v. If requiredModule and module are the same Module Record, set done to true.
+                if &required == self {
+                    break;
+                }
+            }
+        }
+
+        // 14. Return index.
+        Ok(index)
+    }
+
+    /// Abstract operation `InitializeEnvironment ( )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    fn initialize_environment(&self, context: &mut Context) -> JsResult<()> {
+        // 1. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        for entry in &self.inner.indirect_export_entries {
+            // a. Let resolution be module.ResolveExport(e.[[ExportName]]).
+            // b. If resolution is either null or ambiguous, throw a SyntaxError exception.
+            self.resolve_export_or_throw(entry.export_name(), context)?;
+        }
+
+        // 5. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
+        // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
+        //     c. If in.[[ImportName]] is namespace-object, then
+        //         ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+        //     d. Else,
+        //         iv. Else, perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]], resolution.[[BindingName]]).
+        // 18-24. Create the bindings of the module body and instantiate the hoisted functions.
+        context.push_compile_time_environment(true);
+
+        let source_name = self
+            .path()
+            .map(|path| JsString::new(path.display().to_string()));
+        let init_code = {
+            let mut compiler = ByteCompiler::new(Sym::MAIN, true, context);
+            compiler.set_source_name(source_name.clone());
+            compiler.create_module_declarations(&self.inner.body)?;
+            compiler.compile_module_initialization(&self.inner.body)?;
+            Gc::new(compiler.finish())
+        };
+        let code = {
+            let mut compiler = ByteCompiler::new(Sym::MAIN, true, context);
+            compiler.set_source_name(source_name);
+            compiler.compile_module_body(&self.inner.body)?;
+            Gc::new(compiler.finish())
+        };
+
+        let (num_bindings, compile_environment) = context.pop_compile_time_environment();
+
+        let saved_environments = context.vm.environments.pop_to_global();
+        context.vm.environments.push_module(
+            num_bindings,
+            compile_environment.clone(),
+            self.clone(),
+        );
+        let environment = context.vm.environments.current();
+        context.vm.environments.truncate(1);
+        context.vm.environments.extend(saved_environments);
+
+        // 6. Set module.[[Environment]] to env.
+        *self.inner.environment.borrow_mut() = Some(environment.clone());
+        *self.inner.code.borrow_mut() = Some(code);
+
+        // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
+        let mut import_bindings = Vec::new();
+        for entry in &self.inner.import_entries {
+            // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
+            let imported = self.get_imported_module(entry.module_request());
+            let index = compile_environment
+                .borrow()
+                .get_binding(entry.local_name())
+                .expect("import binding must exist")
+                .binding_index();
+
+            let module = match entry.import_name() {
+                // c. If in.[[ImportName]] is namespace-object, then
+                ImportName::Namespace => imported,
+                ImportName::Name(name) => {
+                    // d. Else,
+                    //     i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
+                    //     ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
+                    match imported.resolve_export_or_throw(name, context)? {
+                        // iv. Else, perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]], resolution.[[BindingName]]).
+                        (module, BindingName::Name(name)) => {
+                            import_bindings.push(ImportBinding {
+                                index,
+                                module,
+                                name,
+                            });
+                            continue;
+                        }
+                        // iii. If resolution.[[BindingName]] is namespace, then
+                        (module, BindingName::Namespace) => module,
+                    }
+                }
+            };
+
+            // Let namespace be GetModuleNamespace(importedModule).
+            // Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+            let namespace = module.namespace(context)?;
+            environment.initialize(index, namespace.into());
+        }
+        *self.inner.import_bindings.borrow_mut() = import_bindings;
+
+        // Instantiate the hoisted declarations of the module body.
+        self.execute_in_environment(init_code, context)?;
+
+        Ok(())
+    }
+
+    /// Evaluates the module and all its dependencies.
+    ///
+    /// Evaluating a module more than once has no effect; the result of the first evaluation is
+    /// returned instead.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    ///
+    /// # Errors
+    ///
+    /// Returns the error thrown by the evaluation of the module or one of its dependencies.
+    pub fn evaluate(&self, context: &mut Context) -> JsResult<()> {
+        let _timer = Profiler::global().start_event("Module evaluation", "Main");
+
+        // 1. Assert: This call to Evaluate is not happening at the same time as another call to Evaluate within the surrounding agent.
+        // 2. Assert: module.[[Status]] is one of linked, evaluating-async, or evaluated.
+        if matches!(
+            self.status(),
+            ModuleStatus::Unlinked | ModuleStatus::Linking
+        ) {
+            return Err(JsNativeError::typ()
+                .with_message("cannot evaluate a module that is not linked")
+                .into());
+        }
+
+        // 4. Let stack be a new empty List.
+        let mut stack = Vec::new();
+
+        // 6. Let result be Completion(InnerModuleEvaluation(module, stack, 0)).
+        // 7. If result is an abrupt completion, then
+        if let Err(err) = self.inner_evaluate(&mut stack, 0, context) {
+            // a. For each Cyclic Module Record m of stack, do
+            for module in stack {
+                // i. Assert: m.[[Status]] is evaluating.
+                // ii. Set m.[[Status]] to evaluated.
+                // iii. Set m.[[EvaluationError]] to result.
+                module.inner.status.set(ModuleStatus::Evaluated);
+                *module.inner.evaluation_error.borrow_mut() = Some(err.clone());
+            }
+
+            return Err(err);
+        }
+
+        // 8. Else,
+        //     a. Assert: module.[[Status]] is either evaluating-async or evaluated.
+        //     c. Assert: stack is empty.
+        debug_assert!(stack.is_empty());
+
+        Ok(())
+    }
+
+    /// Abstract operation `InnerModuleEvaluation ( module, stack, index )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-innermoduleevaluation
+    fn inner_evaluate(
+        &self,
+        stack: &mut Vec<Self>,
+        mut index: usize,
+        context: &mut Context,
+    ) -> JsResult<usize> {
+        match self.status() {
+            // 2. If module.[[Status]] is evaluating-async or evaluated, then
+            ModuleStatus::Evaluated => {
+                // a. If module.[[EvaluationError]] is empty, return index.
+                // b. Otherwise, return ? module.[[EvaluationError]].
+                return match self.inner.evaluation_error.borrow().clone() {
+                    Some(err) => Err(err),
+                    None => Ok(index),
+                };
+            }
+            // 3. If module.[[Status]] is evaluating, return index.
+            ModuleStatus::Evaluating => return Ok(index),
+            // 4. Assert: module.[[Status]] is linked.
+            _ => debug_assert_eq!(self.status(), ModuleStatus::Linked),
+        }
+
+        // 5. Set module.[[Status]] to evaluating.
+        // 6. Set module.[[DFSIndex]] to index.
+        // 7. Set module.[[DFSAncestorIndex]] to index.
+        // 9. Set index to index + 1.
+        // 10. Append module to stack.
+        self.inner.status.set(ModuleStatus::Evaluating);
+        self.inner.dfs_index.set(index);
+        self.inner.dfs_ancestor_index.set(index);
+        index += 1;
+        stack.push(self.clone());
+
+        // 11. For each String required of module.[[RequestedModules]], do
+        let loaded = self.inner.loaded_modules.borrow().clone();
+        for required in loaded {
+            // a. Let requiredModule be GetImportedModule(module, required).
+            // b. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
+            index = required.inner_evaluate(stack, index, context)?;
+
+            // c. If requiredModule is a Cyclic Module Record, then
+            //     ii. If requiredModule.[[Status]] is evaluating, then
+            if required.status() == ModuleStatus::Evaluating {
+                // 1. Set module.[[DFSAncestorIndex]] to min(module.[[DFSAncestorIndex]], requiredModule.[[DFSAncestorIndex]]).
+                self.inner.dfs_ancestor_index.set(
+                    self.inner
+                        .dfs_ancestor_index
+                        .get()
+                        .min(required.inner.dfs_ancestor_index.get()),
+                );
+            }
+        }
+
+        // 14. Else, perform ? module.ExecuteModule().
+        let code = self
+            .inner
+            .code
+            .borrow()
+            .clone()
+            .expect("linked module must have been compiled");
+        self.execute_in_environment(code, context)?;
+
+        // 16. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
+        if self.inner.dfs_ancestor_index.get() == self.inner.dfs_index.get() {
+            // a. Let done be false.
+            // b. Repeat, while done is false,
+            while let Some(required) = stack.pop() {
+                // i. Let requiredModule be the last element of stack.
+                // ii. Remove the last element of stack.
+                // iv. If requiredModule.[[AsyncEvaluation]] is false, set requiredModule.[[Status]] to evaluated.
+                required.inner.status.set(ModuleStatus::Evaluated);
+
+                // vi. If requiredModule and module are the same Module Record, set done to true.
+                if &required == self {
+                    break;
+                }
+            }
+        }
+
+        // 17. Return index.
+        Ok(index)
+    }
+
+    /// Abstract operation `GetModuleNamespace ( module )`.
+    ///
+    /// Returns the namespace object of the module, creating it if necessary.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    pub fn namespace(&self, context: &mut Context) -> JsResult<JsObject> {
+        // 3. Let namespace be module.[[Namespace]].
+        // 4. If namespace is empty, then
+        if let Some(namespace) = self.inner.namespace.borrow().clone() {
+            // 5. Return namespace.
+            return Ok(namespace);
+        }
+
+        // a. Let exportedNames be module.GetExportedNames().
+        // b. Let unambiguousNames be a new empty List.
+        // c. For each element name of exportedNames, do
+        //     i. Let resolution be module.ResolveExport(name).
+        //     ii. If resolution is a ResolvedBinding Record, append name to unambiguousNames.
+        let mut exports: Vec<_> = self
+            .get_exported_names(&mut Vec::new())
+            .into_iter()
+            .filter(|name| {
+                matches!(
+                    self.resolve_export(*name, &mut Vec::new()),
+                    ResolvedExport::Resolved { .. }
+                )
+            })
+            .map(|name| {
+                (
+                    JsString::from(context.interner().resolve_expect(name)),
+                    name,
+                )
+            })
+            .collect();
+
+        // d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames).
+        // Let sortedExports be a List whose elements are the elements of exports ordered as if
+        // an Array of the same values had been sorted using %Array.prototype.sort% using
+        // undefined as comparefn.
+        exports.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let namespace = JsObject::from_proto_and_data(
+            None,
+            ObjectData::module_namespace(ModuleNamespace {
+                module: self.clone(),
+                exports,
+            }),
+        );
+
+        // The initial value of the @@toStringTag property is the String value "Module".
+        // This property has the attributes { [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }.
+        namespace.insert(
+            WellKnownSymbols::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value("Module")
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+        namespace.__prevent_extensions__(context)?;
+
+        *self.inner.namespace.borrow_mut() = Some(namespace.clone());
+
+        Ok(namespace)
+    }
+
+    /// Abstract operation `GetImportedModule ( referrer, specifier )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-GetImportedModule
+    fn get_imported_module(&self, request: Sym) -> Self {
+        let position = self
+            .inner
+            .requested_modules
+            .iter()
+            .position(|r| *r == request)
+            .expect("module request must exist");
+        self.inner
+            .loaded_modules
+            .borrow()
+            .get(position)
+            .expect("requested modules must be loaded before linking")
+            .clone()
+    }
+
+    /// Abstract operation `GetExportedNames ( [ exportStarSet ] )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn get_exported_names(&self, export_star_set: &mut Vec<Self>) -> Vec<Sym> {
+        // 2. If exportStarSet contains module, then
+        if export_star_set.contains(self) {
+            // a. Assert: We've reached the starting point of an export * circularity.
+            // b. Return a new empty List.
+            return Vec::new();
+        }
+
+        // 3. Append module to exportStarSet.
+        export_star_set.push(self.clone());
+
+        // 4. Let exportedNames be a new empty List.
+        // 5. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        // 6. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        let mut exported_names: Vec<Sym> = self
+            .inner
+            .local_export_entries
+            .iter()
+            .map(|e| e.export_name())
+            .chain(
+                self.inner
+                    .indirect_export_entries
+                    .iter()
+                    .map(|e| e.export_name()),
+            )
+            .collect();
+
+        // 7. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for request in &self.inner.star_export_entries {
+            // a. Let requestedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+            // b. Let starNames be requestedModule.GetExportedNames(exportStarSet).
+            let requested = self.get_imported_module(*request);
+
+            // c. For each element n of starNames, do
+            for name in requested.get_exported_names(export_star_set) {
+                // i. If SameValue(n, "default") is false, then
+                //     1. If exportedNames does not contain n, then
+                if name != Sym::DEFAULT && !exported_names.contains(&name) {
+                    // a. Append n to exportedNames.
+                    exported_names.push(name);
+                }
+            }
+        }
+
+        // 8. Return exportedNames.
+        exported_names
+    }
+
+    /// Abstract operation `ResolveExport ( exportName [ , resolveSet ] )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(
+        &self,
+        export_name: Sym,
+        resolve_set: &mut Vec<(Self, Sym)>,
+    ) -> ResolvedExport {
+        // 2. For each Record { [[Module]], [[ExportName]] } r of resolveSet, do
+        //     a. If module and r.[[Module]] are the same Module Record and SameValue(exportName, r.[[ExportName]]) is true, then
+        if resolve_set
+            .iter()
+            .any(|(module, name)| module == self && *name == export_name)
+        {
+            // i. Assert: This is a circular import request.
+            // ii. Return null.
+            return ResolvedExport::NotFound;
+        }
+
+        // 3. Append the Record { [[Module]]: module, [[ExportName]]: exportName } to resolveSet.
+        resolve_set.push((self.clone(), export_name));
+
+        // 4. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        for entry in &self.inner.local_export_entries {
+            // a. If SameValue(exportName, e.[[ExportName]]) is true, then
+            if entry.export_name() == export_name {
+                // i. Assert: module provides the direct binding for this export.
+                // ii. Return ResolvedBinding Record { [[Module]]: module, [[BindingName]]: e.[[LocalName]] }.
+                return ResolvedExport::Resolved {
+                    module: self.clone(),
+                    binding: BindingName::Name(entry.local_name()),
+                };
+            }
+        }
+
+        // 5. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        for entry in &self.inner.indirect_export_entries {
+            // a. If SameValue(exportName, e.[[ExportName]]) is true, then
+            if entry.export_name() == export_name {
+                // i. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+                let imported = self.get_imported_module(entry.module_request());
+
+                return match entry.import_name() {
+                    // ii. If e.[[ImportName]] is all, then
+                    //     1. Assert: module does not provide the direct binding for this export.
+                    //     2. Return ResolvedBinding Record { [[Module]]: importedModule, [[BindingName]]: namespace }.
+                    ReExportImportName::Star => ResolvedExport::Resolved {
+                        module: imported,
+                        binding: BindingName::Namespace,
+                    },
+                    // iii. Else,
+                    //     1. Assert: module imports a specific binding for this export.
+                    //     2. Return importedModule.ResolveExport(e.[[ImportName]], resolveSet).
+                    ReExportImportName::Name(name) => imported.resolve_export(name, resolve_set),
+                };
+            }
+        }
+
+        // 6. If SameValue(exportName, "default") is true, then
+        if export_name == Sym::DEFAULT {
+            // a. Assert: A default export was not explicitly defined by this module.
+            // b. Return null.
+            // c. NOTE: A default export cannot be provided by an export * from "mod" declaration.
+            return ResolvedExport::NotFound;
+        }
+
+        // 7. Let starResolution be null.
+        let mut star_resolution = ResolvedExport::NotFound;
+
+        // 8. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for request in &self.inner.star_export_entries {
+            // a. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+            // b. Let resolution be importedModule.ResolveExport(exportName, resolveSet).
+            let imported = self.get_imported_module(*request);
+            match imported.resolve_export(export_name, resolve_set) {
+                // c. If resolution is ambiguous, return ambiguous.
+                ResolvedExport::Ambiguous => return ResolvedExport::Ambiguous,
+                // d. If resolution is not null, then
+                ResolvedExport::Resolved { module, binding } => match &star_resolution {
+                    // ii. If starResolution is null, set starResolution to resolution.
+                    ResolvedExport::NotFound => {
+                        star_resolution = ResolvedExport::Resolved { module, binding };
+                    }
+                    // iii. Else,
+                    //     1. Assert: There is more than one * import that includes the requested name.
+                    //     2. If resolution.[[Module]] and starResolution.[[Module]] are not the same Module Record, return ambiguous.
+                    //     3. If resolution.[[BindingName]] is not starResolution.[[BindingName]], return ambiguous.
+                    ResolvedExport::Resolved {
+                        module: star_module,
+                        binding: star_binding,
+                    } => {
+                        if module != *star_module || binding != *star_binding {
+                            return ResolvedExport::Ambiguous;
+                        }
+                    }
+                    ResolvedExport::Ambiguous => unreachable!(),
+                },
+                ResolvedExport::NotFound => {}
+            }
+        }
+
+        // 9. Return starResolution.
+        star_resolution
+    }
+
+    /// Resolves an export of this module, throwing a `SyntaxError` if it cannot be resolved.
+    fn resolve_export_or_throw(
+        &self,
+        export_name: Sym,
+        context: &mut Context,
+    ) -> JsResult<(Self, BindingName)> {
+        match self.resolve_export(export_name, &mut Vec::new()) {
+            ResolvedExport::Resolved { module, binding } => Ok((module, binding)),
+            ResolvedExport::NotFound => Err(JsNativeError::syntax()
+                .with_message(format!(
+                    "the requested module does not provide an export named '{}'",
+                    context.interner().resolve_expect(export_name)
+                ))
+                .into()),
+            ResolvedExport::Ambiguous => Err(JsNativeError::syntax()
+                .with_message(format!(
+                    "the requested module provides an ambiguous export named '{}'",
+                    context.interner().resolve_expect(export_name)
+                ))
+                .into()),
+        }
+    }
+
+    /// Gets the current value of the export `export_name` of this module.
+    ///
+    /// This is used by the `[[Get]]` internal method of module namespace objects.
+    pub(crate) fn get_export_value(
+        &self,
+        export_name: Sym,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        match self.resolve_export_or_throw(export_name, context)? {
+            (module, BindingName::Namespace) => module.namespace(context).map(Into::into),
+            (module, BindingName::Name(name)) => match module.get_binding_value(name) {
+                Some(value) => Ok(value),
+                None => Err(JsNativeError::reference()
+                    .with_message(format!(
+                        "{} is not initialized",
+                        context.interner().resolve_expect(name)
+                    ))
+                    .into()),
+            },
+        }
+    }
+
+    /// Gets the value of the binding `name` of the module environment, or `None` if the binding
+    /// is not initialized yet.
+    fn get_binding_value(&self, name: Sym) -> Option<JsValue> {
+        let environment = self.inner.environment.borrow().clone()?;
+        let index = environment
+            .compile_env()
+            .borrow()
+            .get_binding(name)?
+            .binding_index();
+        environment.get_optional(index)
+    }
+
+    /// Gets the value of the import binding at `index` of the module environment, or `None` if
+    /// the binding it refers to is not initialized yet.
+    pub(crate) fn get_indirect_binding(&self, index: usize) -> Option<JsValue> {
+        let (module, name) = self
+            .inner
+            .import_bindings
+            .borrow()
+            .iter()
+            .find(|binding| binding.index == index)
+            .map(|binding| (binding.module.clone(), binding.name))?;
+        module.get_binding_value(name)
+    }
+
+    /// Executes `code` in the realm of the module, with the module environment as the current
+    /// environment.
+    fn execute_in_environment(
+        &self,
+        code: Gc<CodeBlock>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let environment = self
+            .inner
+            .environment
+            .borrow()
+            .clone()
+            .expect("module environment must be initialized");
+
+        let previous_realm = context.enter_realm(self.realm().clone());
+        let saved_environments = context.vm.environments.pop_to_global();
+        context.vm.environments.extend(vec![environment]);
+        let result = context.execute(code);
+        context.vm.environments.truncate(1);
+        context.vm.environments.extend(saved_environments);
+        context.enter_realm(previous_realm);
+
+        result
+    }
+}
+
+impl PartialEq for Module {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Module {}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Modules can import each other, so the loaded modules must not be printed.
+        f.debug_struct("Module")
+            .field("path", &self.inner.path)
+            .field("status", &self.inner.status.get())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The internal data of a module namespace exotic object.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct ModuleNamespace {
+    /// The `[[Module]]` internal slot.
+    module: Module,
+
+    /// The `[[Exports]]` internal slot, sorted by code unit order.
+    #[unsafe_ignore_trace]
+    exports: Vec<(JsString, Sym)>,
+}
+
+impl ModuleNamespace {
+    /// Gets the module of the namespace object.
+    #[inline]
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Gets the exported names of the namespace object.
+    #[inline]
+    pub fn exports(&self) -> impl Iterator<Item = &JsString> {
+        self.exports.iter().map(|(name, _)| name)
+    }
+
+    /// Gets the export name of the module corresponding to the property `key`, if it is an
+    /// element of `[[Exports]]`.
+    pub(crate) fn export_name(&self, key: &PropertyKey) -> Option<Sym> {
+        let find = |key: &[u16]| {
+            self.exports
+                .iter()
+                .find(|(name, _)| name[..] == *key)
+                .map(|(_, sym)| *sym)
+        };
+        match key {
+            PropertyKey::String(key) => find(key),
+            PropertyKey::Index(index) => find(&JsString::from(index.to_string())),
+            PropertyKey::Symbol(_) => None,
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use super::{MapModuleLoader, ModuleStatus, SimpleModuleLoader};
+use crate::{error::JsNativeErrorKind, object::JsObject, Context, JsValue};
+use std::{fs, path::PathBuf, rc::Rc};
+
+fn context_with_modules(loader: MapModuleLoader) -> Context {
+    Context::builder().module_loader(Rc::new(loader)).build()
+}
+
+/// Creates an empty directory for the modules of the test `name`.
+fn module_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("boa-{name}-{}", std::process::id()));
+    let _ignored = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn get(namespace: &JsObject, key: &str, context: &mut Context) -> JsValue {
+    namespace.get(key, context).expect("get must not fail")
+}
+
+#[test]
+fn eval_module_exports() {
+    let mut context = Context::default();
+    let module = context
+        .eval_module(
+            r#"
+            export const a = 1;
+            export let b = a + 1;
+            export function c() { return b + 1; }
+            const d = 4;
+            export { d as e };
+            "#,
+        )
+        .unwrap();
+    assert_eq!(module.status(), ModuleStatus::Evaluated);
+
+    let namespace = module.namespace(&mut context).unwrap();
+    assert_eq!(get(&namespace, "a", &mut context), JsValue::new(1));
+    assert_eq!(get(&namespace, "b", &mut context), JsValue::new(2));
+    assert_eq!(get(&namespace, "e", &mut context), JsValue::new(4));
+    assert!(get(&namespace, "d", &mut context).is_undefined());
+
+    let c = get(&namespace, "c", &mut context);
+    let result = c
+        .as_callable()
+        .unwrap()
+        .call(&JsValue::undefined(), &[], &mut context)
+        .unwrap();
+    assert_eq!(result, JsValue::new(3));
+}
+
+#[test]
+fn module_scope() {
+    let mut context = Context::default();
+    let module = context
+        .eval_module(
+            r#"
+            var a = 1;
+            export const isUndefined = this === undefined;
+            "#,
+        )
+        .unwrap();
+
+    let namespace = module.namespace(&mut context).unwrap();
+    assert_eq!(
+        get(&namespace, "isUndefined", &mut context),
+        JsValue::new(true)
+    );
+
+    // Module declarations do not leak into the global scope.
+    assert!(context.eval("typeof a").unwrap() == JsValue::new("undefined"));
+}
+
+#[test]
+fn imports_are_live_bindings() {
+    let loader = MapModuleLoader::new().with_module(
+        "counter",
+        r#"
+        export let count = 0;
+        export function increment() { count++; }
+        "#,
+    );
+    let mut context = context_with_modules(loader);
+
+    let module = context
+        .eval_module(
+            r#"
+            import { count, increment } from "counter";
+            export const before = count;
+            increment();
+            increment();
+            export const after = count;
+            "#,
+        )
+        .unwrap();
+
+    let namespace = module.namespace(&mut context).unwrap();
+    assert_eq!(get(&namespace, "before", &mut context), JsValue::new(0));
+    assert_eq!(get(&namespace, "after", &mut context), JsValue::new(2));
+}
+
+#[test]
+fn imports_are_immutable() {
+    let loader = MapModuleLoader::new().with_module("a", "export let a = 1;");
+    let mut context = context_with_modules(loader);
+
+    let result = context.eval_module(r#"import { a } from "a"; a = 2;"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn default_exports() {
+    let loader = MapModuleLoader::new()
+        .with_module("expression", "export default 6 * 7;")
+        .with_module("function", "export default function () { return 'f'; }")
+        .with_module("class", "export default class { static x = 'c'; }");
+    let mut context = context_with_modules(loader);
+
+    let module = context
+        .eval_module(
+            r#"
+            import expression from "expression";
+            import fn from "function";
+            import Class from "class";
+            export const values = [expression, fn(), Class.x, fn.name];
+            "#,
+        )
+        .unwrap();
+
+    let namespace = module.namespace(&mut context).unwrap();
+    let values = get(&namespace, "values", &mut context);
+    let values = values.as_object().unwrap();
+    assert_eq!(get(values, "0", &mut context), JsValue::new(42));
+    assert_eq!(get(values, "1", &mut context), JsValue::new("f"));
+    assert_eq!(get(values, "2", &mut context), JsValue::new("c"));
+    assert_eq!(get(values, "3", &mut context), JsValue::new("default"));
+}
+
+#[test]
+fn re_exports() {
+    let loader = MapModuleLoader::new()
+        .with_module("a", "export const a = 'a'; export default 'default';")
+        .with_module("b", "export const b = 'b';")
+        .with_module(
+            "all",
+            r#"
+            export * from "a";
+            export { b as renamed } from "b";
+            export * as ns from "b";
+            "#,
+        );
+    let mut context = context_with_modules(loader);
+
+    let module = context
+        .eval_module(
+            r#"
+            import { a, renamed, ns } from "all";
+            import * as all from "all";
+            export const values = [a, renamed, ns.b, "default" in all];
+            "#,
+        )
+        .unwrap();
+
+    let namespace = module.namespace(&mut context).unwrap();
+    let values = get(&namespace, "values", &mut context);
+    let values = values.as_object().unwrap();
+    assert_eq!(get(values, "0", &mut context), JsValue::new("a"));
+    assert_eq!(get(values, "1", &mut context), JsValue::new("b"));
+    assert_eq!(get(values, "2", &mut context), JsValue::new("b"));
+    assert_eq!(get(values, "3", &mut context), JsValue::new(false));
+}
+
+#[test]
+fn namespace_object() {
+    let loader = MapModuleLoader::new().with_module("m", "export const b = 2, a = 1;");
+    let mut context = context_with_modules(loader);
+
+    context
+        .eval_module(
+            r#"
+            import * as ns from "m";
+            globalThis.ns = ns;
+            "#,
+        )
+        .unwrap();
+
+    let check = |src: &str, context: &mut Context| context.eval(src).unwrap();
+    assert_eq!(
+        check("Object.keys(ns).join()", &mut context),
+        JsValue::new("a,b")
+    );
+    assert_eq!(
+        check("Object.prototype.toString.call(ns)", &mut context),
+        JsValue::new("[object Module]")
+    );
+    assert_eq!(
+        check("Object.getPrototypeOf(ns)", &mut context),
+        JsValue::null()
+    );
+    assert_eq!(
+        check("Object.isExtensible(ns)", &mut context),
+        JsValue::new(false)
+    );
+    assert_eq!(
+        check("Reflect.set(ns, 'a', 5)", &mut context),
+        JsValue::new(false)
+    );
+    assert_eq!(
+        check("Reflect.deleteProperty(ns, 'a')", &mut context),
+        JsValue::new(false)
+    );
+    assert!(context.eval("'use strict'; ns.a = 5;").is_err());
+}
+
+#[test]
+fn cyclic_imports() {
+    let loader = MapModuleLoader::new()
+        .with_module(
+            "even",
+            r#"
+            import { isOdd } from "odd";
+            export function isEven(n) { return n === 0 ? true : isOdd(n - 1); }
+            "#,
+        )
+        .with_module(
+            "odd",
+            r#"
+            import { isEven } from "even";
+            export function isOdd(n) { return n === 0 ? false : isEven(n - 1); }
+            "#,
+        );
+    let mut context = context_with_modules(loader);
+
+    let module = context
+        .eval_module(
+            r#"
+            import { isEven } from "even";
+            export const result = isEven(10);
+            "#,
+        )
+        .unwrap();
+
+    let namespace = module.namespace(&mut context).unwrap();
+    assert_eq!(get(&namespace, "result", &mut context), JsValue::new(true));
+}
+
+#[test]
+fn modules_are_evaluated_once() {
+    let loader = MapModuleLoader::new()
+        .with_module("counter", "globalThis.count = (globalThis.count ?? 0) + 1;")
+        .with_module("a", r#"import "counter";"#)
+        .with_module("b", r#"import "counter";"#);
+    let mut context = context_with_modules(loader);
+
+    context
+        .eval_module(
+            r#"
+            import "a";
+            import "b";
+            import "counter";
+            "#,
+        )
+        .unwrap();
+
+    assert_eq!(context.eval("count").unwrap(), JsValue::new(1));
+}
+
+#[test]
+fn temporal_dead_zone() {
+    let loader = MapModuleLoader::new()
+        .with_module(
+            "a",
+            r#"
+        import { b } from "b";
+        export const a = 1;
+        "#,
+        )
+        .with_module(
+            "b",
+            r#"
+        import { a } from "a";
+        export const b = a;
+        "#,
+        );
+    let mut context = context_with_modules(loader);
+
+    let result = context.eval_module(r#"import "a";"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn unresolvable_imports() {
+    let loader = MapModuleLoader::new().with_module("a", "export const a = 1;");
+    let mut context = context_with_modules(loader);
+
+    assert!(context.eval_module(r#"import { b } from "a";"#).is_err());
+    assert!(context
+        .eval_module(r#"import { a } from "missing";"#)
+        .is_err());
+}
+
+#[test]
+fn module_loading_disabled_by_default() {
+    let mut context = Context::default();
+    assert!(context.eval_module(r#"import "a";"#).is_err());
+}
+
+#[test]
+fn cyclic_imports_of_the_entry_module() {
+    let dir = module_dir("cyclic-entry");
+    fs::write(
+        dir.join("a.js"),
+        r#"
+        import { getA } from "./b.js";
+        globalThis.evaluations = (globalThis.evaluations ?? 0) + 1;
+        export const a = "a";
+        export const aFromB = () => getA();
+        "#,
+    )
+    .unwrap();
+    fs::write(
+        dir.join("b.js"),
+        r#"
+        import { a } from "./a.js";
+        export const getA = () => a;
+        "#,
+    )
+    .unwrap();
+
+    let loader = Rc::new(SimpleModuleLoader::new(&dir).unwrap());
+    let mut context = Context::builder().module_loader(loader.clone()).build();
+    let path = dir.join("a.js");
+    let module = loader
+        .parse_entry_module(fs::read(&path).unwrap(), &path, &mut context)
+        .unwrap();
+    module.load(&mut context).unwrap();
+    module.link(&mut context).unwrap();
+    module.evaluate(&mut context).unwrap();
+
+    let namespace = module.namespace(&mut context).unwrap();
+    let a_from_b = get(&namespace, "aFromB", &mut context);
+    let a = a_from_b
+        .as_callable()
+        .unwrap()
+        .call(&JsValue::undefined(), &[], &mut context)
+        .unwrap();
+    assert_eq!(a, JsValue::new("a"));
+    assert_eq!(context.eval("evaluations").unwrap(), JsValue::new(1));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn symbolic_links_cannot_escape_the_root() {
+    let dir = module_dir("symlink-escape");
+    let root = dir.join("root");
+    fs::create_dir(&root).unwrap();
+    fs::write(dir.join("secret.js"), "export const secret = 42;").unwrap();
+    std::os::unix::fs::symlink(dir.join("secret.js"), root.join("link.js")).unwrap();
+
+    let loader = SimpleModuleLoader::new(&root).unwrap();
+    let mut context = Context::builder().module_loader(Rc::new(loader)).build();
+    assert!(context.eval_module(r#"import "./link.js";"#).is_err());
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_paths_are_rejected() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let dir = module_dir("non-utf8");
+    let path = dir.join(OsStr::from_bytes(b"\xFF.js"));
+    if fs::write(&path, "export const a = 1;").is_err() {
+        // The file system does not allow the name either.
+        fs::remove_dir_all(dir).unwrap();
+        return;
+    }
+
+    let loader = SimpleModuleLoader::new(&dir).unwrap();
+    let mut context = Context::default();
+    let error = loader
+        .parse_entry_module(fs::read(&path).unwrap(), &path, &mut context)
+        .unwrap_err();
+    assert_eq!(error.as_native().unwrap().kind(), &JsNativeErrorKind::Type);
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -33,9 +33,9 @@ pub(crate) static GLOBAL_INTERNAL_METHODS: InternalObjectMethods = InternalObjec
 #[inline]
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn global_get_own_property(
-    _obj: &JsObject,
+    obj: &JsObject,
     key: &PropertyKey,
-    context: &mut Context,
+    _context: &mut Context,
 ) -> JsResult<Option<PropertyDescriptor>> {
     let _timer = Profiler::global().start_event("Object::global_get_own_property", "object");
     // 1. Assert: IsPropertyKey(P) is true.
@@ -52,7 +52,7 @@ pub(crate) fn global_get_own_property(
     // 7. Set D.[[Enumerable]] to the value of X's [[Enumerable]] attribute.
     // 8. Set D.[[Configurable]] to the value of X's [[Configurable]] attribute.
     // 9. Return D.
-    Ok(context.realm.global_property_map.get(key))
+    Ok(obj.borrow().properties().get(key))
 }
 
 /// Abstract operation `OrdinaryIsExtensible`.
@@ -63,9 +63,9 @@ pub(crate) fn global_get_own_property(
 /// [spec]: https://tc39.es/ecma262/#sec-ordinaryisextensible
 #[inline]
 #[allow(clippy::unnecessary_wraps)]
-pub(crate) fn global_is_extensible(_obj: &JsObject, context: &mut Context) -> JsResult<bool> {
+pub(crate) fn global_is_extensible(obj: &JsObject, _context: &mut Context) -> JsResult<bool> {
     // 1. Return O.[[Extensible]].
-    Ok(context.realm.global_extensible)
+    Ok(obj.borrow().extensible)
 }
 
 /// Abstract operation `OrdinaryPreventExtensions`.
@@ -76,9 +76,9 @@ pub(crate) fn global_is_extensible(_obj: &JsObject, context: &mut Context) -> Js
 /// [spec]: https://tc39.es/ecma262/#sec-ordinarypreventextensions
 #[inline]
 #[allow(clippy::unnecessary_wraps)]
-pub(crate) fn global_prevent_extensions(_obj: &JsObject, context: &mut Context) -> JsResult<bool> {
+pub(crate) fn global_prevent_extensions(obj: &JsObject, _context: &mut Context) -> JsResult<bool> {
     // 1. Set O.[[Extensible]] to false.
-    context.realm.global_extensible = false;
+    obj.borrow_mut().extensible = false;
 
     // 2. Return true.
     Ok(true)
@@ -107,7 +107,7 @@ pub(crate) fn global_define_own_property(
 
     // 3. Return ValidateAndApplyPropertyDescriptor(O, P, extensible, Desc, current).
     Ok(validate_and_apply_property_descriptor(
-        &key, extensible, desc, current, context,
+        obj, &key, extensible, desc, current,
     ))
 }
 
@@ -120,12 +120,12 @@ pub(crate) fn global_define_own_property(
 #[inline]
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn global_has_property(
-    _obj: &JsObject,
+    obj: &JsObject,
     key: &PropertyKey,
-    context: &mut Context,
+    _context: &mut Context,
 ) -> JsResult<bool> {
     let _timer = Profiler::global().start_event("Object::global_has_property", "object");
-    Ok(context.realm.global_property_map.contains_key(key))
+    Ok(obj.borrow().properties().contains_key(key))
 }
 
 /// Abstract operation `OrdinaryGet`.
@@ -177,17 +177,18 @@ pub(crate) fn global_get(
 #[inline]
 #[allow(clippy::needless_pass_by_value)]
 pub(crate) fn global_set(
-    _obj: &JsObject,
+    obj: &JsObject,
     key: PropertyKey,
     value: JsValue,
     _receiver: JsValue,
     context: &mut Context,
 ) -> JsResult<bool> {
-    global_set_no_receiver(&key, value, context)
+    global_set_no_receiver(obj, &key, value, context)
 }
 
 #[inline]
 pub(crate) fn global_set_no_receiver(
+    obj: &JsObject,
     key: &PropertyKey,
     value: JsValue,
     context: &mut Context,
@@ -202,7 +203,7 @@ pub(crate) fn global_set_no_receiver(
     // https://tc39.es/ecma262/multipage/ordinary-and-exotic-objects-behaviours.html#sec-ordinarysetwithowndescriptor
 
     // 1. Assert: IsPropertyKey(P) is true.
-    let own_desc = if let Some(desc) = context.realm.global_property_map.get(key) {
+    let own_desc = if let Some(desc) = obj.borrow().properties().get(key) {
         desc
     }
     // c. Else,
@@ -224,7 +225,7 @@ pub(crate) fn global_set_no_receiver(
 
         // c. Let existingDescriptor be ? Receiver.[[GetOwnProperty]](P).
         // d. If existingDescriptor is not undefined, then
-        let desc = if let Some(existing_desc) = context.realm.global_property_map.get(key) {
+        let desc = if let Some(existing_desc) = obj.borrow().properties().get(key) {
             // i. If IsAccessorDescriptor(existingDescriptor) is true, return false.
             if existing_desc.is_accessor_descriptor() {
                 return Ok(false);
@@ -250,14 +251,14 @@ pub(crate) fn global_set_no_receiver(
         };
 
         // 1. Let current be ? O.[[GetOwnProperty]](P).
-        let current = context.realm.global_property_map.get(key);
+        let current = obj.borrow().properties().get(key);
 
         // 2. Let extensible be ? IsExtensible(O).
-        let extensible = context.realm.global_extensible;
+        let extensible = obj.borrow().extensible;
 
         // 3. Return ValidateAndApplyPropertyDescriptor(O, P, extensible, Desc, current).
         return Ok(validate_and_apply_property_descriptor(
-            key, extensible, desc, current, context,
+            obj, key, extensible, desc, current,
         ));
     }
 
@@ -268,7 +269,7 @@ pub(crate) fn global_set_no_receiver(
     match own_desc.set() {
         Some(set) if !set.is_undefined() => {
             // 7. Perform ? Call(setter, Receiver, « V »).
-            context.call(set, &context.global_object().clone().into(), &[value])?;
+            context.call(set, &obj.clone().into(), &[value])?;
 
             // 8. Return true.
             Ok(true)
@@ -287,18 +288,19 @@ pub(crate) fn global_set_no_receiver(
 #[inline]
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn global_delete(
-    _obj: &JsObject,
+    obj: &JsObject,
     key: &PropertyKey,
-    context: &mut Context,
+    _context: &mut Context,
 ) -> JsResult<bool> {
     let _timer = Profiler::global().start_event("Object::global_delete", "object");
     // 1. Assert: IsPropertyKey(P) is true.
     // 2. Let desc be ? O.[[GetOwnProperty]](P).
-    match context.realm.global_property_map.get(key) {
+    let desc = obj.borrow().properties().get(key);
+    match desc {
         // 4. If desc.[[Configurable]] is true, then
         Some(desc) if desc.expect_configurable() => {
             // a. Remove the own property with name P from O.
-            context.realm.global_property_map.remove(key);
+            obj.borrow_mut().properties_mut().remove(key);
             // b. Return true.
             Ok(true)
         }
@@ -317,11 +319,11 @@ pub(crate) fn global_delete(
 /// [spec]: https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
 #[inline]
 pub(crate) fn validate_and_apply_property_descriptor(
+    obj: &JsObject,
     key: &PropertyKey,
     extensible: bool,
     desc: PropertyDescriptor,
     current: Option<PropertyDescriptor>,
-    context: &mut Context,
 ) -> bool {
     let _timer = Profiler::global().start_event(
         "Object::global_validate_and_apply_property_descriptor",
@@ -340,7 +342,7 @@ pub(crate) fn validate_and_apply_property_descriptor(
         }
 
         // b. Assert: extensible is true.
-        context.realm.global_property_map.insert(
+        obj.borrow_mut().properties_mut().insert(
             key,
             // c. If IsGenericDescriptor(Desc) is true or IsDataDescriptor(Desc) is true, then
             if desc.is_generic_descriptor() || desc.is_data_descriptor() {
@@ -454,7 +456,7 @@ pub(crate) fn validate_and_apply_property_descriptor(
     // a. For each field of Desc that is present, set the corresponding attribute of the
     // property named P of object O to the value of the field.
     current.fill_with(&desc);
-    context.realm.global_property_map.insert(key, current);
+    obj.borrow_mut().properties_mut().insert(key, current);
 
     // 10. Return true.
     true
@@ -21,6 +21,7 @@ pub(super) mod bound_function;
 pub(super) mod function;
 pub(crate) mod global;
 pub(super) mod integer_indexed;
+pub(super) mod module_namespace;
 pub(super) mod proxy;
 pub(super) mod string;
 
@@ -297,6 +298,36 @@ pub(crate) static ORDINARY_INTERNAL_METHODS: InternalObjectMethods = InternalObj
     __construct__: None,
 };
 
+impl InternalObjectMethods {
+    /// Checks if `[[GetPrototypeOf]]`, `[[GetOwnProperty]]` and `[[Get]]` are the ordinary
+    /// internal methods, which get the string keyed properties from the property map of the
+    /// object.
+    #[inline]
+    pub(crate) fn has_ordinary_get(&self) -> bool {
+        self.__get_prototype_of__ as usize
+            == ORDINARY_INTERNAL_METHODS.__get_prototype_of__ as usize
+            && self.__get_own_property__ as usize
+                == ORDINARY_INTERNAL_METHODS.__get_own_property__ as usize
+            && self.__get__ as usize == ORDINARY_INTERNAL_METHODS.__get__ as usize
+    }
+
+    /// Checks if `[[GetPrototypeOf]]`, `[[IsExtensible]]`, `[[GetOwnProperty]]`,
+    /// `[[DefineOwnProperty]]` and `[[Set]]` are the ordinary internal methods, which set the
+    /// string keyed properties in the property map of the object.
+    #[inline]
+    pub(crate) fn has_ordinary_set(&self) -> bool {
+        self.__get_prototype_of__ as usize
+            == ORDINARY_INTERNAL_METHODS.__get_prototype_of__ as usize
+            && self.__is_extensible__ as usize
+                == ORDINARY_INTERNAL_METHODS.__is_extensible__ as usize
+            && self.__get_own_property__ as usize
+                == ORDINARY_INTERNAL_METHODS.__get_own_property__ as usize
+            && self.__define_own_property__ as usize
+                == ORDINARY_INTERNAL_METHODS.__define_own_property__ as usize
+            && self.__set__ as usize == ORDINARY_INTERNAL_METHODS.__set__ as usize
+    }
+}
+
 /// The internal representation of the internal methods of a `JsObject`.
 ///
 /// This struct allows us to dynamically dispatch exotic objects with their
@@ -489,12 +520,14 @@ pub(crate) fn ordinary_define_own_property(
     let extensible = obj.__is_extensible__(context)?;
 
     // 3. Return ValidateAndApplyPropertyDescriptor(O, P, extensible, Desc, current).
-    Ok(validate_and_apply_property_descriptor(
-        Some((obj, key)),
-        extensible,
-        desc,
-        current,
-    ))
+    let defined =
+        validate_and_apply_property_descriptor(Some((obj, key)), extensible, desc, current);
+
+    // Builtins like `Array.prototype.fill` can define any number of properties without running
+    // a single instruction, so the heap limit is also checked here.
+    context.check_heap_limit()?;
+
+    Ok(defined)
 }
 
 /// Abstract operation `OrdinaryHasProperty`.
@@ -958,10 +991,13 @@ where
         if let Some(proto) = object.get(PROTOTYPE, context)?.as_object() {
             return Ok(proto.clone());
         }
+
+        // 3. If Type(proto) is not Object, then
+        // a. Let realm be ? GetFunctionRealm(constructor).
+        let realm = object.get_function_realm(context)?;
+
+        // b. Set proto to realm's intrinsic object named intrinsicDefaultProto.
+        return Ok(default(realm.intrinsics().constructors()).prototype());
     }
-    // 3. If Type(proto) is not Object, then
-    // TODO: handle realms
-    // a. Let realm be ? GetFunctionRealm(constructor).
-    // b. Set proto to realm's intrinsic object named intrinsicDefaultProto.
     Ok(default(context.intrinsics().constructors()).prototype())
 }
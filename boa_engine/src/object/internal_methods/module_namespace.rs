@@ -0,0 +1,254 @@
+use crate::{
+    object::JsObject,
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsResult, JsValue,
+};
+
+use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
+
+/// Definitions of the internal object methods for module namespace exotic objects.
+///
+/// The `[[GetPrototypeOf]]`, `[[SetPrototypeOf]]`, `[[IsExtensible]]` and `[[PreventExtensions]]`
+/// internal methods behave like the ordinary ones, since namespace objects are created with a
+/// `null` prototype and are never extensible.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+pub(crate) static MODULE_NAMESPACE_EXOTIC_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        __get_own_property__: module_namespace_exotic_get_own_property,
+        __define_own_property__: module_namespace_exotic_define_own_property,
+        __has_property__: module_namespace_exotic_has_property,
+        __get__: module_namespace_exotic_get,
+        __set__: module_namespace_exotic_set,
+        __delete__: module_namespace_exotic_delete,
+        __own_property_keys__: module_namespace_exotic_own_property_keys,
+        ..ORDINARY_INTERNAL_METHODS
+    };
+
+/// `[[GetOwnProperty]]` for module namespace exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-getownproperty-p
+#[inline]
+pub(crate) fn module_namespace_exotic_get_own_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<Option<PropertyDescriptor>> {
+    // 1. If Type(P) is Symbol, return OrdinaryGetOwnProperty(O, P).
+    if let PropertyKey::Symbol(_) = key {
+        return super::ordinary_get_own_property(obj, key, context);
+    }
+
+    // 2. Let exports be O.[[Exports]].
+    // 3. If P is not an element of exports, return undefined.
+    if !has_export(obj, key) {
+        return Ok(None);
+    }
+
+    // 4. Let value be ? O.[[Get]](P, O).
+    let value = module_namespace_exotic_get(obj, key, obj.clone().into(), context)?;
+
+    // 5. Return PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: false }.
+    Ok(Some(
+        PropertyDescriptor::builder()
+            .value(value)
+            .writable(true)
+            .enumerable(true)
+            .configurable(false)
+            .build(),
+    ))
+}
+
+/// `[[DefineOwnProperty]]` for module namespace exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-defineownproperty-p-desc
+#[inline]
+pub(crate) fn module_namespace_exotic_define_own_property(
+    obj: &JsObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. If Type(P) is Symbol, return ! OrdinaryDefineOwnProperty(O, P, Desc).
+    if let PropertyKey::Symbol(_) = key {
+        return super::ordinary_define_own_property(obj, key, desc, context);
+    }
+
+    // 2. Let current be ? O.[[GetOwnProperty]](P).
+    // 3. If current is undefined, return false.
+    let current = match module_namespace_exotic_get_own_property(obj, &key, context)? {
+        Some(current) => current,
+        None => return Ok(false),
+    };
+
+    // 4. If Desc has a [[Configurable]] field and Desc.[[Configurable]] is true, return false.
+    // 5. If Desc has an [[Enumerable]] field and Desc.[[Enumerable]] is false, return false.
+    // 6. If IsAccessorDescriptor(Desc) is true, return false.
+    // 7. If Desc has a [[Writable]] field and Desc.[[Writable]] is false, return false.
+    if desc.configurable() == Some(true)
+        || desc.enumerable() == Some(false)
+        || desc.is_accessor_descriptor()
+        || desc.writable() == Some(false)
+    {
+        return Ok(false);
+    }
+
+    // 8. If Desc has a [[Value]] field, return SameValue(Desc.[[Value]], current.[[Value]]).
+    // 9. Return true.
+    Ok(desc.value().map_or(true, |value| {
+        JsValue::same_value(value, current.expect_value())
+    }))
+}
+
+/// `[[HasProperty]]` for module namespace exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-hasproperty-p
+#[inline]
+pub(crate) fn module_namespace_exotic_has_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. If Type(P) is Symbol, return ! OrdinaryHasProperty(O, P).
+    if let PropertyKey::Symbol(_) = key {
+        return super::ordinary_has_property(obj, key, context);
+    }
+
+    // 2. Let exports be O.[[Exports]].
+    // 3. If P is an element of exports, return true.
+    // 4. Return false.
+    Ok(has_export(obj, key))
+}
+
+/// `[[Get]]` for module namespace exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-get-p-receiver
+#[inline]
+pub(crate) fn module_namespace_exotic_get(
+    obj: &JsObject,
+    key: &PropertyKey,
+    receiver: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If Type(P) is Symbol, then
+    if let PropertyKey::Symbol(_) = key {
+        // a. Return ! OrdinaryGet(O, P, Receiver).
+        return super::ordinary_get(obj, key, receiver, context);
+    }
+
+    // 2. Let exports be O.[[Exports]].
+    // 3. If P is not an element of exports, return undefined.
+    let (module, name) = {
+        let obj = obj.borrow();
+        let namespace = obj
+            .as_module_namespace()
+            .expect("object must be a module namespace");
+        match namespace.export_name(key) {
+            Some(name) => (namespace.module().clone(), name),
+            None => return Ok(JsValue::undefined()),
+        }
+    };
+
+    // 4. Let m be O.[[Module]].
+    // 5-12. Resolve the binding and return its value.
+    module.get_export_value(name, context)
+}
+
+/// `[[Set]]` for module namespace exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-set-p-v-receiver
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn module_namespace_exotic_set(
+    _obj: &JsObject,
+    _key: PropertyKey,
+    _value: JsValue,
+    _receiver: JsValue,
+    _context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Return false.
+    Ok(false)
+}
+
+/// `[[Delete]]` for module namespace exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-delete-p
+#[inline]
+pub(crate) fn module_namespace_exotic_delete(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. If Type(P) is Symbol, then
+    if let PropertyKey::Symbol(_) = key {
+        // a. Return ! OrdinaryDelete(O, P).
+        return super::ordinary_delete(obj, key, context);
+    }
+
+    // 2. Let exports be O.[[Exports]].
+    // 3. If P is an element of exports, return false.
+    // 4. Return true.
+    Ok(!has_export(obj, key))
+}
+
+/// `[[OwnPropertyKeys]]` for module namespace exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-ownpropertykeys
+#[inline]
+pub(crate) fn module_namespace_exotic_own_property_keys(
+    obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<Vec<PropertyKey>> {
+    // 1. Let exports be O.[[Exports]].
+    let mut keys: Vec<PropertyKey> = obj
+        .borrow()
+        .as_module_namespace()
+        .expect("object must be a module namespace")
+        .exports()
+        .map(|export| export.clone().into())
+        .collect();
+
+    // 2. Let symbolKeys be OrdinaryOwnPropertyKeys(O).
+    // 3. Return the list-concatenation of exports and symbolKeys.
+    keys.extend(
+        super::ordinary_own_property_keys(obj, context)?
+            .into_iter()
+            .filter(|key| matches!(key, PropertyKey::Symbol(_))),
+    );
+
+    Ok(keys)
+}
+
+/// Returns `true` if `key` is an element of the `[[Exports]]` of the namespace object.
+#[inline]
+fn has_export(obj: &JsObject, key: &PropertyKey) -> bool {
+    obj.borrow()
+        .as_module_namespace()
+        .expect("object must be a module namespace")
+        .export_name(key)
+        .is_some()
+}
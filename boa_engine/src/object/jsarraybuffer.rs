@@ -1,9 +1,11 @@
 use crate::{
     builtins::array_buffer::ArrayBuffer,
     context::intrinsics::StandardConstructors,
+    error::JsNativeError,
     object::{
         internal_methods::get_prototype_from_constructor, JsObject, JsObjectType, ObjectData,
     },
+    vm::HeapCharge,
     Context, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
@@ -27,6 +29,32 @@ impl JsArrayBuffer {
                 .constructor()
                 .into(),
             byte_length as u64,
+            None,
+            context,
+        )?;
+
+        Ok(Self { inner })
+    }
+
+    /// Create a new resizable array buffer with byte length, which can be resized up to
+    /// `max_byte_length` bytes.
+    ///
+    /// Throws a `RangeError` if `byte_length` is greater than `max_byte_length`.
+    #[inline]
+    pub fn new_resizable(
+        byte_length: usize,
+        max_byte_length: usize,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let inner = ArrayBuffer::allocate(
+            &context
+                .intrinsics()
+                .constructors()
+                .array_buffer()
+                .constructor()
+                .into(),
+            byte_length as u64,
+            Some(max_byte_length as u64),
             context,
         )?;
 
@@ -67,8 +95,10 @@ impl JsArrayBuffer {
         // 3. Set obj.[[ArrayBufferData]] to block.
         // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
         obj.borrow_mut().data = ObjectData::array_buffer(ArrayBuffer {
-            array_buffer_data: Some(block),
+            data_charge: HeapCharge::new(block.capacity()),
+            array_buffer_data: Some(block.into()),
             array_buffer_byte_length: byte_length as u64,
+            array_buffer_max_byte_length: None,
             array_buffer_detach_key: JsValue::Undefined,
         });
 
@@ -77,13 +107,21 @@ impl JsArrayBuffer {
 
     /// Create a [`JsArrayBuffer`] from a [`JsObject`], if the object is not an array buffer throw a `TypeError`.
     ///
+    /// `SharedArrayBuffer` objects are not array buffers, use [`JsSharedArrayBuffer`][super::JsSharedArrayBuffer] for them.
+    ///
     /// This does not clone the fields of the array buffer, it only does a shallow clone of the object.
     #[inline]
-    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
-        if object.borrow().is_array_buffer() {
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object
+            .borrow()
+            .as_array_buffer()
+            .map_or(false, |buffer| !buffer.is_shared_array_buffer())
+        {
             Ok(Self { inner: object })
         } else {
-            context.throw_type_error("object is not an ArrayBuffer")
+            Err(JsNativeError::typ()
+                .with_message("object is not an ArrayBuffer")
+                .into())
         }
     }
 
@@ -95,6 +133,62 @@ impl JsArrayBuffer {
             .as_number()
             .expect("expected a number") as usize
     }
+
+    /// Returns the maximum byte length the array buffer can be resized to, which is its byte
+    /// length if it is not resizable.
+    #[inline]
+    pub fn max_byte_length(&self, context: &mut Context) -> usize {
+        ArrayBuffer::get_max_byte_length(&self.inner.clone().into(), &[], context)
+            .expect("it should not throw")
+            .as_number()
+            .expect("expected a number") as usize
+    }
+
+    /// Returns `true` if the array buffer can be resized.
+    #[inline]
+    pub fn is_resizable(&self) -> bool {
+        !self
+            .inner
+            .borrow()
+            .as_array_buffer()
+            .expect("must be an ArrayBuffer")
+            .is_fixed_length_array_buffer()
+    }
+
+    /// Resizes the array buffer to `new_byte_length` bytes.
+    ///
+    /// Throws a `TypeError` if the array buffer is not resizable or is detached, and a
+    /// `RangeError` if `new_byte_length` is greater than its maximum byte length.
+    #[inline]
+    pub fn resize(&self, new_byte_length: usize, context: &mut Context) -> JsResult<()> {
+        ArrayBuffer::resize(
+            &self.inner.clone().into(),
+            &[new_byte_length.into()],
+            context,
+        )?;
+
+        Ok(())
+    }
+
+    /// Moves the contents of the array buffer into a new array buffer of `new_byte_length`
+    /// bytes, or of the same byte length if it is `None`, detaching this one.
+    ///
+    /// The new array buffer is resizable if this one is, with the same maximum byte length.
+    #[inline]
+    pub fn transfer(
+        &self,
+        new_byte_length: Option<usize>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let inner = ArrayBuffer::copy_and_detach(
+            &self.inner,
+            &new_byte_length.map_or_else(JsValue::undefined, Into::into),
+            true,
+            context,
+        )?;
+
+        Ok(Self { inner })
+    }
 }
 
 impl From<JsArrayBuffer> for JsObject {
@@ -0,0 +1,241 @@
+//! This module implements a wrapper for the `DataView` Builtin Javascript Object
+use crate::{
+    builtins::DataView,
+    error::JsNativeError,
+    object::{JsArrayBuffer, JsObject, JsObjectType},
+    Context, JsBigInt, JsResult, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use num_traits::ToPrimitive;
+use std::ops::Deref;
+
+/// `JsDataView` provides a wrapper for Boa's implementation of the Javascript `DataView` object.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    object::{JsArrayBuffer, JsDataView},
+/// #    Context,
+/// # };
+///
+/// // Create a default `Context`
+/// let context = &mut Context::default();
+///
+/// // Create a view over the last 4 bytes of an 8 byte buffer.
+/// let buffer = JsArrayBuffer::new(8, context).unwrap();
+/// let view = JsDataView::from_js_array_buffer(&buffer, Some(4), None, context).unwrap();
+/// assert_eq!(view.byte_length(context).unwrap(), 4);
+///
+/// view.set_uint16(0, 0xABCD, true, context).unwrap();
+/// assert_eq!(view.get_uint8(0, false, context).unwrap(), 0xCD);
+/// assert_eq!(view.get_uint16(0, false, context).unwrap(), 0xCDAB);
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsDataView {
+    inner: JsObject,
+}
+
+macro_rules! number_accessors {
+    ($(($get:ident, $set:ident, $ty:ty)),* $(,)?) => {
+        $(
+            #[doc = concat!("Reads the `", stringify!($ty), "` value at `byte_offset` from the start of the view.")]
+            #[inline]
+            #[allow(trivial_numeric_casts)]
+            pub fn $get(
+                &self,
+                byte_offset: u64,
+                little_endian: bool,
+                context: &mut Context,
+            ) -> JsResult<$ty> {
+                let value = DataView::$get(
+                    &self.inner.clone().into(),
+                    &[byte_offset.into(), little_endian.into()],
+                    context,
+                )?;
+                Ok(value.as_number().expect("view value must be a number") as $ty)
+            }
+
+            #[doc = concat!("Writes the `", stringify!($ty), "` value at `byte_offset` from the start of the view.")]
+            #[inline]
+            pub fn $set(
+                &self,
+                byte_offset: u64,
+                value: $ty,
+                little_endian: bool,
+                context: &mut Context,
+            ) -> JsResult<()> {
+                DataView::$set(
+                    &self.inner.clone().into(),
+                    &[byte_offset.into(), value.into(), little_endian.into()],
+                    context,
+                )?;
+                Ok(())
+            }
+        )*
+    };
+}
+
+macro_rules! bigint_accessors {
+    ($(($get:ident, $set:ident, $ty:ty, $to:ident)),* $(,)?) => {
+        $(
+            #[doc = concat!("Reads the `", stringify!($ty), "` value at `byte_offset` from the start of the view.")]
+            #[inline]
+            pub fn $get(
+                &self,
+                byte_offset: u64,
+                little_endian: bool,
+                context: &mut Context,
+            ) -> JsResult<$ty> {
+                let value = DataView::$get(
+                    &self.inner.clone().into(),
+                    &[byte_offset.into(), little_endian.into()],
+                    context,
+                )?;
+                Ok(value
+                    .as_bigint()
+                    .and_then(|bigint| bigint.as_inner().$to())
+                    .expect("view value must be a BigInt in range"))
+            }
+
+            #[doc = concat!("Writes the `", stringify!($ty), "` value at `byte_offset` from the start of the view.")]
+            #[inline]
+            pub fn $set(
+                &self,
+                byte_offset: u64,
+                value: $ty,
+                little_endian: bool,
+                context: &mut Context,
+            ) -> JsResult<()> {
+                DataView::$set(
+                    &self.inner.clone().into(),
+                    &[
+                        byte_offset.into(),
+                        JsBigInt::from(value).into(),
+                        little_endian.into(),
+                    ],
+                    context,
+                )?;
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl JsDataView {
+    /// Creates a new view over `buffer`, starting at `byte_offset` and spanning `byte_length`
+    /// bytes.
+    ///
+    /// Same as calling `new DataView(buffer, byteOffset, byteLength)` in JavaScript. If the
+    /// length is `None`, the view spans until the end of the buffer.
+    #[inline]
+    pub fn from_js_array_buffer(
+        buffer: &JsArrayBuffer,
+        byte_offset: Option<u64>,
+        byte_length: Option<u64>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .data_view()
+            .constructor()
+            .into();
+        let view = DataView::constructor(
+            &constructor,
+            &[
+                buffer.clone().into(),
+                byte_offset.map_or_else(JsValue::undefined, Into::into),
+                byte_length.map_or_else(JsValue::undefined, Into::into),
+            ],
+            context,
+        )?;
+        Self::from_object(
+            view.as_object()
+                .expect("DataView constructor must return an object")
+                .clone(),
+        )
+    }
+
+    /// Creates a [`JsDataView`] from a [`JsObject`], or returns a `TypeError` if the provided
+    /// object is not a `DataView`.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.borrow().is_data_view() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a DataView")
+                .into())
+        }
+    }
+
+    /// Returns the array buffer viewed by the data view.
+    #[inline]
+    pub fn buffer(&self, context: &mut Context) -> JsResult<JsArrayBuffer> {
+        let buffer = DataView::get_buffer(&self.inner.clone().into(), &[], context)?;
+        JsArrayBuffer::from_object(
+            buffer
+                .as_object()
+                .expect("the viewed buffer must be an object")
+                .clone(),
+        )
+    }
+
+    /// Returns the length in bytes of the view, or a `TypeError` if its buffer is detached.
+    #[inline]
+    pub fn byte_length(&self, context: &mut Context) -> JsResult<u64> {
+        let length = DataView::get_byte_length(&self.inner.clone().into(), &[], context)?;
+        Ok(length.as_number().expect("byte length must be a number") as u64)
+    }
+
+    /// Returns the offset in bytes of the view from the start of its buffer, or a `TypeError` if
+    /// its buffer is detached.
+    #[inline]
+    pub fn byte_offset(&self, context: &mut Context) -> JsResult<u64> {
+        let offset = DataView::get_byte_offset(&self.inner.clone().into(), &[], context)?;
+        Ok(offset.as_number().expect("byte offset must be a number") as u64)
+    }
+
+    number_accessors! {
+        (get_int8, set_int8, i8),
+        (get_uint8, set_uint8, u8),
+        (get_int16, set_int16, i16),
+        (get_uint16, set_uint16, u16),
+        (get_int32, set_int32, i32),
+        (get_uint32, set_uint32, u32),
+        (get_float32, set_float32, f32),
+        (get_float64, set_float64, f64),
+    }
+
+    bigint_accessors! {
+        (get_big_int64, set_big_int64, i64, to_i64),
+        (get_big_uint64, set_big_uint64, u64, to_u64),
+    }
+}
+
+impl From<JsDataView> for JsObject {
+    #[inline]
+    fn from(o: JsDataView) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsDataView> for JsValue {
+    #[inline]
+    fn from(o: JsDataView) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsDataView {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsDataView {}
@@ -0,0 +1,139 @@
+//! This module implements a wrapper for the `Date` Builtin Javascript Object
+use crate::{
+    builtins::Date,
+    error::JsNativeError,
+    object::{JsObject, JsObjectType, ObjectData},
+    Context, JsResult, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use chrono::{DateTime, Local, TimeZone, Utc};
+use std::ops::Deref;
+
+/// `JsDate` provides a wrapper for Boa's implementation of the Javascript `Date` object.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    object::JsDate,
+/// #    Context,
+/// # };
+/// # use chrono::{TimeZone, Utc};
+///
+/// // Create a default `Context`
+/// let context = &mut Context::default();
+///
+/// // Create a `JsDate` from a `chrono` date and time.
+/// let datetime = Utc.ymd(2022, 11, 3).and_hms(12, 30, 0);
+/// let date = JsDate::from_datetime(&datetime, context);
+///
+/// assert_eq!(date.get_time(), 1_667_478_600_000.0);
+/// assert_eq!(date.to_datetime(), Some(datetime));
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsDate {
+    inner: JsObject,
+}
+
+impl JsDate {
+    /// Creates a new [`JsDate`] representing the current date and time.
+    ///
+    /// Same as calling `new Date()` in JavaScript.
+    #[inline]
+    pub fn new(context: &mut Context) -> Self {
+        Self::from_date(Date::default(), context)
+    }
+
+    /// Creates a new [`JsDate`] representing the given `chrono` date and time.
+    #[inline]
+    pub fn from_datetime<Tz>(datetime: &DateTime<Tz>, context: &mut Context) -> Self
+    where
+        Tz: TimeZone,
+    {
+        Self::from_date(Date::new(Some(datetime.naive_utc())), context)
+    }
+
+    fn from_date(date: Date, context: &mut Context) -> Self {
+        let prototype = context.intrinsics().constructors().date().prototype();
+        Self {
+            inner: JsObject::from_proto_and_data(prototype, ObjectData::date(date)),
+        }
+    }
+
+    /// Creates a [`JsDate`] from a [`JsObject`], or returns a `TypeError` if the provided
+    /// object is not a `Date`.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.borrow().is_date() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a Date")
+                .into())
+        }
+    }
+
+    fn date(&self) -> Date {
+        *self
+            .inner
+            .borrow()
+            .as_date()
+            .expect("`JsDate` must always wrap a date")
+    }
+
+    /// Returns the number of milliseconds since the Unix Epoch, or `NaN` if the date is invalid.
+    ///
+    /// Same as JavaScript's `date.getTime()`.
+    #[inline]
+    pub fn get_time(&self) -> f64 {
+        self.date().get_time()
+    }
+
+    /// Converts the date to a UTC `chrono` date and time, or returns `None` if the date is invalid.
+    #[inline]
+    pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        self.date().to_utc()
+    }
+
+    /// Converts the date to a `chrono` date and time in the local time zone, or returns `None` if
+    /// the date is invalid.
+    #[inline]
+    pub fn to_local_datetime(&self) -> Option<DateTime<Local>> {
+        self.date().to_local()
+    }
+
+    /// Returns the date formatted as an ISO 8601 string, or a `RangeError` if the date is
+    /// invalid.
+    ///
+    /// Same as JavaScript's `date.toISOString()`.
+    #[inline]
+    pub fn to_iso_string(&self, context: &mut Context) -> JsResult<JsValue> {
+        Date::to_iso_string(&self.inner.clone().into(), &[], context)
+    }
+}
+
+impl From<JsDate> for JsObject {
+    #[inline]
+    fn from(o: JsDate) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsDate> for JsValue {
+    #[inline]
+    fn from(o: JsDate) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsDate {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsDate {}
@@ -1,6 +1,8 @@
 use crate::{
+    error::JsNativeError,
     object::{JsObject, JsObjectType},
-    JsValue,
+    value::{IntoJs, TryFromJs},
+    Context, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use std::ops::Deref;
@@ -26,8 +28,136 @@ impl JsFunction {
             .is_callable()
             .then(|| Self::from_object_unchecked(object))
     }
+
+    /// Calls the function with the given `this` value and arguments.
+    ///
+    /// Same as JavaScript's `function.call(this, ...args)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{object::JsFunction, Context, JsValue};
+    /// let mut context = Context::default();
+    ///
+    /// let add = context.eval("(a, b) => a + b").unwrap();
+    /// let add = JsFunction::from_object(add.as_object().unwrap().clone()).unwrap();
+    ///
+    /// let sum = add
+    ///     .call(&JsValue::undefined(), &[1.into(), 2.into()], &mut context)
+    ///     .unwrap();
+    /// assert_eq!(sum, JsValue::new(3));
+    /// ```
+    #[inline]
+    pub fn call(
+        &self,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        self.inner.__call__(this, args, context)
+    }
+
+    /// Calls the function with arguments converted with [`IntoJs`], and converts its result
+    /// with [`TryFromJs`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{object::JsFunction, Context, JsValue};
+    /// let mut context = Context::default();
+    ///
+    /// let join = context.eval("(words, sep) => words.join(sep)").unwrap();
+    /// let join = JsFunction::from_object(join.as_object().unwrap().clone()).unwrap();
+    ///
+    /// let sentence: String = join
+    ///     .call_with(&JsValue::undefined(), (vec!["a", "b"], "-"), &mut context)
+    ///     .unwrap();
+    /// assert_eq!(sentence, "a-b");
+    /// ```
+    #[inline]
+    pub fn call_with<A, R>(&self, this: &JsValue, args: A, context: &mut Context) -> JsResult<R>
+    where
+        A: IntoJsArgs,
+        R: TryFromJs,
+    {
+        let args = args.into_js_args(context);
+        self.call(this, &args, context)?.try_js_into(context)
+    }
+
+    /// Returns `true` if the function can be called with `new`.
+    #[inline]
+    pub fn is_constructor(&self) -> bool {
+        self.inner.is_constructor()
+    }
+
+    /// Calls the function as a constructor, or returns a `TypeError` if it is not a
+    /// constructor.
+    ///
+    /// Same as JavaScript's `new function(...args)`. If `new_target` is `None`, the function
+    /// itself is used as the `new.target`.
+    #[inline]
+    pub fn construct(
+        &self,
+        args: &[JsValue],
+        new_target: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        if !self.is_constructor() {
+            return Err(JsNativeError::typ()
+                .with_message("function is not a constructor")
+                .into());
+        }
+        self.inner
+            .__construct__(args, new_target.unwrap_or(&self.inner), context)
+    }
 }
 
+/// A list of arguments that can be passed to [`JsFunction::call_with`].
+///
+/// It is implemented for tuples of up to six elements implementing [`IntoJs`], where each
+/// element is converted to one argument, and for slices of [`JsValue`]s.
+pub trait IntoJsArgs {
+    /// Converts `self` to a list of arguments.
+    fn into_js_args(self, context: &mut Context) -> Vec<JsValue>;
+}
+
+impl IntoJsArgs for &[JsValue] {
+    #[inline]
+    fn into_js_args(self, _: &mut Context) -> Vec<JsValue> {
+        self.to_vec()
+    }
+}
+
+impl IntoJsArgs for () {
+    #[inline]
+    fn into_js_args(self, _: &mut Context) -> Vec<JsValue> {
+        Vec::new()
+    }
+}
+
+macro_rules! impl_into_js_args_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name),+> IntoJsArgs for ($($name,)+)
+        where
+            $($name: IntoJs,)+
+        {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn into_js_args(self, context: &mut Context) -> Vec<JsValue> {
+                let ($($name,)+) = self;
+                vec![$($name.into_js(context)),+]
+            }
+        }
+    };
+}
+
+impl_into_js_args_for_tuple!(A);
+impl_into_js_args_for_tuple!(A, B);
+impl_into_js_args_for_tuple!(A, B, C);
+impl_into_js_args_for_tuple!(A, B, C, D);
+impl_into_js_args_for_tuple!(A, B, C, D, E);
+impl_into_js_args_for_tuple!(A, B, C, D, E, F);
+
 impl From<JsFunction> for JsObject {
     #[inline]
     fn from(o: JsFunction) -> Self {
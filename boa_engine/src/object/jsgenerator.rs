@@ -0,0 +1,120 @@
+//! This module implements a wrapper for the `Generator` Builtin Javascript Object
+use crate::{
+    builtins::{generator::Generator, iterable::IteratorResult},
+    error::JsNativeError,
+    object::{JsObject, JsObjectType},
+    Context, JsResult, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// `JsGenerator` provides a wrapper for Boa's implementation of the Javascript `Generator`
+/// object.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    object::JsGenerator,
+/// #    Context, JsValue,
+/// # };
+///
+/// // Create a default `Context`
+/// let context = &mut Context::default();
+///
+/// let generator = context
+///     .eval("(function* () { const x = yield 1; yield x * 2; })()")
+///     .unwrap();
+/// let generator = JsGenerator::from_object(generator.as_object().unwrap().clone()).unwrap();
+///
+/// let first = generator.next(JsValue::undefined(), context).unwrap();
+/// assert_eq!(first.value(context).unwrap(), JsValue::new(1));
+///
+/// let second = generator.next(21, context).unwrap();
+/// assert_eq!(second.value(context).unwrap(), JsValue::new(42));
+///
+/// let last = generator.next(JsValue::undefined(), context).unwrap();
+/// assert!(last.complete(context).unwrap());
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsGenerator {
+    inner: JsObject,
+}
+
+impl JsGenerator {
+    /// Creates a [`JsGenerator`] from a [`JsObject`], or returns a `TypeError` if the provided
+    /// object is not a `Generator`.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.borrow().is_generator() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a Generator")
+                .into())
+        }
+    }
+
+    /// Resumes the generator, passing `value` as the result of the current `yield` expression.
+    ///
+    /// Same as JavaScript's `generator.next(value)`.
+    #[inline]
+    pub fn next<T>(&self, value: T, context: &mut Context) -> JsResult<IteratorResult>
+    where
+        T: Into<JsValue>,
+    {
+        let result = Generator::next(&self.inner.clone().into(), &[value.into()], context)?;
+        Ok(IteratorResult::from_generator_result(&result))
+    }
+
+    /// Finishes the generator, running its `finally` blocks, and returns `value` as the result
+    /// unless a `finally` block overrides it.
+    ///
+    /// Same as JavaScript's `generator.return(value)`.
+    #[inline]
+    pub fn r#return<T>(&self, value: T, context: &mut Context) -> JsResult<IteratorResult>
+    where
+        T: Into<JsValue>,
+    {
+        let result = Generator::r#return(&self.inner.clone().into(), &[value.into()], context)?;
+        Ok(IteratorResult::from_generator_result(&result))
+    }
+
+    /// Resumes the generator by throwing `value` at the current `yield` expression.
+    ///
+    /// Same as JavaScript's `generator.throw(value)`.
+    #[inline]
+    pub fn throw<T>(&self, value: T, context: &mut Context) -> JsResult<IteratorResult>
+    where
+        T: Into<JsValue>,
+    {
+        let result = Generator::throw(&self.inner.clone().into(), &[value.into()], context)?;
+        Ok(IteratorResult::from_generator_result(&result))
+    }
+}
+
+impl From<JsGenerator> for JsObject {
+    #[inline]
+    fn from(o: JsGenerator) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsGenerator> for JsValue {
+    #[inline]
+    fn from(o: JsGenerator) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsGenerator {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsGenerator {}
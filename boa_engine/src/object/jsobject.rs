@@ -4,6 +4,7 @@
 
 use super::{JsPrototype, NativeObject, Object, PropertyMap};
 use crate::{
+    error::JsNativeError,
     object::{ObjectData, ObjectKind},
     property::{PropertyDescriptor, PropertyKey},
     value::PreferredType,
@@ -26,7 +27,11 @@ pub type Ref<'a, T> = boa_gc::Ref<'a, T>;
 pub type RefMut<'a, T, U> = boa_gc::RefMut<'a, T, U>;
 
 /// Garbage collected `Object`.
+///
+/// A `JsObject` has the same layout as the [`Gc`] that it wraps, which `JsValue` relies on to
+/// store objects in place.
 #[derive(Trace, Finalize, Clone, Default)]
+#[repr(transparent)]
 pub struct JsObject {
     inner: Gc<boa_gc::Cell<Object>>,
 }
@@ -116,6 +121,18 @@ impl JsObject {
         std::ptr::eq(lhs.as_ref(), rhs.as_ref())
     }
 
+    /// Returns the garbage collected pointer to the `Object`.
+    #[inline]
+    pub(crate) fn inner(&self) -> &Gc<boa_gc::Cell<Object>> {
+        &self.inner
+    }
+
+    /// Creates a `JsObject` from a garbage collected pointer to an `Object`.
+    #[inline]
+    pub(crate) fn from_inner(inner: Gc<boa_gc::Cell<Object>>) -> Self {
+        Self { inner }
+    }
+
     /// Converts an object to a primitive.
     ///
     /// Diverges from the spec to prevent a stack overflow when the object is recursive.
@@ -186,7 +203,9 @@ impl JsObject {
         }
 
         // 6. Throw a TypeError exception.
-        context.throw_type_error("cannot convert object to primitive value")
+        Err(JsNativeError::typ()
+            .with_message("cannot convert object to primitive value")
+            .into())
     }
 
     /// Return `true` if it is a native object and the native type is `T`.
@@ -546,7 +565,9 @@ impl JsObject {
             // b. If IsCallable(getter) is false and getter is not undefined, throw a TypeError exception.
             // todo: extract IsCallable to be callable from Value
             if !getter.is_undefined() && getter.as_object().map_or(true, |o| !o.is_callable()) {
-                return context.throw_type_error("Property descriptor getter must be callable");
+                return Err(JsNativeError::typ()
+                    .with_message("Property descriptor getter must be callable")
+                    .into());
             }
             // c. Set desc.[[Get]] to getter.
             Some(getter)
@@ -562,7 +583,9 @@ impl JsObject {
             // 14.b. If IsCallable(setter) is false and setter is not undefined, throw a TypeError exception.
             // todo: extract IsCallable to be callable from Value
             if !setter.is_undefined() && setter.as_object().map_or(true, |o| !o.is_callable()) {
-                return context.throw_type_error("Property descriptor setter must be callable");
+                return Err(JsNativeError::typ()
+                    .with_message("Property descriptor setter must be callable")
+                    .into());
             }
             // 14.c. Set desc.[[Set]] to setter.
             Some(setter)
@@ -573,10 +596,12 @@ impl JsObject {
         // 15. If desc.[[Get]] is present or desc.[[Set]] is present, then ...
         // a. If desc.[[Value]] is present or desc.[[Writable]] is present, throw a TypeError exception.
         if get.as_ref().or(set.as_ref()).is_some() && desc.inner().is_data_descriptor() {
-            return context.throw_type_error(
-                "Invalid property descriptor.\
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "Invalid property descriptor.\
 Cannot both specify accessors and a value or writable attribute",
-            );
+                )
+                .into());
         }
 
         desc = desc.maybe_get(get).maybe_set(set);
@@ -0,0 +1,184 @@
+//! This module implements a wrapper for the `Promise` Builtin Javascript Object
+use crate::{
+    builtins::{
+        promise::{PromiseCapability, PromiseState},
+        Promise,
+    },
+    error::JsNativeError,
+    object::{JsFunction, JsObject, JsObjectType},
+    Context, JsError, JsResult, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// The functions that settle a pending [`JsPromise`].
+///
+/// These are the resolving functions that the `Promise` constructor passes to its executor.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ResolvingFunctions {
+    /// Resolves the promise with a value, following it if it is a thenable.
+    pub resolve: JsFunction,
+    /// Rejects the promise with a reason.
+    pub reject: JsFunction,
+}
+
+/// `JsPromise` provides a wrapper for Boa's implementation of the Javascript `Promise` object.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    builtins::promise::PromiseState,
+/// #    object::JsPromise,
+/// #    Context, JsValue,
+/// # };
+///
+/// // Create a default `Context`
+/// let context = &mut Context::default();
+///
+/// // Create a pending promise and fulfill it from Rust.
+/// let (promise, resolvers) = JsPromise::new_pending(context);
+/// assert_eq!(promise.state(), PromiseState::Pending);
+///
+/// resolvers
+///     .resolve
+///     .call(&JsValue::undefined(), &[JsValue::new(5)], context)
+///     .unwrap();
+/// assert_eq!(promise.state(), PromiseState::Fulfilled(JsValue::new(5)));
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsPromise {
+    inner: JsObject,
+}
+
+impl JsPromise {
+    /// Creates a new pending promise, and returns it with the functions that settle it.
+    ///
+    /// Same as calling `new Promise((resolve, reject) => ...)` in JavaScript.
+    #[inline]
+    pub fn new_pending(context: &mut Context) -> (Self, ResolvingFunctions) {
+        let constructor = context.intrinsics().constructors().promise().constructor();
+        let capability = PromiseCapability::new(&constructor.into(), context)
+            .expect("creating a capability of %Promise% cannot fail");
+
+        let promise = Self {
+            inner: capability.promise().clone(),
+        };
+        let resolvers = ResolvingFunctions {
+            resolve: capability.resolve().clone(),
+            reject: capability.reject().clone(),
+        };
+        (promise, resolvers)
+    }
+
+    /// Creates a promise resolved with `value`.
+    ///
+    /// Same as JavaScript's `Promise.resolve(value)`.
+    #[inline]
+    pub fn resolve<V>(value: V, context: &mut Context) -> JsResult<Self>
+    where
+        V: Into<JsValue>,
+    {
+        let constructor = context.intrinsics().constructors().promise().constructor();
+        let promise = Promise::promise_resolve(constructor, value.into(), context)?;
+        Self::from_object(
+            promise
+                .as_object()
+                .expect("PromiseResolve must return an object")
+                .clone(),
+        )
+    }
+
+    /// Creates a promise rejected with `error`.
+    ///
+    /// Same as JavaScript's `Promise.reject(error)`.
+    #[inline]
+    pub fn reject<E>(error: E, context: &mut Context) -> JsResult<Self>
+    where
+        E: Into<JsError>,
+    {
+        let (promise, resolvers) = Self::new_pending(context);
+        let reason = error.into().to_opaque(context);
+        resolvers
+            .reject
+            .call(&JsValue::undefined(), &[reason], context)?;
+        Ok(promise)
+    }
+
+    /// Creates a [`JsPromise`] from a [`JsObject`], or returns a `TypeError` if the provided
+    /// object is not a `Promise`.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.borrow().is_promise() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a Promise")
+                .into())
+        }
+    }
+
+    /// Returns the current state of the promise.
+    #[inline]
+    pub fn state(&self) -> PromiseState {
+        self.inner
+            .borrow()
+            .as_promise()
+            .expect("`JsPromise` must always wrap a promise")
+            .state()
+            .clone()
+    }
+
+    /// Registers the callbacks to run when the promise is settled, and returns the promise that
+    /// resolves with the result of the callbacks.
+    ///
+    /// Same as JavaScript's `promise.then(onFulfilled, onRejected)`.
+    #[inline]
+    pub fn then(
+        &self,
+        on_fulfilled: Option<JsFunction>,
+        on_rejected: Option<JsFunction>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let result = Promise::then(
+            &self.inner.clone().into(),
+            &[
+                on_fulfilled.map_or_else(JsValue::undefined, Into::into),
+                on_rejected.map_or_else(JsValue::undefined, Into::into),
+            ],
+            context,
+        )?;
+        Self::from_object(
+            result
+                .as_object()
+                .expect("`then` must return an object")
+                .clone(),
+        )
+    }
+}
+
+impl From<JsPromise> for JsObject {
+    #[inline]
+    fn from(o: JsPromise) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsPromise> for JsValue {
+    #[inline]
+    fn from(o: JsPromise) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsPromise {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsPromise {}
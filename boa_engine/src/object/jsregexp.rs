@@ -0,0 +1,215 @@
+//! This module implements a wrapper for the `RegExp` Builtin Javascript Object
+use crate::{
+    builtins::RegExp,
+    error::JsNativeError,
+    object::{JsArray, JsObject, JsObjectType},
+    Context, JsResult, JsString, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// `JsRegExp` provides a wrapper for Boa's implementation of the Javascript `RegExp` object.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    object::JsRegExp,
+/// #    Context, JsValue,
+/// # };
+///
+/// // Create a default `Context`
+/// let context = &mut Context::default();
+///
+/// // Create a new regular expression.
+/// let regexp = JsRegExp::new("foo(\\d)", "i", context).unwrap();
+///
+/// assert!(regexp.ignore_case(context).unwrap());
+/// assert_eq!(regexp.flags(context).unwrap(), "i");
+///
+/// assert!(regexp.test("FOO1", context).unwrap());
+///
+/// let result = regexp.exec("foo2", context).unwrap().unwrap();
+/// assert_eq!(result.at(1, context).unwrap(), JsValue::new("2"));
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsRegExp {
+    inner: JsObject,
+}
+
+impl JsRegExp {
+    /// Creates a new regular expression from a pattern and its flags, or returns a
+    /// `SyntaxError` if the pattern or the flags are not valid.
+    ///
+    /// Same as calling `new RegExp(pattern, flags)` in JavaScript.
+    #[inline]
+    pub fn new<S>(pattern: S, flags: S, context: &mut Context) -> JsResult<Self>
+    where
+        S: Into<JsString>,
+    {
+        let regexp = RegExp::create(
+            &JsValue::new(pattern.into()),
+            &JsValue::new(flags.into()),
+            context,
+        )?;
+        Self::from_object(
+            regexp
+                .as_object()
+                .expect("RegExpCreate must return an object")
+                .clone(),
+        )
+    }
+
+    /// Creates a [`JsRegExp`] from a [`JsObject`], or returns a `TypeError` if the provided
+    /// object is not a `RegExp`.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.borrow().is_regexp() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a RegExp")
+                .into())
+        }
+    }
+
+    /// Returns `true` if the `d` flag is set.
+    #[inline]
+    pub fn has_indices(&self, context: &mut Context) -> JsResult<bool> {
+        RegExp::get_has_indices(&self.inner.clone().into(), &[], context).map(|v| v.to_boolean())
+    }
+
+    /// Returns `true` if the `g` flag is set.
+    #[inline]
+    pub fn global(&self, context: &mut Context) -> JsResult<bool> {
+        RegExp::get_global(&self.inner.clone().into(), &[], context).map(|v| v.to_boolean())
+    }
+
+    /// Returns `true` if the `i` flag is set.
+    #[inline]
+    pub fn ignore_case(&self, context: &mut Context) -> JsResult<bool> {
+        RegExp::get_ignore_case(&self.inner.clone().into(), &[], context).map(|v| v.to_boolean())
+    }
+
+    /// Returns `true` if the `m` flag is set.
+    #[inline]
+    pub fn multiline(&self, context: &mut Context) -> JsResult<bool> {
+        RegExp::get_multiline(&self.inner.clone().into(), &[], context).map(|v| v.to_boolean())
+    }
+
+    /// Returns `true` if the `s` flag is set.
+    #[inline]
+    pub fn dot_all(&self, context: &mut Context) -> JsResult<bool> {
+        RegExp::get_dot_all(&self.inner.clone().into(), &[], context).map(|v| v.to_boolean())
+    }
+
+    /// Returns `true` if the `u` flag is set.
+    #[inline]
+    pub fn unicode(&self, context: &mut Context) -> JsResult<bool> {
+        RegExp::get_unicode(&self.inner.clone().into(), &[], context).map(|v| v.to_boolean())
+    }
+
+    /// Returns `true` if the `y` flag is set.
+    #[inline]
+    pub fn sticky(&self, context: &mut Context) -> JsResult<bool> {
+        RegExp::get_sticky(&self.inner.clone().into(), &[], context).map(|v| v.to_boolean())
+    }
+
+    /// Returns the flags of the regular expression, in the canonical order.
+    ///
+    /// Same as JavaScript's `regexp.flags`.
+    #[inline]
+    pub fn flags(&self, context: &mut Context) -> JsResult<String> {
+        let flags = RegExp::get_flags(&self.inner.clone().into(), &[], context)?;
+        Ok(flags
+            .as_string()
+            .expect("`flags` must return a string")
+            .to_std_string_escaped())
+    }
+
+    /// Returns the source text of the pattern.
+    ///
+    /// Same as JavaScript's `regexp.source`.
+    #[inline]
+    pub fn source(&self, context: &mut Context) -> JsResult<String> {
+        let source = RegExp::get_source(&self.inner.clone().into(), &[], context)?;
+        Ok(source
+            .as_string()
+            .expect("`source` must return a string")
+            .to_std_string_escaped())
+    }
+
+    /// Returns `true` if the regular expression matches `search`.
+    ///
+    /// Same as JavaScript's `regexp.test(search)`.
+    #[inline]
+    pub fn test<S>(&self, search: S, context: &mut Context) -> JsResult<bool>
+    where
+        S: Into<JsString>,
+    {
+        RegExp::test(
+            &self.inner.clone().into(),
+            &[JsValue::new(search.into())],
+            context,
+        )
+        .map(|v| v.to_boolean())
+    }
+
+    /// Searches for a match in `search`, and returns the match array, or `None` if the regular
+    /// expression does not match.
+    ///
+    /// Same as JavaScript's `regexp.exec(search)`.
+    #[inline]
+    pub fn exec<S>(&self, search: S, context: &mut Context) -> JsResult<Option<JsArray>>
+    where
+        S: Into<JsString>,
+    {
+        let result = RegExp::exec(
+            &self.inner.clone().into(),
+            &[JsValue::new(search.into())],
+            context,
+        )?;
+        result
+            .as_object()
+            .map(|o| JsArray::from_object(o.clone()))
+            .transpose()
+    }
+
+    /// Returns the regular expression as a string, in the `/pattern/flags` form.
+    ///
+    /// Same as JavaScript's `regexp.toString()`.
+    #[inline]
+    pub fn to_string(&self, context: &mut Context) -> JsResult<String> {
+        let string = RegExp::to_string(&self.inner.clone().into(), &[], context)?;
+        Ok(string
+            .as_string()
+            .expect("`toString` must return a string")
+            .to_std_string_escaped())
+    }
+}
+
+impl From<JsRegExp> for JsObject {
+    #[inline]
+    fn from(o: JsRegExp) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsRegExp> for JsValue {
+    #[inline]
+    fn from(o: JsRegExp) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsRegExp {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsRegExp {}
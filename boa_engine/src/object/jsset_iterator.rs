@@ -4,6 +4,7 @@ use boa_gc::{Finalize, Trace};
 
 use crate::{
     builtins::SetIterator,
+    error::JsNativeError,
     object::{JsObject, JsObjectType},
     Context, JsResult, JsValue,
 };
@@ -17,11 +18,13 @@ pub struct JsSetIterator {
 impl JsSetIterator {
     /// Create a `JsSetIterator` from a `JsObject`.
     /// If object is not a `SetIterator`, throw `TypeError`.
-    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
         if object.borrow().is_set_iterator() {
             Ok(Self { inner: object })
         } else {
-            context.throw_type_error("object is not a SetIterator")
+            Err(JsNativeError::typ()
+                .with_message("object is not a SetIterator")
+                .into())
         }
     }
     /// Advances the `JsSetIterator` and gets the next result in the `JsSet`.
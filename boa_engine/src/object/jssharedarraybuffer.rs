@@ -0,0 +1,124 @@
+use crate::{
+    builtins::{
+        array_buffer::{ArrayBuffer, DataBlock},
+        shared_array_buffer::{SharedArrayBuffer, SharedMemory},
+    },
+    context::intrinsics::StandardConstructors,
+    error::JsNativeError,
+    object::{internal_methods::get_prototype_from_constructor, JsObject, JsObjectType},
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// JavaScript `SharedArrayBuffer` rust object.
+///
+/// The memory of the buffer can be shared with the contexts of other threads by sending them the
+/// [`SharedMemory`] returned by [`JsSharedArrayBuffer::memory`].
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsSharedArrayBuffer {
+    inner: JsObject,
+}
+
+impl JsSharedArrayBuffer {
+    /// Create a new shared array buffer with byte length.
+    #[inline]
+    pub fn new(byte_length: usize, context: &mut Context) -> JsResult<Self> {
+        let inner = SharedArrayBuffer::allocate(
+            &context
+                .intrinsics()
+                .constructors()
+                .shared_array_buffer()
+                .constructor()
+                .into(),
+            byte_length as u64,
+            context,
+        )?;
+
+        Ok(Self { inner })
+    }
+
+    /// Create a new shared array buffer that views `memory`.
+    ///
+    /// This does not copy the memory: writes to the new buffer are visible to all the other
+    /// buffers that view the same memory, which can belong to contexts of other threads.
+    #[inline]
+    pub fn from_memory(memory: SharedMemory, context: &mut Context) -> JsResult<Self> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .shared_array_buffer()
+            .constructor()
+            .into();
+        let prototype = get_prototype_from_constructor(
+            &constructor,
+            StandardConstructors::shared_array_buffer,
+            context,
+        )?;
+
+        Ok(Self {
+            inner: SharedArrayBuffer::create(prototype, memory),
+        })
+    }
+
+    /// Create a [`JsSharedArrayBuffer`] from a [`JsObject`], if the object is not a shared array buffer throw a `TypeError`.
+    ///
+    /// This does not clone the fields of the shared array buffer, it only does a shallow clone of the object.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object
+            .borrow()
+            .as_array_buffer()
+            .map_or(false, ArrayBuffer::is_shared_array_buffer)
+        {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a SharedArrayBuffer")
+                .into())
+        }
+    }
+
+    /// Returns the memory viewed by the shared array buffer.
+    #[inline]
+    pub fn memory(&self) -> SharedMemory {
+        match self.inner.borrow().as_array_buffer() {
+            Some(buffer) => match buffer.array_buffer_data {
+                Some(DataBlock::Shared(ref memory)) => memory.clone(),
+                _ => unreachable!("a SharedArrayBuffer must view shared memory"),
+            },
+            None => unreachable!("a JsSharedArrayBuffer must be a SharedArrayBuffer"),
+        }
+    }
+
+    /// Returns the byte length of the shared array buffer.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.memory().len()
+    }
+}
+
+impl From<JsSharedArrayBuffer> for JsObject {
+    #[inline]
+    fn from(o: JsSharedArrayBuffer) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsSharedArrayBuffer> for JsValue {
+    #[inline]
+    fn from(o: JsSharedArrayBuffer) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsSharedArrayBuffer {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsSharedArrayBuffer {}
@@ -0,0 +1,128 @@
+//! This module implements a wrapper for the `WeakMap` Builtin Javascript Object
+use crate::{
+    builtins::WeakMap,
+    error::JsNativeError,
+    object::{JsObject, JsObjectType, ObjectData},
+    Context, JsResult, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// `JsWeakMap` provides a wrapper for Boa's implementation of the Javascript `WeakMap` object.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    object::{JsObject, JsWeakMap},
+/// #    Context, JsValue,
+/// # };
+///
+/// // Create a default `Context`
+/// let context = &mut Context::default();
+///
+/// // Create a new empty `JsWeakMap` and an object to use as a key.
+/// let map = JsWeakMap::new(context);
+/// let key = JsObject::empty();
+///
+/// map.set(&key, "value", context).unwrap();
+/// assert!(map.has(&key, context).unwrap());
+/// assert_eq!(map.get(&key, context).unwrap(), "value".into());
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsWeakMap {
+    inner: JsObject,
+}
+
+impl JsWeakMap {
+    /// Creates a new empty [`JsWeakMap`] object.
+    #[inline]
+    pub fn new(context: &mut Context) -> Self {
+        let prototype = context.intrinsics().constructors().weak_map().prototype();
+        let inner =
+            JsObject::from_proto_and_data(prototype, ObjectData::weak_map(boa_gc::WeakMap::new()));
+
+        Self { inner }
+    }
+
+    /// Creates a [`JsWeakMap`] from a [`JsObject`], or returns a `TypeError` if the provided
+    /// object is not a `WeakMap`.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.borrow().is_weak_map() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a WeakMap")
+                .into())
+        }
+    }
+
+    /// Returns the value associated with the specified key, or `undefined` if the key has no
+    /// associated value.
+    ///
+    /// Same as JavaScript's `weakMap.get(key)`.
+    #[inline]
+    pub fn get(&self, key: &JsObject, context: &mut Context) -> JsResult<JsValue> {
+        WeakMap::get(&self.inner.clone().into(), &[key.clone().into()], context)
+    }
+
+    /// Sets the value for the specified key. Returns the `WeakMap` object.
+    ///
+    /// Same as JavaScript's `weakMap.set(key, value)`.
+    #[inline]
+    pub fn set<V>(&self, key: &JsObject, value: V, context: &mut Context) -> JsResult<JsValue>
+    where
+        V: Into<JsValue>,
+    {
+        WeakMap::set(
+            &self.inner.clone().into(),
+            &[key.clone().into(), value.into()],
+            context,
+        )
+    }
+
+    /// Returns `true` if the `WeakMap` has an entry for the specified key.
+    ///
+    /// Same as JavaScript's `weakMap.has(key)`.
+    #[inline]
+    pub fn has(&self, key: &JsObject, context: &mut Context) -> JsResult<bool> {
+        WeakMap::has(&self.inner.clone().into(), &[key.clone().into()], context)
+            .map(|has| has.to_boolean())
+    }
+
+    /// Removes the entry of the specified key. Returns `true` if an entry was removed.
+    ///
+    /// Same as JavaScript's `weakMap.delete(key)`.
+    #[inline]
+    pub fn delete(&self, key: &JsObject, context: &mut Context) -> JsResult<bool> {
+        WeakMap::delete(&self.inner.clone().into(), &[key.clone().into()], context)
+            .map(|deleted| deleted.to_boolean())
+    }
+}
+
+impl From<JsWeakMap> for JsObject {
+    #[inline]
+    fn from(o: JsWeakMap) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsWeakMap> for JsValue {
+    #[inline]
+    fn from(o: JsWeakMap) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsWeakMap {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsWeakMap {}
@@ -0,0 +1,112 @@
+//! This module implements a wrapper for the `WeakSet` Builtin Javascript Object
+use crate::{
+    builtins::WeakSet,
+    error::JsNativeError,
+    object::{JsObject, JsObjectType, ObjectData},
+    Context, JsResult, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// `JsWeakSet` provides a wrapper for Boa's implementation of the Javascript `WeakSet` object.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    object::{JsObject, JsWeakSet},
+/// #    Context, JsValue,
+/// # };
+///
+/// // Create a default `Context`
+/// let context = &mut Context::default();
+///
+/// // Create a new empty `JsWeakSet` and add an object to it.
+/// let set = JsWeakSet::new(context);
+/// let value = JsObject::empty();
+///
+/// set.add(&value, context).unwrap();
+/// assert!(set.has(&value, context).unwrap());
+/// assert!(set.delete(&value, context).unwrap());
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsWeakSet {
+    inner: JsObject,
+}
+
+impl JsWeakSet {
+    /// Creates a new empty [`JsWeakSet`] object.
+    #[inline]
+    pub fn new(context: &mut Context) -> Self {
+        let prototype = context.intrinsics().constructors().weak_set().prototype();
+        let inner =
+            JsObject::from_proto_and_data(prototype, ObjectData::weak_set(boa_gc::WeakMap::new()));
+
+        Self { inner }
+    }
+
+    /// Creates a [`JsWeakSet`] from a [`JsObject`], or returns a `TypeError` if the provided
+    /// object is not a `WeakSet`.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.borrow().is_weak_set() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a WeakSet")
+                .into())
+        }
+    }
+
+    /// Adds the specified object to the `WeakSet`. Returns the `WeakSet` object.
+    ///
+    /// Same as JavaScript's `weakSet.add(value)`.
+    #[inline]
+    pub fn add(&self, value: &JsObject, context: &mut Context) -> JsResult<JsValue> {
+        WeakSet::add(&self.inner.clone().into(), &[value.clone().into()], context)
+    }
+
+    /// Returns `true` if the specified object is in the `WeakSet`.
+    ///
+    /// Same as JavaScript's `weakSet.has(value)`.
+    #[inline]
+    pub fn has(&self, value: &JsObject, context: &mut Context) -> JsResult<bool> {
+        WeakSet::has(&self.inner.clone().into(), &[value.clone().into()], context)
+            .map(|has| has.to_boolean())
+    }
+
+    /// Removes the specified object from the `WeakSet`. Returns `true` if it was in the set.
+    ///
+    /// Same as JavaScript's `weakSet.delete(value)`.
+    #[inline]
+    pub fn delete(&self, value: &JsObject, context: &mut Context) -> JsResult<bool> {
+        WeakSet::delete(&self.inner.clone().into(), &[value.clone().into()], context)
+            .map(|deleted| deleted.to_boolean())
+    }
+}
+
+impl From<JsWeakSet> for JsObject {
+    #[inline]
+    fn from(o: JsWeakSet) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsWeakSet> for JsValue {
+    #[inline]
+    fn from(o: JsWeakSet) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsWeakSet {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsWeakSet {}
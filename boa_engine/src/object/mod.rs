@@ -13,6 +13,7 @@ use self::internal_methods::{
     function::{CONSTRUCTOR_INTERNAL_METHODS, FUNCTION_INTERNAL_METHODS},
     global::GLOBAL_INTERNAL_METHODS,
     integer_indexed::INTEGER_INDEXED_EXOTIC_INTERNAL_METHODS,
+    module_namespace::MODULE_NAMESPACE_EXOTIC_INTERNAL_METHODS,
     proxy::{
         PROXY_EXOTIC_INTERNAL_METHODS_ALL, PROXY_EXOTIC_INTERNAL_METHODS_BASIC,
         PROXY_EXOTIC_INTERNAL_METHODS_WITH_CALL,
@@ -21,7 +22,10 @@ use self::internal_methods::{
     InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
 };
 #[cfg(feature = "intl")]
-use crate::builtins::intl::date_time_format::DateTimeFormat;
+use crate::builtins::intl::{
+    collator::Collator, date_time_format::DateTimeFormat, number_format::NumberFormat,
+    plural_rules::PluralRules,
+};
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
@@ -30,7 +34,7 @@ use crate::{
         function::arguments::Arguments,
         function::{
             arguments::ParameterMap, BoundFunction, Captures, ConstructorKind, Function,
-            NativeFunctionSignature,
+            NativeFunctionSignature, TypedFunctionSignature,
         },
         generator::Generator,
         map::map_iterator::MapIterator,
@@ -41,21 +45,32 @@ use crate::{
         set::ordered_set::OrderedSet,
         set::set_iterator::SetIterator,
         string::string_iterator::StringIterator,
+        temporal::{
+            Calendar, Duration, Instant, PlainDate, PlainDateTime, PlainTime, TimeZone,
+            ZonedDateTime,
+        },
         typed_array::integer_indexed_object::IntegerIndexed,
-        DataView, Date, Promise, RegExp,
+        DataView, Date, FinalizationRegistry, Promise, RegExp,
     },
     context::intrinsics::StandardConstructor,
+    error::JsNativeError,
+    job::NativeJob,
+    module::ModuleNamespace,
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    vm::{SourceLocation, StackFrame},
     Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
 };
 
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{custom_trace, unsafe_empty_trace, Cell as GcCell, Finalize, Trace, WeakGc, WeakMap};
 use boa_interner::Sym;
 use rustc_hash::FxHashMap;
 use std::{
     any::Any,
     fmt::{self, Debug, Display},
+    future::Future,
     ops::{Deref, DerefMut},
+    rc::Rc,
 };
 
 #[cfg(test)]
@@ -64,26 +79,43 @@ mod tests;
 pub(crate) mod internal_methods;
 mod jsarray;
 mod jsarraybuffer;
+mod jsdataview;
+mod jsdate;
 mod jsfunction;
+mod jsgenerator;
 mod jsmap;
 mod jsmap_iterator;
 mod jsobject;
+mod jspromise;
 mod jsproxy;
+mod jsregexp;
 mod jsset;
 mod jsset_iterator;
+mod jssharedarraybuffer;
 mod jstypedarray;
+mod jsweakmap;
+mod jsweakset;
 mod operations;
 mod property_map;
+pub(crate) mod shape;
 
 pub use jsarray::*;
 pub use jsarraybuffer::*;
+pub use jsdataview::*;
+pub use jsdate::*;
 pub use jsfunction::*;
+pub use jsgenerator::*;
 pub use jsmap::*;
 pub use jsmap_iterator::*;
+pub use jspromise::*;
 pub use jsproxy::*;
+pub use jsregexp::*;
 pub use jsset::*;
 pub use jsset_iterator::*;
+pub use jssharedarraybuffer::*;
 pub use jstypedarray::*;
+pub use jsweakmap::*;
+pub use jsweakset::*;
 
 pub(crate) trait JsObjectType:
     Into<JsValue> + Into<JsObject> + Deref<Target = JsObject>
@@ -172,6 +204,7 @@ pub enum ObjectKind {
     ArrayBuffer(ArrayBuffer),
     Map(OrderedMap<JsValue>),
     MapIterator(MapIterator),
+    ModuleNamespace(ModuleNamespace),
     RegExp(Box<RegExp>),
     RegExpStringIterator(RegExpStringIterator),
     BigInt(JsBigInt),
@@ -184,20 +217,39 @@ pub enum ObjectKind {
     GeneratorFunction(Function),
     Set(OrderedSet<JsValue>),
     SetIterator(SetIterator),
+    WeakMap(WeakMap<GcCell<Object>, JsValue>),
+    WeakSet(WeakMap<GcCell<Object>, ()>),
+    WeakRef(WeakGc<GcCell<Object>>),
+    ShadowRealm(Realm),
+    FinalizationRegistry(FinalizationRegistry),
     String(JsString),
     StringIterator(StringIterator),
     Number(f64),
     Symbol(JsSymbol),
-    Error,
+    Error(Box<[StackFrame]>),
     Ordinary,
     Proxy(Proxy),
     Date(Date),
+    Instant(Instant),
+    PlainDate(PlainDate),
+    PlainTime(PlainTime),
+    PlainDateTime(PlainDateTime),
+    ZonedDateTime(ZonedDateTime),
+    Duration(Duration),
+    TimeZone(TimeZone),
+    Calendar(Calendar),
     Global,
     Arguments(Arguments),
     NativeObject(Box<dyn NativeObject>),
     IntegerIndexed(IntegerIndexed),
     #[cfg(feature = "intl")]
     DateTimeFormat(Box<DateTimeFormat>),
+    #[cfg(feature = "intl")]
+    NumberFormat(Box<NumberFormat>),
+    #[cfg(feature = "intl")]
+    PluralRules(Box<PluralRules>),
+    #[cfg(feature = "intl")]
+    Collator(Box<Collator>),
     Promise(Promise),
 }
 
@@ -208,6 +260,7 @@ unsafe impl Trace for ObjectKind {
             Self::ArrayBuffer(b) => mark(b),
             Self::Map(m) => mark(m),
             Self::MapIterator(i) => mark(i),
+            Self::ModuleNamespace(n) => mark(n),
             Self::RegExpStringIterator(i) => mark(i),
             Self::DataView(v) => mark(v),
             Self::ForInIterator(i) => mark(i),
@@ -216,13 +269,28 @@ unsafe impl Trace for ObjectKind {
             Self::Generator(g) => mark(g),
             Self::Set(s) => mark(s),
             Self::SetIterator(i) => mark(i),
+            Self::WeakMap(m) => mark(m),
+            Self::WeakSet(s) => mark(s),
+            Self::WeakRef(r) => mark(r),
+            Self::ShadowRealm(r) => mark(r),
+            Self::FinalizationRegistry(r) => mark(r),
             Self::StringIterator(i) => mark(i),
             Self::Proxy(p) => mark(p),
             Self::Arguments(a) => mark(a),
             Self::NativeObject(o) => mark(o),
             Self::IntegerIndexed(i) => mark(i),
+            Self::PlainDate(o) => mark(o),
+            Self::PlainTime(o) => mark(o),
+            Self::PlainDateTime(o) => mark(o),
+            Self::ZonedDateTime(o) => mark(o),
             #[cfg(feature = "intl")]
             Self::DateTimeFormat(f) => mark(f),
+            #[cfg(feature = "intl")]
+            Self::NumberFormat(f) => mark(f),
+            #[cfg(feature = "intl")]
+            Self::PluralRules(r) => mark(r),
+            #[cfg(feature = "intl")]
+            Self::Collator(c) => mark(c),
             Self::Promise(p) => mark(p),
             Self::AsyncGenerator(g) => mark(g),
             Self::RegExp(_)
@@ -230,8 +298,12 @@ unsafe impl Trace for ObjectKind {
             | Self::Boolean(_)
             | Self::String(_)
             | Self::Date(_)
+            | Self::Instant(_)
+            | Self::Duration(_)
+            | Self::TimeZone(_)
+            | Self::Calendar(_)
             | Self::Array
-            | Self::Error
+            | Self::Error(_)
             | Self::Ordinary
             | Self::Global
             | Self::Number(_)
@@ -301,6 +373,14 @@ impl ObjectData {
         }
     }
 
+    /// Create the module namespace object data and reference its exclusive internal methods
+    pub fn module_namespace(namespace: ModuleNamespace) -> Self {
+        Self {
+            kind: ObjectKind::ModuleNamespace(namespace),
+            internal_methods: &MODULE_NAMESPACE_EXOTIC_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `RegExp` object data
     pub fn reg_exp(reg_exp: Box<RegExp>) -> Self {
         Self {
@@ -417,6 +497,110 @@ impl ObjectData {
         }
     }
 
+    /// Create the `WeakMap` object data
+    pub fn weak_map(weak_map: WeakMap<GcCell<Object>, JsValue>) -> Self {
+        Self {
+            kind: ObjectKind::WeakMap(weak_map),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakSet` object data
+    pub fn weak_set(weak_set: WeakMap<GcCell<Object>, ()>) -> Self {
+        Self {
+            kind: ObjectKind::WeakSet(weak_set),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakRef` object data
+    pub fn weak_ref(weak_ref: WeakGc<GcCell<Object>>) -> Self {
+        Self {
+            kind: ObjectKind::WeakRef(weak_ref),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `ShadowRealm` object data
+    pub fn shadow_realm(realm: Realm) -> Self {
+        Self {
+            kind: ObjectKind::ShadowRealm(realm),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.Instant` object data
+    pub fn instant(instant: Instant) -> Self {
+        Self {
+            kind: ObjectKind::Instant(instant),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainDate` object data
+    pub fn plain_date(plain_date: PlainDate) -> Self {
+        Self {
+            kind: ObjectKind::PlainDate(plain_date),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainTime` object data
+    pub fn plain_time(plain_time: PlainTime) -> Self {
+        Self {
+            kind: ObjectKind::PlainTime(plain_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainDateTime` object data
+    pub fn plain_date_time(plain_date_time: PlainDateTime) -> Self {
+        Self {
+            kind: ObjectKind::PlainDateTime(plain_date_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.ZonedDateTime` object data
+    pub fn zoned_date_time(zoned_date_time: ZonedDateTime) -> Self {
+        Self {
+            kind: ObjectKind::ZonedDateTime(zoned_date_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.Duration` object data
+    pub fn duration(duration: Duration) -> Self {
+        Self {
+            kind: ObjectKind::Duration(duration),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.TimeZone` object data
+    pub fn time_zone(time_zone: TimeZone) -> Self {
+        Self {
+            kind: ObjectKind::TimeZone(time_zone),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.Calendar` object data
+    pub fn calendar(calendar: Calendar) -> Self {
+        Self {
+            kind: ObjectKind::Calendar(calendar),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `FinalizationRegistry` object data
+    pub fn finalization_registry(registry: FinalizationRegistry) -> Self {
+        Self {
+            kind: ObjectKind::FinalizationRegistry(registry),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `String` object data and reference its exclusive internal methods
     pub fn string(string: JsString) -> Self {
         Self {
@@ -449,10 +633,10 @@ impl ObjectData {
         }
     }
 
-    /// Create the `Error` object data
-    pub fn error() -> Self {
+    /// Create the `Error` object data, for an error created with the stack trace `stack`
+    pub fn error(stack: Vec<StackFrame>) -> Self {
         Self {
-            kind: ObjectKind::Error,
+            kind: ObjectKind::Error(stack.into_boxed_slice()),
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
@@ -531,6 +715,33 @@ impl ObjectData {
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
+
+    /// Create the `NumberFormat` object data
+    #[cfg(feature = "intl")]
+    pub fn number_format(number_format: NumberFormat) -> Self {
+        Self {
+            kind: ObjectKind::NumberFormat(Box::new(number_format)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `PluralRules` object data
+    #[cfg(feature = "intl")]
+    pub fn plural_rules(plural_rules: PluralRules) -> Self {
+        Self {
+            kind: ObjectKind::PluralRules(Box::new(plural_rules)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Collator` object data
+    #[cfg(feature = "intl")]
+    pub fn collator(collator: Collator) -> Self {
+        Self {
+            kind: ObjectKind::Collator(Box::new(collator)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
 }
 
 impl Display for ObjectKind {
@@ -550,12 +761,26 @@ impl Display for ObjectKind {
             Self::RegExpStringIterator(_) => "RegExpStringIterator",
             Self::Map(_) => "Map",
             Self::MapIterator(_) => "MapIterator",
+            Self::ModuleNamespace(_) => "ModuleNamespace",
             Self::Set(_) => "Set",
             Self::SetIterator(_) => "SetIterator",
+            Self::WeakMap(_) => "WeakMap",
+            Self::WeakSet(_) => "WeakSet",
+            Self::WeakRef(_) => "WeakRef",
+            Self::ShadowRealm(_) => "ShadowRealm",
+            Self::Instant(_) => "Temporal.Instant",
+            Self::PlainDate(_) => "Temporal.PlainDate",
+            Self::PlainTime(_) => "Temporal.PlainTime",
+            Self::PlainDateTime(_) => "Temporal.PlainDateTime",
+            Self::ZonedDateTime(_) => "Temporal.ZonedDateTime",
+            Self::Duration(_) => "Temporal.Duration",
+            Self::TimeZone(_) => "Temporal.TimeZone",
+            Self::Calendar(_) => "Temporal.Calendar",
+            Self::FinalizationRegistry(_) => "FinalizationRegistry",
             Self::String(_) => "String",
             Self::StringIterator(_) => "StringIterator",
             Self::Symbol(_) => "Symbol",
-            Self::Error => "Error",
+            Self::Error(_) => "Error",
             Self::Ordinary => "Ordinary",
             Self::Proxy(_) => "Proxy",
             Self::Boolean(_) => "Boolean",
@@ -569,6 +794,12 @@ impl Display for ObjectKind {
             Self::DataView(_) => "DataView",
             #[cfg(feature = "intl")]
             Self::DateTimeFormat(_) => "DateTimeFormat",
+            #[cfg(feature = "intl")]
+            Self::NumberFormat(_) => "NumberFormat",
+            #[cfg(feature = "intl")]
+            Self::PluralRules(_) => "PluralRules",
+            #[cfg(feature = "intl")]
+            Self::Collator(_) => "Collator",
             Self::Promise(_) => "Promise",
         })
     }
@@ -847,6 +1078,30 @@ impl Object {
         }
     }
 
+    /// Checks if it is a module namespace object.
+    #[inline]
+    pub fn is_module_namespace(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::ModuleNamespace(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the module namespace data if the object is a module namespace object.
+    #[inline]
+    pub fn as_module_namespace(&self) -> Option<&ModuleNamespace> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::ModuleNamespace(namespace),
+                ..
+            } => Some(namespace),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn is_set(&self) -> bool {
         matches!(
@@ -903,6 +1158,283 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `WeakMap` object.
+    #[inline]
+    pub fn is_weak_map(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::WeakMap(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_weak_map_ref(&self) -> Option<&WeakMap<GcCell<Self>, JsValue>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(ref map),
+                ..
+            } => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_mut(&mut self) -> Option<&mut WeakMap<GcCell<Self>, JsValue>> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(map),
+                ..
+            } => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `WeakSet` object.
+    #[inline]
+    pub fn is_weak_set(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::WeakSet(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_weak_set_ref(&self) -> Option<&WeakMap<GcCell<Self>, ()>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakSet(ref set),
+                ..
+            } => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set_mut(&mut self) -> Option<&mut WeakMap<GcCell<Self>, ()>> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::WeakSet(set),
+                ..
+            } => Some(set),
+            _ => None,
+        }
+    }
+
+    /// Gets the weak reference data if the object is a `WeakRef`.
+    #[inline]
+    pub fn as_weak_ref(&self) -> Option<&WeakGc<GcCell<Self>>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakRef(ref weak_ref),
+                ..
+            } => Some(weak_ref),
+            _ => None,
+        }
+    }
+
+    /// Gets the realm of the object if it is a `ShadowRealm`.
+    #[inline]
+    pub fn as_shadow_realm(&self) -> Option<&Realm> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::ShadowRealm(ref realm),
+                ..
+            } => Some(realm),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is a `Temporal.Instant`.
+    #[inline]
+    pub fn as_instant(&self) -> Option<&Instant> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Instant(ref instant),
+                ..
+            } => Some(instant),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is a `Temporal.PlainDate`.
+    #[inline]
+    pub fn as_plain_date(&self) -> Option<&PlainDate> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::PlainDate(ref plain_date),
+                ..
+            } => Some(plain_date),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is a `Temporal.PlainTime`.
+    #[inline]
+    pub fn as_plain_time(&self) -> Option<&PlainTime> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::PlainTime(ref plain_time),
+                ..
+            } => Some(plain_time),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is a `Temporal.PlainDateTime`.
+    #[inline]
+    pub fn as_plain_date_time(&self) -> Option<&PlainDateTime> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::PlainDateTime(ref plain_date_time),
+                ..
+            } => Some(plain_date_time),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is a `Temporal.ZonedDateTime`.
+    #[inline]
+    pub fn as_zoned_date_time(&self) -> Option<&ZonedDateTime> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::ZonedDateTime(ref zoned_date_time),
+                ..
+            } => Some(zoned_date_time),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is a `Temporal.Duration`.
+    #[inline]
+    pub fn as_duration(&self) -> Option<&Duration> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Duration(ref duration),
+                ..
+            } => Some(duration),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is a `Temporal.TimeZone`.
+    #[inline]
+    pub fn as_time_zone(&self) -> Option<&TimeZone> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::TimeZone(ref time_zone),
+                ..
+            } => Some(time_zone),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is a `Temporal.Calendar`.
+    #[inline]
+    pub fn as_calendar(&self) -> Option<&Calendar> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Calendar(ref calendar),
+                ..
+            } => Some(calendar),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is an `Intl.NumberFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_number_format(&self) -> Option<&NumberFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::NumberFormat(ref number_format),
+                ..
+            } => Some(number_format),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable data of the object if it is an `Intl.NumberFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_number_format_mut(&mut self) -> Option<&mut NumberFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::NumberFormat(ref mut number_format),
+                ..
+            } => Some(number_format),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is an `Intl.PluralRules`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_plural_rules(&self) -> Option<&PluralRules> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::PluralRules(ref plural_rules),
+                ..
+            } => Some(plural_rules),
+            _ => None,
+        }
+    }
+
+    /// Gets the data of the object if it is an `Intl.Collator`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_collator(&self) -> Option<&Collator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Collator(ref collator),
+                ..
+            } => Some(collator),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable data of the object if it is an `Intl.Collator`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_collator_mut(&mut self) -> Option<&mut Collator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Collator(ref mut collator),
+                ..
+            } => Some(collator),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `FinalizationRegistry` object.
+    #[inline]
+    pub fn is_finalization_registry(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the mutable registry data if the object is a `FinalizationRegistry`.
+    #[inline]
+    pub fn as_finalization_registry_mut(&mut self) -> Option<&mut FinalizationRegistry> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(registry),
+                ..
+            } => Some(registry),
+            _ => None,
+        }
+    }
+
     /// Checks if it a `String` object.
     #[inline]
     pub fn is_string(&self) -> bool {
@@ -1039,12 +1571,34 @@ impl Object {
         matches!(
             self.data,
             ObjectData {
-                kind: ObjectKind::Error,
+                kind: ObjectKind::Error(_),
                 ..
             }
         )
     }
 
+    /// Gets the stack trace that was captured when this error object was created, starting with
+    /// the innermost frame.
+    ///
+    /// Returns `None` if it is not an error object.
+    #[inline]
+    pub fn error_stack(&self) -> Option<&[StackFrame]> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Error(ref stack),
+                ..
+            } => Some(stack),
+            _ => None,
+        }
+    }
+
+    /// Gets the location in the source text where this error object was created, if it is an
+    /// error object and its location is known.
+    #[inline]
+    pub fn error_location(&self) -> Option<&SourceLocation> {
+        self.error_stack()?.first()?.location()
+    }
+
     /// Checks if it a Boolean object.
     #[inline]
     pub fn is_boolean(&self) -> bool {
@@ -1440,6 +1994,26 @@ impl Object {
         &mut self.properties
     }
 
+    /// Checks if new properties can be added to the object.
+    #[inline]
+    pub(crate) fn extensible(&self) -> bool {
+        self.extensible
+    }
+
+    /// Checks if the string keyed properties of the object are got with the ordinary internal
+    /// methods.
+    #[inline]
+    pub(crate) fn has_ordinary_get(&self) -> bool {
+        self.data.internal_methods.has_ordinary_get()
+    }
+
+    /// Checks if the string keyed properties of the object are set with the ordinary internal
+    /// methods.
+    #[inline]
+    pub(crate) fn has_ordinary_set(&self) -> bool {
+        self.data.internal_methods.has_ordinary_set()
+    }
+
     /// Inserts a field in the object `properties` without checking if it's writable.
     ///
     /// If a field was already in the object with the same name, then a `Some` is returned
@@ -1465,6 +2039,14 @@ impl Object {
         self.private_elements.get(&name)
     }
 
+    /// Returns an iterator over the private elements, in no particular order.
+    #[inline]
+    pub(crate) fn private_elements(&self) -> impl Iterator<Item = (Sym, &PrivateElement)> {
+        self.private_elements
+            .iter()
+            .map(|(name, element)| (*name, element))
+    }
+
     /// Set a private element.
     #[inline]
     pub(crate) fn set_private_element(&mut self, name: Sym, value: PrivateElement) {
@@ -1583,6 +2165,28 @@ where
     }
 }
 
+/// The Rust function of an async native function, which is stored in the captures of the
+/// function object since it does not need to be `Copy`.
+///
+/// The function is not traced, so the garbage collected values it captures stay rooted until the
+/// function object is dropped.
+struct AsyncNativeFunction<F>(Rc<F>);
+
+impl<F> Debug for AsyncNativeFunction<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncNativeFunction")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> Finalize for AsyncNativeFunction<F> {}
+
+// SAFETY: The function is never traced, so the garbage collected values it captures are never
+// unrooted, and they are kept alive by their roots.
+unsafe impl<F> Trace for AsyncNativeFunction<F> {
+    unsafe_empty_trace!();
+}
+
 /// Builder for creating native function objects
 #[derive(Debug)]
 pub struct FunctionBuilder<'context> {
@@ -1597,11 +2201,12 @@ impl<'context> FunctionBuilder<'context> {
     #[inline]
     pub fn native(context: &'context mut Context, function: NativeFunctionSignature) -> Self {
         Self {
-            context,
             function: Function::Native {
                 function,
                 constructor: None,
+                realm: context.realm().clone(),
             },
+            context,
             name: JsString::default(),
             length: 0,
         }
@@ -1614,12 +2219,13 @@ impl<'context> FunctionBuilder<'context> {
         F: Fn(&JsValue, &[JsValue], &mut Context) -> JsResult<JsValue> + Copy + 'static,
     {
         Self {
-            context,
             function: Function::Closure {
                 function: Box::new(move |this, args, _, context| function(this, args, context)),
                 constructor: None,
                 captures: Captures::new(()),
+                realm: context.realm().clone(),
             },
+            context,
             name: JsString::default(),
             length: 0,
         }
@@ -1642,18 +2248,127 @@ impl<'context> FunctionBuilder<'context> {
         C: NativeObject,
     {
         Self {
-            context,
             function: Function::Closure {
                 function: Box::new(move |this, args, captures: Captures, context| {
                     let mut captures = captures.as_mut_any();
                     let captures = captures.downcast_mut::<C>().ok_or_else(|| {
-                        context.construct_type_error("cannot downcast `Captures` to given type")
+                        JsNativeError::typ()
+                            .with_message("cannot downcast `Captures` to given type")
                     })?;
                     function(this, args, captures, context)
                 }),
                 constructor: None,
                 captures: Captures::new(captures),
+                realm: context.realm().clone(),
             },
+            context,
+            name: JsString::default(),
+            length: 0,
+        }
+    }
+
+    /// Create a new `FunctionBuilder` for creating a native function with typed parameters.
+    ///
+    /// The arguments are converted with [`TryFromJs`](crate::value::TryFromJs) before calling
+    /// `function`, and its result is converted back with [`IntoJs`](crate::value::IntoJs). The
+    /// `length` of the function is set to its number of parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{object::FunctionBuilder, Context, JsResult, JsValue};
+    /// let mut context = Context::default();
+    ///
+    /// let repeat = FunctionBuilder::typed(
+    ///     &mut context,
+    ///     |text: String, times: Option<usize>, _: &mut Context| -> JsResult<String> {
+    ///         Ok(text.repeat(times.unwrap_or(1)))
+    ///     },
+    /// )
+    /// .name("repeat")
+    /// .build();
+    ///
+    /// let result = repeat
+    ///     .call(&JsValue::undefined(), &["ab".into(), 3.into()], &mut context)
+    ///     .unwrap();
+    /// assert_eq!(result, JsValue::new("ababab"));
+    ///
+    /// // Arguments of the wrong type throw a `TypeError`.
+    /// assert!(repeat
+    ///     .call(&JsValue::undefined(), &[1.into()], &mut context)
+    ///     .is_err());
+    /// ```
+    #[inline]
+    pub fn typed<F, Args>(context: &'context mut Context, function: F) -> Self
+    where
+        F: TypedFunctionSignature<Args>,
+    {
+        Self::closure(context, move |_, args, context| {
+            function.call_typed(args, context)
+        })
+        .length(F::LENGTH)
+    }
+
+    /// Create a new `FunctionBuilder` for creating an async native function.
+    ///
+    /// Calling the function calls `function` to create a [`Future`], and returns a pending
+    /// promise. The future is handed to the [`JobQueue`](crate::job::JobQueue) of the context, and
+    /// the promise is fulfilled or rejected with its result once the queue polls it to completion.
+    ///
+    /// Unlike [`FunctionBuilder::closure`], `function` does not need to be `Copy`, so it can
+    /// capture the state shared by its calls, like an `Rc` of an HTTP client.
+    ///
+    /// # Note
+    ///
+    /// The future cannot borrow the context, so `function` must extract everything it needs
+    /// from the arguments before creating it.
+    ///
+    /// `function` is not traced by the garbage collector, so the garbage collected values it
+    /// captures, such as [`JsObject`]s, are kept alive until the function object is dropped.
+    #[inline]
+    pub fn async_native<F, Fut>(context: &'context mut Context, function: F) -> Self
+    where
+        F: Fn(&JsValue, &[JsValue], &mut Context) -> Fut + 'static,
+        Fut: Future<Output = JsResult<JsValue>> + 'static,
+    {
+        Self {
+            function: Function::Closure {
+                function: Box::new(|this, args, captures: Captures, context| {
+                    // The borrow of the captures must end before calling `function`, since it
+                    // can call this function object again.
+                    let function = captures
+                        .as_any()
+                        .downcast_ref::<AsyncNativeFunction<F>>()
+                        .map(|function| function.0.clone())
+                        .ok_or_else(|| {
+                            JsNativeError::typ()
+                                .with_message("cannot downcast `Captures` to given type")
+                        })?;
+                    let (promise, resolvers) = JsPromise::new_pending(context);
+                    let future = function(this, args, context);
+                    context.enqueue_future_job(Box::pin(async move {
+                        let result = future.await;
+                        NativeJob::new(move |context| match result {
+                            Ok(value) => {
+                                resolvers
+                                    .resolve
+                                    .call(&JsValue::undefined(), &[value], context)
+                            }
+                            Err(error) => {
+                                let reason = error.to_opaque(context);
+                                resolvers
+                                    .reject
+                                    .call(&JsValue::undefined(), &[reason], context)
+                            }
+                        })
+                    }));
+                    Ok(promise.into())
+                }),
+                constructor: None,
+                captures: Captures::new(AsyncNativeFunction(Rc::new(function))),
+                realm: context.realm().clone(),
+            },
+            context,
             name: JsString::default(),
             length: 0,
         }
@@ -1666,9 +2381,9 @@ impl<'context> FunctionBuilder<'context> {
     #[must_use]
     pub fn name<N>(mut self, name: N) -> Self
     where
-        N: AsRef<str>,
+        N: Into<JsString>,
     {
-        self.name = name.as_ref().into();
+        self.name = name.into();
         self
     }
 
@@ -2090,9 +2805,9 @@ impl<'context> ConstructorBuilder<'context> {
     #[inline]
     pub fn name<N>(&mut self, name: N) -> &mut Self
     where
-        N: AsRef<str>,
+        N: Into<JsString>,
     {
-        self.name = name.as_ref().into();
+        self.name = name.into();
         self
     }
 
@@ -2154,6 +2869,7 @@ impl<'context> ConstructorBuilder<'context> {
         let function = Function::Native {
             function: self.function,
             constructor: self.constructor,
+            realm: self.context.realm().clone(),
         };
 
         let length = PropertyDescriptor::builder()
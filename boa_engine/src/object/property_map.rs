@@ -1,9 +1,14 @@
-use super::{PropertyDescriptor, PropertyKey};
-use crate::{property::PropertyDescriptorBuilder, JsString, JsSymbol, JsValue};
+use super::{
+    shape::{Shape, MAX_SHAPE_PROPERTIES},
+    Object, PropertyDescriptor, PropertyKey,
+};
+use crate::{property::PropertyDescriptorBuilder, vm::HeapCharge, JsString, JsSymbol, JsValue};
 use boa_gc::{custom_trace, Finalize, Trace};
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHasher};
-use std::{collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator};
+use std::{
+    collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator, iter::Zip, mem, slice,
+};
 
 /// Type alias to make it easier to work with the string properties on the global object.
 pub(crate) type GlobalPropertyMap =
@@ -35,7 +40,7 @@ unsafe impl<K: Trace> Trace for OrderedHashMap<K> {
 /// - `Sparse` Storage
 ///
 /// By default it is dense storage.
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Finalize)]
 enum IndexedProperties {
     /// Dense storage holds a contiguous array of properties where the index in the array is the key of the property.
     /// These are known to be data descriptors with a value field, writable field set to `true`, configurable field set to `true`, enumerable field set to `true`.
@@ -44,7 +49,12 @@ enum IndexedProperties {
     /// the value field and construct the data property descriptor on demand.
     ///
     /// This storage method is used by default.
-    Dense(Vec<JsValue>),
+    ///
+    /// `has_objects` is set once an object is stored, and tells if the values have to be traced.
+    Dense {
+        values: Vec<JsValue>,
+        has_objects: bool,
+    },
 
     /// Sparse storage this storage is used as a backup if the element keys are not continuous or the property descriptors
     /// are not data descriptors with with a value field, writable field set to `true`, configurable field set to `true`, enumerable field set to `true`.
@@ -57,17 +67,44 @@ enum IndexedProperties {
 impl Default for IndexedProperties {
     #[inline]
     fn default() -> Self {
-        Self::Dense(Vec::new())
+        Self::Dense {
+            values: Vec::new(),
+            has_objects: false,
+        }
     }
 }
 
+unsafe impl Trace for IndexedProperties {
+    custom_trace!(this, {
+        match this {
+            // The values of an array are rooted and unrooted each time the array is borrowed
+            // mutably, so they are skipped when they can't hold an object.
+            Self::Dense {
+                values,
+                has_objects,
+            } => {
+                if *has_objects {
+                    for value in values {
+                        mark(value);
+                    }
+                }
+            }
+            Self::Sparse(map) => {
+                for property in map.values() {
+                    mark(property);
+                }
+            }
+        }
+    });
+}
+
 impl IndexedProperties {
     /// Get a property descriptor if it exists.
     #[inline]
     fn get(&self, key: u32) -> Option<PropertyDescriptor> {
         match self {
             Self::Sparse(ref map) => map.get(&key).cloned(),
-            Self::Dense(ref vec) => vec.get(key as usize).map(|value| {
+            Self::Dense { ref values, .. } => values.get(key as usize).map(|value| {
                 PropertyDescriptorBuilder::new()
                     .writable(true)
                     .enumerable(true)
@@ -104,7 +141,10 @@ impl IndexedProperties {
     fn insert(&mut self, key: u32, property: PropertyDescriptor) -> Option<PropertyDescriptor> {
         let vec = match self {
             Self::Sparse(map) => return map.insert(key, property),
-            Self::Dense(vec) => {
+            Self::Dense {
+                values: vec,
+                has_objects,
+            } => {
                 let len = vec.len() as u32;
                 if key <= len
                     && property.value().is_some()
@@ -118,6 +158,7 @@ impl IndexedProperties {
                         .value()
                         .cloned()
                         .expect("already checked that the property descriptor has a value field");
+                    *has_objects |= value.is_object();
 
                     // If the key is pointing one past the last element, we push it!
                     //
@@ -156,7 +197,7 @@ impl IndexedProperties {
     fn remove(&mut self, key: u32) -> Option<PropertyDescriptor> {
         let vec = match self {
             Self::Sparse(map) => return map.remove(&key),
-            Self::Dense(vec) => {
+            Self::Dense { values: vec, .. } => {
                 // Fast Path: contiguous storage.
 
                 // Has no elements or out of range, nothing to delete!
@@ -195,42 +236,194 @@ impl IndexedProperties {
     fn contains_key(&self, key: u32) -> bool {
         match self {
             Self::Sparse(map) => map.contains_key(&key),
-            Self::Dense(vec) => (0..vec.len() as u32).contains(&key),
+            Self::Dense { values, .. } => (0..values.len() as u32).contains(&key),
         }
     }
 
     fn iter(&self) -> IndexProperties<'_> {
         match self {
-            Self::Dense(vec) => IndexProperties::Dense(vec.iter().enumerate()),
+            Self::Dense { values, .. } => IndexProperties::Dense(values.iter().enumerate()),
             Self::Sparse(map) => IndexProperties::Sparse(map.iter()),
         }
     }
 
     fn keys(&self) -> IndexPropertyKeys<'_> {
         match self {
-            Self::Dense(vec) => IndexPropertyKeys::Dense(0..vec.len() as u32),
+            Self::Dense { values, .. } => IndexPropertyKeys::Dense(0..values.len() as u32),
             Self::Sparse(map) => IndexPropertyKeys::Sparse(map.keys()),
         }
     }
 
     fn values(&self) -> IndexPropertyValues<'_> {
         match self {
-            Self::Dense(vec) => IndexPropertyValues::Dense(vec.iter()),
+            Self::Dense { values, .. } => IndexPropertyValues::Dense(values.iter()),
             Self::Sparse(map) => IndexPropertyValues::Sparse(map.values()),
         }
     }
+
+    /// Returns the approximate number of bytes allocated to store the properties.
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Dense { values, .. } => values.capacity() * mem::size_of::<JsValue>(),
+            Self::Sparse(map) => map.capacity() * (mem::size_of::<(u32, PropertyDescriptor)>() + 1),
+        }
+    }
+}
+
+/// The storage of the string keyed properties of an object.
+///
+/// The properties are stored in slots whose keys are given by a [`Shape`], which is shared with
+/// the other objects that have the same properties. Objects with many properties, or objects that
+/// had properties removed, are used as dictionaries and get their own hash map instead.
+#[derive(Debug, Finalize)]
+enum StringPropertyStorage {
+    Shaped {
+        shape: Shape,
+        slots: Vec<PropertyDescriptor>,
+    },
+    Dictionary(GlobalPropertyMap),
+}
+
+impl Default for StringPropertyStorage {
+    #[inline]
+    fn default() -> Self {
+        Self::Shaped {
+            shape: Shape::root(),
+            slots: Vec::new(),
+        }
+    }
+}
+
+unsafe impl Trace for StringPropertyStorage {
+    custom_trace!(this, {
+        match this {
+            Self::Shaped { slots, .. } => {
+                for property in slots {
+                    mark(property);
+                }
+            }
+            Self::Dictionary(map) => {
+                for property in map.values() {
+                    mark(property);
+                }
+            }
+        }
+    });
+}
+
+impl StringPropertyStorage {
+    /// Gets the property with the key `key`.
+    #[inline]
+    fn get(&self, key: &JsString) -> Option<&PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => shape.slot(key).map(|slot| &slots[slot as usize]),
+            Self::Dictionary(map) => map.get(key),
+        }
+    }
+
+    /// Inserts a property with the key `key`, returning the previous property with that key.
+    fn insert(
+        &mut self,
+        key: &JsString,
+        property: PropertyDescriptor,
+    ) -> Option<PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => {
+                if let Some(slot) = shape.slot(key) {
+                    return Some(std::mem::replace(&mut slots[slot as usize], property));
+                }
+                if shape.len() < MAX_SHAPE_PROPERTIES {
+                    *shape = shape.transition(key);
+                    slots.push(property);
+                    return None;
+                }
+            }
+            Self::Dictionary(map) => return map.insert(key.clone(), property),
+        }
+
+        self.dictionary_mut().insert(key.clone(), property)
+    }
+
+    /// Removes the property with the key `key`, returning it.
+    #[inline]
+    fn remove(&mut self, key: &JsString) -> Option<PropertyDescriptor> {
+        if let Self::Shaped { shape, .. } = self {
+            shape.slot(key)?;
+        }
+        self.dictionary_mut().shift_remove(key)
+    }
+
+    /// Gets the hash map of the properties, converting the storage to a dictionary if it is not.
+    fn dictionary_mut(&mut self) -> &mut GlobalPropertyMap {
+        if let Self::Shaped { shape, slots } = self {
+            let map = shape
+                .keys()
+                .iter()
+                .cloned()
+                .zip(std::mem::take(slots))
+                .collect();
+            *self = Self::Dictionary(map);
+        }
+        match self {
+            Self::Dictionary(map) => map,
+            Self::Shaped { .. } => unreachable!("the storage was converted to a dictionary"),
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> StringProperties<'_> {
+        StringProperties(match self {
+            Self::Shaped { shape, slots } => {
+                StringPropertiesInner::Shaped(shape.keys().iter().zip(slots.iter()))
+            }
+            Self::Dictionary(map) => StringPropertiesInner::Dictionary(map.iter()),
+        })
+    }
+
+    /// Returns the approximate number of bytes allocated to store the properties.
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Shaped { slots, .. } => slots.capacity() * mem::size_of::<PropertyDescriptor>(),
+            Self::Dictionary(map) => ordered_map_heap_size(map),
+        }
+    }
 }
 
-#[derive(Default, Debug, Trace, Finalize)]
+/// Returns the approximate number of bytes allocated by an `IndexMap`, which stores its entries
+/// in a vector and their indices in a hash table.
+fn ordered_map_heap_size<K, V, S>(map: &IndexMap<K, V, S>) -> usize {
+    map.capacity() * (mem::size_of::<(usize, K, V)>() + mem::size_of::<usize>() + 1)
+}
+
+#[derive(Debug, Trace, Finalize)]
 pub struct PropertyMap {
     indexed_properties: IndexedProperties,
     /// Properties
-    string_properties: OrderedHashMap<JsString>,
+    string_properties: StringPropertyStorage,
     /// Symbol Properties
     symbol_properties: OrderedHashMap<JsSymbol>,
+    /// The memory of the object that owns the properties, including their storage, charged to
+    /// the context that created it.
+    #[unsafe_ignore_trace]
+    charge: HeapCharge,
+}
+
+impl Default for PropertyMap {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            indexed_properties: IndexedProperties::default(),
+            string_properties: StringPropertyStorage::default(),
+            symbol_properties: OrderedHashMap::default(),
+            charge: HeapCharge::new(Self::OBJECT_SIZE),
+        }
+    }
 }
 
 impl PropertyMap {
+    /// The approximate number of bytes allocated by the collector for an object.
+    const OBJECT_SIZE: usize = mem::size_of::<boa_gc::Cell<Object>>() + 2 * mem::size_of::<usize>();
+
     pub fn new() -> Self {
         Self::default()
     }
@@ -238,7 +431,7 @@ impl PropertyMap {
     pub fn get(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
         match key {
             PropertyKey::Index(index) => self.indexed_properties.get(*index),
-            PropertyKey::String(string) => self.string_properties.0.get(string).cloned(),
+            PropertyKey::String(string) => self.string_properties.get(string).cloned(),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.get(symbol).cloned(),
         }
     }
@@ -248,34 +441,58 @@ impl PropertyMap {
         key: &PropertyKey,
         property: PropertyDescriptor,
     ) -> Option<PropertyDescriptor> {
-        match &key {
+        let old_property = match &key {
             PropertyKey::Index(index) => self.indexed_properties.insert(*index, property),
-            PropertyKey::String(string) => {
-                self.string_properties.0.insert(string.clone(), property)
-            }
+            PropertyKey::String(string) => self.string_properties.insert(string, property),
             PropertyKey::Symbol(symbol) => {
                 self.symbol_properties.0.insert(symbol.clone(), property)
             }
-        }
+        };
+        self.update_charge();
+        old_property
     }
 
     pub fn remove(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
-        match key {
+        let old_property = match key {
             PropertyKey::Index(index) => self.indexed_properties.remove(*index),
-            PropertyKey::String(string) => self.string_properties.0.shift_remove(string),
+            PropertyKey::String(string) => self.string_properties.remove(string),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.shift_remove(symbol),
+        };
+        self.update_charge();
+        old_property
+    }
+
+    /// Gets the number of bytes charged for the object, including the storage of its properties.
+    #[inline]
+    pub(crate) fn charged_bytes(&self) -> usize {
+        self.charge.bytes()
+    }
+
+    /// Updates the memory charged for the object after its properties were added or removed.
+    #[inline]
+    fn update_charge(&mut self) {
+        let bytes = Self::OBJECT_SIZE
+            + self.indexed_properties.heap_size()
+            + self.string_properties.heap_size()
+            + ordered_map_heap_size(&self.symbol_properties.0);
+        if bytes != self.charge.bytes() {
+            self.charge.resize(bytes);
         }
     }
 
     /// Overrides all the indexed properties, setting it to dense storage.
     pub(crate) fn override_indexed_properties(&mut self, properties: Vec<JsValue>) {
-        self.indexed_properties = IndexedProperties::Dense(properties);
+        self.indexed_properties = IndexedProperties::Dense {
+            has_objects: properties.iter().any(JsValue::is_object),
+            values: properties,
+        };
+        self.update_charge();
     }
 
     /// Returns the vec of dense indexed properties if they exist.
     pub(crate) fn dense_indexed_properties(&self) -> Option<&Vec<JsValue>> {
-        if let IndexedProperties::Dense(properties) = &self.indexed_properties {
-            Some(properties)
+        if let IndexedProperties::Dense { values, .. } = &self.indexed_properties {
+            Some(values)
         } else {
             None
         }
@@ -288,7 +505,7 @@ impl PropertyMap {
     pub fn iter(&self) -> Iter<'_> {
         Iter {
             indexed_properties: self.indexed_properties.iter(),
-            string_properties: self.string_properties.0.iter(),
+            string_properties: self.string_properties.iter(),
             symbol_properties: self.symbol_properties.0.iter(),
         }
     }
@@ -363,7 +580,7 @@ impl PropertyMap {
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_properties(&self) -> StringProperties<'_> {
-        StringProperties(self.string_properties.0.iter())
+        self.string_properties.iter()
     }
 
     /// An iterator visiting all string keys in arbitrary order. The iterator element type is `&'a RcString`.
@@ -371,7 +588,7 @@ impl PropertyMap {
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_property_keys(&self) -> StringPropertyKeys<'_> {
-        StringPropertyKeys(self.string_properties.0.keys())
+        StringPropertyKeys(self.string_properties.iter())
     }
 
     /// An iterator visiting all string values in arbitrary order. The iterator element type is `&'a Property`.
@@ -379,26 +596,90 @@ impl PropertyMap {
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_property_values(&self) -> StringPropertyValues<'_> {
-        StringPropertyValues(self.string_properties.0.values())
+        StringPropertyValues(self.string_properties.iter())
     }
 
     #[inline]
     pub fn contains_key(&self, key: &PropertyKey) -> bool {
         match key {
             PropertyKey::Index(index) => self.indexed_properties.contains_key(*index),
-            PropertyKey::String(string) => self.string_properties.0.contains_key(string),
+            PropertyKey::String(string) => self.string_properties.get(string).is_some(),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.contains_key(symbol),
         }
     }
 
+    /// Gets the hash map of the string keyed properties, moving them to a hash map if they are
+    /// stored in the slots of a shape.
     #[inline]
-    pub(crate) fn string_property_map(&self) -> &GlobalPropertyMap {
-        &self.string_properties.0
+    pub(crate) fn string_property_map_mut(&mut self) -> &mut GlobalPropertyMap {
+        // The growth of the returned map isn't charged, which is fine for the global object, its
+        // only user.
+        self.string_properties.dictionary_mut()
     }
 
+    /// Gets the shape of the string keyed properties, if they are stored in the slots of a shape.
     #[inline]
-    pub(crate) fn string_property_map_mut(&mut self) -> &mut GlobalPropertyMap {
-        &mut self.string_properties.0
+    pub(crate) fn shape(&self) -> Option<&Shape> {
+        match &self.string_properties {
+            StringPropertyStorage::Shaped { shape, .. } => Some(shape),
+            StringPropertyStorage::Dictionary(_) => None,
+        }
+    }
+
+    /// Gets the string keyed property in the slot `slot` of the shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the properties are not stored in the slots of a shape, or if the shape does not
+    /// have the slot.
+    #[inline]
+    pub(crate) fn slot(&self, slot: u32) -> &PropertyDescriptor {
+        match &self.string_properties {
+            StringPropertyStorage::Shaped { slots, .. } => &slots[slot as usize],
+            StringPropertyStorage::Dictionary(_) => {
+                panic!("the string keyed properties must be stored in slots")
+            }
+        }
+    }
+
+    /// Gets the string keyed property in the slot `slot` of the shape mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the properties are not stored in the slots of a shape, or if the shape does not
+    /// have the slot.
+    #[inline]
+    pub(crate) fn slot_mut(&mut self, slot: u32) -> &mut PropertyDescriptor {
+        match &mut self.string_properties {
+            StringPropertyStorage::Shaped { slots, .. } => &mut slots[slot as usize],
+            StringPropertyStorage::Dictionary(_) => {
+                panic!("the string keyed properties must be stored in slots")
+            }
+        }
+    }
+
+    /// Adds a string keyed property that is the last property of `shape`, which must be the
+    /// transition of the current shape with the key of the property.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the properties are not stored in the slots of a shape.
+    #[inline]
+    pub(crate) fn push_slot(&mut self, shape: Shape, property: PropertyDescriptor) {
+        match &mut self.string_properties {
+            StringPropertyStorage::Shaped {
+                shape: current,
+                slots,
+            } => {
+                debug_assert_eq!(shape.len(), slots.len() + 1);
+                *current = shape;
+                slots.push(property);
+            }
+            StringPropertyStorage::Dictionary(_) => {
+                panic!("the string keyed properties must be stored in slots")
+            }
+        }
+        self.update_charge();
     }
 }
 
@@ -406,7 +687,7 @@ impl PropertyMap {
 #[derive(Debug, Clone)]
 pub struct Iter<'a> {
     indexed_properties: IndexProperties<'a>,
-    string_properties: indexmap::map::Iter<'a, JsString, PropertyDescriptor>,
+    string_properties: StringProperties<'a>,
     symbol_properties: indexmap::map::Iter<'a, JsSymbol, PropertyDescriptor>,
 }
 
@@ -692,26 +973,41 @@ impl FusedIterator for IndexPropertyValues<'_> {}
 
 /// An iterator over the `String` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct StringProperties<'a>(indexmap::map::Iter<'a, JsString, PropertyDescriptor>);
+pub struct StringProperties<'a>(StringPropertiesInner<'a>);
+
+#[derive(Debug, Clone)]
+enum StringPropertiesInner<'a> {
+    Shaped(Zip<slice::Iter<'a, JsString>, slice::Iter<'a, PropertyDescriptor>>),
+    Dictionary(indexmap::map::Iter<'a, JsString, PropertyDescriptor>),
+}
 
 impl<'a> Iterator for StringProperties<'a> {
     type Item = (&'a JsString, &'a PropertyDescriptor);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        match &mut self.0 {
+            StringPropertiesInner::Shaped(iter) => iter.next(),
+            StringPropertiesInner::Dictionary(iter) => iter.next(),
+        }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+        match &self.0 {
+            StringPropertiesInner::Shaped(iter) => iter.size_hint(),
+            StringPropertiesInner::Dictionary(iter) => iter.size_hint(),
+        }
     }
 }
 
 impl ExactSizeIterator for StringProperties<'_> {
     #[inline]
     fn len(&self) -> usize {
-        self.0.len()
+        match &self.0 {
+            StringPropertiesInner::Shaped(iter) => iter.len(),
+            StringPropertiesInner::Dictionary(iter) => iter.len(),
+        }
     }
 }
 
@@ -719,14 +1015,14 @@ impl FusedIterator for StringProperties<'_> {}
 
 /// An iterator over the string keys (`RcString`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyKeys<'a>(indexmap::map::Keys<'a, JsString, PropertyDescriptor>);
+pub struct StringPropertyKeys<'a>(StringProperties<'a>);
 
 impl<'a> Iterator for StringPropertyKeys<'a> {
     type Item = &'a JsString;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.0.next().map(|(key, _)| key)
     }
 
     #[inline]
@@ -746,14 +1042,14 @@ impl FusedIterator for StringPropertyKeys<'_> {}
 
 /// An iterator over the string values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyValues<'a>(indexmap::map::Values<'a, JsString, PropertyDescriptor>);
+pub struct StringPropertyValues<'a>(StringProperties<'a>);
 
 impl<'a> Iterator for StringPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.0.next().map(|(_, value)| value)
     }
 
     #[inline]
@@ -0,0 +1,175 @@
+//! Shapes, the shared layouts of the string keyed properties of objects.
+//!
+//! A [`Shape`] maps the string keys of the properties of an object to the indices of the slots
+//! where their property descriptors are stored. Objects that get the same properties added in
+//! the same order share the same shape, because shapes are created by transitions: adding a
+//! property to an object with shape `S` gives it the shape that `S` transitions to with the key
+//! of the property, which is created the first time it is needed and reused afterwards.
+//!
+//! Since a shape identifies the layout of the properties of every object that has it, code that
+//! has looked up a property of an object once can remember the shape of the object and the slot
+//! of the property, and access the property of objects with the same shape without looking up
+//! the key again. This is what the inline caches of the VM do.
+
+use crate::JsString;
+use once_cell::unsync::OnceCell;
+use rustc_hash::FxHashMap;
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::{Rc, Weak},
+};
+
+/// The maximum number of properties of a shape.
+///
+/// Objects that get more properties than this stop using shapes and store their properties in a
+/// hash map, since every shape with many properties that is looked up keeps its own index of
+/// the keys.
+pub(crate) const MAX_SHAPE_PROPERTIES: usize = 64;
+
+/// The maximum number of properties of a shape whose keys are looked up by comparing them one by
+/// one; the keys of larger shapes are looked up in a hash map.
+const MAX_LINEAR_LOOKUP_PROPERTIES: usize = 8;
+
+thread_local! {
+    /// The shape of objects without string keyed properties.
+    static ROOT_SHAPE: Shape = Shape {
+        inner: Rc::new(Inner {
+            parent: None,
+            len: 0,
+            keys: OnceCell::new(),
+            index: OnceCell::new(),
+            transitions: RefCell::default(),
+        }),
+    };
+}
+
+/// The layout of the string keyed properties of an object.
+///
+/// Shapes are cheap to clone, and two shapes are equal if they are the same shape.
+#[derive(Clone)]
+pub(crate) struct Shape {
+    inner: Rc<Inner>,
+}
+
+struct Inner {
+    /// The shape that transitioned to this shape, and the key of the property that was added.
+    ///
+    /// Shapes only store the key they add, so a transition doesn't copy the keys of its parent.
+    parent: Option<(Shape, JsString)>,
+
+    /// The number of properties of the shape.
+    len: usize,
+
+    /// The keys of the properties in the order of their slots, collected when first needed.
+    keys: OnceCell<Vec<JsString>>,
+
+    /// The slots of the properties by key, built when a key of a large shape is first looked up.
+    index: OnceCell<FxHashMap<JsString, u32>>,
+
+    /// The shapes that this shape transitions to when a property is added.
+    ///
+    /// The transitions are weak, so shapes that are not used by any object are dropped.
+    transitions: RefCell<FxHashMap<JsString, Weak<Inner>>>,
+}
+
+impl Shape {
+    /// Gets the shape without properties.
+    #[inline]
+    pub(crate) fn root() -> Self {
+        ROOT_SHAPE.with(Self::clone)
+    }
+
+    /// Gets the number of properties of the shape.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.inner.len
+    }
+
+    /// Gets the keys of the properties of the shape, in the order of their slots.
+    #[inline]
+    pub(crate) fn keys(&self) -> &[JsString] {
+        self.inner.keys.get_or_init(|| {
+            let mut keys: Vec<_> = self.ancestors().map(|(_, key)| key.clone()).collect();
+            keys.reverse();
+            keys
+        })
+    }
+
+    /// Gets the slot of the property with the key `key`.
+    #[inline]
+    pub(crate) fn slot(&self, key: &JsString) -> Option<u32> {
+        if self.len() > MAX_LINEAR_LOOKUP_PROPERTIES {
+            let index = self.inner.index.get_or_init(|| {
+                self.keys()
+                    .iter()
+                    .enumerate()
+                    .map(|(slot, key)| (key.clone(), slot as u32))
+                    .collect()
+            });
+            return index.get(key).copied();
+        }
+        if let Some(keys) = self.inner.keys.get() {
+            return keys.iter().position(|k| k == key).map(|slot| slot as u32);
+        }
+        self.ancestors()
+            .find(|(_, k)| *k == key)
+            .map(|(slot, _)| slot as u32)
+    }
+
+    /// Iterates over the slots and keys of the properties, from the last added to the first.
+    fn ancestors(&self) -> impl Iterator<Item = (usize, &JsString)> {
+        let mut inner = &*self.inner;
+        std::iter::from_fn(move || {
+            let (parent, key) = inner.parent.as_ref()?;
+            let slot = inner.len - 1;
+            inner = &parent.inner;
+            Some((slot, key))
+        })
+    }
+
+    /// Gets the shape of an object with this shape after the property `key` is added to it.
+    ///
+    /// The slot of the new property is the number of properties of this shape.
+    pub(crate) fn transition(&self, key: &JsString) -> Self {
+        debug_assert!(self.slot(key).is_none(), "the key must not be in the shape");
+
+        let mut transitions = self.inner.transitions.borrow_mut();
+        if let Some(inner) = transitions.get(key).and_then(Weak::upgrade) {
+            return Self { inner };
+        }
+
+        let inner = Rc::new(Inner {
+            parent: Some((self.clone(), key.clone())),
+            len: self.len() + 1,
+            keys: OnceCell::new(),
+            index: OnceCell::new(),
+            transitions: RefCell::default(),
+        });
+
+        // Drop the transitions to shapes that are not used anymore each time the number of
+        // transitions doubles, so adding transitions stays cheap.
+        if transitions.len().is_power_of_two() {
+            transitions.retain(|_, shape| shape.strong_count() > 0);
+        }
+        transitions.insert(key.clone(), Rc::downgrade(&inner));
+        Self { inner }
+    }
+}
+
+impl PartialEq for Shape {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Shape {}
+
+impl fmt::Debug for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shape")
+            .field("keys", &self.keys())
+            .finish_non_exhaustive()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{check_output, exec, TestAction};
+use crate::{check_output, exec, forward, Context, TestAction};
 
 #[test]
 fn ordinary_has_instance_nonobject_prototype() {
@@ -44,3 +44,100 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn objects_with_the_same_properties_share_shapes() {
+    let mut context = Context::default();
+    let objects = context
+        .eval(
+            r#"
+            function Point(x, y) { this.x = x; this.y = y; }
+            const a = new Point(1, 2);
+            const b = new Point(3, 4);
+            const c = { y: 1, x: 2 };
+            const d = new Point(5, 6);
+            delete d.y;
+            [a, b, c, d]
+            "#,
+        )
+        .unwrap();
+    let objects = objects.as_object().unwrap();
+    let shape = |index: u32, context: &mut Context| {
+        let object = objects.get(index, context).unwrap();
+        let object = object.as_object().unwrap().borrow();
+        object.properties().shape().cloned()
+    };
+
+    let a = shape(0, &mut context).unwrap();
+    let b = shape(1, &mut context).unwrap();
+    let c = shape(2, &mut context).unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(a.keys().len(), 2);
+
+    // Objects that had properties removed store their properties in a hash map.
+    assert!(shape(3, &mut context).is_none());
+}
+
+#[test]
+fn objects_with_many_properties_are_dictionaries() {
+    let mut context = Context::default();
+    let object = context
+        .eval("const o = {}; for (let i = 0; i < 100; i++) { o['p' + i] = i; } o")
+        .unwrap();
+    assert!(object
+        .as_object()
+        .unwrap()
+        .borrow()
+        .properties()
+        .shape()
+        .is_none());
+    assert_eq!(
+        forward(&mut context, "Object.keys(o).slice(62, 66).join()"),
+        "\"p62,p63,p64,p65\""
+    );
+    assert_eq!(forward(&mut context, "o.p99"), "99");
+}
+
+#[test]
+fn arrays_trace_the_objects_stored_among_primitives() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        r#"
+        const a = [1, 2, 3];
+        a[1] = { x: 42 };
+        a.push({ y: "y" });
+        const entries = Object.entries({ k: { z: 7 } });
+        "#,
+    );
+    boa_gc::force_collect();
+    assert_eq!(forward(&mut context, "a[1].x"), "42");
+    assert_eq!(forward(&mut context, "a[3].y"), "\"y\"");
+    assert_eq!(forward(&mut context, "entries[0][1].z"), "7");
+}
+
+#[test]
+fn objects_with_diverging_shapes_keep_their_own_properties() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        r#"
+        const a = { x: 1, y: 2, z: 3 };
+        const b = { x: 4, y: 5, w: 6 };
+        const c = { x: 7 };
+        "#,
+    );
+    assert_eq!(forward(&mut context, "Object.keys(a).join()"), "\"x,y,z\"");
+    assert_eq!(forward(&mut context, "Object.keys(b).join()"), "\"x,y,w\"");
+    assert_eq!(
+        forward(&mut context, "[a.z, a.w, b.z, b.w].join()"),
+        "\"3,,,6\""
+    );
+    assert_eq!(forward(&mut context, "c.y"), "undefined");
+    forward(&mut context, "c.w = 8; c.y = 9;");
+    assert_eq!(
+        forward(&mut context, "Object.entries(c).join()"),
+        "\"x,7,w,8,y,9\""
+    );
+}
@@ -15,7 +15,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty
 //! [section]: https://tc39.es/ecma262/#sec-property-attributes
 
-use crate::{JsString, JsSymbol, JsValue};
+use crate::{string::utf16, JsString, JsSymbol, JsValue};
 use boa_gc::{Finalize, Trace};
 use std::fmt;
 
@@ -498,7 +498,7 @@ pub enum PropertyKey {
 impl From<JsString> for PropertyKey {
     #[inline]
     fn from(string: JsString) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             Self::Index(index)
         } else {
             Self::String(string)
@@ -506,6 +506,19 @@ impl From<JsString> for PropertyKey {
     }
 }
 
+/// Parses the code units of a string as an integer index, accepting the same inputs as
+/// `str::parse::<u32>`.
+fn parse_index(string: &[u16]) -> Option<u32> {
+    let digits = string.strip_prefix(utf16!("+")).unwrap_or(string);
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(0_u32, |index, &c| {
+        let digit = char::from_u32(u32::from(c))?.to_digit(10)?;
+        index.checked_mul(10)?.checked_add(digit)
+    })
+}
+
 impl From<&str> for PropertyKey {
     #[inline]
     fn from(string: &str) -> Self {
@@ -3,29 +3,74 @@
 //! and other associated state and resources.
 //!
 //! A realm is represented in this implementation as a Realm struct with the fields specified from the spec.
+//!
+//! A [`Context`][crate::Context] always has a current realm, but it can own many of them: new
+//! realms are created with [`Context::create_realm`][crate::Context::create_realm] and made
+//! current with [`Context::enter_realm`][crate::Context::enter_realm]. Every realm has its own
+//! global object and its own set of builtins, so values created in different realms do not share
+//! prototypes.
 
 use crate::{
-    environments::{CompileTimeEnvironment, DeclarativeEnvironmentStack},
-    object::{GlobalPropertyMap, JsObject, ObjectData, PropertyMap},
+    context::intrinsics::Intrinsics,
+    environments::{CompileTimeEnvironment, DeclarativeEnvironment},
+    module::Module,
+    object::{GlobalPropertyMap, JsObject, Object, ObjectData, RefMut},
 };
-use boa_gc::{Cell, Gc};
+use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
+use rustc_hash::FxHashMap;
+use std::fmt;
 
 /// Representation of a Realm.
 ///
 /// In the specification these are called Realm Records.
-#[derive(Debug)]
+///
+/// This is a cheap handle to the realm: clones of it refer to the same realm, and two handles
+/// compare equal if they refer to the same realm.
+#[derive(Clone, Trace, Finalize)]
 pub struct Realm {
+    inner: Gc<Inner>,
+}
+
+#[derive(Trace, Finalize)]
+struct Inner {
+    intrinsics: Intrinsics,
     global_object: JsObject,
-    pub(crate) global_extensible: bool,
-    pub(crate) global_property_map: PropertyMap,
-    pub(crate) environments: DeclarativeEnvironmentStack,
-    pub(crate) compile_env: Gc<Cell<CompileTimeEnvironment>>,
+    global_env: Gc<DeclarativeEnvironment>,
+
+    /// The compile time environment that new code of this realm is compiled in.
+    ///
+    /// This is the global compile time environment, except while code is being compiled.
+    compile_env: Cell<Gc<Cell<CompileTimeEnvironment>>>,
+
+    /// The modules loaded in this realm by the [`ModuleLoader`][crate::module::ModuleLoader] of
+    /// the context, by the key chosen by the loader.
+    loaded_modules: Cell<FxHashMap<String, Module>>,
 }
 
+impl fmt::Debug for Realm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Realm")
+            .field("global_object", &self.inner.global_object)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Realm {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Realm {}
+
 impl Realm {
+    /// Creates a new realm, with a new global object and uninitialized intrinsics.
+    ///
+    /// The builtins are added to the realm by [`Context::create_realm`][crate::Context::create_realm].
     #[inline]
-    pub fn create() -> Self {
+    pub(crate) fn create() -> Self {
         let _timer = Profiler::global().start_event("Realm::create", "realm");
         // Create brand new global object
         // Global has no prototype to pass None to new_obj
@@ -35,28 +80,67 @@ impl Realm {
         let global_compile_environment = Gc::new(Cell::new(CompileTimeEnvironment::new_global()));
 
         Self {
-            global_object,
-            global_extensible: true,
-            global_property_map: PropertyMap::default(),
-            environments: DeclarativeEnvironmentStack::new(global_compile_environment.clone()),
-            compile_env: global_compile_environment,
+            inner: Gc::new(Inner {
+                intrinsics: Intrinsics::default(),
+                global_object,
+                global_env: DeclarativeEnvironment::new_global(global_compile_environment.clone()),
+                compile_env: Cell::new(global_compile_environment),
+                loaded_modules: Cell::default(),
+            }),
         }
     }
 
+    /// Returns the global object of the realm.
+    #[inline]
+    pub fn global_object(&self) -> &JsObject {
+        &self.inner.global_object
+    }
+
+    /// Returns the intrinsic objects and constructors of the realm.
+    #[inline]
+    pub fn intrinsics(&self) -> &Intrinsics {
+        &self.inner.intrinsics
+    }
+
+    /// Returns the global declarative environment of the realm.
+    #[inline]
+    pub(crate) fn global_env(&self) -> &Gc<DeclarativeEnvironment> {
+        &self.inner.global_env
+    }
+
+    /// Returns a mutable reference to the string keyed properties of the global object.
+    #[inline]
+    pub(crate) fn global_bindings_mut(&self) -> RefMut<'_, Object, GlobalPropertyMap> {
+        RefMut::map(self.inner.global_object.borrow_mut(), |global| {
+            global.properties_mut().string_property_map_mut()
+        })
+    }
+
+    /// Returns the compile time environment that new code of this realm is compiled in.
+    #[inline]
+    pub(crate) fn compile_env(&self) -> Gc<Cell<CompileTimeEnvironment>> {
+        self.inner.compile_env.borrow().clone()
+    }
+
     #[inline]
-    pub(crate) fn global_object(&self) -> &JsObject {
-        &self.global_object
+    pub(crate) fn set_compile_env(&self, compile_env: Gc<Cell<CompileTimeEnvironment>>) {
+        *self.inner.compile_env.borrow_mut() = compile_env;
     }
 
+    /// Gets the module that was loaded in this realm with the key `key`, if any.
+    ///
+    /// Module loaders use this to return the same module every time it is imported from the
+    /// same realm, without sharing it with other realms.
     #[inline]
-    pub(crate) fn global_bindings_mut(&mut self) -> &mut GlobalPropertyMap {
-        self.global_property_map.string_property_map_mut()
+    pub fn loaded_module(&self, key: &str) -> Option<Module> {
+        self.inner.loaded_modules.borrow().get(key).cloned()
     }
 
-    /// Set the number of bindings on the global environment.
+    /// Records `module` as the module loaded in this realm with the key `key`.
+    ///
+    /// The module is kept alive as long as the realm.
     #[inline]
-    pub(crate) fn set_global_binding_number(&mut self) {
-        let binding_number = self.compile_env.borrow().num_bindings();
-        self.environments.set_global_binding_number(binding_number);
+    pub fn insert_loaded_module(&self, key: String, module: Module) {
+        self.inner.loaded_modules.borrow_mut().insert(key, module);
     }
 }
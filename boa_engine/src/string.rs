@@ -1,4 +1,20 @@
-use crate::builtins::string::is_trimmable_whitespace;
+//! This module implements the JavaScript `String` primitive, [`JsString`].
+//!
+//! JavaScript strings are sequences of UTF-16 code units which are not required to be valid
+//! UTF-16, since they can contain unpaired surrogates (e.g. `"\uD800"`). For this reason
+//! [`JsString`] stores its contents as a slice of code units instead of as a Rust [`str`], and
+//! conversions to Rust strings report when they lose information.
+//!
+//! String literals can be converted to their UTF-16 representation at compile time with the
+//! [`utf16!`] macro, and [`JsString`]s can be built from literals and other strings with the
+//! [`js_string!`] macro.
+
+use crate::{
+    builtins::string::is_trimmable_whitespace,
+    value::{assert_address_fits, tag_address, untag_address, STRING_TAG},
+    vm::HeapAccount,
+    JsBigInt,
+};
 use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use rustc_hash::{FxHashMap, FxHasher};
 use std::{
@@ -8,460 +24,663 @@ use std::{
     hash::BuildHasherDefault,
     hash::{Hash, Hasher},
     marker::PhantomData,
-    ops::Deref,
+    ops::{Deref, Index},
     ptr::{copy_nonoverlapping, NonNull},
     rc::Rc,
+    slice::SliceIndex,
+    string::FromUtf16Error,
 };
 
-const CONSTANTS_ARRAY: [&str; 419] = [
+/// Utility macro to create a `&'static [u16]` from a string literal, encoded in UTF-16 at
+/// compile time.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::string::utf16;
+///
+/// const HELLO: &[u16] = utf16!("hello");
+/// assert_eq!(HELLO, "hello".encode_utf16().collect::<Vec<_>>());
+/// ```
+#[macro_export]
+macro_rules! utf16 {
+    ($s:literal) => {{
+        const LITERAL: &str = $s;
+        const LEN: usize = $crate::string::__utf16_len(LITERAL);
+        const UTF16: [u16; LEN] = $crate::string::__encode_utf16(LITERAL);
+        &UTF16 as &[u16]
+    }};
+}
+
+#[doc(inline)]
+pub use crate::utf16;
+
+/// Utility macro to create a [`JsString`].
+///
+/// - `js_string!()` creates an empty string.
+/// - `js_string!("literal")` creates a string from a literal, encoded at compile time.
+/// - `js_string!(s)` converts `s` into a [`JsString`].
+/// - `js_string!(s1, s2, ...)` concatenates the code units of all the arguments.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{js_string, string::utf16, JsString};
+///
+/// let empty = js_string!();
+/// assert!(empty.is_empty());
+///
+/// let hello = js_string!("Hello");
+/// let world = JsString::from("world");
+/// let greeting = js_string!(&hello, utf16!(", "), &world);
+/// assert_eq!(greeting, "Hello, world");
+/// ```
+#[macro_export]
+macro_rules! js_string {
+    () => {
+        $crate::JsString::default()
+    };
+    ($s:literal) => {
+        $crate::JsString::from($crate::utf16!($s))
+    };
+    ($s:expr) => {
+        $crate::JsString::from($s)
+    };
+    ($x:expr, $y:expr) => {
+        $crate::JsString::concat($x, $y)
+    };
+    ($($s:expr),+) => {
+        $crate::JsString::concat_array(&[$(&$s[..]),+])
+    };
+}
+
+#[doc(inline)]
+pub use crate::js_string;
+
+/// Returns the number of UTF-16 code units needed to encode `s`.
+///
+/// Implementation detail of the [`utf16!`] macro.
+#[doc(hidden)]
+pub const fn __utf16_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if byte < 0x80 {
+            i += 1;
+            len += 1;
+        } else if byte < 0xE0 {
+            i += 2;
+            len += 1;
+        } else if byte < 0xF0 {
+            i += 3;
+            len += 1;
+        } else {
+            // Code points outside the BMP are encoded as a surrogate pair.
+            i += 4;
+            len += 2;
+        }
+    }
+    len
+}
+
+/// Encodes `s` as UTF-16, where `N` is the length returned by [`__utf16_len`].
+///
+/// Implementation detail of the [`utf16!`] macro.
+#[doc(hidden)]
+pub const fn __encode_utf16<const N: usize>(s: &str) -> [u16; N] {
+    let bytes = s.as_bytes();
+    let mut out = [0; N];
+    let mut i = 0;
+    let mut j = 0;
+    while i < bytes.len() {
+        let byte = bytes[i] as u32;
+        let code_point = if byte < 0x80 {
+            i += 1;
+            byte
+        } else if byte < 0xE0 {
+            let code_point = ((byte & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F);
+            i += 2;
+            code_point
+        } else if byte < 0xF0 {
+            let code_point = ((byte & 0x0F) << 12)
+                | ((bytes[i + 1] as u32 & 0x3F) << 6)
+                | (bytes[i + 2] as u32 & 0x3F);
+            i += 3;
+            code_point
+        } else {
+            let code_point = ((byte & 0x07) << 18)
+                | ((bytes[i + 1] as u32 & 0x3F) << 12)
+                | ((bytes[i + 2] as u32 & 0x3F) << 6)
+                | (bytes[i + 3] as u32 & 0x3F);
+            i += 4;
+            code_point
+        };
+
+        if code_point < 0x10000 {
+            out[j] = code_point as u16;
+            j += 1;
+        } else {
+            let code_point = code_point - 0x10000;
+            out[j] = 0xD800 + (code_point >> 10) as u16;
+            out[j + 1] = 0xDC00 + (code_point & 0x3FF) as u16;
+            j += 2;
+        }
+    }
+    out
+}
+
+/// Represents a Unicode code point within a [`JsString`], which could be a valid
+/// '[Unicode scalar value]', or an unpaired surrogate.
+///
+/// [Unicode scalar value]: https://www.unicode.org/glossary/#unicode_scalar_value
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CodePoint {
+    /// A valid Unicode scalar value.
+    Unicode(char),
+
+    /// An unpaired surrogate.
+    UnpairedSurrogate(u16),
+}
+
+impl CodePoint {
+    /// Gets the number of UTF-16 code units needed to encode this code point.
+    #[inline]
+    pub fn code_unit_count(self) -> usize {
+        match self {
+            Self::Unicode(c) => c.len_utf16(),
+            Self::UnpairedSurrogate(_) => 1,
+        }
+    }
+
+    /// Converts the code point to its [`u32`] representation.
+    #[inline]
+    pub fn as_u32(self) -> u32 {
+        match self {
+            Self::Unicode(c) => u32::from(c),
+            Self::UnpairedSurrogate(surr) => u32::from(surr),
+        }
+    }
+
+    /// If the code point represents a valid Unicode scalar value, returns its [`char`]
+    /// representation, otherwise returns [`None`] on unpaired surrogates.
+    #[inline]
+    pub fn as_char(self) -> Option<char> {
+        match self {
+            Self::Unicode(c) => Some(c),
+            Self::UnpairedSurrogate(_) => None,
+        }
+    }
+
+    /// Encodes this code point as UTF-16 into the provided u16 buffer, and then returns the
+    /// subslice of the buffer that contains the encoded character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough. A buffer of length 2 is large enough to encode
+    /// any code point.
+    #[inline]
+    pub fn encode_utf16(self, dst: &mut [u16]) -> &mut [u16] {
+        match self {
+            Self::Unicode(c) => c.encode_utf16(dst),
+            Self::UnpairedSurrogate(surr) => {
+                dst[0] = surr;
+                &mut dst[0..=0]
+            }
+        }
+    }
+}
+
+const CONSTANTS_ARRAY: [&[u16]; 419] = [
     // Empty string
-    "",
+    utf16!(""),
     // Misc
-    ",",
-    ":",
+    utf16!(","),
+    utf16!(":"),
     // Generic use
-    "name",
-    "length",
-    "arguments",
-    "prototype",
-    "constructor",
-    "return",
-    "throw",
-    "global",
-    "globalThis",
+    utf16!("name"),
+    utf16!("length"),
+    utf16!("arguments"),
+    utf16!("prototype"),
+    utf16!("constructor"),
+    utf16!("return"),
+    utf16!("throw"),
+    utf16!("global"),
+    utf16!("globalThis"),
     // typeof
-    "null",
-    "undefined",
-    "number",
-    "string",
-    "symbol",
-    "bigint",
-    "object",
-    "function",
+    utf16!("null"),
+    utf16!("undefined"),
+    utf16!("number"),
+    utf16!("string"),
+    utf16!("symbol"),
+    utf16!("bigint"),
+    utf16!("object"),
+    utf16!("function"),
     // Property descriptor
-    "value",
-    "get",
-    "set",
-    "writable",
-    "enumerable",
-    "configurable",
+    utf16!("value"),
+    utf16!("get"),
+    utf16!("set"),
+    utf16!("writable"),
+    utf16!("enumerable"),
+    utf16!("configurable"),
     // Object object
-    "Object",
-    "assign",
-    "create",
-    "toString",
-    "valueOf",
-    "is",
-    "seal",
-    "isSealed",
-    "freeze",
-    "isFrozen",
-    "isExtensible",
-    "hasOwnProperty",
-    "isPrototypeOf",
-    "setPrototypeOf",
-    "getPrototypeOf",
-    "defineProperty",
-    "defineProperties",
-    "deleteProperty",
-    "construct",
-    "hasOwn",
-    "ownKeys",
-    "keys",
-    "values",
-    "entries",
-    "fromEntries",
+    utf16!("Object"),
+    utf16!("assign"),
+    utf16!("create"),
+    utf16!("toString"),
+    utf16!("valueOf"),
+    utf16!("is"),
+    utf16!("seal"),
+    utf16!("isSealed"),
+    utf16!("freeze"),
+    utf16!("isFrozen"),
+    utf16!("isExtensible"),
+    utf16!("hasOwnProperty"),
+    utf16!("isPrototypeOf"),
+    utf16!("setPrototypeOf"),
+    utf16!("getPrototypeOf"),
+    utf16!("defineProperty"),
+    utf16!("defineProperties"),
+    utf16!("deleteProperty"),
+    utf16!("construct"),
+    utf16!("hasOwn"),
+    utf16!("ownKeys"),
+    utf16!("keys"),
+    utf16!("values"),
+    utf16!("entries"),
+    utf16!("fromEntries"),
     // Function object
-    "Function",
-    "apply",
-    "bind",
-    "call",
+    utf16!("Function"),
+    utf16!("apply"),
+    utf16!("bind"),
+    utf16!("call"),
     // Generator object
-    "Generator",
+    utf16!("Generator"),
     // Array object
-    "Array",
-    "at",
-    "from",
-    "isArray",
-    "of",
-    "copyWithin",
-    "entries",
-    "every",
-    "fill",
-    "filter",
-    "find",
-    "findIndex",
-    "findLast",
-    "findLastIndex",
-    "flat",
-    "flatMap",
-    "forEach",
-    "includes",
-    "indexOf",
-    "join",
-    "map",
-    "next",
-    "reduce",
-    "reduceRight",
-    "reverse",
-    "shift",
-    "slice",
-    "splice",
-    "some",
-    "sort",
-    "unshift",
-    "push",
-    "pop",
+    utf16!("Array"),
+    utf16!("at"),
+    utf16!("from"),
+    utf16!("isArray"),
+    utf16!("of"),
+    utf16!("copyWithin"),
+    utf16!("entries"),
+    utf16!("every"),
+    utf16!("fill"),
+    utf16!("filter"),
+    utf16!("find"),
+    utf16!("findIndex"),
+    utf16!("findLast"),
+    utf16!("findLastIndex"),
+    utf16!("flat"),
+    utf16!("flatMap"),
+    utf16!("forEach"),
+    utf16!("includes"),
+    utf16!("indexOf"),
+    utf16!("join"),
+    utf16!("map"),
+    utf16!("next"),
+    utf16!("reduce"),
+    utf16!("reduceRight"),
+    utf16!("reverse"),
+    utf16!("shift"),
+    utf16!("slice"),
+    utf16!("splice"),
+    utf16!("some"),
+    utf16!("sort"),
+    utf16!("unshift"),
+    utf16!("push"),
+    utf16!("pop"),
     // String object
-    "String",
-    "charAt",
-    "charCodeAt",
-    "codePointAt",
-    "concat",
-    "endsWith",
-    "fromCharCode",
-    "fromCodePoint",
-    "includes",
-    "indexOf",
-    "lastIndexOf",
-    "match",
-    "matchAll",
-    "normalize",
-    "padEnd",
-    "padStart",
-    "raw",
-    "repeat",
-    "replace",
-    "replaceAll",
-    "search",
-    "slice",
-    "split",
-    "startsWith",
-    "substr",
-    "substring",
-    "toLocaleString",
-    "toLowerCase",
-    "toUpperCase",
-    "trim",
-    "trimEnd",
-    "trimStart",
+    utf16!("String"),
+    utf16!("charAt"),
+    utf16!("charCodeAt"),
+    utf16!("codePointAt"),
+    utf16!("concat"),
+    utf16!("endsWith"),
+    utf16!("fromCharCode"),
+    utf16!("fromCodePoint"),
+    utf16!("includes"),
+    utf16!("indexOf"),
+    utf16!("lastIndexOf"),
+    utf16!("match"),
+    utf16!("matchAll"),
+    utf16!("normalize"),
+    utf16!("padEnd"),
+    utf16!("padStart"),
+    utf16!("raw"),
+    utf16!("repeat"),
+    utf16!("replace"),
+    utf16!("replaceAll"),
+    utf16!("search"),
+    utf16!("slice"),
+    utf16!("split"),
+    utf16!("startsWith"),
+    utf16!("substr"),
+    utf16!("substring"),
+    utf16!("toLocaleString"),
+    utf16!("toLowerCase"),
+    utf16!("toUpperCase"),
+    utf16!("trim"),
+    utf16!("trimEnd"),
+    utf16!("trimStart"),
     // Number object
-    "Number",
-    "Infinity",
-    "NaN",
-    "parseInt",
-    "parseFloat",
-    "isFinite",
-    "isNaN",
-    "parseInt",
-    "EPSILON",
-    "MAX_SAFE_INTEGER",
-    "MIN_SAFE_INTEGER",
-    "MAX_VALUE",
-    "MIN_VALUE",
-    "isSafeInteger",
-    "isInteger",
-    "toExponential",
-    "toFixed",
-    "toPrecision",
+    utf16!("Number"),
+    utf16!("Infinity"),
+    utf16!("NaN"),
+    utf16!("parseInt"),
+    utf16!("parseFloat"),
+    utf16!("isFinite"),
+    utf16!("isNaN"),
+    utf16!("parseInt"),
+    utf16!("EPSILON"),
+    utf16!("MAX_SAFE_INTEGER"),
+    utf16!("MIN_SAFE_INTEGER"),
+    utf16!("MAX_VALUE"),
+    utf16!("MIN_VALUE"),
+    utf16!("isSafeInteger"),
+    utf16!("isInteger"),
+    utf16!("toExponential"),
+    utf16!("toFixed"),
+    utf16!("toPrecision"),
     // Boolean object
-    "Boolean",
+    utf16!("Boolean"),
     // BigInt object
-    "BigInt",
-    "asIntN",
-    "asUintN",
+    utf16!("BigInt"),
+    utf16!("asIntN"),
+    utf16!("asUintN"),
     // RegExp object
-    "RegExp",
-    "exec",
-    "test",
-    "flags",
-    "index",
-    "lastIndex",
-    "hasIndices",
-    "ignoreCase",
-    "multiline",
-    "dotAll",
-    "unicode",
-    "sticky",
-    "source",
-    "get hasIndices",
-    "get global",
-    "get ignoreCase",
-    "get multiline",
-    "get dotAll",
-    "get unicode",
-    "get sticky",
-    "get flags",
-    "get source",
+    utf16!("RegExp"),
+    utf16!("exec"),
+    utf16!("test"),
+    utf16!("flags"),
+    utf16!("index"),
+    utf16!("lastIndex"),
+    utf16!("hasIndices"),
+    utf16!("ignoreCase"),
+    utf16!("multiline"),
+    utf16!("dotAll"),
+    utf16!("unicode"),
+    utf16!("sticky"),
+    utf16!("source"),
+    utf16!("get hasIndices"),
+    utf16!("get global"),
+    utf16!("get ignoreCase"),
+    utf16!("get multiline"),
+    utf16!("get dotAll"),
+    utf16!("get unicode"),
+    utf16!("get sticky"),
+    utf16!("get flags"),
+    utf16!("get source"),
     // Symbol object
-    "Symbol",
-    "for",
-    "keyFor",
-    "description",
-    "asyncIterator",
-    "hasInstance",
-    "species",
-    "Symbol.species",
-    "unscopables",
-    "iterator",
-    "Symbol.iterator",
-    "Symbol.match",
-    "[Symbol.match]",
-    "Symbol.matchAll",
-    "Symbol.replace",
-    "[Symbol.replace]",
-    "Symbol.search",
-    "[Symbol.search]",
-    "Symbol.split",
-    "[Symbol.split]",
-    "toStringTag",
-    "toPrimitive",
-    "get description",
+    utf16!("Symbol"),
+    utf16!("for"),
+    utf16!("keyFor"),
+    utf16!("description"),
+    utf16!("asyncIterator"),
+    utf16!("hasInstance"),
+    utf16!("species"),
+    utf16!("Symbol.species"),
+    utf16!("unscopables"),
+    utf16!("iterator"),
+    utf16!("Symbol.iterator"),
+    utf16!("Symbol.match"),
+    utf16!("[Symbol.match]"),
+    utf16!("Symbol.matchAll"),
+    utf16!("Symbol.replace"),
+    utf16!("[Symbol.replace]"),
+    utf16!("Symbol.search"),
+    utf16!("[Symbol.search]"),
+    utf16!("Symbol.split"),
+    utf16!("[Symbol.split]"),
+    utf16!("toStringTag"),
+    utf16!("toPrimitive"),
+    utf16!("get description"),
     // Map object
-    "Map",
-    "clear",
-    "delete",
-    "get",
-    "has",
-    "set",
-    "size",
+    utf16!("Map"),
+    utf16!("clear"),
+    utf16!("delete"),
+    utf16!("get"),
+    utf16!("has"),
+    utf16!("set"),
+    utf16!("size"),
     // Set object
-    "Set",
-    "add",
+    utf16!("Set"),
+    utf16!("add"),
     // Reflect object
-    "Reflect",
+    utf16!("Reflect"),
     // Proxy object
-    "Proxy",
-    "revocable",
+    utf16!("Proxy"),
+    utf16!("revocable"),
     // Error objects
-    "Error",
-    "AggregateError",
-    "TypeError",
-    "RangeError",
-    "SyntaxError",
-    "ReferenceError",
-    "EvalError",
-    "ThrowTypeError",
-    "URIError",
-    "message",
+    utf16!("Error"),
+    utf16!("AggregateError"),
+    utf16!("TypeError"),
+    utf16!("RangeError"),
+    utf16!("SyntaxError"),
+    utf16!("ReferenceError"),
+    utf16!("EvalError"),
+    utf16!("ThrowTypeError"),
+    utf16!("URIError"),
+    utf16!("message"),
     // Date object
-    "Date",
-    "toJSON",
-    "getDate",
-    "getDay",
-    "getFullYear",
-    "getHours",
-    "getMilliseconds",
-    "getMinutes",
-    "getMonth",
-    "getSeconds",
-    "getTime",
-    "getYear",
-    "getUTCDate",
-    "getUTCDay",
-    "getUTCFullYear",
-    "getUTCHours",
-    "getUTCMinutes",
-    "getUTCMonth",
-    "getUTCSeconds",
-    "setDate",
-    "setFullYear",
-    "setHours",
-    "setMilliseconds",
-    "setMinutes",
-    "setMonth",
-    "setSeconds",
-    "setYear",
-    "setTime",
-    "setUTCDate",
-    "setUTCFullYear",
-    "setUTCHours",
-    "setUTCMinutes",
-    "setUTCMonth",
-    "setUTCSeconds",
-    "toDateString",
-    "toGMTString",
-    "toISOString",
-    "toTimeString",
-    "toUTCString",
-    "now",
-    "UTC",
+    utf16!("Date"),
+    utf16!("toJSON"),
+    utf16!("getDate"),
+    utf16!("getDay"),
+    utf16!("getFullYear"),
+    utf16!("getHours"),
+    utf16!("getMilliseconds"),
+    utf16!("getMinutes"),
+    utf16!("getMonth"),
+    utf16!("getSeconds"),
+    utf16!("getTime"),
+    utf16!("getYear"),
+    utf16!("getUTCDate"),
+    utf16!("getUTCDay"),
+    utf16!("getUTCFullYear"),
+    utf16!("getUTCHours"),
+    utf16!("getUTCMinutes"),
+    utf16!("getUTCMonth"),
+    utf16!("getUTCSeconds"),
+    utf16!("setDate"),
+    utf16!("setFullYear"),
+    utf16!("setHours"),
+    utf16!("setMilliseconds"),
+    utf16!("setMinutes"),
+    utf16!("setMonth"),
+    utf16!("setSeconds"),
+    utf16!("setYear"),
+    utf16!("setTime"),
+    utf16!("setUTCDate"),
+    utf16!("setUTCFullYear"),
+    utf16!("setUTCHours"),
+    utf16!("setUTCMinutes"),
+    utf16!("setUTCMonth"),
+    utf16!("setUTCSeconds"),
+    utf16!("toDateString"),
+    utf16!("toGMTString"),
+    utf16!("toISOString"),
+    utf16!("toTimeString"),
+    utf16!("toUTCString"),
+    utf16!("now"),
+    utf16!("UTC"),
     // JSON object
-    "JSON",
-    "parse",
-    "stringify",
+    utf16!("JSON"),
+    utf16!("parse"),
+    utf16!("stringify"),
     // Iterator object
-    "Array Iterator",
-    "Set Iterator",
-    "String Iterator",
-    "Map Iterator",
-    "For In Iterator",
+    utf16!("Array Iterator"),
+    utf16!("Set Iterator"),
+    utf16!("String Iterator"),
+    utf16!("Map Iterator"),
+    utf16!("For In Iterator"),
     // Math object
-    "Math",
-    "LN10",
-    "LN2",
-    "LOG10E",
-    "LOG2E",
-    "PI",
-    "SQRT1_2",
-    "SQRT2",
-    "abs",
-    "acos",
-    "acosh",
-    "asin",
-    "asinh",
-    "atan",
-    "atanh",
-    "atan2",
-    "cbrt",
-    "ceil",
-    "clz32",
-    "cos",
-    "cosh",
-    "exp",
-    "expm1",
-    "floor",
-    "fround",
-    "hypot",
-    "imul",
-    "log",
-    "log1p",
-    "log10",
-    "log2",
-    "max",
-    "min",
-    "pow",
-    "random",
-    "round",
-    "sign",
-    "sin",
-    "sinh",
-    "sqrt",
-    "tan",
-    "tanh",
-    "trunc",
+    utf16!("Math"),
+    utf16!("LN10"),
+    utf16!("LN2"),
+    utf16!("LOG10E"),
+    utf16!("LOG2E"),
+    utf16!("PI"),
+    utf16!("SQRT1_2"),
+    utf16!("SQRT2"),
+    utf16!("abs"),
+    utf16!("acos"),
+    utf16!("acosh"),
+    utf16!("asin"),
+    utf16!("asinh"),
+    utf16!("atan"),
+    utf16!("atanh"),
+    utf16!("atan2"),
+    utf16!("cbrt"),
+    utf16!("ceil"),
+    utf16!("clz32"),
+    utf16!("cos"),
+    utf16!("cosh"),
+    utf16!("exp"),
+    utf16!("expm1"),
+    utf16!("floor"),
+    utf16!("fround"),
+    utf16!("hypot"),
+    utf16!("imul"),
+    utf16!("log"),
+    utf16!("log1p"),
+    utf16!("log10"),
+    utf16!("log2"),
+    utf16!("max"),
+    utf16!("min"),
+    utf16!("pow"),
+    utf16!("random"),
+    utf16!("round"),
+    utf16!("sign"),
+    utf16!("sin"),
+    utf16!("sinh"),
+    utf16!("sqrt"),
+    utf16!("tan"),
+    utf16!("tanh"),
+    utf16!("trunc"),
     // Intl object
-    "Intl",
-    "DateTimeFormat",
+    utf16!("Intl"),
+    utf16!("DateTimeFormat"),
     // TypedArray object
-    "TypedArray",
-    "ArrayBuffer",
-    "Int8Array",
-    "Uint8Array",
-    "Int16Array",
-    "Uint16Array",
-    "Int32Array",
-    "Uint32Array",
-    "BigInt64Array",
-    "BigUint64Array",
-    "Float32Array",
-    "Float64Array",
-    "buffer",
-    "byteLength",
-    "byteOffset",
-    "isView",
-    "subarray",
-    "get byteLength",
-    "get buffer",
-    "get byteOffset",
-    "get size",
-    "get length",
+    utf16!("TypedArray"),
+    utf16!("ArrayBuffer"),
+    utf16!("Int8Array"),
+    utf16!("Uint8Array"),
+    utf16!("Int16Array"),
+    utf16!("Uint16Array"),
+    utf16!("Int32Array"),
+    utf16!("Uint32Array"),
+    utf16!("BigInt64Array"),
+    utf16!("BigUint64Array"),
+    utf16!("Float32Array"),
+    utf16!("Float64Array"),
+    utf16!("buffer"),
+    utf16!("byteLength"),
+    utf16!("byteOffset"),
+    utf16!("isView"),
+    utf16!("subarray"),
+    utf16!("get byteLength"),
+    utf16!("get buffer"),
+    utf16!("get byteOffset"),
+    utf16!("get size"),
+    utf16!("get length"),
     // DataView object
-    "DataView",
-    "getBigInt64",
-    "getBigUint64",
-    "getFloat32",
-    "getFloat64",
-    "getInt8",
-    "getInt16",
-    "getInt32",
-    "getUint8",
-    "getUint16",
-    "getUint32",
-    "setBigInt64",
-    "setBigUint64",
-    "setFloat32",
-    "setFloat64",
-    "setInt8",
-    "setInt16",
-    "setInt32",
-    "setUint8",
-    "setUint16",
-    "setUint32",
+    utf16!("DataView"),
+    utf16!("getBigInt64"),
+    utf16!("getBigUint64"),
+    utf16!("getFloat32"),
+    utf16!("getFloat64"),
+    utf16!("getInt8"),
+    utf16!("getInt16"),
+    utf16!("getInt32"),
+    utf16!("getUint8"),
+    utf16!("getUint16"),
+    utf16!("getUint32"),
+    utf16!("setBigInt64"),
+    utf16!("setBigUint64"),
+    utf16!("setFloat32"),
+    utf16!("setFloat64"),
+    utf16!("setInt8"),
+    utf16!("setInt16"),
+    utf16!("setInt32"),
+    utf16!("setUint8"),
+    utf16!("setUint16"),
+    utf16!("setUint32"),
     // Console object
-    "console",
-    "assert",
-    "debug",
-    "error",
-    "info",
-    "trace",
-    "warn",
-    "exception",
-    "count",
-    "countReset",
-    "group",
-    "groupCollapsed",
-    "groupEnd",
-    "time",
-    "timeLog",
-    "timeEnd",
-    "dir",
-    "dirxml",
+    utf16!("console"),
+    utf16!("assert"),
+    utf16!("debug"),
+    utf16!("error"),
+    utf16!("info"),
+    utf16!("trace"),
+    utf16!("warn"),
+    utf16!("exception"),
+    utf16!("count"),
+    utf16!("countReset"),
+    utf16!("group"),
+    utf16!("groupCollapsed"),
+    utf16!("groupEnd"),
+    utf16!("time"),
+    utf16!("timeLog"),
+    utf16!("timeEnd"),
+    utf16!("dir"),
+    utf16!("dirxml"),
     // Minified name
-    "a",
-    "b",
-    "c",
-    "d",
-    "e",
-    "f",
-    "g",
-    "h",
-    "i",
-    "j",
-    "k",
-    "l",
-    "m",
-    "n",
-    "o",
-    "p",
-    "q",
-    "r",
-    "s",
-    "t",
-    "u",
-    "v",
-    "w",
-    "x",
-    "y",
-    "z",
-    "A",
-    "B",
-    "C",
-    "D",
-    "E",
-    "F",
-    "G",
-    "H",
-    "I",
-    "J",
-    "K",
-    "L",
-    "M",
-    "N",
-    "O",
-    "P",
-    "Q",
-    "R",
-    "S",
-    "T",
-    "U",
-    "V",
-    "W",
-    "X",
-    "Y",
-    "Z",
-    "_",
-    "$",
+    utf16!("a"),
+    utf16!("b"),
+    utf16!("c"),
+    utf16!("d"),
+    utf16!("e"),
+    utf16!("f"),
+    utf16!("g"),
+    utf16!("h"),
+    utf16!("i"),
+    utf16!("j"),
+    utf16!("k"),
+    utf16!("l"),
+    utf16!("m"),
+    utf16!("n"),
+    utf16!("o"),
+    utf16!("p"),
+    utf16!("q"),
+    utf16!("r"),
+    utf16!("s"),
+    utf16!("t"),
+    utf16!("u"),
+    utf16!("v"),
+    utf16!("w"),
+    utf16!("x"),
+    utf16!("y"),
+    utf16!("z"),
+    utf16!("A"),
+    utf16!("B"),
+    utf16!("C"),
+    utf16!("D"),
+    utf16!("E"),
+    utf16!("F"),
+    utf16!("G"),
+    utf16!("H"),
+    utf16!("I"),
+    utf16!("J"),
+    utf16!("K"),
+    utf16!("L"),
+    utf16!("M"),
+    utf16!("N"),
+    utf16!("O"),
+    utf16!("P"),
+    utf16!("Q"),
+    utf16!("R"),
+    utf16!("S"),
+    utf16!("T"),
+    utf16!("U"),
+    utf16!("V"),
+    utf16!("W"),
+    utf16!("X"),
+    utf16!("Y"),
+    utf16!("Z"),
+    utf16!("_"),
+    utf16!("$"),
 ];
 
 const MAX_CONSTANT_STRING_LENGTH: usize = {
@@ -486,7 +705,7 @@ unsafe fn try_alloc(layout: Layout) -> *mut u8 {
 }
 
 thread_local! {
-    static CONSTANTS: FxHashMap<&'static str, JsString> = {
+    static CONSTANTS: FxHashMap<&'static [u16], JsString> = {
         let mut constants = FxHashMap::with_capacity_and_hasher(
             CONSTANTS_ARRAY.len(),
             BuildHasherDefault::<FxHasher>::default(),
@@ -505,7 +724,7 @@ thread_local! {
 /// The inner representation of a [`JsString`].
 #[repr(C)]
 struct Inner {
-    /// The utf8 length, the number of bytes.
+    /// The number of UTF-16 code units of the string.
     len: usize,
 
     /// The number of references to the string.
@@ -513,88 +732,97 @@ struct Inner {
     /// When this reaches `0` the string is deallocated.
     refcount: Cell<usize>,
 
+    /// The account of the context that the memory of the string is charged to.
+    account: Option<Rc<HeapAccount>>,
+
     /// An empty array which is used to get the offset of string data.
-    data: [u8; 0],
+    data: [u16; 0],
 }
 
 impl Inner {
-    /// Create a new `Inner` from `&str`.
+    /// Allocates a new `Inner` for `len` code units, and calls `write` with a pointer to its
+    /// uninitialized string data.
+    ///
+    /// # Safety
+    ///
+    /// `write` must initialize all the `len` code units of the string data.
     #[inline]
-    fn new(s: &str) -> NonNull<Self> {
+    unsafe fn allocate<F>(len: usize, write: F) -> NonNull<Self>
+    where
+        F: FnOnce(*mut u16),
+    {
         // We get the layout of the `Inner` type and we extend by the size
         // of the string array.
         let inner_layout = Layout::new::<Self>();
         let (layout, offset) = inner_layout
-            .extend(Layout::array::<u8>(s.len()).expect("failed to create memory layout"))
+            .extend(Layout::array::<u16>(len).expect("failed to create memory layout"))
             .expect("failed to extend memory layout");
 
-        let inner = unsafe {
-            let inner = try_alloc(layout).cast::<Self>();
+        let inner = try_alloc(layout).cast::<Self>();
 
-            // Write the first part, the Inner.
-            inner.write(Self {
-                len: s.len(),
-                refcount: Cell::new(1),
-                data: [0; 0],
-            });
+        // Write the first part, the Inner.
+        inner.write(Self {
+            len,
+            refcount: Cell::new(1),
+            account: HeapAccount::charge(layout.size()),
+            data: [0; 0],
+        });
 
-            // Get offset into the string data.
-            let data = (*inner).data.as_mut_ptr();
+        // Get offset into the string data.
+        let data = (*inner).data.as_mut_ptr();
 
-            debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset), data));
+        debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset).cast(), data));
 
-            // Copy string data into data offset.
-            copy_nonoverlapping(s.as_ptr(), data, s.len());
-
-            inner
-        };
+        write(data);
 
         // Safety: We already know it's not null, so this is safe.
-        unsafe { NonNull::new_unchecked(inner) }
+        NonNull::new_unchecked(inner)
     }
 
-    /// Concatenate array of strings.
+    /// Concatenate an array of code unit slices into a new `Inner`.
     #[inline]
-    fn concat_array(strings: &[&str]) -> NonNull<Self> {
+    fn concat_array(strings: &[&[u16]]) -> NonNull<Self> {
         let mut total_string_size = 0;
         for string in strings {
             total_string_size += string.len();
         }
 
-        // We get the layout of the `Inner` type and we extend by the size
-        // of the string array.
-        let inner_layout = Layout::new::<Self>();
-        let (layout, offset) = inner_layout
-            .extend(Layout::array::<u8>(total_string_size).expect("failed to create memory layout"))
-            .expect("failed to extend memory layout");
-
-        let inner = unsafe {
-            let inner = try_alloc(layout).cast::<Self>();
-
-            // Write the first part, the Inner.
-            inner.write(Self {
-                len: total_string_size,
-                refcount: Cell::new(1),
-                data: [0; 0],
-            });
-
-            // Get offset into the string data.
-            let data = (*inner).data.as_mut_ptr();
-
-            debug_assert!(std::ptr::eq(inner.cast::<u8>().add(offset), data));
-
-            // Copy the two string data into data offset.
-            let mut offset = 0;
-            for string in strings {
-                copy_nonoverlapping(string.as_ptr(), data.add(offset), string.len());
-                offset += string.len();
-            }
-
-            inner
-        };
+        // Safety: The slices have `total_string_size` code units in total, which are all copied.
+        unsafe {
+            Self::allocate(total_string_size, |data| {
+                // Copy the string data into data offset.
+                let mut offset = 0;
+                for string in strings {
+                    copy_nonoverlapping(string.as_ptr(), data.add(offset), string.len());
+                    offset += string.len();
+                }
+            })
+        }
+    }
 
-        // Safety: We already know it's not null, so this is safe.
-        unsafe { NonNull::new_unchecked(inner) }
+    /// Creates a new `Inner` from an iterator over its `len` code units, without collecting
+    /// them first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator does not yield exactly `len` code units.
+    #[inline]
+    fn from_code_units<I>(len: usize, code_units: I) -> NonNull<Self>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        // Safety: Every code unit is written, since the iterator must yield exactly `len` of them.
+        unsafe {
+            Self::allocate(len, |data| {
+                let mut written = 0;
+                for code_unit in code_units {
+                    assert!(written < len, "the iterator yielded too many code units");
+                    data.add(written).write(code_unit);
+                    written += 1;
+                }
+                assert_eq!(written, len, "the iterator yielded too few code units");
+            })
+        }
     }
 
     /// Deallocate inner type with string data.
@@ -604,35 +832,40 @@ impl Inner {
 
         let inner_layout = Layout::new::<Self>();
         let (layout, _offset) = inner_layout
-            .extend(Layout::array::<u8>(len).expect("failed to create memory layout"))
+            .extend(Layout::array::<u16>(len).expect("failed to create memory layout"))
             .expect("failed to extend memory layout");
 
+        if let Some(account) = (*x.as_ptr()).account.take() {
+            account.refund(layout.size());
+        }
         dealloc(x.as_ptr().cast::<_>(), layout);
     }
 
     #[inline]
-    fn as_str(&self) -> &str {
-        unsafe {
-            let slice = std::slice::from_raw_parts(self.data.as_ptr(), self.len);
-            std::str::from_utf8_unchecked(slice)
-        }
+    fn as_slice(&self) -> &[u16] {
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr(), self.len) }
     }
 }
 
 /// This represents a JavaScript primitive string.
 ///
-/// This is similar to `Rc<str>`. But unlike `Rc<str>` which stores the length
+/// This is similar to `Rc<[u16]>`. But unlike `Rc<[u16]>` which stores the length
 /// on the stack and a pointer to the data (this is also known as fat pointers).
 /// The `JsString` length and data is stored on the heap. and just an non-null
 /// pointer is kept, so its size is the size of a pointer.
 ///
+/// The string is stored as a sequence of UTF-16 code units, which is what JavaScript
+/// considers a string to be, so it derefs to a `[u16]`. Use [`JsString::to_std_string`]
+/// or [`JsString::to_std_string_escaped`] to convert it to a Rust [`String`].
+///
 /// We define some commonly used string constants in an interner. For these
 /// strings, we no longer allocate memory on the heap to reduce the overhead of
 /// memory allocation and reference counting.
 #[derive(Finalize)]
+#[repr(transparent)]
 pub struct JsString {
     inner: TaggedInner,
-    _marker: PhantomData<Rc<str>>,
+    _marker: PhantomData<Rc<[u16]>>,
 }
 
 // Safety: JsString does not contain any objects which needs to be traced,
@@ -655,6 +888,9 @@ unsafe impl Trace for JsString {
 /// It uses [`NonNull`], which guarantees that `TaggedInner` (and subsequently [`JsString`])
 /// can use the "null pointer optimization" to optimize the size of [`Option<TaggedInner>`].
 ///
+/// The upper bits of the pointer also hold the tag of strings in a [`JsValue`][crate::JsValue],
+/// so the word of a value holding a string is exactly its `TaggedInner`.
+///
 /// # Provenance
 ///
 /// This struct stores a [`NonNull<Inner>`] instead of a [`NonZeroUsize`][std::num::NonZeroUsize]
@@ -670,7 +906,11 @@ struct TaggedInner(NonNull<Inner>);
 impl TaggedInner {
     #[inline]
     unsafe fn new_heap(inner: NonNull<Inner>) -> Self {
-        Self(inner)
+        let address = inner.as_ptr() as usize;
+        assert_address_fits(address);
+        Self(NonNull::new_unchecked(
+            tag_address(STRING_TAG, address) as *mut _
+        ))
     }
 
     /// Create a new static `TaggedInner` from the index of an element inside
@@ -678,7 +918,9 @@ impl TaggedInner {
     #[inline]
     const unsafe fn new_static(idx: usize) -> Self {
         // Safety: We already know it's not null, so this is safe.
-        Self(NonNull::new_unchecked(((idx << 1) | 1) as *mut _))
+        Self(NonNull::new_unchecked(
+            tag_address(STRING_TAG, (idx << 1) | 1) as *mut _,
+        ))
     }
 
     /// Check if `TaggedInner` contains an index for [`CONSTANTS_ARRAY`].
@@ -694,8 +936,8 @@ impl TaggedInner {
     ///
     /// Calling this method with a static `TaggedInner` results in Undefined Behaviour.
     #[inline]
-    const unsafe fn get_heap_unchecked(self) -> NonNull<Inner> {
-        self.0
+    unsafe fn get_heap_unchecked(self) -> NonNull<Inner> {
+        NonNull::new_unchecked(untag_address(self.0.as_ptr() as usize) as *mut _)
     }
 
     /// Returns the string inside [`CONSTANTS_ARRAY`] corresponding to the
@@ -707,9 +949,9 @@ impl TaggedInner {
     /// for [`CONSTANTS_ARRAY`] or a valid pointer to a heap allocated [`Inner`]
     /// results in Undefined Behaviour.
     #[inline]
-    unsafe fn get_static_unchecked(self) -> &'static str {
+    unsafe fn get_static_unchecked(self) -> &'static [u16] {
         // shift right to get the index.
-        CONSTANTS_ARRAY.get_unchecked((self.0.as_ptr() as usize) >> 1)
+        CONSTANTS_ARRAY.get_unchecked(untag_address(self.0.as_ptr() as usize) >> 1)
     }
 }
 
@@ -722,12 +964,12 @@ impl Default for JsString {
 }
 
 /// Enum representing either a reference to a heap allocated [`Inner`]
-/// or a static reference to a [`str`] inside [`CONSTANTS_ARRAY`].
+/// or a static reference to a `[u16]` inside [`CONSTANTS_ARRAY`].
 enum InnerKind<'a> {
     // A string allocated on the heap.
     Heap(&'a Inner),
     // A static string slice.
-    Static(&'static str),
+    Static(&'static [u16]),
 }
 
 impl JsString {
@@ -746,69 +988,84 @@ impl JsString {
         Self::default()
     }
 
-    /// Create a new JavaScript string.
+    /// Create a new JavaScript string from a Rust string.
     #[inline]
     pub fn new<S: AsRef<str>>(s: S) -> Self {
         let s = s.as_ref();
 
-        if s.len() <= MAX_CONSTANT_STRING_LENGTH {
-            if let Some(constant) = CONSTANTS.with(|c| c.get(s).cloned()) {
-                return constant;
-            }
+        // Fast path for ASCII strings: every byte is a code unit, so the string is transcoded
+        // without decoding UTF-8.
+        if s.is_ascii() {
+            return Self::from_code_units(s.len(), s.bytes().map(u16::from));
         }
 
-        Self {
-            // Safety: We already know it's a valid heap pointer.
-            inner: unsafe { TaggedInner::new_heap(Inner::new(s)) },
-            _marker: PhantomData,
-        }
+        Self::from_code_units(s.encode_utf16().count(), s.encode_utf16())
     }
 
-    /// Concatenate two string.
-    pub fn concat<T, U>(x: T, y: U) -> Self
+    /// Create a new JavaScript string from an iterator over its `len` UTF-16 code units.
+    fn from_code_units<I>(len: usize, code_units: I) -> Self
     where
-        T: AsRef<str>,
-        U: AsRef<str>,
+        I: IntoIterator<Item = u16>,
     {
-        let x = x.as_ref();
-        let y = y.as_ref();
-
-        let inner = Inner::concat_array(&[x, y]);
-        let s = unsafe { inner.as_ref() }.as_str();
-
-        if s.len() <= MAX_CONSTANT_STRING_LENGTH {
-            if let Some(constant) = CONSTANTS.with(|c| c.get(s).cloned()) {
-                unsafe { Inner::dealloc(inner) };
-                return constant;
+        // Strings that could be constants are looked up in a copy on the stack.
+        if len <= MAX_CONSTANT_STRING_LENGTH {
+            let mut buffer = [0; MAX_CONSTANT_STRING_LENGTH];
+            for (slot, code_unit) in buffer.iter_mut().zip(code_units) {
+                *slot = code_unit;
             }
+            return Self::from_slice(&buffer[..len]);
         }
 
         Self {
             // Safety: We already know it's a valid heap pointer.
-            inner: unsafe { TaggedInner::new_heap(inner) },
+            inner: unsafe { TaggedInner::new_heap(Inner::from_code_units(len, code_units)) },
             _marker: PhantomData,
         }
     }
 
-    /// Concatenate array of string.
-    pub fn concat_array(strings: &[&str]) -> Self {
-        let inner = Inner::concat_array(strings);
-        let s = unsafe { inner.as_ref() }.as_str();
+    /// Create a new JavaScript string from a slice of UTF-16 code units.
+    #[inline]
+    fn from_slice(s: &[u16]) -> Self {
+        Self::concat_array(&[s])
+    }
+
+    /// Concatenate two string.
+    pub fn concat(x: &[u16], y: &[u16]) -> Self {
+        Self::concat_array(&[x, y])
+    }
 
-        if s.len() <= MAX_CONSTANT_STRING_LENGTH {
-            if let Some(constant) = CONSTANTS.with(|c| c.get(s).cloned()) {
-                unsafe { Inner::dealloc(inner) };
+    /// Concatenate array of string.
+    pub fn concat_array(strings: &[&[u16]]) -> Self {
+        let len: usize = strings.iter().map(|s| s.len()).sum();
+        if len <= MAX_CONSTANT_STRING_LENGTH {
+            let constant = CONSTANTS.with(|c| {
+                if let [s] = strings {
+                    c.get(s).cloned()
+                } else {
+                    let s: Vec<u16> = strings.concat();
+                    c.get(&s[..]).cloned()
+                }
+            });
+            if let Some(constant) = constant {
                 return constant;
             }
         }
 
         Self {
             // Safety: We already know it's a valid heap pointer.
-            inner: unsafe { TaggedInner::new_heap(inner) },
+            inner: unsafe { TaggedInner::new_heap(Inner::concat_array(strings)) },
             _marker: PhantomData,
         }
     }
 
+    /// Consumes the string, returning its tagged pointer as an address.
+    #[inline]
+    pub(crate) fn into_raw(self) -> usize {
+        let raw = self.inner.0.as_ptr() as usize;
+        std::mem::forget(self);
+        raw
+    }
+
     /// Return the inner representation.
     #[inline]
     fn inner(&self) -> InnerKind<'_> {
@@ -822,15 +1079,60 @@ impl JsString {
         }
     }
 
-    /// Return the JavaScript string as a rust `&str`.
+    /// Obtains the underlying `[u16]` slice of the string.
     #[inline]
-    pub fn as_str(&self) -> &str {
+    pub fn as_slice(&self) -> &[u16] {
         match self.inner() {
-            InnerKind::Heap(inner) => inner.as_str(),
+            InnerKind::Heap(inner) => inner.as_slice(),
             InnerKind::Static(inner) => inner,
         }
     }
 
+    /// Decodes a [`JsString`] into a [`String`], returning an error if the string contains
+    /// unpaired surrogates, which cannot be represented in a Rust string.
+    #[inline]
+    pub fn to_std_string(&self) -> Result<String, FromUtf16Error> {
+        let slice = self.as_slice();
+
+        // Fast path for ASCII strings: every code unit is a byte of the UTF-8 encoding.
+        if slice.iter().all(|&c| c < 0x80) {
+            return Ok(slice.iter().map(|&c| c as u8 as char).collect());
+        }
+
+        String::from_utf16(slice)
+    }
+
+    /// Decodes a [`JsString`] into a [`String`], replacing unpaired surrogates with their
+    /// escape sequence, e.g. `\uD800`.
+    ///
+    /// This conversion never fails, but it is lossy: `"\uD800"` and `"\\uD800"` are decoded
+    /// to the same [`String`]. Prefer [`JsString::to_std_string`] when that matters.
+    pub fn to_std_string_escaped(&self) -> String {
+        self.code_points()
+            .map(|cp| match cp {
+                CodePoint::Unicode(c) => c.to_string(),
+                CodePoint::UnpairedSurrogate(surr) => format!("\\u{surr:04X}"),
+            })
+            .collect()
+    }
+
+    /// Decodes a [`JsString`] into a [`String`], replacing unpaired surrogates with the
+    /// replacement character (U+FFFD).
+    #[inline]
+    pub fn to_std_string_lossy(&self) -> String {
+        String::from_utf16_lossy(self.as_slice())
+    }
+
+    /// Gets an iterator of all the Unicode code points of a [`JsString`], yielding unpaired
+    /// surrogates as [`CodePoint::UnpairedSurrogate`].
+    #[inline]
+    pub fn code_points(&self) -> impl Iterator<Item = CodePoint> + Clone + '_ {
+        char::decode_utf16(self.iter().copied()).map(|res| match res {
+            Ok(c) => CodePoint::Unicode(c),
+            Err(e) => CodePoint::UnpairedSurrogate(e.unpaired_surrogate()),
+        })
+    }
+
     /// Gets the number of `JsString`s which point to this allocation.
     #[inline]
     pub fn refcount(this: &Self) -> Option<usize> {
@@ -857,51 +1159,142 @@ impl JsString {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-stringindexof
-    pub(crate) fn index_of(&self, search_value: &Self, from_index: usize) -> Option<usize> {
+    pub(crate) fn index_of(&self, search_value: &[u16], from_index: usize) -> Option<usize> {
         // 1. Assert: Type(string) is String.
         // 2. Assert: Type(searchValue) is String.
         // 3. Assert: fromIndex is a non-negative integer.
 
         // 4. Let len be the length of string.
-        let len = self.encode_utf16().count();
+        let len = self.len();
 
         // 5. If searchValue is the empty String and fromIndex ≤ len, return fromIndex.
-        if search_value.is_empty() && from_index <= len {
-            return Some(from_index);
+        if search_value.is_empty() {
+            return if from_index <= len {
+                Some(from_index)
+            } else {
+                None
+            };
         }
 
         // 6. Let searchLen be the length of searchValue.
-        let search_len = search_value.encode_utf16().count();
-
         // 7. For each integer i starting with fromIndex such that i ≤ len - searchLen, in ascending order, do
-        for i in from_index..=len {
-            if i as isize > (len as isize - search_len as isize) {
-                break;
-            }
+        //     a. Let candidate be the substring of string from i to i + searchLen.
+        //     b. If candidate is the same sequence of code units as searchValue, return i.
+        // 8. Return -1.
+        self.windows(search_value.len())
+            .skip(from_index)
+            .position(|s| s == search_value)
+            .map(|i| i + from_index)
+    }
 
-            // a. Let candidate be the substring of string from i to i + searchLen.
-            let candidate = String::from_utf16_lossy(
-                &self
-                    .encode_utf16()
-                    .skip(i)
-                    .take(search_len)
-                    .collect::<Vec<u16>>(),
-            );
-
-            // b. If candidate is the same sequence of code units as searchValue, return i.
-            if candidate == search_value.as_str() {
-                return Some(i);
-            }
-        }
+    /// `CodePointAt ( string, position )`
+    ///
+    /// Returns the code point starting at `position`, decoding a surrogate pair if there is one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-codepointat
+    ///
+    /// # Panics
+    ///
+    /// If `position` is smaller than size of string.
+    pub(crate) fn code_point_at(&self, position: usize) -> CodePoint {
+        // 1. Let size be the length of string.
+        let size = self.len();
+
+        // 2. Assert: position ≥ 0 and position < size.
+        // position >= 0 ensured by position: usize
+        assert!(position < size);
+
+        // 3. Let first be the code unit at index position within string.
+        // 4. Let cp be the code point whose numeric value is that of first.
+        // 5. If first is not a leading surrogate or trailing surrogate, then
+        // a. Return the Record { [[CodePoint]]: cp, [[CodeUnitCount]]: 1, [[IsUnpairedSurrogate]]: false }.
+        // 6. If first is a trailing surrogate or position + 1 = size, then
+        // a. Return the Record { [[CodePoint]]: cp, [[CodeUnitCount]]: 1, [[IsUnpairedSurrogate]]: true }.
+        // 7. Let second be the code unit at index position + 1 within string.
+        // 8. If second is not a trailing surrogate, then
+        // a. Return the Record { [[CodePoint]]: cp, [[CodeUnitCount]]: 1, [[IsUnpairedSurrogate]]: true }.
+        // 9. Set cp to ! UTF16SurrogatePairToCodePoint(first, second).
+        // 10. Return the Record { [[CodePoint]]: cp, [[CodeUnitCount]]: 2, [[IsUnpairedSurrogate]]: false }.
+        char::decode_utf16(self[position..].iter().copied())
+            .next()
+            .expect("already checked that the length is greater than position")
+            .map_or_else(
+                |e| CodePoint::UnpairedSurrogate(e.unpaired_surrogate()),
+                CodePoint::Unicode,
+            )
+    }
 
-        // 8. Return -1.
-        None
+    /// `TrimString ( string, where )`
+    ///
+    /// Removes the leading whitespace of the string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-trimstring
+    pub(crate) fn trim_start(&self) -> &[u16] {
+        let start = self
+            .iter()
+            .position(|&c| !is_trimmable_code_unit(c))
+            .unwrap_or(self.len());
+        &self[start..]
     }
 
-    pub(crate) fn string_to_number(&self) -> f64 {
-        let string = self.trim_matches(is_trimmable_whitespace);
+    /// `TrimString ( string, where )`
+    ///
+    /// Removes the trailing whitespace of the string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-trimstring
+    pub(crate) fn trim_end(&self) -> &[u16] {
+        let end = self
+            .iter()
+            .rposition(|&c| !is_trimmable_code_unit(c))
+            .map_or(0, |i| i + 1);
+        &self[..end]
+    }
+
+    /// `TrimString ( string, where )`
+    ///
+    /// Removes the leading and trailing whitespace of the string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-trimstring
+    pub(crate) fn trim(&self) -> &[u16] {
+        let start = self
+            .iter()
+            .position(|&c| !is_trimmable_code_unit(c))
+            .unwrap_or(self.len());
+        let end = self
+            .iter()
+            .rposition(|&c| !is_trimmable_code_unit(c))
+            .map_or(start, |i| i + 1);
+        &self[start..end]
+    }
 
-        match string {
+    /// `StringToNumber ( str )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringtonumber
+    pub(crate) fn to_number(&self) -> f64 {
+        // All the characters of a valid numeric literal are ASCII, so a string that
+        // isn't valid UTF-16 can never be a number.
+        let string = if let Ok(string) = String::from_utf16(self.trim()) {
+            string
+        } else {
+            return f64::NAN;
+        };
+
+        match string.as_str() {
             "" => return 0.0,
             "-Infinity" => return f64::NEG_INFINITY,
             "Infinity" | "+Infinity" => return f64::INFINITY,
@@ -940,12 +1333,29 @@ impl JsString {
             return value;
         }
 
-        match string {
+        match string.as_str() {
             // Handle special cases so `fast_float` does not return infinity.
             "inf" | "+inf" | "-inf" => f64::NAN,
             string => fast_float::parse(string).unwrap_or(f64::NAN),
         }
     }
+
+    /// `StringToBigInt ( str )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringtobigint
+    pub(crate) fn to_big_int(&self) -> Option<JsBigInt> {
+        // Same as with `StringToNumber`, unpaired surrogates can't be part of a valid literal.
+        JsBigInt::from_string(&self.to_std_string().ok()?)
+    }
+}
+
+/// Checks if a UTF-16 code unit is a whitespace or line terminator, as removed by `TrimString`.
+#[inline]
+fn is_trimmable_code_unit(c: u16) -> bool {
+    char::from_u32(u32::from(c)).map_or(false, is_trimmable_whitespace)
 }
 
 impl Clone for JsString {
@@ -981,14 +1391,35 @@ impl Drop for JsString {
 impl std::fmt::Debug for JsString {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.as_str().fmt(f)
+        self.to_std_string_escaped().fmt(f)
     }
 }
 
 impl std::fmt::Display for JsString {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.as_str().fmt(f)
+        self.to_std_string_escaped().fmt(f)
+    }
+}
+
+impl From<&[u16]> for JsString {
+    #[inline]
+    fn from(s: &[u16]) -> Self {
+        Self::from_slice(s)
+    }
+}
+
+impl<const N: usize> From<&[u16; N]> for JsString {
+    #[inline]
+    fn from(s: &[u16; N]) -> Self {
+        Self::from_slice(s)
+    }
+}
+
+impl From<Vec<u16>> for JsString {
+    #[inline]
+    fn from(vec: Vec<u16>) -> Self {
+        Self::from_slice(&vec)
     }
 }
 
@@ -1013,26 +1444,35 @@ impl From<String> for JsString {
     }
 }
 
-impl AsRef<str> for JsString {
+impl AsRef<[u16]> for JsString {
     #[inline]
-    fn as_ref(&self) -> &str {
-        self.as_str()
+    fn as_ref(&self) -> &[u16] {
+        self.as_slice()
     }
 }
 
-impl Borrow<str> for JsString {
+impl Borrow<[u16]> for JsString {
     #[inline]
-    fn borrow(&self) -> &str {
-        self.as_str()
+    fn borrow(&self) -> &[u16] {
+        self.as_slice()
     }
 }
 
 impl Deref for JsString {
-    type Target = str;
+    type Target = [u16];
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        self.as_str()
+        self.as_slice()
+    }
+}
+
+impl<I: SliceIndex<[u16]>> Index<I> for JsString {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.as_slice(), index)
     }
 }
 
@@ -1044,7 +1484,7 @@ impl PartialEq<Self> for JsString {
             return true;
         }
 
-        self.as_str() == other.as_str()
+        self.as_slice() == other.as_slice()
     }
 }
 
@@ -1053,55 +1493,76 @@ impl Eq for JsString {}
 impl Hash for JsString {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.as_str().hash(state);
+        self.as_slice().hash(state);
     }
 }
 
 impl PartialOrd for JsString {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.as_str().partial_cmp(other.as_str())
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for JsString {
     #[inline]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.as_str().cmp(other)
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl PartialEq<[u16]> for JsString {
+    #[inline]
+    fn eq(&self, other: &[u16]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl PartialEq<JsString> for [u16] {
+    #[inline]
+    fn eq(&self, other: &JsString) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<const N: usize> PartialEq<[u16; N]> for JsString {
+    #[inline]
+    fn eq(&self, other: &[u16; N]) -> bool {
+        self.as_slice() == other
     }
 }
 
 impl PartialEq<str> for JsString {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        self.as_str() == other
+        self.iter().copied().eq(other.encode_utf16())
     }
 }
 
 impl PartialEq<JsString> for str {
     #[inline]
     fn eq(&self, other: &JsString) -> bool {
-        self == other.as_str()
+        other == self
     }
 }
 
 impl PartialEq<&str> for JsString {
     #[inline]
     fn eq(&self, other: &&str) -> bool {
-        self.as_str() == *other
+        self == *other
     }
 }
 
 impl PartialEq<JsString> for &str {
     #[inline]
     fn eq(&self, other: &JsString) -> bool {
-        *self == other.as_str()
+        other == *self
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::JsString;
+    use super::{CodePoint, JsString};
     use std::mem::size_of;
 
     #[test]
@@ -1117,7 +1578,7 @@ mod tests {
 
     #[test]
     fn refcount() {
-        let x = JsString::new("Hello wrold");
+        let x = js_string!("Hello wrold");
         assert_eq!(JsString::refcount(&x), Some(1));
 
         {
@@ -1141,7 +1602,7 @@ mod tests {
 
     #[test]
     fn static_refcount() {
-        let x = JsString::new("");
+        let x = js_string!();
         assert_eq!(JsString::refcount(&x), None);
 
         {
@@ -1155,34 +1616,34 @@ mod tests {
 
     #[test]
     fn ptr_eq() {
-        let x = JsString::new("Hello");
+        let x = js_string!("Hello");
         let y = x.clone();
 
         assert!(JsString::ptr_eq(&x, &y));
 
-        let z = JsString::new("Hello");
+        let z = js_string!("Hello");
         assert!(!JsString::ptr_eq(&x, &z));
         assert!(!JsString::ptr_eq(&y, &z));
     }
 
     #[test]
     fn static_ptr_eq() {
-        let x = JsString::new("");
+        let x = js_string!();
         let y = x.clone();
 
         assert!(JsString::ptr_eq(&x, &y));
 
-        let z = JsString::new("");
+        let z = js_string!();
         assert!(JsString::ptr_eq(&x, &z));
         assert!(JsString::ptr_eq(&y, &z));
     }
 
     #[test]
-    fn as_str() {
-        let s = "Hello";
-        let x = JsString::new(s);
+    fn as_slice() {
+        let s = utf16!("Hello");
+        let x = js_string!(s);
 
-        assert_eq!(x.as_str(), s);
+        assert_eq!(x.as_slice(), s);
     }
 
     #[test]
@@ -1190,10 +1651,10 @@ mod tests {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
 
-        let s = "Hello, world!";
-        let x = JsString::new(s);
+        let s = utf16!("Hello, world!");
+        let x = js_string!(s);
 
-        assert_eq!(x.as_str(), s);
+        assert_eq!(x.as_slice(), s);
 
         let mut hasher = DefaultHasher::new();
         s.hash(&mut hasher);
@@ -1207,21 +1668,72 @@ mod tests {
 
     #[test]
     fn concat() {
-        let x = JsString::new("hello");
+        let x = js_string!("hello");
         let y = ", ";
-        let z = JsString::new("world");
+        let z = js_string!("world");
         let w = String::from("!");
 
-        let xy = JsString::concat(x, y);
+        let xy = js_string!(&x, &JsString::from(y));
         assert_eq!(xy, "hello, ");
         assert_eq!(JsString::refcount(&xy), Some(1));
 
-        let xyz = JsString::concat(xy, z);
+        let xyz = js_string!(&xy, &z);
         assert_eq!(xyz, "hello, world");
         assert_eq!(JsString::refcount(&xyz), Some(1));
 
-        let xyzw = JsString::concat(xyz, w);
+        let xyzw = js_string!(&xyz, &JsString::from(w));
         assert_eq!(xyzw, "hello, world!");
         assert_eq!(JsString::refcount(&xyzw), Some(1));
     }
+
+    #[test]
+    fn utf16_macro() {
+        assert_eq!(utf16!(""), &[] as &[u16]);
+        assert_eq!(utf16!("abc"), &[0x61, 0x62, 0x63]);
+        assert_eq!(utf16!("é€"), &[0xE9, 0x20AC]);
+        assert_eq!(utf16!("😀"), &[0xD83D, 0xDE00]);
+    }
+
+    #[test]
+    fn unpaired_surrogates() {
+        let s = js_string!(&[0x61, 0xD800, 0x62][..]);
+        assert_eq!(s.len(), 3);
+        assert!(s.to_std_string().is_err());
+        assert_eq!(s.to_std_string_escaped(), "a\\uD800b");
+        assert_eq!(s.to_std_string_lossy(), "a\u{FFFD}b");
+        assert_eq!(
+            s.code_points().collect::<Vec<_>>(),
+            [
+                CodePoint::Unicode('a'),
+                CodePoint::UnpairedSurrogate(0xD800),
+                CodePoint::Unicode('b')
+            ]
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let s = JsString::from("héllo 😀");
+        assert_eq!(s.len(), 8);
+        assert_eq!(s.to_std_string().unwrap(), "héllo 😀");
+        assert_eq!(s, "héllo 😀");
+    }
+
+    #[test]
+    fn trim() {
+        let s = js_string!(" \u{a0}\nhello world\t ");
+        assert_eq!(s.trim(), utf16!("hello world"));
+        assert_eq!(s.trim_start(), utf16!("hello world\t "));
+        assert_eq!(s.trim_end(), utf16!(" \u{a0}\nhello world"));
+    }
+
+    #[test]
+    fn index_of() {
+        let s = js_string!("abcabc");
+        assert_eq!(s.index_of(utf16!("bc"), 0), Some(1));
+        assert_eq!(s.index_of(utf16!("bc"), 2), Some(4));
+        assert_eq!(s.index_of(utf16!("bc"), 5), None);
+        assert_eq!(s.index_of(utf16!(""), 6), Some(6));
+        assert_eq!(s.index_of(utf16!(""), 7), None);
+    }
 }
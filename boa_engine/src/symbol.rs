@@ -15,23 +15,25 @@
 //! [spec]: https://tc39.es/ecma262/#sec-symbol-value
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol
 
-use crate::JsString;
+use crate::{
+    value::{TaggedRc, SYMBOL_TAG},
+    JsString,
+};
 use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use std::{
     cell::Cell,
     fmt::{self, Display},
     hash::{Hash, Hasher},
-    rc::Rc,
 };
 
 /// A structure that contains the JavaScript well known symbols.
 ///
 /// # Examples
 /// ```
-///# use boa_engine::symbol::WellKnownSymbols;
+///# use boa_engine::{string::utf16, symbol::WellKnownSymbols};
 ///
 /// let iterator = WellKnownSymbols::iterator();
-/// assert_eq!(iterator.description().as_deref(), Some("Symbol.iterator"));
+/// assert_eq!(iterator.description().as_deref(), Some(utf16!("Symbol.iterator")));
 /// ```
 /// This is equivalent to `let iterator = Symbol.iterator` in JavaScript.
 #[derive(Debug, Clone)]
@@ -249,8 +251,9 @@ struct Inner {
 
 /// This represents a JavaScript symbol primitive.
 #[derive(Debug, Clone, Finalize)]
+#[repr(transparent)]
 pub struct JsSymbol {
-    inner: Rc<Inner>,
+    inner: TaggedRc<Inner, SYMBOL_TAG>,
 }
 
 // Safety: JsSymbol does not contain any objects which needs to be traced,
@@ -260,6 +263,12 @@ unsafe impl Trace for JsSymbol {
 }
 
 impl JsSymbol {
+    /// Consumes the symbol, returning its tagged address.
+    #[inline]
+    pub(crate) fn into_raw(self) -> usize {
+        self.inner.into_raw()
+    }
+
     /// Create a new symbol.
     #[inline]
     pub fn new(description: Option<JsString>) -> Self {
@@ -270,7 +279,7 @@ impl JsSymbol {
         });
 
         Self {
-            inner: Rc::new(Inner { hash, description }),
+            inner: TaggedRc::new(Inner { hash, description }),
         }
     }
 
@@ -278,7 +287,7 @@ impl JsSymbol {
     #[inline]
     fn with_hash(hash: u64, description: Option<JsString>) -> Self {
         Self {
-            inner: Rc::new(Inner { hash, description }),
+            inner: TaggedRc::new(Inner { hash, description }),
         }
     }
 
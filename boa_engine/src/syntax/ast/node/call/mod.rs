@@ -1,4 +1,7 @@
-use crate::syntax::ast::node::{join_nodes, Node};
+use crate::syntax::ast::{
+    node::{join_nodes, Node},
+    Span,
+};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -22,10 +25,12 @@ mod tests;
 /// [spec]: https://tc39.es/ecma262/#prod-CallExpression
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions#Calling_functions
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Call {
     expr: Box<Node>,
     args: Box<[Node]>,
+    #[cfg_attr(feature = "deser", serde(default))]
+    span: Option<Span>,
 }
 
 impl Call {
@@ -38,9 +43,21 @@ impl Call {
         Self {
             expr: Box::new(expr.into()),
             args: args.into(),
+            span: None,
         }
     }
 
+    /// Sets the span of the source text of the call.
+    ///
+    /// For function calls this is the span of the arguments, and for `new` expressions the span
+    /// of the whole expression.
+    #[inline]
+    #[must_use]
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     /// Gets the name of the function call.
     pub fn expr(&self) -> &Node {
         &self.expr
@@ -50,6 +67,20 @@ impl Call {
     pub fn args(&self) -> &[Node] {
         &self.args
     }
+
+    /// Gets the span of the source text of the call, if the node was created by the parser.
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+// The span is not compared, since it only carries information about the source text that the
+// node was parsed from.
+impl PartialEq for Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.expr == other.expr && self.args == other.args
+    }
 }
 
 impl ToInternedString for Call {
@@ -0,0 +1,380 @@
+//! Export declaration node.
+
+use crate::syntax::ast::node::{Class, Declaration, DeclarationList, Node};
+use boa_interner::{Interner, Sym, ToInternedString};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The kind of re-export in an [`ExportDeclaration`].
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReExportKind {
+    /// Namespaced Re-export (`export * as name from "module-name"`).
+    Namespaced {
+        /// Reexported name for the imported module.
+        name: Option<Sym>,
+    },
+    /// Re-export list (`export { export1, export2 as alias2 } from "module-name"`).
+    Named {
+        /// List of the required re-exports of the re-exported module.
+        names: Box<[ExportSpecifier]>,
+    },
+}
+
+/// An export declaration AST node.
+///
+/// Exports can be of different kinds:
+///  - Re-exports (`export * from "module"`, `export { a } from "module"`).
+///  - Export lists (`export { a, b as c }`).
+///  - Exported declarations (`export let a = 1`, `export function f() {}`).
+///  - Default exports (`export default expr`, `export default function () {}`).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExportDeclaration {
+    /// Re-export.
+    ReExport {
+        /// The kind of reexport declared.
+        kind: ReExportKind,
+        /// Reexported module specifier.
+        specifier: Sym,
+    },
+    /// List of exports.
+    List(Box<[ExportSpecifier]>),
+    /// Exported variable statement or lexical/hoistable/class declaration.
+    Declaration(Node),
+    /// Default exported hoistable declaration.
+    DefaultHoistableDeclaration(Node),
+    /// Default exported class declaration.
+    DefaultClassDeclaration(Class),
+    /// Default exported assignment expression.
+    DefaultAssignmentExpression(Node),
+}
+
+impl ExportDeclaration {
+    /// Returns the module specifier requested by this export declaration, if any.
+    #[inline]
+    pub fn module_request(&self) -> Option<Sym> {
+        if let Self::ReExport { specifier, .. } = self {
+            Some(*specifier)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the names bound by this export declaration.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+    pub(crate) fn bound_names(&self) -> Vec<Sym> {
+        match self {
+            Self::ReExport { .. } | Self::List(_) => Vec::new(),
+            Self::Declaration(node) => declaration_bound_names(node),
+            Self::DefaultHoistableDeclaration(node) => {
+                let mut names = declaration_bound_names(node);
+                if names.is_empty() {
+                    names.push(Sym::DEFAULT);
+                }
+                names
+            }
+            Self::DefaultClassDeclaration(class) => vec![class.name()],
+            Self::DefaultAssignmentExpression(_) => vec![Sym::DEFAULT],
+        }
+    }
+
+    /// Returns the export entries of this export declaration.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportentries
+    pub fn export_entries(&self) -> Vec<ExportEntry> {
+        match self {
+            Self::ReExport { kind, specifier } => match kind {
+                ReExportKind::Namespaced { name: None } => {
+                    vec![ExportEntry::StarReExport {
+                        module_request: *specifier,
+                    }]
+                }
+                ReExportKind::Namespaced { name: Some(name) } => {
+                    vec![ExportEntry::ReExport(IndirectExportEntry::new(
+                        *specifier,
+                        ReExportImportName::Star,
+                        *name,
+                    ))]
+                }
+                ReExportKind::Named { names } => names
+                    .iter()
+                    .map(|spec| {
+                        ExportEntry::ReExport(IndirectExportEntry::new(
+                            *specifier,
+                            ReExportImportName::Name(spec.private_name()),
+                            spec.alias(),
+                        ))
+                    })
+                    .collect(),
+            },
+            Self::List(names) => names
+                .iter()
+                .map(|spec| {
+                    ExportEntry::Ordinary(LocalExportEntry::new(spec.private_name(), spec.alias()))
+                })
+                .collect(),
+            Self::Declaration(node) => declaration_bound_names(node)
+                .into_iter()
+                .map(|name| ExportEntry::Ordinary(LocalExportEntry::new(name, name)))
+                .collect(),
+            Self::DefaultHoistableDeclaration(_)
+            | Self::DefaultClassDeclaration(_)
+            | Self::DefaultAssignmentExpression(_) => self
+                .bound_names()
+                .into_iter()
+                .map(|name| ExportEntry::Ordinary(LocalExportEntry::new(name, Sym::DEFAULT)))
+                .collect(),
+        }
+    }
+
+    /// Returns the names exported by this export declaration.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
+    pub(crate) fn exported_names(&self) -> Vec<Sym> {
+        self.export_entries()
+            .into_iter()
+            .filter_map(|entry| match entry {
+                ExportEntry::Ordinary(entry) => Some(entry.export_name()),
+                ExportEntry::ReExport(entry) => Some(entry.export_name()),
+                ExportEntry::StarReExport { .. } => None,
+            })
+            .collect()
+    }
+}
+
+/// Returns the bound names of an exported declaration node.
+fn declaration_bound_names(node: &Node) -> Vec<Sym> {
+    match node {
+        Node::FunctionDecl(decl) => vec![decl.name()],
+        Node::GeneratorDecl(decl) => vec![decl.name()],
+        Node::AsyncFunctionDecl(decl) => vec![decl.name()],
+        Node::AsyncGeneratorDecl(decl) => vec![decl.name()],
+        Node::ClassDecl(decl) => vec![decl.name()],
+        Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+            let mut names = Vec::new();
+            for decl in list.as_ref() {
+                match decl {
+                    Declaration::Identifier { ident, .. } => names.push(ident.sym()),
+                    Declaration::Pattern(pattern) => names.extend(pattern.idents()),
+                }
+            }
+            names
+        }
+        _ => Vec::new(),
+    }
+}
+
+impl ToInternedString for ExportDeclaration {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let join_specifiers = |names: &[ExportSpecifier]| {
+            names
+                .iter()
+                .map(|spec| spec.to_interned_string(interner))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        match self {
+            Self::ReExport { kind, specifier } => {
+                let specifier = interner.resolve_expect(*specifier);
+                match kind {
+                    ReExportKind::Namespaced { name: None } => {
+                        format!("export * from \"{specifier}\"")
+                    }
+                    ReExportKind::Namespaced { name: Some(name) } => format!(
+                        "export * as {} from \"{specifier}\"",
+                        interner.resolve_expect(*name)
+                    ),
+                    ReExportKind::Named { names } => {
+                        format!(
+                            "export {{ {} }} from \"{specifier}\"",
+                            join_specifiers(names)
+                        )
+                    }
+                }
+            }
+            Self::List(names) => format!("export {{ {} }}", join_specifiers(names)),
+            Self::Declaration(node) => format!("export {}", node.to_interned_string(interner)),
+            Self::DefaultHoistableDeclaration(node) | Self::DefaultAssignmentExpression(node) => {
+                format!("export default {}", node.to_interned_string(interner))
+            }
+            Self::DefaultClassDeclaration(class) => {
+                format!("export default {}", class.to_indented_string(interner, 0))
+            }
+        }
+    }
+}
+
+impl From<DeclarationList> for ExportDeclaration {
+    fn from(list: DeclarationList) -> Self {
+        Self::Declaration(list.into())
+    }
+}
+
+/// Export specifier
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportSpecifier {
+    alias: Sym,
+    private_name: Sym,
+}
+
+impl ExportSpecifier {
+    /// Creates a new [`ExportSpecifier`].
+    #[inline]
+    pub fn new(alias: Sym, private_name: Sym) -> Self {
+        Self {
+            alias,
+            private_name,
+        }
+    }
+
+    /// Gets the original alias.
+    #[inline]
+    pub fn alias(self) -> Sym {
+        self.alias
+    }
+
+    /// Gets the private name of the export inside the module.
+    #[inline]
+    pub fn private_name(self) -> Sym {
+        self.private_name
+    }
+}
+
+impl ToInternedString for ExportSpecifier {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        if self.alias == self.private_name {
+            interner.resolve_expect(self.alias).to_owned()
+        } else {
+            format!(
+                "{} as {}",
+                interner.resolve_expect(self.private_name),
+                interner.resolve_expect(self.alias)
+            )
+        }
+    }
+}
+
+/// The name of the re-exported binding in an [`IndirectExportEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReExportImportName {
+    /// A binding of the imported module.
+    Name(Sym),
+    /// All exports of the module (`export * as name from "module"`).
+    Star,
+}
+
+/// `ExportEntry` record.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-exportentry-records
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportEntry {
+    /// An ordinary export entry
+    Ordinary(LocalExportEntry),
+    /// A star reexport entry.
+    StarReExport {
+        /// The module from where this reexport will import.
+        module_request: Sym,
+    },
+    /// A reexport entry with an export name.
+    ReExport(IndirectExportEntry),
+}
+
+/// A local export entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalExportEntry {
+    local_name: Sym,
+    export_name: Sym,
+}
+
+impl LocalExportEntry {
+    /// Creates a new `LocalExportEntry`.
+    #[inline]
+    pub fn new(local_name: Sym, export_name: Sym) -> Self {
+        Self {
+            local_name,
+            export_name,
+        }
+    }
+
+    /// Gets the local name of this export entry.
+    #[inline]
+    pub fn local_name(self) -> Sym {
+        self.local_name
+    }
+
+    /// Gets the export name of this export entry.
+    #[inline]
+    pub fn export_name(self) -> Sym {
+        self.export_name
+    }
+}
+
+/// A re-export entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndirectExportEntry {
+    module_request: Sym,
+    import_name: ReExportImportName,
+    export_name: Sym,
+}
+
+impl IndirectExportEntry {
+    /// Creates a new `IndirectExportEntry`.
+    #[inline]
+    pub fn new(module_request: Sym, import_name: ReExportImportName, export_name: Sym) -> Self {
+        Self {
+            module_request,
+            import_name,
+            export_name,
+        }
+    }
+
+    /// Gets the module from where this entry reexports.
+    #[inline]
+    pub fn module_request(self) -> Sym {
+        self.module_request
+    }
+
+    /// Gets the import name of the reexport.
+    #[inline]
+    pub fn import_name(self) -> ReExportImportName {
+        self.import_name
+    }
+
+    /// Gets the public alias of the reexport.
+    #[inline]
+    pub fn export_name(self) -> Sym {
+        self.export_name
+    }
+}
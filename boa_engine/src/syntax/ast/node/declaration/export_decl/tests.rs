@@ -0,0 +1,37 @@
+use crate::syntax::ast::node::test_module_formatting;
+
+#[test]
+fn export_declaration_formatting() {
+    test_module_formatting(
+        r#"
+        export * from "a";
+        export * as b from "b";
+        export { c, d as e } from "c";
+        let h, i;
+        export { h, i as j };
+        export var k = 1;
+        export const l = 2;
+        export function m() {};
+        export class N {};
+        "#,
+    );
+}
+
+#[test]
+fn export_default_formatting() {
+    test_module_formatting(
+        r#"
+        export default 1 + 2;
+        "#,
+    );
+    test_module_formatting(
+        r#"
+        export default function f() {};
+        "#,
+    );
+    test_module_formatting(
+        r#"
+        export default class A {};
+        "#,
+    );
+}
@@ -0,0 +1,280 @@
+//! Import declaration node.
+
+use crate::syntax::ast::node::Identifier;
+use boa_interner::{Interner, Sym, ToInternedString};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The kind of import in an [`ImportDeclaration`].
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImportKind {
+    /// Default (`import x from "module"`) or unnamed (`import "module"`) import.
+    DefaultOrUnnamed,
+
+    /// Namespaced import (`import * as name from "module"`).
+    Namespaced {
+        /// Binding for the namespace created from the exports of the imported module.
+        binding: Identifier,
+    },
+
+    /// Import list (`import { c, d as e } from "module"`).
+    Named {
+        /// List of the required exports of the imported module.
+        names: Box<[ImportSpecifier]>,
+    },
+}
+
+/// An import declaration AST node.
+///
+/// Syntax: `import ImportClause FromClause ;` or `import ModuleSpecifier ;`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportDeclaration {
+    /// Binding for the default export of `specifier`.
+    default: Option<Identifier>,
+    /// See [`ImportKind`].
+    kind: ImportKind,
+    /// Module specifier.
+    specifier: Sym,
+}
+
+impl ImportDeclaration {
+    /// Creates a new import declaration.
+    #[inline]
+    pub fn new(default: Option<Identifier>, kind: ImportKind, specifier: Sym) -> Self {
+        Self {
+            default,
+            kind,
+            specifier,
+        }
+    }
+
+    /// Gets the binding for the default export of the module.
+    #[inline]
+    pub fn default(&self) -> Option<Identifier> {
+        self.default
+    }
+
+    /// Gets the module specifier of the import declaration.
+    #[inline]
+    pub fn specifier(&self) -> Sym {
+        self.specifier
+    }
+
+    /// Gets the import kind of the import declaration
+    #[inline]
+    pub fn kind(&self) -> &ImportKind {
+        &self.kind
+    }
+
+    /// Returns the names bound by this import declaration.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+    pub(crate) fn bound_names(&self) -> Vec<Sym> {
+        let mut names = Vec::new();
+        if let Some(default) = self.default {
+            names.push(default.sym());
+        }
+        match &self.kind {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespaced { binding } => names.push(binding.sym()),
+            ImportKind::Named { names: specifiers } => {
+                names.extend(specifiers.iter().map(|spec| spec.binding().sym()));
+            }
+        }
+        names
+    }
+
+    /// Returns the import entries of this import declaration.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-importentries
+    pub fn import_entries(&self) -> Vec<ImportEntry> {
+        let mut entries = Vec::new();
+        if let Some(default) = self.default {
+            entries.push(ImportEntry::new(
+                self.specifier,
+                ImportName::Name(Sym::DEFAULT),
+                default.sym(),
+            ));
+        }
+        match &self.kind {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespaced { binding } => {
+                entries.push(ImportEntry::new(
+                    self.specifier,
+                    ImportName::Namespace,
+                    binding.sym(),
+                ));
+            }
+            ImportKind::Named { names } => {
+                for spec in names.iter() {
+                    entries.push(ImportEntry::new(
+                        self.specifier,
+                        ImportName::Name(spec.export_name()),
+                        spec.binding().sym(),
+                    ));
+                }
+            }
+        }
+        entries
+    }
+}
+
+impl ToInternedString for ImportDeclaration {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let mut buf = "import ".to_owned();
+        if let Some(default) = self.default {
+            buf.push_str(interner.resolve_expect(default.sym()));
+            if !matches!(self.kind, ImportKind::DefaultOrUnnamed) {
+                buf.push_str(", ");
+            }
+        }
+        match &self.kind {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespaced { binding } => {
+                buf.push_str(&format!("* as {}", interner.resolve_expect(binding.sym())));
+            }
+            ImportKind::Named { names } => {
+                buf.push_str("{ ");
+                buf.push_str(
+                    &names
+                        .iter()
+                        .map(|spec| spec.to_interned_string(interner))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                );
+                buf.push_str(" }");
+            }
+        }
+        if self.default.is_some() || !matches!(self.kind, ImportKind::DefaultOrUnnamed) {
+            buf.push_str(" from ");
+        }
+        buf.push_str(&format!("\"{}\"", interner.resolve_expect(self.specifier)));
+        buf
+    }
+}
+
+/// Import specifier
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportSpecifier {
+    binding: Identifier,
+    export_name: Sym,
+}
+
+impl ImportSpecifier {
+    /// Creates a new [`ImportSpecifier`].
+    #[inline]
+    pub fn new(binding: Identifier, export_name: Sym) -> Self {
+        Self {
+            binding,
+            export_name,
+        }
+    }
+
+    /// Gets the binding of the import specifier.
+    #[inline]
+    pub fn binding(self) -> Identifier {
+        self.binding
+    }
+
+    /// Gets the optional export name of the import.
+    #[inline]
+    pub fn export_name(self) -> Sym {
+        self.export_name
+    }
+}
+
+impl ToInternedString for ImportSpecifier {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        if self.binding.sym() == self.export_name {
+            interner.resolve_expect(self.export_name).to_owned()
+        } else {
+            format!(
+                "{} as {}",
+                interner.resolve_expect(self.export_name),
+                interner.resolve_expect(self.binding.sym())
+            )
+        }
+    }
+}
+
+/// The name under which the imported binding is exported by a module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-importentry-record-fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportName {
+    /// The namespace object of the imported module.
+    Namespace,
+    /// A binding of the imported module.
+    Name(Sym),
+}
+
+/// `ImportEntry` record.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#importentry-record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportEntry {
+    module_request: Sym,
+    import_name: ImportName,
+    local_name: Sym,
+}
+
+impl ImportEntry {
+    /// Creates a new `ImportEntry`.
+    #[inline]
+    pub fn new(module_request: Sym, import_name: ImportName, local_name: Sym) -> Self {
+        Self {
+            module_request,
+            import_name,
+            local_name,
+        }
+    }
+
+    /// Gets the module from where the binding must be imported.
+    #[inline]
+    pub fn module_request(self) -> Sym {
+        self.module_request
+    }
+
+    /// Gets the import name of the imported binding.
+    #[inline]
+    pub fn import_name(self) -> ImportName {
+        self.import_name
+    }
+
+    /// Gets the local name of the imported binding.
+    #[inline]
+    pub fn local_name(self) -> Sym {
+        self.local_name
+    }
+}
@@ -0,0 +1,15 @@
+use crate::syntax::ast::node::test_module_formatting;
+
+#[test]
+fn import_declaration_formatting() {
+    test_module_formatting(
+        r#"
+        import "side-effect";
+        import a from "a";
+        import * as b from "b";
+        import c, * as d from "c";
+        import { e, f as g } from "e";
+        import j, { k } from "j";
+        "#,
+    );
+}
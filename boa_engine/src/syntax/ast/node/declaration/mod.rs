@@ -17,10 +17,12 @@ pub mod async_function_expr;
 pub mod async_generator_decl;
 pub mod async_generator_expr;
 pub mod class_decl;
+pub mod export_decl;
 pub mod function_decl;
 pub mod function_expr;
 pub mod generator_decl;
 pub mod generator_expr;
+pub mod import_decl;
 
 pub use self::{
     arrow_function_decl::ArrowFunctionDecl, async_function_decl::AsyncFunctionDecl,
@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::{node::Node, Span};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -26,10 +26,12 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#sec-property-accessors
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Property_accessors#Dot_notation
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct GetConstField {
     obj: Box<Node>,
     field: Sym,
+    #[cfg_attr(feature = "deser", serde(default))]
+    span: Option<Span>,
 }
 
 impl GetConstField {
@@ -41,9 +43,18 @@ impl GetConstField {
         Self {
             obj: Box::new(value.into()),
             field,
+            span: None,
         }
     }
 
+    /// Sets the span of the source text of the access, from the dot to the name of the field.
+    #[inline]
+    #[must_use]
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     /// Gets the original object from where to get the field from.
     pub fn obj(&self) -> &Node {
         &self.obj
@@ -53,6 +64,20 @@ impl GetConstField {
     pub fn field(&self) -> Sym {
         self.field
     }
+
+    /// Gets the span of the source text of the access, if the node was created by the parser.
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+// The span is not compared, since it only carries information about the source text that the
+// node was parsed from.
+impl PartialEq for GetConstField {
+    fn eq(&self, other: &Self) -> bool {
+        self.obj == other.obj && self.field == other.field
+    }
 }
 
 impl ToInternedString for GetConstField {
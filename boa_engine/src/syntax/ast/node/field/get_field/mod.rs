@@ -1,4 +1,4 @@
-use crate::syntax::ast::node::Node;
+use crate::syntax::ast::{node::Node, Span};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -27,10 +27,12 @@ use serde::{Deserialize, Serialize};
 /// [symbol]: https://developer.mozilla.org/en-US/docs/Glossary/Symbol
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Property_accessors#Bracket_notation
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct GetField {
     obj: Box<Node>,
     field: Box<Node>,
+    #[cfg_attr(feature = "deser", serde(default))]
+    span: Option<Span>,
 }
 
 impl GetField {
@@ -51,8 +53,31 @@ impl GetField {
         Self {
             obj: Box::new(value.into()),
             field: Box::new(field.into()),
+            span: None,
         }
     }
+
+    /// Sets the span of the source text of the access, from the opening to the closing bracket.
+    #[inline]
+    #[must_use]
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Gets the span of the source text of the access, if the node was created by the parser.
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+// The span is not compared, since it only carries information about the source text that the
+// node was parsed from.
+impl PartialEq for GetField {
+    fn eq(&self, other: &Self) -> bool {
+        self.obj == other.obj && self.field == other.field
+    }
 }
 
 impl ToInternedString for GetField {
@@ -11,6 +11,7 @@ pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod module_item_list;
 pub mod new;
 pub mod object;
 pub mod operator;
@@ -32,13 +33,15 @@ pub use self::{
     conditional::{ConditionalOp, If},
     declaration::{
         async_generator_decl::AsyncGeneratorDecl, async_generator_expr::AsyncGeneratorExpr,
-        class_decl::Class, generator_decl::GeneratorDecl, generator_expr::GeneratorExpr,
-        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList,
-        DeclarationPattern, FunctionDecl, FunctionExpr,
+        class_decl::Class, export_decl::ExportDeclaration, generator_decl::GeneratorDecl,
+        generator_expr::GeneratorExpr, import_decl::ImportDeclaration, ArrowFunctionDecl,
+        AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList, DeclarationPattern,
+        FunctionDecl, FunctionExpr,
     },
     field::{get_private_field::GetPrivateField, GetConstField, GetField, GetSuperField},
     identifier::Identifier,
     iteration::{Break, Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+    module_item_list::{ModuleItem, ModuleItemList},
     new::New,
     object::Object,
     operator::{Assign, BinOp, UnaryOp},
@@ -1300,6 +1303,34 @@ where
 fn test_formatting(source: &'static str) {
     use crate::{syntax::Parser, Context};
 
+    check_formatting(source, |scenario| {
+        let mut context = Context::default();
+        Parser::new(scenario.as_bytes())
+            .parse_all(&mut context)
+            .expect("parsing failed")
+            .to_interned_string(context.interner())
+    });
+}
+
+/// Same as [`test_formatting`], but parses the source code as a module.
+#[cfg(test)]
+fn test_module_formatting(source: &'static str) {
+    use crate::{syntax::Parser, Context};
+
+    check_formatting(source, |scenario| {
+        let mut context = Context::default();
+        Parser::new(scenario.as_bytes())
+            .parse_module(&mut context)
+            .expect("parsing failed")
+            .to_interned_string(context.interner())
+    });
+}
+
+#[cfg(test)]
+fn check_formatting<F>(source: &'static str, format: F)
+where
+    F: FnOnce(&str) -> String,
+{
     // Remove preceding newline.
     let source = &source[1..];
 
@@ -1313,11 +1344,7 @@ fn test_formatting(source: &'static str) {
         .map(|l| &l[characters_to_remove..]) // Remove preceding whitespace from each line
         .collect::<Vec<&'static str>>()
         .join("\n");
-    let mut context = Context::default();
-    let result = Parser::new(scenario.as_bytes())
-        .parse_all(&mut context)
-        .expect("parsing failed")
-        .to_interned_string(context.interner());
+    let result = format(&scenario);
     if scenario != result {
         eprint!("========= Expected:\n{scenario}");
         eprint!("========= Got:\n{result}");
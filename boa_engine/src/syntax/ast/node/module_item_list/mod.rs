@@ -0,0 +1,311 @@
+//! Module item list node.
+
+use crate::syntax::ast::{
+    node::{
+        declaration::{
+            export_decl::{ExportDeclaration, ExportEntry},
+            import_decl::{ImportDeclaration, ImportEntry},
+        },
+        Node, StatementList,
+    },
+    Span,
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+use rustc_hash::FxHashSet;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// Module item list AST node.
+///
+/// It contains a list of module items, which are the top level items of an ECMAScript module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default)]
+pub struct ModuleItemList {
+    items: Box<[ModuleItem]>,
+    #[cfg_attr(feature = "deser", serde(default))]
+    spans: Box<[Span]>,
+}
+
+impl ModuleItemList {
+    /// Creates a new module item list, where each item is located at the span with the same
+    /// index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of spans is not the same as the number of items.
+    #[inline]
+    pub fn with_spans<I, S>(items: I, spans: S) -> Self
+    where
+        I: Into<Box<[ModuleItem]>>,
+        S: Into<Box<[Span]>>,
+    {
+        let items = items.into();
+        let spans = spans.into();
+        assert_eq!(
+            items.len(),
+            spans.len(),
+            "every module item must have exactly one span"
+        );
+
+        Self { items, spans }
+    }
+
+    /// Gets the list of module items.
+    #[inline]
+    pub fn items(&self) -> &[ModuleItem] {
+        &self.items
+    }
+
+    /// Gets the source text spans of the module items.
+    ///
+    /// This is empty if the module item list was not created by the parser.
+    #[inline]
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Returns the module specifiers requested by this module, in source text order and
+    /// without duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
+    pub fn requests(&self) -> Vec<Sym> {
+        let mut requests = Vec::new();
+        for item in self.items.iter() {
+            let request = match item {
+                ModuleItem::ImportDeclaration(import) => Some(import.specifier()),
+                ModuleItem::ExportDeclaration(export) => export.module_request(),
+                ModuleItem::StatementListItem(_) => None,
+            };
+            if let Some(request) = request {
+                if !requests.contains(&request) {
+                    requests.push(request);
+                }
+            }
+        }
+        requests
+    }
+
+    /// Returns the import entries of this module.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-importentries
+    pub fn import_entries(&self) -> Vec<ImportEntry> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::ImportDeclaration(import) => Some(import.import_entries()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the export entries of this module.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportentries
+    pub fn export_entries(&self) -> Vec<ExportEntry> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::ExportDeclaration(export) => Some(export.export_entries()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the names exported by this module.
+    ///
+    /// The returned list may contain duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
+    pub(crate) fn exported_names(&self) -> Vec<Sym> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::ExportDeclaration(export) => Some(export.exported_names()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the local names referenced by export lists (`export { a, b as c }`).
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportedbindings
+    pub(crate) fn exported_bindings(&self) -> Vec<Sym> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::ExportDeclaration(ExportDeclaration::List(names)) => {
+                    Some(names.iter().map(|spec| spec.private_name()))
+                }
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the lexically declared names of this module.
+    ///
+    /// The returned list may contain duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-lexicallydeclarednames
+    pub(crate) fn lexically_declared_names(&self) -> Vec<Sym> {
+        let mut names = Vec::new();
+        for item in self.items.iter() {
+            match item {
+                ModuleItem::ImportDeclaration(import) => names.extend(import.bound_names()),
+                ModuleItem::ExportDeclaration(
+                    export @ (ExportDeclaration::DefaultHoistableDeclaration(_)
+                    | ExportDeclaration::DefaultClassDeclaration(_)
+                    | ExportDeclaration::DefaultAssignmentExpression(_)),
+                ) => names.extend(export.bound_names()),
+                ModuleItem::ExportDeclaration(ExportDeclaration::Declaration(node))
+                | ModuleItem::StatementListItem(node) => {
+                    // At the top level of a module, function declarations are treated like
+                    // lexical declarations rather than like var declarations.
+                    names.extend(
+                        StatementList::from(vec![node.clone()])
+                            .lexically_declared_names()
+                            .into_iter()
+                            .map(|(name, _)| name),
+                    );
+                }
+                ModuleItem::ExportDeclaration(_) => {}
+            }
+        }
+        names
+    }
+
+    /// Returns the var declared names of this module.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-vardeclarednames
+    pub(crate) fn var_declared_names(&self, vars: &mut FxHashSet<Sym>) {
+        for item in self.items.iter() {
+            match item {
+                ModuleItem::ExportDeclaration(ExportDeclaration::Declaration(node))
+                | ModuleItem::StatementListItem(node) => node.var_declared_names(vars),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<T> From<T> for ModuleItemList
+where
+    T: Into<Box<[ModuleItem]>>,
+{
+    #[inline]
+    fn from(items: T) -> Self {
+        Self {
+            items: items.into(),
+            spans: Box::default(),
+        }
+    }
+}
+
+// Spans are not compared, since they only carry information about the source text that the
+// module items were parsed from.
+impl PartialEq for ModuleItemList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl ToInternedString for ModuleItemList {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let mut buf = String::new();
+        for item in self.items.iter() {
+            buf.push_str(&item.to_interned_string(interner));
+            match item {
+                ModuleItem::StatementListItem(
+                    Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_),
+                ) => {}
+                _ => buf.push(';'),
+            }
+            buf.push('\n');
+        }
+        buf
+    }
+}
+
+/// Module item AST node.
+///
+/// This is an extension over a [`StatementList`], which can also include multiple
+/// [`ImportDeclaration`] and [`ExportDeclaration`] nodes.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModuleItem {
+    /// See [`ImportDeclaration`].
+    ImportDeclaration(ImportDeclaration),
+    /// See [`ExportDeclaration`].
+    ExportDeclaration(ExportDeclaration),
+    /// A statement or declaration that is neither imported nor exported.
+    StatementListItem(Node),
+}
+
+impl ToInternedString for ModuleItem {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        match self {
+            Self::ImportDeclaration(import) => import.to_interned_string(interner),
+            Self::ExportDeclaration(export) => export.to_interned_string(interner),
+            Self::StatementListItem(node) => node.to_indented_string(interner, 0),
+        }
+    }
+}
+
+impl From<ImportDeclaration> for ModuleItem {
+    #[inline]
+    fn from(import: ImportDeclaration) -> Self {
+        Self::ImportDeclaration(import)
+    }
+}
+
+impl From<ExportDeclaration> for ModuleItem {
+    #[inline]
+    fn from(export: ExportDeclaration) -> Self {
+        Self::ExportDeclaration(export)
+    }
+}
+
+impl From<Node> for ModuleItem {
+    #[inline]
+    fn from(node: Node) -> Self {
+        Self::StatementListItem(node)
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    syntax::{
+        ast::node::{
+            declaration::{
+                export_decl::{ExportEntry, ReExportImportName},
+                import_decl::ImportName,
+            },
+            test_module_formatting,
+        },
+        Parser,
+    },
+    Context,
+};
+use boa_interner::Sym;
+
+#[test]
+fn module_item_list_formatting() {
+    test_module_formatting(
+        r#"
+        import { a } from "a";
+        let b = a + 1;
+        if (b) {
+            b = 2;
+        }
+        export { b };
+        "#,
+    );
+}
+
+#[test]
+fn module_requests() {
+    let mut context = Context::default();
+    let module = Parser::new(
+        br#"
+        import a from "a";
+        export * from "b";
+        import { c } from "a";
+        export { d } from "c";
+        "#
+        .as_ref(),
+    )
+    .parse_module(&mut context)
+    .expect("parsing failed");
+
+    let requests: Vec<_> = module
+        .requests()
+        .into_iter()
+        .map(|request| context.interner().resolve_expect(request))
+        .collect();
+    assert_eq!(requests, ["a", "b", "c"]);
+}
+
+#[test]
+fn module_entries() {
+    let mut context = Context::default();
+    let module = Parser::new(
+        br#"
+        import a, * as b from "a";
+        export { a as c };
+        export * from "b";
+        export { d as e } from "c";
+        export default 1;
+        "#
+        .as_ref(),
+    )
+    .parse_module(&mut context)
+    .expect("parsing failed");
+    let interner = context.interner();
+    let sym = |name| interner.get(name).expect("name must be interned");
+
+    let imports = module.import_entries();
+    assert_eq!(imports.len(), 2);
+    assert_eq!(imports[0].import_name(), ImportName::Name(Sym::DEFAULT));
+    assert_eq!(imports[0].local_name(), sym("a"));
+    assert_eq!(imports[1].import_name(), ImportName::Namespace);
+    assert_eq!(imports[1].local_name(), sym("b"));
+
+    let exports = module.export_entries();
+    assert_eq!(exports.len(), 4);
+    assert!(matches!(
+        exports[0],
+        ExportEntry::Ordinary(entry) if entry.local_name() == sym("a") && entry.export_name() == sym("c")
+    ));
+    assert!(matches!(
+        exports[1],
+        ExportEntry::StarReExport { module_request } if module_request == sym("b")
+    ));
+    assert!(matches!(
+        exports[2],
+        ExportEntry::ReExport(entry)
+            if entry.import_name() == ReExportImportName::Name(sym("d"))
+                && entry.export_name() == sym("e")
+    ));
+    assert!(matches!(
+        exports[3],
+        ExportEntry::Ordinary(entry) if entry.local_name() == Sym::DEFAULT && entry.export_name() == Sym::DEFAULT
+    ));
+}
@@ -1,4 +1,7 @@
-use crate::syntax::ast::node::{Call, Node};
+use crate::syntax::ast::{
+    node::{Call, Node},
+    Span,
+};
 use boa_interner::{Interner, ToInternedString};
 
 #[cfg(feature = "deser")]
@@ -39,6 +42,12 @@ impl New {
         self.call.args()
     }
 
+    /// Gets the span of the source text of the expression, if the node was created by the parser.
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.call.span()
+    }
+
     /// Returns the inner call
     pub(crate) fn call(&self) -> &Call {
         &self.call
@@ -1,6 +1,9 @@
 //! Statement list node.
 
-use crate::syntax::ast::node::{Declaration, Node};
+use crate::syntax::ast::{
+    node::{Declaration, Node},
+    Span,
+};
 use boa_interner::{Interner, Sym, ToInternedString};
 
 use rustc_hash::FxHashSet;
@@ -19,19 +22,55 @@ mod tests;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct StatementList {
     items: Box<[Node]>,
+    #[cfg_attr(feature = "deser", serde(default))]
+    spans: Box<[Span]>,
     strict: bool,
 }
 
 impl StatementList {
+    /// Creates a new statement list, where each item is located at the span with the same index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of spans is not the same as the number of items.
+    #[inline]
+    pub fn with_spans<I, S>(items: I, spans: S) -> Self
+    where
+        I: Into<Box<[Node]>>,
+        S: Into<Box<[Span]>>,
+    {
+        let items = items.into();
+        let spans = spans.into();
+        assert_eq!(
+            items.len(),
+            spans.len(),
+            "every statement must have exactly one span"
+        );
+
+        Self {
+            items,
+            spans,
+            strict: false,
+        }
+    }
+
     /// Gets the list of items.
     #[inline]
     pub fn items(&self) -> &[Node] {
         &self.items
     }
 
+    /// Gets the source text spans of the items.
+    ///
+    /// This is empty if the statement list was not created by the parser.
+    #[inline]
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
     /// Get the strict mode.
     #[inline]
     pub fn strict(&self) -> bool {
@@ -180,11 +219,20 @@ where
     fn from(stm: T) -> Self {
         Self {
             items: stm.into(),
+            spans: Box::default(),
             strict: false,
         }
     }
 }
 
+// Spans are not compared, since they only carry information about the source text that the
+// statements were parsed from.
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.strict == other.strict
+    }
+}
+
 impl ToInternedString for StatementList {
     fn to_interned_string(&self, interner: &Interner) -> String {
         self.to_indented_string(interner, 0)
@@ -96,6 +96,11 @@ impl Switch {
         self.default.as_ref().map(StatementList::items)
     }
 
+    /// Gets the default statement list, if any, including the spans of its statements.
+    pub(crate) fn default_statement_list(&self) -> Option<&StatementList> {
+        self.default.as_ref()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
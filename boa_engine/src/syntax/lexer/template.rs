@@ -80,9 +80,7 @@ impl TemplateString {
             }
         }
 
-        let str = buf.to_string_lossy();
-
-        Ok(interner.get_or_intern(&str))
+        Ok(interner.get_or_intern_utf16(&buf))
     }
 }
 
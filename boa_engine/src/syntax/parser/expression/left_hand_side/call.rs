@@ -14,7 +14,7 @@ use crate::syntax::{
             field::{GetConstField, GetField},
             Call, Node,
         },
-        Punctuator,
+        Punctuator, Span,
     },
     lexer::TokenKind,
     parser::{
@@ -66,9 +66,11 @@ where
         let token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
 
         let mut lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+            let start = token.span().start();
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Node::from(Call::new(self.first_member_expr, args))
+            let span = Span::new(start, cursor.last_token_end());
+            Node::from(Call::new(self.first_member_expr, args).with_span(span))
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(ParseError::expected(
@@ -81,21 +83,27 @@ where
 
         while let Some(tok) = cursor.peek(0, interner)? {
             let token = tok.clone();
+            let start = token.span().start();
             match token.kind() {
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = Node::from(Call::new(lhs, args));
+                    let span = Span::new(start, cursor.last_token_end());
+                    lhs = Node::from(Call::new(lhs, args).with_span(span));
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?; // We move the parser forward.
 
-                    match cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?.kind() {
+                    let name = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+                    let span = Span::new(start, name.span().end());
+                    match name.kind() {
                         TokenKind::Identifier(name) => {
-                            lhs = GetConstField::new(lhs, *name).into();
+                            lhs = GetConstField::new(lhs, *name).with_span(span).into();
                         }
                         TokenKind::Keyword((kw, _)) => {
-                            lhs = GetConstField::new(lhs, kw.to_sym(interner)).into();
+                            lhs = GetConstField::new(lhs, kw.to_sym(interner))
+                                .with_span(span)
+                                .into();
                         }
                         _ => {
                             return Err(ParseError::expected(
@@ -111,8 +119,10 @@ where
                     let _next = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?; // We move the parser.
                     let idx = Expression::new(None, true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    cursor.expect(Punctuator::CloseBracket, "call expression", interner)?;
-                    lhs = GetField::new(lhs, idx).into();
+                    let close =
+                        cursor.expect(Punctuator::CloseBracket, "call expression", interner)?;
+                    let span = Span::new(start, close.span().end());
+                    lhs = GetField::new(lhs, idx).with_span(span).into();
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
                     lhs = TaggedTemplateLiteral::new(
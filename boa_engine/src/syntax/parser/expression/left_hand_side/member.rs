@@ -12,7 +12,7 @@ use crate::syntax::{
             field::{get_private_field::GetPrivateField, GetConstField, GetField},
             Call, GetSuperField, New, Node,
         },
-        Keyword, Punctuator,
+        Keyword, Punctuator, Span,
     },
     lexer::TokenKind,
     parser::{
@@ -73,6 +73,7 @@ where
                 ));
             }
             TokenKind::Keyword((Keyword::New, false)) => {
+                let start = token.span().start();
                 let _next = cursor.next(interner).expect("new keyword disappeared");
                 let lhs = self.parse(cursor, interner)?;
                 let args = match cursor.peek(0, interner)? {
@@ -82,7 +83,8 @@ where
                     }
                     _ => Box::new([]),
                 };
-                let call_node = Call::new(lhs, args);
+                let span = Span::new(start, cursor.last_token_end());
+                let call_node = Call::new(lhs, args).with_span(span);
 
                 Node::from(New::from(call_node))
             }
@@ -140,6 +142,7 @@ where
         };
 
         while let Some(tok) = cursor.peek(0, interner)? {
+            let start = tok.span().start();
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor
@@ -147,26 +150,27 @@ where
                         .expect("dot punctuator token disappeared"); // We move the parser forward.
 
                     let token = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+                    let span = Span::new(start, token.span().end());
 
                     match token.kind() {
-                        TokenKind::Identifier(name) => lhs = GetConstField::new(lhs, *name).into(),
+                        TokenKind::Identifier(name) => {
+                            lhs = GetConstField::new(lhs, *name).with_span(span).into();
+                        }
                         TokenKind::Keyword((kw, _)) => {
-                            lhs = GetConstField::new(lhs, kw.to_sym(interner)).into();
+                            lhs = GetConstField::new(lhs, kw.to_sym(interner))
+                                .with_span(span)
+                                .into();
                         }
                         TokenKind::BooleanLiteral(bool) => {
-                            match bool {
-                                true => {
-                                    lhs = GetConstField::new(lhs, Keyword::True.to_sym(interner))
-                                        .into();
-                                }
-                                false => {
-                                    lhs = GetConstField::new(lhs, Keyword::False.to_sym(interner))
-                                        .into();
-                                }
-                            };
+                            let field = if *bool { Keyword::True } else { Keyword::False };
+                            lhs = GetConstField::new(lhs, field.to_sym(interner))
+                                .with_span(span)
+                                .into();
                         }
                         TokenKind::NullLiteral => {
-                            lhs = GetConstField::new(lhs, Keyword::Null.to_sym(interner)).into();
+                            lhs = GetConstField::new(lhs, Keyword::Null.to_sym(interner))
+                                .with_span(span)
+                                .into();
                         }
                         TokenKind::PrivateIdentifier(name) => {
                             cursor.push_used_private_identifier(*name, token.span().start())?;
@@ -188,8 +192,10 @@ where
                         .expect("open bracket punctuator token disappeared"); // We move the parser forward.
                     let idx = Expression::new(None, true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    cursor.expect(Punctuator::CloseBracket, "member expression", interner)?;
-                    lhs = GetField::new(lhs, idx).into();
+                    let close =
+                        cursor.expect(Punctuator::CloseBracket, "member expression", interner)?;
+                    let span = Span::new(start, close.span().end());
+                    lhs = GetField::new(lhs, idx).with_span(span).into();
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
                     lhs = TaggedTemplateLiteral::new(
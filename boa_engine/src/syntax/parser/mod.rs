@@ -14,7 +14,7 @@ mod tests;
 use crate::{
     syntax::{
         ast::{
-            node::{ContainsSymbol, FormalParameterList, StatementList},
+            node::{self, ContainsSymbol, FormalParameterList, ModuleItemList, StatementList},
             Position,
         },
         lexer::TokenKind,
@@ -23,7 +23,7 @@ use crate::{
             function::{FormalParameters, FunctionStatementList},
         },
     },
-    Context,
+    Context, JsString,
 };
 use boa_interner::{Interner, Sym};
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -138,6 +138,17 @@ impl<R> Parser<R> {
         Script::new(false).parse(&mut self.cursor, context)
     }
 
+    /// Parse the full input as an [ECMAScript Module][spec] into the boa AST representation.
+    /// The resulting `ModuleItemList` can be used to create a [`Module`][crate::module::Module].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Module
+    pub fn parse_module(&mut self, context: &mut Context) -> Result<ModuleItemList, ParseError>
+    where
+        R: Read,
+    {
+        Module::parse(&mut self.cursor, context)
+    }
+
     pub(crate) fn parse_eval(
         &mut self,
         direct: bool,
@@ -147,7 +158,7 @@ impl<R> Parser<R> {
         R: Read,
     {
         let (in_method, in_derived_constructor) = if let Some(function_env) = context
-            .realm
+            .vm
             .environments
             .get_this_environment()
             .as_function_slots()
@@ -299,12 +310,12 @@ impl Script {
                         ));
                     }
                     if !is_function_declaration {
-                        let name_str = context.interner().resolve_expect(*name);
+                        let name_str = JsString::from(context.interner().resolve_expect(*name));
                         let desc = context
-                            .realm
-                            .global_property_map
-                            .string_property_map()
-                            .get(name_str);
+                            .global_object()
+                            .borrow()
+                            .properties()
+                            .get(&name_str.into());
                         let non_configurable_binding_exists = match desc {
                             Some(desc) => !matches!(desc.configurable(), Some(true)),
                             None => false,
@@ -383,3 +394,116 @@ where
         Ok(body)
     }
 }
+
+/// Parses a full module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub struct Module;
+
+impl Module {
+    fn parse<R: Read>(
+        cursor: &mut Cursor<R>,
+        context: &mut Context,
+    ) -> Result<ModuleItemList, ParseError> {
+        // Module code is always strict mode code.
+        cursor.set_strict_mode(true);
+
+        match cursor.peek(0, context.interner_mut())? {
+            Some(_) => ModuleBody.parse(cursor, context.interner_mut()),
+            None => Ok(ModuleItemList::default()),
+        }
+    }
+}
+
+/// Parses a module body.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleBody
+#[derive(Debug, Clone, Copy)]
+pub struct ModuleBody;
+
+impl<R> TokenParser<R> for ModuleBody
+where
+    R: Read,
+{
+    type Output = ModuleItemList;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let body = self::statement::ModuleItemList.parse(cursor, interner)?;
+
+        // It is a Syntax Error if the ExportedNames of ModuleItemList contains any duplicate entries.
+        let mut exported_names = FxHashSet::default();
+        for name in body.exported_names() {
+            if !exported_names.insert(name) {
+                return Err(ParseError::general(
+                    "duplicate export name",
+                    Position::new(1, 1),
+                ));
+            }
+        }
+
+        // It is a Syntax Error if the LexicallyDeclaredNames of ModuleItemList contains any duplicate entries.
+        // It is a Syntax Error if any element of the LexicallyDeclaredNames of ModuleItemList also occurs in the VarDeclaredNames of ModuleItemList.
+        let mut var_declared_names = FxHashSet::default();
+        body.var_declared_names(&mut var_declared_names);
+        let mut lexically_declared_names = FxHashSet::default();
+        for name in body.lexically_declared_names() {
+            if !lexically_declared_names.insert(name) {
+                return Err(ParseError::general(
+                    "lexical name declared multiple times",
+                    Position::new(1, 1),
+                ));
+            }
+            if var_declared_names.contains(&name) {
+                return Err(ParseError::general(
+                    "lexical name declared in var names",
+                    Position::new(1, 1),
+                ));
+            }
+        }
+
+        // It is a Syntax Error if any element of the ExportedBindings of ModuleItemList does not also occur in either
+        // the VarDeclaredNames of ModuleItemList, or the LexicallyDeclaredNames of ModuleItemList.
+        for name in body.exported_bindings() {
+            if !var_declared_names.contains(&name) && !lexically_declared_names.contains(&name) {
+                return Err(ParseError::general(
+                    "exported binding is not declared in the module",
+                    Position::new(1, 1),
+                ));
+            }
+        }
+
+        // It is a Syntax Error if ModuleItemList Contains super.
+        for item in body.items() {
+            let node = match item {
+                node::ModuleItem::StatementListItem(node)
+                | node::ModuleItem::ExportDeclaration(
+                    node::ExportDeclaration::Declaration(node)
+                    | node::ExportDeclaration::DefaultHoistableDeclaration(node)
+                    | node::ExportDeclaration::DefaultAssignmentExpression(node),
+                ) => node,
+                _ => continue,
+            };
+            if node.contains(ContainsSymbol::SuperCall)
+                || node.contains(ContainsSymbol::SuperProperty)
+            {
+                return Err(ParseError::general(
+                    "invalid super usage",
+                    Position::new(1, 1),
+                ));
+            }
+        }
+
+        Ok(body)
+    }
+}
@@ -0,0 +1,236 @@
+//! Export declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+//! [spec]: https://tc39.es/ecma262/#sec-exports
+
+use super::{
+    hoistable::HoistableDeclaration,
+    import::{expect_identifier_name, FromClause, ModuleExportName},
+    Declaration,
+};
+use crate::syntax::{
+    ast::{
+        node::{
+            declaration::export_decl::{ExportSpecifier, ReExportKind},
+            ExportDeclaration as ExportDeclarationNode, Node,
+        },
+        Keyword, Position, Punctuator,
+    },
+    lexer::{Token, TokenKind},
+    parser::{
+        expression::AssignmentExpression, statement::variable::VariableStatement, Cursor,
+        ParseError, TokenParser,
+    },
+};
+use boa_interner::{Interner, Sym};
+use boa_profiler::Profiler;
+use std::io::Read;
+
+/// Parses an export declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ExportDeclaration;
+
+impl<R> TokenParser<R> for ExportDeclaration
+where
+    R: Read,
+{
+    type Output = ExportDeclarationNode;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("ExportDeclaration", "Parsing");
+
+        cursor.expect((Keyword::Export, false), "export declaration", interner)?;
+
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        let export = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next(interner).expect("token disappeared");
+                let name = if cursor
+                    .peek(0, interner)?
+                    .map_or(false, |tok| *tok.kind() == TokenKind::Identifier(Sym::AS))
+                {
+                    cursor.next(interner).expect("token disappeared");
+                    Some(ModuleExportName.parse(cursor, interner)?.0)
+                } else {
+                    None
+                };
+                let specifier = FromClause::new("export declaration").parse(cursor, interner)?;
+                cursor.expect_semicolon("export declaration", interner)?;
+
+                ExportDeclarationNode::ReExport {
+                    kind: ReExportKind::Namespaced { name },
+                    specifier,
+                }
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let names = NamedExports.parse(cursor, interner)?;
+
+                let export = if cursor
+                    .peek(0, interner)?
+                    .map_or(false, |tok| *tok.kind() == TokenKind::Identifier(Sym::FROM))
+                {
+                    let specifier =
+                        FromClause::new("export declaration").parse(cursor, interner)?;
+                    ExportDeclarationNode::ReExport {
+                        kind: ReExportKind::Named {
+                            names: names.specifiers.into_boxed_slice(),
+                        },
+                        specifier,
+                    }
+                } else {
+                    // Early Error: It is a Syntax Error if ReferencedBindings of NamedExports
+                    // contains any StringLiterals or reserved words.
+                    if let Some(position) = names.invalid_local {
+                        return Err(ParseError::general(
+                            "invalid local binding in export list",
+                            position,
+                        ));
+                    }
+                    ExportDeclarationNode::List(names.specifiers.into_boxed_slice())
+                };
+                cursor.expect_semicolon("export declaration", interner)?;
+
+                export
+            }
+            TokenKind::Keyword((Keyword::Var, false)) => VariableStatement::new(false, false)
+                .parse(cursor, interner)
+                .map(ExportDeclarationNode::from)?,
+            TokenKind::Keyword((Keyword::Default, false)) => {
+                cursor.next(interner).expect("token disappeared");
+
+                let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+                let is_hoistable = match tok.kind() {
+                    TokenKind::Keyword((Keyword::Function | Keyword::Class, _)) => true,
+                    TokenKind::Keyword((Keyword::Async, _)) => matches!(
+                        cursor.peek(1, interner)?.map(Token::kind),
+                        Some(TokenKind::Keyword((Keyword::Function, _)))
+                    ),
+                    _ => false,
+                };
+
+                if is_hoistable {
+                    match HoistableDeclaration::new(false, false, true).parse(cursor, interner)? {
+                        Node::ClassDecl(class) => {
+                            ExportDeclarationNode::DefaultClassDeclaration(class)
+                        }
+                        node => ExportDeclarationNode::DefaultHoistableDeclaration(node),
+                    }
+                } else {
+                    let expr = AssignmentExpression::new(Some(Sym::DEFAULT), true, false, false)
+                        .parse(cursor, interner)?;
+                    cursor.expect_semicolon("export declaration", interner)?;
+                    ExportDeclarationNode::DefaultAssignmentExpression(expr)
+                }
+            }
+            TokenKind::Keyword((
+                Keyword::Function | Keyword::Async | Keyword::Class | Keyword::Const | Keyword::Let,
+                false,
+            )) => Declaration::new(false, false, true)
+                .parse(cursor, interner)
+                .map(ExportDeclarationNode::Declaration)?,
+            _ => {
+                return Err(ParseError::unexpected(
+                    tok.to_string(interner),
+                    tok.span(),
+                    "export declaration",
+                ))
+            }
+        };
+
+        Ok(export)
+    }
+}
+
+/// The output of the [`NamedExports`] parser.
+#[derive(Debug)]
+struct NamedExportsList {
+    specifiers: Vec<ExportSpecifier>,
+    /// Position of the first local name that cannot reference a binding, if any.
+    invalid_local: Option<Position>,
+}
+
+/// Parses a list of named exports.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NamedExports
+#[derive(Debug, Clone, Copy)]
+struct NamedExports;
+
+impl<R> TokenParser<R> for NamedExports
+where
+    R: Read,
+{
+    type Output = NamedExportsList;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("NamedExports", "Parsing");
+
+        cursor.expect(Punctuator::OpenBlock, "export declaration", interner)?;
+
+        let mut specifiers = Vec::new();
+        let mut invalid_local = None;
+        loop {
+            let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+            if let TokenKind::Punctuator(Punctuator::CloseBlock) = tok.kind() {
+                cursor.next(interner).expect("token disappeared");
+                break;
+            }
+
+            let position = tok.span().start();
+            let (private_name, is_invalid_local) = ModuleExportName.parse(cursor, interner)?;
+            if is_invalid_local && invalid_local.is_none() {
+                invalid_local = Some(position);
+            }
+
+            let alias = if cursor
+                .peek(0, interner)?
+                .map_or(false, |tok| *tok.kind() == TokenKind::Identifier(Sym::AS))
+            {
+                expect_identifier_name(cursor, Sym::AS, "export declaration", interner)?;
+                ModuleExportName.parse(cursor, interner)?.0
+            } else {
+                private_name
+            };
+            specifiers.push(ExportSpecifier::new(alias, private_name));
+
+            let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Comma) => {}
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                _ => {
+                    return Err(ParseError::expected(
+                        [",".to_owned(), "}".to_owned()],
+                        tok.to_string(interner),
+                        tok.span(),
+                        "export declaration",
+                    ))
+                }
+            }
+        }
+
+        Ok(NamedExportsList {
+            specifiers,
+            invalid_local,
+        })
+    }
+}
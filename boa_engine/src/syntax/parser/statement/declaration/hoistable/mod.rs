@@ -105,13 +105,17 @@ where
                     .parse(cursor, interner)
                     .map(Node::from)
                 } else {
-                    AsyncFunctionDeclaration::new(self.allow_yield, self.allow_await, false)
-                        .parse(cursor, interner)
-                        .map(Node::from)
+                    AsyncFunctionDeclaration::new(
+                        self.allow_yield,
+                        self.allow_await,
+                        self.is_default,
+                    )
+                    .parse(cursor, interner)
+                    .map(Node::from)
                 }
             }
             TokenKind::Keyword((Keyword::Class, false)) => {
-                ClassDeclaration::new(self.allow_yield, self.allow_await, false)
+                ClassDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
                     .parse(cursor, interner)
                     .map(Node::from)
             }
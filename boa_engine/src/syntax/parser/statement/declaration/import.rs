@@ -0,0 +1,306 @@
+//! Import declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+//! [spec]: https://tc39.es/ecma262/#sec-imports
+
+use crate::syntax::{
+    ast::{
+        node::{
+            declaration::import_decl::{ImportKind, ImportSpecifier},
+            Identifier, ImportDeclaration as ImportDeclarationNode,
+        },
+        Keyword, Punctuator,
+    },
+    lexer::{Token, TokenKind},
+    parser::{expression::BindingIdentifier, Cursor, ParseError, TokenParser},
+};
+use boa_interner::{Interner, Sym};
+use boa_profiler::Profiler;
+use std::io::Read;
+
+/// Parses an import declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ImportDeclaration;
+
+impl<R> TokenParser<R> for ImportDeclaration
+where
+    R: Read,
+{
+    type Output = ImportDeclarationNode;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("ImportDeclaration", "Parsing");
+
+        cursor.expect((Keyword::Import, false), "import declaration", interner)?;
+
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        // `import ModuleSpecifier ;`
+        if let TokenKind::StringLiteral(specifier) = *tok.kind() {
+            cursor
+                .next(interner)
+                .expect("string literal token disappeared");
+            cursor.expect_semicolon("import declaration", interner)?;
+            return Ok(ImportDeclarationNode::new(
+                None,
+                ImportKind::DefaultOrUnnamed,
+                specifier,
+            ));
+        }
+
+        let default =
+            if let TokenKind::Punctuator(Punctuator::Mul | Punctuator::OpenBlock) = tok.kind() {
+                None
+            } else {
+                let binding = BindingIdentifier::new(false, true).parse(cursor, interner)?;
+                Some(Identifier::new(binding))
+            };
+
+        let kind = if default.is_none() || cursor.next_if(Punctuator::Comma, interner)?.is_some() {
+            let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Mul) => {
+                    cursor.next(interner).expect("token disappeared");
+                    expect_identifier_name(cursor, Sym::AS, "import declaration", interner)?;
+                    let binding = BindingIdentifier::new(false, true).parse(cursor, interner)?;
+                    ImportKind::Namespaced {
+                        binding: Identifier::new(binding),
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBlock) => ImportKind::Named {
+                    names: NamedImports.parse(cursor, interner)?,
+                },
+                _ => {
+                    return Err(ParseError::expected(
+                        ["*".to_owned(), "{".to_owned()],
+                        tok.to_string(interner),
+                        tok.span(),
+                        "import declaration",
+                    ))
+                }
+            }
+        } else {
+            ImportKind::DefaultOrUnnamed
+        };
+
+        let specifier = FromClause::new("import declaration").parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
+
+        Ok(ImportDeclarationNode::new(default, kind, specifier))
+    }
+}
+
+/// Parses a list of named imports.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NamedImports
+#[derive(Debug, Clone, Copy)]
+struct NamedImports;
+
+impl<R> TokenParser<R> for NamedImports
+where
+    R: Read,
+{
+    type Output = Box<[ImportSpecifier]>;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("NamedImports", "Parsing");
+
+        cursor.expect(Punctuator::OpenBlock, "import declaration", interner)?;
+
+        let mut list = Vec::new();
+        loop {
+            let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+            if let TokenKind::Punctuator(Punctuator::CloseBlock) = tok.kind() {
+                cursor.next(interner).expect("token disappeared");
+                break;
+            }
+
+            list.push(ImportSpecifierParser.parse(cursor, interner)?);
+
+            let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Comma) => {}
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                _ => {
+                    return Err(ParseError::expected(
+                        [",".to_owned(), "}".to_owned()],
+                        tok.to_string(interner),
+                        tok.span(),
+                        "import declaration",
+                    ))
+                }
+            }
+        }
+
+        Ok(list.into_boxed_slice())
+    }
+}
+
+/// Parses a single import specifier.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[derive(Debug, Clone, Copy)]
+struct ImportSpecifierParser;
+
+impl<R> TokenParser<R> for ImportSpecifierParser
+where
+    R: Read,
+{
+    type Output = ImportSpecifier;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("ImportSpecifier", "Parsing");
+
+        let has_alias = matches!(
+            cursor.peek(1, interner)?.map(Token::kind),
+            Some(TokenKind::Identifier(Sym::AS))
+        );
+
+        if !has_alias {
+            // `ImportedBinding`: the export name is also the local binding.
+            let binding = BindingIdentifier::new(false, true).parse(cursor, interner)?;
+            return Ok(ImportSpecifier::new(Identifier::new(binding), binding));
+        }
+
+        let export_name = ModuleExportName.parse(cursor, interner)?.0;
+        expect_identifier_name(cursor, Sym::AS, "import declaration", interner)?;
+        let binding = BindingIdentifier::new(false, true).parse(cursor, interner)?;
+
+        Ok(ImportSpecifier::new(Identifier::new(binding), export_name))
+    }
+}
+
+/// Parses a `from` clause, returning the module specifier.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-FromClause
+#[derive(Debug, Clone, Copy)]
+pub(super) struct FromClause {
+    context: &'static str,
+}
+
+impl FromClause {
+    /// Creates a new `FromClause` parser.
+    pub(super) fn new(context: &'static str) -> Self {
+        Self { context }
+    }
+}
+
+impl<R> TokenParser<R> for FromClause
+where
+    R: Read,
+{
+    type Output = Sym;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("FromClause", "Parsing");
+
+        expect_identifier_name(cursor, Sym::FROM, self.context, interner)?;
+
+        let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::StringLiteral(specifier) = tok.kind() {
+            Ok(*specifier)
+        } else {
+            Err(ParseError::expected(
+                ["string literal".to_owned()],
+                tok.to_string(interner),
+                tok.span(),
+                self.context,
+            ))
+        }
+    }
+}
+
+/// Parses a module export name, which can be an identifier name or a string literal.
+///
+/// The second value of the output is `true` if the name was written as a string literal or as
+/// a reserved word, which means it cannot be used to reference a local binding.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleExportName
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ModuleExportName;
+
+impl<R> TokenParser<R> for ModuleExportName
+where
+    R: Read,
+{
+    type Output = (Sym, bool);
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::Identifier(name) => Ok((*name, false)),
+            TokenKind::StringLiteral(name) => Ok((*name, true)),
+            TokenKind::Keyword((keyword, _)) => Ok((keyword.to_sym(interner), true)),
+            TokenKind::BooleanLiteral(true) => Ok((interner.get_or_intern_static("true"), true)),
+            TokenKind::BooleanLiteral(false) => Ok((interner.get_or_intern_static("false"), true)),
+            TokenKind::NullLiteral => Ok((interner.get_or_intern_static("null"), true)),
+            _ => Err(ParseError::expected(
+                ["identifier".to_owned(), "string literal".to_owned()],
+                tok.to_string(interner),
+                tok.span(),
+                "module export name",
+            )),
+        }
+    }
+}
+
+/// Consumes the next token if it is the contextual keyword `name` (e.g. `as` or `from`).
+pub(super) fn expect_identifier_name<R: Read>(
+    cursor: &mut Cursor<R>,
+    name: Sym,
+    context: &'static str,
+    interner: &mut Interner,
+) -> Result<(), ParseError> {
+    let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+    if *tok.kind() == TokenKind::Identifier(name) {
+        Ok(())
+    } else {
+        Err(ParseError::expected(
+            [interner.resolve_expect(name).to_owned()],
+            tok.to_string(interner),
+            tok.span(),
+            context,
+        ))
+    }
+}
@@ -7,7 +7,9 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements#Declarations
 //! [spec]:https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 
+mod export;
 pub(in crate::syntax::parser) mod hoistable;
+mod import;
 mod lexical;
 #[cfg(test)]
 mod tests;
@@ -22,7 +24,9 @@ use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
 
-pub(in crate::syntax::parser) use hoistable::class_decl::ClassTail;
+pub(in crate::syntax::parser) use self::{
+    export::ExportDeclaration, hoistable::class_decl::ClassTail, import::ImportDeclaration,
+};
 pub(in crate::syntax) use hoistable::class_decl::PrivateElement;
 
 /// Parses a declaration.
@@ -48,7 +48,7 @@ use crate::syntax::{
                 DeclarationPatternArray, DeclarationPatternObject,
             },
         },
-        Keyword, Node, Punctuator,
+        Keyword, Node, Punctuator, Span,
     },
     lexer::{Error as LexError, InputElement, Token, TokenKind},
     parser::expression::{await_expr::AwaitExpression, BindingIdentifier, Initializer},
@@ -59,6 +59,7 @@ use std::{io::Read, vec};
 
 pub(in crate::syntax::parser) use declaration::ClassTail;
 pub(in crate::syntax) use declaration::PrivateElement;
+use declaration::{ExportDeclaration, ImportDeclaration};
 
 /// Statement parsing.
 ///
@@ -289,24 +290,25 @@ where
         let mut items = Vec::new();
 
         loop {
-            match cursor.peek(0, interner)? {
+            let start = match cursor.peek(0, interner)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
                 None => break,
-                _ => {}
-            }
+                Some(token) => token.span().start(),
+            };
 
             let item =
                 StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor, interner)?;
-            items.push(item);
+            items.push((item, Span::new(start, cursor.last_token_end())));
 
             // move the cursor forward for any consecutive semicolon.
             while cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {}
         }
 
-        items.sort_by(Node::hoistable_order);
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let (items, spans): (Vec<_>, Vec<_>) = items.into_iter().unzip();
 
-        Ok(items.into())
+        Ok(node::StatementList::with_spans(items, spans))
     }
 }
 
@@ -370,6 +372,86 @@ where
     }
 }
 
+/// Reads a list of module items.
+///
+/// Unlike a [`StatementList`], the items are kept in source text order, since hoisting of
+/// module level declarations happens when the module environment is initialized.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ModuleItemList;
+
+impl<R> TokenParser<R> for ModuleItemList
+where
+    R: Read,
+{
+    type Output = node::ModuleItemList;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("ModuleItemList", "Parsing");
+        let mut items = Vec::new();
+        let mut spans = Vec::new();
+
+        while let Some(token) = cursor.peek(0, interner)? {
+            let start = token.span().start();
+            items.push(ModuleItem.parse(cursor, interner)?);
+            spans.push(Span::new(start, cursor.last_token_end()));
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {}
+        }
+
+        Ok(node::ModuleItemList::with_spans(items, spans))
+    }
+}
+
+/// Module item parsing.
+///
+/// A module item can either be an import declaration, an export declaration or a statement
+/// list item.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[derive(Debug, Clone, Copy)]
+struct ModuleItem;
+
+impl<R> TokenParser<R> for ModuleItem
+where
+    R: Read,
+{
+    type Output = node::ModuleItem;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = Profiler::global().start_event("ModuleItem", "Parsing");
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        match *tok.kind() {
+            TokenKind::Keyword((Keyword::Import, _)) => ImportDeclaration
+                .parse(cursor, interner)
+                .map(node::ModuleItem::from),
+            TokenKind::Keyword((Keyword::Export, _)) => ExportDeclaration
+                .parse(cursor, interner)
+                .map(node::ModuleItem::from),
+            _ => StatementListItem::new(false, false, false)
+                .parse(cursor, interner)
+                .map(node::ModuleItem::from),
+        }
+    }
+}
+
 /// `ObjectBindingPattern` pattern parsing.
 ///
 /// More information:
@@ -11,7 +11,7 @@ use crate::{
             StatementList, UnaryOp,
         },
         op::{self, CompOp, LogOp, NumOp},
-        Const,
+        Const, Position, Span,
     },
     Context,
 };
@@ -40,6 +40,15 @@ pub(super) fn check_invalid(js: &str) {
     assert!(Parser::new(js.as_bytes()).parse_all(&mut context).is_err());
 }
 
+/// Checks that the given javascript string creates a parse error when parsed as a module.
+#[track_caller]
+pub(super) fn check_invalid_module(js: &str) {
+    let mut context = Context::default();
+    assert!(Parser::new(js.as_bytes())
+        .parse_module(&mut context)
+        .is_err());
+}
+
 /// Should be parsed as `new Class().method()` instead of `new (Class().method())`
 #[test]
 fn check_construct_call_precedence() {
@@ -482,3 +491,65 @@ fn hashbang_use_strict_with_with_statement() {
 fn hashbang_comment() {
     check_parser(r"#!Comment Here", vec![], Interner::default());
 }
+
+#[test]
+fn module_is_strict() {
+    check_invalid_module("with({}) {}");
+    check_invalid_module("var let = 1;");
+}
+
+#[test]
+fn import_export_only_in_module() {
+    check_invalid(r#"import a from "a";"#);
+    check_invalid("export const a = 1;");
+}
+
+#[test]
+fn module_early_errors() {
+    // Duplicate exported names.
+    check_invalid_module("export const a = 1; export { a };");
+    check_invalid_module("export default 1; export default 2;");
+    // Duplicate lexically declared names.
+    check_invalid_module(r#"import a from "a"; let a;"#);
+    // Lexically declared names clashing with var declared names.
+    check_invalid_module("let a; var a;");
+    // Exported bindings must be declared.
+    check_invalid_module("export { a };");
+    // Exported bindings cannot be string literals.
+    check_invalid_module(r#"export { "a" };"#);
+    // `import` and `export` are only allowed at the top level.
+    check_invalid_module(r#"{ import a from "a"; }"#);
+    check_invalid_module("function f() { export const a = 1; }");
+}
+
+#[test]
+fn statement_spans() {
+    let mut context = Context::default();
+    let list = Parser::new("let a = 1;\n  if (a) {\n    a++;\n  }\nfunction f() {}".as_bytes())
+        .parse_all(&mut context)
+        .expect("failed to parse");
+
+    let span = |start: (u32, u32), end: (u32, u32)| {
+        Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    };
+
+    // Hoisted declarations are moved to the front together with their spans.
+    assert!(matches!(list.items()[0], Node::FunctionDecl(_)));
+    assert_eq!(
+        list.spans(),
+        [
+            span((5, 1), (5, 16)),
+            span((1, 1), (1, 10)),
+            span((2, 3), (4, 4)),
+        ]
+    );
+
+    let block = match &list.items()[2] {
+        Node::If(if_node) => match if_node.body() {
+            Node::Block(block) => block.statement_list().clone(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(block.spans(), [span((3, 5), (3, 10))]);
+}
@@ -1,6 +1,6 @@
 use crate::{
-    builtins::Number, check_output, exec, forward, forward_val, value::IntegerOrInfinity, Context,
-    JsValue, TestAction,
+    builtins::Number, check_output, error::JsNativeErrorKind, exec, forward, forward_val,
+    value::IntegerOrInfinity, Context, JsValue, TestAction,
 };
 
 #[test]
@@ -1235,11 +1235,13 @@ fn to_object() {
     assert!(JsValue::undefined()
         .to_object(&mut context)
         .unwrap_err()
-        .is_object());
+        .as_native()
+        .map_or(false, |error| error.kind() == &JsNativeErrorKind::Type));
     assert!(JsValue::null()
         .to_object(&mut context)
         .unwrap_err()
-        .is_object());
+        .as_native()
+        .map_or(false, |error| error.kind() == &JsNativeErrorKind::Type));
 }
 
 #[test]
@@ -1632,3 +1634,62 @@ fn test_empty_statement() {
     "#;
     assert_eq!(&exec(src), "10");
 }
+
+#[test]
+fn functions_run_in_their_own_realm() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var name = 'main'; function getName() { return name; }",
+    );
+
+    let realm = context.create_realm();
+    let main = context.enter_realm(realm.clone());
+    forward(
+        &mut context,
+        "var name = 'tenant'; function makeArray() { return []; }",
+    );
+    let make_array = context
+        .global_object()
+        .clone()
+        .get("makeArray", &mut context)
+        .unwrap();
+
+    context.enter_realm(main);
+    context
+        .global_object()
+        .clone()
+        .set("makeArray", make_array, true, &mut context)
+        .unwrap();
+
+    // Functions of another realm see the globals and intrinsics of that realm.
+    assert_eq!(forward(&mut context, "getName()"), "\"main\"");
+    assert_eq!(
+        forward(&mut context, "makeArray() instanceof Array"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Array.isArray(makeArray())"), "true");
+    assert_eq!(forward(&mut context, "name"), "\"main\"");
+
+    // Constructors of another realm fall back to the intrinsics of their own realm.
+    let main = context.enter_realm(realm);
+    forward(&mut context, "function Ctor() {} Ctor.prototype = null;");
+    let ctor = context
+        .global_object()
+        .clone()
+        .get("Ctor", &mut context)
+        .unwrap();
+    context.enter_realm(main);
+    context
+        .global_object()
+        .clone()
+        .set("Ctor", ctor, true, &mut context)
+        .unwrap();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(new Ctor()) === Object.prototype"
+        ),
+        "false"
+    );
+}
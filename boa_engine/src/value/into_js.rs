@@ -0,0 +1,126 @@
+//! This module implements the [`IntoJs`] trait, to convert Rust types into JavaScript values.
+
+use super::JsValue;
+use crate::{
+    builtins::Array,
+    object::{JsArray, JsObject},
+    Context, JsBigInt, JsString,
+};
+use std::collections::HashMap;
+
+/// Conversion from a Rust type to a [`JsValue`].
+///
+/// This is the counterpart of [`TryFromJs`](super::TryFromJs). Unlike [`Into<JsValue>`], the
+/// conversion has access to the [`Context`], so collections can be converted to arrays and
+/// objects.
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{value::IntoJs, Context, JsValue};
+/// use std::collections::HashMap;
+///
+/// let mut context = Context::default();
+///
+/// let scores = HashMap::from([("alice".to_owned(), vec![10, 7]), ("bob".to_owned(), vec![3])]);
+/// let value = scores.into_js(&mut context);
+/// context.register_global_property("scores", value, Default::default());
+///
+/// assert_eq!(context.eval("scores.alice[0] + scores.bob[0]").unwrap(), JsValue::new(13));
+/// ```
+pub trait IntoJs {
+    /// Converts `self` to a [`JsValue`].
+    fn into_js(self, context: &mut Context) -> JsValue;
+}
+
+macro_rules! impl_into_js_with_from {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoJs for $ty {
+                #[inline]
+                fn into_js(self, _: &mut Context) -> JsValue {
+                    JsValue::from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_js_with_from!(
+    JsValue, bool, char, i8, u8, i16, u16, i32, u32, i64, u64, usize, f32, f64, JsString, String,
+    &str, JsBigInt, JsObject, JsArray
+);
+
+impl IntoJs for () {
+    /// Converts `()` to `undefined`.
+    #[inline]
+    fn into_js(self, _: &mut Context) -> JsValue {
+        JsValue::undefined()
+    }
+}
+
+impl<T> IntoJs for Option<T>
+where
+    T: IntoJs,
+{
+    /// Converts `None` to `undefined`.
+    #[inline]
+    fn into_js(self, context: &mut Context) -> JsValue {
+        self.map_or_else(JsValue::undefined, |value| value.into_js(context))
+    }
+}
+
+impl<T> IntoJs for Vec<T>
+where
+    T: IntoJs,
+{
+    /// Converts the `Vec` to an array, converting each element.
+    fn into_js(self, context: &mut Context) -> JsValue {
+        let elements: Vec<_> = self
+            .into_iter()
+            .map(|element| element.into_js(context))
+            .collect();
+        Array::create_array_from_list(elements, context).into()
+    }
+}
+
+impl<T, S> IntoJs for HashMap<String, T, S>
+where
+    T: IntoJs,
+{
+    /// Converts the `HashMap` to an ordinary object, with a property for every entry.
+    fn into_js(self, context: &mut Context) -> JsValue {
+        let object = context.construct_object();
+        for (key, value) in self {
+            let value = value.into_js(context);
+            object
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining a property of a new ordinary object cannot fail");
+        }
+        object.into()
+    }
+}
+
+macro_rules! impl_into_js_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name),+> IntoJs for ($($name,)+)
+        where
+            $($name: IntoJs,)+
+        {
+            /// Converts the tuple to an array, converting each element.
+            #[allow(non_snake_case)]
+            fn into_js(self, context: &mut Context) -> JsValue {
+                let ($($name,)+) = self;
+                let elements = [$($name.into_js(context)),+];
+                Array::create_array_from_list(elements, context).into()
+            }
+        }
+    };
+}
+
+impl_into_js_for_tuple!(A);
+impl_into_js_for_tuple!(A, B);
+impl_into_js_for_tuple!(A, B, C);
+impl_into_js_for_tuple!(A, B, C, D);
+impl_into_js_for_tuple!(A, B, C, D, E);
+impl_into_js_for_tuple!(A, B, C, D, E, F);
@@ -10,22 +10,26 @@ use crate::{
         number::{f64_to_int32, f64_to_uint32},
         Number,
     },
+    error::JsNativeError,
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyKey},
     symbol::{JsSymbol, WellKnownSymbols},
+    vm::StackFrame,
     Context, JsBigInt, JsResult, JsString,
 };
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::Finalize;
 use boa_profiler::Profiler;
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::Zero;
 use once_cell::sync::Lazy;
 use std::{
+    cell::Cell,
     collections::HashSet,
     fmt::{self, Display},
+    marker::PhantomData,
     ops::Sub,
-    str::FromStr,
+    rc::Rc,
 };
 
 mod conversions;
@@ -33,17 +37,26 @@ pub(crate) mod display;
 mod equality;
 mod hash;
 mod integer;
+mod into_js;
+mod nan_boxing;
 mod operations;
 mod serde_json;
+mod try_from_js;
 mod r#type;
 
-pub use conversions::*;
+pub use boa_macros::TryFromJs;
+pub(crate) use conversions::IntoOrUndefined;
+pub use conversions::TryFromCharError;
 pub use display::ValueDisplay;
-pub use equality::*;
-pub use hash::*;
 pub use integer::IntegerOrInfinity;
-pub use operations::*;
+pub use into_js::IntoJs;
+pub use nan_boxing::JsVariant;
+pub(crate) use nan_boxing::{
+    assert_address_fits, tag_address, untag_address, TaggedRc, BIGINT_TAG, STRING_TAG, SYMBOL_TAG,
+};
+pub use operations::AbstractRelation;
 pub use r#type::Type;
+pub use try_from_js::TryFromJs;
 
 static TWO_E_64: Lazy<BigInt> = Lazy::new(|| {
     const TWO_E_64: u128 = 2u128.pow(64);
@@ -56,34 +69,85 @@ static TWO_E_63: Lazy<BigInt> = Lazy::new(|| {
 });
 
 /// A Javascript value
-#[derive(Finalize, Debug, Clone)]
-pub enum JsValue {
+///
+/// Values are NaN-boxed in a single 64-bit word, see the `nan_boxing` module for the layout.
+///
+/// # Migrating from the `JsValue` enum
+///
+/// `JsValue` used to be an enum. It cannot be matched on anymore, since a single word has no room
+/// for the discriminant of an enum next to a double or a pointer, but its methods are unchanged,
+/// and the accessors like [`JsValue::as_string`] still return references.
+///  - Use [`JsValue::variant`] to match on the kind of a value: its [`JsVariant`] has the
+///    variants that `JsValue` had, and borrows the same data, so
+///    `match &value { JsValue::String(s) => .. }` becomes
+///    `match value.variant() { JsVariant::String(s) => .. }`.
+///  - The variant names remain as constructors, so `JsValue::Integer(1)` and
+///    `JsValue::Undefined` still create values.
+#[derive(Finalize)]
+#[repr(transparent)]
+pub struct JsValue {
+    bits: Cell<u64>,
+    _marker: PhantomData<Rc<()>>,
+}
+
+/// Constructors named after the kinds of values, which were the variants of `JsValue` before it
+/// was NaN-boxed.
+#[allow(non_snake_case, non_upper_case_globals)]
+impl JsValue {
     /// `null` - A null value, for when a value doesn't exist.
-    Null,
+    ///
+    /// Values have interior mutability, so use [`JsValue::null`] instead to borrow a `null`.
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub const Null: Self = Self::from_bits(nan_boxing::NULL);
+
     /// `undefined` - An undefined value, for when a field or index doesn't exist.
-    Undefined,
+    ///
+    /// Values have interior mutability, so use [`JsValue::undefined`] instead to borrow an
+    /// `undefined`.
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub const Undefined: Self = Self::from_bits(nan_boxing::UNDEFINED);
+
     /// `boolean` - A `true` / `false` value, for if a certain criteria is met.
-    Boolean(bool),
-    /// `String` - A UTF-8 string, such as `"Hello, world"`.
-    String(JsString),
+    #[inline]
+    pub fn Boolean(value: bool) -> Self {
+        Self::from_bool(value)
+    }
+
+    /// `String` - A UTF-16 string, such as `"Hello, world"`.
+    #[inline]
+    pub fn String(value: JsString) -> Self {
+        Self::from_string(value)
+    }
+
     /// `Number` - A 64-bit floating point number, such as `3.1415`
-    Rational(f64),
+    #[inline]
+    pub fn Rational(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+
     /// `Number` - A 32-bit integer, such as `42`.
-    Integer(i32),
+    #[inline]
+    pub fn Integer(value: i32) -> Self {
+        Self::from_i32(value)
+    }
+
     /// `BigInt` - holds any arbitrary large signed integer.
-    BigInt(JsBigInt),
+    #[inline]
+    pub fn BigInt(value: JsBigInt) -> Self {
+        Self::from_bigint(value)
+    }
+
     /// `Object` - An object, such as `Math`, represented by a binary tree of string keys to Javascript values.
-    Object(JsObject),
-    /// `Symbol` - A Symbol Primitive type.
-    Symbol(JsSymbol),
-}
+    #[inline]
+    pub fn Object(value: JsObject) -> Self {
+        Self::from_object(value)
+    }
 
-unsafe impl Trace for JsValue {
-    custom_trace! {this, {
-        if let Self::Object(o) = this {
-            mark(o);
-        }
-    }}
+    /// `Symbol` - A Symbol Primitive type.
+    #[inline]
+    pub fn Symbol(value: JsSymbol) -> Self {
+        Self::from_symbol(value)
+    }
 }
 
 impl JsValue {
@@ -129,15 +193,34 @@ impl JsValue {
     /// Returns true if the value is an object
     #[inline]
     pub fn is_object(&self) -> bool {
-        matches!(self, Self::Object(_))
+        self.object_ref().is_some()
     }
 
     #[inline]
     pub fn as_object(&self) -> Option<&JsObject> {
-        match *self {
-            Self::Object(ref o) => Some(o),
-            _ => None,
-        }
+        self.object_ref()
+    }
+
+    /// Gets the stack trace that was captured when this value was created, if it is an error
+    /// object, starting with the innermost frame.
+    ///
+    /// This is useful to log where an error thrown by JavaScript code comes from.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa_engine::Context;
+    /// let mut context = Context::default();
+    ///
+    /// let error = context
+    ///     .eval_with_name("function f() {\n  null.x;\n}\nf();", "script.js")
+    ///     .unwrap_err();
+    /// let stack = error.to_opaque(&mut context).stack_trace().unwrap();
+    ///
+    /// assert_eq!(stack[0].to_string(), "f (script.js:2:7)");
+    /// assert_eq!(stack[1].to_string(), "<main> (script.js:4:2)");
+    /// ```
+    pub fn stack_trace(&self) -> Option<Vec<StackFrame>> {
+        self.as_object()?.borrow().error_stack().map(<[_]>::to_vec)
     }
 
     /// It determines if the value is a callable function with a `[[Call]]` internal method.
@@ -148,7 +231,7 @@ impl JsValue {
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     #[inline]
     pub fn is_callable(&self) -> bool {
-        matches!(self, Self::Object(obj) if obj.is_callable())
+        matches!(self.as_object(), Some(obj) if obj.is_callable())
     }
 
     #[inline]
@@ -159,7 +242,7 @@ impl JsValue {
     /// Returns true if the value is a constructor object.
     #[inline]
     pub fn is_constructor(&self) -> bool {
-        matches!(self, Self::Object(obj) if obj.is_constructor())
+        matches!(self.as_object(), Some(obj) if obj.is_constructor())
     }
 
     #[inline]
@@ -170,7 +253,7 @@ impl JsValue {
     /// Returns true if the value is a promise object.
     #[inline]
     pub fn is_promise(&self) -> bool {
-        matches!(self, Self::Object(obj) if obj.is_promise())
+        matches!(self.as_object(), Some(obj) if obj.is_promise())
     }
 
     #[inline]
@@ -181,38 +264,35 @@ impl JsValue {
     /// Returns true if the value is a symbol.
     #[inline]
     pub fn is_symbol(&self) -> bool {
-        matches!(self, Self::Symbol(_))
+        self.symbol_ref().is_some()
     }
 
     pub fn as_symbol(&self) -> Option<JsSymbol> {
-        match self {
-            Self::Symbol(symbol) => Some(symbol.clone()),
-            _ => None,
-        }
+        self.symbol_ref().cloned()
     }
 
     /// Returns true if the value is undefined.
     #[inline]
     pub fn is_undefined(&self) -> bool {
-        matches!(self, Self::Undefined)
+        self.is_undefined_bits()
     }
 
     /// Returns true if the value is null.
     #[inline]
     pub fn is_null(&self) -> bool {
-        matches!(self, Self::Null)
+        self.is_null_bits()
     }
 
     /// Returns true if the value is null or undefined.
     #[inline]
     pub fn is_null_or_undefined(&self) -> bool {
-        matches!(self, Self::Null | Self::Undefined)
+        self.is_null_bits() || self.is_undefined_bits()
     }
 
     /// Returns true if the value is a 64-bit floating-point number.
     #[inline]
     pub fn is_double(&self) -> bool {
-        matches!(self, Self::Rational(_))
+        self.f64_bits().is_some()
     }
 
     /// Returns true if the value is integer.
@@ -223,9 +303,9 @@ impl JsValue {
         // equal to the original then it is an integer.
         let is_rational_integer = |n: f64| n == f64::from(n as i32);
 
-        match *self {
-            Self::Integer(_) => true,
-            Self::Rational(n) if is_rational_integer(n) => true,
+        match self.variant() {
+            JsVariant::Integer(_) => true,
+            JsVariant::Rational(n) if is_rational_integer(n) => true,
             _ => false,
         }
     }
@@ -233,60 +313,47 @@ impl JsValue {
     /// Returns true if the value is a number.
     #[inline]
     pub fn is_number(&self) -> bool {
-        matches!(self, Self::Rational(_) | Self::Integer(_))
+        self.i32_bits().is_some() || self.f64_bits().is_some()
     }
 
     #[inline]
     pub fn as_number(&self) -> Option<f64> {
-        match *self {
-            Self::Integer(integer) => Some(integer.into()),
-            Self::Rational(rational) => Some(rational),
-            _ => None,
-        }
+        self.i32_bits().map(f64::from).or_else(|| self.f64_bits())
     }
 
     /// Returns true if the value is a string.
     #[inline]
     pub fn is_string(&self) -> bool {
-        matches!(self, Self::String(_))
+        self.string_ref().is_some()
     }
 
     /// Returns the string if the values is a string, otherwise `None`.
     #[inline]
     pub fn as_string(&self) -> Option<&JsString> {
-        match self {
-            Self::String(ref string) => Some(string),
-            _ => None,
-        }
+        self.string_ref()
     }
 
     /// Returns true if the value is a boolean.
     #[inline]
     pub fn is_boolean(&self) -> bool {
-        matches!(self, Self::Boolean(_))
+        self.bool_bits().is_some()
     }
 
     #[inline]
     pub fn as_boolean(&self) -> Option<bool> {
-        match self {
-            Self::Boolean(boolean) => Some(*boolean),
-            _ => None,
-        }
+        self.bool_bits()
     }
 
     /// Returns true if the value is a bigint.
     #[inline]
     pub fn is_bigint(&self) -> bool {
-        matches!(self, Self::BigInt(_))
+        self.bigint_ref().is_some()
     }
 
     /// Returns an optional reference to a `BigInt` if the value is a `BigInt` primitive.
     #[inline]
     pub fn as_bigint(&self) -> Option<&JsBigInt> {
-        match self {
-            Self::BigInt(bigint) => Some(bigint),
-            _ => None,
-        }
+        self.bigint_ref()
     }
 
     /// Converts the value to a `bool` type.
@@ -296,13 +363,13 @@ impl JsValue {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-toboolean
     pub fn to_boolean(&self) -> bool {
-        match *self {
-            Self::Symbol(_) | Self::Object(_) => true,
-            Self::String(ref s) if !s.is_empty() => true,
-            Self::Rational(n) if n != 0.0 && !n.is_nan() => true,
-            Self::Integer(n) if n != 0 => true,
-            Self::BigInt(ref n) if !n.is_zero() => true,
-            Self::Boolean(v) => v,
+        match self.variant() {
+            JsVariant::Symbol(_) | JsVariant::Object(_) => true,
+            JsVariant::String(s) if !s.is_empty() => true,
+            JsVariant::Rational(n) if n != 0.0 && !n.is_nan() => true,
+            JsVariant::Integer(n) if n != 0 => true,
+            JsVariant::BigInt(n) if !n.is_zero() => true,
+            JsVariant::Boolean(v) => v,
             _ => false,
         }
     }
@@ -316,8 +383,8 @@ impl JsValue {
     {
         let key = key.into();
         let _timer = Profiler::global().start_event("Value::get_property", "value");
-        match self {
-            Self::Object(ref object) => {
+        match self.as_object() {
+            Some(object) => {
                 // TODO: had to skip `__get_own_properties__` since we don't have context here
                 let property = object.borrow().properties().get(&key);
                 if property.is_some() {
@@ -337,7 +404,7 @@ impl JsValue {
     /// Set the kind of an object.
     #[inline]
     pub fn set_data(&self, data: ObjectData) {
-        if let Self::Object(ref obj) = *self {
+        if let Some(obj) = self.as_object() {
             obj.borrow_mut().data = data;
         }
     }
@@ -375,7 +442,9 @@ impl JsValue {
                 // v. If Type(result) is not Object, return result.
                 // vi. Throw a TypeError exception.
                 return if result.is_object() {
-                    context.throw_type_error("Symbol.toPrimitive cannot return an object")
+                    Err(JsNativeError::typ()
+                        .with_message("Symbol.toPrimitive cannot return an object")
+                        .into())
                 } else {
                     Ok(result)
                 };
@@ -404,29 +473,37 @@ impl JsValue {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-tobigint
     pub fn to_bigint(&self, context: &mut Context) -> JsResult<JsBigInt> {
-        match self {
-            Self::Null => context.throw_type_error("cannot convert null to a BigInt"),
-            Self::Undefined => context.throw_type_error("cannot convert undefined to a BigInt"),
-            Self::String(ref string) => {
-                if let Some(value) = JsBigInt::from_string(string) {
+        match self.variant() {
+            JsVariant::Null => Err(JsNativeError::typ()
+                .with_message("cannot convert null to a BigInt")
+                .into()),
+            JsVariant::Undefined => Err(JsNativeError::typ()
+                .with_message("cannot convert undefined to a BigInt")
+                .into()),
+            JsVariant::String(string) => {
+                if let Some(value) = string.to_big_int() {
                     Ok(value)
                 } else {
-                    context.throw_syntax_error(format!(
-                        "cannot convert string '{string}' to bigint primitive",
-                    ))
+                    Err(JsNativeError::syntax()
+                        .with_message(format!(
+                            "cannot convert string '{string}' to bigint primitive",
+                        ))
+                        .into())
                 }
             }
-            Self::Boolean(true) => Ok(JsBigInt::one()),
-            Self::Boolean(false) => Ok(JsBigInt::zero()),
-            Self::Integer(_) | Self::Rational(_) => {
-                context.throw_type_error("cannot convert Number to a BigInt")
-            }
-            Self::BigInt(b) => Ok(b.clone()),
-            Self::Object(_) => {
+            JsVariant::Boolean(true) => Ok(JsBigInt::one()),
+            JsVariant::Boolean(false) => Ok(JsBigInt::zero()),
+            JsVariant::Integer(_) | JsVariant::Rational(_) => Err(JsNativeError::typ()
+                .with_message("cannot convert Number to a BigInt")
+                .into()),
+            JsVariant::BigInt(b) => Ok(b.clone()),
+            JsVariant::Object(_) => {
                 let primitive = self.to_primitive(context, PreferredType::Number)?;
                 primitive.to_bigint(context)
             }
-            Self::Symbol(_) => context.throw_type_error("cannot convert Symbol to a BigInt"),
+            JsVariant::Symbol(_) => Err(JsNativeError::typ()
+                .with_message("cannot convert Symbol to a BigInt")
+                .into()),
         }
     }
 
@@ -456,16 +533,18 @@ impl JsValue {
     ///
     /// This function is equivalent to `String(value)` in JavaScript.
     pub fn to_string(&self, context: &mut Context) -> JsResult<JsString> {
-        match self {
-            Self::Null => Ok("null".into()),
-            Self::Undefined => Ok("undefined".into()),
-            Self::Boolean(boolean) => Ok(boolean.to_string().into()),
-            Self::Rational(rational) => Ok(Number::to_native_string(*rational).into()),
-            Self::Integer(integer) => Ok(integer.to_string().into()),
-            Self::String(string) => Ok(string.clone()),
-            Self::Symbol(_) => context.throw_type_error("can't convert symbol to string"),
-            Self::BigInt(ref bigint) => Ok(bigint.to_string().into()),
-            Self::Object(_) => {
+        match self.variant() {
+            JsVariant::Null => Ok("null".into()),
+            JsVariant::Undefined => Ok("undefined".into()),
+            JsVariant::Boolean(boolean) => Ok(boolean.to_string().into()),
+            JsVariant::Rational(rational) => Ok(Number::to_native_string(rational).into()),
+            JsVariant::Integer(integer) => Ok(integer.to_string().into()),
+            JsVariant::String(string) => Ok(string.clone()),
+            JsVariant::Symbol(_) => Err(JsNativeError::typ()
+                .with_message("can't convert symbol to string")
+                .into()),
+            JsVariant::BigInt(bigint) => Ok(bigint.to_string().into()),
+            JsVariant::Object(_) => {
                 let primitive = self.to_primitive(context, PreferredType::String)?;
                 primitive.to_string(context)
             }
@@ -478,32 +557,32 @@ impl JsValue {
     ///
     /// See: <https://tc39.es/ecma262/#sec-toobject>
     pub fn to_object(&self, context: &mut Context) -> JsResult<JsObject> {
-        match self {
-            Self::Undefined | Self::Null => {
-                context.throw_type_error("cannot convert 'null' or 'undefined' to object")
-            }
-            Self::Boolean(boolean) => {
+        match self.variant() {
+            JsVariant::Undefined | JsVariant::Null => Err(JsNativeError::typ()
+                .with_message("cannot convert 'null' or 'undefined' to object")
+                .into()),
+            JsVariant::Boolean(boolean) => {
                 let prototype = context.intrinsics().constructors().boolean().prototype();
                 Ok(JsObject::from_proto_and_data(
                     prototype,
-                    ObjectData::boolean(*boolean),
+                    ObjectData::boolean(boolean),
                 ))
             }
-            Self::Integer(integer) => {
+            JsVariant::Integer(integer) => {
                 let prototype = context.intrinsics().constructors().number().prototype();
                 Ok(JsObject::from_proto_and_data(
                     prototype,
-                    ObjectData::number(f64::from(*integer)),
+                    ObjectData::number(f64::from(integer)),
                 ))
             }
-            Self::Rational(rational) => {
+            JsVariant::Rational(rational) => {
                 let prototype = context.intrinsics().constructors().number().prototype();
                 Ok(JsObject::from_proto_and_data(
                     prototype,
-                    ObjectData::number(*rational),
+                    ObjectData::number(rational),
                 ))
             }
-            Self::String(ref string) => {
+            JsVariant::String(string) => {
                 let prototype = context.intrinsics().constructors().string().prototype();
 
                 let object =
@@ -512,21 +591,21 @@ impl JsValue {
                 object.insert_property(
                     "length",
                     PropertyDescriptor::builder()
-                        .value(string.encode_utf16().count())
+                        .value(string.len())
                         .writable(false)
                         .enumerable(false)
                         .configurable(false),
                 );
                 Ok(object)
             }
-            Self::Symbol(ref symbol) => {
+            JsVariant::Symbol(symbol) => {
                 let prototype = context.intrinsics().constructors().symbol().prototype();
                 Ok(JsObject::from_proto_and_data(
                     prototype,
                     ObjectData::symbol(symbol.clone()),
                 ))
             }
-            Self::BigInt(ref bigint) => {
+            JsVariant::BigInt(bigint) => {
                 let prototype = context
                     .intrinsics()
                     .constructors()
@@ -537,7 +616,7 @@ impl JsValue {
                     ObjectData::big_int(bigint.clone()),
                 ))
             }
-            Self::Object(jsobject) => Ok(jsobject.clone()),
+            JsVariant::Object(jsobject) => Ok(jsobject.clone()),
         }
     }
 
@@ -545,16 +624,19 @@ impl JsValue {
     ///
     /// See <https://tc39.es/ecma262/#sec-topropertykey>
     pub fn to_property_key(&self, context: &mut Context) -> JsResult<PropertyKey> {
-        Ok(match self {
+        Ok(match self.variant() {
             // Fast path:
-            Self::String(string) => string.clone().into(),
-            Self::Symbol(symbol) => symbol.clone().into(),
+            JsVariant::String(string) => string.clone().into(),
+            JsVariant::Symbol(symbol) => symbol.clone().into(),
             // Slow path:
-            _ => match self.to_primitive(context, PreferredType::String)? {
-                Self::String(ref string) => string.clone().into(),
-                Self::Symbol(ref symbol) => symbol.clone().into(),
-                primitive => primitive.to_string(context)?.into(),
-            },
+            _ => {
+                let primitive = self.to_primitive(context, PreferredType::String)?;
+                match primitive.variant() {
+                    JsVariant::String(string) => string.clone().into(),
+                    JsVariant::Symbol(symbol) => symbol.clone().into(),
+                    _ => primitive.to_string(context)?.into(),
+                }
+            }
         })
     }
 
@@ -576,7 +658,7 @@ impl JsValue {
     /// See: <https://tc39.es/ecma262/#sec-touint32>
     pub fn to_u32(&self, context: &mut Context) -> JsResult<u32> {
         // This is the fast path, if the value is Integer we can just return it.
-        if let JsValue::Integer(number) = *self {
+        if let Some(number) = self.i32_bits() {
             return Ok(number as u32);
         }
         let number = self.to_number(context)?;
@@ -589,7 +671,7 @@ impl JsValue {
     /// See: <https://tc39.es/ecma262/#sec-toint32>
     pub fn to_i32(&self, context: &mut Context) -> JsResult<i32> {
         // This is the fast path, if the value is Integer we can just return it.
-        if let JsValue::Integer(number) = *self {
+        if let Some(number) = self.i32_bits() {
             return Ok(number);
         }
         let number = self.to_number(context)?;
@@ -810,7 +892,9 @@ impl JsValue {
 
         // c. If ! SameValue(𝔽(integer), clamped) is false, throw a RangeError exception.
         if integer != clamped {
-            return context.throw_range_error("Index must be between 0 and  2^53 - 1");
+            return Err(JsNativeError::range()
+                .with_message("Index must be between 0 and  2^53 - 1")
+                .into());
         }
 
         // d. Assert: 0 ≤ integer ≤ 2^53 - 1.
@@ -870,16 +954,20 @@ impl JsValue {
     ///
     /// See: <https://tc39.es/ecma262/#sec-tonumber>
     pub fn to_number(&self, context: &mut Context) -> JsResult<f64> {
-        match *self {
-            Self::Null => Ok(0.0),
-            Self::Undefined => Ok(f64::NAN),
-            Self::Boolean(b) => Ok(if b { 1.0 } else { 0.0 }),
-            Self::String(ref string) => Ok(string.string_to_number()),
-            Self::Rational(number) => Ok(number),
-            Self::Integer(integer) => Ok(f64::from(integer)),
-            Self::Symbol(_) => context.throw_type_error("argument must not be a symbol"),
-            Self::BigInt(_) => context.throw_type_error("argument must not be a bigint"),
-            Self::Object(_) => {
+        match self.variant() {
+            JsVariant::Null => Ok(0.0),
+            JsVariant::Undefined => Ok(f64::NAN),
+            JsVariant::Boolean(b) => Ok(if b { 1.0 } else { 0.0 }),
+            JsVariant::String(string) => Ok(string.to_number()),
+            JsVariant::Rational(number) => Ok(number),
+            JsVariant::Integer(integer) => Ok(f64::from(integer)),
+            JsVariant::Symbol(_) => Err(JsNativeError::typ()
+                .with_message("argument must not be a symbol")
+                .into()),
+            JsVariant::BigInt(_) => Err(JsNativeError::typ()
+                .with_message("argument must not be a bigint")
+                .into()),
+            JsVariant::Object(_) => {
                 let primitive = self.to_primitive(context, PreferredType::Number)?;
                 primitive.to_number(context)
             }
@@ -911,9 +999,11 @@ impl JsValue {
     /// [table]: https://tc39.es/ecma262/#table-14
     /// [spec]: https://tc39.es/ecma262/#sec-requireobjectcoercible
     #[inline]
-    pub fn require_object_coercible(&self, context: &mut Context) -> JsResult<&Self> {
+    pub fn require_object_coercible(&self) -> JsResult<&Self> {
         if self.is_null_or_undefined() {
-            context.throw_type_error("cannot convert null or undefined to Object")
+            Err(JsNativeError::typ()
+                .with_message("cannot convert null or undefined to Object")
+                .into())
         } else {
             Ok(self)
         }
@@ -924,9 +1014,9 @@ impl JsValue {
         // 1. If Type(Obj) is not Object, throw a TypeError exception.
         self.as_object()
             .ok_or_else(|| {
-                context.construct_type_error(
-                    "Cannot construct a property descriptor from a non-object",
-                )
+                JsNativeError::typ()
+                    .with_message("Cannot construct a property descriptor from a non-object")
+                    .into()
             })
             .and_then(|obj| obj.to_property_descriptor(context))
     }
@@ -939,15 +1029,15 @@ impl JsValue {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-typeof-operator
     pub fn type_of(&self) -> JsString {
-        match *self {
-            Self::Rational(_) | Self::Integer(_) => "number",
-            Self::String(_) => "string",
-            Self::Boolean(_) => "boolean",
-            Self::Symbol(_) => "symbol",
-            Self::Null => "object",
-            Self::Undefined => "undefined",
-            Self::BigInt(_) => "bigint",
-            Self::Object(ref object) => {
+        match self.variant() {
+            JsVariant::Rational(_) | JsVariant::Integer(_) => "number",
+            JsVariant::String(_) => "string",
+            JsVariant::Boolean(_) => "boolean",
+            JsVariant::Symbol(_) => "symbol",
+            JsVariant::Null => "object",
+            JsVariant::Undefined => "undefined",
+            JsVariant::BigInt(_) => "bigint",
+            JsVariant::Object(object) => {
                 if object.is_callable() {
                     "function"
                 } else {
@@ -983,7 +1073,7 @@ impl JsValue {
 
 impl Default for JsValue {
     fn default() -> Self {
-        Self::Undefined
+        Self::undefined()
     }
 }
 
@@ -0,0 +1,528 @@
+//! The NaN-boxed representation of [`JsValue`].
+//!
+//! A value is stored in a single 64-bit word, whose upper 16 bits tell its kind:
+//!
+//! | Upper 16 bits     | Kind                                                              |
+//! |-------------------|-------------------------------------------------------------------|
+//! | `0x0000`          | `undefined`, `null` and booleans if the word is below `0x10`,     |
+//! |                   | otherwise a [`JsObject`], stored in place                         |
+//! | `0x0001`          | A [`JsString`]                                                    |
+//! | `0x0002`          | A [`JsBigInt`]                                                    |
+//! | `0x0003`          | A [`JsSymbol`]                                                    |
+//! | `0x0004..=0xFFF4` | A double, offset by `0x0004 << 48`                                |
+//! | `0xFFFF`          | A 32-bit integer, in the lower 32 bits                            |
+//!
+//! Doubles are stored offset so that the words of objects are exactly the pointers of their
+//! [`JsObject`]s, which lets [`JsValue::as_object`] return a reference to the object stored in
+//! the value. All `NaN`s are stored as the canonical `NaN`, so the offset doubles can only reach
+//! `0xFFF4` (negative infinity).
+//!
+//! The other pointers are stored in the lower 48 bits. This assumes that heap addresses fit in
+//! 48 bits, which holds for the default 4-level page tables of `x86_64` and `aarch64`, but not
+//! for 5-level paging (`LA57`), where user space addresses can use 57 bits, nor for `aarch64`
+//! pointers tagged in their top byte (`TBI`, `MTE` or `HWASan` allocators). Every heap pointer
+//! stored in a value goes through [`assert_address_fits`], which panics instead of truncating an
+//! address that does not fit. [`JsString`], [`JsBigInt`] and
+//! [`JsSymbol`] keep the tag of their kind in the upper bits of their own pointer (see
+//! [`TaggedRc`]), so the word of a value holding one of them is also exactly the primitive, and
+//! values can lend references to them like they do for objects.
+
+use super::JsValue;
+use crate::{object::JsObject, JsBigInt, JsString, JsSymbol};
+use boa_gc::{Finalize, Trace};
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    ops::Deref,
+    ptr::{self, NonNull},
+    rc::Rc,
+};
+
+// Objects are stored in place, so the lower bytes of a value have to be the bytes of the object.
+#[cfg(all(target_pointer_width = "32", target_endian = "big"))]
+compile_error!("NaN-boxed values are not supported on 32-bit big endian platforms");
+
+const TAG_SHIFT: u32 = 48;
+const PAYLOAD_MASK: u64 = (1 << TAG_SHIFT) - 1;
+
+pub(super) const UNDEFINED: u64 = 0x0A;
+pub(super) const NULL: u64 = 0x02;
+const FALSE: u64 = 0x06;
+const TRUE: u64 = 0x07;
+
+/// Words of the `0x0000` tag below this value are immediates instead of objects.
+const IMMEDIATE_LIMIT: u64 = 0x10;
+
+pub(crate) const STRING_TAG: u64 = 0x0001;
+pub(crate) const BIGINT_TAG: u64 = 0x0002;
+pub(crate) const SYMBOL_TAG: u64 = 0x0003;
+const INTEGER_TAG: u64 = 0xFFFF;
+const DOUBLE_OFFSET: u64 = 0x0004 << TAG_SHIFT;
+
+/// The canonical `NaN`, which is the only `NaN` stored in a value.
+const CANONICAL_NAN: u64 = 0x7FF8_0000_0000_0000;
+
+/// A value that can be stored in a `static`.
+///
+/// Values are not `Sync` because tracing an object mutates its root flag, but immediates are never
+/// mutated, so they can be shared.
+#[repr(transparent)]
+struct StaticValue(JsValue);
+
+// SAFETY: the only `StaticValue` is `undefined`, which has no data to mutate.
+unsafe impl Sync for StaticValue {}
+
+static UNDEFINED_VALUE: StaticValue = StaticValue(JsValue::from_bits(UNDEFINED));
+
+/// Adds a tag to an address, as seen by the lower `usize` of the word of a value.
+///
+/// On 32-bit platforms the tag is in the upper half of the word, so addresses are not changed.
+#[inline]
+pub(crate) const fn tag_address(tag: u64, address: usize) -> usize {
+    address | (tag << TAG_SHIFT) as usize
+}
+
+/// Removes the tag of an address returned by [`tag_address`].
+#[inline]
+pub(crate) const fn untag_address(raw: usize) -> usize {
+    raw & PAYLOAD_MASK as usize
+}
+
+/// Checks that the address of a heap allocated primitive fits in the payload of a value.
+#[inline]
+pub(crate) fn assert_address_fits(address: usize) {
+    assert!(
+        untag_address(address) == address,
+        "the pointer does not fit in the payload of a value"
+    );
+}
+
+/// Tags the raw word of a heap allocated primitive, which only has its tag in the word on
+/// 64-bit platforms.
+#[inline]
+fn tag_pointer(tag: u64, raw: usize) -> u64 {
+    raw as u64 | tag << TAG_SHIFT
+}
+
+/// Casts a reference to a value to a reference to the primitive stored in place in its word.
+///
+/// # Safety
+///
+/// The value must hold a `T`, and `T` must have the layout of the lower `usize` of the word.
+#[inline]
+unsafe fn cast_ref<T>(value: &JsValue) -> &T {
+    &*(value as *const JsValue).cast::<T>()
+}
+
+impl JsValue {
+    #[inline]
+    pub(super) const fn from_bits(bits: u64) -> Self {
+        Self {
+            bits: Cell::new(bits),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets a reference to an `undefined` that lives for the whole program.
+    #[inline]
+    pub(crate) fn undefined_ref() -> &'static Self {
+        &UNDEFINED_VALUE.0
+    }
+
+    #[inline]
+    fn tag(&self) -> u64 {
+        self.bits.get() >> TAG_SHIFT
+    }
+
+    #[inline]
+    pub(super) fn from_bool(value: bool) -> Self {
+        Self::from_bits(if value { TRUE } else { FALSE })
+    }
+
+    #[inline]
+    pub(super) fn from_f64(value: f64) -> Self {
+        let bits = if value.is_nan() {
+            CANONICAL_NAN
+        } else {
+            value.to_bits()
+        };
+        Self::from_bits(bits + DOUBLE_OFFSET)
+    }
+
+    #[inline]
+    pub(super) fn from_i32(value: i32) -> Self {
+        Self::from_bits(INTEGER_TAG << TAG_SHIFT | u64::from(value as u32))
+    }
+
+    #[inline]
+    pub(super) fn from_string(value: JsString) -> Self {
+        Self::from_bits(tag_pointer(STRING_TAG, value.into_raw()))
+    }
+
+    #[inline]
+    pub(super) fn from_bigint(value: JsBigInt) -> Self {
+        Self::from_bits(tag_pointer(BIGINT_TAG, value.into_raw()))
+    }
+
+    #[inline]
+    pub(super) fn from_symbol(value: JsSymbol) -> Self {
+        Self::from_bits(tag_pointer(SYMBOL_TAG, value.into_raw()))
+    }
+
+    #[inline]
+    pub(super) fn from_object(value: JsObject) -> Self {
+        let value = ManuallyDrop::new(value);
+        // SAFETY: `JsObject` is a transparent wrapper of a `Gc`, which is a transparent wrapper
+        // of its pointer, so it can be read as an address.
+        let raw = unsafe { mem::transmute_copy::<JsObject, usize>(&value) };
+        assert_address_fits(raw);
+        let raw = raw as u64;
+        debug_assert!(raw >= IMMEDIATE_LIMIT);
+        Self::from_bits(raw)
+    }
+
+    #[inline]
+    pub(super) fn is_undefined_bits(&self) -> bool {
+        self.bits.get() == UNDEFINED
+    }
+
+    #[inline]
+    pub(super) fn is_null_bits(&self) -> bool {
+        self.bits.get() == NULL
+    }
+
+    /// Gets the boolean stored in the value, if it is a boolean.
+    #[inline]
+    pub(super) fn bool_bits(&self) -> Option<bool> {
+        match self.bits.get() {
+            TRUE => Some(true),
+            FALSE => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Gets the 32-bit integer stored in the value, if it is an integer.
+    #[inline]
+    pub(super) fn i32_bits(&self) -> Option<i32> {
+        if self.tag() == INTEGER_TAG {
+            Some(self.bits.get() as u32 as i32)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the double stored in the value, if it is a double.
+    #[inline]
+    pub(super) fn f64_bits(&self) -> Option<f64> {
+        let tag = self.tag();
+        if tag > SYMBOL_TAG && tag < INTEGER_TAG {
+            Some(f64::from_bits(self.bits.get() - DOUBLE_OFFSET))
+        } else {
+            None
+        }
+    }
+
+    /// Gets a reference to the object stored in the value, if it is an object.
+    #[inline]
+    pub(super) fn object_ref(&self) -> Option<&JsObject> {
+        // This is checked for every value of an object each time the object is borrowed mutably,
+        // so it is a single comparison: words below the limit wrap around to the top.
+        if self.bits.get().wrapping_sub(IMMEDIATE_LIMIT) < PAYLOAD_MASK + 1 - IMMEDIATE_LIMIT {
+            // SAFETY: The word of the value is the word of the object, and `JsObject` has the
+            // layout of a `Cell` of its pointer, like the `Cell` of the value.
+            Some(unsafe { &*(self as *const Self).cast::<JsObject>() })
+        } else {
+            None
+        }
+    }
+
+    /// Gets a reference to the string stored in the value, if it is a string.
+    #[inline]
+    pub(super) fn string_ref(&self) -> Option<&JsString> {
+        if self.tag() == STRING_TAG {
+            // SAFETY: The word of the value is the tagged pointer of the string, which is
+            // `repr(transparent)`.
+            Some(unsafe { cast_ref(self) })
+        } else {
+            None
+        }
+    }
+
+    /// Gets a reference to the bigint stored in the value, if it is a bigint.
+    #[inline]
+    pub(super) fn bigint_ref(&self) -> Option<&JsBigInt> {
+        if self.tag() == BIGINT_TAG {
+            // SAFETY: The word of the value is the `TaggedRc` of the bigint, which is
+            // `repr(transparent)`.
+            Some(unsafe { cast_ref(self) })
+        } else {
+            None
+        }
+    }
+
+    /// Gets a reference to the symbol stored in the value, if it is a symbol.
+    #[inline]
+    pub(super) fn symbol_ref(&self) -> Option<&JsSymbol> {
+        if self.tag() == SYMBOL_TAG {
+            // SAFETY: The word of the value is the `TaggedRc` of the symbol, which is
+            // `repr(transparent)`.
+            Some(unsafe { cast_ref(self) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the variant of the value, to match on its kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{value::JsVariant, JsValue};
+    ///
+    /// let value = JsValue::new(3);
+    /// match value.variant() {
+    ///     JsVariant::Integer(integer) => assert_eq!(integer, 3),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[inline]
+    pub fn variant(&self) -> JsVariant<'_> {
+        let bits = self.bits.get();
+        match bits >> TAG_SHIFT {
+            0 => match bits {
+                UNDEFINED => JsVariant::Undefined,
+                NULL => JsVariant::Null,
+                TRUE => JsVariant::Boolean(true),
+                FALSE => JsVariant::Boolean(false),
+                _ => JsVariant::Object(self.object_ref().expect("the value must be an object")),
+            },
+            STRING_TAG => JsVariant::String(self.string_ref().expect("the value must be a string")),
+            BIGINT_TAG => JsVariant::BigInt(self.bigint_ref().expect("the value must be a bigint")),
+            SYMBOL_TAG => JsVariant::Symbol(self.symbol_ref().expect("the value must be a symbol")),
+            INTEGER_TAG => JsVariant::Integer(bits as u32 as i32),
+            _ => JsVariant::Rational(f64::from_bits(bits - DOUBLE_OFFSET)),
+        }
+    }
+}
+
+impl Clone for JsValue {
+    #[inline]
+    fn clone(&self) -> Self {
+        match self.variant() {
+            JsVariant::Object(object) => Self::from_object(object.clone()),
+            JsVariant::String(string) => Self::from_string(string.clone()),
+            JsVariant::BigInt(bigint) => Self::from_bigint(bigint.clone()),
+            JsVariant::Symbol(symbol) => Self::from_symbol(symbol.clone()),
+            _ => Self::from_bits(self.bits.get()),
+        }
+    }
+}
+
+impl Drop for JsValue {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The value owns the primitive stored in its word, and it is not used again.
+        unsafe {
+            match self.variant() {
+                JsVariant::Object(object) => drop(ptr::read(object)),
+                JsVariant::String(string) => drop(ptr::read(string)),
+                JsVariant::BigInt(bigint) => drop(ptr::read(bigint)),
+                JsVariant::Symbol(symbol) => drop(ptr::read(symbol)),
+                _ => {}
+            }
+        }
+    }
+}
+
+unsafe impl Trace for JsValue {
+    #[inline]
+    unsafe fn trace(&self) {
+        if let Some(object) = self.object_ref() {
+            object.trace();
+        }
+    }
+
+    #[inline]
+    unsafe fn root(&self) {
+        if let Some(object) = self.object_ref() {
+            object.root();
+        }
+    }
+
+    #[inline]
+    unsafe fn unroot(&self) {
+        if let Some(object) = self.object_ref() {
+            object.unroot();
+        }
+    }
+
+    #[inline]
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+        if let Some(object) = self.object_ref() {
+            object.finalize_glue();
+        }
+    }
+}
+
+impl fmt::Debug for JsValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.variant().fmt(f)
+    }
+}
+
+/// The kind of a [`JsValue`], with the data it holds.
+///
+/// This is returned by [`JsValue::variant`] to match on the kind of a value.
+#[derive(Debug)]
+pub enum JsVariant<'a> {
+    /// `null` - A null value, for when a value doesn't exist.
+    Null,
+    /// `undefined` - An undefined value, for when a field or index doesn't exist.
+    Undefined,
+    /// `boolean` - A `true` / `false` value, for if a certain criteria is met.
+    Boolean(bool),
+    /// `String` - A UTF-16 string, such as `"Hello, world"`.
+    String(&'a JsString),
+    /// `Number` - A 64-bit floating point number, such as `3.1415`
+    Rational(f64),
+    /// `Number` - A 32-bit integer, such as `42`.
+    Integer(i32),
+    /// `BigInt` - holds any arbitrary large signed integer.
+    BigInt(&'a JsBigInt),
+    /// `Object` - An object, such as `Math`, represented by a binary tree of string keys to Javascript values.
+    Object(&'a JsObject),
+    /// `Symbol` - A Symbol Primitive type.
+    Symbol(&'a JsSymbol),
+}
+
+/// A reference counted pointer whose address carries the tag of a kind of value in its upper
+/// bits.
+///
+/// This is the representation of [`JsBigInt`] and [`JsSymbol`], so that the word of a value holding
+/// one of them is exactly their pointer.
+#[repr(transparent)]
+pub(crate) struct TaggedRc<T, const TAG: u64> {
+    raw: NonNull<T>,
+    _marker: PhantomData<Rc<T>>,
+}
+
+impl<T, const TAG: u64> TaggedRc<T, TAG> {
+    /// Allocates a new reference counted `value`.
+    #[inline]
+    pub(crate) fn new(value: T) -> Self {
+        let address = Rc::into_raw(Rc::new(value)) as usize;
+        assert_address_fits(address);
+        Self {
+            // SAFETY: The address of an allocation is not null, and neither is its tagged address.
+            raw: unsafe { NonNull::new_unchecked(tag_address(TAG, address) as *mut T) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the pointer of the allocation, without its tag.
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        untag_address(self.raw.as_ptr() as usize) as *const T
+    }
+
+    /// Consumes the pointer, returning its tagged address.
+    #[inline]
+    pub(crate) fn into_raw(self) -> usize {
+        let raw = self.raw.as_ptr() as usize;
+        mem::forget(self);
+        raw
+    }
+}
+
+impl<T, const TAG: u64> Deref for TaggedRc<T, TAG> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: The pointer is the pointer of an `Rc` that is kept alive by `self`.
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+impl<T, const TAG: u64> AsRef<T> for TaggedRc<T, TAG> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T, const TAG: u64> Clone for TaggedRc<T, TAG> {
+    #[inline]
+    fn clone(&self) -> Self {
+        // SAFETY: The pointer is the pointer of an `Rc` that is kept alive by `self`.
+        unsafe { Rc::increment_strong_count(self.as_ptr()) };
+        Self {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const TAG: u64> Drop for TaggedRc<T, TAG> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The pointer was returned by `Rc::into_raw`, and `self` owns one of its
+        // references.
+        drop(unsafe { Rc::from_raw(self.as_ptr()) });
+    }
+}
+
+impl<T: fmt::Debug, const TAG: u64> fmt::Debug for TaggedRc<T, TAG> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: PartialEq, const TAG: u64> PartialEq for TaggedRc<T, TAG> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq, const TAG: u64> Eq for TaggedRc<T, TAG> {}
+
+impl<T: PartialOrd, const TAG: u64> PartialOrd for TaggedRc<T, TAG> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord, const TAG: u64> Ord for TaggedRc<T, TAG> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash, const TAG: u64> Hash for TaggedRc<T, TAG> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+#[cfg(feature = "deser")]
+impl<T: serde::Serialize, const TAG: u64> serde::Serialize for TaggedRc<T, TAG> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "deser")]
+impl<'de, T: serde::Deserialize<'de>, const TAG: u64> serde::Deserialize<'de> for TaggedRc<T, TAG> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
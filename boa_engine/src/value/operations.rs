@@ -1,75 +1,81 @@
 use super::{
-    Context, FromStr, JsBigInt, JsResult, JsString, JsValue, Numeric, PreferredType,
+    Context, JsBigInt, JsResult, JsString, JsValue, JsVariant, Numeric, PreferredType,
     WellKnownSymbols,
 };
 use crate::builtins::number::{f64_to_int32, f64_to_uint32, Number};
+use crate::error::JsNativeError;
 
 impl JsValue {
     #[inline]
     pub fn add(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
             // Numeric add
-            (Self::Integer(x), Self::Integer(y)) => x
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => x
                 .checked_add(*y)
                 .map_or_else(|| Self::new(f64::from(*x) + f64::from(*y)), Self::new),
-            (Self::Rational(x), Self::Rational(y)) => Self::new(x + y),
-            (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x) + y),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(x + f64::from(*y)),
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::add(x, y)),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => Self::new(x + y),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Self::new(f64::from(*x) + y),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Self::new(x + f64::from(*y)),
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => Self::new(JsBigInt::add(x, y)),
 
             // String concat
-            (Self::String(ref x), Self::String(ref y)) => Self::from(JsString::concat(x, y)),
-            (Self::String(ref x), y) => Self::from(JsString::concat(x, y.to_string(context)?)),
-            (x, Self::String(ref y)) => Self::from(JsString::concat(x.to_string(context)?, y)),
+            (JsVariant::String(x), JsVariant::String(y)) => Self::from(JsString::concat(x, y)),
+            (JsVariant::String(x), _) => {
+                Self::from(JsString::concat(x, &other.to_string(context)?))
+            }
+            (_, JsVariant::String(y)) => Self::from(JsString::concat(&self.to_string(context)?, y)),
 
             // Slow path:
-            (_, _) => match (
-                self.to_primitive(context, PreferredType::Default)?,
-                other.to_primitive(context, PreferredType::Default)?,
-            ) {
-                (Self::String(ref x), ref y) => {
-                    Self::from(JsString::concat(x, y.to_string(context)?))
-                }
-                (ref x, Self::String(ref y)) => {
-                    Self::from(JsString::concat(x.to_string(context)?, y))
-                }
-                (x, y) => match (x.to_numeric(context)?, y.to_numeric(context)?) {
-                    (Numeric::Number(x), Numeric::Number(y)) => Self::new(x + y),
-                    (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
-                        Self::new(JsBigInt::add(x, y))
+            (_, _) => {
+                let x = self.to_primitive(context, PreferredType::Default)?;
+                let y = other.to_primitive(context, PreferredType::Default)?;
+                match (x.variant(), y.variant()) {
+                    (JsVariant::String(x), _) => {
+                        Self::from(JsString::concat(x, &y.to_string(context)?))
+                    }
+                    (_, JsVariant::String(y)) => {
+                        Self::from(JsString::concat(&x.to_string(context)?, y))
                     }
                     (_, _) => {
-                        return context.throw_type_error(
-                            "cannot mix BigInt and other types, use explicit conversions",
-                        )
+                        match (x.to_numeric(context)?, y.to_numeric(context)?) {
+                            (Numeric::Number(x), Numeric::Number(y)) => Self::new(x + y),
+                            (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
+                                Self::new(JsBigInt::add(x, y))
+                            }
+                            (_, _) => return Err(JsNativeError::typ()
+                                .with_message(
+                                    "cannot mix BigInt and other types, use explicit conversions",
+                                )
+                                .into()),
+                        }
                     }
-                },
-            },
+                }
+            }
         })
     }
 
     #[inline]
     pub fn sub(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => x
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => x
                 .checked_sub(*y)
                 .map_or_else(|| Self::new(f64::from(*x) - f64::from(*y)), Self::new),
-            (Self::Rational(x), Self::Rational(y)) => Self::new(x - y),
-            (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x) - y),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(x - f64::from(*y)),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => Self::new(x - y),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Self::new(f64::from(*x) - y),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Self::new(x - f64::from(*y)),
 
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::sub(x, y)),
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => Self::new(JsBigInt::sub(x, y)),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
                 (Numeric::Number(a), Numeric::Number(b)) => Self::new(a - b),
                 (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => Self::new(JsBigInt::sub(x, y)),
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -77,25 +83,25 @@ impl JsValue {
 
     #[inline]
     pub fn mul(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => x
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => x
                 .checked_mul(*y)
                 .map_or_else(|| Self::new(f64::from(*x) * f64::from(*y)), Self::new),
-            (Self::Rational(x), Self::Rational(y)) => Self::new(x * y),
-            (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x) * y),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(x * f64::from(*y)),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => Self::new(x * y),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Self::new(f64::from(*x) * y),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Self::new(x * f64::from(*y)),
 
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::mul(x, y)),
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => Self::new(JsBigInt::mul(x, y)),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
                 (Numeric::Number(a), Numeric::Number(b)) => Self::new(a * b),
                 (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => Self::new(JsBigInt::mul(x, y)),
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -103,19 +109,21 @@ impl JsValue {
 
     #[inline]
     pub fn div(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => x
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => x
                 .checked_div(*y)
                 .filter(|div| *y * div == *x)
                 .map_or_else(|| Self::new(f64::from(*x) / f64::from(*y)), Self::new),
-            (Self::Rational(x), Self::Rational(y)) => Self::new(x / y),
-            (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x) / y),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(x / f64::from(*y)),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => Self::new(x / y),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Self::new(f64::from(*x) / y),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Self::new(x / f64::from(*y)),
 
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => {
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => {
                 if y.is_zero() {
-                    return context.throw_range_error("BigInt division by zero");
+                    return Err(JsNativeError::range()
+                        .with_message("BigInt division by zero")
+                        .into());
                 }
                 Self::new(JsBigInt::div(x, y))
             }
@@ -125,14 +133,16 @@ impl JsValue {
                 (Numeric::Number(a), Numeric::Number(b)) => Self::new(a / b),
                 (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
                     if y.is_zero() {
-                        return context.throw_range_error("BigInt division by zero");
+                        return Err(JsNativeError::range()
+                            .with_message("BigInt division by zero")
+                            .into());
                     }
                     Self::new(JsBigInt::div(x, y))
                 }
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -140,9 +150,9 @@ impl JsValue {
 
     #[inline]
     pub fn rem(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => {
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => {
                 if *y == 0 {
                     Self::nan()
                 } else {
@@ -152,17 +162,21 @@ impl JsValue {
                     }
                 }
             }
-            (Self::Rational(x), Self::Rational(y)) => Self::new((x % y).copysign(*x)),
-            (Self::Integer(x), Self::Rational(y)) => {
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => Self::new((x % y).copysign(*x)),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => {
                 let x = f64::from(*x);
                 Self::new((x % y).copysign(x))
             }
 
-            (Self::Rational(x), Self::Integer(y)) => Self::new((x % f64::from(*y)).copysign(*x)),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => {
+                Self::new((x % f64::from(*y)).copysign(*x))
+            }
 
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => {
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => {
                 if y.is_zero() {
-                    return context.throw_range_error("BigInt division by zero");
+                    return Err(JsNativeError::range()
+                        .with_message("BigInt division by zero")
+                        .into());
                 }
                 Self::new(JsBigInt::rem(x, y))
             }
@@ -172,14 +186,16 @@ impl JsValue {
                 (Numeric::Number(a), Numeric::Number(b)) => Self::new(a % b),
                 (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
                     if y.is_zero() {
-                        return context.throw_range_error("BigInt division by zero");
+                        return Err(JsNativeError::range()
+                            .with_message("BigInt division by zero")
+                            .into());
                     }
                     Self::new(JsBigInt::rem(x, y))
                 }
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -187,28 +203,26 @@ impl JsValue {
 
     #[inline]
     pub fn pow(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => u32::try_from(*y)
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => u32::try_from(*y)
                 .ok()
                 .and_then(|y| x.checked_pow(y))
                 .map_or_else(|| Self::new(f64::from(*x).powi(*y)), Self::new),
-            (Self::Rational(x), Self::Rational(y)) => Self::new(x.powf(*y)),
-            (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x).powf(*y)),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(x.powi(*y)),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => Self::new(x.powf(*y)),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Self::new(f64::from(*x).powf(*y)),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Self::new(x.powi(*y)),
 
-            (Self::BigInt(ref a), Self::BigInt(ref b)) => Self::new(JsBigInt::pow(a, b, context)?),
+            (JsVariant::BigInt(a), JsVariant::BigInt(b)) => Self::new(JsBigInt::pow(a, b)?),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
                 (Numeric::Number(a), Numeric::Number(b)) => Self::new(a.powf(b)),
-                (Numeric::BigInt(ref a), Numeric::BigInt(ref b)) => {
-                    Self::new(JsBigInt::pow(a, b, context)?)
-                }
+                (Numeric::BigInt(ref a), Numeric::BigInt(ref b)) => Self::new(JsBigInt::pow(a, b)?),
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -216,16 +230,16 @@ impl JsValue {
 
     #[inline]
     pub fn bitand(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::new(x & y),
-            (Self::Rational(x), Self::Rational(y)) => {
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => Self::new(x & y),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => {
                 Self::new(f64_to_int32(*x) & f64_to_int32(*y))
             }
-            (Self::Integer(x), Self::Rational(y)) => Self::new(x & f64_to_int32(*y)),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(f64_to_int32(*x) & y),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Self::new(x & f64_to_int32(*y)),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Self::new(f64_to_int32(*x) & y),
 
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::bitand(x, y)),
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => Self::new(JsBigInt::bitand(x, y)),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
@@ -236,9 +250,9 @@ impl JsValue {
                     Self::new(JsBigInt::bitand(x, y))
                 }
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -246,16 +260,16 @@ impl JsValue {
 
     #[inline]
     pub fn bitor(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::new(x | y),
-            (Self::Rational(x), Self::Rational(y)) => {
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => Self::new(x | y),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => {
                 Self::new(f64_to_int32(*x) | f64_to_int32(*y))
             }
-            (Self::Integer(x), Self::Rational(y)) => Self::new(x | f64_to_int32(*y)),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(f64_to_int32(*x) | y),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Self::new(x | f64_to_int32(*y)),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Self::new(f64_to_int32(*x) | y),
 
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::bitor(x, y)),
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => Self::new(JsBigInt::bitor(x, y)),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
@@ -266,9 +280,9 @@ impl JsValue {
                     Self::new(JsBigInt::bitor(x, y))
                 }
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -276,16 +290,16 @@ impl JsValue {
 
     #[inline]
     pub fn bitxor(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::new(x ^ y),
-            (Self::Rational(x), Self::Rational(y)) => {
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => Self::new(x ^ y),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => {
                 Self::new(f64_to_int32(*x) ^ f64_to_int32(*y))
             }
-            (Self::Integer(x), Self::Rational(y)) => Self::new(x ^ f64_to_int32(*y)),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(f64_to_int32(*x) ^ y),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Self::new(x ^ f64_to_int32(*y)),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Self::new(f64_to_int32(*x) ^ y),
 
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::bitxor(x, y)),
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => Self::new(JsBigInt::bitxor(x, y)),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
@@ -296,9 +310,9 @@ impl JsValue {
                     Self::new(JsBigInt::bitxor(x, y))
                 }
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -306,20 +320,20 @@ impl JsValue {
 
     #[inline]
     pub fn shl(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::new(x.wrapping_shl(*y as u32)),
-            (Self::Rational(x), Self::Rational(y)) => {
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => Self::new(x.wrapping_shl(*y as u32)),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => {
                 Self::new(f64_to_int32(*x).wrapping_shl(f64_to_uint32(*y)))
             }
-            (Self::Integer(x), Self::Rational(y)) => Self::new(x.wrapping_shl(f64_to_uint32(*y))),
-            (Self::Rational(x), Self::Integer(y)) => {
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => {
+                Self::new(x.wrapping_shl(f64_to_uint32(*y)))
+            }
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => {
                 Self::new(f64_to_int32(*x).wrapping_shl(*y as u32))
             }
 
-            (Self::BigInt(ref a), Self::BigInt(ref b)) => {
-                Self::new(JsBigInt::shift_left(a, b, context)?)
-            }
+            (JsVariant::BigInt(a), JsVariant::BigInt(b)) => Self::new(JsBigInt::shift_left(a, b)?),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
@@ -327,12 +341,12 @@ impl JsValue {
                     Self::new(f64_to_int32(x).wrapping_shl(f64_to_uint32(y)))
                 }
                 (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
-                    Self::new(JsBigInt::shift_left(x, y, context)?)
+                    Self::new(JsBigInt::shift_left(x, y)?)
                 }
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -340,20 +354,20 @@ impl JsValue {
 
     #[inline]
     pub fn shr(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::new(x.wrapping_shr(*y as u32)),
-            (Self::Rational(x), Self::Rational(y)) => {
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => Self::new(x.wrapping_shr(*y as u32)),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => {
                 Self::new(f64_to_int32(*x).wrapping_shr(f64_to_uint32(*y)))
             }
-            (Self::Integer(x), Self::Rational(y)) => Self::new(x.wrapping_shr(f64_to_uint32(*y))),
-            (Self::Rational(x), Self::Integer(y)) => {
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => {
+                Self::new(x.wrapping_shr(f64_to_uint32(*y)))
+            }
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => {
                 Self::new(f64_to_int32(*x).wrapping_shr(*y as u32))
             }
 
-            (Self::BigInt(ref a), Self::BigInt(ref b)) => {
-                Self::new(JsBigInt::shift_right(a, b, context)?)
-            }
+            (JsVariant::BigInt(a), JsVariant::BigInt(b)) => Self::new(JsBigInt::shift_right(a, b)?),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
@@ -361,12 +375,12 @@ impl JsValue {
                     Self::new(f64_to_int32(x).wrapping_shr(f64_to_uint32(y)))
                 }
                 (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
-                    Self::new(JsBigInt::shift_right(x, y, context)?)
+                    Self::new(JsBigInt::shift_right(x, y)?)
                 }
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -374,16 +388,18 @@ impl JsValue {
 
     #[inline]
     pub fn ushr(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::new((*x as u32).wrapping_shr(*y as u32)),
-            (Self::Rational(x), Self::Rational(y)) => {
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => {
+                Self::new((*x as u32).wrapping_shr(*y as u32))
+            }
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => {
                 Self::new(f64_to_uint32(*x).wrapping_shr(f64_to_uint32(*y)))
             }
-            (Self::Integer(x), Self::Rational(y)) => {
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => {
                 Self::new((*x as u32).wrapping_shr(f64_to_uint32(*y)))
             }
-            (Self::Rational(x), Self::Integer(y)) => {
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => {
                 Self::new(f64_to_uint32(*x).wrapping_shr(*y as u32))
             }
 
@@ -393,13 +409,14 @@ impl JsValue {
                     Self::new(f64_to_uint32(x).wrapping_shr(f64_to_uint32(y)))
                 }
                 (Numeric::BigInt(_), Numeric::BigInt(_)) => {
-                    return context
-                        .throw_type_error("BigInts have no unsigned right shift, use >> instead");
+                    return Err(JsNativeError::typ()
+                        .with_message("BigInts have no unsigned right shift, use >> instead")
+                        .into());
                 }
                 (_, _) => {
-                    return context.throw_type_error(
-                        "cannot mix BigInt and other types, use explicit conversions",
-                    );
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot mix BigInt and other types, use explicit conversions")
+                        .into());
                 }
             },
         })
@@ -415,10 +432,12 @@ impl JsValue {
     pub fn instance_of(&self, target: &Self, context: &mut Context) -> JsResult<bool> {
         // 1. If Type(target) is not Object, throw a TypeError exception.
         if !target.is_object() {
-            return context.throw_type_error(format!(
-                "right-hand side of 'instanceof' should be an object, got {}",
-                target.type_of()
-            ));
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "right-hand side of 'instanceof' should be an object, got {}",
+                    target.type_of()
+                ))
+                .into());
         }
 
         // 2. Let instOfHandler be ? GetMethod(target, @@hasInstance).
@@ -436,29 +455,28 @@ impl JsValue {
             }
             None => {
                 // 4. If IsCallable(target) is false, throw a TypeError exception.
-                context.throw_type_error("right-hand side of 'instanceof' is not callable")
+                Err(JsNativeError::typ()
+                    .with_message("right-hand side of 'instanceof' is not callable")
+                    .into())
             }
         }
     }
 
     #[inline]
     pub fn neg(&self, context: &mut Context) -> JsResult<Self> {
-        Ok(match *self {
-            Self::Symbol(_) | Self::Undefined => Self::new(f64::NAN),
-            Self::Object(_) => Self::new(match self.to_numeric_number(context) {
-                Ok(num) => -num,
-                Err(_) => f64::NAN,
-            }),
-            Self::String(ref str) => Self::new(match f64::from_str(str) {
+        Ok(match self.variant() {
+            JsVariant::Symbol(_) | JsVariant::Undefined => Self::new(f64::NAN),
+            JsVariant::Object(_) => Self::new(match self.to_numeric_number(context) {
                 Ok(num) => -num,
                 Err(_) => f64::NAN,
             }),
-            Self::Rational(num) => Self::new(-num),
-            Self::Integer(num) if num == 0 => Self::new(-f64::from(0)),
-            Self::Integer(num) => Self::new(-num),
-            Self::Boolean(true) => Self::new(1),
-            Self::Boolean(false) | Self::Null => Self::new(0),
-            Self::BigInt(ref x) => Self::new(JsBigInt::neg(x)),
+            JsVariant::String(str) => Self::new(-str.to_number()),
+            JsVariant::Rational(num) => Self::new(-num),
+            JsVariant::Integer(0) => Self::new(-f64::from(0)),
+            JsVariant::Integer(num) => Self::new(-num),
+            JsVariant::Boolean(true) => Self::new(1),
+            JsVariant::Boolean(false) | JsVariant::Null => Self::new(0),
+            JsVariant::BigInt(x) => Self::new(JsBigInt::neg(x)),
         })
     }
 
@@ -490,13 +508,13 @@ impl JsValue {
         left_first: bool,
         context: &mut Context,
     ) -> JsResult<AbstractRelation> {
-        Ok(match (self, other) {
+        Ok(match (&self.variant(), &other.variant()) {
             // Fast path (for some common operations):
-            (Self::Integer(x), Self::Integer(y)) => (x < y).into(),
-            (Self::Integer(x), Self::Rational(y)) => Number::less_than(f64::from(*x), *y),
-            (Self::Rational(x), Self::Integer(y)) => Number::less_than(*x, f64::from(*y)),
-            (Self::Rational(x), Self::Rational(y)) => Number::less_than(*x, *y),
-            (Self::BigInt(ref x), Self::BigInt(ref y)) => (x < y).into(),
+            (JsVariant::Integer(x), JsVariant::Integer(y)) => (x < y).into(),
+            (JsVariant::Integer(x), JsVariant::Rational(y)) => Number::less_than(f64::from(*x), *y),
+            (JsVariant::Rational(x), JsVariant::Integer(y)) => Number::less_than(*x, f64::from(*y)),
+            (JsVariant::Rational(x), JsVariant::Rational(y)) => Number::less_than(*x, *y),
+            (JsVariant::BigInt(x), JsVariant::BigInt(y)) => (x < y).into(),
 
             // Slow path:
             (_, _) => {
@@ -511,36 +529,24 @@ impl JsValue {
                     (px, py)
                 };
 
-                match (px, py) {
-                    (Self::String(ref x), Self::String(ref y)) => {
-                        if x.starts_with(y.as_str()) {
-                            return Ok(AbstractRelation::False);
-                        }
-                        if y.starts_with(x.as_str()) {
-                            return Ok(AbstractRelation::True);
-                        }
-                        for (x, y) in x.chars().zip(y.chars()) {
-                            if x != y {
-                                return Ok((x < y).into());
-                            }
-                        }
-                        unreachable!()
-                    }
-                    (Self::BigInt(ref x), Self::String(ref y)) => {
-                        if let Some(y) = JsBigInt::from_string(y) {
-                            (*x < y).into()
+                match (&px.variant(), &py.variant()) {
+                    // Strings are compared by their code units.
+                    (JsVariant::String(x), JsVariant::String(y)) => (x < y).into(),
+                    (JsVariant::BigInt(x), JsVariant::String(y)) => {
+                        if let Some(y) = y.to_big_int() {
+                            (**x < y).into()
                         } else {
                             AbstractRelation::Undefined
                         }
                     }
-                    (Self::String(ref x), Self::BigInt(ref y)) => {
-                        if let Some(x) = JsBigInt::from_string(x) {
-                            (x < *y).into()
+                    (JsVariant::String(x), JsVariant::BigInt(y)) => {
+                        if let Some(x) = x.to_big_int() {
+                            (x < **y).into()
                         } else {
                             AbstractRelation::Undefined
                         }
                     }
-                    (px, py) => match (px.to_numeric(context)?, py.to_numeric(context)?) {
+                    (_, _) => match (px.to_numeric(context)?, py.to_numeric(context)?) {
                         (Numeric::Number(x), Numeric::Number(y)) => Number::less_than(x, y),
                         (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => (x < y).into(),
                         (Numeric::BigInt(ref x), Numeric::Number(y)) => {
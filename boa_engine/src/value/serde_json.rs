@@ -1,8 +1,9 @@
 //! This module implements the conversions from and into [`serde_json::Value`].
 
-use super::JsValue;
+use super::{JsValue, JsVariant};
 use crate::{
     builtins::Array,
+    error::JsNativeError,
     property::{PropertyDescriptor, PropertyKey},
     Context, JsResult,
 };
@@ -49,9 +50,9 @@ impl JsValue {
                 .map(|i| Self::Integer(i as i32))
                 .or_else(|| num.as_f64().map(Self::Rational))
                 .ok_or_else(|| {
-                    context.construct_type_error(format!(
-                        "could not convert JSON number {num} to JsValue"
-                    ))
+                    JsNativeError::typ()
+                        .with_message(format!("could not convert JSON number {num} to JsValue"))
+                        .into()
                 }),
             Value::String(string) => Ok(Self::from(string.as_str())),
             Value::Array(vec) => {
@@ -104,15 +105,17 @@ impl JsValue {
     /// # assert_eq!(json, back_to_json);
     /// ```
     pub fn to_json(&self, context: &mut Context) -> JsResult<Value> {
-        match self {
-            Self::Null => Ok(Value::Null),
-            Self::Undefined => todo!("undefined to JSON"),
-            &Self::Boolean(b) => Ok(b.into()),
-            Self::String(string) => Ok(string.as_str().into()),
-            &Self::Rational(rat) => Ok(rat.into()),
-            &Self::Integer(int) => Ok(int.into()),
-            Self::BigInt(_bigint) => context.throw_type_error("cannot convert bigint to JSON"),
-            Self::Object(obj) => {
+        match self.variant() {
+            JsVariant::Null => Ok(Value::Null),
+            JsVariant::Undefined => todo!("undefined to JSON"),
+            JsVariant::Boolean(b) => Ok(b.into()),
+            JsVariant::String(string) => Ok(string.to_std_string_escaped().into()),
+            JsVariant::Rational(rat) => Ok(rat.into()),
+            JsVariant::Integer(int) => Ok(int.into()),
+            JsVariant::BigInt(_bigint) => Err(JsNativeError::typ()
+                .with_message("cannot convert bigint to JSON")
+                .into()),
+            JsVariant::Object(obj) => {
                 if obj.is_array() {
                     let len = obj.length_of_array_like(context)?;
                     let mut arr = Vec::with_capacity(len as usize);
@@ -131,10 +134,12 @@ impl JsValue {
                     let mut map = Map::new();
                     for (key, property) in obj.borrow().properties().iter() {
                         let key = match &key {
-                            PropertyKey::String(string) => string.as_str().to_owned(),
+                            PropertyKey::String(string) => string.to_std_string_escaped(),
                             PropertyKey::Index(i) => i.to_string(),
                             PropertyKey::Symbol(_sym) => {
-                                return context.throw_type_error("cannot convert Symbol to JSON")
+                                return Err(JsNativeError::typ()
+                                    .with_message("cannot convert Symbol to JSON")
+                                    .into())
                             }
                         };
 
@@ -149,7 +154,9 @@ impl JsValue {
                     Ok(Value::Object(map))
                 }
             }
-            Self::Symbol(_sym) => context.throw_type_error("cannot convert Symbol to JSON"),
+            JsVariant::Symbol(_sym) => Err(JsNativeError::typ()
+                .with_message("cannot convert Symbol to JSON")
+                .into()),
         }
     }
 }
@@ -198,7 +205,7 @@ mod tests {
             let phones = obj.get("phones", &mut context).unwrap();
             let phones = phones.as_object().unwrap();
 
-            let arr = JsArray::from_object(phones.clone(), &mut context).unwrap();
+            let arr = JsArray::from_object(phones.clone()).unwrap();
             assert_eq!(arr.at(0, &mut context).unwrap(), "+44 1234567".into());
             assert_eq!(arr.at(1, &mut context).unwrap(), JsValue::from(-45_i32));
             assert!(arr.at(2, &mut context).unwrap().is_object());
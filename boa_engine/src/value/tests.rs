@@ -51,6 +51,104 @@ fn number_is_true() {
     assert!(!JsValue::nan().to_boolean());
 }
 
+#[test]
+fn values_are_one_word() {
+    assert_eq!(std::mem::size_of::<JsValue>(), 8);
+}
+
+#[test]
+fn values_keep_their_data() {
+    let context = Context::default();
+    let object = context.construct_object();
+    let symbol = JsSymbol::new(Some("sym".into()));
+
+    assert!(JsValue::undefined().is_undefined());
+    assert!(JsValue::null().is_null());
+    assert_eq!(JsValue::new(true).as_boolean(), Some(true));
+    assert_eq!(JsValue::new(false).as_boolean(), Some(false));
+    for integer in [0, 1, -1, i32::MIN, i32::MAX] {
+        assert_eq!(JsValue::new(integer).as_number(), Some(f64::from(integer)));
+    }
+    for rational in [
+        0.5,
+        -0.0,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        let value = JsValue::Rational(rational);
+        assert_eq!(
+            value.as_number().map(f64::to_bits),
+            Some(rational.to_bits())
+        );
+    }
+    assert_eq!(JsValue::new("hello").as_string().unwrap(), "hello");
+    assert_eq!(
+        JsValue::new(JsBigInt::new(42)).as_bigint(),
+        Some(&JsBigInt::new(42))
+    );
+    assert_eq!(JsValue::new(symbol.clone()).as_symbol(), Some(symbol));
+    assert!(JsObject::equals(
+        JsValue::new(object.clone()).as_object().unwrap(),
+        &object
+    ));
+}
+
+#[test]
+fn values_lend_references_to_their_data() {
+    let values = [
+        JsValue::new(""),
+        JsValue::new("a string that is not interned"),
+        JsValue::new(JsBigInt::new(42)),
+        JsValue::new(JsSymbol::new(None)),
+    ];
+    for value in &values {
+        let expected = value.clone();
+        match value.variant() {
+            JsVariant::String(string) => assert_eq!(Some(string), expected.as_string()),
+            JsVariant::BigInt(bigint) => assert_eq!(Some(bigint), expected.as_bigint()),
+            JsVariant::Symbol(symbol) => assert_eq!(Some(symbol.clone()), expected.as_symbol()),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn nans_are_canonicalized() {
+    let negative_nan = f64::from_bits(0xFFF8_0000_0000_0001);
+    let payload_nan = f64::from_bits(0x7FF0_0000_DEAD_BEEF);
+    for nan in [f64::NAN, negative_nan, payload_nan] {
+        let value = JsValue::new(nan);
+        assert!(value.is_number());
+        assert!(value.as_number().unwrap().is_nan());
+        assert!(matches!(value.variant(), JsVariant::Rational(_)));
+    }
+}
+
+#[test]
+fn cloned_values_share_their_data() {
+    let string = JsValue::new("shared");
+    let clone = string.clone();
+    drop(string);
+    assert_eq!(clone.as_string().unwrap(), "shared");
+
+    let mut context = Context::default();
+    let object = JsValue::new(context.construct_object());
+    object
+        .as_object()
+        .unwrap()
+        .set("x", 1, false, &mut context)
+        .unwrap();
+    let clone = object.clone();
+    drop(object);
+    boa_gc::force_collect();
+    assert_eq!(
+        clone.as_object().unwrap().get("x", &mut context).unwrap(),
+        JsValue::new(1)
+    );
+}
+
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Equality_comparisons_and_sameness
 #[test]
 fn abstract_equality_comparison() {
@@ -1335,3 +1433,84 @@ mod abstract_relational_comparison {
         check_comparison!(context, "'InvalidBigInt' >= -100n" => false);
     }
 }
+
+#[test]
+fn try_from_js() {
+    use std::collections::HashMap;
+
+    let mut context = Context::default();
+
+    assert_eq!(
+        JsValue::new(42).try_js_into::<u8>(&mut context).unwrap(),
+        42
+    );
+    assert_eq!(
+        JsValue::new(-1.5).try_js_into::<f64>(&mut context).unwrap(),
+        -1.5
+    );
+    assert!(JsValue::new(-1).try_js_into::<u32>(&mut context).is_err());
+    assert!(JsValue::new(1.5).try_js_into::<i32>(&mut context).is_err());
+    assert!(JsValue::new(f64::NAN)
+        .try_js_into::<i64>(&mut context)
+        .is_err());
+    assert!(JsValue::new("1").try_js_into::<i32>(&mut context).is_err());
+
+    let error = JsValue::new(true)
+        .try_js_into::<String>(&mut context)
+        .unwrap_err()
+        .to_opaque(&mut context)
+        .to_string(&mut context)
+        .unwrap();
+    assert_eq!(
+        error.to_std_string_escaped(),
+        "TypeError: cannot convert value of type `boolean` to a string"
+    );
+
+    assert_eq!(
+        JsValue::undefined()
+            .try_js_into::<Option<String>>(&mut context)
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        JsValue::new("text")
+            .try_js_into::<Option<String>>(&mut context)
+            .unwrap(),
+        Some("text".to_owned())
+    );
+
+    let value = forward_val(&mut context, "[[1, 'a'], [2, 'b']]").unwrap();
+    let pairs: Vec<(i32, String)> = value.try_js_into(&mut context).unwrap();
+    assert_eq!(pairs, [(1, "a".to_owned()), (2, "b".to_owned())]);
+
+    let value = forward_val(&mut context, "({ x: [1.5], y: [], [Symbol()]: 3 })").unwrap();
+    let map: HashMap<String, Vec<f64>> = value.try_js_into(&mut context).unwrap();
+    assert_eq!(
+        map,
+        HashMap::from([("x".to_owned(), vec![1.5]), ("y".to_owned(), vec![])])
+    );
+
+    let value = forward_val(&mut context, "[1, 'two']").unwrap();
+    assert!(value.try_js_into::<Vec<i32>>(&mut context).is_err());
+}
+
+#[test]
+fn into_js() {
+    use std::collections::HashMap;
+
+    let mut context = Context::default();
+
+    let value = vec![(1, "one"), (2, "two")].into_js(&mut context);
+    context.register_global_property("pairs", value, Default::default());
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(pairs)"),
+        r#""[[1,"one"],[2,"two"]]""#
+    );
+
+    let value = HashMap::from([("key".to_owned(), Some(true))]).into_js(&mut context);
+    context.register_global_property("map", value, Default::default());
+    assert_eq!(forward(&mut context, "map.key"), "true");
+
+    assert_eq!(None::<i32>.into_js(&mut context), JsValue::undefined());
+    assert_eq!(().into_js(&mut context), JsValue::undefined());
+}
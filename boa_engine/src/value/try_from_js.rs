@@ -0,0 +1,280 @@
+//! This module implements the [`TryFromJs`] trait, to convert JavaScript values into Rust types.
+
+use super::JsValue;
+use crate::{
+    error::JsNativeError, object::JsObject, property::PropertyNameKind, Context, JsBigInt,
+    JsResult, JsString,
+};
+use std::{collections::HashMap, hash::BuildHasher};
+
+/// Conversion from a [`JsValue`] to a Rust type.
+///
+/// Unlike the `to_*` methods of [`JsValue`], these conversions do not coerce the value: they
+/// fail with a `TypeError` if the value does not have the expected type, or if a number does not
+/// fit in the target integer type.
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{value::TryFromJs, Context, JsValue};
+///
+/// let mut context = Context::default();
+///
+/// let value = context.eval("[1, 2, 3]").unwrap();
+/// let numbers: Vec<u8> = value.try_js_into(&mut context).unwrap();
+/// assert_eq!(numbers, [1, 2, 3]);
+///
+/// assert!(u8::try_from_js(&JsValue::new(256), &mut context).is_err());
+/// assert!(String::try_from_js(&JsValue::new(1), &mut context).is_err());
+/// ```
+///
+/// It can be derived for structs with named fields, which are converted from the properties of
+/// an object. The property names are the field names in `camelCase`, unless they are renamed:
+///
+/// ```
+/// use boa_engine::{value::TryFromJs, Context};
+///
+/// #[derive(Debug, PartialEq, TryFromJs)]
+/// struct Options {
+///     max_depth: u32,
+///     #[boa(rename = "label")]
+///     name: Option<String>,
+/// }
+///
+/// let mut context = Context::default();
+///
+/// let value = context.eval("({ maxDepth: 3, label: 'root' })").unwrap();
+/// let options: Options = value.try_js_into(&mut context).unwrap();
+/// assert_eq!(
+///     options,
+///     Options { max_depth: 3, name: Some("root".to_owned()) }
+/// );
+///
+/// let value = context.eval("({ maxDepth: 'deep' })").unwrap();
+/// assert!(Options::try_from_js(&value, &mut context).is_err());
+/// ```
+pub trait TryFromJs: Sized {
+    /// Converts a [`JsValue`] to `Self`, or returns a `TypeError` if the value does not
+    /// represent a `Self`.
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self>;
+}
+
+impl JsValue {
+    /// Converts the value to a Rust type, using its [`TryFromJs`] implementation.
+    #[inline]
+    pub fn try_js_into<T>(&self, context: &mut Context) -> JsResult<T>
+    where
+        T: TryFromJs,
+    {
+        T::try_from_js(self, context)
+    }
+}
+
+/// Creates the `TypeError` of a failed conversion.
+fn conversion_error(value: &JsValue, expected: &str) -> JsNativeError {
+    JsNativeError::typ().with_message(format!(
+        "cannot convert value of type `{}` to {expected}",
+        value.type_of().to_std_string_escaped()
+    ))
+}
+
+impl TryFromJs for JsValue {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl TryFromJs for bool {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        value
+            .as_boolean()
+            .ok_or_else(|| conversion_error(value, "a boolean").into())
+    }
+}
+
+impl TryFromJs for JsString {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        value
+            .as_string()
+            .cloned()
+            .ok_or_else(|| conversion_error(value, "a string").into())
+    }
+}
+
+impl TryFromJs for String {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        JsString::try_from_js(value, context)?
+            .to_std_string()
+            .map_err(|_| {
+                JsNativeError::typ()
+                    .with_message("cannot convert a string with unpaired surrogates to UTF-8")
+                    .into()
+            })
+    }
+}
+
+impl TryFromJs for JsObject {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        value
+            .as_object()
+            .cloned()
+            .ok_or_else(|| conversion_error(value, "an object").into())
+    }
+}
+
+impl TryFromJs for JsBigInt {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        value
+            .as_bigint()
+            .cloned()
+            .ok_or_else(|| conversion_error(value, "a BigInt").into())
+    }
+}
+
+impl TryFromJs for f64 {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        value
+            .as_number()
+            .ok_or_else(|| conversion_error(value, "a number").into())
+    }
+}
+
+impl TryFromJs for f32 {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        f64::try_from_js(value, context).map(|n| n as Self)
+    }
+}
+
+macro_rules! impl_try_from_js_for_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFromJs for $ty {
+                #[inline]
+                #[allow(clippy::float_cmp, clippy::cast_lossless)]
+                fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+                    let number = f64::try_from_js(value, context)?;
+                    // `MAX + 1` is a power of two, so it is exactly representable as a `f64`
+                    // even for 64-bit integers, where `MAX` itself is not.
+                    if number.trunc() == number
+                        && number >= Self::MIN as f64
+                        && number < Self::MAX as f64 + 1.0
+                    {
+                        Ok(number as Self)
+                    } else {
+                        Err(JsNativeError::typ()
+                            .with_message(format!(
+                                "cannot convert {number} to `{}`",
+                                stringify!($ty)
+                            ))
+                            .into())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_js_for_integer!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
+impl<T> TryFromJs for Option<T>
+where
+    T: TryFromJs,
+{
+    /// Converts `undefined` and `null` to `None`, and every other value to `Some`.
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if value.is_null_or_undefined() {
+            Ok(None)
+        } else {
+            T::try_from_js(value, context).map(Some)
+        }
+    }
+}
+
+/// The maximum number of elements reserved by the conversion to a `Vec` before converting them.
+const MAX_PREALLOCATED_ELEMENTS: u64 = 1024;
+
+impl<T> TryFromJs for Vec<T>
+where
+    T: TryFromJs,
+{
+    /// Converts an array-like object to a `Vec`, converting each element.
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| conversion_error(value, "an array"))?;
+        let length = object.length_of_array_like(context)?;
+
+        // The length is controlled by the script, so only a bounded capacity is reserved up
+        // front and the vector grows as the elements are converted.
+        let mut vec = Vec::with_capacity(length.min(MAX_PREALLOCATED_ELEMENTS) as usize);
+        for index in 0..length {
+            let element = object.get(index, context)?;
+            vec.push(T::try_from_js(&element, context)?);
+        }
+        Ok(vec)
+    }
+}
+
+impl<T, S> TryFromJs for HashMap<String, T, S>
+where
+    T: TryFromJs,
+    S: BuildHasher + Default,
+{
+    /// Converts the own enumerable string-keyed properties of an object to a `HashMap`,
+    /// converting each value.
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| conversion_error(value, "an object"))?;
+        let keys = object.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+
+        let mut map = Self::with_capacity_and_hasher(keys.len(), S::default());
+        for key in keys {
+            let key = key
+                .as_string()
+                .expect("enumerable own property names must be strings")
+                .clone();
+            let value = object.get(key.clone(), context)?;
+            map.insert(
+                String::try_from_js(&key.into(), context)?,
+                T::try_from_js(&value, context)?,
+            );
+        }
+        Ok(map)
+    }
+}
+
+macro_rules! impl_try_from_js_for_tuple {
+    ($($name:ident: $index:literal),+) => {
+        impl<$($name),+> TryFromJs for ($($name,)+)
+        where
+            $($name: TryFromJs,)+
+        {
+            /// Converts the first elements of an array-like object to a tuple.
+            fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+                let object = value
+                    .as_object()
+                    .ok_or_else(|| conversion_error(value, "an array"))?;
+                Ok(($(
+                    $name::try_from_js(&object.get($index, context)?, context)?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_try_from_js_for_tuple!(A: 0);
+impl_try_from_js_for_tuple!(A: 0, B: 1);
+impl_try_from_js_for_tuple!(A: 0, B: 1, C: 2);
+impl_try_from_js_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_try_from_js_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_try_from_js_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
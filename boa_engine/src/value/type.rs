@@ -1,4 +1,4 @@
-use super::JsValue;
+use super::{JsValue, JsVariant};
 
 /// Possible types of values as defined at <https://tc39.es/ecma262/#sec-typeof-operator>.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -21,15 +21,15 @@ impl JsValue {
     ///
     /// Check [`JsValue::type_of`] if you need to call the `typeof` operator.
     pub fn get_type(&self) -> Type {
-        match *self {
-            Self::Rational(_) | Self::Integer(_) => Type::Number,
-            Self::String(_) => Type::String,
-            Self::Boolean(_) => Type::Boolean,
-            Self::Symbol(_) => Type::Symbol,
-            Self::Null => Type::Null,
-            Self::Undefined => Type::Undefined,
-            Self::BigInt(_) => Type::BigInt,
-            Self::Object(_) => Type::Object,
+        match self.variant() {
+            JsVariant::Rational(_) | JsVariant::Integer(_) => Type::Number,
+            JsVariant::String(_) => Type::String,
+            JsVariant::Boolean(_) => Type::Boolean,
+            JsVariant::Symbol(_) => Type::Symbol,
+            JsVariant::Null => Type::Null,
+            JsVariant::Undefined => Type::Undefined,
+            JsVariant::BigInt(_) => Type::BigInt,
+            JsVariant::Object(_) => Type::Object,
         }
     }
 }
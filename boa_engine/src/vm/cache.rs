@@ -0,0 +1,859 @@
+//! Binary serialization of compiled [`CodeBlock`]s.
+//!
+//! A bytecode cache stores a tree of `CodeBlock`s together with everything they reference that
+//! does not live in the code blocks themselves: the strings of the interned symbols and the
+//! compile time environments. Loading a cache into a [`Context`] restores the code without
+//! lexing, parsing or compiling the source text again.
+//!
+//! The format has the following layout, with all integers stored in little endian:
+//!
+//! - header: the `BOAC` magic bytes, the format version, the version of the engine and a
+//!   checksum of the rest of the cache.
+//! - string table: the UTF-16 code units of every symbol referenced by the code.
+//! - environment table: the compile time environments, ordered so that every environment comes
+//!   after its outer environment.
+//! - the code block tree.
+//!
+//! Caches are only loadable by the same version of the engine that produced them, since the
+//! bytecode instruction set is not stable between versions. The checksum rejects caches that were
+//! truncated or corrupted on disk, and the instructions of every code block are verified when it is
+//! loaded, so that their operands cannot index out of the tables of the code block.
+
+use crate::{
+    builtins::function::ThisMode,
+    environments::{BindingLocator, CompileTimeBinding, CompileTimeEnvironment},
+    error::JsNativeError,
+    syntax::ast::{node::FormalParameterListFlags, Position},
+    vm::{CodeBlock, FunctionParameters, Opcode, PropertyCache},
+    Context, JsBigInt, JsResult, JsString, JsValue,
+};
+use boa_gc::{Cell, Gc};
+use boa_interner::Sym;
+use rustc_hash::FxHashMap;
+use std::{convert::TryFrom, mem::size_of};
+
+/// The magic bytes at the start of every bytecode cache.
+const MAGIC: &[u8; 4] = b"BOAC";
+
+/// The version of the bytecode cache format.
+///
+/// This has to be increased every time the layout of the format changes.
+const FORMAT_VERSION: u32 = 3;
+
+/// The version of the engine that produced a bytecode cache.
+const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The index of the global environment in the environment table.
+const GLOBAL_ENVIRONMENT: u32 = 0;
+
+const LITERAL_STRING: u8 = 0;
+const LITERAL_BIGINT: u8 = 1;
+
+const BINDING_DECLARATIVE: u8 = 0;
+const BINDING_GLOBAL: u8 = 1;
+const BINDING_MUTATE_IMMUTABLE: u8 = 2;
+
+/// Serializes the `CodeBlock` tree with root `code`, which must have been compiled by `context`.
+pub(crate) fn serialize(code: &CodeBlock, context: &Context) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+    // The global environment is always stored, since the declarative bindings of the code can
+    // refer to it without it being in the compile time environments of any code block.
+    encoder.environment_index(&context.realm.compile_env());
+    let mut body = Vec::new();
+    encoder.write_code_block(&mut body, code);
+
+    let mut out = Vec::with_capacity(body.len());
+    out.extend_from_slice(MAGIC);
+    write_u32(&mut out, FORMAT_VERSION);
+    write_bytes(&mut out, ENGINE_VERSION.as_bytes());
+
+    let mut payload = Vec::new();
+    write_usize(&mut payload, encoder.symbols.len());
+    for sym in &encoder.symbols {
+        write_utf16(&mut payload, &context.interner().resolve_expect_utf16(*sym));
+    }
+
+    write_usize(&mut payload, encoder.environment_indices.len());
+    payload.extend_from_slice(&encoder.environment_table);
+    payload.extend_from_slice(&body);
+
+    out.extend_from_slice(&checksum(&payload).to_le_bytes());
+    out.extend_from_slice(&payload);
+    out
+}
+
+/// Deserializes a `CodeBlock` tree, interning its strings in the interner of `context` and
+/// declaring the global bindings it needs in the global environment of `context`.
+pub(crate) fn deserialize(bytes: &[u8], context: &mut Context) -> JsResult<Gc<CodeBlock>> {
+    let mut reader = Reader { bytes, position: 0 };
+
+    if reader.read_slice(MAGIC.len())? != MAGIC {
+        return Err(JsNativeError::error()
+            .with_message("invalid bytecode cache: missing magic bytes")
+            .into());
+    }
+    let format_version = reader.read_u32()?;
+    if format_version != FORMAT_VERSION {
+        return Err(JsNativeError::error()
+            .with_message(format!(
+                "unsupported bytecode cache format version {format_version}, expected {FORMAT_VERSION}"
+            ))
+            .into());
+    }
+    let len = reader.read_usize()?;
+    let engine_version = reader.read_slice(len)?;
+    if engine_version != ENGINE_VERSION.as_bytes() {
+        return Err(JsNativeError::error()
+            .with_message(format!(
+                "bytecode cache was created by version {} of the engine, expected {ENGINE_VERSION}",
+                String::from_utf8_lossy(engine_version)
+            ))
+            .into());
+    }
+
+    let expected_checksum = reader.read_u64()?;
+    if checksum(&bytes[reader.position..]) != expected_checksum {
+        return Err(JsNativeError::error()
+            .with_message("invalid bytecode cache: checksum mismatch")
+            .into());
+    }
+
+    let symbol_count = reader.read_usize()?;
+    let mut symbols = Vec::new();
+    for _ in 0..symbol_count {
+        let string = reader.read_utf16()?;
+        symbols.push(context.interner_mut().get_or_intern_utf16(&string));
+    }
+
+    let mut decoder = Decoder {
+        symbols,
+        environments: Vec::new(),
+    };
+
+    let environment_count = reader.read_usize()?;
+    for index in 0..environment_count {
+        decoder.read_environment(&mut reader, index, context)?;
+    }
+
+    let code = decoder.read_code_block(&mut reader)?;
+    if reader.position != bytes.len() {
+        return Err(Reader::invalid());
+    }
+
+    declare_global_vars(&code, context);
+
+    Ok(Gc::new(code))
+}
+
+/// Computes the 64-bit FNV-1a hash of `bytes`, which is stored in the header of the caches to
+/// detect the caches that were corrupted.
+pub(super) fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Creates the global object properties for the `var` and function declarations of `code` and
+/// its inner functions, like the bytecode compiler does when it compiles them.
+fn declare_global_vars(code: &CodeBlock, context: &mut Context) {
+    for name in &code.global_var_names {
+        context.create_mutable_binding(*name, true);
+    }
+    for function in &code.functions {
+        declare_global_vars(function, context);
+    }
+}
+
+#[derive(Default)]
+struct Encoder {
+    symbols: Vec<Sym>,
+    symbol_indices: FxHashMap<Sym, u32>,
+    /// Maps the addresses of the environments in the environment table to their indices.
+    environment_indices: FxHashMap<*const Cell<CompileTimeEnvironment>, u32>,
+    environment_table: Vec<u8>,
+}
+
+impl Encoder {
+    fn write_sym(&mut self, out: &mut Vec<u8>, sym: Sym) {
+        let index = if let Some(index) = self.symbol_indices.get(&sym) {
+            *index
+        } else {
+            let index = self.symbols.len() as u32;
+            self.symbols.push(sym);
+            self.symbol_indices.insert(sym, index);
+            index
+        };
+        write_u32(out, index);
+    }
+
+    /// Writes the index of `environment` in the environment table, adding it and its outer
+    /// environments to the table if they are not in it yet.
+    fn write_environment(
+        &mut self,
+        out: &mut Vec<u8>,
+        environment: &Gc<Cell<CompileTimeEnvironment>>,
+    ) {
+        let index = self.environment_index(environment);
+        write_u32(out, index);
+    }
+
+    fn environment_index(&mut self, environment: &Gc<Cell<CompileTimeEnvironment>>) -> u32 {
+        let address: *const Cell<CompileTimeEnvironment> = &**environment;
+        if let Some(index) = self.environment_indices.get(&address) {
+            return *index;
+        }
+
+        let borrow = environment.borrow();
+        let mut entry = Vec::new();
+        if let Some(outer) = borrow.outer() {
+            let outer = self.environment_index(&outer);
+            write_u32(&mut entry, outer);
+        } else {
+            // The global environment is always the first entry.
+            if !self.environment_indices.is_empty() {
+                return GLOBAL_ENVIRONMENT;
+            }
+            write_u32(&mut entry, u32::MAX);
+        }
+        write_bool(&mut entry, borrow.is_function());
+
+        let mut bindings = borrow.bindings().collect::<Vec<_>>();
+        bindings.sort_by_key(|(_, binding)| binding.index);
+        write_usize(&mut entry, bindings.len());
+        for (name, binding) in bindings {
+            self.write_sym(&mut entry, name);
+            write_usize(&mut entry, binding.index);
+            write_bool(&mut entry, binding.mutable);
+            write_bool(&mut entry, binding.lex);
+        }
+        drop(borrow);
+
+        self.environment_table.extend_from_slice(&entry);
+        let index = self.environment_indices.len() as u32;
+        self.environment_indices.insert(address, index);
+        index
+    }
+
+    fn write_binding(&mut self, out: &mut Vec<u8>, binding: &BindingLocator) {
+        if binding.is_global() {
+            out.push(BINDING_GLOBAL);
+            self.write_sym(out, binding.name());
+        } else if binding.is_mutate_immutable() {
+            out.push(BINDING_MUTATE_IMMUTABLE);
+            self.write_sym(out, binding.name());
+        } else {
+            out.push(BINDING_DECLARATIVE);
+            self.write_sym(out, binding.name());
+            write_usize(out, binding.environment_index());
+            write_usize(out, binding.binding_index());
+        }
+    }
+
+    fn write_code_block(&mut self, out: &mut Vec<u8>, code: &CodeBlock) {
+        self.write_sym(out, code.name);
+        write_u32(out, code.length);
+        write_bool(out, code.strict);
+        out.push(match code.this_mode {
+            ThisMode::Lexical => 0,
+            ThisMode::Strict => 1,
+            ThisMode::Global => 2,
+        });
+
+        write_usize(out, code.params.names.len());
+        for names in code.params.names.iter() {
+            write_usize(out, names.len());
+            for name in names.iter() {
+                self.write_sym(out, *name);
+            }
+        }
+        out.push(code.params.flags.bits());
+
+        write_bytes(out, &code.code);
+
+        write_usize(out, code.literals.len());
+        for literal in &code.literals {
+            if let Some(string) = literal.as_string() {
+                out.push(LITERAL_STRING);
+                write_utf16(out, string);
+            } else if let Some(bigint) = literal.as_bigint() {
+                out.push(LITERAL_BIGINT);
+                write_bytes(out, bigint.to_string_radix(16).as_bytes());
+            } else {
+                unreachable!("literals can only be strings or bigints");
+            }
+        }
+
+        write_usize(out, code.names.len());
+        for name in &code.names {
+            self.write_sym(out, *name);
+        }
+
+        write_usize(out, code.property_caches.len());
+
+        write_usize(out, code.bindings.len());
+        for binding in &code.bindings {
+            self.write_binding(out, binding);
+        }
+
+        write_usize(out, code.num_bindings);
+
+        write_usize(out, code.functions.len());
+        for function in &code.functions {
+            self.write_code_block(out, function);
+        }
+
+        if let Some(binding) = &code.arguments_binding {
+            write_bool(out, true);
+            self.write_binding(out, binding);
+        } else {
+            write_bool(out, false);
+        }
+
+        write_usize(out, code.compile_environments.len());
+        for environment in &code.compile_environments {
+            self.write_environment(out, environment);
+        }
+
+        write_bool(out, code.is_class_constructor);
+        write_u32(out, code.function_environment_push_location);
+
+        if let Some(source_name) = &code.source_name {
+            write_bool(out, true);
+            write_utf16(out, source_name);
+        } else {
+            write_bool(out, false);
+        }
+
+        write_usize(out, code.line_table.len());
+        for (location, position) in &code.line_table {
+            write_u32(out, *location);
+            write_u32(out, position.line_number());
+            write_u32(out, position.column_number());
+        }
+
+        write_usize(out, code.global_var_names.len());
+        for name in &code.global_var_names {
+            self.write_sym(out, *name);
+        }
+    }
+}
+
+struct Decoder {
+    symbols: Vec<Sym>,
+    environments: Vec<Gc<Cell<CompileTimeEnvironment>>>,
+}
+
+impl Decoder {
+    fn read_sym(&self, reader: &mut Reader<'_>) -> JsResult<Sym> {
+        let index = reader.read_u32()? as usize;
+        self.symbols.get(index).copied().ok_or_else(Reader::invalid)
+    }
+
+    fn read_environment_index(
+        &self,
+        reader: &mut Reader<'_>,
+    ) -> JsResult<Gc<Cell<CompileTimeEnvironment>>> {
+        let index = reader.read_u32()? as usize;
+        self.environments
+            .get(index)
+            .cloned()
+            .ok_or_else(Reader::invalid)
+    }
+
+    /// Reads the environment at `index` of the environment table.
+    ///
+    /// The global environment is not recreated. Instead, its bindings are declared in the global
+    /// environment of `context`, which fails if they conflict with the existing bindings.
+    fn read_environment(
+        &mut self,
+        reader: &mut Reader<'_>,
+        index: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let outer = reader.read_u32()?;
+        let function_scope = reader.read_bool()?;
+        let global = index == GLOBAL_ENVIRONMENT as usize;
+        if global != (outer == u32::MAX) {
+            return Err(Reader::invalid());
+        }
+
+        let environment = if global {
+            context.realm.compile_env()
+        } else {
+            let outer = self
+                .environments
+                .get(outer as usize)
+                .cloned()
+                .ok_or_else(Reader::invalid)?;
+            Gc::new(Cell::new(CompileTimeEnvironment::new(
+                outer,
+                function_scope,
+            )))
+        };
+
+        let binding_count = reader.read_usize()?;
+        for _ in 0..binding_count {
+            let name = self.read_sym(reader)?;
+            let binding = CompileTimeBinding {
+                index: reader.read_usize()?,
+                mutable: reader.read_bool()?,
+                lex: reader.read_bool()?,
+            };
+
+            let mut environment = environment.borrow_mut();
+            if global {
+                match environment.get_compile_time_binding(name) {
+                    Some(existing) if existing == binding => continue,
+                    None if binding.index == environment.num_bindings() => {}
+                    _ => {
+                        return Err(JsNativeError::error()
+                            .with_message(format!(
+                                "bytecode cache conflicts with the global binding `{}`",
+                                context.interner().resolve_expect(name)
+                            ))
+                            .into());
+                    }
+                }
+            }
+            environment.insert_binding(name, binding);
+        }
+
+        self.environments.push(environment);
+        Ok(())
+    }
+
+    fn read_binding(&self, reader: &mut Reader<'_>) -> JsResult<BindingLocator> {
+        let kind = reader.read_u8()?;
+        let name = self.read_sym(reader)?;
+        match kind {
+            BINDING_DECLARATIVE => Ok(BindingLocator::declarative(
+                name,
+                reader.read_usize()?,
+                reader.read_usize()?,
+            )),
+            BINDING_GLOBAL => Ok(BindingLocator::global(name)),
+            BINDING_MUTATE_IMMUTABLE => Ok(BindingLocator::mutate_immutable(name)),
+            _ => Err(Reader::invalid()),
+        }
+    }
+
+    fn read_code_block(&self, reader: &mut Reader<'_>) -> JsResult<CodeBlock> {
+        let name = self.read_sym(reader)?;
+        let length = reader.read_u32()?;
+        let strict = reader.read_bool()?;
+        let mut code = CodeBlock::new(name, length, strict);
+
+        code.this_mode = match reader.read_u8()? {
+            0 => ThisMode::Lexical,
+            1 => ThisMode::Strict,
+            2 => ThisMode::Global,
+            _ => return Err(Reader::invalid()),
+        };
+
+        let parameter_count = reader.read_usize()?;
+        let mut parameters = Vec::new();
+        for _ in 0..parameter_count {
+            let name_count = reader.read_usize()?;
+            let mut names = Vec::new();
+            for _ in 0..name_count {
+                names.push(self.read_sym(reader)?);
+            }
+            parameters.push(names.into_boxed_slice());
+        }
+        code.params = FunctionParameters {
+            names: parameters.into_boxed_slice(),
+            flags: FormalParameterListFlags::from_bits(reader.read_u8()?)
+                .ok_or_else(Reader::invalid)?,
+        };
+
+        let len = reader.read_usize()?;
+        code.code = reader.read_slice(len)?.to_vec();
+
+        let literal_count = reader.read_usize()?;
+        for _ in 0..literal_count {
+            let literal = match reader.read_u8()? {
+                LITERAL_STRING => JsValue::new(JsString::from(reader.read_utf16()?)),
+                LITERAL_BIGINT => {
+                    let len = reader.read_usize()?;
+                    let digits = std::str::from_utf8(reader.read_slice(len)?)
+                        .map_err(|_| Reader::invalid())?;
+                    JsValue::new(
+                        JsBigInt::from_string_radix(digits, 16).ok_or_else(Reader::invalid)?,
+                    )
+                }
+                _ => return Err(Reader::invalid()),
+            };
+            code.literals.push(literal);
+        }
+
+        let name_count = reader.read_usize()?;
+        for _ in 0..name_count {
+            code.names.push(self.read_sym(reader)?);
+        }
+
+        // Every property cache belongs to an instruction, so there cannot be more caches than
+        // bytes of code.
+        let property_cache_count = reader.read_usize()?;
+        if property_cache_count > code.code.len() {
+            return Err(Reader::invalid());
+        }
+        code.property_caches = vec![PropertyCache::default(); property_cache_count];
+
+        let binding_count = reader.read_usize()?;
+        for _ in 0..binding_count {
+            code.bindings.push(self.read_binding(reader)?);
+        }
+
+        code.num_bindings = reader.read_usize()?;
+
+        let function_count = reader.read_usize()?;
+        for _ in 0..function_count {
+            code.functions.push(Gc::new(self.read_code_block(reader)?));
+        }
+
+        if reader.read_bool()? {
+            code.arguments_binding = Some(self.read_binding(reader)?);
+        }
+
+        let environment_count = reader.read_usize()?;
+        for _ in 0..environment_count {
+            code.compile_environments
+                .push(self.read_environment_index(reader)?);
+        }
+
+        code.is_class_constructor = reader.read_bool()?;
+        code.function_environment_push_location = reader.read_u32()?;
+
+        if reader.read_bool()? {
+            code.source_name = Some(JsString::from(reader.read_utf16()?));
+        }
+
+        let line_count = reader.read_usize()?;
+        for _ in 0..line_count {
+            let location = reader.read_u32()?;
+            let line_number = reader.read_u32()?;
+            let column_number = reader.read_u32()?;
+            if line_number == 0 || column_number == 0 {
+                return Err(Reader::invalid());
+            }
+            code.line_table
+                .push((location, Position::new(line_number, column_number)));
+        }
+
+        let global_var_count = reader.read_usize()?;
+        for _ in 0..global_var_count {
+            code.global_var_names.push(self.read_sym(reader)?);
+        }
+
+        verify_instructions(&code)?;
+
+        Ok(code)
+    }
+}
+
+/// Checks that the bytecode of `code` only contains valid instructions, whose operands are inside
+/// of the bytecode, index into the tables of `code` and jump to the start of an instruction.
+fn verify_instructions(code: &CodeBlock) -> JsResult<()> {
+    let bytes = &code.code;
+    let mut instruction_starts = vec![false; bytes.len() + 1];
+    let mut jump_targets = Vec::new();
+
+    let mut pc = 0;
+    while pc < bytes.len() {
+        instruction_starts[pc] = true;
+        let opcode = Opcode::try_from(bytes[pc]).map_err(|_| Reader::invalid())?;
+        pc += size_of::<Opcode>();
+
+        let mut operand = || -> JsResult<usize> {
+            let operand = bytes
+                .get(pc..pc + size_of::<u32>())
+                .ok_or_else(Reader::invalid)?;
+            pc += size_of::<u32>();
+            usize::try_from(u32::from_le_bytes([
+                operand[0], operand[1], operand[2], operand[3],
+            ]))
+            .map_err(|_| Reader::invalid())
+        };
+        let in_range = |index: usize, len: usize| {
+            if index < len {
+                Ok(())
+            } else {
+                Err(Reader::invalid())
+            }
+        };
+
+        match opcode {
+            Opcode::PushInt8 => pc += size_of::<i8>(),
+            Opcode::PushInt16 => pc += size_of::<i16>(),
+            Opcode::PushInt32 => pc += size_of::<i32>(),
+            Opcode::PushRational => pc += size_of::<f64>(),
+            Opcode::PushLiteral => in_range(operand()?, code.literals.len())?,
+            Opcode::Jump
+            | Opcode::JumpIfFalse
+            | Opcode::JumpIfNotUndefined
+            | Opcode::CatchStart
+            | Opcode::FinallySetJump
+            | Opcode::Case
+            | Opcode::Default
+            | Opcode::LogicalAnd
+            | Opcode::LogicalOr
+            | Opcode::Coalesce
+            | Opcode::ForInLoopInitIterator
+            | Opcode::ForInLoopNext
+            | Opcode::GeneratorNextDelegate => jump_targets.push(operand()?),
+            Opcode::TryStart => {
+                jump_targets.push(operand()?);
+                jump_targets.push(operand()?);
+            }
+            Opcode::CallEval
+            | Opcode::Call
+            | Opcode::New
+            | Opcode::SuperCall
+            | Opcode::ConcatToString => {
+                operand()?;
+            }
+            Opcode::CopyDataProperties => {
+                operand()?;
+                operand()?;
+            }
+            Opcode::PushDeclarativeEnvironment | Opcode::PushFunctionEnvironment => {
+                operand()?;
+                in_range(operand()?, code.compile_environments.len())?;
+            }
+            Opcode::GetFunction
+            | Opcode::GetFunctionAsync
+            | Opcode::GetGenerator
+            | Opcode::GetGeneratorAsync => in_range(operand()?, code.functions.len())?,
+            Opcode::DefInitArg
+            | Opcode::DefVar
+            | Opcode::DefInitVar
+            | Opcode::DefLet
+            | Opcode::DefInitLet
+            | Opcode::DefInitConst
+            | Opcode::GetName
+            | Opcode::GetNameOrUndefined
+            | Opcode::SetName => in_range(operand()?, code.bindings.len())?,
+            Opcode::GetPropertyByName | Opcode::SetPropertyByName => {
+                in_range(operand()?, code.names.len())?;
+                in_range(operand()?, code.property_caches.len())?;
+            }
+            Opcode::DefineOwnPropertyByName
+            | Opcode::DefineClassMethodByName
+            | Opcode::SetPropertyGetterByName
+            | Opcode::DefineClassGetterByName
+            | Opcode::SetPropertySetterByName
+            | Opcode::DefineClassSetterByName
+            | Opcode::AssignPrivateField
+            | Opcode::SetPrivateField
+            | Opcode::SetPrivateMethod
+            | Opcode::SetPrivateSetter
+            | Opcode::SetPrivateGetter
+            | Opcode::GetPrivateField
+            | Opcode::DeletePropertyByName
+            | Opcode::PushClassFieldPrivate
+            | Opcode::PushClassPrivateGetter
+            | Opcode::PushClassPrivateSetter
+            | Opcode::PushClassPrivateMethod => in_range(operand()?, code.names.len())?,
+            Opcode::Pop
+            | Opcode::PopIfThrown
+            | Opcode::Dup
+            | Opcode::Swap
+            | Opcode::PushZero
+            | Opcode::PushOne
+            | Opcode::PushNaN
+            | Opcode::PushPositiveInfinity
+            | Opcode::PushNegativeInfinity
+            | Opcode::PushNull
+            | Opcode::PushTrue
+            | Opcode::PushFalse
+            | Opcode::PushUndefined
+            | Opcode::PushEmptyObject
+            | Opcode::PushClassPrototype
+            | Opcode::SetClassPrototype
+            | Opcode::SetHomeObject
+            | Opcode::Add
+            | Opcode::Sub
+            | Opcode::Div
+            | Opcode::Mul
+            | Opcode::Mod
+            | Opcode::Pow
+            | Opcode::ShiftRight
+            | Opcode::ShiftLeft
+            | Opcode::UnsignedShiftRight
+            | Opcode::BitOr
+            | Opcode::BitAnd
+            | Opcode::BitXor
+            | Opcode::BitNot
+            | Opcode::In
+            | Opcode::Eq
+            | Opcode::StrictEq
+            | Opcode::NotEq
+            | Opcode::StrictNotEq
+            | Opcode::GreaterThan
+            | Opcode::GreaterThanOrEq
+            | Opcode::LessThan
+            | Opcode::LessThanOrEq
+            | Opcode::InstanceOf
+            | Opcode::TypeOf
+            | Opcode::Void
+            | Opcode::LogicalNot
+            | Opcode::Pos
+            | Opcode::Neg
+            | Opcode::Inc
+            | Opcode::IncPost
+            | Opcode::Dec
+            | Opcode::DecPost
+            | Opcode::GetPropertyByValue
+            | Opcode::GetPropertyByValuePush
+            | Opcode::SetPropertyByValue
+            | Opcode::DefineOwnPropertyByValue
+            | Opcode::DefineClassMethodByValue
+            | Opcode::SetPropertyGetterByValue
+            | Opcode::DefineClassGetterByValue
+            | Opcode::SetPropertySetterByValue
+            | Opcode::DefineClassSetterByValue
+            | Opcode::DeletePropertyByValue
+            | Opcode::ToPropertyKey
+            | Opcode::ToBoolean
+            | Opcode::Throw
+            | Opcode::TryEnd
+            | Opcode::CatchEnd
+            | Opcode::CatchEnd2
+            | Opcode::FinallyStart
+            | Opcode::FinallyEnd
+            | Opcode::This
+            | Opcode::Super
+            | Opcode::Return
+            | Opcode::PopEnvironment
+            | Opcode::LoopStart
+            | Opcode::LoopContinue
+            | Opcode::LoopEnd
+            | Opcode::InitIterator
+            | Opcode::IteratorNext
+            | Opcode::IteratorClose
+            | Opcode::IteratorToArray
+            | Opcode::RequireObjectCoercible
+            | Opcode::ValueNotNullOrUndefined
+            | Opcode::RestParameterInit
+            | Opcode::RestParameterPop
+            | Opcode::PushValueToArray
+            | Opcode::PushElisionToArray
+            | Opcode::PushIteratorToArray
+            | Opcode::PushNewArray
+            | Opcode::PopOnReturnAdd
+            | Opcode::PopOnReturnSub
+            | Opcode::Yield
+            | Opcode::GeneratorNext
+            | Opcode::AsyncGeneratorNext
+            | Opcode::PushClassField
+            | Opcode::SuperCallDerived
+            | Opcode::Await
+            | Opcode::CallEvalSpread
+            | Opcode::CallSpread
+            | Opcode::NewSpread
+            | Opcode::SuperCallSpread
+            | Opcode::Nop => {}
+        }
+    }
+
+    if pc != bytes.len() {
+        return Err(Reader::invalid());
+    }
+    instruction_starts[bytes.len()] = true;
+    for target in jump_targets {
+        if !instruction_starts.get(target).copied().unwrap_or(false) {
+            return Err(Reader::invalid());
+        }
+    }
+
+    Ok(())
+}
+
+/// A cursor over the bytes of a bytecode cache.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// The error returned for malformed bytecode caches.
+    fn invalid() -> crate::JsError {
+        JsNativeError::error()
+            .with_message("invalid bytecode cache: malformed data")
+            .into()
+    }
+
+    fn read_slice(&mut self, len: usize) -> JsResult<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(Self::invalid)?;
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self) -> JsResult<u8> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    fn read_bool(&mut self) -> JsResult<bool> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Self::invalid()),
+        }
+    }
+
+    fn read_u32(&mut self) -> JsResult<u32> {
+        let bytes = self.read_slice(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_u64(&mut self) -> JsResult<u64> {
+        let bytes = self.read_slice(8)?;
+        let mut array = [0; 8];
+        array.copy_from_slice(bytes);
+        Ok(u64::from_le_bytes(array))
+    }
+
+    fn read_usize(&mut self) -> JsResult<usize> {
+        usize::try_from(self.read_u32()?).map_err(|_| Self::invalid())
+    }
+
+    fn read_utf16(&mut self) -> JsResult<Vec<u16>> {
+        let len = self.read_usize()?;
+        let units = self
+            .read_slice(len.checked_mul(2).ok_or_else(Self::invalid)?)?
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect();
+        Ok(units)
+    }
+}
+
+fn write_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_usize(out: &mut Vec<u8>, value: usize) {
+    let value = u32::try_from(value).expect("bytecode cache values must fit in a u32");
+    write_u32(out, value);
+}
+
+fn write_bool(out: &mut Vec<u8>, value: bool) {
+    out.push(u8::from(value));
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_usize(out, bytes.len());
+    out.extend_from_slice(bytes);
+}
+
+fn write_utf16(out: &mut Vec<u8>, string: &[u16]) {
+    write_usize(out, string.len());
+    for unit in string {
+        out.extend_from_slice(&unit.to_le_bytes());
+    }
+}
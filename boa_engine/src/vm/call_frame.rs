@@ -2,8 +2,14 @@
 //!
 //! This module will provides everything needed to implement the `CallFrame`
 
-use crate::{object::JsObject, vm::CodeBlock};
+use crate::{
+    object::JsObject,
+    vm::{CodeBlock, SourceLocation},
+    JsString,
+};
 use boa_gc::{Finalize, Gc, Trace};
+use boa_interner::{Interner, Sym};
+use std::fmt;
 
 #[derive(Clone, Debug, Finalize, Trace)]
 pub struct CallFrame {
@@ -39,6 +45,29 @@ pub struct CallFrame {
 }
 
 impl CallFrame {
+    /// Gets the location in the source text of the instruction that is currently executed by
+    /// this frame, if the frame has position information for it.
+    pub(crate) fn location(&self) -> Option<SourceLocation> {
+        // The program counter already points past the opcode of the current instruction.
+        let pc = self.pc.saturating_sub(1) as u32;
+        let position = self.code.position(pc)?;
+        Some(SourceLocation::new(self.code.source_name.clone(), position))
+    }
+
+    /// Captures the function name and the current location of this frame.
+    pub(crate) fn stack_frame(&self, interner: &Interner) -> StackFrame {
+        let function_name = if self.code.name == Sym::EMPTY_STRING {
+            JsString::new("<anonymous>")
+        } else {
+            interner.resolve_expect(self.code.name).into()
+        };
+
+        StackFrame {
+            function_name,
+            location: self.location(),
+        }
+    }
+
     /// Tracks that one environment has been pushed in the current loop block.
     pub(crate) fn loop_env_stack_inc(&mut self) {
         *self
@@ -121,3 +150,39 @@ pub(crate) enum GeneratorResumeKind {
     Throw,
     Return,
 }
+
+/// An entry of a captured stack trace.
+///
+/// Stack traces are captured when an error object is created, and can be obtained with
+/// [`JsValue::stack_trace`](crate::JsValue::stack_trace).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+    function_name: JsString,
+    location: Option<SourceLocation>,
+}
+
+impl StackFrame {
+    /// Gets the name of the function that was executing in this frame.
+    ///
+    /// Anonymous functions are named `<anonymous>` and the top level code of scripts and
+    /// modules is named `<main>`.
+    #[inline]
+    pub fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+
+    /// Gets the location in the source text that was executing in this frame, if it is known.
+    #[inline]
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_ref()
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} ({location})", self.function_name),
+            None => write!(f, "{}", self.function_name),
+        }
+    }
+}
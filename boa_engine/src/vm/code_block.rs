@@ -13,14 +13,19 @@ use crate::{
     },
     context::intrinsics::StandardConstructors,
     environments::{BindingLocator, CompileTimeEnvironment},
+    error::JsNativeError,
     object::{
         internal_methods::get_prototype_from_constructor, JsObject, ObjectData, PrivateElement,
     },
     property::PropertyDescriptor,
-    syntax::ast::node::FormalParameterList,
+    syntax::ast::{
+        node::{FormalParameterList, FormalParameterListFlags},
+        Position,
+    },
+    value::JsVariant,
     vm::call_frame::GeneratorResumeKind,
-    vm::{call_frame::FinallyReturn, CallFrame, Opcode},
-    Context, JsResult, JsValue,
+    vm::{call_frame::FinallyReturn, CallFrame, Opcode, PropertyCache},
+    Context, JsResult, JsString, JsValue,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_interner::{Interner, Sym, ToInternedString};
@@ -49,6 +54,91 @@ unsafe impl Readable for i64 {}
 unsafe impl Readable for f32 {}
 unsafe impl Readable for f64 {}
 
+/// A location in a source text, used to report where some code was executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    source_name: Option<JsString>,
+    position: Position,
+}
+
+impl SourceLocation {
+    /// Creates a new `SourceLocation`.
+    #[inline]
+    pub fn new(source_name: Option<JsString>, position: Position) -> Self {
+        Self {
+            source_name,
+            position,
+        }
+    }
+
+    /// Gets the name of the source text, if it has one.
+    #[inline]
+    pub fn source_name(&self) -> Option<&JsString> {
+        self.source_name.as_ref()
+    }
+
+    /// Gets the line and column in the source text.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self
+            .source_name
+            .as_ref()
+            .map_or_else(|| "<anonymous>".to_owned(), JsString::to_std_string_escaped);
+        write!(f, "{name}:{}", self.position)
+    }
+}
+
+/// The parameters of a function, with the information needed to call it.
+///
+/// The initializers and binding patterns of the parameters are compiled into the bytecode of the
+/// function, so only the names and the properties of the parameter list are kept.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FunctionParameters {
+    /// The names bound by each parameter.
+    pub(crate) names: Box<[Box<[Sym]>]>,
+    pub(crate) flags: FormalParameterListFlags,
+}
+
+impl FunctionParameters {
+    /// Returns the number of parameters, including the rest parameter.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Indicates if the parameter list is simple.
+    #[inline]
+    pub(crate) fn is_simple(&self) -> bool {
+        self.flags.contains(FormalParameterListFlags::IS_SIMPLE)
+    }
+
+    /// Indicates if the parameter list has expressions in its parameters.
+    #[inline]
+    pub(crate) fn has_expressions(&self) -> bool {
+        self.flags
+            .contains(FormalParameterListFlags::HAS_EXPRESSIONS)
+    }
+}
+
+impl From<&FormalParameterList> for FunctionParameters {
+    fn from(params: &FormalParameterList) -> Self {
+        Self {
+            names: params
+                .parameters
+                .iter()
+                .map(|param| param.names().into_boxed_slice())
+                .collect(),
+            flags: params.flags,
+        }
+    }
+}
+
 /// The internal representation of a JavaScript function.
 ///
 /// A `CodeBlock` is generated for each function compiled by the
@@ -71,7 +161,7 @@ pub struct CodeBlock {
 
     /// Parameters passed to this function.
     #[unsafe_ignore_trace]
-    pub(crate) params: FormalParameterList,
+    pub(crate) params: FunctionParameters,
 
     /// Bytecode
     pub(crate) code: Vec<u8>,
@@ -83,6 +173,10 @@ pub struct CodeBlock {
     #[unsafe_ignore_trace]
     pub(crate) names: Vec<Sym>,
 
+    /// The inline caches of the property accesses by name.
+    #[unsafe_ignore_trace]
+    pub(crate) property_caches: Vec<PropertyCache>,
+
     /// Locators for all bindings in the codeblock.
     #[unsafe_ignore_trace]
     pub(crate) bindings: Vec<BindingLocator>,
@@ -108,6 +202,19 @@ pub struct CodeBlock {
     /// We execute the parameter expressions in the function code and push the function environment afterward.
     /// When the execution of the parameter expressions throws an error, we do not need to pop the function environment.
     pub(crate) function_environment_push_location: u32,
+
+    /// The name of the source text that this function was compiled from, if any.
+    pub(crate) source_name: Option<JsString>,
+
+    /// Maps bytecode locations to the positions in the source text where the code at those
+    /// locations starts, ordered by bytecode location.
+    #[unsafe_ignore_trace]
+    pub(crate) line_table: Vec<(u32, Position)>,
+
+    /// Names of the `var` and function declarations of this code that were created as properties
+    /// of the global object at compile time.
+    #[unsafe_ignore_trace]
+    pub(crate) global_var_names: Vec<Sym>,
 }
 
 impl CodeBlock {
@@ -117,6 +224,7 @@ impl CodeBlock {
             code: Vec::new(),
             literals: Vec::new(),
             names: Vec::new(),
+            property_caches: Vec::new(),
             bindings: Vec::new(),
             num_bindings: 0,
             functions: Vec::new(),
@@ -124,14 +232,33 @@ impl CodeBlock {
             length,
             strict,
             this_mode: ThisMode::Global,
-            params: FormalParameterList::default(),
+            params: FunctionParameters::default(),
             arguments_binding: None,
             compile_environments: Vec::new(),
             is_class_constructor: false,
             function_environment_push_location: 0,
+            source_name: None,
+            line_table: Vec::new(),
+            global_var_names: Vec::new(),
         }
     }
 
+    /// Gets the name of the source text that this function was compiled from, if any.
+    #[inline]
+    pub fn source_name(&self) -> Option<&JsString> {
+        self.source_name.as_ref()
+    }
+
+    /// Gets the position in the source text of the instruction at the bytecode location `pc`.
+    ///
+    /// Returns `None` if there is no position information for that instruction.
+    pub fn position(&self, pc: u32) -> Option<Position> {
+        let index = self
+            .line_table
+            .partition_point(|(location, _)| *location <= pc);
+        index.checked_sub(1).map(|index| self.line_table[index].1)
+    }
+
     /// Read type T from code.
     ///
     /// # Safety
@@ -249,9 +376,17 @@ impl CodeBlock {
                     interner.resolve_expect(self.bindings[operand as usize].name()),
                 )
             }
-            Opcode::GetPropertyByName
-            | Opcode::SetPropertyByName
-            | Opcode::DefineOwnPropertyByName
+            Opcode::GetPropertyByName | Opcode::SetPropertyByName => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let cache = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!(
+                    "{operand:04}: '{}', cache: {cache}",
+                    interner.resolve_expect(self.names[operand as usize]),
+                )
+            }
+            Opcode::DefineOwnPropertyByName
             | Opcode::DefineClassMethodByName
             | Opcode::SetPropertyGetterByName
             | Opcode::DefineClassGetterByName
@@ -499,13 +634,15 @@ pub(crate) fn create_function_object(
 
         Function::Async {
             code,
-            environments: context.realm.environments.clone(),
+            environments: context.vm.environments.clone(),
+            realm: context.realm.clone(),
             promise_capability,
         }
     } else {
         Function::Ordinary {
             code,
-            environments: context.realm.environments.clone(),
+            environments: context.vm.environments.clone(),
+            realm: context.realm.clone(),
             constructor_kind: ConstructorKind::Base,
             home_object: None,
             fields: Vec::new(),
@@ -599,7 +736,8 @@ pub(crate) fn create_generator_function_object(
     let constructor = if r#async {
         let function = Function::AsyncGenerator {
             code,
-            environments: context.realm.environments.clone(),
+            environments: context.vm.environments.clone(),
+            realm: context.realm.clone(),
         };
         JsObject::from_proto_and_data(
             function_prototype,
@@ -608,7 +746,8 @@ pub(crate) fn create_generator_function_object(
     } else {
         let function = Function::Generator {
             code,
-            environments: context.realm.environments.clone(),
+            environments: context.vm.environments.clone(),
+            realm: context.realm.clone(),
         };
         JsObject::from_proto_and_data(function_prototype, ObjectData::generator_function(function))
     };
@@ -643,16 +782,38 @@ impl JsObject {
         let this_function_object = self.clone();
 
         if !self.is_callable() {
-            return context.throw_type_error("not a callable function");
+            return Err(JsNativeError::typ()
+                .with_message("not a callable function")
+                .into());
+        }
+
+        if context.vm.frames.len() >= context.vm.runtime_limits.recursion_limit() {
+            return Err(JsNativeError::range()
+                .with_message("Maximum call stack size exceeded")
+                .into());
         }
 
         let object = self.borrow();
         let function_object = object.as_function().expect("not a function");
 
+        // Functions run in the realm they were created in.
+        {
+            let realm = function_object.realm();
+            if *realm != context.realm {
+                let realm = realm.clone();
+                drop(object);
+                let previous = context.enter_realm(realm);
+                let result = self.call_internal(this, args, context);
+                context.enter_realm(previous);
+                return result;
+            }
+        }
+
         match function_object {
             Function::Native {
                 function,
                 constructor,
+                ..
             } => {
                 let function = *function;
                 let constructor = *constructor;
@@ -681,11 +842,12 @@ impl JsObject {
                 drop(object);
 
                 if code.is_class_constructor {
-                    return context
-                        .throw_type_error("Class constructor cannot be invoked without 'new'");
+                    return Err(JsNativeError::typ()
+                        .with_message("Class constructor cannot be invoked without 'new'")
+                        .into());
                 }
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
@@ -704,7 +866,7 @@ impl JsObject {
                 };
 
                 if code.params.has_expressions() {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[1].clone(),
                         this,
@@ -713,7 +875,7 @@ impl JsObject {
                         lexical_this_mode,
                     );
                 } else {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[0].clone(),
                         this,
@@ -727,7 +889,7 @@ impl JsObject {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -736,7 +898,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -746,12 +908,9 @@ impl JsObject {
                 let arg_count = args.len();
 
                 // Push function arguments to the stack.
-                let args = if code.params.parameters.len() > args.len() {
+                let args = if code.params.len() > args.len() {
                     let mut v = args.to_vec();
-                    v.extend(vec![
-                        JsValue::Undefined;
-                        code.params.parameters.len() - args.len()
-                    ]);
+                    v.extend(vec![JsValue::Undefined; code.params.len() - args.len()]);
                     v
                 } else {
                     args.to_vec()
@@ -761,7 +920,7 @@ impl JsObject {
                     context.vm.push(arg);
                 }
 
-                let param_count = code.params.parameters.len();
+                let param_count = code.params.len();
                 let has_expressions = code.params.has_expressions();
 
                 context.vm.push_frame(CallFrame {
@@ -786,14 +945,14 @@ impl JsObject {
                 let result = context.run();
                 let frame = context.vm.pop_frame().expect("must have frame");
 
-                context.realm.environments.pop();
+                context.vm.environments.pop();
                 if has_expressions
                     && frame.pc > frame.code.function_environment_push_location as usize
                 {
-                    context.realm.environments.pop();
+                    context.vm.environments.pop();
                 }
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let (result, _) = result?;
                 Ok(result)
@@ -802,13 +961,14 @@ impl JsObject {
                 code,
                 environments,
                 promise_capability,
+                ..
             } => {
                 let code = code.clone();
                 let mut environments = environments.clone();
                 let promise = promise_capability.promise().clone();
                 drop(object);
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
@@ -827,7 +987,7 @@ impl JsObject {
                 };
 
                 if code.params.has_expressions() {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[1].clone(),
                         this,
@@ -836,7 +996,7 @@ impl JsObject {
                         lexical_this_mode,
                     );
                 } else {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[0].clone(),
                         this,
@@ -850,7 +1010,7 @@ impl JsObject {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -859,7 +1019,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -869,12 +1029,9 @@ impl JsObject {
                 let arg_count = args.len();
 
                 // Push function arguments to the stack.
-                let args = if code.params.parameters.len() > args.len() {
+                let args = if code.params.len() > args.len() {
                     let mut v = args.to_vec();
-                    v.extend(vec![
-                        JsValue::Undefined;
-                        code.params.parameters.len() - args.len()
-                    ]);
+                    v.extend(vec![JsValue::Undefined; code.params.len() - args.len()]);
                     v
                 } else {
                     args.to_vec()
@@ -884,7 +1041,7 @@ impl JsObject {
                     context.vm.push(arg);
                 }
 
-                let param_count = code.params.parameters.len();
+                let param_count = code.params.len();
                 let has_expressions = code.params.has_expressions();
 
                 context.vm.push_frame(CallFrame {
@@ -909,23 +1066,28 @@ impl JsObject {
                 let _result = context.run();
                 let frame = context.vm.pop_frame().expect("must have frame");
 
-                context.realm.environments.pop();
+                context.vm.environments.pop();
                 if has_expressions
                     && frame.pc > frame.code.function_environment_push_location as usize
                 {
-                    context.realm.environments.pop();
+                    context.vm.environments.pop();
                 }
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 Ok(promise.into())
             }
-            Function::Generator { code, environments } => {
+            Function::Generator {
+                code,
+                environments,
+                realm,
+            } => {
                 let code = code.clone();
                 let mut environments = environments.clone();
+                let realm = realm.clone();
                 drop(object);
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
@@ -944,7 +1106,7 @@ impl JsObject {
                 };
 
                 if code.params.has_expressions() {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[1].clone(),
                         this,
@@ -953,7 +1115,7 @@ impl JsObject {
                         lexical_this_mode,
                     );
                 } else {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[0].clone(),
                         this,
@@ -967,7 +1129,7 @@ impl JsObject {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -976,7 +1138,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -986,19 +1148,16 @@ impl JsObject {
                 let arg_count = args.len();
 
                 // Push function arguments to the stack.
-                let mut args = if code.params.parameters.len() > args.len() {
+                let mut args = if code.params.len() > args.len() {
                     let mut v = args.to_vec();
-                    v.extend(vec![
-                        JsValue::Undefined;
-                        code.params.parameters.len() - args.len()
-                    ]);
+                    v.extend(vec![JsValue::Undefined; code.params.len() - args.len()]);
                     v
                 } else {
                     args.to_vec()
                 };
                 args.reverse();
 
-                let param_count = code.params.parameters.len();
+                let param_count = code.params.len();
 
                 let call_frame = CallFrame {
                     code,
@@ -1026,7 +1185,7 @@ impl JsObject {
                 let init_result = context.run();
 
                 let call_frame = context.vm.pop_frame().expect("frame must exist");
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
                 std::mem::swap(&mut context.vm.stack, &mut stack);
 
                 let prototype = if let Some(prototype) = this_function_object
@@ -1045,6 +1204,7 @@ impl JsObject {
                         state: GeneratorState::SuspendedStart,
                         context: Some(Gc::new(Cell::new(GeneratorContext {
                             environments,
+                            realm,
                             call_frame,
                             stack,
                         }))),
@@ -1055,12 +1215,17 @@ impl JsObject {
 
                 Ok(generator.into())
             }
-            Function::AsyncGenerator { code, environments } => {
+            Function::AsyncGenerator {
+                code,
+                environments,
+                realm,
+            } => {
                 let code = code.clone();
                 let mut environments = environments.clone();
+                let realm = realm.clone();
                 drop(object);
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
@@ -1079,7 +1244,7 @@ impl JsObject {
                 };
 
                 if code.params.has_expressions() {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[1].clone(),
                         this,
@@ -1088,7 +1253,7 @@ impl JsObject {
                         lexical_this_mode,
                     );
                 } else {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[0].clone(),
                         this,
@@ -1102,7 +1267,7 @@ impl JsObject {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -1111,7 +1276,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -1121,19 +1286,16 @@ impl JsObject {
                 let arg_count = args.len();
 
                 // Push function arguments to the stack.
-                let mut args = if code.params.parameters.len() > args.len() {
+                let mut args = if code.params.len() > args.len() {
                     let mut v = args.to_vec();
-                    v.extend(vec![
-                        JsValue::Undefined;
-                        code.params.parameters.len() - args.len()
-                    ]);
+                    v.extend(vec![JsValue::Undefined; code.params.len() - args.len()]);
                     v
                 } else {
                     args.to_vec()
                 };
                 args.reverse();
 
-                let param_count = code.params.parameters.len();
+                let param_count = code.params.len();
 
                 let call_frame = CallFrame {
                     code,
@@ -1161,7 +1323,7 @@ impl JsObject {
                 let init_result = context.run();
 
                 let call_frame = context.vm.pop_frame().expect("frame must exist");
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
                 std::mem::swap(&mut context.vm.stack, &mut stack);
 
                 let prototype = if let Some(prototype) = this_function_object
@@ -1184,6 +1346,7 @@ impl JsObject {
                         state: AsyncGeneratorState::SuspendedStart,
                         context: Some(Gc::new(Cell::new(GeneratorContext {
                             environments,
+                            realm,
                             call_frame,
                             stack,
                         }))),
@@ -1222,12 +1385,33 @@ impl JsObject {
         };
 
         if !self.is_constructor() {
-            return context.throw_type_error("not a constructor function");
+            return Err(JsNativeError::typ()
+                .with_message("not a constructor function")
+                .into());
+        }
+
+        if context.vm.frames.len() >= context.vm.runtime_limits.recursion_limit() {
+            return Err(JsNativeError::range()
+                .with_message("Maximum call stack size exceeded")
+                .into());
         }
 
         let object = self.borrow();
         let function_object = object.as_function().expect("not a function");
 
+        // Functions run in the realm they were created in.
+        {
+            let realm = function_object.realm();
+            if *realm != context.realm {
+                let realm = realm.clone();
+                drop(object);
+                let previous = context.enter_realm(realm);
+                let result = self.construct_internal(args, this_target, context);
+                context.enter_realm(previous);
+                return result;
+            }
+        }
+
         match function_object {
             Function::Native {
                 function,
@@ -1238,15 +1422,18 @@ impl JsObject {
                 let constructor = *constructor;
                 drop(object);
 
-                match function(this_target, args, context)? {
-                    JsValue::Object(ref o) => Ok(o.clone()),
-                    val => {
-                        if constructor.expect("hmm").is_base() || val.is_undefined() {
+                let result = function(this_target, args, context)?;
+                match result.variant() {
+                    JsVariant::Object(o) => Ok(o.clone()),
+                    _ => {
+                        if constructor.expect("hmm").is_base() || result.is_undefined() {
                             create_this(context)
                         } else {
-                            context.throw_type_error(
-                                "Derived constructor can only return an Object or undefined",
-                            )
+                            Err(JsNativeError::typ()
+                                .with_message(
+                                    "Derived constructor can only return an Object or undefined",
+                                )
+                                .into())
                         }
                     }
                 }
@@ -1262,15 +1449,18 @@ impl JsObject {
                 let constructor = *constructor;
                 drop(object);
 
-                match (function)(this_target, args, captures, context)? {
-                    JsValue::Object(ref o) => Ok(o.clone()),
-                    val => {
-                        if constructor.expect("hmma").is_base() || val.is_undefined() {
+                let result = (function)(this_target, args, captures, context)?;
+                match result.variant() {
+                    JsVariant::Object(o) => Ok(o.clone()),
+                    _ => {
+                        if constructor.expect("hmma").is_base() || result.is_undefined() {
                             create_this(context)
                         } else {
-                            context.throw_type_error(
-                                "Derived constructor can only return an Object or undefined",
-                            )
+                            Err(JsNativeError::typ()
+                                .with_message(
+                                    "Derived constructor can only return an Object or undefined",
+                                )
+                                .into())
                         }
                     }
                 }
@@ -1286,7 +1476,7 @@ impl JsObject {
                 let constructor_kind = *constructor_kind;
                 drop(object);
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let this = if constructor_kind.is_base() {
                     // If the prototype of the constructor is not an object, then use the default object
@@ -1310,7 +1500,7 @@ impl JsObject {
                 let new_target = this_target.as_object().expect("must be object");
 
                 if code.params.has_expressions() {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[1].clone(),
                         this.clone().map(Into::into),
@@ -1319,7 +1509,7 @@ impl JsObject {
                         false,
                     );
                 } else {
-                    context.realm.environments.push_function(
+                    context.vm.environments.push_function(
                         code.num_bindings,
                         code.compile_environments[0].clone(),
                         this.clone().map(Into::into),
@@ -1329,25 +1519,13 @@ impl JsObject {
                     );
                 }
 
-                let mut arguments_in_parameter_names = false;
-                let mut is_simple_parameter_list = true;
-                let mut has_parameter_expressions = false;
-
-                for param in code.params.parameters.iter() {
-                    has_parameter_expressions = has_parameter_expressions || param.init().is_some();
-                    arguments_in_parameter_names =
-                        arguments_in_parameter_names || param.names().contains(&Sym::ARGUMENTS);
-                    is_simple_parameter_list = is_simple_parameter_list
-                        && !param.is_rest_param()
-                        && param.is_identifier()
-                        && param.init().is_none();
-                }
+                let has_parameter_expressions = code.params.has_expressions();
 
                 if let Some(binding) = code.arguments_binding {
                     let arguments_obj = if code.strict || !code.params.is_simple() {
                         Arguments::create_unmapped_arguments_object(args, context)
                     } else {
-                        let env = context.realm.environments.current();
+                        let env = context.vm.environments.current();
                         Arguments::create_mapped_arguments_object(
                             &this_function_object,
                             &code.params,
@@ -1356,7 +1534,7 @@ impl JsObject {
                             context,
                         )
                     };
-                    context.realm.environments.put_value(
+                    context.vm.environments.put_value(
                         binding.environment_index(),
                         binding.binding_index(),
                         arguments_obj.into(),
@@ -1366,12 +1544,9 @@ impl JsObject {
                 let arg_count = args.len();
 
                 // Push function arguments to the stack.
-                let args = if code.params.parameters.len() > args.len() {
+                let args = if code.params.len() > args.len() {
                     let mut v = args.to_vec();
-                    v.extend(vec![
-                        JsValue::Undefined;
-                        code.params.parameters.len() - args.len()
-                    ]);
+                    v.extend(vec![JsValue::Undefined; code.params.len() - args.len()]);
                     v
                 } else {
                     args.to_vec()
@@ -1381,7 +1556,7 @@ impl JsObject {
                     context.vm.push(arg);
                 }
 
-                let param_count = code.params.parameters.len();
+                let param_count = code.params.len();
 
                 context.vm.push_frame(CallFrame {
                     code,
@@ -1406,12 +1581,12 @@ impl JsObject {
 
                 context.vm.pop_frame();
 
-                let mut environment = context.realm.environments.pop();
+                let mut environment = context.vm.environments.pop();
                 if has_parameter_expressions {
-                    environment = context.realm.environments.pop();
+                    environment = context.vm.environments.pop();
                 }
 
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                std::mem::swap(&mut environments, &mut context.vm.environments);
 
                 let (result, _) = result?;
 
@@ -1420,7 +1595,9 @@ impl JsObject {
                 } else if let Some(this) = this {
                     Ok(this)
                 } else if !result.is_undefined() {
-                    context.throw_type_error("Function constructor must not return non-object")
+                    Err(JsNativeError::typ()
+                        .with_message("Function constructor must not return non-object")
+                        .into())
                 } else {
                     let function_env = environment
                         .slots()
@@ -1433,8 +1610,8 @@ impl JsObject {
                             .expect("this binding must be object")
                             .clone())
                     } else {
-                        //context.throw_type_error("Function constructor must not return non-object")
-                        context.throw_reference_error("Must call super constructor in derived class before accessing 'this' or returning from derived constructor")
+                        //Err(JsNativeError::typ().with_message("Function constructor must not return non-object").into())
+                        Err(JsNativeError::reference().with_message("Must call super constructor in derived class before accessing 'this' or returning from derived constructor").into())
                     }
                 }
             }
@@ -0,0 +1,116 @@
+//! Accounting of the memory allocated by the code executed by a [`Context`][crate::Context], used
+//! to enforce the heap limit of its [`RuntimeLimits`][super::RuntimeLimits].
+//!
+//! Every context owns a [`HeapAccount`]. The account of the context that last started running
+//! code is the current account of the thread, and the allocations of objects, strings and array
+//! buffers are charged to it. Each allocation keeps a reference to the account it was charged to,
+//! so the bytes are returned to the right account when it is freed, even if another context is
+//! running by then.
+
+use std::{cell::Cell, cell::RefCell, rc::Rc};
+
+thread_local!(static CURRENT: RefCell<Option<Rc<HeapAccount>>> = const { RefCell::new(None) });
+
+/// The number of bytes charged to a context.
+#[derive(Debug, Default)]
+pub(crate) struct HeapAccount {
+    used: Cell<usize>,
+}
+
+impl HeapAccount {
+    /// Gets the number of bytes currently charged to the account.
+    #[inline]
+    pub(crate) fn used(&self) -> usize {
+        self.used.get()
+    }
+
+    /// Makes `account` the account that the allocations of the current thread are charged to.
+    pub(crate) fn make_current(account: &Rc<Self>) {
+        CURRENT.with(|current| {
+            let mut current = current.borrow_mut();
+            if !matches!(&*current, Some(current) if Rc::ptr_eq(current, account)) {
+                *current = Some(account.clone());
+            }
+        });
+    }
+
+    /// Charges `bytes` to the current account of the thread, returning the account.
+    ///
+    /// Returns `None` if no context has run code on the thread yet.
+    pub(crate) fn charge(bytes: usize) -> Option<Rc<Self>> {
+        CURRENT.with(|current| {
+            let account = current.borrow().clone()?;
+            account.add(bytes);
+            Some(account)
+        })
+    }
+
+    /// Adds `bytes` to the bytes charged to the account.
+    #[inline]
+    fn add(&self, bytes: usize) {
+        self.used.set(self.used.get().saturating_add(bytes));
+    }
+
+    /// Returns `bytes` that were previously charged to the account.
+    #[inline]
+    pub(crate) fn refund(&self, bytes: usize) {
+        self.used.set(self.used.get().saturating_sub(bytes));
+    }
+}
+
+/// An amount of memory charged to a [`HeapAccount`], which is refunded when the charge is
+/// dropped.
+#[derive(Debug, Default)]
+pub(crate) struct HeapCharge {
+    account: Option<Rc<HeapAccount>>,
+    bytes: usize,
+}
+
+impl HeapCharge {
+    /// Charges `bytes` to the current account of the thread.
+    #[inline]
+    pub(crate) fn new(bytes: usize) -> Self {
+        Self {
+            account: HeapAccount::charge(bytes),
+            bytes,
+        }
+    }
+
+    /// Gets the number of charged bytes.
+    #[inline]
+    pub(crate) fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Changes the number of charged bytes, charging or refunding the difference to the account
+    /// of the charge.
+    pub(crate) fn resize(&mut self, bytes: usize) {
+        if let Some(account) = &self.account {
+            account.refund(self.bytes);
+            account.add(bytes);
+        }
+        self.bytes = bytes;
+    }
+}
+
+impl Clone for HeapCharge {
+    #[inline]
+    fn clone(&self) -> Self {
+        if let Some(account) = &self.account {
+            account.add(self.bytes);
+        }
+        Self {
+            account: self.account.clone(),
+            bytes: self.bytes,
+        }
+    }
+}
+
+impl Drop for HeapCharge {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(account) = &self.account {
+            account.refund(self.bytes);
+        }
+    }
+}
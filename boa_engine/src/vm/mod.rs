@@ -9,10 +9,11 @@ use crate::{
         iterable::IteratorRecord,
         Array, ForInIterator, JsArgs, Number, Promise,
     },
-    environments::EnvironmentSlots,
+    environments::{DeclarativeEnvironmentStack, EnvironmentSlots},
+    error::{JsError, JsNativeError},
     object::{FunctionBuilder, JsFunction, JsObject, ObjectData, PrivateElement},
     property::{DescriptorKind, PropertyDescriptor, PropertyDescriptorBuilder, PropertyKey},
-    value::Numeric,
+    value::{JsVariant, Numeric},
     vm::{
         call_frame::CatchAddresses,
         code_block::{initialize_instance_elements, Readable},
@@ -21,18 +22,29 @@ use crate::{
 };
 use boa_interner::ToInternedString;
 use boa_profiler::Profiler;
-use std::{convert::TryInto, mem::size_of, ops::Neg, time::Instant};
+use std::{convert::TryInto, mem::size_of, ops::Neg, rc::Rc, time::Instant};
 
+pub(crate) mod cache;
 mod call_frame;
 mod code_block;
+mod heap;
 mod opcode;
-
-pub use {call_frame::CallFrame, code_block::CodeBlock, opcode::Opcode};
+mod property_cache;
+mod runtime_limits;
+
+pub use {
+    call_frame::{CallFrame, StackFrame},
+    code_block::{CodeBlock, SourceLocation},
+    opcode::Opcode,
+    runtime_limits::{InterruptHandle, RuntimeLimits},
+};
 
 pub(crate) use {
     call_frame::{FinallyReturn, GeneratorResumeKind, TryStackEntry},
-    code_block::{create_function_object, create_generator_function_object},
+    code_block::{create_function_object, create_generator_function_object, FunctionParameters},
+    heap::{HeapAccount, HeapCharge},
     opcode::BindingOpcode,
+    property_cache::PropertyCache,
 };
 
 #[cfg(test)]
@@ -42,8 +54,19 @@ mod tests;
 pub struct Vm {
     pub(crate) frames: Vec<CallFrame>,
     pub(crate) stack: Vec<JsValue>,
+    pub(crate) environments: DeclarativeEnvironmentStack,
     pub(crate) trace: bool,
-    pub(crate) stack_size_limit: usize,
+    pub(crate) runtime_limits: RuntimeLimits,
+    pub(crate) interrupt_handle: InterruptHandle,
+
+    /// The number of instructions executed since the start of the current execution.
+    pub(crate) instruction_count: u64,
+
+    /// The error that terminated the current execution, if any.
+    pub(crate) termination: Option<JsError>,
+
+    /// The memory charged to the context.
+    pub(crate) heap: Rc<HeapAccount>,
 }
 
 impl Vm {
@@ -122,8 +145,83 @@ pub(crate) enum ReturnType {
     Yield,
 }
 
+/// The message of the error that terminates an execution interrupted by an [`InterruptHandle`].
+pub(crate) const INTERRUPTED_MESSAGE: &str = "Script execution was interrupted";
+
 impl Context {
+    /// Checks if the current execution must be terminated before running the next instruction,
+    /// because it was interrupted or because it exceeded the instruction limit or the heap limit.
+    fn check_termination(&mut self) -> JsResult<()> {
+        if let Some(error) = &self.vm.termination {
+            return Err(error.clone());
+        }
+
+        let message = if self.vm.interrupt_handle.take() {
+            INTERRUPTED_MESSAGE
+        } else {
+            self.vm.instruction_count += 1;
+            match self.vm.runtime_limits.instruction_limit() {
+                Some(limit) if self.vm.instruction_count > limit => {
+                    "Maximum instruction count exceeded"
+                }
+                _ => return self.check_heap_limit(),
+            }
+        };
+
+        Err(self.terminate(message))
+    }
+
+    /// Terminates the current execution with an uncatchable `RangeError`, returning the error.
+    pub(crate) fn terminate(&mut self, message: &'static str) -> JsError {
+        let error: JsError = JsNativeError::range().with_message(message).into();
+        self.vm.termination = Some(error.clone());
+        error
+    }
+
+    /// Checks that the memory charged to the context does not exceed the heap limit, terminating
+    /// the current execution if it does.
+    #[inline]
+    pub(crate) fn check_heap_limit(&mut self) -> JsResult<()> {
+        self.reserve_heap(0)
+    }
+
+    /// Checks that `bytes` more bytes can be charged to the context without exceeding the heap
+    /// limit, terminating the current execution if they can't.
+    ///
+    /// A garbage collection runs to free the unreachable allocations before giving up.
+    #[inline]
+    pub(crate) fn reserve_heap(&mut self, bytes: usize) -> JsResult<()> {
+        match self.vm.runtime_limits.heap_limit() {
+            Some(limit) if self.vm.heap.used().saturating_add(bytes) > limit => {
+                self.collect_within_heap_limit(bytes, limit)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Collects the garbage of the heap, terminating the current execution if `bytes` more bytes
+    /// still can't be charged to the context without exceeding `limit`.
+    #[cold]
+    fn collect_within_heap_limit(&mut self, bytes: usize, limit: usize) -> JsResult<()> {
+        if let Some(error) = &self.vm.termination {
+            return Err(error.clone());
+        }
+
+        boa_gc::force_collect();
+        if self.vm.heap.used().saturating_add(bytes) <= limit {
+            return Ok(());
+        }
+
+        let error: JsError = JsNativeError::range()
+            .with_message("Maximum heap size exceeded")
+            .into();
+        self.vm.termination = Some(error.clone());
+        Err(error)
+    }
+
     fn execute_instruction(&mut self) -> JsResult<ShouldExit> {
+        self.check_termination()?;
+
         macro_rules! bin_op {
             ($op:ident) => {{
                 let rhs = self.vm.pop();
@@ -205,8 +303,9 @@ impl Context {
                 if let Some(superclass) = superclass.as_constructor() {
                     let proto = superclass.get("prototype", self)?;
                     if !proto.is_object() && !proto.is_null() {
-                        return self
-                            .throw_type_error("superclass prototype must be an object or null");
+                        return Err(JsNativeError::typ()
+                            .with_message("superclass prototype must be an object or null")
+                            .into());
                     }
 
                     let class = self.vm.pop();
@@ -231,15 +330,17 @@ impl Context {
                 } else if superclass.is_null() {
                     self.vm.push(JsValue::Null);
                 } else {
-                    return self.throw_type_error("superclass must be a constructor");
+                    return Err(JsNativeError::typ()
+                        .with_message("superclass must be a constructor")
+                        .into());
                 }
             }
             Opcode::SetClassPrototype => {
                 let prototype_value = self.vm.pop();
-                let prototype = match &prototype_value {
-                    JsValue::Object(proto) => Some(proto.clone()),
-                    JsValue::Null => None,
-                    JsValue::Undefined => {
+                let prototype = match prototype_value.variant() {
+                    JsVariant::Object(proto) => Some(proto.clone()),
+                    JsVariant::Null => None,
+                    JsVariant::Undefined => {
                         Some(self.intrinsics().constructors().object().prototype.clone())
                     }
                     _ => unreachable!(),
@@ -386,10 +487,12 @@ impl Context {
                 let lhs = self.vm.pop();
 
                 if !rhs.is_object() {
-                    return self.throw_type_error(format!(
-                        "right-hand side of 'in' should be an object, got {}",
-                        rhs.type_of()
-                    ));
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "right-hand side of 'in' should be an object, got {}",
+                            rhs.type_of()
+                        ))
+                        .into());
                 }
                 let key = lhs.to_property_key(self)?;
                 let value = self.has_property(&rhs, &key)?;
@@ -491,7 +594,7 @@ impl Context {
                             .build(),
                     );
                 } else {
-                    self.realm.environments.put_value_if_uninitialized(
+                    self.vm.environments.put_value_if_uninitialized(
                         binding_locator.environment_index(),
                         binding_locator.binding_index(),
                         JsValue::Undefined,
@@ -509,11 +612,12 @@ impl Context {
                         .interner()
                         .resolve_expect(binding_locator.name())
                         .into();
+                    let global = self.global_object().clone();
                     crate::object::internal_methods::global::global_set_no_receiver(
-                        &key, value, self,
+                        &global, &key, value, self,
                     )?;
                 } else {
-                    self.realm.environments.put_value(
+                    self.vm.environments.put_value(
                         binding_locator.environment_index(),
                         binding_locator.binding_index(),
                         value,
@@ -523,7 +627,7 @@ impl Context {
             Opcode::DefLet => {
                 let index = self.vm.read::<u32>();
                 let binding_locator = self.vm.frame().code.bindings[index as usize];
-                self.realm.environments.put_value(
+                self.vm.environments.put_value(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
                     JsValue::Undefined,
@@ -533,7 +637,7 @@ impl Context {
                 let index = self.vm.read::<u32>();
                 let value = self.vm.pop();
                 let binding_locator = self.vm.frame().code.bindings[index as usize];
-                self.realm.environments.put_value(
+                self.vm.environments.put_value(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
                     value,
@@ -546,7 +650,7 @@ impl Context {
 
                 let value = if binding_locator.is_global() {
                     if let Some(value) = self
-                        .realm
+                        .vm
                         .environments
                         .get_value_global_poisoned(binding_locator.name())
                     {
@@ -556,7 +660,8 @@ impl Context {
                             .interner()
                             .resolve_expect(binding_locator.name())
                             .into();
-                        match self.global_bindings_mut().get(&key) {
+                        let desc = self.global_bindings_mut().get(&key).cloned();
+                        match desc {
                             Some(desc) => match desc.kind() {
                                 DescriptorKind::Data {
                                     value: Some(value), ..
@@ -568,16 +673,19 @@ impl Context {
                                     self.call(&get, &self.global_object().clone().into(), &[])?
                                 }
                                 _ => {
-                                    return self
-                                        .throw_reference_error(format!("{key} is not defined"))
+                                    return Err(JsNativeError::reference()
+                                        .with_message(format!("{key} is not defined"))
+                                        .into())
                                 }
                             },
                             _ => {
-                                return self.throw_reference_error(format!("{key} is not defined"))
+                                return Err(JsNativeError::reference()
+                                    .with_message(format!("{key} is not defined"))
+                                    .into())
                             }
                         }
                     }
-                } else if let Some(value) = self.realm.environments.get_value_optional(
+                } else if let Some(value) = self.vm.environments.get_value_optional(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
                     binding_locator.name(),
@@ -586,7 +694,9 @@ impl Context {
                 } else {
                     let name =
                         JsString::from(self.interner().resolve_expect(binding_locator.name()));
-                    return self.throw_reference_error(format!("{name} is not initialized"));
+                    return Err(JsNativeError::reference()
+                        .with_message(format!("{name} is not initialized"))
+                        .into());
                 };
 
                 self.vm.push(value);
@@ -597,7 +707,7 @@ impl Context {
                 binding_locator.throw_mutate_immutable(self)?;
                 let value = if binding_locator.is_global() {
                     if let Some(value) = self
-                        .realm
+                        .vm
                         .environments
                         .get_value_global_poisoned(binding_locator.name())
                     {
@@ -607,7 +717,8 @@ impl Context {
                             .interner()
                             .resolve_expect(binding_locator.name())
                             .into();
-                        match self.global_bindings_mut().get(&key) {
+                        let desc = self.global_bindings_mut().get(&key).cloned();
+                        match desc {
                             Some(desc) => match desc.kind() {
                                 DescriptorKind::Data {
                                     value: Some(value), ..
@@ -623,7 +734,7 @@ impl Context {
                             _ => JsValue::undefined(),
                         }
                     }
-                } else if let Some(value) = self.realm.environments.get_value_optional(
+                } else if let Some(value) = self.vm.environments.get_value_optional(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
                     binding_locator.name(),
@@ -643,7 +754,7 @@ impl Context {
 
                 if binding_locator.is_global() {
                     if !self
-                        .realm
+                        .vm
                         .environments
                         .put_value_global_poisoned(binding_locator.name(), &value)
                     {
@@ -654,34 +765,38 @@ impl Context {
                         let exists = self.global_bindings_mut().contains_key(&key);
 
                         if !exists && self.vm.frame().code.strict {
-                            return self.throw_reference_error(format!(
-                                "assignment to undeclared variable {key}"
-                            ));
+                            return Err(JsNativeError::reference()
+                                .with_message(format!("assignment to undeclared variable {key}"))
+                                .into());
                         }
 
+                        let global = self.global_object().clone();
                         let success =
                             crate::object::internal_methods::global::global_set_no_receiver(
+                                &global,
                                 &key.clone().into(),
                                 value,
                                 self,
                             )?;
 
                         if !success && self.vm.frame().code.strict {
-                            return self.throw_type_error(format!(
-                                "cannot set non-writable property: {key}",
-                            ));
+                            return Err(JsNativeError::typ()
+                                .with_message(format!("cannot set non-writable property: {key}",))
+                                .into());
                         }
                     }
-                } else if !self.realm.environments.put_value_if_initialized(
+                } else if !self.vm.environments.put_value_if_initialized(
                     binding_locator.environment_index(),
                     binding_locator.binding_index(),
                     binding_locator.name(),
                     value,
                 ) {
-                    self.throw_reference_error(format!(
-                        "cannot access '{}' before initialization",
-                        self.interner().resolve_expect(binding_locator.name())
-                    ))?;
+                    return Err(JsNativeError::reference()
+                        .with_message(format!(
+                            "cannot access '{}' before initialization",
+                            self.interner().resolve_expect(binding_locator.name())
+                        ))
+                        .into());
                 }
             }
             Opcode::Jump => {
@@ -732,6 +847,7 @@ impl Context {
             }
             Opcode::GetPropertyByName => {
                 let index = self.vm.read::<u32>();
+                let cache_index = self.vm.read::<u32>();
 
                 let value = self.vm.pop();
                 let object = if let Some(object) = value.as_object() {
@@ -740,9 +856,17 @@ impl Context {
                     value.to_object(self)?
                 };
 
-                let name = self.vm.frame().code.names[index as usize];
-                let name: PropertyKey = self.interner().resolve_expect(name).into();
-                let result = object.get(name, self)?;
+                let code = self.vm.frame().code.clone();
+                let cache = &code.property_caches[cache_index as usize];
+                let result = if let Some(result) = cache.get(&object, self) {
+                    result?
+                } else {
+                    let name = code.names[index as usize];
+                    let name: PropertyKey = self.interner().resolve_expect(name).into();
+
+                    cache.fill_get(&object, &name);
+                    object.get(name, self)?
+                };
 
                 self.vm.push(result);
             }
@@ -777,6 +901,7 @@ impl Context {
             }
             Opcode::SetPropertyByName => {
                 let index = self.vm.read::<u32>();
+                let cache_index = self.vm.read::<u32>();
 
                 let object = self.vm.pop();
                 let value = self.vm.pop();
@@ -786,10 +911,18 @@ impl Context {
                     object.to_object(self)?
                 };
 
-                let name = self.vm.frame().code.names[index as usize];
-                let name: PropertyKey = self.interner().resolve_expect(name).into();
+                let code = self.vm.frame().code.clone();
+                let cache = &code.property_caches[cache_index as usize];
+                if let Err(value) = cache.set(&object, value) {
+                    let name = code.names[index as usize];
+                    let name: PropertyKey = self.interner().resolve_expect(name).into();
 
-                object.set(name, value, self.vm.frame().code.strict, self)?;
+                    let shapes = cache.prepare_set(&object, &name);
+                    object.set(name.clone(), value, code.strict, self)?;
+                    if let Some(shapes) = shapes {
+                        cache.fill_set(&object, &name, shapes);
+                    }
+                }
             }
             Opcode::DefineOwnPropertyByName => {
                 let index = self.vm.read::<u32>();
@@ -1125,7 +1258,9 @@ impl Context {
                                 .set_private_element(name, PrivateElement::Field(value));
                         }
                         Some(PrivateElement::Method(_)) => {
-                            return self.throw_type_error("private method is not writable");
+                            return Err(JsNativeError::typ()
+                                .with_message("private method is not writable")
+                                .into());
                         }
                         Some(PrivateElement::Accessor {
                             setter: Some(setter),
@@ -1136,14 +1271,20 @@ impl Context {
                             setter.call(&object.clone().into(), &[value], self)?;
                         }
                         None => {
-                            return self.throw_type_error("private field not defined");
+                            return Err(JsNativeError::typ()
+                                .with_message("private field not defined")
+                                .into());
                         }
                         _ => {
-                            return self.throw_type_error("private field defined without a setter");
+                            return Err(JsNativeError::typ()
+                                .with_message("private field defined without a setter")
+                                .into());
                         }
                     }
                 } else {
-                    return self.throw_type_error("cannot set private property on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private property on non-object")
+                        .into());
                 }
             }
             Opcode::SetPrivateField => {
@@ -1165,7 +1306,9 @@ impl Context {
                         object_borrow_mut.set_private_element(name, PrivateElement::Field(value));
                     }
                 } else {
-                    return self.throw_type_error("cannot set private property on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private property on non-object")
+                        .into());
                 }
             }
             Opcode::SetPrivateMethod => {
@@ -1179,7 +1322,9 @@ impl Context {
                     object_borrow_mut
                         .set_private_element(name, PrivateElement::Method(value.clone()));
                 } else {
-                    return self.throw_type_error("cannot set private setter on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private setter on non-object")
+                        .into());
                 }
             }
             Opcode::SetPrivateSetter => {
@@ -1192,7 +1337,9 @@ impl Context {
                     let mut object_borrow_mut = object.borrow_mut();
                     object_borrow_mut.set_private_element_setter(name, value.clone());
                 } else {
-                    return self.throw_type_error("cannot set private setter on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private setter on non-object")
+                        .into());
                 }
             }
             Opcode::SetPrivateGetter => {
@@ -1205,7 +1352,9 @@ impl Context {
                     let mut object_borrow_mut = object.borrow_mut();
                     object_borrow_mut.set_private_element_getter(name, value.clone());
                 } else {
-                    return self.throw_type_error("cannot set private getter on non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot set private getter on non-object")
+                        .into());
                 }
             }
             Opcode::GetPrivateField => {
@@ -1226,16 +1375,20 @@ impl Context {
                                 self.vm.push(value);
                             }
                             PrivateElement::Accessor { .. } => {
-                                return self.throw_type_error(
-                                    "private property was defined without a getter",
-                                );
+                                return Err(JsNativeError::typ()
+                                    .with_message("private property was defined without a getter")
+                                    .into());
                             }
                         }
                     } else {
-                        return self.throw_type_error("private property does not exist");
+                        return Err(JsNativeError::typ()
+                            .with_message("private property does not exist")
+                            .into());
                     }
                 } else {
-                    return self.throw_type_error("cannot read private property from non-object");
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot read private property from non-object")
+                        .into());
                 }
             }
             Opcode::PushClassField => {
@@ -1351,7 +1504,9 @@ impl Context {
                 let object = self.vm.pop();
                 let result = object.to_object(self)?.__delete__(&key, self)?;
                 if !result && self.vm.frame().code.strict {
-                    return Err(self.construct_type_error("Cannot delete property"));
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot delete property")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1362,7 +1517,9 @@ impl Context {
                     .to_object(self)?
                     .__delete__(&key.to_property_key(self)?, self)?;
                 if !result && self.vm.frame().code.strict {
-                    return Err(self.construct_type_error("Cannot delete property"));
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot delete property")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1393,7 +1550,7 @@ impl Context {
             }
             Opcode::Throw => {
                 let value = self.vm.pop();
-                return Err(value);
+                return Err(JsError::from_opaque(value));
             }
             Opcode::TryStart => {
                 let next = self.vm.read::<u32>();
@@ -1414,7 +1571,7 @@ impl Context {
                 self.vm.frame_mut().catch.pop();
                 let try_stack_entry = self.vm.frame_mut().try_env_stack.pop().expect("must exist");
                 for _ in 0..try_stack_entry.num_env {
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                 }
                 let mut num_env = try_stack_entry.num_env;
                 for _ in 0..try_stack_entry.num_loop_stack_entries {
@@ -1476,7 +1633,7 @@ impl Context {
                         return Ok(ShouldExit::True);
                     }
                     FinallyReturn::Err => {
-                        return Err(self.vm.pop());
+                        return Err(JsError::from_opaque(self.vm.pop()));
                     }
                 }
             }
@@ -1490,26 +1647,25 @@ impl Context {
                     .expect("finally jump must exist here") = Some(address);
             }
             Opcode::This => {
-                let env = self.realm.environments.get_this_environment();
-                match env {
+                let env = self.vm.environments.get_this_environment();
+                let this = match env {
                     EnvironmentSlots::Function(env) => {
                         let env_b = env.borrow();
                         if let Some(this) = env_b.get_this_binding() {
-                            self.vm.push(this);
+                            this.clone()
                         } else {
                             drop(env_b);
-                            return self.throw_reference_error("Must call super constructor in derived class before accessing 'this' or returning from derived constructor");
+                            return Err(JsNativeError::reference().with_message("Must call super constructor in derived class before accessing 'this' or returning from derived constructor").into());
                         }
                     }
-                    EnvironmentSlots::Global => {
-                        let this = self.realm.global_object();
-                        self.vm.push(this.clone());
-                    }
-                }
+                    EnvironmentSlots::Global => self.realm.global_object().clone().into(),
+                    EnvironmentSlots::Module(_) => JsValue::undefined(),
+                };
+                self.vm.push(this);
             }
             Opcode::Super => {
                 let env = self
-                    .realm
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
@@ -1523,7 +1679,7 @@ impl Context {
                         .expect("must be function object");
                     function.get_home_object().cloned()
                 } else {
-                    return self.throw_range_error("Must call super constructor in derived class before accessing 'this' or returning from derived constructor");
+                    return Err(JsNativeError::range().with_message("Must call super constructor in derived class before accessing 'this' or returning from derived constructor").into());
                 };
 
                 if let Some(home) = home {
@@ -1534,7 +1690,7 @@ impl Context {
                     }
                 } else {
                     self.vm.push(JsValue::Null);
-                };
+                }
             }
             Opcode::SuperCall => {
                 let argument_count = self.vm.read::<u32>();
@@ -1546,7 +1702,7 @@ impl Context {
 
                 let (new_target, active_function) = {
                     let this_env = self
-                        .realm
+                        .vm
                         .environments
                         .get_this_environment()
                         .as_function_slots()
@@ -1565,7 +1721,9 @@ impl Context {
                     .expect("function object must have prototype");
 
                 if !super_constructor.is_constructor() {
-                    return self.throw_type_error("super constructor object must be constructor");
+                    return Err(JsNativeError::typ()
+                        .with_message("super constructor object must be constructor")
+                        .into());
                 }
 
                 let result = super_constructor.__construct__(&arguments, &new_target, self)?;
@@ -1573,14 +1731,16 @@ impl Context {
                 initialize_instance_elements(&result, &active_function, self)?;
 
                 let this_env = self
-                    .realm
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
                     .expect("super call must be in function environment");
 
                 if !this_env.borrow_mut().bind_this_value(&result) {
-                    return self.throw_reference_error("this already initialized");
+                    return Err(JsNativeError::reference()
+                        .with_message("this already initialized")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1599,7 +1759,7 @@ impl Context {
 
                 let (new_target, active_function) = {
                     let this_env = self
-                        .realm
+                        .vm
                         .environments
                         .get_this_environment()
                         .as_function_slots()
@@ -1618,7 +1778,9 @@ impl Context {
                     .expect("function object must have prototype");
 
                 if !super_constructor.is_constructor() {
-                    return self.throw_type_error("super constructor object must be constructor");
+                    return Err(JsNativeError::typ()
+                        .with_message("super constructor object must be constructor")
+                        .into());
                 }
 
                 let result = super_constructor.__construct__(&arguments, &new_target, self)?;
@@ -1626,14 +1788,16 @@ impl Context {
                 initialize_instance_elements(&result, &active_function, self)?;
 
                 let this_env = self
-                    .realm
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
                     .expect("super call must be in function environment");
 
                 if !this_env.borrow_mut().bind_this_value(&result) {
-                    return self.throw_reference_error("this already initialized");
+                    return Err(JsNativeError::reference()
+                        .with_message("this already initialized")
+                        .into());
                 }
                 self.vm.push(result);
             }
@@ -1647,7 +1811,7 @@ impl Context {
 
                 let (new_target, active_function) = {
                     let this_env = self
-                        .realm
+                        .vm
                         .environments
                         .get_this_environment()
                         .as_function_slots()
@@ -1666,7 +1830,9 @@ impl Context {
                     .expect("function object must have prototype");
 
                 if !super_constructor.is_constructor() {
-                    return self.throw_type_error("super constructor object must be constructor");
+                    return Err(JsNativeError::typ()
+                        .with_message("super constructor object must be constructor")
+                        .into());
                 }
 
                 let result = super_constructor.__construct__(&arguments, &new_target, self)?;
@@ -1674,13 +1840,15 @@ impl Context {
                 initialize_instance_elements(&result, &active_function, self)?;
 
                 let this_env = self
-                    .realm
+                    .vm
                     .environments
                     .get_this_environment()
                     .as_function_slots()
                     .expect("super call must be in function environment");
                 if !this_env.borrow_mut().bind_this_value(&result) {
-                    return self.throw_reference_error("this already initialized");
+                    return Err(JsNativeError::reference()
+                        .with_message("this already initialized")
+                        .into());
                 }
 
                 self.vm.push(result);
@@ -1726,8 +1894,10 @@ impl Context {
                 self.vm.push(function);
             }
             Opcode::CallEval => {
-                if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                if self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.len() {
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
                 let argument_count = self.vm.read::<u32>();
                 let mut arguments = Vec::with_capacity(argument_count as usize);
@@ -1739,9 +1909,13 @@ impl Context {
                 let func = self.vm.pop();
                 let this = self.vm.pop();
 
-                let object = match func {
-                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
-                    _ => return self.throw_type_error("not a callable function"),
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
                 };
 
                 // A native function with the name "eval" implies, that is this the built-in eval function.
@@ -1763,8 +1937,10 @@ impl Context {
                 }
             }
             Opcode::CallEvalSpread => {
-                if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                if self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.len() {
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
 
                 // Get the arguments that are stored as an array object on the stack.
@@ -1782,9 +1958,13 @@ impl Context {
                 let func = self.vm.pop();
                 let this = self.vm.pop();
 
-                let object = match func {
-                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
-                    _ => return self.throw_type_error("not a callable function"),
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
                 };
 
                 // A native function with the name "eval" implies, that is this the built-in eval function.
@@ -1806,8 +1986,10 @@ impl Context {
                 }
             }
             Opcode::Call => {
-                if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                if self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.len() {
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
                 let argument_count = self.vm.read::<u32>();
                 let mut arguments = Vec::with_capacity(argument_count as usize);
@@ -1819,9 +2001,13 @@ impl Context {
                 let func = self.vm.pop();
                 let this = self.vm.pop();
 
-                let object = match func {
-                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
-                    _ => return self.throw_type_error("not a callable function"),
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
                 };
 
                 let result = object.__call__(&this, &arguments, self)?;
@@ -1829,8 +2015,10 @@ impl Context {
                 self.vm.push(result);
             }
             Opcode::CallSpread => {
-                if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                if self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.len() {
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
 
                 // Get the arguments that are stored as an array object on the stack.
@@ -1848,9 +2036,13 @@ impl Context {
                 let func = self.vm.pop();
                 let this = self.vm.pop();
 
-                let object = match func {
-                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
-                    _ => return self.throw_type_error("not a callable function"),
+                let object = match func.variant() {
+                    JsVariant::Object(object) if object.is_callable() => object.clone(),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message("not a callable function")
+                            .into())
+                    }
                 };
 
                 let result = object.__call__(&this, &arguments, self)?;
@@ -1858,8 +2050,10 @@ impl Context {
                 self.vm.push(result);
             }
             Opcode::New => {
-                if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                if self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.len() {
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
                 let argument_count = self.vm.read::<u32>();
                 let mut arguments = Vec::with_capacity(argument_count as usize);
@@ -1871,14 +2065,20 @@ impl Context {
 
                 let result = func
                     .as_constructor()
-                    .ok_or_else(|| self.construct_type_error("not a constructor"))
+                    .ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("not a constructor")
+                            .into()
+                    })
                     .and_then(|cons| cons.__construct__(&arguments, cons, self))?;
 
                 self.vm.push(result);
             }
             Opcode::NewSpread => {
-                if self.vm.stack_size_limit <= self.vm.stack.len() {
-                    return self.throw_range_error("Maximum call stack size exceeded");
+                if self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.len() {
+                    return Err(JsNativeError::range()
+                        .with_message("Maximum call stack size exceeded")
+                        .into());
                 }
                 // Get the arguments that are stored as an array object on the stack.
                 let arguments_array = self.vm.pop();
@@ -1896,7 +2096,11 @@ impl Context {
 
                 let result = func
                     .as_constructor()
-                    .ok_or_else(|| self.construct_type_error("not a constructor"))
+                    .ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("not a constructor")
+                            .into()
+                    })
                     .and_then(|cons| cons.__construct__(&arguments, cons, self))?;
 
                 self.vm.push(result);
@@ -1911,7 +2115,7 @@ impl Context {
                     let try_stack_entry =
                         self.vm.frame_mut().try_env_stack.pop().expect("must exist");
                     for _ in 0..try_stack_entry.num_env {
-                        self.realm.environments.pop();
+                        self.vm.environments.pop();
                     }
                     let mut num_env = try_stack_entry.num_env;
                     for _ in 0..try_stack_entry.num_loop_stack_entries {
@@ -1938,7 +2142,7 @@ impl Context {
                 let compile_environment = self.vm.frame().code.compile_environments
                     [compile_environments_index as usize]
                     .clone();
-                self.realm
+                self.vm
                     .environments
                     .push_declarative(num_bindings as usize, compile_environment);
                 self.vm.frame_mut().loop_env_stack_inc();
@@ -1950,12 +2154,12 @@ impl Context {
                 let compile_environment = self.vm.frame().code.compile_environments
                     [compile_environments_index as usize]
                     .clone();
-                self.realm
+                self.vm
                     .environments
                     .push_function_inherit(num_bindings as usize, compile_environment);
             }
             Opcode::PopEnvironment => {
-                self.realm.environments.pop();
+                self.vm.environments.pop();
                 self.vm.frame_mut().loop_env_stack_dec();
                 self.vm.frame_mut().try_env_stack_dec();
             }
@@ -1973,7 +2177,7 @@ impl Context {
                 let env_num_copy = *env_num;
                 *env_num = 0;
                 for _ in 0..env_num_copy {
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                 }
             }
             Opcode::LoopEnd => {
@@ -1984,7 +2188,7 @@ impl Context {
                     .pop()
                     .expect("loop env stack entry must exist");
                 for _ in 0..env_num {
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                     self.vm.frame_mut().try_env_stack_dec();
                 }
                 self.vm.frame_mut().try_env_stack_loop_dec();
@@ -2005,7 +2209,9 @@ impl Context {
                     .as_ref()
                     .map(PropertyDescriptor::expect_value)
                     .cloned()
-                    .ok_or_else(|| self.construct_type_error("Could not find property `next`"))?;
+                    .ok_or_else(|| {
+                        JsNativeError::typ().with_message("Could not find property `next`")
+                    })?;
 
                 self.vm.push(iterator);
                 self.vm.push(next_method);
@@ -2106,7 +2312,7 @@ impl Context {
                     self.vm.frame_mut().pc = address as usize;
                     self.vm.frame_mut().loop_env_stack_dec();
                     self.vm.frame_mut().try_env_stack_dec();
-                    self.realm.environments.pop();
+                    self.vm.environments.pop();
                     self.vm.push(iterator.clone());
                     self.vm.push(next_method);
                     self.vm.push(done);
@@ -2120,22 +2326,26 @@ impl Context {
                 }
                 strings.reverse();
                 let s = JsString::concat_array(
-                    &strings.iter().map(JsString::as_str).collect::<Vec<&str>>(),
+                    &strings.iter().map(JsString::as_slice).collect::<Vec<_>>(),
                 );
                 self.vm.push(s);
             }
             Opcode::RequireObjectCoercible => {
                 let value = self.vm.pop();
-                let value = value.require_object_coercible(self)?;
+                let value = value.require_object_coercible()?;
                 self.vm.push(value);
             }
             Opcode::ValueNotNullOrUndefined => {
                 let value = self.vm.pop();
                 if value.is_null() {
-                    return self.throw_type_error("Cannot destructure 'null' value");
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot destructure 'null' value")
+                        .into());
                 }
                 if value.is_undefined() {
-                    return self.throw_type_error("Cannot destructure 'undefined' value");
+                    return Err(JsNativeError::typ()
+                        .with_message("Cannot destructure 'undefined' value")
+                        .into());
                 }
                 self.vm.push(value);
             }
@@ -2179,7 +2389,7 @@ impl Context {
                 GeneratorResumeKind::Normal => return Ok(ShouldExit::False),
                 GeneratorResumeKind::Throw => {
                     let received = self.vm.pop();
-                    return Err(received);
+                    return Err(JsError::from_opaque(received));
                 }
                 GeneratorResumeKind::Return => {
                     let mut finally_left = false;
@@ -2206,7 +2416,7 @@ impl Context {
                 let value = self.vm.pop();
 
                 if self.vm.frame().generator_resume_kind == GeneratorResumeKind::Throw {
-                    return Err(value);
+                    return Err(JsError::from_opaque(value));
                 }
 
                 let completion = Ok(value);
@@ -2234,9 +2444,11 @@ impl Context {
                 if let Some(next) = gen.queue.front() {
                     let (completion, r#return) = &next.completion;
                     if *r#return {
-                        match completion {
-                            Ok(value) | Err(value) => self.vm.push(value),
-                        }
+                        let value = match completion {
+                            Ok(value) => value.clone(),
+                            Err(e) => e.clone().to_opaque(self),
+                        };
+                        self.vm.push(value);
                         self.vm.push(true);
                     } else {
                         self.vm.push(completion.clone()?);
@@ -2267,7 +2479,8 @@ impl Context {
                         let result =
                             self.call(&next_method, &iterator.clone().into(), &[received])?;
                         let result_object = result.as_object().ok_or_else(|| {
-                            self.construct_type_error("generator next method returned non-object")
+                            JsNativeError::typ()
+                                .with_message("generator next method returned non-object")
                         })?;
                         let done = result_object.get("done", self)?.to_boolean();
                         if done {
@@ -2288,9 +2501,8 @@ impl Context {
                         if let Some(throw) = throw {
                             let result = throw.call(&iterator.clone().into(), &[received], self)?;
                             let result_object = result.as_object().ok_or_else(|| {
-                                self.construct_type_error(
-                                    "generator throw method returned non-object",
-                                )
+                                JsNativeError::typ()
+                                    .with_message("generator throw method returned non-object")
                             })?;
                             let done = result_object.get("done", self)?.to_boolean();
                             if done {
@@ -2310,9 +2522,9 @@ impl Context {
                         let iterator_record =
                             IteratorRecord::new(iterator.clone(), next_method, done);
                         iterator_record.close(Ok(JsValue::Undefined), self)?;
-                        let error =
-                            self.construct_type_error("iterator does not have a throw method");
-                        return Err(error);
+                        let error = JsNativeError::typ()
+                            .with_message("iterator does not have a throw method");
+                        return Err(error.into());
                     }
                     GeneratorResumeKind::Return => {
                         let r#return = iterator.get_method("return", self)?;
@@ -2320,9 +2532,8 @@ impl Context {
                             let result =
                                 r#return.call(&iterator.clone().into(), &[received], self)?;
                             let result_object = result.as_object().ok_or_else(|| {
-                                self.construct_type_error(
-                                    "generator return method returned non-object",
-                                )
+                                JsNativeError::typ()
+                                    .with_message("generator return method returned non-object")
                             })?;
                             let done = result_object.get("done", self)?.to_boolean();
                             if done {
@@ -2358,7 +2569,7 @@ impl Context {
                 // 4. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
                 let on_fulfilled = FunctionBuilder::closure_with_captures(
                     self,
-                    |_this, args, (environment, stack, frame), context| {
+                    |_this, args, (environment, stack, frame, realm), context| {
                         // a. Let prevContext be the running execution context.
                         // b. Suspend prevContext.
                         // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
@@ -2366,8 +2577,9 @@ impl Context {
                         // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
                         // f. Return undefined.
 
-                        std::mem::swap(&mut context.realm.environments, environment);
+                        std::mem::swap(&mut context.vm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
+                        std::mem::swap(&mut context.realm, realm);
                         context.vm.push_frame(frame.clone());
 
                         context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Normal;
@@ -2378,15 +2590,17 @@ impl Context {
                             .vm
                             .pop_frame()
                             .expect("generator call frame must exist");
-                        std::mem::swap(&mut context.realm.environments, environment);
+                        std::mem::swap(&mut context.vm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
+                        std::mem::swap(&mut context.realm, realm);
 
                         Ok(JsValue::undefined())
                     },
                     (
-                        self.realm.environments.clone(),
+                        self.vm.environments.clone(),
                         self.vm.stack.clone(),
                         self.vm.frame().clone(),
+                        self.realm.clone(),
                     ),
                 )
                 .name("")
@@ -2397,7 +2611,7 @@ impl Context {
                 // 6. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
                 let on_rejected = FunctionBuilder::closure_with_captures(
                     self,
-                    |_this, args, (environment, stack, frame), context| {
+                    |_this, args, (environment, stack, frame, realm), context| {
                         // a. Let prevContext be the running execution context.
                         // b. Suspend prevContext.
                         // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
@@ -2405,8 +2619,9 @@ impl Context {
                         // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
                         // f. Return undefined.
 
-                        std::mem::swap(&mut context.realm.environments, environment);
+                        std::mem::swap(&mut context.vm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
+                        std::mem::swap(&mut context.realm, realm);
                         context.vm.push_frame(frame.clone());
 
                         context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Throw;
@@ -2417,15 +2632,17 @@ impl Context {
                             .vm
                             .pop_frame()
                             .expect("generator call frame must exist");
-                        std::mem::swap(&mut context.realm.environments, environment);
+                        std::mem::swap(&mut context.vm.environments, environment);
                         std::mem::swap(&mut context.vm.stack, stack);
+                        std::mem::swap(&mut context.realm, realm);
 
                         Ok(JsValue::undefined())
                     },
                     (
-                        self.realm.environments.clone(),
+                        self.vm.environments.clone(),
                         self.vm.stack.clone(),
                         self.vm.frame().clone(),
+                        self.realm.clone(),
                     ),
                 )
                 .name("")
@@ -2458,6 +2675,8 @@ impl Context {
 
         let _timer = Profiler::global().start_event("run", "vm");
 
+        HeapAccount::make_current(&self.vm.heap);
+
         if self.vm.trace {
             let msg = if self.vm.frames.last().is_some() {
                 " Call Frame "
@@ -2486,7 +2705,7 @@ impl Context {
         // If the current executing function is an async function we have to resolve/reject it's promise at the end.
         // The relevant spec section is 3. in [AsyncBlockStart](https://tc39.es/ecma262/#sec-asyncblockstart).
         let promise_capability = self
-            .realm
+            .vm
             .environments
             .get_this_environment()
             .as_function_slots()
@@ -2585,9 +2804,16 @@ impl Context {
                     let result = self.vm.stack.pop().unwrap_or(JsValue::Undefined);
                     return Ok((result, ReturnType::Yield));
                 }
-                Err(e) => {
-                    if let Some(address) = self.vm.frame().catch.last() {
-                        let address = address.next;
+                Err(mut e) => {
+                    e.record_stack_trace(self);
+
+                    // Terminations cannot be caught.
+                    let catch_address = if self.vm.termination.is_none() {
+                        self.vm.frame().catch.last().map(|address| address.next)
+                    } else {
+                        None
+                    };
+                    if let Some(address) = catch_address {
                         let try_stack_entry = self
                             .vm
                             .frame_mut()
@@ -2598,7 +2824,7 @@ impl Context {
                         try_stack_entry.num_env = 0;
                         try_stack_entry.num_loop_stack_entries = 0;
                         for _ in 0..try_stack_entry_copy.num_env {
-                            self.realm.environments.pop();
+                            self.vm.environments.pop();
                         }
                         let mut num_env = try_stack_entry_copy.num_env;
                         for _ in 0..try_stack_entry_copy.num_loop_stack_entries {
@@ -2624,12 +2850,14 @@ impl Context {
                         self.vm.frame_mut().catch.pop();
                         self.vm.frame_mut().finally_return = FinallyReturn::Err;
                         self.vm.frame_mut().thrown = true;
+                        let e = e.to_opaque(self);
                         self.vm.push(e);
                     } else {
                         self.vm.stack.truncate(start_stack_size);
 
                         // Step 3.f in [AsyncBlockStart](https://tc39.es/ecma262/#sec-asyncblockstart).
                         if let Some(promise_capability) = promise_capability {
+                            let e = e.to_opaque(self);
                             promise_capability
                                 .reject()
                                 .call(&JsValue::undefined(), &[e.clone()], self)
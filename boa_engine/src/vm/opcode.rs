@@ -506,7 +506,7 @@ pub enum Opcode {
     ///
     /// Like `object.name`
     ///
-    /// Operands: name_index: `u32`
+    /// Operands: name_index: `u32`, cache_index: `u32`
     ///
     /// Stack: object **=>** value
     GetPropertyByName,
@@ -533,7 +533,7 @@ pub enum Opcode {
     ///
     /// Like `object.name = value`
     ///
-    /// Operands: name_index: `u32`
+    /// Operands: name_index: `u32`, cache_index: `u32`
     ///
     /// Stack: value, object **=>**
     SetPropertyByName,
@@ -0,0 +1,247 @@
+//! Inline caches of the property accesses of the VM.
+//!
+//! Every `GetPropertyByName` and `SetPropertyByName` instruction has a [`PropertyCache`], which
+//! remembers the [`Shape`]s of the objects that the last access went through and the slot where
+//! the property was found. When the instruction accesses an object with the same shapes again,
+//! the property is read or written directly in its slot, without looking up its key.
+//!
+//! Shapes only describe the string keyed properties of an object, so a cached access is only
+//! valid if every object on the way gets and sets its properties with the ordinary internal
+//! methods. Everything else, such as the prototype of an object or the attributes of a property,
+//! is checked again on every access.
+
+use crate::{
+    object::{shape::Shape, JsObject, Object},
+    property::{DescriptorKind, PropertyDescriptor, PropertyKey},
+    Context, JsResult, JsValue,
+};
+use std::cell::RefCell;
+
+/// The inline cache of a property access instruction.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PropertyCache {
+    entry: RefCell<Option<Entry>>,
+}
+
+#[derive(Debug, Clone)]
+enum Entry {
+    /// The property was found in the slot `slot` of an object of the prototype chain of the
+    /// receiver.
+    ///
+    /// `shapes` holds the shapes of the receiver and of its prototypes, up to the object that
+    /// has the property.
+    Get { shapes: Box<[Shape]>, slot: u32 },
+
+    /// The property is a writable data property in the slot `slot` of the receiver.
+    Replace { shape: Shape, slot: u32 },
+
+    /// The property was not found in the prototype chain of the receiver, so it was added to
+    /// the receiver, which got the shape `shape`.
+    ///
+    /// `shapes` holds the shapes of the receiver and of all its prototypes.
+    Add { shapes: Box<[Shape]>, shape: Shape },
+}
+
+impl PropertyCache {
+    /// Gets the property of `object` if the access hits the cache.
+    ///
+    /// Returns `None` if the property has to be got with `[[Get]]`, in which case
+    /// [`Self::fill_get`] should be called first.
+    pub(crate) fn get(
+        &self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> Option<JsResult<JsValue>> {
+        let getter = {
+            let (holder, slot) = match &*self.entry.borrow() {
+                Some(Entry::Get { shapes, slot }) => {
+                    (walk(object, shapes, Object::has_ordinary_get)?, *slot)
+                }
+                _ => return None,
+            };
+
+            let holder = holder.borrow();
+            match holder.properties().slot(slot).kind() {
+                DescriptorKind::Data {
+                    value: Some(value), ..
+                } => return Some(Ok(value.clone())),
+                DescriptorKind::Accessor { get: Some(get), .. } if !get.is_undefined() => {
+                    get.clone()
+                }
+                _ => return Some(Ok(JsValue::undefined())),
+            }
+        };
+
+        Some(context.call(&getter, &object.clone().into(), &[]))
+    }
+
+    /// Sets the property `key` of `object` to `value` if the access hits the cache.
+    ///
+    /// Returns the value back if the property has to be set with `[[Set]]`, in which case
+    /// [`Self::fill_set`] must be called with the result of [`Self::prepare_set`] afterwards.
+    pub(crate) fn set(&self, object: &JsObject, value: JsValue) -> Result<(), JsValue> {
+        let entry = self.entry.borrow();
+        match &*entry {
+            Some(Entry::Replace { shape, slot }) => {
+                let mut object = object.borrow_mut();
+                if !object.has_ordinary_set() || object.properties().shape() != Some(shape) {
+                    return Err(value);
+                }
+                let property = object.properties_mut().slot_mut(*slot);
+                if property.writable() != Some(true) {
+                    return Err(value);
+                }
+                property.fill_with(&PropertyDescriptor::builder().value(value).build());
+                Ok(())
+            }
+            Some(Entry::Add { shapes, shape }) => {
+                let last = match walk(object, shapes, Object::has_ordinary_set) {
+                    Some(last) => last,
+                    None => return Err(value),
+                };
+                if last.borrow().prototype().is_some() || !object.borrow().extensible() {
+                    return Err(value);
+                }
+                object.borrow_mut().properties_mut().push_slot(
+                    shape.clone(),
+                    PropertyDescriptor::builder()
+                        .value(value)
+                        .writable(true)
+                        .enumerable(true)
+                        .configurable(true)
+                        .build(),
+                );
+                Ok(())
+            }
+            _ => Err(value),
+        }
+    }
+
+    /// Inspects `object` before the property `key` is set with `[[Set]]` after a miss.
+    ///
+    /// If the property is a writable data property of `object`, the cache is filled right away.
+    /// If the property would be added to `object`, the shapes of its prototype chain are
+    /// returned, to be passed to [`Self::fill_set`].
+    pub(crate) fn prepare_set(&self, object: &JsObject, key: &PropertyKey) -> Option<Box<[Shape]>> {
+        let key = match key {
+            PropertyKey::String(key) => key,
+            _ => return None,
+        };
+
+        {
+            let object = object.borrow();
+            if !object.has_ordinary_set() {
+                return None;
+            }
+            let shape = object.properties().shape()?;
+            if let Some(slot) = shape.slot(key) {
+                if object.properties().slot(slot).writable() == Some(true) {
+                    *self.entry.borrow_mut() = Some(Entry::Replace {
+                        shape: shape.clone(),
+                        slot,
+                    });
+                }
+                return None;
+            }
+        }
+
+        let mut shapes = Vec::new();
+        let mut current = object.clone();
+        loop {
+            let prototype = {
+                let current = current.borrow();
+                if !current.has_ordinary_set() {
+                    return None;
+                }
+                let shape = current.properties().shape()?;
+                if shape.slot(key).is_some() {
+                    return None;
+                }
+                shapes.push(shape.clone());
+                current.prototype().clone()
+            };
+            match prototype {
+                Some(prototype) => current = prototype,
+                None => return Some(shapes.into_boxed_slice()),
+            }
+        }
+    }
+
+    /// Fills the cache after the property `key` was added to `object` by `[[Set]]`.
+    ///
+    /// `shapes` are the shapes returned by [`Self::prepare_set`] before the property was set.
+    pub(crate) fn fill_set(&self, object: &JsObject, key: &PropertyKey, shapes: Box<[Shape]>) {
+        let object = object.borrow();
+        let shape = match object.properties().shape() {
+            Some(shape) => shape,
+            None => return,
+        };
+        let added = shape.len() == shapes[0].len() + 1
+            && matches!(key, PropertyKey::String(key) if shape.slot(key) == Some(shapes[0].len() as u32));
+        if added {
+            *self.entry.borrow_mut() = Some(Entry::Add {
+                shapes,
+                shape: shape.clone(),
+            });
+        }
+    }
+
+    /// Fills the cache with the location of the property `key` in the prototype chain of
+    /// `object`, if it can be cached.
+    pub(crate) fn fill_get(&self, object: &JsObject, key: &PropertyKey) {
+        let key = match key {
+            PropertyKey::String(key) => key,
+            _ => return,
+        };
+
+        let mut shapes = Vec::new();
+        let mut current = object.clone();
+        loop {
+            let prototype = {
+                let current = current.borrow();
+                if !current.has_ordinary_get() {
+                    return;
+                }
+                let shape = match current.properties().shape() {
+                    Some(shape) => shape,
+                    None => return,
+                };
+                shapes.push(shape.clone());
+                if let Some(slot) = shape.slot(key) {
+                    *self.entry.borrow_mut() = Some(Entry::Get {
+                        shapes: shapes.into_boxed_slice(),
+                        slot,
+                    });
+                    return;
+                }
+                current.prototype().clone()
+            };
+            match prototype {
+                Some(prototype) => current = prototype,
+                None => return,
+            }
+        }
+    }
+}
+
+/// Walks the prototype chain of `object`, checking that the objects have the shapes `shapes`
+/// and pass the check `ordinary`.
+///
+/// Returns the last object of the walk.
+fn walk(object: &JsObject, shapes: &[Shape], ordinary: fn(&Object) -> bool) -> Option<JsObject> {
+    let mut current = object.clone();
+    for (index, shape) in shapes.iter().enumerate() {
+        let prototype = {
+            let borrowed = current.borrow();
+            if !ordinary(&borrowed) || borrowed.properties().shape() != Some(shape) {
+                return None;
+            }
+            if index + 1 == shapes.len() {
+                break;
+            }
+            borrowed.prototype().clone()?
+        };
+        current = prototype;
+    }
+    Some(current)
+}
@@ -0,0 +1,128 @@
+//! Limits on the resources that the code executed by a [`Context`][crate::Context] can use.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// The limits on the execution of a [`Context`][crate::Context].
+///
+/// Exceeding the recursion limit or the stack size limit throws a `RangeError`, which can be
+/// caught by the executed code. Exceeding the instruction limit or the heap limit terminates the
+/// execution instead: the error cannot be caught by `try` statements and every instruction
+/// executed after it fails with the same error, until it is returned by
+/// [`Context::execute`][crate::Context::execute].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuntimeLimits {
+    instruction_limit: Option<u64>,
+    recursion_limit: usize,
+    stack_size_limit: usize,
+    heap_limit: Option<usize>,
+}
+
+impl Default for RuntimeLimits {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            instruction_limit: None,
+            recursion_limit: 400,
+            stack_size_limit: 1024,
+            heap_limit: None,
+        }
+    }
+}
+
+impl RuntimeLimits {
+    /// Gets the maximum number of instructions executed by a single call to
+    /// [`Context::execute`][crate::Context::execute], if any.
+    #[inline]
+    pub fn instruction_limit(&self) -> Option<u64> {
+        self.instruction_limit
+    }
+
+    /// Sets the maximum number of instructions executed by a single call to
+    /// [`Context::execute`][crate::Context::execute], including the jobs it runs.
+    ///
+    /// Functions called directly by the host outside of an execution count towards the limit
+    /// of the previous execution.
+    #[inline]
+    pub fn set_instruction_limit(&mut self, limit: Option<u64>) {
+        self.instruction_limit = limit;
+    }
+
+    /// Gets the maximum number of nested function calls.
+    #[inline]
+    pub fn recursion_limit(&self) -> usize {
+        self.recursion_limit
+    }
+
+    /// Sets the maximum number of nested function calls.
+    ///
+    /// Every call runs on the native stack of the thread executing the context, so raising
+    /// this limit may require a bigger native stack to avoid overflowing it.
+    #[inline]
+    pub fn set_recursion_limit(&mut self, limit: usize) {
+        self.recursion_limit = limit;
+    }
+
+    /// Gets the maximum number of values on the stack of the VM, which is shared by all the
+    /// active call frames.
+    #[inline]
+    pub fn stack_size_limit(&self) -> usize {
+        self.stack_size_limit
+    }
+
+    /// Sets the maximum number of values on the stack of the VM, which is shared by all the
+    /// active call frames.
+    ///
+    /// The limit is checked before calling a function, so it bounds the values of the callers
+    /// together with the arguments of the call.
+    #[inline]
+    pub fn set_stack_size_limit(&mut self, limit: usize) {
+        self.stack_size_limit = limit;
+    }
+
+    /// Gets the maximum number of bytes of memory charged to the context, if any.
+    #[inline]
+    pub fn heap_limit(&self) -> Option<usize> {
+        self.heap_limit
+    }
+
+    /// Sets the maximum number of bytes of memory charged to the context.
+    ///
+    /// Objects, including the storage of their properties, strings and `ArrayBuffer` data are
+    /// charged to the context that was running code when they were allocated, until they are
+    /// freed. See [`Context::heap_usage`][crate::Context::heap_usage].
+    ///
+    /// The limit is checked before every instruction, when properties are defined, and before
+    /// allocating the data of an `ArrayBuffer` or repeating a string. Once it is exceeded, a
+    /// garbage collection runs to free the unreachable allocations, and the execution is only
+    /// terminated if the memory is still above the limit, so the memory of a context can exceed
+    /// the limit by a single allocation.
+    #[inline]
+    pub fn set_heap_limit(&mut self, limit: Option<usize>) {
+        self.heap_limit = limit;
+    }
+}
+
+/// A handle to interrupt the execution of a [`Context`][crate::Context] from another thread.
+///
+/// Interrupting terminates the running execution the same way as exceeding the instruction
+/// limit of the [`RuntimeLimits`]. If no code is running, the next execution is terminated as
+/// soon as it starts.
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle(Arc<AtomicBool>);
+
+impl InterruptHandle {
+    /// Requests the termination of the execution of the context.
+    #[inline]
+    pub fn interrupt(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Consumes a pending interrupt request, returning `true` if there was one.
+    #[inline]
+    pub(crate) fn take(&self) -> bool {
+        self.0.load(Ordering::Relaxed) && self.0.swap(false, Ordering::Relaxed)
+    }
+}
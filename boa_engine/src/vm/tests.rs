@@ -1,4 +1,8 @@
-use crate::{exec, Context, JsValue};
+use crate::{
+    check_output, error::JsError, exec, syntax::ast::Position, vm::RuntimeLimits, Context,
+    JsString, JsValue, TestAction,
+};
+use boa_gc::GcConfig;
 
 #[test]
 fn typeof_string() {
@@ -43,7 +47,10 @@ fn try_catch_finally_from_init() {
         }
     "#;
 
-    assert_eq!(Context::default().eval(source.as_bytes()), Err("h".into()));
+    assert_eq!(
+        Context::default().eval(source.as_bytes()),
+        Err(JsError::from_opaque("h".into()))
+    );
 }
 
 #[test]
@@ -137,3 +144,515 @@ fn finally_block_binding_env() {
         Ok(JsValue::from("Hey hey people"))
     );
 }
+
+#[test]
+fn error_location() {
+    let source = r#"
+let a = 1;
+function f() {
+    a.b.c;
+}
+try {
+    f();
+} catch (e) {
+    e
+}
+"#;
+
+    let mut context = Context::default();
+    let error = context.eval_with_name(source, "script.js").unwrap();
+    let error = error.as_object().unwrap().borrow();
+    let location = error.error_location().unwrap();
+    assert_eq!(
+        location.source_name().map(JsString::to_std_string_escaped),
+        Some("script.js".to_owned())
+    );
+    assert_eq!(location.position(), Position::new(4, 8));
+    assert_eq!(location.to_string(), "script.js:4:8");
+}
+
+#[test]
+fn error_location_after_nested_statements() {
+    let source = r#"
+if (true) {
+    1;
+}
+new Error()"#;
+
+    let mut context = Context::default();
+    let error = context.eval(source).unwrap();
+    let error = error.as_object().unwrap().borrow();
+    let location = error.error_location().unwrap();
+    assert_eq!(location.source_name(), None);
+    assert_eq!(location.to_string(), "<anonymous>:5:1");
+}
+
+#[test]
+fn error_location_in_loop_condition() {
+    let source = r#"
+let i = 0, e;
+while (i < 2 ? true : (e = new Error(), false)) {
+    i++;
+}
+e"#;
+
+    let mut context = Context::default();
+    let error = context.eval(source).unwrap();
+    let error = error.as_object().unwrap().borrow();
+    assert_eq!(
+        error.error_location().unwrap().position(),
+        Position::new(3, 28)
+    );
+}
+
+#[test]
+fn recursion_limit() {
+    let mut context = Context::default();
+    let result = context
+        .eval(
+            r#"
+            function f(n) { return n === 0 ? 0 : f(n - 1) + 1; }
+            let error;
+            try { f(100000); } catch (e) { error = e; }
+            [error instanceof RangeError, f(100)]
+            "#,
+        )
+        .unwrap();
+    assert_eq!(result.display().to_string(), "[ true, 100 ]");
+}
+
+#[test]
+fn default_recursion_limit_fits_in_stack() {
+    let mut context = Context::default();
+    let limit = context.runtime_limits().recursion_limit();
+    let result = context
+        .eval(format!(
+            "function f(n) {{ return n === 0 ? 0 : [n].map(x => f(x - 1))[0] + 1; }} f({})",
+            limit / 2 - 1
+        ))
+        .unwrap();
+    assert_eq!(result, JsValue::new(limit / 2 - 1));
+}
+
+#[test]
+fn instruction_limit_is_not_catchable() {
+    let mut limits = RuntimeLimits::default();
+    limits.set_instruction_limit(Some(10_000));
+    let mut context = Context::builder().runtime_limits(limits).build();
+
+    let error = context
+        .eval("var caught = false; while (true) { try { for (;;) {} } catch { caught = true; } }")
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "RangeError: Maximum instruction count exceeded"
+    );
+    assert_eq!(context.eval("caught").unwrap(), JsValue::new(false));
+
+    for source in [
+        "(async function () { for (;;) {} })(); 1",
+        "function f() { Promise.resolve().then(f); } f(); 1",
+    ] {
+        let error = context.eval(source).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "RangeError: Maximum instruction count exceeded"
+        );
+    }
+}
+
+#[test]
+fn interrupt_handle() {
+    let mut context = Context::default();
+    let handle = context.interrupt_handle();
+
+    let thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        handle.interrupt();
+    });
+    let error = context.eval("for (;;) {}").unwrap_err();
+    thread.join().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "RangeError: Script execution was interrupted"
+    );
+
+    assert_eq!(context.eval("1 + 1").unwrap(), JsValue::new(2));
+}
+
+#[test]
+fn uncaught_error_pops_environments() {
+    let mut context = Context::default();
+    assert!(context
+        .eval("function f() { let y = 2; return y; } { let x = 1; throw 1; }")
+        .is_err());
+    assert_eq!(context.eval("f()").unwrap(), JsValue::new(2));
+}
+
+/// Compiles `src` in a new context and returns its bytecode cache.
+fn bytecode_cache(src: &str) -> Vec<u8> {
+    let mut context = Context::default();
+    let statement_list = context.parse(src).unwrap();
+    let code_block = context
+        .compile_with_name(&statement_list, "cached.js")
+        .unwrap();
+    context.serialize_code_block(&code_block)
+}
+
+#[test]
+fn bytecode_cache_roundtrip() {
+    let cache = bytecode_cache(
+        r#"
+        var counter = 0;
+        let prefix = "count: \uD800";
+        const big = 12345678901234567890n;
+        function increment(step = 1) {
+            counter += step;
+            return counter;
+        }
+        class Point {
+            #x;
+            constructor(x) { this.#x = x; }
+            get x() { return this.#x; }
+        }
+        const makeAdder = (a) => (b) => a + b;
+        increment();
+        increment(2);
+        { let scoped = makeAdder(new Point(3).x)(4); increment(scoped); }
+        prefix + counter + (big * 2n === 24691357802469135780n)
+    "#,
+    );
+
+    let mut context = Context::default();
+    let code_block = context.deserialize_code_block(&cache).unwrap();
+    assert_eq!(code_block.source_name(), Some(&JsString::from("cached.js")));
+    let value = context.execute(code_block).unwrap();
+    assert_eq!(
+        value,
+        JsValue::new(JsString::from(
+            &[
+                "count: ".encode_utf16().collect::<Vec<_>>(),
+                vec![0xD800],
+                "10true".encode_utf16().collect::<Vec<_>>(),
+            ]
+            .concat()[..]
+        ))
+    );
+
+    // The global declarations of the cached code are visible to other scripts.
+    assert_eq!(context.eval("increment(5)").unwrap(), JsValue::new(15));
+    assert_eq!(
+        context.eval("typeof Point").unwrap(),
+        JsValue::new("function")
+    );
+    assert_eq!(
+        context.eval("globalThis.counter").unwrap(),
+        JsValue::new(15)
+    );
+}
+
+#[test]
+fn bytecode_cache_global_conflict() {
+    let cache = bytecode_cache("let x = 1; x");
+
+    let mut context = Context::default();
+    context.eval("let y = 2;").unwrap();
+    let error = context.deserialize_code_block(&cache).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Error: bytecode cache conflicts with the global binding `x`"
+    );
+}
+
+#[test]
+fn bytecode_cache_invalid() {
+    let mut cache = bytecode_cache("1 + 1");
+    let mut context = Context::default();
+
+    let error = context
+        .deserialize_code_block(&cache[..cache.len() - 1])
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Error: invalid bytecode cache: checksum mismatch"
+    );
+
+    let last = cache.len() - 1;
+    cache[last] ^= 1;
+    let error = context.deserialize_code_block(&cache).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Error: invalid bytecode cache: checksum mismatch"
+    );
+    cache[last] ^= 1;
+
+    cache[4] = cache[4].wrapping_add(1);
+    let error = context.deserialize_code_block(&cache).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Error: unsupported bytecode cache format version"));
+}
+
+#[test]
+fn bytecode_cache_control_flow() {
+    let cache = bytecode_cache(
+        r#"
+        function* numbers() { yield 1; yield* [2, 3]; }
+        async function later() { await null; }
+        let log = [];
+        for (const n of numbers()) {
+            switch (n) {
+                case 1: log.push("one"); break;
+                default: log.push(n);
+            }
+        }
+        for (const key in { a: 1, b: 2 }) { log.push(key); }
+        try { throw 1; } catch { log.push("catch"); } finally { log.push("finally"); }
+        const { a, ...rest } = { a: 1, b: 2 };
+        log.push(a ?? rest.b, rest.b && `${a}`, ...[4]);
+        later();
+        log.join()
+    "#,
+    );
+
+    let mut context = Context::default();
+    let code_block = context.deserialize_code_block(&cache).unwrap();
+    assert_eq!(
+        context.execute(code_block).unwrap(),
+        JsValue::new("one,2,3,a,b,catch,finally,1,1,4")
+    );
+}
+
+#[test]
+fn bytecode_cache_invalid_instructions() {
+    let mut context = Context::default();
+    let statement_list = context.parse("1 + 1").unwrap();
+    let code_block = context.compile(&statement_list).unwrap();
+    let mut cache = context.serialize_code_block(&code_block);
+
+    // Replaces the first opcode of the code with an invalid one, and updates the checksum so
+    // that only the verification of the instructions can reject the cache.
+    let mut code = (code_block.code.len() as u32).to_le_bytes().to_vec();
+    code.extend_from_slice(&code_block.code);
+    let start = cache
+        .windows(code.len())
+        .position(|window| window == code)
+        .unwrap();
+    cache[start + 4] = u8::MAX;
+    let checksum_start = 12 + env!("CARGO_PKG_VERSION").len();
+    let checksum = super::cache::checksum(&cache[checksum_start + 8..]);
+    cache[checksum_start..checksum_start + 8].copy_from_slice(&checksum.to_le_bytes());
+
+    let error = context.deserialize_code_block(&cache).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Error: invalid bytecode cache: malformed data"
+    );
+}
+
+#[test]
+fn property_cache_prototype_changes() {
+    check_output(&[
+        TestAction::Execute(
+            r#"
+            function get(o) { return o.value; }
+            const proto = { value: 1 };
+            const o = Object.create(proto);
+            const results = [get(o), get(o)];
+            proto.value = 2;
+            results.push(get(o));
+            o.value = 3;
+            results.push(get(o));
+            delete o.value;
+            results.push(get(o));
+            Object.setPrototypeOf(o, { value: 4 });
+            results.push(get(o));
+            Object.setPrototypeOf(o, null);
+            results.push(get(o));
+            "#,
+        ),
+        TestAction::TestEq("results.join()", "\"1,1,2,3,2,4,\""),
+    ]);
+}
+
+#[test]
+fn property_cache_accessors() {
+    check_output(&[
+        TestAction::Execute(
+            r#"
+            function get(o) { return o.value; }
+            function set(o, v) { o.value = v; }
+            let stored = 0;
+            const proto = {
+                get value() { return this.offset + stored; },
+                set value(v) { stored = v; },
+            };
+            const a = Object.create(proto);
+            a.offset = 10;
+            const results = [get(a), get(a)];
+            set(a, 5);
+            set(a, 6);
+            results.push(get(a), Object.keys(a).join());
+            "#,
+        ),
+        TestAction::TestEq("results.join()", "\"10,10,16,offset\""),
+    ]);
+}
+
+#[test]
+fn property_cache_sets() {
+    check_output(&[
+        TestAction::Execute(
+            r#"
+            function init(o) { o.x = 1; o.y = 2; return o; }
+            const a = init({});
+            const b = init({});
+            const frozen = Object.freeze({});
+            init(frozen);
+            const sealed = Object.freeze({ x: 0, y: 0 });
+            init(sealed);
+            const proto = {};
+            const c = Object.create(proto);
+            Object.defineProperty(proto, "x", { value: 5, writable: false });
+            init(c);
+            function update(o) { o.x += 1; }
+            update(a);
+            update(a);
+            "#,
+        ),
+        TestAction::TestEq("[a.x, a.y, b.x, b.y].join()", "\"3,2,1,2\""),
+        TestAction::TestEq("Object.keys(frozen).length", "0"),
+        TestAction::TestEq("[sealed.x, sealed.y].join()", "\"0,0\""),
+        TestAction::TestEq("[c.x, c.y, Object.keys(c).join()].join()", "\"5,2,y\""),
+        TestAction::TestEq("Object.getOwnPropertyDescriptor(a, 'x').writable", "true"),
+        TestAction::Execute(
+            "const arr = [1, 2, 3]; function truncate(o) { o.length = 1; } truncate(arr); truncate(arr);",
+        ),
+        TestAction::TestEq("arr.join()", "\"1\""),
+    ]);
+}
+
+#[test]
+fn collections_preserve_reachable_objects() {
+    let mut config = GcConfig::default();
+    config.set_young_generation_size(16 * 1024);
+    config.set_major_threshold(64 * 1024);
+    config.set_mark_step_size(16);
+    let mut context = Context::builder().gc_config(config).build();
+
+    let result = context
+        .eval(
+            r"
+            const list = { next: null, value: -1 };
+            const map = new Map();
+            for (let i = 0; i < 5000; i++) {
+                list.next = { next: list.next, value: i, garbage: [i, { i }] };
+                map.set(i, { i });
+            }
+            let sum = 0;
+            for (let node = list.next; node !== null; node = node.next) {
+                sum += node.value + node.garbage[1].i;
+            }
+            for (const [key, value] of map) {
+                sum -= key + value.i;
+            }
+            sum
+            ",
+        )
+        .unwrap();
+    assert_eq!(result, JsValue::new(0));
+
+    let stats = context.gc_stats();
+    assert!(stats.minor_collections() > 0);
+    assert!(stats.major_collections() > 0);
+    assert!(stats.pauses() >= stats.minor_collections() + stats.major_collections());
+}
+
+#[test]
+fn heap_limit_is_not_catchable() {
+    fn limited_context() -> Context {
+        let mut context = Context::default();
+        let mut limits = context.runtime_limits();
+        limits.set_heap_limit(Some(context.heap_usage() + 1024 * 1024));
+        context.set_runtime_limits(limits);
+        context
+    }
+
+    for source in [
+        "try { new Array(1e9).fill(0); } catch {}",
+        "try { new ArrayBuffer(1e9); } catch {}",
+        "const b = new ArrayBuffer(0, { maxByteLength: 1e9 }); try { b.resize(1e9); } catch {}",
+        "try { 'a'.repeat(1e8); } catch {}",
+        "let s = 'ab'; try { for (;;) { s += s; } } catch {}",
+        "const objects = []; try { for (;;) { objects.push({}); } } catch {}",
+    ] {
+        let mut context = limited_context();
+        let error = context.eval(source).unwrap_err();
+        assert_eq!(error.to_string(), "RangeError: Maximum heap size exceeded");
+    }
+
+    // The garbage is collected before giving up.
+    let mut context = limited_context();
+    let result = context
+        .eval("let sum = 0; for (let i = 0; i < 50000; i++) { sum += [i, { i }][1].i; } sum")
+        .unwrap();
+    assert_eq!(result, JsValue::new(1_249_975_000));
+}
+
+#[test]
+fn resized_array_buffers_are_charged() {
+    let mut context = Context::default();
+    context
+        .eval("var buffer = new ArrayBuffer(0, { maxByteLength: 1e8 });")
+        .unwrap();
+    let before = context.heap_usage();
+
+    context.eval("buffer.resize(1e6)").unwrap();
+    assert!(context.heap_usage() >= before + 1_000_000);
+
+    context.eval("buffer.resize(0)").unwrap();
+    assert!(context.heap_usage() < before + 1_000_000);
+}
+
+#[test]
+fn error_location_in_multiline_expression() {
+    let source = r#"
+try {
+    [1, 2]
+        .map((x) => x)
+        .filter(undefined.callback);
+} catch (e) {
+    e
+}
+"#;
+
+    let mut context = Context::default();
+    let error = context.eval(source).unwrap();
+    let error = error.as_object().unwrap().borrow();
+    assert_eq!(
+        error.error_location().unwrap().position(),
+        Position::new(5, 26)
+    );
+}
+
+#[test]
+fn error_location_in_chained_call() {
+    let source = r#"
+let o = { a: {} };
+try {
+    o
+        .a
+        .b();
+} catch (e) {
+    e
+}
+"#;
+
+    let mut context = Context::default();
+    let error = context.eval(source).unwrap();
+    let error = error.as_object().unwrap().borrow();
+    assert_eq!(
+        error.error_location().unwrap().position(),
+        Position::new(6, 11)
+    );
+}
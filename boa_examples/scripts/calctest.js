@@ -1,5 +1,5 @@
 //load module
-let calc = require("./scripts/calc.js");
+import * as calc from "./calc.js";
 
 console.log("Using calc module");
 console.log("Add: " + calc.add(3, 3));
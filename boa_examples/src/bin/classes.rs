@@ -2,7 +2,7 @@
 use boa_engine::{
     class::{Class, ClassBuilder},
     property::Attribute,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -44,7 +44,9 @@ impl Person {
         }
         // If `this` was not an object or the type of `this` was not a native object `Person`,
         // we throw a `TypeError`.
-        context.throw_type_error("'this' is not a Person object")
+        Err(JsNativeError::typ()
+            .with_message("'this' is not a Person object")
+            .into())
     }
 }
 
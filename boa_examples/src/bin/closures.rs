@@ -2,13 +2,15 @@
 // inside Rust and call them from Javascript.
 
 use boa_engine::{
+    js_string,
     object::{FunctionBuilder, JsObject},
     property::{Attribute, PropertyDescriptor},
-    Context, JsString, JsValue,
+    string::utf16,
+    Context, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 
-fn main() -> Result<(), JsValue> {
+fn main() -> JsResult<()> {
     // We create a new `Context` to create a new Javascript executor.
     let mut context = Context::default();
 
@@ -67,12 +69,12 @@ fn main() -> Result<(), JsValue> {
             let name = captures.object.get("name", context)?;
 
             // We create a new message from our captured variable.
-            let message = JsString::concat_array(&[
-                "message from `",
-                name.to_string(context)?.as_str(),
-                "`: ",
-                captures.greeting.as_str(),
-            ]);
+            let message = js_string!(
+                utf16!("message from `"),
+                &name.to_string(context)?,
+                utf16!("`: "),
+                &captures.greeting
+            );
 
             // We can also mutate the moved data inside the closure.
             captures.greeting = format!("{} Hello!", captures.greeting).into();
@@ -0,0 +1,178 @@
+//! The state the collector shares with the pointers it manages while the program runs.
+//!
+//! Pointers need to cooperate with the collector in two situations:
+//!
+//! - While a major collection is marking incrementally, the program keeps running between the
+//!   steps of the collection. Storing a pointer in the heap could hide an allocation from the
+//!   collector if the allocation storing it was already traced, so every pointer that is stored
+//!   in the heap is marked (a Dijkstra write barrier).
+//! - Minor collections only trace the young generation, so they need to know which old
+//!   allocations point to young ones. Mutating the contents of a [`GcCell`][crate::GcCell] that
+//!   lives in an old allocation adds the allocation to the remembered set if any of the new
+//!   contents are young.
+//!
+//! Pointers are stored in the heap when they are unrooted, which happens when a new allocation
+//! is created and when a mutable borrow of a `GcCell` ends, so both barriers are run from there.
+
+use crate::internals::{ErasedValue, GcBox, GcBoxHeader};
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    mem,
+    ptr::NonNull,
+};
+
+/// The phase of the collector of the current thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Phase {
+    /// No collection is in progress.
+    Idle,
+    /// A minor collection is in progress. Old allocations are considered reachable.
+    Minor,
+    /// A major collection is marking the heap. The program may run between its steps.
+    Marking,
+    /// The allocations pointed to by a value are being collected by [`crate::edges`].
+    Inspecting,
+}
+
+/// The lists of allocations used by the collector while it marks the heap.
+#[derive(Default)]
+struct Worklists {
+    /// Allocations that were marked but whose contents haven't been traced yet.
+    gray: Vec<NonNull<GcBox<dyn ErasedValue>>>,
+    /// Old allocations that may point to young allocations.
+    remembered: Vec<NonNull<GcBox<dyn ErasedValue>>>,
+    /// Every old allocation, indexed by its address.
+    old_boxes: BTreeMap<usize, NonNull<GcBox<dyn ErasedValue>>>,
+}
+
+thread_local!(static PHASE: Cell<Phase> = const { Cell::new(Phase::Idle) });
+
+// Set when a young allocation is stored in the heap, to find out if the mutation of a `GcCell`
+// created any pointer from its allocation to the young generation.
+thread_local!(static STORED_YOUNG: Cell<bool> = const { Cell::new(false) });
+
+thread_local!(static WORKLISTS: RefCell<Worklists> = RefCell::new(Worklists::default()));
+
+/// Returns the phase of the collector.
+pub(crate) fn phase() -> Phase {
+    PHASE.with(Cell::get)
+}
+
+/// Sets the phase of the collector.
+pub(crate) fn set_phase(phase: Phase) {
+    PHASE.with(|current| current.set(phase));
+}
+
+/// Returns `true` if the worklists of the current thread can still be used.
+///
+/// This is `false` while the thread is being destroyed, once they have been dropped.
+pub(crate) fn is_available() -> bool {
+    WORKLISTS.try_with(|_| ()).is_ok()
+}
+
+/// Returns `true` if the collection in progress has to consider the allocation reachable.
+///
+/// Old allocations are not traced by minor collections, so they are always reachable for them.
+pub(crate) fn is_reachable(header: &GcBoxHeader) -> bool {
+    header.is_marked() || (header.is_old() && phase() == Phase::Minor)
+}
+
+/// Marks an allocation as reachable, queueing it to have its contents traced.
+pub(crate) fn mark(ptr: NonNull<GcBox<dyn ErasedValue>>) {
+    // SAFETY: only pointers to live allocations are marked.
+    let header = unsafe { &ptr.as_ref().header };
+    if is_reachable(header) {
+        return;
+    }
+    header.mark();
+    WORKLISTS.with(|lists| lists.borrow_mut().gray.push(ptr));
+}
+
+/// Takes the next allocation whose contents need to be traced.
+pub(crate) fn pop_gray() -> Option<NonNull<GcBox<dyn ErasedValue>>> {
+    WORKLISTS.with(|lists| lists.borrow_mut().gray.pop())
+}
+
+/// Runs the write barrier for a pointer to `ptr` that was just stored in the heap.
+pub(crate) fn on_store(ptr: NonNull<GcBox<dyn ErasedValue>>) {
+    // SAFETY: only pointers to live allocations can be stored.
+    let header = unsafe { &ptr.as_ref().header };
+    if !header.is_old() {
+        STORED_YOUNG.with(|stored| stored.set(true));
+    }
+    if phase() == Phase::Marking {
+        mark(ptr);
+    }
+}
+
+/// Starts tracking whether young allocations are stored in the heap.
+pub(crate) fn start_mutation() {
+    STORED_YOUNG.with(|stored| stored.set(false));
+}
+
+/// Adds the old allocation containing the address `cell` to the remembered set if any young
+/// allocation was stored since the last call to [`start_mutation`].
+pub(crate) fn end_mutation(cell: usize) {
+    if !STORED_YOUNG.with(|stored| stored.replace(false)) {
+        return;
+    }
+    WORKLISTS.with(|lists| {
+        let mut lists = lists.borrow_mut();
+        let container = lists
+            .old_boxes
+            .range(..=cell)
+            .next_back()
+            .map(|(_, ptr)| *ptr);
+        if let Some(ptr) = container {
+            // SAFETY: the pointers of the map are removed as soon as their allocations are freed.
+            let gc_box = unsafe { ptr.as_ref() };
+            let start = ptr.as_ptr().cast::<u8>() as usize;
+            if cell < start + mem::size_of_val(gc_box) && !gc_box.header.is_remembered() {
+                gc_box.header.set_remembered(true);
+                lists.remembered.push(ptr);
+            }
+        }
+    });
+}
+
+/// Clears the list of allocations whose contents need to be traced.
+pub(crate) fn clear_gray() {
+    WORKLISTS.with(|lists| lists.borrow_mut().gray.clear());
+}
+
+/// Returns the allocations of the remembered set.
+pub(crate) fn remembered() -> Vec<NonNull<GcBox<dyn ErasedValue>>> {
+    WORKLISTS.with(|lists| lists.borrow().remembered.clone())
+}
+
+/// Empties the remembered set, once no old allocation can point to a young one.
+pub(crate) fn clear_remembered() {
+    let remembered = WORKLISTS.with(|lists| mem::take(&mut lists.borrow_mut().remembered));
+    for ptr in remembered {
+        // SAFETY: old allocations are only freed by major collections, which empty the set.
+        unsafe { ptr.as_ref().header.set_remembered(false) };
+    }
+}
+
+/// Moves an allocation to the old generation.
+pub(crate) fn promote(ptr: NonNull<GcBox<dyn ErasedValue>>) {
+    // SAFETY: only live allocations are promoted.
+    unsafe { ptr.as_ref().header.set_old() };
+    WORKLISTS.with(|lists| {
+        lists
+            .borrow_mut()
+            .old_boxes
+            .insert(ptr.as_ptr().cast::<u8>() as usize, ptr);
+    });
+}
+
+/// Forgets an old allocation that is about to be freed.
+pub(crate) fn forget_old(ptr: NonNull<GcBox<dyn ErasedValue>>) {
+    WORKLISTS.with(|lists| {
+        lists
+            .borrow_mut()
+            .old_boxes
+            .remove(&(ptr.as_ptr().cast::<u8>() as usize));
+    });
+}
@@ -0,0 +1,121 @@
+//! The configuration of the collector.
+
+/// The thresholds that decide when the collector of the current thread runs.
+///
+/// Allocations start in the young generation, which is collected by minor collections once it
+/// grows past [`young_generation_size`][Self::young_generation_size]. The allocations that
+/// survive a collection are moved to the old generation, which is collected by major
+/// collections once it grows past a threshold that starts at
+/// [`major_threshold`][Self::major_threshold].
+///
+/// Major collections mark the heap incrementally: each allocation performed while a major
+/// collection is in progress traces up to [`mark_step_size`][Self::mark_step_size] allocations,
+/// so the program only pauses for short periods of time.
+///
+/// # Examples
+///
+/// ```
+/// use boa_gc::GcConfig;
+///
+/// let mut config = GcConfig::default();
+/// config.set_young_generation_size(4 * 1024 * 1024);
+/// boa_gc::set_config(config);
+///
+/// assert_eq!(boa_gc::config().young_generation_size(), 4 * 1024 * 1024);
+/// # boa_gc::set_config(GcConfig::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GcConfig {
+    young_generation_size: usize,
+    major_threshold: usize,
+    used_space_ratio: f64,
+    mark_step_size: usize,
+}
+
+impl Default for GcConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            young_generation_size: 256 * 1024,
+            major_threshold: 1024 * 1024,
+            used_space_ratio: 0.7,
+            mark_step_size: 1024,
+        }
+    }
+}
+
+impl GcConfig {
+    /// Gets the number of bytes that can be allocated in the young generation before a minor
+    /// collection runs.
+    #[inline]
+    pub fn young_generation_size(&self) -> usize {
+        self.young_generation_size
+    }
+
+    /// Sets the number of bytes that can be allocated in the young generation before a minor
+    /// collection runs.
+    #[inline]
+    pub fn set_young_generation_size(&mut self, size: usize) {
+        self.young_generation_size = size;
+    }
+
+    /// Gets the initial number of bytes the old generation can hold before a major collection
+    /// starts.
+    #[inline]
+    pub fn major_threshold(&self) -> usize {
+        self.major_threshold
+    }
+
+    /// Sets the initial number of bytes the old generation can hold before a major collection
+    /// starts.
+    ///
+    /// After each major collection, the threshold grows so that the live bytes are at most
+    /// [`used_space_ratio`][Self::used_space_ratio] of it, but it never shrinks below this value.
+    #[inline]
+    pub fn set_major_threshold(&mut self, threshold: usize) {
+        self.major_threshold = threshold;
+    }
+
+    /// Gets the maximum ratio between the live bytes of the old generation and the threshold of
+    /// the next major collection.
+    #[inline]
+    pub fn used_space_ratio(&self) -> f64 {
+        self.used_space_ratio
+    }
+
+    /// Sets the maximum ratio between the live bytes of the old generation and the threshold of
+    /// the next major collection.
+    ///
+    /// Lower ratios make major collections less frequent, at the cost of a bigger heap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ratio is not in the `(0, 1]` range.
+    #[inline]
+    pub fn set_used_space_ratio(&mut self, ratio: f64) {
+        assert!(
+            ratio > 0.0 && ratio <= 1.0,
+            "the used space ratio must be in the (0, 1] range"
+        );
+        self.used_space_ratio = ratio;
+    }
+
+    /// Gets the number of allocations traced by each step of a major collection.
+    #[inline]
+    pub fn mark_step_size(&self) -> usize {
+        self.mark_step_size
+    }
+
+    /// Sets the number of allocations traced by each step of a major collection.
+    ///
+    /// Setting it to `usize::MAX` marks the whole heap in a single step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is zero.
+    #[inline]
+    pub fn set_mark_step_size(&mut self, size: usize) {
+        assert!(size > 0, "the mark step size must not be zero");
+        self.mark_step_size = size;
+    }
+}
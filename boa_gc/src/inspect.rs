@@ -0,0 +1,179 @@
+//! Inspection of the allocations of the heap, used to build heap snapshots.
+
+use crate::{
+    barrier::{self, Phase},
+    force_collect,
+    internals::{ErasedValue, GcBox},
+    Gc, Trace,
+};
+use std::{
+    any::TypeId,
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+    ptr::NonNull,
+};
+
+/// A type-erased handle to a live allocation of the collector of the current thread.
+///
+/// Like a [`Gc`], the handle is a root, so the allocation stays alive while the handle exists.
+/// Handles are compared by the address of their allocation.
+///
+/// # Examples
+///
+/// ```
+/// use boa_gc::{Gc, GcAllocation, GcCell};
+///
+/// let inner = Gc::new(1);
+/// let outer = Gc::new(GcCell::new(vec![inner.clone(), inner.clone()]));
+///
+/// let allocation = GcAllocation::new(&outer);
+/// let edges = allocation.edges();
+/// assert_eq!(edges, [GcAllocation::new(&inner)]);
+/// assert_eq!(*edges[0].downcast::<i32>().unwrap(), 1);
+/// assert!(edges[0].downcast::<u32>().is_none());
+/// ```
+pub struct GcAllocation {
+    ptr: NonNull<GcBox<dyn ErasedValue>>,
+}
+
+impl GcAllocation {
+    /// Creates a handle to the allocation that `gc` points to.
+    pub fn new<T: Trace>(gc: &Gc<T>) -> Self {
+        // SAFETY: the allocation is kept alive by `gc`.
+        unsafe { Self::from_ptr(gc.inner_ptr()) }
+    }
+
+    /// Creates a handle to the live allocation `ptr`, rooting it.
+    unsafe fn from_ptr(ptr: NonNull<GcBox<dyn ErasedValue>>) -> Self {
+        ptr.as_ref().root_inner();
+        Self { ptr }
+    }
+
+    fn inner(&self) -> &GcBox<dyn ErasedValue> {
+        // SAFETY: the handle roots the allocation, so it can't be freed.
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// Returns the address of the allocation, which identifies it while it is alive.
+    #[inline]
+    pub fn address(&self) -> usize {
+        self.ptr.as_ptr().cast::<u8>() as usize
+    }
+
+    /// Returns the number of bytes of the allocation, including the header used by the
+    /// collector.
+    ///
+    /// The memory owned by the value outside of the allocation, like the buffer of a `Vec`, is
+    /// not included.
+    #[inline]
+    pub fn size(&self) -> usize {
+        mem::size_of_val(self.inner())
+    }
+
+    /// Returns the name of the type of the value of the allocation, as given by
+    /// [`std::any::type_name`].
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.inner().value().value_type_name()
+    }
+
+    /// Returns a [`Gc`] pointing to the allocation, if its value is a `T`.
+    pub fn downcast<T: Trace>(&self) -> Option<Gc<T>> {
+        if self.inner().value().value_type_id() != TypeId::of::<T>() {
+            return None;
+        }
+        // SAFETY: the value of the allocation is a `T`, and the allocation is alive.
+        unsafe { Some(Gc::from_box_ptr(self.ptr.cast::<GcBox<T>>())) }
+    }
+
+    /// Returns the allocations that the value of this allocation points to.
+    ///
+    /// See [`edges`] for more details.
+    pub fn edges(&self) -> Vec<Self> {
+        edges(self.inner().value())
+    }
+}
+
+impl Clone for GcAllocation {
+    #[inline]
+    fn clone(&self) -> Self {
+        // SAFETY: the allocation is kept alive by `self`.
+        unsafe { Self::from_ptr(self.ptr) }
+    }
+}
+
+impl Drop for GcAllocation {
+    #[inline]
+    fn drop(&mut self) {
+        self.inner().unroot_inner();
+    }
+}
+
+impl PartialEq for GcAllocation {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.address() == other.address()
+    }
+}
+
+impl Eq for GcAllocation {}
+
+impl Hash for GcAllocation {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+    }
+}
+
+impl fmt::Debug for GcAllocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GcAllocation")
+            .field("address", &(self.address() as *const u8))
+            .field("type_name", &self.type_name())
+            .field("size", &self.size())
+            .finish()
+    }
+}
+
+/// Returns the allocations that `value` points to directly, without duplicates, in the order
+/// they are traced.
+///
+/// Only strong pointers are edges: the values of [`WeakGc`][crate::WeakGc]s and
+/// [`Ephemeron`][crate::Ephemeron]s are not included. The contents of a `GcCell` that is
+/// mutably borrowed are not traced, so they are not included either.
+///
+/// If a major collection is marking the heap, it is finished first.
+///
+/// # Panics
+///
+/// Panics if called while a collection is running, e.g. from a finalizer.
+pub fn edges<T: Trace + ?Sized>(value: &T) -> Vec<GcAllocation> {
+    if barrier::phase() == Phase::Marking {
+        force_collect();
+    }
+    assert_eq!(
+        barrier::phase(),
+        Phase::Idle,
+        "the heap can't be inspected during a collection"
+    );
+
+    // No allocation is marked between collections, so the marks are used to find the
+    // allocations traced by `value`, and cleared afterwards.
+    barrier::set_phase(Phase::Inspecting);
+    // SAFETY: tracing only marks the allocations and queues them in the gray worklist.
+    unsafe { value.trace() };
+    let mut edges = Vec::new();
+    while let Some(ptr) = barrier::pop_gray() {
+        // SAFETY: only live allocations are traced.
+        unsafe {
+            ptr.as_ref().header.unmark();
+            edges.push(GcAllocation::from_ptr(ptr));
+        }
+    }
+    barrier::set_phase(Phase::Idle);
+
+    // The gray worklist is a stack.
+    edges.reverse();
+    edges
+}
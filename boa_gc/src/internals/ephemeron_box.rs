@@ -0,0 +1,136 @@
+use crate::{
+    barrier,
+    internals::{GcBox, GcBoxHeader},
+    Trace,
+};
+use std::{
+    cell::{Cell, UnsafeCell},
+    ptr::NonNull,
+};
+
+/// The contents of a live ephemeron: a weak pointer to the key and the value it keeps alive.
+struct EphemeronData<K: Trace + 'static, V: Trace + 'static> {
+    key: NonNull<GcBox<K>>,
+    value: V,
+}
+
+/// A garbage collected ephemeron, linked into the thread-local list of ephemerons.
+///
+/// The key of an ephemeron is only held weakly, and its value is only reachable through the
+/// ephemeron while the key is reachable from somewhere else. Once the collector finds the key
+/// unreachable, the entry is cleared and the value is dropped.
+pub(crate) struct EphemeronBox<K: Trace + 'static, V: Trace + 'static> {
+    pub(crate) header: GcBoxHeader,
+    next: Cell<Option<NonNull<dyn ErasedEphemeronBox>>>,
+    data: UnsafeCell<Option<EphemeronData<K, V>>>,
+}
+
+impl<K: Trace, V: Trace> EphemeronBox<K, V> {
+    /// Creates a new, unlinked `EphemeronBox` pointing to the `key` allocation.
+    ///
+    /// The value is expected to be unrooted by the caller once it lives inside the box.
+    pub(crate) fn new(key: NonNull<GcBox<K>>, value: V) -> Self {
+        Self {
+            header: GcBoxHeader::new(),
+            next: Cell::new(None),
+            data: UnsafeCell::new(Some(EphemeronData { key, value })),
+        }
+    }
+
+    /// Returns the allocation of the key, if it hasn't been collected.
+    pub(crate) fn key(&self) -> Option<NonNull<GcBox<K>>> {
+        // SAFETY: the data is only mutated by the collector, which never runs while a
+        // reference obtained from this method is alive.
+        unsafe { (*self.data.get()).as_ref().map(|data| data.key) }
+    }
+
+    /// Returns a reference to the value, if the key hasn't been collected.
+    ///
+    /// # Safety
+    ///
+    /// The returned reference must not outlive a garbage collection, since the collector may
+    /// drop the value if the key becomes unreachable.
+    pub(crate) unsafe fn value(&self) -> Option<&V> {
+        (*self.data.get()).as_ref().map(|data| &data.value)
+    }
+
+    /// Increases the root count on this `EphemeronBox`.
+    pub(crate) fn root_inner(&self) {
+        self.header.inc_roots();
+    }
+
+    /// Decreases the root count on this `EphemeronBox`.
+    pub(crate) fn unroot_inner(&self) {
+        self.header.dec_roots();
+    }
+}
+
+/// Type erased operations the collector needs to perform on an [`EphemeronBox`].
+pub(crate) trait ErasedEphemeronBox {
+    /// Returns the header of the ephemeron.
+    fn header(&self) -> &GcBoxHeader;
+
+    /// Returns the link to the next ephemeron of the thread-local list.
+    fn next(&self) -> &Cell<Option<NonNull<dyn ErasedEphemeronBox>>>;
+
+    /// Traces the value of the ephemeron if its key has been marked.
+    ///
+    /// Returns `false` if the key hasn't been marked yet, meaning the ephemeron must be visited
+    /// again if the mark phase makes any further progress.
+    unsafe fn trace_value(&self) -> bool;
+
+    /// Traces the value of the ephemeron regardless of its key.
+    ///
+    /// Minor collections use this to keep the young allocations of live ephemerons alive until
+    /// the next major collection.
+    unsafe fn trace_value_unconditionally(&self);
+
+    /// Drops the value of the ephemeron if its key wasn't marked.
+    unsafe fn clear_dead_key(&self);
+
+    /// Runs the finalizers of the value of the ephemeron.
+    fn finalize_value(&self);
+}
+
+impl<K: Trace, V: Trace> ErasedEphemeronBox for EphemeronBox<K, V> {
+    fn header(&self) -> &GcBoxHeader {
+        &self.header
+    }
+
+    fn next(&self) -> &Cell<Option<NonNull<dyn ErasedEphemeronBox>>> {
+        &self.next
+    }
+
+    unsafe fn trace_value(&self) -> bool {
+        match &*self.data.get() {
+            Some(data) if barrier::is_reachable(&(*data.key.as_ptr()).header) => {
+                data.value.trace();
+                true
+            }
+            Some(_) => false,
+            None => true,
+        }
+    }
+
+    unsafe fn trace_value_unconditionally(&self) {
+        if let Some(data) = &*self.data.get() {
+            data.value.trace();
+        }
+    }
+
+    unsafe fn clear_dead_key(&self) {
+        let data = &mut *self.data.get();
+        if let Some(EphemeronData { key, .. }) = data {
+            if !barrier::is_reachable(&(*key.as_ptr()).header) {
+                *data = None;
+            }
+        }
+    }
+
+    fn finalize_value(&self) {
+        // SAFETY: finalizers run before the sweep phase, so the value is still valid.
+        if let Some(value) = unsafe { self.value() } {
+            value.finalize_glue();
+        }
+    }
+}
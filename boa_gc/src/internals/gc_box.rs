@@ -0,0 +1,155 @@
+use crate::Trace;
+use std::{
+    any::{self, Any, TypeId},
+    cell::Cell,
+    ptr::{self, NonNull},
+};
+
+/// The allocation was reached during the current mark phase.
+const MARKED: u8 = 0b001;
+
+/// The allocation survived a collection, so it belongs to the old generation.
+const OLD: u8 = 0b010;
+
+/// The allocation is old and is in the remembered set.
+const REMEMBERED: u8 = 0b100;
+
+/// The header shared by every allocation managed by the collector.
+pub(crate) struct GcBoxHeader {
+    roots: Cell<usize>,
+    flags: Cell<u8>,
+}
+
+impl GcBoxHeader {
+    /// Creates a new, rooted, unmarked and young header.
+    pub(crate) fn new() -> Self {
+        Self {
+            roots: Cell::new(1),
+            flags: Cell::new(0),
+        }
+    }
+
+    /// Returns the number of roots pointing to this allocation.
+    pub(crate) fn roots(&self) -> usize {
+        self.roots.get()
+    }
+
+    /// Increases the root count of this allocation.
+    pub(crate) fn inc_roots(&self) {
+        // abort if the count overflows to prevent `mem::forget` loops that could otherwise lead to
+        // erroneous drops
+        self.roots.set(
+            self.roots
+                .get()
+                .checked_add(1)
+                .expect("root count overflow"),
+        );
+    }
+
+    /// Decreases the root count of this allocation.
+    pub(crate) fn dec_roots(&self) {
+        self.roots.set(self.roots.get() - 1);
+    }
+
+    /// Returns `true` if the allocation was reached during the current mark phase.
+    pub(crate) fn is_marked(&self) -> bool {
+        self.flags.get() & MARKED != 0
+    }
+
+    /// Marks the allocation as reachable.
+    pub(crate) fn mark(&self) {
+        self.flags.set(self.flags.get() | MARKED);
+    }
+
+    /// Clears the mark of the allocation.
+    pub(crate) fn unmark(&self) {
+        self.flags.set(self.flags.get() & !MARKED);
+    }
+
+    /// Returns `true` if the allocation belongs to the old generation.
+    pub(crate) fn is_old(&self) -> bool {
+        self.flags.get() & OLD != 0
+    }
+
+    /// Moves the allocation to the old generation.
+    pub(crate) fn set_old(&self) {
+        self.flags.set(self.flags.get() | OLD);
+    }
+
+    /// Returns `true` if the allocation is in the remembered set.
+    pub(crate) fn is_remembered(&self) -> bool {
+        self.flags.get() & REMEMBERED != 0
+    }
+
+    /// Sets whether the allocation is in the remembered set.
+    pub(crate) fn set_remembered(&self, remembered: bool) {
+        if remembered {
+            self.flags.set(self.flags.get() | REMEMBERED);
+        } else {
+            self.flags.set(self.flags.get() & !REMEMBERED);
+        }
+    }
+}
+
+/// The value of an allocation whose type was erased, which can still be identified.
+pub(crate) trait ErasedValue: Trace {
+    /// Returns the `TypeId` of the type of the value.
+    fn value_type_id(&self) -> TypeId;
+
+    /// Returns the name of the type of the value.
+    fn value_type_name(&self) -> &'static str;
+}
+
+impl<T: Trace + Any> ErasedValue for T {
+    fn value_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn value_type_name(&self) -> &'static str {
+        any::type_name::<T>()
+    }
+}
+
+/// A garbage collected allocation, linked into the thread-local list of boxes.
+pub(crate) struct GcBox<T: Trace + ?Sized + 'static> {
+    pub(crate) header: GcBoxHeader,
+    pub(crate) next: Option<NonNull<GcBox<dyn ErasedValue>>>,
+    value: T,
+}
+
+impl<T: Trace> GcBox<T> {
+    /// Creates a new, unlinked `GcBox` containing `value`.
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            header: GcBoxHeader::new(),
+            next: None,
+            value,
+        }
+    }
+}
+
+impl<T: Trace + ?Sized> GcBox<T> {
+    /// Returns `true` if the two references refer to the same `GcBox`.
+    pub(crate) fn ptr_eq(this: &Self, other: &Self) -> bool {
+        // Use .header to ignore fat pointer vtables, to work around
+        // https://github.com/rust-lang/rust/issues/46139
+        ptr::eq(&this.header, &other.header)
+    }
+
+    /// Increases the root count on this `GcBox`.
+    /// Roots prevent the `GcBox` from being destroyed by the garbage collector.
+    pub(crate) fn root_inner(&self) {
+        self.header.inc_roots();
+    }
+
+    /// Decreases the root count on this `GcBox`.
+    /// Roots prevent the `GcBox` from being destroyed by the garbage collector.
+    pub(crate) fn unroot_inner(&self) {
+        self.header.dec_roots();
+    }
+
+    /// Returns a reference to the `GcBox`'s value.
+    pub(crate) fn value(&self) -> &T {
+        &self.value
+    }
+}
@@ -1,6 +1,566 @@
 //! Garbage collector for the Boa JavaScript engine.
+//!
+//! This is a single-threaded, generational and incremental mark and sweep garbage collector,
+//! originally based on the design of the [`gc`](https://crates.io/crates/gc) crate, extended
+//! with support for ephemerons and weak references:
+//!
+//! - [`Gc`] is a strong, rooted pointer to a garbage collected value.
+//! - [`Cell`] provides interior mutability for values stored behind a [`Gc`].
+//! - [`Ephemeron`] is a key-value pair where the key is held weakly, and the value is only
+//!   kept alive while the key is reachable from somewhere else.
+//! - [`WeakGc`] is a weak pointer to a garbage collected value.
+//! - [`WeakMap`] is a table of ephemerons indexed by the identity of their keys.
+//!
+//! # Collections
+//!
+//! Every thread has its own heap, split in two generations:
+//!
+//! - New allocations are placed in the young generation. Once it grows past a threshold, a minor
+//!   collection traces the young allocations reachable from roots or from old allocations that
+//!   were mutated since the last collection, and moves the survivors to the old generation.
+//! - Once the old generation grows past a threshold, a major collection marks the whole heap
+//!   incrementally, a small step on each allocation, and then sweeps it. Values that are stored
+//!   in the heap while marking are marked right away, so the program can keep running between
+//!   steps.
+//!
+//! The thresholds are set through [`GcConfig`], and the collections can be observed through
+//! [`GcStats`]. The graph of allocations can be walked with [`GcAllocation`] to find out what
+//! keeps values alive.
 
-pub use gc::{
-    custom_trace, finalizer_safe, force_collect, unsafe_empty_trace, Finalize, Gc, GcCell as Cell,
-    GcCellRef as Ref, GcCellRefMut as RefMut, Trace,
+#![warn(
+    clippy::perf,
+    clippy::single_match_else,
+    clippy::dbg_macro,
+    clippy::doc_markdown,
+    clippy::wildcard_imports,
+    clippy::struct_excessive_bools,
+    clippy::redundant_closure_for_method_calls,
+    clippy::use_self,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style
+)]
+#![allow(clippy::module_name_repetitions, clippy::missing_safety_doc)]
+
+mod barrier;
+mod cell;
+mod config;
+mod inspect;
+mod internals;
+mod pointers;
+mod stats;
+mod trace;
+
+#[cfg(test)]
+mod tests;
+
+// Allows the derives to refer to `::boa_gc` inside this crate.
+#[cfg(test)]
+extern crate self as boa_gc;
+
+pub use boa_macros::{Finalize, Trace};
+
+pub use crate::{
+    cell::{BorrowError, BorrowMutError, GcCell, GcCellRef, GcCellRefMut},
+    config::GcConfig,
+    inspect::{edges, GcAllocation},
+    pointers::{Ephemeron, Gc, WeakGc, WeakMap},
+    stats::GcStats,
+    trace::{Finalize, Trace},
+};
+
+pub use crate::cell::{GcCell as Cell, GcCellRef as Ref, GcCellRefMut as RefMut};
+
+use crate::{
+    barrier::Phase,
+    internals::{EphemeronBox, ErasedEphemeronBox, ErasedValue, GcBox},
+    stats::PauseStart,
 };
+use std::{
+    cell::{Cell as StdCell, RefCell},
+    iter, mem,
+    ptr::NonNull,
+};
+
+/// A list of allocations, linked through their `next` field.
+type BoxList = Option<NonNull<GcBox<dyn ErasedValue>>>;
+
+/// The state of the garbage collector of the current thread.
+struct GcState {
+    config: GcConfig,
+    stats: GcStats,
+    young_bytes: usize,
+    old_bytes: usize,
+    major_threshold: usize,
+    young_start: BoxList,
+    old_start: BoxList,
+    ephemerons_start: Option<NonNull<dyn ErasedEphemeronBox>>,
+}
+
+impl Drop for GcState {
+    fn drop(&mut self) {
+        // The worklists of the collector may be destroyed before its state when the thread
+        // exits, in which case the remaining allocations are leaked.
+        if barrier::is_available() {
+            self.collect_all();
+        }
+        // We have no choice but to leak any remaining nodes that
+        // might be referenced from other thread-local variables.
+    }
+}
+
+// Whether or not the thread is currently in the sweep phase of garbage collection.
+// During this phase, attempts to dereference a `Gc<T>` pointer will trigger a panic.
+thread_local!(static GC_DROPPING: StdCell<bool> = const { StdCell::new(false) });
+
+// The garbage collector's internal state.
+thread_local!(static GC_STATE: RefCell<GcState> = RefCell::new(GcState::new(GcConfig::default())));
+
+/// Sets the `GC_DROPPING` flag for as long as it lives.
+struct DropGuard;
+
+impl DropGuard {
+    fn new() -> Self {
+        GC_DROPPING.with(|dropping| dropping.set(true));
+        Self
+    }
+}
+
+impl Drop for DropGuard {
+    fn drop(&mut self) {
+        GC_DROPPING.with(|dropping| dropping.set(false));
+    }
+}
+
+/// Returns `true` if it is safe for a type to run [`Finalize::finalize`].
+///
+/// This is `false` while the collector is sweeping, because the values being dropped may point
+/// to allocations that were already freed.
+pub fn finalizer_safe() -> bool {
+    GC_DROPPING.with(|dropping| !dropping.get())
+}
+
+/// Iterates over the allocations of a list.
+fn boxes(start: BoxList) -> impl Iterator<Item = NonNull<GcBox<dyn ErasedValue>>> {
+    // SAFETY: the lists only contain live allocations.
+    iter::successors(start, |node| unsafe { node.as_ref().next })
+}
+
+impl GcState {
+    fn new(config: GcConfig) -> Self {
+        Self {
+            config,
+            stats: GcStats::default(),
+            young_bytes: 0,
+            old_bytes: 0,
+            major_threshold: config.major_threshold(),
+            young_start: None,
+            old_start: None,
+            ephemerons_start: None,
+        }
+    }
+
+    /// Iterates over every ephemeron.
+    fn ephemerons(&self) -> impl Iterator<Item = NonNull<dyn ErasedEphemeronBox>> {
+        // SAFETY: the list only contains live ephemerons.
+        iter::successors(self.ephemerons_start, |node| unsafe {
+            node.as_ref().next().get()
+        })
+    }
+
+    /// Returns the threshold of the old generation that starts the next major collection.
+    fn next_major_threshold(&self) -> usize {
+        let threshold = (self.old_bytes as f64 / self.config.used_space_ratio()) as usize;
+        threshold.max(self.config.major_threshold())
+    }
+
+    /// Returns the statistics of the collector, including the current size of the heap.
+    fn stats(&self) -> GcStats {
+        GcStats {
+            heap_bytes: self.young_bytes + self.old_bytes,
+            young_bytes: self.young_bytes,
+            ..self.stats
+        }
+    }
+
+    /// Runs the collection work that is due before allocating.
+    fn maybe_collect(&mut self) {
+        if barrier::phase() == Phase::Marking {
+            self.mark_step();
+            return;
+        }
+        if self.young_bytes > self.config.young_generation_size() {
+            self.collect_young();
+        }
+        if self.old_bytes > self.major_threshold {
+            self.start_major();
+        }
+    }
+
+    /// Runs a minor collection, which only collects the young generation.
+    fn collect_young(&mut self) {
+        let start = PauseStart::now();
+        barrier::set_phase(Phase::Minor);
+        unsafe {
+            self.mark_young();
+            if self.finalize() {
+                // Finalizers may have made some of the unreachable values reachable again.
+                self.unmark();
+                self.mark_young();
+            }
+
+            let _guard = DropGuard::new();
+            for node in self.ephemerons() {
+                let ephemeron = &*node.as_ptr();
+                ephemeron.header().unmark();
+                ephemeron.clear_dead_key();
+            }
+            self.promote_young();
+        }
+        barrier::clear_remembered();
+        barrier::set_phase(Phase::Idle);
+
+        self.stats.minor_collections += 1;
+        self.stats.live_bytes = self.old_bytes;
+        self.stats.record_pause(start);
+    }
+
+    /// Marks every young allocation reachable from a root, from a remembered old allocation or
+    /// from an ephemeron.
+    unsafe fn mark_young(&self) {
+        for node in boxes(self.young_start) {
+            if node.as_ref().header.roots() > 0 {
+                barrier::mark(node);
+            }
+        }
+        for node in barrier::remembered() {
+            node.as_ref().value().trace();
+        }
+        // The keys of ephemerons are only resolved by major collections, so their values are
+        // kept alive until then.
+        for node in self.ephemerons() {
+            node.as_ref().trace_value_unconditionally();
+        }
+        trace_gray(usize::MAX);
+    }
+
+    /// Starts a major collection, marking the allocations pointed to by roots.
+    fn start_major(&mut self) {
+        let start = PauseStart::now();
+        barrier::set_phase(Phase::Marking);
+        unsafe { self.mark_roots() };
+        self.stats.record_pause(start);
+    }
+
+    /// Runs a step of the major collection in progress, finishing it if the whole heap has been
+    /// marked.
+    fn mark_step(&mut self) {
+        let start = PauseStart::now();
+        if unsafe { trace_gray(self.config.mark_step_size()) } {
+            unsafe { self.finish_major() };
+        }
+        self.stats.record_pause(start);
+    }
+
+    /// Runs a whole major collection, or finishes the one in progress.
+    fn collect_all(&mut self) {
+        let start = PauseStart::now();
+        barrier::set_phase(Phase::Marking);
+        unsafe { self.finish_major() };
+        self.stats.record_pause(start);
+    }
+
+    /// Marks the allocations and ephemerons pointed to by roots.
+    unsafe fn mark_roots(&self) {
+        for node in boxes(self.young_start).chain(boxes(self.old_start)) {
+            if node.as_ref().header.roots() > 0 {
+                barrier::mark(node);
+            }
+        }
+        for node in self.ephemerons() {
+            let ephemeron = &*node.as_ptr();
+            if ephemeron.header().roots() > 0 {
+                ephemeron.header().mark();
+            }
+        }
+    }
+
+    /// Marks every allocation reachable from a root.
+    ///
+    /// Ephemerons are resolved until a fixpoint is reached: the value of a reachable ephemeron
+    /// is only traced once its key has been found reachable through some other path.
+    unsafe fn mark_all(&self) {
+        // Roots may have changed while the program ran between the steps of the collection.
+        self.mark_roots();
+        trace_gray(usize::MAX);
+
+        let mut pending = self.ephemerons().collect::<Vec<_>>();
+        loop {
+            let mut progress = false;
+            pending.retain(|node| {
+                let ephemeron = &*node.as_ptr();
+                if ephemeron.header().is_marked() && ephemeron.trace_value() {
+                    progress = true;
+                    false
+                } else {
+                    true
+                }
+            });
+            trace_gray(usize::MAX);
+            if !progress {
+                break;
+            }
+        }
+    }
+
+    /// Finishes the marking of the major collection in progress and sweeps the whole heap.
+    unsafe fn finish_major(&mut self) {
+        self.mark_all();
+        if self.finalize() {
+            // Finalizers may have made some of the unreachable values reachable again, by
+            // storing them in the heap, where the write barrier marks them, or in roots.
+            self.mark_all();
+        }
+        self.sweep();
+        barrier::clear_remembered();
+        barrier::set_phase(Phase::Idle);
+
+        self.stats.major_collections += 1;
+        self.stats.live_bytes = self.old_bytes;
+        self.major_threshold = self.next_major_threshold();
+    }
+
+    /// Runs the finalizers of every unmarked allocation that the collection in progress can
+    /// free, returning `true` if there were any.
+    unsafe fn finalize(&self) -> bool {
+        let mut unmarked = false;
+
+        for node in self.collected_boxes() {
+            let node = &*node.as_ptr();
+            if !node.header.is_marked() {
+                node.value().finalize_glue();
+                unmarked = true;
+            }
+        }
+
+        if barrier::phase() == Phase::Marking {
+            for node in self.ephemerons() {
+                let ephemeron = &*node.as_ptr();
+                if !ephemeron.header().is_marked() {
+                    ephemeron.finalize_value();
+                    unmarked = true;
+                }
+            }
+        }
+
+        unmarked
+    }
+
+    /// Clears the marks of every allocation that the collection in progress can free.
+    unsafe fn unmark(&self) {
+        barrier::clear_gray();
+        for node in self.collected_boxes() {
+            node.as_ref().header.unmark();
+        }
+        for node in self.ephemerons() {
+            node.as_ref().header().unmark();
+        }
+    }
+
+    /// Iterates over the allocations that the collection in progress can free.
+    fn collected_boxes(&self) -> impl Iterator<Item = NonNull<GcBox<dyn ErasedValue>>> {
+        let old = if barrier::phase() == Phase::Marking {
+            self.old_start
+        } else {
+            None
+        };
+        boxes(self.young_start).chain(boxes(old))
+    }
+
+    /// Frees every unmarked allocation and clears the marks of the rest.
+    unsafe fn sweep(&mut self) {
+        let _guard = DropGuard::new();
+
+        // Ephemerons must be swept first, since clearing them needs to check the marks of
+        // their keys before those are freed.
+        let mut previous: Option<NonNull<dyn ErasedEphemeronBox>> = None;
+        let mut head = self.ephemerons_start;
+        while let Some(node) = head {
+            let ephemeron = &*node.as_ptr();
+            head = ephemeron.next().get();
+            if ephemeron.header().is_marked() {
+                ephemeron.header().unmark();
+                ephemeron.clear_dead_key();
+                previous = Some(node);
+            } else {
+                match previous {
+                    Some(previous) => (*previous.as_ptr()).next().set(head),
+                    None => self.ephemerons_start = head,
+                }
+                let ephemeron = Box::from_raw(node.as_ptr());
+                self.old_bytes -= mem::size_of_val::<dyn ErasedEphemeronBox>(&*ephemeron);
+            }
+        }
+
+        let mut incoming: *mut BoxList = &mut self.old_start;
+        while let Some(node) = *incoming {
+            if (*node.as_ptr()).header.is_marked() {
+                (*node.as_ptr()).header.unmark();
+                incoming = &mut (*node.as_ptr()).next;
+            } else {
+                barrier::forget_old(node);
+                let mut node = Box::from_raw(node.as_ptr());
+                self.old_bytes -= mem::size_of_val::<GcBox<_>>(&*node);
+                *incoming = node.next.take();
+            }
+        }
+
+        self.promote_young();
+    }
+
+    /// Frees every unmarked young allocation and moves the rest to the old generation.
+    unsafe fn promote_young(&mut self) {
+        let mut head = self.young_start.take();
+        while let Some(node) = head {
+            head = (*node.as_ptr()).next;
+            let size = mem::size_of_val::<GcBox<_>>(node.as_ref());
+            self.young_bytes -= size;
+            if (*node.as_ptr()).header.is_marked() {
+                (*node.as_ptr()).header.unmark();
+                barrier::promote(node);
+                (*node.as_ptr()).next = self.old_start;
+                self.old_start = Some(node);
+                self.old_bytes += size;
+            } else {
+                drop(Box::from_raw(node.as_ptr()));
+            }
+        }
+    }
+}
+
+/// Traces the contents of up to `budget` marked allocations, returning `true` if there are no
+/// more allocations left to trace.
+unsafe fn trace_gray(budget: usize) -> bool {
+    for _ in 0..budget {
+        match barrier::pop_gray() {
+            Some(node) => node.as_ref().value().trace(),
+            None => return true,
+        }
+    }
+    false
+}
+
+/// Moves `value` to the heap and appends it to the thread-local `GcBox` chain.
+///
+/// A `GcBox` allocated this way starts its life rooted.
+pub(crate) fn allocate_box<T: Trace>(value: GcBox<T>) -> NonNull<GcBox<T>> {
+    GC_STATE.with(|st| {
+        let mut st = st.borrow_mut();
+        st.maybe_collect();
+
+        let mut value = Box::new(value);
+        // The allocations of a major collection in progress have all been marked, so new ones
+        // are marked to avoid tracing them.
+        if barrier::phase() == Phase::Marking {
+            value.header.mark();
+        }
+        value.next = st.young_start.take();
+        let ptr = NonNull::from(Box::leak(value));
+
+        let erased: NonNull<GcBox<dyn ErasedValue>> = ptr;
+        st.young_start = Some(erased);
+        st.young_bytes += mem::size_of::<GcBox<T>>();
+
+        ptr
+    })
+}
+
+/// Moves `value` to the heap and appends it to the thread-local ephemeron chain.
+///
+/// An `EphemeronBox` allocated this way starts its life rooted.
+pub(crate) fn allocate_ephemeron<K: Trace, V: Trace>(
+    value: EphemeronBox<K, V>,
+) -> NonNull<EphemeronBox<K, V>> {
+    GC_STATE.with(|st| {
+        let mut st = st.borrow_mut();
+        st.maybe_collect();
+
+        if barrier::phase() == Phase::Marking {
+            value.header.mark();
+        }
+        let ptr = NonNull::from(Box::leak(Box::new(value)));
+
+        let erased: NonNull<dyn ErasedEphemeronBox> = ptr;
+        // SAFETY: the box was just allocated and isn't shared with anyone yet.
+        unsafe { erased.as_ref().next().set(st.ephemerons_start.take()) };
+        st.ephemerons_start = Some(erased);
+        // Ephemerons are only freed by major collections, so they count as old allocations.
+        st.old_bytes += mem::size_of::<EphemeronBox<K, V>>();
+
+        ptr
+    })
+}
+
+/// Immediately runs a full garbage collection on the current thread.
+///
+/// Collections are otherwise only triggered by allocations, once the allocated memory grows
+/// past a threshold. Hosts can use this function to collect at a known point, e.g. to test
+/// code that observes collections through weak references. If a major collection is in
+/// progress, it is finished.
+///
+/// This will panic if executed while a collection is currently in progress
+///
+/// # Examples
+///
+/// ```
+/// use boa_gc::{force_collect, Gc, WeakGc};
+///
+/// let value = Gc::new(String::from("value"));
+/// let weak = WeakGc::new(&value);
+///
+/// force_collect();
+/// assert!(weak.is_alive());
+///
+/// drop(value);
+/// force_collect();
+/// assert!(!weak.is_alive());
+/// ```
+pub fn force_collect() {
+    GC_STATE.with(|st| st.borrow_mut().collect_all());
+}
+
+/// Returns the statistics of the collector of the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use boa_gc::{force_collect, Gc};
+///
+/// let before = boa_gc::stats();
+/// let value = Gc::new([0u8; 64]);
+/// force_collect();
+///
+/// let after = boa_gc::stats();
+/// assert_eq!(after.major_collections(), before.major_collections() + 1);
+/// assert!(after.live_bytes() >= 64);
+/// # drop(value);
+/// ```
+pub fn stats() -> GcStats {
+    GC_STATE.with(|st| st.borrow().stats())
+}
+
+/// Returns the configuration of the collector of the current thread.
+pub fn config() -> GcConfig {
+    GC_STATE.with(|st| st.borrow().config)
+}
+
+/// Sets the configuration of the collector of the current thread.
+///
+/// The new thresholds are used from the next allocation onwards.
+pub fn set_config(config: GcConfig) {
+    GC_STATE.with(|st| {
+        let mut st = st.borrow_mut();
+        st.config = config;
+        st.major_threshold = st.next_major_threshold();
+    });
+}
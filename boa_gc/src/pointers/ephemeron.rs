@@ -0,0 +1,171 @@
+use crate::{
+    allocate_ephemeron,
+    barrier::{self, Phase},
+    finalizer_safe,
+    internals::EphemeronBox,
+    pointers::{clear_root_bit, is_rooted, set_root_bit},
+    Finalize, Gc, Trace,
+};
+use std::{cell::Cell, fmt, marker::PhantomData, mem, ptr::NonNull, rc::Rc};
+
+/// A key-value pair where the key is held weakly.
+///
+/// The value of an ephemeron is kept alive by the ephemeron only while its key is reachable
+/// through a path that doesn't go through the ephemeron itself. When the collector finds the
+/// key unreachable, both the key and the value are removed from the ephemeron, which is then
+/// left empty.
+pub struct Ephemeron<K: Trace + 'static, V: Trace + 'static> {
+    ptr_root: Cell<NonNull<EphemeronBox<K, V>>>,
+    marker: PhantomData<Rc<(Box<K>, V)>>,
+}
+
+impl<K: Trace, V: Trace> Ephemeron<K, V> {
+    /// Creates a new `Ephemeron` associating `value` with the allocation pointed to by `key`.
+    ///
+    /// # Collection
+    ///
+    /// This method could trigger a garbage collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_gc::{force_collect, Ephemeron, Gc};
+    ///
+    /// let key = Gc::new(1);
+    /// let ephemeron = Ephemeron::new(&key, String::from("one"));
+    /// assert_eq!(ephemeron.value(), Some(String::from("one")));
+    ///
+    /// drop(key);
+    /// force_collect();
+    /// assert_eq!(ephemeron.value(), None);
+    /// ```
+    pub fn new(key: &Gc<K>, value: V) -> Self {
+        assert!(mem::align_of::<EphemeronBox<K, V>>() > 1);
+
+        unsafe {
+            let ptr = allocate_ephemeron(EphemeronBox::new(key.inner_ptr(), value));
+
+            // The value now lives in the heap, so it doesn't need to be rooted anymore.
+            if let Some(value) = ptr.as_ref().value() {
+                value.unroot();
+            }
+            let ephemeron = Self {
+                ptr_root: Cell::new(ptr),
+                marker: PhantomData,
+            };
+            ephemeron.set_root();
+            ephemeron
+        }
+    }
+
+    /// Returns the key of the ephemeron, or `None` if it has been collected.
+    pub fn key(&self) -> Option<Gc<K>> {
+        self.inner()
+            .key()
+            // SAFETY: the key is cleared by the collector as soon as it becomes unreachable,
+            // so a pointer that is still stored in the ephemeron is always valid.
+            .map(|key| unsafe { Gc::from_box_ptr(key) })
+    }
+
+    /// Returns a clone of the value of the ephemeron, or `None` if the key has been collected.
+    pub fn value(&self) -> Option<V>
+    where
+        V: Clone,
+    {
+        // SAFETY: the reference is dropped before returning, so it cannot outlive a collection.
+        unsafe { self.inner().value().cloned() }
+    }
+
+    /// Returns `true` if the key of the ephemeron is still alive.
+    pub fn has_value(&self) -> bool {
+        self.inner().key().is_some()
+    }
+
+    /// Returns `true` if the key of the ephemeron is the allocation pointed to by `key`.
+    pub fn key_is(&self, key: &Gc<K>) -> bool {
+        self.inner().key().map_or(false, |ptr| {
+            ptr.cast::<u8>() == key.inner_ptr().cast::<u8>()
+        })
+    }
+
+    fn rooted(&self) -> bool {
+        is_rooted(self.ptr_root.get())
+    }
+
+    unsafe fn set_root(&self) {
+        self.ptr_root.set(set_root_bit(self.ptr_root.get()));
+    }
+
+    unsafe fn clear_root(&self) {
+        self.ptr_root.set(clear_root_bit(self.ptr_root.get()));
+    }
+
+    #[inline]
+    fn inner(&self) -> &EphemeronBox<K, V> {
+        assert!(finalizer_safe());
+        unsafe { &*clear_root_bit(self.ptr_root.get()).as_ptr() }
+    }
+}
+
+impl<K: Trace, V: Trace> Finalize for Ephemeron<K, V> {}
+
+unsafe impl<K: Trace, V: Trace> Trace for Ephemeron<K, V> {
+    #[inline]
+    unsafe fn trace(&self) {
+        // Ephemerons are weak, so they aren't edges of the values that point to them.
+        if barrier::phase() == Phase::Inspecting {
+            return;
+        }
+        // The value is traced by the collector once it knows whether the key is reachable.
+        self.inner().header.mark();
+    }
+
+    #[inline]
+    unsafe fn root(&self) {
+        assert!(!self.rooted(), "Can't double-root an Ephemeron<K, V>");
+        self.inner().root_inner();
+        self.set_root();
+    }
+
+    #[inline]
+    unsafe fn unroot(&self) {
+        assert!(self.rooted(), "Can't double-unroot an Ephemeron<K, V>");
+        self.inner().unroot_inner();
+        self.clear_root();
+    }
+
+    #[inline]
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+    }
+}
+
+impl<K: Trace, V: Trace> Clone for Ephemeron<K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.inner().root_inner();
+        let ephemeron = Self {
+            ptr_root: Cell::new(self.ptr_root.get()),
+            marker: PhantomData,
+        };
+        unsafe { ephemeron.set_root() };
+        ephemeron
+    }
+}
+
+impl<K: Trace, V: Trace> Drop for Ephemeron<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.rooted() {
+            self.inner().unroot_inner();
+        }
+    }
+}
+
+impl<K: Trace, V: Trace> fmt::Debug for Ephemeron<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ephemeron")
+            .field("has_value", &self.has_value())
+            .finish()
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    allocate_box, barrier, finalizer_safe,
+    internals::GcBox,
+    pointers::{clear_root_bit, is_rooted, set_root_bit},
+    Finalize, Trace,
+};
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    ptr::NonNull,
+    rc::Rc,
+};
+
+/// A garbage-collected pointer type over an immutable value.
+///
+/// See the [module level documentation](./index.html) for more details.
+///
+/// A `Gc` has the same layout as a pointer to its allocation, whose lowest bit stores whether the
+/// `Gc` is a root.
+#[repr(transparent)]
+pub struct Gc<T: Trace + 'static> {
+    ptr_root: Cell<NonNull<GcBox<T>>>,
+    marker: PhantomData<Rc<T>>,
+}
+
+impl<T: Trace> Gc<T> {
+    /// Constructs a new `Gc<T>` with the given value.
+    ///
+    /// # Collection
+    ///
+    /// This method could trigger a garbage collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_gc::Gc;
+    ///
+    /// let five = Gc::new(5);
+    /// assert_eq!(*five, 5);
+    /// ```
+    pub fn new(value: T) -> Self {
+        assert!(mem::align_of::<GcBox<T>>() > 1);
+
+        unsafe {
+            // Allocate the memory for the object
+            let ptr = allocate_box(GcBox::new(value));
+
+            // When we create a Gc<T>, all pointers which have been moved to the
+            // heap no longer need to be rooted, so we unroot them.
+            ptr.as_ref().value().unroot();
+            let gc = Self {
+                ptr_root: Cell::new(ptr),
+                marker: PhantomData,
+            };
+            gc.set_root();
+            gc
+        }
+    }
+
+    /// Returns `true` if the two `Gc`s point to the same allocation.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        GcBox::ptr_eq(this.inner(), other.inner())
+    }
+
+    /// Creates a new rooted `Gc` from a pointer to a live allocation.
+    pub(crate) unsafe fn from_box_ptr(ptr: NonNull<GcBox<T>>) -> Self {
+        ptr.as_ref().root_inner();
+        let gc = Self {
+            ptr_root: Cell::new(ptr),
+            marker: PhantomData,
+        };
+        gc.set_root();
+        gc
+    }
+
+    /// Returns the pointer to the allocation of this `Gc`.
+    pub(crate) fn inner_ptr(&self) -> NonNull<GcBox<T>> {
+        assert!(finalizer_safe());
+        unsafe { clear_root_bit(self.ptr_root.get()) }
+    }
+
+    fn rooted(&self) -> bool {
+        is_rooted(self.ptr_root.get())
+    }
+
+    unsafe fn set_root(&self) {
+        self.ptr_root.set(set_root_bit(self.ptr_root.get()));
+    }
+
+    unsafe fn clear_root(&self) {
+        self.ptr_root.set(clear_root_bit(self.ptr_root.get()));
+    }
+
+    #[inline]
+    fn inner(&self) -> &GcBox<T> {
+        // If we are currently in the dropping phase of garbage collection,
+        // it would be undefined behavior to dereference this pointer.
+        // By opting into `Trace` you're agreeing to not dereference this pointer
+        // within your drop method, meaning that it should be safe.
+        //
+        // This assert exists just in case.
+        unsafe { &*self.inner_ptr().as_ptr() }
+    }
+}
+
+impl<T: Trace> Finalize for Gc<T> {}
+
+unsafe impl<T: Trace> Trace for Gc<T> {
+    #[inline]
+    unsafe fn trace(&self) {
+        barrier::mark(self.inner_ptr());
+    }
+
+    #[inline]
+    unsafe fn root(&self) {
+        assert!(!self.rooted(), "Can't double-root a Gc<T>");
+        // Try to get inner before modifying our state. Inner may be
+        // inaccessible due to this method being invoked during the sweeping
+        // phase, and we don't want to modify our state before panicking.
+        self.inner().root_inner();
+        self.set_root();
+    }
+
+    #[inline]
+    unsafe fn unroot(&self) {
+        assert!(self.rooted(), "Can't double-unroot a Gc<T>");
+        // Try to get inner before modifying our state. Inner may be
+        // inaccessible due to this method being invoked during the sweeping
+        // phase, and we don't want to modify our state before panicking.
+        self.inner().unroot_inner();
+        self.clear_root();
+        // The pointer is now stored in the heap.
+        barrier::on_store(self.inner_ptr());
+    }
+
+    #[inline]
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+    }
+}
+
+impl<T: Trace> Clone for Gc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe { Self::from_box_ptr(self.inner_ptr()) }
+    }
+}
+
+impl<T: Trace> Deref for Gc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.inner().value()
+    }
+}
+
+impl<T: Trace> Drop for Gc<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // If this pointer was a root, we should unroot it.
+        if self.rooted() {
+            self.inner().unroot_inner();
+        }
+    }
+}
+
+impl<T: Trace + Default> Default for Gc<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T: Trace + PartialEq> PartialEq for Gc<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Trace + Eq> Eq for Gc<T> {}
+
+impl<T: Trace + PartialOrd> PartialOrd for Gc<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Trace + Ord> Ord for Gc<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Trace + Hash> Hash for Gc<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T: Trace + Display> Display for Gc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<T: Trace + Debug> Debug for Gc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Trace> fmt::Pointer for Gc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner(), f)
+    }
+}
+
+impl<T: Trace> From<T> for Gc<T> {
+    fn from(t: T) -> Self {
+        Self::new(t)
+    }
+}
+
+impl<T: Trace> std::borrow::Borrow<T> for Gc<T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: Trace> AsRef<T> for Gc<T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
@@ -0,0 +1,32 @@
+//! Pointers represented as `Gc`, `Ephemeron` and `WeakGc`, plus the `WeakMap` table.
+
+mod ephemeron;
+mod gc;
+mod weak;
+mod weak_map;
+
+pub use self::{ephemeron::Ephemeron, gc::Gc, weak::WeakGc, weak_map::WeakMap};
+
+use std::ptr::NonNull;
+
+// Pointers to allocations managed by the collector store whether they are a root in the lowest
+// bit of the address, which is always zero because of the alignment of the allocations.
+
+/// Returns `true` if the root bit of `ptr` is set.
+pub(crate) fn is_rooted<T: ?Sized>(ptr: NonNull<T>) -> bool {
+    ptr.as_ptr().cast::<u8>() as usize & 1 != 0
+}
+
+/// Returns `ptr` with its root bit set.
+pub(crate) unsafe fn set_root_bit<T: ?Sized>(ptr: NonNull<T>) -> NonNull<T> {
+    let mut ptr = ptr.as_ptr();
+    *(&mut ptr as *mut *mut T).cast::<usize>() |= 1;
+    NonNull::new_unchecked(ptr)
+}
+
+/// Returns `ptr` with its root bit cleared.
+pub(crate) unsafe fn clear_root_bit<T: ?Sized>(ptr: NonNull<T>) -> NonNull<T> {
+    let mut ptr = ptr.as_ptr();
+    *(&mut ptr as *mut *mut T).cast::<usize>() &= !1;
+    NonNull::new_unchecked(ptr)
+}
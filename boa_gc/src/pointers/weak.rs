@@ -0,0 +1,76 @@
+use crate::{Ephemeron, Finalize, Gc, Trace};
+use std::fmt;
+
+/// A weak reference to a [`Gc`].
+///
+/// A `WeakGc` doesn't keep the value it points to alive. Once the value has been collected,
+/// [`WeakGc::upgrade`] returns `None`.
+pub struct WeakGc<T: Trace + 'static> {
+    inner: Ephemeron<T, ()>,
+}
+
+impl<T: Trace> WeakGc<T> {
+    /// Creates a new weak pointer to the value pointed to by `value`.
+    ///
+    /// # Collection
+    ///
+    /// This method could trigger a garbage collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_gc::{force_collect, Gc, WeakGc};
+    ///
+    /// let value = Gc::new(5);
+    /// let weak = WeakGc::new(&value);
+    /// assert_eq!(weak.upgrade().as_deref(), Some(&5));
+    ///
+    /// drop(value);
+    /// force_collect();
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn new(value: &Gc<T>) -> Self {
+        Self {
+            inner: Ephemeron::new(value, ()),
+        }
+    }
+
+    /// Returns a strong pointer to the value, or `None` if it has been collected.
+    pub fn upgrade(&self) -> Option<Gc<T>> {
+        self.inner.key()
+    }
+
+    /// Returns `true` if the value hasn't been collected.
+    pub fn is_alive(&self) -> bool {
+        self.inner.has_value()
+    }
+
+    /// Returns `true` if this weak pointer points to the allocation of `value`.
+    pub fn ptr_eq(&self, value: &Gc<T>) -> bool {
+        self.inner.key_is(value)
+    }
+}
+
+impl<T: Trace> Finalize for WeakGc<T> {}
+
+unsafe impl<T: Trace> Trace for WeakGc<T> {
+    crate::custom_trace!(this, {
+        mark(&this.inner);
+    });
+}
+
+impl<T: Trace> Clone for WeakGc<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Trace> fmt::Debug for WeakGc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakGc")
+            .field("is_alive", &self.is_alive())
+            .finish()
+    }
+}
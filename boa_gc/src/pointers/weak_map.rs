@@ -0,0 +1,94 @@
+use crate::{Ephemeron, Finalize, Gc, Trace};
+use std::{collections::HashMap, fmt};
+
+/// The minimum number of entries a [`WeakMap`] can hold before it starts pruning dead entries.
+const MIN_PRUNE_THRESHOLD: usize = 16;
+
+/// A map from garbage collected keys to values, where the keys are held weakly.
+///
+/// Entries are indexed by the identity of the allocation of their keys, and each entry is an
+/// [`Ephemeron`], so a value is only kept alive by the map while its key is reachable from
+/// somewhere else. Entries whose key has been collected are removed lazily.
+pub struct WeakMap<K: Trace + 'static, V: Trace + 'static> {
+    entries: HashMap<usize, Ephemeron<K, V>>,
+    prune_threshold: usize,
+}
+
+impl<K: Trace, V: Trace> WeakMap<K, V> {
+    /// Creates a new, empty `WeakMap`.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            prune_threshold: MIN_PRUNE_THRESHOLD,
+        }
+    }
+
+    /// Returns the key used to index the entry of `key`.
+    fn address(key: &Gc<K>) -> usize {
+        key.inner_ptr().cast::<u8>().as_ptr() as usize
+    }
+
+    /// Returns the entry of `key`, if it is in the map.
+    fn entry(&self, key: &Gc<K>) -> Option<&Ephemeron<K, V>> {
+        self.entries
+            .get(&Self::address(key))
+            // An entry whose key was collected could share its address with a newer allocation.
+            .filter(|entry| entry.key_is(key))
+    }
+
+    /// Returns a clone of the value associated with `key`.
+    pub fn get(&self, key: &Gc<K>) -> Option<V>
+    where
+        V: Clone,
+    {
+        self.entry(key).and_then(Ephemeron::value)
+    }
+
+    /// Returns `true` if the map contains an entry for `key`.
+    pub fn contains_key(&self, key: &Gc<K>) -> bool {
+        self.entry(key).is_some()
+    }
+
+    /// Associates `value` with `key`, replacing any previous value.
+    ///
+    /// # Collection
+    ///
+    /// This method could trigger a garbage collection.
+    pub fn insert(&mut self, key: &Gc<K>, value: V) {
+        if self.entries.len() >= self.prune_threshold {
+            self.entries.retain(|_, entry| entry.has_value());
+            self.prune_threshold = (self.entries.len() * 2).max(MIN_PRUNE_THRESHOLD);
+        }
+        self.entries
+            .insert(Self::address(key), Ephemeron::new(key, value));
+    }
+
+    /// Removes the entry of `key`, returning `true` if it was in the map.
+    pub fn remove(&mut self, key: &Gc<K>) -> bool {
+        self.entries
+            .remove(&Self::address(key))
+            .map_or(false, |entry| entry.key_is(key))
+    }
+}
+
+impl<K: Trace, V: Trace> Default for WeakMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Trace, V: Trace> Finalize for WeakMap<K, V> {}
+
+unsafe impl<K: Trace, V: Trace> Trace for WeakMap<K, V> {
+    crate::custom_trace!(this, {
+        for entry in this.entries.values() {
+            mark(entry);
+        }
+    });
+}
+
+impl<K: Trace, V: Trace> fmt::Debug for WeakMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakMap").finish_non_exhaustive()
+    }
+}
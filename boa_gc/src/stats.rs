@@ -0,0 +1,97 @@
+//! Statistics about the collections of the current thread.
+
+use std::time::Duration;
+
+/// Statistics about the collector of the current thread, returned by [`stats`][crate::stats].
+///
+/// Pauses are the periods of time the program is stopped by the collector: a whole minor
+/// collection, or a single step of a major collection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GcStats {
+    pub(crate) minor_collections: u64,
+    pub(crate) major_collections: u64,
+    pub(crate) pauses: u64,
+    pub(crate) total_pause: Duration,
+    pub(crate) max_pause: Duration,
+    pub(crate) live_bytes: usize,
+    pub(crate) heap_bytes: usize,
+    pub(crate) young_bytes: usize,
+}
+
+impl GcStats {
+    /// Gets the number of minor collections, which only collect the young generation.
+    #[inline]
+    pub fn minor_collections(&self) -> u64 {
+        self.minor_collections
+    }
+
+    /// Gets the number of finished major collections, which collect the whole heap.
+    #[inline]
+    pub fn major_collections(&self) -> u64 {
+        self.major_collections
+    }
+
+    /// Gets the number of times the collector paused the program.
+    #[inline]
+    pub fn pauses(&self) -> u64 {
+        self.pauses
+    }
+
+    /// Gets the total time the program was paused by the collector.
+    ///
+    /// Pause times are not measured on `wasm32` targets without WASI, so this is always zero
+    /// there.
+    #[inline]
+    pub fn total_pause(&self) -> Duration {
+        self.total_pause
+    }
+
+    /// Gets the longest time the program was paused by the collector.
+    #[inline]
+    pub fn max_pause(&self) -> Duration {
+        self.max_pause
+    }
+
+    /// Gets the number of bytes that survived the last collection.
+    #[inline]
+    pub fn live_bytes(&self) -> usize {
+        self.live_bytes
+    }
+
+    /// Gets the number of bytes currently allocated by the collector.
+    #[inline]
+    pub fn heap_bytes(&self) -> usize {
+        self.heap_bytes
+    }
+
+    /// Gets the number of bytes currently allocated in the young generation.
+    #[inline]
+    pub fn young_bytes(&self) -> usize {
+        self.young_bytes
+    }
+
+    /// Records a pause of the program that started at `start`.
+    pub(crate) fn record_pause(&mut self, start: Option<PauseStart>) {
+        let pause = start.map(|start| start.0.elapsed()).unwrap_or_default();
+        self.pauses += 1;
+        self.total_pause += pause;
+        self.max_pause = self.max_pause.max(pause);
+    }
+}
+
+/// The instant a pause of the program started at.
+pub(crate) struct PauseStart(std::time::Instant);
+
+impl PauseStart {
+    /// Returns the current instant, if time can be measured on the target.
+    #[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+    pub(crate) fn now() -> Option<Self> {
+        Some(Self(std::time::Instant::now()))
+    }
+
+    /// Returns the current instant, if time can be measured on the target.
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    pub(crate) fn now() -> Option<Self> {
+        None
+    }
+}
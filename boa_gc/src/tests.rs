@@ -0,0 +1,340 @@
+use crate::{
+    force_collect, trace_gray, unsafe_empty_trace, Ephemeron, Finalize, Gc, GcAllocation, GcCell,
+    GcConfig, Trace, WeakGc, WeakMap, GC_STATE,
+};
+use std::{cell::Cell, rc::Rc};
+
+/// Runs a minor collection.
+fn collect_young() {
+    GC_STATE.with(|st| st.borrow_mut().collect_young());
+}
+
+/// Starts a major collection and traces the contents of `budget` allocations, leaving the
+/// collection in progress.
+fn start_major(budget: usize) {
+    GC_STATE.with(|st| {
+        st.borrow_mut().start_major();
+        // SAFETY: the collection is in progress, so the gray allocations are live.
+        unsafe { trace_gray(budget) };
+    });
+}
+
+/// A value that counts how many times it has been dropped.
+struct DropCounter {
+    drops: Rc<Cell<usize>>,
+}
+
+impl Finalize for DropCounter {}
+
+unsafe impl Trace for DropCounter {
+    unsafe_empty_trace!();
+}
+
+impl DropCounter {
+    fn new(drops: &Rc<Cell<usize>>) -> Self {
+        Self {
+            drops: drops.clone(),
+        }
+    }
+}
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+/// A node of a possibly cyclic graph.
+#[derive(Trace, Finalize)]
+struct Node {
+    next: GcCell<Option<Gc<Self>>>,
+    counter: DropCounter,
+}
+
+#[test]
+fn gc_collects_unreachable_values() {
+    let drops = Rc::new(Cell::new(0));
+    let value = Gc::new(DropCounter::new(&drops));
+    force_collect();
+    assert_eq!(drops.get(), 0);
+
+    drop(value);
+    force_collect();
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn gc_collects_cycles() {
+    let drops = Rc::new(Cell::new(0));
+    let a = Gc::new(Node {
+        next: GcCell::new(None),
+        counter: DropCounter::new(&drops),
+    });
+    let b = Gc::new(Node {
+        next: GcCell::new(Some(a.clone())),
+        counter: DropCounter::new(&drops),
+    });
+    *a.next.borrow_mut() = Some(b.clone());
+
+    drop(b);
+    force_collect();
+    assert_eq!(drops.get(), 0);
+
+    drop(a);
+    force_collect();
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn weak_gc_upgrade() {
+    let value = Gc::new(String::from("value"));
+    let weak = WeakGc::new(&value);
+
+    force_collect();
+    assert!(weak.is_alive());
+    assert!(weak.ptr_eq(&value));
+    assert_eq!(weak.upgrade().as_deref().map(String::as_str), Some("value"));
+
+    drop(value);
+    force_collect();
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn ephemeron_keeps_value_alive_while_key_is_alive() {
+    let drops = Rc::new(Cell::new(0));
+    let key = Gc::new(1);
+    let ephemeron = Ephemeron::new(&key, Gc::new(DropCounter::new(&drops)));
+
+    force_collect();
+    assert!(ephemeron.has_value());
+    assert_eq!(drops.get(), 0);
+
+    drop(key);
+    force_collect();
+    assert!(!ephemeron.has_value());
+    assert!(ephemeron.key().is_none());
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn ephemeron_value_does_not_keep_key_alive() {
+    let drops = Rc::new(Cell::new(0));
+    let key = Gc::new(Node {
+        next: GcCell::new(None),
+        counter: DropCounter::new(&drops),
+    });
+    // The value points back to the key, which must not prevent its collection.
+    let ephemeron = Ephemeron::new(&key, key.clone());
+
+    drop(key);
+    force_collect();
+    assert!(!ephemeron.has_value());
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn ephemeron_chains_are_resolved() {
+    let drops = Rc::new(Cell::new(0));
+    let first = Gc::new(0);
+
+    // The value of each ephemeron is the key of the next one, so the whole chain stays alive
+    // only through the first key.
+    let second = Gc::new(1);
+    let third = Gc::new(DropCounter::new(&drops));
+    let a = Ephemeron::new(&second, third.clone());
+    let b = Ephemeron::new(&first, second.clone());
+    drop((second, third));
+
+    force_collect();
+    assert!(a.has_value());
+    assert!(b.has_value());
+    assert_eq!(drops.get(), 0);
+
+    drop(first);
+    force_collect();
+    assert!(!a.has_value());
+    assert!(!b.has_value());
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn weak_map_entries() {
+    let mut map = WeakMap::new();
+    let a = Gc::new(1);
+    let b = Gc::new(2);
+
+    map.insert(&a, String::from("a"));
+    assert_eq!(map.get(&a).as_deref(), Some("a"));
+    assert!(!map.contains_key(&b));
+
+    map.insert(&a, String::from("A"));
+    assert_eq!(map.get(&a).as_deref(), Some("A"));
+
+    assert!(!map.remove(&b));
+    assert!(map.remove(&a));
+    assert!(!map.contains_key(&a));
+}
+
+#[test]
+fn weak_map_does_not_keep_keys_alive() {
+    let drops = Rc::new(Cell::new(0));
+    let map = Gc::new(GcCell::new(WeakMap::new()));
+    let key = Gc::new(DropCounter::new(&drops));
+    let value = Gc::new(DropCounter::new(&drops));
+    map.borrow_mut().insert(&key, value);
+
+    force_collect();
+    assert_eq!(drops.get(), 0);
+    assert!(map.borrow().contains_key(&key));
+
+    drop(key);
+    force_collect();
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn minor_collection_frees_young_garbage() {
+    let drops = Rc::new(Cell::new(0));
+    let kept = Gc::new(DropCounter::new(&drops));
+    drop(Gc::new(DropCounter::new(&drops)));
+
+    let before = crate::stats();
+    collect_young();
+    assert_eq!(drops.get(), 1);
+
+    let after = crate::stats();
+    assert_eq!(after.minor_collections(), before.minor_collections() + 1);
+    assert_eq!(after.major_collections(), before.major_collections());
+    assert_eq!(after.young_bytes(), 0);
+
+    drop(kept);
+    force_collect();
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn minor_collection_keeps_young_values_stored_in_old_ones() {
+    let drops = Rc::new(Cell::new(0));
+    let old = Gc::new(Node {
+        next: GcCell::new(None),
+        counter: DropCounter::new(&drops),
+    });
+    force_collect();
+
+    let young = Gc::new(Node {
+        next: GcCell::new(None),
+        counter: DropCounter::new(&drops),
+    });
+    *old.next.borrow_mut() = Some(young.clone());
+    drop(young);
+
+    collect_young();
+    assert_eq!(drops.get(), 0);
+
+    *old.next.borrow_mut() = None;
+    force_collect();
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn incremental_marking_keeps_values_moved_to_traced_ones() {
+    let drops = Rc::new(Cell::new(0));
+    let node = || {
+        Gc::new(Node {
+            next: GcCell::new(None),
+            counter: DropCounter::new(&drops),
+        })
+    };
+    let a = node();
+    let c = node();
+    let b = node();
+    *b.next.borrow_mut() = Some(c);
+    let root = node();
+    *root.next.borrow_mut() = Some(b);
+
+    // Roots are traced from the oldest one, so only `a` is traced before the program moves the
+    // only pointer to `c` from `b` to `a`. Only the write barrier can find out that `c` is still
+    // reachable.
+    start_major(1);
+    let c = root
+        .next
+        .borrow()
+        .as_ref()
+        .unwrap()
+        .next
+        .borrow_mut()
+        .take();
+    *a.next.borrow_mut() = c;
+    force_collect();
+    assert_eq!(drops.get(), 0);
+    assert!(a.next.borrow().is_some());
+
+    drop((a, root));
+    force_collect();
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn allocations_trigger_collections() {
+    let drops = Rc::new(Cell::new(0));
+    let mut config = GcConfig::default();
+    config.set_young_generation_size(0);
+    config.set_major_threshold(0);
+    config.set_mark_step_size(1);
+    crate::set_config(config);
+
+    let before = crate::stats();
+    let list = Gc::new(Node {
+        next: GcCell::new(None),
+        counter: DropCounter::new(&drops),
+    });
+    for _ in 0..100 {
+        let node = Gc::new(Node {
+            next: GcCell::new(list.next.borrow_mut().take()),
+            counter: DropCounter::new(&drops),
+        });
+        *list.next.borrow_mut() = Some(node);
+        drop(Gc::new(DropCounter::new(&drops)));
+    }
+
+    let after = crate::stats();
+    assert!(after.minor_collections() > before.minor_collections());
+    assert!(after.major_collections() > before.major_collections());
+    assert!(after.pauses() > before.pauses());
+    assert!(after.max_pause() <= after.total_pause());
+
+    // Only the garbage counters are dropped, the list is still reachable.
+    force_collect();
+    assert_eq!(drops.get(), 100);
+
+    crate::set_config(GcConfig::default());
+    drop(list);
+    force_collect();
+    assert_eq!(drops.get(), 201);
+}
+
+#[test]
+fn edges_only_include_strong_pointers() {
+    let drops = Rc::new(Cell::new(0));
+    let target = Gc::new(DropCounter::new(&drops));
+    let weak = Gc::new(WeakGc::new(&target));
+    let strong = Gc::new(GcCell::new(vec![target.clone(), target.clone()]));
+
+    assert!(GcAllocation::new(&weak).edges().is_empty());
+    let edges = GcAllocation::new(&strong).edges();
+    assert_eq!(edges, [GcAllocation::new(&target)]);
+    assert!(edges[0].type_name().ends_with("DropCounter"));
+    assert!(edges[0].downcast::<DropCounter>().is_some());
+    drop(edges);
+
+    // Inspecting the heap finishes the major collection in progress, and leaves no marks
+    // behind for the next one.
+    start_major(1);
+    assert_eq!(GcAllocation::new(&strong).edges().len(), 1);
+    drop((target, strong));
+    force_collect();
+    assert_eq!(drops.get(), 1);
+    assert!(!weak.is_alive());
+}
@@ -81,7 +81,10 @@ mod tests;
 use fixed_string::FixedString;
 pub use sym::*;
 
-use std::fmt::{Debug, Display};
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Display},
+};
 
 use interned_str::InternedStr;
 use rustc_hash::FxHashMap;
@@ -105,6 +108,11 @@ pub struct Interner {
     spans: Vec<InternedStr>,
     head: FixedString,
     full: Vec<FixedString>,
+    // Strings with unpaired surrogates cannot be represented as `str`, so they
+    // are stored separately, alongside a lossy `str` version used by `resolve`.
+    utf16_symbols: FxHashMap<Box<[u16]>, Sym>,
+    utf16_strings: FxHashMap<Sym, Box<[u16]>>,
+    lossy: Vec<Box<str>>,
 }
 
 impl Interner {
@@ -122,6 +130,9 @@ impl Interner {
             spans: Vec::with_capacity(capacity),
             head: FixedString::new(capacity),
             full: Vec::new(),
+            utf16_symbols: FxHashMap::default(),
+            utf16_strings: FxHashMap::default(),
+            lossy: Vec::new(),
         }
     }
 
@@ -237,6 +248,41 @@ impl Interner {
         })
     }
 
+    /// Interns the given UTF-16 string.
+    ///
+    /// Well-formed strings are interned as if they were passed to
+    /// [`Interner::get_or_intern`]. Strings containing unpaired surrogates
+    /// get their own symbol, which [`Interner::resolve`] maps to a lossy
+    /// representation and [`Interner::resolve_utf16`] maps to the original
+    /// code units.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    pub fn get_or_intern_utf16(&mut self, string: &[u16]) -> Sym {
+        if let Ok(string) = String::from_utf16(string) {
+            return self.get_or_intern(string);
+        }
+        if let Some(sym) = self.utf16_symbols.get(string) {
+            return *sym;
+        }
+
+        let lossy = String::from_utf16_lossy(string).into_boxed_str();
+        let next = Sym::new(self.len() + 1).expect("cannot get interner symbol: integer overflow");
+
+        // SAFETY: The heap allocation of a `Box<str>` is never moved, and the
+        // box is kept alive inside `lossy` for the whole life of the `Interner`.
+        // The lossy string is not added to `symbols`, since it must not be
+        // returned when interning its valid counterpart.
+        self.spans
+            .push(unsafe { InternedStr::new(std::ptr::NonNull::from(&*lossy)) });
+        self.lossy.push(lossy);
+        self.utf16_symbols.insert(string.into(), next);
+        self.utf16_strings.insert(next, string.into());
+        next
+    }
+
     /// Returns the string for the given symbol if any.
     #[inline]
     pub fn resolve(&self, symbol: Sym) -> Option<&str> {
@@ -260,6 +306,28 @@ impl Interner {
         self.resolve(symbol).expect("string disappeared")
     }
 
+    /// Returns the UTF-16 code units of the string for the given symbol if any.
+    ///
+    /// Unlike [`Interner::resolve`], this preserves the unpaired surrogates of
+    /// strings interned with [`Interner::get_or_intern_utf16`].
+    pub fn resolve_utf16(&self, symbol: Sym) -> Option<Cow<'_, [u16]>> {
+        if let Some(string) = self.utf16_strings.get(&symbol) {
+            return Some(Cow::Borrowed(string));
+        }
+        self.resolve(symbol)
+            .map(|string| Cow::Owned(string.encode_utf16().collect()))
+    }
+
+    /// Returns the UTF-16 code units of the string for the given symbol.
+    ///
+    /// # Panics
+    ///
+    /// If the interner cannot resolve the given symbol.
+    #[inline]
+    pub fn resolve_expect_utf16(&self, symbol: Sym) -> Cow<'_, [u16]> {
+        self.resolve_utf16(symbol).expect("string disappeared")
+    }
+
     /// Gets the symbol of the common string if one of them
     fn get_common(string: &str) -> Option<Sym> {
         COMMON_STRINGS.get_index(string).map(|idx|
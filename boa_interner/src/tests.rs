@@ -56,3 +56,20 @@ fn check_static_resolve() {
         assert_eq!(sym, new_sym);
     }
 }
+
+#[test]
+fn check_utf16() {
+    let mut interner = Interner::default();
+
+    let valid: Vec<u16> = "hello".encode_utf16().collect();
+    let sym = interner.get_or_intern_utf16(&valid);
+    assert_eq!(sym, interner.get_or_intern("hello"));
+    assert_eq!(&*interner.resolve_expect_utf16(sym), &valid[..]);
+
+    let lone = [0x61, 0xD83D, 0x62];
+    let lone_sym = interner.get_or_intern_utf16(&lone);
+    assert_eq!(lone_sym, interner.get_or_intern_utf16(&lone));
+    assert_eq!(&*interner.resolve_expect_utf16(lone_sym), &lone[..]);
+    assert_eq!(interner.resolve_expect(lone_sym), "a\u{FFFD}b");
+    assert_ne!(interner.get_or_intern("a\u{FFFD}b"), lone_sym);
+}
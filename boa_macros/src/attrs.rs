@@ -0,0 +1,75 @@
+//! Parsing of the `#[boa(...)]` helper attributes shared by the engine macros.
+
+use syn::{spanned::Spanned, Attribute, Error, Lit, Meta, NestedMeta, Result};
+
+/// The options that can be set with `#[boa(...)]` attributes.
+#[derive(Debug, Default)]
+pub(crate) struct BoaAttrs {
+    /// `#[boa(rename = "name")]`: the name of the item in JavaScript.
+    pub(crate) rename: Option<String>,
+    /// `#[boa(constructor)]`: marks the constructor of a class.
+    pub(crate) constructor: bool,
+}
+
+impl BoaAttrs {
+    /// Parses every `#[boa(...)]` attribute in `attrs`, rejecting the options that are not in
+    /// `allowed`.
+    pub(crate) fn parse(attrs: &[Attribute], allowed: &[&str]) -> Result<Self> {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("boa")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new(meta.span(), "expected `#[boa(...)]`")),
+            };
+            for nested in list.nested {
+                let meta = match nested {
+                    NestedMeta::Meta(meta) => meta,
+                    NestedMeta::Lit(lit) => {
+                        return Err(Error::new(lit.span(), "unexpected literal"))
+                    }
+                };
+                let name = meta
+                    .path()
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                if !allowed.contains(&name.as_str()) {
+                    return Err(Error::new(
+                        meta.span(),
+                        format!(
+                            "unsupported option, expected one of: {}",
+                            allowed.join(", ")
+                        ),
+                    ));
+                }
+                match meta {
+                    Meta::Path(_) if name == "constructor" => result.constructor = true,
+                    Meta::NameValue(value) if name == "rename" => match value.lit {
+                        Lit::Str(lit) => result.rename = Some(lit.value()),
+                        lit => return Err(Error::new(lit.span(), "expected a string literal")),
+                    },
+                    meta => return Err(Error::new(meta.span(), "invalid option syntax")),
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Converts a `snake_case` Rust identifier to the `camelCase` used by JavaScript APIs.
+pub(crate) fn to_camel_case(ident: &str) -> String {
+    let ident = ident.trim_start_matches("r#");
+    let mut result = String::with_capacity(ident.len());
+    let mut upper = false;
+    for c in ident.chars() {
+        if c == '_' && !result.is_empty() {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
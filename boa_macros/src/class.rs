@@ -0,0 +1,355 @@
+//! Implementation of the `JsClass` derive and the `#[js_class]` attribute.
+
+use crate::attrs::{to_camel_case, BoaAttrs};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    spanned::Spanned, DeriveInput, Error, FnArg, ImplItem, ImplItemMethod, ItemImpl, Result,
+    ReturnType, Type,
+};
+
+/// Implements `Class` for a type, taking its name from the type (or `#[boa(rename = "...")]`)
+/// and delegating the constructor and the methods to its `ClassMethods` implementation.
+pub(crate) fn derive_js_class(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let js_name = BoaAttrs::parse(&input.attrs, &["rename"])?
+        .rename
+        .unwrap_or_else(|| name.to_string());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::boa_engine::class::Class for #name #ty_generics #where_clause {
+            const NAME: &'static str = #js_name;
+            const LENGTH: usize = <Self as ::boa_engine::class::ClassMethods>::LENGTH;
+
+            fn constructor(
+                this: &::boa_engine::JsValue,
+                args: &[::boa_engine::JsValue],
+                context: &mut ::boa_engine::Context,
+            ) -> ::boa_engine::JsResult<Self> {
+                <Self as ::boa_engine::class::ClassMethods>::constructor(this, args, context)
+            }
+
+            fn init(
+                class: &mut ::boa_engine::class::ClassBuilder<'_>,
+            ) -> ::boa_engine::JsResult<()> {
+                <Self as ::boa_engine::class::ClassMethods>::init(class)
+            }
+        }
+    })
+}
+
+/// Implements `ClassMethods` for the type of an impl block, registering each of its methods.
+pub(crate) fn js_class(mut item: ItemImpl) -> Result<TokenStream> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(Error::new(
+            path.span(),
+            "`#[js_class]` must be used on an inherent impl block",
+        ));
+    }
+
+    let mut constructor = None;
+    let mut methods = Vec::new();
+    for impl_item in &mut item.items {
+        if let ImplItem::Method(method) = impl_item {
+            let method = Method::parse(method)?;
+            if method.constructor {
+                if constructor.is_some() {
+                    return Err(Error::new(
+                        method.ident.span(),
+                        "a class can only have one constructor",
+                    ));
+                }
+                constructor = Some(method);
+            } else {
+                methods.push(method);
+            }
+        }
+    }
+
+    let self_ty = &item.self_ty;
+    let class_name = quote!(#self_ty).to_string();
+    let (length, constructor) = if let Some(constructor) = constructor {
+        let convert_args = constructor.convert_args();
+        let call = constructor.call(None);
+        (
+            constructor.params.len(),
+            quote! {
+                #convert_args
+                #call
+            },
+        )
+    } else {
+        let error = format!("`{class_name}` does not have a constructor");
+        (
+            0,
+            quote! {
+                let _ = (args, context);
+                ::boa_engine::JsResult::Err(
+                    ::boa_engine::JsNativeError::typ().with_message(#error).into()
+                )
+            },
+        )
+    };
+
+    let registrations = methods.iter().map(|method| {
+        let js_name = &method.js_name;
+        let length = method.params.len();
+        let function = method.native_function(&class_name);
+        if matches!(method.receiver, Receiver::None) {
+            quote!(class.static_method(#js_name, #length, #function);)
+        } else {
+            quote!(class.method(#js_name, #length, #function);)
+        }
+    });
+
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    Ok(quote! {
+        #item
+
+        #[automatically_derived]
+        impl #impl_generics ::boa_engine::class::ClassMethods for #self_ty #where_clause {
+            const LENGTH: usize = #length;
+
+            fn constructor(
+                _: &::boa_engine::JsValue,
+                args: &[::boa_engine::JsValue],
+                context: &mut ::boa_engine::Context,
+            ) -> ::boa_engine::JsResult<Self> {
+                #constructor
+            }
+
+            fn init(
+                class: &mut ::boa_engine::class::ClassBuilder<'_>,
+            ) -> ::boa_engine::JsResult<()> {
+                #(#registrations)*
+                ::boa_engine::JsResult::Ok(())
+            }
+        }
+    })
+}
+
+/// The receiver of a method in a `#[js_class]` block.
+enum Receiver {
+    /// A static method or the constructor.
+    None,
+    /// `&self`, with its span.
+    Ref(Span),
+    /// `&mut self`.
+    Mut,
+}
+
+/// The parsed signature of a method in a `#[js_class]` block.
+struct Method {
+    ident: syn::Ident,
+    js_name: String,
+    receiver: Receiver,
+    constructor: bool,
+    /// The types of the parameters converted from the arguments.
+    params: Vec<Type>,
+    /// The position of the `&mut Context` parameter among the other parameters, if any.
+    context: Option<usize>,
+    /// `true` if the method returns a `Result` that must be propagated.
+    fallible: bool,
+}
+
+impl Method {
+    /// Parses a method, removing its `#[boa(...)]` attributes.
+    fn parse(method: &mut ImplItemMethod) -> Result<Self> {
+        let attrs = BoaAttrs::parse(&method.attrs, &["rename", "constructor"])?;
+        method.attrs.retain(|attr| !attr.path.is_ident("boa"));
+
+        let sig = &method.sig;
+        let mut receiver = Receiver::None;
+        let mut params = Vec::new();
+        let mut context = None;
+        for input in &sig.inputs {
+            match input {
+                FnArg::Receiver(r) => {
+                    if r.reference.is_none() {
+                        return Err(Error::new(
+                            r.span(),
+                            "methods of a class must take `self` by reference",
+                        ));
+                    }
+                    receiver = if r.mutability.is_some() {
+                        Receiver::Mut
+                    } else {
+                        Receiver::Ref(r.span())
+                    };
+                }
+                FnArg::Typed(arg) if is_context(&arg.ty) => {
+                    if context.is_some() {
+                        return Err(Error::new(arg.span(), "duplicate `&mut Context` parameter"));
+                    }
+                    context = Some(params.len());
+                }
+                FnArg::Typed(arg) => params.push((*arg.ty).clone()),
+            }
+        }
+        if attrs.constructor && !matches!(receiver, Receiver::None) {
+            return Err(Error::new(
+                sig.span(),
+                "the constructor of a class cannot take `self`",
+            ));
+        }
+        // The receiver stays borrowed during the call, so code run with the context could not
+        // access the object at all.
+        if matches!(receiver, Receiver::Mut) && context.is_some() {
+            return Err(Error::new(
+                sig.span(),
+                "a method taking `&mut self` cannot take a `&mut Context`, since the object \
+                 cannot be used by the code it runs; take `&self` instead",
+            ));
+        }
+
+        Ok(Self {
+            js_name: attrs
+                .rename
+                .unwrap_or_else(|| to_camel_case(&sig.ident.to_string())),
+            ident: sig.ident.clone(),
+            receiver,
+            constructor: attrs.constructor,
+            params,
+            context,
+            fallible: is_result(&sig.output),
+        })
+    }
+
+    /// Generates the conversion of every argument to the type of its parameter.
+    fn convert_args(&self) -> TokenStream {
+        let conversions = self.params.iter().enumerate().map(|(i, ty)| {
+            let arg = format_ident!("arg{}", i);
+            quote! {
+                let #arg = <#ty as ::boa_engine::value::TryFromJs>::try_from_js(
+                    ::boa_engine::builtins::JsArgs::get_or_undefined(args, #i),
+                    context,
+                )?;
+            }
+        });
+        quote!(#(#conversions)*)
+    }
+
+    /// Generates the call to the method with the converted arguments, evaluating to a
+    /// `JsResult` of the returned value.
+    fn call(&self, receiver: Option<TokenStream>) -> TokenStream {
+        let ident = &self.ident;
+        let mut args: Vec<_> = (0..self.params.len())
+            .map(|i| {
+                let arg = format_ident!("arg{}", i);
+                quote!(#arg)
+            })
+            .collect();
+        if let Some(position) = self.context {
+            args.insert(position, quote!(context));
+        }
+        args.splice(0..0, receiver);
+
+        let call = quote!(Self::#ident(#(#args),*));
+        if self.fallible {
+            quote!(#call.map_err(::std::convert::Into::<::boa_engine::JsError>::into))
+        } else {
+            quote!(::boa_engine::JsResult::Ok(#call))
+        }
+    }
+
+    /// Generates the native function registered for the method.
+    fn native_function(&self, class_name: &str) -> TokenStream {
+        let convert_args = self.convert_args();
+        let error = format!(
+            "`{class_name}.prototype.{}` called on an incompatible receiver",
+            self.js_name
+        );
+        let busy_error = format!(
+            "`{class_name}.prototype.{}` called on an object that is already in use by another method",
+            self.js_name
+        );
+        let (borrow, receiver) = match self.receiver {
+            Receiver::None => (quote!(), None),
+            Receiver::Ref(span) => {
+                let borrow = quote! {
+                    let object = this.as_object().ok_or_else(|| {
+                        ::boa_engine::JsNativeError::typ().with_message(#error)
+                    })?;
+                    let object = object.try_borrow().map_err(|_| {
+                        ::boa_engine::JsNativeError::typ().with_message(#busy_error)
+                    })?;
+                    let object = object.downcast_ref::<Self>().ok_or_else(|| {
+                        ::boa_engine::JsNativeError::typ().with_message(#error)
+                    })?;
+                };
+                if self.context.is_some() {
+                    // The code run with the context can modify the object, so the method gets a
+                    // clone of its data instead of keeping the object borrowed.
+                    let clone = quote_spanned!(span=> <Self as ::std::clone::Clone>::clone(object));
+                    (
+                        quote! {
+                            let object = {
+                                #borrow
+                                #clone
+                            };
+                        },
+                        Some(quote!(&object)),
+                    )
+                } else {
+                    (borrow, Some(quote!(object)))
+                }
+            }
+            Receiver::Mut => (
+                quote! {
+                    let object = this.as_object().ok_or_else(|| {
+                        ::boa_engine::JsNativeError::typ().with_message(#error)
+                    })?;
+                    let mut object = object.try_borrow_mut().map_err(|_| {
+                        ::boa_engine::JsNativeError::typ().with_message(#busy_error)
+                    })?;
+                    let object = object.downcast_mut::<Self>().ok_or_else(|| {
+                        ::boa_engine::JsNativeError::typ().with_message(#error)
+                    })?;
+                },
+                Some(quote!(object)),
+            ),
+        };
+        let call = self.call(receiver);
+
+        quote! {
+            |this: &::boa_engine::JsValue,
+             args: &[::boa_engine::JsValue],
+             context: &mut ::boa_engine::Context|
+             -> ::boa_engine::JsResult<::boa_engine::JsValue> {
+                let _ = this;
+                #convert_args
+                // The receiver is only borrowed during the call, after the arguments have been
+                // converted, since the conversions can run arbitrary code.
+                let result = {
+                    #borrow
+                    #call?
+                };
+                ::boa_engine::JsResult::Ok(::boa_engine::value::IntoJs::into_js(result, context))
+            }
+        }
+    }
+}
+
+/// Returns `true` if `ty` is `&mut Context`.
+fn is_context(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) if reference.mutability.is_some() => {
+            matches!(&*reference.elem, Type::Path(path)
+                if path.path.segments.last().map_or(false, |segment| segment.ident == "Context"))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if the return type is a `Result` or a `JsResult`.
+fn is_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => matches!(&**ty, Type::Path(path)
+        if path.path.segments.last().map_or(false, |segment| {
+            segment.ident == "Result" || segment.ident == "JsResult"
+        })),
+        ReturnType::Default => false,
+    }
+}
@@ -0,0 +1,162 @@
+//! Procedural macros used by the Boa JavaScript engine.
+//!
+//! This crate provides:
+//!  - the `Trace` and `Finalize` derives used by [`boa_gc`][gc]. The generated code refers to
+//!    items through the `::boa_gc` path, so crates using these derives must depend on `boa_gc`
+//!    directly.
+//!  - the `JsClass` and `TryFromJs` derives and the `#[js_class]` attribute, which help
+//!    embedders expose Rust types to JavaScript. They are re-exported by [`boa_engine`][engine],
+//!    and the generated code refers to items through the `::boa_engine` path.
+//!
+//! [gc]: https://docs.rs/boa_gc
+//! [engine]: https://docs.rs/boa_engine
+
+mod attrs;
+mod class;
+mod try_from_js;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, ItemImpl};
+use synstructure::{decl_derive, AddBounds, Structure};
+
+decl_derive!([Trace, attributes(unsafe_ignore_trace)] => derive_trace);
+
+/// Derives the `Trace` trait, marking every field that isn't annotated with
+/// `#[unsafe_ignore_trace]`.
+fn derive_trace(mut s: Structure<'_>) -> proc_macro2::TokenStream {
+    s.underscore_const(true);
+    s.filter(|bi| {
+        !bi.ast()
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("unsafe_ignore_trace"))
+    });
+    let trace_body = s.each(|bi| quote!(mark(#bi)));
+
+    s.add_bounds(AddBounds::Fields);
+    let trace_impl = s.unsafe_bound_impl(
+        quote!(::boa_gc::Trace),
+        quote! {
+            #[inline] unsafe fn trace(&self) {
+                #[allow(dead_code)]
+                #[inline]
+                unsafe fn mark<T: ::boa_gc::Trace + ?Sized>(it: &T) {
+                    ::boa_gc::Trace::trace(it);
+                }
+                match *self { #trace_body }
+            }
+            #[inline] unsafe fn root(&self) {
+                #[allow(dead_code)]
+                #[inline]
+                unsafe fn mark<T: ::boa_gc::Trace + ?Sized>(it: &T) {
+                    ::boa_gc::Trace::root(it);
+                }
+                match *self { #trace_body }
+            }
+            #[inline] unsafe fn unroot(&self) {
+                #[allow(dead_code)]
+                #[inline]
+                unsafe fn mark<T: ::boa_gc::Trace + ?Sized>(it: &T) {
+                    ::boa_gc::Trace::unroot(it);
+                }
+                match *self { #trace_body }
+            }
+            #[inline] fn finalize_glue(&self) {
+                ::boa_gc::Finalize::finalize(self);
+                #[allow(dead_code)]
+                #[inline]
+                fn mark<T: ::boa_gc::Trace + ?Sized>(it: &T) {
+                    ::boa_gc::Trace::finalize_glue(it);
+                }
+                match *self { #trace_body }
+            }
+        },
+    );
+
+    // We also implement drop to prevent unsafe drop implementations on this
+    // type and encourage people to use Finalize. This implementation will
+    // call `Finalize::finalize` if it is safe to do so.
+    let drop_impl = s.unbound_impl(
+        quote!(::std::ops::Drop),
+        quote! {
+            fn drop(&mut self) {
+                if ::boa_gc::finalizer_safe() {
+                    ::boa_gc::Finalize::finalize(self);
+                }
+            }
+        },
+    );
+
+    quote! {
+        #trace_impl
+        #drop_impl
+    }
+}
+
+decl_derive!([Finalize] => derive_finalize);
+
+/// Derives an empty implementation of the `Finalize` trait.
+#[allow(clippy::needless_pass_by_value)]
+fn derive_finalize(mut s: Structure<'_>) -> proc_macro2::TokenStream {
+    s.underscore_const(true);
+    s.unbound_impl(quote!(::boa_gc::Finalize), quote!())
+}
+
+/// Derives the `Class` trait of `boa_engine`.
+///
+/// The class is named after the type, unless a `#[boa(rename = "Name")]` attribute is present.
+/// Its constructor and methods are taken from the `ClassMethods` implementation of the type,
+/// which is usually generated by a [`macro@js_class`] impl block.
+#[proc_macro_derive(JsClass, attributes(boa))]
+pub fn derive_js_class(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    class::derive_js_class(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements the `ClassMethods` trait of `boa_engine` from an inherent impl block.
+///
+/// Every method of the block is registered in the class, with its name converted to
+/// `camelCase` unless a `#[boa(rename = "name")]` attribute is present:
+///  - methods taking `&self` or `&mut self` are added to the prototype, and throw a `TypeError`
+///    if `this` is not an instance of the class.
+///  - methods without a receiver are added to the class object itself, except for the one
+///    marked with `#[boa(constructor)]`, which constructs the class instances.
+///
+/// The parameters are converted from the arguments with `TryFromJs`, except for an optional
+/// `&mut Context` parameter, and the returned value is converted with `IntoJs`. If the method
+/// returns a `Result`, its error is thrown.
+///
+/// The object is borrowed while its method runs, so a method taking `&mut self` cannot take a
+/// `&mut Context`. A method taking `&self` and a `&mut Context` can run code that modifies the
+/// object, so it is called with a clone of the data of the object instead: the type must
+/// implement `Clone`, and the method does not see the changes made by the code it runs.
+#[proc_macro_attribute]
+pub fn js_class(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`#[js_class]` does not take any arguments",
+        )
+        .into_compile_error()
+        .into();
+    }
+    let item = parse_macro_input!(item as ItemImpl);
+    class::js_class(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives the `TryFromJs` trait of `boa_engine` for a struct with named fields.
+///
+/// Every field is converted from the property of a JavaScript object with the same name in
+/// `camelCase`, unless a `#[boa(rename = "name")]` attribute is present.
+#[proc_macro_derive(TryFromJs, attributes(boa))]
+pub fn derive_try_from_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    try_from_js::derive_try_from_js(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
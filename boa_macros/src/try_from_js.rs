@@ -0,0 +1,67 @@
+//! Implementation of the `TryFromJs` derive.
+
+use crate::attrs::{to_camel_case, BoaAttrs};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+/// Implements `TryFromJs` for a struct with named fields, reading every field from the property
+/// of the same name (in `camelCase`) of a JavaScript object.
+pub(crate) fn derive_try_from_js(input: &DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "`TryFromJs` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`TryFromJs` can only be derived for structs with named fields",
+            ))
+        }
+    };
+    BoaAttrs::parse(&input.attrs, &[])?;
+
+    let mut field_conversions = Vec::with_capacity(fields.len());
+    for field in fields {
+        let ident = field
+            .ident
+            .as_ref()
+            .expect("named fields have an identifier");
+        let ty = &field.ty;
+        let name = BoaAttrs::parse(&field.attrs, &["rename"])?
+            .rename
+            .unwrap_or_else(|| to_camel_case(&ident.to_string()));
+        field_conversions.push(quote! {
+            #ident: <#ty as ::boa_engine::value::TryFromJs>::try_from_js(
+                &object.get(#name, context)?,
+                context,
+            )?
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let error = format!("cannot convert a non-object value to `{name}`");
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::boa_engine::value::TryFromJs for #name #ty_generics #where_clause {
+            fn try_from_js(
+                value: &::boa_engine::JsValue,
+                context: &mut ::boa_engine::Context,
+            ) -> ::boa_engine::JsResult<Self> {
+                let object = value.as_object().ok_or_else(|| {
+                    ::boa_engine::JsNativeError::typ().with_message(#error)
+                })?;
+                ::std::result::Result::Ok(Self {
+                    #(#field_conversions,)*
+                })
+            }
+        }
+    })
+}
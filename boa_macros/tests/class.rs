@@ -0,0 +1,219 @@
+use boa_engine::{
+    class::{js_class, JsClass},
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+#[derive(Debug, Clone, Trace, Finalize, JsClass)]
+#[boa(rename = "Point2D")]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[js_class]
+impl Point {
+    #[boa(constructor)]
+    fn new(x: f64, y: Option<f64>) -> Self {
+        Self {
+            x,
+            y: y.unwrap_or(0.0),
+        }
+    }
+
+    #[boa(rename = "len")]
+    fn length(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    fn scale_by(&mut self, factor: f64) -> f64 {
+        self.x *= factor;
+        self.y *= factor;
+        self.x
+    }
+
+    fn with_coordinates(&self, callback: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let callback = callback
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| JsNativeError::typ().with_message("expected a function"))?;
+        callback.call(
+            &JsValue::undefined(),
+            &[self.x.into(), self.y.into()],
+            context,
+        )
+    }
+
+    fn checked_length(x: f64, y: f64) -> Result<f64, JsNativeError> {
+        if x.is_finite() && y.is_finite() {
+            Ok(x.hypot(y))
+        } else {
+            Err(JsNativeError::range().with_message("the coordinates must be finite"))
+        }
+    }
+}
+
+#[derive(Debug, Trace, Finalize, JsClass)]
+struct Empty;
+
+#[js_class]
+impl Empty {}
+
+fn context() -> Context {
+    let mut context = Context::default();
+    context.register_global_class::<Point>().unwrap();
+    context.register_global_class::<Empty>().unwrap();
+    context
+}
+
+fn eval(context: &mut Context, source: &str) -> String {
+    match context.eval(source) {
+        Ok(value) => value.display().to_string(),
+        Err(error) => format!("Uncaught {error}"),
+    }
+}
+
+#[test]
+fn class_name_and_length() {
+    let mut context = context();
+    assert_eq!(eval(&mut context, "Point2D.name"), "\"Point2D\"");
+    assert_eq!(eval(&mut context, "Point2D.length"), "2");
+    assert_eq!(eval(&mut context, "typeof Point"), "\"undefined\"");
+}
+
+#[test]
+fn constructor_converts_arguments() {
+    let mut context = context();
+    assert_eq!(eval(&mut context, "new Point2D(3, 4).len()"), "5");
+    assert_eq!(eval(&mut context, "new Point2D(3).len()"), "3");
+    assert_eq!(
+        eval(
+            &mut context,
+            "try { new Point2D('3') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        eval(&mut context, "new Point2D(1) instanceof Point2D"),
+        "true"
+    );
+}
+
+#[test]
+fn methods_are_camel_cased_and_renamed() {
+    let mut context = context();
+    assert_eq!(
+        eval(
+            &mut context,
+            "Object.getOwnPropertyNames(Point2D.prototype).sort().join()"
+        ),
+        "\"constructor,len,scaleBy,withCoordinates\""
+    );
+    assert_eq!(eval(&mut context, "Point2D.prototype.scaleBy.length"), "1");
+    assert_eq!(
+        eval(&mut context, "typeof Point2D.checkedLength"),
+        "\"function\""
+    );
+}
+
+#[test]
+fn mutable_and_static_methods() {
+    let mut context = context();
+    assert_eq!(
+        eval(
+            &mut context,
+            "const p = new Point2D(1, 2); p.scaleBy(3); p.len() ** 2"
+        ),
+        "45.00000000000001"
+    );
+    assert_eq!(eval(&mut context, "Point2D.checkedLength(3, 4)"), "5");
+    assert_eq!(
+        eval(
+            &mut context,
+            "try { Point2D.checkedLength(Infinity, 0) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn methods_with_context() {
+    let mut context = context();
+    assert_eq!(
+        eval(
+            &mut context,
+            "new Point2D(1, 2).withCoordinates((x, y) => `${x},${y}`)"
+        ),
+        "\"1,2\""
+    );
+    assert_eq!(
+        eval(
+            &mut context,
+            "const q = new Point2D(1, 2); q.withCoordinates(() => q.len())"
+        ),
+        "2.23606797749979"
+    );
+}
+
+#[test]
+fn incompatible_receivers_throw() {
+    let mut context = context();
+    assert_eq!(
+        eval(
+            &mut context,
+            "try { Point2D.prototype.len.call({}) } catch (e) { e.message }"
+        ),
+        "\"`Point.prototype.len` called on an incompatible receiver\""
+    );
+    assert_eq!(
+        eval(
+            &mut context,
+            "try { Point2D.prototype.scaleBy.call(new Empty(), 2) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        eval(
+            &mut context,
+            "try { new Empty().len() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn callbacks_can_modify_the_receiver() {
+    let mut context = context();
+    assert_eq!(
+        eval(
+            &mut context,
+            r#"
+            const p = new Point2D(1, 2);
+            p.withCoordinates(() => { p.z = 1; });
+            p.z
+            "#
+        ),
+        "1"
+    );
+    assert_eq!(
+        eval(
+            &mut context,
+            r#"
+            const r = new Point2D(1, 2);
+            r.withCoordinates(() => r.scaleBy(2));
+            "#
+        ),
+        "2"
+    );
+    assert_eq!(eval(&mut context, "r.scaleBy(2)"), "4");
+}
+
+#[test]
+fn classes_without_constructor() {
+    let mut context = context();
+    assert_eq!(eval(&mut context, "Empty.length"), "0");
+    assert_eq!(
+        eval(&mut context, "try { new Empty() } catch (e) { e.message }"),
+        "\"`Empty` does not have a constructor\""
+    );
+}
@@ -0,0 +1,15 @@
+use boa_engine::class::js_class;
+
+struct Counter {
+    count: u32,
+}
+
+#[js_class]
+impl Counter {
+    #[boa(constructor)]
+    fn new(&self) -> Self {
+        Self { count: self.count }
+    }
+}
+
+fn main() {}
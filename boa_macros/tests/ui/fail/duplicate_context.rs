@@ -0,0 +1,12 @@
+use boa_engine::class::js_class;
+
+struct Counter {
+    count: u32,
+}
+
+#[js_class]
+impl Counter {
+    fn reset(_first: &mut boa_engine::Context, _second: &mut boa_engine::Context) {}
+}
+
+fn main() {}
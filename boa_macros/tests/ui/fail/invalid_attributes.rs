@@ -0,0 +1,22 @@
+use boa_engine::class::{js_class, JsClass};
+use boa_gc::{Finalize, Trace};
+
+#[derive(Debug, Trace, Finalize, JsClass)]
+#[boa(constructor)]
+struct First;
+
+#[derive(Debug, Trace, Finalize, JsClass)]
+#[boa(rename = 1)]
+struct Second;
+
+struct Third;
+
+#[js_class]
+impl Third {
+    #[boa(getter)]
+    fn value(&self) -> u32 {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use boa_engine::class::js_class;
+
+struct Counter;
+
+#[js_class(name = "Other")]
+impl Counter {}
+
+trait Named {
+    fn name(&self) -> String;
+}
+
+#[js_class]
+impl Named for Counter {
+    fn name(&self) -> String {
+        String::new()
+    }
+}
+
+fn main() {}
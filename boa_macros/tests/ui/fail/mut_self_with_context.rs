@@ -0,0 +1,15 @@
+use boa_engine::class::js_class;
+
+struct Counter {
+    count: u32,
+}
+
+#[js_class]
+impl Counter {
+    fn increment(&mut self, _context: &mut boa_engine::Context) -> u32 {
+        self.count += 1;
+        self.count
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use boa_engine::{
+    class::{js_class, JsClass},
+    Context,
+};
+use boa_gc::{Finalize, Trace};
+
+#[derive(Debug, Trace, Finalize, JsClass)]
+struct Counter {
+    count: u32,
+}
+
+#[js_class]
+impl Counter {
+    fn count(&self, _context: &mut Context) -> u32 {
+        self.count
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use boa_engine::class::js_class;
+
+struct Counter {
+    count: u32,
+}
+
+#[js_class]
+impl Counter {
+    fn into_count(self) -> u32 {
+        self.count
+    }
+}
+
+fn main() {}
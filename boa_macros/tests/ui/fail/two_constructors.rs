@@ -0,0 +1,20 @@
+use boa_engine::class::js_class;
+
+struct Counter {
+    count: u32,
+}
+
+#[js_class]
+impl Counter {
+    #[boa(constructor)]
+    fn new() -> Self {
+        Self { count: 0 }
+    }
+
+    #[boa(constructor)]
+    fn with_count(count: u32) -> Self {
+        Self { count }
+    }
+}
+
+fn main() {}
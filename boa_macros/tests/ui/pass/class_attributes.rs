@@ -0,0 +1,48 @@
+use boa_engine::{
+    class::{js_class, JsClass},
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+#[derive(Debug, Trace, Finalize, JsClass)]
+#[boa(rename = "Point2D")]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[js_class]
+impl Point {
+    #[boa(constructor)]
+    fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    #[boa(rename = "len")]
+    fn length(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    fn scale(&mut self, factor: f64) {
+        self.x *= factor;
+        self.y *= factor;
+    }
+
+    fn map(&self, callback: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let callback = callback
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| boa_engine::JsNativeError::typ().with_message("expected a function"))?;
+        callback.call(
+            &JsValue::undefined(),
+            &[self.x.into(), self.y.into()],
+            context,
+        )
+    }
+
+    fn distance(x: f64, y: f64) -> f64 {
+        x.hypot(y)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use boa_engine::class::{js_class, JsClass};
+use boa_gc::{Finalize, Trace};
+
+#[derive(Debug, Trace, Finalize, JsClass)]
+struct Registry;
+
+#[js_class]
+impl Registry {
+    fn size(&self) -> u32 {
+        0
+    }
+}
+
+fn main() {}
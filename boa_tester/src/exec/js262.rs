@@ -1,21 +1,33 @@
 use boa_engine::{
-    builtins::JsArgs,
-    object::{JsObject, ObjectInitializer},
+    builtins::{shared_array_buffer::SharedMemory, JsArgs},
+    object::{FunctionBuilder, JsObject, JsSharedArrayBuffer, ObjectInitializer},
     property::Attribute,
-    Context, JsResult, JsValue,
+    Context, JsBigInt, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use once_cell::sync::Lazy;
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc,
+    sync::{mpsc, Arc, Mutex, PoisonError},
+    thread,
+    time::{Duration, Instant},
 };
 
 /// Initializes the object in the context.
 pub(super) fn init(context: &mut Context) -> JsObject {
     let global_obj = context.global_object().clone();
+    let agent = init_agent(context);
 
     let obj = ObjectInitializer::new(context)
+        .function(clear_kept_objects, "clearKeptObjects", 0)
         .function(create_realm, "createRealm", 0)
         .function(detach_array_buffer, "detachArrayBuffer", 2)
         .function(eval_script, "evalScript", 1)
         .function(gc, "gc", 0)
         .property("global", global_obj, Attribute::default())
-        // .property("agent", agent, Attribute::default())
+        .property("agent", agent, Attribute::default())
         .build();
 
     context.register_global_property("$262", obj.clone(), Attribute::empty());
@@ -23,6 +35,16 @@ pub(super) fn init(context: &mut Context) -> JsObject {
     obj
 }
 
+/// The `$262.clearKeptObjects()` function.
+///
+/// Calls the `ClearKeptObjects` abstract operation, releasing the targets of the `WeakRef`s
+/// created or dereferenced during the current execution.
+#[allow(clippy::unnecessary_wraps)]
+fn clear_kept_objects(_this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    context.clear_kept_objects();
+    Ok(JsValue::undefined())
+}
+
 /// The `$262.createRealm()` function.
 ///
 /// Creates a new ECMAScript Realm, defines this API on the new realm's global object, and
@@ -40,32 +62,32 @@ fn create_realm(_this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsRes
 /// The `$262.detachArrayBuffer()` function.
 ///
 /// Implements the `DetachArrayBuffer` abstract operation.
-fn detach_array_buffer(
-    _this: &JsValue,
-    args: &[JsValue],
-    context: &mut Context,
-) -> JsResult<JsValue> {
+fn detach_array_buffer(_this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
     #[inline]
-    fn type_err(context: &mut Context) -> JsValue {
-        context.construct_type_error("The provided object was not an ArrayBuffer")
+    fn type_err() -> JsNativeError {
+        JsNativeError::typ().with_message("The provided object was not an ArrayBuffer")
     }
 
     let array_buffer = args
         .get(0)
         .and_then(JsValue::as_object)
-        .ok_or_else(|| type_err(context))?;
+        .ok_or_else(type_err)?;
     let mut array_buffer = array_buffer.borrow_mut();
-    let array_buffer = array_buffer
-        .as_array_buffer_mut()
-        .ok_or_else(|| type_err(context))?;
+    let array_buffer = array_buffer.as_array_buffer_mut().ok_or_else(type_err)?;
+
+    // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false.
+    if array_buffer.is_shared_array_buffer() {
+        return Err(type_err().into());
+    }
 
-    // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false. TODO
     // 2. If key is not present, set key to undefined.
     let key = args.get_or_undefined(1);
 
     // 3. If SameValue(arrayBuffer.[[ArrayBufferDetachKey]], key) is false, throw a TypeError exception.
     if !JsValue::same_value(&array_buffer.array_buffer_detach_key, key) {
-        return context.throw_type_error("Cannot detach array buffer with different key");
+        return Err(JsNativeError::typ()
+            .with_message("Cannot detach array buffer with different key")
+            .into());
     }
 
     // 4. Set arrayBuffer.[[ArrayBufferData]] to null.
@@ -83,12 +105,15 @@ fn detach_array_buffer(
 /// Accepts a string value as its first argument and executes it as an ECMAScript script.
 fn eval_script(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
     if let Some(source_text) = args.get(0).and_then(JsValue::as_string) {
-        match context.parse(source_text.as_str()) {
+        let source_text = source_text.to_std_string_escaped();
+        match context.parse(&source_text) {
             // TODO: check strict
-            Err(e) => context.throw_type_error(format!("Uncaught Syntax Error: {e}")),
+            Err(e) => Err(JsNativeError::typ()
+                .with_message(format!("Uncaught Syntax Error: {e}"))
+                .into()),
             // Calling eval here parses the code a second time.
             // TODO: We can fix this after we have have defined the public api for the vm executer.
-            Ok(_) => context.eval(source_text.as_str()),
+            Ok(_) => context.eval(&source_text),
         }
     } else {
         Ok(JsValue::undefined())
@@ -105,3 +130,307 @@ fn gc(_this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsResult<JsValu
     boa_gc::force_collect();
     Ok(JsValue::undefined())
 }
+
+/// The time origin of `$262.agent.monotonicNow()`, shared by the main agent and all the agents
+/// it starts.
+static TIME_ORIGIN: Lazy<Instant> = Lazy::new(Instant::now);
+
+/// A message sent by `$262.agent.broadcast()` to the agents.
+#[derive(Debug)]
+struct Broadcast {
+    memory: SharedMemory,
+    id: BroadcastId,
+    /// Tells the main agent that the message was received.
+    received: mpsc::Sender<()>,
+}
+
+/// The id sent along with a broadcast, which is either a `Number` or a `BigInt`.
+///
+/// `BigInt`s cannot be sent to other threads, so they are sent as their string representation.
+#[derive(Debug)]
+enum BroadcastId {
+    Number(f64),
+    BigInt(String),
+}
+
+/// The reports sent by the agents with `$262.agent.report()`, in the order they were sent.
+type Reports = Arc<Mutex<VecDeque<String>>>;
+
+/// The agents started by the main agent with `$262.agent.start()`.
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+struct Agents {
+    #[unsafe_ignore_trace]
+    inner: Rc<RefCell<AgentsInner>>,
+}
+
+#[derive(Debug, Default)]
+struct AgentsInner {
+    /// The channels used to send broadcasts to the agents.
+    ///
+    /// Dropping them makes the agents still waiting for a broadcast return.
+    broadcasts: Vec<mpsc::Sender<Broadcast>>,
+    reports: Reports,
+}
+
+/// Creates the `$262.agent` object of the main agent.
+fn init_agent(context: &mut Context) -> JsObject {
+    let agents = Agents::default();
+
+    let start = FunctionBuilder::closure_with_captures(context, agent_start, agents.clone())
+        .name("start")
+        .length(1)
+        .build();
+    let broadcast =
+        FunctionBuilder::closure_with_captures(context, agent_broadcast, agents.clone())
+            .name("broadcast")
+            .length(2)
+            .build();
+    let get_report = FunctionBuilder::closure_with_captures(context, agent_get_report, agents)
+        .name("getReport")
+        .length(0)
+        .build();
+
+    ObjectInitializer::new(context)
+        .property("start", start, Attribute::default())
+        .property("broadcast", broadcast, Attribute::default())
+        .property("getReport", get_report, Attribute::default())
+        .function(agent_sleep, "sleep", 1)
+        .function(agent_monotonic_now, "monotonicNow", 0)
+        .build()
+}
+
+/// The `$262.agent.start()` function.
+///
+/// Starts a new agent on its own thread, which runs the script passed as argument.
+fn agent_start(
+    _this: &JsValue,
+    args: &[JsValue],
+    agents: &mut Agents,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let source = args.get_or_undefined(0).to_string(context)?;
+    let source = source.to_std_string_escaped();
+
+    let (sender, receiver) = mpsc::channel();
+    let mut agents = agents.inner.borrow_mut();
+    agents.broadcasts.push(sender);
+    let reports = agents.reports.clone();
+
+    thread::Builder::new()
+        .name("test262 agent".into())
+        .spawn(move || {
+            let mut context = Context::default();
+            let agent = init_child_agent(&mut context, receiver, reports);
+            let js_262 = ObjectInitializer::new(&mut context)
+                .property("agent", agent, Attribute::default())
+                .build();
+            context.register_global_property("$262", js_262, Attribute::empty());
+
+            // The errors of the agent can only be observed through its reports.
+            let _result = context.eval(&source);
+        })
+        .map_err(|e| JsNativeError::error().with_message(format!("could not start agent: {e}")))?;
+
+    Ok(JsValue::undefined())
+}
+
+/// The `$262.agent.broadcast()` function.
+///
+/// Sends a `SharedArrayBuffer` and a `Number` or `BigInt` id to all the agents, and blocks until
+/// all of them have received it.
+fn agent_broadcast(
+    _this: &JsValue,
+    args: &[JsValue],
+    agents: &mut Agents,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let buffer = args
+        .get_or_undefined(0)
+        .as_object()
+        .cloned()
+        .ok_or_else(|| {
+            JsNativeError::typ().with_message("can only broadcast a SharedArrayBuffer")
+        })?;
+    let memory = JsSharedArrayBuffer::from_object(buffer)?.memory();
+
+    let id = args.get_or_undefined(1);
+    let id = if let Some(id) = id.as_bigint() {
+        BroadcastId::BigInt(id.to_string())
+    } else {
+        BroadcastId::Number(id.to_number(context)?)
+    };
+
+    let (received, acknowledgements) = mpsc::channel();
+    let mut sent = 0;
+    for agent in &agents.inner.borrow().broadcasts {
+        let message = Broadcast {
+            memory: memory.clone(),
+            id: match id {
+                BroadcastId::Number(n) => BroadcastId::Number(n),
+                BroadcastId::BigInt(ref n) => BroadcastId::BigInt(n.clone()),
+            },
+            received: received.clone(),
+        };
+
+        // Agents that already finished cannot receive the message.
+        if agent.send(message).is_ok() {
+            sent += 1;
+        }
+    }
+    drop(received);
+
+    for _ in 0..sent {
+        // All the senders are dropped if an agent finishes without receiving the message.
+        if acknowledgements.recv().is_err() {
+            break;
+        }
+    }
+
+    Ok(JsValue::undefined())
+}
+
+/// The `$262.agent.getReport()` function.
+///
+/// Returns the oldest report of the agents that was not returned yet, or `null` if there is none.
+#[allow(clippy::unnecessary_wraps)]
+fn agent_get_report(
+    _this: &JsValue,
+    _: &[JsValue],
+    agents: &mut Agents,
+    _context: &mut Context,
+) -> JsResult<JsValue> {
+    let report = agents
+        .inner
+        .borrow()
+        .reports
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .pop_front();
+
+    Ok(report.map_or_else(JsValue::null, JsValue::from))
+}
+
+/// The `$262.agent.sleep()` function.
+///
+/// Suspends the current agent for the given number of milliseconds.
+fn agent_sleep(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let ms = args.get_or_undefined(0).to_number(context)?;
+    if ms > 0.0 {
+        thread::sleep(Duration::from_micros((ms * 1000.0) as u64));
+    }
+
+    Ok(JsValue::undefined())
+}
+
+/// The `$262.agent.monotonicNow()` function.
+///
+/// Returns the number of milliseconds elapsed since an origin shared by all the agents.
+#[allow(clippy::unnecessary_wraps)]
+fn agent_monotonic_now(_this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Ok((TIME_ORIGIN.elapsed().as_millis() as f64).into())
+}
+
+/// The state of an agent started with `$262.agent.start()`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ChildAgent {
+    #[unsafe_ignore_trace]
+    broadcasts: Rc<mpsc::Receiver<Broadcast>>,
+    #[unsafe_ignore_trace]
+    reports: Reports,
+}
+
+/// Creates the `$262.agent` object of an agent started with `$262.agent.start()`.
+fn init_child_agent(
+    context: &mut Context,
+    broadcasts: mpsc::Receiver<Broadcast>,
+    reports: Reports,
+) -> JsObject {
+    let agent = ChildAgent {
+        broadcasts: Rc::new(broadcasts),
+        reports,
+    };
+
+    let receive_broadcast =
+        FunctionBuilder::closure_with_captures(context, agent_receive_broadcast, agent.clone())
+            .name("receiveBroadcast")
+            .length(1)
+            .build();
+    let report = FunctionBuilder::closure_with_captures(context, agent_report, agent)
+        .name("report")
+        .length(1)
+        .build();
+
+    ObjectInitializer::new(context)
+        .property("receiveBroadcast", receive_broadcast, Attribute::default())
+        .property("report", report, Attribute::default())
+        .function(agent_leaving, "leaving", 0)
+        .function(agent_sleep, "sleep", 1)
+        .function(agent_monotonic_now, "monotonicNow", 0)
+        .build()
+}
+
+/// The `$262.agent.receiveBroadcast()` function of the agents.
+///
+/// Blocks until the main agent broadcasts a message, and then calls the callback with the
+/// `SharedArrayBuffer` and the id of the message.
+fn agent_receive_broadcast(
+    _this: &JsValue,
+    args: &[JsValue],
+    agent: &mut ChildAgent,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let callback = args
+        .get_or_undefined(0)
+        .as_callable()
+        .cloned()
+        .ok_or_else(|| JsNativeError::typ().with_message("the callback must be callable"))?;
+
+    // The main agent stopped, so no message will ever be received.
+    let message = if let Ok(message) = agent.broadcasts.recv() {
+        message
+    } else {
+        return Ok(JsValue::undefined());
+    };
+    let _ignored = message.received.send(());
+
+    let buffer = JsSharedArrayBuffer::from_memory(message.memory, context)?;
+    let id = match message.id {
+        BroadcastId::Number(n) => JsValue::from(n),
+        BroadcastId::BigInt(n) => JsBigInt::from_string(&n)
+            .expect("the id must be the string representation of a BigInt")
+            .into(),
+    };
+
+    callback.call(&JsValue::undefined(), &[buffer.into(), id], context)?;
+    context.run_jobs()?;
+
+    Ok(JsValue::undefined())
+}
+
+/// The `$262.agent.report()` function of the agents.
+///
+/// Sends a string to the main agent, which receives it with `$262.agent.getReport()`.
+fn agent_report(
+    _this: &JsValue,
+    args: &[JsValue],
+    agent: &mut ChildAgent,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let report = args.get_or_undefined(0).to_string(context)?;
+    agent
+        .reports
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push_back(report.to_std_string_escaped());
+
+    Ok(JsValue::undefined())
+}
+
+/// The `$262.agent.leaving()` function of the agents.
+///
+/// Signals that the agent is done. The agent finishes when its script finishes, so this does
+/// nothing.
+#[allow(clippy::unnecessary_wraps)]
+fn agent_leaving(_this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Ok(JsValue::undefined())
+}
@@ -7,13 +7,17 @@ use super::{
     TestSuite, IGNORED,
 };
 use boa_engine::{
-    builtins::JsArgs, object::FunctionBuilder, property::Attribute, syntax::Parser, Context,
-    JsResult, JsValue,
+    builtins::{temporal::ZoneInfoTimeZoneProvider, JsArgs},
+    module::{Module, SimpleModuleLoader},
+    object::FunctionBuilder,
+    property::Attribute,
+    syntax::Parser,
+    Context, JsResult, JsValue,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use colored::Colorize;
 use rayon::prelude::*;
-use std::panic;
+use std::{panic, path::Path, rc::Rc};
 
 impl TestSuite {
     /// Runs the test suite.
@@ -114,6 +118,11 @@ impl TestSuite {
 impl Test {
     /// Runs the test.
     pub(crate) fn run(&self, harness: &Harness, verbose: u8) -> Vec<TestResult> {
+        // Module code is always strict, so modules are only run once, without any directive.
+        if self.flags.contains(TestFlags::MODULE) {
+            return vec![self.run_once(harness, false, verbose)];
+        }
+
         let mut results = Vec::new();
         if self.flags.contains(TestFlags::STRICT) && !self.flags.contains(TestFlags::RAW) {
             results.push(self.run_once(harness, true, verbose));
@@ -169,19 +178,21 @@ impl Test {
                 )) {
             let res = panic::catch_unwind(|| match self.expected_outcome {
                 Outcome::Positive => {
-                    let mut context = Context::default();
+                    let mut context = self.create_context();
 
                     let callback_obj = CallbackObject::default();
                     // TODO: timeout
                     match self.set_up_env(harness, &mut context, callback_obj.clone()) {
                         Ok(_) => {
-                            let res = context.eval(&test_content);
+                            let res = self.eval(&mut context, &test_content);
 
                             let passed = res.is_ok()
                                 && matches!(*callback_obj.result.borrow(), Some(true) | None);
                             let text = match res {
                                 Ok(val) => val.display().to_string(),
-                                Err(e) => format!("Uncaught {}", e.display()),
+                                Err(e) => {
+                                    format!("Uncaught {}", e.to_opaque(&mut context).display())
+                                }
                             };
 
                             (passed, text)
@@ -200,32 +211,73 @@ impl Test {
                         self.name
                     );
 
-                    let mut context = Context::default();
-                    match context.parse(&test_content) {
-                        Ok(statement_list) => match context.compile(&statement_list) {
-                            Ok(_) => (false, "StatementList compilation should fail".to_owned()),
-                            Err(e) => (true, format!("Uncaught {e:?}")),
-                        },
-                        Err(e) => (true, format!("Uncaught {e}")),
+                    let mut context = self.create_context();
+                    if self.flags.contains(TestFlags::MODULE) {
+                        match Module::parse(&test_content, Some(self.path.as_path()), &mut context)
+                        {
+                            Ok(_) => (false, "Module parsing should fail".to_owned()),
+                            Err(e) => (true, format!("Uncaught {e}")),
+                        }
+                    } else {
+                        match context.parse(&test_content) {
+                            Ok(statement_list) => match context.compile(&statement_list) {
+                                Ok(_) => {
+                                    (false, "StatementList compilation should fail".to_owned())
+                                }
+                                Err(e) => (true, format!("Uncaught {e:?}")),
+                            },
+                            Err(e) => (true, format!("Uncaught {e}")),
+                        }
                     }
                 }
                 Outcome::Negative {
                     phase: Phase::Resolution,
-                    error_type: _,
-                } => todo!("check module resolution errors"),
+                    ref error_type,
+                } => {
+                    let mut context = self.create_context();
+                    let res = self
+                        .parse_module(&test_content, &mut context)
+                        .and_then(|module| {
+                            module.load(&mut context)?;
+                            module.link(&mut context)
+                        });
+                    match res {
+                        Ok(_) => (false, "Module linking should fail".to_owned()),
+                        Err(e) => {
+                            let e = e.to_opaque(&mut context);
+                            let passed = e
+                                .display()
+                                .internals(true)
+                                .to_string()
+                                .contains(error_type.as_ref());
+
+                            (passed, format!("Uncaught {}", e.display()))
+                        }
+                    }
+                }
                 Outcome::Negative {
                     phase: Phase::Runtime,
                     ref error_type,
                 } => {
-                    let mut context = Context::default();
-                    if let Err(e) = Parser::new(test_content.as_bytes()).parse_all(&mut context) {
+                    let mut context = self.create_context();
+                    let parsed = if self.flags.contains(TestFlags::MODULE) {
+                        Parser::new(test_content.as_bytes())
+                            .parse_module(&mut context)
+                            .map(|_| ())
+                    } else {
+                        Parser::new(test_content.as_bytes())
+                            .parse_all(&mut context)
+                            .map(|_| ())
+                    };
+                    if let Err(e) = parsed {
                         (false, format!("Uncaught {e}"))
                     } else {
                         // TODO: timeout
                         match self.set_up_env(harness, &mut context, CallbackObject::default()) {
-                            Ok(_) => match context.eval(&test_content) {
+                            Ok(_) => match self.eval(&mut context, &test_content) {
                                 Ok(res) => (false, res.display().to_string()),
                                 Err(e) => {
+                                    let e = e.to_opaque(&mut context);
                                     let passed = e
                                         .display()
                                         .internals(true)
@@ -312,6 +364,58 @@ impl Test {
         }
     }
 
+    /// Creates the context used to run the test.
+    ///
+    /// Module tests can import the fixture files that are next to them, the main agent of
+    /// `CanBlockIsFalse` tests cannot be suspended by `Atomics.wait`, and the `Temporal` tests
+    /// use the time zone database of the system, if it has one.
+    fn create_context(&self) -> Context {
+        let mut builder =
+            Context::builder().can_block(!self.flags.contains(TestFlags::CAN_BLOCK_IS_FALSE));
+        if let Ok(provider) = ZoneInfoTimeZoneProvider::from_system() {
+            builder = builder.time_zone_provider(Rc::new(provider));
+        }
+        match self.module_loader() {
+            Some(loader) => builder.module_loader(Rc::new(loader)).build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Creates the loader of the modules imported by the test, if it is a module.
+    fn module_loader(&self) -> Option<SimpleModuleLoader> {
+        if !self.flags.contains(TestFlags::MODULE) {
+            return None;
+        }
+
+        let root = self.path.parent().unwrap_or_else(|| Path::new("."));
+        SimpleModuleLoader::new(root).ok()
+    }
+
+    /// Parses the test code as a module.
+    ///
+    /// The module is registered in the module map of its realm, since tests can import
+    /// themselves. The map is shared by all the loaders of the realm, so it is also seen by the
+    /// loader of the context.
+    fn parse_module(&self, code: &str, context: &mut Context) -> JsResult<Module> {
+        match self.module_loader() {
+            Some(loader) => loader.parse_entry_module(code, &self.path, context),
+            None => Module::parse(code, Some(self.path.as_path()), context),
+        }
+    }
+
+    /// Evaluates the test code, either as a script or as a module.
+    fn eval(&self, context: &mut Context, code: &str) -> JsResult<JsValue> {
+        if self.flags.contains(TestFlags::MODULE) {
+            let module = self.parse_module(code, context)?;
+            module.load(context)?;
+            module.link(context)?;
+            module.evaluate(context)?;
+            Ok(JsValue::undefined())
+        } else {
+            context.eval(code)
+        }
+    }
+
     /// Sets the environment up to run the test.
     fn set_up_env(
         &self,
@@ -331,15 +435,15 @@ impl Test {
 
         context
             .eval(harness.assert.as_ref())
-            .map_err(|e| format!("could not run assert.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run assert.js:\n{e}"))?;
         context
             .eval(harness.sta.as_ref())
-            .map_err(|e| format!("could not run sta.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run sta.js:\n{e}"))?;
 
         if self.flags.contains(TestFlags::ASYNC) {
             context
                 .eval(harness.doneprint_handle.as_ref())
-                .map_err(|e| format!("could not run doneprintHandle.js:\n{}", e.display()))?;
+                .map_err(|e| format!("could not run doneprintHandle.js:\n{e}"))?;
         }
 
         for include in self.includes.iter() {
@@ -351,12 +455,7 @@ impl Test {
                         .ok_or_else(|| format!("could not find the {include} include file."))?
                         .as_ref(),
                 )
-                .map_err(|e| {
-                    format!(
-                        "could not run the {include} include file:\nUncaught {}",
-                        e.display()
-                    )
-                })?;
+                .map_err(|e| format!("could not run the {include} include file:\nUncaught {e}"))?;
         }
 
         Ok(())
@@ -394,7 +493,7 @@ fn test262_print(
     _context: &mut Context,
 ) -> JsResult<JsValue> {
     if let Some(message) = args.get_or_undefined(0).as_string() {
-        *captures.result.borrow_mut() = Some(message.as_str() == "Test262:AsyncTestComplete");
+        *captures.result.borrow_mut() = Some(*message == "Test262:AsyncTestComplete");
     } else {
         *captures.result.borrow_mut() = Some(false);
     }
@@ -417,13 +417,14 @@ struct Test {
     expected_outcome: Outcome,
     includes: Box<[Box<str>]>,
     locale: Locale,
+    path: PathBuf,
     content: Box<str>,
 }
 
 impl Test {
     /// Creates a new test.
     #[inline]
-    fn new<N, C>(name: N, content: C, metadata: MetaData) -> Self
+    fn new<N, C>(name: N, path: PathBuf, content: C, metadata: MetaData) -> Self
     where
         N: Into<Box<str>>,
         C: Into<Box<str>>,
@@ -438,6 +439,7 @@ impl Test {
             expected_outcome: Outcome::from(metadata.negative),
             includes: metadata.includes,
             locale: metadata.locale,
+            path,
             content: content.into(),
         }
     }
@@ -178,7 +178,7 @@ pub(super) fn read_test(path: &Path) -> io::Result<Test> {
     let content = fs::read_to_string(path)?;
     let metadata = read_metadata(&content, path)?;
 
-    Ok(Test::new(name, content, metadata))
+    Ok(Test::new(name, path.to_path_buf(), content, metadata))
 }
 
 /// Reads the metadata from the input test code.